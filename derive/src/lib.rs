@@ -20,8 +20,8 @@ use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::spanned::Spanned;
 use syn::{
     parse::{Parse, ParseStream},
-    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Fields, GenericParam, Generics,
-    Ident, Index, LitStr, Meta, Token, Type, TypePath,
+    parse_macro_input, parse_quote, Attribute, Data, DataUnion, DeriveInput, Fields, GenericParam,
+    Generics, Ident, Index, LitStr, Meta, Token, Type, TypePath,
 };
 use syn::{Path, PathArguments};
 
@@ -51,6 +51,262 @@ pub fn derive_visit_immutable(input: proc_macro::TokenStream) -> proc_macro::Tok
     )
 }
 
+/// Implementation of `[#derive(HeapSize)]`
+#[proc_macro_derive(HeapSize)]
+pub fn derive_heap_size(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    if let Data::Union(data) = &input.data {
+        return union_error(data, "HeapSize");
+    }
+    let name = input.ident;
+
+    let mut generics = input.generics;
+    for param in &mut generics.params {
+        if let GenericParam::Type(ref mut type_param) = *param {
+            type_param
+                .bounds
+                .push(parse_quote!(sqlparser::ast::HeapSize));
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let body = heap_size_children(&input.data);
+
+    let expanded = quote! {
+        impl #impl_generics sqlparser::ast::HeapSize for #name #ty_generics #where_clause {
+            fn add_heap_size(&self, usage: &mut sqlparser::ast::HeapUsage) {
+                #body
+            }
+        }
+    };
+
+    proc_macro::TokenStream::from(expanded)
+}
+
+// Reject a union, whose active field a derive cannot know.
+fn union_error(data: &DataUnion, derive: &str) -> proc_macro::TokenStream {
+    syn::Error::new(
+        data.union_token.span,
+        format!("`{derive}` cannot be derived for unions"),
+    )
+    .to_compile_error()
+    .into()
+}
+
+// Generate one `add_heap_size` call per field. Types without fields own
+// nothing beyond their inline size.
+fn heap_size_children(data: &Data) -> TokenStream {
+    let add = |value: TokenStream, span: proc_macro2::Span| quote_spanned!(span => sqlparser::ast::HeapSize::add_heap_size(#value, usage););
+    match data {
+        Data::Struct(data) if data.fields.is_empty() => quote!(let _ = usage;),
+        Data::Struct(data) => {
+            let adds = data.fields.iter().enumerate().map(|(i, f)| match &f.ident {
+                Some(name) => add(quote!(&self.#name), f.span()),
+                None => {
+                    let index = Index::from(i);
+                    add(quote!(&self.#index), f.span())
+                }
+            });
+            quote!(#(#adds)*)
+        }
+        Data::Enum(data) if data.variants.iter().all(|v| v.fields.is_empty()) => {
+            quote!(let _ = usage;)
+        }
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|v| {
+                let name = &v.ident;
+                match &v.fields {
+                    Fields::Named(fields) => {
+                        let names = fields.named.iter().map(|f| &f.ident);
+                        let adds = fields
+                            .named
+                            .iter()
+                            .map(|f| add(f.ident.to_token_stream(), f.span()));
+                        quote!(Self::#name { #(#names),* } => { #(#adds)* })
+                    }
+                    Fields::Unnamed(fields) => {
+                        let names = (0..fields.unnamed.len()).map(|i| format_ident!("_{}", i));
+                        let adds =
+                            fields.unnamed.iter().enumerate().map(|(i, f)| {
+                                add(format_ident!("_{}", i).to_token_stream(), f.span())
+                            });
+                        quote!(Self::#name ( #(#names),* ) => { #(#adds)* })
+                    }
+                    Fields::Unit => quote!(Self::#name => {}),
+                }
+            });
+            quote! {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+        Data::Union(_) => unreachable!("unions are rejected by the derive"),
+    }
+}
+
+/// Implementation of `[#derive(AstCodec)]`
+#[proc_macro_derive(AstCodec)]
+pub fn derive_ast_codec(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    if let Data::Union(data) = &input.data {
+        return union_error(data, "AstCodec");
+    }
+    let name = input.ident;
+
+    let mut generics = input.generics;
+    // The name of a generic type does not identify its shape, so only
+    // types without type parameters are described once per schema.
+    let mut memoize = true;
+    for param in &mut generics.params {
+        if let GenericParam::Type(ref mut type_param) = *param {
+            type_param
+                .bounds
+                .push(parse_quote!(sqlparser::ast::AstCodec));
+            memoize = false;
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (encode, decode, describe) = ast_codec_children(&name, &input.data);
+    let type_name = name.to_string();
+
+    let expanded = quote! {
+        impl #impl_generics sqlparser::ast::AstCodec for #name #ty_generics #where_clause {
+            fn encode(&self, encoder: &mut sqlparser::ast::AstEncoder<'_>) {
+                #encode
+            }
+
+            fn decode(
+                decoder: &mut sqlparser::ast::AstDecoder<'_>,
+            ) -> ::core::result::Result<Self, sqlparser::ast::AstCodecError> {
+                #decode
+            }
+
+            fn describe(schema: &mut sqlparser::ast::AstSchema) {
+                if schema.begin_type(#type_name, #memoize) {
+                    #describe
+                    schema.end_type();
+                }
+            }
+        }
+    };
+
+    proc_macro::TokenStream::from(expanded)
+}
+
+// Generate the `encode`, `decode` and `describe` bodies. Fields are coded in
+// declaration order and enum variants by their position.
+fn ast_codec_children(name: &Ident, data: &Data) -> (TokenStream, TokenStream, TokenStream) {
+    let encode = |value: TokenStream, span: proc_macro2::Span| quote_spanned!(span => sqlparser::ast::AstCodec::encode(#value, encoder););
+    let decode = |span: proc_macro2::Span| quote_spanned!(span => sqlparser::ast::AstCodec::decode(decoder)?);
+    let describe = |field: String, ty: &Type| {
+        quote_spanned!(ty.span() =>
+            schema.field(#field);
+            <#ty as sqlparser::ast::AstCodec>::describe(schema);
+        )
+    };
+    let describe_fields = |fields: &Fields| {
+        let described = fields.iter().enumerate().map(|(i, f)| {
+            let field = match &f.ident {
+                Some(ident) => ident.to_string(),
+                None => i.to_string(),
+            };
+            describe(field, &f.ty)
+        });
+        quote!(#(#described)*)
+    };
+    let construct = |path: TokenStream, fields: &Fields| match fields {
+        Fields::Named(fields) => {
+            let values = fields.named.iter().map(|f| {
+                let name = &f.ident;
+                let value = decode(f.span());
+                quote!(#name: #value)
+            });
+            quote!(#path { #(#values),* })
+        }
+        Fields::Unnamed(fields) => {
+            let values = fields.unnamed.iter().map(|f| decode(f.span()));
+            quote!(#path ( #(#values),* ))
+        }
+        Fields::Unit => path,
+    };
+    match data {
+        Data::Struct(data) => {
+            let encodes = data.fields.iter().enumerate().map(|(i, f)| match &f.ident {
+                Some(name) => encode(quote!(&self.#name), f.span()),
+                None => {
+                    let index = Index::from(i);
+                    encode(quote!(&self.#index), f.span())
+                }
+            });
+            let encode_body = if data.fields.is_empty() {
+                quote!(let _ = encoder;)
+            } else {
+                quote!(#(#encodes)*)
+            };
+            let value = construct(quote!(Self), &data.fields);
+            let decode_body = if data.fields.is_empty() {
+                quote!(let _ = decoder; Ok(#value))
+            } else {
+                quote!(Ok(#value))
+            };
+            (encode_body, decode_body, describe_fields(&data.fields))
+        }
+        Data::Enum(data) => {
+            let encode_arms = data.variants.iter().enumerate().map(|(index, v)| {
+                let name = &v.ident;
+                match &v.fields {
+                    Fields::Named(fields) => {
+                        let names = fields.named.iter().map(|f| &f.ident);
+                        let encodes = fields
+                            .named
+                            .iter()
+                            .map(|f| encode(f.ident.to_token_stream(), f.span()));
+                        quote!(Self::#name { #(#names),* } => { encoder.write_len(#index); #(#encodes)* })
+                    }
+                    Fields::Unnamed(fields) => {
+                        let names = (0..fields.unnamed.len()).map(|i| format_ident!("_{}", i));
+                        let encodes = fields.unnamed.iter().enumerate().map(|(i, f)| {
+                            encode(format_ident!("_{}", i).to_token_stream(), f.span())
+                        });
+                        quote!(Self::#name ( #(#names),* ) => { encoder.write_len(#index); #(#encodes)* })
+                    }
+                    Fields::Unit => quote!(Self::#name => encoder.write_len(#index)),
+                }
+            });
+            let decode_arms = data.variants.iter().enumerate().map(|(index, v)| {
+                let variant = &v.ident;
+                let index = index as u64;
+                let value = construct(quote!(Self::#variant), &v.fields);
+                quote!(#index => Ok(#value))
+            });
+            let type_name = name.to_string();
+            let describe_variants = data.variants.iter().map(|v| {
+                let variant = v.ident.to_string();
+                let fields = describe_fields(&v.fields);
+                quote!(schema.variant(#variant); #fields)
+            });
+            (
+                quote! {
+                    match self {
+                        #(#encode_arms,)*
+                    }
+                },
+                quote! {
+                    match decoder.read_variant()? {
+                        #(#decode_arms,)*
+                        index => Err(sqlparser::ast::AstCodecError::InvalidVariant {
+                            type_name: #type_name,
+                            index,
+                        }),
+                    }
+                },
+                quote!(#(#describe_variants)*),
+            )
+        }
+        Data::Union(_) => unreachable!("unions are rejected by the derive"),
+    }
+}
+
 struct VisitType {
     visit_trait: TokenStream,
     visitor_trait: TokenStream,
@@ -21,6 +21,7 @@ use std::time::Duration;
 use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
 use sqlparser::dialect::PostgreSqlDialect;
 use sqlparser::parser::Parser;
+use sqlparser::splitter::split_statements;
 use sqlparser::tokenizer::Tokenizer;
 use sqlparser::ParsedSql;
 use sqlparser_bench::postgres_corpus::{postgres_corpus, Family, QueryCase, Tier};
@@ -201,6 +202,26 @@ fn public_tokenizer(c: &mut Criterion) {
     group.finish();
 }
 
+fn statement_splitter(c: &mut Criterion) {
+    let corpus = postgres_corpus();
+    let workload = corpus
+        .iter()
+        .filter(|case| case.family != Family::Scaling)
+        .collect::<Vec<_>>();
+    let dialect = PostgreSqlDialect {};
+    let mut group = c.benchmark_group("postgres/split_statements");
+
+    group.throughput(Throughput::Bytes(bytes(&workload)));
+    group.bench_function("workload", |b| {
+        b.iter(|| {
+            for case in &workload {
+                black_box(split_statements(&dialect, black_box(&case.sql)).unwrap());
+            }
+        });
+    });
+    group.finish();
+}
+
 fn parser_core(c: &mut Criterion) {
     let corpus = postgres_corpus();
     let dialect = PostgreSqlDialect {};
@@ -299,6 +320,6 @@ criterion_group! {
         .sample_size(40)
         .noise_threshold(0.02)
         .significance_level(0.05);
-    targets = end_to_end, document, parser_prepare, public_tokenizer, parser_core, sentinels, scaling_curves
+    targets = end_to_end, document, parser_prepare, public_tokenizer, statement_splitter, parser_core, sentinels, scaling_curves
 }
 criterion_main!(benches);
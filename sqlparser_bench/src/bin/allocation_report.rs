@@ -18,17 +18,20 @@
 use std::alloc::{GlobalAlloc, Layout, System};
 use std::hint::black_box;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use sqlparser::dialect::PostgreSqlDialect;
-use sqlparser::parser::Parser;
+use sqlparser::dialect::{Dialect, PostgreSqlDialect};
+use sqlparser::parser::{Parser, ParserOptions};
 use sqlparser::tokenizer::Tokenizer;
-use sqlparser::ParsedSql;
+use sqlparser::{AstChunkPool, ParsedSql};
 use sqlparser_bench::postgres_corpus::{postgres_corpus, Family, QueryCase};
 
 struct CountingAllocator;
 
 static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
 static REALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static DEALLOCATIONS: AtomicU64 = AtomicU64::new(0);
 static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
 static LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
 static BASELINE_LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
@@ -55,6 +58,7 @@ unsafe impl GlobalAlloc for CountingAllocator {
     }
 
     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        DEALLOCATIONS.fetch_add(1, Ordering::Relaxed);
         LIVE_BYTES.fetch_sub(layout.size() as u64, Ordering::Relaxed);
         // SAFETY: The pointer and layout came from the system allocator.
         unsafe { System.dealloc(ptr, layout) }
@@ -75,11 +79,9 @@ unsafe impl GlobalAlloc for CountingAllocator {
 
 fn record_live_allocation(bytes: u64) {
     let live = LIVE_BYTES.fetch_add(bytes, Ordering::Relaxed) + bytes;
-    let _ = PEAK_LIVE_BYTES.fetch_update(
-        Ordering::Relaxed,
-        Ordering::Relaxed,
-        |peak| (live > peak).then_some(live),
-    );
+    let _ = PEAK_LIVE_BYTES.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |peak| {
+        (live > peak).then_some(live)
+    });
 }
 
 #[derive(Clone, Copy)]
@@ -98,6 +100,12 @@ struct ArenaSnapshot {
     requested_bytes: u64,
     committed_bytes: u64,
     slack_bytes: u64,
+    list_allocations: u64,
+    list_bytes: u64,
+    borrowed_leaves: u64,
+    borrowed_leaf_bytes: u64,
+    copied_leaves: u64,
+    copied_leaf_bytes: u64,
 }
 
 fn reset() {
@@ -140,6 +148,50 @@ fn print_result(pipeline: &str, subset: &str, query_count: usize, result: Alloca
     );
 }
 
+/// Deallocations and wall time spent dropping `value`.
+fn measure_teardown<T>(value: T) -> (u64, Duration) {
+    let deallocations = DEALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    drop(value);
+    let elapsed = start.elapsed();
+    (
+        DEALLOCATIONS.load(Ordering::Relaxed) - deallocations,
+        elapsed,
+    )
+}
+
+fn print_teardown(
+    pipeline: &str,
+    subset: &str,
+    query_count: usize,
+    destructor_free: usize,
+    (deallocations, elapsed): (u64, Duration),
+) {
+    println!(
+        "{pipeline:<16} {subset:<18} {query_count:>3}  {destructor_free:>8}  {deallocations:>8}  {nanos:>12}",
+        nanos = elapsed.as_nanos(),
+    );
+}
+
+/// Fastest of `runs` timed passes of `f`.
+fn measure_best(runs: usize, mut f: impl FnMut()) -> Duration {
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+fn print_lifecycle(pipeline: &str, subset: &str, query_count: usize, elapsed: Duration) {
+    println!(
+        "{pipeline:<16} {subset:<18} {query_count:>3}  {nanos:>12}",
+        nanos = elapsed.as_nanos(),
+    );
+}
+
 fn print_arena_result(subset: &str, query_count: usize, result: &ArenaSnapshot) {
     println!(
         "arena_storage    {subset:<18} {query_count:>3}  {nodes:>8}  {source:>12}  {requested:>12}  {committed:>12}  {slack:>12}",
@@ -149,8 +201,27 @@ fn print_arena_result(subset: &str, query_count: usize, result: &ArenaSnapshot)
         committed = result.committed_bytes,
         slack = result.slack_bytes,
     );
+    println!(
+        "arena_lists      {subset:<18} {query_count:>3}  {lists:>8}  {list_bytes:>12}",
+        lists = result.list_allocations,
+        list_bytes = result.list_bytes,
+    );
+    println!(
+        "leaf_text        {subset:<18} {query_count:>3}  {borrowed:>8}  {copied:>8}  {borrowed_bytes:>12}  {copied_bytes:>12}",
+        borrowed = result.borrowed_leaves,
+        copied = result.copied_leaves,
+        borrowed_bytes = result.borrowed_leaf_bytes,
+        copied_bytes = result.copied_leaf_bytes,
+    );
 }
 
+/// Idle chunk retention of the pool used by the `document_pooled` row.
+const POOL_RETAINED_BYTES: usize = 256 * 1024;
+
+/// Timed passes per family for the `lifecycle_*` rows, of which the fastest
+/// is reported.
+const LIFECYCLE_RUNS: usize = 21;
+
 fn main() {
     let corpus = postgres_corpus();
     let dialect = PostgreSqlDialect {};
@@ -185,7 +256,9 @@ fn main() {
     }
 
     if std::env::args().any(|arg| arg == "--arena-details") {
-        println!("\ncase,source_bytes,tokens,nodes,requested,committed,slack");
+        println!(
+            "\ncase,source_bytes,tokens,nodes,requested,committed,slack,lists,borrowed_leaves,copied_leaves"
+        );
         for case in &corpus {
             let tokens = Tokenizer::new(&dialect, &case.sql)
                 .tokenize_with_location()
@@ -194,7 +267,7 @@ fn main() {
             let document = ParsedSql::parse(&dialect, case.sql.as_str()).unwrap();
             let stats = document.arena_stats();
             println!(
-                "{},{},{},{},{},{},{}",
+                "{},{},{},{},{},{},{},{},{},{}",
                 case.id,
                 case.sql.len(),
                 tokens,
@@ -202,6 +275,9 @@ fn main() {
                 stats.requested_bytes,
                 stats.committed_bytes,
                 stats.slack_bytes,
+                stats.list_allocations,
+                stats.borrowed_leaves,
+                stats.copied_leaves,
             );
         }
     }
@@ -225,12 +301,113 @@ fn report_subset(name: &str, cases: &[&QueryCase], dialect: &PostgreSqlDialect)
             arena.requested_bytes += stats.requested_bytes as u64;
             arena.committed_bytes += stats.committed_bytes as u64;
             arena.slack_bytes += stats.slack_bytes as u64;
+            arena.list_allocations += stats.list_allocations as u64;
+            arena.list_bytes += stats.list_bytes as u64;
+            arena.borrowed_leaves += stats.borrowed_leaves as u64;
+            arena.borrowed_leaf_bytes += stats.borrowed_leaf_bytes as u64;
+            arena.copied_leaves += stats.copied_leaves as u64;
+            arena.copied_leaf_bytes += stats.copied_leaf_bytes as u64;
             black_box(document);
         }
     });
     print_result("document", name, cases.len(), document);
     print_arena_result(name, cases.len(), &arena);
 
+    // The same parse-and-drop loop drawing chunks from a warmed pool, as a
+    // server parsing a steady stream of statements would.
+    let pool = Arc::new(AstChunkPool::new(POOL_RETAINED_BYTES));
+    let options = ParserOptions::new()
+        .with_trailing_commas(dialect.supports_trailing_commas())
+        .with_chunk_pool(Arc::clone(&pool));
+    for case in cases {
+        black_box(
+            ParsedSql::parse_with_options(dialect, case.sql.as_str(), options.clone()).unwrap(),
+        );
+    }
+    let warm = pool.stats();
+    let document_pooled = measure(|| {
+        for case in cases {
+            black_box(
+                ParsedSql::parse_with_options(dialect, case.sql.as_str(), options.clone()).unwrap(),
+            );
+        }
+    });
+    print_result("document_pooled", name, cases.len(), document_pooled);
+    let stats = pool.stats();
+    println!(
+        "{:<16} {name:<18} {:>3}  hits {:>8}  misses {:>8}  retained {:>12}",
+        "chunk_pool",
+        cases.len(),
+        stats.hits - warm.hits,
+        stats.misses - warm.misses,
+        stats.retained_bytes,
+    );
+
+    // Keep every document alive to compare heap retention with the arena
+    // bytes the documents report.
+    let mut documents = Vec::with_capacity(cases.len());
+    let document_live = measure(|| {
+        for case in cases {
+            documents.push(ParsedSql::parse(dialect, case.sql.as_str()).unwrap());
+        }
+    });
+    print_result("document_live", name, cases.len(), document_live);
+
+    // Rewrite every live document without editing it, as a preprocessing
+    // pass that finds nothing to change does. The parents stay alive, so
+    // this reports what the rewrites retain in addition to them.
+    let mut rewrites = Vec::with_capacity(cases.len());
+    let document_rewrite = measure(|| {
+        for document in &documents {
+            rewrites.push(document.rewrite_with_session(|_| ()).0);
+        }
+    });
+    print_result("document_rewrite", name, cases.len(), document_rewrite);
+    drop(rewrites);
+
+    // Teardown: documents proven destructor-free release their chunks without
+    // walking the tree. Owned parses always walk it.
+    let destructor_free = documents
+        .iter()
+        .filter(|document| document.arena_stats().destructor_free)
+        .count();
+    print_teardown(
+        "teardown_doc",
+        name,
+        cases.len(),
+        destructor_free,
+        measure_teardown(documents),
+    );
+    let owned = cases
+        .iter()
+        .map(|case| Parser::parse_sql(dialect, &case.sql).unwrap())
+        .collect::<Vec<_>>();
+    print_teardown(
+        "teardown_owned",
+        name,
+        cases.len(),
+        0,
+        measure_teardown(owned),
+    );
+
+    // End to end: parse, freeze and drop every document, so the time spent
+    // proving documents destructor-free is charged against what their
+    // teardown saves. Owned parses are timed the same way.
+    let lifecycle_doc = measure_best(LIFECYCLE_RUNS, || {
+        for case in cases {
+            drop(black_box(
+                ParsedSql::parse(dialect, case.sql.as_str()).unwrap(),
+            ));
+        }
+    });
+    print_lifecycle("lifecycle_doc", name, cases.len(), lifecycle_doc);
+    let lifecycle_owned = measure_best(LIFECYCLE_RUNS, || {
+        for case in cases {
+            drop(black_box(Parser::parse_sql(dialect, &case.sql).unwrap()));
+        }
+    });
+    print_lifecycle("lifecycle_owned", name, cases.len(), lifecycle_owned);
+
     let prepare = measure(|| {
         for case in cases {
             black_box(Parser::new(dialect).try_with_sql(&case.sql).unwrap());
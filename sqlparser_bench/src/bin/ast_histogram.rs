@@ -0,0 +1,106 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! Per-node-type `AstBox` allocation counts for each corpus family.
+//!
+//! Run with `cargo run --release --features ast-histogram --bin ast_histogram`.
+//! Pass `--all` to list every type instead of the largest ones.
+
+use std::hint::black_box;
+
+use sqlparser::dialect::PostgreSqlDialect;
+use sqlparser::{AstAllocationHistogram, ParsedSql};
+use sqlparser_bench::postgres_corpus::{postgres_corpus, Family};
+
+/// Types listed per family, by bytes allocated, unless `--all` is passed.
+const TOP_TYPES: usize = 12;
+
+fn main() {
+    let corpus = postgres_corpus();
+    let dialect = PostgreSqlDialect {};
+    let all = std::env::args().any(|arg| arg == "--all");
+
+    for family in Family::ALL {
+        let cases = corpus
+            .iter()
+            .filter(|case| case.family == family)
+            .collect::<Vec<_>>();
+        let mut histogram = AstAllocationHistogram::default();
+        for case in &cases {
+            let document = black_box(ParsedSql::parse(&dialect, case.sql.as_str()).unwrap());
+            histogram.merge(&document.arena_stats().allocations_by_type);
+        }
+        print_family(family.as_str(), cases.len(), &histogram, all);
+    }
+}
+
+fn print_family(family: &str, query_count: usize, histogram: &AstAllocationHistogram, all: bool) {
+    let total = histogram.total();
+    println!("\n{family} ({query_count} SQL)");
+    println!("type                              allocs      bytes  bytes %  bytes/alloc");
+    println!("------------------------------ --------- ---------- -------- ------------");
+
+    let mut types = histogram.iter().collect::<Vec<_>>();
+    types.sort_by(|(left_name, left), (right_name, right)| {
+        right.bytes.cmp(&left.bytes).then(left_name.cmp(right_name))
+    });
+    let listed = if all { types.len() } else { TOP_TYPES };
+    let mut rest = (0, 0);
+    for (index, (name, counts)) in types.iter().enumerate() {
+        if index < listed {
+            print_row(
+                short_type_name(name),
+                counts.allocations,
+                counts.bytes,
+                total.bytes,
+            );
+        } else {
+            rest.0 += counts.allocations;
+            rest.1 += counts.bytes;
+        }
+    }
+    if rest.0 > 0 {
+        let other = format!("({} other types)", types.len() - listed);
+        print_row(&other, rest.0, rest.1, total.bytes);
+    }
+    print_row("total", total.allocations, total.bytes, total.bytes);
+}
+
+fn print_row(name: &str, allocations: u64, bytes: u64, total_bytes: u64) {
+    println!(
+        "{name:<30} {allocations:>9} {bytes:>10} {share:>7.1}% {average:>12.1}",
+        share = percent(bytes, total_bytes),
+        average = bytes as f64 / allocations.max(1) as f64,
+    );
+}
+
+fn percent(part: u64, whole: u64) -> f64 {
+    if whole == 0 {
+        0.0
+    } else {
+        part as f64 * 100.0 / whole as f64
+    }
+}
+
+/// `sqlparser::ast::query::Select` as `Select`, keeping generic arguments
+/// readable.
+fn short_type_name(name: &str) -> &str {
+    let generic = name.find('<').unwrap_or(name.len());
+    match name[..generic].rfind("::") {
+        Some(index) => &name[index + 2..],
+        None => name,
+    }
+}
@@ -0,0 +1,203 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The subcommands, each run once per input.
+
+use std::fs;
+
+use serde_json::{json, Value};
+use sqlparser::ast::Statement;
+use sqlparser::dialect::Dialect;
+use sqlparser::keywords::Keyword;
+use sqlparser::optimizer_hints::parse_optimizer_hints;
+use sqlparser::parser::{Parser, ParserError, ParserOptions, StatementError};
+use sqlparser::splitter::split_statements;
+use sqlparser::tokenizer::{Location, Span, TokenizerError};
+
+use crate::{FmtMode, Input, Status};
+
+/// Print `{ "path": ..., "statements": [...] }` with the AST of each
+/// statement.
+pub(crate) fn parse(dialect: &dyn Dialect, input: &Input, status: &mut Status) {
+    let parsed = Parser::new(dialect)
+        .with_options(parser_options(dialect))
+        .try_with_sql(&input.sql)
+        .and_then(|parser| parser.parse_statements());
+    match parsed {
+        Ok(statements) => print_json(json!({ "path": input.name, "statements": statements })),
+        Err(error) => status.error(parser_error(input, &error, Location::empty())),
+    }
+}
+
+/// Pretty-print the statements of an input with `{:#}`, with their
+/// comments, or compare or replace it with them, as `mode` says.
+///
+/// Comments are kept around statements, select items, column definitions
+/// and PL/SQL declarations. Inputs with comments elsewhere, such as inside
+/// an expression, are left as they are, with a warning.
+pub(crate) fn fmt(dialect: &dyn Dialect, input: &Input, mode: FmtMode, status: &mut Status) {
+    let options = parser_options(dialect).with_attach_comments(true);
+    let parsed = Parser::new(dialect)
+        .with_options(options)
+        .try_with_sql(&input.sql)
+        .and_then(|parser| {
+            let statements = parser.parse_statements()?;
+            Ok((statements, parser.unattached_comment_count()))
+        });
+    let formatted = match parsed {
+        Ok((statements, 0)) => format_statements(&statements),
+        Ok(_) => {
+            eprintln!(
+                "{}: warning: not formatted, since formatting would drop some of its comments",
+                input.name
+            );
+            input.sql.clone()
+        }
+        Err(error) => return status.error(parser_error(input, &error, Location::empty())),
+    };
+
+    match mode {
+        FmtMode::Print => print!("{formatted}"),
+        FmtMode::Check => {
+            if formatted != input.sql {
+                println!("{}", input.name);
+                status.failed = true;
+            }
+        }
+        FmtMode::Write => {
+            if let Some(path) = input.path.as_ref().filter(|_| formatted != input.sql) {
+                if let Err(error) = fs::write(path, formatted) {
+                    status.error(format!("{}: {error}", input.name));
+                }
+            }
+        }
+    }
+}
+
+/// Print every syntax error of an input, from a tolerant parse that keeps
+/// going after the first one.
+pub(crate) fn check(dialect: &dyn Dialect, input: &Input, status: &mut Status) {
+    let errors = match Parser::parse_sql_tolerant(dialect, &input.sql, parser_options(dialect)) {
+        Ok(partial) => partial.errors,
+        Err(error) => vec![StatementError {
+            error,
+            span: Span::empty(),
+        }],
+    };
+    for StatementError { error, span } in &errors {
+        println!("{}", parser_error(input, error, span.start));
+        status.failed = true;
+    }
+}
+
+/// Print `path:line:column: start..end KEYWORD` for each statement, with
+/// the byte range of the statement and the keyword it starts with.
+pub(crate) fn split(dialect: &dyn Dialect, input: &Input, status: &mut Status) {
+    let statements = match split_statements(dialect, &input.sql) {
+        Ok(statements) => statements,
+        Err(error) => return status.error(tokenizer_error(input, &error)),
+    };
+    for statement in statements {
+        let (line, column) = line_column(&input.sql, statement.range.start);
+        let keyword = match statement.keyword {
+            Keyword::NoKeyword => String::new(),
+            keyword => format!(" {keyword:?}"),
+        };
+        println!(
+            "{}:{line}:{column}: {}..{}{keyword}",
+            input.name, statement.range.start, statement.range.end
+        );
+    }
+}
+
+/// Print `{ "path": ..., "hints": [...] }` with the optimizer hints of an
+/// input.
+pub(crate) fn hints(dialect: &dyn Dialect, input: &Input, status: &mut Status) {
+    match parse_optimizer_hints(dialect, &input.sql) {
+        Ok(hints) => print_json(json!({ "path": input.name, "hints": hints })),
+        Err(error) => status.error(tokenizer_error(input, &error)),
+    }
+}
+
+/// The options `Parser::new` uses for `dialect`, which the commands
+/// start from.
+fn parser_options(dialect: &dyn Dialect) -> ParserOptions {
+    ParserOptions::new().with_trailing_commas(dialect.supports_trailing_commas())
+}
+
+/// The statements printed with `{:#}`, each followed by a semicolon, and
+/// separated by blank lines.
+fn format_statements(statements: &[Statement]) -> String {
+    statements
+        .iter()
+        .map(|statement| format!("{statement:#};\n"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn print_json(value: Value) {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&value).expect("JSON values serialize")
+    );
+}
+
+/// `path:line:column: message` for a parser error, placed at the token it
+/// was reported at, or else at `fallback`.
+fn parser_error(input: &Input, error: &ParserError, fallback: Location) -> String {
+    let Some(syntax) = error.syntax_error() else {
+        return format!("{}{error}", position(input, fallback));
+    };
+    // Errors at the end of the input are reported at the EOF token, which
+    // has no location.
+    let location = if syntax.span.start.line == 0 {
+        let (line, column) = line_column(&input.sql, input.sql.len());
+        Location::new(line as u64, column as u64)
+    } else {
+        syntax.span.start
+    };
+    let mut message = format!("{}{}", position(input, location), syntax.message);
+    if let Some(hint) = &syntax.hint {
+        message.push_str(&format!(" ({hint})"));
+    }
+    message
+}
+
+/// `path:line:column: message` for a tokenizer error.
+fn tokenizer_error(input: &Input, error: &TokenizerError) -> String {
+    format!("{}{}", position(input, error.location), error.message)
+}
+
+/// The `path:line:column: ` prefix of a message, or `path: ` when the
+/// location is unknown.
+fn position(input: &Input, location: Location) -> String {
+    if location.line == 0 {
+        format!("{}: ", input.name)
+    } else {
+        format!("{}:{}:{}: ", input.name, location.line, location.column)
+    }
+}
+
+/// The 1-based line and column, counted in characters, of byte `offset`.
+fn line_column(sql: &str, offset: usize) -> (usize, usize) {
+    let before = &sql[..offset];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
@@ -0,0 +1,277 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The `sqlparser` command-line tool.
+//!
+//! Every subcommand reads SQL from the files named on the command line, from
+//! the `.sql` files below the directories named there, or from stdin when no
+//! path or `-` is given. Problems with an input are reported as
+//! `path:line:column: message` and make the tool exit with status 1, so it
+//! can run over migration directories in a pre-commit hook.
+
+mod commands;
+
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use sqlparser::dialect::{dialect_from_str, Dialect};
+
+/// Name of the dialect used without `--dialect`.
+const DEFAULT_DIALECT: &str = "postgresql";
+
+const USAGE: &str = "\
+Usage: sqlparser <COMMAND> [OPTIONS] [PATH]...
+
+Commands:
+  parse   Print the AST of each input as JSON
+  fmt     Pretty-print each input
+  check   Report every syntax error as PATH:LINE:COLUMN: MESSAGE
+  split   Print the position and byte range of each statement
+  hints   Print the optimizer hints of each input as JSON
+
+Options:
+  --dialect <NAME>  SQL dialect: postgresql, mysql, mssql or oracle
+                    [default: postgresql]
+  --check           fmt: list inputs that are not formatted instead of
+                    printing them
+  --write           fmt: rewrite files that are not formatted
+  -h, --help        Print this help
+  -V, --version     Print the version
+
+Directories are searched recursively for .sql files. Without a PATH, or
+with `-`, SQL is read from stdin.
+";
+
+/// A subcommand of the tool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Parse,
+    Fmt,
+    Check,
+    Split,
+    Hints,
+}
+
+/// What `fmt` does with its output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FmtMode {
+    /// Print the formatted inputs.
+    Print,
+    /// List the inputs whose formatting differs.
+    Check,
+    /// Rewrite the files whose formatting differs.
+    Write,
+}
+
+/// Parsed command-line arguments.
+struct Options {
+    command: Command,
+    dialect: Box<dyn Dialect>,
+    fmt_mode: FmtMode,
+    paths: Vec<String>,
+}
+
+/// One SQL text to process.
+struct Input {
+    /// The path as shown in messages, or `<stdin>`.
+    name: String,
+    /// The file to write back to, if the input is a file.
+    path: Option<PathBuf>,
+    sql: String,
+}
+
+/// The outcome of running a subcommand over all inputs.
+#[derive(Debug, Default)]
+struct Status {
+    failed: bool,
+}
+
+impl Status {
+    /// Report a problem with an input on stderr.
+    fn error(&mut self, message: impl AsRef<str>) {
+        eprintln!("{}", message.as_ref());
+        self.failed = true;
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(Some(options)) => options,
+        Ok(None) => return ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("sqlparser: {message}\n\nTry 'sqlparser --help' for more information.");
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut status = Status::default();
+    for input in read_inputs(&options.paths, &mut status) {
+        if options.fmt_mode == FmtMode::Write && input.path.is_none() {
+            status.error("sqlparser: --write cannot rewrite stdin");
+            continue;
+        }
+        let dialect = &*options.dialect;
+        match options.command {
+            Command::Parse => commands::parse(dialect, &input, &mut status),
+            Command::Fmt => commands::fmt(dialect, &input, options.fmt_mode, &mut status),
+            Command::Check => commands::check(dialect, &input, &mut status),
+            Command::Split => commands::split(dialect, &input, &mut status),
+            Command::Hints => commands::hints(dialect, &input, &mut status),
+        }
+    }
+    if status.failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Parse the arguments after the program name. `Ok(None)` means that help
+/// or the version was printed.
+fn parse_args(args: &[String]) -> Result<Option<Options>, String> {
+    let Some(command) = args.first() else {
+        return Err("missing command".to_string());
+    };
+    let command = match command.as_str() {
+        "-h" | "--help" | "help" => {
+            print!("{USAGE}");
+            return Ok(None);
+        }
+        "-V" | "--version" => {
+            println!("sqlparser {}", env!("CARGO_PKG_VERSION"));
+            return Ok(None);
+        }
+        "parse" => Command::Parse,
+        "fmt" => Command::Fmt,
+        "check" => Command::Check,
+        "split" => Command::Split,
+        "hints" => Command::Hints,
+        other => return Err(format!("unknown command '{other}'")),
+    };
+
+    let mut dialect_name = DEFAULT_DIALECT.to_string();
+    let mut fmt_mode = FmtMode::Print;
+    let mut paths = Vec::new();
+    let mut args = args[1..].iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                print!("{USAGE}");
+                return Ok(None);
+            }
+            "--dialect" => {
+                dialect_name = args.next().ok_or("--dialect needs a value")?.to_string();
+            }
+            "--check" | "--write" if command != Command::Fmt => {
+                return Err(format!("{arg} only applies to fmt"));
+            }
+            "--check" | "--write" if fmt_mode != FmtMode::Print => {
+                return Err("--check and --write cannot be combined".to_string());
+            }
+            "--check" => fmt_mode = FmtMode::Check,
+            "--write" => fmt_mode = FmtMode::Write,
+            "--" => paths.extend(args.by_ref().cloned()),
+            _ => {
+                if let Some(name) = arg.strip_prefix("--dialect=") {
+                    dialect_name = name.to_string();
+                } else if arg.starts_with('-') && arg != "-" {
+                    return Err(format!("unknown option '{arg}'"));
+                } else {
+                    paths.push(arg.clone());
+                }
+            }
+        }
+    }
+    let dialect = dialect_from_str(&dialect_name)
+        .ok_or_else(|| format!("unknown dialect '{dialect_name}'"))?;
+    Ok(Some(Options {
+        command,
+        dialect,
+        fmt_mode,
+        paths,
+    }))
+}
+
+/// Read every input named by `paths`, in order, reporting the ones that
+/// cannot be read.
+fn read_inputs(paths: &[String], status: &mut Status) -> Vec<Input> {
+    if paths.is_empty() {
+        return read_stdin(status).into_iter().collect();
+    }
+    let mut inputs = Vec::new();
+    for path in paths {
+        if path == "-" {
+            inputs.extend(read_stdin(status));
+            continue;
+        }
+        let mut files = Vec::new();
+        let path = Path::new(path);
+        if path.is_dir() {
+            if let Err(error) = collect_sql_files(path, &mut files) {
+                status.error(format!("{}: {error}", path.display()));
+            }
+        } else {
+            files.push(path.to_path_buf());
+        }
+        for file in files {
+            match fs::read_to_string(&file) {
+                Ok(sql) => inputs.push(Input {
+                    name: file.display().to_string(),
+                    path: Some(file),
+                    sql,
+                }),
+                Err(error) => status.error(format!("{}: {error}", file.display())),
+            }
+        }
+    }
+    inputs
+}
+
+fn read_stdin(status: &mut Status) -> Option<Input> {
+    let mut sql = String::new();
+    if let Err(error) = io::stdin().read_to_string(&mut sql) {
+        status.error(format!("<stdin>: {error}"));
+        return None;
+    }
+    Some(Input {
+        name: "<stdin>".to_string(),
+        path: None,
+        sql,
+    })
+}
+
+/// Add the `.sql` files below `dir` to `files`, in path order.
+fn collect_sql_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    let mut entries = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            collect_sql_files(&path, files)?;
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("sql"))
+        {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
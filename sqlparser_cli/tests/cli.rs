@@ -0,0 +1,232 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tests that run the `sqlparser` binary over files in a scratch directory.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+use serde_json::Value;
+
+/// A directory of SQL files, removed when dropped.
+struct Scratch(PathBuf);
+
+impl Scratch {
+    fn new(name: &str, files: &[(&str, &str)]) -> Self {
+        let dir = std::env::temp_dir().join(format!("sqlparser-cli-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        for (path, sql) in files {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, sql).unwrap();
+        }
+        Self(dir)
+    }
+
+    fn path(&self, path: &str) -> String {
+        self.0.join(path).display().to_string()
+    }
+}
+
+impl Drop for Scratch {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn sqlparser(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_sqlparser"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+fn stderr(output: &Output) -> &str {
+    std::str::from_utf8(&output.stderr).unwrap()
+}
+
+#[test]
+fn check_reports_every_error_with_its_position() {
+    let scratch = Scratch::new(
+        "check",
+        &[
+            ("migrations/001_init.sql", "CREATE TABLE t (a INT);\n"),
+            (
+                "migrations/002_broken.sql",
+                "SELECT a, FROM t;\nSELECT 1;\nSELECT c FROM t WHERE (d;\n",
+            ),
+            ("migrations/notes.txt", "not SQL"),
+        ],
+    );
+    let output = sqlparser(&["check", &scratch.path("migrations")], "");
+    let broken = scratch.path("migrations/002_broken.sql");
+    assert_eq!(
+        stdout(&output),
+        format!(
+            "{broken}:1:11: Expected: an expression, found: FROM\n\
+             {broken}:3:25: Expected: ), found: ;\n"
+        )
+    );
+    assert_eq!(output.status.code(), Some(1));
+
+    let output = sqlparser(&["check", &scratch.path("migrations/001_init.sql")], "");
+    assert_eq!(stdout(&output), "");
+    assert!(output.status.success());
+}
+
+#[test]
+fn parse_prints_the_ast_as_json() {
+    let output = sqlparser(&["parse"], "SELECT a FROM t");
+    assert!(output.status.success(), "{}", stderr(&output));
+    let json: Value = serde_json::from_str(stdout(&output)).unwrap();
+    assert_eq!(json["path"], "<stdin>");
+    let statements = json["statements"].as_array().unwrap();
+    assert_eq!(statements.len(), 1);
+    assert!(statements[0].get("Query").is_some());
+
+    let output = sqlparser(&["parse", "-"], "SELECT a FROM");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stderr(&output),
+        "<stdin>:1:14: Expected: identifier, found: EOF\n"
+    );
+}
+
+#[test]
+fn fmt_prints_checks_and_rewrites() {
+    let scratch = Scratch::new(
+        "fmt",
+        &[
+            ("a.sql", "select a from t where b = 1; select 2"),
+            ("b.sql", "SELECT\n  1;\n"),
+            ("c.sql", "-- keep me\nselect 3"),
+            ("d.sql", "select 1 + /* inner */ 2"),
+        ],
+    );
+    let formatted = "SELECT\n  a\nFROM\n  t\nWHERE\n  b = 1;\n\nSELECT\n  2;\n";
+
+    let output = sqlparser(&["fmt", &scratch.path("a.sql")], "");
+    assert_eq!(stdout(&output), formatted);
+    assert!(output.status.success());
+
+    let output = sqlparser(&["fmt", &scratch.path("c.sql")], "");
+    assert_eq!(stdout(&output), "-- keep me\nSELECT\n  3;\n");
+
+    let output = sqlparser(&["fmt", "--check", &scratch.path("")], "");
+    assert_eq!(
+        stdout(&output),
+        format!("{}\n{}\n", scratch.path("a.sql"), scratch.path("c.sql"))
+    );
+    assert!(stderr(&output).contains("d.sql: warning: not formatted"));
+    assert_eq!(output.status.code(), Some(1));
+
+    let output = sqlparser(&["fmt", "--write", &scratch.path("")], "");
+    assert!(output.status.success());
+    assert_eq!(
+        fs::read_to_string(scratch.path("a.sql")).unwrap(),
+        formatted
+    );
+    assert_eq!(
+        fs::read_to_string(scratch.path("c.sql")).unwrap(),
+        "-- keep me\nSELECT\n  3;\n"
+    );
+    assert_eq!(
+        fs::read_to_string(scratch.path("d.sql")).unwrap(),
+        "select 1 + /* inner */ 2"
+    );
+    let output = sqlparser(&["fmt", "--check", &scratch.path("")], "");
+    assert!(output.status.success());
+}
+
+#[test]
+fn split_prints_statement_boundaries() {
+    let sql = "DELIMITER $$\nCREATE PROCEDURE p() BEGIN SELECT 1; END$$\nDELIMITER ;\nSELECT 2;";
+    let output = sqlparser(&["split", "--dialect", "mysql"], sql);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        stdout(&output),
+        "<stdin>:2:1: 13..53 CREATE\n<stdin>:4:1: 68..76 SELECT\n"
+    );
+}
+
+#[test]
+fn hints_prints_optimizer_hints_as_json() {
+    let output = sqlparser(
+        &["hints", "--dialect=mysql"],
+        "SELECT /*+ USE_INDEX(t idx) NO_PARALLEL */ a FROM t",
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    let json: Value = serde_json::from_str(stdout(&output)).unwrap();
+    assert_eq!(
+        json["hints"],
+        serde_json::json!([
+            {
+                "directive": { "UseIndex": { "table": "t", "index": "idx" } },
+                "span": [7, 42],
+            },
+            { "directive": "NoParallel", "span": [7, 42] },
+        ])
+    );
+}
+
+#[test]
+fn usage_errors() {
+    for (args, message) in [
+        (&[][..], "missing command"),
+        (&["lint"][..], "unknown command 'lint'"),
+        (
+            &["check", "--dialect", "cobol"][..],
+            "unknown dialect 'cobol'",
+        ),
+        (&["check", "--dialect"][..], "--dialect needs a value"),
+        (&["check", "--write"][..], "--write only applies to fmt"),
+        (&["check", "--verbose"][..], "unknown option '--verbose'"),
+    ] {
+        let output = sqlparser(args, "");
+        assert_eq!(output.status.code(), Some(2), "{args:?}");
+        assert!(
+            stderr(&output).starts_with(&format!("sqlparser: {message}\n")),
+            "{}",
+            stderr(&output)
+        );
+    }
+
+    let output = sqlparser(&["--help"], "");
+    assert!(output.status.success());
+    assert!(stdout(&output).starts_with("Usage: sqlparser <COMMAND>"));
+
+    let missing = Path::new("/nonexistent/file.sql");
+    let output = sqlparser(&["check", missing.to_str().unwrap()], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).starts_with("/nonexistent/file.sql: "));
+}
@@ -0,0 +1,41 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A language server for SQL built on `sqlparser`.
+//!
+//! The server speaks the Language Server Protocol and provides:
+//!
+//! * diagnostics for every syntax error of a document, from a tolerant
+//!   parse that keeps going after the first error;
+//! * a document outline of the statements, with the common table
+//!   expressions of each query nested below it;
+//! * whole-document formatting with the `{:#}` pretty printer, for
+//!   documents that parse and have no comments;
+//! * keyword completion.
+//!
+//! Documents are parsed with the dialect named by the `dialect` setting,
+//! which is read from the `initializationOptions` of the `initialize`
+//! request and from the `sqlparser` section of
+//! `workspace/didChangeConfiguration`, and accepts the names known to
+//! [`dialect_from_str`](sqlparser::dialect::dialect_from_str). The default
+//! is [`DEFAULT_DIALECT`].
+
+mod server;
+mod text;
+pub mod transport;
+
+pub use server::{run, Server, DEFAULT_DIALECT};
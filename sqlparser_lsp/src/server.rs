@@ -0,0 +1,514 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Request and notification handlers.
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+
+use serde_json::{json, Value};
+use sqlparser::ast::{Query, Spanned, Statement};
+use sqlparser::completion::completions_at;
+use sqlparser::dialect::{dialect_from_str, Dialect};
+use sqlparser::parser::{Parser, ParserOptions, StatementError};
+use sqlparser::tokenizer::Span;
+use sqlparser::ParsedSql;
+
+use crate::text::LineIndex;
+use crate::transport::{read_message, write_message};
+
+/// Name of the dialect used until the client selects one.
+pub const DEFAULT_DIALECT: &str = "postgresql";
+
+// JSON-RPC and LSP error codes.
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const SERVER_NOT_INITIALIZED: i64 = -32002;
+
+// LSP enumeration values.
+const TEXT_DOCUMENT_SYNC_FULL: u8 = 1;
+const DIAGNOSTIC_SEVERITY_ERROR: u8 = 1;
+const MESSAGE_TYPE_WARNING: u8 = 2;
+const SYMBOL_KIND_OBJECT: u8 = 19;
+const SYMBOL_KIND_STRUCT: u8 = 23;
+const COMPLETION_ITEM_KIND_KEYWORD: u8 = 14;
+
+/// Longest statement summary shown in the document outline, in characters.
+const MAX_SYMBOL_NAME_CHARS: usize = 40;
+
+/// An error returned in place of a request result.
+struct ResponseError {
+    code: i64,
+    message: String,
+}
+
+impl ResponseError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// The state of one language server session: the selected dialect and the
+/// text of every open document.
+///
+/// [`Server::handle`] processes one decoded JSON-RPC message at a time, so
+/// the server can be driven without a transport; [`run`] connects it to a
+/// framed byte stream such as stdio.
+pub struct Server {
+    dialect_name: String,
+    dialect: Box<dyn Dialect>,
+    documents: HashMap<String, String>,
+    initialized: bool,
+    shutdown: bool,
+    exit_code: Option<i32>,
+    notifications: Vec<Value>,
+}
+
+impl Default for Server {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Server {
+    /// Create a server that uses [`DEFAULT_DIALECT`] and has no open
+    /// documents.
+    pub fn new() -> Self {
+        Self {
+            dialect_name: DEFAULT_DIALECT.to_string(),
+            dialect: dialect_from_str(DEFAULT_DIALECT).expect("default dialect exists"),
+            documents: HashMap::new(),
+            initialized: false,
+            shutdown: false,
+            exit_code: None,
+            notifications: Vec::new(),
+        }
+    }
+
+    /// Name of the dialect documents are parsed with.
+    pub fn dialect_name(&self) -> &str {
+        &self.dialect_name
+    }
+
+    /// The process exit code, once the client has sent `exit`: 0 after a
+    /// `shutdown` request and 1 otherwise.
+    pub fn exit_code(&self) -> Option<i32> {
+        self.exit_code
+    }
+
+    /// Handle one JSON-RPC message and return the messages to send back:
+    /// the response to a request, followed by any notifications it caused.
+    pub fn handle(&mut self, message: Value) -> Vec<Value> {
+        let method = message.get("method").and_then(Value::as_str);
+        let params = message.get("params").unwrap_or(&Value::Null);
+        let mut messages = match (method, message.get("id")) {
+            (Some(method), Some(id)) => {
+                let result = self.handle_request(method, params);
+                vec![response(id.clone(), result)]
+            }
+            (Some(method), None) => {
+                self.handle_notification(method, params);
+                Vec::new()
+            }
+            // The server sends no requests, so there are no responses to
+            // match.
+            (None, Some(_)) => Vec::new(),
+            (None, None) => vec![response(
+                Value::Null,
+                Err(ResponseError::new(
+                    INVALID_REQUEST,
+                    "message without a method",
+                )),
+            )],
+        };
+        messages.append(&mut self.notifications);
+        messages
+    }
+
+    fn handle_request(&mut self, method: &str, params: &Value) -> Result<Value, ResponseError> {
+        if self.shutdown {
+            return Err(ResponseError::new(
+                INVALID_REQUEST,
+                "the server is shutting down",
+            ));
+        }
+        if !self.initialized && method != "initialize" {
+            return Err(ResponseError::new(
+                SERVER_NOT_INITIALIZED,
+                "the server is not initialized",
+            ));
+        }
+        match method {
+            "initialize" => Ok(self.initialize(params)),
+            "shutdown" => {
+                self.shutdown = true;
+                Ok(Value::Null)
+            }
+            "textDocument/documentSymbol" => {
+                let text = self.document(params)?;
+                Ok(self.document_symbols(text))
+            }
+            "textDocument/formatting" => {
+                let text = self.document(params)?;
+                Ok(self.format(text))
+            }
+            "textDocument/completion" => {
+                let text = self.document(params)?;
+                Ok(self.complete(text, &params["position"]))
+            }
+            _ => Err(ResponseError::new(
+                METHOD_NOT_FOUND,
+                format!("unsupported request: {method}"),
+            )),
+        }
+    }
+
+    fn handle_notification(&mut self, method: &str, params: &Value) {
+        if method == "exit" {
+            self.exit_code = Some(if self.shutdown { 0 } else { 1 });
+            return;
+        }
+        if !self.initialized || self.shutdown {
+            return;
+        }
+        if method == "workspace/didChangeConfiguration" {
+            self.configure(&params["settings"]["sqlparser"]);
+            return;
+        }
+        let Some(uri) = params["textDocument"]["uri"].as_str() else {
+            return;
+        };
+        match method {
+            "textDocument/didOpen" => {
+                let text = params["textDocument"]["text"].as_str().unwrap_or_default();
+                self.documents.insert(uri.to_string(), text.to_string());
+                self.publish_diagnostics(uri);
+            }
+            "textDocument/didChange" => {
+                // The server asks for full document sync, so the last change
+                // holds the whole text.
+                let changes = params["contentChanges"].as_array();
+                let Some(text) = changes
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str())
+                else {
+                    return;
+                };
+                self.documents.insert(uri.to_string(), text.to_string());
+                self.publish_diagnostics(uri);
+            }
+            "textDocument/didClose" => {
+                self.documents.remove(uri);
+                self.notifications.push(notification(
+                    "textDocument/publishDiagnostics",
+                    json!({ "uri": uri, "diagnostics": [] }),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    fn initialize(&mut self, params: &Value) -> Value {
+        self.initialized = true;
+        self.configure(&params["initializationOptions"]);
+        json!({
+            "capabilities": {
+                "textDocumentSync": {
+                    "openClose": true,
+                    "change": TEXT_DOCUMENT_SYNC_FULL,
+                },
+                "documentSymbolProvider": true,
+                "documentFormattingProvider": true,
+                "completionProvider": {},
+            },
+            "serverInfo": {
+                "name": "sqlparser-lsp",
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+        })
+    }
+
+    /// Apply the `dialect` setting of `settings`, if present, and refresh the
+    /// diagnostics of open documents when it changes. An unknown dialect is
+    /// reported to the user and leaves the current one selected.
+    fn configure(&mut self, settings: &Value) {
+        let Some(name) = settings["dialect"].as_str() else {
+            return;
+        };
+        let Some(dialect) = dialect_from_str(name) else {
+            self.notifications.push(notification(
+                "window/showMessage",
+                json!({
+                    "type": MESSAGE_TYPE_WARNING,
+                    "message": format!(
+                        "Unknown SQL dialect `{name}`, still using `{}`",
+                        self.dialect_name
+                    ),
+                }),
+            ));
+            return;
+        };
+        if name.eq_ignore_ascii_case(&self.dialect_name) {
+            return;
+        }
+        self.dialect_name = name.to_lowercase();
+        self.dialect = dialect;
+        let mut uris: Vec<String> = self.documents.keys().cloned().collect();
+        uris.sort();
+        for uri in uris {
+            self.publish_diagnostics(&uri);
+        }
+    }
+
+    /// The text of the open document named by `params`.
+    fn document(&self, params: &Value) -> Result<&str, ResponseError> {
+        let uri = params["textDocument"]["uri"]
+            .as_str()
+            .ok_or_else(|| ResponseError::new(INVALID_PARAMS, "missing textDocument.uri"))?;
+        let text = self.documents.get(uri).ok_or_else(|| {
+            ResponseError::new(INVALID_PARAMS, format!("unknown document: {uri}"))
+        })?;
+        Ok(text)
+    }
+
+    fn publish_diagnostics(&mut self, uri: &str) {
+        let Some(text) = self.documents.get(uri) else {
+            return;
+        };
+        let diagnostics = self.diagnostics(text);
+        self.notifications.push(notification(
+            "textDocument/publishDiagnostics",
+            json!({ "uri": uri, "diagnostics": diagnostics }),
+        ));
+    }
+
+    /// One diagnostic per error of a tolerant parse, at the token the error
+    /// was reported at, or over the skipped tokens when that is unknown.
+    fn diagnostics(&self, text: &str) -> Vec<Value> {
+        let errors = match ParsedSql::parse_tolerant(&*self.dialect, text, self.parser_options()) {
+            Ok((_, errors)) => errors,
+            Err(error) => vec![StatementError {
+                error,
+                span: Span::empty(),
+            }],
+        };
+        let index = LineIndex::new(text);
+        errors
+            .iter()
+            .map(|StatementError { error, span }| {
+                let (span, message) = match error.syntax_error() {
+                    Some(syntax) => {
+                        let message = match &syntax.hint {
+                            Some(hint) => format!("{}\n{hint}", syntax.message),
+                            None => syntax.message.clone(),
+                        };
+                        (syntax.span, message)
+                    }
+                    None => (*span, error.to_string()),
+                };
+                json!({
+                    "range": index.range(span),
+                    "severity": DIAGNOSTIC_SEVERITY_ERROR,
+                    "source": "sqlparser",
+                    "message": message,
+                })
+            })
+            .collect()
+    }
+
+    /// The outline of a document: its statements, with the common table
+    /// expressions of queries nested below them.
+    fn document_symbols(&self, text: &str) -> Value {
+        let Ok((document, _)) =
+            ParsedSql::parse_tolerant(&*self.dialect, text, self.parser_options())
+        else {
+            return json!([]);
+        };
+        let index = LineIndex::new(text);
+        let symbols: Vec<Value> = document
+            .statements()
+            .iter()
+            .enumerate()
+            .filter_map(|(i, statement)| {
+                let span = document.statement_span(i)?;
+                let source = document.statement_source(i)?;
+                let range = index.range(span);
+                let children = match statement {
+                    Statement::Query(query) => cte_symbols(&index, query),
+                    _ => Vec::new(),
+                };
+                Some(json!({
+                    "name": statement_summary(source),
+                    "kind": SYMBOL_KIND_OBJECT,
+                    "range": range,
+                    "selectionRange": range,
+                    "children": children,
+                }))
+            })
+            .collect();
+        json!(symbols)
+    }
+
+    /// The options `Parser::new` uses for the dialect of the server.
+    fn parser_options(&self) -> ParserOptions {
+        ParserOptions::new().with_trailing_commas(self.dialect.supports_trailing_commas())
+    }
+
+    /// Replace the document with its statements pretty-printed by `{:#}`,
+    /// with their comments.
+    ///
+    /// Documents that fail to parse are left alone, and so are documents
+    /// with comments that formatting would drop, such as comments inside an
+    /// expression.
+    fn format(&self, text: &str) -> Value {
+        let options = self.parser_options().with_attach_comments(true);
+        let Ok(parser) = Parser::new(&*self.dialect)
+            .with_options(options)
+            .try_with_sql(text)
+        else {
+            return Value::Null;
+        };
+        let Ok(statements) = parser.parse_statements() else {
+            return Value::Null;
+        };
+        if parser.unattached_comment_count() > 0 {
+            return Value::Null;
+        }
+        if statements.is_empty() {
+            return json!([]);
+        }
+        let mut formatted = statements
+            .iter()
+            .map(|statement| format!("{statement:#}"))
+            .collect::<Vec<_>>()
+            .join(";\n\n");
+        formatted.push_str(";\n");
+        if formatted == text {
+            return json!([]);
+        }
+        let index = LineIndex::new(text);
+        json!([{
+            "range": { "start": index.position(0), "end": index.position(text.len()) },
+            "newText": formatted,
+        }])
+    }
+
+    /// Keywords accepted at `position`, replacing the word being typed and
+    /// matching its case when it is all lowercase.
+    fn complete(&self, text: &str, position: &Value) -> Value {
+        let index = LineIndex::new(text);
+        let completions = completions_at(&*self.dialect, text, index.offset(position));
+        let prefix = &text[completions.replace.clone()];
+        let lowercase =
+            prefix.chars().any(char::is_lowercase) && !prefix.chars().any(char::is_uppercase);
+        let range = json!({
+            "start": index.position(completions.replace.start),
+            "end": index.position(completions.replace.end),
+        });
+        let items: Vec<Value> = completions
+            .keywords
+            .iter()
+            .map(|keyword| {
+                let mut label = format!("{keyword:?}");
+                if lowercase {
+                    label.make_ascii_lowercase();
+                }
+                json!({
+                    "label": label,
+                    "kind": COMPLETION_ITEM_KIND_KEYWORD,
+                    "textEdit": { "range": range, "newText": label },
+                })
+            })
+            .collect();
+        json!({ "isIncomplete": false, "items": items })
+    }
+}
+
+/// Outline entries for the common table expressions of `query`, with the
+/// ones of each CTE's own query nested below it.
+fn cte_symbols(index: &LineIndex, query: &Query) -> Vec<Value> {
+    let Some(with) = &query.with else {
+        return Vec::new();
+    };
+    with.cte_tables
+        .iter()
+        .map(|cte| {
+            json!({
+                "name": cte.alias.name.to_string(),
+                "kind": SYMBOL_KIND_STRUCT,
+                "range": index.range(cte.span()),
+                "selectionRange": index.range(cte.alias.name.span),
+                "children": cte_symbols(index, &cte.query),
+            })
+        })
+        .collect()
+}
+
+/// The source of a statement on one line, shortened to
+/// [`MAX_SYMBOL_NAME_CHARS`].
+fn statement_summary(source: &str) -> String {
+    let summary = source.split_whitespace().collect::<Vec<_>>().join(" ");
+    match summary.char_indices().nth(MAX_SYMBOL_NAME_CHARS) {
+        Some((end, _)) => format!("{}…", &summary[..end]),
+        None => summary,
+    }
+}
+
+fn response(id: Value, result: Result<Value, ResponseError>) -> Value {
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(ResponseError { code, message }) => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": code, "message": message },
+        }),
+    }
+}
+
+fn notification(method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "method": method, "params": params })
+}
+
+/// Serve one session over a framed byte stream, such as stdin and stdout,
+/// and return the exit code requested by the client.
+///
+/// The session ends at the `exit` notification or at the end of `input`,
+/// which counts as an exit without a preceding `shutdown`.
+pub fn run(mut input: impl BufRead, mut output: impl Write) -> io::Result<i32> {
+    let mut server = Server::new();
+    while let Some(body) = read_message(&mut input)? {
+        let replies = match serde_json::from_slice(&body) {
+            Ok(message) => server.handle(message),
+            Err(error) => vec![response(
+                Value::Null,
+                Err(ResponseError::new(PARSE_ERROR, error.to_string())),
+            )],
+        };
+        for reply in &replies {
+            write_message(&mut output, reply)?;
+        }
+        if let Some(code) = server.exit_code() {
+            return Ok(code);
+        }
+    }
+    Ok(1)
+}
@@ -0,0 +1,129 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversion between byte offsets in a document and LSP positions.
+//!
+//! LSP positions count lines from 0 and characters in UTF-16 code units,
+//! while the parser reports byte offsets and 1-based lines and columns that
+//! count characters.
+
+use serde_json::{json, Value};
+use sqlparser::tokenizer::{Location, Span};
+
+/// Line starts of a document, for converting between offsets and positions.
+pub(crate) struct LineIndex<'a> {
+    text: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    pub(crate) fn new(text: &'a str) -> Self {
+        let line_starts = core::iter::once(0)
+            .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        Self { text, line_starts }
+    }
+
+    /// The LSP position of byte `offset`, which is clamped to the document
+    /// and moved back to a character boundary.
+    pub(crate) fn position(&self, offset: usize) -> Value {
+        let mut offset = offset.min(self.text.len());
+        while !self.text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let character: usize = self.text[self.line_starts[line]..offset]
+            .chars()
+            .map(char::len_utf16)
+            .sum();
+        json!({ "line": line, "character": character })
+    }
+
+    /// The byte offset of an LSP position. Positions past the end of a line
+    /// resolve to the end of that line, and lines past the end of the
+    /// document to its end.
+    pub(crate) fn offset(&self, position: &Value) -> usize {
+        let line = position["line"].as_u64().unwrap_or(0) as usize;
+        let character = position["character"].as_u64().unwrap_or(0) as usize;
+        let Some(&start) = self.line_starts.get(line) else {
+            return self.text.len();
+        };
+        let line_text = self.text[start..].split('\n').next().unwrap_or("");
+        let mut units = 0;
+        for (index, ch) in line_text.char_indices() {
+            if units >= character {
+                return start + index;
+            }
+            units += ch.len_utf16();
+        }
+        start + line_text.len()
+    }
+
+    /// The byte offset of a parser location, from its recorded byte offset
+    /// or else from its line and column.
+    pub(crate) fn location_offset(&self, location: Location) -> usize {
+        if let Some(offset) = location.byte_offset() {
+            return offset.min(self.text.len());
+        }
+        let line = (location.line as usize).saturating_sub(1);
+        let Some(&start) = self.line_starts.get(line) else {
+            return self.text.len();
+        };
+        let column = (location.column as usize).saturating_sub(1);
+        self.text[start..]
+            .char_indices()
+            .nth(column)
+            .map_or(self.text.len(), |(index, _)| start + index)
+    }
+
+    /// The LSP range covered by a parser span.
+    pub(crate) fn range(&self, span: Span) -> Value {
+        let start = self.location_offset(span.start);
+        let end = self.location_offset(span.end).max(start);
+        json!({ "start": self.position(start), "end": self.position(end) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_count_utf16_code_units() {
+        let text = "SELECT 'é😀', a\nFROM t";
+        let index = LineIndex::new(text);
+
+        let a = text.find(", a").unwrap() + 2;
+        assert_eq!(index.position(a), json!({ "line": 0, "character": 14 }));
+        assert_eq!(index.offset(&index.position(a)), a);
+
+        let from = text.find("FROM").unwrap();
+        assert_eq!(index.position(from), json!({ "line": 1, "character": 0 }));
+        assert_eq!(
+            index.offset(&json!({ "line": 0, "character": 100 })),
+            from - 1
+        );
+        assert_eq!(
+            index.offset(&json!({ "line": 5, "character": 0 })),
+            text.len()
+        );
+
+        // Line 2, column 3 is the `O` of `FROM`.
+        let location = Location::new(2, 3);
+        assert_eq!(index.location_offset(location), from + 2);
+    }
+}
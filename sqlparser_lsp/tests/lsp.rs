@@ -0,0 +1,418 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! End-to-end tests that drive the server over in-memory pipes with a
+//! minimal JSON-RPC client.
+
+use std::io::{self, BufReader, Read, Write};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::thread::{self, JoinHandle};
+
+use serde_json::{json, Value};
+use sqlparser_lsp::transport::{read_message, write_message, MAX_MESSAGE_BYTES};
+
+/// The reading end of an in-memory pipe.
+struct PipeReader {
+    receiver: Receiver<Vec<u8>>,
+    buffer: Vec<u8>,
+    position: usize,
+}
+
+impl Read for PipeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position == self.buffer.len() {
+            // A closed pipe reads as the end of the input.
+            let Ok(chunk) = self.receiver.recv() else {
+                return Ok(0);
+            };
+            self.buffer = chunk;
+            self.position = 0;
+        }
+        let len = buf.len().min(self.buffer.len() - self.position);
+        buf[..len].copy_from_slice(&self.buffer[self.position..self.position + len]);
+        self.position += len;
+        Ok(len)
+    }
+}
+
+/// The writing end of an in-memory pipe.
+struct PipeWriter(Sender<Vec<u8>>);
+
+impl Write for PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0
+            .send(buf.to_vec())
+            .map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn pipe() -> (PipeWriter, PipeReader) {
+    let (sender, receiver) = channel();
+    let reader = PipeReader {
+        receiver,
+        buffer: Vec::new(),
+        position: 0,
+    };
+    (PipeWriter(sender), reader)
+}
+
+/// A client connected to a server running on another thread.
+struct Client {
+    input: PipeWriter,
+    output: BufReader<PipeReader>,
+    server: JoinHandle<io::Result<i32>>,
+    next_id: u64,
+}
+
+impl Client {
+    fn start() -> Self {
+        let (input, server_input) = pipe();
+        let (server_output, output) = pipe();
+        let server =
+            thread::spawn(move || sqlparser_lsp::run(BufReader::new(server_input), server_output));
+        Self {
+            input,
+            output: BufReader::new(output),
+            server,
+            next_id: 1,
+        }
+    }
+
+    fn initialize(options: Value) -> Self {
+        let mut client = Self::start();
+        let result = client.request("initialize", json!({ "initializationOptions": options }));
+        assert_eq!(result["serverInfo"]["name"], "sqlparser-lsp");
+        client.notify("initialized", json!({}));
+        client
+    }
+
+    fn send(&mut self, message: Value) {
+        write_message(&mut self.input, &message).unwrap();
+    }
+
+    fn receive(&mut self) -> Value {
+        let body = read_message(&mut self.output).unwrap().expect("a message");
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    fn notify(&mut self, method: &str, params: Value) {
+        self.send(json!({ "jsonrpc": "2.0", "method": method, "params": params }));
+    }
+
+    /// Send a request and return its response.
+    fn request_raw(&mut self, method: &str, params: Value) -> Value {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.send(json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }));
+        let response = self.receive();
+        assert_eq!(response["id"], id);
+        response
+    }
+
+    /// Send a request and return its result.
+    fn request(&mut self, method: &str, params: Value) -> Value {
+        let response = self.request_raw(method, params);
+        assert!(response.get("error").is_none(), "{response}");
+        response["result"].clone()
+    }
+
+    /// Receive a notification, which must be for `method`, and return its
+    /// parameters.
+    fn expect_notification(&mut self, method: &str) -> Value {
+        let message = self.receive();
+        assert_eq!(message["method"], method, "{message}");
+        message["params"].clone()
+    }
+
+    /// Open a document and return the diagnostics published for it.
+    fn open(&mut self, uri: &str, text: &str) -> Vec<Value> {
+        self.notify(
+            "textDocument/didOpen",
+            json!({
+                "textDocument": { "uri": uri, "languageId": "sql", "version": 1, "text": text },
+            }),
+        );
+        let params = self.expect_notification("textDocument/publishDiagnostics");
+        assert_eq!(params["uri"], uri);
+        params["diagnostics"].as_array().unwrap().clone()
+    }
+
+    /// Shut the server down and return its exit code.
+    fn shutdown(mut self) -> i32 {
+        assert_eq!(self.request("shutdown", Value::Null), Value::Null);
+        self.notify("exit", Value::Null);
+        self.server.join().unwrap().unwrap()
+    }
+}
+
+fn position(line: u64, character: u64) -> Value {
+    json!({ "line": line, "character": character })
+}
+
+fn range(start: (u64, u64), end: (u64, u64)) -> Value {
+    json!({ "start": position(start.0, start.1), "end": position(end.0, end.1) })
+}
+
+#[test]
+fn diagnostics_cover_every_error() {
+    let mut client = Client::initialize(Value::Null);
+
+    let diagnostics = client.open("file:///ok.sql", "SELECT 1;\nSELECT 2;\n");
+    assert!(diagnostics.is_empty());
+
+    let diagnostics = client.open(
+        "file:///errors.sql",
+        "SELECT a, FROM t;\nSELECT b FROM u;\nSELECT c FROM t WHERE (d;\n",
+    );
+    assert_eq!(diagnostics.len(), 2, "{diagnostics:?}");
+    assert_eq!(diagnostics[0]["range"], range((0, 10), (0, 14)));
+    assert_eq!(diagnostics[0]["severity"], 1);
+    assert_eq!(diagnostics[0]["source"], "sqlparser");
+    assert_eq!(diagnostics[1]["range"], range((2, 24), (2, 25)));
+
+    client.notify(
+        "textDocument/didChange",
+        json!({
+            "textDocument": { "uri": "file:///errors.sql", "version": 2 },
+            "contentChanges": [{ "text": "SELECT a FROM t;\n" }],
+        }),
+    );
+    let params = client.expect_notification("textDocument/publishDiagnostics");
+    assert_eq!(params["diagnostics"], json!([]));
+
+    // Ranges count UTF-16 code units.
+    let diagnostics = client.open("file:///unicode.sql", "SELECT '😀', FROM t");
+    assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+    assert_eq!(diagnostics[0]["range"], range((0, 13), (0, 17)));
+
+    client.notify(
+        "textDocument/didClose",
+        json!({ "textDocument": { "uri": "file:///unicode.sql" } }),
+    );
+    let params = client.expect_notification("textDocument/publishDiagnostics");
+    assert_eq!(params["diagnostics"], json!([]));
+
+    assert_eq!(client.shutdown(), 0);
+}
+
+#[test]
+fn outline_lists_statements_and_ctes() {
+    let mut client = Client::initialize(Value::Null);
+    let text = "WITH recent AS (SELECT * FROM orders),\n  \
+                totals AS (WITH x AS (SELECT 1) SELECT * FROM x)\n\
+                SELECT * FROM recent;\n\
+                CREATE TABLE t (a INT, b INT, c INT, d INT, e INT, f INT);\n";
+    client.open("file:///outline.sql", text);
+
+    let symbols = client.request(
+        "textDocument/documentSymbol",
+        json!({ "textDocument": { "uri": "file:///outline.sql" } }),
+    );
+    let symbols = symbols.as_array().unwrap();
+    assert_eq!(symbols.len(), 2);
+
+    assert_eq!(
+        symbols[0]["name"],
+        "WITH recent AS (SELECT * FROM orders), t…"
+    );
+    assert_eq!(symbols[0]["range"], range((0, 0), (2, 20)));
+    let ctes = symbols[0]["children"].as_array().unwrap();
+    assert_eq!(ctes.len(), 2);
+    assert_eq!(ctes[0]["name"], "recent");
+    assert_eq!(ctes[0]["range"], range((0, 5), (0, 37)));
+    assert_eq!(ctes[0]["selectionRange"], range((0, 5), (0, 11)));
+    assert_eq!(ctes[1]["name"], "totals");
+    assert_eq!(ctes[1]["children"][0]["name"], "x");
+
+    assert_eq!(symbols[1]["range"], range((3, 0), (3, 57)));
+    assert_eq!(symbols[1]["children"], json!([]));
+
+    assert_eq!(client.shutdown(), 0);
+}
+
+#[test]
+fn formatting_pretty_prints_statements() {
+    let mut client = Client::initialize(Value::Null);
+    let text = "select a, b from t where a = 1; select 2";
+    client.open("file:///format.sql", text);
+    let edits = client.request(
+        "textDocument/formatting",
+        json!({
+            "textDocument": { "uri": "file:///format.sql" },
+            "options": { "tabSize": 2, "insertSpaces": true },
+        }),
+    );
+    let expected = "SELECT\n  a,\n  b\nFROM\n  t\nWHERE\n  a = 1;\n\nSELECT\n  2;\n";
+    assert_eq!(
+        edits,
+        json!([{ "range": range((0, 0), (0, 40)), "newText": expected }])
+    );
+
+    // Formatting is idempotent.
+    client.open("file:///formatted.sql", expected);
+    let edits = client.request(
+        "textDocument/formatting",
+        json!({ "textDocument": { "uri": "file:///formatted.sql" } }),
+    );
+    assert_eq!(edits, json!([]));
+
+    // Comments around statements and select items are kept.
+    client.open("file:///comments.sql", "-- totals\nselect 1");
+    let edits = client.request(
+        "textDocument/formatting",
+        json!({ "textDocument": { "uri": "file:///comments.sql" } }),
+    );
+    assert_eq!(
+        edits,
+        json!([{ "range": range((0, 0), (1, 8)), "newText": "-- totals\nSELECT\n  1;\n" }])
+    );
+
+    // Documents with errors, or with comments that formatting would drop,
+    // are left alone.
+    for (uri, text) in [
+        ("file:///invalid.sql", "SELECT a FROM"),
+        ("file:///inner.sql", "SELECT 1 + /* inner */ 2"),
+    ] {
+        client.open(uri, text);
+        let edits = client.request(
+            "textDocument/formatting",
+            json!({ "textDocument": { "uri": uri } }),
+        );
+        assert_eq!(edits, Value::Null);
+    }
+
+    assert_eq!(client.shutdown(), 0);
+}
+
+#[test]
+fn completion_offers_keywords() {
+    let mut client = Client::initialize(Value::Null);
+    client.open("file:///complete.sql", "SELECT a\nFROM t gr");
+
+    let list = client.request(
+        "textDocument/completion",
+        json!({
+            "textDocument": { "uri": "file:///complete.sql" },
+            "position": position(1, 9),
+        }),
+    );
+    assert_eq!(list["isIncomplete"], false);
+    assert_eq!(
+        list["items"],
+        json!([{
+            "label": "group",
+            "kind": 14,
+            "textEdit": { "range": range((1, 7), (1, 9)), "newText": "group" },
+        }])
+    );
+
+    let list = client.request(
+        "textDocument/completion",
+        json!({
+            "textDocument": { "uri": "file:///complete.sql" },
+            "position": position(1, 7),
+        }),
+    );
+    let labels: Vec<&str> = list["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["label"].as_str().unwrap())
+        .collect();
+    assert!(labels.contains(&"WHERE"), "{labels:?}");
+    assert!(labels.contains(&"JOIN"), "{labels:?}");
+
+    assert_eq!(client.shutdown(), 0);
+}
+
+#[test]
+fn dialect_follows_workspace_settings() {
+    // Backticks quote identifiers only in MySQL.
+    let text = "SELECT `a` FROM t";
+
+    let mut client = Client::initialize(json!({ "dialect": "mysql" }));
+    assert!(client.open("file:///t.sql", text).is_empty());
+
+    client.notify(
+        "workspace/didChangeConfiguration",
+        json!({ "settings": { "sqlparser": { "dialect": "postgresql" } } }),
+    );
+    let params = client.expect_notification("textDocument/publishDiagnostics");
+    assert_eq!(params["diagnostics"].as_array().unwrap().len(), 1);
+
+    // An unknown dialect is reported and the current one kept.
+    client.notify(
+        "workspace/didChangeConfiguration",
+        json!({ "settings": { "sqlparser": { "dialect": "cobol" } } }),
+    );
+    let params = client.expect_notification("window/showMessage");
+    assert_eq!(params["type"], 2);
+    assert_eq!(
+        params["message"],
+        "Unknown SQL dialect `cobol`, still using `postgresql`"
+    );
+
+    assert_eq!(client.shutdown(), 0);
+}
+
+#[test]
+fn protocol_errors() {
+    let mut client = Client::start();
+    let response = client.request_raw("textDocument/documentSymbol", json!({}));
+    assert_eq!(response["error"]["code"], -32002);
+
+    client.request("initialize", json!({}));
+    let response = client.request_raw("textDocument/hover", json!({}));
+    assert_eq!(response["error"]["code"], -32601);
+    let response = client.request_raw(
+        "textDocument/documentSymbol",
+        json!({ "textDocument": { "uri": "file:///missing.sql" } }),
+    );
+    assert_eq!(response["error"]["code"], -32602);
+
+    client.send(json!({ "jsonrpc": "2.0", "id": 99 }));
+    write!(client.input, "Content-Length: 2\r\n\r\n{{]").unwrap();
+    let response = client.receive();
+    assert_eq!(response["id"], Value::Null);
+    assert_eq!(response["error"]["code"], -32700);
+
+    // Exiting without a shutdown request is an error.
+    client.notify("exit", Value::Null);
+    assert_eq!(client.server.join().unwrap().unwrap(), 1);
+}
+
+#[test]
+fn oversized_messages_are_refused() {
+    let header = format!("Content-Length: {}\r\n\r\n", MAX_MESSAGE_BYTES + 1);
+    let error = read_message(&mut header.as_bytes()).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+    let header = format!("Content-Length: {}\r\n\r\n", usize::MAX);
+    let error = read_message(&mut header.as_bytes()).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+    let message = b"Content-Length: 2\r\n\r\n{}";
+    assert_eq!(
+        read_message(&mut &message[..]).unwrap(),
+        Some(b"{}".to_vec())
+    );
+}
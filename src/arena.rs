@@ -18,35 +18,62 @@
 //! Document-owned storage for parsed SQL syntax.
 //!
 //! [`ParsedSql`] is the ownership boundary for arena-backed ASTs. Recursive
-//! [`AstBox`] nodes created while parsing a document are allocated from one
-//! bump arena. The document exposes only shared statement references, so an
-//! arena pointer cannot escape as an owned subtree. Legacy parser entry points
-//! remain heap-backed and continue returning owned statements.
+//! [`AstBox`] nodes and [`AstVec`] lists created while parsing a document are
+//! allocated from one bump arena. The document exposes only shared statement
+//! references, so an arena pointer cannot escape as an owned subtree, and
+//! edits made before it is frozen go through an [`EditSession`] whose
+//! lifetime brand keeps arena syntax inside the editing callback. Legacy
+//! parser entry points remain heap-backed and continue returning owned
+//! statements.
 
 use core::borrow::{Borrow, BorrowMut};
+use core::cell::{Cell, RefCell};
 use core::cmp::Ordering;
 use core::fmt;
 use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
 use core::ops::{Deref, DerefMut};
 use core::ptr::NonNull;
 
 #[cfg(not(feature = "std"))]
-use alloc::boxed::Box;
+use alloc::{
+    alloc::{alloc, dealloc, handle_alloc_error, Layout},
+    borrow::Cow,
+    borrow::ToOwned,
+    boxed::Box,
+    string::String,
+    vec as alloc_vec,
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::{
+    alloc::{alloc, dealloc, handle_alloc_error, Layout},
+    borrow::Cow,
+    vec as alloc_vec,
+};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+use crate::parser::{LimitExceeded, ParserError, ParserLimit};
+
 /// A recursive AST allocation.
 ///
 /// Heap-backed values behave like `Box<T>`. Arena-backed values are valid only
 /// while their owning [`ParsedSql`] is alive; `ParsedSql` never exposes an
 /// owned arena subtree. Cloning an arena-backed value outside a parse scope
 /// produces a normal heap-backed deep clone.
+///
+/// While [`ParsedSql::rewrite`] builds a child document, cloning a node of
+/// the parent document yields a *shared* box: a non-owning pointer into the
+/// parent's arena, which the child keeps alive. Mutable access to a shared
+/// box first copies the node into the child's arena, so only edited paths
+/// are materialized.
 pub struct AstBox<T> {
-    // The low pointer bit records the storage mode. `AstBoxStorage` has at
-    // least pointer alignment even when T itself is byte-aligned, so that bit
-    // is always available. Keeping this wrapper pointer-sized is important:
+    // The two low pointer bits record the storage mode. `AstBoxStorage` has
+    // at least pointer alignment even when T itself is byte-aligned, so those
+    // bits are always available. Keeping this wrapper pointer-sized is important:
     // recursive boxes are embedded throughout the AST, and a separate bool
     // would inflate every parent node (and, recursively, every allocation).
     ptr_and_tag: NonNull<AstBoxStorage<T>>,
@@ -63,13 +90,16 @@ struct AstBoxStorage<T> {
 }
 
 const ARENA_TAG: usize = 1;
+// Set together with ARENA_TAG on boxes that borrow a node of an ancestor
+// document's arena instead of owning one.
+const SHARED_TAG: usize = 2;
 
 #[cfg(feature = "std")]
 thread_local! {
     static AST_DROP_DEPTH: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
 }
 
-impl<T> AstBox<T> {
+impl<T: ArenaResident> AstBox<T> {
     /// Allocate a value using the active document arena, or the global heap
     /// when no document parse is active.
     pub fn new(value: T) -> Self {
@@ -81,6 +111,10 @@ impl<T> AstBox<T> {
         #[cfg(feature = "std")]
         let storage = match active_arena_alloc(storage) {
             Ok(ptr) => {
+                #[cfg(feature = "ast-histogram")]
+                note_arena_box::<T>();
+                // SAFETY: The arena slot was just initialized with the value.
+                note_arena_placement(unsafe { &ptr.as_ref().value });
                 return Self {
                     ptr_and_tag: tag_arena_pointer(ptr),
                     marker: PhantomData,
@@ -89,6 +123,34 @@ impl<T> AstBox<T> {
             Err(storage) => storage,
         };
 
+        Self::new_on_heap(storage)
+    }
+
+    /// Like [`AstBox::new`], but fails without allocating when the value
+    /// does not fit the remaining byte budget of the active document arena.
+    pub(crate) fn try_new(value: T) -> Result<Self, ParserError> {
+        check_arena_budget(Layout::new::<AstBoxStorage<T>>())?;
+        Ok(Self::new(value))
+    }
+}
+
+impl<T> AstBox<T> {
+    /// Allocate a value in an explicitly passed `arena`, failing without
+    /// allocating when it does not fit the arena's remaining byte budget.
+    pub(crate) fn try_new_in(value: T, arena: &AstArena) -> Result<Self, ParserError> {
+        let ptr = arena.alloc(AstBoxStorage {
+            _pointer_alignment: [],
+            value,
+        })?;
+        #[cfg(feature = "ast-histogram")]
+        arena.allocations_by_type.borrow_mut().record::<T>();
+        Ok(Self {
+            ptr_and_tag: tag_arena_pointer(ptr),
+            marker: PhantomData,
+        })
+    }
+
+    fn new_on_heap(storage: AstBoxStorage<T>) -> Self {
         let ptr_and_tag = NonNull::from(Box::leak(Box::new(storage)));
         Self {
             ptr_and_tag,
@@ -113,6 +175,8 @@ impl<T> AstBox<T> {
             drop(this);
             owned
         } else {
+            #[cfg(feature = "std")]
+            note_edit_change(this.storage_ptr().cast());
             let ptr = this.storage_ptr().as_ptr();
             core::mem::forget(this);
             // SAFETY: Heap-backed pointers are created with `Box::leak`
@@ -126,6 +190,10 @@ impl<T> AstBox<T> {
     /// crate. Arena-backed descendants remain tied to the same document, so
     /// this operation is intentionally not public.
     pub(crate) fn into_inner(this: Self) -> T {
+        assert!(
+            !this.is_shared(),
+            "cannot move a value out of a node shared with another document"
+        );
         if this.is_arena_allocated() {
             let ptr = this.storage_ptr().as_ptr();
             core::mem::forget(this);
@@ -134,6 +202,8 @@ impl<T> AstBox<T> {
             // reclaimed with the arena.
             unsafe { core::ptr::addr_of!((*ptr).value).read() }
         } else {
+            #[cfg(feature = "std")]
+            note_edit_change(this.storage_ptr().cast());
             let ptr = this.storage_ptr().as_ptr();
             core::mem::forget(this);
             // SAFETY: Heap-backed pointers originate from Box::leak.
@@ -146,16 +216,23 @@ impl<T> AstBox<T> {
         self.ptr_and_tag.addr().get() & ARENA_TAG != 0
     }
 
+    /// Whether this node borrows storage from the arena of the document a
+    /// rewrite was derived from, rather than owning a copy.
+    pub fn is_shared(&self) -> bool {
+        self.ptr_and_tag.addr().get() & SHARED_TAG != 0
+    }
+
     fn storage_ptr(&self) -> NonNull<AstBoxStorage<T>> {
         self.ptr_and_tag.map_addr(|address| {
             // SAFETY: clearing a tag from a non-null allocation address
             // cannot produce zero.
-            unsafe { core::num::NonZeroUsize::new_unchecked(address.get() & !ARENA_TAG) }
+            unsafe {
+                core::num::NonZeroUsize::new_unchecked(address.get() & !(ARENA_TAG | SHARED_TAG))
+            }
         })
     }
 }
 
-#[cfg(feature = "std")]
 fn tag_arena_pointer<T>(ptr: NonNull<AstBoxStorage<T>>) -> NonNull<AstBoxStorage<T>> {
     debug_assert_eq!(ptr.addr().get() & ARENA_TAG, 0);
     ptr.map_addr(|address| {
@@ -166,6 +243,10 @@ fn tag_arena_pointer<T>(ptr: NonNull<AstBoxStorage<T>>) -> NonNull<AstBoxStorage
 
 impl<T> Drop for AstBox<T> {
     fn drop(&mut self) {
+        if self.is_shared() {
+            // The owning document drops the node.
+            return;
+        }
         #[cfg(feature = "std")]
         {
             AST_DROP_DEPTH.with(|depth| {
@@ -197,7 +278,6 @@ impl<T> Drop for AstBox<T> {
                 }
                 drop(restore);
             });
-            return;
         }
 
         #[cfg(not(feature = "std"))]
@@ -208,6 +288,11 @@ impl<T> Drop for AstBox<T> {
 impl<T> AstBox<T> {
     fn drop_storage(&mut self) {
         if self.is_arena_allocated() {
+            #[cfg(feature = "std")]
+            if adopted_by_edit(self.storage_ptr().cast()) {
+                // The statement that replaced this one's owner owns it now.
+                return;
+            }
             // SAFETY: An arena node is uniquely owned by its parent AST node.
             // Its storage remains live until ParsedSql drops the arena after
             // dropping all statement roots. We run T's destructor but leave
@@ -218,6 +303,8 @@ impl<T> AstBox<T> {
                 ))
             };
         } else {
+            #[cfg(feature = "std")]
+            note_edit_change(self.storage_ptr().cast());
             // SAFETY: Heap nodes originate from `Box::leak`, and this is their
             // unique owning AstBox.
             unsafe { drop(Box::from_raw(self.storage_ptr().as_ptr())) };
@@ -234,18 +321,61 @@ impl<T> Deref for AstBox<T> {
     }
 }
 
-impl<T> DerefMut for AstBox<T> {
+impl<T: Clone + ArenaResident> DerefMut for AstBox<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        // SAFETY: AstBox uniquely owns its pointee. Frozen ParsedSql only
-        // exposes shared references, so this is reachable for arena nodes only
-        // while the parser owns and builds the tree.
+        if self.is_shared() {
+            // Copy on write. The copy's own children are cloned under the
+            // same rewrite, so they stay shared until they are edited too.
+            *self = Self::new((**self).clone());
+        }
+        // The node was checked when it was placed; what the caller writes
+        // now has to be checked when the document is frozen.
+        #[cfg(feature = "std")]
+        if self.is_arena_allocated() {
+            note_arena_edit();
+        } else {
+            note_edit_change(self.storage_ptr().cast());
+        }
+        // SAFETY: AstBox uniquely owns its pointee once it is not shared.
+        // Frozen ParsedSql only exposes shared references, so this is
+        // reachable for arena nodes only while their document is built.
         unsafe { &mut self.storage_ptr().as_mut().value }
     }
 }
 
-impl<T: Clone> Clone for AstBox<T> {
+impl<T: Clone + ArenaResident> Clone for AstBox<T> {
     fn clone(&self) -> Self {
-        Self::new((**self).clone())
+        #[cfg(feature = "std")]
+        if !self.is_arena_allocated() {
+            if let Some(origin) = take_edit_origin(self.storage_ptr().cast()) {
+                // An unchanged node of a statement edited through
+                // `EditSession::edit_statement` returns to the document node
+                // it was copied from.
+                return Self {
+                    ptr_and_tag: origin.cast(),
+                    marker: PhantomData,
+                };
+            }
+        } else if active_arena_shares(self.storage_ptr().cast()) {
+            return Self {
+                ptr_and_tag: self.ptr_and_tag.map_addr(|address| {
+                    // SAFETY: setting bits on a non-zero address remains
+                    // non-zero.
+                    unsafe {
+                        core::num::NonZeroUsize::new_unchecked(
+                            address.get() | ARENA_TAG | SHARED_TAG,
+                        )
+                    }
+                }),
+                marker: PhantomData,
+            };
+        }
+        let copy = Self::new((**self).clone());
+        #[cfg(feature = "std")]
+        if self.is_arena_allocated() && !copy.is_arena_allocated() {
+            note_edit_copy(copy.storage_ptr().cast(), self.ptr_and_tag.cast());
+        }
+        copy
     }
 }
 
@@ -299,7 +429,7 @@ impl<T> AsRef<T> for AstBox<T> {
     }
 }
 
-impl<T> AsMut<T> for AstBox<T> {
+impl<T: Clone + ArenaResident> AsMut<T> for AstBox<T> {
     fn as_mut(&mut self) -> &mut T {
         self
     }
@@ -311,19 +441,19 @@ impl<T> Borrow<T> for AstBox<T> {
     }
 }
 
-impl<T> BorrowMut<T> for AstBox<T> {
+impl<T: Clone + ArenaResident> BorrowMut<T> for AstBox<T> {
     fn borrow_mut(&mut self) -> &mut T {
         self
     }
 }
 
-impl<T> From<T> for AstBox<T> {
+impl<T: ArenaResident> From<T> for AstBox<T> {
     fn from(value: T) -> Self {
         Self::new(value)
     }
 }
 
-impl<T: Default> Default for AstBox<T> {
+impl<T: Default + ArenaResident> Default for AstBox<T> {
     fn default() -> Self {
         Self::new(T::default())
     }
@@ -346,7 +476,7 @@ impl<T: Serialize> Serialize for AstBox<T> {
 }
 
 #[cfg(feature = "serde")]
-impl<'de, T: Deserialize<'de>> Deserialize<'de> for AstBox<T> {
+impl<'de, T: Deserialize<'de> + ArenaResident> Deserialize<'de> for AstBox<T> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
@@ -355,392 +485,2355 @@ impl<'de, T: Deserialize<'de>> Deserialize<'de> for AstBox<T> {
     }
 }
 
-#[cfg(feature = "std")]
-mod document {
-    use super::*;
-    use crate::ast::Statement;
-    use crate::dialect::Dialect;
-    use crate::optimizer_hints::{parse_optimizer_hints, OptimizerHint};
-    use crate::parser::{Parser, ParserError, ParserOptions};
-    use crate::tokenizer::{Location, Span};
-    use std::alloc::{alloc, dealloc, handle_alloc_error, Layout};
-    use std::cell::{Cell, RefCell};
-    use std::sync::Arc;
+/// A list of AST nodes.
+///
+/// Heap-backed lists behave like `Vec<T>`. A list converted from a `Vec` or
+/// cloned while a document is built moves its elements into an exactly sized
+/// block of the document arena and follows the same lifetime rules as an
+/// arena-backed [`AstBox`]. Growing an arena-backed list moves it to a larger
+/// arena block during a build and to the heap otherwise.
+pub struct AstVec<T> {
+    ptr: NonNull<T>,
+    len: usize,
+    // The high bit records the storage mode. Allocations never exceed
+    // `isize::MAX` bytes, so the capacity of a list of sized elements cannot
+    // reach it. Lists of zero-sized elements never use the arena.
+    capacity_and_tag: usize,
+    marker: PhantomData<T>,
+}
 
-    // Fixed-size chunks trade a small bounded tail for predictable retained
-    // memory. Large individual AST nodes receive a right-sized dedicated
-    // chunk. The corpus benchmark measures this constant directly.
-    const AST_CHUNK_BYTES: usize = 1024;
-    const LARGE_CHUNK_GRANULARITY: usize = 64;
+const ARENA_VEC_TAG: usize = 1 << (usize::BITS - 1);
 
-    struct AstArenaChunk {
-        ptr: NonNull<u8>,
-        layout: Layout,
-        cursor: usize,
-    }
-
-    impl AstArenaChunk {
-        fn for_value<T>() -> Self {
-            let value_size = core::mem::size_of::<T>().max(1);
-            let value_align = core::mem::align_of::<T>();
-            let minimum = value_size
-                .checked_add(value_align.saturating_sub(1))
-                .expect("AST arena allocation size overflow");
-            let size = if minimum <= AST_CHUNK_BYTES {
-                AST_CHUNK_BYTES
-            } else {
-                minimum
-                    .checked_add(LARGE_CHUNK_GRANULARITY - 1)
-                    .expect("AST arena chunk size overflow")
-                    / LARGE_CHUNK_GRANULARITY
-                    * LARGE_CHUNK_GRANULARITY
-            };
-            let layout =
-                Layout::from_size_align(size, value_align.max(core::mem::align_of::<usize>()))
-                    .expect("valid AST arena chunk layout");
-            // SAFETY: `layout` is non-zero and valid. Allocation failure is
-            // handled with the standard allocation error path.
-            let ptr = unsafe { alloc(layout) };
-            let ptr = NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout));
-            Self {
-                ptr,
-                layout,
-                cursor: 0,
-            }
+impl<T> AstVec<T> {
+    /// Create an empty list without allocating.
+    pub const fn new() -> Self {
+        Self {
+            ptr: NonNull::dangling(),
+            len: 0,
+            capacity_and_tag: 0,
+            marker: PhantomData,
         }
+    }
 
-        fn try_alloc<T>(&mut self, value: T) -> Result<NonNull<T>, T> {
-            let align = core::mem::align_of::<T>();
-            if self.layout.align() < align {
-                return Err(value);
-            }
-            let aligned_cursor = self
-                .cursor
-                .checked_add(align - 1)
-                .map(|cursor| cursor & !(align - 1));
-            let Some(start) = aligned_cursor else {
-                return Err(value);
-            };
-            let occupied = core::mem::size_of::<T>().max(1);
-            let Some(end) = start.checked_add(occupied) else {
-                return Err(value);
-            };
-            if end > self.layout.size() {
-                return Err(value);
-            }
+    /// Whether the elements are stored in their parsed document's arena.
+    pub fn is_arena_allocated(&self) -> bool {
+        core::mem::size_of::<T>() != 0 && self.capacity_and_tag & ARENA_VEC_TAG != 0
+    }
 
-            // SAFETY: `start..end` is within this chunk and aligned for T.
-            // The bump cursor makes the slot unique for the chunk lifetime.
-            let ptr = unsafe { self.ptr.as_ptr().add(start).cast::<T>() };
-            // SAFETY: The destination is valid, aligned, and uninitialized.
-            unsafe { ptr.write(value) };
-            self.cursor = end;
-            // SAFETY: A successful global allocation plus an in-bounds offset
-            // cannot produce a null pointer.
-            Ok(unsafe { NonNull::new_unchecked(ptr) })
+    /// Number of elements the list can hold without moving its storage.
+    pub fn capacity(&self) -> usize {
+        if self.is_arena_allocated() {
+            self.capacity_and_tag & !ARENA_VEC_TAG
+        } else {
+            self.capacity_and_tag
         }
     }
 
-    impl Drop for AstArenaChunk {
-        fn drop(&mut self) {
-            // SAFETY: `ptr` was allocated with exactly `layout`. AST payloads
-            // are dropped through their owning AstBox before chunks are freed.
-            unsafe { dealloc(self.ptr.as_ptr(), self.layout) };
-        }
+    /// The elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: `ptr` is non-null and aligned, and the first `len` elements
+        // are initialized in both storage modes.
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
     }
 
-    struct BuildingAstArena {
-        chunks: RefCell<Vec<AstArenaChunk>>,
-        committed_bytes: Cell<usize>,
-        requested_bytes: Cell<usize>,
-        node_allocations: Cell<usize>,
+    /// The elements as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: As for `as_slice`; the list uniquely owns its elements.
+        // Frozen ParsedSql only exposes shared references, so this is
+        // reachable for arena lists only while the tree is being built.
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
     }
 
-    impl BuildingAstArena {
-        fn new() -> Self {
-            Self {
-                chunks: RefCell::new(Vec::new()),
-                committed_bytes: Cell::new(0),
-                requested_bytes: Cell::new(0),
-                node_allocations: Cell::new(0),
+    /// Move the elements into a `Vec` without cloning them.
+    ///
+    /// A heap list hands over its buffer. An arena list copies its elements
+    /// into a new buffer and leaves its block to the arena.
+    pub fn into_vec(self) -> Vec<T> {
+        let this = ManuallyDrop::new(self);
+        if this.is_arena_allocated() {
+            let mut vec = Vec::with_capacity(this.len);
+            // SAFETY: The new buffer has room for `len` elements, which are
+            // moved out of the arena block; forgetting `this` prevents them
+            // from being dropped twice.
+            unsafe {
+                core::ptr::copy_nonoverlapping(this.ptr.as_ptr(), vec.as_mut_ptr(), this.len);
+                vec.set_len(this.len);
             }
+            vec
+        } else {
+            // SAFETY: Heap lists hold the raw parts of a `Vec`.
+            unsafe { Vec::from_raw_parts(this.ptr.as_ptr(), this.len, this.capacity_and_tag) }
         }
+    }
 
-        fn alloc<T>(&self, value: T) -> NonNull<T> {
-            let mut chunks = self.chunks.borrow_mut();
-            let mut value = Some(value);
-            // Reuse aligned tail space in earlier chunks before committing a
-            // new one. Documents normally have only a handful of chunks, and
-            // this keeps large enum nodes from stranding room that later small
-            // nodes can occupy.
-            for chunk in chunks.iter_mut().rev() {
-                match chunk.try_alloc(value.take().expect("AST value is present")) {
-                    Ok(ptr) => return ptr,
-                    Err(returned) => value = Some(returned),
-                }
-            }
+    /// Consume the list and return independently owned elements.
+    ///
+    /// A heap list moves its elements without cloning. An arena list is
+    /// deep-cloned with arena allocation temporarily disabled, as in
+    /// [`AstBox::into_owned`].
+    pub fn into_owned(self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        if self.is_arena_allocated() {
+            #[cfg(feature = "std")]
+            let owned = without_active_arena(|| self.to_vec());
+            #[cfg(not(feature = "std"))]
+            let owned = self.to_vec();
+            drop(self);
+            owned
+        } else {
+            self.into_vec()
+        }
+    }
 
-            let mut chunk = AstArenaChunk::for_value::<T>();
-            let committed = chunk.layout.size();
-            let ptr = chunk
-                .try_alloc(value.expect("AST value is present"))
-                .unwrap_or_else(|_| unreachable!("fresh AST arena chunk fits its value"));
-            chunks.push(chunk);
-            self.committed_bytes.set(
-                self.committed_bytes
-                    .get()
-                    .checked_add(committed)
-                    .expect("AST arena committed byte count overflow"),
-            );
-            ptr
+    /// Reserve room for at least `additional` more elements.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self
+            .len
+            .checked_add(additional)
+            .expect("AstVec capacity overflow");
+        if required <= self.capacity() {
+            return;
+        }
+        if !self.is_arena_allocated() {
+            // SAFETY: Heap lists hold the raw parts of a `Vec`. The temporary
+            // is not dropped, so its buffer stays owned by `self` until the
+            // new parts are stored.
+            let mut vec = ManuallyDrop::new(unsafe {
+                Vec::from_raw_parts(self.ptr.as_ptr(), self.len, self.capacity_and_tag)
+            });
+            vec.reserve(additional);
+            self.set_heap_parts(vec);
+            return;
         }
 
-        fn freeze(self) -> FrozenAstArena {
-            FrozenAstArena {
-                _chunks: self.chunks.into_inner(),
-                committed_bytes: self.committed_bytes.get(),
-                requested_bytes: self.requested_bytes.get(),
-                node_allocations: self.node_allocations.get(),
-            }
+        let capacity = required.max(self.capacity().saturating_mul(2));
+        #[cfg(feature = "std")]
+        if let Some(ptr) = active_arena_alloc_slice::<T>(capacity) {
+            // SAFETY: The new block has room for `capacity >= len` elements
+            // and does not overlap the old one, which is left to the arena.
+            unsafe { core::ptr::copy_nonoverlapping(self.ptr.as_ptr(), ptr.as_ptr(), self.len) };
+            self.ptr = ptr;
+            self.capacity_and_tag = capacity | ARENA_VEC_TAG;
+            return;
         }
+        let mut vec = Vec::with_capacity(capacity);
+        // SAFETY: The elements move to the new heap buffer; the arena block
+        // is abandoned without dropping them.
+        unsafe {
+            core::ptr::copy_nonoverlapping(self.ptr.as_ptr(), vec.as_mut_ptr(), self.len);
+            vec.set_len(self.len);
+        }
+        self.set_heap_parts(ManuallyDrop::new(vec));
     }
 
-    thread_local! {
-        static ACTIVE_ARENA: Cell<*const BuildingAstArena> =
-            const { Cell::new(core::ptr::null()) };
+    fn set_heap_parts(&mut self, mut vec: ManuallyDrop<Vec<T>>) {
+        // SAFETY: A Vec buffer pointer is never null.
+        self.ptr = unsafe { NonNull::new_unchecked(vec.as_mut_ptr()) };
+        self.len = vec.len();
+        self.capacity_and_tag = vec.capacity();
     }
 
-    pub(super) fn active_arena_alloc<T>(value: T) -> Result<NonNull<T>, T> {
-        ACTIVE_ARENA.with(|active| {
-            let arena = active.get();
-            if arena.is_null() {
-                Err(value)
-            } else {
-                // SAFETY: ACTIVE_ARENA is installed by `with_arena` for the
-                // dynamic extent of parsing. The building arena outlives every
-                // allocation returned during that scope.
-                let arena = unsafe { &*arena };
-                arena.requested_bytes.set(
-                    arena
-                        .requested_bytes
-                        .get()
-                        .saturating_add(core::mem::size_of::<T>()),
-                );
-                arena
-                    .node_allocations
-                    .set(arena.node_allocations.get().saturating_add(1));
-                Ok(arena.alloc(value))
-            }
-        })
+    /// Append an element.
+    pub fn push(&mut self, value: T) {
+        self.reserve(1);
+        // SAFETY: `reserve` made room for one more element.
+        unsafe { self.ptr.as_ptr().add(self.len).write(value) };
+        self.len += 1;
     }
 
-    pub(super) fn without_active_arena<T>(f: impl FnOnce() -> T) -> T {
-        ACTIVE_ARENA.with(|active| {
-            let previous = active.replace(core::ptr::null());
-            struct Restore<'a> {
-                active: &'a Cell<*const BuildingAstArena>,
-                previous: *const BuildingAstArena,
-            }
-            impl Drop for Restore<'_> {
-                fn drop(&mut self) {
-                    self.active.set(self.previous);
-                }
-            }
-            let restore = Restore { active, previous };
-            let result = f();
-            drop(restore);
-            result
-        })
+    /// Remove and return the last element.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // SAFETY: The element was initialized and is no longer counted.
+        Some(unsafe { self.ptr.as_ptr().add(self.len).read() })
     }
 
-    fn with_arena<T>(arena: &BuildingAstArena, f: impl FnOnce() -> T) -> T {
-        ACTIVE_ARENA.with(|active| {
-            let previous = active.replace(arena as *const BuildingAstArena);
-            struct Restore<'a> {
-                active: &'a Cell<*const BuildingAstArena>,
-                previous: *const BuildingAstArena,
-            }
-            impl Drop for Restore<'_> {
-                fn drop(&mut self) {
-                    self.active.set(self.previous);
-                }
-            }
-            let restore = Restore { active, previous };
-            let result = f();
-            drop(restore);
-            result
-        })
+    /// Insert an element at `index`, shifting later elements right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(
+            index <= self.len,
+            "insertion index (is {index}) should be <= len (is {})",
+            self.len
+        );
+        self.reserve(1);
+        // SAFETY: `reserve` made room to shift `index..len` by one.
+        unsafe {
+            let slot = self.ptr.as_ptr().add(index);
+            core::ptr::copy(slot, slot.add(1), self.len - index);
+            slot.write(value);
+        }
+        self.len += 1;
     }
 
-    /// Allocation statistics for a frozen syntax document.
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-    pub struct AstArenaStats {
-        /// Number of recursive AST nodes allocated from the arena.
-        pub node_allocations: usize,
-        /// Payload bytes requested by recursive AST nodes.
-        pub requested_bytes: usize,
-        /// Bytes committed in arena chunks for recursive AST nodes.
-        pub committed_bytes: usize,
-        /// Committed bytes not occupied by requested node payloads.
-        pub slack_bytes: usize,
+    /// Remove and return the element at `index`, shifting later elements
+    /// left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(
+            index < self.len,
+            "removal index (is {index}) should be < len (is {})",
+            self.len
+        );
+        // SAFETY: The element at `index` is initialized; the tail is shifted
+        // over its slot before the length shrinks.
+        unsafe {
+            let slot = self.ptr.as_ptr().add(index);
+            let value = slot.read();
+            core::ptr::copy(slot.add(1), slot, self.len - index - 1);
+            self.len -= 1;
+            value
+        }
     }
 
-    struct FrozenAstArena {
-        _chunks: Vec<AstArenaChunk>,
-        committed_bytes: usize,
-        requested_bytes: usize,
-        node_allocations: usize,
+    /// Drop the elements from `len` onwards.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        let tail = core::ptr::slice_from_raw_parts_mut(
+            // SAFETY: `len < self.len`, so the offset is in bounds.
+            unsafe { self.ptr.as_ptr().add(len) },
+            self.len - len,
+        );
+        // Shrink first so a panicking destructor cannot cause a double drop.
+        self.len = len;
+        // SAFETY: The tail elements are initialized and no longer counted.
+        unsafe { core::ptr::drop_in_place(tail) };
     }
 
-    impl FrozenAstArena {
-        fn stats(&self) -> AstArenaStats {
-            AstArenaStats {
-                node_allocations: self.node_allocations,
-                requested_bytes: self.requested_bytes,
-                committed_bytes: self.committed_bytes,
-                slack_bytes: self.committed_bytes.saturating_sub(self.requested_bytes),
+    /// Drop all elements, keeping the storage.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Keep only the elements for which `keep` returns true, in order.
+    pub fn retain(&mut self, mut keep: impl FnMut(&T) -> bool) {
+        let len = self.len;
+        // Elements are leaked rather than dropped twice if `keep` panics.
+        self.len = 0;
+        let mut kept = 0;
+        for index in 0..len {
+            // SAFETY: `index < len`, and every element at or after `index` is
+            // still initialized and in place.
+            unsafe {
+                let element = self.ptr.as_ptr().add(index);
+                if keep(&*element) {
+                    if kept != index {
+                        core::ptr::copy_nonoverlapping(element, self.ptr.as_ptr().add(kept), 1);
+                    }
+                    kept += 1;
+                } else {
+                    core::ptr::drop_in_place(element);
+                }
             }
         }
+        self.len = kept;
     }
 
-    // SAFETY: FrozenAstArena exposes no allocation or mutable access after
-    // construction. AstBox pointees are read-only through ParsedSql.
-    unsafe impl Sync for FrozenAstArena {}
-    // SAFETY: Frozen chunks are immutable and their allocations may be freed
-    // on any thread supported by the global allocator.
-    unsafe impl Send for FrozenAstArena {}
-
-    /// An owned SQL source and its frozen parsed statement batch.
-    pub struct ParsedSql {
-        // Statements must drop before the bump storage they reference. Struct
-        // fields are dropped in declaration order.
-        statements: Vec<Statement>,
-        statement_spans: Vec<Span>,
-        optimizer_hints: Vec<OptimizerHint>,
-        source: Arc<str>,
-        arena: FrozenAstArena,
+    /// Move all elements of `other` to the end of this list.
+    pub fn append(&mut self, other: &mut Vec<T>) {
+        self.extend(other.drain(..));
     }
+}
 
-    impl ParsedSql {
-        /// Parse SQL into one shareable, arena-owned syntax document.
-        pub fn parse(
-            dialect: &dyn Dialect,
-            source: impl Into<Arc<str>>,
-        ) -> Result<Arc<Self>, ParserError> {
-            // SAFETY: The no-op callback cannot move an arena node out of the
-            // document under construction.
-            unsafe { Self::parse_and_edit(dialect, source, |_| ()) }.map(|(document, ())| document)
+impl<T> Drop for AstVec<T> {
+    fn drop(&mut self) {
+        if self.is_arena_allocated() {
+            // SAFETY: The elements are uniquely owned by this list. Like an
+            // arena AstBox, the block itself is freed with the arena.
+            unsafe { core::ptr::drop_in_place(self.as_mut_slice()) };
+        } else {
+            // SAFETY: Heap lists hold the raw parts of a `Vec`.
+            unsafe {
+                drop(Vec::from_raw_parts(
+                    self.ptr.as_ptr(),
+                    self.len,
+                    self.capacity_and_tag,
+                ))
+            };
         }
+    }
+}
 
-        /// Parse SQL with explicit parser options into one shareable,
-        /// arena-owned syntax document.
-        pub fn parse_with_options(
-            dialect: &dyn Dialect,
-            source: impl Into<Arc<str>>,
-            options: ParserOptions,
-        ) -> Result<Arc<Self>, ParserError> {
-            // SAFETY: The no-op callback cannot move an arena node out of the
-            // document under construction.
-            unsafe { Self::parse_and_edit_with_options(dialect, source, options, |_| ()) }
-                .map(|(document, ())| document)
-        }
+impl<T> Deref for AstVec<T> {
+    type Target = [T];
 
-        /// Parse and mutate a document while its arena is still in the
-        /// building state, then freeze it into a shareable owner.
-        ///
-        /// Recursive nodes created by `edit` are allocated in the same arena
-        /// as parser-created nodes. The callback result can itself be a
-        /// consumer-specific `Result`, allowing callers to preserve their own
-        /// error type without coupling it to [`ParserError`].
-        /// # Safety
-        ///
-        /// `edit` must not move or copy an arena-backed AST node anywhere that
-        /// can outlive the returned document. Any callback result containing
-        /// syntax must remain owned by, and be destroyed before, that document.
-        pub unsafe fn parse_and_edit<R>(
-            dialect: &dyn Dialect,
-            source: impl Into<Arc<str>>,
-            edit: impl FnOnce(&mut [Statement]) -> R,
-        ) -> Result<(Arc<Self>, R), ParserError> {
-            let options =
-                ParserOptions::new().with_trailing_commas(dialect.supports_trailing_commas());
-            // SAFETY: The caller accepts and must uphold the same syntax
-            // ownership contract documented on this method.
-            unsafe { Self::parse_and_edit_with_options(dialect, source, options, edit) }
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> DerefMut for AstVec<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Clone> Clone for AstVec<T> {
+    fn clone(&self) -> Self {
+        #[cfg(feature = "std")]
+        if let Some(ptr) = active_arena_alloc_slice::<T>(self.len) {
+            let mut cloned = Self {
+                ptr,
+                len: 0,
+                capacity_and_tag: self.len | ARENA_VEC_TAG,
+                marker: PhantomData,
+            };
+            for item in self.iter() {
+                // SAFETY: The block has room for `self.len` elements. If a
+                // clone panics, dropping `cloned` drops only the elements
+                // written so far.
+                unsafe { cloned.ptr.as_ptr().add(cloned.len).write(item.clone()) };
+                cloned.len += 1;
+            }
+            return cloned;
+        }
+        Self::from(self.to_vec())
+    }
+}
+
+impl<T> AstVec<T> {
+    /// Like converting `vec` with [`From`], but fails without allocating
+    /// when its elements do not fit the remaining byte budget of the active
+    /// document arena.
+    pub(crate) fn try_from_vec(vec: Vec<T>) -> Result<Self, ParserError> {
+        if !vec.is_empty() && core::mem::size_of::<T>() != 0 {
+            let layout = Layout::array::<T>(vec.len()).expect("valid list layout");
+            check_arena_budget(layout)?;
+        }
+        Ok(Self::from(vec))
+    }
+}
+
+impl<T> From<Vec<T>> for AstVec<T> {
+    fn from(vec: Vec<T>) -> Self {
+        #[cfg(feature = "std")]
+        if let Some(ptr) = active_arena_alloc_slice::<T>(vec.len()) {
+            let mut vec = vec;
+            let len = vec.len();
+            // SAFETY: The arena block has room for `len` elements. They are
+            // moved out of `vec`, which then frees only its buffer.
+            unsafe {
+                core::ptr::copy_nonoverlapping(vec.as_ptr(), ptr.as_ptr(), len);
+                vec.set_len(0);
+            }
+            return Self {
+                ptr,
+                len,
+                capacity_and_tag: len | ARENA_VEC_TAG,
+                marker: PhantomData,
+            };
+        }
+        let mut this = Self::new();
+        this.set_heap_parts(ManuallyDrop::new(vec));
+        this
+    }
+}
+
+impl<T> From<AstVec<T>> for Vec<T> {
+    fn from(list: AstVec<T>) -> Self {
+        list.into_vec()
+    }
+}
+
+impl<T: Clone> From<&[T]> for AstVec<T> {
+    fn from(items: &[T]) -> Self {
+        Self::from(items.to_vec())
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for AstVec<T> {
+    fn from(items: [T; N]) -> Self {
+        Self::from(Vec::from(items))
+    }
+}
+
+impl<T> FromIterator<T> for AstVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(Vec::from_iter(iter))
+    }
+}
+
+impl<T> Extend<T> for AstVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T> IntoIterator for AstVec<T> {
+    type Item = T;
+    type IntoIter = alloc_vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_vec().into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a AstVec<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut AstVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> Default for AstVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for AstVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<T: PartialEq> PartialEq for AstVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq> PartialEq<Vec<T>> for AstVec<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq> PartialEq<AstVec<T>> for Vec<T> {
+    fn eq(&self, other: &AstVec<T>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq> PartialEq<[T]> for AstVec<T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for AstVec<T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq> Eq for AstVec<T> {}
+
+impl<T: PartialOrd> PartialOrd for AstVec<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord> Ord for AstVec<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: Hash> Hash for AstVec<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T> AsRef<[T]> for AstVec<T> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> AsMut<[T]> for AstVec<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T> Borrow<[T]> for AstVec<T> {
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> BorrowMut<[T]> for AstVec<T> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+// SAFETY: AstVec uniquely owns its elements, like Vec<T>.
+unsafe impl<T: Send> Send for AstVec<T> {}
+// SAFETY: Shared access requires T: Sync, and frozen arena storage is immutable.
+unsafe impl<T: Sync> Sync for AstVec<T> {}
+
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for AstVec<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for AstVec<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::deserialize(deserializer).map(Self::from)
+    }
+}
+
+/// Leaf text of the AST, such as an identifier or a literal value.
+///
+/// Text parsed into a document that appears verbatim in the SQL source is a
+/// slice of the source retained by the owning [`ParsedSql`], so it needs no
+/// allocation of its own. Text that unescaping or case folding changed, and
+/// all text created outside a document build, owns a `String`. Cloning
+/// source-backed text outside a build of a document sharing the same source
+/// produces owned text, as does [`AstBox::into_owned`] for its subtree.
+pub struct AstStr {
+    repr: AstStrRepr,
+}
+
+enum AstStrRepr {
+    Owned(String),
+    // A slice of the source of the document under construction. The source
+    // `Arc<str>` is owned by the same ParsedSql as the node holding this text.
+    // Sharing String's layout niche keeps AstStr the size of a String.
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    Source {
+        ptr: NonNull<u8>,
+        len: usize,
+    },
+}
+
+impl AstStr {
+    /// Empty text. This does not allocate.
+    pub const fn new() -> Self {
+        Self {
+            repr: AstStrRepr::Owned(String::new()),
+        }
+    }
+
+    /// Text that was tokenized from the source at byte `offset`, borrowed
+    /// from the document being built when the source holds exactly `text`
+    /// there. Borrowed `text` is only copied when the source does not hold it.
+    #[cfg_attr(feature = "bigdecimal", allow(dead_code))]
+    pub(crate) fn from_source_offset(text: Cow<'_, str>, offset: Option<usize>) -> Self {
+        #[cfg(feature = "std")]
+        if let Some(ptr) = offset.and_then(|offset| active_source_text_at(offset, &text)) {
+            return Self {
+                repr: AstStrRepr::Source {
+                    ptr,
+                    len: text.len(),
+                },
+            };
+        }
+        #[cfg(not(feature = "std"))]
+        let _ = offset;
+        Self::from(text.into_owned())
+    }
+
+    /// The text as a string slice.
+    pub fn as_str(&self) -> &str {
+        match &self.repr {
+            AstStrRepr::Owned(text) => text,
+            // SAFETY: `ptr..ptr + len` was checked to be a slice of a `str`
+            // source kept alive by the document that owns this node.
+            AstStrRepr::Source { ptr, len } => unsafe {
+                core::str::from_utf8_unchecked(core::slice::from_raw_parts(ptr.as_ptr(), *len))
+            },
+        }
+    }
+
+    /// Whether the text is a slice of its parsed document's source.
+    pub fn is_source_backed(&self) -> bool {
+        matches!(self.repr, AstStrRepr::Source { .. })
+    }
+
+    /// Mutable access to the text, copying source-backed text first.
+    pub fn to_mut(&mut self) -> &mut String {
+        if let AstStrRepr::Source { .. } = self.repr {
+            self.repr = AstStrRepr::Owned(self.as_str().to_owned());
+        }
+        match &mut self.repr {
+            AstStrRepr::Owned(text) => text,
+            AstStrRepr::Source { .. } => unreachable!("source-backed text was copied"),
+        }
+    }
+
+    /// Consume the text and return an owned `String`.
+    pub fn into_string(self) -> String {
+        match self.repr {
+            AstStrRepr::Owned(text) => text,
+            AstStrRepr::Source { .. } => self.as_str().to_owned(),
+        }
+    }
+}
+
+impl Deref for AstStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Clone for AstStr {
+    fn clone(&self) -> Self {
+        match &self.repr {
+            AstStrRepr::Owned(text) => Self::from(text.clone()),
+            AstStrRepr::Source { .. } => Self::from(self.as_str()),
+        }
+    }
+}
+
+impl Default for AstStr {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<String> for AstStr {
+    fn from(text: String) -> Self {
+        #[cfg(feature = "std")]
+        note_owned_leaf(&text);
+        Self {
+            repr: AstStrRepr::Owned(text),
+        }
+    }
+}
+
+/// Borrows `text` when it is a slice of the source of the document being
+/// built, and copies it otherwise.
+impl From<&str> for AstStr {
+    fn from(text: &str) -> Self {
+        #[cfg(feature = "std")]
+        if let Some(ptr) = active_source_slice(text) {
+            return Self {
+                repr: AstStrRepr::Source {
+                    ptr,
+                    len: text.len(),
+                },
+            };
+        }
+        Self::from(text.to_owned())
+    }
+}
+
+impl From<&String> for AstStr {
+    fn from(text: &String) -> Self {
+        Self::from(text.as_str())
+    }
+}
+
+impl From<Cow<'_, str>> for AstStr {
+    fn from(text: Cow<'_, str>) -> Self {
+        match text {
+            Cow::Borrowed(text) => Self::from(text),
+            Cow::Owned(text) => Self::from(text),
+        }
+    }
+}
+
+impl From<AstStr> for String {
+    fn from(text: AstStr) -> Self {
+        text.into_string()
+    }
+}
+
+impl core::str::FromStr for AstStr {
+    type Err = core::convert::Infallible;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(text))
+    }
+}
+
+impl fmt::Debug for AstStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for AstStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq for AstStr {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for AstStr {}
+
+impl PartialOrd for AstStr {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AstStr {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for AstStr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+macro_rules! impl_ast_str_eq {
+    ($($other:ty),+) => {
+        $(impl PartialEq<$other> for AstStr {
+            fn eq(&self, other: &$other) -> bool {
+                self.as_str() == AsRef::<str>::as_ref(other)
+            }
+        }
+        impl PartialEq<AstStr> for $other {
+            fn eq(&self, other: &AstStr) -> bool {
+                AsRef::<str>::as_ref(self) == other.as_str()
+            }
+        })+
+    };
+}
+
+impl_ast_str_eq!(str, &str, String);
+
+impl AsRef<str> for AstStr {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl Borrow<str> for AstStr {
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+
+// SAFETY: Owned text is a String. Source-backed text is an immutable slice
+// of an `Arc<str>` owned by the same ParsedSql as the node holding it.
+unsafe impl Send for AstStr {}
+// SAFETY: Shared access only reads immutable text.
+unsafe impl Sync for AstStr {}
+
+#[cfg(feature = "serde")]
+impl Serialize for AstStr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for AstStr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+/// Syntax that a document can release without running its destructors.
+///
+/// A value is arena-resident when it owns no heap allocation: everything it
+/// owns is stored inline, in a document arena, or in the document source, so
+/// dropping it releases nothing. That is exactly when its [`HeapSize`]
+/// reports no heap bytes, which is how every sized type is arena-resident.
+/// A document checks each node as it places it in its arena and then frees
+/// its chunks without walking the tree.
+pub trait ArenaResident {
+    /// Whether this value and everything it owns hold no heap allocation.
+    fn is_arena_resident(&self) -> bool;
+}
+
+impl<T: HeapSize> ArenaResident for T {
+    fn is_arena_resident(&self) -> bool {
+        self.heap_size().heap_bytes == 0
+    }
+}
+
+/// Bytes a value owns beyond its own inline size, by where they are stored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HeapUsage {
+    /// Bytes of global heap allocations: owned text, `Vec` and `Box`
+    /// buffers, and heap-backed [`AstBox`] and [`AstVec`] storage.
+    pub heap_bytes: usize,
+    /// Bytes of arena-backed [`AstBox`] and [`AstVec`] storage. These are
+    /// payload bytes within the document's committed arena chunks.
+    pub arena_bytes: usize,
+}
+
+impl HeapUsage {
+    /// Bytes held in either the heap or an arena.
+    pub fn total_bytes(&self) -> usize {
+        self.heap_bytes.saturating_add(self.arena_bytes)
+    }
+
+    fn add(&mut self, bytes: usize, arena: bool) {
+        let counter = if arena {
+            &mut self.arena_bytes
+        } else {
+            &mut self.heap_bytes
+        };
+        *counter = counter.saturating_add(bytes);
+    }
+}
+
+/// Deep accounting of the memory an AST value retains.
+///
+/// Source-backed leaf text owns no bytes, since the document source holds
+/// it. Nodes a rewritten document shares with the document it was derived
+/// from are counted by that document, not by the one sharing them. The
+/// trait is derived for every AST type.
+///
+/// ```
+/// # use sqlparser::{ast::HeapSize, dialect::PostgreSqlDialect, parser::Parser};
+/// let statements = Parser::parse_sql(&PostgreSqlDialect {}, "SELECT a + 1 FROM t").unwrap();
+/// let usage = statements[0].heap_size();
+/// assert!(usage.heap_bytes > 0);
+/// assert_eq!(usage.arena_bytes, 0);
+/// ```
+pub trait HeapSize {
+    /// Add the bytes this value owns beyond its inline size to `usage`.
+    fn add_heap_size(&self, usage: &mut HeapUsage);
+
+    /// The bytes this value owns beyond its inline size.
+    fn heap_size(&self) -> HeapUsage {
+        let mut usage = HeapUsage::default();
+        self.add_heap_size(&mut usage);
+        usage
+    }
+}
+
+impl<T: HeapSize> HeapSize for AstBox<T> {
+    #[cfg_attr(feature = "recursive-protection", recursive::recursive)]
+    fn add_heap_size(&self, usage: &mut HeapUsage) {
+        if self.is_shared() {
+            return;
+        }
+        usage.add(
+            core::mem::size_of::<AstBoxStorage<T>>(),
+            self.is_arena_allocated(),
+        );
+        // The boxes of a node being placed in the arena were checked when
+        // they were placed themselves.
+        if !placing_in_arena() {
+            (**self).add_heap_size(usage);
+        }
+    }
+}
+
+impl<T: HeapSize> HeapSize for AstVec<T> {
+    fn add_heap_size(&self, usage: &mut HeapUsage) {
+        usage.add(
+            self.capacity().saturating_mul(core::mem::size_of::<T>()),
+            self.is_arena_allocated(),
+        );
+        self.iter().for_each(|element| element.add_heap_size(usage));
+    }
+}
+
+impl HeapSize for AstStr {
+    fn add_heap_size(&self, usage: &mut HeapUsage) {
+        if let AstStrRepr::Owned(text) = &self.repr {
+            text.add_heap_size(usage);
+        }
+    }
+}
+
+impl HeapSize for String {
+    fn add_heap_size(&self, usage: &mut HeapUsage) {
+        usage.add(self.capacity(), false);
+    }
+}
+
+impl<T: HeapSize> HeapSize for Vec<T> {
+    fn add_heap_size(&self, usage: &mut HeapUsage) {
+        usage.add(
+            self.capacity().saturating_mul(core::mem::size_of::<T>()),
+            false,
+        );
+        self.iter().for_each(|element| element.add_heap_size(usage));
+    }
+}
+
+impl<T: HeapSize> HeapSize for Box<T> {
+    fn add_heap_size(&self, usage: &mut HeapUsage) {
+        usage.add(core::mem::size_of::<T>(), false);
+        (**self).add_heap_size(usage);
+    }
+}
+
+impl<B: ToOwned + ?Sized> HeapSize for Cow<'_, B>
+where
+    B::Owned: HeapSize,
+{
+    fn add_heap_size(&self, usage: &mut HeapUsage) {
+        if let Cow::Owned(owned) = self {
+            owned.add_heap_size(usage);
+        }
+    }
+}
+
+impl<T: HeapSize> HeapSize for Option<T> {
+    fn add_heap_size(&self, usage: &mut HeapUsage) {
+        if let Some(value) = self {
+            value.add_heap_size(usage);
+        }
+    }
+}
+
+impl<A: HeapSize, B: HeapSize> HeapSize for (A, B) {
+    fn add_heap_size(&self, usage: &mut HeapUsage) {
+        self.0.add_heap_size(usage);
+        self.1.add_heap_size(usage);
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl HeapSize for bigdecimal::BigDecimal {
+    fn add_heap_size(&self, usage: &mut HeapUsage) {
+        // The digits are stored as 64-bit words, each holding a little over
+        // 19 decimal digits. This estimates their buffer without its spare
+        // capacity.
+        let words = self.digits().div_ceil(19);
+        usage.add(
+            usize::try_from(words)
+                .unwrap_or(usize::MAX)
+                .saturating_mul(core::mem::size_of::<u64>()),
+            false,
+        );
+    }
+}
+
+macro_rules! heap_size_inline {
+    ($($t:ty),+) => {
+        $(impl HeapSize for $t {
+            fn add_heap_size(&self, _usage: &mut HeapUsage) {}
+        })+
+    };
+}
+
+heap_size_inline!(bool, char, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+
+// Fixed-size chunks trade a small bounded tail for predictable retained
+// memory. Large individual AST nodes receive a right-sized dedicated
+// chunk. The corpus benchmark measures this constant directly.
+const AST_CHUNK_BYTES: usize = 1024;
+const LARGE_CHUNK_GRANULARITY: usize = 64;
+
+struct AstArenaChunk {
+    ptr: NonNull<u8>,
+    layout: Layout,
+    cursor: usize,
+}
+
+impl AstArenaChunk {
+    /// The chunk layout that fits `value`: a standard chunk, or a
+    /// dedicated one for a large node.
+    fn layout_for(value: Layout) -> Layout {
+        let value_size = value.size().max(1);
+        let value_align = value.align();
+        let minimum = value_size
+            .checked_add(value_align.saturating_sub(1))
+            .expect("AST arena allocation size overflow");
+        let size = if minimum <= AST_CHUNK_BYTES {
+            AST_CHUNK_BYTES
+        } else {
+            minimum
+                .checked_add(LARGE_CHUNK_GRANULARITY - 1)
+                .expect("AST arena chunk size overflow")
+                / LARGE_CHUNK_GRANULARITY
+                * LARGE_CHUNK_GRANULARITY
+        };
+        Layout::from_size_align(size, value_align.max(core::mem::align_of::<usize>()))
+            .expect("valid AST arena chunk layout")
+    }
+
+    /// The layout of standard chunks, the only ones an [`AstChunkPool`]
+    /// retains.
+    #[cfg(feature = "std")]
+    fn standard_layout() -> Layout {
+        Self::layout_for(Layout::new::<usize>())
+    }
+
+    fn with_layout(layout: Layout) -> Self {
+        // SAFETY: `layout` is non-zero and valid. Allocation failure is
+        // handled with the standard allocation error path.
+        let ptr = unsafe { alloc(layout) };
+        let ptr = NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout));
+        Self {
+            ptr,
+            layout,
+            cursor: 0,
+        }
+    }
+
+    /// The byte range `value` would occupy in this chunk, if it fits.
+    fn slot(&self, value: Layout) -> Option<(usize, usize)> {
+        let align = value.align();
+        if self.layout.align() < align {
+            return None;
+        }
+        let start = self
+            .cursor
+            .checked_add(align - 1)
+            .map(|cursor| cursor & !(align - 1))?;
+        let occupied = value.size().max(1);
+        let end = start.checked_add(occupied)?;
+        (end <= self.layout.size()).then_some((start, end))
+    }
+
+    fn try_alloc(&mut self, value: Layout) -> Option<NonNull<u8>> {
+        let (start, end) = self.slot(value)?;
+
+        // SAFETY: `start..end` is within this chunk and aligned for the
+        // requested layout. The bump cursor makes the slot unique for the
+        // chunk lifetime.
+        let ptr = unsafe { self.ptr.as_ptr().add(start) };
+        self.cursor = end;
+        // SAFETY: A successful global allocation plus an in-bounds offset
+        // cannot produce a null pointer.
+        Some(unsafe { NonNull::new_unchecked(ptr) })
+    }
+}
+
+impl Drop for AstArenaChunk {
+    fn drop(&mut self) {
+        // SAFETY: `ptr` was allocated with exactly `layout`. AST payloads
+        // are dropped through their owning AstBox before chunks are freed.
+        unsafe { dealloc(self.ptr.as_ptr(), self.layout) };
+    }
+}
+
+// SAFETY: A chunk uniquely owns its allocation, which the global
+// allocator may free on any thread. Chunks only move between threads
+// through an `AstChunkPool`, once no node refers to them.
+unsafe impl Send for AstArenaChunk {}
+
+/// Bump-allocate `value` in `chunks`, reusing aligned tail space in earlier
+/// chunks before committing the chunk `commit` returns for the layout
+/// [`AstArenaChunk::layout_for`] chooses.
+fn bump_alloc(
+    chunks: &mut Vec<AstArenaChunk>,
+    value: Layout,
+    commit: impl FnOnce(Layout) -> AstArenaChunk,
+) -> NonNull<u8> {
+    // Documents normally have only a handful of chunks, and this keeps large
+    // enum nodes from stranding room that later small nodes can occupy.
+    for chunk in chunks.iter_mut().rev() {
+        if let Some(ptr) = chunk.try_alloc(value) {
+            return ptr;
+        }
+    }
+
+    let mut chunk = commit(AstArenaChunk::layout_for(value));
+    let ptr = chunk
+        .try_alloc(value)
+        .unwrap_or_else(|| unreachable!("fresh AST arena chunk fits its value"));
+    chunks.push(chunk);
+    ptr
+}
+
+/// Check that placing `value` in `chunks` keeps the bytes they commit
+/// within `max`, before [`bump_alloc`] places it. Only a value that needs a
+/// fresh chunk commits more.
+fn check_budget(
+    chunks: &[AstArenaChunk],
+    committed: usize,
+    max: Option<usize>,
+    value: Layout,
+) -> Result<(), ParserError> {
+    let Some(max) = max else {
+        return Ok(());
+    };
+    if chunks.iter().any(|chunk| chunk.slot(value).is_some()) {
+        return Ok(());
+    }
+    match committed.checked_add(AstArenaChunk::layout_for(value).size()) {
+        Some(committed) if committed <= max => Ok(()),
+        _ => Err(LimitExceeded {
+            limit: ParserLimit::ArenaBytes,
+            max,
+        }
+        .into()),
+    }
+}
+
+/// An arena handed to the parser explicitly, for building a
+/// [`CoreParsedSql`] without the thread-local arena scope that
+/// [`ParsedSql`] parses under.
+pub(crate) struct AstArena {
+    chunks: RefCell<Vec<AstArenaChunk>>,
+    committed_bytes: Cell<usize>,
+    requested_bytes: Cell<usize>,
+    node_allocations: Cell<usize>,
+    #[cfg(feature = "ast-histogram")]
+    allocations_by_type: RefCell<AstAllocationHistogram>,
+    // See `ParserLimits::max_arena_bytes`. Allocations that would exceed it
+    // fail before anything is committed.
+    max_committed_bytes: Option<usize>,
+}
+
+impl AstArena {
+    pub(crate) fn new(max_committed_bytes: Option<usize>) -> Self {
+        Self {
+            chunks: RefCell::new(Vec::new()),
+            committed_bytes: Cell::new(0),
+            requested_bytes: Cell::new(0),
+            node_allocations: Cell::new(0),
+            #[cfg(feature = "ast-histogram")]
+            allocations_by_type: RefCell::default(),
+            max_committed_bytes,
+        }
+    }
+
+    fn alloc<T>(&self, value: T) -> Result<NonNull<T>, ParserError> {
+        let mut chunks = self.chunks.borrow_mut();
+        check_budget(
+            &chunks,
+            self.committed_bytes.get(),
+            self.max_committed_bytes,
+            Layout::new::<T>(),
+        )?;
+        let ptr = bump_alloc(&mut chunks, Layout::new::<T>(), |layout| {
+            let committed = self
+                .committed_bytes
+                .get()
+                .checked_add(layout.size())
+                .expect("AST arena committed byte count overflow");
+            self.committed_bytes.set(committed);
+            AstArenaChunk::with_layout(layout)
+        });
+        let ptr = ptr.cast::<T>();
+        // SAFETY: The slot is valid, aligned for T, and uninitialized.
+        unsafe { ptr.as_ptr().write(value) };
+        self.requested_bytes.set(
+            self.requested_bytes
+                .get()
+                .saturating_add(core::mem::size_of::<T>()),
+        );
+        self.node_allocations
+            .set(self.node_allocations.get().saturating_add(1));
+        Ok(ptr)
+    }
+
+    fn stats(&self, destructor_free: bool) -> AstArenaStats {
+        let committed_bytes = self.committed_bytes.get();
+        let requested_bytes = self.requested_bytes.get();
+        AstArenaStats {
+            node_allocations: self.node_allocations.get(),
+            requested_bytes,
+            committed_bytes,
+            slack_bytes: committed_bytes.saturating_sub(requested_bytes),
+            list_allocations: 0,
+            list_bytes: 0,
+            borrowed_leaves: 0,
+            borrowed_leaf_bytes: 0,
+            copied_leaves: 0,
+            copied_leaf_bytes: 0,
+            shared_subtrees: 0,
+            shared_bytes: 0,
+            destructor_free,
+            #[cfg(feature = "ast-histogram")]
+            allocations_by_type: self.allocations_by_type.borrow().clone(),
+        }
+    }
+}
+
+/// Allocation statistics for a frozen syntax document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AstArenaStats {
+    /// Number of recursive AST nodes allocated from the arena.
+    pub node_allocations: usize,
+    /// Payload bytes requested by recursive AST nodes and lists.
+    pub requested_bytes: usize,
+    /// Bytes committed in this document's own arena chunks for recursive
+    /// AST nodes and lists. These are private to the document and freed
+    /// with it.
+    pub committed_bytes: usize,
+    /// Committed bytes not occupied by requested payloads.
+    pub slack_bytes: usize,
+    /// Number of list blocks allocated from the arena.
+    pub list_allocations: usize,
+    /// Element bytes requested by list blocks, included in
+    /// `requested_bytes`.
+    pub list_bytes: usize,
+    /// Number of leaf texts stored as slices of the document source.
+    pub borrowed_leaves: usize,
+    /// Source bytes referenced by borrowed leaf texts.
+    pub borrowed_leaf_bytes: usize,
+    /// Number of leaf texts that own a heap copy, because unescaping or
+    /// case folding changed them or they were not taken from the source.
+    pub copied_leaves: usize,
+    /// Bytes of leaf text owned by heap copies.
+    pub copied_leaf_bytes: usize,
+    /// Number of subtrees a rewrite shared with the documents it was
+    /// derived from instead of copying them.
+    pub shared_subtrees: usize,
+    /// Bytes committed by the arenas of the documents this document was
+    /// rewritten from, which it keeps alive. Zero for parsed documents.
+    pub shared_bytes: usize,
+    /// Whether the statements own no heap allocation, so that dropping
+    /// the document frees its chunks without walking the syntax tree.
+    pub destructor_free: bool,
+    /// The `node_allocations` per boxed node type.
+    #[cfg(feature = "ast-histogram")]
+    pub allocations_by_type: AstAllocationHistogram,
+}
+
+/// Per-type [`AstBox`] allocation counts, collected only when the
+/// `ast-histogram` feature is enabled.
+#[cfg(feature = "ast-histogram")]
+mod histogram {
+    use std::collections::BTreeMap;
+
+    use super::AstBoxStorage;
+
+    /// Allocation counts for one boxed node type.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct AstTypeAllocations {
+        /// Number of boxes allocated.
+        pub allocations: u64,
+        /// Bytes allocated for them, including alignment padding of the box
+        /// storage.
+        pub bytes: u64,
+    }
+
+    impl AstTypeAllocations {
+        fn add(&mut self, other: AstTypeAllocations) {
+            self.allocations += other.allocations;
+            self.bytes += other.bytes;
+        }
+    }
+
+    /// The [`AstBox`](super::AstBox) allocations of one document arena,
+    /// keyed by the full type name of the boxed node, such as
+    /// `sqlparser::ast::Expr`. See [`AstArenaStats`](super::AstArenaStats).
+    ///
+    /// Boxes shared between a rewritten document and its parent allocate
+    /// nothing and are not counted.
+    ///
+    /// ```
+    /// # use sqlparser::{dialect::PostgreSqlDialect, ParsedSql};
+    /// let document = ParsedSql::parse(&PostgreSqlDialect {}, "SELECT (a + 1) * 2").unwrap();
+    /// let histogram = document.arena_stats().allocations_by_type;
+    /// assert!(histogram.get("sqlparser::ast::Expr").unwrap().allocations >= 2);
+    /// ```
+    #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    pub struct AstAllocationHistogram {
+        types: BTreeMap<&'static str, AstTypeAllocations>,
+    }
+
+    impl AstAllocationHistogram {
+        /// Counts for the boxed type named `type_name`.
+        pub fn get(&self, type_name: &str) -> Option<AstTypeAllocations> {
+            self.types.get(type_name).copied()
+        }
+
+        /// Counts per boxed type, ordered by type name.
+        pub fn iter(&self) -> impl Iterator<Item = (&'static str, AstTypeAllocations)> + '_ {
+            self.types.iter().map(|(name, counts)| (*name, *counts))
+        }
+
+        /// Counts summed over every type.
+        pub fn total(&self) -> AstTypeAllocations {
+            let mut total = AstTypeAllocations::default();
+            for counts in self.types.values() {
+                total.add(*counts);
+            }
+            total
+        }
+
+        /// Add the counts of `other` to this histogram.
+        pub fn merge(&mut self, other: &AstAllocationHistogram) {
+            for (name, counts) in other.iter() {
+                self.add(name, counts);
+            }
+        }
+
+        /// Count one arena box holding a `T`.
+        pub(super) fn record<T>(&mut self) {
+            self.add(
+                core::any::type_name::<T>(),
+                AstTypeAllocations {
+                    allocations: 1,
+                    bytes: core::mem::size_of::<AstBoxStorage<T>>() as u64,
+                },
+            );
+        }
+
+        fn add(&mut self, type_name: &'static str, counts: AstTypeAllocations) {
+            self.types.entry(type_name).or_default().add(counts);
+        }
+    }
+}
+
+#[cfg(feature = "ast-histogram")]
+pub use histogram::{AstAllocationHistogram, AstTypeAllocations};
+
+#[cfg(feature = "std")]
+mod document {
+    use super::*;
+    use crate::ast::Statement;
+    use crate::codec::{cached_fingerprint, AstCodec, AstCodecError, AstDecoder, AstEncoder};
+    use crate::dialect::Dialect;
+    use crate::optimizer_hints::{parse_optimizer_hints_with_limits, OptimizerHint};
+    use crate::parser::{
+        Parser, ParserError, ParserOptions, PartialStatements, RecoveredStatements, StatementError,
+    };
+    use crate::tokenizer::{Location, Span};
+    use core::any::TypeId;
+    use std::cell::{Cell, RefCell};
+    use std::collections::{HashMap, HashSet};
+    use std::ops::Range;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+    /// A thread-safe pool of arena chunks shared by document parses.
+    ///
+    /// Every [`ParsedSql`] otherwise allocates its arena chunks from the
+    /// system allocator and frees them when it drops. Documents parsed with
+    /// a pool set through [`ParserOptions::with_chunk_pool`], and documents
+    /// derived from them, take standard-size chunks from the pool and return
+    /// them when they drop. Idle chunks beyond the pool's retention cap are
+    /// freed; chunks sized for a single large node are never pooled.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use sqlparser::{dialect::PostgreSqlDialect, parser::{ParserError, ParserOptions}};
+    /// # use sqlparser::{AstChunkPool, ParsedSql};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let pool = Arc::new(AstChunkPool::new(64 * 1024));
+    /// let options = ParserOptions::new().with_chunk_pool(Arc::clone(&pool));
+    /// for _ in 0..2 {
+    ///     ParsedSql::parse_with_options(&PostgreSqlDialect {}, "SELECT a + 1 FROM t", options.clone())?;
+    /// }
+    /// assert!(pool.stats().hits > 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub struct AstChunkPool {
+        chunks: Mutex<Vec<AstArenaChunk>>,
+        max_retained_bytes: usize,
+        hits: AtomicUsize,
+        misses: AtomicUsize,
+        discarded: AtomicUsize,
+    }
+
+    /// Counters of an [`AstChunkPool`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct AstChunkPoolStats {
+        /// Chunk requests served with an idle pooled chunk.
+        pub hits: usize,
+        /// Chunk requests that found the pool empty and allocated.
+        pub misses: usize,
+        /// Chunks freed on return because the pool was at its retention cap.
+        pub discarded: usize,
+        /// Number of idle chunks held by the pool.
+        pub retained_chunks: usize,
+        /// Bytes of idle chunks held by the pool.
+        pub retained_bytes: usize,
+        /// The pool's retention cap in bytes.
+        pub max_retained_bytes: usize,
+    }
+
+    impl AstChunkPool {
+        /// Create an empty pool that keeps at most `max_retained_bytes` of
+        /// idle chunks.
+        pub fn new(max_retained_bytes: usize) -> Self {
+            Self {
+                chunks: Mutex::new(Vec::new()),
+                max_retained_bytes,
+                hits: AtomicUsize::new(0),
+                misses: AtomicUsize::new(0),
+                discarded: AtomicUsize::new(0),
+            }
+        }
+
+        /// Hit and miss counters and the pool's current occupancy.
+        pub fn stats(&self) -> AstChunkPoolStats {
+            let retained_chunks = self.lock().len();
+            AstChunkPoolStats {
+                hits: self.hits.load(Ordering::Relaxed),
+                misses: self.misses.load(Ordering::Relaxed),
+                discarded: self.discarded.load(Ordering::Relaxed),
+                retained_chunks,
+                retained_bytes: retained_chunks * AstArenaChunk::standard_layout().size(),
+                max_retained_bytes: self.max_retained_bytes,
+            }
+        }
+
+        /// Free every idle chunk.
+        pub fn clear(&self) {
+            let chunks = core::mem::take(&mut *self.lock());
+            drop(chunks);
+        }
+
+        fn lock(&self) -> MutexGuard<'_, Vec<AstArenaChunk>> {
+            // A panic while the lock is held cannot leave the chunk list
+            // inconsistent, so a poisoned pool stays usable.
+            self.chunks.lock().unwrap_or_else(PoisonError::into_inner)
+        }
+
+        /// A chunk with `layout`, from the pool when it is a standard one.
+        fn take(&self, layout: Layout) -> AstArenaChunk {
+            if layout != AstArenaChunk::standard_layout() {
+                return AstArenaChunk::with_layout(layout);
+            }
+            match self.lock().pop() {
+                Some(chunk) => {
+                    self.hits.fetch_add(1, Ordering::Relaxed);
+                    chunk
+                }
+                None => {
+                    self.misses.fetch_add(1, Ordering::Relaxed);
+                    AstArenaChunk::with_layout(layout)
+                }
+            }
+        }
+
+        /// Take back the standard chunks of a dropped document, up to the
+        /// retention cap, and free the others.
+        fn recycle(&self, chunks: Vec<AstArenaChunk>) {
+            let standard = AstArenaChunk::standard_layout();
+            let capacity = self.max_retained_bytes / standard.size();
+            let mut freed = Vec::new();
+            let mut pooled = self.lock();
+            for mut chunk in chunks {
+                if chunk.layout == standard && pooled.len() < capacity {
+                    chunk.cursor = 0;
+                    pooled.push(chunk);
+                } else {
+                    if chunk.layout == standard {
+                        self.discarded.fetch_add(1, Ordering::Relaxed);
+                    }
+                    freed.push(chunk);
+                }
+            }
+            drop(pooled);
+        }
+    }
+
+    impl fmt::Debug for AstChunkPool {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("AstChunkPool")
+                .field("stats", &self.stats())
+                .finish()
+        }
+    }
+
+    /// Pools compare by identity, so that parser options holding the same
+    /// pool are equal.
+    impl PartialEq for AstChunkPool {
+        fn eq(&self, other: &Self) -> bool {
+            core::ptr::eq(self, other)
+        }
+    }
+
+    impl Eq for AstChunkPool {}
+
+    struct BuildingAstArena {
+        chunks: RefCell<Vec<AstArenaChunk>>,
+        committed_bytes: Cell<usize>,
+        requested_bytes: Cell<usize>,
+        node_allocations: Cell<usize>,
+        list_allocations: Cell<usize>,
+        list_bytes: Cell<usize>,
+        // The document's source text, which leaf text may borrow. The
+        // document retains the `Arc<str>` for as long as its statements.
+        source: NonNull<str>,
+        borrowed_leaves: Cell<usize>,
+        borrowed_leaf_bytes: Cell<usize>,
+        copied_leaves: Cell<usize>,
+        copied_leaf_bytes: Cell<usize>,
+        // Address ranges of the frozen chunks of the documents a rewrite is
+        // derived from, sorted by start. Nodes inside them are shared rather
+        // than copied when cloned during the build.
+        shared_chunks: Vec<Range<usize>>,
+        shared_subtrees: Cell<usize>,
+        // Where standard chunks come from and, once frozen, return to.
+        pool: Option<Arc<AstChunkPool>>,
+        // While parsing untrusted input, the most bytes the arena may
+        // commit. The parser checks each allocation against it first, see
+        // `check_budget`.
+        max_committed_bytes: Cell<Option<usize>>,
+        // Whether a value that owns heap allocations was placed in the
+        // arena, and whether a placed node was edited in place afterwards.
+        // Together with the statement roots they decide at freeze time
+        // whether the document must run destructors, without a tree walk
+        // unless a node was edited.
+        owns_heap: Cell<bool>,
+        edited_in_place: Cell<bool>,
+        #[cfg(feature = "ast-histogram")]
+        allocations_by_type: RefCell<AstAllocationHistogram>,
+    }
+
+    impl BuildingAstArena {
+        fn new(source: &Arc<str>) -> Self {
+            Self::with_shared_chunks(source, Vec::new())
+        }
+
+        /// A building arena for a rewrite of `parent`, which may share the
+        /// nodes of `parent` and of every document it was itself derived
+        /// from.
+        fn for_rewrite(parent: &ParsedSql) -> Self {
+            let mut shared_chunks = Vec::new();
+            let mut ancestor = Some(parent);
+            while let Some(document) = ancestor {
+                shared_chunks.extend(document.arena.chunk_ranges());
+                ancestor = document.parent.as_deref();
+            }
+            shared_chunks.sort_unstable_by_key(|range| range.start);
+            Self::with_shared_chunks(&parent.source, shared_chunks)
+                .with_pool(parent.arena.pool.clone())
+        }
+
+        fn with_shared_chunks(source: &Arc<str>, shared_chunks: Vec<Range<usize>>) -> Self {
+            Self {
+                chunks: RefCell::new(Vec::new()),
+                committed_bytes: Cell::new(0),
+                requested_bytes: Cell::new(0),
+                node_allocations: Cell::new(0),
+                list_allocations: Cell::new(0),
+                list_bytes: Cell::new(0),
+                source: NonNull::from(&**source),
+                borrowed_leaves: Cell::new(0),
+                borrowed_leaf_bytes: Cell::new(0),
+                copied_leaves: Cell::new(0),
+                copied_leaf_bytes: Cell::new(0),
+                shared_chunks,
+                shared_subtrees: Cell::new(0),
+                pool: None,
+                max_committed_bytes: Cell::new(None),
+                owns_heap: Cell::new(false),
+                edited_in_place: Cell::new(false),
+                #[cfg(feature = "ast-histogram")]
+                allocations_by_type: RefCell::default(),
+            }
+        }
+
+        fn with_pool(mut self, pool: Option<Arc<AstChunkPool>>) -> Self {
+            self.pool = pool;
+            self
+        }
+
+        /// A building arena for parsing SQL with `options`.
+        fn for_parse(source: &Arc<str>, options: &ParserOptions) -> Self {
+            let arena = Self::new(source).with_pool(options.chunk_pool.clone());
+            arena
+                .max_committed_bytes
+                .set(options.limits.max_arena_bytes);
+            arena
+        }
+
+        /// End the parse. Edits made afterwards are not limited by the
+        /// arena's byte budget.
+        fn finish_parse(&self) {
+            self.max_committed_bytes.set(None);
+        }
+
+        fn shares(&self, address: usize) -> bool {
+            let index = self
+                .shared_chunks
+                .partition_point(|range| range.start <= address);
+            index > 0 && self.shared_chunks[index - 1].contains(&address)
+        }
+
+        fn source(&self) -> &str {
+            // SAFETY: The arena is built while its document's source is
+            // alive, and the source is never mutated.
+            unsafe { self.source.as_ref() }
+        }
+
+        fn borrow_leaf(&self, text: &str) -> NonNull<u8> {
+            self.borrowed_leaves
+                .set(self.borrowed_leaves.get().saturating_add(1));
+            self.borrowed_leaf_bytes
+                .set(self.borrowed_leaf_bytes.get().saturating_add(text.len()));
+            NonNull::from(text).cast()
+        }
+
+        fn alloc<T>(&self, value: T) -> NonNull<T> {
+            let ptr = self.alloc_layout(Layout::new::<T>()).cast::<T>();
+            // SAFETY: The slot is valid, aligned for T, and uninitialized.
+            unsafe { ptr.as_ptr().write(value) };
+            ptr
+        }
+
+        fn alloc_layout(&self, value: Layout) -> NonNull<u8> {
+            bump_alloc(&mut self.chunks.borrow_mut(), value, |layout| {
+                let committed = self
+                    .committed_bytes
+                    .get()
+                    .checked_add(layout.size())
+                    .expect("AST arena committed byte count overflow");
+                self.committed_bytes.set(committed);
+                match &self.pool {
+                    Some(pool) => pool.take(layout),
+                    None => AstArenaChunk::with_layout(layout),
+                }
+            })
+        }
+
+        /// Check that `value` fits the remaining byte budget of the parse,
+        /// before it is allocated.
+        fn check_budget(&self, value: Layout) -> Result<(), ParserError> {
+            check_budget(
+                &self.chunks.borrow(),
+                self.committed_bytes.get(),
+                self.max_committed_bytes.get(),
+                value,
+            )
+        }
+
+        /// Freeze the arena that holds `statements`, proving whether they
+        /// can be released without running their destructors.
+        ///
+        /// Every boxed node was checked when it was placed, so only the
+        /// statement roots remain to be checked, unless a placed node was
+        /// edited in place since and the whole tree has to be walked.
+        fn freeze(self, statements: &[Statement]) -> FrozenAstArena {
+            let destructor_free = !self.owns_heap.get()
+                && if self.edited_in_place.get() {
+                    statements.iter().all(ArenaResident::is_arena_resident)
+                } else {
+                    statements.iter().all(resident_when_placed)
+                };
+            FrozenAstArena {
+                chunks: self.chunks.into_inner(),
+                committed_bytes: self.committed_bytes.get(),
+                requested_bytes: self.requested_bytes.get(),
+                node_allocations: self.node_allocations.get(),
+                list_allocations: self.list_allocations.get(),
+                list_bytes: self.list_bytes.get(),
+                borrowed_leaves: self.borrowed_leaves.get(),
+                borrowed_leaf_bytes: self.borrowed_leaf_bytes.get(),
+                copied_leaves: self.copied_leaves.get(),
+                copied_leaf_bytes: self.copied_leaf_bytes.get(),
+                shared_subtrees: self.shared_subtrees.get(),
+                destructor_free,
+                #[cfg(feature = "ast-histogram")]
+                allocations_by_type: self.allocations_by_type.into_inner(),
+                pool: self.pool,
+            }
+        }
+    }
+
+    thread_local! {
+        static ACTIVE_ARENA: Cell<*const BuildingAstArena> =
+            const { Cell::new(core::ptr::null()) };
+        static PLACING_IN_ARENA: Cell<bool> = const { Cell::new(false) };
+        static EDITS: RefCell<Vec<EditOrigins>> = const { RefCell::new(Vec::new()) };
+        static EDITING: Cell<bool> = const { Cell::new(false) };
+    }
+
+    /// The step an [`EditSession::edit_statement`] call is at.
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum EditStep {
+        /// Copying the statement out of the document.
+        CopyingOut,
+        /// Running the caller's edit on the copy.
+        Editing,
+        /// Copying the edited statement back into the document and dropping
+        /// the statement it replaces.
+        CopyingBack,
+    }
+
+    /// Where the nodes of a statement being edited through
+    /// [`EditSession::edit_statement`] came from.
+    ///
+    /// The caller edits a heap copy, so nothing it moves out can point into
+    /// the document. `origins` maps every node of the copy, by address, to
+    /// the tagged pointer of the document node it was copied from, for as
+    /// long as the copy is known to equal it: mutable access to a node or
+    /// freeing it forgets the node. Copying the statement back reuses the
+    /// original of every node still known, so only edited paths are copied.
+    struct EditOrigins {
+        step: EditStep,
+        origins: HashMap<usize, NonNull<u8>>,
+        /// Nodes of the replaced statement that the edited statement took
+        /// over, which dropping the replaced statement must leave alone.
+        adopted: HashSet<usize>,
+    }
+
+    /// An [`EditOrigins`] entry on the `EDITS` stack, popped on drop so a
+    /// panicking edit leaves no state behind.
+    struct EditScope(());
+
+    impl EditScope {
+        fn enter() -> Self {
+            EDITS.with(|edits| {
+                edits.borrow_mut().push(EditOrigins {
+                    step: EditStep::CopyingOut,
+                    origins: HashMap::new(),
+                    adopted: HashSet::new(),
+                })
+            });
+            EDITING.with(|editing| editing.set(true));
+            EditScope(())
+        }
+
+        fn step(&self, step: EditStep) {
+            with_current_edit(|edit| edit.step = step);
+        }
+    }
+
+    impl Drop for EditScope {
+        fn drop(&mut self) {
+            let edit = EDITS.with(|edits| {
+                let mut edits = edits.borrow_mut();
+                let edit = edits.pop();
+                EDITING.with(|editing| editing.set(!edits.is_empty()));
+                edit
+            });
+            drop(edit);
+        }
+    }
+
+    fn with_current_edit<T>(f: impl FnOnce(&mut EditOrigins) -> T) -> Option<T> {
+        if !EDITING.with(Cell::get) {
+            return None;
+        }
+        EDITS.with(|edits| edits.borrow_mut().last_mut().map(f))
+    }
+
+    /// Record that the heap node `copy` was copied from the document node
+    /// `origin` while a statement is copied out for editing.
+    pub(super) fn note_edit_copy(copy: NonNull<u8>, origin: NonNull<u8>) {
+        with_current_edit(|edit| {
+            if edit.step == EditStep::CopyingOut {
+                edit.origins.insert(copy.addr().get(), origin);
+            }
+        });
+    }
+
+    /// Forget where the heap node `node` came from, because it may be
+    /// changed or freed.
+    pub(super) fn note_edit_change(node: NonNull<u8>) {
+        if !EDITING.with(Cell::get) {
+            return;
+        }
+        EDITS.with(|edits| {
+            for edit in edits.borrow_mut().iter_mut() {
+                edit.origins.remove(&node.addr().get());
+            }
+        });
+    }
+
+    /// The document node that the unchanged heap node `node` was copied
+    /// from, while an edited statement is copied back into the document.
+    pub(super) fn take_edit_origin(node: NonNull<u8>) -> Option<NonNull<u8>> {
+        with_current_edit(|edit| {
+            if edit.step != EditStep::CopyingBack {
+                return None;
+            }
+            let origin = edit.origins.remove(&node.addr().get())?;
+            let address = origin.addr().get() & !(ARENA_TAG | SHARED_TAG);
+            let with_tag = |tag| {
+                // SAFETY: `address` is the non-zero address of an arena node.
+                origin.with_addr(unsafe { core::num::NonZeroUsize::new_unchecked(address | tag) })
+            };
+            // Below a shared node, nodes of an ancestor document carry that
+            // document's owning tag, so ownership is decided by address.
+            if active_arena_shares(with_tag(0)) {
+                Some(with_tag(ARENA_TAG | SHARED_TAG))
+            } else {
+                edit.adopted.insert(address);
+                Some(with_tag(ARENA_TAG))
+            }
+        })
+        .flatten()
+    }
+
+    /// Whether the document node `node` was taken over by an edited
+    /// statement, so dropping the statement it replaces must not drop it.
+    pub(super) fn adopted_by_edit(node: NonNull<u8>) -> bool {
+        with_current_edit(|edit| edit.adopted.contains(&node.addr().get())).unwrap_or(false)
+    }
+
+    /// Whether a value placed in the arena being built is being checked.
+    /// Arena boxes it holds were checked when they were placed themselves.
+    pub(super) fn placing_in_arena() -> bool {
+        PLACING_IN_ARENA.with(Cell::get)
+    }
+
+    /// Whether `value` owns no heap allocation, trusting the arena boxes it
+    /// holds to have been checked when they were placed.
+    fn resident_when_placed<T: ArenaResident>(value: &T) -> bool {
+        PLACING_IN_ARENA.with(|placing| {
+            let previous = placing.replace(true);
+            let resident = value.is_arena_resident();
+            placing.set(previous);
+            resident
+        })
+    }
+
+    /// Record whether a value just placed in the arena being built owns
+    /// heap allocations.
+    pub(super) fn note_arena_placement<T: ArenaResident>(value: &T) {
+        with_active_arena(|arena| {
+            if !arena.owns_heap.get() && !resident_when_placed(value) {
+                arena.owns_heap.set(true);
+            }
+            Some(())
+        });
+    }
+
+    /// Record that a node placed in the arena being built may have been
+    /// changed since it was checked.
+    pub(super) fn note_arena_edit() {
+        with_active_arena(|arena| {
+            arena.edited_in_place.set(true);
+            Some(())
+        });
+    }
+
+    pub(super) fn active_arena_alloc<T>(value: T) -> Result<NonNull<T>, T> {
+        ACTIVE_ARENA.with(|active| {
+            let arena = active.get();
+            if arena.is_null() {
+                Err(value)
+            } else {
+                // SAFETY: ACTIVE_ARENA is installed by `with_arena` for the
+                // dynamic extent of parsing. The building arena outlives every
+                // allocation returned during that scope.
+                let arena = unsafe { &*arena };
+                let ptr = arena.alloc(value);
+                arena.requested_bytes.set(
+                    arena
+                        .requested_bytes
+                        .get()
+                        .saturating_add(core::mem::size_of::<T>()),
+                );
+                arena
+                    .node_allocations
+                    .set(arena.node_allocations.get().saturating_add(1));
+                Ok(ptr)
+            }
+        })
+    }
+
+    /// Uninitialized arena storage for `len` list elements, or `None` when no
+    /// document is being built or the list needs no storage.
+    pub(super) fn active_arena_alloc_slice<T>(len: usize) -> Option<NonNull<T>> {
+        if len == 0 || core::mem::size_of::<T>() == 0 {
+            return None;
+        }
+        with_active_arena(|arena| {
+            let layout = Layout::array::<T>(len).ok()?;
+            let ptr = arena.alloc_layout(layout);
+            arena
+                .requested_bytes
+                .set(arena.requested_bytes.get().saturating_add(layout.size()));
+            arena
+                .list_allocations
+                .set(arena.list_allocations.get().saturating_add(1));
+            arena
+                .list_bytes
+                .set(arena.list_bytes.get().saturating_add(layout.size()));
+            Some(ptr.cast())
+        })
+    }
+
+    /// Whether a node at `ptr` belongs to a document that the document being
+    /// built is derived from, so that cloning it may share it. Counts the
+    /// shared subtree.
+    pub(super) fn active_arena_shares(ptr: NonNull<u8>) -> bool {
+        with_active_arena(|arena| {
+            arena.shares(ptr.addr().get()).then(|| {
+                arena
+                    .shared_subtrees
+                    .set(arena.shared_subtrees.get().saturating_add(1));
+            })
+        })
+        .is_some()
+    }
+
+    /// Check that `value` fits the remaining byte budget of the arena being
+    /// built, if any.
+    pub(super) fn active_arena_check_budget(value: Layout) -> Result<(), ParserError> {
+        with_active_arena(|arena| arena.check_budget(value).err()).map_or(Ok(()), Err)
+    }
+
+    fn with_active_arena<T>(f: impl FnOnce(&BuildingAstArena) -> Option<T>) -> Option<T> {
+        ACTIVE_ARENA.with(|active| {
+            let arena = active.get();
+            // SAFETY: See `active_arena_alloc`.
+            (!arena.is_null()).then(|| f(unsafe { &*arena })).flatten()
+        })
+    }
+
+    /// Source-backed storage for `text` when it is a non-empty slice of the
+    /// source of the document being built.
+    pub(super) fn active_source_slice(text: &str) -> Option<NonNull<u8>> {
+        if text.is_empty() {
+            return None;
+        }
+        with_active_arena(|arena| {
+            let source = arena.source().as_bytes().as_ptr_range();
+            let text_range = text.as_bytes().as_ptr_range();
+            (source.start <= text_range.start && text_range.end <= source.end)
+                .then(|| arena.borrow_leaf(text))
+        })
+    }
+
+    /// Source-backed storage for `text` when the source of the document
+    /// being built holds exactly `text` at byte `offset`.
+    #[cfg_attr(feature = "bigdecimal", allow(dead_code))]
+    pub(super) fn active_source_text_at(offset: usize, text: &str) -> Option<NonNull<u8>> {
+        if text.is_empty() {
+            return None;
+        }
+        with_active_arena(|arena| {
+            let end = offset.checked_add(text.len())?;
+            let slice = arena.source().get(offset..end)?;
+            (slice == text).then(|| arena.borrow_leaf(slice))
+        })
+    }
+
+    /// Count an arena box holding a `T` by its node type.
+    #[cfg(feature = "ast-histogram")]
+    pub(super) fn note_arena_box<T>() {
+        with_active_arena(|arena| {
+            arena.allocations_by_type.borrow_mut().record::<T>();
+            Some(())
+        });
+    }
+
+    /// Count leaf text copied into its own allocation during a build.
+    pub(super) fn note_owned_leaf(text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        with_active_arena(|arena| {
+            arena
+                .copied_leaves
+                .set(arena.copied_leaves.get().saturating_add(1));
+            arena
+                .copied_leaf_bytes
+                .set(arena.copied_leaf_bytes.get().saturating_add(text.len()));
+            Some(())
+        });
+    }
+
+    pub(super) fn without_active_arena<T>(f: impl FnOnce() -> T) -> T {
+        ACTIVE_ARENA.with(|active| {
+            let previous = active.replace(core::ptr::null());
+            struct Restore<'a> {
+                active: &'a Cell<*const BuildingAstArena>,
+                previous: *const BuildingAstArena,
+            }
+            impl Drop for Restore<'_> {
+                fn drop(&mut self) {
+                    self.active.set(self.previous);
+                }
+            }
+            let restore = Restore { active, previous };
+            let result = f();
+            drop(restore);
+            result
+        })
+    }
+
+    fn with_arena<T>(arena: &BuildingAstArena, f: impl FnOnce() -> T) -> T {
+        ACTIVE_ARENA.with(|active| {
+            let previous = active.replace(arena as *const BuildingAstArena);
+            struct Restore<'a> {
+                active: &'a Cell<*const BuildingAstArena>,
+                previous: *const BuildingAstArena,
+            }
+            impl Drop for Restore<'_> {
+                fn drop(&mut self) {
+                    self.active.set(self.previous);
+                }
+            }
+            let restore = Restore { active, previous };
+            let result = f();
+            drop(restore);
+            result
+        })
+    }
+
+    struct FrozenAstArena {
+        chunks: Vec<AstArenaChunk>,
+        committed_bytes: usize,
+        requested_bytes: usize,
+        node_allocations: usize,
+        list_allocations: usize,
+        list_bytes: usize,
+        borrowed_leaves: usize,
+        borrowed_leaf_bytes: usize,
+        copied_leaves: usize,
+        copied_leaf_bytes: usize,
+        shared_subtrees: usize,
+        destructor_free: bool,
+        #[cfg(feature = "ast-histogram")]
+        allocations_by_type: AstAllocationHistogram,
+        pool: Option<Arc<AstChunkPool>>,
+    }
+
+    impl FrozenAstArena {
+        fn chunk_ranges(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+            self.chunks.iter().map(|chunk| {
+                let start = chunk.ptr.addr().get();
+                start..start + chunk.layout.size()
+            })
+        }
+
+        fn stats(&self) -> AstArenaStats {
+            AstArenaStats {
+                node_allocations: self.node_allocations,
+                requested_bytes: self.requested_bytes,
+                committed_bytes: self.committed_bytes,
+                slack_bytes: self.committed_bytes.saturating_sub(self.requested_bytes),
+                list_allocations: self.list_allocations,
+                list_bytes: self.list_bytes,
+                borrowed_leaves: self.borrowed_leaves,
+                borrowed_leaf_bytes: self.borrowed_leaf_bytes,
+                copied_leaves: self.copied_leaves,
+                copied_leaf_bytes: self.copied_leaf_bytes,
+                shared_subtrees: self.shared_subtrees,
+                shared_bytes: 0,
+                destructor_free: self.destructor_free,
+                #[cfg(feature = "ast-histogram")]
+                allocations_by_type: self.allocations_by_type.clone(),
+            }
+        }
+    }
+
+    impl Drop for FrozenAstArena {
+        fn drop(&mut self) {
+            if let Some(pool) = &self.pool {
+                pool.recycle(core::mem::take(&mut self.chunks));
+            }
+        }
+    }
+
+    // SAFETY: FrozenAstArena exposes no allocation or mutable access after
+    // construction. AstBox pointees are read-only through ParsedSql.
+    unsafe impl Sync for FrozenAstArena {}
+    // SAFETY: Frozen chunks are immutable and their allocations may be freed
+    // on any thread supported by the global allocator.
+    unsafe impl Send for FrozenAstArena {}
+
+    /// An owned SQL source and its frozen parsed statement batch.
+    pub struct ParsedSql {
+        // Statements must drop before the bump storage they reference. Struct
+        // fields are dropped in declaration order.
+        statements: Vec<Statement>,
+        statement_spans: Vec<Span>,
+        optimizer_hints: Vec<OptimizerHint>,
+        source: Arc<str>,
+        arena: FrozenAstArena,
+        // The document this one was rewritten from, whose arena holds the
+        // nodes shared by this document's statements.
+        parent: Option<Arc<ParsedSql>>,
+    }
+
+    impl ParsedSql {
+        /// Parse SQL into one shareable, arena-owned syntax document.
+        pub fn parse(
+            dialect: &dyn Dialect,
+            source: impl Into<Arc<str>>,
+        ) -> Result<Arc<Self>, ParserError> {
+            Self::parse_with_session(dialect, source, |_| ()).map(|(document, ())| document)
         }
 
-        /// Parse and mutate a document with explicit parser options before
-        /// freezing it.
+        /// Parse SQL with explicit parser options into one shareable,
+        /// arena-owned syntax document.
+        pub fn parse_with_options(
+            dialect: &dyn Dialect,
+            source: impl Into<Arc<str>>,
+            options: ParserOptions,
+        ) -> Result<Arc<Self>, ParserError> {
+            Self::parse_with_session_and_options(dialect, source, options, |_| ())
+                .map(|(document, ())| document)
+        }
+
+        /// Parse SQL in recovery mode into one shareable document holding
+        /// every statement that parsed, along with an error for each
+        /// statement that did not. See
+        /// [`Parser::parse_statements_with_recovery`].
         ///
-        /// # Safety
+        /// Only tokenizer errors abort the document.
+        pub fn parse_with_recovery(
+            dialect: &dyn Dialect,
+            source: impl Into<Arc<str>>,
+            options: ParserOptions,
+        ) -> Result<(Arc<Self>, Vec<StatementError>), ParserError> {
+            Self::parse_batch(dialect, source.into(), options, |sql, options| {
+                let RecoveredStatements {
+                    statements,
+                    spans,
+                    errors,
+                } = Parser::parse_sql_with_recovery(dialect, sql, options)?;
+                Ok((statements, spans, errors))
+            })
+        }
+
+        /// Parse SQL in tolerant mode into one shareable document holding
+        /// a partial AST for each statement that failed to parse, along with
+        /// the errors replaced by placeholders. See
+        /// [`Parser::parse_statements_tolerant`].
         ///
-        /// `edit` must obey the same ownership contract as
-        /// [`Self::parse_and_edit`].
-        pub unsafe fn parse_and_edit_with_options<R>(
+        /// Only tokenizer errors abort the document.
+        pub fn parse_tolerant(
             dialect: &dyn Dialect,
             source: impl Into<Arc<str>>,
             options: ParserOptions,
-            edit: impl FnOnce(&mut [Statement]) -> R,
+        ) -> Result<(Arc<Self>, Vec<StatementError>), ParserError> {
+            Self::parse_batch(dialect, source.into(), options, |sql, options| {
+                let PartialStatements {
+                    statements,
+                    spans,
+                    errors,
+                } = Parser::parse_sql_tolerant(dialect, sql, options)?;
+                Ok((statements, spans, errors))
+            })
+        }
+
+        /// Parse a batch into a document with `parse`, which reports the
+        /// errors of statements instead of failing.
+        fn parse_batch(
+            dialect: &dyn Dialect,
+            source: Arc<str>,
+            options: ParserOptions,
+            parse: impl FnOnce(
+                &str,
+                ParserOptions,
+            )
+                -> Result<(Vec<Statement>, Vec<Span>, Vec<StatementError>), ParserError>,
+        ) -> Result<(Arc<Self>, Vec<StatementError>), ParserError> {
+            let optimizer_hints =
+                parse_optimizer_hints_with_limits(dialect, &source, options.limits)?;
+            let arena = BuildingAstArena::for_parse(&source, &options);
+            let parsed = with_arena(&arena, || parse(&source, options));
+            arena.finish_parse();
+            let (statements, statement_spans, errors) = parsed?;
+            if let Some(StatementError {
+                error: error @ ParserError::LimitExceeded(_),
+                ..
+            }) = errors.last()
+            {
+                return Err(error.clone());
+            }
+            let arena = arena.freeze(&statements);
+            let document = Arc::new(Self {
+                statements,
+                statement_spans,
+                optimizer_hints,
+                source,
+                arena,
+                parent: None,
+            });
+            Ok((document, errors))
+        }
+
+        /// Parse a document and edit it through an [`EditSession`] before
+        /// freezing it into a shareable owner.
+        ///
+        /// The callback result can itself be a consumer-specific `Result`,
+        /// allowing callers to preserve their own error type without coupling
+        /// it to [`ParserError`].
+        pub fn parse_with_session<R>(
+            dialect: &dyn Dialect,
+            source: impl Into<Arc<str>>,
+            edit: impl for<'doc> FnOnce(&mut EditSession<'doc>) -> R,
+        ) -> Result<(Arc<Self>, R), ParserError> {
+            let options =
+                ParserOptions::new().with_trailing_commas(dialect.supports_trailing_commas());
+            Self::parse_with_session_and_options(dialect, source, options, edit)
+        }
+
+        /// Parse a document with explicit parser options and edit it through
+        /// an [`EditSession`] before freezing it.
+        pub fn parse_with_session_and_options<R>(
+            dialect: &dyn Dialect,
+            source: impl Into<Arc<str>>,
+            options: ParserOptions,
+            edit: impl for<'doc> FnOnce(&mut EditSession<'doc>) -> R,
         ) -> Result<(Arc<Self>, R), ParserError> {
             let source = source.into();
-            let optimizer_hints = parse_optimizer_hints(dialect, &source)?;
-            let arena = BuildingAstArena::new();
-            let (statements, statement_spans, edit_result) = with_arena(&arena, || {
-                let (mut statements, statement_spans) =
-                    Parser::parse_sql_with_spans_and_options(dialect, &source, options)?;
-                let edit_result = edit(&mut statements);
-                Ok::<_, ParserError>((statements, statement_spans, edit_result))
-            })?;
+            let optimizer_hints =
+                parse_optimizer_hints_with_limits(dialect, &source, options.limits)?;
+            let arena = BuildingAstArena::for_parse(&source, &options);
+            let parsed = with_arena(&arena, || {
+                Parser::parse_sql_with_spans_and_options(dialect, &source, options)
+            });
+            arena.finish_parse();
+            let (mut statements, statement_spans) = parsed?;
+            let edit_result = run_edit_session(&arena, &mut statements, edit);
+            let arena = arena.freeze(&statements);
             let document = Arc::new(Self {
                 statements,
                 statement_spans,
                 optimizer_hints,
                 source,
-                arena: arena.freeze(),
+                arena,
+                parent: None,
             });
             Ok((document, edit_result))
         }
 
-        /// Clone this document into a new arena, apply one edit session, and
-        /// freeze the result. The source buffer is shared between documents.
+        /// Parse and mutate a document while its arena is still in the
+        /// building state, then freeze it into a shareable owner.
+        ///
+        /// Recursive nodes created by `edit` are allocated in the same arena
+        /// as parser-created nodes. This is the in-place form of
+        /// [`Self::parse_with_session`], for callers that cannot afford the
+        /// session's copy-on-write edits.
+        /// # Safety
+        ///
+        /// `edit` must obey the contract of [`EditSession::edit_unchecked`]:
+        /// it must not move or copy an arena-backed AST node, or
+        /// source-backed [`AstStr`] leaf text, anywhere that can outlive the
+        /// returned document.
+        pub unsafe fn parse_and_edit<R>(
+            dialect: &dyn Dialect,
+            source: impl Into<Arc<str>>,
+            edit: impl FnOnce(&mut [Statement]) -> R,
+        ) -> Result<(Arc<Self>, R), ParserError> {
+            // SAFETY: The caller upholds the contract of `edit_unchecked`,
+            // which is the contract documented on this method.
+            Self::parse_with_session(dialect, source, |session| unsafe {
+                session.edit_unchecked(edit)
+            })
+        }
+
+        /// Parse and mutate a document with explicit parser options before
+        /// freezing it.
+        ///
         /// # Safety
         ///
-        /// `edit` must not let an arena-backed node escape independently of
-        /// the returned document. See [`Self::parse_and_edit`].
-        pub unsafe fn rewrite<R>(
-            &self,
+        /// `edit` must obey the same ownership contract as
+        /// [`Self::parse_and_edit`].
+        pub unsafe fn parse_and_edit_with_options<R>(
+            dialect: &dyn Dialect,
+            source: impl Into<Arc<str>>,
+            options: ParserOptions,
             edit: impl FnOnce(&mut [Statement]) -> R,
+        ) -> Result<(Arc<Self>, R), ParserError> {
+            // SAFETY: See `parse_and_edit`.
+            Self::parse_with_session_and_options(dialect, source, options, |session| unsafe {
+                session.edit_unchecked(edit)
+            })
+        }
+
+        /// Derive a new document from this one, apply one edit session, and
+        /// freeze the result.
+        ///
+        /// The new document shares the source buffer and, copy-on-write, the
+        /// recursive nodes of this document: it keeps this document alive,
+        /// and only the nodes on paths that are edited are copied into its
+        /// own arena. [`AstArenaStats::shared_bytes`] reports the memory kept
+        /// alive this way.
+        pub fn rewrite_with_session<R>(
+            self: &Arc<Self>,
+            edit: impl for<'doc> FnOnce(&mut EditSession<'doc>) -> R,
         ) -> (Arc<Self>, R) {
-            let arena = BuildingAstArena::new();
-            let (statements, edit_result) = with_arena(&arena, || {
-                let mut statements = self.statements.clone();
-                let edit_result = edit(&mut statements);
-                (statements, edit_result)
-            });
+            let arena = BuildingAstArena::for_rewrite(self);
+            let mut statements = with_arena(&arena, || self.statements.clone());
+            let edit_result = run_edit_session(&arena, &mut statements, edit);
+            let arena = arena.freeze(&statements);
             let document = Arc::new(Self {
                 statements,
                 statement_spans: self.statement_spans.clone(),
                 optimizer_hints: self.optimizer_hints.clone(),
                 source: Arc::clone(&self.source),
-                arena: arena.freeze(),
+                arena,
+                parent: Some(Arc::clone(self)),
             });
             (document, edit_result)
         }
 
+        /// Derive a new document from this one and mutate it in place before
+        /// freezing the result.
+        ///
+        /// Untouched subtrees stay shared with this document as in
+        /// [`Self::rewrite_with_session`], but nodes are copied one at a time
+        /// as `edit` reaches them through mutable references, so editing one
+        /// literal copies only the nodes above it.
+        /// # Safety
+        ///
+        /// `edit` must not let an arena-backed node escape independently of
+        /// the returned document. See [`Self::parse_and_edit`].
+        pub unsafe fn rewrite<R>(
+            self: &Arc<Self>,
+            edit: impl FnOnce(&mut [Statement]) -> R,
+        ) -> (Arc<Self>, R) {
+            // SAFETY: See `parse_and_edit`.
+            self.rewrite_with_session(|session| unsafe { session.edit_unchecked(edit) })
+        }
+
         /// Adopt an already-owned statement batch behind the same document
         /// boundary used by arena parses.
         ///
         /// Recursive nodes in `statements` retain their existing storage
         /// mode. This is primarily an interoperability bridge for callers
         /// that construct syntax programmatically; parsed input should use
-        /// [`Self::parse`] or [`Self::parse_and_edit`] so its recursive nodes
-        /// are arena-backed.
+        /// [`Self::parse`] or [`Self::parse_with_session`] so its recursive
+        /// nodes are arena-backed.
         pub fn from_statements(
             source: impl Into<Arc<str>>,
             statements: Vec<Statement>,
         ) -> Arc<Self> {
-            // SAFETY: The no-op callback cannot let syntax escape.
-            unsafe { Self::from_statements_and_edit(source, statements, |_| ()) }.0
+            Self::from_statements_with_session(source, statements, |_| ()).0
+        }
+
+        /// Adopt an already-owned statement batch and edit it through an
+        /// [`EditSession`] before freezing it behind a document owner.
+        ///
+        /// Existing recursive nodes preserve their current storage until the
+        /// session replaces their statement.
+        pub fn from_statements_with_session<R>(
+            source: impl Into<Arc<str>>,
+            statements: Vec<Statement>,
+            edit: impl for<'doc> FnOnce(&mut EditSession<'doc>) -> R,
+        ) -> (Arc<Self>, R) {
+            let statement_spans = vec![Span::empty(); statements.len()];
+            Self::adopt_statements(source.into(), statements, statement_spans, edit)
         }
 
         /// Adopt and mutate an already-owned statement batch before freezing
@@ -754,34 +2847,44 @@ mod document {
         /// the returned document. See [`Self::parse_and_edit`].
         pub unsafe fn from_statements_and_edit<R>(
             source: impl Into<Arc<str>>,
-            mut statements: Vec<Statement>,
+            statements: Vec<Statement>,
             edit: impl FnOnce(&mut [Statement]) -> R,
         ) -> (Arc<Self>, R) {
-            let source = source.into();
-            let arena = BuildingAstArena::new();
-            let statement_spans = vec![Span::empty(); statements.len()];
-            let edit_result = with_arena(&arena, || edit(&mut statements));
-            let document = Arc::new(Self {
-                statements,
-                statement_spans,
-                optimizer_hints: Vec::new(),
-                source,
-                arena: arena.freeze(),
-            });
-            (document, edit_result)
+            // SAFETY: See `parse_and_edit`.
+            Self::from_statements_with_session(source, statements, |session| unsafe {
+                session.edit_unchecked(edit)
+            })
         }
 
         /// Adopt statements together with their own source spans, so
-        /// [`Self::statement_source`] works on the resulting document.
+        /// [`Self::statement_source`] works on the resulting document, and
+        /// edit them through an [`EditSession`].
         ///
-        /// Unlike [`Self::from_statements_and_edit`] — whose adopted
+        /// Unlike [`Self::from_statements_with_session`] — whose adopted
         /// statements deliberately carry empty spans because nothing ties
         /// their spans to `source` — the caller here guarantees that
         /// `source` is the exact text the statements' spans index. For a
         /// nested statement extracted from a larger document (`PREPARE name
         /// AS <stmt>`), that is the larger document's full source. Spans are
         /// captured before `edit` runs, so `statement_source` reflects the
-        /// original text exactly as [`Self::parse_and_edit`] does.
+        /// original text exactly as [`Self::parse_with_session`] does.
+        pub fn from_spanned_statements_with_session<R>(
+            source: impl Into<Arc<str>>,
+            statements: Vec<Statement>,
+            edit: impl for<'doc> FnOnce(&mut EditSession<'doc>) -> R,
+        ) -> (Arc<Self>, R) {
+            use crate::ast::Spanned;
+
+            let statement_spans = statements
+                .iter()
+                .map(|statement| statement.span())
+                .collect();
+            Self::adopt_statements(source.into(), statements, statement_spans, edit)
+        }
+
+        /// Adopt statements together with their own source spans and mutate
+        /// them in place before freezing the document. See
+        /// [`Self::from_spanned_statements_with_session`].
         ///
         /// # Safety
         ///
@@ -789,24 +2892,31 @@ mod document {
         /// the returned document. See [`Self::parse_and_edit`].
         pub unsafe fn from_spanned_statements_and_edit<R>(
             source: impl Into<Arc<str>>,
-            mut statements: Vec<Statement>,
+            statements: Vec<Statement>,
             edit: impl FnOnce(&mut [Statement]) -> R,
         ) -> (Arc<Self>, R) {
-            use crate::ast::Spanned;
+            // SAFETY: See `parse_and_edit`.
+            Self::from_spanned_statements_with_session(source, statements, |session| unsafe {
+                session.edit_unchecked(edit)
+            })
+        }
 
-            let source = source.into();
-            let arena = BuildingAstArena::new();
-            let statement_spans = statements
-                .iter()
-                .map(|statement| statement.span())
-                .collect();
-            let edit_result = with_arena(&arena, || edit(&mut statements));
+        fn adopt_statements<R>(
+            source: Arc<str>,
+            mut statements: Vec<Statement>,
+            statement_spans: Vec<Span>,
+            edit: impl for<'doc> FnOnce(&mut EditSession<'doc>) -> R,
+        ) -> (Arc<Self>, R) {
+            let arena = BuildingAstArena::new(&source);
+            let edit_result = run_edit_session(&arena, &mut statements, edit);
+            let arena = arena.freeze(&statements);
             let document = Arc::new(Self {
                 statements,
                 statement_spans,
                 optimizer_hints: Vec::new(),
                 source,
-                arena: arena.freeze(),
+                arena,
+                parent: None,
             });
             (document, edit_result)
         }
@@ -830,15 +2940,12 @@ mod document {
         /// This lets consumers associate hints with nested statements without
         /// slicing and reparsing their SQL text.
         pub fn optimizer_hints_for_span(&self, span: Span) -> Vec<OptimizerHint> {
-            let Some(start) = source_location_to_offset(&self.source, span.start) else {
-                return Vec::new();
-            };
-            let Some(end) = source_location_to_offset(&self.source, span.end) else {
+            let Some(range) = span_to_byte_range(&self.source, span) else {
                 return Vec::new();
             };
             self.optimizer_hints
                 .iter()
-                .filter(|hint| hint.span.0 >= start && hint.span.1 <= end)
+                .filter(|hint| hint.span.0 >= range.start && hint.span.1 <= range.end)
                 .cloned()
                 .collect()
         }
@@ -863,15 +2970,100 @@ mod document {
         /// spans and spans that do not map cleanly into the retained source
         /// return `None`.
         pub fn source_for_span(&self, span: Span) -> Option<&str> {
-            let start = source_location_to_offset(&self.source, span.start)?;
-            let end = source_location_to_offset(&self.source, span.end)?;
-            let statement = self.source.get(start..end)?.trim();
+            let range = span_to_byte_range(&self.source, span)?;
+            let statement = self.source.get(range)?.trim();
             (!statement.is_empty()).then_some(statement)
         }
 
         /// Recursive-node arena statistics.
         pub fn arena_stats(&self) -> AstArenaStats {
-            self.arena.stats()
+            let mut stats = self.arena.stats();
+            let mut ancestor = self.parent.as_deref();
+            while let Some(document) = ancestor {
+                stats.shared_bytes = stats
+                    .shared_bytes
+                    .saturating_add(document.arena.committed_bytes);
+                ancestor = document.parent.as_deref();
+            }
+            stats
+        }
+
+        /// Bytes this document keeps allocated.
+        ///
+        /// This counts the document itself, its source, its statement roots,
+        /// spans and optimizer hints, the heap allocations of its statements
+        /// as measured by [`HeapSize`], and its committed arena chunks, which
+        /// hold its arena-backed syntax. A rewritten document also counts
+        /// everything retained by the documents it was derived from, whose
+        /// source it shares and counts once. The documents returned by
+        /// [`split_statements`](Self::split_statements) share one source,
+        /// which each of them counts.
+        pub fn retained_bytes(&self) -> usize {
+            let mut bytes = 0usize;
+            let mut counted_source: Option<&Arc<str>> = None;
+            let mut document = Some(self);
+            while let Some(current) = document {
+                if !counted_source.is_some_and(|source| Arc::ptr_eq(source, &current.source)) {
+                    bytes = bytes.saturating_add(current.source.len());
+                    counted_source = Some(&current.source);
+                }
+                let own_bytes = [
+                    core::mem::size_of::<Self>(),
+                    current.statements.heap_size().heap_bytes,
+                    current.statement_spans.capacity() * core::mem::size_of::<Span>(),
+                    current.optimizer_hints.heap_size().heap_bytes,
+                    current.arena.committed_bytes,
+                ];
+                bytes = own_bytes.into_iter().fold(bytes, usize::saturating_add);
+                document = current.parent.as_deref();
+            }
+            bytes
+        }
+
+        /// Encode the document with [`AstCodec`]: its source, statements,
+        /// statement spans and optimizer hints.
+        ///
+        /// The source is stored once, and leaf text borrowed from it is
+        /// stored as a byte range of it. A rewritten document is encoded as
+        /// a standalone one.
+        pub fn to_bytes(&self) -> Vec<u8> {
+            let mut encoder = AstEncoder::new();
+            encoder.write_header(document_fingerprint());
+            encoder.write_str(&self.source);
+            encoder.set_source(&self.source);
+            self.statements.encode(&mut encoder);
+            self.statement_spans.encode(&mut encoder);
+            self.optimizer_hints.encode(&mut encoder);
+            encoder.into_bytes()
+        }
+
+        /// Decode a document written by [`Self::to_bytes`].
+        ///
+        /// Statements are decoded directly into the arena of the new
+        /// document, and their leaf text borrows from its source where the
+        /// encoded document's did.
+        pub fn from_bytes(bytes: &[u8]) -> Result<Arc<Self>, AstCodecError> {
+            let mut decoder = AstDecoder::new(bytes);
+            decoder.read_header(document_fingerprint())?;
+            let source: Arc<str> = Arc::from(decoder.read_str()?);
+            let arena = BuildingAstArena::new(&source);
+            let (statements, statement_spans, optimizer_hints) = with_arena(&arena, || {
+                let mut decoder = AstDecoder::with_source(decoder.rest(), &source);
+                let statements = Vec::<Statement>::decode(&mut decoder)?;
+                let statement_spans = Vec::<Span>::decode(&mut decoder)?;
+                let optimizer_hints = Vec::<OptimizerHint>::decode(&mut decoder)?;
+                decoder.finish()?;
+                Ok((statements, statement_spans, optimizer_hints))
+            })?;
+            let arena = arena.freeze(&statements);
+            Ok(Arc::new(Self {
+                statements,
+                statement_spans,
+                optimizer_hints,
+                source,
+                arena,
+                parent: None,
+            }))
         }
 
         /// Create a cheap owned handle to one statement root.
@@ -881,6 +3073,64 @@ mod document {
                 index,
             })
         }
+
+        /// Partition the batch into one document per statement.
+        ///
+        /// Each statement is copied into an arena of its own, so a returned
+        /// document keeps only its statement's syntax alive and the others,
+        /// as well as this document, can be dropped independently. All of
+        /// them share this document's source: `statement_span(0)`,
+        /// `statement_source(0)`, and `optimizer_hints_for_span` answer as
+        /// they do here for the same statement. A returned document holds
+        /// the optimizer hints that lie inside its statement.
+        pub fn split_statements(&self) -> Vec<Arc<Self>> {
+            self.statements
+                .iter()
+                .zip(&self.statement_spans)
+                .map(|(statement, &span)| {
+                    let arena =
+                        BuildingAstArena::new(&self.source).with_pool(self.arena.pool.clone());
+                    let statements = vec![with_arena(&arena, || statement.clone())];
+                    let arena = arena.freeze(&statements);
+                    Arc::new(Self {
+                        statements,
+                        statement_spans: vec![span],
+                        optimizer_hints: self.optimizer_hints_for_span(span),
+                        source: Arc::clone(&self.source),
+                        arena,
+                        parent: None,
+                    })
+                })
+                .collect()
+        }
+    }
+
+    // The shape of an encoded document, which `ParsedSql::to_bytes` writes
+    // field by field.
+    fn document_fingerprint() -> u64 {
+        cached_fingerprint(TypeId::of::<ParsedSql>(), |schema| {
+            if schema.begin_type("ParsedSql", true) {
+                schema.field("source");
+                String::describe(schema);
+                schema.field("statements");
+                Vec::<Statement>::describe(schema);
+                schema.field("statement_spans");
+                Vec::<Span>::describe(schema);
+                schema.field("optimizer_hints");
+                Vec::<OptimizerHint>::describe(schema);
+                schema.end_type();
+            }
+        })
+    }
+
+    /// Byte range of `span` in `source`. Spans built by hand rather than by
+    /// the tokenizer carry no byte offsets and are resolved by line/column.
+    fn span_to_byte_range(source: &str, span: Span) -> Option<Range<usize>> {
+        span.byte_range().or_else(|| {
+            let start = source_location_to_offset(source, span.start)?;
+            let end = source_location_to_offset(source, span.end)?;
+            Some(start..end)
+        })
     }
 
     fn source_location_to_offset(source: &str, location: Location) -> Option<usize> {
@@ -908,6 +3158,18 @@ mod document {
         (line.chars().count() == target_column).then_some(line_end)
     }
 
+    impl Drop for ParsedSql {
+        fn drop(&mut self) {
+            if self.arena.destructor_free {
+                // SAFETY: `freeze` proved that the statements own no heap
+                // allocation, so their destructors would release nothing and
+                // leaking them is sound. Their nodes are freed with the arena
+                // chunks, and the vector still frees its own buffer.
+                unsafe { self.statements.set_len(0) };
+            }
+        }
+    }
+
     impl Deref for ParsedSql {
         type Target = [Statement];
 
@@ -922,6 +3184,183 @@ mod document {
         }
     }
 
+    /// Edit access to a document whose arena is still being built.
+    ///
+    /// A session is lent to the callback of [`ParsedSql::parse_with_session`]
+    /// and its siblings. Its `'doc` lifetime is an invariant brand chosen
+    /// separately for every callback, so neither the session nor syntax
+    /// borrowed from it can leave the callback or be exchanged with the
+    /// session of another document.
+    ///
+    /// The safe methods never hand out an owned arena node or a mutable
+    /// reference into the arena. [`Self::edit_statement`] edits a heap copy
+    /// of a statement and copies the edited nodes into the arena, and code running
+    /// in the callback allocates from the heap, so nothing it creates or
+    /// clones can point into the document. [`Self::edit_unchecked`] edits in
+    /// place for callers that uphold the ownership contract themselves.
+    ///
+    /// ```
+    /// use sqlparser::ast::{Comments, Expr, Ident, SelectItem, SetExpr, Statement};
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    /// use sqlparser::ParsedSql;
+    ///
+    /// let (document, projected) =
+    ///     ParsedSql::parse_with_session(&PostgreSqlDialect {}, "SELECT a FROM t", |session| {
+    ///         session.edit_statement(0, |statement| {
+    ///             let Statement::Query(query) = statement else { unreachable!() };
+    ///             let SetExpr::Select(select) = query.body.as_mut() else { unreachable!() };
+    ///             select.projection.push(SelectItem::UnnamedExpr {
+    ///                 expr: Expr::Identifier(Ident::new("b").into()),
+    ///                 comments: Comments::default(),
+    ///             });
+    ///             select.projection.len()
+    ///         })
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(projected, 2);
+    /// assert_eq!(document.statements()[0].to_string(), "SELECT a, b FROM t");
+    /// ```
+    ///
+    /// The session cannot be kept after its callback returns:
+    ///
+    /// ```compile_fail
+    /// # use sqlparser::{dialect::PostgreSqlDialect, ParsedSql};
+    /// let mut kept = None;
+    /// let _ = ParsedSql::parse_with_session(&PostgreSqlDialect {}, "SELECT 1", |session| {
+    ///     kept = Some(session);
+    /// });
+    /// ```
+    ///
+    /// Arena nodes borrowed from the session cannot be returned:
+    ///
+    /// ```compile_fail
+    /// # use sqlparser::{ast::Statement, dialect::PostgreSqlDialect, ParsedSql};
+    /// let _ = ParsedSql::parse_with_session(&PostgreSqlDialect {}, "SELECT 1", |session| {
+    ///     match &session.statements()[0] {
+    ///         Statement::Query(query) => Some(query),
+    ///         _ => None,
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// A session cannot stand in for the session of another document, even
+    /// behind a shared reference. Only the brand rules this out: without
+    /// it, both references would shorten to a common lifetime.
+    ///
+    /// ```compile_fail,E0521
+    /// # use sqlparser::{dialect::PostgreSqlDialect, EditSession, ParsedSql};
+    /// let dialect = PostgreSqlDialect {};
+    /// let _ = ParsedSql::parse_with_session(&dialect, "SELECT 1", |outer| {
+    ///     let _ = ParsedSql::parse_with_session(&dialect, "SELECT 2", |inner| {
+    ///         let mut session: &EditSession<'_> = inner;
+    ///         session = outer;
+    ///         session.statements().len()
+    ///     });
+    /// });
+    /// ```
+    ///
+    /// And the sessions of two documents cannot be exchanged, which would
+    /// move each document's arena syntax into the other:
+    ///
+    /// ```compile_fail
+    /// # use sqlparser::{dialect::PostgreSqlDialect, ParsedSql};
+    /// let dialect = PostgreSqlDialect {};
+    /// let _ = ParsedSql::parse_with_session(&dialect, "SELECT 1", |outer| {
+    ///     let _ = ParsedSql::parse_with_session(&dialect, "SELECT 2", |inner| {
+    ///         std::mem::swap(outer, inner);
+    ///     });
+    /// });
+    /// ```
+    pub struct EditSession<'doc> {
+        statements: &'doc mut Vec<Statement>,
+        arena: &'doc BuildingAstArena,
+        brand: PhantomData<fn(&'doc ()) -> &'doc ()>,
+    }
+
+    fn run_edit_session<R>(
+        arena: &BuildingAstArena,
+        statements: &mut Vec<Statement>,
+        edit: impl for<'doc> FnOnce(&mut EditSession<'doc>) -> R,
+    ) -> R {
+        let mut session = EditSession {
+            statements,
+            arena,
+            brand: PhantomData,
+        };
+        // Only session methods allocate from the document arena. This also
+        // suspends the arena of an enclosing document build.
+        without_active_arena(|| edit(&mut session))
+    }
+
+    impl EditSession<'_> {
+        /// The statements being edited.
+        pub fn statements(&self) -> &[Statement] {
+            self.statements
+        }
+
+        /// The source text of the document.
+        pub fn source(&self) -> &str {
+            self.arena.source()
+        }
+
+        /// Replace the statement at `index`, copying `statement` into the
+        /// document arena.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `index` is out of bounds.
+        pub fn replace_statement(&mut self, index: usize, statement: Statement) {
+            let slot = &mut self.statements[index];
+            *slot = with_arena(self.arena, || statement.clone());
+        }
+
+        /// Edit a heap copy of the statement at `index` and copy the result
+        /// back into the document arena.
+        ///
+        /// Edits are copy-on-write: only nodes the edit reached through
+        /// `&mut` are copied back into the arena. Every other subtree of the
+        /// result is the document node it was copied from, still shared with
+        /// the parent document after [`ParsedSql::rewrite_with_session`].
+        /// Since the edit only sees the heap copy, nothing it moves out of
+        /// the statement can outlive the document.
+        ///
+        /// The storage of the replaced nodes is reclaimed with the arena.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `index` is out of bounds.
+        pub fn edit_statement<R>(
+            &mut self,
+            index: usize,
+            edit: impl FnOnce(&mut Statement) -> R,
+        ) -> R {
+            let slot = &mut self.statements[index];
+            let scope = EditScope::enter();
+            let mut statement = slot.clone();
+            scope.step(EditStep::Editing);
+            let result = edit(&mut statement);
+            scope.step(EditStep::CopyingBack);
+            let edited = with_arena(self.arena, || statement.clone());
+            // Nodes `edited` took over are skipped while the scope is open.
+            drop(core::mem::replace(slot, edited));
+            drop(scope);
+            result
+        }
+
+        /// Edit the statements in place, allocating new syntax from the
+        /// document arena.
+        ///
+        /// # Safety
+        ///
+        /// `edit` must not move or copy an arena-backed AST node, or
+        /// source-backed [`AstStr`] leaf text, anywhere that can outlive the
+        /// document. Any callback result containing syntax must remain owned
+        /// by, and be destroyed before, that document.
+        pub unsafe fn edit_unchecked<R>(&mut self, edit: impl FnOnce(&mut [Statement]) -> R) -> R {
+            with_arena(self.arena, || edit(self.statements))
+        }
+    }
+
     /// A shareable document-and-index handle to one statement root.
     #[derive(Clone)]
     pub struct StatementHandle {
@@ -1004,15 +3443,170 @@ mod document {
         use crate::dialect::PostgreSqlDialect;
 
         #[test]
-        fn ast_box_is_pointer_sized_for_all_payload_alignments() {
+        fn ast_box_is_pointer_sized_for_all_payload_alignments() {
+            assert_eq!(
+                core::mem::size_of::<AstBox<Statement>>(),
+                core::mem::size_of::<usize>()
+            );
+            assert_eq!(
+                core::mem::size_of::<AstBox<u8>>(),
+                core::mem::size_of::<usize>()
+            );
+        }
+
+        #[test]
+        fn ast_str_is_string_sized() {
+            assert_eq!(
+                core::mem::size_of::<AstStr>(),
+                core::mem::size_of::<String>()
+            );
+        }
+
+        #[test]
+        fn ast_vec_is_vec_sized() {
+            assert_eq!(
+                core::mem::size_of::<AstVec<Statement>>(),
+                core::mem::size_of::<Vec<Statement>>()
+            );
+            assert_eq!(
+                core::mem::size_of::<Option<AstVec<u8>>>(),
+                core::mem::size_of::<Option<Vec<u8>>>()
+            );
+        }
+
+        #[test]
+        fn heap_ast_vec_matches_vec() {
+            let mut list = AstVec::from(vec![1, 2, 3]);
+            let mut expected = vec![1, 2, 3];
+            assert!(!list.is_arena_allocated());
+
+            list.push(4);
+            expected.push(4);
+            list.insert(0, 0);
+            expected.insert(0, 0);
+            assert_eq!(list.remove(2), expected.remove(2));
+            list.retain(|value| value % 2 == 0);
+            expected.retain(|value| value % 2 == 0);
+            list.extend([6, 8]);
+            expected.extend([6, 8]);
+            assert_eq!(list.pop(), expected.pop());
+            list.truncate(3);
+            expected.truncate(3);
+            assert_eq!(list, expected);
+            assert_eq!(list.into_vec(), expected);
+
+            let units = AstVec::from(vec![(), ()]);
+            assert!(!units.is_arena_allocated());
+            assert_eq!(units.len(), 2);
+        }
+
+        fn projection_leaves(statement: &Statement) -> Vec<(&str, bool)> {
+            use crate::ast::{Expr, SelectItem, SetExpr, Value};
+
+            let Statement::Query(query) = statement else {
+                panic!("expected query")
+            };
+            let SetExpr::Select(select) = query.body.as_ref() else {
+                panic!("expected select")
+            };
+            select
+                .projection
+                .iter()
+                .map(|item| match item {
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::Identifier(ident),
+                        ..
+                    } => &ident.value,
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::Value(value),
+                        ..
+                    } => match &value.value {
+                        Value::SingleQuotedString(text) => text,
+                        _ => panic!("unexpected projection item {item}"),
+                    },
+                    _ => panic!("unexpected projection item {item}"),
+                })
+                .map(|leaf| (leaf.as_str(), leaf.is_source_backed()))
+                .collect()
+        }
+
+        #[test]
+        fn document_leaf_text_borrows_source_unless_it_changed() {
+            let dialect = PostgreSqlDialect {};
+            let sql = "SELECT plain, Folded, \"Quoted\", 'text', 'it''s' FROM t";
+            let document = ParsedSql::parse(&dialect, sql).unwrap();
             assert_eq!(
-                core::mem::size_of::<AstBox<Statement>>(),
-                core::mem::size_of::<usize>()
+                projection_leaves(&document.statements()[0]),
+                [
+                    ("plain", true),
+                    ("folded", false),
+                    ("Quoted", true),
+                    ("text", true),
+                    ("it's", false),
+                ]
             );
+            let stats = document.arena_stats();
+            assert!(stats.borrowed_leaves >= 4);
+            assert!(stats.copied_leaves >= 2);
+
+            let owned = Parser::parse_sql(&dialect, sql).unwrap();
+            assert_eq!(document.statements(), owned.as_slice());
+            assert!(projection_leaves(&owned[0])
+                .iter()
+                .all(|(_, source_backed)| !source_backed));
+        }
+
+        #[cfg(not(feature = "bigdecimal"))]
+        #[test]
+        fn document_number_literals_borrow_source() {
+            use crate::ast::{Expr, SelectItem, SetExpr, Value};
+
+            let document = ParsedSql::parse(&PostgreSqlDialect {}, "SELECT 1_000, 42L").unwrap();
+            let Statement::Query(query) = &document.statements()[0] else {
+                panic!("expected query")
+            };
+            let SetExpr::Select(select) = query.body.as_ref() else {
+                panic!("expected select")
+            };
+            let numbers = select
+                .projection
+                .iter()
+                .map(|item| match item {
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::Value(value),
+                        ..
+                    } => match &value.value {
+                        Value::Number(number, _) => (number.as_str(), number.is_source_backed()),
+                        _ => panic!("unexpected projection item {item}"),
+                    },
+                    _ => panic!("unexpected projection item {item}"),
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(numbers, [("1_000", true), ("42", true)]);
+        }
+
+        #[test]
+        fn detached_leaf_text_outlives_its_document() {
+            let document =
+                ParsedSql::parse(&PostgreSqlDialect {}, "SELECT name FROM users").unwrap();
+            let Statement::Query(query) = &document.statements()[0] else {
+                panic!("expected query")
+            };
+            let detached = AstBox::into_owned(query.clone());
+            let cloned = document.statements()[0].clone();
+            // SAFETY: The callback does not move syntax out of the document.
+            let (rewritten, ()) = unsafe { document.rewrite(|_| ()) };
+            drop(document);
+
+            assert_eq!(projection_leaves(&cloned), [("name", false)]);
+            assert_eq!(detached.to_string(), "SELECT name FROM users");
+            assert_eq!(cloned.to_string(), "SELECT name FROM users");
+            // A rewrite shares the source, so its leaves keep borrowing it.
             assert_eq!(
-                core::mem::size_of::<AstBox<u8>>(),
-                core::mem::size_of::<usize>()
+                projection_leaves(&rewritten.statements()[0]),
+                [("name", true)]
             );
+            assert_eq!(rewritten.arena_stats().copied_leaves, 0);
         }
 
         #[test]
@@ -1061,6 +3655,78 @@ mod document {
             assert_eq!(cloned.to_string(), "SELECT (1 + 2)");
         }
 
+        fn select(statement: &Statement) -> &crate::ast::Select {
+            let Statement::Query(query) = statement else {
+                panic!("expected query")
+            };
+            let crate::ast::SetExpr::Select(select) = query.body.as_ref() else {
+                panic!("expected select")
+            };
+            select
+        }
+
+        #[test]
+        fn document_lists_use_the_arena() {
+            let dialect = PostgreSqlDialect {};
+            let sql = "SELECT a, s.b FROM t JOIN s.u AS u ON t.id = u.id WHERE c IN (1, 2)";
+            let document = ParsedSql::parse(&dialect, sql).unwrap();
+            let owned = Parser::parse_sql(&dialect, sql).unwrap();
+            assert_eq!(document.statements(), owned.as_slice());
+
+            let document_select = select(&document.statements()[0]);
+            assert!(document_select.projection.is_arena_allocated());
+            assert!(document_select.from.is_arena_allocated());
+            assert!(document_select.from[0].joins.is_arena_allocated());
+            assert_eq!(
+                document_select.projection.capacity(),
+                document_select.projection.len()
+            );
+            let stats = document.arena_stats();
+            assert!(stats.list_allocations >= 5);
+            assert!(stats.list_bytes > 0 && stats.list_bytes < stats.requested_bytes);
+
+            let owned_select = select(&owned[0]);
+            assert!(!owned_select.projection.is_arena_allocated());
+            assert!(!owned_select.from[0].joins.is_arena_allocated());
+        }
+
+        #[test]
+        fn arena_lists_grow_in_the_arena_and_detach_to_the_heap() {
+            let dialect = PostgreSqlDialect {};
+            // SAFETY: The callback keeps the edited list inside the document
+            // and drops the scratch lists it creates.
+            let (document, ()) = unsafe {
+                ParsedSql::parse_and_edit(&dialect, "SELECT a FROM t", |statements| {
+                    let Statement::Query(query) = &mut statements[0] else {
+                        panic!("expected query")
+                    };
+                    let crate::ast::SetExpr::Select(select) = query.body.as_mut() else {
+                        panic!("expected select")
+                    };
+                    let projection = &mut select.projection;
+                    projection.push(crate::ast::SelectItem::Wildcard(Default::default()));
+                    assert!(projection.is_arena_allocated());
+                    assert_eq!(projection.len(), 2);
+
+                    let mut scratch = AstVec::from(vec![1_u32, 2]);
+                    assert!(scratch.is_arena_allocated());
+                    crate::arena::with_heap_ast_allocations(|| scratch.extend(3..10));
+                    assert!(!scratch.is_arena_allocated());
+                    assert_eq!(scratch, (1..10).collect::<Vec<_>>());
+                })
+            }
+            .unwrap();
+            assert_eq!(document.statements()[0].to_string(), "SELECT a, * FROM t");
+
+            let projection = &select(&document.statements()[0]).projection;
+            let cloned = projection.clone();
+            assert!(!cloned.is_arena_allocated());
+            let detached = cloned.clone().into_owned();
+            drop(document);
+            assert_eq!(cloned, detached);
+            assert_eq!(cloned[0].to_string(), "a");
+        }
+
         #[test]
         fn edit_and_rewrite_keep_recursive_nodes_in_the_document_arena() {
             let dialect = PostgreSqlDialect {};
@@ -1088,9 +3754,10 @@ mod document {
                     };
                     query.body = AstBox::new(crate::ast::SetExpr::Values(crate::ast::Values {
                         explicit_row: false,
-                        rows: vec![vec![crate::ast::Expr::value(crate::test_utils::number(
-                            "2",
-                        ))]],
+                        rows: vec![
+                            vec![crate::ast::Expr::value(crate::test_utils::number("2"))].into(),
+                        ]
+                        .into(),
                         value_keyword: false,
                     }));
                 })
@@ -1100,49 +3767,617 @@ mod document {
             };
             assert!(query.body.is_arena_allocated());
             assert_eq!(
-                rewritten.statements()[0].to_string(),
-                "VALUES (2) FOR UPDATE"
+                rewritten.statements()[0].to_string(),
+                "VALUES (2) FOR UPDATE"
+            );
+        }
+
+        #[test]
+        fn edit_session_copies_edits_into_the_document_arena() {
+            let dialect = PostgreSqlDialect {};
+            let (document, callback_box) =
+                ParsedSql::parse_with_session(&dialect, "SELECT 1; SELECT 2", |session| {
+                    assert_eq!(session.source(), "SELECT 1; SELECT 2");
+                    session.edit_statement(0, |statement| {
+                        let Statement::Query(query) = statement else {
+                            panic!("expected query")
+                        };
+                        assert!(!query.is_arena_allocated());
+                        query.locks.push(crate::ast::LockClause {
+                            lock_type: crate::ast::LockType::Update,
+                            of: None,
+                            nonblock: None,
+                        });
+                    });
+                    let replacement = Parser::parse_sql(&dialect, "SELECT 3").unwrap();
+                    session.replace_statement(1, replacement.into_iter().next().unwrap());
+                    AstBox::new(1_u8)
+                })
+                .unwrap();
+            assert!(!callback_box.is_arena_allocated());
+            assert_eq!(document.statements()[0].to_string(), "SELECT 1 FOR UPDATE");
+            assert_eq!(document.statements()[1].to_string(), "SELECT 3");
+            assert!(document
+                .statements()
+                .iter()
+                .all(|statement| matches!(statement, Statement::Query(query) if query.is_arena_allocated())));
+
+            let (rewritten, ()) = document.rewrite_with_session(|session| {
+                session.edit_statement(1, |statement| {
+                    *statement = Parser::parse_sql(&dialect, "SELECT 4").unwrap().remove(0);
+                })
+            });
+            let Statement::Query(query) = &rewritten.statements()[1] else {
+                panic!("expected query")
+            };
+            assert!(query.is_arena_allocated());
+            assert_eq!(query.to_string(), "SELECT 4");
+            assert_eq!(document.statements()[1].to_string(), "SELECT 3");
+        }
+
+        #[test]
+        fn edit_session_suspends_an_enclosing_document_arena() {
+            let dialect = PostgreSqlDialect {};
+            let statements = Parser::parse_sql(&dialect, "SELECT 1").unwrap();
+            // SAFETY: The callback lets no syntax escape the outer document.
+            let (_outer, ()) = unsafe {
+                ParsedSql::parse_and_edit(&dialect, "SELECT 0", |_| {
+                    let (adopted, ()) = ParsedSql::from_statements_with_session(
+                        "SELECT 1",
+                        statements,
+                        |session| {
+                            assert!(!AstBox::new(1_u8).is_arena_allocated());
+                            let statement = session.statements()[0].clone();
+                            session.replace_statement(0, statement);
+                        },
+                    );
+                    let Statement::Query(query) = &adopted.statements()[0] else {
+                        panic!("expected query")
+                    };
+                    assert!(query.is_arena_allocated());
+                    assert!(adopted.arena_stats().node_allocations > 0);
+                })
+            }
+            .unwrap();
+        }
+
+        #[test]
+        fn edit_statement_keeps_untouched_subtrees() {
+            use crate::ast::{Expr, SetExpr};
+
+            fn edit_right(statement: &mut Statement) {
+                let Statement::Query(query) = statement else {
+                    panic!("expected query")
+                };
+                let SetExpr::Select(select) = query.body.as_mut() else {
+                    panic!("expected select")
+                };
+                let Some(Expr::BinaryOp { right, .. }) = select.selection.as_deref_mut() else {
+                    panic!("expected comparison")
+                };
+                **right = Expr::value(crate::test_utils::number("3"));
+            }
+            fn comparison(statement: &Statement) -> (&AstBox<Expr>, &AstBox<Expr>) {
+                let Some(Expr::BinaryOp { left, right, .. }) =
+                    select(statement).selection.as_deref()
+                else {
+                    panic!("expected comparison")
+                };
+                (left, right)
+            }
+
+            let dialect = PostgreSqlDialect {};
+            let sql = "SELECT a FROM t WHERE 'a long string literal' = 1; SELECT c FROM u";
+            let document = ParsedSql::parse(&dialect, sql).unwrap();
+
+            // A safe edit of a rewrite copies only the path to the edit.
+            let (rewritten, ()) =
+                document.rewrite_with_session(|session| session.edit_statement(0, edit_right));
+            assert_eq!(
+                rewritten.statements()[0].to_string(),
+                "SELECT a FROM t WHERE 'a long string literal' = 3"
+            );
+            assert_eq!(
+                document.statements()[0].to_string(),
+                "SELECT a FROM t WHERE 'a long string literal' = 1"
+            );
+            let Statement::Query(edited) = &rewritten.statements()[0] else {
+                panic!("expected query")
+            };
+            assert!(edited.is_arena_allocated() && !edited.is_shared());
+            let (left, right) = comparison(&rewritten[0]);
+            assert!(left.is_shared());
+            assert!(right.is_arena_allocated() && !right.is_shared());
+            let Statement::Query(untouched) = &rewritten.statements()[1] else {
+                panic!("expected query")
+            };
+            assert!(untouched.is_shared());
+            assert!(rewritten.arena_stats().shared_subtrees >= 2);
+
+            // A safe edit of a new document keeps the untouched nodes it owns.
+            let mut left_before = 0;
+            let (edited, ()) = ParsedSql::parse_with_session(&dialect, sql, |session| {
+                left_before =
+                    core::ptr::from_ref::<Expr>(comparison(&session.statements()[0]).0).addr();
+                session.edit_statement(0, edit_right)
+            })
+            .unwrap();
+            let (left, _) = comparison(&edited[0]);
+            assert_eq!(core::ptr::from_ref::<Expr>(left).addr(), left_before);
+            assert!(left.is_arena_allocated() && !left.is_shared());
+            assert_eq!(
+                edited.statements()[0].to_string(),
+                "SELECT a FROM t WHERE 'a long string literal' = 3"
+            );
+            drop(document);
+            assert_eq!(
+                rewritten.statements()[0].to_string(),
+                "SELECT a FROM t WHERE 'a long string literal' = 3"
+            );
+        }
+
+        #[test]
+        fn rewrite_shares_untouched_subtrees_with_its_parent() {
+            use crate::ast::{Expr, SetExpr};
+
+            let dialect = PostgreSqlDialect {};
+            let document = ParsedSql::parse(
+                &dialect,
+                "SELECT a FROM t WHERE b = 1; SELECT c FROM u WHERE d = 2",
+            )
+            .unwrap();
+            // SAFETY: The callback only mutates nodes owned by the document.
+            let (rewritten, ()) = unsafe {
+                document.rewrite(|statements| {
+                    let Statement::Query(query) = &mut statements[0] else {
+                        panic!("expected query")
+                    };
+                    let SetExpr::Select(select) = query.body.as_mut() else {
+                        panic!("expected select")
+                    };
+                    let Some(Expr::BinaryOp { right, .. }) = select.selection.as_deref_mut() else {
+                        panic!("expected comparison")
+                    };
+                    **right = Expr::value(crate::test_utils::number("3"));
+                })
+            };
+            assert_eq!(
+                rewritten.statements()[0].to_string(),
+                "SELECT a FROM t WHERE b = 3"
+            );
+            assert_eq!(
+                document.statements()[0].to_string(),
+                "SELECT a FROM t WHERE b = 1"
+            );
+
+            // Only the path to the edited literal was copied.
+            let Statement::Query(edited) = &rewritten.statements()[0] else {
+                panic!("expected query")
+            };
+            assert!(edited.is_arena_allocated() && !edited.is_shared());
+            let Some(Expr::BinaryOp { left, right, .. }) =
+                select(&rewritten[0]).selection.as_deref()
+            else {
+                panic!("expected comparison")
+            };
+            assert!(left.is_shared());
+            assert!(!right.is_shared());
+            let Statement::Query(untouched) = &rewritten.statements()[1] else {
+                panic!("expected query")
+            };
+            assert!(untouched.is_shared());
+
+            let parent_bytes = document.arena_stats().committed_bytes;
+            let stats = rewritten.arena_stats();
+            assert!(stats.shared_subtrees >= 2);
+            assert_eq!(stats.shared_bytes, parent_bytes);
+            assert!(stats.committed_bytes < parent_bytes);
+            assert_eq!(document.arena_stats().shared_bytes, 0);
+
+            // The rewrite keeps the parent's arena alive, and a rewrite of the
+            // rewrite shares the nodes of both.
+            drop(document);
+            let (again, ()) = rewritten.rewrite_with_session(|_| ());
+            assert_eq!(
+                again.arena_stats().shared_bytes,
+                parent_bytes + stats.committed_bytes
+            );
+            assert_eq!(again.arena_stats().committed_bytes, 0);
+            drop(rewritten);
+            assert_eq!(
+                again.statements()[1].to_string(),
+                "SELECT c FROM u WHERE d = 2"
+            );
+        }
+
+        #[test]
+        fn heap_scratch_scope_restores_document_arena_after_panic() {
+            let dialect = PostgreSqlDialect {};
+            // SAFETY: The callback only inspects allocation modes and does not
+            // let any arena-backed syntax escape the returned document.
+            let (document, ()) = unsafe {
+                ParsedSql::parse_and_edit(&dialect, Arc::<str>::from("SELECT 1"), |_statements| {
+                    let before = AstBox::new(1_u8);
+                    assert!(before.is_arena_allocated());
+
+                    let scratch = crate::arena::with_heap_ast_allocations(|| AstBox::new(2_u8));
+                    assert!(!scratch.is_arena_allocated());
+
+                    let panic_result = std::panic::catch_unwind(|| {
+                        crate::arena::with_heap_ast_allocations(|| {
+                            let scratch = AstBox::new(3_u8);
+                            assert!(!scratch.is_arena_allocated());
+                            panic!("exercise scope restoration");
+                        });
+                    });
+                    assert!(panic_result.is_err());
+
+                    let after = AstBox::new(4_u8);
+                    assert!(after.is_arena_allocated());
+                })
+            }
+            .unwrap();
+            assert_eq!(document.statements()[0].to_string(), "SELECT 1");
+        }
+
+        #[test]
+        fn statement_handle_keeps_document_alive() {
+            let document =
+                ParsedSql::parse(&PostgreSqlDialect {}, Arc::<str>::from("SELECT 42")).unwrap();
+            let handle = document.statement(0).unwrap();
+            drop(document);
+            assert_eq!(handle.get().to_string(), "SELECT 42");
+            assert_eq!(handle.index(), 0);
+        }
+
+        #[test]
+        fn arena_resident_documents_are_destructor_free() {
+            use crate::ast::ArenaResident;
+
+            let dialect = PostgreSqlDialect {};
+            let document =
+                ParsedSql::parse(&dialect, "SELECT a, f(b) FROM t WHERE c IN ($1, d)").unwrap();
+            assert!(document.arena_stats().destructor_free);
+            assert!(document.statements()[0].is_arena_resident());
+
+            // The rewrite shares every node, so it is destructor-free too, and
+            // dropping it must not disturb the parent's nodes.
+            let (rewritten, ()) = document.rewrite_with_session(|_| ());
+            assert!(rewritten.arena_stats().destructor_free);
+            drop(rewritten);
+            assert_eq!(
+                document.statements()[0].to_string(),
+                "SELECT a, f(b) FROM t WHERE c IN ($1, d)"
+            );
+
+            // Statement-level lists are arena lists too.
+            for sql in [
+                "WITH c AS (SELECT a FROM t) SELECT a FROM c GROUP BY a ORDER BY a FOR UPDATE",
+                "SELECT DISTINCT ON (a) a, CASE WHEN b THEN c END, sum(d) OVER (PARTITION BY e ORDER BY f) FROM t AS x (a, b) JOIN u USING (a)",
+                "INSERT INTO t (a, b) VALUES ($1, $2), ($3, $4) ON CONFLICT (a) DO UPDATE SET b = excluded.b RETURNING a",
+                "UPDATE t SET a = $1, b = $2 FROM u WHERE c = $3 RETURNING a",
+                "DELETE FROM t USING u WHERE c = $1 RETURNING a",
+            ] {
+                let document = ParsedSql::parse(&dialect, sql).unwrap();
+                assert!(document.arena_stats().destructor_free, "{sql}");
+            }
+
+            // Unescaping copies the text to the heap, and so do heap parses.
+            let escaped = ParsedSql::parse(&dialect, "SELECT 'it''s'").unwrap();
+            assert!(!escaped.arena_stats().destructor_free);
+            let owned = Parser::parse_sql(&dialect, "SELECT a FROM t").unwrap();
+            assert!(!owned[0].is_arena_resident());
+            let adopted = ParsedSql::from_statements("SELECT a FROM t", owned);
+            assert!(!adopted.arena_stats().destructor_free);
+        }
+
+        #[test]
+        fn nodes_edited_in_place_are_checked_when_frozen() {
+            use crate::ast::{GroupByExpr, GroupByWithModifier, SetExpr};
+
+            let document = ParsedSql::parse(&PostgreSqlDialect {}, "SELECT a FROM t").unwrap();
+            assert!(document.arena_stats().destructor_free);
+
+            // The edit places no new box, so only the freeze can notice the
+            // heap list it stores in an arena node.
+            // SAFETY: The edit moves no syntax out of the document.
+            let (rewritten, ()) = unsafe {
+                document.rewrite(|statements| {
+                    let Statement::Query(query) = &mut statements[0] else {
+                        unreachable!()
+                    };
+                    let SetExpr::Select(select) = &mut *query.body else {
+                        unreachable!()
+                    };
+                    select.group_by = GroupByExpr::All(vec![GroupByWithModifier::Rollup]);
+                })
+            };
+            assert!(!rewritten.arena_stats().destructor_free);
+            assert_eq!(
+                rewritten.statements()[0].to_string(),
+                "SELECT a FROM t GROUP BY ALL WITH ROLLUP"
+            );
+        }
+
+        #[test]
+        fn heap_size_separates_arena_and_heap_storage() {
+            use crate::ast::{ArenaResident, HeapSize};
+
+            let dialect = PostgreSqlDialect {};
+            let sql = "SELECT a, f(b) FROM t WHERE c IN ($1, d)";
+            let document = ParsedSql::parse(&dialect, sql).unwrap();
+            let usage = document.statements()[0].heap_size();
+            assert_eq!(usage.heap_bytes, 0);
+            assert!(usage.arena_bytes > 0);
+            assert!(usage.arena_bytes <= document.arena_stats().requested_bytes);
+
+            let owned = Parser::parse_sql(&dialect, sql).unwrap();
+            let usage = owned[0].heap_size();
+            assert_eq!(usage.arena_bytes, 0);
+            assert!(usage.heap_bytes > document.statements()[0].heap_size().arena_bytes);
+
+            // Only syntax without heap bytes is arena-resident.
+            assert!(document.statements()[0].is_arena_resident());
+            assert!(!owned[0].is_arena_resident());
+
+            // Unescaped text is the only heap allocation of this statement.
+            let escaped = ParsedSql::parse(&dialect, "SELECT 'it''s'").unwrap();
+            let usage = escaped.statements()[0].heap_size();
+            assert!(usage.heap_bytes >= "it's".len());
+
+            let retained = document.retained_bytes();
+            let stats = document.arena_stats();
+            assert!(retained > sql.len() + stats.committed_bytes);
+
+            // Shared nodes are counted by the document they belong to, which
+            // the rewrite retains.
+            let (rewritten, ()) = document.rewrite_with_session(|_| ());
+            assert_eq!(rewritten.statements()[0].heap_size(), HeapUsage::default());
+            assert!(rewritten.retained_bytes() > retained);
+
+            // A rewrite shares the source of its parent, which is counted once.
+            let padded = format!("{sql} -- {}", "x".repeat(64 * 1024));
+            let document = ParsedSql::parse(&dialect, padded.as_str()).unwrap();
+            let (rewritten, ()) = document.rewrite_with_session(|_| ());
+            let added = rewritten.retained_bytes() - document.retained_bytes();
+            assert!(added < padded.len(), "{added}");
+        }
+
+        #[test]
+        fn attached_comments_follow_the_document() {
+            let sql = "-- first\nSELECT a FROM t;\nSELECT b, -- second\n c FROM u";
+            let options = ParserOptions::new().with_attach_comments(true);
+            let document =
+                ParsedSql::parse_with_options(&PostgreSqlDialect {}, sql, options).unwrap();
+            let display = |document: &ParsedSql| {
+                document
+                    .statements()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(
+                display(&document),
+                [
+                    "-- first\nSELECT a FROM t",
+                    "SELECT b -- second\n, c FROM u"
+                ]
+            );
+
+            let decoded = ParsedSql::from_bytes(&document.to_bytes()).unwrap();
+            assert_eq!(display(&decoded), display(&document));
+            let (rewritten, ()) = document.rewrite_with_session(|_| ());
+            assert_eq!(display(&rewritten), display(&document));
+            let (edited, ()) = document.rewrite_with_session(|session| {
+                session.edit_statement(0, |statement| {
+                    let comments = statement.comments().unwrap().clone();
+                    *statement = Parser::parse_sql(&PostgreSqlDialect {}, "SELECT z FROM t")
+                        .unwrap()
+                        .remove(0);
+                    *statement.comments_mut().unwrap() = comments;
+                })
+            });
+            assert_eq!(
+                edited.statements()[0].to_string(),
+                "-- first\nSELECT z FROM t"
+            );
+
+            let parts = document.split_statements();
+            assert_eq!(
+                parts
+                    .iter()
+                    .flat_map(|part| display(part))
+                    .collect::<Vec<_>>(),
+                display(&document)
             );
         }
 
         #[test]
-        fn heap_scratch_scope_restores_document_arena_after_panic() {
+        fn split_statements_release_independently() {
+            let document = ParsedSql::parse(
+                &PostgreSqlDialect {},
+                "SELECT /*+ NO_PARALLEL */ a FROM t WHERE b = 1;\nSELECT c FROM u",
+            )
+            .unwrap();
+            let (rewritten, ()) = document.rewrite_with_session(|_| ());
+            let parts = rewritten.split_statements();
+            assert_eq!(parts.len(), 2);
+
+            for (index, part) in parts.iter().enumerate() {
+                assert!(Arc::ptr_eq(&part.source, &document.source));
+                assert_eq!(part.statements().len(), 1);
+                assert_eq!(part.statement_span(0), document.statement_span(index));
+                assert_eq!(part.statement_source(0), document.statement_source(index));
+                let span = part.statement_span(0).unwrap();
+                assert_eq!(
+                    part.optimizer_hints_for_span(span),
+                    document.optimizer_hints_for_span(span)
+                );
+                let Statement::Query(query) = &part.statements()[0] else {
+                    panic!("expected query")
+                };
+                assert!(query.is_arena_allocated() && !query.is_shared());
+                assert_eq!(part.arena_stats().shared_bytes, 0);
+            }
+            assert_eq!(parts[0].optimizer_hints().len(), 1);
+            assert!(parts[1].optimizer_hints().is_empty());
+
+            let second = parts[1].statement(0).unwrap();
+            drop(document);
+            drop(rewritten);
+            drop(parts);
+            assert_eq!(second.to_string(), "SELECT c FROM u");
+            assert_eq!(second.source(), Some("SELECT c FROM u"));
+            assert_eq!(Arc::strong_count(second.document()), 1);
+        }
+
+        #[test]
+        fn chunk_pool_recycles_chunks_across_documents() {
+            let sql = "SELECT a, b + 1 FROM t WHERE c = 2 ORDER BY a";
+            let pool = Arc::new(AstChunkPool::new(AST_CHUNK_BYTES * 2));
+            let options = ParserOptions::new().with_chunk_pool(Arc::clone(&pool));
+
+            let first =
+                ParsedSql::parse_with_options(&PostgreSqlDialect {}, sql, options.clone()).unwrap();
+            let chunks = first
+                .arena
+                .chunks
+                .iter()
+                .filter(|chunk| chunk.layout == AstArenaChunk::standard_layout())
+                .count();
+            assert!(chunks > 2);
+            let stats = pool.stats();
+            assert_eq!((stats.hits, stats.misses), (0, chunks));
+            drop(first);
+            let retained = chunks.min(2);
+            assert_eq!(pool.stats().retained_chunks, retained);
+            assert_eq!(pool.stats().retained_bytes, retained * AST_CHUNK_BYTES);
+            assert_eq!(pool.stats().discarded, chunks - retained);
+
+            // Rewrites and splits draw from the pool of their parent.
+            let second =
+                ParsedSql::parse_with_options(&PostgreSqlDialect {}, sql, options).unwrap();
+            assert_eq!(second.statements()[0].to_string(), sql);
+            let (rewritten, ()) = second.rewrite_with_session(|_| ());
+            let parts = rewritten.split_statements();
+            let stats = pool.stats();
+            assert_eq!(stats.hits, retained);
+            assert!(stats.misses > chunks);
+            assert_eq!(stats.retained_chunks, 0);
+            drop((second, rewritten, parts));
+            assert_eq!(pool.stats().retained_chunks, 2);
+
+            pool.clear();
+            assert_eq!(pool.stats().retained_chunks, 0);
+            let unpooled = ParsedSql::parse(&PostgreSqlDialect {}, sql).unwrap();
+            drop(unpooled);
+            assert_eq!(pool.stats().hits, retained);
+        }
+
+        #[test]
+        fn arena_byte_limit_stops_the_parse() {
+            use crate::parser::{LimitExceeded, ParserLimit, ParserLimits};
+
+            let sql = format!(
+                "SELECT {} FROM t; SELECT 1",
+                (0..200)
+                    .map(|i| format!("c{i} + {i}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
             let dialect = PostgreSqlDialect {};
-            // SAFETY: The callback only inspects allocation modes and does not
-            // let any arena-backed syntax escape the returned document.
-            let (document, ()) = unsafe {
-                ParsedSql::parse_and_edit(&dialect, Arc::<str>::from("SELECT 1"), |_statements| {
-                    let before = AstBox::new(1_u8);
-                    assert!(before.is_arena_allocated());
+            let committed = ParsedSql::parse(&dialect, sql.as_str())
+                .unwrap()
+                .arena_stats()
+                .committed_bytes;
+            let limited = |max| {
+                ParserOptions::new().with_limits(ParserLimits::new().with_max_arena_bytes(max))
+            };
 
-                    let scratch = crate::arena::with_heap_ast_allocations(|| AstBox::new(2_u8));
-                    assert!(!scratch.is_arena_allocated());
+            let document =
+                ParsedSql::parse_with_options(&dialect, sql.as_str(), limited(committed)).unwrap();
+            assert_eq!(document.arena_stats().committed_bytes, committed);
 
-                    let panic_result = std::panic::catch_unwind(|| {
-                        crate::arena::with_heap_ast_allocations(|| {
-                            let scratch = AstBox::new(3_u8);
-                            assert!(!scratch.is_arena_allocated());
-                            panic!("exercise scope restoration");
-                        });
-                    });
-                    assert!(panic_result.is_err());
+            let max = committed / 2;
+            let exceeded = ParserError::LimitExceeded(LimitExceeded {
+                limit: ParserLimit::ArenaBytes,
+                max,
+            });
+            assert_eq!(
+                ParsedSql::parse_with_options(&dialect, sql.as_str(), limited(max)).unwrap_err(),
+                exceeded
+            );
+            assert_eq!(
+                ParsedSql::parse_with_recovery(&dialect, sql.as_str(), limited(max)).unwrap_err(),
+                exceeded
+            );
 
-                    let after = AstBox::new(4_u8);
-                    assert!(after.is_arena_allocated());
+            // Source and token limits also cover the optimizer hint scan.
+            let limited = ParserOptions::new().with_limits(ParserLimits::new().with_max_tokens(3));
+            assert_eq!(
+                ParsedSql::parse_with_options(&dialect, "SELECT /*+ hint */ a, b", limited)
+                    .unwrap_err(),
+                ParserError::LimitExceeded(LimitExceeded {
+                    limit: ParserLimit::Tokens,
+                    max: 3,
                 })
-            }
-            .unwrap();
-            assert_eq!(document.statements()[0].to_string(), "SELECT 1");
+            );
         }
 
+        #[cfg(feature = "ast-histogram")]
         #[test]
-        fn statement_handle_keeps_document_alive() {
-            let document =
-                ParsedSql::parse(&PostgreSqlDialect {}, Arc::<str>::from("SELECT 42")).unwrap();
-            let handle = document.statement(0).unwrap();
-            drop(document);
-            assert_eq!(handle.get().to_string(), "SELECT 42");
-            assert_eq!(handle.index(), 0);
+        fn arena_stats_count_boxes_per_type() {
+            use crate::ast::Expr;
+            use crate::CoreParsedSql;
+
+            let sql = "SELECT a + 1 FROM t WHERE b = 2";
+            let stats = ParsedSql::parse(&PostgreSqlDialect {}, sql)
+                .unwrap()
+                .arena_stats();
+            let histogram = &stats.allocations_by_type;
+            let expr = histogram.get(core::any::type_name::<Expr>()).unwrap();
+            assert!(expr.allocations > 0);
+            assert_eq!(
+                expr.bytes,
+                expr.allocations * core::mem::size_of::<AstBoxStorage<Expr>>() as u64
+            );
+            assert_eq!(histogram.total().allocations, stats.node_allocations as u64);
+
+            // Each document counts only its own boxes.
+            let core = CoreParsedSql::parse(&PostgreSqlDialect {}, sql)
+                .unwrap()
+                .arena_stats();
+            assert_eq!(core.allocations_by_type, *histogram);
+
+            let mut merged = histogram.clone();
+            merged.merge(&core.allocations_by_type);
+            assert_eq!(merged.total().bytes, 2 * histogram.total().bytes);
+        }
+
+        #[test]
+        fn chunk_pool_is_shared_across_threads() {
+            let pool = Arc::new(AstChunkPool::new(AST_CHUNK_BYTES * 16));
+            let options = ParserOptions::new().with_chunk_pool(Arc::clone(&pool));
+            std::thread::scope(|scope| {
+                for _ in 0..4 {
+                    let options = options.clone();
+                    scope.spawn(move || {
+                        for index in 0..50 {
+                            let sql = format!("SELECT x + {index} FROM t");
+                            let document = ParsedSql::parse_with_options(
+                                &PostgreSqlDialect {},
+                                sql.as_str(),
+                                options.clone(),
+                            )
+                            .unwrap();
+                            assert_eq!(document.statements()[0].to_string(), sql);
+                        }
+                    });
+                }
+            });
+            let stats = pool.stats();
+            assert!(stats.hits > stats.misses);
+            assert!(stats.retained_bytes <= stats.max_retained_bytes);
         }
 
         #[test]
@@ -1188,6 +4423,58 @@ mod document {
             );
         }
 
+        #[test]
+        fn document_parse_errors_carry_source_positions() {
+            let sql = "SELECT 1;\nSELECT 'open";
+            let err = ParsedSql::parse(&PostgreSqlDialect {}, Arc::<str>::from(sql)).unwrap_err();
+            let error = err.syntax_error().expect("structured tokenizer error");
+            assert_eq!(error.kind, crate::parser::SyntaxErrorKind::Tokenizer);
+            assert_eq!(error.span.start, Location::new(2, 8));
+            assert_eq!(error.byte_offset, Some(17));
+
+            let sql = "SELECT 1;\nSELECT 2 3";
+            let err = ParsedSql::parse(&PostgreSqlDialect {}, Arc::<str>::from(sql)).unwrap_err();
+            assert_eq!(err.byte_offset(), Some(sql.len() - 1));
+            assert_eq!(
+                err.to_string(),
+                "sql parser error: Expected: end of statement, found: 3 at Line: 2, Column: 10"
+            );
+        }
+
+        #[test]
+        fn recovering_document_keeps_statements_around_failures() {
+            let sql = "SELECT 1;\nSELEC 2;\nSELECT 3";
+            let (document, errors) = ParsedSql::parse_with_recovery(
+                &PostgreSqlDialect {},
+                Arc::<str>::from(sql),
+                ParserOptions::new(),
+            )
+            .unwrap();
+            assert_eq!(document.statements().len(), 2);
+            assert_eq!(document.statement_source(0), Some("SELECT 1"));
+            assert_eq!(document.statement_source(1), Some("SELECT 3"));
+            assert_eq!(errors.len(), 1);
+            assert_eq!(document.source_for_span(errors[0].span), Some("SELEC 2"));
+            assert_eq!(errors[0].error.byte_offset(), Some(10));
+        }
+
+        #[test]
+        fn tolerant_document_keeps_partial_statements() {
+            let sql = "SELECT 1;\nSELECT a, FROM t;\nSELEC 3";
+            let (document, errors) = ParsedSql::parse_tolerant(
+                &PostgreSqlDialect {},
+                Arc::<str>::from(sql),
+                ParserOptions::new(),
+            )
+            .unwrap();
+            assert_eq!(document.statements().len(), 2);
+            assert_eq!(document.statement_source(1), Some("SELECT a, FROM t"));
+            assert_eq!(document.statements()[1].to_string(), "SELECT a,  FROM t");
+            assert_eq!(errors.len(), 2);
+            assert_eq!(errors[0].error.byte_offset(), Some(20));
+            assert_eq!(document.source_for_span(errors[1].span), Some("SELEC 3"));
+        }
+
         #[cfg(feature = "std")]
         #[test]
         fn deeply_nested_document_drops_without_stack_overflow() {
@@ -1216,10 +4503,373 @@ mod document {
     }
 }
 
+mod core_document {
+    use super::*;
+    use crate::ast::Statement;
+    use crate::dialect::Dialect;
+    use crate::parser::{Parser, ParserError, ParserOptions};
+    use crate::tokenizer::Span;
+    #[cfg(all(not(feature = "std"), target_has_atomic = "ptr"))]
+    use alloc::sync::Arc;
+    #[cfg(feature = "std")]
+    use std::sync::Arc;
+
+    /// An owned SQL source and its parsed statement batch, for targets
+    /// without `std`.
+    ///
+    /// [`ParsedSql`](crate::ParsedSql) scopes its arena through a
+    /// thread-local, which `core` does not provide. This document instead
+    /// hands its arena to the parser explicitly, which allocates boxed
+    /// syntax nodes in it. Lists and leaf text use the global heap, and a
+    /// document is not editable once parsed.
+    ///
+    /// ```
+    /// # use sqlparser::{dialect::PostgreSqlDialect, parser::ParserError, CoreParsedSql};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let document = CoreParsedSql::parse(&PostgreSqlDialect {}, "SELECT a + 1 FROM t; SELECT 2")?;
+    /// assert_eq!(document.len(), 2);
+    /// assert_eq!(document.statement_source(0), Some("SELECT a + 1 FROM t"));
+    /// assert!(document.arena_stats().node_allocations > 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub struct CoreParsedSql {
+        // Statements must drop before the arena their nodes live in. Struct
+        // fields are dropped in declaration order.
+        statements: Vec<Statement>,
+        statement_spans: Vec<Span>,
+        source: Box<str>,
+        arena: AstArena,
+    }
+
+    impl CoreParsedSql {
+        /// Parse SQL into one arena-owned syntax document.
+        pub fn parse(
+            dialect: &dyn Dialect,
+            source: impl Into<Box<str>>,
+        ) -> Result<Self, ParserError> {
+            let options =
+                ParserOptions::new().with_trailing_commas(dialect.supports_trailing_commas());
+            Self::parse_with_options(dialect, source, options)
+        }
+
+        /// Parse SQL with explicit parser options into one arena-owned
+        /// syntax document.
+        pub fn parse_with_options(
+            dialect: &dyn Dialect,
+            source: impl Into<Box<str>>,
+            options: ParserOptions,
+        ) -> Result<Self, ParserError> {
+            let source = source.into();
+            let arena = AstArena::new(options.limits.max_arena_bytes);
+            let parse = || {
+                Parser::new(dialect)
+                    .with_options(options)
+                    .with_explicit_arena(&arena)
+                    .try_with_sql(&source)?
+                    .parse_statements_with_spans()
+            };
+            // Keep list and leaf allocations out of any document arena that
+            // is being built on this thread.
+            #[cfg(feature = "std")]
+            let parsed = without_active_arena(parse);
+            #[cfg(not(feature = "std"))]
+            let parsed = parse();
+            let (statements, statement_spans) = parsed?;
+            Ok(Self {
+                statements,
+                statement_spans,
+                source,
+                arena,
+            })
+        }
+
+        /// Parsed statement roots.
+        pub fn statements(&self) -> &[Statement] {
+            &self.statements
+        }
+
+        /// Exact top-level source span consumed for a parsed statement.
+        pub fn statement_span(&self, index: usize) -> Option<Span> {
+            self.statement_spans.get(index).copied()
+        }
+
+        /// Original source text consumed for one top-level statement.
+        /// Delimiters and surrounding trivia are excluded.
+        pub fn statement_source(&self, index: usize) -> Option<&str> {
+            let range = self.statement_span(index)?.byte_range()?;
+            let statement = self.source.get(range)?.trim();
+            (!statement.is_empty()).then_some(statement)
+        }
+
+        /// Complete SQL source owned by this document.
+        pub fn source(&self) -> &str {
+            &self.source
+        }
+
+        /// Recursive-node arena statistics. Only boxed nodes are counted,
+        /// because lists and leaf text are not allocated in the arena.
+        pub fn arena_stats(&self) -> AstArenaStats {
+            self.arena.stats(false)
+        }
+
+        /// Create a cheap owned handle to one statement root.
+        #[cfg(target_has_atomic = "ptr")]
+        pub fn statement(self: &Arc<Self>, index: usize) -> Option<CoreStatementHandle> {
+            (index < self.statements.len()).then(|| CoreStatementHandle {
+                document: Arc::clone(self),
+                index,
+            })
+        }
+    }
+
+    // SAFETY: The arena's cells are only written while the document is being
+    // parsed, before it is shared. Afterwards its nodes are reachable only
+    // through shared references to the statements.
+    unsafe impl Sync for CoreParsedSql {}
+
+    impl Deref for CoreParsedSql {
+        type Target = [Statement];
+
+        fn deref(&self) -> &Self::Target {
+            self.statements()
+        }
+    }
+
+    impl AsRef<[Statement]> for CoreParsedSql {
+        fn as_ref(&self) -> &[Statement] {
+            self.statements()
+        }
+    }
+
+    impl fmt::Debug for CoreParsedSql {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("CoreParsedSql")
+                .field("source", &self.source)
+                .field("statements", &self.statements)
+                .field("arena", &self.arena_stats())
+                .finish()
+        }
+    }
+
+    /// A shareable document-and-index handle to one statement root of a
+    /// [`CoreParsedSql`], with the semantics of
+    /// [`StatementHandle`](crate::StatementHandle).
+    #[cfg(target_has_atomic = "ptr")]
+    #[derive(Clone)]
+    pub struct CoreStatementHandle {
+        document: Arc<CoreParsedSql>,
+        index: usize,
+    }
+
+    #[cfg(target_has_atomic = "ptr")]
+    mod handle {
+        use super::*;
+
+        impl CoreStatementHandle {
+            /// Borrow the statement for the lifetime of this handle.
+            pub fn get(&self) -> &Statement {
+                &self.document.statements[self.index]
+            }
+
+            /// Zero-based root index within the parsed document.
+            pub fn index(&self) -> usize {
+                self.index
+            }
+
+            /// Owning parsed document.
+            pub fn document(&self) -> &Arc<CoreParsedSql> {
+                &self.document
+            }
+
+            /// Original source text consumed for this statement.
+            pub fn source(&self) -> Option<&str> {
+                self.document.statement_source(self.index)
+            }
+        }
+
+        impl Deref for CoreStatementHandle {
+            type Target = Statement;
+
+            fn deref(&self) -> &Self::Target {
+                self.get()
+            }
+        }
+
+        impl AsRef<Statement> for CoreStatementHandle {
+            fn as_ref(&self) -> &Statement {
+                self.get()
+            }
+        }
+
+        impl Borrow<Statement> for CoreStatementHandle {
+            fn borrow(&self) -> &Statement {
+                self.get()
+            }
+        }
+
+        impl fmt::Display for CoreStatementHandle {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self.get(), f)
+            }
+        }
+
+        impl fmt::Debug for CoreStatementHandle {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct("CoreStatementHandle")
+                    .field("index", &self.index)
+                    .field("statement", &self.get())
+                    .finish()
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::ast::{Expr, SetExpr};
+        use crate::dialect::PostgreSqlDialect;
+        use crate::parser::ParserLimits;
+
+        #[test]
+        fn core_document_allocates_boxed_nodes_in_its_arena() {
+            let sql = "SELECT (a + 1) * 2 FROM t WHERE b IN (SELECT c FROM u)";
+            let document = CoreParsedSql::parse(&PostgreSqlDialect {}, sql).unwrap();
+            assert_eq!(document.statements()[0].to_string(), sql);
+            assert_eq!(document.statement_source(0), Some(sql));
+
+            let Statement::Query(query) = &document[0] else {
+                panic!("expected a query");
+            };
+            assert!(query.is_arena_allocated());
+            let SetExpr::Select(select) = &*query.body else {
+                panic!("expected a select");
+            };
+            let Some(Expr::InSubquery { subquery, .. }) = select.selection.as_deref() else {
+                panic!("expected IN (subquery)");
+            };
+            assert!(subquery.is_arena_allocated());
+
+            let stats = document.arena_stats();
+            assert!(stats.node_allocations > 0);
+            assert!(stats.committed_bytes >= stats.requested_bytes);
+
+            // A clone owns its nodes, so it outlives the document.
+            let cloned = document.statements()[0].clone();
+            drop(document);
+            assert_eq!(cloned.to_string(), sql);
+        }
+
+        #[test]
+        fn core_statement_handles_share_the_document() {
+            let document = Arc::new(
+                CoreParsedSql::parse(&PostgreSqlDialect {}, "SELECT 1; SELECT 2").unwrap(),
+            );
+            let handle = document.statement(1).unwrap();
+            assert!(document.statement(2).is_none());
+            drop(document);
+
+            let thread_handle = handle.clone();
+            let rendered = std::thread::spawn(move || thread_handle.to_string())
+                .join()
+                .unwrap();
+            assert_eq!(rendered, "SELECT 2");
+            assert_eq!(handle.source(), Some("SELECT 2"));
+            assert_eq!(handle.index(), 1);
+        }
+
+        #[test]
+        fn core_document_enforces_the_arena_byte_limit() {
+            let options = ParserOptions::new()
+                .with_limits(ParserLimits::new().with_max_arena_bytes(AST_CHUNK_BYTES));
+            let sql = vec!["SELECT (1 + 2) * 3"; 500].join("; ");
+            let error =
+                CoreParsedSql::parse_with_options(&PostgreSqlDialect {}, sql, options).unwrap_err();
+            assert_eq!(
+                error,
+                ParserError::LimitExceeded(LimitExceeded {
+                    limit: ParserLimit::ArenaBytes,
+                    max: AST_CHUNK_BYTES,
+                })
+            );
+        }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn core_document_boxes_statement_variants_in_its_arena() {
+            use crate::dialect::{Dialect, MsSqlDialect};
+            use crate::ParsedSql;
+
+            // Each statement is stored behind a boxed `Statement` variant, so
+            // a box allocated on the heap instead of the explicit arena would
+            // show up as one node fewer than the thread-local arena counts.
+            let postgres = PostgreSqlDialect {};
+            let mssql = MsSqlDialect {};
+            let cases: [(&dyn Dialect, &str); 10] = [
+                (&postgres, "SELECT a FROM t"),
+                (&postgres, "INSERT INTO t (a) VALUES (1)"),
+                (&postgres, "UPDATE t SET a = 1 WHERE b = 2"),
+                (&postgres, "DELETE FROM t WHERE a = 1"),
+                (&postgres, "CREATE TABLE t (a INT)"),
+                (&postgres, "CREATE VIEW v AS SELECT a FROM t"),
+                (&postgres, "CREATE ROLE r"),
+                (&postgres, "CREATE EXTENSION e"),
+                (&mssql, "IF 1 = 1 SELECT 1 ELSE SELECT 2"),
+                (
+                    &mssql,
+                    "CREATE TRIGGER tr ON t AFTER INSERT AS BEGIN SELECT 1; END",
+                ),
+            ];
+            for (dialect, sql) in cases {
+                let threaded = ParsedSql::parse(dialect, sql).unwrap();
+                let core = CoreParsedSql::parse(dialect, sql).unwrap();
+                assert_eq!(
+                    core.arena_stats().node_allocations,
+                    threaded.arena_stats().node_allocations,
+                    "{sql}"
+                );
+                assert!(core.arena_stats().node_allocations > 0, "{sql}");
+            }
+        }
+    }
+}
+
+pub use core_document::CoreParsedSql;
+#[cfg(target_has_atomic = "ptr")]
+pub use core_document::CoreStatementHandle;
+
+#[cfg(feature = "ast-histogram")]
+use document::note_arena_box;
 #[cfg(feature = "std")]
-use document::{active_arena_alloc, without_active_arena};
+use document::{
+    active_arena_alloc, active_arena_alloc_slice, active_arena_check_budget, active_arena_shares,
+    active_source_slice, active_source_text_at, note_arena_edit, note_arena_placement,
+    note_owned_leaf, placing_in_arena, without_active_arena,
+};
+#[cfg(feature = "std")]
+use document::{adopted_by_edit, note_edit_change, note_edit_copy, take_edit_origin};
+
+#[cfg(not(feature = "std"))]
+fn placing_in_arena() -> bool {
+    false
+}
 #[cfg(feature = "std")]
-pub use document::{AstArenaStats, ParsedSql, StatementHandle};
+pub use document::{AstChunkPool, AstChunkPoolStats, EditSession, ParsedSql, StatementHandle};
+
+/// Check that `value` fits the remaining byte budget of the document arena
+/// being built, before it is allocated. See
+/// [`ParserLimits::max_arena_bytes`](crate::parser::ParserLimits::max_arena_bytes).
+fn check_arena_budget(value: Layout) -> Result<(), ParserError> {
+    #[cfg(feature = "std")]
+    {
+        active_arena_check_budget(value)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = value;
+        Ok(())
+    }
+}
 
 /// Run temporary AST work with document-arena allocation suspended.
 ///
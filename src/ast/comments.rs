@@ -0,0 +1,114 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! SQL Abstract Syntax Tree (AST) for source comments.
+//!
+//! Comments are only kept when parsing with
+//! [`ParserOptions::with_attach_comments`](crate::parser::ParserOptions::with_attach_comments).
+use super::*;
+
+use core::fmt::Write;
+
+use crate::tokenizer::Whitespace;
+
+/// A comment from the SQL source.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum SourceComment {
+    /// A comment running to the end of the line, such as `-- text`.
+    /// `text` does not include the line break.
+    SingleLine { prefix: String, text: String },
+    /// A `/* text */` comment.
+    MultiLine(String),
+}
+
+impl SourceComment {
+    /// Convert a comment token, or return `None` for other whitespace.
+    pub fn from_whitespace(whitespace: &Whitespace) -> Option<Self> {
+        match whitespace {
+            Whitespace::SingleLineComment { comment, prefix } => Some(SourceComment::SingleLine {
+                prefix: prefix.to_string(),
+                text: comment.trim_end_matches(['\n', '\r']).to_string(),
+            }),
+            Whitespace::MultiLineComment(text) => Some(SourceComment::MultiLine(text.to_string())),
+            _ => None,
+        }
+    }
+
+    /// Whether the comment runs to the end of the line, so that anything
+    /// displayed after it must start on a new line.
+    pub fn is_single_line(&self) -> bool {
+        matches!(self, SourceComment::SingleLine { .. })
+    }
+}
+
+impl fmt::Display for SourceComment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SourceComment::SingleLine { prefix, text } => write!(f, "{prefix}{text}"),
+            SourceComment::MultiLine(text) => write!(f, "/*{text}*/"),
+        }
+    }
+}
+
+/// The comments attached to an AST node.
+///
+/// Leading comments are those between the previous token and the node.
+/// Trailing comments follow the node on the line where it ends, possibly
+/// after the `,` or `;` that separates it from the next one.
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct Comments {
+    pub leading: Vec<SourceComment>,
+    pub trailing: Vec<SourceComment>,
+}
+
+impl Comments {
+    /// Returns true when there are no comments.
+    pub fn is_empty(&self) -> bool {
+        self.leading.is_empty() && self.trailing.is_empty()
+    }
+
+    /// Display the leading comments, each followed by a line break or a
+    /// space, ready for the node to follow.
+    pub(crate) fn fmt_leading(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for comment in &self.leading {
+            comment.fmt(f)?;
+            if comment.is_single_line() {
+                f.write_char('\n')?;
+            } else {
+                SpaceOrNewline.fmt(f)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Display the trailing comments after a node. A line break follows a
+    /// single-line comment so the rest of the statement is not commented out.
+    pub(crate) fn fmt_trailing(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for comment in &self.trailing {
+            f.write_char(' ')?;
+            comment.fmt(f)?;
+            if comment.is_single_line() {
+                f.write_char('\n')?;
+            }
+        }
+        Ok(())
+    }
+}
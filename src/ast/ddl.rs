@@ -31,6 +31,7 @@ use core::fmt::{self, Display, Write};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use sqlparser_derive::{AstCodec, HeapSize};
 #[cfg(feature = "visitor")]
 use sqlparser_derive::{Visit, VisitMut};
 
@@ -41,7 +42,7 @@ use crate::ast::{
         CheckConstraint, ForeignKeyConstraint, PrimaryKeyConstraint, TableConstraint,
         UniqueConstraint,
     },
-    ArgMode, AttachedToken, CommentDef, ConditionalStatements, CreateFunctionBody,
+    ArgMode, AttachedToken, CommentDef, Comments, ConditionalStatements, CreateFunctionBody,
     CreateFunctionUsing, CreateTableLikeKind, CreateTableOptions, CreateViewParams, DataType, Expr,
     FunctionBehavior, FunctionCalledOnNull, FunctionDesc, FunctionDeterminismSpecifier,
     FunctionParallel, Ident, MaterializedViewRefreshSchedule, MySQLColumnPosition, ObjectName,
@@ -53,7 +54,7 @@ use crate::display_utils::{DisplayCommaSeparated, Indent, NewLine, SpaceOrNewlin
 use crate::tokenizer::{Span, Token};
 
 /// Index column type.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct IndexColumn {
@@ -89,7 +90,7 @@ impl fmt::Display for IndexColumn {
 
 /// ALTER TABLE operation REPLICA IDENTITY values
 /// See [Postgres ALTER TABLE docs](https://www.postgresql.org/docs/current/sql-altertable.html)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ReplicaIdentity {
@@ -111,7 +112,7 @@ impl fmt::Display for ReplicaIdentity {
 }
 
 /// An `ALTER TABLE` (`Statement::AlterTable`) operation
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterTableOperation {
@@ -356,7 +357,7 @@ pub enum AlterTableOperation {
 /// An `ALTER Policy` (`Statement::AlterPolicy`) operation
 ///
 /// [PostgreSQL Documentation](https://www.postgresql.org/docs/current/sql-altertable.html)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterPolicyOperation {
@@ -399,7 +400,7 @@ impl fmt::Display for AlterPolicyOperation {
 /// [MySQL] `ALTER TABLE` algorithm.
 ///
 /// [MySQL]: https://dev.mysql.com/doc/refman/8.4/en/alter-table.html
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterTableAlgorithm {
@@ -423,7 +424,7 @@ impl fmt::Display for AlterTableAlgorithm {
 /// [MySQL] `ALTER TABLE` lock.
 ///
 /// [MySQL]: https://dev.mysql.com/doc/refman/8.4/en/alter-table.html
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterTableLock {
@@ -444,7 +445,7 @@ impl fmt::Display for AlterTableLock {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Owner {
@@ -465,7 +466,7 @@ impl fmt::Display for Owner {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterIndexOperation {
@@ -792,7 +793,7 @@ impl fmt::Display for AlterIndexOperation {
 }
 
 /// An `ALTER TYPE` statement (`Statement::AlterType`)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct AlterType {
@@ -803,7 +804,7 @@ pub struct AlterType {
 }
 
 /// An [AlterType] operation
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterTypeOperation {
@@ -813,7 +814,7 @@ pub enum AlterTypeOperation {
 }
 
 /// An operation supported by PostgreSQL `ALTER VIEW`.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterViewOperation {
@@ -841,7 +842,7 @@ impl fmt::Display for AlterViewOperation {
 }
 
 /// See [AlterTypeOperation::Rename]
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct AlterTypeRename {
@@ -849,7 +850,7 @@ pub struct AlterTypeRename {
 }
 
 /// See [AlterTypeOperation::AddValue]
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct AlterTypeAddValue {
@@ -859,7 +860,7 @@ pub struct AlterTypeAddValue {
 }
 
 /// See [AlterTypeAddValue]
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterTypeAddValuePosition {
@@ -868,7 +869,7 @@ pub enum AlterTypeAddValuePosition {
 }
 
 /// See [AlterTypeOperation::RenameValue]
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct AlterTypeRenameValue {
@@ -911,7 +912,7 @@ impl fmt::Display for AlterTypeOperation {
 }
 
 /// An `ALTER COLUMN` (`Statement::AlterTable`) operation
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterColumnOperation {
@@ -1008,7 +1009,7 @@ impl fmt::Display for AlterColumnOperation {
 /// statements of `MySQL` [(1)].
 ///
 /// [1]: https://dev.mysql.com/doc/refman/8.0/en/create-table.html
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum KeyOrIndexDisplay {
@@ -1056,7 +1057,7 @@ impl fmt::Display for KeyOrIndexDisplay {
 /// [1]: https://dev.mysql.com/doc/refman/8.0/en/create-table.html
 /// [2]: https://dev.mysql.com/doc/refman/8.0/en/create-index.html
 /// [3]: https://www.postgresql.org/docs/14/sql-createindex.html
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum IndexType {
@@ -1092,7 +1093,7 @@ impl fmt::Display for IndexType {
 /// [`CREATE TABLE`]: https://dev.mysql.com/doc/refman/8.4/en/create-table.html
 /// [`CREATE INDEX`]: https://dev.mysql.com/doc/refman/8.4/en/create-index.html
 /// [`ALTER TABLE`]: https://dev.mysql.com/doc/refman/8.4/en/alter-table.html
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum IndexOption {
@@ -1116,7 +1117,7 @@ impl fmt::Display for IndexOption {
 /// [PostgreSQL] unique index nulls handling option: `[ NULLS [ NOT ] DISTINCT ]`
 ///
 /// [PostgreSQL]: https://www.postgresql.org/docs/17/sql-altertable.html
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum NullsDistinctOption {
@@ -1138,7 +1139,7 @@ impl fmt::Display for NullsDistinctOption {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ProcedureParam {
@@ -1165,22 +1166,26 @@ impl fmt::Display for ProcedureParam {
 }
 
 /// SQL column definition
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ColumnDef {
     pub name: Ident,
     pub data_type: DataType,
     pub options: Vec<ColumnOptionDef>,
+    /// Comments around the definition, only collected when parsing with
+    /// [`ParserOptions::with_attach_comments`](crate::parser::ParserOptions::with_attach_comments).
+    pub comments: Comments,
 }
 
 impl fmt::Display for ColumnDef {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.comments.fmt_leading(f)?;
         write!(f, "{} {}", self.name, self.data_type)?;
         for option in &self.options {
             write!(f, " {option}")?;
         }
-        Ok(())
+        self.comments.fmt_trailing(f)
     }
 }
 
@@ -1200,7 +1205,7 @@ impl fmt::Display for ColumnDef {
 /// amount COMMENT 'The total amount for the order line'
 /// created_at DateTime64
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ViewColumnDef {
@@ -1209,7 +1214,7 @@ pub struct ViewColumnDef {
     pub options: Option<ColumnOptions>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ColumnOptions {
@@ -1262,7 +1267,7 @@ impl fmt::Display for ViewColumnDef {
 /// For maximum flexibility, we don't distinguish between constraint and
 /// non-constraint options, lumping them all together under the umbrella of
 /// "column options," and we allow any column option to be named.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ColumnOptionDef {
@@ -1282,7 +1287,7 @@ impl fmt::Display for ColumnOptionDef {
 /// { IDENTITY | AUTOINCREMENT } [ (seed , increment) | START num INCREMENT num ] [ ORDER | NOORDER ]
 /// ```
 /// [MS SQL Server]: https://learn.microsoft.com/en-us/sql/t-sql/statements/create-table-transact-sql-identity-property
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum IdentityPropertyKind {
@@ -1323,7 +1328,7 @@ impl fmt::Display for IdentityPropertyKind {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct IdentityProperty {
@@ -1343,7 +1348,7 @@ pub struct IdentityProperty {
 /// (seed , increment)
 /// ```
 /// [MS SQL Server]: https://learn.microsoft.com/en-us/sql/t-sql/statements/create-table-transact-sql-identity-property
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum IdentityPropertyFormatKind {
@@ -1378,7 +1383,7 @@ impl fmt::Display for IdentityPropertyFormatKind {
         }
     }
 }
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct IdentityParameters {
@@ -1391,7 +1396,7 @@ pub struct IdentityParameters {
 /// ```sql
 /// ORDER | NOORDER
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum IdentityPropertyOrder {
@@ -1410,7 +1415,7 @@ impl fmt::Display for IdentityPropertyOrder {
 
 /// `ColumnOption`s are modifiers that follow a column definition in a `CREATE
 /// TABLE` statement.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ColumnOption {
@@ -1627,7 +1632,7 @@ impl fmt::Display for ColumnOption {
 
 /// `GeneratedAs`s are modifiers that follow a column option in a `generated`.
 /// 'ExpStored' is used for a column generated from an expression and stored.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum GeneratedAs {
@@ -1643,7 +1648,7 @@ pub enum GeneratedAs {
 
 /// `GeneratedExpressionMode`s are modifiers that follow an expression in a `generated`.
 /// No modifier is typically the same as Virtual.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum GeneratedExpressionMode {
@@ -1698,7 +1703,7 @@ pub(crate) fn display_option_spaced<T: fmt::Display>(option: &Option<T>) -> impl
 /// `<constraint_characteristics> = [ DEFERRABLE | NOT DEFERRABLE ] [ INITIALLY DEFERRED | INITIALLY IMMEDIATE ] [ ENFORCED | NOT ENFORCED ]`
 ///
 /// Used in UNIQUE and foreign key constraints. The individual settings may occur in any order.
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ConstraintCharacteristics {
@@ -1710,7 +1715,7 @@ pub struct ConstraintCharacteristics {
     pub enforced: Option<bool>,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DeferrableInitial {
@@ -1777,7 +1782,7 @@ impl fmt::Display for ConstraintCharacteristics {
 /// { RESTRICT | CASCADE | SET NULL | NO ACTION | SET DEFAULT }`
 ///
 /// Used in foreign key constraints in `ON UPDATE` and `ON DELETE` options.
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ReferentialAction {
@@ -1803,7 +1808,7 @@ impl fmt::Display for ReferentialAction {
 /// `<drop behavior> ::= CASCADE | RESTRICT`.
 ///
 /// Used in `DROP` statements.
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DropBehavior {
@@ -1821,7 +1826,7 @@ impl fmt::Display for DropBehavior {
 }
 
 /// SQL user defined type definition
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum UserDefinedTypeRepresentation {
@@ -1869,7 +1874,7 @@ impl fmt::Display for UserDefinedTypeRepresentation {
 }
 
 /// SQL user defined type attribute definition
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct UserDefinedTypeCompositeAttributeDef {
@@ -1910,7 +1915,7 @@ impl fmt::Display for UserDefinedTypeCompositeAttributeDef {
 ///     INTERNALLENGTH = VARIABLE  -- Variable length
 /// );
 /// ```
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum UserDefinedTypeInternalLength {
@@ -1947,7 +1952,7 @@ impl fmt::Display for UserDefinedTypeInternalLength {
 ///     ALIGNMENT = int4  -- 4-byte alignment
 /// );
 /// ```
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Alignment {
@@ -1991,7 +1996,7 @@ impl fmt::Display for Alignment {
 ///     STORAGE = plain
 /// );
 /// ```
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum UserDefinedTypeStorage {
@@ -2033,7 +2038,7 @@ impl fmt::Display for UserDefinedTypeStorage {
 ///     SUBTYPE_DIFF = int4range_subdiff
 /// );
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum UserDefinedTypeRangeOption {
@@ -2088,7 +2093,7 @@ impl fmt::Display for UserDefinedTypeRangeOption {
 ///     ALIGNMENT = double
 /// );
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum UserDefinedTypeSqlDefinitionOption {
@@ -2172,7 +2177,7 @@ impl fmt::Display for UserDefinedTypeSqlDefinitionOption {
 
 /// PARTITION statement used in ALTER TABLE and other statements.
 /// For example, supports syntax like PARTITION ID 'partition_id' and PARTITION expr.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Partition {
@@ -2197,7 +2202,7 @@ impl fmt::Display for Partition {
 }
 
 /// DEDUPLICATE statement used in OPTIMIZE TABLE et al.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Deduplicate {
@@ -2215,7 +2220,7 @@ impl fmt::Display for Deduplicate {
 }
 
 /// CREATE INDEX statement.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateIndex {
@@ -2297,7 +2302,7 @@ impl fmt::Display for CreateIndex {
 }
 
 /// SQL:2016 temporal table system versioning configuration
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateTableSystemVersioning {
@@ -2316,7 +2321,7 @@ impl fmt::Display for CreateTableSystemVersioning {
 }
 
 /// `PARTITION BY { RANGE | LIST | HASH } ( key_def, ... )`
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct PartitionByClause {
@@ -2346,7 +2351,7 @@ impl fmt::Display for PartitionByClause {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum PartitionStrategy {
@@ -2367,7 +2372,7 @@ impl fmt::Display for PartitionStrategy {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OraclePartitionDefinition {
@@ -2386,7 +2391,7 @@ impl fmt::Display for OraclePartitionDefinition {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct PartitionKeyDef {
@@ -2408,7 +2413,7 @@ impl fmt::Display for PartitionKeyDef {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum PartitionKeyExpr {
@@ -2426,7 +2431,7 @@ impl fmt::Display for PartitionKeyExpr {
 }
 
 /// Target partition in a `SPLIT PARTITION ... INTO (...)` statement.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct SplitPartitionTarget {
@@ -2445,7 +2450,7 @@ impl fmt::Display for SplitPartitionTarget {
 }
 
 /// CREATE TABLE statement.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateTable {
@@ -2491,10 +2496,13 @@ pub struct CreateTable {
     pub clustering_by: Option<Vec<OrderByExpr>>,
     /// Declarative horizontal distribution requested for the table.
     pub distribution: Option<TableDistribution>,
+    /// The comments around the statement, see
+    /// [`ParserOptions::with_attach_comments`](crate::parser::ParserOptions::with_attach_comments).
+    pub comments: Comments,
 }
 
 /// Horizontal distribution clause attached to `CREATE TABLE`.
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TableDistribution {
@@ -2512,6 +2520,7 @@ impl fmt::Display for TableDistribution {
 
 impl fmt::Display for CreateTable {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.comments.fmt_leading(f)?;
         // We want to allow the following options
         // Empty column list, allowed by PostgreSQL:
         //   `CREATE TABLE t ()`
@@ -2633,12 +2642,12 @@ impl fmt::Display for CreateTable {
         if let Some(query) = &self.query {
             write!(f, " AS {query}")?;
         }
-        Ok(())
+        self.comments.fmt_trailing(f)
     }
 }
 
 /// CREATE ASSERTION statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateAssertion {
@@ -2659,7 +2668,7 @@ impl fmt::Display for CreateAssertion {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 /// ```sql
@@ -2719,7 +2728,7 @@ impl fmt::Display for CreateDomain {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateFunction {
@@ -2813,10 +2822,14 @@ pub struct CreateFunction {
     pub sql_data_access: Option<SqlDataAccess>,
     /// SQL:2016 POLYMORPHIC keyword for PTF
     pub polymorphic: bool,
+    /// The comments around the statement, see
+    /// [`ParserOptions::with_attach_comments`](crate::parser::ParserOptions::with_attach_comments).
+    pub comments: Comments,
 }
 
 impl fmt::Display for CreateFunction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.comments.fmt_leading(f)?;
         write!(
             f,
             "CREATE {or_alter}{or_replace}{temp}FUNCTION {if_not_exists}{name}",
@@ -2929,14 +2942,14 @@ impl fmt::Display for CreateFunction {
                 write!(f, " AS {bes}")?;
             }
         }
-        Ok(())
+        self.comments.fmt_trailing(f)
     }
 }
 
 /// An `ALTER SCHEMA` (`Statement::AlterSchema`) operation.
 ///
 /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-alterschema.html)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterSchemaOperation {
@@ -2988,7 +3001,7 @@ impl fmt::Display for AlterSchemaOperation {
 /// Note: [MySQL] is the only database that supports the AS keyword for this operation.
 ///
 /// [MySQL]: https://dev.mysql.com/doc/refman/8.4/en/alter-table.html
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum RenameTableNameKind {
@@ -3005,7 +3018,7 @@ impl fmt::Display for RenameTableNameKind {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct AlterSchema {
@@ -3038,7 +3051,7 @@ impl Spanned for RenameTableNameKind {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 /// Whether the syntax used for the trigger object (ROW or STATEMENT) is `FOR` or `FOR EACH`.
@@ -3058,7 +3071,7 @@ impl Display for TriggerObjectKind {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 /// CREATE TRIGGER
@@ -3257,7 +3270,7 @@ impl Display for CreateTrigger {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 /// DROP TRIGGER
@@ -3308,7 +3321,7 @@ impl fmt::Display for DropTrigger {
 /// ```sql
 /// TRUNCATE TABLE table_names [PARTITION (partitions)] [RESTART IDENTITY | CONTINUE IDENTITY] [CASCADE | RESTRICT] [ON CLUSTER cluster_name]
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Truncate {
@@ -3373,7 +3386,7 @@ impl Spanned for Truncate {
 }
 
 /// CREATE VIEW statement.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateView {
@@ -3405,9 +3418,12 @@ pub struct CreateView {
     pub refresh_schedule: Option<MaterializedViewRefreshSchedule>,
     /// Oracle view-specific declaration and constraint clauses.
     pub oracle: Option<OracleCreateViewOptions>,
+    /// The comments around the statement, see
+    /// [`ParserOptions::with_attach_comments`](crate::parser::ParserOptions::with_attach_comments).
+    pub comments: Comments,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleCreateViewOptions {
@@ -3436,7 +3452,7 @@ impl OracleCreateViewOptions {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleCreateMaterializedViewOptions {
@@ -3455,7 +3471,7 @@ impl OracleCreateMaterializedViewOptions {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleMaterializedViewBuild {
@@ -3463,7 +3479,7 @@ pub enum OracleMaterializedViewBuild {
     Deferred,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleObjectView {
@@ -3471,7 +3487,7 @@ pub struct OracleObjectView {
     pub object_identifier: Vec<Expr>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleViewConstraint {
@@ -3481,6 +3497,7 @@ pub enum OracleViewConstraint {
 
 impl fmt::Display for CreateView {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.comments.fmt_leading(f)?;
         write!(
             f,
             "CREATE {or_alter}{or_replace}",
@@ -3602,13 +3619,13 @@ impl fmt::Display for CreateView {
                 }
             }
         }
-        Ok(())
+        self.comments.fmt_trailing(f)
     }
 }
 
 /// CREATE EXTENSION statement
 /// Note: this is a PostgreSQL-specific statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateExtension {
@@ -3662,7 +3679,7 @@ impl Spanned for CreateExtension {
 ///
 /// PostgreSQL Documentation:
 /// <https://www.postgresql.org/docs/current/sql-dropextension.html>
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct DropExtension {
@@ -3693,7 +3710,7 @@ impl Spanned for DropExtension {
 }
 
 /// ALTER TABLE statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct AlterTable {
@@ -3724,7 +3741,7 @@ impl fmt::Display for AlterTable {
 }
 
 /// DROP FUNCTION statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct DropFunction {
@@ -3758,7 +3775,7 @@ impl Spanned for DropFunction {
 
 /// CREATE OPERATOR statement
 /// See <https://www.postgresql.org/docs/current/sql-createoperator.html>
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateOperator {
@@ -3787,7 +3804,7 @@ pub struct CreateOperator {
 }
 
 /// PostgreSQL `CREATE AGGREGATE`.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateAggregate {
@@ -3798,7 +3815,7 @@ pub struct CreateAggregate {
     pub options: Vec<SqlOption>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CreateCastMethod {
@@ -3807,7 +3824,7 @@ pub enum CreateCastMethod {
     Binary,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CreateCastContext {
@@ -3817,7 +3834,7 @@ pub enum CreateCastContext {
 }
 
 /// PostgreSQL `CREATE CAST`.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateCast {
@@ -3828,7 +3845,7 @@ pub struct CreateCast {
 }
 
 /// PostgreSQL `CREATE STATISTICS`.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateStatistics {
@@ -3841,7 +3858,7 @@ pub struct CreateStatistics {
 }
 
 /// PostgreSQL `CREATE TABLE name OF composite_type`.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateTypedTable {
@@ -3853,7 +3870,7 @@ pub struct CreateTypedTable {
 
 /// CREATE OPERATOR FAMILY statement
 /// See <https://www.postgresql.org/docs/current/sql-createopfamily.html>
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateOperatorFamily {
@@ -3865,7 +3882,7 @@ pub struct CreateOperatorFamily {
 
 /// CREATE OPERATOR CLASS statement
 /// See <https://www.postgresql.org/docs/current/sql-createopclass.html>
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateOperatorClass {
@@ -4032,7 +4049,7 @@ impl fmt::Display for CreateOperatorClass {
 }
 
 /// Operator argument types for CREATE OPERATOR CLASS
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OperatorArgTypes {
@@ -4047,7 +4064,7 @@ impl fmt::Display for OperatorArgTypes {
 }
 
 /// An item in a CREATE OPERATOR CLASS statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OperatorClassItem {
@@ -4074,7 +4091,7 @@ pub enum OperatorClassItem {
 }
 
 /// Purpose of an operator in an operator class
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OperatorPurpose {
@@ -4137,7 +4154,7 @@ impl fmt::Display for OperatorPurpose {
 // SQL/PGQ (ISO/IEC 9075-16:2023) Property Graph structures
 
 /// Graph KEY clause: `KEY (column, ...)`
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct GraphKeyClause {
@@ -4155,7 +4172,7 @@ impl fmt::Display for GraphKeyClause {
 /// SQL property graphs can rename an underlying column with `AS`. Expression
 /// properties remain represented by [`Expr`] so consumers can either lower
 /// them or reject them without recovering structure from SQL text.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct GraphPropertyDefinition {
@@ -4174,7 +4191,7 @@ impl fmt::Display for GraphPropertyDefinition {
 }
 
 /// Typed graph-property exposure mode.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum GraphPropertiesClause {
@@ -4205,7 +4222,7 @@ impl fmt::Display for GraphPropertiesClause {
 }
 
 /// Graph edge endpoint: `[KEY (column, ...)] REFERENCES table`
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct GraphEdgeEndpoint {
@@ -4229,7 +4246,7 @@ impl fmt::Display for GraphEdgeEndpoint {
 }
 
 /// Vertex table definition in CREATE PROPERTY GRAPH
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct GraphVertexTableDefinition {
@@ -4257,7 +4274,7 @@ impl fmt::Display for GraphVertexTableDefinition {
 }
 
 /// Edge table definition in CREATE PROPERTY GRAPH
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct GraphEdgeTableDefinition {
@@ -4292,7 +4309,7 @@ impl fmt::Display for GraphEdgeTableDefinition {
 }
 
 /// CREATE PROPERTY GRAPH statement (SQL/PGQ)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreatePropertyGraph {
@@ -4346,7 +4363,7 @@ impl fmt::Display for CreatePropertyGraph {
 }
 
 /// DROP PROPERTY GRAPH statement (SQL/PGQ)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct DropPropertyGraph {
@@ -23,15 +23,16 @@ use crate::ast::Box;
 use core::fmt::{self, Display};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use sqlparser_derive::{AstCodec, HeapSize};
 #[cfg(feature = "visitor")]
 use sqlparser_derive::{Visit, VisitMut};
 
 use crate::display_utils::{indented_list, Indent, SpaceOrNewline};
 
 use super::{
-    display_comma_separated, helpers::attached_token::AttachedToken, Assignment, Expr, FromTable,
-    Ident, InsertAliases, MysqlInsertPriority, ObjectName, OnInsert, OrderByExpr, Query,
-    SelectItem, TableObject, TableWithJoins, UpdateTableFromKind,
+    display_comma_separated, helpers::attached_token::AttachedToken, Assignment, AstVec, Comments,
+    Expr, FromTable, Ident, InsertAliases, MysqlInsertPriority, ObjectName, OnInsert, OrderByExpr,
+    Query, SelectItem, TableObject, TableWithJoins, UpdateTableFromKind,
 };
 
 /// FOR PORTION OF clause used in UPDATE and DELETE statements for temporal tables.
@@ -42,7 +43,7 @@ use super::{
 /// ```sql
 /// FOR PORTION OF period_name FROM start_expr TO end_expr
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ForPortionOf {
@@ -62,7 +63,7 @@ impl Display for ForPortionOf {
 }
 
 /// OVERRIDING { SYSTEM | USER } VALUE clause for INSERT (PostgreSQL).
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OverridingKind {
@@ -81,11 +82,11 @@ impl Display for OverridingKind {
 
 /// Expressions returned by a DML statement and optional targets receiving
 /// those values.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ReturningClause {
-    pub expressions: Vec<SelectItem>,
+    pub expressions: AstVec<SelectItem>,
     pub bulk_collect: bool,
     pub into: Option<Vec<Expr>>,
 }
@@ -107,7 +108,7 @@ impl Display for ReturningClause {
 }
 
 /// INSERT statement.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Insert {
@@ -122,7 +123,7 @@ pub struct Insert {
     /// table_name as foo (for PostgreSQL)
     pub table_alias: Option<Ident>,
     /// COLUMNS
-    pub columns: Vec<Ident>,
+    pub columns: AstVec<Ident>,
     /// OVERRIDING { SYSTEM | USER } VALUE (PostgreSQL)
     pub overriding: Option<OverridingKind>,
     /// OVERWRITE - INSERT OVERWRITE INTO syntax
@@ -149,10 +150,14 @@ pub struct Insert {
     pub insert_alias: Option<InsertAliases>,
     /// Oracle DML error logging.
     pub error_logging: Option<OracleErrorLoggingClause>,
+    /// The comments around the statement, see
+    /// [`ParserOptions::with_attach_comments`](crate::parser::ParserOptions::with_attach_comments).
+    pub comments: Comments,
 }
 
 impl Display for Insert {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.comments.fmt_leading(f)?;
         let table_name = if let Some(alias) = &self.table_alias {
             format!("{0} AS {alias}", self.table)
         } else {
@@ -231,12 +236,12 @@ impl Display for Insert {
             SpaceOrNewline.fmt(f)?;
             error_logging.fmt(f)?;
         }
-        Ok(())
+        self.comments.fmt_trailing(f)
     }
 }
 
 /// DELETE statement.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Delete {
@@ -249,7 +254,7 @@ pub struct Delete {
     /// FOR PORTION OF (SQL:2016 temporal tables)
     pub for_portion_of: Option<ForPortionOf>,
     /// USING (Postgres, MySQL)
-    pub using: Option<Vec<TableWithJoins>>,
+    pub using: Option<AstVec<TableWithJoins>>,
     /// WHERE
     pub selection: Option<Expr>,
     /// RETURNING
@@ -258,10 +263,14 @@ pub struct Delete {
     pub order_by: Vec<OrderByExpr>,
     /// LIMIT (MySQL)
     pub limit: Option<Expr>,
+    /// The comments around the statement, see
+    /// [`ParserOptions::with_attach_comments`](crate::parser::ParserOptions::with_attach_comments).
+    pub comments: Comments,
 }
 
 impl Display for Delete {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.comments.fmt_leading(f)?;
         f.write_str("DELETE")?;
         if !self.tables.is_empty() {
             indented_list(f, &self.tables)?;
@@ -305,12 +314,12 @@ impl Display for Delete {
             SpaceOrNewline.fmt(f)?;
             Indent(limit).fmt(f)?;
         }
-        Ok(())
+        self.comments.fmt_trailing(f)
     }
 }
 
 /// UPDATE statement.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Update {
@@ -321,7 +330,7 @@ pub struct Update {
     /// FOR PORTION OF (SQL:2016 temporal tables)
     pub for_portion_of: Option<ForPortionOf>,
     /// Column assignments
-    pub assignments: Vec<Assignment>,
+    pub assignments: AstVec<Assignment>,
     /// Table which provide value to be set
     pub from: Option<UpdateTableFromKind>,
     /// WHERE
@@ -332,10 +341,14 @@ pub struct Update {
     pub limit: Option<Expr>,
     /// Oracle DML error logging.
     pub error_logging: Option<OracleErrorLoggingClause>,
+    /// The comments around the statement, see
+    /// [`ParserOptions::with_attach_comments`](crate::parser::ParserOptions::with_attach_comments).
+    pub comments: Comments,
 }
 
 impl Display for Update {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.comments.fmt_leading(f)?;
         f.write_str("UPDATE ")?;
         self.table.fmt(f)?;
         if let Some(for_portion_of) = &self.for_portion_of {
@@ -370,11 +383,11 @@ impl Display for Update {
             SpaceOrNewline.fmt(f)?;
             error_logging.fmt(f)?;
         }
-        Ok(())
+        self.comments.fmt_trailing(f)
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleErrorLoggingClause {
@@ -383,7 +396,7 @@ pub struct OracleErrorLoggingClause {
     pub reject_limit: Option<OracleRejectLimit>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleRejectLimit {
@@ -411,7 +424,7 @@ impl Display for OracleErrorLoggingClause {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleMultiTableInsertMode {
@@ -419,7 +432,7 @@ pub enum OracleMultiTableInsertMode {
     First,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleMultiTableInsert {
@@ -429,7 +442,7 @@ pub struct OracleMultiTableInsert {
     pub source: Box<Query>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleMultiTableInsertBranch {
@@ -437,7 +450,7 @@ pub struct OracleMultiTableInsertBranch {
     pub targets: Vec<OracleMultiTableInsertTarget>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleMultiTableInsertTarget {
@@ -25,6 +25,7 @@ use crate::tokenizer::{BorrowedToken, Span, TokenWithSpan};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use sqlparser_derive::{AstCodec, HeapSize};
 #[cfg(feature = "visitor")]
 use sqlparser_derive::{Visit, VisitMut};
 
@@ -79,7 +80,7 @@ use sqlparser_derive::{Visit, VisitMut};
 /// assert_eq!(AttachedToken::from(tok1), AttachedToken::from(tok2)); // attached tokens are
 /// ```
 /// // period @ line 2, column 20
-#[derive(Clone)]
+#[derive(Clone, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct AttachedToken(pub Span, pub Option<Keyword>);
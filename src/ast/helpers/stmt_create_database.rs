@@ -21,10 +21,11 @@ use alloc::{format, string::String};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use sqlparser_derive::{AstCodec, HeapSize};
 #[cfg(feature = "visitor")]
 use sqlparser_derive::{Visit, VisitMut};
 
-use crate::ast::{AttachedToken, ObjectName, Statement};
+use crate::ast::{AttachedToken, Box, CreateDatabase, ObjectName, Statement};
 use crate::parser::ParserError;
 use crate::tokenizer::{Token, TokenWithSpan};
 
@@ -50,7 +51,7 @@ use crate::tokenizer::{Token, TokenWithSpan};
 /// ```
 ///
 /// [1]: Statement::CreateDatabase
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateDatabaseBuilder {
@@ -135,7 +136,7 @@ impl CreateDatabaseBuilder {
     }
 
     pub fn build(self) -> Statement {
-        Statement::CreateDatabase {
+        Statement::CreateDatabase(Box::new(CreateDatabase {
             create_token: AttachedToken::from(TokenWithSpan::wrap(Token::make_word(
                 "CREATE", None,
             ))),
@@ -150,7 +151,7 @@ impl CreateDatabaseBuilder {
             comment: self.comment,
             catalog_sync: self.catalog_sync,
             compatibility: self.compatibility,
-        }
+        }))
     }
 }
 
@@ -159,32 +160,35 @@ impl TryFrom<Statement> for CreateDatabaseBuilder {
 
     fn try_from(stmt: Statement) -> Result<Self, Self::Error> {
         match stmt {
-            Statement::CreateDatabase {
-                create_token: _,
-                db_name,
-                if_not_exists,
-                location,
-                managed_location,
-                owner,
-                or_replace,
-                transient,
-                clone,
-                comment,
-                catalog_sync,
-                compatibility,
-            } => Ok(Self {
-                db_name,
-                if_not_exists,
-                location,
-                managed_location,
-                owner,
-                or_replace,
-                transient,
-                clone,
-                comment,
-                catalog_sync,
-                compatibility,
-            }),
+            Statement::CreateDatabase(create_database) => {
+                let CreateDatabase {
+                    create_token: _,
+                    db_name,
+                    if_not_exists,
+                    location,
+                    managed_location,
+                    owner,
+                    or_replace,
+                    transient,
+                    clone,
+                    comment,
+                    catalog_sync,
+                    compatibility,
+                } = Box::into_owned(create_database);
+                Ok(Self {
+                    db_name,
+                    if_not_exists,
+                    location,
+                    managed_location,
+                    owner,
+                    or_replace,
+                    transient,
+                    clone,
+                    comment,
+                    catalog_sync,
+                    compatibility,
+                })
+            }
             _ => Err(ParserError::ParserError(format!(
                 "Expected create database statement, but received: {stmt}"
             ))),
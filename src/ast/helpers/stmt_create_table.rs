@@ -23,12 +23,13 @@ use crate::ast::Box;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use sqlparser_derive::{AstCodec, HeapSize};
 #[cfg(feature = "visitor")]
 use sqlparser_derive::{Visit, VisitMut};
 
 use crate::ast::ddl::PartitionByClause;
 use crate::ast::{
-    ColumnDef, CommentDef, CreateTable, CreateTableLikeKind, CreateTableOptions,
+    ColumnDef, CommentDef, Comments, CreateTable, CreateTableLikeKind, CreateTableOptions,
     CreateTableSystemVersioning, ObjectName, OnCommit, OrderByExpr, PartitionBoundSpec, Query,
     Statement, TableConstraint, TableDistribution, TableVersion,
 };
@@ -44,13 +45,14 @@ use crate::parser::ParserError;
 /// # Example
 /// ```rust
 /// use sqlparser::ast::helpers::stmt_create_table::CreateTableBuilder;
-/// use sqlparser::ast::{ColumnDef, DataType, Ident, ObjectName};
+/// use sqlparser::ast::{ColumnDef, Comments, DataType, Ident, ObjectName};
 /// let builder = CreateTableBuilder::new(ObjectName::from(vec![Ident::new("table_name")]))
 ///    .if_not_exists(true)
 ///    .columns(vec![ColumnDef {
 ///        name: Ident::new("c1"),
 ///        data_type: DataType::Int(None),
 ///        options: vec![],
+///        comments: Comments::default(),
 /// }]);
 /// // You can access internal elements with ease
 /// assert!(builder.if_not_exists);
@@ -62,7 +64,7 @@ use crate::parser::ParserError;
 /// ```
 ///
 /// [1]: crate::ast::Statement::CreateTable
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateTableBuilder {
@@ -92,6 +94,7 @@ pub struct CreateTableBuilder {
     pub partition_bound: Option<PartitionBoundSpec>,
     pub clustering_by: Option<Vec<OrderByExpr>>,
     pub distribution: Option<TableDistribution>,
+    pub comments: Comments,
 }
 
 impl CreateTableBuilder {
@@ -123,6 +126,7 @@ impl CreateTableBuilder {
             partition_bound: None,
             clustering_by: None,
             distribution: None,
+            comments: Comments::default(),
         }
     }
     pub fn or_replace(mut self, or_replace: bool) -> Self {
@@ -253,7 +257,18 @@ impl CreateTableBuilder {
         self
     }
 
+    pub fn comments(mut self, comments: Comments) -> Self {
+        self.comments = comments;
+        self
+    }
+
     pub fn build(self) -> Statement {
+        self.build_create_table().into()
+    }
+
+    /// Build the `CREATE TABLE` body without boxing it into a [`Statement`],
+    /// so the parser can allocate the box in its own arena.
+    pub(crate) fn build_create_table(self) -> CreateTable {
         CreateTable {
             or_replace: self.or_replace,
             temporary: self.temporary,
@@ -281,8 +296,8 @@ impl CreateTableBuilder {
             partition_bound: self.partition_bound,
             clustering_by: self.clustering_by,
             distribution: self.distribution,
+            comments: self.comments,
         }
-        .into()
     }
 }
 
@@ -293,61 +308,66 @@ impl TryFrom<Statement> for CreateTableBuilder {
     // ownership.
     fn try_from(stmt: Statement) -> Result<Self, Self::Error> {
         match stmt {
-            Statement::CreateTable(CreateTable {
-                or_replace,
-                temporary,
-                external,
-                global,
-                if_not_exists,
-                volatile,
-                dynamic,
-                name,
-                columns,
-                constraints,
-                location,
-                query,
-                without_rowid,
-                like,
-                clone,
-                version,
-                comment,
-                on_commit,
-                inherits,
-                table_options,
-                system_versioning,
-                partition_by,
-                partition_of,
-                partition_bound,
-                clustering_by,
-                distribution,
-            }) => Ok(Self {
-                or_replace,
-                temporary,
-                external,
-                global,
-                if_not_exists,
-                dynamic,
-                name,
-                columns,
-                constraints,
-                location,
-                query,
-                without_rowid,
-                like,
-                clone,
-                version,
-                comment,
-                on_commit,
-                inherits,
-                volatile,
-                table_options,
-                system_versioning,
-                partition_by,
-                partition_of,
-                partition_bound,
-                clustering_by,
-                distribution,
-            }),
+            Statement::CreateTable(create_table) => {
+                let CreateTable {
+                    or_replace,
+                    temporary,
+                    external,
+                    global,
+                    if_not_exists,
+                    volatile,
+                    dynamic,
+                    name,
+                    columns,
+                    constraints,
+                    location,
+                    query,
+                    without_rowid,
+                    like,
+                    clone,
+                    version,
+                    comment,
+                    on_commit,
+                    inherits,
+                    table_options,
+                    system_versioning,
+                    partition_by,
+                    partition_of,
+                    partition_bound,
+                    clustering_by,
+                    distribution,
+                    comments,
+                } = Box::into_owned(create_table);
+                Ok(Self {
+                    or_replace,
+                    temporary,
+                    external,
+                    global,
+                    if_not_exists,
+                    dynamic,
+                    name,
+                    columns,
+                    constraints,
+                    location,
+                    query,
+                    without_rowid,
+                    like,
+                    clone,
+                    version,
+                    comment,
+                    on_commit,
+                    inherits,
+                    volatile,
+                    table_options,
+                    system_versioning,
+                    partition_by,
+                    partition_of,
+                    partition_bound,
+                    clustering_by,
+                    distribution,
+                    comments,
+                })
+            }
             _ => Err(ParserError::ParserError(format!(
                 "Expected create table statement, but received: {stmt}"
             ))),
@@ -16,8 +16,11 @@
 // under the License.
 
 //! SQL Abstract Syntax Tree (AST) types
-pub use crate::arena::AstBox;
 pub(crate) use crate::arena::AstBox as Box;
+pub use crate::arena::{ArenaResident, AstBox, AstStr, AstVec, HeapSize, HeapUsage};
+pub use crate::codec::{
+    AstCodec, AstCodecError, AstDecoder, AstEncoder, AstSchema, AST_CODEC_VERSION,
+};
 #[cfg(not(feature = "std"))]
 use alloc::{
     format,
@@ -37,6 +40,7 @@ use core::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use sqlparser_derive::{AstCodec, HeapSize};
 #[cfg(feature = "visitor")]
 use sqlparser_derive::{Visit, VisitMut};
 
@@ -46,6 +50,7 @@ use crate::{
     keywords::Keyword,
 };
 
+pub use self::comments::{Comments, SourceComment};
 pub use self::data_type::{
     ArrayElemTypeDef, BinaryLength, CharLengthUnits, CharacterLength, DataType, EnumMember,
     ExactNumberInfo, IntervalFields, MdArrayTypeDef, StructBracketKind, TimezoneInfo,
@@ -132,6 +137,7 @@ pub use visitor::*;
 
 pub use self::data_type::GeometricTypeKind;
 
+mod comments;
 mod data_type;
 mod dcl;
 mod ddl;
@@ -201,12 +207,16 @@ fn format_statement_list(f: &mut fmt::Formatter, statements: &[Statement]) -> fm
 }
 
 /// An identifier, decomposed into its value or character data and the quote style.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+#[cfg_attr(
+    feature = "visitor",
+    derive(Visit, VisitMut),
+    visit(with = "visit_ident")
+)]
 pub struct Ident {
     /// The value of the identifier without quotes.
-    pub value: String,
+    pub value: AstStr,
     /// The starting quote if any. Valid quote characters are the single quote,
     /// double quote, backtick, and opening square bracket.
     pub quote_style: Option<char>,
@@ -276,7 +286,7 @@ impl Ident {
     /// Create a new identifier with the given value and no quotes and an empty span.
     pub fn new<S>(value: S) -> Self
     where
-        S: Into<String>,
+        S: Into<AstStr>,
     {
         Ident {
             value: value.into(),
@@ -289,7 +299,7 @@ impl Ident {
     /// panics if the given quote is not a valid quote character.
     pub fn with_quote<S>(quote: char, value: S) -> Self
     where
-        S: Into<String>,
+        S: Into<AstStr>,
     {
         assert!(quote == '\'' || quote == '"' || quote == '`' || quote == '[');
         Ident {
@@ -301,7 +311,7 @@ impl Ident {
 
     pub fn with_span<S>(span: Span, value: S) -> Self
     where
-        S: Into<String>,
+        S: Into<AstStr>,
     {
         Ident {
             value: value.into(),
@@ -312,7 +322,7 @@ impl Ident {
 
     pub fn with_quote_and_span<S>(quote: char, span: Span, value: S) -> Self
     where
-        S: Into<String>,
+        S: Into<AstStr>,
     {
         assert!(quote == '\'' || quote == '"' || quote == '`' || quote == '[');
         Ident {
@@ -326,7 +336,7 @@ impl Ident {
 impl From<&str> for Ident {
     fn from(value: &str) -> Self {
         Ident {
-            value: value.to_string(),
+            value: value.into(),
             quote_style: None,
             span: Span::empty(),
         }
@@ -348,10 +358,10 @@ impl fmt::Display for Ident {
 }
 
 /// A name of a table, view, custom type, etc., possibly multi-part, i.e. db.schema.obj
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
-pub struct ObjectName(pub Vec<ObjectNamePart>);
+pub struct ObjectName(pub AstVec<ObjectNamePart>);
 
 impl From<Vec<Ident>> for ObjectName {
     fn from(idents: Vec<Ident>) -> Self {
@@ -366,7 +376,7 @@ impl fmt::Display for ObjectName {
 }
 
 /// A single part of an ObjectName
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ObjectNamePart {
@@ -395,7 +405,7 @@ impl fmt::Display for ObjectNamePart {
 /// An object name part that consists of a function that dynamically
 /// constructs identifiers.
 ///
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ObjectNamePartFunction {
@@ -412,7 +422,7 @@ impl fmt::Display for ObjectNamePartFunction {
 
 /// Represents an Array Expression, either
 /// `ARRAY[..]`, or `[..]`
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Array {
@@ -443,7 +453,7 @@ impl fmt::Display for Array {
 /// - `x(0:*)` - named dimension with lower bound and open upper bound
 ///
 /// Reference: [SQL/MDA Standard](https://www.iso.org/standard/84807.html)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct MdArrayDimension {
@@ -479,7 +489,7 @@ impl fmt::Display for MdArrayDimension {
 /// - `MDARRAY[x, y] VALUES` - MDARRAY with named dimensions
 ///
 /// Reference: [SQL/MDA Standard](https://www.iso.org/standard/84807.html)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct MdArray {
@@ -508,7 +518,7 @@ impl fmt::Display for MdArray {
 /// The parser does not validate the `<value>`, nor does it ensure
 /// that the `<leading_field>` units >= the units in `<last_field>`,
 /// so the user will have to reject intervals like `HOUR TO YEAR`.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Interval {
@@ -566,7 +576,7 @@ impl fmt::Display for Interval {
 
 /// A field definition within a struct
 ///
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct StructField {
@@ -592,7 +602,7 @@ impl fmt::Display for StructField {
 }
 
 /// Options for `CAST` / `TRY_CAST`
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CastFormat {
@@ -601,7 +611,7 @@ pub enum CastFormat {
 }
 
 /// An element of a JSON path.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum JsonPathElem {
@@ -614,7 +624,7 @@ pub enum JsonPathElem {
 }
 
 /// A JSON path.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct JsonPath {
@@ -648,7 +658,7 @@ impl fmt::Display for JsonPath {
 }
 
 /// The syntax used for in a cast expression.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CastKind {
@@ -665,7 +675,7 @@ pub enum CastKind {
 /// `MATCH` type for constraint references
 ///
 /// See: <https://www.postgresql.org/docs/current/sql-createtable.html#SQL-CREATETABLE-PARMS-REFERENCES>
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ConstraintReferenceMatchKind {
@@ -691,7 +701,7 @@ impl fmt::Display for ConstraintReferenceMatchKind {
 ///
 /// The `EXTRACT` expression can support either the `from` syntax
 /// or the comma syntax.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ExtractSyntax {
@@ -709,7 +719,7 @@ pub enum ExtractSyntax {
 ///
 /// Other dialects either support `CEIL/FLOOR( <expr> [, <scale>])` format or just
 /// `CEIL/FLOOR(<expr>)`.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CeilFloorKind {
@@ -724,7 +734,7 @@ pub enum CeilFloorKind {
 /// Used in SQL/PGQ for path element quantification:
 /// - `ALL(x IN e | predicate)` - true if predicate holds for all elements
 /// - `ANY(x IN e | predicate)` - true if predicate holds for any element
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum QuantifiedPredicateKind {
@@ -743,7 +753,7 @@ impl fmt::Display for QuantifiedPredicateKind {
 
 /// A WHEN clause in a CASE expression containing both
 /// the condition and its corresponding result
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CaseWhen {
@@ -762,6 +772,44 @@ impl fmt::Display for CaseWhen {
     }
 }
 
+/// `CASE [<operand>] WHEN <condition> THEN <result> ... [ELSE <result>] END`
+///
+/// Note we only recognize a complete single expression as `<condition>`,
+/// not `< 0` nor `1, 2, 3` as allowed in a `<simple when clause>` per
+/// <https://jakewheat.github.io/sql-overview/sql-2011-foundation-grammar.html#simple-when-clause>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CaseExpr {
+    pub case_token: AttachedToken,
+    pub end_token: AttachedToken,
+    pub operand: Option<Box<Expr>>,
+    pub conditions: AstVec<CaseWhen>,
+    pub else_result: Option<Box<Expr>>,
+}
+
+impl fmt::Display for CaseExpr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("CASE")?;
+        if let Some(operand) = &self.operand {
+            f.write_str(" ")?;
+            operand.fmt(f)?;
+        }
+        for when in &self.conditions {
+            SpaceOrNewline.fmt(f)?;
+            Indent(when).fmt(f)?;
+        }
+        if let Some(else_result) = &self.else_result {
+            SpaceOrNewline.fmt(f)?;
+            Indent("ELSE").fmt(f)?;
+            SpaceOrNewline.fmt(f)?;
+            Indent(Indent(else_result)).fmt(f)?;
+        }
+        SpaceOrNewline.fmt(f)?;
+        f.write_str("END")
+    }
+}
+
 /// An SQL expression of any type.
 ///
 /// # Semantics / Type Checking
@@ -775,7 +823,7 @@ impl fmt::Display for CaseWhen {
 ///
 /// # Equality and Hashing Does not Include Source Locations
 /// SQL:2016 T461: BETWEEN SYMMETRIC/ASYMMETRIC modifier
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum BetweenSymmetric {
@@ -802,7 +850,7 @@ impl fmt::Display for BetweenSymmetric {
 /// of the expression (not bitwise comparison). This means that `Expr` instances
 /// that are semantically equivalent but have different spans (locations in the
 /// source tree) will compare as equal.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "visitor",
@@ -811,9 +859,9 @@ impl fmt::Display for BetweenSymmetric {
 )]
 pub enum Expr {
     /// Identifier e.g. table name or column name
-    Identifier(Ident),
+    Identifier(Box<Ident>),
     /// Multi-part identifier, e.g. `table_alias.column` or `schema.table.col`
-    CompoundIdentifier(Vec<Ident>),
+    CompoundIdentifier(AstVec<Ident>),
     /// Multi-part expression access.
     ///
     /// This structure represents an access chain in structured / nested types
@@ -848,49 +896,49 @@ pub enum Expr {
     IsFalse {
         expr: Box<Expr>,
         /// Token for the last keyword in the expression (for span tracking)
-        suffix_token: AttachedToken,
+        suffix_token: Box<AttachedToken>,
     },
     /// `IS NOT FALSE` operator
     IsNotFalse {
         expr: Box<Expr>,
         /// Token for the last keyword in the expression (for span tracking)
-        suffix_token: AttachedToken,
+        suffix_token: Box<AttachedToken>,
     },
     /// `IS TRUE` operator
     IsTrue {
         expr: Box<Expr>,
         /// Token for the last keyword in the expression (for span tracking)
-        suffix_token: AttachedToken,
+        suffix_token: Box<AttachedToken>,
     },
     /// `IS NOT TRUE` operator
     IsNotTrue {
         expr: Box<Expr>,
         /// Token for the last keyword in the expression (for span tracking)
-        suffix_token: AttachedToken,
+        suffix_token: Box<AttachedToken>,
     },
     /// `IS NULL` operator
     IsNull {
         expr: Box<Expr>,
         /// Token for the last keyword in the expression (for span tracking)
-        suffix_token: AttachedToken,
+        suffix_token: Box<AttachedToken>,
     },
     /// `IS NOT NULL` operator
     IsNotNull {
         expr: Box<Expr>,
         /// Token for the last keyword in the expression (for span tracking)
-        suffix_token: AttachedToken,
+        suffix_token: Box<AttachedToken>,
     },
     /// `IS UNKNOWN` operator
     IsUnknown {
         expr: Box<Expr>,
         /// Token for the last keyword in the expression (for span tracking)
-        suffix_token: AttachedToken,
+        suffix_token: Box<AttachedToken>,
     },
     /// `IS NOT UNKNOWN` operator
     IsNotUnknown {
         expr: Box<Expr>,
         /// Token for the last keyword in the expression (for span tracking)
-        suffix_token: AttachedToken,
+        suffix_token: Box<AttachedToken>,
     },
     /// `IS DISTINCT FROM` operator
     IsDistinctFrom(Box<Expr>, Box<Expr>),
@@ -948,7 +996,7 @@ pub enum Expr {
         /// Whether the predicate is negated (IS NOT LABELED)
         negated: bool,
         /// The label to test for
-        label: Ident,
+        label: Box<Ident>,
     },
     /// `<node_expr> IS SOURCE OF <edge_expr>`
     ///
@@ -1043,7 +1091,7 @@ pub enum Expr {
         /// ALL or ANY
         quantifier: QuantifiedPredicateKind,
         /// Bound variable(s): x or (x, y)
-        variables: OneOrManyWithParens<Ident>,
+        variables: Box<OneOrManyWithParens<Ident>>,
         /// The collection expression: e
         collection: Box<Expr>,
         /// The predicate expression: x.active = true
@@ -1052,7 +1100,7 @@ pub enum Expr {
     /// `[ NOT ] IN (val1, val2, ...)`
     InList {
         expr: Box<Expr>,
-        list: Vec<Expr>,
+        list: AstVec<Expr>,
         negated: bool,
     },
     /// `[ NOT ] IN (SELECT ...)`
@@ -1150,9 +1198,9 @@ pub enum Expr {
         /// The expression to convert
         expr: Box<Expr>,
         /// The target data type
-        data_type: Option<DataType>,
+        data_type: Option<Box<DataType>>,
         /// The target character encoding
-        charset: Option<ObjectName>,
+        charset: Option<Box<ObjectName>>,
         /// whether the target comes before the expr (MSSQL syntax)
         target_before_value: bool,
         /// How to translate the expression.
@@ -1164,9 +1212,9 @@ pub enum Expr {
     Cast {
         kind: CastKind,
         expr: Box<Expr>,
-        data_type: DataType,
+        data_type: Box<DataType>,
         /// Optional CAST(string_expression AS type FORMAT format_string_expression)
-        format: Option<CastFormat>,
+        format: Option<Box<CastFormat>>,
     },
     /// AT a timestamp to a different timezone e.g. `FROM_UNIXTIME(0) AT TIME ZONE 'UTC-06:00'`
     AtTimeZone {
@@ -1185,7 +1233,7 @@ pub enum Expr {
     /// Oracle `TREAT(expr AS type)` expression.
     OracleTreat {
         expr: Box<Expr>,
-        data_type: DataType,
+        data_type: Box<DataType>,
     },
     /// Oracle cursor expression.
     Cursor(Box<Query>),
@@ -1280,11 +1328,11 @@ pub enum Expr {
     /// Nested expression e.g. `(foo > bar)` or `(1)`
     Nested(Box<Expr>),
     /// A literal value, such as string, number, date or NULL
-    Value(ValueWithSpan),
+    Value(Box<ValueWithSpan>),
     /// Prefixed expression, e.g. introducer strings, projection prefix
     /// <https://dev.mysql.com/doc/refman/8.0/en/charset-introducer.html>
     Prefixed {
-        prefix: Ident,
+        prefix: Box<Ident>,
         /// The value of the constant.
         /// Hint: you can unwrap the string value using `value.into_string()`.
         value: Box<Expr>,
@@ -1292,9 +1340,9 @@ pub enum Expr {
     /// A constant of form `<data_type> 'value'`.
     /// This can represent ANSI SQL `DATE`, `TIME`, and `TIMESTAMP` literals (such as `DATE '2020-01-01'`),
     /// as well as constants of other types (a non-standard PostgreSQL extension).
-    TypedString(TypedString),
+    TypedString(Box<TypedString>),
     /// Scalar function call e.g. `LEFT(foo, 5)`
-    Function(Function),
+    Function(Box<Function>),
     /// Oracle ordered aggregate selection.
     OracleKeep {
         aggregate: Box<Expr>,
@@ -1321,9 +1369,9 @@ pub enum Expr {
         /// The expression to serialize
         expr: Box<Expr>,
         /// The target data type
-        as_type: DataType,
+        as_type: Box<DataType>,
         /// Optional encoding
-        encoding: Option<Ident>,
+        encoding: Option<Box<Ident>>,
         /// Optional version string
         version: Option<String>,
         /// Optional indentation (true for INDENT, false for NO INDENT, None for neither)
@@ -1334,7 +1382,7 @@ pub enum Expr {
     /// See SQL:2016 standard, X-Series (XML processing instruction).
     XmlPi {
         /// The target name
-        name: Ident,
+        name: Box<Ident>,
         /// Optional content expression
         content: Option<Box<Expr>>,
     },
@@ -1357,17 +1405,7 @@ pub enum Expr {
         elements: Vec<XmlForestElement>,
     },
     /// `CASE [<operand>] WHEN <condition> THEN <result> ... [ELSE <result>] END`
-    ///
-    /// Note we only recognize a complete single expression as `<condition>`,
-    /// not `< 0` nor `1, 2, 3` as allowed in a `<simple when clause>` per
-    /// <https://jakewheat.github.io/sql-overview/sql-2011-foundation-grammar.html#simple-when-clause>
-    Case {
-        case_token: AttachedToken,
-        end_token: AttachedToken,
-        operand: Option<Box<Expr>>,
-        conditions: Vec<CaseWhen>,
-        else_result: Option<Box<Expr>>,
-    },
+    Case(Box<CaseExpr>),
     /// An exists expression `[ NOT ] EXISTS(SELECT ...)`, used in expressions like
     /// `WHERE [ NOT ] EXISTS (SELECT ...)`.
     Exists {
@@ -1384,7 +1422,7 @@ pub enum Expr {
     /// The `ROLLUP` expr.
     Rollup(Vec<Vec<Expr>>),
     /// ROW / TUPLE a single value, such as `SELECT (1, 2)`
-    Tuple(Vec<Expr>),
+    Tuple(AstVec<Expr>),
     /// `Struct` literal expression
     /// Syntax:
     /// ```sql
@@ -1404,7 +1442,7 @@ pub enum Expr {
     /// SQL/MDA (ISO/IEC 9075-15) provides multi-dimensional array support.
     MdArray(MdArray),
     /// An interval expression e.g. `INTERVAL '1' YEAR`
-    Interval(Interval),
+    Interval(Box<Interval>),
     /// `MySQL` specific text search function [(1)].
     ///
     /// Syntax:
@@ -1419,14 +1457,14 @@ pub enum Expr {
         /// `(<col>, <col>, ...)`.
         columns: Vec<ObjectName>,
         /// `<expr>`.
-        match_value: Value,
+        match_value: Box<Value>,
         /// `<search modifier>`
         opt_search_modifier: Option<SearchModifier>,
     },
     Wildcard(AttachedToken),
     /// Qualified wildcard, e.g. `alias.*` or `schema.table.*`.
     /// (Same caveats apply to `QualifiedWildcard` as to `Wildcard`.)
-    QualifiedWildcard(ObjectName, AttachedToken),
+    QualifiedWildcard(ObjectName, Box<AttachedToken>),
     /// Some dialects support an older syntax for outer joins where columns are
     /// marked with the `(+)` operator in the WHERE clause, for example:
     ///
@@ -1450,7 +1488,7 @@ pub enum Expr {
     /// param -> expr | (param1, ...) -> expr
     /// ```
     ///
-    Lambda(LambdaFunction),
+    Lambda(Box<LambdaFunction>),
     /// Checks membership of a value in a JSON array
     MemberOf(MemberOf),
     /// Oracle object collection membership predicate.
@@ -1475,19 +1513,27 @@ pub enum Expr {
     ///
     /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-update.html)
     CurrentOf {
-        cursor_name: Ident,
+        cursor_name: Box<Ident>,
     },
+    /// Placeholder for an expression that failed to parse, carrying the span
+    /// of the tokens skipped in its place, which may be empty.
+    ///
+    /// Only produced by [`Parser::parse_statements_tolerant`]. Displays as
+    /// nothing.
+    ///
+    /// [`Parser::parse_statements_tolerant`]: crate::parser::Parser::parse_statements_tolerant
+    Error(AttachedToken),
 }
 
 impl Expr {
     /// Creates a new [`Expr::Value`]
     pub fn value(value: impl Into<ValueWithSpan>) -> Self {
-        Expr::Value(value.into())
+        Expr::Value(Box::new(value.into()))
     }
 }
 
 /// The contents inside the `[` and `]` in a subscript expression.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Subscript {
@@ -1564,7 +1610,7 @@ impl fmt::Display for Subscript {
 
 /// An element of a [`Expr::CompoundFieldAccess`].
 /// It can be an expression or a subscript.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AccessExpr {
@@ -1584,7 +1630,7 @@ impl fmt::Display for AccessExpr {
 }
 
 /// A lambda function.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct LambdaFunction {
@@ -1622,7 +1668,7 @@ impl fmt::Display for LambdaFunction {
 ///
 /// assert_eq!(one.into_iter().chain(many).collect::<Vec<_>>(), vec!["a", "a", "b"] );
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OneOrManyWithParens<T> {
@@ -2295,31 +2341,7 @@ impl fmt::Display for Expr {
                 }
                 write!(f, ")")
             }
-            Expr::Case {
-                case_token: _,
-                end_token: _,
-                operand,
-                conditions,
-                else_result,
-            } => {
-                f.write_str("CASE")?;
-                if let Some(operand) = operand {
-                    f.write_str(" ")?;
-                    operand.fmt(f)?;
-                }
-                for when in conditions {
-                    SpaceOrNewline.fmt(f)?;
-                    Indent(when).fmt(f)?;
-                }
-                if let Some(else_result) = else_result {
-                    SpaceOrNewline.fmt(f)?;
-                    Indent("ELSE").fmt(f)?;
-                    SpaceOrNewline.fmt(f)?;
-                    Indent(Indent(else_result)).fmt(f)?;
-                }
-                SpaceOrNewline.fmt(f)?;
-                f.write_str("END")
-            }
+            Expr::Case(case) => case.fmt(f),
             Expr::Exists { subquery, negated } => write!(
                 f,
                 "{}EXISTS ({})",
@@ -2519,11 +2541,12 @@ impl fmt::Display for Expr {
             Expr::CurrentOf { cursor_name } => {
                 write!(f, "CURRENT OF {cursor_name}")
             }
+            Expr::Error(_) => Ok(()),
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum WindowType {
@@ -2547,7 +2570,7 @@ impl Display for WindowType {
 }
 
 /// A window specification (i.e. `OVER ([window_name] PARTITION BY .. ORDER BY .. etc.)`)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct WindowSpec {
@@ -2559,9 +2582,9 @@ pub struct WindowSpec {
     /// [2]: https://www.postgresql.org/docs/current/sql-expressions.html#SYNTAX-WINDOW-FUNCTIONS
     pub window_name: Option<Ident>,
     /// `OVER (PARTITION BY ...)`
-    pub partition_by: Vec<Expr>,
+    pub partition_by: AstVec<Expr>,
     /// `OVER (ORDER BY ...)`
-    pub order_by: Vec<OrderByExpr>,
+    pub order_by: AstVec<OrderByExpr>,
     /// `OVER (window frame)`
     pub window_frame: Option<WindowFrame>,
 }
@@ -2620,7 +2643,7 @@ impl fmt::Display for WindowSpec {
 ///
 /// Note: The parser does not validate the specified bounds; the caller should
 /// reject invalid bounds like `ROWS UNBOUNDED FOLLOWING` before execution.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct WindowFrame {
@@ -2648,7 +2671,7 @@ impl Default for WindowFrame {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum WindowFrameUnits {
@@ -2670,7 +2693,7 @@ impl fmt::Display for WindowFrameUnits {
 /// Specifies the EXCLUDE clause for a window frame.
 ///
 /// See SQL:2016 section 7.11 (window clause), part T612-15.
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum WindowFrameExclude {
@@ -2698,7 +2721,7 @@ impl fmt::Display for WindowFrameExclude {
 /// Specifies Ignore / Respect NULL within window functions.
 /// For example
 /// `FIRST_VALUE(column2) IGNORE NULLS OVER (PARTITION BY column1)`
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum NullTreatment {
@@ -2717,7 +2740,7 @@ impl fmt::Display for NullTreatment {
 
 /// Specifies whether NTH_VALUE counts from the first or last row.
 /// SQL:2016 feature T619.
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum NthValueOrder {
@@ -2735,7 +2758,7 @@ impl fmt::Display for NthValueOrder {
 }
 
 /// Specifies [WindowFrame]'s `start_bound` and `end_bound`
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum WindowFrameBound {
@@ -2759,7 +2782,7 @@ impl fmt::Display for WindowFrameBound {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AddDropSync {
@@ -2778,7 +2801,7 @@ impl fmt::Display for AddDropSync {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ShowCreateObject {
@@ -2803,7 +2826,7 @@ impl fmt::Display for ShowCreateObject {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CommentObject {
@@ -2830,7 +2853,7 @@ impl fmt::Display for CommentObject {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Password {
@@ -2852,7 +2875,7 @@ pub enum Password {
 /// END CASE;
 /// ```
 ///
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CaseStatement {
@@ -2866,7 +2889,7 @@ pub struct CaseStatement {
     pub end_case_token: AttachedToken,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleCaseControl {
@@ -2965,7 +2988,7 @@ impl fmt::Display for CaseStatement {
 /// IF 1=1 SELECT 1 ELSE SELECT 2
 /// ```
 /// [MSSQL](https://learn.microsoft.com/en-us/sql/t-sql/language-elements/if-else-transact-sql?view=sql-server-ver16)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct IfStatement {
@@ -3013,7 +3036,7 @@ impl fmt::Display for IfStatement {
 /// ```
 ///
 /// [MsSql](https://learn.microsoft.com/en-us/sql/t-sql/language-elements/while-transact-sql)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct WhileStatement {
@@ -3082,7 +3105,7 @@ impl fmt::Display for WhileStatement {
 ///
 /// [MySQL](https://dev.mysql.com/doc/refman/8.0/en/loop.html)
 /// [SQL:2016](https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#loop-statement)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct LoopStatement {
@@ -3118,7 +3141,7 @@ impl fmt::Display for LoopStatement {
 /// ```
 ///
 /// [SQL:2016](https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#sql-compound-statement)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct LabeledBlock {
@@ -3187,7 +3210,7 @@ impl fmt::Display for LabeledBlock {
 ///
 /// [MySQL](https://dev.mysql.com/doc/refman/8.0/en/repeat.html)
 /// [SQL:2016](https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#repeat-statement)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct RepeatStatement {
@@ -3215,7 +3238,7 @@ impl fmt::Display for RepeatStatement {
 }
 
 /// Variants of FOR loop iteration
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ForLoopVariant {
@@ -3270,7 +3293,7 @@ pub enum ForLoopVariant {
 ///
 /// [SQL:2016](https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#for-statement)
 /// [PostgreSQL FOR](https://www.postgresql.org/docs/current/plpgsql-control-structures.html#PLPGSQL-INTEGER-FOR)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ForStatement {
@@ -3364,7 +3387,7 @@ impl fmt::Display for ForStatement {
 ///
 /// [MySQL](https://dev.mysql.com/doc/refman/8.0/en/leave.html)
 /// [SQL:2016](https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#leave-statement)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct LeaveStatement {
@@ -3391,7 +3414,7 @@ impl fmt::Display for LeaveStatement {
 ///
 /// [MySQL](https://dev.mysql.com/doc/refman/8.0/en/iterate.html)
 /// [SQL:2016](https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#iterate-statement)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct IterateStatement {
@@ -3426,7 +3449,7 @@ impl fmt::Display for IterateStatement {
 /// ```
 ///
 /// [PostgreSQL FOREACH](https://www.postgresql.org/docs/current/plpgsql-control-structures.html#PLPGSQL-FOREACH-ARRAY)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ForeachStatement {
@@ -3480,7 +3503,7 @@ impl fmt::Display for ForeachStatement {
 /// ```
 ///
 /// [PostgreSQL EXIT](https://www.postgresql.org/docs/current/plpgsql-control-structures.html#PLPGSQL-STATEMENTS-EXITING-LOOP)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ExitStatement {
@@ -3511,7 +3534,7 @@ impl fmt::Display for ExitStatement {
 /// ```
 ///
 /// [PostgreSQL CONTINUE](https://www.postgresql.org/docs/current/plpgsql-control-structures.html#PLPGSQL-STATEMENTS-EXITING-LOOP)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ContinueStatement {
@@ -3537,7 +3560,7 @@ impl fmt::Display for ContinueStatement {
 /// A `GET DIAGNOSTICS` statement
 ///
 /// [SQL:2016](https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#get-diagnostics-statement)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct GetDiagnosticsStatement {
@@ -3563,7 +3586,7 @@ impl fmt::Display for GetDiagnosticsStatement {
 }
 
 /// The kind of GET DIAGNOSTICS: statement or condition
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum GetDiagnosticsKind {
@@ -3608,7 +3631,7 @@ impl fmt::Display for GetDiagnosticsKind {
 }
 
 /// An assignment in a GET DIAGNOSTICS statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct DiagnosticsAssignment {
@@ -3623,7 +3646,7 @@ impl fmt::Display for DiagnosticsAssignment {
 }
 
 /// A diagnostics item in a GET DIAGNOSTICS statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DiagnosticsItem {
@@ -3678,7 +3701,7 @@ impl fmt::Display for DiagnosticsItem {
 ///    FETCH NEXT FROM c1 INTO @var1, @var2;
 /// END
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ConditionalStatementBlock {
@@ -3722,7 +3745,7 @@ impl fmt::Display for ConditionalStatementBlock {
 }
 
 /// A list of statements in a [ConditionalStatementBlock].
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ConditionalStatements {
@@ -3756,7 +3779,7 @@ impl fmt::Display for ConditionalStatements {
 }
 
 /// Cursor scrollability option
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CursorScrollOption {
@@ -3769,7 +3792,7 @@ pub enum CursorScrollOption {
 }
 
 /// SQL/PSM cursor parameter
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CursorParameter {
@@ -3778,7 +3801,7 @@ pub struct CursorParameter {
 }
 
 /// Cursor declaration for SQL/PSM
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct SqlPsmCursorDeclaration {
@@ -3789,7 +3812,7 @@ pub struct SqlPsmCursorDeclaration {
 
 /// SQL/PSM data type specification.
 /// Supports standard types plus %TYPE and %ROWTYPE references.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SqlPsmDataType {
@@ -3806,7 +3829,7 @@ pub enum SqlPsmDataType {
 }
 
 /// The assignment operator used for default values in declarations.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DeclarationAssignmentOperator {
@@ -3829,7 +3852,7 @@ impl fmt::Display for DeclarationAssignmentOperator {
 }
 
 /// A SQL/PSM variable declaration in a DECLARE section.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct SqlPsmDeclaration {
@@ -3840,10 +3863,13 @@ pub struct SqlPsmDeclaration {
     pub not_null: bool,
     pub default_operator: Option<DeclarationAssignmentOperator>,
     pub default: Option<Expr>,
+    /// The comments around the declaration, see
+    /// [`ParserOptions::with_attach_comments`](crate::parser::ParserOptions::with_attach_comments).
+    pub comments: Comments,
 }
 
 /// A PL/SQL procedure or function, shared by standalone and nested declarations.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OraclePlSqlRoutine {
@@ -3854,9 +3880,12 @@ pub struct OraclePlSqlRoutine {
     pub return_type: Option<SqlPsmDataType>,
     pub clauses: Vec<OraclePlSqlRoutineClause>,
     pub body: OraclePlSqlRoutineBody,
+    /// The comments around the declaration, see
+    /// [`ParserOptions::with_attach_comments`](crate::parser::ParserOptions::with_attach_comments).
+    pub comments: Comments,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OraclePlSqlRoutineKind {
@@ -3864,7 +3893,7 @@ pub enum OraclePlSqlRoutineKind {
     Function,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OraclePlSqlParameter {
@@ -3876,7 +3905,7 @@ pub struct OraclePlSqlParameter {
     pub default: Option<Expr>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OraclePlSqlRoutineClause {
@@ -3895,7 +3924,7 @@ pub enum OraclePlSqlRoutineClause {
     SqlMacro(Option<Ident>),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OraclePlSqlAuthid {
@@ -3903,7 +3932,7 @@ pub enum OraclePlSqlAuthid {
     Definer,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OraclePlSqlAccessor {
@@ -3911,7 +3940,7 @@ pub struct OraclePlSqlAccessor {
     pub name: ObjectName,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OraclePlSqlRoutineBody {
@@ -3925,7 +3954,7 @@ pub enum OraclePlSqlRoutineBody {
     Declaration,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OraclePlSqlCallSpec {
@@ -3935,7 +3964,7 @@ pub struct OraclePlSqlCallSpec {
     pub parameters: Vec<OraclePlSqlCallSpecParameter>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OraclePlSqlCallSpecParameter {
@@ -3944,7 +3973,7 @@ pub struct OraclePlSqlCallSpecParameter {
     pub data_type: SqlPsmDataType,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleCreatePlSqlRoutine {
@@ -3956,7 +3985,7 @@ pub struct OracleCreatePlSqlRoutine {
     pub routine: OraclePlSqlRoutine,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleCreatePackage {
@@ -3974,7 +4003,7 @@ pub struct OracleCreatePackage {
     pub end_name: Option<Ident>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OraclePackageClause {
@@ -3984,7 +4013,7 @@ pub enum OraclePackageClause {
     Sharing(Ident),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OraclePlSqlUnitKind {
@@ -3996,7 +4025,7 @@ pub enum OraclePlSqlUnitKind {
     Library,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleAlterPlSqlUnit {
@@ -4005,7 +4034,7 @@ pub struct OracleAlterPlSqlUnit {
     pub action: OracleAlterPlSqlUnitAction,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleAlterPlSqlUnitAction {
@@ -4019,7 +4048,7 @@ pub enum OracleAlterPlSqlUnitAction {
     Disable,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OraclePlSqlCompileTarget {
@@ -4027,7 +4056,7 @@ pub enum OraclePlSqlCompileTarget {
     Body,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OraclePlSqlCompileParameter {
@@ -4035,7 +4064,7 @@ pub struct OraclePlSqlCompileParameter {
     pub value: Expr,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleDropPlSqlUnit {
@@ -4045,7 +4074,7 @@ pub struct OracleDropPlSqlUnit {
     pub force: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleCreateTrigger {
@@ -4068,7 +4097,7 @@ pub struct OracleCreateTrigger {
 }
 
 /// An alias declared by an Oracle trigger `REFERENCING` clause.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleTriggerReferencing {
@@ -4077,7 +4106,7 @@ pub struct OracleTriggerReferencing {
     pub alias: Ident,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleTriggerReferencingKind {
@@ -4086,7 +4115,7 @@ pub enum OracleTriggerReferencingKind {
     Parent,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleTriggerTiming {
@@ -4095,7 +4124,7 @@ pub enum OracleTriggerTiming {
     InsteadOf,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleTriggerCrossedition {
@@ -4103,7 +4132,7 @@ pub enum OracleTriggerCrossedition {
     Reverse,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleTriggerOrdering {
@@ -4111,7 +4140,7 @@ pub struct OracleTriggerOrdering {
     pub triggers: Vec<ObjectName>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleTriggerOrderingKind {
@@ -4119,7 +4148,7 @@ pub enum OracleTriggerOrderingKind {
     Precedes,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleTriggerEvent {
@@ -4152,7 +4181,7 @@ pub enum OracleTriggerEvent {
     SetContainer,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleTriggerTarget {
@@ -4163,7 +4192,7 @@ pub enum OracleTriggerTarget {
     PluggableDatabase,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleTriggerBody {
@@ -4175,7 +4204,7 @@ pub enum OracleTriggerBody {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleCompoundTriggerSection {
@@ -4184,7 +4213,7 @@ pub struct OracleCompoundTriggerSection {
     pub block: BeginEndStatements,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleCompoundTriggerTiming {
@@ -4194,7 +4223,7 @@ pub enum OracleCompoundTriggerTiming {
     AfterStatement,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleCreateType {
@@ -4207,7 +4236,7 @@ pub struct OracleCreateType {
     pub definition: OracleTypeDefinition,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleTypeDefinition {
@@ -4225,7 +4254,7 @@ pub enum OracleTypeDefinition {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleObjectTypeElement {
@@ -4236,7 +4265,7 @@ pub enum OracleObjectTypeElement {
     Method(OracleObjectTypeMethod),
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleObjectTypeMethod {
@@ -4244,7 +4273,7 @@ pub struct OracleObjectTypeMethod {
     pub routine: OraclePlSqlRoutine,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleObjectTypeMethodModifier {
@@ -4252,7 +4281,7 @@ pub enum OracleObjectTypeMethodModifier {
     Static,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleAlterType {
@@ -4262,14 +4291,14 @@ pub struct OracleAlterType {
     pub including_table_data: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleAlterTypeAction {
     AddAttributes(Vec<SqlPsmDeclaration>),
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleCreateLibrary {
@@ -4282,7 +4311,7 @@ pub struct OracleCreateLibrary {
     pub credential: Option<ObjectName>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum PlSqlDeclaration {
@@ -4291,35 +4320,74 @@ pub enum PlSqlDeclaration {
         name: Ident,
         data_type: SqlPsmDataType,
         not_null: bool,
+        comments: Comments,
     },
     RecordType {
         name: Ident,
         fields: Vec<SqlPsmDeclaration>,
+        comments: Comments,
     },
     CollectionType {
         name: Ident,
         kind: PlSqlCollectionKind,
         element_type: SqlPsmDataType,
         index_by: Option<SqlPsmDataType>,
+        comments: Comments,
     },
     RefCursorType {
         name: Ident,
         return_type: Option<SqlPsmDataType>,
+        comments: Comments,
     },
     Cursor {
         name: Ident,
         parameters: Vec<SqlPsmDeclaration>,
         return_type: Option<SqlPsmDataType>,
         query: Box<Query>,
+        comments: Comments,
     },
     Exception {
         name: Ident,
+        comments: Comments,
     },
     Pragma(Pragma),
     Routine(Box<OraclePlSqlRoutine>),
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+impl PlSqlDeclaration {
+    /// The comments attached to this declaration, see
+    /// [`ParserOptions::with_attach_comments`](crate::parser::ParserOptions::with_attach_comments).
+    pub fn comments(&self) -> &Comments {
+        match self {
+            Self::Variable(declaration) => &declaration.comments,
+            Self::Pragma(pragma) => &pragma.comments,
+            Self::Routine(routine) => &routine.comments,
+            Self::Subtype { comments, .. }
+            | Self::RecordType { comments, .. }
+            | Self::CollectionType { comments, .. }
+            | Self::RefCursorType { comments, .. }
+            | Self::Cursor { comments, .. }
+            | Self::Exception { comments, .. } => comments,
+        }
+    }
+
+    /// Mutable access to the comments attached to this declaration.
+    pub fn comments_mut(&mut self) -> &mut Comments {
+        match self {
+            Self::Variable(declaration) => &mut declaration.comments,
+            Self::Pragma(pragma) => &mut pragma.comments,
+            Self::Routine(routine) => &mut routine.comments,
+            Self::Subtype { comments, .. }
+            | Self::RecordType { comments, .. }
+            | Self::CollectionType { comments, .. }
+            | Self::RefCursorType { comments, .. }
+            | Self::Cursor { comments, .. }
+            | Self::Exception { comments, .. } => comments,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum PlSqlCollectionKind {
@@ -4327,16 +4395,19 @@ pub enum PlSqlCollectionKind {
     Varray(Expr),
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Pragma {
     pub name: ObjectName,
     pub arguments: Vec<Expr>,
     pub is_eq: bool,
+    /// The comments around the declaration, see
+    /// [`ParserOptions::with_attach_comments`](crate::parser::ParserOptions::with_attach_comments).
+    pub comments: Comments,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct PlSqlFetch {
@@ -4346,7 +4417,7 @@ pub struct PlSqlFetch {
     pub limit: Option<Expr>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct PlSqlForAll {
@@ -4356,7 +4427,7 @@ pub struct PlSqlForAll {
     pub statement: Box<Statement>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum PlSqlForAllBounds {
@@ -4365,7 +4436,7 @@ pub enum PlSqlForAllBounds {
     ValuesOf { collection: Expr },
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct PlSqlExecuteImmediate {
@@ -4376,7 +4447,7 @@ pub struct PlSqlExecuteImmediate {
     pub returning_into: Vec<Expr>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct PlSqlUsingArgument {
@@ -4384,7 +4455,7 @@ pub struct PlSqlUsingArgument {
     pub expr: Expr,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct PlSqlConditionalCompilation {
@@ -4392,7 +4463,7 @@ pub struct PlSqlConditionalCompilation {
     pub else_statements: Vec<Statement>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct PlSqlConditionalCompilationBranch {
@@ -4400,7 +4471,7 @@ pub struct PlSqlConditionalCompilationBranch {
     pub statements: Vec<Statement>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum PlSqlParameterMode {
@@ -4570,6 +4641,7 @@ impl fmt::Display for OraclePlSqlRoutineBody {
 
 impl fmt::Display for OraclePlSqlRoutine {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.comments.fmt_leading(f)?;
         write!(f, "{} {}", self.kind, self.name)?;
         if self.has_parameter_list {
             write!(f, "({})", display_comma_separated(&self.parameters))?;
@@ -4580,7 +4652,8 @@ impl fmt::Display for OraclePlSqlRoutine {
         for clause in &self.clauses {
             write!(f, " {clause}")?;
         }
-        self.body.fmt(f)
+        self.body.fmt(f)?;
+        self.comments.fmt_trailing(f)
     }
 }
 
@@ -5076,49 +5149,71 @@ impl fmt::Display for OracleCreateLibrary {
 
 impl fmt::Display for PlSqlDeclaration {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // The struct payloads display their own comments.
         match self {
             Self::Variable(declaration) => declaration.fmt(f),
+            Self::Pragma(pragma) => pragma.fmt(f),
+            Self::Routine(routine) => routine.fmt(f),
             Self::Subtype {
                 name,
                 data_type,
                 not_null,
+                comments,
             } => {
+                comments.fmt_leading(f)?;
                 write!(f, "SUBTYPE {name} IS {data_type}")?;
                 if *not_null {
                     write!(f, " NOT NULL")?;
                 }
-                Ok(())
+                comments.fmt_trailing(f)
+            }
+            Self::RecordType {
+                name,
+                fields,
+                comments,
+            } => {
+                comments.fmt_leading(f)?;
+                write!(
+                    f,
+                    "TYPE {name} IS RECORD ({})",
+                    display_comma_separated(fields)
+                )?;
+                comments.fmt_trailing(f)
             }
-            Self::RecordType { name, fields } => write!(
-                f,
-                "TYPE {name} IS RECORD ({})",
-                display_comma_separated(fields)
-            ),
             Self::CollectionType {
                 name,
                 kind,
                 element_type,
                 index_by,
+                comments,
             } => {
+                comments.fmt_leading(f)?;
                 write!(f, "TYPE {name} IS {kind} OF {element_type}")?;
                 if let Some(index_by) = index_by {
                     write!(f, " INDEX BY {index_by}")?;
                 }
-                Ok(())
+                comments.fmt_trailing(f)
             }
-            Self::RefCursorType { name, return_type } => {
+            Self::RefCursorType {
+                name,
+                return_type,
+                comments,
+            } => {
+                comments.fmt_leading(f)?;
                 write!(f, "TYPE {name} IS REF CURSOR")?;
                 if let Some(return_type) = return_type {
                     write!(f, " RETURN {return_type}")?;
                 }
-                Ok(())
+                comments.fmt_trailing(f)
             }
             Self::Cursor {
                 name,
                 parameters,
                 return_type,
                 query,
+                comments,
             } => {
+                comments.fmt_leading(f)?;
                 write!(f, "CURSOR {name}")?;
                 if !parameters.is_empty() {
                     write!(f, "({})", display_comma_separated(parameters))?;
@@ -5126,11 +5221,14 @@ impl fmt::Display for PlSqlDeclaration {
                 if let Some(return_type) = return_type {
                     write!(f, " RETURN {return_type}")?;
                 }
-                write!(f, " IS {query}")
+                write!(f, " IS {query}")?;
+                comments.fmt_trailing(f)
+            }
+            Self::Exception { name, comments } => {
+                comments.fmt_leading(f)?;
+                write!(f, "{name} EXCEPTION")?;
+                comments.fmt_trailing(f)
             }
-            Self::Exception { name } => write!(f, "{name} EXCEPTION"),
-            Self::Pragma(pragma) => pragma.fmt(f),
-            Self::Routine(routine) => routine.fmt(f),
         }
     }
 }
@@ -5146,16 +5244,16 @@ impl fmt::Display for PlSqlCollectionKind {
 
 impl fmt::Display for Pragma {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.comments.fmt_leading(f)?;
         write!(f, "PRAGMA {}", self.name)?;
         if !self.arguments.is_empty() {
             if self.is_eq {
-                write!(f, " = {}", self.arguments[0])
+                write!(f, " = {}", self.arguments[0])?;
             } else {
-                write!(f, "({})", display_comma_separated(&self.arguments))
+                write!(f, "({})", display_comma_separated(&self.arguments))?;
             }
-        } else {
-            Ok(())
         }
+        self.comments.fmt_trailing(f)
     }
 }
 
@@ -5290,6 +5388,7 @@ impl fmt::Display for SqlPsmDataType {
 
 impl fmt::Display for SqlPsmDeclaration {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.comments.fmt_leading(f)?;
         write!(f, "{}", self.name)?;
         if self.constant {
             write!(f, " CONSTANT")?;
@@ -5308,7 +5407,7 @@ impl fmt::Display for SqlPsmDeclaration {
                 write!(f, " := {}", default)?;
             }
         }
-        Ok(())
+        self.comments.fmt_trailing(f)
     }
 }
 
@@ -5320,7 +5419,7 @@ impl fmt::Display for SqlPsmDeclaration {
 ///     SELECT 2;
 /// END
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct BeginEndStatements {
@@ -5381,7 +5480,7 @@ impl fmt::Display for BeginEndStatements {
 }
 
 /// RAISE statement level (PL/pgSQL)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum RaiseLevel {
@@ -5407,7 +5506,7 @@ impl fmt::Display for RaiseLevel {
 }
 
 /// USING option for RAISE statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum RaiseOption {
@@ -5439,7 +5538,7 @@ impl fmt::Display for RaiseOption {
 }
 
 /// A USING clause item in RAISE
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct RaiseUsingItem {
@@ -5455,7 +5554,7 @@ impl fmt::Display for RaiseUsingItem {
 }
 
 /// The message part of a RAISE statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum RaiseMessage {
@@ -5485,7 +5584,7 @@ impl fmt::Display for RaiseMessage {
 /// ```
 ///
 /// [PostgreSQL](https://www.postgresql.org/docs/current/plpgsql-errors-and-messages.html)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct RaiseStatement {
@@ -5533,7 +5632,7 @@ impl fmt::Display for RaiseStatement {
 /// SIGNAL SQLSTATE '45000'
 /// SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Custom error'
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct SignalStatement {
@@ -5566,7 +5665,7 @@ impl fmt::Display for SignalStatement {
 /// RESIGNAL
 /// RESIGNAL SQLSTATE '45000'
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ResignalStatement {
@@ -5602,7 +5701,7 @@ impl fmt::Display for ResignalStatement {
 /// MESSAGE_TEXT = 'Custom error'
 /// MYSQL_ERRNO = 1234
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct SignalSetItem {
@@ -5627,7 +5726,7 @@ impl fmt::Display for SignalSetItem {
 /// ```
 ///
 /// [PostgreSQL](https://www.postgresql.org/docs/current/plpgsql-statements.html#PLPGSQL-STATEMENTS-PERFORM)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct PerformStatement {
@@ -5648,7 +5747,7 @@ impl fmt::Display for PerformStatement {
 /// my_var := 42;
 /// result := x + y;
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct SqlPsmAssignment {
@@ -5681,7 +5780,7 @@ impl fmt::Display for SqlPsmAssignment {
 /// END
 /// $$;
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct DoStatement {
@@ -5707,7 +5806,7 @@ impl fmt::Display for DoStatement {
 }
 
 /// The body of a DO statement, either a structured block or raw expression.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DoBody {
@@ -5733,7 +5832,7 @@ impl fmt::Display for DoBody {
 /// DECLARE variable_name := 42
 /// DECLARE variable_name DEFAULT 42
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DeclareAssignment {
@@ -5791,7 +5890,7 @@ impl fmt::Display for DeclareAssignment {
 }
 
 /// Handler type for DECLARE HANDLER statements.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DeclareHandlerType {
@@ -5811,7 +5910,7 @@ impl fmt::Display for DeclareHandlerType {
 }
 
 /// Represents the type of a `DECLARE` statement.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DeclareType {
@@ -5877,7 +5976,7 @@ impl fmt::Display for DeclareType {
 /// ```
 ///
 /// [PostgreSQL]: https://www.postgresql.org/docs/current/sql-declare.html
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Declare {
@@ -6000,7 +6099,7 @@ impl fmt::Display for Declare {
 }
 
 /// Sql options of a `CREATE TABLE` statement.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CreateTableOptions {
@@ -6058,14 +6157,14 @@ impl fmt::Display for CreateTableOptions {
 /// ```sql
 /// [FROM] table
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FromTable {
     /// An explicit `FROM` keyword was specified.
-    WithFromKeyword(Vec<TableWithJoins>),
+    WithFromKeyword(AstVec<TableWithJoins>),
     /// `FROM` keyword was omitted.
-    WithoutKeyword(Vec<TableWithJoins>),
+    WithoutKeyword(AstVec<TableWithJoins>),
 }
 impl Display for FromTable {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -6085,7 +6184,7 @@ impl Display for FromTable {
 /// AS [ PERMISSIVE | RESTRICTIVE ]
 /// ```
 /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createpolicy.html)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CreatePolicyType {
@@ -6098,7 +6197,7 @@ pub enum CreatePolicyType {
 /// FOR [ALL | SELECT | INSERT | UPDATE | DELETE]
 /// ```
 /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createpolicy.html)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CreatePolicyCommand {
@@ -6118,7 +6217,7 @@ pub enum CreatePolicyCommand {
 /// ```
 ///
 /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createpublication.html)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct PublicationTable {
@@ -6153,7 +6252,7 @@ impl fmt::Display for PublicationTable {
 /// ```
 ///
 /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createpublication.html)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum PublicationObject {
@@ -6191,7 +6290,7 @@ impl fmt::Display for PublicationObject {
 /// adds, sets, or drops.
 ///
 /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createpublication.html)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum PublicationForObject {
@@ -6230,7 +6329,7 @@ impl fmt::Display for PublicationForObject {
 /// An action of an `ALTER PUBLICATION` statement.
 ///
 /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-alterpublication.html)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterPublicationAction {
@@ -6247,7 +6346,7 @@ pub enum AlterPublicationAction {
 /// An action of a PostgreSQL `ALTER SUBSCRIPTION` statement.
 ///
 /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-altersubscription.html)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterSubscriptionAction {
@@ -6281,7 +6380,7 @@ pub enum AlterSubscriptionAction {
 /// The event type for a PostgreSQL `CREATE RULE` statement.
 ///
 /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createrule.html)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum RuleEvent {
@@ -6305,7 +6404,7 @@ impl fmt::Display for RuleEvent {
 /// The commands for a PostgreSQL `CREATE RULE` action.
 ///
 /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createrule.html)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum RuleCommands {
@@ -6333,7 +6432,7 @@ impl fmt::Display for RuleCommands {
 /// The action type for a PostgreSQL `CREATE RULE` statement.
 ///
 /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createrule.html)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum RuleAction {
@@ -6358,7 +6457,7 @@ impl fmt::Display for RuleAction {
 /// The type of a PostgreSQL text search object.
 ///
 /// See [PostgreSQL](https://www.postgresql.org/docs/current/textsearch.html)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TextSearchObjectType {
@@ -6380,7 +6479,7 @@ impl fmt::Display for TextSearchObjectType {
 }
 
 /// Wrapper for SET statement with token tracking
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct SetStatement {
@@ -6395,7 +6494,7 @@ impl fmt::Display for SetStatement {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Set {
@@ -6584,7 +6683,7 @@ impl Display for Set {
 /// A representation of a `WHEN` arm with all the identifiers catched and the statements to execute
 /// for the arm.
 ///
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ExceptionWhen {
@@ -6610,7 +6709,7 @@ impl Display for ExceptionWhen {
 }
 
 /// ANALYZE TABLE statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Analyze {
@@ -6666,7 +6765,7 @@ impl fmt::Display for Analyze {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum BackupAuditRetentionValue {
@@ -6693,7 +6792,7 @@ impl fmt::Display for BackupAuditRetentionValue {
 }
 
 /// The admission change requested by a [`Statement::TableMaintenance`] command.
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TableMaintenanceAction {
@@ -6714,7 +6813,7 @@ impl fmt::Display for TableMaintenanceAction {
 
 /// A storage size written as a bare byte count or a count with a unit suffix,
 /// such as `1G`, `512M`, or `67108864`.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct StorageSize {
@@ -6734,7 +6833,7 @@ impl fmt::Display for StorageSize {
 }
 
 /// The growth ceiling of a datafile added by [`AlterTablespaceOperation::AddDatafile`].
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DatafileMaxSize {
@@ -6754,7 +6853,7 @@ impl fmt::Display for DatafileMaxSize {
 }
 
 /// An operation applied by [`Statement::AlterTablespace`].
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterTablespaceOperation {
@@ -6803,7 +6902,7 @@ impl fmt::Display for AlterTablespaceOperation {
 }
 
 /// The admission change requested by a [`Statement::AlterTenant`] command.
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TenantMaintenanceAction {
@@ -6823,7 +6922,7 @@ impl fmt::Display for TenantMaintenanceAction {
 }
 
 /// Datafile encryption-key custody operation.
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum EncryptionKeyOperation {
@@ -6832,8 +6931,7 @@ pub enum EncryptionKeyOperation {
 }
 
 /// A top-level statement (SELECT, INSERT, CREATE, etc.)
-#[allow(clippy::large_enum_variant)]
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "visitor",
@@ -6842,22 +6940,22 @@ pub enum EncryptionKeyOperation {
 )]
 pub enum Statement {
     /// Oracle `CREATE` statement for an Oracle-specific object.
-    OracleCreate(OracleCreateStatement),
+    OracleCreate(Box<OracleCreateStatement>),
     /// Oracle administrative command with a command-specific AST.
-    OracleCommand(OracleCommandStatement),
+    OracleCommand(Box<OracleCommandStatement>),
     /// Oracle `ALTER` statement for an Oracle-specific object.
-    OracleAlter(OracleAlterStatement),
+    OracleAlter(Box<OracleAlterStatement>),
     /// Oracle `DROP` statement for an Oracle-specific schema object.
-    OracleDrop(OracleDropStatement),
+    OracleDrop(Box<OracleDropStatement>),
     /// Oracle `LOCK TABLE` statement.
-    OracleLockTable(OracleLockTable),
+    OracleLockTable(Box<OracleLockTable>),
     /// PostgreSQL `LOCK TABLE` statement.
     PgLockTable(PgLockTable),
     /// ```sql
     /// ANALYZE
     /// ```
     Analyze(Analyze),
-    Set(SetStatement),
+    Set(Box<SetStatement>),
     /// ```sql
     /// SET BACKUP AUDIT RETENTION TO {DEFAULT | <duration>}
     /// ```
@@ -6875,33 +6973,33 @@ pub enum Statement {
     /// ```sql
     /// INSERT
     /// ```
-    Insert(Insert),
+    Insert(Box<Insert>),
     /// A `CASE` statement.
-    Case(CaseStatement),
+    Case(Box<CaseStatement>),
     /// An `IF` statement.
-    If(IfStatement),
+    If(Box<IfStatement>),
     /// A `WHILE` statement.
-    While(WhileStatement),
+    While(Box<WhileStatement>),
     /// A `LOOP` statement.
-    Loop(LoopStatement),
+    Loop(Box<LoopStatement>),
     /// A `REPEAT` statement.
-    Repeat(RepeatStatement),
+    Repeat(Box<RepeatStatement>),
     /// A `FOR` statement (cursor iteration or integer range).
-    For(ForStatement),
+    For(Box<ForStatement>),
     /// A `FOREACH` statement (array iteration).
-    Foreach(ForeachStatement),
+    Foreach(Box<ForeachStatement>),
     /// A `LEAVE` statement (exit loop).
     Leave(LeaveStatement),
     /// An `ITERATE` statement (continue loop).
     Iterate(IterateStatement),
     /// An `EXIT` statement (exit loop with optional condition).
-    Exit(ExitStatement),
+    Exit(Box<ExitStatement>),
     /// A `CONTINUE` statement (continue loop with optional condition).
-    Continue(ContinueStatement),
+    Continue(Box<ContinueStatement>),
     /// A labeled `BEGIN...END` block.
-    LabeledBlock(LabeledBlock),
+    LabeledBlock(Box<LabeledBlock>),
     /// A PL/SQL block with declarations and optional exception handlers.
-    PlSqlBlock(BeginEndStatements),
+    PlSqlBlock(Box<BeginEndStatements>),
     /// A PL/SQL statement prefixed by a `<<label>>`.
     PlSqlLabeled {
         label: Ident,
@@ -6910,19 +7008,19 @@ pub enum Statement {
     /// A PL/SQL `GOTO` statement.
     PlSqlGoto(Ident),
     /// A PL/SQL cursor fetch.
-    PlSqlFetch(PlSqlFetch),
+    PlSqlFetch(Box<PlSqlFetch>),
     /// A PL/SQL bulk DML loop.
-    PlSqlForAll(PlSqlForAll),
+    PlSqlForAll(Box<PlSqlForAll>),
     /// A PL/SQL dynamic SQL statement.
-    PlSqlExecuteImmediate(PlSqlExecuteImmediate),
+    PlSqlExecuteImmediate(Box<PlSqlExecuteImmediate>),
     /// A PL/SQL pipelined-function row emission.
     PlSqlPipeRow(Expr),
     /// A PL/SQL conditional-compilation directive.
     PlSqlConditionalCompilation(PlSqlConditionalCompilation),
     /// A `GET DIAGNOSTICS` statement.
-    GetDiagnostics(GetDiagnosticsStatement),
+    GetDiagnostics(Box<GetDiagnosticsStatement>),
     /// A `RAISE` statement.
-    Raise(RaiseStatement),
+    Raise(Box<RaiseStatement>),
     /// A `SIGNAL` statement.
     Signal(SignalStatement),
     /// A `RESIGNAL` statement.
@@ -6930,13 +7028,13 @@ pub enum Statement {
     /// A `PERFORM` statement (SQL/PSM, PL/pgSQL).
     Perform(PerformStatement),
     /// A SQL/PSM assignment statement using `:=`.
-    SqlPsmAssignment(SqlPsmAssignment),
+    SqlPsmAssignment(Box<SqlPsmAssignment>),
     /// A `DO` statement (PostgreSQL anonymous code block).
     ///
     /// ```sql
     /// DO $$ BEGIN RAISE NOTICE 'Hello'; END $$;
     /// ```
-    Do(DoStatement),
+    Do(Box<DoStatement>),
     /// A `NULL` statement (SQL/PSM no-op).
     ///
     /// Used as a placeholder in control structures when no action is needed.
@@ -6947,15 +7045,15 @@ pub enum Statement {
     /// ```sql
     /// CALL <function>
     /// ```
-    Call(Function),
+    Call(Box<Function>),
     /// A PL/SQL procedure invocation without a `CALL` keyword.
-    PlSqlProcedureCall(Function),
+    PlSqlProcedureCall(Box<Function>),
     /// ```sql
     /// COPY [TO | FROM] ...
     /// ```
     Copy {
         /// The source of 'COPY TO', or the target of 'COPY FROM'
-        source: CopySource,
+        source: Box<CopySource>,
         /// If true, is a 'COPY TO' statement. If false is a 'COPY FROM'
         to: bool,
         /// The target of 'COPY TO', or the source of 'COPY FROM'
@@ -6971,7 +7069,7 @@ pub enum Statement {
     /// OPEN cursor_name
     /// ```
     /// Opens a cursor.
-    Open(OpenStatement),
+    Open(Box<OpenStatement>),
     /// ```sql
     /// CLOSE
     /// ```
@@ -6983,30 +7081,30 @@ pub enum Statement {
     /// ```sql
     /// UPDATE
     /// ```
-    Update(Update),
+    Update(Box<Update>),
     /// ```sql
     /// DELETE
     /// ```
-    Delete(Delete),
+    Delete(Box<Delete>),
     /// ```sql
     /// CREATE VIEW
     /// ```
-    CreateView(CreateView),
+    CreateView(Box<CreateView>),
     /// ```sql
     /// CREATE TABLE
     /// ```
-    CreateTable(CreateTable),
+    CreateTable(Box<CreateTable>),
     /// ```sql
     /// `CREATE INDEX`
     /// ```
-    CreateIndex(CreateIndex),
+    CreateIndex(Box<CreateIndex>),
     /// ```sql
     /// CREATE ROLE
     /// ```
     /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createrole.html)
-    CreateRole(CreateRole),
+    CreateRole(Box<CreateRole>),
     /// A `CREATE SERVER` statement.
-    CreateServer(CreateServerStatement),
+    CreateServer(Box<CreateServerStatement>),
     /// A `ALTER SERVER` statement (SQL/MED).
     /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-alterserver.html)
     AlterServer(AlterServerStatement),
@@ -7015,7 +7113,7 @@ pub enum Statement {
     DropServer(DropServerStatement),
     /// A `CREATE FOREIGN DATA WRAPPER` statement (SQL/MED).
     /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createforeigndatawrapper.html)
-    CreateForeignDataWrapper(CreateForeignDataWrapperStatement),
+    CreateForeignDataWrapper(Box<CreateForeignDataWrapperStatement>),
     /// A `ALTER FOREIGN DATA WRAPPER` statement (SQL/MED).
     /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-alterforeigndatawrapper.html)
     AlterForeignDataWrapper(AlterForeignDataWrapperStatement),
@@ -7024,7 +7122,7 @@ pub enum Statement {
     DropForeignDataWrapper(DropForeignDataWrapperStatement),
     /// A `CREATE FOREIGN TABLE` statement (SQL/MED).
     /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createforeigntable.html)
-    CreateForeignTable(CreateForeignTableStatement),
+    CreateForeignTable(Box<CreateForeignTableStatement>),
     /// A `ALTER FOREIGN TABLE` statement (SQL/MED).
     /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-alterforeigntable.html)
     AlterForeignTable(AlterForeignTableStatement),
@@ -7033,41 +7131,30 @@ pub enum Statement {
     DropForeignTable(DropForeignTableStatement),
     /// A `CREATE USER MAPPING` statement (SQL/MED).
     /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createusermapping.html)
-    CreateUserMapping(CreateUserMappingStatement),
+    CreateUserMapping(Box<CreateUserMappingStatement>),
     /// A `ALTER USER MAPPING` statement (SQL/MED).
     /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-alterusermapping.html)
-    AlterUserMapping(AlterUserMappingStatement),
+    AlterUserMapping(Box<AlterUserMappingStatement>),
     /// A `DROP USER MAPPING` statement (SQL/MED).
     /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-dropusermapping.html)
-    DropUserMapping(DropUserMappingStatement),
+    DropUserMapping(Box<DropUserMappingStatement>),
     /// A `IMPORT FOREIGN SCHEMA` statement (SQL/MED).
     /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-importforeignschema.html)
-    ImportForeignSchema(ImportForeignSchemaStatement),
+    ImportForeignSchema(Box<ImportForeignSchemaStatement>),
     /// ```sql
     /// CREATE POLICY
     /// ```
     /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createpolicy.html)
-    CreatePolicy {
-        #[cfg_attr(feature = "visitor", visit(with = "visit_token"))]
-        token: AttachedToken,
-        name: Ident,
-        #[cfg_attr(feature = "visitor", visit(with = "visit_relation"))]
-        table_name: ObjectName,
-        policy_type: Option<CreatePolicyType>,
-        command: Option<CreatePolicyCommand>,
-        to: Option<Vec<Owner>>,
-        using: Option<Expr>,
-        with_check: Option<Expr>,
-    },
+    CreatePolicy(Box<CreatePolicy>),
     /// ```sql
     /// CREATE OPERATOR
     /// ```
     /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createoperator.html)
-    CreateOperator(CreateOperator),
+    CreateOperator(Box<CreateOperator>),
     /// PostgreSQL `CREATE AGGREGATE`.
     CreateAggregate(CreateAggregate),
     /// PostgreSQL `CREATE CAST`.
-    CreateCast(CreateCast),
+    CreateCast(Box<CreateCast>),
     /// PostgreSQL `CREATE STATISTICS`.
     CreateStatistics(CreateStatistics),
     /// PostgreSQL typed table declaration (`CREATE TABLE name OF type`).
@@ -7081,7 +7168,7 @@ pub enum Statement {
     /// CREATE OPERATOR CLASS
     /// ```
     /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createopclass.html)
-    CreateOperatorClass(CreateOperatorClass),
+    CreateOperatorClass(Box<CreateOperatorClass>),
     /// ```sql
     /// CREATE ASSERTION
     /// ```
@@ -7091,7 +7178,7 @@ pub enum Statement {
     /// CREATE PROPERTY GRAPH
     /// ```
     /// SQL/PGQ (ISO/IEC 9075-16:2023)
-    CreatePropertyGraph(CreatePropertyGraph),
+    CreatePropertyGraph(Box<CreatePropertyGraph>),
     /// ```sql
     /// ALTER TABLE
     /// ```
@@ -7123,7 +7210,7 @@ pub enum Statement {
         /// Materialized view name
         #[cfg_attr(feature = "visitor", visit(with = "visit_relation"))]
         name: ObjectName,
-        operation: AlterMaterializedViewOperation,
+        operation: Box<AlterMaterializedViewOperation>,
     },
     /// ```sql
     /// REFRESH MATERIALIZED VIEW [ CONCURRENTLY ] name [ FAST | COMPLETE ]
@@ -7144,15 +7231,15 @@ pub enum Statement {
     /// ALTER TYPE
     /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-altertype.html)
     /// ```
-    AlterType(AlterType),
+    AlterType(Box<AlterType>),
     /// ```sql
     /// ALTER ROLE
     /// ```
     AlterRole {
         #[cfg_attr(feature = "visitor", visit(with = "visit_token"))]
         token: AttachedToken,
-        name: Ident,
-        operation: AlterRoleOperation,
+        name: Box<Ident>,
+        operation: Box<AlterRoleOperation>,
     },
     /// ```sql
     /// ALTER SYSTEM
@@ -7160,7 +7247,7 @@ pub enum Statement {
     AlterSystem {
         #[cfg_attr(feature = "visitor", visit(with = "visit_token"))]
         token: AttachedToken,
-        operation: AlterConfigurationOperation,
+        operation: Box<AlterConfigurationOperation>,
     },
     /// ```sql
     /// ALTER DATABASE
@@ -7170,7 +7257,7 @@ pub enum Statement {
         token: AttachedToken,
         #[cfg_attr(feature = "visitor", visit(with = "visit_relation"))]
         database_name: ObjectName,
-        operation: AlterConfigurationOperation,
+        operation: Box<AlterConfigurationOperation>,
     },
     /// ```sql
     /// ALTER POLICY <NAME> ON <TABLE NAME> [<OPERATION>]
@@ -7179,10 +7266,10 @@ pub enum Statement {
     AlterPolicy {
         #[cfg_attr(feature = "visitor", visit(with = "visit_token"))]
         token: AttachedToken,
-        name: Ident,
+        name: Box<Ident>,
         #[cfg_attr(feature = "visitor", visit(with = "visit_relation"))]
         table_name: ObjectName,
-        operation: AlterPolicyOperation,
+        operation: Box<AlterPolicyOperation>,
     },
     /// ```sql
     /// ALTER SEQUENCE sequence_name [sequence_options]
@@ -7190,7 +7277,7 @@ pub enum Statement {
     /// SQL:2016 T174: Sequence generator support
     AlterSequence {
         #[cfg_attr(feature = "visitor", visit(with = "visit_token"))]
-        token: AttachedToken,
+        token: Box<AttachedToken>,
         name: ObjectName,
         if_exists: bool,
         sequence_options: Vec<SequenceOptions>,
@@ -7266,7 +7353,7 @@ pub enum Statement {
         #[cfg_attr(feature = "visitor", visit(with = "visit_token"))]
         token: AttachedToken,
         if_exists: bool,
-        name: Ident,
+        name: Box<Ident>,
         table_name: ObjectName,
         drop_behavior: Option<DropBehavior>,
     },
@@ -7280,7 +7367,7 @@ pub enum Statement {
     /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createpublication.html)
     CreatePublication {
         /// Publication name
-        name: Ident,
+        name: Box<Ident>,
         /// `FOR ALL TABLES`, `FOR TABLE ...`, or `FOR TABLES IN SCHEMA ...`.
         /// `None` when the publication is created with no `FOR` clause (an empty
         /// publication that tables are later added to via `ALTER PUBLICATION`).
@@ -7315,16 +7402,7 @@ pub enum Statement {
     /// ```
     ///
     /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createsubscription.html)
-    CreateSubscription {
-        /// Subscription name
-        name: Ident,
-        /// Connection string (single-quoted)
-        connection_string: String,
-        /// List of publication names
-        publications: Vec<Ident>,
-        /// `WITH ( param = value, ... )`
-        with_options: Vec<SqlOption>,
-    },
+    CreateSubscription(Box<CreateSubscription>),
     /// ```sql
     /// ALTER SUBSCRIPTION name action;
     /// ```
@@ -7332,7 +7410,7 @@ pub enum Statement {
     /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-altersubscription.html)
     AlterSubscription {
         /// Subscription name.
-        name: Ident,
+        name: Box<Ident>,
         /// The modification applied to the subscription.
         action: AlterSubscriptionAction,
     },
@@ -7354,11 +7432,11 @@ pub enum Statement {
     /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createrule.html)
     CreateRule {
         or_replace: bool,
-        name: Ident,
+        name: Box<Ident>,
         event: RuleEvent,
         table_name: ObjectName,
-        condition: Option<Expr>,
-        action: RuleAction,
+        condition: Option<Box<Expr>>,
+        action: Box<RuleAction>,
     },
     /// ```sql
     /// DROP RULE [ IF EXISTS ] name ON table_name [ CASCADE | RESTRICT ]
@@ -7414,7 +7492,7 @@ pub enum Statement {
     /// ```
     ///
     /// Note: this is a PostgreSQL-specific statement,
-    CreateExtension(CreateExtension),
+    CreateExtension(Box<CreateExtension>),
     /// ```sql
     /// DROP EXTENSION [ IF EXISTS ] name [, ...] [ CASCADE | RESTRICT ]
     /// ```
@@ -7431,8 +7509,8 @@ pub enum Statement {
     Fetch {
         fetch_token: AttachedToken,
         /// Cursor name
-        name: Ident,
-        direction: FetchDirection,
+        name: Box<Ident>,
+        direction: Box<FetchDirection>,
         /// Optional position - when None, defaults to NEXT
         position: Option<FetchPosition>,
         /// Optional, It's possible to fetch rows form cursor to the table
@@ -7448,7 +7526,7 @@ pub enum Statement {
         direction: FetchDirection,
         /// FROM or IN keyword before cursor name
         position: Option<FetchPosition>,
-        name: Ident,
+        name: Box<Ident>,
     },
     /// ```sql
     /// EXECUTE
@@ -7492,7 +7570,7 @@ pub enum Statement {
     /// Note: this is a Presto-specific statement.
     ShowFunctions {
         show_token: AttachedToken,
-        filter: Option<ShowStatementFilter>,
+        filter: Option<Box<ShowStatementFilter>>,
     },
     /// ```sql
     /// SHOW <variable>
@@ -7511,7 +7589,7 @@ pub enum Statement {
     ShowStatus {
         #[cfg_attr(feature = "visitor", visit(with = "visit_token"))]
         token: AttachedToken,
-        filter: Option<ShowStatementFilter>,
+        filter: Option<Box<ShowStatementFilter>>,
         global: bool,
         session: bool,
     },
@@ -7522,7 +7600,7 @@ pub enum Statement {
     /// Note: this is a MySQL-specific statement.
     ShowVariables {
         show_token: AttachedToken,
-        filter: Option<ShowStatementFilter>,
+        filter: Option<Box<ShowStatementFilter>>,
         global: bool,
         session: bool,
     },
@@ -7543,7 +7621,7 @@ pub enum Statement {
         show_token: AttachedToken,
         extended: bool,
         full: bool,
-        show_options: ShowStatementOptions,
+        show_options: Box<ShowStatementOptions>,
     },
     /// ```sql
     /// SHOW DATABASES
@@ -7552,7 +7630,7 @@ pub enum Statement {
         show_token: AttachedToken,
         terse: bool,
         history: bool,
-        show_options: ShowStatementOptions,
+        show_options: Box<ShowStatementOptions>,
     },
     /// ```sql
     /// SHOW SCHEMAS
@@ -7561,14 +7639,14 @@ pub enum Statement {
         show_token: AttachedToken,
         terse: bool,
         history: bool,
-        show_options: ShowStatementOptions,
+        show_options: Box<ShowStatementOptions>,
     },
     // ```sql
     // SHOW {CHARACTER SET | CHARSET}
     // ```
     // [MySQL]:
     // <https://dev.mysql.com/doc/refman/8.4/en/show.html#:~:text=SHOW%20%7BCHARACTER%20SET%20%7C%20CHARSET%7D%20%5Blike_or_where%5D>
-    ShowCharset(ShowCharset),
+    ShowCharset(Box<ShowCharset>),
     /// ```sql
     /// SHOW TABLES
     /// ```
@@ -7579,7 +7657,7 @@ pub enum Statement {
         extended: bool,
         full: bool,
         external: bool,
-        show_options: ShowStatementOptions,
+        show_options: Box<ShowStatementOptions>,
     },
     /// ```sql
     /// SHOW VIEWS
@@ -7587,7 +7665,7 @@ pub enum Statement {
     ShowViews {
         terse: bool,
         materialized: bool,
-        show_options: ShowStatementOptions,
+        show_options: Box<ShowStatementOptions>,
     },
     /// ```sql
     /// SHOW COLLATION
@@ -7596,7 +7674,7 @@ pub enum Statement {
     /// Note: this is a MySQL-specific statement.
     ShowCollation {
         show_token: AttachedToken,
-        filter: Option<ShowStatementFilter>,
+        filter: Option<Box<ShowStatementFilter>>,
     },
     /// ```sql
     /// `USE ...`
@@ -7613,7 +7691,7 @@ pub enum Statement {
     /// If `begin` is true
     StartTransaction {
         /// The `START` or `BEGIN` token
-        start_token: AttachedToken,
+        start_token: Box<AttachedToken>,
         modes: Vec<TransactionMode>,
         begin: bool,
         transaction: Option<BeginTransactionKind>,
@@ -7671,7 +7749,7 @@ pub enum Statement {
         chain: bool,
         end: bool,
         modifier: Option<TransactionModifier>,
-        oracle: Option<OracleCommitOptions>,
+        oracle: Option<Box<OracleCommitOptions>>,
     },
     /// ```sql
     /// ROLLBACK [ TRANSACTION | WORK ] [ AND [ NO ] CHAIN ] [ TO [ SAVEPOINT ] savepoint_name ]
@@ -7680,7 +7758,7 @@ pub enum Statement {
         /// The `ROLLBACK` token
         rollback_token: AttachedToken,
         chain: bool,
-        savepoint: Option<Ident>,
+        savepoint: Option<Box<Ident>>,
     },
     /// ```sql
     /// CHECKPOINT
@@ -7720,26 +7798,7 @@ pub enum Statement {
     ///     TO {TIMESTAMP '<ts>' | HLC '<n>' | LSN <n>}
     ///     [AS NEW TENANT <name>] [DRY RUN]
     /// ```
-    Restore {
-        /// What to restore: DATABASE or a specific table name
-        object_type: Option<ObjectName>,
-        /// Tenant to restore; mutually exclusive with `object_type`
-        tenant: Option<Ident>,
-        /// Destination tenant for a restore-as-copy (`AS NEW TENANT`);
-        /// only valid together with `tenant`
-        as_new_tenant: Option<Ident>,
-        /// Source S3 URI
-        location: Option<String>,
-        /// Target timestamp (mutually exclusive with the other targets)
-        target_timestamp: Option<String>,
-        /// Target hybrid-logical-clock commit timestamp (mutually exclusive
-        /// with the other targets)
-        target_hlc: Option<u64>,
-        /// Target LSN (mutually exclusive with the other targets)
-        target_lsn: Option<u64>,
-        /// DRY RUN — preview without executing
-        dry_run: bool,
-    },
+    Restore(Box<RestoreStatement>),
     /// ```sql
     /// RECOVER PAGE <page_id> FROM TABLE <table_name>
     /// ```
@@ -7783,9 +7842,9 @@ pub enum Statement {
     /// ```
     CreateSchema {
         /// The `CREATE` token
-        create_token: AttachedToken,
+        create_token: Box<AttachedToken>,
         /// `<schema name> | AUTHORIZATION <schema authorization identifier>  | <schema name>  AUTHORIZATION <schema authorization identifier>`
-        schema_name: SchemaName,
+        schema_name: Box<SchemaName>,
         if_not_exists: bool,
         /// Schema properties.
         ///
@@ -7808,7 +7867,7 @@ pub enum Statement {
         /// CREATE SCHEMA myschema DEFAULT COLLATE 'und:ci';
         /// ```
         ///
-        default_collate_spec: Option<Expr>,
+        default_collate_spec: Option<Box<Expr>>,
         /// Clones a schema
         ///
         /// ```sql
@@ -7820,23 +7879,7 @@ pub enum Statement {
     /// ```sql
     /// CREATE DATABASE
     /// ```
-    CreateDatabase {
-        /// The `CREATE` token
-        create_token: AttachedToken,
-        db_name: ObjectName,
-        if_not_exists: bool,
-        location: Option<String>,
-        managed_location: Option<String>,
-        owner: Option<ObjectName>,
-        or_replace: bool,
-        transient: bool,
-        clone: Option<ObjectName>,
-        comment: Option<String>,
-        catalog_sync: Option<String>,
-        /// Gantry `COMPATIBILITY '<dialect>'` — the SQL dialect the new
-        /// database is permanently bound to.
-        compatibility: Option<String>,
-    },
+    CreateDatabase(Box<CreateDatabase>),
     /// ```sql
     /// CREATE FUNCTION
     /// ```
@@ -7844,54 +7887,54 @@ pub enum Statement {
     /// Supported variants:
     /// 1. [PostgreSQL](https://www.postgresql.org/docs/15/sql-createfunction.html)
     /// 2. [MsSql](https://learn.microsoft.com/en-us/sql/t-sql/statements/create-function-transact-sql)
-    CreateFunction(CreateFunction),
+    CreateFunction(Box<CreateFunction>),
     /// CREATE TRIGGER statement. See struct [CreateTrigger] for details.
-    CreateTrigger(CreateTrigger),
+    CreateTrigger(Box<CreateTrigger>),
     /// DROP TRIGGER statement. See struct [DropTrigger] for details.
     DropTrigger(DropTrigger),
     /// ```sql
     /// CREATE PROCEDURE
     /// ```
-    OracleCreatePlSqlRoutine(OracleCreatePlSqlRoutine),
-    OracleCreatePackage(OracleCreatePackage),
-    OracleCreateTrigger(OracleCreateTrigger),
-    OracleCreateType(OracleCreateType),
+    OracleCreatePlSqlRoutine(Box<OracleCreatePlSqlRoutine>),
+    OracleCreatePackage(Box<OracleCreatePackage>),
+    OracleCreateTrigger(Box<OracleCreateTrigger>),
+    OracleCreateType(Box<OracleCreateType>),
     OracleAlterType(OracleAlterType),
-    OracleCreateLibrary(OracleCreateLibrary),
+    OracleCreateLibrary(Box<OracleCreateLibrary>),
     OracleAlterPlSqlUnit(OracleAlterPlSqlUnit),
     OracleDropPlSqlUnit(OracleDropPlSqlUnit),
     CreateProcedure {
         /// The `CREATE` token
-        create_token: AttachedToken,
+        create_token: Box<AttachedToken>,
         or_alter: bool,
         name: ObjectName,
         params: Option<Vec<ProcedureParam>>,
-        language: Option<Ident>,
+        language: Option<Box<Ident>>,
         security: Option<ProcedureSecurity>,
         set_options: Vec<ProcedureSetConfig>,
         /// Whether AS keyword was used before the body
         has_as: bool,
-        body: ConditionalStatements,
+        body: Box<ConditionalStatements>,
     },
     /// ```sql
     /// ASSERT <condition> [AS <message>]
     /// ```
     Assert {
         assert_token: AttachedToken,
-        condition: Expr,
-        message: Option<Expr>,
+        condition: Box<Expr>,
+        message: Option<Box<Expr>>,
     },
     /// ```sql
     /// GRANT privileges ON objects TO grantees
     /// ```
     Grant {
-        grant_token: AttachedToken,
+        grant_token: Box<AttachedToken>,
         privileges: Privileges,
-        objects: Option<GrantObjects>,
+        objects: Option<Box<GrantObjects>>,
         grantees: Vec<Grantee>,
         with_grant_option: bool,
-        as_grantor: Option<Ident>,
-        granted_by: Option<Ident>,
+        as_grantor: Option<Box<Ident>>,
+        granted_by: Option<Box<Ident>>,
     },
     /// PostgreSQL `ALTER DEFAULT PRIVILEGES`.
     AlterDefaultPrivileges {
@@ -7899,22 +7942,22 @@ pub enum Statement {
         alter_token: AttachedToken,
         target_roles: Vec<Ident>,
         schemas: Vec<ObjectName>,
-        action: AlterDefaultPrivilegesAction,
+        action: Box<AlterDefaultPrivilegesAction>,
     },
     /// ```sql
     /// DENY privileges ON object TO grantees
     /// ```
-    Deny(DenyStatement),
+    Deny(Box<DenyStatement>),
     /// ```sql
     /// REVOKE privileges ON objects FROM grantees
     /// REVOKE GRANT OPTION FOR privileges ON objects FROM grantees
     /// ```
     Revoke {
-        revoke_token: AttachedToken,
+        revoke_token: Box<AttachedToken>,
         privileges: Privileges,
-        objects: Option<GrantObjects>,
+        objects: Option<Box<GrantObjects>>,
         grantees: Vec<Grantee>,
-        granted_by: Option<Ident>,
+        granted_by: Option<Box<Ident>>,
         cascade: Option<CascadeOption>,
         /// If true, this is `REVOKE GRANT OPTION FOR ...` (SQL:2016)
         grant_option_for: bool,
@@ -7932,7 +7975,7 @@ pub enum Statement {
         /// If true, the grantee can grant the role to others
         with_admin_option: bool,
         /// The grantor of the role
-        granted_by: Option<Ident>,
+        granted_by: Option<Box<Ident>>,
     },
     /// ```sql
     /// REVOKE role_name [, role_name ...] FROM user [, user ...] [CASCADE | RESTRICT]
@@ -7946,7 +7989,7 @@ pub enum Statement {
         /// The recipients of the role revocation
         grantees: Vec<Grantee>,
         /// The grantor of the role
-        granted_by: Option<Ident>,
+        granted_by: Option<Box<Ident>>,
         /// Cascade or restrict
         cascade: Option<CascadeOption>,
         /// If true, this is `REVOKE ADMIN OPTION FOR ...`
@@ -7960,7 +8003,7 @@ pub enum Statement {
     Deallocate {
         /// The `DEALLOCATE` token
         deallocate_token: AttachedToken,
-        name: Ident,
+        name: Box<Ident>,
         prepare: bool,
     },
     /// ```sql
@@ -7971,8 +8014,8 @@ pub enum Statement {
     /// MSSQL: <https://learn.microsoft.com/en-us/sql/relational-databases/stored-procedures/execute-a-stored-procedure>
     Execute {
         /// The `EXECUTE` token
-        execute_token: AttachedToken,
-        name: Option<ObjectName>,
+        execute_token: Box<AttachedToken>,
+        name: Option<Box<ObjectName>>,
         parameters: Vec<Expr>,
         has_parentheses: bool,
         /// Is this an `EXECUTE IMMEDIATE`
@@ -7994,7 +8037,7 @@ pub enum Statement {
     Prepare {
         /// The `PREPARE` token
         prepare_token: AttachedToken,
-        name: Ident,
+        name: Box<Ident>,
         data_types: Vec<DataType>,
         statement: Box<Statement>,
     },
@@ -8086,7 +8129,7 @@ pub enum Statement {
     Savepoint {
         /// The `SAVEPOINT` token
         savepoint_token: AttachedToken,
-        name: Ident,
+        name: Box<Ident>,
     },
     /// ```sql
     /// RELEASE [ SAVEPOINT ] savepoint_name
@@ -8094,7 +8137,7 @@ pub enum Statement {
     ReleaseSavepoint {
         /// The `RELEASE` token
         release_token: AttachedToken,
-        name: Ident,
+        name: Box<Ident>,
     },
     /// A `MERGE` statement.
     ///
@@ -8103,30 +8146,30 @@ pub enum Statement {
     /// ```
     /// [MSSQL](https://learn.microsoft.com/en-us/sql/t-sql/statements/merge-transact-sql?view=sql-server-ver16)
     Merge {
-        merge_token: AttachedToken,
+        merge_token: Box<AttachedToken>,
         /// optional INTO keyword
         into: bool,
         /// Specifies the table to merge
-        table: TableFactor,
+        table: Box<TableFactor>,
         /// Specifies the table or subquery to join with the target table
-        source: TableFactor,
+        source: Box<TableFactor>,
         /// Specifies the expression on which to join the target table and source
         on: Box<Expr>,
         /// Specifies the actions to perform when values match or do not match.
         clauses: Vec<MergeClause>,
         // Specifies the output to save changes in MSSQL
-        output: Option<OutputClause>,
+        output: Option<Box<OutputClause>>,
         /// Oracle DML error logging.
-        error_logging: Option<OracleErrorLoggingClause>,
+        error_logging: Option<Box<OracleErrorLoggingClause>>,
     },
     /// Oracle conditional or unconditional multitable INSERT.
     OracleMultiTableInsert(OracleMultiTableInsert),
     /// Oracle private temporary, blockchain, or immutable table.
-    OracleCreateTable(OracleCreateTable),
+    OracleCreateTable(Box<OracleCreateTable>),
     /// Oracle external table declaration.
     OracleCreateExternalTable {
         name: ObjectName,
-        definition: OracleExternalTableDefinition,
+        definition: Box<OracleExternalTableDefinition>,
     },
     /// ```sql
     /// CACHE [ FLAG ] TABLE <table_name> [ OPTIONS('K1' = 'V1', 'K2' = V2) ] [ AS ] [ <query> ]
@@ -8135,7 +8178,7 @@ pub enum Statement {
     /// See [Spark SQL docs] for more details.
     ///
     Cache {
-        cache_token: AttachedToken,
+        cache_token: Box<AttachedToken>,
         /// Table flag
         table_flag: Option<ObjectName>,
         /// Table name
@@ -8164,16 +8207,16 @@ pub enum Statement {
     /// Define a new sequence:
     CreateSequence {
         /// The `CREATE` token
-        create_token: AttachedToken,
+        create_token: Box<AttachedToken>,
         temporary: bool,
         if_not_exists: bool,
         name: ObjectName,
-        data_type: Option<DataType>,
+        data_type: Option<Box<DataType>>,
         sequence_options: Vec<SequenceOptions>,
         owned_by: Option<ObjectName>,
     },
     /// A `CREATE DOMAIN` statement.
-    CreateDomain(CreateDomain),
+    CreateDomain(Box<CreateDomain>),
     /// ```sql
     /// CREATE TYPE <name>
     /// ```
@@ -8188,7 +8231,7 @@ pub enum Statement {
     /// ```
     Pragma {
         pragma_token: AttachedToken,
-        pragma: Pragma,
+        pragma: Box<Pragma>,
     },
     /// ```sql
     /// LOCK TABLES <table_name> [READ [LOCAL] | [LOW_PRIORITY] WRITE]
@@ -8217,16 +8260,7 @@ pub enum Statement {
     /// ```sql
     /// UNLOAD('statement') TO <destination> [ OPTIONS ]
     /// ```
-    Unload {
-        #[cfg_attr(feature = "visitor", visit(with = "visit_token"))]
-        unload_token: AttachedToken,
-        query: Option<Box<Query>>,
-        query_text: Option<String>,
-        to: Ident,
-        auth: Option<IamRoleKind>,
-        with: Vec<SqlOption>,
-        options: Vec<CopyLegacyOption>,
-    },
+    Unload(Box<UnloadStatement>),
     /// ```sql
     /// OPTIMIZE TABLE [db.]name [ON CLUSTER cluster] [PARTITION partition | PARTITION ID 'partition_id'] [FINAL] [DEDUPLICATE [BY expression]]
     /// ```
@@ -8235,10 +8269,10 @@ pub enum Statement {
         #[cfg_attr(feature = "visitor", visit(with = "visit_token"))]
         token: AttachedToken,
         name: ObjectName,
-        on_cluster: Option<Ident>,
-        partition: Option<Partition>,
+        on_cluster: Option<Box<Ident>>,
+        partition: Option<Box<Partition>>,
         include_final: bool,
-        deduplicate: Option<Deduplicate>,
+        deduplicate: Option<Box<Deduplicate>>,
     },
     /// ```sql
     /// LISTEN
@@ -8249,7 +8283,7 @@ pub enum Statement {
     LISTEN {
         /// The `LISTEN` token
         listen_token: AttachedToken,
-        channel: Ident,
+        channel: Box<Ident>,
     },
     /// ```sql
     /// UNLISTEN
@@ -8260,7 +8294,7 @@ pub enum Statement {
     UNLISTEN {
         /// The `UNLISTEN` token
         unlisten_token: AttachedToken,
-        channel: Ident,
+        channel: Box<Ident>,
     },
     /// ```sql
     /// NOTIFY channel [ , payload ]
@@ -8271,7 +8305,7 @@ pub enum Statement {
     NOTIFY {
         /// The `NOTIFY` token
         notify_token: AttachedToken,
-        channel: Ident,
+        channel: Box<Ident>,
         payload: Option<String>,
     },
     /// ```sql
@@ -8316,12 +8350,12 @@ pub enum Statement {
         #[cfg_attr(feature = "visitor", visit(with = "visit_token"))]
         create_token: AttachedToken,
         if_not_exists: bool,
-        name: Ident,
+        name: Box<Ident>,
         /// The directory new datafiles are created in. PostgreSQL spells this
         /// `LOCATION`; it is mandatory because a tablespace with no location
         /// has nowhere to put the datafiles `ALTER TABLESPACE … ADD DATAFILE`
         /// creates.
-        location: Value,
+        location: Box<Value>,
         options: Vec<SqlOption>,
     },
     /// ```sql
@@ -8330,8 +8364,8 @@ pub enum Statement {
     AlterTablespace {
         #[cfg_attr(feature = "visitor", visit(with = "visit_token"))]
         alter_token: AttachedToken,
-        name: Ident,
-        operation: AlterTablespaceOperation,
+        name: Box<Ident>,
+        operation: Box<AlterTablespaceOperation>,
     },
     /// ```sql
     /// ALTER TENANT tenant_name { QUIESCE | RESUME }
@@ -8340,7 +8374,7 @@ pub enum Statement {
     AlterTenant {
         #[cfg_attr(feature = "visitor", visit(with = "visit_token"))]
         alter_token: AttachedToken,
-        tenant_name: Ident,
+        tenant_name: Box<Ident>,
         #[cfg_attr(feature = "visitor", visit(with = "visit_token"))]
         action_token: AttachedToken,
         action: TenantMaintenanceAction,
@@ -8353,7 +8387,7 @@ pub enum Statement {
     /// Loading files into tables
     LoadData {
         #[cfg_attr(feature = "visitor", visit(with = "visit_token"))]
-        load_token: AttachedToken,
+        load_token: Box<AttachedToken>,
         local: bool,
         inpath: String,
         overwrite: bool,
@@ -8375,7 +8409,7 @@ pub enum Statement {
     /// See <https://learn.microsoft.com/en-us/sql/t-sql/language-elements/raiserror-transact-sql?view=sql-server-ver16>
     RaisError {
         #[cfg_attr(feature = "visitor", visit(with = "visit_token"))]
-        token: AttachedToken,
+        token: Box<AttachedToken>,
         message: Box<Expr>,
         severity: Box<Expr>,
         state: Box<Expr>,
@@ -8393,21 +8427,21 @@ pub enum Statement {
     /// ```
     ///
     /// See [ReturnStatement]
-    Return(ReturnStatement),
+    Return(Box<ReturnStatement>),
     /// ```sql
     /// CREATE [OR REPLACE] USER <user> [IF NOT EXISTS]
     /// ```
-    CreateUser(CreateUser),
+    CreateUser(Box<CreateUser>),
     /// ```sql
     /// ALTER USER \[ IF EXISTS \] \[ <name> \]
     /// ```
-    AlterUser(AlterUser),
+    AlterUser(Box<AlterUser>),
     /// Re-sorts rows and reclaims space in either a specified table or all tables in the current database
     ///
     /// ```sql
     /// VACUUM tbl
     /// ```
-    Vacuum(VacuumStatement),
+    Vacuum(Box<VacuumStatement>),
     /// Rebuilds an index or the indexes owned by a PostgreSQL catalog object.
     ///
     /// ```sql
@@ -8425,7 +8459,28 @@ pub enum Statement {
     Reset(ResetStatement),
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+// Size budgets for the hottest AST nodes. Every `Vec<Statement>`, every
+// operand slot and every `AstBox` payload pays for the largest variant, so a
+// single large inline variant makes every node bigger. When one of these
+// fails, box the offending variant's payload rather than raising the budget;
+// `cargo run --bin ast_layout` in `sqlparser_bench` prints the current sizes.
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+    assert!(
+        core::mem::size_of::<Expr>() <= 64,
+        "Expr exceeds its 64-byte budget"
+    );
+    assert!(
+        core::mem::size_of::<Statement>() <= 128,
+        "Statement exceeds its 128-byte budget"
+    );
+    assert!(
+        core::mem::size_of::<SetExpr>() <= 32,
+        "SetExpr exceeds its 32-byte budget"
+    );
+};
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleCommitOptions {
@@ -8435,7 +8490,7 @@ pub struct OracleCommitOptions {
     pub force: Option<OracleCommitForce>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleCommitWrite {
@@ -8443,7 +8498,7 @@ pub struct OracleCommitWrite {
     pub immediate: Option<bool>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleCommitForce {
@@ -8451,7 +8506,7 @@ pub struct OracleCommitForce {
     pub scn: Option<u64>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleCreateStatement {
@@ -8462,7 +8517,7 @@ pub struct OracleCreateStatement {
     pub definition: OracleCreateDefinition,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleSize {
@@ -8470,7 +8525,7 @@ pub struct OracleSize {
     pub unit: Option<Ident>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleTypedName {
@@ -8478,7 +8533,7 @@ pub struct OracleTypedName {
     pub data_type: DataType,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleJoinGroupEntry {
@@ -8486,7 +8541,7 @@ pub struct OracleJoinGroupEntry {
     pub columns: Vec<Ident>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleAnalyticDimension {
@@ -8495,7 +8550,7 @@ pub struct OracleAnalyticDimension {
     pub references: Ident,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleAnalyticMeasure {
@@ -8503,7 +8558,7 @@ pub struct OracleAnalyticMeasure {
     pub fact: ObjectName,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleAttributeLevel {
@@ -8512,7 +8567,7 @@ pub struct OracleAttributeLevel {
     pub determines: Vec<Ident>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleDimensionLevel {
@@ -8520,7 +8575,7 @@ pub struct OracleDimensionLevel {
     pub value: ObjectName,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleJsonField {
@@ -8528,7 +8583,7 @@ pub struct OracleJsonField {
     pub value: Expr,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OraclePropertyGraphVertex {
@@ -8536,7 +8591,7 @@ pub struct OraclePropertyGraphVertex {
     pub key: Vec<Ident>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OraclePropertyGraphEdge {
@@ -8550,7 +8605,7 @@ pub struct OraclePropertyGraphEdge {
     pub destination_columns: Vec<Ident>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleParameterFileKind {
@@ -8558,7 +8613,7 @@ pub enum OracleParameterFileKind {
     Spfile,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleDiskgroupRedundancy {
@@ -8569,7 +8624,7 @@ pub enum OracleDiskgroupRedundancy {
     Extended,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleIndexKind {
@@ -8578,7 +8633,7 @@ pub enum OracleIndexKind {
     Vector,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleIndexOptions {
@@ -8591,7 +8646,7 @@ pub struct OracleIndexOptions {
     pub vector_parameters: Vec<OracleIndexParameter>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleIndexParameter {
@@ -8599,7 +8654,7 @@ pub struct OracleIndexParameter {
     pub value: Expr,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleCreateDefinition {
@@ -9459,7 +9514,7 @@ impl fmt::Display for OracleCreateStatement {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleCommandStatement {
@@ -9468,7 +9523,7 @@ pub struct OracleCommandStatement {
     pub command: OracleCommand,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleCommand {
@@ -9548,7 +9603,7 @@ pub enum OracleCommand {
     },
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleSystemPrivilege {
@@ -9556,7 +9611,7 @@ pub enum OracleSystemPrivilege {
     CreateTable,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleAuditOutcome {
@@ -9723,7 +9778,7 @@ impl fmt::Display for OracleCommandStatement {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleAlterStatement {
@@ -9734,7 +9789,7 @@ pub struct OracleAlterStatement {
     pub operation: OracleAlterOperation,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleAlterObjectType {
@@ -9783,7 +9838,7 @@ pub enum OracleAlterObjectType {
     View,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleAlterTarget {
@@ -9792,7 +9847,7 @@ pub enum OracleAlterTarget {
     On(ObjectName),
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleAlterOperation {
@@ -9854,7 +9909,7 @@ pub enum OracleAlterOperation {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleResourceValue {
@@ -9862,7 +9917,7 @@ pub struct OracleResourceValue {
     pub value: Expr,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleCreateTable {
@@ -9873,7 +9928,7 @@ pub struct OracleCreateTable {
     pub options: OracleCreateTableOptions,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleCreateTableKind {
@@ -9882,7 +9937,7 @@ pub enum OracleCreateTableKind {
     Immutable,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleCreateTableOptions {
@@ -9899,7 +9954,7 @@ pub enum OracleCreateTableOptions {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleBlockchainHashing {
@@ -9907,7 +9962,7 @@ pub struct OracleBlockchainHashing {
     pub version: Ident,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleExternalTableDefinition {
@@ -9919,7 +9974,7 @@ pub struct OracleExternalTableDefinition {
     pub reject_limit: Option<OracleRejectLimit>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleExternalAccessParameter {
@@ -10164,7 +10219,7 @@ impl fmt::Display for OracleAlterStatement {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleDropStatement {
@@ -10176,7 +10231,7 @@ pub struct OracleDropStatement {
     pub options: Vec<OracleDropOption>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleDropObjectType {
@@ -10225,7 +10280,7 @@ pub enum OracleDropObjectType {
     User,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleDropTarget {
@@ -10234,7 +10289,7 @@ pub enum OracleDropTarget {
     On(ObjectName),
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleDropOption {
@@ -10331,7 +10386,7 @@ impl fmt::Display for OracleDropStatement {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleLockTable {
@@ -10342,7 +10397,7 @@ pub struct OracleLockTable {
     pub wait: Option<OracleLockWait>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleLockMode {
@@ -10354,7 +10409,7 @@ pub enum OracleLockMode {
     Exclusive,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleLockWait {
@@ -10392,7 +10447,7 @@ impl fmt::Display for OracleLockTable {
 }
 
 /// PostgreSQL `LOCK [ TABLE ] [ ONLY ] name [, ...] [ IN lockmode MODE ] [ NOWAIT ]`
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct PgLockTable {
@@ -10406,7 +10461,7 @@ pub struct PgLockTable {
 }
 
 /// The eight PostgreSQL table lock modes.
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum PgLockTableMode {
@@ -10452,7 +10507,7 @@ impl fmt::Display for PgLockTable {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleAdministrativeAction {
@@ -10543,7 +10598,7 @@ impl From<ddl::Truncate> for Statement {
 }
 
 /// An `ALTER MATERIALIZED VIEW` operation.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterMaterializedViewOperation {
@@ -10579,7 +10634,7 @@ impl fmt::Display for AlterMaterializedViewOperation {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleMaterializedViewRefreshMethod {
@@ -10598,7 +10653,7 @@ impl fmt::Display for OracleMaterializedViewRefreshMethod {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleMaterializedViewRefreshMode {
@@ -10618,7 +10673,7 @@ impl fmt::Display for OracleMaterializedViewRefreshMode {
 /// Refresh schedule clause for materialized view CREATE and ALTER.
 ///
 /// `REFRESH SCHEDULE EVERY '<interval>' [START AT '<timestamp>'] [METHOD FAST|COMPLETE]`
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct MaterializedViewRefreshSchedule {
@@ -10642,7 +10697,7 @@ impl fmt::Display for MaterializedViewRefreshSchedule {
 
 /// Refresh method suffix on `REFRESH MATERIALIZED VIEW` (Oracle / dbl-server
 /// extension to standard PostgreSQL syntax).
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum MaterializedViewRefreshMethod {
@@ -10665,7 +10720,7 @@ impl fmt::Display for MaterializedViewRefreshMethod {
 /// {COPY | REVOKE} CURRENT GRANTS
 /// ```
 ///
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum RaisErrorOption {
@@ -10684,6 +10739,40 @@ impl fmt::Display for RaisErrorOption {
     }
 }
 
+impl Statement {
+    /// The comments attached to this statement, for the statements that
+    /// carry them: queries, `INSERT`, `UPDATE`, `DELETE`, `CREATE TABLE`,
+    /// `CREATE VIEW` and `CREATE FUNCTION`. See
+    /// [`ParserOptions::with_attach_comments`](crate::parser::ParserOptions::with_attach_comments).
+    pub fn comments(&self) -> Option<&Comments> {
+        match self {
+            Statement::Query(query) => Some(&query.comments),
+            Statement::Insert(insert) => Some(&insert.comments),
+            Statement::Update(update) => Some(&update.comments),
+            Statement::Delete(delete) => Some(&delete.comments),
+            Statement::CreateTable(create_table) => Some(&create_table.comments),
+            Statement::CreateView(create_view) => Some(&create_view.comments),
+            Statement::CreateFunction(create_function) => Some(&create_function.comments),
+            _ => None,
+        }
+    }
+
+    /// Mutable access to the comments attached to this statement, or `None`
+    /// when it cannot carry comments, see [`Statement::comments`].
+    pub fn comments_mut(&mut self) -> Option<&mut Comments> {
+        match self {
+            Statement::Query(query) => Some(&mut query.comments),
+            Statement::Insert(insert) => Some(&mut insert.comments),
+            Statement::Update(update) => Some(&mut update.comments),
+            Statement::Delete(delete) => Some(&mut delete.comments),
+            Statement::CreateTable(create_table) => Some(&mut create_table.comments),
+            Statement::CreateView(create_view) => Some(&mut create_view.comments),
+            Statement::CreateFunction(create_function) => Some(&mut create_function.comments),
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for Statement {
     /// Formats a SQL statement with support for pretty printing.
     ///
@@ -10992,7 +11081,7 @@ impl fmt::Display for Statement {
                 values,
             } => {
                 write!(f, "COPY")?;
-                match source {
+                match &**source {
                     CopySource::Query(query) => write!(f, " ({query})")?,
                     CopySource::Table {
                         table_name,
@@ -11035,55 +11124,7 @@ impl fmt::Display for Statement {
 
                 Ok(())
             }
-            Statement::CreateDatabase {
-                create_token: _,
-                db_name,
-                if_not_exists,
-                location,
-                managed_location,
-                owner,
-                or_replace,
-                transient,
-                clone,
-                comment,
-                catalog_sync,
-                compatibility,
-            } => {
-                write!(
-                    f,
-                    "CREATE {or_replace}{transient}DATABASE {if_not_exists}{name}",
-                    or_replace = if *or_replace { "OR REPLACE " } else { "" },
-                    transient = if *transient { "TRANSIENT " } else { "" },
-                    if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
-                    name = db_name,
-                )?;
-
-                if let Some(l) = location {
-                    write!(f, " LOCATION '{l}'")?;
-                }
-                if let Some(ml) = managed_location {
-                    write!(f, " MANAGEDLOCATION '{ml}'")?;
-                }
-                if let Some(owner) = owner {
-                    write!(f, " OWNER {owner}")?;
-                }
-                if let Some(clone) = clone {
-                    write!(f, " CLONE {clone}")?;
-                }
-
-                if let Some(comment) = comment {
-                    write!(f, " COMMENT = '{comment}'")?;
-                }
-
-                if let Some(sync) = catalog_sync {
-                    write!(f, " CATALOG_SYNC = '{sync}'")?;
-                }
-
-                if let Some(compatibility) = compatibility {
-                    write!(f, " COMPATIBILITY '{compatibility}'")?;
-                }
-                Ok(())
-            }
+            Statement::CreateDatabase(create_database) => create_database.fmt(f),
             Statement::CreateFunction(create_function) => create_function.fmt(f),
             Statement::CreateDomain(create_domain) => create_domain.fmt(f),
             Statement::CreateTrigger(create_trigger) => create_trigger.fmt(f),
@@ -11186,49 +11227,7 @@ impl fmt::Display for Statement {
             Statement::AlterUserMapping(stmt) => write!(f, "{stmt}"),
             Statement::DropUserMapping(stmt) => write!(f, "{stmt}"),
             Statement::ImportForeignSchema(stmt) => write!(f, "{stmt}"),
-            Statement::CreatePolicy {
-                name,
-                table_name,
-                policy_type,
-                command,
-                to,
-                using,
-                with_check,
-                ..
-            } => {
-                write!(f, "CREATE POLICY {name} ON {table_name}")?;
-
-                if let Some(policy_type) = policy_type {
-                    match policy_type {
-                        CreatePolicyType::Permissive => write!(f, " AS PERMISSIVE")?,
-                        CreatePolicyType::Restrictive => write!(f, " AS RESTRICTIVE")?,
-                    }
-                }
-
-                if let Some(command) = command {
-                    match command {
-                        CreatePolicyCommand::All => write!(f, " FOR ALL")?,
-                        CreatePolicyCommand::Select => write!(f, " FOR SELECT")?,
-                        CreatePolicyCommand::Insert => write!(f, " FOR INSERT")?,
-                        CreatePolicyCommand::Update => write!(f, " FOR UPDATE")?,
-                        CreatePolicyCommand::Delete => write!(f, " FOR DELETE")?,
-                    }
-                }
-
-                if let Some(to) = to {
-                    write!(f, " TO {}", display_comma_separated(to))?;
-                }
-
-                if let Some(using) = using {
-                    write!(f, " USING ({using})")?;
-                }
-
-                if let Some(with_check) = with_check {
-                    write!(f, " WITH CHECK ({with_check})")?;
-                }
-
-                Ok(())
-            }
+            Statement::CreatePolicy(create_policy) => create_policy.fmt(f),
             Statement::CreateOperator(create_operator) => create_operator.fmt(f),
             Statement::CreateAggregate(create_aggregate) => create_aggregate.fmt(f),
             Statement::CreateCast(create_cast) => create_cast.fmt(f),
@@ -11267,9 +11266,10 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
-            Statement::AlterType(AlterType {
-                name, operation, ..
-            }) => {
+            Statement::AlterType(alter_type) => {
+                let AlterType {
+                    name, operation, ..
+                } = &**alter_type;
                 write!(f, "ALTER TYPE {name} {operation}")
             }
             Statement::AlterRole {
@@ -11441,22 +11441,7 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
-            Statement::CreateSubscription {
-                name,
-                connection_string,
-                publications,
-                with_options,
-            } => {
-                write!(
-                    f,
-                    "CREATE SUBSCRIPTION {name} CONNECTION '{connection_string}' PUBLICATION {}",
-                    display_comma_separated(publications)
-                )?;
-                if !with_options.is_empty() {
-                    write!(f, " WITH ({})", display_comma_separated(with_options))?;
-                }
-                Ok(())
-            }
+            Statement::CreateSubscription(create_subscription) => create_subscription.fmt(f),
             Statement::AlterSubscription { name, action } => {
                 write!(f, "ALTER SUBSCRIPTION {name} ")?;
                 match action {
@@ -11860,44 +11845,7 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
-            Statement::Restore {
-                object_type,
-                tenant,
-                as_new_tenant,
-                location,
-                target_timestamp,
-                target_hlc,
-                target_lsn,
-                dry_run,
-            } => {
-                write!(f, "RESTORE")?;
-                if let Some(tenant) = tenant {
-                    write!(f, " TENANT {tenant}")?;
-                } else if let Some(obj) = object_type {
-                    write!(f, " {obj}")?;
-                } else {
-                    write!(f, " DATABASE")?;
-                }
-                if let Some(loc) = location {
-                    write!(f, " FROM '{loc}'")?;
-                }
-                if let Some(ts) = target_timestamp {
-                    write!(f, " TO TIMESTAMP '{ts}'")?;
-                }
-                if let Some(hlc) = target_hlc {
-                    write!(f, " TO HLC '{hlc}'")?;
-                }
-                if let Some(lsn) = target_lsn {
-                    write!(f, " TO LSN {lsn}")?;
-                }
-                if let Some(destination) = as_new_tenant {
-                    write!(f, " AS NEW TENANT {destination}")?;
-                }
-                if *dry_run {
-                    write!(f, " DRY RUN")?;
-                }
-                Ok(())
-            }
+            Statement::Restore(restore) => restore.fmt(f),
             Statement::RecoverPage {
                 page_id,
                 table_name,
@@ -12328,34 +12276,7 @@ impl fmt::Display for Statement {
             Statement::UnlockTables { unlock_token: _ } => {
                 write!(f, "UNLOCK TABLES")
             }
-            Statement::Unload {
-                unload_token: _,
-                query,
-                query_text,
-                to,
-                auth,
-                with,
-                options,
-            } => {
-                write!(f, "UNLOAD(")?;
-                if let Some(query) = query {
-                    write!(f, "{query}")?;
-                }
-                if let Some(query_text) = query_text {
-                    write!(f, "'{query_text}'")?;
-                }
-                write!(f, ") TO {to}")?;
-                if let Some(auth) = auth {
-                    write!(f, " IAM_ROLE {auth}")?;
-                }
-                if !with.is_empty() {
-                    write!(f, " WITH ({})", display_comma_separated(with))?;
-                }
-                if !options.is_empty() {
-                    write!(f, " {}", display_separated(options, " "))?;
-                }
-                Ok(())
-            }
+            Statement::Unload(unload) => unload.fmt(f),
             Statement::OptimizeTable {
                 token: _,
                 name,
@@ -12487,7 +12408,7 @@ impl fmt::Display for Statement {
 ///     [ MINVALUE minvalue | NO MINVALUE ] [ MAXVALUE maxvalue | NO MAXVALUE ]
 ///     [ START [ WITH ] start ] [ CACHE cache ] [ [ NO ] CYCLE ]
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SequenceOptions {
@@ -12562,7 +12483,7 @@ impl fmt::Display for SequenceOptions {
 }
 
 /// Assignment for a `SET` statement (name [=|TO] value)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct SetAssignment {
@@ -12586,7 +12507,7 @@ impl fmt::Display for SetAssignment {
 /// Target of a `TRUNCATE TABLE` command
 ///
 /// Note this is its own struct because `visit_relation` requires an `ObjectName` (not a `Vec<ObjectName>`)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TruncateTableTarget {
@@ -12610,7 +12531,7 @@ impl fmt::Display for TruncateTableTarget {
 
 /// PostgreSQL identity option for TRUNCATE table
 /// [ RESTART IDENTITY | CONTINUE IDENTITY ]
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TruncateIdentityOption {
@@ -12620,7 +12541,7 @@ pub enum TruncateIdentityOption {
 
 /// Cascade/restrict option for Postgres TRUNCATE table, MySQL GRANT/REVOKE, etc.
 /// [ CASCADE | RESTRICT ]
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CascadeOption {
@@ -12628,7 +12549,7 @@ pub enum CascadeOption {
     Restrict,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleTruncateStorage {
@@ -12657,7 +12578,7 @@ impl Display for CascadeOption {
 }
 
 /// Transaction started with [ TRANSACTION | WORK ]
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum BeginTransactionKind {
@@ -12676,7 +12597,7 @@ impl Display for BeginTransactionKind {
 
 /// Can use to describe options in  create sequence or table column type identity
 /// [ MINVALUE minvalue | NO MINVALUE ] [ MAXVALUE maxvalue | NO MAXVALUE ]
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum MinMaxValue {
@@ -12688,7 +12609,7 @@ pub enum MinMaxValue {
     Some(Expr),
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 #[non_exhaustive]
@@ -12699,7 +12620,7 @@ pub enum OnInsert {
     OnConflict(OnConflict),
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct InsertAliases {
@@ -12707,21 +12628,21 @@ pub struct InsertAliases {
     pub col_aliases: Option<Vec<Ident>>,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OnConflict {
     pub conflict_target: Option<ConflictTarget>,
     pub action: OnConflictAction,
 }
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ConflictTarget {
-    Columns(Vec<Ident>),
+    Columns(AstVec<Ident>),
     OnConstraint(ObjectName),
 }
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OnConflictAction {
@@ -12729,12 +12650,12 @@ pub enum OnConflictAction {
     DoUpdate(DoUpdate),
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct DoUpdate {
     /// Column assignments
-    pub assignments: Vec<Assignment>,
+    pub assignments: AstVec<Assignment>,
     /// WHERE
     pub selection: Option<Expr>,
 }
@@ -12791,7 +12712,7 @@ impl fmt::Display for OnConflictAction {
 }
 
 /// Privileges granted in a GRANT statement or revoked in a REVOKE statement.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Privileges {
@@ -12828,7 +12749,7 @@ impl fmt::Display for Privileges {
 }
 
 /// Specific direction for FETCH statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FetchDirection {
@@ -12894,7 +12815,7 @@ impl fmt::Display for FetchDirection {
 /// The "position" for a FETCH statement.
 ///
 /// [MsSql](https://learn.microsoft.com/en-us/sql/t-sql/language-elements/fetch-transact-sql)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FetchPosition {
@@ -12914,7 +12835,7 @@ impl fmt::Display for FetchPosition {
 }
 
 /// A privilege on a database object (table, sequence, etc.).
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Action {
@@ -13024,7 +12945,7 @@ impl fmt::Display for Action {
 }
 
 /// The principal that receives the privileges
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Grantee {
@@ -13068,7 +12989,7 @@ impl fmt::Display for Grantee {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum GranteesType {
@@ -13084,7 +13005,7 @@ pub enum GranteesType {
 }
 
 /// Users/roles designated in a GRANT/REVOKE
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum GranteeName {
@@ -13106,7 +13027,7 @@ impl fmt::Display for GranteeName {
 }
 
 /// Objects on which privileges are granted in a GRANT statement.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum GrantObjects {
@@ -13406,7 +13327,7 @@ impl fmt::Display for GrantObjects {
 /// A `DENY` statement
 ///
 /// [MsSql](https://learn.microsoft.com/en-us/sql/t-sql/statements/deny-transact-sql)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct DenyStatement {
@@ -13445,7 +13366,7 @@ impl fmt::Display for DenyStatement {
 }
 
 /// SQL assignment `foo = expr` as used in SQLUpdate
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Assignment {
@@ -13462,7 +13383,7 @@ impl fmt::Display for Assignment {
 /// Left-hand side of an assignment in an UPDATE statement,
 /// e.g. `foo` in `foo = 5` (ColumnName assignment) or
 /// `(a, b)` in `(a, b) = (1, 2)` (Tuple assignment).
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AssignmentTarget {
@@ -13481,7 +13402,7 @@ impl fmt::Display for AssignmentTarget {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FunctionArgExpr {
@@ -13512,7 +13433,7 @@ impl fmt::Display for FunctionArgExpr {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 /// Operator used to separate function arguments
@@ -13542,7 +13463,7 @@ impl fmt::Display for FunctionArgOperator {
 }
 
 /// SQL:2016 PTF table argument source
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum PtfTableSource {
@@ -13560,7 +13481,7 @@ impl fmt::Display for PtfTableSource {
 }
 
 /// SQL:2016 PTF semantics (ROW SEMANTICS or SET SEMANTICS)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum PtfSemantics {
@@ -13578,7 +13499,7 @@ impl fmt::Display for PtfSemantics {
 }
 
 /// SQL:2016 PTF table argument with optional modifiers
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct PtfTableArg {
@@ -13613,7 +13534,7 @@ impl fmt::Display for PtfTableArg {
 }
 
 /// SQL:2016 DESCRIPTOR for column specifications
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Descriptor {
@@ -13627,7 +13548,7 @@ impl fmt::Display for Descriptor {
 }
 
 /// SQL:2016 PTF COLUMNS clause for JSON extraction
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct PtfColumn {
@@ -13646,7 +13567,7 @@ impl fmt::Display for PtfColumn {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FunctionArg {
@@ -13702,7 +13623,7 @@ impl fmt::Display for FunctionArg {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CloseCursor {
@@ -13720,7 +13641,7 @@ impl fmt::Display for CloseCursor {
 }
 
 /// A Drop Domain statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct DropDomain {
@@ -13735,7 +13656,7 @@ pub struct DropDomain {
 }
 
 /// A Drop Assertion statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct DropAssertion {
@@ -13766,7 +13687,7 @@ impl fmt::Display for DropAssertion {
 /// A constant of form `<data_type> 'value'`.
 /// This can represent ANSI SQL `DATE`, `TIME`, and `TIMESTAMP` literals (such as `DATE '2020-01-01'`),
 /// as well as constants of other types (a non-standard PostgreSQL extension).
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct TypedString {
@@ -13810,7 +13731,7 @@ impl fmt::Display for TypedString {
 }
 
 /// A function call
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleKeepRank {
@@ -13828,7 +13749,7 @@ impl fmt::Display for OracleKeepRank {
 }
 
 /// A function call
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Function {
@@ -14042,7 +13963,7 @@ impl Function {
 }
 
 /// The arguments passed to a function call.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FunctionArguments {
@@ -14068,14 +13989,14 @@ impl fmt::Display for FunctionArguments {
 }
 
 /// This represents everything inside the parentheses when calling a function.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct FunctionArgumentList {
     /// `[ ALL | DISTINCT ]`
     pub duplicate_treatment: Option<DuplicateTreatment>,
     /// The function arguments.
-    pub args: Vec<FunctionArg>,
+    pub args: AstVec<FunctionArg>,
     /// Additional clauses specified within the argument list.
     pub clauses: Vec<FunctionArgumentClause>,
 }
@@ -14096,7 +14017,7 @@ impl fmt::Display for FunctionArgumentList {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FunctionArgumentClause {
@@ -14181,7 +14102,7 @@ impl fmt::Display for FunctionArgumentClause {
 }
 
 /// A method call
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Method {
@@ -14201,7 +14122,7 @@ impl fmt::Display for Method {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DuplicateTreatment {
@@ -14220,7 +14141,7 @@ impl fmt::Display for DuplicateTreatment {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AnalyzeFormatKind {
@@ -14239,7 +14160,7 @@ impl fmt::Display for AnalyzeFormatKind {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AnalyzeFormat {
@@ -14263,7 +14184,7 @@ impl fmt::Display for AnalyzeFormat {
 }
 
 /// The `ON OVERFLOW` clause of a LISTAGG invocation
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ListAggOnOverflow {
@@ -14299,7 +14220,7 @@ impl fmt::Display for ListAggOnOverflow {
 }
 
 /// The `HAVING` clause in a call to `ANY_VALUE`.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct HavingBound(pub HavingBoundKind, pub Expr);
@@ -14310,7 +14231,7 @@ impl fmt::Display for HavingBound {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum HavingBoundKind {
@@ -14327,7 +14248,7 @@ impl fmt::Display for HavingBoundKind {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleDropOptions {
@@ -14351,7 +14272,7 @@ impl fmt::Display for OracleDropOptions {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ObjectType {
@@ -14388,7 +14309,7 @@ impl fmt::Display for ObjectType {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum KillType {
@@ -14409,7 +14330,7 @@ impl fmt::Display for KillType {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DescribeAlias {
@@ -14429,7 +14350,7 @@ impl fmt::Display for DescribeAlias {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ClusteredIndex {
@@ -14448,7 +14369,7 @@ impl fmt::Display for ClusteredIndex {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TableOptionsClustered {
@@ -14478,7 +14399,7 @@ impl fmt::Display for TableOptionsClustered {
 }
 
 /// Specifies which partition the boundary values on table partitioning belongs to.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum PartitionRangeDirection {
@@ -14486,7 +14407,7 @@ pub enum PartitionRangeDirection {
     Right,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SqlOption {
@@ -14586,7 +14507,7 @@ impl fmt::Display for SqlOption {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum StorageType {
@@ -14594,7 +14515,7 @@ pub enum StorageType {
     Memory,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 /// MySql TableSpace option
@@ -14604,10 +14525,309 @@ pub struct TablespaceOption {
     pub storage: Option<StorageType>,
 }
 
+/// A `CREATE POLICY` statement.
+///
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createpolicy.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreatePolicy {
+    #[cfg_attr(feature = "visitor", visit(with = "visit_token"))]
+    pub token: AttachedToken,
+    pub name: Ident,
+    #[cfg_attr(feature = "visitor", visit(with = "visit_relation"))]
+    pub table_name: ObjectName,
+    pub policy_type: Option<CreatePolicyType>,
+    pub command: Option<CreatePolicyCommand>,
+    pub to: Option<Vec<Owner>>,
+    pub using: Option<Expr>,
+    pub with_check: Option<Expr>,
+}
+
+impl fmt::Display for CreatePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let CreatePolicy {
+            name,
+            table_name,
+            policy_type,
+            command,
+            to,
+            using,
+            with_check,
+            ..
+        } = self;
+
+        write!(f, "CREATE POLICY {name} ON {table_name}")?;
+
+        if let Some(policy_type) = policy_type {
+            match policy_type {
+                CreatePolicyType::Permissive => write!(f, " AS PERMISSIVE")?,
+                CreatePolicyType::Restrictive => write!(f, " AS RESTRICTIVE")?,
+            }
+        }
+
+        if let Some(command) = command {
+            match command {
+                CreatePolicyCommand::All => write!(f, " FOR ALL")?,
+                CreatePolicyCommand::Select => write!(f, " FOR SELECT")?,
+                CreatePolicyCommand::Insert => write!(f, " FOR INSERT")?,
+                CreatePolicyCommand::Update => write!(f, " FOR UPDATE")?,
+                CreatePolicyCommand::Delete => write!(f, " FOR DELETE")?,
+            }
+        }
+
+        if let Some(to) = to {
+            write!(f, " TO {}", display_comma_separated(to))?;
+        }
+
+        if let Some(using) = using {
+            write!(f, " USING ({using})")?;
+        }
+
+        if let Some(with_check) = with_check {
+            write!(f, " WITH CHECK ({with_check})")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A `CREATE SUBSCRIPTION` statement.
+///
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createsubscription.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateSubscription {
+    /// Subscription name
+    pub name: Ident,
+    /// Connection string (single-quoted)
+    pub connection_string: String,
+    /// List of publication names
+    pub publications: Vec<Ident>,
+    /// `WITH ( param = value, ... )`
+    pub with_options: Vec<SqlOption>,
+}
+
+impl fmt::Display for CreateSubscription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let CreateSubscription {
+            name,
+            connection_string,
+            publications,
+            with_options,
+        } = self;
+
+        write!(
+            f,
+            "CREATE SUBSCRIPTION {name} CONNECTION '{connection_string}' PUBLICATION {}",
+            display_comma_separated(publications)
+        )?;
+        if !with_options.is_empty() {
+            write!(f, " WITH ({})", display_comma_separated(with_options))?;
+        }
+        Ok(())
+    }
+}
+
+/// A `RESTORE` statement.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct RestoreStatement {
+    /// What to restore: DATABASE or a specific table name
+    pub object_type: Option<ObjectName>,
+    /// Tenant to restore; mutually exclusive with `object_type`
+    pub tenant: Option<Ident>,
+    /// Destination tenant for a restore-as-copy (`AS NEW TENANT`);
+    /// only valid together with `tenant`
+    pub as_new_tenant: Option<Ident>,
+    /// Source S3 URI
+    pub location: Option<String>,
+    /// Target timestamp (mutually exclusive with the other targets)
+    pub target_timestamp: Option<String>,
+    /// Target hybrid-logical-clock commit timestamp (mutually exclusive
+    /// with the other targets)
+    pub target_hlc: Option<u64>,
+    /// Target LSN (mutually exclusive with the other targets)
+    pub target_lsn: Option<u64>,
+    /// DRY RUN — preview without executing
+    pub dry_run: bool,
+}
+
+impl fmt::Display for RestoreStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let RestoreStatement {
+            object_type,
+            tenant,
+            as_new_tenant,
+            location,
+            target_timestamp,
+            target_hlc,
+            target_lsn,
+            dry_run,
+        } = self;
+
+        write!(f, "RESTORE")?;
+        if let Some(tenant) = tenant {
+            write!(f, " TENANT {tenant}")?;
+        } else if let Some(obj) = object_type {
+            write!(f, " {obj}")?;
+        } else {
+            write!(f, " DATABASE")?;
+        }
+        if let Some(loc) = location {
+            write!(f, " FROM '{loc}'")?;
+        }
+        if let Some(ts) = target_timestamp {
+            write!(f, " TO TIMESTAMP '{ts}'")?;
+        }
+        if let Some(hlc) = target_hlc {
+            write!(f, " TO HLC '{hlc}'")?;
+        }
+        if let Some(lsn) = target_lsn {
+            write!(f, " TO LSN {lsn}")?;
+        }
+        if let Some(destination) = as_new_tenant {
+            write!(f, " AS NEW TENANT {destination}")?;
+        }
+        if *dry_run {
+            write!(f, " DRY RUN")?;
+        }
+        Ok(())
+    }
+}
+
+/// A `CREATE DATABASE` statement.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateDatabase {
+    /// The `CREATE` token
+    pub create_token: AttachedToken,
+    pub db_name: ObjectName,
+    pub if_not_exists: bool,
+    pub location: Option<String>,
+    pub managed_location: Option<String>,
+    pub owner: Option<ObjectName>,
+    pub or_replace: bool,
+    pub transient: bool,
+    pub clone: Option<ObjectName>,
+    pub comment: Option<String>,
+    pub catalog_sync: Option<String>,
+    /// Gantry `COMPATIBILITY '<dialect>'` — the SQL dialect the new
+    /// database is permanently bound to.
+    pub compatibility: Option<String>,
+}
+
+impl fmt::Display for CreateDatabase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let CreateDatabase {
+            create_token: _,
+            db_name,
+            if_not_exists,
+            location,
+            managed_location,
+            owner,
+            or_replace,
+            transient,
+            clone,
+            comment,
+            catalog_sync,
+            compatibility,
+        } = self;
+
+        write!(
+            f,
+            "CREATE {or_replace}{transient}DATABASE {if_not_exists}{name}",
+            or_replace = if *or_replace { "OR REPLACE " } else { "" },
+            transient = if *transient { "TRANSIENT " } else { "" },
+            if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
+            name = db_name,
+        )?;
+
+        if let Some(l) = location {
+            write!(f, " LOCATION '{l}'")?;
+        }
+        if let Some(ml) = managed_location {
+            write!(f, " MANAGEDLOCATION '{ml}'")?;
+        }
+        if let Some(owner) = owner {
+            write!(f, " OWNER {owner}")?;
+        }
+        if let Some(clone) = clone {
+            write!(f, " CLONE {clone}")?;
+        }
+
+        if let Some(comment) = comment {
+            write!(f, " COMMENT = '{comment}'")?;
+        }
+
+        if let Some(sync) = catalog_sync {
+            write!(f, " CATALOG_SYNC = '{sync}'")?;
+        }
+
+        if let Some(compatibility) = compatibility {
+            write!(f, " COMPATIBILITY '{compatibility}'")?;
+        }
+        Ok(())
+    }
+}
+
+/// An `UNLOAD` statement.
+///
+/// [Athena](https://docs.aws.amazon.com/athena/latest/ug/unload.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct UnloadStatement {
+    #[cfg_attr(feature = "visitor", visit(with = "visit_token"))]
+    pub unload_token: AttachedToken,
+    pub query: Option<Box<Query>>,
+    pub query_text: Option<String>,
+    pub to: Ident,
+    pub auth: Option<IamRoleKind>,
+    pub with: Vec<SqlOption>,
+    pub options: Vec<CopyLegacyOption>,
+}
+
+impl fmt::Display for UnloadStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let UnloadStatement {
+            unload_token: _,
+            query,
+            query_text,
+            to,
+            auth,
+            with,
+            options,
+        } = self;
+
+        write!(f, "UNLOAD(")?;
+        if let Some(query) = query {
+            write!(f, "{query}")?;
+        }
+        if let Some(query_text) = query_text {
+            write!(f, "'{query_text}'")?;
+        }
+        write!(f, ") TO {to}")?;
+        if let Some(auth) = auth {
+            write!(f, " IAM_ROLE {auth}")?;
+        }
+        if !with.is_empty() {
+            write!(f, " WITH ({})", display_comma_separated(with))?;
+        }
+        if !options.is_empty() {
+            write!(f, " {}", display_separated(options, " "))?;
+        }
+        Ok(())
+    }
+}
+
 /// A `CREATE SERVER` statement.
 ///
 /// [PostgreSQL Documentation](https://www.postgresql.org/docs/current/sql-createserver.html)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateServerStatement {
@@ -14657,7 +14877,7 @@ impl fmt::Display for CreateServerStatement {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateServerOption {
@@ -14676,7 +14896,7 @@ impl fmt::Display for CreateServerOption {
 // ============================================================================
 
 /// An option modification for ALTER statements (SET, ADD, DROP)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SqlMedOptionAction {
@@ -14699,7 +14919,7 @@ impl fmt::Display for SqlMedOptionAction {
 }
 
 /// ALTER SERVER statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct AlterServerStatement {
@@ -14720,7 +14940,7 @@ impl fmt::Display for AlterServerStatement {
 }
 
 /// Operations for ALTER SERVER
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterServerOperation {
@@ -14744,7 +14964,7 @@ impl fmt::Display for AlterServerOperation {
 }
 
 /// DROP SERVER statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct DropServerStatement {
@@ -14770,7 +14990,7 @@ impl fmt::Display for DropServerStatement {
 }
 
 /// CREATE FOREIGN DATA WRAPPER statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateForeignDataWrapperStatement {
@@ -14816,7 +15036,7 @@ impl fmt::Display for CreateForeignDataWrapperStatement {
 }
 
 /// ALTER FOREIGN DATA WRAPPER statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct AlterForeignDataWrapperStatement {
@@ -14837,7 +15057,7 @@ impl fmt::Display for AlterForeignDataWrapperStatement {
 }
 
 /// Operations for ALTER FOREIGN DATA WRAPPER
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterForeignDataWrapperOperation {
@@ -14867,7 +15087,7 @@ impl fmt::Display for AlterForeignDataWrapperOperation {
 }
 
 /// DROP FOREIGN DATA WRAPPER statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct DropForeignDataWrapperStatement {
@@ -14893,7 +15113,7 @@ impl fmt::Display for DropForeignDataWrapperStatement {
 }
 
 /// CREATE FOREIGN TABLE statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateForeignTableStatement {
@@ -14956,7 +15176,7 @@ impl fmt::Display for CreateForeignTableStatement {
 }
 
 /// Partition bound specification for foreign tables
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum PartitionBoundSpec {
@@ -14996,7 +15216,7 @@ impl fmt::Display for PartitionBoundSpec {
 }
 
 /// ALTER FOREIGN TABLE statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct AlterForeignTableStatement {
@@ -15026,7 +15246,7 @@ impl fmt::Display for AlterForeignTableStatement {
 }
 
 /// Operations for ALTER FOREIGN TABLE
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterForeignTableOperation {
@@ -15056,7 +15276,7 @@ pub enum AlterForeignTableOperation {
 }
 
 /// Actions for ALTER COLUMN in foreign tables
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum AlterColumnAction {
@@ -15139,7 +15359,7 @@ impl fmt::Display for AlterForeignTableOperation {
 }
 
 /// DROP FOREIGN TABLE statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct DropForeignTableStatement {
@@ -15165,7 +15385,7 @@ impl fmt::Display for DropForeignTableStatement {
 }
 
 /// User specification for USER MAPPING statements
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum UserMappingUser {
@@ -15194,7 +15414,7 @@ impl fmt::Display for UserMappingUser {
 }
 
 /// CREATE USER MAPPING statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateUserMappingStatement {
@@ -15221,7 +15441,7 @@ impl fmt::Display for CreateUserMappingStatement {
 }
 
 /// ALTER USER MAPPING statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct AlterUserMappingStatement {
@@ -15245,7 +15465,7 @@ impl fmt::Display for AlterUserMappingStatement {
 }
 
 /// DROP USER MAPPING statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct DropUserMappingStatement {
@@ -15267,7 +15487,7 @@ impl fmt::Display for DropUserMappingStatement {
 }
 
 /// IMPORT FOREIGN SCHEMA limit type
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ImportForeignSchemaLimitType {
@@ -15285,7 +15505,7 @@ impl fmt::Display for ImportForeignSchemaLimitType {
 }
 
 /// IMPORT FOREIGN SCHEMA statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ImportForeignSchemaStatement {
@@ -15321,7 +15541,7 @@ impl fmt::Display for ImportForeignSchemaStatement {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TransactionMode {
@@ -15339,7 +15559,7 @@ impl fmt::Display for TransactionMode {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TransactionAccessMode {
@@ -15357,7 +15577,7 @@ impl fmt::Display for TransactionAccessMode {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TransactionIsolationLevel {
@@ -15384,7 +15604,7 @@ impl fmt::Display for TransactionIsolationLevel {
 /// Modifier for the transaction in the `BEGIN` syntax
 ///
 /// MS-SQL: <https://learn.microsoft.com/en-us/sql/t-sql/language-elements/try-catch-transact-sql>
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TransactionModifier {
@@ -15409,7 +15629,7 @@ impl fmt::Display for TransactionModifier {
 }
 
 /// Constraint checking mode for SET CONSTRAINTS statement.
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ConstraintCheckTime {
@@ -15426,7 +15646,7 @@ impl fmt::Display for ConstraintCheckTime {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ShowStatementFilter {
@@ -15448,7 +15668,7 @@ impl fmt::Display for ShowStatementFilter {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ShowStatementInClause {
@@ -15471,7 +15691,7 @@ impl fmt::Display for ShowStatementInClause {
 /// See [Mysql documentation](https://dev.mysql.com/doc/refman/8.0/en/replace.html)
 /// See [Mysql documentation](https://dev.mysql.com/doc/refman/8.0/en/insert.html)
 /// for more details.
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum MysqlInsertPriority {
@@ -15491,7 +15711,7 @@ impl fmt::Display for crate::ast::MysqlInsertPriority {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CopySource {
@@ -15505,7 +15725,7 @@ pub enum CopySource {
     Query(Box<Query>),
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CopyTarget {
@@ -15537,7 +15757,7 @@ impl fmt::Display for CopyTarget {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OnCommit {
@@ -15549,7 +15769,7 @@ pub enum OnCommit {
 /// An option in `COPY` statement.
 ///
 /// <https://www.postgresql.org/docs/14/sql-copy.html>
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CopyOption {
@@ -15620,7 +15840,7 @@ impl fmt::Display for CopyOption {
 /// An option in `COPY` statement before PostgreSQL version 9.0.
 ///
 /// [PostgreSQL](https://www.postgresql.org/docs/8.4/sql-copy.html)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CopyLegacyOption {
@@ -15805,7 +16025,7 @@ impl fmt::Display for CopyLegacyOption {
 /// ```sql
 /// SIZE \[ MB | GB \]
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct FileSize {
@@ -15823,7 +16043,7 @@ impl fmt::Display for FileSize {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FileSizeUnit {
@@ -15845,7 +16065,7 @@ impl fmt::Display for FileSizeUnit {
 /// ```sql
 /// PARTITION BY ( column_name [, ... ] ) [ INCLUDE ]
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct UnloadPartitionBy {
@@ -15867,7 +16087,7 @@ impl fmt::Display for UnloadPartitionBy {
 /// An `IAM_ROLE` option in the AWS ecosystem
 ///
 /// An `IAM_ROLE` option in the AWS ecosystem.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum IamRoleKind {
@@ -15889,7 +16109,7 @@ impl fmt::Display for IamRoleKind {
 /// A `CSV` option in `COPY` statement before PostgreSQL version 9.0.
 ///
 /// <https://www.postgresql.org/docs/8.4/sql-copy.html>
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CopyLegacyCsvOption {
@@ -15926,7 +16146,7 @@ impl fmt::Display for CopyLegacyCsvOption {
 /// ```sql
 /// MERGE INTO T USING U ON FALSE WHEN MATCHED THEN DELETE
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum MergeClauseKind {
@@ -15953,7 +16173,7 @@ impl Display for MergeClauseKind {
 
 /// The type of expression used to insert rows within a `MERGE` statement.
 ///
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum MergeInsertKind {
@@ -15994,7 +16214,7 @@ impl Display for MergeInsertKind {
 /// INSERT ROW
 /// ```
 ///
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct MergeInsertExpr {
@@ -16032,7 +16252,7 @@ impl Display for MergeInsertExpr {
 /// INSERT (product, quantity) VALUES(product, quantity)
 /// ```
 ///
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum MergeAction {
@@ -16098,7 +16318,7 @@ impl Display for MergeAction {
 /// ```sql
 /// WHEN NOT MATCHED BY SOURCE AND product LIKE '%washer%' THEN DELETE
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct MergeClause {
@@ -16128,7 +16348,7 @@ impl Display for MergeClause {
 /// Example:
 /// OUTPUT $action, deleted.* INTO dbo.temp_products;
 /// [mssql](https://learn.microsoft.com/en-us/sql/t-sql/queries/output-clause-transact-sql)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OutputClause {
@@ -16164,7 +16384,7 @@ impl fmt::Display for OutputClause {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DiscardObject {
@@ -16185,7 +16405,7 @@ impl fmt::Display for DiscardObject {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FlushType {
@@ -16224,7 +16444,7 @@ impl fmt::Display for FlushType {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FlushLocation {
@@ -16242,7 +16462,7 @@ impl fmt::Display for FlushLocation {
 }
 
 /// Optional context modifier for statements that can be or `LOCAL`, `GLOBAL`, or `SESSION`.
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ContextModifier {
@@ -16288,7 +16508,7 @@ impl fmt::Display for DropFunctionOption {
 }
 
 /// Function description for DROP FUNCTION and CREATE TRIGGER.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct FunctionDesc {
@@ -16307,7 +16527,7 @@ impl fmt::Display for FunctionDesc {
 }
 
 /// Function argument in CREATE OR DROP FUNCTION.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OperateFunctionArg {
@@ -16363,7 +16583,7 @@ impl fmt::Display for OperateFunctionArg {
 }
 
 /// The mode of an argument in CREATE FUNCTION.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ArgMode {
@@ -16387,7 +16607,7 @@ impl fmt::Display for ArgMode {
 /// SQL:2016 XML passing mechanism for function parameters.
 ///
 /// <https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#XML-passing-mechanism>
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum XmlPassingMechanism {
@@ -16405,7 +16625,7 @@ impl fmt::Display for XmlPassingMechanism {
 }
 
 /// These attributes inform the query optimizer about the behavior of the function.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FunctionBehavior {
@@ -16425,7 +16645,7 @@ impl fmt::Display for FunctionBehavior {
 }
 
 /// These attributes describe the behavior of the function when called with a null argument.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FunctionCalledOnNull {
@@ -16445,7 +16665,7 @@ impl fmt::Display for FunctionCalledOnNull {
 }
 
 /// If it is safe for PostgreSQL to call the function from multiple threads at once
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FunctionParallel {
@@ -16465,7 +16685,7 @@ impl fmt::Display for FunctionParallel {
 }
 
 /// Security execution context for PostgreSQL CREATE PROCEDURE.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ProcedureSecurity {
@@ -16483,7 +16703,7 @@ impl fmt::Display for ProcedureSecurity {
 }
 
 /// PostgreSQL CREATE PROCEDURE SET option.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ProcedureSetConfig {
@@ -16504,7 +16724,7 @@ impl fmt::Display for ProcedureSetConfig {
 }
 
 /// SQL:2016 SQL data access characteristic for functions
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SqlDataAccess {
@@ -16526,7 +16746,7 @@ impl fmt::Display for SqlDataAccess {
 }
 
 /// Determinism specifier used in a UDF definition.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum FunctionDeterminismSpecifier {
@@ -16552,7 +16772,7 @@ impl fmt::Display for FunctionDeterminismSpecifier {
 ///
 /// [PostgreSQL]: https://www.postgresql.org/docs/15/sql-createfunction.html
 /// [MsSql]: https://learn.microsoft.com/en-us/sql/t-sql/statements/create-function-transact-sql
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CreateFunctionBody {
@@ -16631,7 +16851,7 @@ pub enum CreateFunctionBody {
     AsReturnSelect(Select),
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CreateFunctionUsing {
@@ -16654,7 +16874,7 @@ impl fmt::Display for CreateFunctionUsing {
 /// Schema possible naming variants ([1]).
 ///
 /// [1]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#schema-definition
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SchemaName {
@@ -16685,7 +16905,7 @@ impl fmt::Display for SchemaName {
 /// Fulltext search modifiers ([1]).
 ///
 /// [1]: https://dev.mysql.com/doc/refman/8.0/en/fulltext-search.html#function_match
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SearchModifier {
@@ -16720,7 +16940,7 @@ impl fmt::Display for SearchModifier {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct LockTable {
@@ -16746,7 +16966,7 @@ impl fmt::Display for LockTable {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum LockTableType {
@@ -16777,7 +16997,7 @@ impl fmt::Display for LockTableType {
 
 /// MySQL `ALTER TABLE` only  [FIRST | AFTER column_name]
 #[allow(clippy::large_enum_variant)]
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum MySQLColumnPosition {
@@ -16798,7 +17018,7 @@ impl Display for MySQLColumnPosition {
 }
 
 /// MySQL `CREATE VIEW` algorithm parameter: [ALGORITHM = {UNDEFINED | MERGE | TEMPTABLE}]
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CreateViewAlgorithm {
@@ -16817,7 +17037,7 @@ impl Display for CreateViewAlgorithm {
     }
 }
 /// MySQL `CREATE VIEW` security parameter: [SQL SECURITY { DEFINER | INVOKER }]
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CreateViewSecurity {
@@ -16837,7 +17057,7 @@ impl Display for CreateViewSecurity {
 /// [MySQL] `CREATE VIEW` additional parameters
 ///
 /// [MySQL]: https://dev.mysql.com/doc/refman/9.1/en/create-view.html
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateViewParams {
@@ -16866,7 +17086,7 @@ impl Display for CreateViewParams {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 /// Key/Value, where the value is a (optionally named) list of identifiers
@@ -16883,7 +17103,7 @@ pub struct NamedParenthesizedList {
 }
 
 /// Helper to indicate if a comment includes the `=` in the display form
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CommentDef {
@@ -16915,7 +17135,7 @@ impl Display for CommentDef {
 /// let items = WrappedCollection::NoWrapping(vec!["one", "two", "three"]);
 /// assert_eq!("one, two, three", items.to_string());
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum WrappedCollection<T> {
@@ -16964,7 +17184,7 @@ where
 ///
 /// VACUUM (VERBOSE, ANALYZE ON, PARALLEL 10) my_table;
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct UtilityOption {
@@ -16984,7 +17204,7 @@ impl Display for UtilityOption {
 
 /// Represents the different options available for `SHOW`
 /// statements to filter the results.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ShowStatementOptions {
@@ -17030,7 +17250,7 @@ impl Display for ShowStatementOptions {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ShowStatementFilterPosition {
@@ -17038,7 +17258,7 @@ pub enum ShowStatementFilterPosition {
     Suffix(ShowStatementFilter), // For example: SHOW COLUMNS IN tbl LIKE '%name%'
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ShowStatementInParentType {
@@ -17061,7 +17281,7 @@ impl fmt::Display for ShowStatementInParentType {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ShowStatementIn {
@@ -17085,7 +17305,7 @@ impl fmt::Display for ShowStatementIn {
 }
 
 /// A Show Charset statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ShowCharset {
@@ -17105,8 +17325,8 @@ impl fmt::Display for ShowCharset {
         } else {
             write!(f, " CHARACTER SET")?;
         }
-        if self.filter.is_some() {
-            write!(f, " {}", self.filter.as_ref().unwrap())?;
+        if let Some(filter) = &self.filter {
+            write!(f, " {filter}")?;
         }
         Ok(())
     }
@@ -17121,7 +17341,7 @@ impl fmt::Display for ShowCharset {
 /// ```
 ///
 /// <https://learn.microsoft.com/en-us/sql/t-sql/functions/json-object-transact-sql?view=sql-server-ver16#json_null_clause>
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum JsonNullClause {
@@ -17144,7 +17364,7 @@ impl Display for JsonNullClause {
 /// ```sql
 /// JSON_OBJECT('a': 1 RETURNING jsonb)
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct JsonReturningClause {
@@ -17158,7 +17378,7 @@ impl Display for JsonReturningClause {
 }
 
 /// The behavior for JSON functions when the path returns empty results or errors.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum JsonOnBehavior {
@@ -17197,7 +17417,7 @@ impl Display for JsonOnBehavior {
 
 /// The wrapper option for JSON_QUERY function.
 /// Controls whether the result is wrapped in a JSON array.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum JsonQueryWrapper {
@@ -17234,7 +17454,7 @@ impl Display for JsonQueryWrapper {
 }
 
 /// rename object definition
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct RenameTable {
@@ -17257,7 +17477,7 @@ impl fmt::Display for RenameTable {
 }
 
 /// Represents the referenced table in an `INSERT INTO` statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TableObject {
@@ -17286,7 +17506,7 @@ impl fmt::Display for TableObject {
 }
 
 /// Represents a SET SESSION AUTHORIZATION statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct SetSessionAuthorizationParam {
@@ -17301,7 +17521,7 @@ impl fmt::Display for SetSessionAuthorizationParam {
 }
 
 /// Represents the parameter kind for SET SESSION AUTHORIZATION
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SetSessionAuthorizationParamKind {
@@ -17321,7 +17541,7 @@ impl fmt::Display for SetSessionAuthorizationParamKind {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SetSessionParamKind {
@@ -17342,7 +17562,7 @@ impl fmt::Display for SetSessionParamKind {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct SetSessionParamGeneric {
@@ -17356,7 +17576,7 @@ impl fmt::Display for SetSessionParamGeneric {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct SetSessionParamIdentityInsert {
@@ -17370,7 +17590,7 @@ impl fmt::Display for SetSessionParamIdentityInsert {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct SetSessionParamOffsets {
@@ -17389,7 +17609,7 @@ impl fmt::Display for SetSessionParamOffsets {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct SetSessionParamStatistics {
@@ -17403,7 +17623,7 @@ impl fmt::Display for SetSessionParamStatistics {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SessionParamStatsTopic {
@@ -17424,7 +17644,7 @@ impl fmt::Display for SessionParamStatsTopic {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum SessionParamValue {
@@ -17441,7 +17661,7 @@ impl fmt::Display for SessionParamValue {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct PrintStatement {
@@ -17461,7 +17681,7 @@ impl fmt::Display for PrintStatement {
 ///
 /// [MsSql triggers](https://learn.microsoft.com/en-us/sql/t-sql/statements/create-trigger-transact-sql)
 /// [MsSql functions](https://learn.microsoft.com/en-us/sql/t-sql/statements/create-function-transact-sql)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ReturnStatement {
@@ -17493,7 +17713,7 @@ impl fmt::Display for ReturnStatement {
 /// Variants of a `RETURN` statement
 ///
 /// [PostgreSQL RETURN](https://www.postgresql.org/docs/current/plpgsql-control-structures.html#PLPGSQL-STATEMENTS-RETURNING)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ReturnStatementValue {
@@ -17513,7 +17733,7 @@ pub enum ReturnStatementValue {
 }
 
 /// Variants of OPEN FOR clause in PL/pgSQL
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OpenFor {
@@ -17539,7 +17759,7 @@ pub enum OpenFor {
 }
 
 /// A positional or named actual argument in an Oracle `OPEN` statement.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OracleCursorArgument {
@@ -17557,7 +17777,7 @@ impl fmt::Display for OracleCursorArgument {
 }
 
 /// INTO clause for EXECUTE statement with optional STRICT
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ExecuteInto {
@@ -17610,7 +17830,7 @@ impl fmt::Display for ExecuteInto {
 }
 
 /// Represents an `OPEN` statement.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct OpenStatement {
@@ -17633,7 +17853,7 @@ impl fmt::Display for OpenStatement {
 /// Specifies Include / Exclude NULL within UNPIVOT command.
 /// For example
 /// `UNPIVOT (column1 FOR new_column IN (col3, col4, col5, col6))`
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum NullInclusion {
@@ -17657,7 +17877,7 @@ impl fmt::Display for NullInclusion {
 /// <value> MEMBER OF(<array>)
 /// ```
 /// [MySQL](https://dev.mysql.com/doc/refman/8.4/en/json-search-functions.html#operator_member-of)
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct MemberOf {
@@ -17665,7 +17885,7 @@ pub struct MemberOf {
     pub array: Box<Expr>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleLikeKind {
@@ -17684,7 +17904,7 @@ impl fmt::Display for OracleLikeKind {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum OracleIsPredicate {
@@ -17721,7 +17941,7 @@ impl fmt::Display for MemberOf {
 /// CREATE [OR REPLACE] USER [IF NOT EXISTS] <name> [OPTIONS]
 /// ```
 ///
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateUser {
@@ -17775,7 +17995,7 @@ impl fmt::Display for CreateUser {
 /// ALTER USER [ IF EXISTS ] [ <name> ] [ OPTIONS ]
 /// ```
 ///
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct AlterUser {
@@ -17815,7 +18035,7 @@ impl fmt::Display for AlterUser {
 /// '''sql
 /// CREATE TABLE new LIKE old ...
 /// '''
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CreateTableLikeKind {
@@ -17829,7 +18049,7 @@ pub enum CreateTableLikeKind {
     Plain(CreateTableLike),
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CreateTableLikeDefaults {
@@ -17846,7 +18066,7 @@ impl fmt::Display for CreateTableLikeDefaults {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CreateTableLikeOption {
@@ -17867,7 +18087,7 @@ impl fmt::Display for CreateTableLikeOption {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct CreateTableLike {
@@ -17894,7 +18114,7 @@ impl fmt::Display for CreateTableLike {
 /// '''sql
 /// VACUUM [ FULL | SORT ONLY | DELETE ONLY | REINDEX | RECLUSTER ] [ ANALYZE ] [ \[ table_name \] [ TO threshold PERCENT ] \[ BOOST \] ]
 /// '''
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct VacuumStatement {
@@ -17912,7 +18132,7 @@ pub struct VacuumStatement {
 }
 
 /// The catalog object whose indexes PostgreSQL should rebuild.
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum ReindexTarget {
@@ -17936,7 +18156,7 @@ impl fmt::Display for ReindexTarget {
 }
 
 /// A PostgreSQL `REINDEX` statement.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ReindexStatement {
@@ -18000,7 +18220,7 @@ impl fmt::Display for VacuumStatement {
 }
 
 /// Variants of the RESET statement
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Reset {
@@ -18015,7 +18235,7 @@ pub enum Reset {
 /// ```sql
 /// RESET { ALL | <configuration_parameter> }
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ResetStatement {
@@ -18048,25 +18268,25 @@ impl From<Box<Query>> for Statement {
 
 impl From<Insert> for Statement {
     fn from(i: Insert) -> Self {
-        Self::Insert(i)
+        Self::Insert(Box::new(i))
     }
 }
 
 impl From<Update> for Statement {
     fn from(u: Update) -> Self {
-        Self::Update(u)
+        Self::Update(Box::new(u))
     }
 }
 
 impl From<CreateView> for Statement {
     fn from(cv: CreateView) -> Self {
-        Self::CreateView(cv)
+        Self::CreateView(Box::new(cv))
     }
 }
 
 impl From<CreateRole> for Statement {
     fn from(cr: CreateRole) -> Self {
-        Self::CreateRole(cr)
+        Self::CreateRole(Box::new(cr))
     }
 }
 
@@ -18084,7 +18304,7 @@ impl From<DropFunction> for Statement {
 
 impl From<CreateExtension> for Statement {
     fn from(ce: CreateExtension) -> Self {
-        Self::CreateExtension(ce)
+        Self::CreateExtension(Box::new(ce))
     }
 }
 
@@ -18096,67 +18316,67 @@ impl From<DropExtension> for Statement {
 
 impl From<CaseStatement> for Statement {
     fn from(c: CaseStatement) -> Self {
-        Self::Case(c)
+        Self::Case(Box::new(c))
     }
 }
 
 impl From<IfStatement> for Statement {
     fn from(i: IfStatement) -> Self {
-        Self::If(i)
+        Self::If(Box::new(i))
     }
 }
 
 impl From<WhileStatement> for Statement {
     fn from(w: WhileStatement) -> Self {
-        Self::While(w)
+        Self::While(Box::new(w))
     }
 }
 
 impl From<RaiseStatement> for Statement {
     fn from(r: RaiseStatement) -> Self {
-        Self::Raise(r)
+        Self::Raise(Box::new(r))
     }
 }
 
 impl From<Function> for Statement {
     fn from(f: Function) -> Self {
-        Self::Call(f)
+        Self::Call(Box::new(f))
     }
 }
 
 impl From<OpenStatement> for Statement {
     fn from(o: OpenStatement) -> Self {
-        Self::Open(o)
+        Self::Open(Box::new(o))
     }
 }
 
 impl From<Delete> for Statement {
     fn from(d: Delete) -> Self {
-        Self::Delete(d)
+        Self::Delete(Box::new(d))
     }
 }
 
 impl From<CreateTable> for Statement {
     fn from(c: CreateTable) -> Self {
-        Self::CreateTable(c)
+        Self::CreateTable(Box::new(c))
     }
 }
 
 impl From<CreateIndex> for Statement {
     fn from(c: CreateIndex) -> Self {
-        Self::CreateIndex(c)
+        Self::CreateIndex(Box::new(c))
     }
 }
 
 impl From<CreateServerStatement> for Statement {
     fn from(c: CreateServerStatement) -> Self {
-        Self::CreateServer(c)
+        Self::CreateServer(Box::new(c))
     }
 }
 
 impl From<CreatePropertyGraph> for Statement {
     fn from(c: CreatePropertyGraph) -> Self {
-        Self::CreatePropertyGraph(c)
+        Self::CreatePropertyGraph(Box::new(c))
     }
 }
 
@@ -18174,7 +18394,7 @@ impl From<AlterSchema> for Statement {
 
 impl From<AlterType> for Statement {
     fn from(a: AlterType) -> Self {
-        Self::AlterType(a)
+        Self::AlterType(Box::new(a))
     }
 }
 
@@ -18186,7 +18406,7 @@ impl From<DropDomain> for Statement {
 
 impl From<ShowCharset> for Statement {
     fn from(s: ShowCharset) -> Self {
-        Self::ShowCharset(s)
+        Self::ShowCharset(Box::new(s))
     }
 }
 
@@ -18198,13 +18418,13 @@ impl From<Use> for Statement {
 
 impl From<CreateFunction> for Statement {
     fn from(c: CreateFunction) -> Self {
-        Self::CreateFunction(c)
+        Self::CreateFunction(Box::new(c))
     }
 }
 
 impl From<CreateTrigger> for Statement {
     fn from(c: CreateTrigger) -> Self {
-        Self::CreateTrigger(c)
+        Self::CreateTrigger(Box::new(c))
     }
 }
 
@@ -18216,13 +18436,13 @@ impl From<DropTrigger> for Statement {
 
 impl From<DenyStatement> for Statement {
     fn from(d: DenyStatement) -> Self {
-        Self::Deny(d)
+        Self::Deny(Box::new(d))
     }
 }
 
 impl From<CreateDomain> for Statement {
     fn from(c: CreateDomain) -> Self {
-        Self::CreateDomain(c)
+        Self::CreateDomain(Box::new(c))
     }
 }
 
@@ -18246,19 +18466,19 @@ impl From<PrintStatement> for Statement {
 
 impl From<ReturnStatement> for Statement {
     fn from(r: ReturnStatement) -> Self {
-        Self::Return(r)
+        Self::Return(Box::new(r))
     }
 }
 
 impl From<CreateUser> for Statement {
     fn from(c: CreateUser) -> Self {
-        Self::CreateUser(c)
+        Self::CreateUser(Box::new(c))
     }
 }
 
 impl From<VacuumStatement> for Statement {
     fn from(v: VacuumStatement) -> Self {
-        Self::Vacuum(v)
+        Self::Vacuum(Box::new(v))
     }
 }
 
@@ -18290,27 +18510,27 @@ mod tests {
     fn test_grouping_sets_display() {
         // a and b in different group
         let grouping_sets = Expr::GroupingSets(vec![
-            vec![Expr::Identifier(Ident::new("a"))],
-            vec![Expr::Identifier(Ident::new("b"))],
+            vec![Expr::Identifier(Box::new(Ident::new("a")))],
+            vec![Expr::Identifier(Box::new(Ident::new("b")))],
         ]);
         assert_eq!("GROUPING SETS ((a), (b))", format!("{grouping_sets}"));
 
         // a and b in the same group
         let grouping_sets = Expr::GroupingSets(vec![vec![
-            Expr::Identifier(Ident::new("a")),
-            Expr::Identifier(Ident::new("b")),
+            Expr::Identifier(Box::new(Ident::new("a"))),
+            Expr::Identifier(Box::new(Ident::new("b"))),
         ]]);
         assert_eq!("GROUPING SETS ((a, b))", format!("{grouping_sets}"));
 
         // (a, b) and (c, d) in different group
         let grouping_sets = Expr::GroupingSets(vec![
             vec![
-                Expr::Identifier(Ident::new("a")),
-                Expr::Identifier(Ident::new("b")),
+                Expr::Identifier(Box::new(Ident::new("a"))),
+                Expr::Identifier(Box::new(Ident::new("b"))),
             ],
             vec![
-                Expr::Identifier(Ident::new("c")),
-                Expr::Identifier(Ident::new("d")),
+                Expr::Identifier(Box::new(Ident::new("c"))),
+                Expr::Identifier(Box::new(Ident::new("d"))),
             ],
         ]);
         assert_eq!("GROUPING SETS ((a, b), (c, d))", format!("{grouping_sets}"));
@@ -18319,16 +18539,16 @@ mod tests {
     #[test]
     fn test_deep_binary_expr_display_does_not_overflow() {
         let mut expr = Expr::Nested(Box::new(Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::new("c0"))),
+            left: Box::new(Expr::Identifier(Box::new(Ident::new("c0")))),
             op: BinaryOperator::Eq,
-            right: Box::new(Expr::Identifier(Ident::new("v0"))),
+            right: Box::new(Expr::Identifier(Box::new(Ident::new("v0")))),
         }));
 
         for i in 1..20_000 {
             let next = Expr::Nested(Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new(format!("c{i}")))),
+                left: Box::new(Expr::Identifier(Box::new(Ident::new(format!("c{i}"))))),
                 op: BinaryOperator::Eq,
-                right: Box::new(Expr::Identifier(Ident::new(format!("v{i}")))),
+                right: Box::new(Expr::Identifier(Box::new(Ident::new(format!("v{i}"))))),
             }));
             expr = Expr::Nested(Box::new(Expr::BinaryOp {
                 left: Box::new(expr),
@@ -18346,85 +18566,85 @@ mod tests {
 
     #[test]
     fn test_rollup_display() {
-        let rollup = Expr::Rollup(vec![vec![Expr::Identifier(Ident::new("a"))]]);
+        let rollup = Expr::Rollup(vec![vec![Expr::Identifier(Box::new(Ident::new("a")))]]);
         assert_eq!("ROLLUP (a)", format!("{rollup}"));
 
         let rollup = Expr::Rollup(vec![vec![
-            Expr::Identifier(Ident::new("a")),
-            Expr::Identifier(Ident::new("b")),
+            Expr::Identifier(Box::new(Ident::new("a"))),
+            Expr::Identifier(Box::new(Ident::new("b"))),
         ]]);
         assert_eq!("ROLLUP ((a, b))", format!("{rollup}"));
 
         let rollup = Expr::Rollup(vec![
-            vec![Expr::Identifier(Ident::new("a"))],
-            vec![Expr::Identifier(Ident::new("b"))],
+            vec![Expr::Identifier(Box::new(Ident::new("a")))],
+            vec![Expr::Identifier(Box::new(Ident::new("b")))],
         ]);
         assert_eq!("ROLLUP (a, b)", format!("{rollup}"));
 
         let rollup = Expr::Rollup(vec![
-            vec![Expr::Identifier(Ident::new("a"))],
+            vec![Expr::Identifier(Box::new(Ident::new("a")))],
             vec![
-                Expr::Identifier(Ident::new("b")),
-                Expr::Identifier(Ident::new("c")),
+                Expr::Identifier(Box::new(Ident::new("b"))),
+                Expr::Identifier(Box::new(Ident::new("c"))),
             ],
-            vec![Expr::Identifier(Ident::new("d"))],
+            vec![Expr::Identifier(Box::new(Ident::new("d")))],
         ]);
         assert_eq!("ROLLUP (a, (b, c), d)", format!("{rollup}"));
     }
 
     #[test]
     fn test_cube_display() {
-        let cube = Expr::Cube(vec![vec![Expr::Identifier(Ident::new("a"))]]);
+        let cube = Expr::Cube(vec![vec![Expr::Identifier(Box::new(Ident::new("a")))]]);
         assert_eq!("CUBE (a)", format!("{cube}"));
 
         let cube = Expr::Cube(vec![vec![
-            Expr::Identifier(Ident::new("a")),
-            Expr::Identifier(Ident::new("b")),
+            Expr::Identifier(Box::new(Ident::new("a"))),
+            Expr::Identifier(Box::new(Ident::new("b"))),
         ]]);
         assert_eq!("CUBE ((a, b))", format!("{cube}"));
 
         let cube = Expr::Cube(vec![
-            vec![Expr::Identifier(Ident::new("a"))],
-            vec![Expr::Identifier(Ident::new("b"))],
+            vec![Expr::Identifier(Box::new(Ident::new("a")))],
+            vec![Expr::Identifier(Box::new(Ident::new("b")))],
         ]);
         assert_eq!("CUBE (a, b)", format!("{cube}"));
 
         let cube = Expr::Cube(vec![
-            vec![Expr::Identifier(Ident::new("a"))],
+            vec![Expr::Identifier(Box::new(Ident::new("a")))],
             vec![
-                Expr::Identifier(Ident::new("b")),
-                Expr::Identifier(Ident::new("c")),
+                Expr::Identifier(Box::new(Ident::new("b"))),
+                Expr::Identifier(Box::new(Ident::new("c"))),
             ],
-            vec![Expr::Identifier(Ident::new("d"))],
+            vec![Expr::Identifier(Box::new(Ident::new("d")))],
         ]);
         assert_eq!("CUBE (a, (b, c), d)", format!("{cube}"));
     }
 
     #[test]
     fn test_interval_display() {
-        let interval = Expr::Interval(Interval {
-            value: Box::new(Expr::Value(
-                Value::SingleQuotedString(String::from("123:45.67")).with_empty_span(),
-            )),
+        let interval = Expr::Interval(Box::new(Interval {
+            value: Box::new(Expr::Value(Box::new(
+                Value::SingleQuotedString("123:45.67".into()).with_empty_span(),
+            ))),
             leading_field: Some(DateTimeField::Minute),
             leading_precision: Some(10),
             last_field: Some(DateTimeField::Second),
             fractional_seconds_precision: Some(9),
-        });
+        }));
         assert_eq!(
             "INTERVAL '123:45.67' MINUTE (10) TO SECOND (9)",
             format!("{interval}"),
         );
 
-        let interval = Expr::Interval(Interval {
-            value: Box::new(Expr::Value(
-                Value::SingleQuotedString(String::from("5")).with_empty_span(),
-            )),
+        let interval = Expr::Interval(Box::new(Interval {
+            value: Box::new(Expr::Value(Box::new(
+                Value::SingleQuotedString("5".into()).with_empty_span(),
+            ))),
             leading_field: Some(DateTimeField::Second),
             leading_precision: Some(1),
             last_field: None,
             fractional_seconds_precision: Some(3),
-        });
+        }));
         assert_eq!("INTERVAL '5' SECOND (1, 3)", format!("{interval}"));
     }
 
@@ -18,7 +18,7 @@
 use crate::ast::{
     ddl::AlterSchema, query::SelectItemQualifiedWildcardKind, AlterSchemaOperation, AlterTable,
     ColumnOptions, CreateAggregate, CreateCast, CreateOperator, CreateOperatorClass,
-    CreateOperatorFamily, CreateStatistics, CreateTypedTable, CreateView, Owner, TypedString,
+    CreateOperatorFamily, CreateStatistics, CreateTypedTable, CreateView, Owner,
 };
 use core::iter;
 
@@ -27,7 +27,7 @@ use crate::tokenizer::Span;
 use super::{
     value::ValueWithSpan, AccessExpr, AlterColumnOperation, AlterIndexOperation,
     AlterTableOperation, Analyze, Array, Assignment, AssignmentTarget, BeginEndStatements,
-    CaseStatement, CloseCursor, ClusteredIndex, ColumnDef, ColumnOption, ColumnOptionDef,
+    CaseExpr, CaseStatement, CloseCursor, ClusteredIndex, ColumnDef, ColumnOption, ColumnOptionDef,
     ConditionalStatementBlock, ConditionalStatements, ConflictTarget, ConnectBy,
     ConstraintCharacteristics, CopySource, CreateIndex, CreateTable, CreateTableOptions, Cte,
     Delete, DoBody, DoStatement, DoUpdate, ExceptSelectItem, Expr, ExprWithAlias, Fetch,
@@ -110,6 +110,7 @@ impl Spanned for Query {
             fetch,
             locks: _,      // todo
             for_clause: _, // todo, mssql specific
+            comments: _,
         } = self;
 
         union_spans(
@@ -431,7 +432,7 @@ impl Spanned for Statement {
             Statement::Checkpoint { checkpoint_token } => checkpoint_token.0,
             Statement::EncryptionKey { token, .. } => token.0,
             Statement::Backup { .. }
-            | Statement::Restore { .. }
+            | Statement::Restore(_)
             | Statement::RecoverPage { .. }
             | Statement::ValidateBackup { .. }
             | Statement::ShowBackups { .. }
@@ -448,17 +449,12 @@ impl Spanned for Statement {
                 .0
                 .union(&schema_name.span())
                 .union_opt(&clone.as_ref().map(|c| c.span())),
-            Statement::CreateDatabase {
-                create_token,
-                db_name,
-                owner,
-                clone,
-                ..
-            } => create_token
+            Statement::CreateDatabase(create_database) => create_database
+                .create_token
                 .0
-                .union(&db_name.span())
-                .union_opt(&owner.as_ref().map(|o| o.span()))
-                .union_opt(&clone.as_ref().map(|c| c.span())),
+                .union(&create_database.db_name.span())
+                .union_opt(&create_database.owner.as_ref().map(|o| o.span()))
+                .union_opt(&create_database.clone.as_ref().map(|c| c.span())),
             Statement::CreateFunction(create_function) => create_function.token.0,
             Statement::CreateDomain(create_domain) => create_domain.token.0,
             Statement::CreateTrigger(create_trigger) => create_trigger.token.0,
@@ -545,9 +541,9 @@ impl Spanned for Statement {
             Statement::Pragma { pragma_token, .. } => pragma_token.0,
             Statement::LockTables { lock_token, .. } => lock_token.0,
             Statement::UnlockTables { unlock_token } => unlock_token.0,
-            Statement::Unload { unload_token, .. } => unload_token.0,
+            Statement::Unload(unload) => unload.unload_token.0,
             Statement::OptimizeTable { token, .. } => token.0,
-            Statement::CreatePolicy { token, .. } => token.0,
+            Statement::CreatePolicy(create_policy) => create_policy.token.0,
             Statement::AlterPolicy { token, .. } => token.0,
             Statement::DropPolicy { token, .. } => token.0,
             Statement::ShowDatabases { show_token, .. } => show_token.0,
@@ -615,7 +611,7 @@ impl Spanned for Statement {
             Statement::CreatePublication { .. } => Span::empty(),
             Statement::AlterPublication { .. } => Span::empty(),
             Statement::DropPublication { .. } => Span::empty(),
-            Statement::CreateSubscription { .. } => Span::empty(),
+            Statement::CreateSubscription(_) => Span::empty(),
             Statement::AlterSubscription { .. } => Span::empty(),
             Statement::DropSubscription { .. } => Span::empty(),
             Statement::CreateRule { .. } => Span::empty(),
@@ -669,6 +665,7 @@ impl Spanned for CreateTable {
             partition_bound: _,
             clustering_by,
             distribution: _,
+            comments: _,
         } = self;
 
         union_spans(
@@ -689,6 +686,7 @@ impl Spanned for ColumnDef {
             name,
             data_type: _, // enum
             options,
+            comments: _,
         } = self;
 
         union_spans(core::iter::once(name.span).chain(options.iter().map(|i| i.span())))
@@ -1064,6 +1062,7 @@ impl Spanned for Delete {
             returning,
             order_by,
             limit,
+            comments: _,
         } = self;
 
         union_spans(
@@ -1099,6 +1098,7 @@ impl Spanned for Update {
             returning,
             limit,
             error_logging: _,
+            comments: _,
         } = self;
 
         union_spans(
@@ -1454,6 +1454,7 @@ impl Spanned for Insert {
             insert_alias: _, // todo, mysql specific
             assignments,
             error_logging: _,
+            comments: _,
         } = self;
 
         union_spans(
@@ -1697,7 +1698,7 @@ impl Spanned for Expr {
                 .union(&union_spans(collation.0.iter().map(|i| i.span()))),
             Expr::Nested(expr) => expr.span(),
             Expr::Value(value) => value.span(),
-            Expr::TypedString(TypedString { value, .. }) => value.span(),
+            Expr::TypedString(typed_string) => typed_string.value.span(),
             Expr::Function(function) => function.span(),
             Expr::OracleKeep {
                 aggregate,
@@ -1810,26 +1811,7 @@ impl Spanned for Expr {
                     ),
             ),
             Expr::Prefixed { value, .. } => value.span(),
-            Expr::Case {
-                case_token,
-                end_token,
-                operand,
-                conditions,
-                else_result,
-            } => union_spans(
-                iter::once(case_token.0)
-                    .chain(
-                        operand
-                            .as_ref()
-                            .map(|i| i.span())
-                            .into_iter()
-                            .chain(conditions.iter().flat_map(|case_when| {
-                                [case_when.condition.span(), case_when.result.span()]
-                            }))
-                            .chain(else_result.as_ref().map(|i| i.span())),
-                    )
-                    .chain(iter::once(end_token.0)),
-            ),
+            Expr::Case(case) => case.span(),
             Expr::Exists { subquery, .. } => subquery.span(),
             Expr::Subquery(query) => query.span(),
             Expr::Struct { .. } => Span::empty(),
@@ -1858,6 +1840,7 @@ impl Spanned for Expr {
                 predicate,
             } => collection.span().union(&predicate.span()),
             Expr::CurrentOf { cursor_name } => cursor_name.span,
+            Expr::Error(token) => token.0,
         }
     }
 }
@@ -2094,8 +2077,12 @@ impl Spanned for SelectItemQualifiedWildcardKind {
 impl Spanned for SelectItem {
     fn span(&self) -> Span {
         match self {
-            SelectItem::UnnamedExpr(expr) => expr.span(),
-            SelectItem::ExprWithAlias { expr, alias } => expr.span().union(&alias.span),
+            SelectItem::UnnamedExpr { expr, comments: _ } => expr.span(),
+            SelectItem::ExprWithAlias {
+                expr,
+                alias,
+                comments: _,
+            } => expr.span().union(&alias.span),
             SelectItem::QualifiedWildcard(kind, wildcard_additional_options) => union_spans(
                 [kind.span()]
                     .into_iter()
@@ -2114,6 +2101,7 @@ impl Spanned for WildcardAdditionalOptions {
             opt_except,
             opt_replace,
             opt_rename,
+            comments: _,
         } = self;
 
         union_spans(
@@ -2314,6 +2302,7 @@ impl Spanned for TableFactor {
                     .map(|i| i.span())
                     .chain(alias.as_ref().map(|a| a.span())),
             ),
+            TableFactor::Error(token) => token.0,
         }
     }
 }
@@ -2328,6 +2317,33 @@ impl Spanned for PivotValueSource {
     }
 }
 
+impl Spanned for CaseExpr {
+    fn span(&self) -> Span {
+        let CaseExpr {
+            case_token,
+            end_token,
+            operand,
+            conditions,
+            else_result,
+        } = self;
+
+        union_spans(
+            iter::once(case_token.0)
+                .chain(
+                    operand
+                        .as_ref()
+                        .map(|i| i.span())
+                        .into_iter()
+                        .chain(conditions.iter().flat_map(|case_when| {
+                            [case_when.condition.span(), case_when.result.span()]
+                        }))
+                        .chain(else_result.as_ref().map(|i| i.span())),
+                )
+                .chain(iter::once(end_token.0)),
+        )
+    }
+}
+
 impl Spanned for ExprWithAlias {
     fn span(&self) -> Span {
         let ExprWithAlias { expr, alias } = self;
@@ -2626,9 +2642,7 @@ impl Spanned for SelectInto {
 
 impl Spanned for UpdateTableFromKind {
     fn span(&self) -> Span {
-        let from = match self {
-            UpdateTableFromKind::AfterSet(from) => from,
-        };
+        let UpdateTableFromKind::AfterSet(from) = self;
         union_spans(from.iter().map(|t| t.span()))
     }
 }
@@ -2718,6 +2732,7 @@ impl Spanned for SqlPsmDeclaration {
             not_null: _,
             default_operator: _,
             default,
+            comments: _,
         } = self;
         union_spans(
             core::iter::once(name.span())
@@ -2736,8 +2751,13 @@ impl Spanned for PlSqlDeclaration {
                 name,
                 data_type,
                 not_null: _,
+                comments: _,
             } => name.span().union(&data_type.span()),
-            Self::RecordType { name, fields } => union_spans(
+            Self::RecordType {
+                name,
+                fields,
+                comments: _,
+            } => union_spans(
                 core::iter::once(name.span()).chain(fields.iter().map(|field| field.span())),
             ),
             Self::CollectionType {
@@ -2745,6 +2765,7 @@ impl Spanned for PlSqlDeclaration {
                 kind,
                 element_type,
                 index_by,
+                comments: _,
             } => {
                 let kind_span = match kind {
                     PlSqlCollectionKind::NestedTable => Span::empty(),
@@ -2756,7 +2777,11 @@ impl Spanned for PlSqlDeclaration {
                         .chain(index_by.iter().map(|data_type| data_type.span())),
                 )
             }
-            Self::RefCursorType { name, return_type } => union_spans(
+            Self::RefCursorType {
+                name,
+                return_type,
+                comments: _,
+            } => union_spans(
                 core::iter::once(name.span())
                     .chain(return_type.iter().map(|data_type| data_type.span())),
             ),
@@ -2765,13 +2790,14 @@ impl Spanned for PlSqlDeclaration {
                 parameters,
                 return_type,
                 query,
+                comments: _,
             } => union_spans(
                 core::iter::once(name.span())
                     .chain(parameters.iter().map(|parameter| parameter.span()))
                     .chain(return_type.iter().map(|data_type| data_type.span()))
                     .chain(core::iter::once(query.span())),
             ),
-            Self::Exception { name } => name.span(),
+            Self::Exception { name, comments: _ } => name.span(),
             Self::Pragma(pragma) => pragma.span(),
             Self::Routine(routine) => routine.name.span(),
         }
@@ -3154,10 +3180,17 @@ pub mod tests {
             Statement::Query(q) => {
                 let col = &q.body.as_select().unwrap().projection[0];
                 match col {
-                    SelectItem::UnnamedExpr(Expr::Value(ValueWithSpan {
-                        value: Value::Placeholder(s),
-                        span,
-                    })) => {
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::Value(value),
+                        ..
+                    } => {
+                        let ValueWithSpan {
+                            value: Value::Placeholder(s),
+                            span,
+                        } = &**value
+                        else {
+                            panic!("expected placeholder; got {value:?}");
+                        };
                         assert_eq!(":foobar", s);
                         assert_eq!(&Span::new((3, 3).into(), (3, 10).into()), span);
                     }
@@ -20,13 +20,19 @@ use alloc::string::String;
 
 use core::fmt;
 
+use crate::ast::Box;
+
 #[cfg(feature = "bigdecimal")]
 use bigdecimal::BigDecimal;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{ast::Ident, tokenizer::Span};
+use crate::{
+    ast::{AstStr, Ident},
+    tokenizer::Span,
+};
+use sqlparser_derive::{AstCodec, HeapSize};
 #[cfg(feature = "visitor")]
 use sqlparser_derive::{Visit, VisitMut};
 
@@ -36,7 +42,7 @@ use sqlparser_derive::{Visit, VisitMut};
 /// ```
 /// # use sqlparser::ast::{Value, ValueWithSpan};
 /// # use sqlparser::tokenizer::{Location, Span};
-/// let value = Value::SingleQuotedString(String::from("endpoint"));
+/// let value = Value::SingleQuotedString("endpoint".into());
 /// // from line 1, column 1 to line 1, column 7
 /// let span = Span::new(Location::new(1, 1), Location::new(1, 7));
 /// let value_with_span = value.with_span(span);
@@ -48,7 +54,7 @@ use sqlparser_derive::{Visit, VisitMut};
 /// ```
 /// # use sqlparser::ast::{Value, ValueWithSpan};
 /// # use sqlparser::tokenizer::{Location, Span};
-/// let value = Value::SingleQuotedString(String::from("endpoint"));
+/// let value = Value::SingleQuotedString("endpoint".into());
 /// let value_with_span = value.with_empty_span();
 /// assert_eq!(value_with_span.span, Span::empty());
 /// ```
@@ -57,14 +63,14 @@ use sqlparser_derive::{Visit, VisitMut};
 /// ```
 /// # use sqlparser::ast::{Value, ValueWithSpan};
 /// # use sqlparser::tokenizer::{Location, Span};
-/// let value = Value::SingleQuotedString(String::from("endpoint"));
+/// let value = Value::SingleQuotedString("endpoint".into());
 /// // converting `Value` to `ValueWithSpan` results in an empty span
 /// let value_with_span: ValueWithSpan = value.into();
 /// assert_eq!(value_with_span.span, Span::empty());
 /// // convert back to `Value`
 /// let value: Value = value_with_span.into();
 /// ```
-#[derive(Debug, Clone, Eq)]
+#[derive(Debug, Clone, Eq, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct ValueWithSpan {
@@ -109,7 +115,7 @@ impl From<ValueWithSpan> for Value {
 }
 
 /// Primitive SQL values such as number and string
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "visitor",
@@ -119,20 +125,20 @@ impl From<ValueWithSpan> for Value {
 pub enum Value {
     /// Numeric literal
     #[cfg(not(feature = "bigdecimal"))]
-    Number(String, bool),
+    Number(AstStr, bool),
     #[cfg(feature = "bigdecimal")]
     // HINT: use `test_utils::number` to make an instance of
     // Value::Number This might help if you your tests pass locally
     // but fail on CI with the `--all-features` flag enabled
-    Number(BigDecimal, bool),
+    Number(Box<BigDecimal>, bool),
     /// Oracle binary single-precision floating-point literal (`1.25f`).
     OracleBinaryFloat(String),
     /// Oracle binary double-precision floating-point literal (`6.022d`).
     OracleBinaryDouble(String),
     /// 'string value'
-    SingleQuotedString(String),
+    SingleQuotedString(AstStr),
     // $<tag_name>$string value$<tag_name>$ (postgres syntax)
-    DollarQuotedString(DollarQuotedString),
+    DollarQuotedString(Box<DollarQuotedString>),
     /// e'string value' (postgres extension)
     /// See [Postgres docs](https://www.postgresql.org/docs/8.3/sql-syntax-lexical.html#SQL-SYNTAX-STRINGS)
     /// for more details.
@@ -146,7 +152,7 @@ pub enum Value {
     /// N'string value'
     NationalStringLiteral(String),
     /// q'<delimiter>string value<delimiter>'
-    AlternativeQuotedString(AlternativeQuotedString),
+    AlternativeQuotedString(Box<AlternativeQuotedString>),
     /// X'hex value'
     HexStringLiteral(String),
 
@@ -156,7 +162,7 @@ pub enum Value {
     /// `NULL` value
     Null,
     /// `?` or `$` Prepared statement arg placeholder
-    Placeholder(String),
+    Placeholder(AstStr),
     /// A PL/SQL inquiry directive such as `$$PLSQL_UNIT`.
     PlSqlInquiryDirective(String),
 }
@@ -172,15 +178,15 @@ impl Value {
     /// If the underlying literal is a string, regardless of quote style, returns the associated string value
     pub fn into_string(self) -> Option<String> {
         match self {
-            Value::SingleQuotedString(s)
-            | Value::DoubleQuotedString(s)
+            Value::SingleQuotedString(s) => Some(s.into_string()),
+            Value::DoubleQuotedString(s)
             | Value::SingleQuotedByteStringLiteral(s)
             | Value::EscapedStringLiteral(s)
             | Value::UnicodeStringLiteral(s)
             | Value::NationalStringLiteral(s)
             | Value::HexStringLiteral(s) => Some(s),
-            Value::AlternativeQuotedString(s) => Some(s.value),
-            Value::DollarQuotedString(s) => Some(s.value),
+            Value::AlternativeQuotedString(s) => Some(Box::into_owned(s).value),
+            Value::DollarQuotedString(s) => Some(Box::into_owned(s).value),
             _ => None,
         }
     }
@@ -223,7 +229,7 @@ impl fmt::Display for Value {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct AlternativeQuotedString {
@@ -255,7 +261,7 @@ impl fmt::Display for AlternativeQuotedString {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct DollarQuotedString {
@@ -276,7 +282,7 @@ impl fmt::Display for DollarQuotedString {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum DateTimeField {
@@ -290,7 +296,7 @@ pub enum DateTimeField {
     /// WEEK(MONDAY)
     /// ```
     ///
-    Week(Option<Ident>),
+    Week(Option<Box<Ident>>),
     Weeks,
     Day,
     DayOfWeek,
@@ -334,7 +340,7 @@ pub enum DateTimeField {
     /// ```sql
     /// EXTRACT(q FROM CURRENT_TIMESTAMP)
     /// ```
-    Custom(Ident),
+    Custom(Box<Ident>),
 }
 
 impl fmt::Display for DateTimeField {
@@ -394,7 +400,7 @@ impl fmt::Display for DateTimeField {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 /// The Unicode Standard defines four normalization forms, which are intended to eliminate
@@ -426,7 +432,7 @@ impl fmt::Display for NormalizationForm {
 /// The type of JSON predicate used in `<expr> IS [NOT] JSON [<type>]`.
 ///
 /// See SQL:2016 standard, section 8.20 (JSON predicate).
-#[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum JsonPredicateType {
@@ -454,7 +460,7 @@ impl fmt::Display for JsonPredicateType {
 /// The unique keys constraint for the JSON predicate.
 ///
 /// See SQL:2016 standard, section 8.20 (JSON predicate).
-#[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum JsonPredicateUniqueKeyConstraint {
@@ -617,7 +623,7 @@ pub fn escape_unicode_string(s: &str) -> EscapeUnicodeStringLiteral<'_> {
     EscapeUnicodeStringLiteral(s)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TrimWhereField {
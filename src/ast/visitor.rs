@@ -24,7 +24,10 @@ use std::borrow::Cow;
 
 use crate::ast::helpers::attached_token::AttachedToken;
 use crate::ast::Box;
-use crate::ast::{DataType, Expr, ObjectName, Query, Statement, TableFactor, Value};
+use crate::ast::{
+    ArenaResident, AstStr, AstVec, DataType, Expr, Ident, ObjectName, Query, Statement,
+    TableFactor, Value,
+};
 use core::ops::ControlFlow;
 
 /// A type that can be visited by a [`Visitor`]. See [`Visitor`] for
@@ -75,6 +78,15 @@ impl<T: Visit> Visit for Vec<T> {
     }
 }
 
+impl<T: Visit> Visit for AstVec<T> {
+    fn visit<V: Visitor>(&self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for v in self {
+            v.visit(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+
 impl<T: Visit> Visit for Box<T> {
     fn visit<V: Visitor>(&self, visitor: &mut V) -> ControlFlow<V::Break> {
         T::visit(self, visitor)
@@ -99,7 +111,16 @@ impl<T: VisitMut> VisitMut for Vec<T> {
     }
 }
 
-impl<T: VisitMut> VisitMut for Box<T> {
+impl<T: VisitMut> VisitMut for AstVec<T> {
+    fn visit<V: VisitorMut>(&mut self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for v in self {
+            v.visit(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+impl<T: VisitMut + Clone + ArenaResident> VisitMut for Box<T> {
     fn visit<V: VisitorMut>(&mut self, visitor: &mut V) -> ControlFlow<V::Break> {
         T::visit(self, visitor)
     }
@@ -120,7 +141,7 @@ macro_rules! visit_noop {
     };
 }
 
-visit_noop!(u8, u16, u32, u64, i8, i16, i32, i64, char, bool, String);
+visit_noop!(u8, u16, u32, u64, i8, i16, i32, i64, char, bool, String, AstStr);
 
 #[cfg(feature = "bigdecimal")]
 visit_noop!(bigdecimal::BigDecimal);
@@ -287,6 +308,16 @@ pub trait Visitor {
     fn post_visit_token(&mut self, _token: &AttachedToken) -> ControlFlow<Self::Break> {
         ControlFlow::Continue(())
     }
+
+    /// Invoked for any identifier that appear in the AST before visiting children
+    fn pre_visit_ident(&mut self, _ident: &Ident) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked for any identifier that appear in the AST after visiting children
+    fn post_visit_ident(&mut self, _ident: &Ident) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
 }
 
 /// A visitor that can be used to mutate an AST tree.
@@ -306,7 +337,7 @@ pub trait Visitor {
 /// ```
 /// # use sqlparser::parser::Parser;
 /// # use sqlparser::dialect::PostgreSqlDialect;
-/// # use sqlparser::ast::{VisitMut, VisitorMut, ObjectName, Expr, Ident};
+/// # use sqlparser::ast::{VisitMut, VisitorMut, ObjectName, Expr};
 /// # use core::ops::ControlFlow;
 ///
 /// // A visitor that replaces "to_replace" with "replaced" in all expressions
@@ -317,8 +348,8 @@ pub trait Visitor {
 ///   type Break = ();
 ///
 ///   fn post_visit_expr(&mut self, expr: &mut Expr) -> ControlFlow<Self::Break> {
-///     if let Expr::Identifier(Ident{ value, ..}) = expr {
-///         *value = value.replace("to_replace", "replaced")
+///     if let Expr::Identifier(ident) = expr {
+///         ident.value = ident.value.replace("to_replace", "replaced").into()
 ///     }
 ///     ControlFlow::Continue(())
 ///   }
@@ -425,6 +456,16 @@ pub trait VisitorMut {
     fn post_visit_token(&mut self, _token: &mut AttachedToken) -> ControlFlow<Self::Break> {
         ControlFlow::Continue(())
     }
+
+    /// Invoked for any identifier that appear in the AST before visiting children
+    fn pre_visit_ident(&mut self, _ident: &mut Ident) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked for any identifier that appear in the AST after visiting children
+    fn post_visit_ident(&mut self, _ident: &mut Ident) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
 }
 
 struct RelationVisitor<F>(F);
@@ -616,12 +657,12 @@ where
 /// visit_expressions_mut(&mut statements, |expr| {
 ///   if matches!(expr, Expr::Identifier(col_name) if col_name.value == "x") {
 ///     let old_expr = std::mem::replace(expr, Expr::value(Value::Null));
-///     *expr = Expr::Function(Function {
+///     *expr = Expr::Function(AstBox::new(Function {
 ///           name: ObjectName::from(vec![Ident::new("f")]),
 ///           uses_odbc_syntax: false,
 ///           args: FunctionArguments::List(FunctionArgumentList {
 ///               duplicate_treatment: None,
-///               args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(old_expr))],
+///               args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(old_expr))].into(),
 ///               clauses: vec![],
 ///           }),
 ///           null_treatment: None,
@@ -630,7 +671,7 @@ where
 ///           parameters: FunctionArguments::None,
 ///           within_group: vec![],
 ///           nth_value_order: None,
-///      });
+///      }));
 ///   }
 ///   ControlFlow::<()>::Continue(())
 /// });
@@ -1035,7 +1076,7 @@ mod visit_mut_tests {
 
         fn pre_visit_value(&mut self, value: &mut Value) -> ControlFlow<Self::Break> {
             self.index += 1;
-            *value = Value::SingleQuotedString(format!("REDACTED_{}", self.index));
+            *value = Value::SingleQuotedString(format!("REDACTED_{}", self.index).into());
             ControlFlow::Continue(())
         }
 
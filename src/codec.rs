@@ -0,0 +1,809 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Versioned binary encoding of syntax trees
+//!
+//! [`AstCodec`] stores a [`Statement`](crate::ast::Statement),
+//! [`Expr`](crate::ast::Expr), [`DataType`](crate::ast::DataType) or any
+//! other AST value as compact bytes, keeping every span and quote style, so
+//! a stored tree can be restored without rendering and reparsing SQL.
+//! [`ParsedSql::to_bytes`](crate::ParsedSql::to_bytes) encodes a whole
+//! document, and its decoder rebuilds the statements directly in a new
+//! document arena.
+//!
+//! Every encoding starts with a header holding [`AST_CODEC_VERSION`] and a
+//! fingerprint of the shape of the encoded type: its type, variant and field
+//! names, in order, down to the leaves. Bytes written by a build whose AST
+//! differs in any way are rejected with
+//! [`AstCodecError::SchemaMismatch`] instead of being misread. Keywords are
+//! stored by name, so adding keywords keeps stored trees readable.
+//!
+//! ```
+//! # use sqlparser::ast::{AstCodec, Statement};
+//! # use sqlparser::dialect::PostgreSqlDialect;
+//! # use sqlparser::parser::Parser;
+//! let statements = Parser::parse_sql(&PostgreSqlDialect {}, "SELECT \"A\" FROM t").unwrap();
+//! let bytes = statements[0].to_bytes();
+//! let decoded = Statement::from_bytes(&bytes).unwrap();
+//! assert_eq!(decoded, statements[0]);
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    borrow::Cow, borrow::ToOwned, boxed::Box, collections::BTreeSet, string::String, vec::Vec,
+};
+use core::any::TypeId;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::{borrow::Cow, collections::BTreeSet};
+
+use crate::arena::{ArenaResident, AstBox, AstStr, AstVec};
+use crate::keywords::{Keyword, ALL_KEYWORDS, ALL_KEYWORDS_INDEX};
+
+/// Version of the encoding format written by [`AstCodec::to_bytes`].
+///
+/// It changes when the way values are laid out changes. Changes to the AST
+/// itself are detected by the schema fingerprint instead.
+pub const AST_CODEC_VERSION: u16 = 1;
+
+const MAGIC: &[u8; 4] = b"SQLA";
+
+/// An error decoding bytes written by [`AstCodec`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AstCodecError {
+    /// The bytes do not start with an AST encoding header.
+    InvalidHeader,
+    /// The bytes were written with another version of the encoding format.
+    UnsupportedVersion { found: u16, expected: u16 },
+    /// The bytes were written for another type, or by a build whose AST has
+    /// a different shape.
+    SchemaMismatch { found: u64, expected: u64 },
+    /// The bytes end in the middle of a value.
+    UnexpectedEnd,
+    /// An enum variant index that the type does not have.
+    InvalidVariant { type_name: &'static str, index: u64 },
+    /// A leaf value that is out of range or malformed.
+    InvalidValue(&'static str),
+    /// Bytes remain after the encoded value.
+    TrailingBytes,
+}
+
+impl fmt::Display for AstCodecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AstCodecError::InvalidHeader => f.write_str("ast codec error: invalid header"),
+            AstCodecError::UnsupportedVersion { found, expected } => write!(
+                f,
+                "ast codec error: unsupported version {found}, expected {expected}"
+            ),
+            AstCodecError::SchemaMismatch { found, expected } => write!(
+                f,
+                "ast codec error: schema fingerprint {found:016x} does not match {expected:016x}"
+            ),
+            AstCodecError::UnexpectedEnd => f.write_str("ast codec error: unexpected end of input"),
+            AstCodecError::InvalidVariant { type_name, index } => {
+                write!(f, "ast codec error: invalid variant {index} of {type_name}")
+            }
+            AstCodecError::InvalidValue(what) => write!(f, "ast codec error: invalid {what}"),
+            AstCodecError::TrailingBytes => f.write_str("ast codec error: trailing bytes"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AstCodecError {}
+
+/// Binary encoding of an AST value.
+///
+/// The trait is derived for every AST type. Fields are written in
+/// declaration order and enum variants by position, so the encoding carries
+/// no names; [`describe`](Self::describe) records them in the schema
+/// fingerprint instead.
+pub trait AstCodec: Sized {
+    /// Append the encoding of this value to `encoder`.
+    fn encode(&self, encoder: &mut AstEncoder<'_>);
+
+    /// Read a value written by [`encode`](Self::encode).
+    fn decode(decoder: &mut AstDecoder<'_>) -> Result<Self, AstCodecError>;
+
+    /// Add the shape of this type to `schema`.
+    fn describe(schema: &mut AstSchema);
+
+    /// Encode this value behind a version and schema header.
+    fn to_bytes(&self) -> Vec<u8>
+    where
+        Self: 'static,
+    {
+        let mut encoder = AstEncoder::new();
+        encoder.write_header(schema_fingerprint::<Self>());
+        self.encode(&mut encoder);
+        encoder.into_bytes()
+    }
+
+    /// Decode a value written by [`to_bytes`](Self::to_bytes), rejecting
+    /// bytes written with another format version or AST shape.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, AstCodecError>
+    where
+        Self: 'static,
+    {
+        let mut decoder = AstDecoder::new(bytes);
+        decoder.read_header(schema_fingerprint::<Self>())?;
+        let value = Self::decode(&mut decoder)?;
+        decoder.finish()?;
+        Ok(value)
+    }
+}
+
+/// Output buffer of [`AstCodec::encode`].
+pub struct AstEncoder<'a> {
+    bytes: Vec<u8>,
+    // The source text of the document being encoded. Leaf text borrowed
+    // from it is written as a byte range instead of being copied.
+    source: Option<&'a str>,
+}
+
+impl<'a> AstEncoder<'a> {
+    /// An encoder that copies all leaf text.
+    pub fn new() -> Self {
+        Self {
+            bytes: Vec::new(),
+            source: None,
+        }
+    }
+
+    /// An encoder that writes leaf text borrowed from `source` as a byte
+    /// range of it. The bytes must be decoded with the same source.
+    pub fn with_source(source: &'a str) -> Self {
+        Self {
+            bytes: Vec::new(),
+            source: Some(source),
+        }
+    }
+
+    /// The bytes written so far.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn set_source(&mut self, source: &'a str) {
+        self.source = Some(source);
+    }
+
+    pub(crate) fn write_header(&mut self, fingerprint: u64) {
+        self.bytes.extend_from_slice(MAGIC);
+        self.bytes
+            .extend_from_slice(&AST_CODEC_VERSION.to_le_bytes());
+        self.bytes.extend_from_slice(&fingerprint.to_le_bytes());
+    }
+
+    /// Write an unsigned integer as a LEB128 varint.
+    pub fn write_varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.bytes.push(value as u8 | 0x80);
+            value >>= 7;
+        }
+        self.bytes.push(value as u8);
+    }
+
+    /// Write raw bytes.
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    /// Write a length or an enum variant index.
+    pub fn write_len(&mut self, len: usize) {
+        self.write_varint(len as u64);
+    }
+
+    /// Write leaf text, as a range of the source when it is a slice of it.
+    pub fn write_str(&mut self, text: &str) {
+        let len = text.len() as u64;
+        if let Some(offset) = self.source_offset(text) {
+            self.write_varint(len << 1 | 1);
+            self.write_varint(offset as u64);
+        } else {
+            self.write_varint(len << 1);
+            self.write_bytes(text.as_bytes());
+        }
+    }
+
+    fn source_offset(&self, text: &str) -> Option<usize> {
+        let source = self.source?.as_bytes().as_ptr_range();
+        let text_range = text.as_bytes().as_ptr_range();
+        (!text.is_empty() && source.start <= text_range.start && text_range.end <= source.end)
+            .then(|| text_range.start as usize - source.start as usize)
+    }
+}
+
+impl Default for AstEncoder<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Input of [`AstCodec::decode`].
+pub struct AstDecoder<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    source: Option<&'a str>,
+}
+
+impl<'a> AstDecoder<'a> {
+    /// A decoder over bytes written without a source.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            position: 0,
+            source: None,
+        }
+    }
+
+    /// A decoder over bytes written by [`AstEncoder::with_source`] for
+    /// `source`. Leaf text stored as a range of the source is sliced from
+    /// it, which borrows the slice when decoding into a document arena.
+    pub fn with_source(bytes: &'a [u8], source: &'a str) -> Self {
+        Self {
+            bytes,
+            position: 0,
+            source: Some(source),
+        }
+    }
+
+    /// Bytes not read yet.
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    pub(crate) fn read_header(&mut self, expected: u64) -> Result<(), AstCodecError> {
+        if self.read_bytes(MAGIC.len()).ok() != Some(MAGIC.as_slice()) {
+            return Err(AstCodecError::InvalidHeader);
+        }
+        let version = u16::from_le_bytes(self.read_array()?);
+        if version != AST_CODEC_VERSION {
+            return Err(AstCodecError::UnsupportedVersion {
+                found: version,
+                expected: AST_CODEC_VERSION,
+            });
+        }
+        let found = u64::from_le_bytes(self.read_array()?);
+        if found != expected {
+            return Err(AstCodecError::SchemaMismatch { found, expected });
+        }
+        Ok(())
+    }
+
+    /// The bytes not read yet.
+    #[cfg(feature = "std")]
+    pub(crate) fn rest(&self) -> &'a [u8] {
+        &self.bytes[self.position..]
+    }
+
+    /// Fail unless every byte has been read.
+    pub fn finish(&self) -> Result<(), AstCodecError> {
+        if self.remaining() == 0 {
+            Ok(())
+        } else {
+            Err(AstCodecError::TrailingBytes)
+        }
+    }
+
+    /// Read a LEB128 varint.
+    pub fn read_varint(&mut self) -> Result<u64, AstCodecError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let [byte] = self.read_array()?;
+            let bits = u64::from(byte & 0x7f);
+            if shift == 63 && bits > 1 {
+                return Err(AstCodecError::InvalidValue("varint"));
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(AstCodecError::InvalidValue("varint"))
+    }
+
+    /// Read `len` raw bytes.
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], AstCodecError> {
+        if len > self.remaining() {
+            return Err(AstCodecError::UnexpectedEnd);
+        }
+        let bytes = &self.bytes[self.position..self.position + len];
+        self.position += len;
+        Ok(bytes)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], AstCodecError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.read_bytes(N)?);
+        Ok(array)
+    }
+
+    /// Read an enum variant index.
+    pub fn read_variant(&mut self) -> Result<u64, AstCodecError> {
+        self.read_varint()
+    }
+
+    /// Read the length of a list. Every element takes at least one byte,
+    /// so a length beyond the remaining input is rejected before anything
+    /// is allocated for it.
+    pub fn read_len(&mut self) -> Result<usize, AstCodecError> {
+        let len = self.read_varint()?;
+        if len > self.remaining() as u64 {
+            return Err(AstCodecError::UnexpectedEnd);
+        }
+        Ok(len as usize)
+    }
+
+    /// Read leaf text written by [`AstEncoder::write_str`].
+    pub fn read_str(&mut self) -> Result<&'a str, AstCodecError> {
+        let header = self.read_varint()?;
+        let len = usize::try_from(header >> 1).map_err(|_| AstCodecError::UnexpectedEnd)?;
+        if header & 1 == 0 {
+            return core::str::from_utf8(self.read_bytes(len)?)
+                .map_err(|_| AstCodecError::InvalidValue("utf-8 text"));
+        }
+        let offset = usize::try_from(self.read_varint()?)
+            .map_err(|_| AstCodecError::InvalidValue("source range"))?;
+        let source = self
+            .source
+            .ok_or(AstCodecError::InvalidValue("source range without a source"))?;
+        offset
+            .checked_add(len)
+            .and_then(|end| source.get(offset..end))
+            .ok_or(AstCodecError::InvalidValue("source range"))
+    }
+}
+
+/// Fingerprint of the shape of an [`AstCodec`] type.
+///
+/// [`AstCodec::describe`] feeds the names of a type, its variants and its
+/// fields, in order, followed by the shape of each field. A type already
+/// described is referred to by name, which also ends recursion.
+pub struct AstSchema {
+    hash: u64,
+    described: BTreeSet<&'static str>,
+}
+
+impl AstSchema {
+    /// An empty schema.
+    pub fn new() -> Self {
+        Self {
+            hash: 0xcbf2_9ce4_8422_2325,
+            described: BTreeSet::new(),
+        }
+    }
+
+    /// The fingerprint of the shape of `T`.
+    pub fn of<T: AstCodec>() -> u64 {
+        let mut schema = Self::new();
+        T::describe(&mut schema);
+        schema.fingerprint()
+    }
+
+    /// The fingerprint of everything described so far.
+    pub fn fingerprint(&self) -> u64 {
+        self.hash
+    }
+
+    fn write(&mut self, tag: u8, name: &str) {
+        // 64-bit FNV-1a over the tag, the name length and the name.
+        let len = (name.len() as u32).to_le_bytes();
+        for byte in [tag].iter().chain(&len).chain(name.as_bytes()) {
+            self.hash ^= u64::from(*byte);
+            self.hash = self.hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    /// Describe a leaf encoding such as an integer or text.
+    pub fn leaf(&mut self, name: &str) {
+        self.write(b'l', name);
+    }
+
+    /// Start describing the type `name`. Returns `false`, having recorded
+    /// a reference to it, when `memoize` is set and `name` was already
+    /// described; otherwise the caller describes its variants and fields
+    /// and calls [`end_type`](Self::end_type). Generic types must not be
+    /// memoized, since their name does not identify their shape.
+    pub fn begin_type(&mut self, name: &'static str, memoize: bool) -> bool {
+        if memoize && !self.described.insert(name) {
+            self.write(b'r', name);
+            return false;
+        }
+        self.write(b't', name);
+        true
+    }
+
+    /// Start the next variant of the enum being described.
+    pub fn variant(&mut self, name: &str) {
+        self.write(b'v', name);
+    }
+
+    /// Name the next field, whose shape follows.
+    pub fn field(&mut self, name: &str) {
+        self.write(b'f', name);
+    }
+
+    /// End the type started by [`begin_type`](Self::begin_type).
+    pub fn end_type(&mut self) {
+        self.write(b'e', "");
+    }
+}
+
+impl Default for AstSchema {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The schema fingerprint of `T`, computed once per type.
+pub(crate) fn schema_fingerprint<T: AstCodec + 'static>() -> u64 {
+    cached_fingerprint(TypeId::of::<T>(), T::describe)
+}
+
+/// The fingerprint described by `describe`, cached under `key`.
+pub(crate) fn cached_fingerprint(key: TypeId, describe: fn(&mut AstSchema)) -> u64 {
+    let fingerprint = || {
+        let mut schema = AstSchema::new();
+        describe(&mut schema);
+        schema.fingerprint()
+    };
+    #[cfg(feature = "std")]
+    {
+        use std::collections::BTreeMap;
+        use std::sync::{Mutex, PoisonError};
+
+        static FINGERPRINTS: Mutex<BTreeMap<TypeId, u64>> = Mutex::new(BTreeMap::new());
+        if let Some(fingerprint) = FINGERPRINTS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&key)
+        {
+            return *fingerprint;
+        }
+        let fingerprint = fingerprint();
+        FINGERPRINTS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, fingerprint);
+        fingerprint
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = key;
+        fingerprint()
+    }
+}
+
+impl<T: AstCodec + ArenaResident> AstCodec for AstBox<T> {
+    #[cfg_attr(feature = "recursive-protection", recursive::recursive)]
+    fn encode(&self, encoder: &mut AstEncoder<'_>) {
+        (**self).encode(encoder);
+    }
+
+    #[cfg_attr(feature = "recursive-protection", recursive::recursive)]
+    fn decode(decoder: &mut AstDecoder<'_>) -> Result<Self, AstCodecError> {
+        T::decode(decoder).map(AstBox::new)
+    }
+
+    fn describe(schema: &mut AstSchema) {
+        T::describe(schema);
+    }
+}
+
+impl<T: AstCodec> AstCodec for Box<T> {
+    fn encode(&self, encoder: &mut AstEncoder<'_>) {
+        (**self).encode(encoder);
+    }
+
+    fn decode(decoder: &mut AstDecoder<'_>) -> Result<Self, AstCodecError> {
+        T::decode(decoder).map(Box::new)
+    }
+
+    fn describe(schema: &mut AstSchema) {
+        T::describe(schema);
+    }
+}
+
+impl<T: AstCodec> AstCodec for Vec<T> {
+    fn encode(&self, encoder: &mut AstEncoder<'_>) {
+        encoder.write_len(self.len());
+        self.iter().for_each(|element| element.encode(encoder));
+    }
+
+    fn decode(decoder: &mut AstDecoder<'_>) -> Result<Self, AstCodecError> {
+        let len = decoder.read_len()?;
+        let mut elements = Vec::with_capacity(len);
+        for _ in 0..len {
+            elements.push(T::decode(decoder)?);
+        }
+        Ok(elements)
+    }
+
+    fn describe(schema: &mut AstSchema) {
+        schema.leaf("seq");
+        T::describe(schema);
+    }
+}
+
+impl<T: AstCodec> AstCodec for AstVec<T> {
+    fn encode(&self, encoder: &mut AstEncoder<'_>) {
+        encoder.write_len(self.len());
+        self.iter().for_each(|element| element.encode(encoder));
+    }
+
+    fn decode(decoder: &mut AstDecoder<'_>) -> Result<Self, AstCodecError> {
+        Vec::decode(decoder).map(AstVec::from)
+    }
+
+    fn describe(schema: &mut AstSchema) {
+        Vec::<T>::describe(schema);
+    }
+}
+
+impl<T: AstCodec> AstCodec for Option<T> {
+    fn encode(&self, encoder: &mut AstEncoder<'_>) {
+        match self {
+            None => encoder.write_len(0),
+            Some(value) => {
+                encoder.write_len(1);
+                value.encode(encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut AstDecoder<'_>) -> Result<Self, AstCodecError> {
+        match decoder.read_variant()? {
+            0 => Ok(None),
+            1 => T::decode(decoder).map(Some),
+            index => Err(AstCodecError::InvalidVariant {
+                type_name: "Option",
+                index,
+            }),
+        }
+    }
+
+    fn describe(schema: &mut AstSchema) {
+        schema.leaf("option");
+        T::describe(schema);
+    }
+}
+
+impl<A: AstCodec, B: AstCodec> AstCodec for (A, B) {
+    fn encode(&self, encoder: &mut AstEncoder<'_>) {
+        self.0.encode(encoder);
+        self.1.encode(encoder);
+    }
+
+    fn decode(decoder: &mut AstDecoder<'_>) -> Result<Self, AstCodecError> {
+        Ok((A::decode(decoder)?, B::decode(decoder)?))
+    }
+
+    fn describe(schema: &mut AstSchema) {
+        schema.leaf("pair");
+        A::describe(schema);
+        B::describe(schema);
+    }
+}
+
+// Every kind of leaf text shares one encoding, so a field may change
+// between them without invalidating stored trees.
+impl AstCodec for AstStr {
+    fn encode(&self, encoder: &mut AstEncoder<'_>) {
+        encoder.write_str(self);
+    }
+
+    fn decode(decoder: &mut AstDecoder<'_>) -> Result<Self, AstCodecError> {
+        decoder.read_str().map(AstStr::from)
+    }
+
+    fn describe(schema: &mut AstSchema) {
+        schema.leaf("str");
+    }
+}
+
+impl AstCodec for String {
+    fn encode(&self, encoder: &mut AstEncoder<'_>) {
+        encoder.write_str(self);
+    }
+
+    fn decode(decoder: &mut AstDecoder<'_>) -> Result<Self, AstCodecError> {
+        decoder.read_str().map(str::to_owned)
+    }
+
+    fn describe(schema: &mut AstSchema) {
+        schema.leaf("str");
+    }
+}
+
+impl AstCodec for Cow<'_, str> {
+    fn encode(&self, encoder: &mut AstEncoder<'_>) {
+        encoder.write_str(self);
+    }
+
+    fn decode(decoder: &mut AstDecoder<'_>) -> Result<Self, AstCodecError> {
+        decoder.read_str().map(|text| Cow::Owned(text.to_owned()))
+    }
+
+    fn describe(schema: &mut AstSchema) {
+        schema.leaf("str");
+    }
+}
+
+// Keywords are stored by name rather than by their position in the keyword
+// list, which changes whenever a keyword is added.
+impl AstCodec for Keyword {
+    fn encode(&self, encoder: &mut AstEncoder<'_>) {
+        let name = match *self {
+            Keyword::NoKeyword => "",
+            keyword => ALL_KEYWORDS[keyword as usize - 1],
+        };
+        encoder.write_len(name.len());
+        encoder.write_bytes(name.as_bytes());
+    }
+
+    fn decode(decoder: &mut AstDecoder<'_>) -> Result<Self, AstCodecError> {
+        let len = decoder.read_len()?;
+        let name = decoder.read_bytes(len)?;
+        if name.is_empty() {
+            return Ok(Keyword::NoKeyword);
+        }
+        ALL_KEYWORDS
+            .binary_search_by(|keyword| keyword.as_bytes().cmp(name))
+            .map(|index| ALL_KEYWORDS_INDEX[index])
+            .map_err(|_| AstCodecError::InvalidValue("keyword"))
+    }
+
+    fn describe(schema: &mut AstSchema) {
+        schema.leaf("keyword");
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl AstCodec for bigdecimal::BigDecimal {
+    fn encode(&self, encoder: &mut AstEncoder<'_>) {
+        encoder.write_str(&self.to_string());
+    }
+
+    fn decode(decoder: &mut AstDecoder<'_>) -> Result<Self, AstCodecError> {
+        decoder
+            .read_str()?
+            .parse()
+            .map_err(|_| AstCodecError::InvalidValue("decimal"))
+    }
+
+    fn describe(schema: &mut AstSchema) {
+        schema.leaf("decimal");
+    }
+}
+
+impl AstCodec for bool {
+    fn encode(&self, encoder: &mut AstEncoder<'_>) {
+        encoder.write_bytes(&[u8::from(*self)]);
+    }
+
+    fn decode(decoder: &mut AstDecoder<'_>) -> Result<Self, AstCodecError> {
+        match decoder.read_array()? {
+            [0] => Ok(false),
+            [1] => Ok(true),
+            _ => Err(AstCodecError::InvalidValue("bool")),
+        }
+    }
+
+    fn describe(schema: &mut AstSchema) {
+        schema.leaf("bool");
+    }
+}
+
+impl AstCodec for char {
+    fn encode(&self, encoder: &mut AstEncoder<'_>) {
+        encoder.write_varint(u64::from(*self));
+    }
+
+    fn decode(decoder: &mut AstDecoder<'_>) -> Result<Self, AstCodecError> {
+        u32::try_from(decoder.read_varint()?)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or(AstCodecError::InvalidValue("char"))
+    }
+
+    fn describe(schema: &mut AstSchema) {
+        schema.leaf("char");
+    }
+}
+
+impl AstCodec for u8 {
+    fn encode(&self, encoder: &mut AstEncoder<'_>) {
+        encoder.write_bytes(&[*self]);
+    }
+
+    fn decode(decoder: &mut AstDecoder<'_>) -> Result<Self, AstCodecError> {
+        decoder.read_array().map(|[byte]| byte)
+    }
+
+    fn describe(schema: &mut AstSchema) {
+        schema.leaf("u8");
+    }
+}
+
+macro_rules! ast_codec_unsigned {
+    ($($t:ty),+) => {
+        $(impl AstCodec for $t {
+            fn encode(&self, encoder: &mut AstEncoder<'_>) {
+                encoder.write_varint(*self as u64);
+            }
+
+            fn decode(decoder: &mut AstDecoder<'_>) -> Result<Self, AstCodecError> {
+                <$t>::try_from(decoder.read_varint()?)
+                    .map_err(|_| AstCodecError::InvalidValue(stringify!($t)))
+            }
+
+            fn describe(schema: &mut AstSchema) {
+                schema.leaf(stringify!($t));
+            }
+        })+
+    };
+}
+
+ast_codec_unsigned!(u16, u32, u64, usize);
+
+// Signed integers are zigzag encoded so that small negative values stay
+// short.
+macro_rules! ast_codec_signed {
+    ($($t:ty),+) => {
+        $(impl AstCodec for $t {
+            fn encode(&self, encoder: &mut AstEncoder<'_>) {
+                let value = *self as i64;
+                encoder.write_varint(((value << 1) ^ (value >> 63)) as u64);
+            }
+
+            fn decode(decoder: &mut AstDecoder<'_>) -> Result<Self, AstCodecError> {
+                let value = decoder.read_varint()?;
+                let value = (value >> 1) as i64 ^ -((value & 1) as i64);
+                <$t>::try_from(value).map_err(|_| AstCodecError::InvalidValue(stringify!($t)))
+            }
+
+            fn describe(schema: &mut AstSchema) {
+                schema.leaf(stringify!($t));
+            }
+        })+
+    };
+}
+
+ast_codec_signed!(i8, i16, i32, i64, isize);
+
+macro_rules! ast_codec_float {
+    ($($t:ty),+) => {
+        $(impl AstCodec for $t {
+            fn encode(&self, encoder: &mut AstEncoder<'_>) {
+                encoder.write_bytes(&self.to_le_bytes());
+            }
+
+            fn decode(decoder: &mut AstDecoder<'_>) -> Result<Self, AstCodecError> {
+                decoder.read_array().map(<$t>::from_le_bytes)
+            }
+
+            fn describe(schema: &mut AstSchema) {
+                schema.leaf(stringify!($t));
+            }
+        })+
+    };
+}
+
+ast_codec_float!(f32, f64);
@@ -0,0 +1,281 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Completion candidates at a cursor position
+//!
+//! [`completions_at`] parses a source string up to a cursor and reports
+//! what the grammar accepts there: keywords, punctuation tokens and
+//! categories such as `identifier` or `an expression`, together with the
+//! parsing contexts around the cursor, such as a table name in a `FROM`
+//! clause. The candidates are the expectations that
+//! [`ErrorTracker`](crate::parser::ErrorTracker) collects at the end of the
+//! text, including the optional keywords the parser probed for there.
+//!
+//! ```
+//! # use sqlparser::completion::completions_at;
+//! # use sqlparser::dialect::PostgreSqlDialect;
+//! # use sqlparser::keywords::Keyword;
+//! let sql = "SELECT a FROM t WH";
+//! let completions = completions_at(&PostgreSqlDialect {}, sql, sql.len());
+//! assert_eq!(&sql[completions.replace.clone()], "WH");
+//! assert_eq!(completions.keywords, [Keyword::WHERE]);
+//!
+//! let sql = "SELECT a FROM ";
+//! let completions = completions_at(&PostgreSqlDialect {}, sql, sql.len());
+//! assert_eq!(completions.categories, ["identifier"]);
+//! assert_eq!(
+//!     completions.context_description().as_deref(),
+//!     Some("table name in FROM clause")
+//! );
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+use core::ops::Range;
+
+use crate::dialect::Dialect;
+use crate::keywords::{Keyword, ALL_KEYWORDS};
+use crate::parser::{ExpectedItem, ParseContext, Parser};
+use crate::tokenizer::{BorrowedToken, Tokenizer, Whitespace};
+
+/// What the grammar accepts at a cursor, found by [`completions_at`].
+///
+/// All lists are empty when the cursor is inside a comment or a malformed
+/// lexeme, or when the text before the cursor has a syntax error.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Completions {
+    /// Byte range of the source that a candidate replaces: the word being
+    /// typed before the cursor, or an empty range at the cursor.
+    pub replace: Range<usize>,
+    /// Keywords accepted at the cursor that start with the word being
+    /// typed, in the order the parser tried them.
+    pub keywords: Vec<Keyword>,
+    /// Punctuation and operator tokens accepted at the cursor, such as `(`.
+    pub tokens: Vec<String>,
+    /// Other elements accepted at the cursor, such as `identifier` or
+    /// `an expression`.
+    pub categories: Vec<String>,
+    /// Parsing contexts at the cursor, innermost first.
+    pub contexts: Vec<ParseContext>,
+}
+
+impl Completions {
+    /// Whether no candidates were found.
+    pub fn is_empty(&self) -> bool {
+        self.keywords.is_empty() && self.tokens.is_empty() && self.categories.is_empty()
+    }
+
+    /// Where the cursor is, such as `table name in FROM clause`, from the
+    /// innermost contexts up to the clause or statement around them, or
+    /// `None` outside of any tracked context.
+    pub fn context_description(&self) -> Option<String> {
+        let mut names = Vec::new();
+        for context in &self.contexts {
+            names.push(context.display_name());
+            if !matches!(
+                context,
+                ParseContext::TableName
+                    | ParseContext::Expression
+                    | ParseContext::ColumnDefinition
+                    | ParseContext::CaseExpression
+                    | ParseContext::FunctionCall
+            ) {
+                break;
+            }
+        }
+        if names.is_empty() {
+            return None;
+        }
+        Some(names.join(" in "))
+    }
+}
+
+/// Report what the grammar of `dialect` accepts at byte `offset` of `sql`.
+///
+/// Only the text before the cursor is parsed. When the cursor is at the end
+/// of an unquoted word, that word is the prefix being typed: parsing stops
+/// before it, and only the keywords starting with it are kept. An `offset`
+/// past the end of `sql`, or inside a character, is moved back to the
+/// previous character boundary.
+pub fn completions_at(dialect: &dyn Dialect, sql: &str, offset: usize) -> Completions {
+    let mut cursor = offset.min(sql.len());
+    while !sql.is_char_boundary(cursor) {
+        cursor -= 1;
+    }
+    let empty = Completions {
+        replace: cursor..cursor,
+        ..Completions::default()
+    };
+
+    // Malformed lexemes become error tokens, and no limits are set, so
+    // tokenizing cannot fail.
+    let tokens = Tokenizer::new(dialect, &sql[..cursor])
+        .with_error_recovery(true)
+        .tokenize_with_location()
+        .unwrap_or_default();
+    let last = tokens
+        .iter()
+        .rev()
+        .find(|token| !matches!(token.token, BorrowedToken::EOF));
+    let prefix_start = match last.map(|token| (&token.token, token.span.start.byte_offset())) {
+        Some((BorrowedToken::Word(word), Some(start))) if word.quote_style.is_none() => start,
+        Some((BorrowedToken::Whitespace(Whitespace::SingleLineComment { comment, .. }), _))
+            if !comment.ends_with('\n') =>
+        {
+            return empty
+        }
+        Some((BorrowedToken::Error(_), _)) => return empty,
+        _ => cursor,
+    };
+
+    let Ok(parser) = Parser::new(dialect).try_with_sql(&sql[..prefix_start]) else {
+        return empty;
+    };
+    let Some((expected, contexts)) = parser.completion_candidates() else {
+        return empty;
+    };
+
+    let prefix = sql[prefix_start..cursor].to_uppercase();
+    let mut completions = Completions {
+        replace: prefix_start..cursor,
+        contexts,
+        ..Completions::default()
+    };
+    for item in expected {
+        match item {
+            ExpectedItem::Keyword(Keyword::NoKeyword) => {}
+            ExpectedItem::Keyword(keyword) => {
+                if ALL_KEYWORDS[keyword as usize - 1].starts_with(&prefix) {
+                    completions.keywords.push(keyword);
+                }
+            }
+            ExpectedItem::Token(token) => completions.tokens.push(token),
+            ExpectedItem::Category(category) => completions.categories.push(category),
+        }
+    }
+    completions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialect::{MySqlDialect, PostgreSqlDialect};
+
+    fn complete(dialect: &dyn Dialect, sql: &str) -> Completions {
+        completions_at(dialect, sql, sql.len())
+    }
+
+    #[test]
+    fn candidates_follow_the_grammar() {
+        let dialect = PostgreSqlDialect {};
+
+        let completions = complete(&dialect, "");
+        assert_eq!(completions.categories, ["an SQL statement"]);
+        assert_eq!(completions.context_description(), None);
+
+        let completions = complete(&dialect, "SELECT a FROM t ");
+        for keyword in [
+            Keyword::WHERE,
+            Keyword::GROUP,
+            Keyword::ORDER,
+            Keyword::JOIN,
+        ] {
+            assert!(completions.keywords.contains(&keyword), "{keyword:?}");
+        }
+        assert!(completions.tokens.contains(&";".to_string()));
+        assert_eq!(completions.context_description(), None);
+
+        let completions = complete(&dialect, "SELECT a FROM t GROUP ");
+        assert_eq!(completions.keywords, [Keyword::BY]);
+
+        let completions = complete(&dialect, "SELECT a FROM t WHERE b = 1 AND ");
+        assert!(completions
+            .categories
+            .contains(&"an expression".to_string()));
+        assert_eq!(
+            completions.context_description().as_deref(),
+            Some("WHERE clause")
+        );
+
+        let completions = complete(&dialect, "SELECT a FROM (SELECT b FROM ");
+        assert_eq!(completions.categories, ["identifier"]);
+        assert_eq!(
+            completions.context_description().as_deref(),
+            Some("table name in FROM clause")
+        );
+        assert_eq!(completions.contexts.len(), 4);
+
+        let completions = complete(&MySqlDialect {}, "UPDATE t SET a = 1 WHERE ");
+        assert_eq!(completions.contexts[0], ParseContext::WhereClause);
+    }
+
+    #[test]
+    fn word_before_the_cursor_is_a_prefix() {
+        let sql = "SELECT a FROM t ORD";
+        let completions = complete(&PostgreSqlDialect {}, sql);
+        assert_eq!(completions.replace, 16..19);
+        assert_eq!(completions.keywords, [Keyword::ORDER]);
+
+        let sql = "select a from t where a = 1 or";
+        let completions = complete(&PostgreSqlDialect {}, sql);
+        assert_eq!(&sql[completions.replace], "or");
+        assert_eq!(completions.keywords, [Keyword::OR, Keyword::ORDER]);
+
+        // The cursor is in the middle of `FROM`; the rest is ignored.
+        let sql = "SELECT a FROM t";
+        let completions = completions_at(&PostgreSqlDialect {}, sql, 11);
+        assert_eq!(completions.replace, 9..11);
+        assert!(completions.keywords.contains(&Keyword::FROM));
+    }
+
+    #[test]
+    fn operator_and_join_keywords_follow_the_dialect() {
+        let sql = "SELECT a FROM t WHERE b IL";
+        assert_eq!(
+            complete(&PostgreSqlDialect {}, sql).keywords,
+            [Keyword::ILIKE]
+        );
+        assert!(complete(&MySqlDialect {}, sql).keywords.is_empty());
+
+        let sql = "SELECT a FROM t WHERE b R";
+        assert_eq!(
+            complete(&MySqlDialect {}, sql).keywords,
+            [Keyword::REGEXP, Keyword::RLIKE]
+        );
+        assert_eq!(
+            complete(&PostgreSqlDialect {}, "SELECT a FROM t WHERE b SIM").keywords,
+            [Keyword::SIMILAR]
+        );
+
+        let completions = complete(&PostgreSqlDialect {}, "SELECT a FROM t S");
+        assert!(completions.keywords.contains(&Keyword::SEMI));
+        assert!(completions.keywords.contains(&Keyword::STRAIGHT_JOIN));
+    }
+
+    #[test]
+    fn no_candidates_in_comments_strings_or_after_errors() {
+        let dialect = PostgreSqlDialect {};
+        assert!(complete(&dialect, "SELECT a -- note ").is_empty());
+        assert!(complete(&dialect, "SELECT 'open ").is_empty());
+        assert!(complete(&dialect, "SELECT a FROM t WHERE ) ").is_empty());
+        assert!(!complete(&dialect, "SELECT a /* note */ ").is_empty());
+
+        let sql = "SELECT é";
+        let completions = completions_at(&dialect, sql, sql.len() - 1);
+        assert_eq!(completions.replace.end, 7);
+    }
+}
@@ -38,7 +38,9 @@ use crate::parser::{Parser, ParserError};
 use crate::tokenizer::BorrowedToken;
 
 #[cfg(not(feature = "std"))]
-use alloc::{borrow::ToOwned, boxed::Box, string::String};
+use alloc::{borrow::Cow, boxed::Box};
+#[cfg(feature = "std")]
+use std::borrow::Cow;
 
 /// Convenience check if a [`Parser`] uses a certain dialect.
 ///
@@ -152,9 +154,15 @@ pub trait Dialect: Debug + Any {
     /// Canonicalize an identifier's textual form when constructing the AST.
     ///
     /// The default keeps the identifier unchanged. Dialects that define
-    /// case-folding for unquoted identifiers can override this hook.
-    fn canonicalize_identifier(&self, identifier: &str, _quote_style: Option<char>) -> String {
-        identifier.to_owned()
+    /// case-folding for unquoted identifiers can override this hook. Return
+    /// the borrowed input when folding does not change it, so document
+    /// parses can keep the identifier as a slice of the source.
+    fn canonicalize_identifier<'a>(
+        &self,
+        identifier: &'a str,
+        _quote_style: Option<char>,
+    ) -> Cow<'a, str> {
+        Cow::Borrowed(identifier)
     }
 
     /// Determine if a character is a valid start character for an unquoted identifier
@@ -599,6 +607,26 @@ pub trait Dialect: Debug + Any {
         None
     }
 
+    /// Keywords that start an infix operator after an expression, such as
+    /// `LIKE`.
+    ///
+    /// Infix operators are matched by precedence rather than probed for, so
+    /// the parser offers these keywords as completion candidates after an
+    /// expression instead.
+    fn infix_operator_keywords(&self) -> &'static [Keyword] {
+        &[
+            Keyword::AND,
+            Keyword::OR,
+            Keyword::IS,
+            Keyword::NOT,
+            Keyword::IN,
+            Keyword::BETWEEN,
+            Keyword::LIKE,
+            Keyword::SIMILAR,
+            Keyword::OVERLAPS,
+        ]
+    }
+
     /// Get the precedence of the next token, looking at the full token stream.
     ///
     /// A higher number => higher precedence
@@ -1498,7 +1526,11 @@ impl<D: Dialect> Dialect for DelegatingDialect<D> {
         self.0.identifier_quote_style(identifier)
     }
 
-    fn canonicalize_identifier(&self, identifier: &str, quote_style: Option<char>) -> String {
+    fn canonicalize_identifier<'a>(
+        &self,
+        identifier: &'a str,
+        quote_style: Option<char>,
+    ) -> Cow<'a, str> {
         self.0.canonicalize_identifier(identifier, quote_style)
     }
 
@@ -1627,6 +1659,10 @@ impl<D: Dialect> Dialect for DelegatingDialect<D> {
         self.0.get_next_precedence(parser)
     }
 
+    fn infix_operator_keywords(&self) -> &'static [Keyword] {
+        self.0.infix_operator_keywords()
+    }
+
     fn get_next_precedence_default(&self, parser: &Parser) -> Result<u8, ParserError> {
         self.0.get_next_precedence_default(parser)
     }
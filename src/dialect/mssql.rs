@@ -128,6 +128,18 @@ impl Dialect for MsSqlDialect {
         &[GranteesType::Public]
     }
 
+    fn infix_operator_keywords(&self) -> &'static [Keyword] {
+        &[
+            Keyword::AND,
+            Keyword::OR,
+            Keyword::IS,
+            Keyword::NOT,
+            Keyword::IN,
+            Keyword::BETWEEN,
+            Keyword::LIKE,
+        ]
+    }
+
     fn is_column_alias(&self, kw: &Keyword, _parser: &Parser) -> bool {
         !keywords::RESERVED_FOR_COLUMN_ALIAS.contains(kw) && !RESERVED_FOR_COLUMN_ALIAS.contains(kw)
     }
@@ -249,13 +261,12 @@ impl MsSqlDialect {
             parser.prev_token();
         }
 
-        Ok(IfStatement {
+        Ok(Statement::If(parser.new_box(IfStatement {
             if_block,
             else_block,
             elseif_blocks: Vec::new(),
             end_token: None,
-        }
-        .into())
+        })?))
     }
 
     /// Parse `CREATE TRIGGER` for [MsSql]
@@ -276,7 +287,7 @@ impl MsSqlDialect {
         parser.expect_keyword_is(Keyword::AS)?;
         let statements = Some(parser.parse_conditional_statements(&[Keyword::END])?);
 
-        Ok(CreateTrigger {
+        Ok(Statement::CreateTrigger(parser.new_box(CreateTrigger {
             token,
             or_alter,
             temporary: false,
@@ -295,8 +306,7 @@ impl MsSqlDialect {
             statements_as: true,
             statements,
             characteristics: None,
-        }
-        .into())
+        })?))
     }
 
     /// Parse a sequence of statements, optionally separated by semicolon.
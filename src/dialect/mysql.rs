@@ -16,7 +16,7 @@
 // under the License.
 
 use crate::{
-    ast::{AttachedToken, BinaryOperator, Expr, LockTable, LockTableType, Statement},
+    ast::{AttachedToken, LockTable, LockTableType, Statement},
     dialect::Dialect,
     keywords::Keyword,
     parser::{Parser, ParserError},
@@ -82,24 +82,6 @@ impl Dialect for MySqlDialect {
         true
     }
 
-    fn parse_infix(
-        &self,
-        parser: &crate::parser::Parser,
-        expr: &crate::ast::Expr,
-        _precedence: u8,
-    ) -> Option<Result<crate::ast::Expr, ParserError>> {
-        // Parse DIV as an operator
-        if parser.parse_keyword(Keyword::DIV) {
-            Some(Ok(Expr::BinaryOp {
-                left: crate::ast::Box::new(expr.clone()),
-                op: BinaryOperator::MyIntegerDivide,
-                right: crate::ast::Box::new(parser.parse_expr().unwrap()),
-            }))
-        } else {
-            None
-        }
-    }
-
     fn parse_statement(&self, parser: &Parser) -> Option<Result<Statement, ParserError>> {
         // Need to peek first to get the token before consuming
         if parser.peek_keyword(Keyword::LOCK) {
@@ -123,6 +105,23 @@ impl Dialect for MySqlDialect {
         }
     }
 
+    fn infix_operator_keywords(&self) -> &'static [Keyword] {
+        &[
+            Keyword::AND,
+            Keyword::OR,
+            Keyword::XOR,
+            Keyword::IS,
+            Keyword::NOT,
+            Keyword::IN,
+            Keyword::BETWEEN,
+            Keyword::LIKE,
+            Keyword::REGEXP,
+            Keyword::RLIKE,
+            Keyword::DIV,
+            Keyword::MEMBER,
+        ]
+    }
+
     fn require_interval_qualifier(&self) -> bool {
         true
     }
@@ -21,7 +21,9 @@ use crate::parser::{Parser, ParserError};
 use crate::tokenizer::BorrowedToken;
 
 #[cfg(not(feature = "std"))]
-use alloc::{borrow::ToOwned, string::String};
+use alloc::borrow::Cow;
+#[cfg(feature = "std")]
+use std::borrow::Cow;
 
 /// A dialect for Oracle AI Database.
 /// Oracle grammar extensions are represented directly in the parser and AST.
@@ -33,11 +35,15 @@ impl Dialect for OracleDialect {
         Some('"')
     }
 
-    fn canonicalize_identifier(&self, identifier: &str, quote_style: Option<char>) -> String {
-        if quote_style.is_none() {
-            identifier.to_uppercase()
+    fn canonicalize_identifier<'a>(
+        &self,
+        identifier: &'a str,
+        quote_style: Option<char>,
+    ) -> Cow<'a, str> {
+        if quote_style.is_none() && identifier.chars().any(|ch| ch.to_uppercase().ne([ch])) {
+            Cow::Owned(identifier.to_uppercase())
         } else {
-            identifier.to_owned()
+            Cow::Borrowed(identifier)
         }
     }
 
@@ -127,4 +133,17 @@ impl Dialect for OracleDialect {
             _ => None,
         }
     }
+
+    fn infix_operator_keywords(&self) -> &'static [Keyword] {
+        &[
+            Keyword::AND,
+            Keyword::OR,
+            Keyword::IS,
+            Keyword::NOT,
+            Keyword::IN,
+            Keyword::BETWEEN,
+            Keyword::LIKE,
+            Keyword::MEMBER,
+        ]
+    }
 }
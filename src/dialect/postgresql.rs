@@ -34,7 +34,9 @@ use crate::parser::{Parser, ParserError};
 use crate::tokenizer::BorrowedToken;
 
 #[cfg(not(feature = "std"))]
-use alloc::{borrow::ToOwned, string::String};
+use alloc::borrow::Cow;
+#[cfg(feature = "std")]
+use std::borrow::Cow;
 
 /// A [`Dialect`] for [PostgreSQL](https://www.postgresql.org/)
 #[derive(Debug)]
@@ -67,11 +69,15 @@ impl Dialect for PostgreSqlDialect {
         true
     }
 
-    fn canonicalize_identifier(&self, identifier: &str, quote_style: Option<char>) -> String {
-        if quote_style.is_none() {
-            identifier.to_lowercase()
+    fn canonicalize_identifier<'a>(
+        &self,
+        identifier: &'a str,
+        quote_style: Option<char>,
+    ) -> Cow<'a, str> {
+        if quote_style.is_none() && identifier.chars().any(|ch| ch.to_lowercase().ne([ch])) {
+            Cow::Owned(identifier.to_lowercase())
         } else {
-            identifier.to_owned()
+            Cow::Borrowed(identifier)
         }
     }
 
@@ -162,6 +168,23 @@ impl Dialect for PostgreSqlDialect {
         }
     }
 
+    fn infix_operator_keywords(&self) -> &'static [Keyword] {
+        &[
+            Keyword::AND,
+            Keyword::OR,
+            Keyword::IS,
+            Keyword::NOT,
+            Keyword::IN,
+            Keyword::BETWEEN,
+            Keyword::LIKE,
+            Keyword::ILIKE,
+            Keyword::SIMILAR,
+            Keyword::OVERLAPS,
+            Keyword::NOTNULL,
+            Keyword::OPERATOR,
+        ]
+    }
+
     fn supports_filter_during_aggregation(&self) -> bool {
         true
     }
@@ -0,0 +1,372 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Token classification for syntax highlighting
+//!
+//! [`classify_tokens`] tokenizes a source string with the rules of a
+//! dialect and reports a [`TokenCategory`] for the span of every token
+//! other than plain whitespace. Malformed lexemes are reported as
+//! [`TokenCategory::Error`] spans rather than failing the whole text, so
+//! that text being typed can still be highlighted.
+//!
+//! Whether a keyword is reserved follows the dialect's
+//! [`is_reserved_for_identifier`](Dialect::is_reserved_for_identifier),
+//! [`is_column_alias`](Dialect::is_column_alias) and
+//! [`is_table_alias`](Dialect::is_table_alias). With the `visitor` feature,
+//! `classify_tokens_with_parser` also asks the parser which keywords it
+//! read as identifiers, as `key` in `SELECT key FROM t`.
+//!
+//! ```
+//! # use sqlparser::dialect::PostgreSqlDialect;
+//! # use sqlparser::highlight::{classify_tokens, TokenCategory};
+//! let sql = "SELECT \"Name\", 1 FROM t /*+ NO_PARALLEL */ WHERE x = $1 -- done";
+//! let categories: Vec<_> = classify_tokens(&PostgreSqlDialect {}, sql)
+//!     .into_iter()
+//!     .map(|token| (&sql[token.span.byte_range().unwrap()], token.category))
+//!     .collect();
+//! assert_eq!(
+//!     categories,
+//!     [
+//!         ("SELECT", TokenCategory::ReservedKeyword),
+//!         ("\"Name\"", TokenCategory::QuotedIdentifier),
+//!         (",", TokenCategory::Punctuation),
+//!         ("1", TokenCategory::NumberLiteral),
+//!         ("FROM", TokenCategory::ReservedKeyword),
+//!         ("t", TokenCategory::Identifier),
+//!         ("/*+ NO_PARALLEL */", TokenCategory::OptimizerHint),
+//!         ("WHERE", TokenCategory::ReservedKeyword),
+//!         ("x", TokenCategory::Identifier),
+//!         ("=", TokenCategory::Operator),
+//!         ("$1", TokenCategory::Placeholder),
+//!         ("-- done", TokenCategory::Comment),
+//!     ]
+//! );
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::dialect::Dialect;
+use crate::keywords::Keyword;
+use crate::parser::Parser;
+#[cfg(feature = "visitor")]
+use crate::tokenizer::Location;
+use crate::tokenizer::{BorrowedToken, Span, Tokenizer, Whitespace};
+
+/// The syntactic role of a token, for highlighting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenCategory {
+    /// A keyword the dialect does not accept as an unquoted identifier in
+    /// every position, such as `SELECT` or `FROM`.
+    ReservedKeyword,
+    /// Any other keyword, such as `KEY` or `TEXT`.
+    Keyword,
+    /// An unquoted identifier.
+    Identifier,
+    /// A delimited identifier, such as `"Name"`, `` `name` `` or `[name]`.
+    QuotedIdentifier,
+    /// A character string literal in any quoting form, including national,
+    /// escaped, Unicode, dollar-quoted and alternative-quoted strings.
+    StringLiteral,
+    /// A numeric literal.
+    NumberLiteral,
+    /// A hexadecimal or byte string literal, such as `X'1F'` or `B'101'`.
+    BinaryLiteral,
+    /// `TRUE` or `FALSE`.
+    BooleanLiteral,
+    /// `NULL`.
+    NullLiteral,
+    /// An operator, such as `=`, `||`, `::` or `->>`.
+    Operator,
+    /// A comma, semicolon, period, parenthesis, bracket or brace.
+    Punctuation,
+    /// A prepared statement placeholder, such as `?`, `$1` or `:name`.
+    Placeholder,
+    /// A comment.
+    Comment,
+    /// An optimizer hint comment, `/*+ ... */`.
+    OptimizerHint,
+    /// A malformed lexeme, such as an unterminated string literal, or a
+    /// character that could not be tokenized.
+    Error,
+}
+
+/// The category of one token found by [`classify_tokens`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClassifiedToken {
+    /// Source span of the token. See [`Span::byte_range`] for its bytes.
+    pub span: Span,
+    /// How the token should be highlighted.
+    pub category: TokenCategory,
+}
+
+/// Classify every token of `sql` other than plain whitespace, in source
+/// order, following the rules of `dialect`.
+pub fn classify_tokens(dialect: &dyn Dialect, sql: &str) -> Vec<ClassifiedToken> {
+    // Malformed lexemes become error tokens, and no limits are set, so
+    // tokenizing cannot fail.
+    let tokens = Tokenizer::new(dialect, sql)
+        .with_error_recovery(true)
+        .tokenize_with_location()
+        .unwrap_or_default();
+    let parser = Parser::new(dialect);
+    tokens
+        .into_iter()
+        .filter_map(|token| {
+            let category = classify(dialect, &parser, &token.token)?;
+            Some(ClassifiedToken {
+                span: token.span,
+                category,
+            })
+        })
+        .collect()
+}
+
+/// [`classify_tokens`], also reporting keywords the parser read as
+/// identifiers, such as `key` in `SELECT key FROM t`, as
+/// [`TokenCategory::Identifier`].
+///
+/// Statements that fail to parse are skipped, as by
+/// [`Parser::parse_statements_with_recovery`], and keep their lexical
+/// categories, as does the whole text when it cannot be tokenized.
+///
+/// ```
+/// # use sqlparser::dialect::PostgreSqlDialect;
+/// # use sqlparser::highlight::{classify_tokens, classify_tokens_with_parser, TokenCategory};
+/// let sql = "SELECT key, value FROM t";
+/// let dialect = PostgreSqlDialect {};
+/// assert_eq!(classify_tokens(&dialect, sql)[1].category, TokenCategory::Keyword);
+/// assert_eq!(
+///     classify_tokens_with_parser(&dialect, sql)[1].category,
+///     TokenCategory::Identifier
+/// );
+/// ```
+#[cfg(feature = "visitor")]
+pub fn classify_tokens_with_parser(dialect: &dyn Dialect, sql: &str) -> Vec<ClassifiedToken> {
+    let mut tokens = classify_tokens(dialect, sql);
+    let identifiers = parsed_identifiers(dialect, sql);
+    for token in &mut tokens {
+        if matches!(
+            token.category,
+            TokenCategory::ReservedKeyword
+                | TokenCategory::Keyword
+                | TokenCategory::BooleanLiteral
+                | TokenCategory::NullLiteral
+        ) && identifiers.binary_search(&token.span.start).is_ok()
+        {
+            token.category = TokenCategory::Identifier;
+        }
+    }
+    tokens
+}
+
+/// Sorted start locations of the identifiers in the statements of `sql`
+/// that parse.
+#[cfg(feature = "visitor")]
+fn parsed_identifiers(dialect: &dyn Dialect, sql: &str) -> Vec<Location> {
+    use crate::ast::{Ident, Visit, Visitor};
+    use core::ops::ControlFlow;
+
+    struct Identifiers(Vec<Location>);
+
+    impl Visitor for Identifiers {
+        type Break = ();
+
+        fn pre_visit_ident(&mut self, ident: &Ident) -> ControlFlow<()> {
+            if ident.span != Span::empty() {
+                self.0.push(ident.span.start);
+            }
+            ControlFlow::Continue(())
+        }
+    }
+
+    let mut identifiers = Identifiers(Vec::new());
+    if let Ok(parser) = Parser::new(dialect).try_with_sql(sql) {
+        let _ = parser
+            .parse_statements_with_recovery()
+            .statements
+            .visit(&mut identifiers);
+    }
+    let mut identifiers = identifiers.0;
+    identifiers.sort_unstable();
+    identifiers
+}
+
+fn classify(
+    dialect: &dyn Dialect,
+    parser: &Parser,
+    token: &BorrowedToken,
+) -> Option<TokenCategory> {
+    let category = match token {
+        BorrowedToken::EOF
+        | BorrowedToken::Whitespace(Whitespace::Space | Whitespace::Newline | Whitespace::Tab) => {
+            return None
+        }
+        BorrowedToken::Word(word) if word.quote_style.is_some() => TokenCategory::QuotedIdentifier,
+        BorrowedToken::Word(word) => match word.keyword {
+            Keyword::NoKeyword => TokenCategory::Identifier,
+            Keyword::TRUE | Keyword::FALSE => TokenCategory::BooleanLiteral,
+            Keyword::NULL => TokenCategory::NullLiteral,
+            keyword if is_reserved(dialect, parser, keyword) => TokenCategory::ReservedKeyword,
+            _ => TokenCategory::Keyword,
+        },
+        BorrowedToken::Number(..) => TokenCategory::NumberLiteral,
+        BorrowedToken::SingleQuotedString(_)
+        | BorrowedToken::DoubleQuotedString(_)
+        | BorrowedToken::DollarQuotedString(_)
+        | BorrowedToken::NationalStringLiteral(_)
+        | BorrowedToken::AlternativeQuotedString(_)
+        | BorrowedToken::EscapedStringLiteral(_)
+        | BorrowedToken::UnicodeStringLiteral(_) => TokenCategory::StringLiteral,
+        BorrowedToken::HexStringLiteral(_) | BorrowedToken::SingleQuotedByteStringLiteral(_) => {
+            TokenCategory::BinaryLiteral
+        }
+        BorrowedToken::Whitespace(Whitespace::MultiLineComment(comment))
+            if comment.starts_with('+') =>
+        {
+            TokenCategory::OptimizerHint
+        }
+        BorrowedToken::Whitespace(_) => TokenCategory::Comment,
+        BorrowedToken::Placeholder(_) => TokenCategory::Placeholder,
+        BorrowedToken::Comma
+        | BorrowedToken::SemiColon
+        | BorrowedToken::Period
+        | BorrowedToken::LParen
+        | BorrowedToken::RParen
+        | BorrowedToken::LBracket
+        | BorrowedToken::RBracket
+        | BorrowedToken::LBrace
+        | BorrowedToken::RBrace => TokenCategory::Punctuation,
+        BorrowedToken::Char(_) | BorrowedToken::Error(_) => TokenCategory::Error,
+        _ => TokenCategory::Operator,
+    };
+    Some(category)
+}
+
+fn is_reserved(dialect: &dyn Dialect, parser: &Parser, keyword: Keyword) -> bool {
+    dialect.is_reserved_for_identifier(keyword)
+        || !dialect.is_column_alias(&keyword, parser)
+        || !dialect.is_table_alias(&keyword, parser)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialect::{MsSqlDialect, MySqlDialect, OracleDialect, PostgreSqlDialect};
+
+    fn categories(tokens: Vec<ClassifiedToken>, sql: &str) -> Vec<(&str, TokenCategory)> {
+        tokens
+            .into_iter()
+            .map(|token| (&sql[token.span.byte_range().unwrap()], token.category))
+            .collect()
+    }
+
+    fn classify_sql<'a>(dialect: &dyn Dialect, sql: &'a str) -> Vec<(&'a str, TokenCategory)> {
+        categories(classify_tokens(dialect, sql), sql)
+    }
+
+    #[test]
+    fn literal_kinds_and_dialect_quoting() {
+        use TokenCategory::*;
+        let sql = "SELECT `a`, \"b\", X'1F', N'n', TRUE, NULL, 1.5e3 FROM t LIMIT ?";
+        assert_eq!(
+            classify_sql(&MySqlDialect {}, sql),
+            [
+                ("SELECT", ReservedKeyword),
+                ("`a`", QuotedIdentifier),
+                (",", Punctuation),
+                ("\"b\"", StringLiteral),
+                (",", Punctuation),
+                ("X'1F'", BinaryLiteral),
+                (",", Punctuation),
+                ("N'n'", StringLiteral),
+                (",", Punctuation),
+                ("TRUE", BooleanLiteral),
+                (",", Punctuation),
+                ("NULL", NullLiteral),
+                (",", Punctuation),
+                ("1.5e3", NumberLiteral),
+                ("FROM", ReservedKeyword),
+                ("t", Identifier),
+                ("LIMIT", ReservedKeyword),
+                ("?", Placeholder),
+            ]
+        );
+
+        let sql = "SELECT [a b], @v FROM t -- note";
+        assert_eq!(
+            classify_sql(&MsSqlDialect {}, sql),
+            [
+                ("SELECT", ReservedKeyword),
+                ("[a b]", QuotedIdentifier),
+                (",", Punctuation),
+                ("@v", Identifier),
+                ("FROM", ReservedKeyword),
+                ("t", Identifier),
+                ("-- note", Comment),
+            ]
+        );
+
+        let sql = "SELECT q'[it's]', a::text, b->>'k' FROM t";
+        assert_eq!(
+            classify_sql(&OracleDialect {}, sql)[1],
+            ("q'[it's]'", StringLiteral)
+        );
+        let sql = "SELECT a::text, b->>'k' FROM t";
+        let postgres = classify_sql(&PostgreSqlDialect {}, sql);
+        assert!(postgres.contains(&("::", Operator)));
+        assert!(postgres.contains(&("->>", Operator)));
+        assert!(postgres.contains(&("text", Keyword)));
+    }
+
+    #[test]
+    fn malformed_lexemes_and_hints() {
+        use TokenCategory::*;
+        let sql = "SELECT /*+ FULL(t) */ /* plain */ 'open\nFROM t";
+        assert_eq!(
+            classify_sql(&OracleDialect {}, sql),
+            [
+                ("SELECT", ReservedKeyword),
+                ("/*+ FULL(t) */", OptimizerHint),
+                ("/* plain */", Comment),
+                ("'open", Error),
+                ("FROM", ReservedKeyword),
+                ("t", Identifier),
+            ]
+        );
+    }
+
+    #[cfg(feature = "visitor")]
+    #[test]
+    fn parser_decides_keywords_used_as_identifiers() {
+        use TokenCategory::*;
+        let sql = "SELECT key, true, name FROM t AS user; SELEC value";
+        let dialect = PostgreSqlDialect {};
+        let lexical = classify_sql(&dialect, sql);
+        assert!(lexical.contains(&("key", Keyword)));
+        assert!(lexical.contains(&("value", Keyword)));
+
+        let parsed = categories(classify_tokens_with_parser(&dialect, sql), sql);
+        assert!(parsed.contains(&("key", Identifier)));
+        assert!(parsed.contains(&("name", Identifier)));
+        assert!(parsed.contains(&("user", Identifier)));
+        assert!(parsed.contains(&("true", BooleanLiteral)));
+        assert!(parsed.contains(&("AS", Keyword)));
+        // The failed statement keeps its lexical categories.
+        assert!(parsed.contains(&("value", Keyword)));
+    }
+}
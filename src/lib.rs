@@ -166,19 +166,28 @@ extern crate pretty_assertions;
 
 pub mod arena;
 pub mod ast;
+pub mod codec;
+pub mod completion;
 #[macro_use]
 pub mod dialect;
 mod display_utils;
+pub mod highlight;
 pub mod keywords;
 #[cfg(feature = "std")]
 pub mod optimizer_hints;
 #[cfg(feature = "std")]
 pub mod oracle_compat;
 pub mod parser;
+pub mod splitter;
 pub mod tokenizer;
 
+#[cfg(target_has_atomic = "ptr")]
+pub use arena::CoreStatementHandle;
+#[cfg(feature = "ast-histogram")]
+pub use arena::{AstAllocationHistogram, AstTypeAllocations};
+pub use arena::{AstArenaStats, CoreParsedSql};
 #[cfg(feature = "std")]
-pub use arena::{AstArenaStats, ParsedSql, StatementHandle};
+pub use arena::{AstChunkPool, AstChunkPoolStats, EditSession, ParsedSql, StatementHandle};
 
 #[doc(hidden)]
 // This is required to make utilities accessible by both the crate-internal
@@ -5,10 +5,15 @@
 //! text before it reaches the parser.
 
 use crate::dialect::Dialect;
-use crate::tokenizer::{BorrowedToken, Location, Tokenizer, TokenizerError, Whitespace};
+use crate::parser::ParserLimits;
+use crate::tokenizer::{BorrowedToken, Tokenizer, TokenizerError, Whitespace};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use sqlparser_derive::{AstCodec, HeapSize};
 
 /// One optimizer hint parsed from a `/*+ ... */` comment.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, HeapSize, AstCodec)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OptimizerHint {
     pub directive: OptimizerHintDirective,
     /// Exact UTF-8 byte offsets `[start, end)` of the containing hint block.
@@ -18,7 +23,8 @@ pub struct OptimizerHint {
 /// Typed optimizer-hint directives. Unknown directives remain observable so
 /// consumers can preserve forward compatibility without accepting semantics
 /// they do not implement.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, HeapSize, AstCodec)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum OptimizerHintDirective {
     UseIndex { table: String, index: String },
     ForceIndex { table: String, index: String },
@@ -44,7 +50,19 @@ pub fn parse_optimizer_hints(
     dialect: &dyn Dialect,
     source: &str,
 ) -> Result<Vec<OptimizerHint>, TokenizerError> {
-    let tokens = Tokenizer::new(dialect, source).tokenize_with_location()?;
+    parse_optimizer_hints_with_limits(dialect, source, ParserLimits::default())
+}
+
+/// [`parse_optimizer_hints`], tokenizing under the source and token limits
+/// of `limits`.
+pub(crate) fn parse_optimizer_hints_with_limits(
+    dialect: &dyn Dialect,
+    source: &str,
+    limits: ParserLimits,
+) -> Result<Vec<OptimizerHint>, TokenizerError> {
+    let tokens = Tokenizer::new(dialect, source)
+        .with_limits(limits)
+        .tokenize_with_location()?;
     let mut hints = Vec::new();
 
     for token in tokens {
@@ -54,13 +72,10 @@ pub fn parse_optimizer_hints(
         let Some(content) = comment.strip_prefix('+') else {
             continue;
         };
-        let Some(start) = source_location_to_offset(source, token.span.start) else {
+        let Some(range) = token.span.byte_range() else {
             continue;
         };
-        let Some(end) = source_location_to_offset(source, token.span.end) else {
-            continue;
-        };
-        parse_hint_content(content, (start, end), &mut hints);
+        parse_hint_content(content, (range.start, range.end), &mut hints);
     }
 
     Ok(hints)
@@ -153,31 +168,6 @@ fn parse_directive(name: &str, args: Vec<String>) -> OptimizerHintDirective {
     }
 }
 
-fn source_location_to_offset(source: &str, location: Location) -> Option<usize> {
-    if location.line == 0 || location.column == 0 {
-        return None;
-    }
-    let target_line = usize::try_from(location.line - 1).ok()?;
-    let line_start = if target_line == 0 {
-        0
-    } else {
-        source
-            .match_indices('\n')
-            .nth(target_line - 1)
-            .map(|(offset, _)| offset + 1)?
-    };
-    let line_end = source[line_start..]
-        .find('\n')
-        .map(|offset| line_start + offset)
-        .unwrap_or(source.len());
-    let line = source.get(line_start..line_end)?;
-    let target_column = usize::try_from(location.column - 1).ok()?;
-    if let Some((offset, _)) = line.char_indices().nth(target_column) {
-        return Some(line_start + offset);
-    }
-    (line.chars().count() == target_column).then_some(line_end)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
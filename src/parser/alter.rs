@@ -51,7 +51,10 @@ impl Parser<'_> {
 
         let operation = self.parse_pg_alter_configuration_operation()?;
 
-        Ok(Statement::AlterSystem { token, operation })
+        Ok(Statement::AlterSystem {
+            token,
+            operation: self.new_box(operation)?,
+        })
     }
 
     pub fn parse_alter_database(&self) -> Result<Statement, ParserError> {
@@ -81,7 +84,7 @@ impl Parser<'_> {
         Ok(Statement::AlterDatabase {
             token,
             database_name,
-            operation,
+            operation: self.new_box(operation)?,
         })
     }
 
@@ -132,9 +135,9 @@ impl Parser<'_> {
             let new_name = self.parse_identifier()?;
             Ok(Statement::AlterPolicy {
                 token,
-                name,
+                name: self.new_box(name)?,
                 table_name,
-                operation: AlterPolicyOperation::Rename { new_name },
+                operation: self.new_box(AlterPolicyOperation::Rename { new_name })?,
             })
         } else {
             let to = if self.parse_keyword(Keyword::TO) {
@@ -162,13 +165,13 @@ impl Parser<'_> {
             };
             Ok(Statement::AlterPolicy {
                 token,
-                name,
+                name: self.new_box(name)?,
                 table_name,
-                operation: AlterPolicyOperation::Apply {
+                operation: self.new_box(AlterPolicyOperation::Apply {
                     to,
                     using,
                     with_check,
-                },
+                })?,
             })
         }
     }
@@ -205,14 +208,14 @@ impl Parser<'_> {
             vec![]
         };
 
-        Ok(Statement::AlterUser(AlterUser {
+        Ok(Statement::AlterUser(self.new_box(AlterUser {
             token,
             if_exists,
             name,
             rename_to,
             set_props,
             unset_props,
-        }))
+        })?))
     }
 
     fn parse_mssql_alter_role(&self) -> Result<Statement, ParserError> {
@@ -251,8 +254,8 @@ impl Parser<'_> {
 
         Ok(Statement::AlterRole {
             token,
-            name: role_name,
-            operation,
+            name: self.new_box(role_name)?,
+            operation: self.new_box(operation)?,
         })
     }
 
@@ -308,8 +311,8 @@ impl Parser<'_> {
 
         Ok(Statement::AlterRole {
             token,
-            name: role_name,
-            operation,
+            name: self.new_box(role_name)?,
+            operation: self.new_box(operation)?,
         })
     }
 
@@ -431,7 +434,7 @@ impl Parser<'_> {
             Some(Keyword::NOBYPASSRLS) => RoleOption::BypassRLS(false),
             Some(Keyword::CONNECTION) => {
                 self.expect_keyword_is(Keyword::LIMIT)?;
-                RoleOption::ConnectionLimit(Expr::Value(self.parse_number_value()?))
+                RoleOption::ConnectionLimit(Expr::Value(self.new_box(self.parse_number_value()?)?))
             }
             Some(Keyword::CREATEDB) => RoleOption::CreateDB(true),
             Some(Keyword::NOCREATEDB) => RoleOption::CreateDB(false),
@@ -445,7 +448,7 @@ impl Parser<'_> {
                 let password = if self.parse_keyword(Keyword::NULL) {
                     Password::NullPassword
                 } else {
-                    Password::Password(Expr::Value(self.parse_value()?))
+                    Password::Password(Expr::Value(self.new_box(self.parse_value()?)?))
                 };
                 RoleOption::Password(password)
             }
@@ -455,7 +458,7 @@ impl Parser<'_> {
             Some(Keyword::NOSUPERUSER) => RoleOption::SuperUser(false),
             Some(Keyword::VALID) => {
                 self.expect_keyword_is(Keyword::UNTIL)?;
-                RoleOption::ValidUntil(Expr::Value(self.parse_value()?))
+                RoleOption::ValidUntil(Expr::Value(self.new_box(self.parse_value()?)?))
             }
             _ => self.expected("option", self.peek_token())?,
         };
@@ -12,8 +12,11 @@
 
 //! SQL Parser
 
+use crate::arena::AstArena;
 use crate::arena::AstBox as Box;
 #[cfg(not(feature = "std"))]
+use alloc::boxed::Box as HeapBox;
+#[cfg(not(feature = "std"))]
 use alloc::{
     borrow::Cow,
     format,
@@ -28,6 +31,12 @@ use core::{
 use helpers::attached_token::AttachedToken;
 #[cfg(feature = "std")]
 use std::borrow::Cow;
+#[cfg(feature = "std")]
+use std::sync::Arc;
+// Errors outlive the document arena a failed parse was building, so they
+// must never use `AstBox`.
+#[cfg(feature = "std")]
+use std::boxed::Box as HeapBox;
 
 use log::debug;
 use strsim::jaro_winkler;
@@ -36,16 +45,18 @@ use recursion::RecursionCounter;
 use IsLateral::*;
 use IsOptional::*;
 
+#[cfg(feature = "std")]
+use crate::arena::AstChunkPool;
 use crate::ast::helpers::{
     key_value_options::{
         KeyValueOption, KeyValueOptionKind, KeyValueOptions, KeyValueOptionsDelimiter,
     },
     stmt_create_table::{CreateTableBuilder, CreateTableConfiguration},
 };
-use crate::ast::Statement::CreatePolicy;
 use crate::ast::*;
 use crate::dialect::*;
 use crate::keywords::Keyword;
+use crate::splitter::{begin_starts_transaction, is_lone_slash, significant_index, BlockTracker};
 use crate::tokenizer::*;
 use core::cell::{Cell, RefCell};
 use sqlparser::parser::ParserState::ColumnDefinition;
@@ -57,12 +68,327 @@ fn box_into_inner<T>(value: Box<T>) -> T {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum ParserError {
     TokenizerError(String),
     ParserError(String),
     RecursionLimitExceeded,
+    /// A tokenizer or parser error reported at a known source position,
+    /// together with the diagnostics gathered while reaching it.
+    ///
+    /// Errors from token streams without locations (see
+    /// [`Parser::with_tokens`]) keep using the message-only variants.
+    SyntaxError(HeapBox<SyntaxError>),
+    /// The input exceeded one of the [`ParserLimits`] set in the
+    /// [`ParserOptions`].
+    LimitExceeded(LimitExceeded),
+}
+
+/// A size limit of [`ParserLimits`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParserLimit {
+    /// [`ParserLimits::max_source_bytes`]
+    SourceBytes,
+    /// [`ParserLimits::max_tokens`]
+    Tokens,
+    /// [`ParserLimits::max_arena_bytes`]
+    ArenaBytes,
+    /// [`ParserLimits::max_list_length`]
+    ListLength,
+}
+
+impl fmt::Display for ParserLimit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ParserLimit::SourceBytes => "source size",
+            ParserLimit::Tokens => "token count",
+            ParserLimit::ArenaBytes => "arena size",
+            ParserLimit::ListLength => "list length",
+        })
+    }
+}
+
+/// The limit that stopped tokenizing or parsing, with its configured
+/// maximum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LimitExceeded {
+    pub limit: ParserLimit,
+    pub max: usize,
+}
+
+impl fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} limit of {} exceeded", self.limit, self.max)
+    }
+}
+
+impl From<LimitExceeded> for ParserError {
+    fn from(e: LimitExceeded) -> Self {
+        ParserError::LimitExceeded(e)
+    }
+}
+
+/// Size limits for parsing untrusted SQL. Each one is checked before the
+/// memory it bounds is allocated, and fails with
+/// [`ParserError::LimitExceeded`] naming the limit. All limits default to
+/// `None`, meaning unlimited.
+///
+/// ```
+/// # use sqlparser::{dialect::PostgreSqlDialect, parser::*};
+/// let options = ParserOptions::new()
+///     .with_limits(ParserLimits::new().with_max_list_length(2));
+/// let error = Parser::new(&PostgreSqlDialect {})
+///     .with_options(options)
+///     .try_with_sql("SELECT a, b, c FROM t")
+///     .and_then(|parser| parser.parse_statements())
+///     .unwrap_err();
+/// assert_eq!(
+///     error,
+///     ParserError::LimitExceeded(LimitExceeded {
+///         limit: ParserLimit::ListLength,
+///         max: 2,
+///     })
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ParserLimits {
+    /// Maximum length of the SQL text in bytes, checked by the tokenizer
+    /// before it produces any token.
+    pub max_source_bytes: Option<usize>,
+    /// Maximum number of tokens, not counting whitespace and comments.
+    pub max_tokens: Option<usize>,
+    /// Maximum bytes of arena chunks a [`ParsedSql`](crate::ParsedSql)
+    /// document may commit while it is parsed. Each allocation is checked
+    /// against the remaining budget before it is made, and one that does
+    /// not fit fails the parse. A [`Parser`] used on its own allocates from
+    /// the heap and ignores this limit.
+    pub max_arena_bytes: Option<usize>,
+    /// Maximum number of items in one comma-, period- or keyword-separated
+    /// list.
+    pub max_list_length: Option<usize>,
+}
+
+impl ParserLimits {
+    /// Create limits with every limit unset.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set [`ParserLimits::max_source_bytes`].
+    pub fn with_max_source_bytes(mut self, max: usize) -> Self {
+        self.max_source_bytes = Some(max);
+        self
+    }
+
+    /// Set [`ParserLimits::max_tokens`].
+    pub fn with_max_tokens(mut self, max: usize) -> Self {
+        self.max_tokens = Some(max);
+        self
+    }
+
+    /// Set [`ParserLimits::max_arena_bytes`].
+    pub fn with_max_arena_bytes(mut self, max: usize) -> Self {
+        self.max_arena_bytes = Some(max);
+        self
+    }
+
+    /// Set [`ParserLimits::max_list_length`].
+    pub fn with_max_list_length(mut self, max: usize) -> Self {
+        self.max_list_length = Some(max);
+        self
+    }
+}
+
+/// The parsing stage that reported a [`SyntaxError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SyntaxErrorKind {
+    /// The SQL text could not be tokenized.
+    Tokenizer,
+    /// The token stream did not match the grammar.
+    Parser,
+}
+
+/// Structured diagnostics for an error at a known source position.
+///
+/// `Display` renders the same text as the message-only [`ParserError`]
+/// variants, so `message` never includes the `at Line: X, Column: Y` suffix;
+/// consumers that need the position read `span` or `byte_offset` instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxError {
+    /// Stage that reported the error.
+    pub kind: SyntaxErrorKind,
+    /// Human-readable message, without the location suffix.
+    pub message: String,
+    /// Span of the token at which the error was reported.
+    pub span: Span,
+    /// UTF-8 byte offset of `span.start` in the parsed source, when the
+    /// source text is known.
+    pub byte_offset: Option<usize>,
+    /// What the parser would have accepted at the error position.
+    pub expected: Vec<ExpectedItem>,
+    /// Parsing contexts active at the error position, innermost first.
+    pub contexts: Vec<ParseContext>,
+    /// Typo suggestion, such as `Did you mean 'FROM'?`.
+    pub hint: Option<String>,
+}
+
+impl SyntaxError {
+    /// Create an error at `span` with no expected items, contexts or hint.
+    ///
+    /// The byte offset is taken from `span.start`, when it records one.
+    pub fn new(kind: SyntaxErrorKind, message: impl Into<String>, span: Span) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            span,
+            byte_offset: span.start.byte_offset(),
+            expected: Vec::new(),
+            contexts: Vec::new(),
+            hint: None,
+        }
+    }
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.message, self.span.start)
+    }
+}
+
+impl ParserError {
+    /// Create a parser error at `location`. Unknown locations produce the
+    /// message-only [`ParserError::ParserError`] variant.
+    pub fn at(message: impl Into<String>, location: Location) -> Self {
+        let message = message.into();
+        if location.line == 0 {
+            return ParserError::ParserError(message);
+        }
+        ParserError::SyntaxError(HeapBox::new(SyntaxError::new(
+            SyntaxErrorKind::Parser,
+            message,
+            location.span_to(location),
+        )))
+    }
+
+    /// Structured diagnostics, when the error position is known.
+    pub fn syntax_error(&self) -> Option<&SyntaxError> {
+        match self {
+            ParserError::SyntaxError(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Span of the token at which the error was reported.
+    pub fn span(&self) -> Option<Span> {
+        self.syntax_error().map(|error| error.span)
+    }
+
+    /// UTF-8 byte offset of the error position in the parsed source.
+    pub fn byte_offset(&self) -> Option<usize> {
+        self.syntax_error().and_then(|error| error.byte_offset)
+    }
 }
 
+/// A statement skipped by [`Parser::parse_statements_with_recovery`], or
+/// an error replaced by a placeholder by
+/// [`Parser::parse_statements_tolerant`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatementError {
+    /// The error reported for the statement.
+    pub error: ParserError,
+    /// Source span of the tokens skipped while resynchronizing, from the
+    /// start of the failed statement up to, but not including, the
+    /// delimiter the parser resumed at. For a placeholder, the span of the
+    /// placeholder, which may be empty.
+    pub span: Span,
+}
+
+/// The outcome of parsing a statement batch in recovery mode.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RecoveredStatements {
+    /// Statements that parsed successfully, in source order.
+    pub statements: Vec<Statement>,
+    /// Source span of each entry in `statements`.
+    pub spans: Vec<Span>,
+    /// One entry per statement that failed to parse, in source order.
+    pub errors: Vec<StatementError>,
+}
+
+/// The outcome of parsing a statement batch in tolerant mode.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PartialStatements {
+    /// Statements in source order. Expressions and table factors that failed
+    /// to parse are [`Expr::Error`] and [`TableFactor::Error`] placeholders.
+    pub statements: Vec<Statement>,
+    /// Source span of each entry in `statements`.
+    pub spans: Vec<Span>,
+    /// Errors in source order: one per placeholder or skipped trailing
+    /// tokens of a statement, and one per statement that could not be
+    /// parsed even partially.
+    pub errors: Vec<StatementError>,
+}
+
+/// Keywords that start a new statement when resynchronizing a batch whose
+/// statements need not be separated by semicolons.
+const RECOVERY_STATEMENT_KEYWORDS: &[Keyword] = &[
+    Keyword::ALTER,
+    Keyword::BEGIN,
+    Keyword::COMMIT,
+    Keyword::CREATE,
+    Keyword::DECLARE,
+    Keyword::DELETE,
+    Keyword::DROP,
+    Keyword::EXEC,
+    Keyword::GRANT,
+    Keyword::INSERT,
+    Keyword::MERGE,
+    Keyword::PRINT,
+    Keyword::REVOKE,
+    Keyword::ROLLBACK,
+    Keyword::SELECT,
+    Keyword::TRUNCATE,
+    Keyword::UPDATE,
+    Keyword::USE,
+];
+
+/// Keywords at which the tokens skipped for a placeholder in tolerant mode
+/// end, because a clause or another part of the enclosing construct may
+/// start there.
+const TOLERANT_RESUME_KEYWORDS: &[Keyword] = &[
+    Keyword::AND,
+    Keyword::AS,
+    Keyword::CROSS,
+    Keyword::ELSE,
+    Keyword::END,
+    Keyword::EXCEPT,
+    Keyword::FETCH,
+    Keyword::FROM,
+    Keyword::FULL,
+    Keyword::GROUP,
+    Keyword::HAVING,
+    Keyword::INNER,
+    Keyword::INTERSECT,
+    Keyword::INTO,
+    Keyword::JOIN,
+    Keyword::LEFT,
+    Keyword::LIMIT,
+    Keyword::OFFSET,
+    Keyword::ON,
+    Keyword::OR,
+    Keyword::ORDER,
+    Keyword::QUALIFY,
+    Keyword::RETURNING,
+    Keyword::RIGHT,
+    Keyword::SET,
+    Keyword::THEN,
+    Keyword::UNION,
+    Keyword::USING,
+    Keyword::VALUES,
+    Keyword::WHEN,
+    Keyword::WHERE,
+    Keyword::WINDOW,
+];
+
 /// Diagnostic emitted when `CREATE TABLE` specifies both supported table
 /// placement forms.
 pub const CREATE_TABLE_PLACEMENT_CONFLICT_MESSAGE: &str =
@@ -71,13 +397,13 @@ pub const CREATE_TABLE_PLACEMENT_CONFLICT_MESSAGE: &str =
 // Use `Parser::expected` instead, if possible
 macro_rules! parser_err {
     ($MSG:expr, $loc:expr) => {
-        Err(ParserError::ParserError(format!("{}{}", $MSG, $loc)))
+        Err(ParserError::at($MSG, $loc))
     };
 }
 
 /// Context for where an error occurred in parsing.
 /// Used to provide human-friendly error messages like "in SELECT clause".
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ParseContext {
     SelectClause,
     FromClause,
@@ -172,8 +498,13 @@ pub struct ErrorTracker {
     expected_items: RefCell<Vec<ExpectedItem>>,
     /// Stack of parsing contexts for error messages
     context_stack: RefCell<Vec<ParseContext>>,
+    /// Context stack captured when the furthest position was first reached
+    furthest_contexts: RefCell<Vec<ParseContext>>,
     /// Optional typo hint (e.g., "Did you mean 'FROM'?")
     typo_hint: RefCell<Option<String>>,
+    /// Positions past this index are recorded at it, so that expectations
+    /// at the end of input accumulate wherever they were reported
+    end_index: Cell<usize>,
 }
 
 impl Default for ErrorTracker {
@@ -189,7 +520,9 @@ impl ErrorTracker {
             furthest_index: Cell::new(0),
             expected_items: RefCell::new(Vec::new()),
             context_stack: RefCell::new(Vec::new()),
+            furthest_contexts: RefCell::new(Vec::new()),
             typo_hint: RefCell::new(None),
+            end_index: Cell::new(usize::MAX),
         }
     }
 
@@ -198,11 +531,18 @@ impl ErrorTracker {
         self.furthest_index.set(0);
         self.expected_items.borrow_mut().clear();
         self.context_stack.borrow_mut().clear();
+        self.furthest_contexts.borrow_mut().clear();
         *self.typo_hint.borrow_mut() = None;
     }
 
+    /// Treat every token index from `index` on as the end of input
+    pub fn set_end_index(&self, index: usize) {
+        self.end_index.set(index);
+    }
+
     /// Record an expectation at the given token index
     pub fn record(&self, index: usize, expected: ExpectedItem) {
+        let index = index.min(self.end_index.get());
         let current = self.furthest_index.get();
         if index > current {
             // New furthest position - clear old expectations
@@ -210,6 +550,11 @@ impl ErrorTracker {
             let mut items = self.expected_items.borrow_mut();
             items.clear();
             items.push(expected);
+            // Remember where we were, since the context guards unwind before
+            // the final error is built
+            self.furthest_contexts
+                .borrow_mut()
+                .clone_from(&self.context_stack.borrow());
             // Clear typo hint since we're at a new position
             *self.typo_hint.borrow_mut() = None;
         } else if index == current {
@@ -218,6 +563,15 @@ impl ErrorTracker {
             if !items.contains(&expected) {
                 items.push(expected);
             }
+            // Keep only the contexts shared by every expectation here
+            let stack = self.context_stack.borrow();
+            let mut contexts = self.furthest_contexts.borrow_mut();
+            let shared = contexts
+                .iter()
+                .zip(stack.iter())
+                .take_while(|(a, b)| a == b)
+                .count();
+            contexts.truncate(shared);
         }
         // If index < current, this expectation is not useful
     }
@@ -227,6 +581,45 @@ impl ErrorTracker {
         self.furthest_index.get()
     }
 
+    /// What was expected at the furthest position.
+    ///
+    /// Categories that merely restate a keyword or token already listed (an
+    /// `expect_keyword` failure records both) are omitted.
+    pub fn expected_items(&self) -> Vec<ExpectedItem> {
+        let items = self.expected_items.borrow();
+        items
+            .iter()
+            .filter(|item| match item {
+                ExpectedItem::Category(category) => !items.iter().any(|other| {
+                    !matches!(other, ExpectedItem::Category(_)) && other.to_string() == *category
+                }),
+                _ => true,
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Parsing contexts active when the furthest position was reached,
+    /// innermost first
+    pub fn furthest_contexts(&self) -> Vec<ParseContext> {
+        self.furthest_contexts
+            .borrow()
+            .iter()
+            .rev()
+            .copied()
+            .collect()
+    }
+
+    /// Parsing contexts currently entered, innermost first
+    pub fn contexts(&self) -> Vec<ParseContext> {
+        self.context_stack.borrow().iter().rev().copied().collect()
+    }
+
+    /// Typo hint recorded for the furthest position
+    pub fn typo_hint(&self) -> Option<String> {
+        self.typo_hint.borrow().clone()
+    }
+
     /// Check if we have a tracked error that's further than the given index
     pub fn has_better_error(&self, current_index: usize) -> bool {
         self.furthest_index.get() > current_index
@@ -440,21 +833,31 @@ pub enum WildcardExpr {
 
 impl From<TokenizerError> for ParserError {
     fn from(e: TokenizerError) -> Self {
-        ParserError::TokenizerError(e.to_string())
+        if let Some(limit) = e.limit() {
+            return ParserError::LimitExceeded(limit);
+        }
+        if e.location.line == 0 {
+            return ParserError::TokenizerError(e.to_string());
+        }
+        ParserError::SyntaxError(HeapBox::new(SyntaxError::new(
+            SyntaxErrorKind::Tokenizer,
+            e.message,
+            e.location.span_to(e.location),
+        )))
     }
 }
 
 impl fmt::Display for ParserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "sql parser error: {}",
-            match self {
-                ParserError::TokenizerError(s) => s,
-                ParserError::ParserError(s) => s,
-                ParserError::RecursionLimitExceeded => "recursion limit exceeded",
+        match self {
+            ParserError::TokenizerError(s) => write!(f, "sql parser error: {s}"),
+            ParserError::ParserError(s) => write!(f, "sql parser error: {s}"),
+            ParserError::RecursionLimitExceeded => {
+                f.write_str("sql parser error: recursion limit exceeded")
             }
-        )
+            ParserError::SyntaxError(e) => write!(f, "sql parser error: {e}"),
+            ParserError::LimitExceeded(e) => write!(f, "sql parser error: {e}"),
+        }
     }
 }
 
@@ -468,8 +871,8 @@ const DEFAULT_REMAINING_DEPTH: usize = 50;
 const EOF_TOKEN: TokenWithSpan = TokenWithSpan {
     token: BorrowedToken::EOF,
     span: Span {
-        start: Location { line: 0, column: 0 },
-        end: Location { line: 0, column: 0 },
+        start: Location::empty(),
+        end: Location::empty(),
     },
 };
 
@@ -509,6 +912,16 @@ pub struct ParserOptions {
     /// Controls if the parser expects a semi-colon token
     /// between statements. Default is `true`.
     pub require_semicolon_stmt_delimiter: bool,
+    /// Keep source comments on the AST. See
+    /// [`ParserOptions::with_attach_comments`]. Default is `false`.
+    pub attach_comments: bool,
+    /// Arena chunk pool for documents parsed with these options. See
+    /// [`ParserOptions::with_chunk_pool`]. Default is `None`.
+    #[cfg(feature = "std")]
+    pub chunk_pool: Option<Arc<AstChunkPool>>,
+    /// Size limits for untrusted input. See
+    /// [`ParserOptions::with_limits`]. Default is no limits.
+    pub limits: ParserLimits,
 }
 
 impl Default for ParserOptions {
@@ -518,6 +931,10 @@ impl Default for ParserOptions {
             bracket_quoted_identifiers: false,
             unescape: true,
             require_semicolon_stmt_delimiter: true,
+            attach_comments: false,
+            #[cfg(feature = "std")]
+            chunk_pool: None,
+            limits: ParserLimits::default(),
         }
     }
 }
@@ -557,6 +974,56 @@ impl ParserOptions {
         self.unescape = unescape;
         self
     }
+
+    /// Set if comments are attached to the AST. Defaults to false.
+    ///
+    /// When enabled, comments next to a top-level statement, a select item,
+    /// a column definition or a PL/SQL declaration are kept in the
+    /// `comments` field of that node, such as [`ColumnDef::comments`], and
+    /// printed again by its `Display` implementation. Of the statements,
+    /// those listed at [`Statement::comments`] carry comments. Other
+    /// comments are dropped as usual, see
+    /// [`Parser::unattached_comment_count`].
+    ///
+    /// ```
+    /// # use sqlparser::{parser::{Parser, ParserError, ParserOptions}, dialect::PostgreSqlDialect};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let sql = "-- active users\nSELECT id, name FROM users /* v2 */";
+    /// let statements = Parser::new(&PostgreSqlDialect {})
+    ///     .with_options(ParserOptions::new().with_attach_comments(true))
+    ///     .try_with_sql(sql)?
+    ///     .parse_statements()?;
+    /// assert_eq!(
+    ///     statements[0].to_string(),
+    ///     "-- active users\nSELECT id, name FROM users /* v2 */"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_attach_comments(mut self, attach_comments: bool) -> Self {
+        self.attach_comments = attach_comments;
+        self
+    }
+
+    /// Set the pool that documents parsed with these options draw their
+    /// arena chunks from and return them to. Defaults to none, in which
+    /// case each [`ParsedSql`](crate::ParsedSql) allocates and frees its own
+    /// chunks.
+    ///
+    /// The pool only affects document parsing; a [`Parser`] does not
+    /// allocate from an arena.
+    #[cfg(feature = "std")]
+    pub fn with_chunk_pool(mut self, pool: Arc<AstChunkPool>) -> Self {
+        self.chunk_pool = Some(pool);
+        self
+    }
+
+    /// Set the size limits checked while tokenizing and parsing. See
+    /// [`ParserLimits`].
+    pub fn with_limits(mut self, limits: ParserLimits) -> Self {
+        self.limits = limits;
+        self
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -616,6 +1083,9 @@ enum ParserState {
 pub struct Parser<'a> {
     /// The tokens
     tokens: Vec<TokenWithSpan<'a>>,
+    /// The SQL text the tokens were read from, when known. Used to resolve
+    /// error positions to byte offsets.
+    sql: Option<&'a str>,
     /// Whether the token stream contains whitespace/comment trivia.
     tokens_include_whitespace: bool,
     /// The index of the first unprocessed token in [`Parser::tokens`].
@@ -637,6 +1107,15 @@ pub struct Parser<'a> {
     /// Build rich diagnostics. Successful full-statement parsing disables
     /// this and retries with it enabled only when the fast pass fails.
     detailed_errors: Cell<bool>,
+    /// Also record the keywords and tokens probed for at the end of input,
+    /// which lists what the grammar accepts there for
+    /// [`completions_at`](crate::completion::completions_at).
+    collect_candidates: Cell<bool>,
+    /// Replace expressions and table factors that fail to parse with
+    /// placeholders, for [`Self::parse_statements_tolerant`].
+    tolerant: Cell<bool>,
+    /// Errors replaced by placeholders in the statement being parsed.
+    tolerated_errors: RefCell<Vec<StatementError>>,
     /// Nesting depth of a PL/SQL or SQL/PSM body. Some tokens, including
     /// PostgreSQL's procedural `RETURNING ... INTO`, are legal only here.
     procedural_body_depth: Cell<usize>,
@@ -644,6 +1123,14 @@ pub struct Parser<'a> {
     /// This lets PL/pgSQL `ALIAS FOR` declarations resolve through grammar,
     /// without rewriting the body text before parsing it.
     routine_args: Vec<OperateFunctionArg>,
+    /// Comments in tokens before this index have already been attached to
+    /// a node, when [`ParserOptions::attach_comments`] is set.
+    comments_attached_until: Cell<usize>,
+    /// Number of comments attached to nodes so far.
+    attached_comment_count: Cell<usize>,
+    /// Arena that boxed AST nodes are allocated in, when one is passed
+    /// explicitly instead of through the thread-local document arena.
+    arena: Option<&'a AstArena>,
 }
 
 impl<'a> Parser<'a> {
@@ -666,6 +1153,7 @@ impl<'a> Parser<'a> {
         let features = dialect.features();
         Self {
             tokens: vec![],
+            sql: None,
             tokens_include_whitespace: false,
             index: Cell::new(0),
             state: Cell::new(ParserState::Normal),
@@ -675,8 +1163,14 @@ impl<'a> Parser<'a> {
             options: ParserOptions::new().with_trailing_commas(dialect.supports_trailing_commas()),
             error_tracker: ErrorTracker::new(),
             detailed_errors: Cell::new(true),
+            collect_candidates: Cell::new(false),
+            tolerant: Cell::new(false),
+            tolerated_errors: RefCell::new(Vec::new()),
             procedural_body_depth: Cell::new(0),
             routine_args: Vec::new(),
+            comments_attached_until: Cell::new(0),
+            attached_comment_count: Cell::new(0),
+            arena: None,
         }
     }
 
@@ -745,8 +1239,15 @@ impl<'a> Parser<'a> {
         // Check if the fallback is a generic "Expected" error that should be replaced
         // We're very conservative: only replace errors about keywords (words with letters)
         // Preserve errors about tokens, punctuation, and descriptive phrases
-        let should_replace = match &fallback {
-            ParserError::ParserError(msg) => {
+        let message = match &fallback {
+            ParserError::ParserError(msg) => Some(msg.as_str()),
+            ParserError::SyntaxError(error) if error.kind == SyntaxErrorKind::Parser => {
+                Some(error.message.as_str())
+            }
+            _ => None,
+        };
+        let should_replace = match message {
+            Some(msg) => {
                 // Only consider replacing if it starts with "Expected:"
                 if let Some(rest) = msg.strip_prefix("Expected: ") {
                     if let Some(expected_part) = rest.split(", found:").next() {
@@ -763,7 +1264,7 @@ impl<'a> Parser<'a> {
                     false
                 }
             }
-            _ => false,
+            None => false,
         };
 
         // Only use the tracked error if:
@@ -790,7 +1291,13 @@ impl<'a> Parser<'a> {
                 let furthest_idx = self.error_tracker.furthest_index();
                 let found = self.tokens.get(furthest_idx).unwrap_or(&EOF_TOKEN);
                 let msg = self.error_tracker.format_error(found);
-                return parser_err!(msg, found.span.start);
+                return Err(self.syntax_error(
+                    msg,
+                    found,
+                    self.error_tracker.expected_items(),
+                    self.error_tracker.furthest_contexts(),
+                    self.error_tracker.typo_hint(),
+                ));
             }
         }
         Err(fallback)
@@ -850,22 +1357,55 @@ impl<'a> Parser<'a> {
         self
     }
 
+    /// Allocate boxed AST nodes in `arena` rather than in the thread-local
+    /// document arena, if any, or on the heap.
+    pub(crate) fn with_explicit_arena(mut self, arena: &'a AstArena) -> Self {
+        self.arena = Some(arena);
+        self
+    }
+
+    /// Box an AST node in this parser's explicit arena, if it has one,
+    /// failing if it does not fit the arena's remaining byte budget.
+    pub(crate) fn new_box<T: ArenaResident>(&self, value: T) -> Result<Box<T>, ParserError> {
+        match self.arena {
+            Some(arena) => Box::try_new_in(value, arena),
+            None => Box::try_new(value),
+        }
+    }
+
+    /// Convert parsed list items to an AST list, failing if they do not fit
+    /// the document arena's remaining byte budget.
+    pub(crate) fn new_list<T>(&self, items: Vec<T>) -> Result<AstVec<T>, ParserError> {
+        AstVec::try_from_vec(items)
+    }
+
+    /// Build an object name from `idents`, like [`ObjectName::from`], but
+    /// within the document arena's byte budget.
+    fn object_name(&self, idents: Vec<Ident>) -> Result<ObjectName, ParserError> {
+        let parts = idents.into_iter().map(ObjectNamePart::Identifier).collect();
+        Ok(ObjectName(self.new_list(parts)?))
+    }
+
     /// Reset this parser to parse the specified token stream
     pub fn with_tokens_with_locations(self, tokens: Vec<TokenWithSpan<'a>>) -> Self {
         let tokens_include_whitespace = tokens
             .iter()
             .any(|token| matches!(token.token, BorrowedToken::Whitespace(_)));
-        self.with_tokens_with_locations_and_whitespace(tokens, tokens_include_whitespace)
+        self.with_tokens_with_locations_and_whitespace(tokens, tokens_include_whitespace, None)
     }
 
     fn with_tokens_with_locations_and_whitespace(
         mut self,
         tokens: Vec<TokenWithSpan<'a>>,
         tokens_include_whitespace: bool,
+        sql: Option<&'a str>,
     ) -> Self {
         self.tokens_include_whitespace = tokens_include_whitespace;
         self.tokens = tokens;
+        self.sql = sql;
         self.index = Cell::new(0);
+        self.comments_attached_until = Cell::new(0);
+        self.attached_comment_count = Cell::new(0);
         self.error_tracker.reset();
         self
     }
@@ -890,12 +1430,31 @@ impl<'a> Parser<'a> {
     ///
     /// See example on [`Parser::new()`] for an example
     pub fn try_with_sql(self, sql: &'a str) -> Result<Self, ParserError> {
+        self.try_with_sql_at(sql, Location::new(1, 1))
+    }
+
+    /// Like [`Self::try_with_sql`], for `sql` that starts at `start` within
+    /// a larger script, such as a statement of a
+    /// [`StatementReader`](crate::splitter::StatementReader). Spans and
+    /// errors report lines and columns of the script; byte ranges stay
+    /// relative to `sql`.
+    pub fn try_with_sql_at(self, sql: &'a str, start: Location) -> Result<Self, ParserError> {
         debug!("Parsing sql '{sql}'...");
-        let (tokens, tokens_include_whitespace) = Tokenizer::new(self.dialect, sql)
+        let mut tokenizer = Tokenizer::new(self.dialect, sql)
+            .with_start_location(start)
             .with_unescape(self.options.unescape)
             .with_bracket_quoted_identifiers(self.options.bracket_quoted_identifiers)
-            .tokenize_for_parser()?;
-        Ok(self.with_tokens_with_locations_and_whitespace(tokens, tokens_include_whitespace))
+            .with_limits(self.options.limits);
+        let (tokens, tokens_include_whitespace) = if self.options.attach_comments {
+            (tokenizer.tokenize_with_location()?, true)
+        } else {
+            tokenizer.tokenize_for_parser()?
+        };
+        Ok(self.with_tokens_with_locations_and_whitespace(
+            tokens,
+            tokens_include_whitespace,
+            Some(sql),
+        ))
     }
 
     /// Parse potentially multiple statements
@@ -924,66 +1483,607 @@ impl<'a> Parser<'a> {
     pub fn parse_statements_with_spans(&self) -> Result<(Vec<Statement>, Vec<Span>), ParserError> {
         let start_index = self.index.get();
         let start_state = self.state.get();
+        let start_comments = self.comment_checkpoint();
         let previous_detailed_errors = self.detailed_errors.replace(false);
         let result = self.parse_statements_inner_with_spans();
         self.detailed_errors.set(previous_detailed_errors);
 
         if result.is_ok()
             || !previous_detailed_errors
-            || matches!(&result, Err(ParserError::RecursionLimitExceeded))
+            || matches!(
+                &result,
+                Err(ParserError::RecursionLimitExceeded | ParserError::LimitExceeded(_))
+            )
         {
             return result;
         }
 
         self.index.set(start_index);
         self.state.set(start_state);
+        self.restore_comments(start_comments);
         self.error_tracker.reset();
         self.parse_statements_inner_with_spans()
     }
 
-    fn parse_statements_inner_with_spans(
-        &self,
-    ) -> Result<(Vec<Statement>, Vec<Span>), ParserError> {
-        let mut stmts = Vec::new();
-        let mut spans = Vec::new();
-        let mut expecting_statement_delimiter = false;
-        loop {
-            // ignore empty statements (between successive statement delimiters)
-            while self.consume_token(&BorrowedToken::SemiColon) {
-                expecting_statement_delimiter = false;
-            }
+    fn parse_statements_inner_with_spans(
+        &self,
+    ) -> Result<(Vec<Statement>, Vec<Span>), ParserError> {
+        let mut stmts = Vec::new();
+        let mut spans = Vec::new();
+        let mut expecting_statement_delimiter = false;
+        loop {
+            // ignore empty statements (between successive statement delimiters)
+            while self.consume_token(&BorrowedToken::SemiColon) {
+                expecting_statement_delimiter = false;
+            }
+
+            if !self.options.require_semicolon_stmt_delimiter {
+                expecting_statement_delimiter = false;
+            }
+
+            match self.peek_token().token {
+                BorrowedToken::EOF => {
+                    if !expecting_statement_delimiter {
+                        self.record_candidate(|| {
+                            ExpectedItem::Category("an SQL statement".to_string())
+                        });
+                    }
+                    break;
+                }
+
+                // end of statement
+                BorrowedToken::Word(word)
+                    if expecting_statement_delimiter && word.keyword == Keyword::END =>
+                {
+                    break;
+                }
+                _ => {}
+            }
+
+            if expecting_statement_delimiter {
+                return self.expected("end of statement", self.peek_token());
+            }
+
+            let start = self.peek_token_ref().span.start;
+            let statement = match self.parse_statement_with_comments() {
+                Ok(stmt) => stmt,
+                Err(e) => return self.build_best_error(e),
+            };
+            let end = self.get_current_token().span.end;
+            stmts.push(statement);
+            spans.push(Span::new(start, end));
+            expecting_statement_delimiter = true;
+        }
+        Ok((stmts, spans))
+    }
+
+    /// Parse the statements up to the end of input and report what the
+    /// grammar accepts there, with the parsing contexts active there,
+    /// innermost first.
+    ///
+    /// Returns `None` when parsing fails before the end of input.
+    pub(crate) fn completion_candidates(&self) -> Option<(Vec<ExpectedItem>, Vec<ParseContext>)> {
+        let end = self
+            .tokens
+            .iter()
+            .rposition(|token| {
+                !matches!(
+                    token.token,
+                    BorrowedToken::Whitespace(_) | BorrowedToken::EOF
+                )
+            })
+            .map_or(0, |index| index + 1);
+        self.error_tracker.reset();
+        self.error_tracker.set_end_index(end);
+        self.collect_candidates.set(true);
+        let _ = self.parse_statements_inner_with_spans();
+        self.collect_candidates.set(false);
+        self.error_tracker.set_end_index(usize::MAX);
+
+        if self.error_tracker.furthest_index() < end {
+            return None;
+        }
+        Some((
+            self.error_tracker.expected_items(),
+            self.error_tracker.furthest_contexts(),
+        ))
+    }
+
+    /// Parse potentially multiple statements, skipping the ones that fail
+    /// instead of aborting the whole batch.
+    ///
+    /// After an error the parser resynchronizes at the next `;` outside of a
+    /// `BEGIN ... END` block or, when
+    /// [`ParserOptions::require_semicolon_stmt_delimiter`] is off, at the
+    /// next keyword that starts a statement. With [`OracleDialect`] a `/`
+    /// alone on its line also ends a statement, as in SQL*Plus scripts.
+    ///
+    /// Example
+    /// ```
+    /// # use sqlparser::{parser::{Parser, ParserError}, dialect::PostgreSqlDialect};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let dialect = PostgreSqlDialect{};
+    /// let recovered = Parser::new(&dialect)
+    ///   .try_with_sql("SELECT 1; SELEC 2; SELECT 3")?
+    ///   .parse_statements_with_recovery();
+    /// assert_eq!(recovered.statements.len(), 2);
+    /// assert_eq!(recovered.errors.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_statements_with_recovery(&self) -> RecoveredStatements {
+        let mut recovered = RecoveredStatements::default();
+        loop {
+            while self.consume_token(&BorrowedToken::SemiColon) || self.consume_script_terminator()
+            {
+            }
+            if matches!(self.peek_token_ref().token, BorrowedToken::EOF) {
+                break;
+            }
+
+            let start_index = self.index.get();
+            let start = self.peek_token_ref().span.start;
+            match self.parse_recoverable_statement() {
+                Ok(statement) => {
+                    let end = self.get_current_token().span.end;
+                    recovered.statements.push(statement);
+                    recovered.spans.push(Span::new(start, end));
+                }
+                Err(error @ ParserError::LimitExceeded(_)) => {
+                    // The rest of the input is not parsed once a limit is
+                    // exceeded.
+                    let end = self.tokens.last().map_or(start, |token| token.span.end);
+                    recovered.errors.push(StatementError {
+                        error,
+                        span: Span::new(start, end),
+                    });
+                    break;
+                }
+                Err(error) => {
+                    let end = self.synchronize_after_error(start_index);
+                    recovered.errors.push(StatementError {
+                        error,
+                        span: Span::new(start, end),
+                    });
+                }
+            }
+        }
+        recovered
+    }
+
+    /// Parse one statement and check that a delimiter follows it, retrying
+    /// with detailed diagnostics on failure like
+    /// [`Self::parse_statements_with_spans`].
+    fn parse_recoverable_statement(&self) -> Result<Statement, ParserError> {
+        let start_index = self.index.get();
+        let start_state = self.state.get();
+        let start_comments = self.comment_checkpoint();
+        let previous_detailed_errors = self.detailed_errors.replace(false);
+        self.error_tracker.reset();
+        let result = self.parse_delimited_statement();
+        self.detailed_errors.set(previous_detailed_errors);
+
+        if result.is_ok()
+            || !previous_detailed_errors
+            || matches!(
+                &result,
+                Err(ParserError::RecursionLimitExceeded | ParserError::LimitExceeded(_))
+            )
+        {
+            return result;
+        }
+
+        self.index.set(start_index);
+        self.state.set(start_state);
+        self.restore_comments(start_comments);
+        self.error_tracker.reset();
+        self.parse_delimited_statement()
+    }
+
+    fn parse_delimited_statement(&self) -> Result<Statement, ParserError> {
+        let statement = match self.parse_statement_with_comments() {
+            Ok(stmt) => stmt,
+            Err(e) => return self.build_best_error(e),
+        };
+        if !self.at_statement_delimiter() {
+            return self.expected("end of statement", self.peek_token());
+        }
+        Ok(statement)
+    }
+
+    /// Whether a statement may end before the next token.
+    fn at_statement_delimiter(&self) -> bool {
+        !self.options.require_semicolon_stmt_delimiter
+            || matches!(
+                self.peek_token_ref().token,
+                BorrowedToken::SemiColon | BorrowedToken::EOF
+            )
+            || self.is_script_terminator(self.significant_token_index(self.index.get()))
+    }
+
+    /// Parse potentially multiple statements, keeping a partial AST for the
+    /// ones that fail to parse instead of skipping them.
+    ///
+    /// Each statement is parsed strictly first, so statements that parse
+    /// produce the same AST as with [`Self::parse_statements`]. A statement
+    /// that fails is parsed again, replacing each expression or table factor
+    /// that fails to parse with an [`Expr::Error`] or [`TableFactor::Error`]
+    /// placeholder. The placeholder spans the tokens skipped up to the next
+    /// `,`, closing bracket or keyword that may continue the enclosing
+    /// construct, such as `FROM` or `WHERE`. Tokens after the end of a
+    /// partial statement are skipped up to its delimiter. A statement that
+    /// cannot be parsed even partially, such as one that does not start with
+    /// a known keyword, is skipped as by
+    /// [`Self::parse_statements_with_recovery`].
+    ///
+    /// Example
+    /// ```
+    /// # use sqlparser::{parser::{Parser, ParserError}, dialect::PostgreSqlDialect};
+    /// # use sqlparser::ast::Spanned;
+    /// # fn main() -> Result<(), ParserError> {
+    /// let dialect = PostgreSqlDialect{};
+    /// let parsed = Parser::new(&dialect)
+    ///   .try_with_sql("SELECT a, FROM t WHERE")?
+    ///   .parse_statements_tolerant();
+    /// assert_eq!(parsed.statements.len(), 1);
+    /// assert_eq!(parsed.statements[0].to_string(), "SELECT a,  FROM t WHERE ");
+    /// assert_eq!(parsed.errors.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_statements_tolerant(&self) -> PartialStatements {
+        let mut parsed = PartialStatements::default();
+        loop {
+            while self.consume_token(&BorrowedToken::SemiColon) || self.consume_script_terminator()
+            {
+            }
+            if matches!(self.peek_token_ref().token, BorrowedToken::EOF) {
+                break;
+            }
+
+            let start_index = self.index.get();
+            let start_state = self.state.get();
+            let start_comments = self.comment_checkpoint();
+            let start = self.peek_token_ref().span.start;
+            match self.parse_recoverable_statement() {
+                Ok(statement) => {
+                    let end = self.get_current_token().span.end;
+                    parsed.statements.push(statement);
+                    parsed.spans.push(Span::new(start, end));
+                }
+                Err(error @ ParserError::LimitExceeded(_)) => {
+                    let end = self.tokens.last().map_or(start, |token| token.span.end);
+                    parsed.errors.push(StatementError {
+                        error,
+                        span: Span::new(start, end),
+                    });
+                    break;
+                }
+                Err(error) => {
+                    self.index.set(start_index);
+                    self.state.set(start_state);
+                    self.restore_comments(start_comments);
+                    match self.parse_partial_statement(start_index) {
+                        Some((statement, end, errors)) => {
+                            parsed.statements.push(statement);
+                            parsed.spans.push(Span::new(start, end));
+                            parsed.errors.extend(errors);
+                        }
+                        None => {
+                            let end = self.synchronize_after_error(start_index);
+                            parsed.errors.push(StatementError {
+                                error,
+                                span: Span::new(start, end),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        parsed
+    }
+
+    /// Parse the statement at `start_index` in tolerant mode, returning it
+    /// with the end of its last token and the errors replaced by
+    /// placeholders, or `None` when it has no partial AST.
+    fn parse_partial_statement(
+        &self,
+        start_index: usize,
+    ) -> Option<(Statement, Location, Vec<StatementError>)> {
+        self.error_tracker.reset();
+        self.tolerated_errors.borrow_mut().clear();
+        let previous_detailed_errors = self.detailed_errors.replace(true);
+        self.tolerant.set(true);
+        let result = self.parse_statement_with_comments();
+        self.tolerant.set(false);
+        self.detailed_errors.set(previous_detailed_errors);
+        let mut errors = self.tolerated_errors.take();
+
+        let statement = result.ok()?;
+        let end = self.get_current_token().span.end;
+        if !self.at_statement_delimiter() {
+            let start = self.peek_token_ref().span.start;
+            let error = self
+                .expected::<()>("end of statement", self.peek_token())
+                .unwrap_err();
+            let skipped_end = self.synchronize_after_error(start_index);
+            errors.push(StatementError {
+                error,
+                span: Span::new(start, skipped_end),
+            });
+        }
+        // A statement that failed strictly but needs no placeholder in
+        // tolerant mode has no error to report for it; skip it instead.
+        if errors.is_empty() {
+            return None;
+        }
+        Some((statement, end, errors))
+    }
+
+    /// In tolerant mode, skip the tokens from `start_index` of an expression
+    /// or table factor that failed to parse with `error`, up to where the
+    /// enclosing construct may resume, and record the error. Returns the
+    /// span of the skipped tokens for a placeholder, or `error` itself
+    /// outside of tolerant mode.
+    fn tolerate_error(
+        &self,
+        start_index: usize,
+        error: ParserError,
+    ) -> Result<AttachedToken, ParserError> {
+        // Every placeholder skips tokens or precedes one, so more of them
+        // than tokens means that the grammar loops at the same position.
+        if !self.tolerant.get()
+            || matches!(
+                error,
+                ParserError::RecursionLimitExceeded | ParserError::LimitExceeded(_)
+            )
+            || self.tolerated_errors.borrow().len() > self.tokens.len()
+        {
+            return Err(error);
+        }
+        self.index.set(start_index);
+        let start = match self.peek_token_ref() {
+            token if token.token == BorrowedToken::EOF => self.get_current_token().span.end,
+            token => token.span.start,
+        };
+        let mut end = start;
+        let mut depth = 0usize;
+        loop {
+            let token = self.peek_token_ref();
+            match &token.token {
+                BorrowedToken::EOF | BorrowedToken::SemiColon => break,
+                BorrowedToken::LParen | BorrowedToken::LBracket | BorrowedToken::LBrace => {
+                    depth += 1
+                }
+                BorrowedToken::RParen | BorrowedToken::RBracket | BorrowedToken::RBrace => {
+                    if depth == 0 {
+                        break;
+                    }
+                    depth -= 1;
+                }
+                BorrowedToken::Comma if depth == 0 => break,
+                BorrowedToken::Word(_) if depth == 0 && self.at_tolerant_resume_keyword() => break,
+                _ => {}
+            }
+            end = token.span.end;
+            self.advance_token();
+        }
+        let span = Span::new(start, end);
+        let mut tolerated_errors = self.tolerated_errors.borrow_mut();
+        // Placeholders in the skipped tokens are not part of the AST.
+        tolerated_errors.retain(|tolerated| tolerated.span.start < start);
+        tolerated_errors.push(StatementError { error, span });
+        Ok(AttachedToken(span, None))
+    }
+
+    /// Whether the next token is a keyword at which tolerant mode stops
+    /// skipping tokens, and which does not start an expression or table
+    /// factor there. A keyword followed by `(` is taken as a function
+    /// name, as in `LEFT(s, 1)`.
+    fn at_tolerant_resume_keyword(&self) -> bool {
+        match &self.peek_token_ref().token {
+            BorrowedToken::Word(word) if word.quote_style.is_none() => {
+                (TOLERANT_RESUME_KEYWORDS.contains(&word.keyword)
+                    || RECOVERY_STATEMENT_KEYWORDS.contains(&word.keyword))
+                    && self.peek_nth_token_ref(1).token != BorrowedToken::LParen
+            }
+            _ => false,
+        }
+    }
+
+    /// Skip the rest of a statement that failed to parse, leaving the parser
+    /// at the delimiter to resume from, and return the end of the last
+    /// skipped token.
+    ///
+    /// The scan restarts at the beginning of the statement so that block
+    /// nesting is known, and never stops before the furthest token the
+    /// parser reached: a `;` the parser consumed belonged to a body.
+    fn synchronize_after_error(&self, start_index: usize) -> Location {
+        let error_index = self.error_tracker.furthest_index().max(self.index.get());
+        let first = self.significant_token_index(start_index);
+        let mut blocks = BlockTracker::new(self.dialect);
+        let mut end = Location::empty();
+        let mut index = first;
+        while let Some(token) = self.tokens.get(index) {
+            let resumable = index > first && index >= error_index && !blocks.in_block();
+            match &token.token {
+                BorrowedToken::EOF => break,
+                BorrowedToken::SemiColon if resumable => break,
+                BorrowedToken::Div if index > first && self.is_script_terminator(index) => break,
+                BorrowedToken::Word(word)
+                    if resumable
+                        && !blocks.in_parens()
+                        && !self.options.require_semicolon_stmt_delimiter
+                        && RECOVERY_STATEMENT_KEYWORDS.contains(&word.keyword) =>
+                {
+                    break
+                }
+                _ => blocks.observe(&self.tokens, index),
+            }
+            end = token.span.end;
+            index = self.significant_token_index(index + 1);
+        }
+        self.index.set(index);
+        end
+    }
+
+    /// Consume an Oracle `/` script terminator, if one is next.
+    fn consume_script_terminator(&self) -> bool {
+        let index = self.significant_token_index(self.index.get());
+        if self.is_script_terminator(index) {
+            self.index.set(index + 1);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Whether the token at `index` is a `/` alone on its line, which ends
+    /// a PL/SQL unit in Oracle scripts.
+    fn is_script_terminator(&self, index: usize) -> bool {
+        self.dialect.is::<OracleDialect>() && is_lone_slash(&self.tokens, index)
+    }
+
+    /// Index of the first token at or after `index` that is not whitespace.
+    fn significant_token_index(&self, index: usize) -> usize {
+        significant_index(&self.tokens, index)
+    }
+
+    /// Number of comments in the tokens that were not attached to a node,
+    /// because they are not next to a node that keeps comments. Printing
+    /// the AST drops these. Always 0 unless
+    /// [`ParserOptions::attach_comments`] is set.
+    pub fn unattached_comment_count(&self) -> usize {
+        if !self.options.attach_comments {
+            return 0;
+        }
+        let comments = self
+            .tokens
+            .iter()
+            .filter(|token| match &token.token {
+                BorrowedToken::Whitespace(whitespace) => {
+                    SourceComment::from_whitespace(whitespace).is_some()
+                }
+                _ => false,
+            })
+            .count();
+        comments.saturating_sub(self.attached_comment_count.get())
+    }
+
+    /// Parse a top-level statement with its comments attached, when it is
+    /// one that carries them.
+    fn parse_statement_with_comments(&self) -> Result<Statement, ParserError> {
+        let (mut statement, comments) =
+            self.parse_with_comments(&BorrowedToken::SemiColon, || self.parse_statement())?;
+        if !comments.is_empty() {
+            self.attach_comments(statement.comments_mut(), comments);
+        }
+        Ok(statement)
+    }
+
+    /// Run `parse` and, when [`ParserOptions::attach_comments`] is set,
+    /// return the comments before the parsed node and those after it on
+    /// the line where it ends, looking past one `separator` token.
+    ///
+    /// A comment is attached at most once: nodes parsed later, such as the
+    /// statement enclosing a select item, skip comments already taken.
+    fn parse_with_comments<T>(
+        &self,
+        separator: &BorrowedToken,
+        parse: impl FnOnce() -> Result<T, ParserError>,
+    ) -> Result<(T, Comments), ParserError> {
+        if !self.options.attach_comments {
+            return Ok((parse()?, Comments::default()));
+        }
+        let leading = self.take_leading_comments();
+        let node = parse()?;
+        let trailing = self.take_trailing_comments(separator);
+        Ok((node, Comments { leading, trailing }))
+    }
+
+    /// Store `comments` in the `comments` field of a node and count them as
+    /// attached. Without a field to keep them in, they stay unattached.
+    fn attach_comments(&self, field: Option<&mut Comments>, comments: Comments) {
+        if let Some(field) = field {
+            self.attached_comment_count.set(
+                self.attached_comment_count.get()
+                    + comments.leading.len()
+                    + comments.trailing.len(),
+            );
+            *field = comments;
+        }
+    }
+
+    /// Where comment attachment stands, to undo it with
+    /// [`Self::restore_comments`] when backtracking.
+    fn comment_checkpoint(&self) -> (usize, usize) {
+        (
+            self.comments_attached_until.get(),
+            self.attached_comment_count.get(),
+        )
+    }
 
-            if !self.options.require_semicolon_stmt_delimiter {
-                expecting_statement_delimiter = false;
-            }
+    fn restore_comments(&self, (attached_until, attached): (usize, usize)) {
+        self.comments_attached_until.set(attached_until);
+        self.attached_comment_count.set(attached);
+    }
 
-            match self.peek_token().token {
-                BorrowedToken::EOF => break,
+    fn take_leading_comments(&self) -> Vec<SourceComment> {
+        let attached = self.comments_attached_until.get();
+        let next = self.significant_token_index(self.index.get());
+        let mut first = next;
+        while first > attached && self.is_whitespace_at(first - 1) {
+            first -= 1;
+        }
+        self.comments_attached_until.set(next.max(attached));
+        self.tokens[first..next.min(self.tokens.len())]
+            .iter()
+            .filter_map(|token| match &token.token {
+                BorrowedToken::Whitespace(whitespace) => SourceComment::from_whitespace(whitespace),
+                _ => None,
+            })
+            .collect()
+    }
 
-                // end of statement
-                BorrowedToken::Word(word) => {
-                    if expecting_statement_delimiter && word.keyword == Keyword::END {
-                        break;
+    fn take_trailing_comments(&self, separator: &BorrowedToken) -> Vec<SourceComment> {
+        let mut end = self.index.get().min(self.tokens.len());
+        while end > 0 && self.is_whitespace_at(end - 1) {
+            end -= 1;
+        }
+        let Some(last) = end.checked_sub(1).map(|index| &self.tokens[index]) else {
+            return vec![];
+        };
+        let line = last.span.end.line;
+        let mut comments = vec![];
+        let mut separated = false;
+        let mut index = end.max(self.comments_attached_until.get());
+        while let Some(token) = self.tokens.get(index) {
+            if token.span.start.line != line {
+                break;
+            }
+            match &token.token {
+                BorrowedToken::Whitespace(whitespace) => {
+                    if let Some(comment) = SourceComment::from_whitespace(whitespace) {
+                        comments.push(comment);
+                        self.comments_attached_until.set(index + 1);
                     }
                 }
-                _ => {}
-            }
-
-            if expecting_statement_delimiter {
-                return self.expected("end of statement", self.peek_token());
+                token if !separated && token == separator => separated = true,
+                _ => break,
             }
-
-            let start = self.peek_token_ref().span.start;
-            let statement = match self.parse_statement() {
-                Ok(stmt) => stmt,
-                Err(e) => return self.build_best_error(e),
-            };
-            let end = self.get_current_token().span.end;
-            stmts.push(statement);
-            spans.push(Span::new(start, end));
-            expecting_statement_delimiter = true;
+            index += 1;
         }
-        Ok((stmts, spans))
+        comments
+    }
+
+    fn is_whitespace_at(&self, index: usize) -> bool {
+        matches!(
+            self.tokens.get(index),
+            Some(TokenWithSpan {
+                token: BorrowedToken::Whitespace(_),
+                ..
+            })
+        )
     }
 
     /// Convenience method to parse a string with one or more SQL
@@ -1029,6 +2129,38 @@ impl<'a> Parser<'a> {
             .parse_statements_with_spans()
     }
 
+    /// Parse SQL in recovery mode, returning every statement that parsed
+    /// together with an error for each one that did not. See
+    /// [`Self::parse_statements_with_recovery`].
+    ///
+    /// Only tokenizer errors abort the batch.
+    pub fn parse_sql_with_recovery(
+        dialect: &dyn Dialect,
+        sql: &str,
+        options: ParserOptions,
+    ) -> Result<RecoveredStatements, ParserError> {
+        Ok(Parser::new(dialect)
+            .with_options(options)
+            .try_with_sql(sql)?
+            .parse_statements_with_recovery())
+    }
+
+    /// Parse SQL in tolerant mode, returning every statement with a partial
+    /// AST for the ones that failed to parse, together with the errors
+    /// replaced by placeholders. See [`Self::parse_statements_tolerant`].
+    ///
+    /// Only tokenizer errors abort the batch.
+    pub fn parse_sql_tolerant(
+        dialect: &dyn Dialect,
+        sql: &str,
+        options: ParserOptions,
+    ) -> Result<PartialStatements, ParserError> {
+        Ok(Parser::new(dialect)
+            .with_options(options)
+            .try_with_sql(sql)?
+            .parse_statements_tolerant())
+    }
+
     /// Parse a single top-level statement (such as SELECT, INSERT, CREATE, etc.),
     /// stopping before the statement separator, if any.
     pub fn parse_statement(&self) -> Result<Statement, ParserError> {
@@ -1184,7 +2316,8 @@ impl<'a> Parser<'a> {
                     Keyword::DISCARD => self.parse_discard(),
                     Keyword::DECLARE if self.dialect.is::<OracleDialect>() => {
                         self.prev_token();
-                        self.parse_sql_psm_block().map(Statement::PlSqlBlock)
+                        self.parse_sql_psm_block()
+                            .and_then(|block| self.new_box(block).map(Statement::PlSqlBlock))
                     }
                     Keyword::DECLARE => self.parse_declare(),
                     Keyword::FETCH if self.dialect.is::<OracleDialect>() => {
@@ -1255,7 +2388,8 @@ impl<'a> Parser<'a> {
                     }
                     Keyword::BEGIN if self.dialect.is::<OracleDialect>() => {
                         self.prev_token();
-                        self.parse_sql_psm_block().map(Statement::PlSqlBlock)
+                        self.parse_sql_psm_block()
+                            .and_then(|block| self.new_box(block).map(Statement::PlSqlBlock))
                     }
                     Keyword::BEGIN => self.parse_begin(),
                     Keyword::END => self.parse_end(),
@@ -1395,10 +2529,12 @@ impl<'a> Parser<'a> {
                                             Expr::Identifier(_) | Expr::CompoundIdentifier(_)
                                         ));
                                 if is_assignment {
-                                    Ok(Statement::SqlPsmAssignment(SqlPsmAssignment {
-                                        target: box_into_inner(left),
-                                        value: box_into_inner(right),
-                                    }))
+                                    Ok(Statement::SqlPsmAssignment(self.new_box(
+                                        SqlPsmAssignment {
+                                            target: box_into_inner(left),
+                                            value: box_into_inner(right),
+                                        },
+                                    )?))
                                 } else {
                                     parser_err!(
                                         "Not a SQL/PSM assignment",
@@ -1418,9 +2554,9 @@ impl<'a> Parser<'a> {
                             if let Ok(Some(call)) = self.maybe_parse(|parser| {
                                 let expr = parser.parse_expr()?;
                                 let function = match expr {
-                                    Expr::Function(function) => function,
+                                    Expr::Function(function) => box_into_inner(function),
                                     Expr::Identifier(identifier) => Function {
-                                        name: ObjectName::from(vec![identifier]),
+                                        name: self.object_name(vec![box_into_inner(identifier)])?,
                                         uses_odbc_syntax: false,
                                         parameters: FunctionArguments::None,
                                         args: FunctionArguments::None,
@@ -1431,7 +2567,7 @@ impl<'a> Parser<'a> {
                                         within_group: vec![],
                                     },
                                     Expr::CompoundIdentifier(identifiers) => Function {
-                                        name: ObjectName::from(identifiers),
+                                        name: self.object_name(identifiers.into_vec())?,
                                         uses_odbc_syntax: false,
                                         parameters: FunctionArguments::None,
                                         args: FunctionArguments::None,
@@ -1448,7 +2584,7 @@ impl<'a> Parser<'a> {
                                         );
                                     }
                                 };
-                                Ok(Statement::PlSqlProcedureCall(function))
+                                Ok(Statement::PlSqlProcedureCall(self.new_box(function)?))
                             }) {
                                 return Ok(call);
                             }
@@ -1474,12 +2610,13 @@ impl<'a> Parser<'a> {
                     let statement = parser.parse_statement()?;
                     Ok(Statement::PlSqlLabeled {
                         label,
-                        statement: Box::new(statement),
+                        statement: self.new_box(statement)?,
                     })
                 })? {
                     Ok(statement)
                 } else {
-                    self.parse_sql_psm_block().map(Statement::PlSqlBlock)
+                    self.parse_sql_psm_block()
+                        .and_then(|block| self.new_box(block).map(Statement::PlSqlBlock))
                 }
             }
             BorrowedToken::Placeholder(ref directive)
@@ -1492,10 +2629,12 @@ impl<'a> Parser<'a> {
                 let expr = self.parse_expr()?;
                 if let Expr::BinaryOp { left, op, right } = expr {
                     if matches!(op, BinaryOperator::Assignment) {
-                        Ok(Statement::SqlPsmAssignment(SqlPsmAssignment {
-                            target: box_into_inner(left),
-                            value: box_into_inner(right),
-                        }))
+                        Ok(Statement::SqlPsmAssignment(self.new_box(
+                            SqlPsmAssignment {
+                                target: box_into_inner(left),
+                                value: box_into_inner(right),
+                            },
+                        )?))
                     } else {
                         self.expected("a PL/SQL assignment", next_token)
                     }
@@ -1782,7 +2921,7 @@ impl<'a> Parser<'a> {
         let token = self.next_token();
         match token.token {
             BorrowedToken::Word(word) => Ok(Ident {
-                value: word.value.into_owned(),
+                value: word.value.into(),
                 quote_style: word.quote_style,
                 span: token.span,
             }),
@@ -1997,7 +3136,7 @@ impl<'a> Parser<'a> {
                     } else {
                         None
                     };
-                    return Ok(Statement::CreateDatabase {
+                    return Ok(Statement::CreateDatabase(self.new_box(CreateDatabase {
                         create_token: create_token.clone(),
                         db_name: name,
                         if_not_exists: false,
@@ -2010,7 +3149,7 @@ impl<'a> Parser<'a> {
                         comment: None,
                         catalog_sync: None,
                         compatibility,
-                    });
+                    })?));
                 }
                 self.expect_oracle_words(&["USER", "SYS", "IDENTIFIED", "BY"])?;
                 let sys_password = self.parse_identifier()?;
@@ -2672,16 +3811,15 @@ impl<'a> Parser<'a> {
         ) {
             return self.expected("end of Oracle CREATE statement", self.peek_token());
         }
-        if let OracleCreateDefinition::Index {
-            kind: OracleIndexKind::Standard,
-            unique,
-            name,
-            table,
-            columns,
-            options,
-        } = &definition
-        {
-            if !or_replace
+        let definition = match definition {
+            OracleCreateDefinition::Index {
+                kind: OracleIndexKind::Standard,
+                unique,
+                name,
+                table,
+                columns,
+                options,
+            } if !or_replace
                 && !and_compile
                 && !options.online
                 && !options.local
@@ -2689,14 +3827,14 @@ impl<'a> Parser<'a> {
                 && options.parameters.is_none()
                 && options.vector_distance.is_none()
                 && options.target_accuracy.is_none()
-                && options.vector_parameters.is_empty()
+                && options.vector_parameters.is_empty() =>
             {
-                return Ok(Statement::CreateIndex(CreateIndex {
-                    name: Some(name.clone()),
-                    table_name: table.clone(),
+                return Ok(Statement::CreateIndex(self.new_box(CreateIndex {
+                    name: Some(name),
+                    table_name: table,
                     using: None,
-                    columns: columns.clone(),
-                    unique: *unique,
+                    columns,
+                    unique,
                     concurrently: false,
                     if_not_exists: false,
                     include: vec![],
@@ -2705,15 +3843,18 @@ impl<'a> Parser<'a> {
                     predicate: None,
                     index_options: vec![],
                     alter_options: vec![],
-                }));
+                })?));
             }
-        }
-        Ok(Statement::OracleCreate(OracleCreateStatement {
-            create_token,
-            or_replace,
-            and_compile,
-            definition,
-        }))
+            definition => definition,
+        };
+        Ok(Statement::OracleCreate(self.new_box(
+            OracleCreateStatement {
+                create_token,
+                or_replace,
+                and_compile,
+                definition,
+            },
+        )?))
     }
 
     fn parse_oracle_drop_statement(
@@ -2888,13 +4029,15 @@ impl<'a> Parser<'a> {
             );
         }
 
-        Ok(Statement::OracleDrop(OracleDropStatement {
-            drop_token,
-            object_type,
-            if_exists,
-            target,
-            options,
-        }))
+        Ok(Statement::OracleDrop(self.new_box(
+            OracleDropStatement {
+                drop_token,
+                object_type,
+                if_exists,
+                target,
+                options,
+            },
+        )?))
     }
 
     fn parse_oracle_alter_statement(
@@ -3193,12 +4336,14 @@ impl<'a> Parser<'a> {
             return self.expected("end of Oracle ALTER statement", self.peek_token());
         }
 
-        Ok(Statement::OracleAlter(OracleAlterStatement {
-            alter_token,
-            object_type,
-            target,
-            operation,
-        }))
+        Ok(Statement::OracleAlter(self.new_box(
+            OracleAlterStatement {
+                alter_token,
+                object_type,
+                target,
+                operation,
+            },
+        )?))
     }
 
     fn parse_oracle_audit_outcome(&self) -> Result<OracleAuditOutcome, ParserError> {
@@ -3238,19 +4383,21 @@ impl<'a> Parser<'a> {
         };
         self.ensure_oracle_command_end("end of SET TRANSACTION statement")?;
         if name.is_none() {
-            Ok(Statement::Set(SetStatement {
+            Ok(Statement::Set(self.new_box(SetStatement {
                 token: command_token,
                 inner: Set::SetTransaction {
                     modes,
                     snapshot: None,
                     session: false,
                 },
-            }))
+            })?))
         } else {
-            Ok(Statement::OracleCommand(OracleCommandStatement {
-                command_token,
-                command: OracleCommand::SetTransaction { modes, name },
-            }))
+            Ok(Statement::OracleCommand(self.new_box(
+                OracleCommandStatement {
+                    command_token,
+                    command: OracleCommand::SetTransaction { modes, name },
+                },
+            )?))
         }
     }
 
@@ -3263,10 +4410,12 @@ impl<'a> Parser<'a> {
         self.expect_oracle_words(&["IDENTIFIED", "BY"])?;
         let password = self.parse_identifier()?;
         self.ensure_oracle_command_end("end of SET ROLE statement")?;
-        Ok(Statement::OracleCommand(OracleCommandStatement {
-            command_token,
-            command: OracleCommand::SetRole { role, password },
-        }))
+        Ok(Statement::OracleCommand(self.new_box(
+            OracleCommandStatement {
+                command_token,
+                command: OracleCommand::SetRole { role, password },
+            },
+        )?))
     }
 
     fn parse_oracle_explain_plan(
@@ -3283,16 +4432,18 @@ impl<'a> Parser<'a> {
         self.expect_keyword(Keyword::INTO)?;
         let into = self.parse_object_name(false)?;
         self.expect_keyword(Keyword::FOR)?;
-        let statement = Box::new(self.parse_statement()?);
+        let statement = self.new_box(self.parse_statement()?)?;
         self.ensure_oracle_command_end("end of EXPLAIN PLAN statement")?;
-        Ok(Statement::OracleCommand(OracleCommandStatement {
-            command_token,
-            command: OracleCommand::ExplainPlan {
-                statement_id,
-                into,
-                statement,
+        Ok(Statement::OracleCommand(self.new_box(
+            OracleCommandStatement {
+                command_token,
+                command: OracleCommand::ExplainPlan {
+                    statement_id,
+                    into,
+                    statement,
+                },
             },
-        }))
+        )?))
     }
 
     fn parse_oracle_grant_system(
@@ -3313,14 +4464,16 @@ impl<'a> Parser<'a> {
         let grantees = self.parse_comma_separated(|parser| parser.parse_object_name(false))?;
         let admin_option = self.parse_keywords(&[Keyword::WITH, Keyword::ADMIN, Keyword::OPTION]);
         self.ensure_oracle_command_end("end of GRANT system privileges statement")?;
-        Ok(Statement::OracleCommand(OracleCommandStatement {
-            command_token,
-            command: OracleCommand::GrantSystemPrivileges {
-                privileges,
-                grantees,
-                admin_option,
+        Ok(Statement::OracleCommand(self.new_box(
+            OracleCommandStatement {
+                command_token,
+                command: OracleCommand::GrantSystemPrivileges {
+                    privileges,
+                    grantees,
+                    admin_option,
+                },
             },
-        }))
+        )?))
     }
 
     fn parse_oracle_command_statement(
@@ -3473,10 +4626,12 @@ impl<'a> Parser<'a> {
         ) {
             return self.expected("end of Oracle command", self.peek_token());
         }
-        Ok(Statement::OracleCommand(OracleCommandStatement {
-            command_token,
-            command,
-        }))
+        Ok(Statement::OracleCommand(self.new_box(
+            OracleCommandStatement {
+                command_token,
+                command,
+            },
+        )?))
     }
 
     /// Parse a `CASE` statement.
@@ -3496,25 +4651,21 @@ impl<'a> Parser<'a> {
             let mut oracle_when_controls = vec![];
             while self.parse_keyword(Keyword::WHEN) {
                 let start_token = AttachedToken::from(self.get_current_token().clone());
-                let controls = self.parse_comma_separated(Parser::parse_oracle_case_control)?;
+                let mut controls = self.parse_comma_separated(Parser::parse_oracle_case_control)?;
                 let then_token = self.expect_keyword(Keyword::THEN)?;
                 let conditional_statements = self.parse_conditional_statements(&[
                     Keyword::WHEN,
                     Keyword::ELSE,
                     Keyword::END,
                 ])?;
-                let simple_condition = if controls.len() == 1 {
-                    match &controls[0] {
-                        OracleCaseControl::Expression(expression) => Some(expression.clone()),
-                        OracleCaseControl::Comparison { .. } => None,
+                let (simple_condition, extended) = match controls.as_slice() {
+                    [OracleCaseControl::Expression(_)] => {
+                        let Some(OracleCaseControl::Expression(expression)) = controls.pop() else {
+                            unreachable!("a single expression control was just matched")
+                        };
+                        (Some(expression), None)
                     }
-                } else {
-                    None
-                };
-                let extended = if simple_condition.is_some() {
-                    None
-                } else {
-                    Some(controls)
+                    _ => (None, Some(controls)),
                 };
                 when_blocks.push(ConditionalStatementBlock {
                     start_token,
@@ -3553,14 +4704,14 @@ impl<'a> Parser<'a> {
             end_case_token = self.expect_keyword(Keyword::CASE)?;
         }
 
-        Ok(Statement::Case(CaseStatement {
+        Ok(Statement::Case(self.new_box(CaseStatement {
             case_token: AttachedToken::from(case_token),
             match_expr,
             when_blocks,
             oracle_when_controls,
             else_block,
             end_case_token: AttachedToken::from(end_case_token),
-        }))
+        })?))
     }
 
     fn parse_oracle_case_control(&self) -> Result<OracleCaseControl, ParserError> {
@@ -3692,12 +4843,12 @@ impl<'a> Parser<'a> {
         self.expect_keyword_is(Keyword::END)?;
         let end_token = self.expect_keyword(Keyword::IF)?;
 
-        Ok(Statement::If(IfStatement {
+        Ok(Statement::If(self.new_box(IfStatement {
             if_block,
             elseif_blocks,
             else_block,
             end_token: Some(AttachedToken::from(end_token)),
-        }))
+        })?))
     }
 
     /// Parse a `WHILE` statement.
@@ -3741,7 +4892,7 @@ impl<'a> Parser<'a> {
                 None
             };
 
-            return Ok(Statement::While(WhileStatement {
+            return Ok(Statement::While(self.new_box(WhileStatement {
                 label,
                 condition: Some(condition),
                 body,
@@ -3749,7 +4900,7 @@ impl<'a> Parser<'a> {
                 has_do_keyword,
                 has_loop_keyword,
                 while_block: None,
-            }));
+            })?));
         }
 
         // Legacy/MSSQL syntax: WHILE condition statement (no END WHILE)
@@ -3784,7 +4935,7 @@ impl<'a> Parser<'a> {
             conditional_statements,
         };
 
-        Ok(Statement::While(WhileStatement {
+        Ok(Statement::While(self.new_box(WhileStatement {
             label,
             condition: None,
             body: ConditionalStatements::Sequence { statements: vec![] },
@@ -3792,7 +4943,7 @@ impl<'a> Parser<'a> {
             has_do_keyword: false,
             has_loop_keyword: false,
             while_block: Some(while_block),
-        }))
+        })?))
     }
 
     /// Parse a `LOOP` statement.
@@ -3818,11 +4969,11 @@ impl<'a> Parser<'a> {
             None
         };
 
-        Ok(Statement::Loop(LoopStatement {
+        Ok(Statement::Loop(self.new_box(LoopStatement {
             label,
             body,
             end_label,
-        }))
+        })?))
     }
 
     /// Parse a `REPEAT` statement.
@@ -3850,12 +5001,12 @@ impl<'a> Parser<'a> {
             None
         };
 
-        Ok(Statement::Repeat(RepeatStatement {
+        Ok(Statement::Repeat(self.new_box(RepeatStatement {
             label,
             body,
             until,
             end_label,
-        }))
+        })?))
     }
 
     /// Parse a `FOR` statement.
@@ -3882,7 +5033,7 @@ impl<'a> Parser<'a> {
             self.expect_keyword(Keyword::IN)?;
             ForLoopVariant::Iterator {
                 mutable,
-                collection: Box::new(self.parse_expr()?),
+                collection: self.new_box(self.parse_expr()?)?,
             }
         } else if self.parse_keyword(Keyword::AS) {
             // Query variant: FOR loop_name AS [cursor_name CURSOR FOR] query DO ... END FOR
@@ -3900,7 +5051,7 @@ impl<'a> Parser<'a> {
             // Could be integer range, dynamic query, or just a regular IN expression
             if self.parse_keyword(Keyword::EXECUTE) {
                 // Dynamic query: FOR loop_name IN EXECUTE expr [USING ...]
-                let query_expr = Box::new(self.parse_expr()?);
+                let query_expr = self.new_box(self.parse_expr()?)?;
                 let using = if self.parse_keyword(Keyword::USING) {
                     Some(self.parse_comma_separated(|p| p.parse_expr())?)
                 } else {
@@ -3958,7 +5109,7 @@ impl<'a> Parser<'a> {
                 // [REVERSE] lower..upper [BY step]
                 // [REVERSE] lower TO upper [BY step]   (compat form)
                 let reverse = self.parse_keyword(Keyword::REVERSE);
-                let lower = Box::new(self.parse_expr()?);
+                let lower = self.new_box(self.parse_expr()?)?;
 
                 if self.dialect.is::<OracleDialect>() && self.peek_keyword(Keyword::LOOP) {
                     ForLoopVariant::CursorVariable { cursor: lower }
@@ -3967,9 +5118,9 @@ impl<'a> Parser<'a> {
                         self.expect_token(&BorrowedToken::DoubleDot)?;
                     }
 
-                    let upper = Box::new(self.parse_expr()?);
+                    let upper = self.new_box(self.parse_expr()?)?;
                     let step = if self.parse_keyword(Keyword::BY) {
-                        Some(Box::new(self.parse_expr()?))
+                        Some(self.new_box(self.parse_expr()?)?)
                     } else {
                         None
                     };
@@ -4029,14 +5180,14 @@ impl<'a> Parser<'a> {
             None
         };
 
-        Ok(Statement::For(ForStatement {
+        Ok(Statement::For(self.new_box(ForStatement {
             token,
             label,
             loop_name,
             variant,
             body,
             end_label,
-        }))
+        })?))
     }
 
     /// Parse a `LEAVE` statement.
@@ -4125,7 +5276,7 @@ impl<'a> Parser<'a> {
         self.expect_keyword_is(Keyword::ARRAY)?;
 
         // Parse the array expression
-        let array_expr = Box::new(self.parse_expr()?);
+        let array_expr = self.new_box(self.parse_expr()?)?;
 
         // Expect LOOP
         self.expect_keyword_is(Keyword::LOOP)?;
@@ -4150,7 +5301,7 @@ impl<'a> Parser<'a> {
             None
         };
 
-        Ok(Statement::Foreach(ForeachStatement {
+        Ok(Statement::Foreach(self.new_box(ForeachStatement {
             token,
             label,
             loop_name,
@@ -4158,7 +5309,7 @@ impl<'a> Parser<'a> {
             array_expr,
             body,
             end_label,
-        }))
+        })?))
     }
 
     /// Parse an `EXIT` statement.
@@ -4190,7 +5341,9 @@ impl<'a> Parser<'a> {
             None
         };
 
-        Ok(Statement::Exit(ExitStatement { label, condition }))
+        Ok(Statement::Exit(
+            self.new_box(ExitStatement { label, condition })?,
+        ))
     }
 
     /// Parse a `CONTINUE` statement.
@@ -4222,7 +5375,9 @@ impl<'a> Parser<'a> {
             None
         };
 
-        Ok(Statement::Continue(ContinueStatement { label, condition }))
+        Ok(Statement::Continue(
+            self.new_box(ContinueStatement { label, condition })?,
+        ))
     }
 
     fn parse_get_diagnostics(&self) -> Result<Statement, ParserError> {
@@ -4249,11 +5404,13 @@ impl<'a> Parser<'a> {
             GetDiagnosticsKind::Statement(assignments)
         };
 
-        Ok(Statement::GetDiagnostics(GetDiagnosticsStatement {
-            token,
-            stacked,
-            kind,
-        }))
+        Ok(Statement::GetDiagnostics(self.new_box(
+            GetDiagnosticsStatement {
+                token,
+                stacked,
+                kind,
+            },
+        )?))
     }
 
     fn parse_diagnostics_assignments(&self) -> Result<Vec<DiagnosticsAssignment>, ParserError> {
@@ -4389,13 +5546,13 @@ impl<'a> Parser<'a> {
                     end_label: None,
                 })
             } else {
-                let mut sequence = vec![Statement::LabeledBlock(LabeledBlock {
+                let mut sequence = vec![Statement::LabeledBlock(self.new_box(LabeledBlock {
                     token: AttachedToken::from(begin_token),
                     label: None,
                     statements,
                     exception: exception_handlers,
                     end_label: None,
-                })];
+                })?)];
                 sequence.extend(self.parse_statement_list(terminal_keywords)?);
                 ConditionalStatements::Sequence {
                     statements: sequence,
@@ -4521,12 +5678,12 @@ impl<'a> Parser<'a> {
             })?;
         }
 
-        Ok(Statement::Raise(RaiseStatement {
+        Ok(Statement::Raise(self.new_box(RaiseStatement {
             level,
             message,
             format_args,
             using,
-        }))
+        })?))
     }
 
     /// Parse a `PERFORM` statement (SQL/PSM, PL/pgSQL).
@@ -4583,7 +5740,7 @@ impl<'a> Parser<'a> {
         };
 
         Ok(Statement::Perform(PerformStatement {
-            query: Box::new(box_into_inner(query)),
+            query: self.new_box(box_into_inner(query))?,
         }))
     }
 
@@ -4638,11 +5795,11 @@ impl<'a> Parser<'a> {
             DoBody::RawBody(body_expr)
         };
 
-        Ok(Statement::Do(DoStatement {
+        Ok(Statement::Do(self.new_box(DoStatement {
             token,
             language,
             body,
-        }))
+        })?))
     }
 
     pub fn parse_signal(&self) -> Result<Statement, ParserError> {
@@ -4924,7 +6081,7 @@ impl<'a> Parser<'a> {
             ])
             .is_some()
         {
-            ObjectName(vec![])
+            ObjectName(AstVec::new())
         } else {
             self.parse_object_name(false)?
         };
@@ -4989,34 +6146,33 @@ impl<'a> Parser<'a> {
 
         let next_token = self.next_token();
         match next_token.token {
-            t @ (BorrowedToken::Word(_) | BorrowedToken::SingleQuotedString(_)) => {
-                if self.peek_token().token == BorrowedToken::Period {
-                    let mut id_parts: Vec<Ident> = vec![match t {
-                        BorrowedToken::Word(w) => self.word_to_ident(w, next_token.span),
-                        BorrowedToken::SingleQuotedString(s) => Ident::with_quote('\'', s),
-                        _ => unreachable!(), // We matched above
-                    }];
-
-                    while self.consume_token(&BorrowedToken::Period) {
-                        let next_token = self.next_token();
-                        match next_token.token {
-                            BorrowedToken::Word(w) => {
-                                id_parts.push(self.word_to_ident(w, next_token.span))
-                            }
-                            BorrowedToken::SingleQuotedString(s) => {
-                                // single-quoted identifiers
-                                id_parts.push(Ident::with_quote('\'', s))
-                            }
-                            BorrowedToken::Mul => {
-                                return Ok(Expr::QualifiedWildcard(
-                                    ObjectName::from(id_parts),
-                                    AttachedToken::from(next_token),
-                                ));
-                            }
-                            _ => {
-                                return self
-                                    .expected("an identifier or a '*' after '.'", next_token);
-                            }
+            t @ (BorrowedToken::Word(_) | BorrowedToken::SingleQuotedString(_))
+                if self.peek_token().token == BorrowedToken::Period =>
+            {
+                let mut id_parts: Vec<Ident> = vec![match t {
+                    BorrowedToken::Word(w) => self.word_to_ident(w, next_token.span),
+                    BorrowedToken::SingleQuotedString(s) => Ident::with_quote('\'', s),
+                    _ => unreachable!(), // We matched above
+                }];
+
+                while self.consume_token(&BorrowedToken::Period) {
+                    let next_token = self.next_token();
+                    match next_token.token {
+                        BorrowedToken::Word(w) => {
+                            id_parts.push(self.word_to_ident(w, next_token.span))
+                        }
+                        BorrowedToken::SingleQuotedString(s) => {
+                            // single-quoted identifiers
+                            id_parts.push(Ident::with_quote('\'', s))
+                        }
+                        BorrowedToken::Mul => {
+                            return Ok(Expr::QualifiedWildcard(
+                                self.object_name(id_parts)?,
+                                self.new_box(AttachedToken::from(next_token))?,
+                            ));
+                        }
+                        _ => {
+                            return self.expected("an identifier or a '*' after '.'", next_token);
                         }
                     }
                 }
@@ -5061,7 +6217,18 @@ impl<'a> Parser<'a> {
     pub fn parse_subexpr(&self, precedence: u8) -> Result<Expr, ParserError> {
         let _guard = self.recursion_counter.try_decrease()?;
         debug!("parsing expr");
-        let mut expr = self.parse_prefix()?;
+        let start_index = self.index.get();
+        // The dialect may accept a keyword such as `WHERE` as an identifier,
+        // which tolerant mode rather takes as where the expression is missing.
+        let prefix = if self.tolerant.get() && self.at_tolerant_resume_keyword() {
+            self.expected_ref("an expression", self.peek_token_ref())
+        } else {
+            self.parse_prefix()
+        };
+        let mut expr = match prefix {
+            Ok(expr) => expr,
+            Err(error) => Expr::Error(self.tolerate_error(start_index, error)?),
+        };
 
         // Most expressions do not contain compound field or subscript access.
         // Let the vector allocate on the first actual access instead of paying
@@ -5069,6 +6236,11 @@ impl<'a> Parser<'a> {
         expr = self.parse_compound_expr(expr, Vec::new())?;
 
         debug!("prefix: {expr:?}");
+        if self.collect_candidates.get() {
+            for keyword in self.dialect.infix_operator_keywords() {
+                self.record_candidate(|| ExpectedItem::Keyword(*keyword));
+            }
+        }
         loop {
             let next_precedence = self.get_next_precedence()?;
             debug!("next precedence: {next_precedence:?}");
@@ -5099,8 +6271,8 @@ impl<'a> Parser<'a> {
 
         Ok(Statement::Assert {
             assert_token,
-            condition,
-            message,
+            condition: self.new_box(condition)?,
+            message: message.map(|v| self.new_box(v)).transpose()?,
         })
     }
 
@@ -5109,7 +6281,7 @@ impl<'a> Parser<'a> {
         let name = self.parse_identifier()?;
         Ok(Statement::Savepoint {
             savepoint_token,
-            name,
+            name: self.new_box(name)?,
         })
     }
 
@@ -5120,7 +6292,7 @@ impl<'a> Parser<'a> {
 
         Ok(Statement::ReleaseSavepoint {
             release_token,
-            name,
+            name: self.new_box(name)?,
         })
     }
 
@@ -5129,7 +6301,7 @@ impl<'a> Parser<'a> {
         let channel = self.parse_identifier()?;
         Ok(Statement::LISTEN {
             listen_token,
-            channel,
+            channel: self.new_box(channel)?,
         })
     }
 
@@ -5148,7 +6320,7 @@ impl<'a> Parser<'a> {
         };
         Ok(Statement::UNLISTEN {
             unlisten_token,
-            channel,
+            channel: self.new_box(channel)?,
         })
     }
 
@@ -5162,7 +6334,7 @@ impl<'a> Parser<'a> {
         };
         Ok(Statement::NOTIFY {
             notify_token,
-            channel,
+            channel: self.new_box(channel)?,
             payload,
         })
     }
@@ -5244,8 +6416,8 @@ impl<'a> Parser<'a> {
         Ok(Statement::CreateTablespace {
             create_token,
             if_not_exists,
-            name,
-            location,
+            name: self.new_box(name)?,
+            location: self.new_box(location)?,
             options,
         })
     }
@@ -5299,8 +6471,8 @@ impl<'a> Parser<'a> {
         };
         Ok(Statement::AlterTablespace {
             alter_token,
-            name,
-            operation,
+            name: self.new_box(name)?,
+            operation: self.new_box(operation)?,
         })
     }
 
@@ -5336,16 +6508,18 @@ impl<'a> Parser<'a> {
         match w.keyword {
             Keyword::TRUE | Keyword::FALSE if self.features.supports_boolean_literals => {
                 self.prev_token();
-                Ok(Some(Expr::Value(self.parse_value()?)))
+                Ok(Some(Expr::Value(self.new_box(self.parse_value()?)?)))
             }
             Keyword::NULL => {
                 self.prev_token();
-                Ok(Some(Expr::Value(self.parse_value()?)))
+                Ok(Some(Expr::Value(self.new_box(self.parse_value()?)?)))
             }
             Keyword::CURRENT if self.peek_keyword(Keyword::OF) => {
                 self.expect_keyword(Keyword::OF)?;
                 let cursor_name = self.parse_identifier()?;
-                Ok(Some(Expr::CurrentOf { cursor_name }))
+                Ok(Some(Expr::CurrentOf {
+                    cursor_name: self.new_box(cursor_name)?,
+                }))
             }
             Keyword::CURRENT_CATALOG
             | Keyword::CURRENT_USER
@@ -5364,8 +6538,8 @@ impl<'a> Parser<'a> {
                     self.advance_token();
                     self.advance_token();
                 }
-                Ok(Some(Expr::Function(Function {
-                    name: ObjectName::from(vec![self.word_to_ident(w.clone(), w_span)]),
+                Ok(Some(Expr::Function(self.new_box(Function {
+                    name: self.object_name(vec![self.word_to_ident(w.clone(), w_span)])?,
                     uses_odbc_syntax: false,
                     parameters: FunctionArguments::None,
                     args: FunctionArguments::None,
@@ -5374,17 +6548,15 @@ impl<'a> Parser<'a> {
                     null_treatment: None,
                     over: None,
                     within_group: vec![],
-                })))
+                })?)))
             }
             Keyword::CURRENT_TIMESTAMP
             | Keyword::CURRENT_TIME
             | Keyword::CURRENT_DATE
             | Keyword::LOCALTIME
-            | Keyword::LOCALTIMESTAMP => {
-                Ok(Some(self.parse_time_functions(ObjectName::from(vec![
-                    self.word_to_ident(w.clone(), w_span),
-                ]))?))
-            }
+            | Keyword::LOCALTIMESTAMP => Ok(Some(self.parse_time_functions(
+                self.object_name(vec![self.word_to_ident(w.clone(), w_span)])?,
+            )?)),
             Keyword::CASE => Ok(Some(self.parse_case_expr()?)),
             Keyword::CONVERT => Ok(Some(self.parse_convert_expr(false)?)),
             Keyword::TRY_CONVERT if self.features.supports_try_convert => {
@@ -5399,7 +6571,7 @@ impl<'a> Parser<'a> {
                 let subquery = self.parse_graph_subquery()?;
                 self.expect_token(&BorrowedToken::RBrace)?;
                 Ok(Some(Expr::GraphCount {
-                    subquery: Box::new(subquery),
+                    subquery: self.new_box(subquery)?,
                 }))
             }
             Keyword::VALUE if self.peek_token() == BorrowedToken::LBrace => {
@@ -5407,7 +6579,7 @@ impl<'a> Parser<'a> {
                 let subquery = self.parse_graph_subquery()?;
                 self.expect_token(&BorrowedToken::RBrace)?;
                 Ok(Some(Expr::GraphValue {
-                    subquery: Box::new(subquery),
+                    subquery: self.new_box(subquery)?,
                 }))
             }
             Keyword::COLLECT if self.peek_token() == BorrowedToken::LBrace => {
@@ -5415,7 +6587,7 @@ impl<'a> Parser<'a> {
                 let subquery = self.parse_graph_subquery()?;
                 self.expect_token(&BorrowedToken::RBrace)?;
                 Ok(Some(Expr::GraphCollect {
-                    subquery: Box::new(subquery),
+                    subquery: self.new_box(subquery)?,
                 }))
             }
             Keyword::SUM if self.peek_token() == BorrowedToken::LBrace => {
@@ -5423,7 +6595,7 @@ impl<'a> Parser<'a> {
                 let subquery = self.parse_graph_subquery()?;
                 self.expect_token(&BorrowedToken::RBrace)?;
                 Ok(Some(Expr::GraphSum {
-                    subquery: Box::new(subquery),
+                    subquery: self.new_box(subquery)?,
                 }))
             }
             Keyword::AVG if self.peek_token() == BorrowedToken::LBrace => {
@@ -5431,7 +6603,7 @@ impl<'a> Parser<'a> {
                 let subquery = self.parse_graph_subquery()?;
                 self.expect_token(&BorrowedToken::RBrace)?;
                 Ok(Some(Expr::GraphAvg {
-                    subquery: Box::new(subquery),
+                    subquery: self.new_box(subquery)?,
                 }))
             }
             Keyword::MIN if self.peek_token() == BorrowedToken::LBrace => {
@@ -5439,7 +6611,7 @@ impl<'a> Parser<'a> {
                 let subquery = self.parse_graph_subquery()?;
                 self.expect_token(&BorrowedToken::RBrace)?;
                 Ok(Some(Expr::GraphMin {
-                    subquery: Box::new(subquery),
+                    subquery: self.new_box(subquery)?,
                 }))
             }
             Keyword::MAX if self.peek_token() == BorrowedToken::LBrace => {
@@ -5447,7 +6619,7 @@ impl<'a> Parser<'a> {
                 let subquery = self.parse_graph_subquery()?;
                 self.expect_token(&BorrowedToken::RBrace)?;
                 Ok(Some(Expr::GraphMax {
-                    subquery: Box::new(subquery),
+                    subquery: self.new_box(subquery)?,
                 }))
             }
             // SQL/PGQ quantified predicates: ALL(x IN e | predicate), ANY(x IN e | predicate)
@@ -5477,7 +6649,7 @@ impl<'a> Parser<'a> {
                 let character_set = self.parse_object_name(false)?;
                 self.expect_token(&BorrowedToken::RParen)?;
                 Ok(Some(Expr::OracleTranslateUsing {
-                    expr: Box::new(expr),
+                    expr: self.new_box(expr)?,
                     character_set,
                 }))
             }
@@ -5491,8 +6663,8 @@ impl<'a> Parser<'a> {
                 let data_type = self.parse_data_type()?;
                 self.expect_token(&BorrowedToken::RParen)?;
                 Ok(Some(Expr::OracleTreat {
-                    expr: Box::new(expr),
-                    data_type,
+                    expr: self.new_box(expr)?,
+                    data_type: self.new_box(data_type)?,
                 }))
             }
             Keyword::XMLPARSE => Ok(Some(self.parse_xmlparse()?)),
@@ -5512,8 +6684,8 @@ impl<'a> Parser<'a> {
                 self.expect_token(&BorrowedToken::LParen)?;
                 let query = self.parse_query()?;
                 self.expect_token(&BorrowedToken::RParen)?;
-                Ok(Some(Expr::Function(Function {
-                    name: ObjectName::from(vec![self.word_to_ident(w.clone(), w_span)]),
+                Ok(Some(Expr::Function(self.new_box(Function {
+                    name: self.object_name(vec![self.word_to_ident(w.clone(), w_span)])?,
                     uses_odbc_syntax: false,
                     parameters: FunctionArguments::None,
                     args: FunctionArguments::Subquery(query),
@@ -5522,7 +6694,7 @@ impl<'a> Parser<'a> {
                     null_treatment: None,
                     over: None,
                     within_group: vec![],
-                })))
+                })?)))
             }
             Keyword::CURSOR
                 if self.dialect.is::<OracleDialect>()
@@ -5556,7 +6728,7 @@ impl<'a> Parser<'a> {
             }
             Keyword::PRIOR if matches!(self.state.get(), ParserState::ConnectBy) => {
                 let expr = self.parse_subexpr(self.dialect.prec_value(Precedence::PlusMinus))?;
-                Ok(Some(Expr::Prior(Box::new(expr))))
+                Ok(Some(Expr::Prior(self.new_box(expr)?)))
             }
             Keyword::PERIOD if self.peek_token() == BorrowedToken::LParen => {
                 Ok(Some(self.parse_period_constructor()?))
@@ -5599,7 +6771,7 @@ impl<'a> Parser<'a> {
         match self.peek_token().token {
             BorrowedToken::LParen if !self.peek_outer_join_operator() => {
                 let id_parts = vec![self.word_to_ident(w.clone(), w_span)];
-                self.parse_function(ObjectName::from(id_parts))
+                self.parse_function(self.object_name(id_parts)?)
             }
             // string introducer https://dev.mysql.com/doc/refman/8.0/en/charset-introducer.html
             BorrowedToken::SingleQuotedString(_)
@@ -5608,8 +6780,8 @@ impl<'a> Parser<'a> {
                 if w.value.starts_with('_') =>
             {
                 Ok(Expr::Prefixed {
-                    prefix: self.word_to_ident(w.clone(), w_span),
-                    value: self.parse_introduced_string_expr()?.into(),
+                    prefix: self.new_box(self.word_to_ident(w.clone(), w_span))?,
+                    value: self.new_box(self.parse_introduced_string_expr()?)?,
                 })
             }
             // string introducer https://dev.mysql.com/doc/refman/8.0/en/charset-introducer.html
@@ -5619,18 +6791,20 @@ impl<'a> Parser<'a> {
                 if w.value.starts_with('_') =>
             {
                 Ok(Expr::Prefixed {
-                    prefix: self.word_to_ident(w.clone(), w_span),
-                    value: self.parse_introduced_string_expr()?.into(),
+                    prefix: self.new_box(self.word_to_ident(w.clone(), w_span))?,
+                    value: self.new_box(self.parse_introduced_string_expr()?)?,
                 })
             }
             BorrowedToken::Arrow if self.features.supports_lambda_functions => {
                 self.expect_token(&BorrowedToken::Arrow)?;
-                Ok(Expr::Lambda(LambdaFunction {
+                Ok(Expr::Lambda(self.new_box(LambdaFunction {
                     params: OneOrManyWithParens::One(self.word_to_ident(w.clone(), w_span)),
-                    body: Box::new(self.parse_expr()?),
-                }))
+                    body: self.new_box(self.parse_expr()?)?,
+                })?))
             }
-            _ => Ok(Expr::Identifier(self.word_to_ident(w.clone(), w_span))),
+            _ => Ok(Expr::Identifier(
+                self.new_box(self.word_to_ident(w.clone(), w_span))?,
+            )),
         }
     }
 
@@ -5675,11 +6849,11 @@ impl<'a> Parser<'a> {
                 // an unary negation `NOT ('a' LIKE 'b')`. To solve this, we don't accept the
                 // `type 'string'` syntax for the custom data types at all.
                 DataType::Custom(..) => parser_err!("dummy", loc),
-                data_type => Ok(Expr::TypedString(TypedString {
+                data_type => Ok(Expr::TypedString(self.new_box(TypedString {
                     data_type,
                     value: parser.parse_value()?,
                     uses_odbc_syntax: false,
-                })),
+                })?)),
             }
         })?;
 
@@ -5726,10 +6900,24 @@ impl<'a> Parser<'a> {
                     // we rollback and return the parsing error we got from trying to parse a
                     // special expression (to maintain backwards compatibility of parsing errors).
                     Err(e) => {
+                        let index = self.index.get();
                         if !self.dialect.is_reserved_for_identifier(keyword) {
                             if let Ok(Some(expr)) = self.maybe_parse(|parser| {
                                 parser.parse_expr_prefix_by_unreserved_word(&w, span)
                             }) {
+                                // In tolerant mode, an identifier followed by a keyword
+                                // that ends the expression is rather a malformed special
+                                // expression, as in `CASE WHEN THEN 1 END`.
+                                if !(self.tolerant.get() && self.at_tolerant_resume_keyword()) {
+                                    return Ok(expr);
+                                }
+                                self.index.set(index);
+                            }
+                        }
+                        if self.tolerant.get() {
+                            if let Ok(Some(expr)) =
+                                self.parse_expr_prefix_by_reserved_word(&w, span)
+                            {
                                 return Ok(expr);
                             }
                         }
@@ -5747,17 +6935,17 @@ impl<'a> Parser<'a> {
                 };
                 Ok(Expr::UnaryOp {
                     op,
-                    expr: Box::new(
+                    expr: self.new_box(
                         self.parse_subexpr(self.dialect.prec_value(Precedence::MulDivModOp))?,
-                    ),
+                    )?,
                 })
             }
             BorrowedToken::ExclamationMark if self.features.supports_bang_not_operator => {
                 Ok(Expr::UnaryOp {
                     op: UnaryOperator::BangNot,
-                    expr: Box::new(
+                    expr: self.new_box(
                         self.parse_subexpr(self.dialect.prec_value(Precedence::UnaryNot))?,
-                    ),
+                    )?,
                 })
             }
             tok @ BorrowedToken::DoubleExclamationMark
@@ -5775,14 +6963,15 @@ impl<'a> Parser<'a> {
                 };
                 Ok(Expr::UnaryOp {
                     op,
-                    expr: Box::new(
+                    expr: self.new_box(
                         self.parse_subexpr(self.dialect.prec_value(Precedence::PlusMinus))?,
-                    ),
+                    )?,
                 })
             }
             BorrowedToken::Tilde => Ok(Expr::UnaryOp {
                 op: UnaryOperator::BitwiseNot,
-                expr: Box::new(self.parse_subexpr(self.dialect.prec_value(Precedence::PlusMinus))?),
+                expr: self
+                    .new_box(self.parse_subexpr(self.dialect.prec_value(Precedence::PlusMinus))?)?,
             }),
             tok @ BorrowedToken::Sharp
             | tok @ BorrowedToken::AtDashAt
@@ -5805,18 +6994,18 @@ impl<'a> Parser<'a> {
                 };
                 Ok(Expr::UnaryOp {
                     op,
-                    expr: Box::new(
+                    expr: self.new_box(
                         self.parse_subexpr(self.dialect.prec_value(Precedence::PlusMinus))?,
-                    ),
+                    )?,
                 })
             }
             BorrowedToken::EscapedStringLiteral(_) if dialect_is!(dialect is PostgreSqlDialect) => {
                 self.prev_token();
-                Ok(Expr::Value(self.parse_value()?))
+                Ok(Expr::Value(self.new_box(self.parse_value()?)?))
             }
             BorrowedToken::UnicodeStringLiteral(_) => {
                 self.prev_token();
-                Ok(Expr::Value(self.parse_value()?))
+                Ok(Expr::Value(self.new_box(self.parse_value()?)?))
             }
             BorrowedToken::Number(_, _)
             | BorrowedToken::SingleQuotedString(_)
@@ -5827,7 +7016,7 @@ impl<'a> Parser<'a> {
             | BorrowedToken::AlternativeQuotedString(_)
             | BorrowedToken::HexStringLiteral(_) => {
                 self.prev_token();
-                Ok(Expr::Value(self.parse_value()?))
+                Ok(Expr::Value(self.new_box(self.parse_value()?)?))
             }
             BorrowedToken::LParen => {
                 let expr = if let Some(expr) = self.try_parse_expr_sub_query()? {
@@ -5838,8 +7027,8 @@ impl<'a> Parser<'a> {
                     let exprs = self.parse_comma_separated(Parser::parse_expr)?;
                     match exprs.len() {
                         0 => unreachable!(), // parse_comma_separated ensures 1 or more
-                        1 => Expr::Nested(Box::new(exprs.into_iter().next().unwrap())),
-                        _ => Expr::Tuple(exprs),
+                        1 => Expr::Nested(self.new_box(exprs.into_iter().next().unwrap())?),
+                        _ => Expr::Tuple(self.new_list(exprs)?),
                     }
                 };
                 self.expect_token(&BorrowedToken::RParen)?;
@@ -5847,7 +7036,7 @@ impl<'a> Parser<'a> {
             }
             BorrowedToken::Placeholder(_) | BorrowedToken::Colon | BorrowedToken::AtSign => {
                 self.prev_token();
-                Ok(Expr::Value(self.parse_value()?))
+                Ok(Expr::Value(self.new_box(self.parse_value()?)?))
             }
             BorrowedToken::LBrace => {
                 self.prev_token();
@@ -5858,7 +7047,7 @@ impl<'a> Parser<'a> {
 
         if !self.in_column_definition_state() && self.parse_keyword(Keyword::COLLATE) {
             Ok(Expr::Collate {
-                expr: Box::new(expr),
+                expr: self.new_box(expr)?,
                 collation: self.parse_object_name(false)?,
             })
         } else {
@@ -5867,11 +7056,11 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_geometric_type(&self, kind: GeometricTypeKind) -> Result<Expr, ParserError> {
-        Ok(Expr::TypedString(TypedString {
+        Ok(Expr::TypedString(self.new_box(TypedString {
             data_type: DataType::GeometricType(kind),
             value: self.parse_value()?,
             uses_odbc_syntax: false,
-        }))
+        })?))
     }
 
     /// Try to parse an [Expr::CompoundFieldAccess] like `a.b.c` or `a.b[1].c`.
@@ -5914,11 +7103,11 @@ impl<'a> Parser<'a> {
                         break;
                     }
                     BorrowedToken::SingleQuotedString(s) => {
-                        let expr = Expr::Identifier(Ident::with_quote_and_span(
+                        let expr = Expr::Identifier(self.new_box(Ident::with_quote_and_span(
                             '\'',
                             next_token.span,
                             s.as_ref(),
-                        ));
+                        ))?);
                         chain.push(AccessExpr::Dot(expr));
                         self.advance_token(); // The consumed string
                     }
@@ -5927,7 +7116,7 @@ impl<'a> Parser<'a> {
                             && self.peek_nth_token_ref(1).token != BorrowedToken::LParen =>
                     {
                         chain.push(AccessExpr::Dot(Expr::Identifier(
-                            self.word_to_ident(word.clone(), next_token.span),
+                            self.new_box(self.word_to_ident(word.clone(), next_token.span))?,
                         )));
                         self.advance_token();
                     }
@@ -5945,8 +7134,11 @@ impl<'a> Parser<'a> {
                             chain.push(AccessExpr::Dot(box_into_inner(root)));
                             chain.extend(access_chain);
                         }
-                        Expr::CompoundIdentifier(parts) => chain
-                            .extend(parts.into_iter().map(Expr::Identifier).map(AccessExpr::Dot)),
+                        Expr::CompoundIdentifier(parts) => {
+                            for part in parts {
+                                chain.push(AccessExpr::Dot(Expr::Identifier(self.new_box(part)?)));
+                            }
+                        }
                         expr => {
                             chain.push(AccessExpr::Dot(expr));
                         }
@@ -5967,8 +7159,8 @@ impl<'a> Parser<'a> {
                 return self.expected("an identifier or a '*' after '.'", self.peek_token());
             };
             Ok(Expr::QualifiedWildcard(
-                ObjectName::from(Self::exprs_to_idents(root, chain)?),
-                AttachedToken::from(wildcard_token),
+                self.object_name(Self::exprs_to_idents(root, chain)?)?,
+                self.new_box(AttachedToken::from(wildcard_token))?,
             ))
         } else if self.maybe_parse_outer_join_operator() {
             if !Self::is_all_ident(&root, &chain) {
@@ -5977,11 +7169,11 @@ impl<'a> Parser<'a> {
             let expr = if chain.is_empty() {
                 root
             } else {
-                Expr::CompoundIdentifier(Self::exprs_to_idents(root, chain)?)
+                Expr::CompoundIdentifier(self.new_list(Self::exprs_to_idents(root, chain)?)?)
             };
-            Ok(Expr::OuterJoin(expr.into()))
+            Ok(Expr::OuterJoin(self.new_box(expr)?))
         } else {
-            Self::build_compound_expr(root, chain)
+            self.build_compound_expr(root, chain)
         }
     }
 
@@ -5990,6 +7182,7 @@ impl<'a> Parser<'a> {
     /// or other special cased expressions like [Expr::CompoundIdentifier],
     /// [Expr::OuterJoin].
     fn build_compound_expr(
+        &self,
         root: Expr,
         mut access_chain: Vec<AccessExpr>,
     ) -> Result<Expr, ParserError> {
@@ -5998,10 +7191,9 @@ impl<'a> Parser<'a> {
         }
 
         if Self::is_all_ident(&root, &access_chain) {
-            return Ok(Expr::CompoundIdentifier(Self::exprs_to_idents(
-                root,
-                access_chain,
-            )?));
+            return Ok(Expr::CompoundIdentifier(
+                self.new_list(Self::exprs_to_idents(root, access_chain)?)?,
+            ));
         }
 
         // Flatten qualified function calls.
@@ -6019,9 +7211,10 @@ impl<'a> Parser<'a> {
                 .skip(1) // All except the Function
                 .all(|access| matches!(access, AccessExpr::Dot(Expr::Identifier(_))))
         {
-            let Some(AccessExpr::Dot(Expr::Function(mut func))) = access_chain.pop() else {
+            let Some(AccessExpr::Dot(Expr::Function(func))) = access_chain.pop() else {
                 return parser_err!("expected function expression", root.span().start);
             };
+            let mut func = box_into_inner(func);
 
             let compound_func_name = [root]
                 .into_iter()
@@ -6030,15 +7223,15 @@ impl<'a> Parser<'a> {
                     _ => None,
                 }))
                 .flat_map(|expr| match expr {
-                    Expr::Identifier(ident) => Some(ident),
+                    Expr::Identifier(ident) => Some(box_into_inner(ident)),
                     _ => None,
                 })
                 .map(ObjectNamePart::Identifier)
                 .chain(func.name.0)
                 .collect::<Vec<_>>();
-            func.name = ObjectName(compound_func_name);
+            func.name = ObjectName(self.new_list(compound_func_name)?);
 
-            return Ok(Expr::Function(func));
+            return Ok(Expr::Function(self.new_box(func)?));
         }
 
         // Flatten qualified outer join expressions.
@@ -6063,17 +7256,19 @@ impl<'a> Parser<'a> {
             let mut idents = Self::exprs_to_idents(root, Vec::with_capacity(4))?;
             match box_into_inner(inner_expr) {
                 Expr::CompoundIdentifier(suffix) => idents.extend(suffix),
-                Expr::Identifier(suffix) => idents.push(suffix),
+                Expr::Identifier(suffix) => idents.push(box_into_inner(suffix)),
                 _ => {
                     return parser_err!("column identifier before (+)", token_start);
                 }
             }
 
-            return Ok(Expr::OuterJoin(Expr::CompoundIdentifier(idents).into()));
+            return Ok(Expr::OuterJoin(
+                self.new_box(Expr::CompoundIdentifier(self.new_list(idents)?))?,
+            ));
         }
 
         Ok(Expr::CompoundFieldAccess {
-            root: Box::new(root),
+            root: self.new_box(root)?,
             access_chain,
         })
     }
@@ -6101,10 +7296,10 @@ impl<'a> Parser<'a> {
     fn exprs_to_idents(root: Expr, fields: Vec<AccessExpr>) -> Result<Vec<Ident>, ParserError> {
         let mut idents = Vec::with_capacity(1 + fields.len());
         if let Expr::Identifier(root) = root {
-            idents.push(root);
+            idents.push(box_into_inner(root));
             for x in fields {
                 if let AccessExpr::Dot(Expr::Identifier(ident)) = x {
-                    idents.push(ident);
+                    idents.push(box_into_inner(ident));
                 } else {
                     return parser_err!(
                         format!("Expected identifier, found: {}", x),
@@ -6184,10 +7379,10 @@ impl<'a> Parser<'a> {
             p.expect_token(&BorrowedToken::RParen)?;
             p.expect_token(&BorrowedToken::Arrow)?;
             let expr = p.parse_expr()?;
-            Ok(Expr::Lambda(LambdaFunction {
+            Ok(Expr::Lambda(self.new_box(LambdaFunction {
                 params: OneOrManyWithParens::Many(params),
-                body: Box::new(expr),
-            }))
+                body: self.new_box(expr)?,
+            })?))
         })
     }
 
@@ -6227,11 +7422,11 @@ impl<'a> Parser<'a> {
                 _ => return p.expected("ODBC datetime keyword (t, d, or ts)", token),
             };
             let value = p.parse_value()?;
-            Ok(Expr::TypedString(TypedString {
+            Ok(Expr::TypedString(self.new_box(TypedString {
                 data_type,
                 value,
                 uses_odbc_syntax: true,
-            }))
+            })?))
         })
     }
 
@@ -6249,12 +7444,12 @@ impl<'a> Parser<'a> {
             let fn_name = p.parse_object_name(false)?;
             let mut fn_call = p.parse_function_call(fn_name)?;
             fn_call.uses_odbc_syntax = true;
-            Ok(Expr::Function(fn_call))
+            Ok(Expr::Function(self.new_box(fn_call)?))
         })
     }
 
     pub fn parse_function(&self, name: ObjectName) -> Result<Expr, ParserError> {
-        let aggregate = Expr::Function(self.parse_function_call(name)?);
+        let aggregate = Expr::Function(self.new_box(self.parse_function_call(name)?)?);
         if self.dialect.is::<OracleDialect>() && self.parse_keyword(Keyword::KEEP) {
             self.expect_token(&BorrowedToken::LParen)?;
             self.expect_keyword(Keyword::DENSE_RANK)?;
@@ -6269,7 +7464,7 @@ impl<'a> Parser<'a> {
             let order_by = self.parse_comma_separated(Parser::parse_order_by_expr)?;
             self.expect_token(&BorrowedToken::RParen)?;
             Ok(Expr::OracleKeep {
-                aggregate: Box::new(aggregate),
+                aggregate: self.new_box(aggregate)?,
                 rank,
                 order_by,
             })
@@ -6299,7 +7494,7 @@ impl<'a> Parser<'a> {
             && self.consume_token(&BorrowedToken::LParen)
             && self.parse_keyword(Keyword::WHERE)
         {
-            let filter = Some(Box::new(self.parse_expr()?));
+            let filter = Some(self.new_box(self.parse_expr()?)?);
             self.expect_token(&BorrowedToken::RParen)?;
             filter
         } else {
@@ -6376,7 +7571,7 @@ impl<'a> Parser<'a> {
         } else {
             FunctionArguments::None
         };
-        Ok(Expr::Function(Function {
+        Ok(Expr::Function(self.new_box(Function {
             name,
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
@@ -6386,7 +7581,7 @@ impl<'a> Parser<'a> {
             null_treatment: None,
             over: None,
             within_group: vec![],
-        }))
+        })?))
     }
 
     pub fn parse_window_frame_units(&self) -> Result<WindowFrameUnits, ParserError> {
@@ -6454,10 +7649,10 @@ impl<'a> Parser<'a> {
             let rows = if self.parse_keyword(Keyword::UNBOUNDED) {
                 None
             } else {
-                Some(Box::new(match self.peek_token().token {
+                Some(self.new_box(match self.peek_token().token {
                     BorrowedToken::SingleQuotedString(_) => self.parse_interval()?,
                     _ => self.parse_expr()?,
-                }))
+                })?)
             };
             if self.parse_keyword(Keyword::PRECEDING) {
                 Ok(WindowFrameBound::Preceding(rows))
@@ -6491,7 +7686,7 @@ impl<'a> Parser<'a> {
                 // PostgreSQL allow to use empty tuple as a group by expression,
                 // e.g. `GROUP BY (), name`. Please refer to GROUP BY Clause section in
                 // [PostgreSQL](https://www.postgresql.org/docs/16/sql-select.html)
-                Ok(Expr::Tuple(vec![]))
+                Ok(Expr::Tuple(AstVec::new()))
             } else {
                 self.parse_expr()
             }
@@ -6540,7 +7735,7 @@ impl<'a> Parser<'a> {
         let case_token = AttachedToken::from(current_token);
         let mut operand = None;
         if !self.parse_keyword(Keyword::WHEN) {
-            operand = Some(Box::new(self.parse_expr()?));
+            operand = Some(self.new_box(self.parse_expr()?)?);
             self.expect_keyword_is(Keyword::WHEN)?;
         }
         let mut conditions = Vec::with_capacity(4);
@@ -6554,18 +7749,18 @@ impl<'a> Parser<'a> {
             }
         }
         let else_result = if self.parse_keyword(Keyword::ELSE) {
-            Some(Box::new(self.parse_expr()?))
+            Some(self.new_box(self.parse_expr()?)?)
         } else {
             None
         };
         let end_token = AttachedToken::from(self.expect_keyword(Keyword::END)?);
-        Ok(Expr::Case {
+        Ok(Expr::Case(self.new_box(CaseExpr {
             case_token,
             end_token,
             operand,
-            conditions,
+            conditions: self.new_list(conditions)?,
             else_result,
-        })
+        })?))
     }
 
     pub fn parse_optional_cast_format(&self) -> Result<Option<CastFormat>, ParserError> {
@@ -6602,8 +7797,8 @@ impl<'a> Parser<'a> {
         self.expect_token(&BorrowedToken::RParen)?;
         Ok(Expr::Convert {
             is_try,
-            expr: Box::new(expr),
-            data_type: Some(data_type),
+            expr: self.new_box(expr)?,
+            data_type: Some(self.new_box(data_type)?),
             charset: None,
             target_before_value: true,
             styles,
@@ -6626,9 +7821,9 @@ impl<'a> Parser<'a> {
             self.expect_token(&BorrowedToken::RParen)?;
             return Ok(Expr::Convert {
                 is_try,
-                expr: Box::new(expr),
+                expr: self.new_box(expr)?,
                 data_type: None,
-                charset: Some(charset),
+                charset: Some(self.new_box(charset)?),
                 target_before_value: false,
                 styles: vec![],
             });
@@ -6651,9 +7846,9 @@ impl<'a> Parser<'a> {
         self.expect_token(&BorrowedToken::RParen)?;
         Ok(Expr::Convert {
             is_try,
-            expr: Box::new(expr),
-            data_type: Some(data_type),
-            charset,
+            expr: self.new_box(expr)?,
+            data_type: Some(self.new_box(data_type)?),
+            charset: charset.map(|c| self.new_box(c)).transpose()?,
             target_before_value: false,
             styles: vec![],
         })
@@ -6669,9 +7864,9 @@ impl<'a> Parser<'a> {
         self.expect_token(&BorrowedToken::RParen)?;
         Ok(Expr::Cast {
             kind,
-            expr: Box::new(expr),
-            data_type,
-            format,
+            expr: self.new_box(expr)?,
+            data_type: self.new_box(data_type)?,
+            format: format.map(|f| self.new_box(f)).transpose()?,
         })
     }
 
@@ -6687,7 +7882,7 @@ impl<'a> Parser<'a> {
             self.expect_token(&BorrowedToken::RBrace)?;
             Ok(Expr::GraphExists {
                 negated,
-                pattern: Box::new(pattern),
+                pattern: self.new_box(pattern)?,
             })
         } else {
             // Regular EXISTS with parentheses
@@ -6815,9 +8010,9 @@ impl<'a> Parser<'a> {
 
         Ok(Expr::QuantifiedPredicate {
             quantifier,
-            variables,
-            collection: Box::new(collection),
-            predicate: Box::new(predicate),
+            variables: self.new_box(variables)?,
+            collection: self.new_box(collection)?,
+            predicate: self.new_box(predicate)?,
         })
     }
 
@@ -6835,7 +8030,7 @@ impl<'a> Parser<'a> {
         self.expect_token(&BorrowedToken::RParen)?;
         Ok(Expr::Extract {
             field,
-            expr: Box::new(expr),
+            expr: self.new_box(expr)?,
             syntax,
         })
     }
@@ -6863,12 +8058,12 @@ impl<'a> Parser<'a> {
         self.expect_token(&BorrowedToken::RParen)?;
         if is_ceil {
             Ok(Expr::Ceil {
-                expr: Box::new(expr),
+                expr: self.new_box(expr)?,
                 field,
             })
         } else {
             Ok(Expr::Floor {
-                expr: Box::new(expr),
+                expr: self.new_box(expr)?,
                 field,
             })
         }
@@ -6886,15 +8081,15 @@ impl<'a> Parser<'a> {
             let from = p.parse_expr()?;
             p.expect_token(&BorrowedToken::RParen)?;
             Ok(Expr::Position {
-                expr: Box::new(expr),
-                r#in: Box::new(from),
+                expr: self.new_box(expr)?,
+                r#in: self.new_box(from)?,
             })
         })?;
         match position_expr {
             Some(expr) => Ok(expr),
             // Some dialects support `position` as an ordinary function call
             // without the special `IN` syntax.
-            None => self.parse_function(ObjectName::from(vec![ident])),
+            None => self.parse_function(self.object_name(vec![ident])?),
         }
     }
 
@@ -6923,9 +8118,9 @@ impl<'a> Parser<'a> {
         self.expect_token(&BorrowedToken::RParen)?;
 
         Ok(Expr::Substring {
-            expr: Box::new(expr),
-            substring_from: from_expr.map(Box::new),
-            substring_for: to_expr.map(Box::new),
+            expr: self.new_box(expr)?,
+            substring_from: from_expr.map(|value| self.new_box(value)).transpose()?,
+            substring_for: to_expr.map(|value| self.new_box(value)).transpose()?,
             special,
             shorthand,
         })
@@ -6946,10 +8141,10 @@ impl<'a> Parser<'a> {
         self.expect_token(&BorrowedToken::RParen)?;
 
         Ok(Expr::Overlay {
-            expr: Box::new(expr),
-            overlay_what: Box::new(what_expr),
-            overlay_from: Box::new(from_expr),
-            overlay_for: for_expr.map(Box::new),
+            expr: self.new_box(expr)?,
+            overlay_what: self.new_box(what_expr)?,
+            overlay_from: self.new_box(from_expr)?,
+            overlay_for: for_expr.map(|value| self.new_box(value)).transpose()?,
         })
     }
 
@@ -6968,11 +8163,11 @@ impl<'a> Parser<'a> {
         }
         let expr = self.parse_expr()?;
         if self.parse_keyword(Keyword::FROM) {
-            let trim_what = Box::new(expr);
+            let trim_what = self.new_box(expr)?;
             let expr = self.parse_expr()?;
             self.expect_token(&BorrowedToken::RParen)?;
             Ok(Expr::Trim {
-                expr: Box::new(expr),
+                expr: self.new_box(expr)?,
                 trim_where,
                 trim_what: Some(trim_what),
                 trim_characters: None,
@@ -6980,7 +8175,7 @@ impl<'a> Parser<'a> {
         } else {
             self.expect_token(&BorrowedToken::RParen)?;
             Ok(Expr::Trim {
-                expr: Box::new(expr),
+                expr: self.new_box(expr)?,
                 trim_where,
                 trim_what: None,
                 trim_characters: None,
@@ -7016,7 +8211,7 @@ impl<'a> Parser<'a> {
         };
 
         // Parse the expression
-        let expr = Box::new(self.parse_expr()?);
+        let expr = self.new_box(self.parse_expr()?)?;
 
         // Parse optional PRESERVE or STRIP WHITESPACE
         let whitespace = if self.parse_keyword(Keyword::PRESERVE) {
@@ -7053,7 +8248,7 @@ impl<'a> Parser<'a> {
         };
 
         // Parse the expression
-        let expr = Box::new(self.parse_expr()?);
+        let expr = self.new_box(self.parse_expr()?)?;
 
         // Parse AS type
         self.expect_keyword(Keyword::AS)?;
@@ -7087,8 +8282,8 @@ impl<'a> Parser<'a> {
         Ok(Expr::XmlSerialize {
             document_or_content,
             expr,
-            as_type,
-            encoding,
+            as_type: self.new_box(as_type)?,
+            encoding: encoding.map(|e| self.new_box(e)).transpose()?,
             version,
             indent,
         })
@@ -7107,14 +8302,17 @@ impl<'a> Parser<'a> {
 
         // Parse optional content
         let content = if self.consume_token(&BorrowedToken::Comma) {
-            Some(Box::new(self.parse_expr()?))
+            Some(self.new_box(self.parse_expr()?)?)
         } else {
             None
         };
 
         self.expect_token(&BorrowedToken::RParen)?;
 
-        Ok(Expr::XmlPi { name, content })
+        Ok(Expr::XmlPi {
+            name: self.new_box(name)?,
+            content,
+        })
     }
 
     /// Parse XMLELEMENT function
@@ -7126,7 +8324,7 @@ impl<'a> Parser<'a> {
         self.expect_keyword(Keyword::NAME)?;
 
         // Parse the element name (can be an identifier or a string literal)
-        let name = Box::new(self.parse_expr()?);
+        let name = self.new_box(self.parse_expr()?)?;
 
         // Parse optional XMLATTRIBUTES
         let attributes = if self.consume_token(&BorrowedToken::Comma) {
@@ -7306,7 +8504,7 @@ impl<'a> Parser<'a> {
                     | BorrowedToken::EscapedStringLiteral(_)
                     | BorrowedToken::UnicodeStringLiteral(_)
                     | BorrowedToken::NationalStringLiteral(_)
-                    | BorrowedToken::HexStringLiteral(_) => Some(Box::new(self.parse_expr()?)),
+                    | BorrowedToken::HexStringLiteral(_) => Some(self.new_box(self.parse_expr()?)?),
                     _ => self.expected(
                         "either filler, WITH, or WITHOUT in LISTAGG",
                         self.peek_token(),
@@ -7377,14 +8575,14 @@ impl<'a> Parser<'a> {
                 _ if self.features.allow_extract_custom => {
                     self.prev_token();
                     let custom = self.parse_identifier()?;
-                    Ok(DateTimeField::Custom(custom))
+                    Ok(DateTimeField::Custom(self.new_box(custom)?))
                 }
                 _ => self.expected("date/time field", next_token),
             },
             BorrowedToken::SingleQuotedString(_) if self.features.allow_extract_single_quotes => {
                 self.prev_token();
                 let custom = self.parse_identifier()?;
-                Ok(DateTimeField::Custom(custom))
+                Ok(DateTimeField::Custom(self.new_box(custom)?))
             }
             _ => self.expected("date/time field", next_token),
         }
@@ -7400,14 +8598,15 @@ impl<'a> Parser<'a> {
                 }
                 _ => Ok(Expr::UnaryOp {
                     op: UnaryOperator::Not,
-                    expr: Box::new(
+                    expr: self.new_box(
                         self.parse_subexpr(self.dialect.prec_value(Precedence::UnaryNot))?,
-                    ),
+                    )?,
                 }),
             },
             _ => Ok(Expr::UnaryOp {
                 op: UnaryOperator::Not,
-                expr: Box::new(self.parse_subexpr(self.dialect.prec_value(Precedence::UnaryNot))?),
+                expr: self
+                    .new_box(self.parse_subexpr(self.dialect.prec_value(Precedence::UnaryNot))?)?,
             }),
         }
     }
@@ -7473,7 +8672,7 @@ impl<'a> Parser<'a> {
 
         Ok(Expr::MatchAgainst {
             columns,
-            match_value,
+            match_value: self.new_box(match_value)?,
             opt_search_modifier,
         })
     }
@@ -7577,21 +8776,21 @@ impl<'a> Parser<'a> {
                 }
             };
 
-        Ok(Expr::Interval(Interval {
-            value: Box::new(value),
+        Ok(Expr::Interval(self.new_box(Interval {
+            value: self.new_box(value)?,
             leading_field,
             leading_precision,
             last_field,
             fractional_seconds_precision: fsec_precision,
-        }))
+        })?))
     }
 
     /// Parse a PERIOD constructor: PERIOD (start, end)
     pub fn parse_period_constructor(&self) -> Result<Expr, ParserError> {
         self.expect_token(&BorrowedToken::LParen)?;
-        let start = Box::new(self.parse_expr()?);
+        let start = self.new_box(self.parse_expr()?)?;
         self.expect_token(&BorrowedToken::Comma)?;
-        let end = Box::new(self.parse_expr()?);
+        let end = self.new_box(self.parse_expr()?)?;
         self.expect_token(&BorrowedToken::RParen)?;
         Ok(Expr::Period { start, end })
     }
@@ -7790,6 +8989,16 @@ impl<'a> Parser<'a> {
             return infix;
         }
 
+        // MySQL's integer division takes the rest of the expression as its
+        // right operand.
+        if dialect_of!(self is MySqlDialect) && self.parse_keyword(Keyword::DIV) {
+            return Ok(Expr::BinaryOp {
+                left: self.new_box(expr)?,
+                op: BinaryOperator::MyIntegerDivide,
+                right: self.new_box(self.parse_expr()?)?,
+            });
+        }
+
         let dialect = self.dialect;
 
         self.advance_token();
@@ -8008,23 +9217,23 @@ impl<'a> Parser<'a> {
 
                 Ok(match keyword {
                     Keyword::ALL => Expr::AllOp {
-                        left: Box::new(expr),
+                        left: self.new_box(expr)?,
                         compare_op: op,
-                        right: Box::new(right),
+                        right: self.new_box(right)?,
                     },
                     Keyword::ANY | Keyword::SOME => Expr::AnyOp {
-                        left: Box::new(expr),
+                        left: self.new_box(expr)?,
                         compare_op: op,
-                        right: Box::new(right),
+                        right: self.new_box(right)?,
                         is_some: keyword == Keyword::SOME,
                     },
                     _ => unreachable!(),
                 })
             } else {
                 Ok(Expr::BinaryOp {
-                    left: Box::new(expr),
+                    left: self.new_box(expr)?,
                     op,
-                    right: Box::new(self.parse_subexpr(precedence)?),
+                    right: self.new_box(self.parse_subexpr(precedence)?)?,
                 })
             }
         } else if let BorrowedToken::Word(w) = &tok.token {
@@ -8039,10 +9248,10 @@ impl<'a> Parser<'a> {
                     return Ok(Expr::OracleLike {
                         kind,
                         negated: false,
-                        expr: Box::new(expr),
-                        pattern: Box::new(
+                        expr: self.new_box(expr)?,
+                        pattern: self.new_box(
                             self.parse_subexpr(self.dialect.prec_value(Precedence::Like))?,
-                        ),
+                        )?,
                         escape_char: self.parse_escape_char()?,
                     });
                 }
@@ -8072,120 +9281,126 @@ impl<'a> Parser<'a> {
                             OracleIsPredicate::Of { only, types }
                         };
                         Ok(Expr::OracleIs {
-                            expr: Box::new(expr),
+                            expr: self.new_box(expr)?,
                             negated,
                             predicate,
                         })
                     } else if self.parse_keyword(Keyword::NULL) {
                         let suffix_token = self.attached_token_from_current();
                         Ok(Expr::IsNull {
-                            expr: Box::new(expr),
-                            suffix_token,
+                            expr: self.new_box(expr)?,
+                            suffix_token: self.new_box(suffix_token)?,
                         })
                     } else if self.parse_keywords(&[Keyword::NOT, Keyword::NULL]) {
                         let suffix_token = self.attached_token_from_current();
                         Ok(Expr::IsNotNull {
-                            expr: Box::new(expr),
-                            suffix_token,
+                            expr: self.new_box(expr)?,
+                            suffix_token: self.new_box(suffix_token)?,
                         })
                     } else if self.parse_keywords(&[Keyword::TRUE]) {
                         let suffix_token = self.attached_token_from_current();
                         Ok(Expr::IsTrue {
-                            expr: Box::new(expr),
-                            suffix_token,
+                            expr: self.new_box(expr)?,
+                            suffix_token: self.new_box(suffix_token)?,
                         })
                     } else if self.parse_keywords(&[Keyword::NOT, Keyword::TRUE]) {
                         let suffix_token = self.attached_token_from_current();
                         Ok(Expr::IsNotTrue {
-                            expr: Box::new(expr),
-                            suffix_token,
+                            expr: self.new_box(expr)?,
+                            suffix_token: self.new_box(suffix_token)?,
                         })
                     } else if self.parse_keywords(&[Keyword::FALSE]) {
                         let suffix_token = self.attached_token_from_current();
                         Ok(Expr::IsFalse {
-                            expr: Box::new(expr),
-                            suffix_token,
+                            expr: self.new_box(expr)?,
+                            suffix_token: self.new_box(suffix_token)?,
                         })
                     } else if self.parse_keywords(&[Keyword::NOT, Keyword::FALSE]) {
                         let suffix_token = self.attached_token_from_current();
                         Ok(Expr::IsNotFalse {
-                            expr: Box::new(expr),
-                            suffix_token,
+                            expr: self.new_box(expr)?,
+                            suffix_token: self.new_box(suffix_token)?,
                         })
                     } else if self.parse_keywords(&[Keyword::UNKNOWN]) {
                         let suffix_token = self.attached_token_from_current();
                         Ok(Expr::IsUnknown {
-                            expr: Box::new(expr),
-                            suffix_token,
+                            expr: self.new_box(expr)?,
+                            suffix_token: self.new_box(suffix_token)?,
                         })
                     } else if self.parse_keywords(&[Keyword::NOT, Keyword::UNKNOWN]) {
                         let suffix_token = self.attached_token_from_current();
                         Ok(Expr::IsNotUnknown {
-                            expr: Box::new(expr),
-                            suffix_token,
+                            expr: self.new_box(expr)?,
+                            suffix_token: self.new_box(suffix_token)?,
                         })
                     } else if self.parse_keywords(&[Keyword::DISTINCT, Keyword::FROM]) {
                         let expr2 = self.parse_expr()?;
-                        Ok(Expr::IsDistinctFrom(Box::new(expr), Box::new(expr2)))
+                        Ok(Expr::IsDistinctFrom(
+                            self.new_box(expr)?,
+                            self.new_box(expr2)?,
+                        ))
                     } else if self.parse_keywords(&[Keyword::NOT, Keyword::DISTINCT, Keyword::FROM])
                     {
                         let expr2 = self.parse_expr()?;
-                        Ok(Expr::IsNotDistinctFrom(Box::new(expr), Box::new(expr2)))
+                        Ok(Expr::IsNotDistinctFrom(
+                            self.new_box(expr)?,
+                            self.new_box(expr2)?,
+                        ))
                     } else if self.parse_keyword(Keyword::JSON) {
                         self.parse_is_json(expr, false)
                     } else if self.parse_keywords(&[Keyword::NOT, Keyword::JSON]) {
                         self.parse_is_json(expr, true)
                     } else if self.parse_keyword(Keyword::DOCUMENT) {
                         Ok(Expr::IsDocument {
-                            expr: Box::new(expr),
+                            expr: self.new_box(expr)?,
                             negated: false,
                         })
                     } else if self.parse_keywords(&[Keyword::NOT, Keyword::DOCUMENT]) {
                         Ok(Expr::IsDocument {
-                            expr: Box::new(expr),
+                            expr: self.new_box(expr)?,
                             negated: true,
                         })
                     } else if self.parse_keyword(Keyword::CONTENT) {
                         Ok(Expr::IsContent {
-                            expr: Box::new(expr),
+                            expr: self.new_box(expr)?,
                             negated: false,
                         })
                     } else if self.parse_keywords(&[Keyword::NOT, Keyword::CONTENT]) {
                         Ok(Expr::IsContent {
-                            expr: Box::new(expr),
+                            expr: self.new_box(expr)?,
                             negated: true,
                         })
                     } else if self.parse_keywords(&[Keyword::NOT, Keyword::LABELED]) {
                         let label = self.parse_identifier()?;
                         Ok(Expr::IsLabeled {
-                            expr: Box::new(expr),
+                            expr: self.new_box(expr)?,
                             negated: true,
-                            label,
+                            label: self.new_box(label)?,
                         })
                     } else if self.parse_keyword(Keyword::LABELED) {
                         let label = self.parse_identifier()?;
                         Ok(Expr::IsLabeled {
-                            expr: Box::new(expr),
+                            expr: self.new_box(expr)?,
                             negated: false,
-                            label,
+                            label: self.new_box(label)?,
                         })
                     } else if self.parse_keywords(&[Keyword::SOURCE, Keyword::OF]) {
                         let edge = self.parse_expr()?;
                         Ok(Expr::IsSourceOf {
-                            node: Box::new(expr),
-                            edge: Box::new(edge),
+                            node: self.new_box(expr)?,
+                            edge: self.new_box(edge)?,
                         })
                     } else if self.parse_keywords(&[Keyword::DESTINATION, Keyword::OF]) {
                         let edge = self.parse_expr()?;
                         Ok(Expr::IsDestinationOf {
-                            node: Box::new(expr),
-                            edge: Box::new(edge),
+                            node: self.new_box(expr)?,
+                            edge: self.new_box(edge)?,
                         })
                     } else if self.parse_keywords(&[Keyword::SAME, Keyword::AS]) {
                         let right = self.parse_expr()?;
                         Ok(Expr::IsSameAs {
-                            left: Box::new(expr),
-                            right: Box::new(right),
+                            left: self.new_box(expr)?,
+                            right: self.new_box(right)?,
                         })
                     } else if let Ok(is_normalized) = self.parse_unicode_is_normalized(expr) {
                         Ok(is_normalized)
@@ -8199,13 +9414,13 @@ impl<'a> Parser<'a> {
                 Keyword::AT => {
                     if self.dialect.is::<OracleDialect>() && self.parse_keyword(Keyword::LOCAL) {
                         Ok(Expr::AtLocal {
-                            timestamp: Box::new(expr),
+                            timestamp: self.new_box(expr)?,
                         })
                     } else {
                         self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
                         Ok(Expr::AtTimeZone {
-                            timestamp: Box::new(expr),
-                            time_zone: Box::new(self.parse_subexpr(precedence)?),
+                            timestamp: self.new_box(expr)?,
+                            time_zone: self.new_box(self.parse_subexpr(precedence)?)?,
                         })
                     }
                 }
@@ -8252,10 +9467,10 @@ impl<'a> Parser<'a> {
                         Ok(Expr::OracleLike {
                             kind,
                             negated,
-                            expr: Box::new(expr),
-                            pattern: Box::new(
+                            expr: self.new_box(expr)?,
+                            pattern: self.new_box(
                                 self.parse_subexpr(self.dialect.prec_value(Precedence::Like))?,
-                            ),
+                            )?,
                             escape_char: self.parse_escape_char()?,
                         })
                     } else if self.dialect.is::<OracleDialect>()
@@ -8263,23 +9478,23 @@ impl<'a> Parser<'a> {
                     {
                         self.expect_keyword(Keyword::OF)?;
                         Ok(Expr::OracleMemberOf {
-                            expr: Box::new(expr),
-                            collection: Box::new(self.parse_subexpr(precedence)?),
+                            expr: self.new_box(expr)?,
+                            collection: self.new_box(self.parse_subexpr(precedence)?)?,
                             negated,
                         })
                     } else if regexp || rlike {
                         Ok(Expr::RLike {
                             negated,
-                            expr: Box::new(expr),
-                            pattern: Box::new(
+                            expr: self.new_box(expr)?,
+                            pattern: self.new_box(
                                 self.parse_subexpr(self.dialect.prec_value(Precedence::Like))?,
-                            ),
+                            )?,
                             regexp,
                         })
                     } else if negated && null {
                         Ok(Expr::IsNotNull {
-                            expr: Box::new(expr),
-                            suffix_token: null_token.unwrap(),
+                            expr: self.new_box(expr)?,
+                            suffix_token: self.new_box(null_token.unwrap())?,
                         })
                     } else if self.parse_keyword(Keyword::IN) {
                         self.parse_in(expr, negated)
@@ -8289,29 +9504,29 @@ impl<'a> Parser<'a> {
                         Ok(Expr::Like {
                             negated,
                             any: self.parse_keyword(Keyword::ANY),
-                            expr: Box::new(expr),
-                            pattern: Box::new(
+                            expr: self.new_box(expr)?,
+                            pattern: self.new_box(
                                 self.parse_subexpr(self.dialect.prec_value(Precedence::Like))?,
-                            ),
+                            )?,
                             escape_char: self.parse_escape_char()?,
                         })
                     } else if self.parse_keyword(Keyword::ILIKE) {
                         Ok(Expr::ILike {
                             negated,
                             any: self.parse_keyword(Keyword::ANY),
-                            expr: Box::new(expr),
-                            pattern: Box::new(
+                            expr: self.new_box(expr)?,
+                            pattern: self.new_box(
                                 self.parse_subexpr(self.dialect.prec_value(Precedence::Like))?,
-                            ),
+                            )?,
                             escape_char: self.parse_escape_char()?,
                         })
                     } else if self.parse_keywords(&[Keyword::SIMILAR, Keyword::TO]) {
                         Ok(Expr::SimilarTo {
                             negated,
-                            expr: Box::new(expr),
-                            pattern: Box::new(
+                            expr: self.new_box(expr)?,
+                            pattern: self.new_box(
                                 self.parse_subexpr(self.dialect.prec_value(Precedence::Like))?,
-                            ),
+                            )?,
                             escape_char: self.parse_escape_char()?,
                         })
                     } else {
@@ -8321,16 +9536,16 @@ impl<'a> Parser<'a> {
                 Keyword::NOTNULL if self.features.supports_notnull_operator => {
                     let suffix_token = self.attached_token_from_current();
                     Ok(Expr::IsNotNull {
-                        expr: Box::new(expr),
-                        suffix_token,
+                        expr: self.new_box(expr)?,
+                        suffix_token: self.new_box(suffix_token)?,
                     })
                 }
                 Keyword::MEMBER => {
                     if self.parse_keyword(Keyword::OF) {
                         if self.dialect.is::<OracleDialect>() {
                             Ok(Expr::OracleMemberOf {
-                                expr: Box::new(expr),
-                                collection: Box::new(self.parse_subexpr(precedence)?),
+                                expr: self.new_box(expr)?,
+                                collection: self.new_box(self.parse_subexpr(precedence)?)?,
                                 negated: false,
                             })
                         } else {
@@ -8338,8 +9553,8 @@ impl<'a> Parser<'a> {
                             let array = self.parse_expr()?;
                             self.expect_token(&BorrowedToken::RParen)?;
                             Ok(Expr::MemberOf(MemberOf {
-                                value: Box::new(expr),
-                                array: Box::new(array),
+                                value: self.new_box(expr)?,
+                                array: self.new_box(array)?,
                             }))
                         }
                     } else {
@@ -8350,49 +9565,49 @@ impl<'a> Parser<'a> {
                 Keyword::CONTAINS => {
                     let right = self.parse_subexpr(precedence)?;
                     Ok(Expr::BinaryOp {
-                        left: Box::new(expr),
+                        left: self.new_box(expr)?,
                         op: BinaryOperator::PeriodContains,
-                        right: Box::new(right),
+                        right: self.new_box(right)?,
                     })
                 }
                 Keyword::EQUALS => {
                     let right = self.parse_subexpr(precedence)?;
                     Ok(Expr::BinaryOp {
-                        left: Box::new(expr),
+                        left: self.new_box(expr)?,
                         op: BinaryOperator::PeriodEquals,
-                        right: Box::new(right),
+                        right: self.new_box(right)?,
                     })
                 }
                 Keyword::PRECEDES => {
                     let right = self.parse_subexpr(precedence)?;
                     Ok(Expr::BinaryOp {
-                        left: Box::new(expr),
+                        left: self.new_box(expr)?,
                         op: BinaryOperator::PeriodPrecedes,
-                        right: Box::new(right),
+                        right: self.new_box(right)?,
                     })
                 }
                 Keyword::SUCCEEDS => {
                     let right = self.parse_subexpr(precedence)?;
                     Ok(Expr::BinaryOp {
-                        left: Box::new(expr),
+                        left: self.new_box(expr)?,
                         op: BinaryOperator::PeriodSucceeds,
-                        right: Box::new(right),
+                        right: self.new_box(right)?,
                     })
                 }
                 Keyword::IMMEDIATELY => {
                     if self.parse_keyword(Keyword::PRECEDES) {
                         let right = self.parse_subexpr(precedence)?;
                         Ok(Expr::BinaryOp {
-                            left: Box::new(expr),
+                            left: self.new_box(expr)?,
                             op: BinaryOperator::PeriodImmediatelyPrecedes,
-                            right: Box::new(right),
+                            right: self.new_box(right)?,
                         })
                     } else if self.parse_keyword(Keyword::SUCCEEDS) {
                         let right = self.parse_subexpr(precedence)?;
                         Ok(Expr::BinaryOp {
-                            left: Box::new(expr),
+                            left: self.new_box(expr)?,
                             op: BinaryOperator::PeriodImmediatelySucceeds,
-                            right: Box::new(right),
+                            right: self.new_box(right)?,
                         })
                     } else {
                         self.expected("PRECEDES or SUCCEEDS after IMMEDIATELY", self.peek_token())
@@ -8407,8 +9622,8 @@ impl<'a> Parser<'a> {
         } else if BorrowedToken::DoubleColon == *tok {
             Ok(Expr::Cast {
                 kind: CastKind::DoubleColon,
-                expr: Box::new(expr),
-                data_type: self.parse_data_type()?,
+                expr: self.new_box(expr)?,
+                data_type: self.new_box(self.parse_data_type()?)?,
                 format: None,
             })
         } else if BorrowedToken::ExclamationMark == *tok
@@ -8416,7 +9631,7 @@ impl<'a> Parser<'a> {
         {
             Ok(Expr::UnaryOp {
                 op: UnaryOperator::PGPostfixFactorial,
-                expr: Box::new(expr),
+                expr: self.new_box(expr)?,
             })
         } else if BorrowedToken::LBracket == *tok && self.features.supports_partiql {
             self.prev_token();
@@ -8573,7 +9788,7 @@ impl<'a> Parser<'a> {
     fn parse_json_access(&self, expr: Expr) -> Result<Expr, ParserError> {
         let path = self.parse_json_path()?;
         Ok(Expr::JsonAccess {
-            value: Box::new(expr),
+            value: self.new_box(expr)?,
             path,
         })
     }
@@ -8614,25 +9829,25 @@ impl<'a> Parser<'a> {
             let array_expr = self.parse_expr()?;
             self.expect_token(&BorrowedToken::RParen)?;
             return Ok(Expr::InUnnest {
-                expr: Box::new(expr),
-                array_expr: Box::new(array_expr),
+                expr: self.new_box(expr)?,
+                array_expr: self.new_box(array_expr)?,
                 negated,
             });
         }
         self.expect_token(&BorrowedToken::LParen)?;
         let in_op = match self.maybe_parse(|p| p.parse_query())? {
             Some(subquery) => Expr::InSubquery {
-                expr: Box::new(expr),
+                expr: self.new_box(expr)?,
                 subquery,
                 negated,
             },
             None => Expr::InList {
-                expr: Box::new(expr),
-                list: if self.features.supports_in_empty_list {
+                expr: self.new_box(expr)?,
+                list: self.new_list(if self.features.supports_in_empty_list {
                     self.parse_comma_separated0(Parser::parse_expr, BorrowedToken::RParen)?
                 } else {
                     self.parse_comma_separated(Parser::parse_expr)?
-                },
+                })?,
                 negated,
             },
         };
@@ -8656,11 +9871,11 @@ impl<'a> Parser<'a> {
         self.expect_keyword_is(Keyword::AND)?;
         let high = self.parse_subexpr(self.dialect.prec_value(Precedence::Between))?;
         Ok(Expr::Between {
-            expr: Box::new(expr),
+            expr: self.new_box(expr)?,
             negated,
             symmetric,
-            low: Box::new(low),
-            high: Box::new(high),
+            low: self.new_box(low)?,
+            high: self.new_box(high)?,
         })
     }
 
@@ -8668,8 +9883,8 @@ impl<'a> Parser<'a> {
     pub fn parse_pg_cast(&self, expr: Expr) -> Result<Expr, ParserError> {
         Ok(Expr::Cast {
             kind: CastKind::DoubleColon,
-            expr: Box::new(expr),
-            data_type: self.parse_data_type()?,
+            expr: self.new_box(expr)?,
+            data_type: self.new_box(self.parse_data_type()?)?,
             format: None,
         })
     }
@@ -8944,46 +10159,80 @@ impl<'a> Parser<'a> {
 
     /// Report `found` was encountered instead of `expected`
     pub fn expected<T>(&self, expected: &str, found: TokenWithSpan) -> Result<T, ParserError> {
-        if !self.detailed_errors.get() {
-            return Err(ParserError::ParserError(String::new()));
-        }
-        self.error_tracker.record(
-            self.index.get(),
-            ExpectedItem::Category(expected.to_string()),
-        );
-        parser_err!(
-            format!("Expected: {expected}, found: {found}"),
-            found.span.start
-        )
+        self.expected_ref(expected, &found)
     }
 
     /// report `found` was encountered instead of `expected`
     pub fn expected_ref<T>(&self, expected: &str, found: &TokenWithSpan) -> Result<T, ParserError> {
-        if !self.detailed_errors.get() {
-            return Err(ParserError::ParserError(String::new()));
-        }
-        self.error_tracker.record(
-            self.index.get(),
-            ExpectedItem::Category(expected.to_string()),
-        );
-        parser_err!(
-            format!("Expected: {expected}, found: {found}"),
-            found.span.start
-        )
+        self.expected_at_token(expected, self.index.get(), found)
+    }
+
+    /// Report that the token at `index` was found instead of `expected`.
+    pub fn expected_at<T>(&self, expected: &str, index: usize) -> Result<T, ParserError> {
+        let found = self.tokens.get(index).unwrap_or(&EOF_TOKEN);
+        self.expected_at_token(expected, index, found)
     }
 
-    /// Report that the token at `index` was found instead of `expected`.
-    pub fn expected_at<T>(&self, expected: &str, index: usize) -> Result<T, ParserError> {
+    fn expected_at_token<T>(
+        &self,
+        expected: &str,
+        index: usize,
+        found: &TokenWithSpan,
+    ) -> Result<T, ParserError> {
         if !self.detailed_errors.get() {
             return Err(ParserError::ParserError(String::new()));
         }
-        let found = self.tokens.get(index).unwrap_or(&EOF_TOKEN);
-        self.error_tracker
-            .record(index, ExpectedItem::Category(expected.to_string()));
-        parser_err!(
+        let item = ExpectedItem::Category(expected.to_string());
+        self.error_tracker.record(index, item.clone());
+        // Errors at the furthest position share the expectations and typo
+        // hint accumulated there; an error behind it only knows its own.
+        let (expected_items, hint) = if self.error_tracker.furthest_index() == index {
+            (
+                self.error_tracker.expected_items(),
+                self.error_tracker.typo_hint(),
+            )
+        } else {
+            (vec![item], None)
+        };
+        Err(self.syntax_error(
             format!("Expected: {expected}, found: {found}"),
-            found.span.start
-        )
+            found,
+            expected_items,
+            self.error_tracker.contexts(),
+            hint,
+        ))
+    }
+
+    /// Build the error reported when `found` did not match the grammar.
+    ///
+    /// Errors on located tokens are structured. End of input has no span, so
+    /// it is only structured when the source text is known and the byte
+    /// offset can point past its last character.
+    fn syntax_error(
+        &self,
+        message: String,
+        found: &TokenWithSpan,
+        expected: Vec<ExpectedItem>,
+        contexts: Vec<ParseContext>,
+        hint: Option<String>,
+    ) -> ParserError {
+        let byte_offset = if found.span.start.line != 0 {
+            found.span.start.byte_offset()
+        } else {
+            match (self.sql, &found.token) {
+                (Some(sql), BorrowedToken::EOF) => Some(sql.len()),
+                _ => return ParserError::ParserError(message),
+            }
+        };
+        ParserError::SyntaxError(HeapBox::new(SyntaxError {
+            kind: SyntaxErrorKind::Parser,
+            message,
+            span: found.span,
+            byte_offset,
+            expected,
+            contexts,
+            hint,
+        }))
     }
 
     /// If the current token is the `expected` keyword, consume it and returns
@@ -9000,7 +10249,22 @@ impl<'a> Parser<'a> {
 
     #[must_use]
     pub fn peek_keyword(&self, expected: Keyword) -> bool {
-        matches!(&self.peek_token_ref().token, BorrowedToken::Word(w) if expected == w.keyword)
+        let found =
+            matches!(&self.peek_token_ref().token, BorrowedToken::Word(w) if expected == w.keyword);
+        if !found {
+            self.record_candidate(|| ExpectedItem::Keyword(expected));
+        }
+        found
+    }
+
+    /// Record `expected` as accepted at the end of input, if the next token
+    /// is the end of input and completion candidates are being collected.
+    fn record_candidate(&self, expected: impl FnOnce() -> ExpectedItem) {
+        if self.collect_candidates.get()
+            && matches!(self.peek_token_ref().token, BorrowedToken::EOF)
+        {
+            self.error_tracker.record(self.index.get(), expected());
+        }
     }
 
     /// If the current token is the `expected` keyword followed by
@@ -9031,7 +10295,10 @@ impl<'a> Parser<'a> {
 
                 true
             }
-            _ => false,
+            _ => {
+                self.record_candidate(|| ExpectedItem::Keyword(expected));
+                false
+            }
         }
     }
 
@@ -9079,7 +10346,12 @@ impl<'a> Parser<'a> {
                         *keyword
                     })
             }
-            _ => None,
+            _ => {
+                for keyword in keywords {
+                    self.record_candidate(|| ExpectedItem::Keyword(*keyword));
+                }
+                None
+            }
         }
     }
 
@@ -9160,6 +10432,7 @@ impl<'a> Parser<'a> {
             self.advance_token();
             true
         } else {
+            self.record_candidate(|| ExpectedItem::Token(expected.to_string()));
             false
         }
     }
@@ -9217,12 +10490,22 @@ impl<'a> Parser<'a> {
             self.options.trailing_commas | self.features.supports_projection_trailing_commas;
 
         self.parse_comma_separated_with_trailing_commas(
-            |p| p.parse_select_item(),
+            |p| p.parse_projection_item(),
             trailing_commas,
             Self::is_reserved_for_column_alias,
         )
     }
 
+    /// Parse a [`SelectItem`] of the projection with its comments attached.
+    fn parse_projection_item(&self) -> Result<SelectItem, ParserError> {
+        let (mut item, comments) =
+            self.parse_with_comments(&BorrowedToken::Comma, || self.parse_select_item())?;
+        if !comments.is_empty() {
+            self.attach_comments(Some(item.comments_mut()), comments);
+        }
+        Ok(item)
+    }
+
     pub fn parse_actions_list(&self) -> Result<Vec<Action>, ParserError> {
         let mut values = Vec::with_capacity(4);
         loop {
@@ -9328,6 +10611,7 @@ impl<'a> Parser<'a> {
             ) {
                 break;
             }
+            self.check_list_length(values.len())?;
         }
         Ok(values)
     }
@@ -9343,6 +10627,7 @@ impl<'a> Parser<'a> {
             if !self.consume_token(&BorrowedToken::Period) {
                 break;
             }
+            self.check_list_length(values.len())?;
         }
         Ok(values)
     }
@@ -9362,6 +10647,7 @@ impl<'a> Parser<'a> {
             if !self.parse_keyword(keyword) {
                 break;
             }
+            self.check_list_length(values.len())?;
         }
         Ok(values)
     }
@@ -9409,10 +10695,10 @@ impl<'a> Parser<'a> {
         loop {
             match &self.peek_nth_token_ref(0).token {
                 BorrowedToken::EOF => break,
-                BorrowedToken::Word(w) => {
-                    if w.quote_style.is_none() && terminal_keywords.contains(&w.keyword) {
-                        break;
-                    }
+                BorrowedToken::Word(w)
+                    if w.quote_style.is_none() && terminal_keywords.contains(&w.keyword) =>
+                {
+                    break;
                 }
                 _ => {}
             }
@@ -9428,6 +10714,19 @@ impl<'a> Parser<'a> {
         Ok(values)
     }
 
+    /// Fail before parsing another item of a list that already holds `len`
+    /// items, if that would exceed [`ParserLimits::max_list_length`].
+    fn check_list_length(&self, len: usize) -> Result<(), ParserError> {
+        match self.options.limits.max_list_length {
+            Some(max) if len >= max => Err(LimitExceeded {
+                limit: ParserLimit::ListLength,
+                max,
+            }
+            .into()),
+            _ => Ok(()),
+        }
+    }
+
     /// Default implementation of a predicate that returns true if
     /// the specified keyword is reserved for column alias.
     /// See [Dialect::is_column_alias]
@@ -9436,7 +10735,8 @@ impl<'a> Parser<'a> {
     }
 
     /// Run a parser method `f`, reverting back to the current position if unsuccessful.
-    /// Returns `ParserError::RecursionLimitExceeded` if `f` returns a `RecursionLimitExceeded`.
+    /// Returns `ParserError::RecursionLimitExceeded` or `ParserError::LimitExceeded`
+    /// if `f` returns one of them.
     /// Returns `Ok(None)` if `f` returns any other error.
     pub fn maybe_parse<T, F>(&self, f: F) -> Result<Option<T>, ParserError>
     where
@@ -9445,6 +10745,7 @@ impl<'a> Parser<'a> {
         match self.try_parse(f) {
             Ok(t) => Ok(Some(t)),
             Err(ParserError::RecursionLimitExceeded) => Err(ParserError::RecursionLimitExceeded),
+            Err(error @ ParserError::LimitExceeded(_)) => Err(error),
             _ => Ok(None),
         }
     }
@@ -9455,11 +10756,18 @@ impl<'a> Parser<'a> {
         F: FnMut(&Parser) -> Result<T, ParserError>,
     {
         let index = self.index.get();
-        match f(self) {
+        let comments = self.comment_checkpoint();
+        // A speculative parse must fail rather than produce placeholders,
+        // so that the caller can try the alternatives.
+        let tolerant = self.tolerant.replace(false);
+        let result = f(self);
+        self.tolerant.set(tolerant);
+        match result {
             Ok(t) => Ok(t),
             Err(e) => {
                 // Unwind stack if limit exceeded
                 self.index.set(index);
+                self.restore_comments(comments);
                 Err(e)
             }
         }
@@ -9490,7 +10798,7 @@ impl<'a> Parser<'a> {
             self.parse_comma_separated(Parser::parse_expr)?
         };
         self.expect_token(&BorrowedToken::RParen)?;
-        Ok(Some(Distinct::On(col_names)))
+        Ok(Some(Distinct::On(self.new_list(col_names)?)))
     }
 
     /// Parse a SQL CREATE statement
@@ -9759,13 +11067,15 @@ impl<'a> Parser<'a> {
                 }
             }
         };
-        Ok(Statement::OracleCreateTable(OracleCreateTable {
-            kind,
-            name,
-            columns,
-            constraints,
-            options,
-        }))
+        Ok(Statement::OracleCreateTable(self.new_box(
+            OracleCreateTable {
+                kind,
+                name,
+                columns,
+                constraints,
+                options,
+            },
+        )?))
     }
 
     fn parse_create_user(&self, or_replace: bool) -> Result<Statement, ParserError> {
@@ -9781,7 +11091,7 @@ impl<'a> Parser<'a> {
         } else {
             vec![]
         };
-        Ok(Statement::CreateUser(CreateUser {
+        Ok(Statement::CreateUser(self.new_box(CreateUser {
             token,
             or_replace,
             if_not_exists,
@@ -9795,7 +11105,7 @@ impl<'a> Parser<'a> {
                 options: tags,
                 delimiter: KeyValueOptionsDelimiter::Comma,
             },
-        }))
+        })?))
     }
 
     /// Parse a CACHE TABLE statement
@@ -9818,7 +11128,7 @@ impl<'a> Parser<'a> {
                 }
 
                 Ok(Statement::Cache {
-                    cache_token,
+                    cache_token: self.new_box(cache_token)?,
                     table_flag,
                     table_name,
                     has_as,
@@ -9827,7 +11137,7 @@ impl<'a> Parser<'a> {
                 })
             } else {
                 Ok(Statement::Cache {
-                    cache_token,
+                    cache_token: self.new_box(cache_token)?,
                     table_flag,
                     table_name,
                     has_as,
@@ -9853,7 +11163,7 @@ impl<'a> Parser<'a> {
                     }
 
                     Ok(Statement::Cache {
-                        cache_token,
+                        cache_token: self.new_box(cache_token)?,
                         table_flag,
                         table_name,
                         has_as,
@@ -9862,7 +11172,7 @@ impl<'a> Parser<'a> {
                     })
                 } else {
                     Ok(Statement::Cache {
-                        cache_token,
+                        cache_token: self.new_box(cache_token)?,
                         table_flag,
                         table_name,
                         has_as,
@@ -9939,12 +11249,12 @@ impl<'a> Parser<'a> {
         };
 
         Ok(Statement::CreateSchema {
-            create_token,
-            schema_name,
+            create_token: self.new_box(create_token)?,
+            schema_name: self.new_box(schema_name)?,
             if_not_exists,
             with,
             options,
-            default_collate_spec,
+            default_collate_spec: default_collate_spec.map(|v| self.new_box(v)).transpose()?,
             clone,
         })
     }
@@ -10016,7 +11326,7 @@ impl<'a> Parser<'a> {
             None
         };
 
-        Ok(Statement::CreateDatabase {
+        Ok(Statement::CreateDatabase(self.new_box(CreateDatabase {
             create_token,
             db_name,
             if_not_exists: ine,
@@ -10029,7 +11339,7 @@ impl<'a> Parser<'a> {
             comment: None,
             catalog_sync: None,
             compatibility,
-        })
+        })?))
     }
 
     /// Consume a single PostgreSQL `CREATE DATABASE` option clause
@@ -10150,15 +11460,12 @@ impl<'a> Parser<'a> {
     }
 
     fn extract_string_body(expr: &Expr) -> Option<String> {
-        match expr {
-            Expr::Value(ValueWithSpan {
-                value: Value::DollarQuotedString(ref dqs),
-                ..
-            }) => Some(dqs.value.clone()),
-            Expr::Value(ValueWithSpan {
-                value: Value::SingleQuotedString(ref s),
-                ..
-            }) => Some(s.clone()),
+        let Expr::Value(value) = expr else {
+            return None;
+        };
+        match &value.value {
+            Value::DollarQuotedString(dqs) => Some(dqs.value.clone()),
+            Value::SingleQuotedString(s) => Some(s.to_string()),
             _ => None,
         }
     }
@@ -10248,8 +11555,20 @@ impl<'a> Parser<'a> {
         Ok(SqlPsmDataType::DataType(data_type))
     }
 
-    /// Parse a single SQL/PSM variable declaration
+    /// Parse a single SQL/PSM variable declaration with its comments
+    /// attached.
     fn parse_sql_psm_declaration(&self) -> Result<PlSqlDeclaration, ParserError> {
+        let (mut declaration, comments) = self
+            .parse_with_comments(&BorrowedToken::SemiColon, || {
+                self.parse_sql_psm_declaration_inner()
+            })?;
+        if !comments.is_empty() {
+            self.attach_comments(Some(declaration.comments_mut()), comments);
+        }
+        Ok(declaration)
+    }
+
+    fn parse_sql_psm_declaration_inner(&self) -> Result<PlSqlDeclaration, ParserError> {
         if self.dialect.is::<OracleDialect>() {
             let routine_kind = if self.parse_keyword(Keyword::PROCEDURE) {
                 Some(OraclePlSqlRoutineKind::Procedure)
@@ -10259,9 +11578,9 @@ impl<'a> Parser<'a> {
                 None
             };
             if let Some(kind) = routine_kind {
-                return Ok(PlSqlDeclaration::Routine(Box::new(
-                    self.parse_oracle_plsql_routine(kind, true)?,
-                )));
+                return Ok(PlSqlDeclaration::Routine(
+                    self.new_box(self.parse_oracle_plsql_routine(kind, true)?)?,
+                ));
             }
             if self.parse_keyword(Keyword::PRAGMA) {
                 return Ok(PlSqlDeclaration::Pragma(self.parse_pragma_body()?));
@@ -10275,6 +11594,7 @@ impl<'a> Parser<'a> {
                     name,
                     data_type,
                     not_null,
+                    comments: Comments::default(),
                 });
             }
             if self.parse_keyword(Keyword::TYPE) {
@@ -10287,20 +11607,23 @@ impl<'a> Parser<'a> {
 
         let name = self.parse_identifier()?;
         if self.dialect.is::<OracleDialect>() && self.parse_keyword(Keyword::EXCEPTION) {
-            return Ok(PlSqlDeclaration::Exception { name });
+            return Ok(PlSqlDeclaration::Exception {
+                name,
+                comments: Comments::default(),
+            });
         }
         if self.parse_keyword(Keyword::ALIAS) {
             self.expect_keyword(Keyword::FOR)?;
             let target = self.parse_expr()?;
             let parameter = match &target {
-                Expr::Value(ValueWithSpan {
-                    value: Value::Placeholder(placeholder),
-                    ..
-                }) => placeholder
-                    .strip_prefix('$')
-                    .and_then(|index| index.parse::<usize>().ok())
-                    .and_then(|index| index.checked_sub(1))
-                    .and_then(|index| self.routine_args.get(index)),
+                Expr::Value(value) => match &value.value {
+                    Value::Placeholder(placeholder) => Some(placeholder),
+                    _ => None,
+                }
+                .and_then(|placeholder| placeholder.strip_prefix('$'))
+                .and_then(|index| index.parse::<usize>().ok())
+                .and_then(|index| index.checked_sub(1))
+                .and_then(|index| self.routine_args.get(index)),
                 Expr::Identifier(identifier) => self.routine_args.iter().find(|argument| {
                     argument
                         .name
@@ -10322,6 +11645,7 @@ impl<'a> Parser<'a> {
                 not_null: false,
                 default_operator: Some(DeclarationAssignmentOperator::Assignment),
                 default: Some(target),
+                comments: Comments::default(),
             }
             .into());
         }
@@ -10339,7 +11663,7 @@ impl<'a> Parser<'a> {
     ) -> Result<Statement, ParserError> {
         let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
         let routine = self.parse_oracle_plsql_routine(kind, false)?;
-        Ok(Statement::OracleCreatePlSqlRoutine(
+        Ok(Statement::OracleCreatePlSqlRoutine(self.new_box(
             OracleCreatePlSqlRoutine {
                 create_token,
                 or_replace,
@@ -10347,7 +11671,7 @@ impl<'a> Parser<'a> {
                 if_not_exists,
                 routine,
             },
-        ))
+        )?))
     }
 
     fn parse_oracle_create_package(
@@ -10411,19 +11735,21 @@ impl<'a> Parser<'a> {
             (None, end_name)
         };
 
-        Ok(Statement::OracleCreatePackage(OracleCreatePackage {
-            create_token,
-            or_replace,
-            editionable,
-            is_body,
-            if_not_exists,
-            name,
-            clauses,
-            is_as,
-            declarations,
-            initialization,
-            end_name,
-        }))
+        Ok(Statement::OracleCreatePackage(self.new_box(
+            OracleCreatePackage {
+                create_token,
+                or_replace,
+                editionable,
+                is_body,
+                if_not_exists,
+                name,
+                clauses,
+                is_as,
+                declarations,
+                initialization,
+                end_name,
+            },
+        )?))
     }
 
     fn parse_oracle_create_trigger(
@@ -10569,23 +11895,25 @@ impl<'a> Parser<'a> {
         } else {
             OracleTriggerBody::Block(self.parse_sql_psm_block()?)
         };
-        Ok(Statement::OracleCreateTrigger(OracleCreateTrigger {
-            create_token,
-            or_replace,
-            editionable,
-            if_not_exists,
-            name,
-            timing,
-            events,
-            target,
-            referencing,
-            for_each_row,
-            crossedition,
-            ordering,
-            enabled,
-            when,
-            body,
-        }))
+        Ok(Statement::OracleCreateTrigger(self.new_box(
+            OracleCreateTrigger {
+                create_token,
+                or_replace,
+                editionable,
+                if_not_exists,
+                name,
+                timing,
+                events,
+                target,
+                referencing,
+                for_each_row,
+                crossedition,
+                ordering,
+                enabled,
+                when,
+                body,
+            },
+        )?))
     }
 
     /// Distinguish the PostgreSQL-compatible trigger-function extension from
@@ -10685,14 +12013,16 @@ impl<'a> Parser<'a> {
                 return self.expected("OBJECT, TABLE, or VARRAY", self.peek_token());
             }
         };
-        Ok(Statement::OracleCreateType(OracleCreateType {
-            create_token,
-            or_replace,
-            editionable,
-            is_body,
-            name,
-            definition,
-        }))
+        Ok(Statement::OracleCreateType(self.new_box(
+            OracleCreateType {
+                create_token,
+                or_replace,
+                editionable,
+                is_body,
+                name,
+                definition,
+            },
+        )?))
     }
 
     fn parse_oracle_object_type_element(&self) -> Result<OracleObjectTypeElement, ParserError> {
@@ -10747,14 +12077,16 @@ impl<'a> Parser<'a> {
         } else {
             None
         };
-        Ok(Statement::OracleCreateLibrary(OracleCreateLibrary {
-            create_token,
-            or_replace,
-            name,
-            file,
-            agent,
-            credential,
-        }))
+        Ok(Statement::OracleCreateLibrary(self.new_box(
+            OracleCreateLibrary {
+                create_token,
+                or_replace,
+                name,
+                file,
+                agent,
+                credential,
+            },
+        )?))
     }
 
     fn parse_oracle_trigger_event(&self) -> Result<OracleTriggerEvent, ParserError> {
@@ -11030,6 +12362,7 @@ impl<'a> Parser<'a> {
             return_type,
             clauses,
             body,
+            comments: Comments::default(),
         })
     }
 
@@ -11178,6 +12511,7 @@ impl<'a> Parser<'a> {
             not_null,
             default_operator,
             default,
+            comments: Comments::default(),
         })
     }
 
@@ -11192,7 +12526,11 @@ impl<'a> Parser<'a> {
                 parser.parse_sql_psm_variable_declaration_after_name(name)
             })?;
             self.expect_token(&BorrowedToken::RParen)?;
-            return Ok(PlSqlDeclaration::RecordType { name, fields });
+            return Ok(PlSqlDeclaration::RecordType {
+                name,
+                fields,
+                comments: Comments::default(),
+            });
         }
 
         if self.parse_keyword(Keyword::REF) {
@@ -11202,7 +12540,11 @@ impl<'a> Parser<'a> {
             } else {
                 None
             };
-            return Ok(PlSqlDeclaration::RefCursorType { name, return_type });
+            return Ok(PlSqlDeclaration::RefCursorType {
+                name,
+                return_type,
+                comments: Comments::default(),
+            });
         }
 
         let kind = if self.parse_keyword(Keyword::TABLE) {
@@ -11227,6 +12569,7 @@ impl<'a> Parser<'a> {
             kind,
             element_type,
             index_by,
+            comments: Comments::default(),
         })
     }
 
@@ -11254,6 +12597,7 @@ impl<'a> Parser<'a> {
             parameters,
             return_type,
             query,
+            comments: Comments::default(),
         })
     }
 
@@ -11485,16 +12829,9 @@ impl<'a> Parser<'a> {
                 } else {
                     return self.expected("one of UNSAFE | RESTRICTED | SAFE", self.peek_token());
                 }
-            } else if self.parse_keywords(&[Keyword::EXTERNAL, Keyword::SECURITY]) {
-                ensure_not_set(&body.security, "SECURITY")?;
-                body.security = if self.parse_keyword(Keyword::INVOKER) {
-                    Some(ProcedureSecurity::Invoker)
-                } else if self.parse_keyword(Keyword::DEFINER) {
-                    Some(ProcedureSecurity::Definer)
-                } else {
-                    return self.expected("INVOKER or DEFINER after SECURITY", self.peek_token());
-                };
-            } else if self.parse_keyword(Keyword::SECURITY) {
+            } else if self.parse_keywords(&[Keyword::EXTERNAL, Keyword::SECURITY])
+                || self.parse_keyword(Keyword::SECURITY)
+            {
                 ensure_not_set(&body.security, "SECURITY")?;
                 body.security = if self.parse_keyword(Keyword::INVOKER) {
                     Some(ProcedureSecurity::Invoker)
@@ -11608,7 +12945,7 @@ impl<'a> Parser<'a> {
             }
         }
 
-        Ok(Statement::CreateFunction(CreateFunction {
+        Ok(Statement::CreateFunction(self.new_box(CreateFunction {
             token,
             or_alter: false,
             or_replace,
@@ -11635,7 +12972,8 @@ impl<'a> Parser<'a> {
             remote_connection: None,
             sql_data_access: body.sql_data_access,
             polymorphic: body.polymorphic,
-        }))
+            comments: Comments::default(),
+        })?))
     }
 
     /// Parse `CREATE FUNCTION` for [MsSql]
@@ -11669,7 +13007,9 @@ impl<'a> Parser<'a> {
             };
 
             Ok(DataType::NamedTable {
-                name: ObjectName(vec![ObjectNamePart::Identifier(return_table_name)]),
+                name: ObjectName(
+                    self.new_list(vec![ObjectNamePart::Identifier(return_table_name)])?,
+                ),
                 columns: table_column_defs,
             })
         })?;
@@ -11712,7 +13052,7 @@ impl<'a> Parser<'a> {
             parser_err!("Unparsable function body", self.peek_token().span.start)?
         };
 
-        Ok(Statement::CreateFunction(CreateFunction {
+        Ok(Statement::CreateFunction(self.new_box(CreateFunction {
             token,
             or_alter,
             or_replace,
@@ -11739,7 +13079,8 @@ impl<'a> Parser<'a> {
             set_options: vec![],
             sql_data_access: None,
             polymorphic: false,
-        }))
+            comments: Comments::default(),
+        })?))
     }
 
     fn parse_create_function_name_and_params(
@@ -11937,7 +13278,7 @@ impl<'a> Parser<'a> {
             statements = Some(self.parse_conditional_statements(&[Keyword::END])?);
         }
 
-        Ok(CreateTrigger {
+        Ok(Statement::CreateTrigger(self.new_box(CreateTrigger {
             token,
             or_alter,
             temporary,
@@ -11956,8 +13297,7 @@ impl<'a> Parser<'a> {
             statements_as: false,
             statements,
             characteristics,
-        }
-        .into())
+        })?))
     }
 
     pub fn parse_trigger_period(&self) -> Result<TriggerPeriod, ParserError> {
@@ -12064,7 +13404,7 @@ impl<'a> Parser<'a> {
         } else {
             CreateTableOptions::None
         };
-        Ok(CreateTableBuilder::new(table_name)
+        let create_table = CreateTableBuilder::new(table_name)
             .columns(columns)
             .constraints(constraints)
             .table_options(table_options)
@@ -12072,7 +13412,8 @@ impl<'a> Parser<'a> {
             .if_not_exists(if_not_exists)
             .external(true)
             .location(location)
-            .build())
+            .build_create_table();
+        Ok(Statement::CreateTable(self.new_box(create_table)?))
     }
 
     fn parse_analyze_format_kind(&self) -> Result<AnalyzeFormatKind, ParserError> {
@@ -12303,7 +13644,7 @@ impl<'a> Parser<'a> {
                                 } => {
                                     if let Expr::Identifier(ident) = left.as_ref() {
                                         extracted.push(SqlOption::KeyValue {
-                                            key: ident.clone(),
+                                            key: Ident::clone(ident),
                                             value: box_into_inner(right.clone()),
                                         });
                                     } else {
@@ -12372,7 +13713,7 @@ impl<'a> Parser<'a> {
 
         let oracle = oracle.filter(|options| !options.is_empty());
 
-        Ok(CreateView {
+        Ok(Statement::CreateView(self.new_box(CreateView {
             or_alter,
             name,
             columns,
@@ -12387,8 +13728,8 @@ impl<'a> Parser<'a> {
             late_options,
             refresh_schedule,
             oracle,
-        }
-        .into())
+            comments: Comments::default(),
+        })?))
     }
 
     /// Parse optional parameters for the `CREATE VIEW` statement supported by [MySQL].
@@ -12508,7 +13849,7 @@ impl<'a> Parser<'a> {
             let loc = self
                 .tokens
                 .get(self.index.get() - 1)
-                .map_or(Location { line: 0, column: 0 }, |t| t.span.start);
+                .map_or(Location::empty(), |t| t.span.start);
             match keyword {
                 Keyword::AUTHORIZATION => {
                     if authorization_owner.is_some() {
@@ -12581,7 +13922,9 @@ impl<'a> Parser<'a> {
                         password = if self.parse_keyword(Keyword::NULL) {
                             Some(Password::NullPassword)
                         } else {
-                            Some(Password::Password(Expr::Value(self.parse_value()?)))
+                            Some(Password::Password(Expr::Value(
+                                self.new_box(self.parse_value()?)?,
+                            )))
                         };
                         Ok(())
                     }
@@ -12591,7 +13934,8 @@ impl<'a> Parser<'a> {
                     if connection_limit.is_some() {
                         parser_err!("Found multiple CONNECTION LIMIT", loc)
                     } else {
-                        connection_limit = Some(Expr::Value(self.parse_number_value()?));
+                        connection_limit =
+                            Some(Expr::Value(self.new_box(self.parse_number_value()?)?));
                         Ok(())
                     }
                 }
@@ -12600,7 +13944,7 @@ impl<'a> Parser<'a> {
                     if valid_until.is_some() {
                         parser_err!("Found multiple VALID UNTIL", loc)
                     } else {
-                        valid_until = Some(Expr::Value(self.parse_value()?));
+                        valid_until = Some(Expr::Value(self.new_box(self.parse_value()?)?));
                         Ok(())
                     }
                 }
@@ -12651,7 +13995,7 @@ impl<'a> Parser<'a> {
             }?
         }
 
-        Ok(CreateRole {
+        Ok(Statement::CreateRole(self.new_box(CreateRole {
             names,
             if_not_exists,
             login,
@@ -12670,8 +14014,7 @@ impl<'a> Parser<'a> {
             user,
             admin,
             authorization_owner,
-        }
-        .into())
+        })?))
     }
 
     pub fn parse_owner(&self) -> Result<Owner, ParserError> {
@@ -12713,12 +14056,14 @@ impl<'a> Parser<'a> {
                 })
             })?;
             self.expect_token(&BorrowedToken::RParen)?;
-            return Ok(Statement::OracleCreate(OracleCreateStatement {
-                create_token: token,
-                or_replace: false,
-                and_compile: false,
-                definition: OracleCreateDefinition::MultiColumnDomain { name, columns },
-            }));
+            return Ok(Statement::OracleCreate(self.new_box(
+                OracleCreateStatement {
+                    create_token: token,
+                    or_replace: false,
+                    and_compile: false,
+                    definition: OracleCreateDefinition::MultiColumnDomain { name, columns },
+                },
+            )?));
         }
         let data_type = self.parse_data_type()?;
         let collation = if self.parse_keyword(Keyword::COLLATE) {
@@ -12745,7 +14090,7 @@ impl<'a> Parser<'a> {
             }
         }
 
-        Ok(Statement::CreateDomain(CreateDomain {
+        Ok(Statement::CreateDomain(self.new_box(CreateDomain {
             token,
             name,
             data_type,
@@ -12753,7 +14098,7 @@ impl<'a> Parser<'a> {
             default,
             not_null,
             constraints,
-        }))
+        })?))
     }
 
     /// Parse CREATE ASSERTION statement
@@ -12765,7 +14110,7 @@ impl<'a> Parser<'a> {
         let name = self.parse_object_name(false)?;
         self.expect_keyword(Keyword::CHECK)?;
         self.expect_token(&Token::LParen)?;
-        let expr = Box::new(self.parse_expr()?);
+        let expr = self.new_box(self.parse_expr()?)?;
         self.expect_token(&Token::RParen)?;
 
         Ok(Statement::CreateAssertion(CreateAssertion {
@@ -12799,14 +14144,16 @@ impl<'a> Parser<'a> {
             self.expect_token(&Token::RParen)?;
         }
 
-        Ok(Statement::CreatePropertyGraph(CreatePropertyGraph {
-            token,
-            or_replace,
-            if_not_exists,
-            name,
-            vertex_tables,
-            edge_tables,
-        }))
+        Ok(Statement::CreatePropertyGraph(self.new_box(
+            CreatePropertyGraph {
+                token,
+                or_replace,
+                if_not_exists,
+                name,
+                vertex_tables,
+                edge_tables,
+            },
+        )?))
     }
 
     fn parse_graph_vertex_table_definition(
@@ -13021,7 +14368,7 @@ impl<'a> Parser<'a> {
             None
         };
 
-        Ok(CreatePolicy {
+        Ok(Statement::CreatePolicy(self.new_box(CreatePolicy {
             token,
             name,
             table_name,
@@ -13030,7 +14377,7 @@ impl<'a> Parser<'a> {
             to,
             using,
             with_check,
-        })
+        })?))
     }
 
     /// Parse an operator name, which can contain special characters like +, -, <, >, =
@@ -13059,7 +14406,7 @@ impl<'a> Parser<'a> {
             parts.push(ObjectNamePart::Identifier(Ident::new(token.to_string())));
 
             self.expect_token(&Token::RParen)?;
-            return Ok(ObjectName(parts));
+            return Ok(ObjectName(self.new_list(parts)?));
         }
 
         let mut parts = vec![];
@@ -13075,7 +14422,7 @@ impl<'a> Parser<'a> {
                 break;
             }
         }
-        Ok(ObjectName(parts))
+        Ok(ObjectName(self.new_list(parts)?))
     }
 
     /// Parse a PostgreSQL [Statement::CreateAggregate].
@@ -13129,12 +14476,12 @@ impl<'a> Parser<'a> {
         } else {
             CreateCastContext::Explicit
         };
-        Ok(Statement::CreateCast(CreateCast {
+        Ok(Statement::CreateCast(self.new_box(CreateCast {
             source_type,
             target_type,
             method,
             context,
-        }))
+        })?))
     }
 
     /// Parse a PostgreSQL [Statement::CreateStatistics].
@@ -13262,7 +14609,7 @@ impl<'a> Parser<'a> {
             ParserError::ParserError("CREATE OPERATOR requires FUNCTION parameter".to_string())
         })?;
 
-        Ok(Statement::CreateOperator(CreateOperator {
+        Ok(Statement::CreateOperator(self.new_box(CreateOperator {
             name,
             function,
             is_procedure,
@@ -13274,7 +14621,7 @@ impl<'a> Parser<'a> {
             join,
             hashes,
             merges,
-        }))
+        })?))
     }
 
     /// Parse a [Statement::CreateOperatorFamily]
@@ -13440,14 +14787,16 @@ impl<'a> Parser<'a> {
             break;
         }
 
-        Ok(Statement::CreateOperatorClass(CreateOperatorClass {
-            name,
-            default,
-            for_type,
-            using,
-            family,
-            items,
-        }))
+        Ok(Statement::CreateOperatorClass(self.new_box(
+            CreateOperatorClass {
+                name,
+                default,
+                for_type,
+                using,
+                family,
+                items,
+            },
+        )?))
     }
 
     pub fn parse_drop(&self) -> Result<Statement, ParserError> {
@@ -13685,7 +15034,7 @@ impl<'a> Parser<'a> {
         Ok(Statement::DropPolicy {
             token,
             if_exists,
-            name,
+            name: self.new_box(name)?,
             table_name,
             drop_behavior,
         })
@@ -13713,7 +15062,7 @@ impl<'a> Parser<'a> {
         let with_options = self.parse_options(Keyword::WITH)?;
 
         Ok(Statement::CreatePublication {
-            name,
+            name: self.new_box(name)?,
             for_object,
             with_options,
         })
@@ -13854,12 +15203,14 @@ impl<'a> Parser<'a> {
         let publications = self.parse_comma_separated(|p| p.parse_identifier())?;
         let with_options = self.parse_options(Keyword::WITH)?;
 
-        Ok(Statement::CreateSubscription {
-            name,
-            connection_string,
-            publications,
-            with_options,
-        })
+        Ok(Statement::CreateSubscription(self.new_box(
+            CreateSubscription {
+                name,
+                connection_string,
+                publications,
+                with_options,
+            },
+        )?))
     }
 
     /// ```sql
@@ -13915,7 +15266,10 @@ impl<'a> Parser<'a> {
                 self.peek_token(),
             );
         };
-        Ok(Statement::AlterSubscription { name, action })
+        Ok(Statement::AlterSubscription {
+            name: self.new_box(name)?,
+            action,
+        })
     }
 
     fn parse_parenthesized_sql_options(&self) -> Result<Vec<SqlOption>, ParserError> {
@@ -13981,11 +15335,11 @@ impl<'a> Parser<'a> {
         };
         Ok(Statement::CreateRule {
             or_replace,
-            name,
+            name: self.new_box(name)?,
             event,
             table_name,
-            condition,
-            action,
+            condition: condition.map(|v| self.new_box(v)).transpose()?,
+            action: self.new_box(action)?,
         })
     }
 
@@ -14225,7 +15579,7 @@ impl<'a> Parser<'a> {
                     scroll: None,
                     hold: None,
                     for_query: None,
-                    handler_body: Some(Box::new(body)),
+                    handler_body: Some(self.new_box(body)?),
                 }],
             });
         }
@@ -14243,9 +15597,9 @@ impl<'a> Parser<'a> {
                 stmts: vec![Declare {
                     names: vec![name],
                     data_type: None,
-                    assignment: Some(DeclareAssignment::For(Box::new(Expr::Value(
-                        Value::SingleQuotedString(sqlstate).with_empty_span(),
-                    )))),
+                    assignment: Some(DeclareAssignment::For(self.new_box(Expr::Value(
+                        self.new_box(Value::SingleQuotedString(sqlstate.into()).with_empty_span())?,
+                    ))?)),
                     declare_type: Some(DeclareType::Condition),
                     binary: None,
                     sensitive: None,
@@ -14328,13 +15682,15 @@ impl<'a> Parser<'a> {
         // Parse first variable
         let data_type = Some(self.parse_data_type()?);
         let assignment = if self.parse_keyword(Keyword::DEFAULT) {
-            Some(DeclareAssignment::Default(Box::new(self.parse_expr()?)))
+            Some(DeclareAssignment::Default(
+                self.new_box(self.parse_expr()?)?,
+            ))
         } else if self.consume_token(&BorrowedToken::Assignment) {
-            Some(DeclareAssignment::DuckAssignment(Box::new(
-                self.parse_expr()?,
-            )))
+            Some(DeclareAssignment::DuckAssignment(
+                self.new_box(self.parse_expr()?)?,
+            ))
         } else if self.consume_token(&BorrowedToken::Eq) {
-            Some(DeclareAssignment::Expr(Box::new(self.parse_expr()?)))
+            Some(DeclareAssignment::Expr(self.new_box(self.parse_expr()?)?))
         } else {
             None
         };
@@ -14357,13 +15713,15 @@ impl<'a> Parser<'a> {
             let var_name = self.parse_identifier()?;
             let var_type = Some(self.parse_data_type()?);
             let var_assignment = if self.parse_keyword(Keyword::DEFAULT) {
-                Some(DeclareAssignment::Default(Box::new(self.parse_expr()?)))
+                Some(DeclareAssignment::Default(
+                    self.new_box(self.parse_expr()?)?,
+                ))
             } else if self.consume_token(&BorrowedToken::Assignment) {
-                Some(DeclareAssignment::DuckAssignment(Box::new(
-                    self.parse_expr()?,
-                )))
+                Some(DeclareAssignment::DuckAssignment(
+                    self.new_box(self.parse_expr()?)?,
+                ))
             } else if self.consume_token(&BorrowedToken::Eq) {
-                Some(DeclareAssignment::Expr(Box::new(self.parse_expr()?)))
+                Some(DeclareAssignment::Expr(self.new_box(self.parse_expr()?)?))
             } else {
                 None
             };
@@ -14421,7 +15779,9 @@ impl<'a> Parser<'a> {
             stmts: vec![Declare {
                 names,
                 data_type,
-                assignment: expr.map(|expr| DeclareAssignment::Default(Box::new(expr))),
+                assignment: expr
+                    .map(|expr| self.new_box(expr).map(DeclareAssignment::Default))
+                    .transpose()?,
                 declare_type: None,
                 binary: None,
                 sensitive: None,
@@ -14538,9 +15898,9 @@ impl<'a> Parser<'a> {
         Ok(match self.peek_token().token {
             BorrowedToken::Eq => {
                 self.advance_token(); // Skip `=`
-                Some(DeclareAssignment::MsSqlAssignment(Box::new(
-                    self.parse_expr()?,
-                )))
+                Some(DeclareAssignment::MsSqlAssignment(
+                    self.new_box(self.parse_expr()?)?,
+                ))
             }
             _ => None,
         })
@@ -14611,20 +15971,20 @@ impl<'a> Parser<'a> {
         let name = self.parse_identifier()?;
 
         let into = if self.parse_keyword(Keyword::INTO) {
-            let mut targets = self.parse_object_name(false)?;
+            let mut targets = self.parse_object_name(false)?.0.into_vec();
             while self.consume_token(&BorrowedToken::Comma) {
                 let next = self.parse_object_name(false)?;
-                targets.0.extend(next.0);
+                targets.extend(next.0);
             }
-            Some(targets)
+            Some(ObjectName(self.new_list(targets)?))
         } else {
             None
         };
 
         Ok(Statement::Fetch {
             fetch_token,
-            name,
-            direction,
+            name: self.new_box(name)?,
+            direction: self.new_box(direction)?,
             position,
             into,
         })
@@ -14640,12 +16000,12 @@ impl<'a> Parser<'a> {
         } else {
             None
         };
-        Ok(Statement::PlSqlFetch(PlSqlFetch {
+        Ok(Statement::PlSqlFetch(self.new_box(PlSqlFetch {
             cursor,
             bulk_collect,
             targets,
             limit,
-        }))
+        })?))
     }
 
     fn parse_plsql_forall(&self) -> Result<Statement, ParserError> {
@@ -14666,13 +16026,13 @@ impl<'a> Parser<'a> {
             PlSqlForAllBounds::Range { lower, upper }
         };
         let save_exceptions = self.parse_keywords(&[Keyword::SAVE, Keyword::EXCEPTIONS]);
-        let statement = Box::new(self.parse_statement()?);
-        Ok(Statement::PlSqlForAll(PlSqlForAll {
+        let statement = self.new_box(self.parse_statement()?)?;
+        Ok(Statement::PlSqlForAll(self.new_box(PlSqlForAll {
             index,
             bounds,
             save_exceptions,
             statement,
-        }))
+        })?))
     }
 
     fn parse_plsql_execute_immediate(&self) -> Result<Statement, ParserError> {
@@ -14695,13 +16055,15 @@ impl<'a> Parser<'a> {
         } else {
             vec![]
         };
-        Ok(Statement::PlSqlExecuteImmediate(PlSqlExecuteImmediate {
-            sql,
-            bulk_collect,
-            into,
-            using,
-            returning_into,
-        }))
+        Ok(Statement::PlSqlExecuteImmediate(self.new_box(
+            PlSqlExecuteImmediate {
+                sql,
+                bulk_collect,
+                into,
+                using,
+                returning_into,
+            },
+        )?))
     }
 
     fn parse_plsql_using_argument(&self) -> Result<PlSqlUsingArgument, ParserError> {
@@ -14790,7 +16152,7 @@ impl<'a> Parser<'a> {
         Ok(Statement::Move {
             direction,
             position,
-            name,
+            name: self.new_box(name)?,
         })
     }
 
@@ -14890,7 +16252,7 @@ impl<'a> Parser<'a> {
             alter_options.push(self.parse_alter_table_operation()?)
         }
 
-        Ok(Statement::CreateIndex(CreateIndex {
+        Ok(Statement::CreateIndex(self.new_box(CreateIndex {
             name: index_name,
             table_name,
             using,
@@ -14904,7 +16266,7 @@ impl<'a> Parser<'a> {
             predicate,
             index_options,
             alter_options,
-        }))
+        })?))
     }
 
     pub fn parse_create_extension(&self) -> Result<Statement, ParserError> {
@@ -14926,14 +16288,15 @@ impl<'a> Parser<'a> {
 
         let cascade = self.parse_keyword(Keyword::CASCADE);
 
-        Ok(CreateExtension {
-            name,
-            if_not_exists,
-            schema,
-            version,
-            cascade,
-        }
-        .into())
+        Ok(Statement::CreateExtension(self.new_box(
+            CreateExtension {
+                name,
+                if_not_exists,
+                schema,
+                version,
+                cascade,
+            },
+        )?))
     }
 
     /// Parse a PostgreSQL-specific [Statement::DropExtension] statement.
@@ -15022,7 +16385,7 @@ impl<'a> Parser<'a> {
             definition.reject_limit = self.parse_oracle_reject_limit()?;
             return Ok(Statement::OracleCreateExternalTable {
                 name: table_name,
-                definition,
+                definition: self.new_box(definition)?,
             });
         }
 
@@ -15091,7 +16454,7 @@ impl<'a> Parser<'a> {
             None
         };
 
-        Ok(CreateTableBuilder::new(table_name)
+        let create_table = CreateTableBuilder::new(table_name)
             .temporary(temporary)
             .columns(columns)
             .constraints(constraints)
@@ -15112,7 +16475,8 @@ impl<'a> Parser<'a> {
             .partition_bound(partition_bound)
             .clustering_by(clustering_by)
             .distribution(distribution)
-            .build())
+            .build_create_table();
+        Ok(Statement::CreateTable(self.new_box(create_table)?))
     }
 
     fn parse_oracle_external_table_definition(
@@ -15227,10 +16591,10 @@ impl<'a> Parser<'a> {
         } else if self.parse_keywords(&[Keyword::DROP]) {
             Ok(OnCommit::Drop)
         } else {
-            parser_err!(
-                "Expecting DELETE ROWS, PRESERVE ROWS or DROP",
+            Err(ParserError::ParserError(format!(
+                "Expecting DELETE ROWS, PRESERVE ROWS or DROP{}",
                 self.peek_token()
-            )
+            )))
         }
     }
 
@@ -15522,10 +16886,11 @@ impl<'a> Parser<'a> {
 
         let value = match self
             .maybe_parse(|parser| parser.parse_value())?
-            .map(Expr::Value)
+            .map(|value| self.new_box(value).map(Expr::Value))
+            .transpose()?
         {
             Some(expr) => expr,
-            None => Expr::Identifier(self.parse_identifier()?),
+            None => Expr::Identifier(self.new_box(self.parse_identifier()?)?),
         };
 
         Ok(Some(SqlOption::KeyValue { key, value }))
@@ -15717,6 +17082,15 @@ impl<'a> Parser<'a> {
     }
 
     pub fn parse_column_def(&self) -> Result<ColumnDef, ParserError> {
+        let (mut column, comments) =
+            self.parse_with_comments(&BorrowedToken::Comma, || self.parse_column_def_inner())?;
+        if !comments.is_empty() {
+            self.attach_comments(Some(&mut column.comments), comments);
+        }
+        Ok(column)
+    }
+
+    fn parse_column_def_inner(&self) -> Result<ColumnDef, ParserError> {
         let col_name = self.parse_identifier()?;
         let data_type = self.parse_data_type()?;
         let mut options = Vec::with_capacity(4);
@@ -15741,6 +17115,7 @@ impl<'a> Parser<'a> {
             name: col_name,
             data_type,
             options,
+            comments: Comments::default(),
         })
     }
 
@@ -15857,7 +17232,7 @@ impl<'a> Parser<'a> {
             Ok(Some(
                 CheckConstraint {
                     name: None, // Column-level check constraints don't have names
-                    expr: Box::new(expr),
+                    expr: self.new_box(expr)?,
                     enforced: None, // Could be extended later to support MySQL ENFORCED/NOT ENFORCED
                 }
                 .into(),
@@ -15897,9 +17272,9 @@ impl<'a> Parser<'a> {
         } else if self.parse_keyword(Keyword::SRID)
             && dialect_of!(self is MySqlDialect | PostgreSqlDialect)
         {
-            Ok(Some(ColumnOption::Srid(Box::new(
-                self.parse_column_option_expr()?,
-            ))))
+            Ok(Some(ColumnOption::Srid(
+                self.new_box(self.parse_column_option_expr()?)?,
+            )))
         } else if self.parse_keyword(Keyword::IDENTITY)
             && dialect_of!(self is MsSqlDialect | PostgreSqlDialect)
         {
@@ -16297,7 +17672,7 @@ impl<'a> Parser<'a> {
             }
             BorrowedToken::Word(w) if w.keyword == Keyword::CHECK => {
                 self.expect_token(&BorrowedToken::LParen)?;
-                let expr = Box::new(self.parse_expr()?);
+                let expr = self.new_box(self.parse_expr()?)?;
                 self.expect_token(&BorrowedToken::RParen)?;
 
                 let enforced = if self.parse_keyword(Keyword::ENFORCED) {
@@ -17207,12 +18582,14 @@ impl<'a> Parser<'a> {
             if self.parse_keyword(Keyword::VIEW) {
                 let target = OracleAlterTarget::Name(self.parse_object_name(false)?);
                 self.expect_keyword(Keyword::COMPILE)?;
-                return Ok(Statement::OracleAlter(OracleAlterStatement {
-                    alter_token: AttachedToken::empty(),
-                    object_type: OracleAlterObjectType::View,
-                    target,
-                    operation: OracleAlterOperation::Compile,
-                }));
+                return Ok(Statement::OracleAlter(self.new_box(
+                    OracleAlterStatement {
+                        alter_token: AttachedToken::empty(),
+                        object_type: OracleAlterObjectType::View,
+                        target,
+                        operation: OracleAlterOperation::Compile,
+                    },
+                )?));
             }
         }
         if self.parse_keyword(Keyword::MATERIALIZED) {
@@ -17407,7 +18784,7 @@ impl<'a> Parser<'a> {
         let action_token = self.attached_token_from_current();
         Ok(Statement::AlterTenant {
             alter_token,
-            tenant_name,
+            tenant_name: self.new_box(tenant_name)?,
             action_token,
             action,
         })
@@ -17478,7 +18855,7 @@ impl<'a> Parser<'a> {
         }
 
         Ok(Statement::AlterSequence {
-            token,
+            token: self.new_box(token)?,
             name,
             if_exists,
             sequence_options,
@@ -17610,12 +18987,12 @@ impl<'a> Parser<'a> {
                 alter_token,
                 target_roles,
                 schemas,
-                action: AlterDefaultPrivilegesAction::Grant {
+                action: self.new_box(AlterDefaultPrivilegesAction::Grant {
                     privileges,
                     object,
                     grantees,
                     with_grant_option,
-                },
+                })?,
             })
         } else {
             self.expect_keyword_is(Keyword::FROM)?;
@@ -17630,13 +19007,13 @@ impl<'a> Parser<'a> {
                 alter_token,
                 target_roles,
                 schemas,
-                action: AlterDefaultPrivilegesAction::Revoke {
+                action: self.new_box(AlterDefaultPrivilegesAction::Revoke {
                     privileges,
                     object,
                     grantees,
                     grant_option_for,
                     cascade,
-                },
+                })?,
             })
         }
     }
@@ -17685,7 +19062,10 @@ impl<'a> Parser<'a> {
             );
         };
 
-        Ok(Statement::AlterMaterializedView { name, operation })
+        Ok(Statement::AlterMaterializedView {
+            name,
+            operation: self.new_box(operation)?,
+        })
     }
 
     /// Parse `REFRESH MATERIALIZED VIEW [ CONCURRENTLY ] name [ FAST | COMPLETE ]`.
@@ -17758,11 +19138,11 @@ impl<'a> Parser<'a> {
 
         if self.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
             let new_name = self.parse_identifier()?;
-            Ok(Statement::AlterType(AlterType {
+            Ok(Statement::AlterType(self.new_box(AlterType {
                 token,
                 name,
                 operation: AlterTypeOperation::Rename(AlterTypeRename { new_name }),
-            }))
+            })?))
         } else if self.parse_keywords(&[Keyword::ADD, Keyword::VALUE]) {
             let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
             let new_enum_value = self.parse_identifier()?;
@@ -17774,7 +19154,7 @@ impl<'a> Parser<'a> {
                 None
             };
 
-            Ok(Statement::AlterType(AlterType {
+            Ok(Statement::AlterType(self.new_box(AlterType {
                 token,
                 name,
                 operation: AlterTypeOperation::AddValue(AlterTypeAddValue {
@@ -17782,20 +19162,20 @@ impl<'a> Parser<'a> {
                     value: new_enum_value,
                     position,
                 }),
-            }))
+            })?))
         } else if self.parse_keywords(&[Keyword::RENAME, Keyword::VALUE]) {
             let existing_enum_value = self.parse_identifier()?;
             self.expect_keyword(Keyword::TO)?;
             let new_enum_value = self.parse_identifier()?;
 
-            Ok(Statement::AlterType(AlterType {
+            Ok(Statement::AlterType(self.new_box(AlterType {
                 token,
                 name,
                 operation: AlterTypeOperation::RenameValue(AlterTypeRenameValue {
                     from: existing_enum_value,
                     to: new_enum_value,
                 }),
-            }))
+            })?))
         } else {
             self.expected_ref(
                 "{RENAME TO | { RENAME | ADD } VALUE}",
@@ -17850,14 +19230,14 @@ impl<'a> Parser<'a> {
         let object_name = self.parse_object_name(false)?;
         if self.peek_token().token == BorrowedToken::LParen {
             match self.parse_function(object_name)? {
-                Expr::Function(f) => Ok(Statement::Call(f)),
+                Expr::Function(f) => Ok(Statement::Call(self.new_box(box_into_inner(f))?)),
                 other => parser_err!(
                     format!("Expected a simple procedure call but found: {other}"),
                     self.peek_token().span.start
                 ),
             }
         } else {
-            Ok(Statement::Call(Function {
+            Ok(Statement::Call(self.new_box(Function {
                 name: object_name,
                 uses_odbc_syntax: false,
                 parameters: FunctionArguments::None,
@@ -17867,7 +19247,7 @@ impl<'a> Parser<'a> {
                 null_treatment: None,
                 over: None,
                 within_group: vec![],
-            }))
+            })?))
         }
     }
 
@@ -17941,7 +19321,7 @@ impl<'a> Parser<'a> {
             vec![]
         };
         Ok(Statement::Copy {
-            source,
+            source: self.new_box(source)?,
             to,
             target,
             options,
@@ -17977,7 +19357,7 @@ impl<'a> Parser<'a> {
             // OPEN cursor_name FOR ...
             if self.parse_keyword(Keyword::EXECUTE) {
                 // OPEN cursor_name FOR EXECUTE expr [USING ...]
-                let query_expr = Box::new(self.parse_expr()?);
+                let query_expr = self.new_box(self.parse_expr()?)?;
                 let using = if self.parse_keyword(Keyword::USING) {
                     Some(self.parse_comma_separated(Parser::parse_expr)?)
                 } else {
@@ -17985,7 +19365,7 @@ impl<'a> Parser<'a> {
                 };
                 Some(OpenFor::Execute { query_expr, using })
             } else if self.dialect.is::<OracleDialect>() && !self.peek_sub_query() {
-                let query_expr = Box::new(self.parse_expr()?);
+                let query_expr = self.new_box(self.parse_expr()?)?;
                 let using = if self.parse_keyword(Keyword::USING) {
                     self.parse_comma_separated(Parser::parse_plsql_using_argument)?
                 } else {
@@ -18008,10 +19388,10 @@ impl<'a> Parser<'a> {
             None
         };
 
-        Ok(Statement::Open(OpenStatement {
+        Ok(Statement::Open(self.new_box(OpenStatement {
             cursor_name,
             open_for,
-        }))
+        })?))
     }
 
     pub fn parse_close(&self) -> Result<Statement, ParserError> {
@@ -18358,7 +19738,7 @@ impl<'a> Parser<'a> {
             let loc = self
                 .tokens
                 .get(self.index.get() - 1)
-                .map_or(Location { line: 0, column: 0 }, |t| t.span.start);
+                .map_or(Location::empty(), |t| t.span.start);
             return parser_err!(format!("Expect a char, found {s:?}"), loc);
         }
         Ok(s.chars().next().unwrap())
@@ -18417,7 +19797,7 @@ impl<'a> Parser<'a> {
                 Keyword::NULL => ok_value(Value::Null),
                 Keyword::NoKeyword if w.quote_style.is_some() => match w.quote_style {
                     Some('"') => ok_value(Value::DoubleQuotedString(w.value.into_owned())),
-                    Some('\'') => ok_value(Value::SingleQuotedString(w.value.into_owned())),
+                    Some('\'') => ok_value(Value::SingleQuotedString(w.value.into())),
                     _ => self.expected(
                         "A value?",
                         TokenWithSpan {
@@ -18434,9 +19814,6 @@ impl<'a> Parser<'a> {
                     },
                 ),
             },
-            // The call to n.parse() returns a bigdecimal when the
-            // bigdecimal feature is enabled, and is otherwise a no-op
-            // (i.e., it returns the input string).
             BorrowedToken::Number(n, false) if self.dialect.is::<OracleDialect>() => {
                 match n.as_bytes().last().copied() {
                     Some(b'f' | b'F') => {
@@ -18445,18 +19822,20 @@ impl<'a> Parser<'a> {
                     Some(b'd' | b'D') => {
                         ok_value(Value::OracleBinaryDouble(n[..n.len() - 1].to_string()))
                     }
-                    _ => ok_value(Value::Number(Self::parse(n, span.start)?, false)),
+                    _ => ok_value(Value::Number(self.parse_number_literal(n, span)?, false)),
                 }
             }
-            BorrowedToken::Number(n, l) => ok_value(Value::Number(Self::parse(n, span.start)?, l)),
-            BorrowedToken::SingleQuotedString(ref s) => ok_value(Value::SingleQuotedString(
-                self.maybe_concat_string_literal(s.to_string()),
+            BorrowedToken::Number(n, l) => {
+                ok_value(Value::Number(self.parse_number_literal(n, span)?, l))
+            }
+            BorrowedToken::SingleQuotedString(s) => ok_value(Value::SingleQuotedString(
+                self.maybe_concat_string_literal(s).into(),
             )),
-            BorrowedToken::DoubleQuotedString(ref s) => ok_value(Value::DoubleQuotedString(
-                self.maybe_concat_string_literal(s.to_string()),
+            BorrowedToken::DoubleQuotedString(s) => ok_value(Value::DoubleQuotedString(
+                self.maybe_concat_string_literal(s.into()).into_owned(),
             )),
             BorrowedToken::DollarQuotedString(ref s) => {
-                ok_value(Value::DollarQuotedString(s.clone()))
+                ok_value(Value::DollarQuotedString(self.new_box(s.clone())?))
             }
             BorrowedToken::SingleQuotedByteStringLiteral(ref s) => {
                 ok_value(Value::SingleQuotedByteStringLiteral(s.clone()))
@@ -18465,7 +19844,7 @@ impl<'a> Parser<'a> {
                 ok_value(Value::NationalStringLiteral(s.to_string()))
             }
             BorrowedToken::AlternativeQuotedString(ref s) => {
-                ok_value(Value::AlternativeQuotedString(s.clone()))
+                ok_value(Value::AlternativeQuotedString(self.new_box(s.clone())?))
             }
             BorrowedToken::EscapedStringLiteral(ref s) => {
                 ok_value(Value::EscapedStringLiteral(s.to_string()))
@@ -18481,7 +19860,9 @@ impl<'a> Parser<'a> {
             {
                 ok_value(Value::PlSqlInquiryDirective(s.to_string()))
             }
-            BorrowedToken::Placeholder(ref s) => ok_value(Value::Placeholder(s.to_string())),
+            BorrowedToken::Placeholder(s) => ok_value(Value::Placeholder(
+                AstStr::from_source_offset(s.into(), span.start.byte_offset()),
+            )),
             tok @ BorrowedToken::Colon | tok @ BorrowedToken::AtSign => {
                 // 1. Not calling self.parse_identifier(false)?
                 //    because only in placeholder we want to check
@@ -18499,8 +19880,11 @@ impl<'a> Parser<'a> {
                     BorrowedToken::Number(w, false) => Ok(Ident::with_span(next_token.span, w)),
                     _ => self.expected("placeholder", next_token),
                 }?;
-                Ok(Value::Placeholder(tok.to_string() + &ident.value)
-                    .with_span(Span::new(span.start, ident.span.end)))
+                Ok(Value::Placeholder(AstStr::from_source_offset(
+                    (tok.to_string() + &ident.value).into(),
+                    span.start.byte_offset(),
+                ))
+                .with_span(Span::new(span.start, ident.span.end)))
             }
             unexpected => self.expected(
                 "a value",
@@ -18512,16 +19896,36 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn maybe_concat_string_literal(&self, mut str: String) -> String {
+    /// Build the value of a numeric literal token. The text is parsed into a
+    /// bigdecimal when the bigdecimal feature is enabled, and is otherwise
+    /// kept as is, borrowed from the document source when one is being parsed.
+    #[cfg(feature = "bigdecimal")]
+    fn parse_number_literal(
+        &self,
+        n: String,
+        span: Span,
+    ) -> Result<Box<bigdecimal::BigDecimal>, ParserError> {
+        self.new_box(Self::parse(n, span.start)?)
+    }
+
+    #[cfg(not(feature = "bigdecimal"))]
+    fn parse_number_literal(&self, n: String, span: Span) -> Result<AstStr, ParserError> {
+        Ok(AstStr::from_source_offset(
+            n.into(),
+            span.start.byte_offset(),
+        ))
+    }
+
+    fn maybe_concat_string_literal(&self, mut str: Cow<'a, str>) -> Cow<'a, str> {
         if self.features.supports_string_literal_concatenation {
             loop {
                 match &self.peek_token_ref().token {
                     BorrowedToken::SingleQuotedString(s) => {
-                        str.push_str(s.as_ref());
+                        str.to_mut().push_str(s.as_ref());
                         self.advance_token();
                     }
                     BorrowedToken::DoubleQuotedString(s) => {
-                        str.push_str(s);
+                        str.to_mut().push_str(s);
                         self.advance_token();
                     }
                     _ => break,
@@ -18553,15 +19957,15 @@ impl<'a> Parser<'a> {
         match next_token.token {
             BorrowedToken::Plus => Ok(Expr::UnaryOp {
                 op: UnaryOperator::Plus,
-                expr: Box::new(Expr::Value(self.parse_number_value()?)),
+                expr: self.new_box(Expr::Value(self.new_box(self.parse_number_value()?)?))?,
             }),
             BorrowedToken::Minus => Ok(Expr::UnaryOp {
                 op: UnaryOperator::Minus,
-                expr: Box::new(Expr::Value(self.parse_number_value()?)),
+                expr: self.new_box(Expr::Value(self.new_box(self.parse_number_value()?)?))?,
             }),
             _ => {
                 self.prev_token();
-                Ok(Expr::Value(self.parse_number_value()?))
+                Ok(Expr::Value(self.new_box(self.parse_number_value()?)?))
             }
         }
     }
@@ -18570,14 +19974,14 @@ impl<'a> Parser<'a> {
         let next_token = self.next_token();
         let span = next_token.span;
         match next_token.token {
-            BorrowedToken::SingleQuotedString(ref s) => Ok(Expr::Value(
-                Value::SingleQuotedString(s.to_string()).with_span(span),
+            BorrowedToken::SingleQuotedString(s) => Ok(Expr::Value(
+                self.new_box(Value::SingleQuotedString(s.into()).with_span(span))?,
             )),
             BorrowedToken::DoubleQuotedString(ref s) => Ok(Expr::Value(
-                Value::DoubleQuotedString(s.to_string()).with_span(span),
+                self.new_box(Value::DoubleQuotedString(s.to_string()).with_span(span))?,
             )),
             BorrowedToken::HexStringLiteral(ref s) => Ok(Expr::Value(
-                Value::HexStringLiteral(s.to_string()).with_span(span),
+                self.new_box(Value::HexStringLiteral(s.to_string()).with_span(span))?,
             )),
             unexpected => self.expected(
                 "a string value",
@@ -18607,11 +20011,13 @@ impl<'a> Parser<'a> {
             BorrowedToken::DollarQuotedString(s) if dialect_of!(self is PostgreSqlDialect | OracleDialect) =>
             {
                 self.advance_token();
-                Ok(Expr::Value(Value::DollarQuotedString(s).with_span(span)))
+                Ok(Expr::Value(self.new_box(
+                    Value::DollarQuotedString(self.new_box(s)?).with_span(span),
+                )?))
             }
-            _ => Ok(Expr::Value(
-                Value::SingleQuotedString(self.parse_literal_string()?).with_span(span),
-            )),
+            _ => Ok(Expr::Value(self.new_box(
+                Value::SingleQuotedString(self.parse_literal_string()?.into()).with_span(span),
+            )?)),
         }
     }
 
@@ -18653,7 +20059,7 @@ impl<'a> Parser<'a> {
         })?;
         if self.parse_keyword(Keyword::NORMALIZED) {
             return Ok(Expr::IsNormalized {
-                expr: Box::new(expr),
+                expr: self.new_box(expr)?,
                 form: normalized_form,
                 negated: neg,
             });
@@ -18693,7 +20099,7 @@ impl<'a> Parser<'a> {
         };
 
         Ok(Expr::IsJson {
-            expr: Box::new(expr),
+            expr: self.new_box(expr)?,
             negated,
             json_predicate_type,
             unique_keys,
@@ -18715,10 +20121,10 @@ impl<'a> Parser<'a> {
     pub fn parse_data_type(&self) -> Result<DataType, ParserError> {
         let (ty, trailing_bracket) = self.parse_data_type_helper()?;
         if trailing_bracket.0 {
-            return parser_err!(
-                format!("unmatched > after parsing data type {ty}"),
+            return Err(ParserError::ParserError(format!(
+                "unmatched > after parsing data type {ty}{}",
                 self.peek_token()
-            );
+            )));
         }
 
         Ok(ty)
@@ -18737,7 +20143,7 @@ impl<'a> Parser<'a> {
                 Keyword::BOOL => Ok(DataType::Bool),
                 Keyword::SETOF => {
                     let inner = self.parse_data_type()?;
-                    Ok(DataType::SetOf(Box::new(inner)))
+                    Ok(DataType::SetOf(self.new_box(inner)?))
                 }
                 Keyword::FLOAT => {
                     let precision = self.parse_exact_number_optional_precision_scale()?;
@@ -19062,9 +20468,9 @@ impl<'a> Parser<'a> {
                     self.expect_token(&BorrowedToken::Lt)?;
                     let (inside_type, _trailing_bracket) = self.parse_data_type_helper()?;
                     trailing_bracket = self.expect_closing_angle_bracket(_trailing_bracket)?;
-                    Ok(DataType::Array(ArrayElemTypeDef::AngleBracket(Box::new(
-                        inside_type,
-                    ))))
+                    Ok(DataType::Array(ArrayElemTypeDef::AngleBracket(
+                        self.new_box(inside_type)?,
+                    )))
                 }
                 Keyword::TRIGGER => Ok(DataType::Trigger),
                 Keyword::TABLE => {
@@ -19115,7 +20521,7 @@ impl<'a> Parser<'a> {
                 // Parse optional array data type size
                 let size = self.maybe_parse(|p| p.parse_literal_uint())?;
                 self.expect_token(&BorrowedToken::RBracket)?;
-                data = DataType::Array(ArrayElemTypeDef::SquareBracket(Box::new(data), size))
+                data = DataType::Array(ArrayElemTypeDef::SquareBracket(self.new_box(data)?, size))
             }
         }
 
@@ -19129,7 +20535,7 @@ impl<'a> Parser<'a> {
             } else {
                 None
             };
-            data = DataType::Array(ArrayElemTypeDef::SquareBracket(Box::new(data), size))
+            data = DataType::Array(ArrayElemTypeDef::SquareBracket(self.new_box(data)?, size))
         }
 
         // Parse optional MDARRAY keyword suffix (SQL/MDA: "INTEGER MDARRAY[x, y]")
@@ -19138,7 +20544,7 @@ impl<'a> Parser<'a> {
             let dimensions = self.parse_comma_separated(Parser::parse_identifier)?;
             self.expect_token(&BorrowedToken::RBracket)?;
             data = DataType::MdArray(MdArrayTypeDef {
-                element_type: Box::new(data),
+                element_type: self.new_box(data)?,
                 dimensions,
             });
         }
@@ -19206,7 +20612,7 @@ impl<'a> Parser<'a> {
                 let columns = self.parse_table_alias_column_defs()?;
                 Ok(Some(TableAlias {
                     name,
-                    columns,
+                    columns: self.new_list(columns)?,
                     implicit: !explicit_as,
                 }))
             }
@@ -19339,6 +20745,7 @@ impl<'a> Parser<'a> {
 
     pub fn parse_optional_group_by(&self) -> Result<Option<GroupByExpr>, ParserError> {
         if self.parse_keywords(&[Keyword::GROUP, Keyword::BY]) {
+            let _guard = self.enter_context(ParseContext::GroupByClause);
             if self.dialect.is::<OracleDialect>() && self.parse_keyword(Keyword::VECTOR) {
                 self.expect_token(&BorrowedToken::LParen)?;
                 let vectors = self.parse_comma_separated(|parser| {
@@ -19402,7 +20809,7 @@ impl<'a> Parser<'a> {
             };
             let group_by = match expressions {
                 None => GroupByExpr::All(modifiers),
-                Some(exprs) => GroupByExpr::Expressions(exprs, modifiers),
+                Some(exprs) => GroupByExpr::Expressions(self.new_list(exprs)?, modifiers),
             };
             Ok(Some(group_by))
         } else {
@@ -19412,6 +20819,7 @@ impl<'a> Parser<'a> {
 
     pub fn parse_optional_order_by(&self) -> Result<Option<OrderBy>, ParserError> {
         if self.parse_keyword(Keyword::ORDER) {
+            let _guard = self.enter_context(ParseContext::OrderByClause);
             let siblings = self.parse_keyword(Keyword::SIBLINGS);
             self.expect_keyword(Keyword::BY)?;
             let order_by = if !siblings
@@ -19424,7 +20832,8 @@ impl<'a> Parser<'a> {
                     interpolate: None,
                 }
             } else {
-                let exprs = self.parse_comma_separated(Parser::parse_order_by_expr)?;
+                let exprs =
+                    self.new_list(self.parse_comma_separated(Parser::parse_order_by_expr)?)?;
                 OrderBy {
                     kind: if siblings {
                         OrderByKind::Siblings(exprs)
@@ -19527,7 +20936,7 @@ impl<'a> Parser<'a> {
             if allow_wildcards && self.peek_token().token == BorrowedToken::Mul {
                 let span = self.next_token().span;
                 parts.push(ObjectNamePart::Identifier(Ident {
-                    value: BorrowedToken::Mul.to_string(),
+                    value: "*".into(),
                     quote_style: None,
                     span,
                 }));
@@ -19568,7 +20977,7 @@ impl<'a> Parser<'a> {
             }
         }
 
-        Ok(ObjectName(parts))
+        Ok(ObjectName(self.new_list(parts)?))
     }
 
     /// Parse identifiers
@@ -19691,11 +21100,22 @@ impl<'a> Parser<'a> {
             } else {
                 quote_style
             };
-        let value = self
-            .dialect
-            .canonicalize_identifier(word.value.as_ref(), canonical_quote_style);
+        let value = match word.value {
+            Cow::Borrowed(value) => self
+                .dialect
+                .canonicalize_identifier(value, canonical_quote_style),
+            Cow::Owned(value) => {
+                match self
+                    .dialect
+                    .canonicalize_identifier(&value, canonical_quote_style)
+                {
+                    Cow::Borrowed(_) => Cow::Owned(value),
+                    Cow::Owned(canonical) => Cow::Owned(canonical),
+                }
+            }
+        };
         Ident {
-            value,
+            value: value.into(),
             quote_style,
             span,
         }
@@ -19779,9 +21199,9 @@ impl<'a> Parser<'a> {
         allow_empty: bool,
     ) -> Result<Vec<Expr>, ParserError> {
         self.parse_parenthesized_column_list_inner(optional, allow_empty, |p| {
-            Ok(Expr::CompoundIdentifier(
+            Ok(Expr::CompoundIdentifier(p.new_list(
                 p.parse_period_separated(|p| p.parse_identifier())?,
-            ))
+            )?))
         })
     }
 
@@ -19850,7 +21270,7 @@ impl<'a> Parser<'a> {
             if self.parse_keywords(&[Keyword::WITHOUT, Keyword::OVERLAPS]) {
                 // This is the period name (last item)
                 let period_ident = match col.column.expr {
-                    Expr::Identifier(ident) => ident,
+                    Expr::Identifier(ident) => box_into_inner(ident),
                     _ => {
                         return self.expected(
                             "period identifier before WITHOUT OVERLAPS",
@@ -20191,16 +21611,16 @@ impl<'a> Parser<'a> {
         &self,
         delete_token: TokenWithSpan,
     ) -> Result<Box<SetExpr>, ParserError> {
-        Ok(Box::new(SetExpr::Delete(Box::new(
-            self.parse_delete(delete_token)?,
-        ))))
+        self.new_box(SetExpr::Delete(
+            self.new_box(self.parse_delete(delete_token)?)?,
+        ))
     }
 
     /// Parse a MERGE statement, returning a `Box`ed SetExpr
     ///
     /// This is used to reduce the size of the stack frames in debug builds
     fn parse_merge_setexpr_boxed(&self) -> Result<Box<SetExpr>, ParserError> {
-        Ok(Box::new(SetExpr::Merge(Box::new(self.parse_merge()?))))
+        self.new_box(SetExpr::Merge(self.new_box(self.parse_merge()?)?))
     }
 
     pub fn parse_delete(&self, delete_token: TokenWithSpan) -> Result<Statement, ParserError> {
@@ -20235,6 +21655,7 @@ impl<'a> Parser<'a> {
             None
         };
         let selection = if self.parse_keyword(Keyword::WHERE) {
+            let _guard = self.enter_context(ParseContext::WhereClause);
             Some(self.parse_expr()?)
         } else {
             None
@@ -20255,21 +21676,22 @@ impl<'a> Parser<'a> {
             None
         };
 
-        Ok(Statement::Delete(Delete {
+        Ok(Statement::Delete(self.new_box(Delete {
             delete_token: delete_token.into(),
             tables,
             from: if with_from_keyword {
-                FromTable::WithFromKeyword(from)
+                FromTable::WithFromKeyword(self.new_list(from)?)
             } else {
-                FromTable::WithoutKeyword(from)
+                FromTable::WithoutKeyword(self.new_list(from)?)
             },
             for_portion_of,
-            using,
+            using: using.map(|using| self.new_list(using)).transpose()?,
             selection,
             returning,
             order_by,
             limit,
-        }))
+            comments: Comments::default(),
+        })?))
     }
 
     // KILL [CONNECTION | QUERY | MUTATION] processlist_id
@@ -20344,7 +21766,7 @@ impl<'a> Parser<'a> {
                 query_plan,
                 estimate,
                 rewrite,
-                statement: Box::new(statement),
+                statement: self.new_box(statement)?,
                 format,
                 options,
             }),
@@ -20410,70 +21832,73 @@ impl<'a> Parser<'a> {
             let search = self.parse_cte_search_clause()?;
             let cycle = self.parse_cte_cycle_clause()?;
 
-            Some(Box::new(With {
+            Some(self.new_box(With {
                 with_token: with_token.into(),
                 recursive,
                 oracle_declarations,
-                cte_tables,
+                cte_tables: self.new_list(cte_tables)?,
                 search,
                 cycle,
-            }))
+            })?)
         } else {
             None
         };
         if self.parse_keyword(Keyword::INSERT) {
-            Ok(Query {
+            Ok(self.new_box(Query {
                 with,
                 body: self.parse_insert_setexpr_boxed(self.get_current_token().clone())?,
                 order_by: None,
                 limit_clause: None,
                 fetch: None,
-                locks: vec![],
+                locks: AstVec::new(),
                 for_clause: None,
-            }
-            .into())
+                comments: Comments::default(),
+            })?)
         } else if self.parse_keyword(Keyword::UPDATE) {
-            Ok(Query {
+            Ok(self.new_box(Query {
                 with,
                 body: self.parse_update_setexpr_boxed(self.get_current_token().clone())?,
                 order_by: None,
                 limit_clause: None,
                 fetch: None,
-                locks: vec![],
+                locks: AstVec::new(),
                 for_clause: None,
-            }
-            .into())
+                comments: Comments::default(),
+            })?)
         } else if self.parse_keyword(Keyword::DELETE) {
-            Ok(Query {
+            Ok(self.new_box(Query {
                 with,
                 body: self.parse_delete_setexpr_boxed(self.get_current_token().clone())?,
                 limit_clause: None,
                 order_by: None,
                 fetch: None,
-                locks: vec![],
+                locks: AstVec::new(),
                 for_clause: None,
-            }
-            .into())
+                comments: Comments::default(),
+            })?)
         } else if self.parse_keyword(Keyword::MERGE) {
-            Ok(Query {
+            Ok(self.new_box(Query {
                 with,
                 body: self.parse_merge_setexpr_boxed()?,
                 limit_clause: None,
                 order_by: None,
                 fetch: None,
-                locks: vec![],
+                locks: AstVec::new(),
                 for_clause: None,
-            }
-            .into())
+                comments: Comments::default(),
+            })?)
         } else {
             let body = self.parse_query_body(self.dialect.prec_unknown())?;
 
             let order_by = self.parse_optional_order_by()?;
 
-            let limit_clause = self.parse_optional_limit_clause()?.map(Box::new);
+            let limit_clause = self
+                .parse_optional_limit_clause()?
+                .map(|value| self.new_box(value))
+                .transpose()?;
 
             let fetch = if self.parse_keyword(Keyword::FETCH) {
-                Some(Box::new(self.parse_fetch()?))
+                Some(self.new_box(self.parse_fetch()?)?)
             } else {
                 None
             };
@@ -20488,16 +21913,16 @@ impl<'a> Parser<'a> {
                     locks.push(self.parse_lock()?);
                 }
             }
-            Ok(Query {
+            Ok(self.new_box(Query {
                 with,
                 body,
                 order_by,
                 limit_clause,
                 fetch,
-                locks,
+                locks: self.new_list(locks)?,
                 for_clause,
-            }
-            .into())
+                comments: Comments::default(),
+            })?)
         }
     }
 
@@ -20623,7 +22048,7 @@ impl<'a> Parser<'a> {
 
             let alias = TableAlias {
                 name,
-                columns: vec![],
+                columns: AstVec::new(),
                 implicit: false,
             };
             Cte {
@@ -20651,7 +22076,7 @@ impl<'a> Parser<'a> {
 
             let alias = TableAlias {
                 name,
-                columns,
+                columns: self.new_list(columns)?,
                 implicit: false,
             };
             Cte {
@@ -20758,11 +22183,12 @@ impl<'a> Parser<'a> {
         let expr = if self.peek_keyword(Keyword::SELECT)
             || (self.peek_keyword(Keyword::FROM) && self.features.supports_from_first_select)
         {
-            let select = self.parse_select().map(Box::new)?;
+            let select = self.parse_select().and_then(|value| self.new_box(value))?;
             if dialect_of!(self is OracleDialect) && self.parse_keyword(Keyword::MODEL) {
+                let model = self.parse_oracle_model_clause()?;
                 SetExpr::OracleModel {
                     select,
-                    model: self.parse_oracle_model_clause()?,
+                    model: self.new_box(model)?,
                 }
             } else {
                 SetExpr::Select(select)
@@ -20779,7 +22205,7 @@ impl<'a> Parser<'a> {
             let is_mysql = dialect_of!(self is MySqlDialect);
             SetExpr::Values(self.parse_values(is_mysql, true)?)
         } else if self.parse_keyword(Keyword::TABLE) {
-            SetExpr::Table(Box::new(self.parse_as_table()?))
+            SetExpr::Table(self.new_box(self.parse_as_table()?)?)
         } else {
             return self.expected(
                 "SELECT, VALUES, or a subquery in the query body",
@@ -21173,14 +22599,14 @@ impl<'a> Parser<'a> {
             self.advance_token(); // skip past the set operator
             let set_quantifier = self.parse_set_quantifier(&op);
             expr = SetExpr::SetOperation {
-                left: Box::new(expr),
+                left: self.new_box(expr)?,
                 op: op.unwrap(),
                 set_quantifier,
                 right: self.parse_query_body(next_precedence)?,
             };
         }
 
-        Ok(expr.into())
+        self.new_box(expr)
     }
 
     pub fn parse_set_operator<'b>(&self, token: &BorrowedToken<'b>) -> Option<SetOperator> {
@@ -21236,11 +22662,11 @@ impl<'a> Parser<'a> {
                     distinct: None,
                     top: None,
                     top_before_distinct: false,
-                    projection: vec![],
+                    projection: AstVec::new(),
                     into: None,
-                    from,
+                    from: self.new_list(from)?,
                     selection: None,
-                    group_by: GroupByExpr::Expressions(vec![], vec![]),
+                    group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
                     having: None,
                     qualify: None,
                     named_window: vec![],
@@ -21256,21 +22682,23 @@ impl<'a> Parser<'a> {
         let mut top_before_distinct = false;
         let mut top = None;
         if self.features.supports_top_before_distinct && self.parse_keyword(Keyword::TOP) {
-            top = Some(Box::new(self.parse_top()?));
+            top = Some(self.new_box(self.parse_top()?)?);
             top_before_distinct = true;
         }
         let distinct = self.parse_all_or_distinct()?;
         if !self.features.supports_top_before_distinct {
             if self.parse_keyword(Keyword::SKIP) {
                 let skip = self.parse_top_quantity()?;
-                if self.parse_keyword(Keyword::TOP) {
-                    top = Some(Box::new(self.parse_top()?));
-                }
-                let top = top.get_or_insert_with(|| Box::new(Top::empty()));
-                top.skip = Some(skip);
-                top.skip_before_top = true;
+                let mut skip_top = if self.parse_keyword(Keyword::TOP) {
+                    self.parse_top()?
+                } else {
+                    Top::empty()
+                };
+                skip_top.skip = Some(skip);
+                skip_top.skip_before_top = true;
+                top = Some(self.new_box(skip_top)?);
             } else if self.parse_keyword(Keyword::TOP) {
-                top = Some(Box::new(self.parse_top()?));
+                top = Some(self.new_box(self.parse_top()?)?);
                 if self.parse_keyword(Keyword::SKIP) {
                     top.as_mut().expect("TOP was just parsed").skip =
                         Some(self.parse_top_quantity()?);
@@ -21310,24 +22738,26 @@ impl<'a> Parser<'a> {
         };
 
         let selection = if self.parse_keyword(Keyword::WHERE) {
-            Some(Box::new(self.parse_expr()?))
+            let _guard = self.enter_context(ParseContext::WhereClause);
+            Some(self.new_box(self.parse_expr()?)?)
         } else {
             None
         };
 
         let group_by = self
             .parse_optional_group_by()?
-            .unwrap_or_else(|| GroupByExpr::Expressions(vec![], vec![]));
+            .unwrap_or_else(|| GroupByExpr::Expressions(AstVec::new(), vec![]));
 
         let having = if self.parse_keyword(Keyword::HAVING) {
-            Some(Box::new(self.parse_expr()?))
+            let _guard = self.enter_context(ParseContext::HavingClause);
+            Some(self.new_box(self.parse_expr()?)?)
         } else {
             None
         };
 
         let qualify = if self.dialect.is::<OracleDialect>() && self.parse_keyword(Keyword::QUALIFY)
         {
-            Some(Box::new(self.parse_expr()?))
+            Some(self.new_box(self.parse_expr()?)?)
         } else {
             None
         };
@@ -21344,7 +22774,7 @@ impl<'a> Parser<'a> {
                 .is_some()
         {
             self.prev_token();
-            Some(Box::new(self.parse_connect_by()?))
+            Some(self.new_box(self.parse_connect_by()?)?)
         } else {
             None
         };
@@ -21359,9 +22789,9 @@ impl<'a> Parser<'a> {
             distinct,
             top,
             top_before_distinct,
-            projection,
+            projection: self.new_list(projection)?,
             into,
-            from,
+            from: self.new_list(from)?,
             selection,
             group_by,
             having,
@@ -21475,13 +22905,13 @@ impl<'a> Parser<'a> {
         } else {
             Some(self.parse_identifier()?)
         };
-        Ok(Statement::Set(SetStatement {
+        Ok(Statement::Set(self.new_box(SetStatement {
             token,
             inner: Set::SetRole {
                 context_modifier: modifier,
                 role_name,
             },
-        }))
+        })?))
     }
 
     fn parse_set_constraints(&self) -> Result<Statement, ParserError> {
@@ -21506,14 +22936,14 @@ impl<'a> Parser<'a> {
             return self.expected("DEFERRED or IMMEDIATE", self.peek_token());
         };
 
-        Ok(Statement::Set(SetStatement {
+        Ok(Statement::Set(self.new_box(SetStatement {
             token,
             inner: Set::SetConstraints {
                 all,
                 constraints,
                 mode,
             },
-        }))
+        })?))
     }
 
     fn parse_set_values(&self, parenthesized_assignment: bool) -> Result<Vec<Expr>, ParserError> {
@@ -21660,11 +23090,11 @@ impl<'a> Parser<'a> {
         }
 
         // Helper to wrap Set in SetStatement
-        let wrap_set = |inner: Set| -> Statement {
-            Statement::Set(SetStatement {
+        let wrap_set = |inner: Set| -> Result<Statement, ParserError> {
+            Ok(Statement::Set(self.new_box(SetStatement {
                 token: token.clone(),
                 inner,
-            })
+            })?))
         };
 
         // Handle special cases first
@@ -21672,23 +23102,23 @@ impl<'a> Parser<'a> {
             || self.parse_keyword(Keyword::TIMEZONE)
         {
             if self.consume_token(&BorrowedToken::Eq) || self.parse_keyword(Keyword::TO) {
-                return Ok(wrap_set(Set::SingleAssignment {
+                return wrap_set(Set::SingleAssignment {
                     scope,
-                    variable: ObjectName::from(vec!["TIMEZONE".into()]),
+                    variable: self.object_name(vec!["TIMEZONE".into()])?,
                     values: self.parse_set_values(false)?,
-                }));
+                });
             } else {
                 // A shorthand alias for SET TIME ZONE that doesn't require
                 // the assignment operator. It's originally PostgreSQL specific,
                 // but we allow it for all the dialects
-                return Ok(wrap_set(Set::SetTimeZone {
+                return wrap_set(Set::SetTimeZone {
                     local: scope == Some(ContextModifier::Local),
                     value: self.parse_expr()?,
-                }));
+                });
             }
         } else if self.features.supports_set_names && self.parse_keyword(Keyword::NAMES) {
             if self.parse_keyword(Keyword::DEFAULT) {
-                return Ok(wrap_set(Set::SetNamesDefault {}));
+                return wrap_set(Set::SetNamesDefault {});
             }
             let charset_name = self.parse_identifier()?;
             let collation_name = if self.parse_one_of_keywords(&[Keyword::COLLATE]).is_some() {
@@ -21697,31 +23127,31 @@ impl<'a> Parser<'a> {
                 None
             };
 
-            return Ok(wrap_set(Set::SetNames {
+            return wrap_set(Set::SetNames {
                 charset_name,
                 collation_name,
-            }));
+            });
         } else if self.parse_keyword(Keyword::CHARACTERISTICS) {
             self.expect_keywords(&[Keyword::AS, Keyword::TRANSACTION])?;
-            return Ok(wrap_set(Set::SetTransaction {
+            return wrap_set(Set::SetTransaction {
                 modes: self.parse_transaction_modes()?,
                 snapshot: None,
                 session: true,
-            }));
+            });
         } else if self.parse_keyword(Keyword::TRANSACTION) {
             if self.parse_keyword(Keyword::SNAPSHOT) {
                 let snapshot_id = self.parse_value()?.value;
-                return Ok(wrap_set(Set::SetTransaction {
+                return wrap_set(Set::SetTransaction {
                     modes: vec![],
                     snapshot: Some(snapshot_id),
                     session: false,
-                }));
+                });
             }
-            return Ok(wrap_set(Set::SetTransaction {
+            return wrap_set(Set::SetTransaction {
                 modes: self.parse_transaction_modes()?,
                 snapshot: None,
                 session: false,
-            }));
+            });
         } else if self.parse_keyword(Keyword::AUTHORIZATION) {
             let auth_value = if self.parse_keyword(Keyword::DEFAULT) {
                 SetSessionAuthorizationParamKind::Default
@@ -21729,12 +23159,10 @@ impl<'a> Parser<'a> {
                 let value = self.parse_identifier()?;
                 SetSessionAuthorizationParamKind::User(value)
             };
-            return Ok(wrap_set(Set::SetSessionAuthorization(
-                SetSessionAuthorizationParam {
-                    scope: scope.expect("SET ... AUTHORIZATION must have a scope"),
-                    kind: auth_value,
-                },
-            )));
+            return wrap_set(Set::SetSessionAuthorization(SetSessionAuthorizationParam {
+                scope: scope.expect("SET ... AUTHORIZATION must have a scope"),
+                kind: auth_value,
+            }));
         }
 
         if self.features.supports_comma_separated_set_assignments {
@@ -21746,18 +23174,18 @@ impl<'a> Parser<'a> {
                 .maybe_parse(|parser| parser.parse_comma_separated(Parser::parse_set_assignment))?
             {
                 return if assignments.len() > 1 {
-                    Ok(wrap_set(Set::MultipleAssignments { assignments }))
+                    wrap_set(Set::MultipleAssignments { assignments })
                 } else {
                     let SetAssignment { scope, name, value } =
                         assignments.into_iter().next().ok_or_else(|| {
                             ParserError::ParserError("Expected at least one assignment".to_string())
                         })?;
 
-                    Ok(wrap_set(Set::SingleAssignment {
+                    wrap_set(Set::SingleAssignment {
                         scope,
                         variable: name,
                         values: vec![value],
-                    }))
+                    })
                 };
             }
         }
@@ -21768,8 +23196,8 @@ impl<'a> Parser<'a> {
             let vars = OneOrManyWithParens::Many(
                 self.parse_comma_separated(|parser: &Parser<'a>| parser.parse_identifier())?
                     .into_iter()
-                    .map(|ident| ObjectName::from(vec![ident]))
-                    .collect(),
+                    .map(|ident| self.object_name(vec![ident]))
+                    .collect::<Result<_, _>>()?,
             );
             self.expect_token(&BorrowedToken::RParen)?;
             vars
@@ -21790,7 +23218,7 @@ impl<'a> Parser<'a> {
                 },
             };
 
-            return Ok(wrap_set(stmt));
+            return wrap_set(stmt);
         }
 
         if self.features.supports_set_stmt_without_operator {
@@ -21803,11 +23231,11 @@ impl<'a> Parser<'a> {
 
     pub fn parse_set_session_params(&self) -> Result<Statement, ParserError> {
         let token = self.attached_token_from_current();
-        let wrap_set = |inner: Set| -> Statement {
-            Statement::Set(SetStatement {
+        let wrap_set = |inner: Set| -> Result<Statement, ParserError> {
+            Ok(Statement::Set(self.new_box(SetStatement {
                 token: token.clone(),
                 inner,
-            })
+            })?))
         };
 
         if self.parse_keyword(Keyword::STATISTICS) {
@@ -21824,14 +23252,14 @@ impl<'a> Parser<'a> {
                 _ => return self.expected("IO, PROFILE, TIME or XML", self.peek_token()),
             };
             let value = self.parse_session_param_value()?;
-            Ok(wrap_set(Set::SetSessionParam(
-                SetSessionParamKind::Statistics(SetSessionParamStatistics { topic, value }),
+            wrap_set(Set::SetSessionParam(SetSessionParamKind::Statistics(
+                SetSessionParamStatistics { topic, value },
             )))
         } else if self.parse_keyword(Keyword::IDENTITY_INSERT) {
             let obj = self.parse_object_name(false)?;
             let value = self.parse_session_param_value()?;
-            Ok(wrap_set(Set::SetSessionParam(
-                SetSessionParamKind::IdentityInsert(SetSessionParamIdentityInsert { obj, value }),
+            wrap_set(Set::SetSessionParam(SetSessionParamKind::IdentityInsert(
+                SetSessionParamIdentityInsert { obj, value },
             )))
         } else if self.parse_keyword(Keyword::OFFSETS) {
             let keywords = self.parse_comma_separated(|parser| {
@@ -21842,8 +23270,8 @@ impl<'a> Parser<'a> {
                 }
             })?;
             let value = self.parse_session_param_value()?;
-            Ok(wrap_set(Set::SetSessionParam(
-                SetSessionParamKind::Offsets(SetSessionParamOffsets { keywords, value }),
+            wrap_set(Set::SetSessionParam(SetSessionParamKind::Offsets(
+                SetSessionParamOffsets { keywords, value },
             )))
         } else {
             let names = self.parse_comma_separated(|parser| {
@@ -21854,8 +23282,8 @@ impl<'a> Parser<'a> {
                 }
             })?;
             let value = self.parse_expr()?.to_string();
-            Ok(wrap_set(Set::SetSessionParam(
-                SetSessionParamKind::Generic(SetSessionParamGeneric { names, value }),
+            wrap_set(Set::SetSessionParam(SetSessionParamKind::Generic(
+                SetSessionParamGeneric { names, value },
             )))
         }
     }
@@ -21904,7 +23332,10 @@ impl<'a> Parser<'a> {
         {
             Ok(Statement::ShowVariables {
                 show_token,
-                filter: self.parse_show_statement_filter()?,
+                filter: self
+                    .parse_show_statement_filter()?
+                    .map(|filter| self.new_box(filter))
+                    .transpose()?,
                 session,
                 global,
             })
@@ -21913,7 +23344,10 @@ impl<'a> Parser<'a> {
         {
             Ok(Statement::ShowStatus {
                 token: show_token,
-                filter: self.parse_show_statement_filter()?,
+                filter: self
+                    .parse_show_statement_filter()?
+                    .map(|filter| self.new_box(filter))
+                    .transpose()?,
                 session,
                 global,
             })
@@ -21954,11 +23388,11 @@ impl<'a> Parser<'a> {
         is_shorthand: bool,
     ) -> Result<Statement, ParserError> {
         // parse one of keywords
-        Ok(Statement::ShowCharset(ShowCharset {
+        Ok(Statement::ShowCharset(self.new_box(ShowCharset {
             token,
             is_shorthand,
             filter: self.parse_show_statement_filter()?,
-        }))
+        })?))
     }
 
     fn parse_show_databases(
@@ -21972,7 +23406,7 @@ impl<'a> Parser<'a> {
             show_token,
             terse,
             history,
-            show_options,
+            show_options: self.new_box(show_options)?,
         })
     }
 
@@ -21987,7 +23421,7 @@ impl<'a> Parser<'a> {
             show_token,
             terse,
             history,
-            show_options,
+            show_options: self.new_box(show_options)?,
         })
     }
 
@@ -22031,7 +23465,7 @@ impl<'a> Parser<'a> {
             show_token,
             extended,
             full,
-            show_options,
+            show_options: self.new_box(show_options)?,
         })
     }
 
@@ -22052,7 +23486,7 @@ impl<'a> Parser<'a> {
             extended,
             full,
             external,
-            show_options,
+            show_options: self.new_box(show_options)?,
         })
     }
 
@@ -22061,7 +23495,7 @@ impl<'a> Parser<'a> {
         Ok(Statement::ShowViews {
             materialized,
             terse,
-            show_options,
+            show_options: self.new_box(show_options)?,
         })
     }
 
@@ -22069,7 +23503,10 @@ impl<'a> Parser<'a> {
         &self,
         show_token: AttachedToken,
     ) -> Result<Statement, ParserError> {
-        let filter = self.parse_show_statement_filter()?;
+        let filter = self
+            .parse_show_statement_filter()?
+            .map(|filter| self.new_box(filter))
+            .transpose()?;
         Ok(Statement::ShowFunctions { show_token, filter })
     }
 
@@ -22077,7 +23514,10 @@ impl<'a> Parser<'a> {
         &self,
         show_token: AttachedToken,
     ) -> Result<Statement, ParserError> {
-        let filter = self.parse_show_statement_filter()?;
+        let filter = self
+            .parse_show_statement_filter()?
+            .map(|filter| self.new_box(filter))
+            .transpose()?;
         Ok(Statement::ShowCollation { show_token, filter })
     }
 
@@ -22127,7 +23567,10 @@ impl<'a> Parser<'a> {
         // added to `RESERVED_FOR_TABLE_ALIAS`, otherwise they may be parsed as
         // a table alias.
         let joins = self.parse_joins()?;
-        Ok(TableWithJoins { relation, joins })
+        Ok(TableWithJoins {
+            relation,
+            joins: self.new_list(joins)?,
+        })
     }
 
     fn parse_joins(&self) -> Result<Vec<Join>, ParserError> {
@@ -22175,17 +23618,28 @@ impl<'a> Parser<'a> {
                     relation,
                     global,
                     join_operator: JoinOperator::AsOf {
-                        match_condition: Box::new(match_condition),
+                        match_condition: self.new_box(match_condition)?,
                         constraint: self.parse_join_constraint(false)?,
                     },
                 }
             } else {
                 let natural = self.parse_keyword(Keyword::NATURAL);
-                let peek_keyword = if let BorrowedToken::Word(w) = self.peek_token().token {
-                    w.keyword
-                } else {
-                    Keyword::NoKeyword
-                };
+                // Probe every join keyword so that each is recorded as a
+                // completion candidate.
+                let peek_keyword = [
+                    Keyword::JOIN,
+                    Keyword::INNER,
+                    Keyword::LEFT,
+                    Keyword::RIGHT,
+                    Keyword::FULL,
+                    Keyword::SEMI,
+                    Keyword::ANTI,
+                    Keyword::STRAIGHT_JOIN,
+                    Keyword::OUTER,
+                ]
+                .into_iter()
+                .find(|&keyword| self.peek_keyword(keyword))
+                .unwrap_or(Keyword::NoKeyword);
 
                 let join_operator_type = match peek_keyword {
                     Keyword::INNER | Keyword::JOIN => {
@@ -22280,7 +23734,10 @@ impl<'a> Parser<'a> {
                 {
                     let joins = self.parse_joins()?;
                     relation = TableFactor::NestedJoin {
-                        table_with_joins: Box::new(TableWithJoins { relation, joins }),
+                        table_with_joins: self.new_box(TableWithJoins {
+                            relation,
+                            joins: self.new_list(joins)?,
+                        })?,
                         alias: None,
                     };
                 }
@@ -22345,6 +23802,18 @@ impl<'a> Parser<'a> {
 
     /// A table name or a parenthesized subquery, followed by optional `[AS] alias`
     pub fn parse_table_factor(&self) -> Result<TableFactor, ParserError> {
+        let _guard = self.enter_context(ParseContext::TableName);
+        let start_index = self.index.get();
+        let table_factor = if self.tolerant.get() && self.at_tolerant_resume_keyword() {
+            self.expected_ref("identifier", self.peek_token_ref())
+        } else {
+            self.parse_table_factor_inner()
+        };
+        table_factor
+            .or_else(|error| Ok(TableFactor::Error(self.tolerate_error(start_index, error)?)))
+    }
+
+    fn parse_table_factor_inner(&self) -> Result<TableFactor, ParserError> {
         if self.parse_keyword(Keyword::LATERAL) {
             // LATERAL must always be followed by a subquery or table function.
             if self.consume_token(&BorrowedToken::LParen) {
@@ -22438,7 +23907,7 @@ impl<'a> Parser<'a> {
                 self.expect_token(&BorrowedToken::RParen)?;
                 let alias = self.maybe_parse_table_alias()?;
                 Ok(TableFactor::NestedJoin {
-                    table_with_joins: Box::new(table_and_joins),
+                    table_with_joins: self.new_box(table_and_joins)?,
                     alias,
                 }) // (A)
             } else if let TableFactor::NestedJoin {
@@ -22451,7 +23920,7 @@ impl<'a> Parser<'a> {
                 self.expect_token(&BorrowedToken::RParen)?;
                 let alias = self.maybe_parse_table_alias()?;
                 Ok(TableFactor::NestedJoin {
-                    table_with_joins: Box::new(table_and_joins),
+                    table_with_joins: self.new_box(table_and_joins)?,
                     alias,
                 })
             } else {
@@ -22557,7 +24026,10 @@ impl<'a> Parser<'a> {
             };
 
             // Parse potential version qualifier
-            let version = self.maybe_parse_table_version()?.map(Box::new);
+            let version = self
+                .maybe_parse_table_version()?
+                .map(|value| self.new_box(value))
+                .transpose()?;
 
             // Postgres, MSSQL: table-valued functions:
             let args = if self.consume_token(&BorrowedToken::LParen) {
@@ -22613,7 +24085,8 @@ impl<'a> Parser<'a> {
                                 if a == *lhs && b == *rhs {
                                     p.next_token();
                                     p.next_token();
-                                    let mut expr = Expr::Identifier(Ident::new(*repl));
+                                    let mut expr =
+                                        Expr::Identifier(self.new_box(Ident::new(*repl))?);
                                     // Fold any trailing infix operators (e.g.
                                     // `<= '5s'`) onto the synthetic identifier
                                     // so the hint round-trips as a single
@@ -22732,9 +24205,9 @@ impl<'a> Parser<'a> {
                 None => {
                     let next_token = self.next_token();
                     if let BorrowedToken::Word(w) = next_token.token {
-                        Expr::Value(
-                            Value::Placeholder(w.value.into_owned()).with_span(next_token.span),
-                        )
+                        Expr::Value(self.new_box(
+                            Value::Placeholder(w.value.into()).with_span(next_token.span),
+                        )?)
                     } else {
                         return parser_err!(
                             "Expecting number or byte length e.g. 100M",
@@ -22777,14 +24250,14 @@ impl<'a> Parser<'a> {
             None
         };
 
-        Ok(Box::new(TableSample {
+        self.new_box(TableSample {
             modifier,
             name,
             quantity,
             seed,
             bucket,
             offset,
-        }))
+        })
     }
 
     fn parse_table_sample_seed(
@@ -22956,7 +24429,7 @@ impl<'a> Parser<'a> {
 
         Ok(TableFactor::GraphTable {
             graph_name,
-            match_clause: Box::new(match_clause),
+            match_clause: self.new_box(match_clause)?,
             alias,
         })
     }
@@ -23224,7 +24697,7 @@ impl<'a> Parser<'a> {
 
             if quantifier.is_some() {
                 Ok(GraphPatternExpr::Group {
-                    pattern: Box::new(GraphPatternExpr::Alternation(patterns)),
+                    pattern: self.new_box(GraphPatternExpr::Alternation(patterns))?,
                     quantifier,
                 })
             } else {
@@ -23246,7 +24719,7 @@ impl<'a> Parser<'a> {
             };
 
             Ok(GraphPatternExpr::Group {
-                pattern: Box::new(first_pattern),
+                pattern: self.new_box(first_pattern)?,
                 quantifier,
             })
         }
@@ -23566,7 +25039,7 @@ impl<'a> Parser<'a> {
 
         while self.consume_token(&BorrowedToken::Pipe) {
             let right = self.parse_label_and()?;
-            left = LabelExpression::Or(Box::new(left), Box::new(right));
+            left = LabelExpression::Or(self.new_box(left)?, self.new_box(right)?);
         }
 
         Ok(left)
@@ -23577,7 +25050,7 @@ impl<'a> Parser<'a> {
 
         while self.consume_token(&BorrowedToken::Ampersand) {
             let right = self.parse_label_not()?;
-            left = LabelExpression::And(Box::new(left), Box::new(right));
+            left = LabelExpression::And(self.new_box(left)?, self.new_box(right)?);
         }
 
         Ok(left)
@@ -23586,7 +25059,7 @@ impl<'a> Parser<'a> {
     fn parse_label_not(&self) -> Result<LabelExpression, ParserError> {
         if self.consume_token(&BorrowedToken::ExclamationMark) {
             let expr = self.parse_label_primary()?;
-            Ok(LabelExpression::Not(Box::new(expr)))
+            Ok(LabelExpression::Not(self.new_box(expr)?))
         } else {
             self.parse_label_primary()
         }
@@ -23599,7 +25072,7 @@ impl<'a> Parser<'a> {
         } else if self.consume_token(&BorrowedToken::LParen) {
             let expr = self.parse_label_expression()?;
             self.expect_token(&BorrowedToken::RParen)?;
-            Ok(LabelExpression::Group(Box::new(expr)))
+            Ok(LabelExpression::Group(self.new_box(expr)?))
         } else {
             let ident = self.parse_identifier()?;
             Ok(LabelExpression::Label(ident))
@@ -23872,7 +25345,7 @@ impl<'a> Parser<'a> {
         let alias = self.maybe_parse_table_alias()?;
 
         Ok(TableFactor::MatchRecognize {
-            table: Box::new(table),
+            table: self.new_box(table)?,
             partition_by,
             order_by,
             measures,
@@ -23913,7 +25386,7 @@ impl<'a> Parser<'a> {
             BorrowedToken::LParen => {
                 let pattern = self.parse_pattern()?;
                 self.expect_token(&BorrowedToken::RParen)?;
-                Ok(MatchRecognizePattern::Group(Box::new(pattern)))
+                Ok(MatchRecognizePattern::Group(self.new_box(pattern)?))
             }
             _ => {
                 self.prev_token();
@@ -23976,7 +25449,7 @@ impl<'a> Parser<'a> {
                     break;
                 }
             };
-            pattern = MatchRecognizePattern::Repetition(Box::new(pattern), quantifier);
+            pattern = MatchRecognizePattern::Repetition(self.new_box(pattern)?, quantifier);
         }
         Ok(pattern)
     }
@@ -24218,7 +25691,7 @@ impl<'a> Parser<'a> {
             BorrowedToken::Word(w) => Ok(w.value),
             _ => self.expected("a function identifier", self.peek_token()),
         }?;
-        let expr = self.parse_function(ObjectName::from(vec![Ident::new(function_name)]))?;
+        let expr = self.parse_function(self.object_name(vec![Ident::new(function_name)])?)?;
         let alias = if self.parse_keyword(Keyword::AS) {
             Some(self.parse_identifier()?)
         } else {
@@ -24246,7 +25719,7 @@ impl<'a> Parser<'a> {
     /// let sql = r#"SUM("a") as "b""#;
     /// let mut parser = Parser::new(&PostgreSqlDialect {}).try_with_sql(sql)?;
     /// let expr_with_alias = parser.parse_expr_with_alias()?;
-    /// assert_eq!(Some("b".to_string()), expr_with_alias.alias.map(|x|x.value));
+    /// assert_eq!(Some("b".to_string()), expr_with_alias.alias.map(|x|x.value.into_string()));
     /// # Ok(())
     /// # }
     pub fn parse_expr_with_alias(&self) -> Result<ExprWithAlias, ParserError> {
@@ -24317,7 +25790,7 @@ impl<'a> Parser<'a> {
         self.expect_token(&BorrowedToken::RParen)?;
         let alias = self.maybe_parse_table_alias()?;
         Ok(TableFactor::Pivot {
-            table: Box::new(table),
+            table: self.new_box(table)?,
             xml,
             aggregate_functions,
             value_column,
@@ -24351,7 +25824,7 @@ impl<'a> Parser<'a> {
         self.expect_token(&BorrowedToken::RParen)?;
         let alias = self.maybe_parse_table_alias()?;
         Ok(TableFactor::Unpivot {
-            table: Box::new(table),
+            table: self.new_box(table)?,
             value,
             null_inclusion,
             name,
@@ -24365,10 +25838,10 @@ impl<'a> Parser<'a> {
             Ok(JoinConstraint::Natural)
         } else if self.parse_keyword(Keyword::ON) {
             let constraint = self.parse_expr()?;
-            Ok(JoinConstraint::On(Box::new(constraint)))
+            Ok(JoinConstraint::On(self.new_box(constraint)?))
         } else if self.parse_keyword(Keyword::USING) {
             let columns = self.parse_parenthesized_qualified_column_list(Mandatory, false)?;
-            Ok(JoinConstraint::Using(columns))
+            Ok(JoinConstraint::Using(self.new_list(columns)?))
         } else {
             Ok(JoinConstraint::None)
             //self.expected("ON, or USING after JOIN", self.peek_token())
@@ -24406,13 +25879,13 @@ impl<'a> Parser<'a> {
         };
 
         Ok(Statement::Grant {
-            grant_token,
+            grant_token: self.new_box(grant_token)?,
             privileges,
-            objects,
+            objects: objects.map(|v| self.new_box(v)).transpose()?,
             grantees,
             with_grant_option,
-            as_grantor,
-            granted_by,
+            as_grantor: as_grantor.map(|v| self.new_box(v)).transpose()?,
+            granted_by: granted_by.map(|v| self.new_box(v)).transpose()?,
         })
     }
 
@@ -24440,7 +25913,7 @@ impl<'a> Parser<'a> {
             roles,
             grantees,
             with_admin_option,
-            granted_by,
+            granted_by: granted_by.map(|v| self.new_box(v)).transpose()?,
         })
     }
 
@@ -24490,9 +25963,9 @@ impl<'a> Parser<'a> {
                     // <Namespace>:<GroupName> or <Namespace>:<UserName>
                     let ident = self.parse_identifier()?;
                     if let GranteeName::ObjectName(namespace) = name {
-                        name = GranteeName::ObjectName(ObjectName::from(vec![Ident::new(
-                            format!("{namespace}:{ident}"),
-                        )]));
+                        name = GranteeName::ObjectName(
+                            self.object_name(vec![Ident::new(format!("{namespace}:{ident}"))])?,
+                        );
                     };
                 }
                 Grantee {
@@ -24717,7 +26190,7 @@ impl<'a> Parser<'a> {
                     Some(Keyword::USER) => Some(GrantObjects::Users(objects?)),
                     Some(Keyword::CONNECTION) => Some(GrantObjects::Connections(objects?)),
                     kw @ (Some(Keyword::PROCEDURE) | Some(Keyword::FUNCTION)) => {
-                        if let Some(name) = objects?.first() {
+                        if let Some(name) = objects?.into_iter().next() {
                             self.parse_grant_procedure_or_function(name, &kw)?
                         } else {
                             self.expected("procedure or function name", self.peek_token())?
@@ -24739,7 +26212,7 @@ impl<'a> Parser<'a> {
 
     fn parse_grant_procedure_or_function(
         &self,
-        name: &ObjectName,
+        name: ObjectName,
         kw: &Option<Keyword>,
     ) -> Result<Option<GrantObjects>, ParserError> {
         let arg_types = if self.consume_token(&BorrowedToken::LParen) {
@@ -24750,14 +26223,8 @@ impl<'a> Parser<'a> {
             vec![]
         };
         match kw {
-            Some(Keyword::PROCEDURE) => Ok(Some(GrantObjects::Procedure {
-                name: name.clone(),
-                arg_types,
-            })),
-            Some(Keyword::FUNCTION) => Ok(Some(GrantObjects::Function {
-                name: name.clone(),
-                arg_types,
-            })),
+            Some(Keyword::PROCEDURE) => Ok(Some(GrantObjects::Procedure { name, arg_types })),
+            Some(Keyword::FUNCTION) => Ok(Some(GrantObjects::Function { name, arg_types })),
             _ => self.expected("procedure or function keywords", self.peek_token())?,
         }
     }
@@ -24920,14 +26387,14 @@ impl<'a> Parser<'a> {
             None
         };
 
-        Ok(Statement::Deny(DenyStatement {
+        Ok(Statement::Deny(self.new_box(DenyStatement {
             token,
             privileges,
             objects,
             grantees,
             cascade,
             granted_by,
-        }))
+        })?))
     }
 
     /// Parse a REVOKE statement
@@ -24970,11 +26437,11 @@ impl<'a> Parser<'a> {
         let cascade = self.parse_cascade_option();
 
         Ok(Statement::Revoke {
-            revoke_token,
+            revoke_token: self.new_box(revoke_token)?,
             privileges,
-            objects,
+            objects: objects.map(|v| self.new_box(v)).transpose()?,
             grantees,
-            granted_by,
+            granted_by: granted_by.map(|v| self.new_box(v)).transpose()?,
             cascade,
             grant_option_for,
         })
@@ -25006,7 +26473,7 @@ impl<'a> Parser<'a> {
             revoke_token,
             roles,
             grantees,
-            granted_by,
+            granted_by: granted_by.map(|v| self.new_box(v)).transpose()?,
             cascade,
             admin_option_for,
         })
@@ -25021,12 +26488,12 @@ impl<'a> Parser<'a> {
             );
         }
 
-        let mut insert = self.parse_insert(replace_token)?;
-        if let Statement::Insert(Insert { replace_into, .. }) = &mut insert {
-            *replace_into = true;
+        let mut statement = self.parse_insert(replace_token)?;
+        if let Statement::Insert(insert) = &mut statement {
+            insert.replace_into = true;
         }
 
-        Ok(insert)
+        Ok(statement)
     }
 
     /// Parse an INSERT statement, returning a `Box`ed SetExpr
@@ -25036,9 +26503,9 @@ impl<'a> Parser<'a> {
         &self,
         insert_token: TokenWithSpan,
     ) -> Result<Box<SetExpr>, ParserError> {
-        Ok(Box::new(SetExpr::Insert(Box::new(
-            self.parse_insert(insert_token)?,
-        ))))
+        self.new_box(SetExpr::Insert(
+            self.new_box(self.parse_insert(insert_token)?)?,
+        ))
     }
 
     /// Parse an INSERT statement
@@ -25156,9 +26623,9 @@ impl<'a> Parser<'a> {
                 let conflict_target = if self.parse_keywords(&[Keyword::ON, Keyword::CONSTRAINT]) {
                     Some(ConflictTarget::OnConstraint(self.parse_object_name(false)?))
                 } else if self.peek_token() == BorrowedToken::LParen {
-                    Some(ConflictTarget::Columns(
+                    Some(ConflictTarget::Columns(self.new_list(
                         self.parse_parenthesized_column_list(IsOptional::Mandatory, false)?,
-                    ))
+                    )?))
                 } else {
                     None
                 };
@@ -25176,7 +26643,7 @@ impl<'a> Parser<'a> {
                         None
                     };
                     OnConflictAction::DoUpdate(DoUpdate {
-                        assignments,
+                        assignments: self.new_list(assignments)?,
                         selection,
                     })
                 };
@@ -25204,7 +26671,7 @@ impl<'a> Parser<'a> {
         };
         let error_logging = self.parse_oracle_error_logging_clause()?;
 
-        Ok(Statement::Insert(Insert {
+        Ok(Statement::Insert(self.new_box(Insert {
             insert_token: insert_token.into(),
             table: table_object,
             table_alias,
@@ -25212,7 +26679,7 @@ impl<'a> Parser<'a> {
             into,
             overwrite,
             partitioned,
-            columns,
+            columns: self.new_list(columns)?,
             overriding,
             after_columns,
             source,
@@ -25224,7 +26691,8 @@ impl<'a> Parser<'a> {
             priority,
             insert_alias,
             error_logging,
-        }))
+            comments: Comments::default(),
+        })?))
     }
 
     fn parse_oracle_multitable_insert(&self) -> Result<Statement, ParserError> {
@@ -25330,9 +26798,9 @@ impl<'a> Parser<'a> {
         &self,
         update_token: TokenWithSpan,
     ) -> Result<Box<SetExpr>, ParserError> {
-        Ok(Box::new(SetExpr::Update(Box::new(
-            self.parse_update(update_token)?,
-        ))))
+        self.new_box(SetExpr::Update(
+            self.new_box(self.parse_update(update_token)?)?,
+        ))
     }
 
     pub fn parse_update(&self, update_token: TokenWithSpan) -> Result<Statement, ParserError> {
@@ -25357,12 +26825,13 @@ impl<'a> Parser<'a> {
         let assignments = self.parse_comma_separated(Parser::parse_assignment)?;
         let from = if self.parse_keyword(Keyword::FROM) {
             Some(UpdateTableFromKind::AfterSet(
-                self.parse_table_with_joins()?,
+                self.new_list(self.parse_table_with_joins()?)?,
             ))
         } else {
             None
         };
         let selection = if self.parse_keyword(Keyword::WHERE) {
+            let _guard = self.enter_context(ParseContext::WhereClause);
             Some(self.parse_expr()?)
         } else {
             None
@@ -25378,18 +26847,18 @@ impl<'a> Parser<'a> {
         } else {
             None
         };
-        Ok(Update {
+        Ok(Statement::Update(self.new_box(Update {
             update_token: update_token.into(),
             table,
             for_portion_of,
-            assignments,
+            assignments: self.new_list(assignments)?,
             from,
             selection,
             returning,
             limit,
             error_logging,
-        }
-        .into())
+            comments: Comments::default(),
+        })?))
     }
 
     fn parse_returning_clause(&self) -> Result<ReturningClause, ParserError> {
@@ -25404,7 +26873,7 @@ impl<'a> Parser<'a> {
             None
         };
         Ok(ReturningClause {
-            expressions,
+            expressions: self.new_list(expressions)?,
             bulk_collect,
             into,
         })
@@ -25513,11 +26982,11 @@ impl<'a> Parser<'a> {
             ));
         };
 
-        let array_expr = Self::object_name_to_expr(column)?;
-        Ok(Self::build_array_set_expr(array_expr, index, value))
+        let array_expr = self.object_name_to_expr(column)?;
+        self.build_array_set_expr(array_expr, index, value)
     }
 
-    fn object_name_to_expr(column: &ObjectName) -> Result<Expr, ParserError> {
+    fn object_name_to_expr(&self, column: &ObjectName) -> Result<Expr, ParserError> {
         let idents = column
             .0
             .iter()
@@ -25534,23 +27003,28 @@ impl<'a> Parser<'a> {
             0 => Err(ParserError::ParserError(
                 "Subscript assignment target is empty".to_string(),
             )),
-            1 => Ok(Expr::Identifier(idents[0].clone())),
-            _ => Ok(Expr::CompoundIdentifier(idents)),
+            1 => Ok(Expr::Identifier(self.new_box(idents[0].clone())?)),
+            _ => Ok(Expr::CompoundIdentifier(self.new_list(idents)?)),
         }
     }
 
-    fn build_array_set_expr(array_expr: Expr, index_expr: Expr, value_expr: Expr) -> Expr {
-        Expr::Function(Function {
-            name: ObjectName::from(vec![Ident::new("array_set")]),
+    fn build_array_set_expr(
+        &self,
+        array_expr: Expr,
+        index_expr: Expr,
+        value_expr: Expr,
+    ) -> Result<Expr, ParserError> {
+        Ok(Expr::Function(self.new_box(Function {
+            name: self.object_name(vec![Ident::new("array_set")])?,
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
             args: FunctionArguments::List(FunctionArgumentList {
                 duplicate_treatment: None,
-                args: vec![
+                args: self.new_list(vec![
                     FunctionArg::Unnamed(FunctionArgExpr::Expr(array_expr)),
                     FunctionArg::Unnamed(FunctionArgExpr::Expr(index_expr)),
                     FunctionArg::Unnamed(FunctionArgExpr::Expr(value_expr)),
-                ],
+                ])?,
                 clauses: vec![],
             }),
             filter: None,
@@ -25558,7 +27032,7 @@ impl<'a> Parser<'a> {
             over: None,
             within_group: vec![],
             nth_value_order: None,
-        })
+        })?))
     }
 
     pub fn parse_function_args(&self) -> Result<FunctionArg, ParserError> {
@@ -25851,7 +27325,7 @@ impl<'a> Parser<'a> {
         if self.consume_token(&BorrowedToken::RParen) {
             return Ok(FunctionArgumentList {
                 duplicate_treatment: None,
-                args: vec![],
+                args: AstVec::new(),
                 clauses,
             });
         }
@@ -25929,7 +27403,7 @@ impl<'a> Parser<'a> {
         self.expect_token(&BorrowedToken::RParen)?;
         Ok(FunctionArgumentList {
             duplicate_treatment,
-            args,
+            args: self.new_list(args)?,
             clauses,
         })
     }
@@ -25974,7 +27448,7 @@ impl<'a> Parser<'a> {
             JsonOnBehavior::Unknown
         } else if self.parse_keyword(Keyword::DEFAULT) {
             let expr = self.parse_expr()?;
-            JsonOnBehavior::Default(Box::new(expr))
+            JsonOnBehavior::Default(self.new_box(expr)?)
         } else if self.parse_keywords(&[Keyword::EMPTY, Keyword::ARRAY]) {
             JsonOnBehavior::EmptyArray
         } else if self.parse_keywords(&[Keyword::EMPTY, Keyword::OBJECT]) {
@@ -26071,7 +27545,7 @@ impl<'a> Parser<'a> {
         match self.parse_wildcard_expr()? {
             Expr::QualifiedWildcard(prefix, token) => Ok(SelectItem::QualifiedWildcard(
                 SelectItemQualifiedWildcardKind::ObjectName(prefix),
-                self.parse_wildcard_additional_options_attached(token)?,
+                self.parse_wildcard_additional_options_attached(box_into_inner(token))?,
             )),
             Expr::Wildcard(token) => Ok(SelectItem::Wildcard(
                 self.parse_wildcard_additional_options_attached(token)?,
@@ -26097,7 +27571,8 @@ impl<'a> Parser<'a> {
                 };
                 Ok(SelectItem::ExprWithAlias {
                     expr: box_into_inner(right),
-                    alias,
+                    alias: box_into_inner(alias),
+                    comments: Comments::default(),
                 })
             }
             expr if self.features.supports_select_expr_star
@@ -26109,15 +27584,21 @@ impl<'a> Parser<'a> {
                     self.parse_wildcard_additional_options(wildcard_token)?,
                 ))
             }
-            expr => self
-                .maybe_parse_select_item_alias()
-                .map(|alias| match alias {
+            expr => {
+                let alias = self.maybe_parse_select_item_alias()?;
+                let expr = self.maybe_prefixed_expr(expr, prefix)?;
+                Ok(match alias {
                     Some(alias) => SelectItem::ExprWithAlias {
-                        expr: maybe_prefixed_expr(expr, prefix),
+                        expr,
                         alias,
+                        comments: Comments::default(),
                     },
-                    None => SelectItem::UnnamedExpr(maybe_prefixed_expr(expr, prefix)),
-                }),
+                    None => SelectItem::UnnamedExpr {
+                        expr,
+                        comments: Comments::default(),
+                    },
+                })
+            }
         }
     }
 
@@ -26150,6 +27631,7 @@ impl<'a> Parser<'a> {
             opt_except,
             opt_rename,
             opt_replace,
+            comments: Comments::default(),
         })
     }
 
@@ -26234,7 +27716,7 @@ impl<'a> Parser<'a> {
         let opt_replace = if self.parse_keyword(Keyword::REPLACE) {
             if self.consume_token(&BorrowedToken::LParen) {
                 let items = self.parse_comma_separated(|parser| {
-                    Ok(Box::new(parser.parse_replace_elements()?))
+                    self.new_box(parser.parse_replace_elements()?)
                 })?;
                 self.expect_token(&BorrowedToken::RParen)?;
                 Some(ReplaceSelectItem { items })
@@ -26467,7 +27949,7 @@ impl<'a> Parser<'a> {
         {
             (None, false)
         } else {
-            let quantity = Expr::Value(self.parse_value()?);
+            let quantity = Expr::Value(self.new_box(self.parse_value()?)?);
             let percent = self.parse_keyword(Keyword::PERCENT);
             let _ = self.parse_one_of_keywords(&[Keyword::ROW, Keyword::ROWS]);
             (Some(quantity), percent)
@@ -26558,12 +28040,14 @@ impl<'a> Parser<'a> {
         } else {
             None
         };
-        Ok(Statement::OracleLockTable(OracleLockTable {
-            lock_token: AttachedToken::from(lock_token),
-            tables,
-            mode,
-            wait,
-        }))
+        Ok(Statement::OracleLockTable(self.new_box(
+            OracleLockTable {
+                lock_token: AttachedToken::from(lock_token),
+                tables,
+                mode,
+                wait,
+            },
+        )?))
     }
 
     /// Parse PostgreSQL `LOCK [ TABLE ] [ ONLY ] name [, ...] [ IN lockmode MODE ] [ NOWAIT ]`
@@ -26630,16 +28114,16 @@ impl<'a> Parser<'a> {
             parser.expect_token(&BorrowedToken::LParen)?;
             if allow_empty && parser.peek_token().token == BorrowedToken::RParen {
                 parser.next_token();
-                Ok(vec![])
+                Ok(AstVec::new())
             } else {
                 let exprs = parser.parse_comma_separated(Parser::parse_expr)?;
                 parser.expect_token(&BorrowedToken::RParen)?;
-                Ok(exprs)
+                parser.new_list(exprs)
             }
         })?;
         Ok(Values {
             explicit_row,
-            rows,
+            rows: self.new_list(rows)?,
             value_keyword,
         })
     }
@@ -26648,7 +28132,7 @@ impl<'a> Parser<'a> {
         let start_token = self.attached_token_from_current();
         self.expect_keyword_is(Keyword::TRANSACTION)?;
         Ok(Statement::StartTransaction {
-            start_token,
+            start_token: self.new_box(start_token)?,
             modes: self.parse_transaction_modes()?,
             begin: false,
             transaction: Some(BeginTransactionKind::Transaction),
@@ -26701,7 +28185,7 @@ impl<'a> Parser<'a> {
             _ => None,
         };
         Ok(Statement::StartTransaction {
-            start_token,
+            start_token: self.new_box(start_token)?,
             modes: self.parse_transaction_modes()?,
             begin: true,
             transaction,
@@ -26713,28 +28197,7 @@ impl<'a> Parser<'a> {
     }
 
     fn begin_starts_transaction(&self) -> bool {
-        let has_transaction_syntax = if self.features.supports_start_transaction_modifier {
-            self.peek_keyword(Keyword::DEFERRED)
-                || self.peek_keyword(Keyword::IMMEDIATE)
-                || self.peek_keyword(Keyword::EXCLUSIVE)
-                || self.peek_keyword(Keyword::TRY)
-                || self.peek_keyword(Keyword::CATCH)
-                || self.peek_keyword(Keyword::TRANSACTION)
-                || self.peek_keyword(Keyword::WORK)
-                || self.peek_keyword(Keyword::ISOLATION)
-                || self.peek_keyword(Keyword::READ)
-        } else {
-            self.peek_keyword(Keyword::TRANSACTION)
-                || self.peek_keyword(Keyword::WORK)
-                || self.peek_keyword(Keyword::ISOLATION)
-                || self.peek_keyword(Keyword::READ)
-        };
-
-        has_transaction_syntax
-            || matches!(
-                self.peek_token().token,
-                BorrowedToken::SemiColon | BorrowedToken::EOF
-            )
+        begin_starts_transaction(self.dialect, Some(&self.peek_token_ref().token))
     }
 
     pub fn parse_begin_exception_end(
@@ -26770,7 +28233,7 @@ impl<'a> Parser<'a> {
         self.expect_keyword(Keyword::END)?;
 
         Ok(Statement::StartTransaction {
-            start_token,
+            start_token: self.new_box(start_token)?,
             begin: true,
             statements,
             exception,
@@ -26830,13 +28293,13 @@ impl<'a> Parser<'a> {
             None
         };
 
-        Ok(Statement::LabeledBlock(LabeledBlock {
+        Ok(Statement::LabeledBlock(self.new_box(LabeledBlock {
             token,
             label,
             statements,
             exception,
             end_label,
-        }))
+        })?))
     }
 
     pub fn parse_end(&self) -> Result<Statement, ParserError> {
@@ -26942,12 +28405,12 @@ impl<'a> Parser<'a> {
                 chain: false,
                 end: false,
                 modifier: None,
-                oracle: Some(OracleCommitOptions {
+                oracle: Some(self.new_box(OracleCommitOptions {
                     work,
                     comment,
                     write,
                     force,
-                }),
+                })?),
             });
         }
         Ok(Statement::Commit {
@@ -27118,7 +28581,7 @@ impl<'a> Parser<'a> {
 
         let dry_run = self.parse_keywords(&[Keyword::DRY, Keyword::RUN]);
 
-        Ok(Statement::Restore {
+        Ok(Statement::Restore(self.new_box(RestoreStatement {
             object_type,
             tenant,
             as_new_tenant,
@@ -27127,7 +28590,7 @@ impl<'a> Parser<'a> {
             target_hlc,
             target_lsn,
             dry_run,
-        })
+        })?))
     }
 
     /// Parse RECOVER PAGE <page_id> FROM TABLE <table_name>
@@ -27184,7 +28647,9 @@ impl<'a> Parser<'a> {
         Ok(Statement::Rollback {
             rollback_token,
             chain,
-            savepoint,
+            savepoint: savepoint
+                .map(|savepoint| self.new_box(savepoint))
+                .transpose()?,
         })
     }
 
@@ -27214,11 +28679,11 @@ impl<'a> Parser<'a> {
     pub fn parse_raiserror(&self) -> Result<Statement, ParserError> {
         let token = self.attached_token_from_current();
         self.expect_token(&BorrowedToken::LParen)?;
-        let message = Box::new(self.parse_expr()?);
+        let message = self.new_box(self.parse_expr()?)?;
         self.expect_token(&BorrowedToken::Comma)?;
-        let severity = Box::new(self.parse_expr()?);
+        let severity = self.new_box(self.parse_expr()?)?;
         self.expect_token(&BorrowedToken::Comma)?;
-        let state = Box::new(self.parse_expr()?);
+        let state = self.new_box(self.parse_expr()?)?;
         let arguments = if self.consume_token(&BorrowedToken::Comma) {
             self.parse_comma_separated(Parser::parse_expr)?
         } else {
@@ -27231,7 +28696,7 @@ impl<'a> Parser<'a> {
             vec![]
         };
         Ok(Statement::RaisError {
-            token,
+            token: self.new_box(token)?,
             message,
             severity,
             state,
@@ -27258,7 +28723,7 @@ impl<'a> Parser<'a> {
         let name = self.parse_identifier()?;
         Ok(Statement::Deallocate {
             deallocate_token,
-            name,
+            name: self.new_box(name)?,
             prepare,
         })
     }
@@ -27308,7 +28773,7 @@ impl<'a> Parser<'a> {
                 };
 
                 return Ok(Statement::ExecuteDynamic {
-                    query_expr: Box::new(query_expr),
+                    query_expr: self.new_box(query_expr)?,
                     into,
                     using,
                 });
@@ -27362,9 +28827,9 @@ impl<'a> Parser<'a> {
         let default = self.parse_keyword(Keyword::DEFAULT);
 
         Ok(Statement::Execute {
-            execute_token,
+            execute_token: self.new_box(execute_token)?,
             immediate: name.is_none(),
-            name,
+            name: name.map(|v| self.new_box(v)).transpose()?,
             parameters,
             has_parentheses,
             into,
@@ -27385,10 +28850,10 @@ impl<'a> Parser<'a> {
         }
 
         self.expect_keyword_is(Keyword::AS)?;
-        let statement = Box::new(self.parse_statement()?);
+        let statement = self.new_box(self.parse_statement()?)?;
         Ok(Statement::Prepare {
             prepare_token,
-            name,
+            name: self.new_box(name)?,
             data_types,
             statement,
         })
@@ -27419,7 +28884,7 @@ impl<'a> Parser<'a> {
         while let Some(opt) = self.maybe_parse(|parser| parser.parse_copy_legacy_option())? {
             options.push(opt);
         }
-        Ok(Statement::Unload {
+        Ok(Statement::Unload(self.new_box(UnloadStatement {
             unload_token,
             query,
             query_text,
@@ -27427,7 +28892,7 @@ impl<'a> Parser<'a> {
             auth,
             with,
             options,
-        })
+        })?))
     }
 
     pub fn parse_merge_clauses(&self) -> Result<Vec<MergeClause>, ParserError> {
@@ -27634,14 +29099,14 @@ impl<'a> Parser<'a> {
         let error_logging = self.parse_oracle_error_logging_clause()?;
 
         Ok(Statement::Merge {
-            merge_token,
+            merge_token: self.new_box(merge_token)?,
             into,
-            table,
-            source,
-            on: Box::new(on),
+            table: self.new_box(table)?,
+            source: self.new_box(source)?,
+            on: self.new_box(on)?,
             clauses,
-            output,
-            error_logging,
+            output: output.map(|v| self.new_box(v)).transpose()?,
+            error_logging: error_logging.map(|v| self.new_box(v)).transpose()?,
         })
     }
 
@@ -27650,7 +29115,7 @@ impl<'a> Parser<'a> {
         let pragma_token = self.attached_token_from_current();
         Ok(Statement::Pragma {
             pragma_token,
-            pragma: self.parse_pragma_body()?,
+            pragma: self.new_box(self.parse_pragma_body()?)?,
         })
     }
 
@@ -27667,18 +29132,21 @@ impl<'a> Parser<'a> {
                 name,
                 arguments,
                 is_eq: false,
+                comments: Comments::default(),
             })
         } else if self.consume_token(&BorrowedToken::Eq) {
             Ok(Pragma {
                 name,
                 arguments: vec![self.parse_expr()?],
                 is_eq: true,
+                comments: Comments::default(),
             })
         } else {
             Ok(Pragma {
                 name,
                 arguments: vec![],
                 is_eq: false,
+                comments: Comments::default(),
             })
         }
     }
@@ -27696,7 +29164,7 @@ impl<'a> Parser<'a> {
             let table_name = self.parse_object_name(false)?;
             let partitioned = self.parse_insert_partition()?;
             Ok(Statement::LoadData {
-                load_token,
+                load_token: self.new_box(load_token)?,
                 local,
                 inpath,
                 overwrite,
@@ -27741,10 +29209,10 @@ impl<'a> Parser<'a> {
         Ok(Statement::OptimizeTable {
             token,
             name,
-            on_cluster,
-            partition,
+            on_cluster: on_cluster.map(|v| self.new_box(v)).transpose()?,
+            partition: partition.map(|v| self.new_box(v)).transpose()?,
             include_final,
-            deduplicate,
+            deduplicate: deduplicate.map(|v| self.new_box(v)).transpose()?,
         })
     }
 
@@ -27793,7 +29261,7 @@ impl<'a> Parser<'a> {
         // [ OWNED BY { table_name.column_name | NONE } ]
         let owned_by = if self.parse_keywords(&[Keyword::OWNED, Keyword::BY]) {
             if self.parse_keywords(&[Keyword::NONE]) {
-                Some(ObjectName::from(vec![Ident::new("NONE")]))
+                Some(self.object_name(vec![Ident::new("NONE")])?)
             } else {
                 Some(self.parse_object_name(false)?)
             }
@@ -27801,11 +29269,11 @@ impl<'a> Parser<'a> {
             None
         };
         Ok(Statement::CreateSequence {
-            create_token,
+            create_token: self.new_box(create_token)?,
             temporary,
             if_not_exists,
             name,
-            data_type,
+            data_type: data_type.map(|v| self.new_box(v)).transpose()?,
             sequence_options,
             owned_by,
         })
@@ -27937,15 +29405,17 @@ impl<'a> Parser<'a> {
             self.expect_token(&BorrowedToken::RParen)?;
         }
 
-        Ok(Statement::CreateServer(CreateServerStatement {
-            token,
-            name,
-            if_not_exists: ine,
-            server_type,
-            version,
-            foreign_data_wrapper,
-            options,
-        }))
+        Ok(Statement::CreateServer(self.new_box(
+            CreateServerStatement {
+                token,
+                name,
+                if_not_exists: ine,
+                server_type,
+                version,
+                foreign_data_wrapper,
+                options,
+            },
+        )?))
     }
 
     // =========================================================================
@@ -27978,7 +29448,7 @@ impl<'a> Parser<'a> {
 
         let options = self.parse_sql_med_options()?;
 
-        Ok(Statement::CreateForeignDataWrapper(
+        Ok(Statement::CreateForeignDataWrapper(self.new_box(
             CreateForeignDataWrapperStatement {
                 token,
                 name,
@@ -27989,7 +29459,7 @@ impl<'a> Parser<'a> {
                 no_validator,
                 options,
             },
-        ))
+        )?))
     }
 
     /// Parse a `ALTER FOREIGN DATA WRAPPER` statement.
@@ -28123,17 +29593,19 @@ impl<'a> Parser<'a> {
 
         let options = self.parse_sql_med_options()?;
 
-        Ok(Statement::CreateForeignTable(CreateForeignTableStatement {
-            token,
-            name,
-            if_not_exists,
-            columns,
-            constraints,
-            server,
-            options,
-            partition_of,
-            partition_bound,
-        }))
+        Ok(Statement::CreateForeignTable(self.new_box(
+            CreateForeignTableStatement {
+                token,
+                name,
+                if_not_exists,
+                columns,
+                constraints,
+                server,
+                options,
+                partition_of,
+                partition_bound,
+            },
+        )?))
     }
 
     /// Parse partition bound specification for foreign tables.
@@ -28402,13 +29874,15 @@ impl<'a> Parser<'a> {
 
         let options = self.parse_sql_med_options()?;
 
-        Ok(Statement::CreateUserMapping(CreateUserMappingStatement {
-            token,
-            if_not_exists,
-            user,
-            server,
-            options,
-        }))
+        Ok(Statement::CreateUserMapping(self.new_box(
+            CreateUserMappingStatement {
+                token,
+                if_not_exists,
+                user,
+                server,
+                options,
+            },
+        )?))
     }
 
     /// Parse the user specification for USER MAPPING statements.
@@ -28436,12 +29910,14 @@ impl<'a> Parser<'a> {
         self.expect_keyword(Keyword::OPTIONS)?;
         let options = self.parse_sql_med_option_actions()?;
 
-        Ok(Statement::AlterUserMapping(AlterUserMappingStatement {
-            token,
-            user,
-            server,
-            options,
-        }))
+        Ok(Statement::AlterUserMapping(self.new_box(
+            AlterUserMappingStatement {
+                token,
+                user,
+                server,
+                options,
+            },
+        )?))
     }
 
     /// Parse a `DROP USER MAPPING` statement.
@@ -28452,12 +29928,14 @@ impl<'a> Parser<'a> {
         self.expect_keyword(Keyword::SERVER)?;
         let server = self.parse_object_name(false)?;
 
-        Ok(Statement::DropUserMapping(DropUserMappingStatement {
-            token,
-            if_exists,
-            user,
-            server,
-        }))
+        Ok(Statement::DropUserMapping(self.new_box(
+            DropUserMappingStatement {
+                token,
+                if_exists,
+                user,
+                server,
+            },
+        )?))
     }
 
     /// Parse an `IMPORT FOREIGN SCHEMA` statement.
@@ -28486,7 +29964,7 @@ impl<'a> Parser<'a> {
 
         let options = self.parse_sql_med_options()?;
 
-        Ok(Statement::ImportForeignSchema(
+        Ok(Statement::ImportForeignSchema(self.new_box(
             ImportForeignSchemaStatement {
                 token,
                 remote_schema,
@@ -28496,7 +29974,7 @@ impl<'a> Parser<'a> {
                 local_schema,
                 options,
             },
-        ))
+        )?))
     }
 
     /// Parse OPTIONS clause for SQL/MED statements: OPTIONS (key 'value', ...)
@@ -28687,15 +30165,13 @@ impl<'a> Parser<'a> {
                     BorrowedToken::DollarQuotedString(_) | BorrowedToken::SingleQuotedString(_)
                 ) {
                     let body_string = self.parse_create_function_body_string()?;
-                    raw_body = Some(match body_string {
-                        Expr::Value(ValueWithSpan {
-                            value: Value::DollarQuotedString(dqs),
-                            ..
-                        }) => dqs.value,
-                        Expr::Value(ValueWithSpan {
-                            value: Value::SingleQuotedString(s),
-                            ..
-                        }) => s,
+                    let body_value = match body_string {
+                        Expr::Value(value) => Some(box_into_inner(value).value),
+                        _ => None,
+                    };
+                    raw_body = Some(match body_value {
+                        Some(Value::DollarQuotedString(dqs)) => box_into_inner(dqs).value,
+                        Some(Value::SingleQuotedString(s)) => s.into_string(),
                         _ => {
                             return Err(ParserError::ParserError(
                                 "unsupported procedure body format".to_string(),
@@ -28741,15 +30217,15 @@ impl<'a> Parser<'a> {
         }
 
         Ok(Statement::CreateProcedure {
-            create_token,
+            create_token: self.new_box(create_token)?,
             name,
             or_alter,
             params,
-            language,
+            language: language.map(|v| self.new_box(v)).transpose()?,
             security,
             set_options,
             has_as,
-            body,
+            body: self.new_box(body)?,
         })
     }
 
@@ -28781,8 +30257,8 @@ impl<'a> Parser<'a> {
         };
         Ok(WindowSpec {
             window_name,
-            partition_by,
-            order_by,
+            partition_by: self.new_list(partition_by)?,
+            order_by: self.new_list(order_by)?,
             window_frame,
         })
     }
@@ -29178,7 +30654,7 @@ impl<'a> Parser<'a> {
         let token = self.attached_token_from_current();
         Ok(Statement::Print(PrintStatement {
             token,
-            message: Box::new(self.parse_expr()?),
+            message: self.new_box(self.parse_expr()?)?,
         }))
     }
 
@@ -29199,7 +30675,7 @@ impl<'a> Parser<'a> {
             // RETURN QUERY ... or RETURN QUERY EXECUTE ...
             if self.parse_keyword(Keyword::EXECUTE) {
                 // RETURN QUERY EXECUTE expression [USING ...]
-                let query_expr = Box::new(self.parse_expr()?);
+                let query_expr = self.new_box(self.parse_expr()?)?;
                 let using = if self.parse_keyword(Keyword::USING) {
                     Some(self.parse_comma_separated(|p| p.parse_expr())?)
                 } else {
@@ -29212,13 +30688,13 @@ impl<'a> Parser<'a> {
             }
         } else {
             // RETURN [expression] or bare RETURN
-            match self.maybe_parse(|p| p.parse_expr())? {
-                Some(expr) => Some(ReturnStatementValue::Expr(expr)),
-                None => None,
-            }
+            self.maybe_parse(|p| p.parse_expr())?
+                .map(ReturnStatementValue::Expr)
         };
 
-        Ok(Statement::Return(ReturnStatement { token, value }))
+        Ok(Statement::Return(
+            self.new_box(ReturnStatement { token, value })?,
+        ))
     }
 
     fn parse_vacuum(&self) -> Result<Statement, ParserError> {
@@ -29245,7 +30721,7 @@ impl<'a> Parser<'a> {
                 }
                 _ => (None, None, false),
             };
-        Ok(Statement::Vacuum(VacuumStatement {
+        Ok(Statement::Vacuum(self.new_box(VacuumStatement {
             token,
             full,
             sort_only,
@@ -29256,7 +30732,7 @@ impl<'a> Parser<'a> {
             table_name,
             threshold,
             boost,
-        }))
+        })?))
     }
 
     fn parse_reindex(&self) -> Result<Statement, ParserError> {
@@ -29394,9 +30870,9 @@ impl<'a> Parser<'a> {
                     .parse_one_of_keywords(&[Keyword::FROM, Keyword::IN])
                     .is_some()
                 {
-                    parent_name
-                        .0
-                        .insert(0, ObjectNamePart::Identifier(self.parse_identifier()?));
+                    let mut parts = parent_name.0.into_vec();
+                    parts.insert(0, ObjectNamePart::Identifier(self.parse_identifier()?));
+                    parent_name = ObjectName(self.new_list(parts)?);
                 }
                 (None, Some(parent_name))
             }
@@ -29504,7 +30980,7 @@ impl<'a> Parser<'a> {
                 Ok(KeyValueOption {
                     option_name: key.value.to_string(),
                     option_value: KeyValueOptionKind::Single(Value::Placeholder(
-                        word.value.to_string(),
+                        word.value.to_string().into(),
                     )),
                 })
             }
@@ -29528,9 +31004,9 @@ impl<'a> Parser<'a> {
                     }
                     None => Ok(KeyValueOption {
                         option_name: key.value.to_string(),
-                        option_value: KeyValueOptionKind::KeyValueOptions(Box::new(
-                            self.parse_key_value_options(true, &[])?,
-                        )),
+                        option_value: KeyValueOptionKind::KeyValueOptions(
+                            self.new_box(self.parse_key_value_options(true, &[])?)?,
+                        ),
                     }),
                 }
             }
@@ -29551,9 +31027,9 @@ impl<'a> Parser<'a> {
         if self.parse_keywords(&[Keyword::SESSION, Keyword::AUTHORIZATION]) {
             return Ok(Statement::Reset(ResetStatement {
                 token,
-                reset: Reset::ConfigurationParameter(ObjectName::from(vec![Ident::new(
-                    "session_authorization",
-                )])),
+                reset: Reset::ConfigurationParameter(
+                    self.object_name(vec![Ident::new("session_authorization")])?,
+                ),
             }));
         }
 
@@ -29563,16 +31039,16 @@ impl<'a> Parser<'a> {
             reset: Reset::ConfigurationParameter(obj),
         }))
     }
-}
 
-fn maybe_prefixed_expr(expr: Expr, prefix: Option<Ident>) -> Expr {
-    if let Some(prefix) = prefix {
-        Expr::Prefixed {
-            prefix,
-            value: Box::new(expr),
-        }
-    } else {
-        expr
+    fn maybe_prefixed_expr(&self, expr: Expr, prefix: Option<Ident>) -> Result<Expr, ParserError> {
+        Ok(if let Some(prefix) = prefix {
+            Expr::Prefixed {
+                prefix: self.new_box(prefix)?,
+                value: self.new_box(expr)?,
+            }
+        } else {
+            expr
+        })
     }
 }
 
@@ -29580,7 +31056,7 @@ impl Word<'_> {
     #[deprecated(since = "0.54.0", note = "please use `into_ident` instead")]
     pub fn to_ident(&self, span: Span) -> Ident {
         Ident {
-            value: self.value.to_string(),
+            value: self.value.as_ref().into(),
             quote_style: self.quote_style,
             span,
         }
@@ -29589,7 +31065,7 @@ impl Word<'_> {
     /// Convert this word into an [`Ident`] identifier
     pub fn into_ident(self, span: Span) -> Ident {
         Ident {
-            value: self.value.into_owned(),
+            value: self.value.into(),
             quote_style: self.quote_style,
             span,
         }
@@ -30126,7 +31602,7 @@ mod tests {
         fn mk_expected_col(name: &str) -> IndexColumn {
             IndexColumn {
                 column: OrderByExpr {
-                    expr: Expr::Identifier(name.into()),
+                    expr: Expr::Identifier(Box::new(name.into())),
                     options: OrderByOptions {
                         asc: None,
                         nulls_first: None,
@@ -30233,26 +31709,232 @@ mod tests {
     #[test]
     fn test_tokenizer_error_loc() {
         let sql = "foo '";
-        let ast = Parser::parse_sql(&PostgreSqlDialect {}, sql);
+        let err = Parser::parse_sql(&PostgreSqlDialect {}, sql).unwrap_err();
         assert_eq!(
-            ast,
-            Err(ParserError::TokenizerError(
-                "Unterminated string literal at Line: 1, Column: 5".to_string()
-            ))
+            err.to_string(),
+            "sql parser error: Unterminated string literal at Line: 1, Column: 5"
         );
+        let error = err.syntax_error().unwrap();
+        assert_eq!(error.kind, SyntaxErrorKind::Tokenizer);
+        assert_eq!(error.message, "Unterminated string literal");
+        assert_eq!(error.span.start, Location::new(1, 5));
+        assert_eq!(error.byte_offset, Some(4));
     }
 
     #[test]
     fn test_parser_error_loc() {
         let sql = "SELECT this is a syntax error";
-        let ast = Parser::parse_sql(&PostgreSqlDialect {}, sql);
+        let err = Parser::parse_sql(&PostgreSqlDialect {}, sql).unwrap_err();
         assert_eq!(
-            ast,
-            Err(ParserError::ParserError(
-                "Expected: [NOT] NULL | TRUE | FALSE | DISTINCT | [form] NORMALIZED | JSON | DOCUMENT | CONTENT | LABELED | SOURCE OF | DESTINATION OF | SAME AS after IS, found: a at Line: 1, Column: 16"
-                    .to_string()
-            ))
+            err.to_string(),
+            "sql parser error: Expected: [NOT] NULL | TRUE | FALSE | DISTINCT | [form] NORMALIZED | JSON | DOCUMENT | CONTENT | LABELED | SOURCE OF | DESTINATION OF | SAME AS after IS, found: a at Line: 1, Column: 16"
+        );
+        let error = err.syntax_error().unwrap();
+        assert_eq!(error.kind, SyntaxErrorKind::Parser);
+        assert_eq!(
+            error.span,
+            Span::new(Location::new(1, 16), Location::new(1, 17))
+        );
+        assert_eq!(error.byte_offset, Some(15));
+        assert!(error.message.ends_with("found: a"));
+    }
+
+    #[test]
+    fn test_parser_error_diagnostics() {
+        let sql = "SELECT * FROM t ORDER BYY a";
+        let err = Parser::parse_sql(&PostgreSqlDialect {}, sql).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "sql parser error: Expected: BY, found: BYY at Line: 1, Column: 23"
+        );
+        let error = err.syntax_error().unwrap();
+        assert_eq!(err.span(), Some(error.span));
+        assert_eq!(err.byte_offset(), Some(22));
+        assert_eq!(&sql[22..], "BYY a");
+        assert_eq!(error.expected, vec![ExpectedItem::Keyword(Keyword::BY)]);
+        assert_eq!(error.hint.as_deref(), Some("Did you mean 'BY'?"));
+
+        let err = Parser::parse_sql(&PostgreSqlDialect {}, "DELETE FORM t").unwrap_err();
+        let error = err.syntax_error().unwrap();
+        assert_eq!(error.expected, vec![ExpectedItem::Keyword(Keyword::FROM)]);
+        assert_eq!(error.contexts, vec![ParseContext::DeleteStatement]);
+
+        // End of input has no span, but still resolves to a byte offset.
+        let sql = "SELECT CAST(a AS INT";
+        let err = Parser::parse_sql(&PostgreSqlDialect {}, sql).unwrap_err();
+        assert_eq!(err.to_string(), "sql parser error: Expected: ), found: EOF");
+        let error = err.syntax_error().unwrap();
+        assert_eq!(error.span, Span::empty());
+        assert_eq!(error.byte_offset, Some(sql.len()));
+        assert_eq!(error.contexts, vec![ParseContext::SelectClause]);
+
+        // Token streams without source text keep the message-only variants.
+        let tokens = Tokenizer::new(&PostgreSqlDialect {}, "SELECT 1 2")
+            .tokenized_owned()
+            .unwrap();
+        let err = Parser::new(&PostgreSqlDialect {})
+            .with_tokens(tokens)
+            .parse_statements()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ParserError::ParserError("Expected: end of statement, found: 2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_statements_with_recovery() {
+        let sql = "SELECT 1; SELEC 2; SELECT * FROM t WHRE x = 1; SELECT 3";
+        let recovered =
+            Parser::parse_sql_with_recovery(&PostgreSqlDialect {}, sql, ParserOptions::new())
+                .unwrap();
+        let statements: Vec<_> = recovered.statements.iter().map(|s| s.to_string()).collect();
+        assert_eq!(statements, vec!["SELECT 1", "SELECT 3"]);
+        assert_eq!(
+            recovered.spans,
+            vec![
+                Span::new(Location::new(1, 1), Location::new(1, 9)),
+                Span::new(Location::new(1, 48), Location::new(1, 56)),
+            ]
+        );
+        assert_eq!(recovered.errors.len(), 2);
+        assert_eq!(
+            recovered.errors[0].error.to_string(),
+            "sql parser error: Expected: an SQL statement, found: SELEC at Line: 1, Column: 11"
+        );
+        assert_eq!(
+            recovered.errors[0].span,
+            Span::new(Location::new(1, 11), Location::new(1, 18))
+        );
+        // A statement that parses but is not followed by a delimiter is
+        // reported as a whole, not kept as a truncated statement.
+        assert_eq!(recovered.errors[1].error.byte_offset(), Some(40));
+        assert_eq!(
+            recovered.errors[1].span,
+            Span::new(Location::new(1, 20), Location::new(1, 46))
+        );
+
+        // Semicolons inside a block body do not end the failed statement.
+        let sql = "CREATE PROCEDURE p() BEGIN SELECT 1; SELEC 2; END; SELECT 3";
+        let recovered =
+            Parser::parse_sql_with_recovery(&MySqlDialect {}, sql, ParserOptions::new()).unwrap();
+        assert_eq!(recovered.statements.len(), 1);
+        assert_eq!(recovered.statements[0].to_string(), "SELECT 3");
+        assert_eq!(recovered.errors.len(), 1);
+        assert_eq!(
+            recovered.errors[0].span,
+            Span::new(Location::new(1, 1), Location::new(1, 50))
+        );
+
+        // Without required delimiters, the next statement keyword resumes.
+        let sql = "SELECT 1 SELEC 2 FROM t UPDATE t SETT a = 1 INSERT INTO t VALUES (1)";
+        let mut options = ParserOptions::new();
+        options.require_semicolon_stmt_delimiter = false;
+        let recovered = Parser::parse_sql_with_recovery(&MsSqlDialect {}, sql, options).unwrap();
+        let statements: Vec<_> = recovered.statements.iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            statements,
+            vec!["SELECT 1 AS SELEC", "INSERT INTO t VALUES (1)"]
+        );
+        assert_eq!(recovered.errors.len(), 2);
+        assert_eq!(
+            recovered.errors[1].span,
+            Span::new(Location::new(1, 25), Location::new(1, 44))
+        );
+    }
+
+    #[test]
+    fn test_parse_statements_tolerant() {
+        let sql = "SELECT a, FROM t WHERE; SELEC 2; SELECT f(x, ) FROM WHERE b = 1 c d; SELECT 3";
+        let parsed =
+            Parser::parse_sql_tolerant(&PostgreSqlDialect {}, sql, ParserOptions::new()).unwrap();
+        let statements: Vec<_> = parsed.statements.iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            statements,
+            vec![
+                "SELECT a,  FROM t WHERE ",
+                "SELECT f(x, ) FROM  WHERE b = 1",
+                "SELECT 3"
+            ]
+        );
+        assert_eq!(
+            parsed.spans[1],
+            Span::new(Location::new(1, 34), Location::new(1, 64))
+        );
+        let errors: Vec<_> = parsed
+            .errors
+            .iter()
+            .map(|error| (error.error.byte_offset(), error.span))
+            .collect();
+        let empty_at = |column| {
+            let location = Location::new(1, column);
+            Span::new(location, location)
+        };
+        assert_eq!(
+            errors,
+            vec![
+                // The missing select item and `WHERE` condition
+                (Some(10), empty_at(11)),
+                (Some(22), empty_at(23)),
+                // The statement that does not parse even partially
+                (
+                    Some(24),
+                    Span::new(Location::new(1, 25), Location::new(1, 32))
+                ),
+                // The missing function argument and table, and the tokens
+                // after the end of the statement
+                (Some(45), empty_at(46)),
+                (Some(52), empty_at(53)),
+                (
+                    Some(64),
+                    Span::new(Location::new(1, 65), Location::new(1, 68))
+                ),
+            ]
+        );
+
+        let Statement::Query(query) = &parsed.statements[0] else {
+            panic!("expected a query");
+        };
+        let SetExpr::Select(select) = query.body.as_ref() else {
+            panic!("expected a select");
+        };
+        assert!(matches!(
+            &select.projection[1],
+            SelectItem::UnnamedExpr { expr: Expr::Error(token), .. } if token.0 == empty_at(11)
+        ));
+        assert!(matches!(select.selection.as_deref(), Some(Expr::Error(_))));
+        let Statement::Query(query) = &parsed.statements[1] else {
+            panic!("expected a query");
+        };
+        let SetExpr::Select(select) = query.body.as_ref() else {
+            panic!("expected a select");
+        };
+        assert!(matches!(
+            &select.from[0].relation,
+            TableFactor::Error(token) if token.0 == empty_at(53)
+        ));
+
+        // Skipped tokens are spanned by the placeholder, and strict parsing
+        // of the same statement is unchanged.
+        let sql = "SELECT 1 + * 2 FROM t";
+        let parsed =
+            Parser::parse_sql_tolerant(&PostgreSqlDialect {}, sql, ParserOptions::new()).unwrap();
+        assert_eq!(parsed.statements[0].to_string(), "SELECT 1 +  FROM t");
+        assert_eq!(
+            parsed.errors[0].span,
+            Span::new(Location::new(1, 12), Location::new(1, 15))
+        );
+        assert!(Parser::parse_sql(&PostgreSqlDialect {}, sql).is_err());
+
+        // Keywords that end an expression are not taken as identifiers.
+        let sql = "SELECT CASE WHEN THEN 1 END, MAX(interval) FROM t WHERE a = AND b";
+        let parsed =
+            Parser::parse_sql_tolerant(&PostgreSqlDialect {}, sql, ParserOptions::new()).unwrap();
+        assert_eq!(
+            parsed.statements[0].to_string(),
+            "SELECT CASE WHEN  THEN 1 END, max(interval) FROM t WHERE a =  AND b"
         );
+        assert_eq!(parsed.errors.len(), 2);
     }
 
     #[test]
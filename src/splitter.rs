@@ -0,0 +1,1292 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Statement splitting without parsing
+//!
+//! [`split_statements`] cuts a SQL script into the byte ranges of its
+//! statements using only the token stream, so string literals, quoted
+//! identifiers, comments and dollar-quoted bodies never end a statement.
+//! Besides `;` it follows the batch conventions of each dialect's
+//! command-line client:
+//!
+//! * `BEGIN ... END` blocks, such as PostgreSQL `BEGIN ATOMIC` function
+//!   bodies, keep their inner semicolons.
+//! * MySQL `DELIMITER` directives replace the statement delimiter.
+//! * T-SQL `GO` lines separate batches.
+//! * An Oracle `/` alone on its line ends a PL/SQL unit.
+//! * PostgreSQL `COPY ... FROM STDIN` keeps its inline data.
+//!
+//! The ranges index the original text unchanged. Anything outside a
+//! statement's `range` and `terminator` is whitespace, comments, empty
+//! statements or client directives.
+//!
+//! With the `std` feature, `StatementReader` applies the same rules to a
+//! script read from a [`BufRead`](std::io::BufRead) or a sequence of chunks,
+//! one statement at a time.
+//!
+//! ```
+//! # use sqlparser::dialect::PostgreSqlDialect;
+//! # use sqlparser::keywords::Keyword;
+//! # use sqlparser::splitter::split_statements;
+//! let sql = "SELECT 1; -- note\nINSERT INTO t VALUES ('a;b')";
+//! let statements = split_statements(&PostgreSqlDialect {}, sql).unwrap();
+//! assert_eq!(statements.len(), 2);
+//! assert_eq!(&sql[statements[0].range.clone()], "SELECT 1");
+//! assert_eq!(&sql[statements[1].range.clone()], "INSERT INTO t VALUES ('a;b')");
+//! assert_eq!(statements[1].keyword, Keyword::INSERT);
+//! assert_eq!(statements[1].terminator, None);
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::dialect::{Dialect, MsSqlDialect, MySqlDialect, OracleDialect, PostgreSqlDialect};
+use crate::keywords::Keyword;
+use crate::tokenizer::{BorrowedToken, TokenWithSpan, Tokenizer, TokenizerError};
+
+/// A statement found by [`split_statements`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatementRange {
+    /// UTF-8 byte range of the statement, from the start of its first token
+    /// to the end of its last token.
+    pub range: Range<usize>,
+    /// Byte range of what ended the statement: a `;`, a MySQL custom
+    /// delimiter, an Oracle `/` line, a T-SQL `GO` line, or the `\.` that
+    /// ends `COPY` data. `None` when the script ends first.
+    pub terminator: Option<Range<usize>>,
+    /// The keyword the statement starts with, or [`Keyword::NoKeyword`]
+    /// when it starts with another token.
+    pub keyword: Keyword,
+}
+
+/// Split `sql` into statements following the rules of `dialect`.
+///
+/// Only tokenizer errors are reported; the statements themselves are not
+/// parsed. See the [module documentation](self) for the recognized
+/// delimiters.
+pub fn split_statements(
+    dialect: &dyn Dialect,
+    sql: &str,
+) -> Result<Vec<StatementRange>, TokenizerError> {
+    let tokens = Tokenizer::new(dialect, sql).tokenize_significant()?;
+    Ok(Splitter::new(dialect, sql, &tokens).split())
+}
+
+struct Splitter<'s, 't, 'd> {
+    sql: &'s str,
+    tokens: &'t [TokenWithSpan<'s>],
+    mysql: bool,
+    mssql: bool,
+    oracle: bool,
+    postgres: bool,
+    blocks: BlockTracker<'d>,
+    /// MySQL delimiter set by a `DELIMITER` directive, unless it is `;`.
+    delimiter: Option<&'s str>,
+    /// Start offset and keyword of the statement being collected.
+    pending: Option<(usize, Keyword)>,
+    /// End offset of the last token of the pending statement.
+    end: usize,
+    /// The pending statement is `COPY ... FROM STDIN`.
+    copy_from_stdin: bool,
+    statements: Vec<StatementRange>,
+    /// The MySQL delimiter in effect for each statement of `statements`.
+    delimiters: Vec<Option<&'s str>>,
+}
+
+impl<'s, 't, 'd> Splitter<'s, 't, 'd> {
+    fn new(dialect: &'d dyn Dialect, sql: &'s str, tokens: &'t [TokenWithSpan<'s>]) -> Self {
+        Self {
+            sql,
+            tokens,
+            mysql: dialect.is::<MySqlDialect>(),
+            mssql: dialect.is::<MsSqlDialect>(),
+            oracle: dialect.is::<OracleDialect>(),
+            postgres: dialect.is::<PostgreSqlDialect>(),
+            blocks: BlockTracker::new(dialect),
+            delimiter: None,
+            pending: None,
+            end: 0,
+            copy_from_stdin: false,
+            statements: Vec::new(),
+            delimiters: Vec::new(),
+        }
+    }
+
+    fn split(mut self) -> Vec<StatementRange> {
+        self.run();
+        self.statements
+    }
+
+    fn run(&mut self) {
+        let mut index = 0;
+        while let Some(token) = self.tokens.get(index) {
+            let range = token_range(token);
+            if self.mysql && self.pending.is_none() && self.is_delimiter_directive(index) {
+                index = self.apply_delimiter_directive(index);
+                continue;
+            }
+            if let Some(delimiter) = self.delimiter {
+                index = self.split_custom_delimiter(index, delimiter);
+                continue;
+            }
+            match &token.token {
+                BorrowedToken::EOF => break,
+                BorrowedToken::Word(word)
+                    if self.mssql
+                        && word.quote_style.is_none()
+                        && word.value.eq_ignore_ascii_case("GO")
+                        && starts_line(self.tokens, index) =>
+                {
+                    if let Some(last) = self.batch_separator_end(index) {
+                        let end = token_range(&self.tokens[last]).end;
+                        self.finish(Some(range.start..end));
+                        self.blocks.reset();
+                        index = last + 1;
+                        continue;
+                    }
+                    self.push_token(index, range);
+                }
+                BorrowedToken::Div if self.oracle && is_lone_slash(self.tokens, index) => {
+                    self.finish(Some(range));
+                    self.blocks.reset();
+                }
+                BorrowedToken::SemiColon if !self.blocks.in_block() => {
+                    self.blocks.observe(self.tokens, index);
+                    if self.copy_from_stdin {
+                        index = self.finish_copy_data(index);
+                        continue;
+                    }
+                    self.finish(Some(range));
+                }
+                _ => self.push_token(index, range),
+            }
+            index += 1;
+        }
+        self.finish(None);
+    }
+
+    /// Add the token at `index` to the pending statement.
+    fn push_token(&mut self, index: usize, range: Range<usize>) {
+        let token = &self.tokens[index].token;
+        match self.pending {
+            None => {
+                let keyword = match token {
+                    BorrowedToken::Word(word) if word.quote_style.is_none() => word.keyword,
+                    _ => Keyword::NoKeyword,
+                };
+                self.pending = Some((range.start, keyword));
+            }
+            Some((_, Keyword::COPY)) if self.postgres => {
+                if matches!(token, BorrowedToken::Word(word) if word.keyword == Keyword::STDIN) {
+                    self.copy_from_stdin = true;
+                }
+            }
+            Some(_) => {}
+        }
+        // A custom delimiter ends statements even inside of blocks.
+        if self.delimiter.is_none() {
+            self.blocks.observe(self.tokens, index);
+        }
+        self.end = range.end;
+    }
+
+    /// Record the pending statement, if any, ended by `terminator`.
+    fn finish(&mut self, terminator: Option<Range<usize>>) {
+        if let Some((start, keyword)) = self.pending.take() {
+            self.statements.push(StatementRange {
+                range: start..self.end,
+                terminator,
+                keyword,
+            });
+            self.delimiters.push(self.delimiter);
+        }
+        self.copy_from_stdin = false;
+    }
+
+    /// Extend a `COPY ... FROM STDIN` statement over its inline data, which
+    /// ends at a `\.` line, and return the index after the data.
+    fn finish_copy_data(&mut self, semicolon: usize) -> usize {
+        let mut index = semicolon + 1;
+        while let Some(token) = self.tokens.get(index) {
+            let next = self.tokens.get(index + 1);
+            if let (BorrowedToken::Backslash, Some(period)) = (&token.token, next) {
+                if period.token == BorrowedToken::Period && period.span.start == token.span.end {
+                    let start = token_range(token).start;
+                    let end = token_range(period).end;
+                    self.end = self.sql[..start].trim_end().len().max(self.end);
+                    self.finish(Some(start..end));
+                    return index + 2;
+                }
+            }
+            self.end = token_range(token).end;
+            index += 1;
+        }
+        self.finish(None);
+        index
+    }
+
+    /// A MySQL client `DELIMITER` directive at the start of a line.
+    fn is_delimiter_directive(&self, index: usize) -> bool {
+        matches!(
+            &self.tokens[index].token,
+            BorrowedToken::Word(word) if word.keyword == Keyword::DELIMITER && word.quote_style.is_none()
+        ) && starts_line(self.tokens, index)
+    }
+
+    /// Switch to the delimiter named on the directive's line, and return the
+    /// index of the first token after that line.
+    fn apply_delimiter_directive(&mut self, index: usize) -> usize {
+        let directive = &self.tokens[index];
+        let start = token_range(directive).end;
+        let rest = &self.sql[start..];
+        let line = &rest[..rest.find('\n').unwrap_or(rest.len())];
+        let delimiter = line.split_whitespace().next().unwrap_or("");
+        self.delimiter = match delimiter {
+            "" | ";" => None,
+            delimiter => Some(delimiter),
+        };
+        self.blocks.reset();
+        let line_end = start + line.len();
+        let mut next = index + 1;
+        while self
+            .tokens
+            .get(next)
+            .is_some_and(|token| token_range(token).start < line_end)
+        {
+            next += 1;
+        }
+        next
+    }
+
+    /// Handle the token at `index` while a MySQL custom delimiter is active,
+    /// which ends statements wherever it appears outside of quotes, even
+    /// inside `BEGIN ... END`. Returns the index of the next token to visit.
+    fn split_custom_delimiter(&mut self, index: usize, delimiter: &str) -> usize {
+        let token = &self.tokens[index];
+        let range = token_range(token);
+        let Some(offset) = self.find_delimiter(token, range.clone(), delimiter) else {
+            self.push_token(index, range);
+            return index + 1;
+        };
+        if offset > range.start {
+            self.push_token(index, range.start..offset);
+        }
+        let terminator = offset..offset + delimiter.len();
+        self.finish(Some(terminator.clone()));
+        let mut next = index + 1;
+        while self
+            .tokens
+            .get(next)
+            .is_some_and(|token| token_range(token).start < terminator.end)
+        {
+            next += 1;
+        }
+        next
+    }
+
+    /// Offset of `delimiter` if it starts within `token`. Quoted tokens can
+    /// contain the delimiter without ending the statement.
+    fn find_delimiter(
+        &self,
+        token: &TokenWithSpan,
+        range: Range<usize>,
+        delimiter: &str,
+    ) -> Option<usize> {
+        let text = &self.sql[range.clone()];
+        if matches!(token.token, BorrowedToken::DollarQuotedString(_))
+            || text.contains(['\'', '"', '`'])
+        {
+            return None;
+        }
+        // The delimiter may continue past this token, as `//` spans two
+        // division tokens.
+        let mut end = (range.end + delimiter.len() - 1).min(self.sql.len());
+        while !self.sql.is_char_boundary(end) {
+            end += 1;
+        }
+        self.sql[range.start..end]
+            .find(delimiter)
+            .filter(|offset| *offset < text.len())
+            .map(|offset| range.start + offset)
+    }
+
+    /// For a `GO` at the start of a line, the index of the last token of
+    /// the batch separator line: `GO` and an optional repeat count.
+    fn batch_separator_end(&self, index: usize) -> Option<usize> {
+        let line = self.tokens[index].span.start.line;
+        let on_line = |i: usize| {
+            self.tokens
+                .get(i)
+                .is_some_and(|t| t.span.start.line == line)
+        };
+        let last = match self.tokens.get(index + 1) {
+            Some(TokenWithSpan {
+                token: BorrowedToken::Number(..),
+                ..
+            }) if on_line(index + 1) => index + 1,
+            _ => index,
+        };
+        (!on_line(last + 1)).then_some(last)
+    }
+}
+
+/// The byte range of a token in the tokenized text, which the tokenizer
+/// records for every token whatever the length of the text.
+fn token_range(token: &TokenWithSpan) -> Range<usize> {
+    token
+        .span
+        .byte_range()
+        .expect("tokenizer spans record byte offsets")
+}
+
+/// A block the [`BlockTracker`] is inside of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BlockFrame {
+    /// The declaration section opened by `DECLARE` or a PL/SQL
+    /// subprogram's `IS`/`AS`, which shares its `END` with the following
+    /// `BEGIN`.
+    Declarations,
+    /// A `BEGIN` or `CASE` body closed by its own `END`.
+    Body,
+}
+
+/// Tracks the procedural blocks a token stream is inside of, so that the
+/// semicolons of a block body are not mistaken for statement delimiters.
+///
+/// This is shared by [`split_statements`] and parser error recovery. It is
+/// a heuristic over keywords, not a grammar: control statements such as
+/// `IF ... END IF` are only recognized by their closing `END`.
+#[derive(Debug)]
+pub(crate) struct BlockTracker<'d> {
+    dialect: &'d dyn Dialect,
+    frames: Vec<BlockFrame>,
+    parens: usize,
+    subprogram_header: bool,
+    oracle: bool,
+}
+
+impl<'d> BlockTracker<'d> {
+    pub(crate) fn new(dialect: &'d dyn Dialect) -> Self {
+        Self {
+            dialect,
+            frames: Vec::new(),
+            parens: 0,
+            subprogram_header: false,
+            oracle: dialect.is::<OracleDialect>(),
+        }
+    }
+
+    /// Whether the tokens seen so far leave a block open.
+    pub(crate) fn in_block(&self) -> bool {
+        !self.frames.is_empty()
+    }
+
+    /// Whether the tokens seen so far leave a parenthesis open.
+    pub(crate) fn in_parens(&self) -> bool {
+        self.parens != 0
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.frames.clear();
+        self.parens = 0;
+        self.subprogram_header = false;
+    }
+
+    /// Account for the significant token at `index`.
+    pub(crate) fn observe(&mut self, tokens: &[TokenWithSpan], index: usize) {
+        match &tokens[index].token {
+            BorrowedToken::SemiColon => self.subprogram_header = false,
+            BorrowedToken::LParen => self.parens += 1,
+            BorrowedToken::RParen => self.parens = self.parens.saturating_sub(1),
+            BorrowedToken::Word(word) if word.quote_style.is_none() => match word.keyword {
+                Keyword::BEGIN
+                    if !begin_starts_transaction(
+                        self.dialect,
+                        tokens
+                            .get(significant_index(tokens, index + 1))
+                            .map(|next| &next.token),
+                    ) =>
+                {
+                    match self.frames.last_mut() {
+                        Some(frame @ BlockFrame::Declarations) => *frame = BlockFrame::Body,
+                        _ => self.frames.push(BlockFrame::Body),
+                    }
+                }
+                Keyword::CASE if self.in_block() => self.frames.push(BlockFrame::Body),
+                Keyword::END if !end_closes_control_statement(tokens, index) => {
+                    self.frames.pop();
+                }
+                Keyword::DECLARE if self.oracle => self.frames.push(BlockFrame::Declarations),
+                Keyword::PROCEDURE | Keyword::FUNCTION | Keyword::PACKAGE | Keyword::BODY
+                    if self.oracle && !self.in_parens() =>
+                {
+                    self.subprogram_header = true
+                }
+                Keyword::IS | Keyword::AS
+                    if self.oracle && self.subprogram_header && !self.in_parens() =>
+                {
+                    self.frames.push(BlockFrame::Declarations);
+                    self.subprogram_header = false;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+/// Whether a `BEGIN` followed by `next`, or by nothing, starts a transaction
+/// rather than a procedural block in `dialect`.
+///
+/// The parser decides how to parse `BEGIN` with this, and [`BlockTracker`]
+/// uses it to find the blocks the parser will parse, so the two agree.
+pub(crate) fn begin_starts_transaction(
+    dialect: &dyn Dialect,
+    next: Option<&BorrowedToken>,
+) -> bool {
+    match next {
+        None | Some(BorrowedToken::SemiColon | BorrowedToken::EOF) => true,
+        Some(BorrowedToken::Word(word)) => match word.keyword {
+            Keyword::TRANSACTION | Keyword::WORK | Keyword::ISOLATION | Keyword::READ => true,
+            Keyword::DEFERRED
+            | Keyword::IMMEDIATE
+            | Keyword::EXCLUSIVE
+            | Keyword::TRY
+            | Keyword::CATCH => dialect.supports_start_transaction_modifier(),
+            // T-SQL `BEGIN TRAN` and `BEGIN DISTRIBUTED TRANSACTION`
+            _ => {
+                dialect.supports_start_transaction_modifier()
+                    && (word.value.eq_ignore_ascii_case("TRAN")
+                        || word.value.eq_ignore_ascii_case("DISTRIBUTED"))
+            }
+        },
+        _ => false,
+    }
+}
+
+/// Whether the `END` at `index` closes a control statement such as
+/// `END IF` or `END LOOP`, whose opening keyword is not tracked.
+fn end_closes_control_statement(tokens: &[TokenWithSpan], index: usize) -> bool {
+    match tokens.get(significant_index(tokens, index + 1)) {
+        Some(TokenWithSpan {
+            token: BorrowedToken::Word(word),
+            ..
+        }) => matches!(
+            word.keyword,
+            Keyword::IF | Keyword::LOOP | Keyword::WHILE | Keyword::REPEAT | Keyword::FOR
+        ),
+        _ => false,
+    }
+}
+
+/// Whether the token at `index` is a `/` alone on its line, which ends a
+/// PL/SQL unit in Oracle scripts.
+pub(crate) fn is_lone_slash(tokens: &[TokenWithSpan], index: usize) -> bool {
+    let Some(slash) = tokens.get(index) else {
+        return false;
+    };
+    if slash.token != BorrowedToken::Div || slash.span.start.line == 0 {
+        return false;
+    }
+    let line = slash.span.start.line;
+    starts_line(tokens, index)
+        && tokens
+            .get(significant_index(tokens, index + 1))
+            .is_none_or(|token| token.token == BorrowedToken::EOF || token.span.start.line > line)
+}
+
+/// Whether no significant token precedes the one at `index` on its line.
+fn starts_line(tokens: &[TokenWithSpan], index: usize) -> bool {
+    let line = tokens[index].span.start.line;
+    tokens[..index]
+        .iter()
+        .rev()
+        .find(|token| !matches!(token.token, BorrowedToken::Whitespace(_)))
+        .is_none_or(|token| token.span.end.line < line)
+}
+
+/// Index of the first token at or after `index` that is not whitespace.
+pub(crate) fn significant_index(tokens: &[TokenWithSpan], mut index: usize) -> usize {
+    while matches!(
+        tokens.get(index),
+        Some(TokenWithSpan {
+            token: BorrowedToken::Whitespace(_),
+            ..
+        })
+    ) {
+        index += 1;
+    }
+    index
+}
+
+#[cfg(feature = "std")]
+mod reader {
+    use super::*;
+    use crate::ast::Statement;
+    use crate::parser::{Parser, ParserError};
+    use crate::tokenizer::{Location, Span, Whitespace};
+    use std::collections::VecDeque;
+    use std::fmt;
+    use std::io::{self, BufRead, Read};
+
+    /// Bytes read past the last scan before the buffer is scanned again.
+    const SCAN_BYTES: usize = 8 * 1024;
+
+    /// Tokens ending within this many bytes of the end of the text read so
+    /// far are tokenized again by the next scan. The tokenizer looks only a
+    /// few characters past a lexeme to decide where it ends.
+    const LOOKAHEAD_BYTES: usize = 64;
+
+    /// A statement read by a [`StatementReader`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ScriptStatement {
+        /// The statement text, from the start of its first token to the end
+        /// of its last token.
+        pub sql: String,
+        /// The text that ended the statement, as in
+        /// [`StatementRange::terminator`].
+        pub terminator: Option<String>,
+        /// The keyword the statement starts with, or
+        /// [`Keyword::NoKeyword`].
+        pub keyword: Keyword,
+        /// Line and column of the statement in the script.
+        pub location: Location,
+        /// UTF-8 byte offset of the statement in the script.
+        pub offset: u64,
+    }
+
+    impl ScriptStatement {
+        /// Tokenize the statement, with lines and columns of the script.
+        pub fn tokenize<'a>(
+            &'a self,
+            dialect: &'a dyn Dialect,
+        ) -> Result<Vec<TokenWithSpan<'a>>, TokenizerError> {
+            Tokenizer::new(dialect, &self.sql)
+                .with_start_location(self.location)
+                .tokenize_with_location()
+        }
+
+        /// Parse the statement, with lines and columns of the script in its
+        /// spans and errors.
+        pub fn parse(&self, dialect: &dyn Dialect) -> Result<Vec<Statement>, ParserError> {
+            Parser::new(dialect)
+                .try_with_sql_at(&self.sql, self.location)?
+                .parse_statements()
+        }
+    }
+
+    /// An error reading statements from a script.
+    #[derive(Debug)]
+    pub enum StatementReadError {
+        /// Reading the script failed, or it is not valid UTF-8.
+        Io(io::Error),
+        /// The script could not be tokenized. The location is in the script.
+        Tokenizer(TokenizerError),
+    }
+
+    impl fmt::Display for StatementReadError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                StatementReadError::Io(error) => write!(f, "error reading script: {error}"),
+                StatementReadError::Tokenizer(error) => error.fmt(f),
+            }
+        }
+    }
+
+    impl std::error::Error for StatementReadError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                StatementReadError::Io(error) => Some(error),
+                StatementReadError::Tokenizer(error) => Some(error),
+            }
+        }
+    }
+
+    impl From<io::Error> for StatementReadError {
+        fn from(error: io::Error) -> Self {
+            StatementReadError::Io(error)
+        }
+    }
+
+    impl From<TokenizerError> for StatementReadError {
+        fn from(error: TokenizerError) -> Self {
+            StatementReadError::Tokenizer(error)
+        }
+    }
+
+    /// Splits a script into statements as it is read, following the same
+    /// rules as [`split_statements`], without holding the whole script in
+    /// memory.
+    ///
+    /// Only the text from the start of the last, possibly incomplete,
+    /// statement is kept between reads, so string literals, dollar-quoted
+    /// bodies, `COPY` data and UTF-8 characters may straddle chunks. Its
+    /// tokens are kept too, so each read is tokenized once. A tokenizer
+    /// error follows the statements before it as soon as more input cannot
+    /// resolve it; an unterminated literal is only an error at the end of
+    /// the script.
+    ///
+    /// ```
+    /// # use sqlparser::dialect::PostgreSqlDialect;
+    /// # use sqlparser::splitter::StatementReader;
+    /// # use sqlparser::tokenizer::Location;
+    /// let chunks = ["SELECT 'a;", "b';\nCREATE FUNCTION f() AS $$ SEL", "ECT 1; $$"];
+    /// let statements = StatementReader::from_chunks(&PostgreSqlDialect {}, chunks)
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    /// assert_eq!(statements[0].sql, "SELECT 'a;b'");
+    /// assert_eq!(statements[1].sql, "CREATE FUNCTION f() AS $$ SELECT 1; $$");
+    /// assert_eq!(statements[1].location, Location::new(2, 1));
+    /// assert_eq!(statements[1].offset, 14);
+    /// ```
+    pub struct StatementReader<'d, R> {
+        dialect: &'d dyn Dialect,
+        reader: R,
+        /// Text read but not yet split off, which starts at `location` and
+        /// `offset` in the script.
+        buffer: String,
+        location: Location,
+        offset: u64,
+        /// The MySQL delimiter in effect at the start of `buffer`.
+        delimiter: Option<String>,
+        /// Leading bytes of a UTF-8 character cut by the end of a read.
+        partial_char: Vec<u8>,
+        /// Tokens of `buffer` that more text cannot change, with byte
+        /// offsets into `buffer`.
+        tokens: Vec<TokenWithSpan<'static>>,
+        /// Where tokenizing resumes after `tokens`: a location in the script
+        /// with its byte offset in `buffer`.
+        resume: Location,
+        /// Buffer length from which the buffer is scanned again. It doubles
+        /// while a statement is too long to complete, so that a long
+        /// statement is not split again for every read.
+        scan_at: usize,
+        ready: VecDeque<ScriptStatement>,
+        error: Option<TokenizerError>,
+        eof: bool,
+        done: bool,
+    }
+
+    impl<'d, R: BufRead> StatementReader<'d, R> {
+        /// Read statements from `reader`.
+        pub fn new(dialect: &'d dyn Dialect, reader: R) -> Self {
+            Self {
+                dialect,
+                reader,
+                buffer: String::new(),
+                location: Location::new(1, 1),
+                offset: 0,
+                delimiter: None,
+                partial_char: Vec::new(),
+                tokens: Vec::new(),
+                resume: Location::new(1, 1).with_byte_offset(0),
+                scan_at: SCAN_BYTES,
+                ready: VecDeque::new(),
+                error: None,
+                eof: false,
+                done: false,
+            }
+        }
+
+        /// Append the next read to the buffer.
+        fn read(&mut self) -> io::Result<()> {
+            let bytes = loop {
+                match self.reader.fill_buf() {
+                    Ok(bytes) => break bytes,
+                    Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                    Err(error) => return Err(error),
+                }
+            };
+            if bytes.is_empty() {
+                self.eof = true;
+                return if self.partial_char.is_empty() {
+                    Ok(())
+                } else {
+                    Err(invalid_utf8())
+                };
+            }
+            let len = bytes.len();
+            self.partial_char.extend_from_slice(bytes);
+            self.reader.consume(len);
+
+            let valid = match core::str::from_utf8(&self.partial_char) {
+                Ok(text) => text.len(),
+                Err(error) if error.error_len().is_none() => error.valid_up_to(),
+                Err(_) => return Err(invalid_utf8()),
+            };
+            let text =
+                core::str::from_utf8(&self.partial_char[..valid]).map_err(|_| invalid_utf8())?;
+            self.buffer.push_str(text);
+            self.partial_char.drain(..valid);
+            Ok(())
+        }
+
+        /// Split the complete statements off the buffer. Before the end of
+        /// the script the last statement is kept, as the next read may
+        /// continue it.
+        fn scan(&mut self) {
+            let mut tokens = core::mem::take(&mut self.tokens);
+            let resume = self.resume.byte_offset().unwrap_or(0);
+            let previous = tokens.last().map(|last| {
+                if token_range(last).end < resume {
+                    BorrowedToken::Whitespace(Whitespace::Space)
+                } else {
+                    last.token.clone()
+                }
+            });
+            let mut fresh = Vec::new();
+            let (error, last_scan) = match Tokenizer::new(self.dialect, &self.buffer)
+                .tokenize_significant_from(self.resume, previous, &mut fresh)
+            {
+                Ok(()) => (None, self.eof),
+                // More text could still close an unterminated literal.
+                Err((_, true)) if !self.eof => (None, false),
+                Err((error, _)) => (Some(error), true),
+            };
+            // The tokenizer may have looked at the end of the text for the
+            // tokens ending close to it, so they are tokenized again.
+            let limit = self.buffer.len().saturating_sub(LOOKAHEAD_BYTES);
+            let settled = match fresh.iter().position(|t| token_range(t).end > limit) {
+                Some(index) if !last_scan => index,
+                _ => fresh.len(),
+            };
+            let mut resume = match fresh.get(settled) {
+                Some(token) => token.span.start,
+                None => fresh.last().map_or(self.resume, |token| token.span.end),
+            };
+            let settled = tokens.len() + settled;
+            tokens.extend(fresh.into_iter().map(TokenWithSpan::to_static));
+
+            let mut splitter = Splitter::new(self.dialect, &self.buffer, &tokens);
+            splitter.delimiter = self.delimiter.as_deref();
+            splitter.run();
+
+            let statements = &splitter.statements;
+            // At the end of the script, or at an error that more text cannot
+            // resolve, only an unterminated statement cut short by a
+            // tokenizer error is held back.
+            let complete = match statements.last() {
+                Some(last) if last_scan && (error.is_none() || last.terminator.is_some()) => {
+                    statements.len()
+                }
+                _ => statements.len().saturating_sub(1),
+            };
+            let mut position = 0;
+            let mut location = self.location;
+            for statement in &statements[..complete] {
+                location = advance(location, &self.buffer[position..statement.range.start]);
+                position = statement.range.start;
+                self.ready.push_back(ScriptStatement {
+                    sql: self.buffer[statement.range.clone()].to_owned(),
+                    terminator: statement
+                        .terminator
+                        .clone()
+                        .map(|range| self.buffer[range].to_owned()),
+                    keyword: statement.keyword,
+                    location,
+                    offset: self.offset + statement.range.start as u64,
+                });
+            }
+
+            if last_scan {
+                self.error = error;
+                self.done = true;
+                return;
+            }
+            let Some(last) = complete.checked_sub(1) else {
+                tokens.truncate(settled);
+                self.tokens = tokens;
+                self.resume = resume;
+                self.scan_at = self.scan_at.saturating_mul(2);
+                return;
+            };
+            // Keep the terminator of the last statement split off, so that
+            // the next scan sees what precedes the held statement on its
+            // line. `COPY` data ends after its `\.` line.
+            let cut = match &statements[last].terminator {
+                Some(range) if &self.buffer[range.clone()] == "\\." => range.end,
+                Some(range) => range.start,
+                None => statements[last].range.end,
+            };
+            let delimiter = splitter.delimiters[last].map(str::to_owned);
+            self.location = advance(location, &self.buffer[position..cut]);
+            self.offset += cut as u64;
+            self.delimiter = delimiter;
+            self.buffer.drain(..cut);
+            self.scan_at = self.buffer.len() + SCAN_BYTES;
+
+            // Keep the settled tokens after the cut, with offsets into the
+            // drained buffer. Tokens of the statements split off are final,
+            // but a MySQL delimiter can end within a token, whose rest is
+            // then tokenized again.
+            tokens.truncate(settled);
+            let dropped = tokens.partition_point(|token| token_range(token).start < cut);
+            let straddles = dropped
+                .checked_sub(1)
+                .is_some_and(|last| token_range(&tokens[last]).end > cut);
+            if straddles || resume.byte_offset().unwrap_or(0) < cut {
+                tokens.clear();
+                resume = self.location.with_byte_offset(cut);
+            } else {
+                tokens.drain(..dropped);
+            }
+            for token in &mut tokens {
+                token.span = Span::new(shift(token.span.start, cut), shift(token.span.end, cut));
+            }
+            self.tokens = tokens;
+            self.resume = shift(resume, cut);
+        }
+    }
+
+    impl<'d, I> StatementReader<'d, ChunkReader<I>>
+    where
+        I: Iterator,
+        I::Item: AsRef<[u8]>,
+    {
+        /// Read statements from a sequence of chunks of the script.
+        pub fn from_chunks(
+            dialect: &'d dyn Dialect,
+            chunks: impl IntoIterator<IntoIter = I>,
+        ) -> Self {
+            Self::new(dialect, ChunkReader::new(chunks))
+        }
+    }
+
+    impl<R: BufRead> Iterator for StatementReader<'_, R> {
+        type Item = Result<ScriptStatement, StatementReadError>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            loop {
+                if let Some(statement) = self.ready.pop_front() {
+                    return Some(Ok(statement));
+                }
+                if let Some(error) = self.error.take() {
+                    return Some(Err(error.into()));
+                }
+                if self.done {
+                    return None;
+                }
+                if !self.eof && self.buffer.len() < self.scan_at {
+                    if let Err(error) = self.read() {
+                        self.done = true;
+                        return Some(Err(error.into()));
+                    }
+                } else {
+                    self.scan();
+                }
+            }
+        }
+    }
+
+    /// A [`BufRead`] over a sequence of byte chunks.
+    pub struct ChunkReader<I: Iterator> {
+        chunks: I,
+        chunk: Option<I::Item>,
+        position: usize,
+    }
+
+    impl<I> ChunkReader<I>
+    where
+        I: Iterator,
+        I::Item: AsRef<[u8]>,
+    {
+        /// Read `chunks` one after another.
+        pub fn new(chunks: impl IntoIterator<IntoIter = I>) -> Self {
+            Self {
+                chunks: chunks.into_iter(),
+                chunk: None,
+                position: 0,
+            }
+        }
+    }
+
+    impl<I> Read for ChunkReader<I>
+    where
+        I: Iterator,
+        I::Item: AsRef<[u8]>,
+    {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let available = self.fill_buf()?;
+            let len = available.len().min(buf.len());
+            buf[..len].copy_from_slice(&available[..len]);
+            self.consume(len);
+            Ok(len)
+        }
+    }
+
+    impl<I> BufRead for ChunkReader<I>
+    where
+        I: Iterator,
+        I::Item: AsRef<[u8]>,
+    {
+        fn fill_buf(&mut self) -> io::Result<&[u8]> {
+            loop {
+                if let Some(chunk) = &self.chunk {
+                    if self.position < chunk.as_ref().len() {
+                        break;
+                    }
+                }
+                match self.chunks.next() {
+                    Some(chunk) => {
+                        self.chunk = Some(chunk);
+                        self.position = 0;
+                    }
+                    None => return Ok(&[]),
+                }
+            }
+            Ok(self
+                .chunk
+                .as_ref()
+                .map_or(&[][..], |chunk| &chunk.as_ref()[self.position..]))
+        }
+
+        fn consume(&mut self, amt: usize) {
+            self.position += amt;
+        }
+    }
+
+    /// `location`, with its byte offset into a buffer whose first `cut`
+    /// bytes are drained.
+    fn shift(location: Location, cut: usize) -> Location {
+        let offset = location.byte_offset().map_or(0, |offset| offset - cut);
+        Location::new(location.line, location.column).with_byte_offset(offset)
+    }
+
+    fn invalid_utf8() -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, "script is not valid UTF-8")
+    }
+
+    /// The location after `text` when it starts at `location`, counted as
+    /// the tokenizer counts lines and columns.
+    fn advance(location: Location, text: &str) -> Location {
+        match text.rfind('\n') {
+            Some(last) => Location::new(
+                location.line + text.matches('\n').count() as u64,
+                1 + text[last + 1..].chars().count() as u64,
+            ),
+            None => Location::new(location.line, location.column + text.chars().count() as u64),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub use reader::{ChunkReader, ScriptStatement, StatementReadError, StatementReader};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split<'a>(dialect: &dyn Dialect, sql: &'a str) -> Vec<(&'a str, Option<&'a str>, Keyword)> {
+        split_statements(dialect, sql)
+            .unwrap()
+            .into_iter()
+            .map(|statement| {
+                (
+                    &sql[statement.range],
+                    statement.terminator.map(|range| &sql[range]),
+                    statement.keyword,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn splits_on_semicolons_outside_quotes_and_comments() {
+        let sql = "SELECT ';' /* ; */; ;\n-- ;\n(SELECT \"a;b\") ; DELETE FROM t";
+        assert_eq!(
+            split(&PostgreSqlDialect {}, sql),
+            vec![
+                ("SELECT ';'", Some(";"), Keyword::SELECT),
+                ("(SELECT \"a;b\")", Some(";"), Keyword::NoKeyword),
+                ("DELETE FROM t", None, Keyword::DELETE),
+            ]
+        );
+    }
+
+    #[test]
+    fn postgres_bodies_keep_their_semicolons() {
+        let sql = "CREATE FUNCTION f() RETURNS int LANGUAGE plpgsql AS $$ BEGIN RETURN 1; END $$;\n\
+                   CREATE FUNCTION g() RETURNS int BEGIN ATOMIC SELECT 1; SELECT CASE WHEN true THEN 2 END; END;\n\
+                   BEGIN; COMMIT;";
+        let statements = split(&PostgreSqlDialect {}, sql);
+        assert_eq!(statements.len(), 4);
+        assert!(statements[1].0.ends_with("THEN 2 END; END"));
+        assert_eq!(statements[2], ("BEGIN", Some(";"), Keyword::BEGIN));
+        assert_eq!(statements[3], ("COMMIT", Some(";"), Keyword::COMMIT));
+    }
+
+    #[test]
+    fn postgres_copy_from_stdin_keeps_its_data() {
+        let sql = "COPY t (a, b) FROM stdin;\n1\tx;y\n2\tz\n\\.\nSELECT 1;";
+        assert_eq!(
+            split(&PostgreSqlDialect {}, sql),
+            vec![
+                (
+                    "COPY t (a, b) FROM stdin;\n1\tx;y\n2\tz",
+                    Some("\\."),
+                    Keyword::COPY
+                ),
+                ("SELECT 1", Some(";"), Keyword::SELECT),
+            ]
+        );
+    }
+
+    #[test]
+    fn mysql_delimiter_directives() {
+        let sql = "DELIMITER $$\n\
+                   CREATE PROCEDURE p() BEGIN SELECT '$$'; SELECT 2; END$$\n\
+                   delimiter //\n\
+                   CREATE TRIGGER t BEFORE INSERT ON x FOR EACH ROW SET @a = 1//\n\
+                   DELIMITER ;\n\
+                   SELECT 3;";
+        assert_eq!(
+            split(&MySqlDialect {}, sql),
+            vec![
+                (
+                    "CREATE PROCEDURE p() BEGIN SELECT '$$'; SELECT 2; END",
+                    Some("$$"),
+                    Keyword::CREATE
+                ),
+                (
+                    "CREATE TRIGGER t BEFORE INSERT ON x FOR EACH ROW SET @a = 1",
+                    Some("//"),
+                    Keyword::CREATE
+                ),
+                ("SELECT 3", Some(";"), Keyword::SELECT),
+            ]
+        );
+    }
+
+    #[test]
+    fn mssql_go_batches() {
+        let sql = "CREATE PROCEDURE p AS BEGIN SELECT 1; SELECT 2 END\nGO\n\
+                   BEGIN TRAN; SELECT go FROM t\ngo 5\nBEGIN TRY SELECT 1; END TRY BEGIN CATCH SELECT 2; END CATCH";
+        assert_eq!(
+            split(&MsSqlDialect {}, sql),
+            vec![
+                (
+                    "CREATE PROCEDURE p AS BEGIN SELECT 1; SELECT 2 END",
+                    Some("GO"),
+                    Keyword::CREATE
+                ),
+                ("BEGIN TRAN", Some(";"), Keyword::BEGIN),
+                ("SELECT go FROM t", Some("go 5"), Keyword::SELECT),
+                // The parser reads `BEGIN TRY` and `BEGIN CATCH` as
+                // transaction statements rather than blocks
+                ("BEGIN TRY SELECT 1", Some(";"), Keyword::BEGIN),
+                ("END TRY BEGIN CATCH SELECT 2", Some(";"), Keyword::END),
+                ("END CATCH", None, Keyword::END),
+            ]
+        );
+    }
+
+    #[test]
+    fn oracle_plsql_units_end_at_a_lone_slash() {
+        let sql = "CREATE OR REPLACE PACKAGE BODY p AS\n\
+                   PROCEDURE a IS\n\
+                   x NUMBER;\n\
+                   BEGIN\n\
+                   IF x > 1 THEN\n\
+                   x := CASE WHEN x = 1 THEN 2 END;\n\
+                   END IF;\n\
+                   END a;\n\
+                   END p;\n\
+                   /\n\
+                   SELECT 6\n\
+                   / 3 FROM dual;\n\
+                   BEGIN NULL; END;\n\
+                   /";
+        assert_eq!(
+            split(&OracleDialect {}, sql),
+            vec![
+                (
+                    &sql[..sql.find("END p").unwrap() + 5],
+                    Some(";"),
+                    Keyword::CREATE
+                ),
+                ("SELECT 6\n/ 3 FROM dual", Some(";"), Keyword::SELECT),
+                ("BEGIN NULL; END", Some(";"), Keyword::BEGIN),
+            ]
+        );
+
+        // Without a closing semicolon the slash ends the unit.
+        let sql = "DECLARE x NUMBER; BEGIN x := 1; END\n/\nSELECT 1 FROM dual";
+        assert_eq!(
+            split(&OracleDialect {}, sql),
+            vec![
+                (
+                    "DECLARE x NUMBER; BEGIN x := 1; END",
+                    Some("/"),
+                    Keyword::DECLARE
+                ),
+                ("SELECT 1 FROM dual", None, Keyword::SELECT),
+            ]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    mod reader {
+        use super::*;
+        use crate::ast::Spanned;
+        use crate::tokenizer::Location;
+        use std::collections::HashMap;
+        use std::io::ErrorKind;
+
+        /// Read `sql` in chunks of `size` bytes, which may cut UTF-8
+        /// characters, and check the statements against
+        /// [`split_statements`] over the whole script.
+        fn assert_reads_like_split(dialect: &dyn Dialect, sql: &str, size: usize) {
+            let locations: HashMap<usize, Location> = Tokenizer::new(dialect, sql)
+                .tokenize_with_location()
+                .unwrap()
+                .into_iter()
+                .filter_map(|token| Some((token.span.byte_range()?.start, token.span.start)))
+                .collect();
+            let expected = split_statements(dialect, sql).unwrap();
+            let statements = StatementReader::from_chunks(dialect, sql.as_bytes().chunks(size))
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(statements.len(), expected.len(), "chunk size {size}");
+            for (statement, expected) in statements.iter().zip(&expected) {
+                assert_eq!(statement.sql, sql[expected.range.clone()]);
+                assert_eq!(
+                    statement.terminator.as_deref(),
+                    expected.terminator.clone().map(|range| &sql[range])
+                );
+                assert_eq!(statement.keyword, expected.keyword);
+                assert_eq!(statement.offset, expected.range.start as u64);
+                assert_eq!(statement.location, locations[&expected.range.start]);
+            }
+        }
+
+        fn assert_reads_like_split_in_any_chunks(dialect: &dyn Dialect, script: &str) {
+            // Long enough that statements are split off between reads.
+            let sql = script.repeat(16 * 1024 / script.len() + 2);
+            for size in [1, 2, 3, 7, 100, 4096, sql.len()] {
+                assert_reads_like_split(dialect, &sql, size);
+            }
+        }
+
+        #[test]
+        fn postgres_chunks_straddle_literals_and_bodies() {
+            assert_reads_like_split_in_any_chunks(
+                &PostgreSqlDialect {},
+                "SELECT 'é;€', \"na;me\" /* ; 😀 */ FROM t;\n\
+                 CREATE FUNCTION f() RETURNS int LANGUAGE sql AS $body$ SELECT 1; $body$;\n\
+                 CREATE FUNCTION g() RETURNS int BEGIN ATOMIC SELECT 1; SELECT 2; END;\n\
+                 COPY t (a) FROM stdin;\nx;ü\n\\.\n\
+                 -- trailing ; comment\n",
+            );
+        }
+
+        #[test]
+        fn mysql_delimiters_carry_across_chunks() {
+            assert_reads_like_split_in_any_chunks(
+                &MySqlDialect {},
+                "DELIMITER $$\n\
+                 CREATE PROCEDURE p() BEGIN SELECT '$$'; SELECT 2; END$$\n\
+                 SELECT 'ö' $$\n\
+                 DELIMITER ;\n\
+                 SELECT `a;b` FROM t;\n",
+            );
+        }
+
+        #[test]
+        fn mssql_and_oracle_separators_across_chunks() {
+            assert_reads_like_split_in_any_chunks(
+                &MsSqlDialect {},
+                "SELECT 1; GO\nBEGIN TRAN; SELECT N'ä'\nGO 2\nSELECT go FROM t;\n",
+            );
+            assert_reads_like_split_in_any_chunks(
+                &OracleDialect {},
+                "BEGIN NULL; END;\n/\nDECLARE x NUMBER; BEGIN x := 1; END\n/\nSELECT 6\n/ 3 FROM dual;\n",
+            );
+        }
+
+        #[test]
+        fn statements_parse_with_script_locations() {
+            let sql = "SELECT 1;\n\n  SELECT a,\n  b FROM t;\nSELECT 2 + );";
+            let dialect = PostgreSqlDialect {};
+            let statements = StatementReader::new(&dialect, sql.as_bytes())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(statements[1].location, Location::new(3, 3));
+            let tokens = statements[1].tokenize(&dialect).unwrap();
+            let b = tokens
+                .iter()
+                .find(
+                    |token| matches!(&token.token, BorrowedToken::Word(word) if word.value == "b"),
+                )
+                .unwrap();
+            assert_eq!(b.span.start, Location::new(4, 3));
+
+            let parsed = statements[1].parse(&dialect).unwrap();
+            assert_eq!(parsed[0].span().start, Location::new(3, 3));
+            let error = statements[2].parse(&dialect).unwrap_err().to_string();
+            assert!(error.contains("Line: 5"), "{error}");
+        }
+
+        #[test]
+        fn errors_follow_the_statements_before_them() {
+            let dialect = PostgreSqlDialect {};
+            let mut statements =
+                StatementReader::from_chunks(&dialect, ["SELECT 1;\nSELECT 'a", "b"]);
+            assert_eq!(statements.next().unwrap().unwrap().sql, "SELECT 1");
+            match statements.next() {
+                Some(Err(StatementReadError::Tokenizer(error))) => {
+                    assert_eq!(error.location, Location::new(2, 8))
+                }
+                other => panic!("expected a tokenizer error, got {other:?}"),
+            }
+            assert!(statements.next().is_none());
+
+            for chunks in [&[&b"SELECT '\xff';"[..]][..], &[b"SELECT 1; SELECT '\xc3"]] {
+                let mut statements = StatementReader::from_chunks(&dialect, chunks);
+                match statements.next() {
+                    Some(Err(StatementReadError::Io(error))) => {
+                        assert_eq!(error.kind(), ErrorKind::InvalidData)
+                    }
+                    other => panic!("expected an invalid UTF-8 error, got {other:?}"),
+                }
+                assert!(statements.next().is_none());
+            }
+        }
+
+        #[test]
+        fn errors_do_not_wait_for_the_end_of_the_script() {
+            // More text cannot repair a bad escape, so the error is reported
+            // without reading the rest of the script.
+            let dialect = PostgreSqlDialect {};
+            let script = format!(
+                "SELECT 1;\nSELECT E'\\u00zz';\n{}",
+                "SELECT 2;\n".repeat(1024)
+            );
+            let chunks = std::iter::once(script.into_bytes())
+                .chain(std::iter::from_fn(|| panic!("read past the error")));
+            let mut statements = StatementReader::from_chunks(&dialect, chunks);
+            assert_eq!(statements.next().unwrap().unwrap().sql, "SELECT 1");
+            match statements.next() {
+                Some(Err(StatementReadError::Tokenizer(error))) => {
+                    assert_eq!(error.location, Location::new(2, 8))
+                }
+                other => panic!("expected a tokenizer error, got {other:?}"),
+            }
+            assert!(statements.next().is_none());
+        }
+    }
+}
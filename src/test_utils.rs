@@ -23,12 +23,7 @@
 // via `tests/test_utils/helpers`.
 
 #[cfg(not(feature = "std"))]
-use alloc::{
-    boxed::Box,
-    string::{String, ToString},
-    vec,
-    vec::Vec,
-};
+use alloc::{boxed::Box, string::ToString, vec, vec::Vec};
 use core::fmt::Debug;
 #[cfg(feature = "std")]
 use std::boxed::Box;
@@ -76,6 +71,26 @@ impl TestedDialects {
         self
     }
 
+    /// Round-trip `sql` as a [`ParsedSql`](crate::ParsedSql) document of
+    /// `dialect` through the AST codec, as caches store documents.
+    #[cfg(feature = "std")]
+    fn assert_document_codec_roundtrip(&self, dialect: &dyn Dialect, sql: &str) {
+        let options = self.options.clone().unwrap_or_else(|| {
+            ParserOptions::new().with_trailing_commas(dialect.supports_trailing_commas())
+        });
+        let document = crate::ParsedSql::parse_with_options(dialect, sql, options)
+            .unwrap_or_else(|e| panic!("{e}: {sql}"));
+        let bytes = document.to_bytes();
+        let decoded = crate::ParsedSql::from_bytes(&bytes).expect("encoded document decodes");
+        assert_eq!(decoded.source(), document.source());
+        assert_eq!(decoded.statements(), document.statements(), "{sql}");
+        assert_eq!(decoded.optimizer_hints(), document.optimizer_hints());
+        assert!(
+            decoded.to_bytes() == bytes,
+            "AST codec changed document: {sql}"
+        );
+    }
+
     fn new_parser<'a>(&self, dialect: &'a dyn Dialect) -> Parser<'a> {
         let parser = Parser::new(dialect);
         let parser = if let Some(options) = &self.options {
@@ -181,6 +196,11 @@ impl TestedDialects {
             }
             success_count += 1;
             let stmt = stmts.pop().unwrap();
+            assert_codec_roundtrip(&stmt);
+            #[cfg(feature = "std")]
+            if self.recursion_limit.is_none() {
+                self.assert_document_codec_roundtrip(&**dialect, sql);
+            }
 
             // Verify roundtrip: parse -> display -> parse produces same AST
             let serialized = stmt.to_string();
@@ -232,6 +252,11 @@ impl TestedDialects {
                 .expect(sql)
                 .parse_statements()
                 .expect(sql);
+            stmts.iter().for_each(assert_codec_roundtrip);
+            #[cfg(feature = "std")]
+            if self.recursion_limit.is_none() {
+                self.assert_document_codec_roundtrip(&**dialect, sql);
+            }
 
             // Verify roundtrip per-dialect
             let serialized = stmts
@@ -264,6 +289,7 @@ impl TestedDialects {
             .run_parser_method(sql, |parser| parser.parse_expr())
             .unwrap();
         assert_eq!(canonical, &ast.to_string());
+        assert_codec_roundtrip(&ast);
         ast
     }
 
@@ -379,6 +405,16 @@ where
     all_dialects_where(|d| !except(d))
 }
 
+/// Check that `value` decodes from its [`AstCodec`] encoding to an equal
+/// value that encodes to the same bytes, so that spans and quote styles,
+/// which equality ignores, survive as well.
+pub fn assert_codec_roundtrip<T: AstCodec + Debug + PartialEq + 'static>(value: &T) {
+    let bytes = value.to_bytes();
+    let decoded = T::from_bytes(&bytes).expect("encoded AST decodes");
+    assert_eq!(value, &decoded);
+    assert!(decoded.to_bytes() == bytes, "AST codec changed {value:?}");
+}
+
 pub fn assert_eq_vec<T: ToString>(expected: &[&str], actual: &[T]) {
     assert_eq!(
         expected,
@@ -397,7 +433,7 @@ pub fn only<T>(v: impl IntoIterator<Item = T>) -> T {
 
 pub fn expr_from_projection(item: &SelectItem) -> &Expr {
     match item {
-        SelectItem::UnnamedExpr(expr) => expr,
+        SelectItem::UnnamedExpr { expr, .. } => expr,
         _ => panic!("Expected UnnamedExpr"),
     }
 }
@@ -420,25 +456,29 @@ pub fn alter_table_op(stmt: Statement) -> AlterTableOperation {
 
 /// Creates a `Value::Number`, panic'ing if n is not a number
 pub fn number(n: &str) -> Value {
-    Value::Number(n.parse().unwrap(), false)
+    #[cfg(feature = "bigdecimal")]
+    let n = crate::ast::Box::new(n.parse().unwrap());
+    #[cfg(not(feature = "bigdecimal"))]
+    let n = n.parse().unwrap();
+    Value::Number(n, false)
 }
 
 /// Creates a [Value::SingleQuotedString]
-pub fn single_quoted_string(s: impl Into<String>) -> Value {
+pub fn single_quoted_string(s: impl Into<AstStr>) -> Value {
     Value::SingleQuotedString(s.into())
 }
 
-pub fn table_alias(name: impl Into<String>) -> Option<TableAlias> {
+pub fn table_alias(name: impl Into<AstStr>) -> Option<TableAlias> {
     Some(TableAlias {
         name: Ident::new(name),
-        columns: vec![],
+        columns: AstVec::new(),
         implicit: false,
     })
 }
 
-pub fn table(name: impl Into<String>) -> TableFactor {
+pub fn table(name: impl Into<AstStr>) -> TableFactor {
     TableFactor::Table {
-        name: ObjectName::from(vec![Ident::new(name.into())]),
+        name: ObjectName::from(vec![Ident::new(name)]),
         alias: None,
         args: None,
         with_hints: vec![],
@@ -468,12 +508,12 @@ pub fn table_from_name(name: ObjectName) -> TableFactor {
     }
 }
 
-pub fn table_with_alias(name: impl Into<String>, alias: impl Into<String>) -> TableFactor {
+pub fn table_with_alias(name: impl Into<AstStr>, alias: impl Into<AstStr>) -> TableFactor {
     TableFactor::Table {
         name: ObjectName::from(vec![Ident::new(name)]),
         alias: Some(TableAlias {
             name: Ident::new(alias),
-            columns: vec![],
+            columns: AstVec::new(),
             implicit: false,
         }),
         args: None,
@@ -497,7 +537,7 @@ pub fn join(relation: TableFactor) -> Join {
 }
 
 pub fn call(function: &str, args: impl IntoIterator<Item = Expr>) -> Expr {
-    Expr::Function(Function {
+    Expr::Function(crate::ast::Box::new(Function {
         name: ObjectName::from(vec![Ident::new(function)]),
         uses_odbc_syntax: false,
         parameters: FunctionArguments::None,
@@ -514,18 +554,18 @@ pub fn call(function: &str, args: impl IntoIterator<Item = Expr>) -> Expr {
         null_treatment: None,
         over: None,
         within_group: vec![],
-    })
+    }))
 }
 
 /// Gets the first index column (mysql calls it a key part) of the first index found in a
 /// [`Statement::CreateIndex`], [`Statement::CreateTable`], or [`Statement::AlterTable`].
 pub fn index_column(stmt: Statement) -> Expr {
     match stmt {
-        Statement::CreateIndex(CreateIndex { columns, .. }) => {
-            columns.first().unwrap().column.expr.clone()
+        Statement::CreateIndex(create_index) => {
+            create_index.columns.first().unwrap().column.expr.clone()
         }
-        Statement::CreateTable(CreateTable { constraints, .. }) => {
-            match constraints.first().unwrap() {
+        Statement::CreateTable(create_table) => {
+            match create_table.constraints.first().unwrap() {
                 TableConstraint::Index(constraint) => {
                     constraint.columns.first().unwrap().column.expr.clone()
                 }
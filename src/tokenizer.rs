@@ -32,13 +32,16 @@ use alloc::{
 #[cfg(feature = "std")]
 use std::borrow::Cow;
 
+use core::hash::{Hash, Hasher};
 use core::iter::Peekable;
+use core::ops::Range;
 use core::str::Chars;
 use core::{cmp, fmt};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use sqlparser_derive::{AstCodec, HeapSize};
 #[cfg(feature = "visitor")]
 use sqlparser_derive::{Visit, VisitMut};
 
@@ -46,9 +49,10 @@ use crate::ast::{AlternativeQuotedString, DollarQuotedString};
 use crate::dialect::{Dialect, DialectFeatures};
 use crate::dialect::{MySqlDialect, OracleDialect, PostgreSqlDialect};
 use crate::keywords::Keyword;
+use crate::parser::{LimitExceeded, ParserLimit, ParserLimits};
 
 /// SQL Token enumeration with lifetime parameter for future zero-copy support
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum BorrowedToken<'a> {
@@ -254,6 +258,10 @@ pub enum BorrowedToken<'a> {
     /// This is used to represent any custom binary operator that is not part of the SQL standard.
     /// PostgreSQL allows defining custom binary operators using CREATE OPERATOR.
     CustomBinaryOperator(String),
+    /// A malformed lexeme, such as an unterminated string literal, in place
+    /// of a tokenizer error. Only produced with
+    /// [`Tokenizer::with_error_recovery`].
+    Error(ErrorToken<'a>),
 }
 
 /// Type alias for backward compatibility - Token without explicit lifetime uses 'static
@@ -360,6 +368,7 @@ impl<'a> fmt::Display for BorrowedToken<'a> {
             BorrowedToken::QuestionAnd => write!(f, "?&"),
             BorrowedToken::QuestionPipe => write!(f, "?|"),
             BorrowedToken::CustomBinaryOperator(s) => f.write_str(s),
+            BorrowedToken::Error(e) => f.write_str(&e.text),
         }
     }
 }
@@ -499,6 +508,10 @@ impl<'a> BorrowedToken<'a> {
             BorrowedToken::QuestionAnd => BorrowedToken::QuestionAnd,
             BorrowedToken::QuestionPipe => BorrowedToken::QuestionPipe,
             BorrowedToken::CustomBinaryOperator(s) => BorrowedToken::CustomBinaryOperator(s),
+            BorrowedToken::Error(e) => BorrowedToken::Error(ErrorToken {
+                text: Cow::Owned(e.text.into_owned()),
+                message: e.message,
+            }),
         }
     }
 }
@@ -534,10 +547,20 @@ impl<'a> BorrowedToken<'a> {
             },
         })
     }
+
+    /// Create a delimited identifier token, borrowing its text unless
+    /// unescaping changed it
+    fn make_quoted_word(word: Cow<'a, str>, quote_style: char) -> Self {
+        BorrowedToken::Word(Word {
+            value: word,
+            quote_style: Some(quote_style),
+            keyword: Keyword::NoKeyword,
+        })
+    }
 }
 
 /// A keyword (like SELECT) or an optionally quoted SQL identifier
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Word<'a> {
@@ -576,7 +599,18 @@ impl Word<'_> {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+/// A malformed lexeme kept by [`Tokenizer::with_error_recovery`].
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct ErrorToken<'a> {
+    /// The source text of the lexeme.
+    pub text: Cow<'a, str>,
+    /// The message of the [`TokenizerError`] it would have raised.
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum Whitespace<'a> {
@@ -621,7 +655,21 @@ impl fmt::Display for Whitespace<'_> {
 /// # use sqlparser::tokenizer::Location;
 /// let location = Location::from((1, 1));
 /// ```
-#[derive(Eq, PartialEq, Hash, Clone, Copy, Ord, PartialOrd)]
+///
+/// # Byte offsets
+///
+/// Locations produced by the [`Tokenizer`] also record the UTF-8 byte
+/// offset of the position in the tokenized text. The offset is derived from
+/// the line and column, so it takes no part in comparisons: a tokenized
+/// location equals `Location::new` with the same line and column.
+/// ```
+/// # use sqlparser::tokenizer::Location;
+/// let location = Location::new(1, 3).with_byte_offset(2);
+/// assert_eq!(location.byte_offset(), Some(2));
+/// assert_eq!(location, Location::new(1, 3));
+/// assert_eq!(Location::new(1, 3).byte_offset(), None);
+/// ```
+#[derive(Clone, Copy, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Location {
@@ -633,6 +681,36 @@ pub struct Location {
     ///
     /// Note: Column 0 is used for empty spans
     pub column: u64,
+    /// UTF-8 byte offset plus one, or 0 when the offset is unknown.
+    #[cfg_attr(feature = "serde", serde(default))]
+    offset: u64,
+}
+
+impl PartialEq for Location {
+    fn eq(&self, other: &Self) -> bool {
+        (self.line, self.column) == (other.line, other.column)
+    }
+}
+
+impl Eq for Location {}
+
+impl Hash for Location {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.line.hash(state);
+        self.column.hash(state);
+    }
+}
+
+impl PartialOrd for Location {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Location {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        (self.line, self.column).cmp(&(other.line, other.column))
+    }
 }
 
 impl fmt::Display for Location {
@@ -652,13 +730,17 @@ impl fmt::Debug for Location {
 
 impl Location {
     /// Return an "empty" / unknown location
-    pub fn empty() -> Self {
-        Self { line: 0, column: 0 }
+    pub const fn empty() -> Self {
+        Self::new(0, 0)
     }
 
     /// Create a new `Location` for a given line and column
-    pub fn new(line: u64, column: u64) -> Self {
-        Self { line, column }
+    pub const fn new(line: u64, column: u64) -> Self {
+        Self {
+            line,
+            column,
+            offset: 0,
+        }
     }
 
     /// Create a new location for a given line and column
@@ -669,22 +751,44 @@ impl Location {
         Self::new(line, column)
     }
 
+    /// Record the UTF-8 byte offset of this location in the source text.
+    pub fn with_byte_offset(mut self, offset: usize) -> Self {
+        self.offset = offset as u64 + 1;
+        self
+    }
+
+    /// UTF-8 byte offset of this location in the tokenized text, if known.
+    pub fn byte_offset(&self) -> Option<usize> {
+        self.offset.checked_sub(1).map(|offset| offset as usize)
+    }
+
     /// Combine self and `end` into a new `Span`
     pub fn span_to(self, end: Self) -> Span {
         Span { start: self, end }
     }
+
+    /// `self` if it compares as `wanted` to `other`, else `other`. Equal
+    /// locations prefer the one whose byte offset is known.
+    fn pick(self, other: Self, wanted: cmp::Ordering) -> Self {
+        match self.cmp(&other) {
+            cmp::Ordering::Equal if self.offset == 0 => other,
+            cmp::Ordering::Equal => self,
+            ordering if ordering == wanted => self,
+            _ => other,
+        }
+    }
 }
 
 impl From<(u64, u64)> for Location {
     fn from((line, column): (u64, u64)) -> Self {
-        Self { line, column }
+        Self::new(line, column)
     }
 }
 
 /// A span represents a linear portion of the input string (start, end)
 ///
 /// See [Spanned](crate::ast::Spanned) for more information.
-#[derive(Eq, PartialEq, Hash, Clone, PartialOrd, Ord, Copy)]
+#[derive(Eq, PartialEq, Hash, Clone, PartialOrd, Ord, Copy, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct Span {
@@ -699,10 +803,6 @@ impl fmt::Debug for Span {
 }
 
 impl Span {
-    // An empty span (0, 0) -> (0, 0)
-    // We need a const instance for pattern matching
-    const EMPTY: Span = Self::empty();
-
     /// Create a new span from a start and end [`Location`]
     pub fn new(start: Location, end: Location) -> Span {
         Span { start, end }
@@ -714,11 +814,33 @@ impl Span {
     /// See [Spanned](crate::ast::Spanned) for more information.
     pub const fn empty() -> Span {
         Span {
-            start: Location { line: 0, column: 0 },
-            end: Location { line: 0, column: 0 },
+            start: Location::empty(),
+            end: Location::empty(),
         }
     }
 
+    /// Returns the UTF-8 byte range of this span in the tokenized text
+    ///
+    /// Only spans whose start and end both carry a byte offset, such as
+    /// the spans recorded by the [`Tokenizer`], have a byte range.
+    ///
+    /// # Example
+    /// ```
+    /// # use sqlparser::dialect::PostgreSqlDialect;
+    /// # use sqlparser::tokenizer::Tokenizer;
+    /// let sql = "SELECT déjà FROM t";
+    /// let tokens = Tokenizer::new(&PostgreSqlDialect {}, sql)
+    ///     .tokenize_with_location()
+    ///     .unwrap();
+    /// let range = tokens[2].span.byte_range().unwrap();
+    /// assert_eq!(&sql[range], "déjà");
+    /// ```
+    pub fn byte_range(&self) -> Option<Range<usize>> {
+        let start = self.start.byte_offset()?;
+        let end = self.end.byte_offset()?;
+        (start <= end).then_some(start..end)
+    }
+
     /// Returns the smallest Span that contains both `self` and `other`
     /// If either span is [Span::empty], the other span is returned
     ///
@@ -737,13 +859,15 @@ impl Span {
     pub fn union(&self, other: &Span) -> Span {
         // If either span is empty, return the other
         // this prevents propagating (0, 0) through the tree
-        match (self, other) {
-            (&Span::EMPTY, _) => *other,
-            (_, &Span::EMPTY) => *self,
-            _ => Span {
-                start: cmp::min(self.start, other.start),
-                end: cmp::max(self.end, other.end),
-            },
+        if *self == Span::empty() {
+            *other
+        } else if *other == Span::empty() {
+            *self
+        } else {
+            Span {
+                start: self.start.pick(other.start, cmp::Ordering::Less),
+                end: self.end.pick(other.end, cmp::Ordering::Greater),
+            }
         }
     }
 
@@ -807,7 +931,7 @@ pub type TokenWithLocation<'a> = TokenWithSpan<'a>;
 /// // same token but different locations are not equal
 /// assert_ne!(tok1, tok2);
 /// ```
-#[derive(Debug, Clone, Hash, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Ord, PartialOrd, Eq, PartialEq, HeapSize, AstCodec)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct TokenWithSpan<'a> {
@@ -869,9 +993,36 @@ impl<'a> fmt::Display for TokenWithSpan<'a> {
 
 /// Tokenizer error
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct TokenizerError {
     pub message: String,
     pub location: Location,
+    limit: Option<LimitExceeded>,
+}
+
+impl TokenizerError {
+    /// Create an error with `message` at `location`.
+    pub fn new(message: impl Into<String>, location: Location) -> Self {
+        Self {
+            message: message.into(),
+            location,
+            limit: None,
+        }
+    }
+
+    /// The size limit that stopped tokenizing, if any.
+    pub fn limit(&self) -> Option<LimitExceeded> {
+        self.limit
+    }
+
+    fn limit_exceeded(limit: ParserLimit, max: usize, location: Location) -> Self {
+        let limit = LimitExceeded { limit, max };
+        Self {
+            message: limit.to_string(),
+            location,
+            limit: Some(limit),
+        }
+    }
 }
 
 impl fmt::Display for TokenizerError {
@@ -891,6 +1042,9 @@ struct State<'a> {
     col: u64,
     /// Byte position in the source string
     byte_pos: usize,
+    /// The closing quote of a literal that raised an error before reaching
+    /// it, for error recovery to skip to.
+    open_quote: Option<char>,
 }
 
 impl State<'_> {
@@ -928,10 +1082,7 @@ impl State<'_> {
     }
 
     pub fn location(&self) -> Location {
-        Location {
-            line: self.line,
-            column: self.col,
-        }
+        Location::new(self.line, self.col).with_byte_offset(self.byte_pos)
     }
 }
 
@@ -959,6 +1110,15 @@ pub struct Tokenizer<'a> {
     /// Admit bracket-delimited identifiers without changing the selected SQL
     /// dialect. The parser owns this opt-in compatibility grammar.
     bracket_quoted_identifiers: bool,
+    /// See [`Tokenizer::with_limits`].
+    max_source_bytes: Option<usize>,
+    max_tokens: Option<usize>,
+    /// See [`Tokenizer::with_error_recovery`].
+    recover_errors: bool,
+    /// Line and column of the first character. See
+    /// [`Tokenizer::with_start_location`].
+    start_line: u64,
+    start_column: u64,
 }
 
 impl<'a> Tokenizer<'a> {
@@ -986,6 +1146,11 @@ impl<'a> Tokenizer<'a> {
             query,
             unescape: true,
             bracket_quoted_identifiers: false,
+            max_source_bytes: None,
+            max_tokens: None,
+            recover_errors: false,
+            start_line: 1,
+            start_column: 1,
         }
     }
 
@@ -1031,6 +1196,70 @@ impl<'a> Tokenizer<'a> {
         self
     }
 
+    /// Set the size limits to check. The tokenizer checks
+    /// [`ParserLimits::max_source_bytes`] before producing any token and
+    /// [`ParserLimits::max_tokens`] before storing each token; whitespace
+    /// and comments are not counted. The other limits
+    /// apply to parsing and are ignored here.
+    pub fn with_limits(mut self, limits: ParserLimits) -> Self {
+        self.max_source_bytes = limits.max_source_bytes;
+        self.max_tokens = limits.max_tokens;
+        self
+    }
+
+    /// Set whether malformed lexemes become [`BorrowedToken::Error`] tokens
+    /// instead of failing the whole input, as editors need for text that is
+    /// being typed. Defaults to false.
+    ///
+    /// A malformed lexeme reaching the end of the input, such as an
+    /// unterminated string literal or comment, ends with its first line, and
+    /// tokenizing resumes on the next one. Any other ends where the error was
+    /// found. Exceeding the [limits](Self::with_limits) is still an error.
+    ///
+    /// ```
+    /// # use sqlparser::dialect::PostgreSqlDialect;
+    /// # use sqlparser::tokenizer::{Location, Token, Tokenizer};
+    /// let sql = "SELECT 'abc, d\nFROM t";
+    /// let tokens = Tokenizer::new(&PostgreSqlDialect {}, sql)
+    ///     .with_error_recovery(true)
+    ///     .tokenize_with_location()
+    ///     .unwrap();
+    /// let Token::Error(error) = &tokens[2].token else {
+    ///     panic!("expected an error token");
+    /// };
+    /// assert_eq!(error.text, "'abc, d");
+    /// assert_eq!(error.message, "Unterminated string literal");
+    /// assert_eq!(tokens[2].span.byte_range(), Some(7..14));
+    /// assert_eq!(tokens[4].token, Token::make_keyword("FROM"));
+    /// assert_eq!(tokens[4].span.start, Location::new(2, 1));
+    /// ```
+    pub fn with_error_recovery(mut self, recover_errors: bool) -> Self {
+        self.recover_errors = recover_errors;
+        self
+    }
+
+    /// Number lines and columns from `location` rather than from line 1,
+    /// column 1, for text that starts there within a larger script. Byte
+    /// offsets stay relative to the tokenized text.
+    ///
+    /// ```
+    /// # use sqlparser::dialect::PostgreSqlDialect;
+    /// # use sqlparser::tokenizer::{Location, Tokenizer};
+    /// let tokens = Tokenizer::new(&PostgreSqlDialect {}, "SELECT\n  1")
+    ///     .with_start_location(Location::new(40, 5))
+    ///     .tokenize_with_location()
+    ///     .unwrap();
+    /// assert_eq!(tokens[0].span.start, Location::new(40, 5));
+    /// let number = tokens.last().unwrap();
+    /// assert_eq!(number.span.start, Location::new(41, 3));
+    /// assert_eq!(number.span.byte_range(), Some(9..10));
+    /// ```
+    pub fn with_start_location(mut self, location: Location) -> Self {
+        self.start_line = location.line;
+        self.start_column = location.column;
+        self
+    }
+
     /// Tokenize the statement and produce a vector of tokens
     pub fn tokenize(&mut self) -> Result<Vec<BorrowedToken<'a>>, TokenizerError> {
         let twl = self.tokenize_with_location()?;
@@ -1065,6 +1294,43 @@ impl<'a> Tokenizer<'a> {
         self.tokenize_with_location_into_buf_internal(buf, false)
     }
 
+    /// Tokenize without whitespace and comment tokens, for consumers such as
+    /// the statement splitter that only look at significant tokens.
+    pub(crate) fn tokenize_significant(
+        &mut self,
+    ) -> Result<Vec<TokenWithSpan<'a>>, TokenizerError> {
+        let mut tokens = Vec::new();
+        self.tokenize_with_location_into_buf_internal(&mut tokens, true)?;
+        Ok(tokens)
+    }
+
+    /// [`Self::tokenize_significant`] into `buf` from `start`, a location of
+    /// the text with its byte offset, where tokens follow `previous`. This
+    /// lets a reader that appends text keep the tokens it already has.
+    ///
+    /// On an error `buf` keeps the tokens read before it, and the flag tells
+    /// whether the tokenizer ran out of text, so that more text could still
+    /// resolve the error.
+    #[cfg(feature = "std")]
+    pub(crate) fn tokenize_significant_from(
+        &self,
+        start: Location,
+        previous: Option<BorrowedToken<'a>>,
+        buf: &mut Vec<TokenWithSpan<'a>>,
+    ) -> Result<(), (TokenizerError, bool)> {
+        let byte_pos = start.byte_offset().unwrap_or(0);
+        let mut state = State {
+            peekable: self.query[byte_pos..].chars().peekable(),
+            source: self.query,
+            line: start.line,
+            col: start.column,
+            byte_pos,
+            open_quote: None,
+        };
+        self.tokenize_state_into_buf(&mut state, previous, buf, true)
+            .map_err(|error| (error, state.peek().is_none()))
+    }
+
     /// Tokenize for the SQL parser, omitting trivia that the grammar normally
     /// skips. COPY statements retain the full token stream because their
     /// inline payload parser consumes tabs, newlines, and spaces as data.
@@ -1093,23 +1359,60 @@ impl<'a> Tokenizer<'a> {
         let mut state = State {
             peekable: self.query.chars().peekable(),
             source: self.query,
-            line: 1,
-            col: 1,
+            line: self.start_line,
+            col: self.start_column,
             byte_pos: 0,
+            open_quote: None,
         };
+        self.tokenize_state_into_buf(&mut state, None, buf, skip_whitespace)
+    }
 
+    /// Tokenize from `state` into `buf`. The first token follows the last
+    /// one of `buf`, or `previous` when `buf` is empty.
+    fn tokenize_state_into_buf(
+        &self,
+        state: &mut State<'a>,
+        previous: Option<BorrowedToken<'a>>,
+        buf: &mut Vec<TokenWithSpan<'a>>,
+        skip_whitespace: bool,
+    ) -> Result<(), TokenizerError> {
         let mut location = state.location();
+        if let Some(max) = self.max_source_bytes {
+            if self.query.len() > max {
+                return Err(TokenizerError::limit_exceeded(
+                    ParserLimit::SourceBytes,
+                    max,
+                    location,
+                ));
+            }
+        }
         let whitespace = BorrowedToken::Whitespace(Whitespace::Space);
         let mut skipped_whitespace = false;
+        let mut token_count = 0;
         loop {
             let previous = if skipped_whitespace {
                 Some(&whitespace)
             } else {
-                buf.last().map(|token| &token.token)
+                buf.last().map(|token| &token.token).or(previous.as_ref())
             };
-            let Some(token) = self.next_token(&mut state, previous)? else {
-                break;
+            let token = match self.next_token(state, previous) {
+                Ok(Some(token)) => token,
+                Ok(None) => break,
+                Err(error) if self.recover_errors && error.limit.is_none() => {
+                    self.recover(state, location, error)
+                }
+                Err(error) => return Err(error),
             };
+            if !matches!(token, BorrowedToken::Whitespace(_)) {
+                if self.max_tokens.is_some_and(|max| token_count >= max) {
+                    return Err(TokenizerError::limit_exceeded(
+                        ParserLimit::Tokens,
+                        token_count,
+                        location,
+                    ));
+                }
+                token_count += 1;
+            }
             let span = location.span_to(state.location());
             location = state.location();
 
@@ -1123,6 +1426,53 @@ impl<'a> Tokenizer<'a> {
         Ok(())
     }
 
+    /// Turn the lexeme from `start` that raised `error` into an error token,
+    /// and move `chars` to where tokenizing resumes.
+    fn recover(
+        &self,
+        chars: &mut State<'a>,
+        start: Location,
+        error: TokenizerError,
+    ) -> BorrowedToken<'a> {
+        let source = chars.source;
+        let from = start.byte_offset().unwrap_or(chars.byte_pos);
+        let line_end = |at: usize| source[at..].find('\n').map_or(source.len(), |i| at + i);
+        let mut end = chars.byte_pos;
+        let open_quote = chars.open_quote.take();
+        if chars.peek().is_none() {
+            // Unterminated: let the lexeme end with its first line.
+            end = line_end(from);
+        } else if let Some(quote) = open_quote {
+            // Stopped inside a quote, as at a bad `q'` delimiter: skip to its
+            // closing quote so that it does not open a string literal.
+            end = match source[end..line_end(end)].find(quote) {
+                Some(i) => end + i + 1,
+                None => line_end(end),
+            };
+        }
+        if end <= from {
+            end = from + source[from..].chars().next().map_or(0, char::len_utf8);
+        }
+        if end != chars.byte_pos {
+            let text = &source[from..end];
+            *chars = State {
+                peekable: source[end..].chars().peekable(),
+                source,
+                line: start.line + text.matches('\n').count() as u64,
+                col: match text.rfind('\n') {
+                    Some(i) => 1 + text[i + 1..].chars().count() as u64,
+                    None => start.column + text.chars().count() as u64,
+                },
+                byte_pos: end,
+                open_quote: None,
+            };
+        }
+        BorrowedToken::Error(ErrorToken {
+            text: Cow::Borrowed(&source[from..end]),
+            message: error.message,
+        })
+    }
+
     /// Tokenize an identifier or keyword after consuming the first character(s).
     /// `consumed_byte_len` is the total byte length of the character(s) already consumed.
     fn tokenize_identifier_or_keyword(
@@ -1144,6 +1494,7 @@ impl<'a> Tokenizer<'a> {
                 line: 0,
                 col: 0,
                 byte_pos: 0,
+                open_quote: None,
             };
             let mut s = peeking_take_while(&mut inner_state, |ch| matches!(ch, '0'..='9' | '.'));
             let s2 = peeking_take_while(chars, |ch| matches!(ch, '0'..='9' | '.'));
@@ -1302,11 +1653,11 @@ impl<'a> Tokenizer<'a> {
                     && self.is_compat_bracket_identifier(chars) =>
                 {
                     let word = self.tokenize_quoted_identifier('[', chars)?;
-                    Ok(Some(Token::make_word(&word, Some('['))))
+                    Ok(Some(BorrowedToken::make_quoted_word(word, '[')))
                 }
                 quote_start if self.dialect.is_delimited_identifier_start(ch) => {
                     let word = self.tokenize_quoted_identifier(quote_start, chars)?;
-                    Ok(Some(Token::make_word(&word, Some(quote_start))))
+                    Ok(Some(BorrowedToken::make_quoted_word(word, quote_start)))
                 }
                 // Potentially nested delimited (quoted) identifier
                 quote_start
@@ -1321,12 +1672,12 @@ impl<'a> Tokenizer<'a> {
                     else {
                         // Not a nested identifier, fall through to default handling
                         let word = self.tokenize_quoted_identifier(quote_start, chars)?;
-                        return Ok(Some(Token::make_word(&word, Some(quote_start))));
+                        return Ok(Some(BorrowedToken::make_quoted_word(word, quote_start)));
                     };
 
                     let Some(nested_quote_start) = nested_quote_start else {
                         let word = self.tokenize_quoted_identifier(quote_start, chars)?;
-                        return Ok(Some(Token::make_word(&word, Some(quote_start))));
+                        return Ok(Some(BorrowedToken::make_quoted_word(word, quote_start)));
                     };
 
                     let mut word = vec![];
@@ -1343,7 +1694,10 @@ impl<'a> Tokenizer<'a> {
                         );
                     }
                     word.push(nested_quote_start.into());
-                    word.push(self.tokenize_quoted_identifier(nested_quote_end, chars)?);
+                    word.push(
+                        self.tokenize_quoted_identifier(nested_quote_end, chars)?
+                            .into_owned(),
+                    );
                     word.push(nested_quote_end.into());
                     peeking_take_while(chars, |ch| ch.is_whitespace());
                     if chars.peek() != Some(&quote_end) {
@@ -2121,6 +2475,7 @@ impl<'a> Tokenizer<'a> {
         Err(TokenizerError {
             message: message.into(),
             location: loc,
+            limit: None,
         })
     }
 
@@ -2171,11 +2526,11 @@ impl<'a> Tokenizer<'a> {
         &self,
         quote_start: char,
         chars: &mut State<'a>,
-    ) -> Result<String, TokenizerError> {
+    ) -> Result<Cow<'a, str>, TokenizerError> {
         let error_loc = chars.location();
         chars.next(); // consume the opening quote
         let quote_end = Word::matching_end_quote(quote_start);
-        let (s, last_char) = self.parse_quoted_ident(chars, quote_end)?;
+        let (s, last_char) = self.parse_quoted_ident_borrowed(chars, quote_end)?;
 
         if last_char == Some(quote_end) {
             Ok(s)
@@ -2253,6 +2608,9 @@ impl<'a> Tokenizer<'a> {
             return self.tokenizer_error(starting_loc, "Expected alternative quote delimiter");
         };
         if delimiter.is_whitespace() || delimiter == '\'' {
+            if delimiter != '\'' {
+                chars.open_quote = Some('\'');
+            }
             return self.tokenizer_error(starting_loc, "Invalid alternative quote delimiter");
         }
 
@@ -2483,15 +2841,6 @@ impl<'a> Tokenizer<'a> {
         }
     }
 
-    fn parse_quoted_ident(
-        &self,
-        chars: &mut State<'a>,
-        quote_end: char,
-    ) -> Result<(String, Option<char>), TokenizerError> {
-        let (cow, last_char) = self.parse_quoted_ident_borrowed(chars, quote_end)?;
-        Ok((cow.into_owned(), last_char))
-    }
-
     /// Parse quoted identifier, returning borrowed slice when possible.
     /// Returns `(Cow<'a, str>, Option<char>)` where the `Option<char>` is the closing quote.
     fn parse_quoted_ident_borrowed(
@@ -2696,6 +3045,7 @@ fn borrow_or_unescape_single_quoted_string<'a>(
                         line: 0,
                         col: 0,
                         byte_pos: 0,
+                        open_quote: None,
                     };
 
                     return Unescape::new(&mut temp_state).unescape().map(Cow::Owned);
@@ -2882,6 +3232,7 @@ fn borrow_or_unescape_unicode_single_quoted_string<'a>(
         return Err(TokenizerError {
             message: "Expected opening quote for unicode string literal".to_string(),
             location: error_loc,
+            limit: None,
         });
     }
     chars.next(); // consume the opening quote
@@ -2909,6 +3260,7 @@ fn borrow_or_unescape_unicode_single_quoted_string<'a>(
                             return Err(TokenizerError {
                                 message: "Invalid unicode string literal".to_string(),
                                 location: error_loc,
+                                limit: None,
                             });
                         }
                         return Ok(Cow::Borrowed(&full_content[1..full_content.len() - 1]));
@@ -2922,6 +3274,7 @@ fn borrow_or_unescape_unicode_single_quoted_string<'a>(
                         line: 0,
                         col: 0,
                         byte_pos: 0,
+                        open_quote: None,
                     };
 
                     return process_unicode_string_with_escapes(&mut temp_state, error_loc)
@@ -2940,6 +3293,7 @@ fn borrow_or_unescape_unicode_single_quoted_string<'a>(
                 return Err(TokenizerError {
                     message: "Unterminated unicode encoded string literal".to_string(),
                     location: error_loc,
+                    limit: None,
                 });
             }
         }
@@ -2984,6 +3338,7 @@ fn process_unicode_string_with_escapes(
     Err(TokenizerError {
         message: "Unterminated unicode encoded string literal".to_string(),
         location: error_loc,
+        limit: None,
     })
 }
 
@@ -2997,16 +3352,19 @@ fn take_char_from_hex_digits(
             message: "Unexpected EOF while parsing hex digit in escaped unicode string."
                 .to_string(),
             location: chars.location(),
+            limit: None,
         })?;
         let digit = next_char.to_digit(16).ok_or_else(|| TokenizerError {
             message: format!("Invalid hex digit in escaped unicode string: {next_char}"),
             location: chars.location(),
+            limit: None,
         })?;
         result = result * 16 + digit;
     }
     char::from_u32(result).ok_or_else(|| TokenizerError {
         message: format!("Invalid unicode character: {result:x}"),
         location: chars.location(),
+        limit: None,
     })
 }
 
@@ -3338,7 +3696,8 @@ mod tests {
             tokenizer.tokenize(),
             Err(TokenizerError {
                 message: "Unterminated string literal".to_string(),
-                location: Location { line: 1, column: 8 },
+                location: Location::new(1, 8),
+                limit: None,
             })
         );
     }
@@ -3353,14 +3712,126 @@ mod tests {
             tokenizer.tokenize(),
             Err(TokenizerError {
                 message: "Unterminated string literal".to_string(),
-                location: Location {
-                    line: 1,
-                    column: 35
-                }
+                location: Location::new(1, 35),
+                limit: None,
             })
         );
     }
 
+    #[test]
+    fn tokenize_with_error_recovery() {
+        let recovered = |dialect: &dyn Dialect, sql: &str| {
+            Tokenizer::new(dialect, sql)
+                .with_error_recovery(true)
+                .tokenize_with_location()
+                .unwrap()
+                .into_iter()
+                .filter(|token| !matches!(token.token, BorrowedToken::Whitespace(_)))
+                .map(|token| {
+                    let message = match &token.token {
+                        BorrowedToken::Error(error) => Some(error.message.clone()),
+                        _ => None,
+                    };
+                    (token.token.to_string(), message, token.span.start)
+                })
+                .collect::<Vec<_>>()
+        };
+        let error = |message: &str| Some(message.to_string());
+
+        let sql = "SELECT q' x', 'ok' FROM t\nWHERE a = q'[é]\nAND b = 'oops";
+        compare(
+            vec![
+                ("SELECT".to_string(), None, Location::new(1, 1)),
+                (
+                    "q' x'".to_string(),
+                    error("Invalid alternative quote delimiter"),
+                    Location::new(1, 8),
+                ),
+                (",".to_string(), None, Location::new(1, 13)),
+                ("'ok'".to_string(), None, Location::new(1, 15)),
+                ("FROM".to_string(), None, Location::new(1, 20)),
+                ("t".to_string(), None, Location::new(1, 25)),
+                ("WHERE".to_string(), None, Location::new(2, 1)),
+                ("a".to_string(), None, Location::new(2, 7)),
+                ("=".to_string(), None, Location::new(2, 9)),
+                (
+                    "q'[é]".to_string(),
+                    error("Unterminated alternative quoted string literal"),
+                    Location::new(2, 11),
+                ),
+                ("AND".to_string(), None, Location::new(3, 1)),
+                ("b".to_string(), None, Location::new(3, 5)),
+                ("=".to_string(), None, Location::new(3, 7)),
+                (
+                    "'oops".to_string(),
+                    error("Unterminated string literal"),
+                    Location::new(3, 9),
+                ),
+            ],
+            recovered(&OracleDialect {}, sql),
+        );
+
+        let sql = "/* note\nSELECT $$ 1\n, ._x";
+        compare(
+            vec![
+                (
+                    "/* note".to_string(),
+                    error("Unexpected EOF while in a multi-line comment"),
+                    Location::new(1, 1),
+                ),
+                ("SELECT".to_string(), None, Location::new(2, 1)),
+                (
+                    "$$ 1".to_string(),
+                    error("Unterminated dollar-quoted string"),
+                    Location::new(2, 8),
+                ),
+                (",".to_string(), None, Location::new(3, 1)),
+                (
+                    ".".to_string(),
+                    error("Unexpected character '_'"),
+                    Location::new(3, 3),
+                ),
+                ("_x".to_string(), None, Location::new(3, 4)),
+            ],
+            recovered(&PostgreSqlDialect {}, sql),
+        );
+
+        // An escaped quote does not leave the failed literal open.
+        let sql = r"SELECT E'it\'s\0', 'ok'";
+        compare(
+            vec![
+                ("SELECT".to_string(), None, Location::new(1, 1)),
+                (
+                    r"E'it\'s\0'".to_string(),
+                    error("Unterminated encoded string literal"),
+                    Location::new(1, 8),
+                ),
+                (",".to_string(), None, Location::new(1, 18)),
+                ("'ok'".to_string(), None, Location::new(1, 20)),
+            ],
+            recovered(&PostgreSqlDialect {}, sql),
+        );
+    }
+
+    #[test]
+    fn tokenize_with_error_recovery_keeps_valid_input_and_limits() {
+        let dialect = PostgreSqlDialect {};
+        let sql = "SELECT 'a', $$b$$ /* c */ FROM t -- d";
+        assert_eq!(
+            Tokenizer::new(&dialect, sql)
+                .with_error_recovery(true)
+                .tokenize_with_location(),
+            Tokenizer::new(&dialect, sql).tokenize_with_location()
+        );
+
+        let limited = Tokenizer::new(&dialect, "SELECT 'a")
+            .with_error_recovery(true)
+            .with_limits(ParserLimits::new().with_max_tokens(1))
+            .tokenize_with_location()
+            .unwrap_err();
+        assert!(limited.limit().is_some());
+    }
+
     #[test]
     fn tokenize_invalid_string_cols() {
         let sql = String::from("\n\nSELECT * FROM table\t💝مصطفىh");
@@ -3508,10 +3979,8 @@ mod tests {
             Tokenizer::new(&dialect, &sql).tokenize(),
             Err(TokenizerError {
                 message: "Unterminated dollar-quoted string".into(),
-                location: Location {
-                    line: 1,
-                    column: 86
-                }
+                location: Location::new(1, 86),
+                limit: None,
             })
         );
     }
@@ -3762,7 +4231,8 @@ mod tests {
             tokenizer.tokenize(),
             Err(TokenizerError {
                 message: "Expected close delimiter '\"' before EOF.".to_string(),
-                location: Location { line: 1, column: 1 },
+                location: Location::new(1, 1),
+                limit: None,
             })
         );
     }
@@ -3934,6 +4404,56 @@ mod tests {
         compare(expected, tokens);
     }
 
+    #[test]
+    fn tokenize_with_byte_offsets() {
+        let sql = "SELECT 'é',\n  \"ñame\" -- ü\nFROM t";
+        let dialect = PostgreSqlDialect {};
+        let tokens = Tokenizer::new(&dialect, sql)
+            .tokenize_with_location()
+            .unwrap();
+        for token in &tokens {
+            let range = token.span.byte_range().unwrap();
+            assert_eq!(&sql[range], token.token.to_string(), "{token:?}");
+        }
+
+        let select = tokens.first().unwrap().span;
+        let from = tokens.last().unwrap().span;
+        let union = select.union(&from);
+        assert_eq!(union, Span::new((1, 1).into(), (3, 7).into()));
+        assert_eq!(union.byte_range(), Some(0..sql.len()));
+        assert_eq!(
+            from.union(&Span::empty()).byte_range(),
+            Some(sql.len() - 1..sql.len())
+        );
+
+        // Hand-built locations compare equal but carry no offsets
+        assert_eq!(Span::new((1, 1).into(), (1, 7).into()), select);
+        assert_eq!(Span::new((1, 1).into(), (1, 7).into()).byte_range(), None);
+
+        // A union keeps the known offset of equal endpoints on either side
+        let hand_built = Span::new((1, 1).into(), (3, 7).into());
+        assert_eq!(hand_built.union(&union).byte_range(), Some(0..sql.len()));
+        assert_eq!(union.union(&hand_built).byte_range(), Some(0..sql.len()));
+        assert_eq!(
+            hand_built.union(&from).byte_range(),
+            None,
+            "the start of a hand-built span has no offset"
+        );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn byte_offsets_past_4_gib() {
+        // Scripts larger than 4 GiB keep exact offsets, which the statement
+        // splitter relies on for every token.
+        let start = (5usize << 30) + 7;
+        let span = Span::new(
+            Location::new(1, 1).with_byte_offset(start),
+            Location::new(1, 9).with_byte_offset(start + 8),
+        );
+        assert_eq!(span.byte_range(), Some(start..start + 8));
+    }
+
     fn compare<T: PartialEq + fmt::Debug>(expected: Vec<T>, actual: Vec<T>) {
         //println!("------------------------------");
         //println!("tokens   = {:?}", actual);
@@ -3950,6 +4470,7 @@ mod tests {
             line: 0,
             col: 0,
             byte_pos: 0,
+            open_quote: None,
         };
 
         assert_eq!(
@@ -16,7 +16,7 @@ fn parses_all_procedures_and_routines_in_schema() {
         panic!("expected GRANT statement");
     };
     assert!(matches!(
-        objects,
+        objects.as_deref(),
         Some(GrantObjects::AllProceduresInSchema { schemas })
             if schemas.iter().map(ToString::to_string).collect::<Vec<_>>()
                 == vec!["app".to_string(), "admin".to_string()]
@@ -28,7 +28,7 @@ fn parses_all_procedures_and_routines_in_schema() {
         panic!("expected REVOKE statement");
     };
     assert!(matches!(
-        objects,
+        objects.as_deref(),
         Some(GrantObjects::AllRoutinesInSchema { schemas })
             if schemas.iter().map(ToString::to_string).collect::<Vec<_>>()
                 == vec!["app".to_string()]
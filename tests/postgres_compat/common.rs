@@ -144,7 +144,7 @@ pub struct CreateProcedureExtract<'a> {
     pub or_alter: bool,
     pub name: &'a ObjectName,
     pub params: &'a Option<Vec<ProcedureParam>>,
-    pub language: &'a Option<Ident>,
+    pub language: Option<&'a Ident>,
     pub security: &'a Option<ProcedureSecurity>,
     pub set_options: &'a Vec<ProcedureSetConfig>,
     pub has_as: bool,
@@ -170,7 +170,7 @@ pub fn extract_create_procedure(stmt: &Statement) -> CreateProcedureExtract<'_>
             or_alter: *or_alter,
             name,
             params,
-            language,
+            language: language.as_deref(),
             security,
             set_options,
             has_as: *has_as,
@@ -0,0 +1,285 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![warn(clippy::all)]
+//! Round trips of statements and documents through the binary AST codec.
+//! Every statement verified by the dialect test suites is also checked by
+//! `test_utils::assert_codec_roundtrip`, and the source it came from by the
+//! same document round trip as the benchmark corpus below.
+
+use sqlparser::ast::{
+    AstCodec, AstCodecError, DataType, Expr, SelectItem, SetExpr, Spanned, Statement,
+    AST_CODEC_VERSION,
+};
+use sqlparser::dialect::{Dialect, MsSqlDialect, MySqlDialect, OracleDialect, PostgreSqlDialect};
+use sqlparser::oracle_compat::positive_cases;
+use sqlparser::parser::Parser;
+use sqlparser::ParsedSql;
+
+#[allow(dead_code)]
+#[path = "../sqlparser_bench/src/postgres_corpus.rs"]
+mod postgres_corpus;
+
+const TPCH_QUERIES: [&str; 22] = [
+    include_str!("queries/tpch/1.sql"),
+    include_str!("queries/tpch/2.sql"),
+    include_str!("queries/tpch/3.sql"),
+    include_str!("queries/tpch/4.sql"),
+    include_str!("queries/tpch/5.sql"),
+    include_str!("queries/tpch/6.sql"),
+    include_str!("queries/tpch/7.sql"),
+    include_str!("queries/tpch/8.sql"),
+    include_str!("queries/tpch/9.sql"),
+    include_str!("queries/tpch/10.sql"),
+    include_str!("queries/tpch/11.sql"),
+    include_str!("queries/tpch/12.sql"),
+    include_str!("queries/tpch/13.sql"),
+    include_str!("queries/tpch/14.sql"),
+    include_str!("queries/tpch/15.sql"),
+    include_str!("queries/tpch/16.sql"),
+    include_str!("queries/tpch/17.sql"),
+    include_str!("queries/tpch/18.sql"),
+    include_str!("queries/tpch/19.sql"),
+    include_str!("queries/tpch/20.sql"),
+    include_str!("queries/tpch/21.sql"),
+    include_str!("queries/tpch/22.sql"),
+];
+
+const MYSQL_CASES: &[&str] = &[
+    "SELECT `id`, `name` FROM `shop`.`items` WHERE price > 10 LIMIT 5, 10",
+    "INSERT INTO t (a, b) VALUES (1, 'x') ON DUPLICATE KEY UPDATE b = VALUES(b)",
+    "CREATE TABLE t (id INT UNSIGNED NOT NULL AUTO_INCREMENT PRIMARY KEY, note TEXT) ENGINE=InnoDB",
+    "UPDATE t SET a = a + 1 WHERE id IN (SELECT id FROM u)",
+    "SELECT /*+ NO_INDEX(t idx) */ a FROM t; DELETE FROM t WHERE a IS NULL",
+    "SELECT CAST(a AS CHAR(10)), DATE_ADD(d, INTERVAL 1 DAY) FROM t GROUP BY a",
+];
+
+const MSSQL_CASES: &[&str] = &[
+    "SELECT TOP (10) [id], [name] FROM [dbo].[items] WITH (NOLOCK) WHERE [price] > 10",
+    "DECLARE @total INT = 0",
+    "SELECT a FROM t ORDER BY a OFFSET 5 ROWS FETCH NEXT 10 ROWS ONLY",
+    "CREATE TABLE t (id INT IDENTITY(1, 1) PRIMARY KEY, note NVARCHAR(MAX))",
+    "SELECT * FROM t CROSS APPLY (SELECT b FROM u WHERE u.a = t.a) AS x",
+    "UPDATE t SET a = N'unicode' WHERE id = 1",
+];
+
+fn assert_document_roundtrip(dialect: &dyn Dialect, sql: &str) {
+    let document = ParsedSql::parse(dialect, sql).unwrap_or_else(|e| panic!("{e}: {sql}"));
+    let bytes = document.to_bytes();
+    let decoded = ParsedSql::from_bytes(&bytes).unwrap_or_else(|e| panic!("{e}: {sql}"));
+
+    assert_eq!(decoded.source(), document.source());
+    assert_eq!(decoded.statements(), document.statements(), "{sql}");
+    assert_eq!(decoded.optimizer_hints(), document.optimizer_hints());
+    for (index, statement) in document.statements().iter().enumerate() {
+        assert_eq!(
+            decoded.statement_span(index),
+            document.statement_span(index)
+        );
+        assert_eq!(decoded.statements()[index].span(), statement.span());
+        assert_eq!(
+            decoded.statements()[index].to_string(),
+            statement.to_string()
+        );
+    }
+    // Equality ignores spans; identical bytes show that they survived.
+    assert!(decoded.to_bytes() == bytes, "document changed: {sql}");
+
+    // Decoding allocates into the new document's arena and borrows the
+    // same leaf text from its source, so it owns heap memory exactly when
+    // the parsed document does.
+    assert_eq!(
+        decoded.arena_stats().destructor_free,
+        document.arena_stats().destructor_free
+    );
+}
+
+#[test]
+fn postgres_tpch_documents_roundtrip() {
+    for sql in TPCH_QUERIES {
+        assert_document_roundtrip(&PostgreSqlDialect {}, sql);
+    }
+}
+
+#[test]
+fn postgres_benchmark_corpus_documents_roundtrip() {
+    // The scaling cases recurse deeper than the test harness's default
+    // worker stack allows, as in the benchmark's own corpus tests.
+    std::thread::Builder::new()
+        .name("postgres-corpus-codec".into())
+        .stack_size(16 * 1024 * 1024)
+        .spawn(|| {
+            for case in postgres_corpus::postgres_corpus() {
+                assert_document_roundtrip(&PostgreSqlDialect {}, &case.sql);
+            }
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn oracle_corpus_documents_roundtrip() {
+    for case in positive_cases() {
+        assert_document_roundtrip(&OracleDialect {}, case.sql);
+    }
+}
+
+#[test]
+fn mysql_documents_roundtrip() {
+    for sql in MYSQL_CASES {
+        assert_document_roundtrip(&MySqlDialect {}, sql);
+    }
+}
+
+#[test]
+fn mssql_documents_roundtrip() {
+    for sql in MSSQL_CASES {
+        assert_document_roundtrip(&MsSqlDialect {}, sql);
+    }
+}
+
+#[test]
+fn decoded_document_borrows_its_source() {
+    let document = ParsedSql::parse(&PostgreSqlDialect {}, "SELECT a, 'b' FROM t").unwrap();
+    let decoded = ParsedSql::from_bytes(&document.to_bytes()).unwrap();
+    let Statement::Query(query) = &decoded.statements()[0] else {
+        panic!("expected a query");
+    };
+    let SetExpr::Select(select) = query.body.as_ref() else {
+        panic!("expected a select");
+    };
+    let SelectItem::UnnamedExpr {
+        expr: Expr::Identifier(ident),
+        ..
+    } = &select.projection[0]
+    else {
+        panic!("expected an identifier");
+    };
+    assert!(ident.value.is_source_backed());
+    assert!(query.body.is_arena_allocated());
+    assert!(decoded.arena_stats().destructor_free);
+}
+
+#[test]
+fn statement_keeps_spans_and_quote_styles() {
+    let sql = "SELECT \"Mixed\",\n  [bracketed] FROM t";
+    let statement = Parser::parse_sql(&MsSqlDialect {}, sql).unwrap().remove(0);
+    let decoded = Statement::from_bytes(&statement.to_bytes()).unwrap();
+    assert_eq!(decoded, statement);
+
+    let projection = |statement: &Statement| {
+        let Statement::Query(query) = statement else {
+            panic!("expected a query");
+        };
+        let SetExpr::Select(select) = query.body.as_ref() else {
+            panic!("expected a select");
+        };
+        select
+            .projection
+            .iter()
+            .map(|item| match item {
+                SelectItem::UnnamedExpr {
+                    expr: Expr::Identifier(ident),
+                    ..
+                } => (ident.value.to_string(), ident.quote_style, ident.span),
+                _ => panic!("expected an identifier"),
+            })
+            .collect::<Vec<_>>()
+    };
+    let items = projection(&decoded);
+    assert_eq!(items, projection(&statement));
+    assert_eq!(items[0].1, Some('"'));
+    assert_eq!(items[1].1, Some('['));
+    assert_eq!(items[1].2.start.line, 2);
+}
+
+#[test]
+fn expr_and_data_type_roundtrip() {
+    let dialect = PostgreSqlDialect {};
+    let expr = Parser::new(&dialect)
+        .try_with_sql("CASE WHEN a > -1.5 THEN E'x\\ny' ELSE \"B\" END::text[]")
+        .unwrap()
+        .parse_expr()
+        .unwrap();
+    let decoded = Expr::from_bytes(&expr.to_bytes()).unwrap();
+    assert_eq!(decoded, expr);
+    assert_eq!(decoded.span(), expr.span());
+
+    let data_type = Parser::new(&dialect)
+        .try_with_sql("NUMERIC(10, 2)[]")
+        .unwrap()
+        .parse_data_type()
+        .unwrap();
+    assert_eq!(DataType::from_bytes(&data_type.to_bytes()), Ok(data_type));
+}
+
+#[test]
+fn rejects_incompatible_encodings() {
+    let statement = Parser::parse_sql(&PostgreSqlDialect {}, "SELECT 1")
+        .unwrap()
+        .remove(0);
+    let bytes = statement.to_bytes();
+
+    let mut other_version = bytes.clone();
+    other_version[4..6].copy_from_slice(&(AST_CODEC_VERSION + 1).to_le_bytes());
+    assert_eq!(
+        Statement::from_bytes(&other_version),
+        Err(AstCodecError::UnsupportedVersion {
+            found: AST_CODEC_VERSION + 1,
+            expected: AST_CODEC_VERSION,
+        })
+    );
+
+    let mut other_schema = bytes.clone();
+    other_schema[6] ^= 1;
+    assert!(matches!(
+        Statement::from_bytes(&other_schema),
+        Err(AstCodecError::SchemaMismatch { .. })
+    ));
+    assert!(matches!(
+        Expr::from_bytes(&bytes),
+        Err(AstCodecError::SchemaMismatch { .. })
+    ));
+    assert!(matches!(
+        ParsedSql::from_bytes(&bytes),
+        Err(AstCodecError::SchemaMismatch { .. })
+    ));
+
+    assert_eq!(
+        Statement::from_bytes(b"SQLB"),
+        Err(AstCodecError::InvalidHeader)
+    );
+    assert_eq!(
+        Statement::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(AstCodecError::UnexpectedEnd)
+    );
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(
+        Statement::from_bytes(&trailing),
+        Err(AstCodecError::TrailingBytes)
+    );
+
+    let document = ParsedSql::parse(&PostgreSqlDialect {}, "SELECT 1").unwrap();
+    let mut document_bytes = document.to_bytes();
+    document_bytes[4] ^= 0xff;
+    assert!(matches!(
+        ParsedSql::from_bytes(&document_bytes),
+        Err(AstCodecError::UnsupportedVersion { .. })
+    ));
+}
@@ -35,7 +35,7 @@ use sqlparser::ast::TableFactor::{Pivot, Unpivot};
 use sqlparser::ast::*;
 use sqlparser::dialect::{Dialect, MsSqlDialect, MySqlDialect, PostgreSqlDialect};
 use sqlparser::keywords::{Keyword, ALL_KEYWORDS};
-use sqlparser::parser::{Parser, ParserError, ParserOptions};
+use sqlparser::parser::{Parser, ParserError, ParserLimits, ParserOptions};
 use sqlparser::tokenizer::Tokenizer;
 use sqlparser::tokenizer::{BorrowedToken, Location, Span};
 use test_utils::{
@@ -68,9 +68,10 @@ fn parse_numeric_literal_underscore() {
 
     assert_eq!(
         select.projection,
-        vec![UnnamedExpr(Expr::Value(
-            (number("10_000")).with_empty_span()
-        ))]
+        vec![UnnamedExpr {
+            expr: Expr::Value(Box::new((number("10_000")).with_empty_span())),
+            comments: Comments::default(),
+        }]
     );
 }
 
@@ -142,12 +143,16 @@ fn parse_insert_values() {
         expected_value_keyword: bool,
     ) {
         match verified_stmt(sql) {
-            Statement::Insert(Insert {
-                table: table_name,
-                columns,
-                source: Some(source),
-                ..
-            }) => {
+            Statement::Insert(insert) => {
+                let Insert {
+                    table: table_name,
+                    columns,
+                    source: Some(source),
+                    ..
+                } = Box::into_owned(insert)
+                else {
+                    unreachable!()
+                };
                 assert_eq!(table_name.to_string(), expected_table_name);
                 assert_eq!(columns.len(), expected_columns.len());
                 for (index, column) in columns.iter().enumerate() {
@@ -183,10 +188,12 @@ fn parse_replace_into() {
     let dialect = MsSqlDialect {};
     let sql = "REPLACE INTO public.customer (id, name, active) VALUES (1, 2, 3)";
 
+    let err = Parser::parse_sql(&dialect, sql).unwrap_err();
     assert_eq!(
-        ParserError::ParserError("Unsupported statement REPLACE at Line: 1, Column: 9".to_string()),
-        Parser::parse_sql(&dialect, sql,).unwrap_err(),
-    )
+        "sql parser error: Unsupported statement REPLACE at Line: 1, Column: 9",
+        err.to_string(),
+    );
+    assert_eq!(err.byte_offset(), Some(8));
 }
 
 #[test]
@@ -194,16 +201,17 @@ fn parse_insert_default_values() {
     let insert_with_default_values = verified_stmt("INSERT INTO test_table DEFAULT VALUES");
 
     match insert_with_default_values {
-        Statement::Insert(Insert {
-            after_columns,
-            columns,
-            on,
-            partitioned,
-            returning,
-            source,
-            table: table_name,
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                after_columns,
+                columns,
+                on,
+                partitioned,
+                returning,
+                source,
+                table: table_name,
+                ..
+            } = Box::into_owned(insert);
             assert_eq!(columns, vec![]);
             assert_eq!(after_columns, vec![]);
             assert_eq!(on, None);
@@ -222,16 +230,17 @@ fn parse_insert_default_values() {
         verified_stmt("INSERT INTO test_table DEFAULT VALUES RETURNING test_column");
 
     match insert_with_default_values_and_returning {
-        Statement::Insert(Insert {
-            after_columns,
-            columns,
-            on,
-            partitioned,
-            returning,
-            source,
-            table: table_name,
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                after_columns,
+                columns,
+                on,
+                partitioned,
+                returning,
+                source,
+                table: table_name,
+                ..
+            } = Box::into_owned(insert);
             assert_eq!(after_columns, vec![]);
             assert_eq!(columns, vec![]);
             assert_eq!(on, None);
@@ -250,16 +259,17 @@ fn parse_insert_default_values() {
         verified_stmt("INSERT INTO test_table DEFAULT VALUES ON CONFLICT DO NOTHING");
 
     match insert_with_default_values_and_on_conflict {
-        Statement::Insert(Insert {
-            after_columns,
-            columns,
-            on,
-            partitioned,
-            returning,
-            source,
-            table: table_name,
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                after_columns,
+                columns,
+                on,
+                partitioned,
+                returning,
+                source,
+                table: table_name,
+                ..
+            } = Box::into_owned(insert);
             assert_eq!(after_columns, vec![]);
             assert_eq!(columns, vec![]);
             assert!(on.is_some());
@@ -312,11 +322,17 @@ fn parse_insert_select_returning() {
     dialects.verified_stmt("INSERT INTO t SELECT 1 RETURNING 2");
     let stmt = dialects.verified_stmt("INSERT INTO t SELECT x RETURNING x AS y");
     match stmt {
-        Statement::Insert(Insert {
-            returning: Some(ret),
-            source: Some(_),
-            ..
-        }) => assert_eq!(ret.expressions.len(), 1),
+        Statement::Insert(insert) => {
+            let Insert {
+                returning: Some(ret),
+                source: Some(_),
+                ..
+            } = Box::into_owned(insert)
+            else {
+                unreachable!()
+            };
+            assert_eq!(ret.expressions.len(), 1)
+        }
         _ => unreachable!(),
     }
 }
@@ -325,17 +341,24 @@ fn parse_insert_select_returning() {
 fn parse_insert_select_from_returning() {
     let sql = "INSERT INTO table1 SELECT * FROM table2 RETURNING id";
     match verified_stmt(sql) {
-        Statement::Insert(Insert {
-            table: TableObject::TableName(table_name),
-            source: Some(source),
-            returning: Some(returning),
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                table: TableObject::TableName(table_name),
+                source: Some(source),
+                returning: Some(returning),
+                ..
+            } = Box::into_owned(insert)
+            else {
+                unreachable!()
+            };
             assert_eq!("table1", table_name.to_string());
             assert!(matches!(source.body.as_ref(), SetExpr::Select(_)));
             assert_eq!(
                 returning.expressions,
-                vec![SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("id"))),]
+                vec![SelectItem::UnnamedExpr {
+                    expr: Expr::Identifier(Box::new(Ident::new("id"))),
+                    comments: Comments::default(),
+                },]
             );
         }
         bad_stmt => unreachable!("Expected valid insert, got {:?}", bad_stmt),
@@ -351,12 +374,13 @@ fn parse_returning_as_column_alias() {
 fn parse_update() {
     let sql = "UPDATE t SET a = 1, b = 2, c = 3 WHERE d";
     match verified_stmt(sql) {
-        Statement::Update(Update {
-            table,
-            assignments,
-            selection,
-            ..
-        }) => {
+        Statement::Update(update) => {
+            let Update {
+                table,
+                assignments,
+                selection,
+                ..
+            } = Box::into_owned(update);
             assert_eq!(table.to_string(), "t".to_string());
             assert_eq!(
                 assignments,
@@ -375,7 +399,7 @@ fn parse_update() {
                     },
                 ]
             );
-            assert_eq!(selection.unwrap(), Expr::Identifier("d".into()));
+            assert_eq!(selection.unwrap(), Expr::Identifier(Box::new("d".into())));
         }
         _ => unreachable!(),
     }
@@ -407,79 +431,90 @@ fn parse_update_set_from() {
     let stmt = dialects.verified_stmt(sql);
     assert_eq!(
         stmt,
-        Statement::Update(Update {
+        Statement::Update(Box::new(Update {
             update_token: AttachedToken::empty(),
             table: TableWithJoins {
                 relation: table_from_name(ObjectName::from(vec![Ident::new("t1")])),
-                joins: vec![],
+                joins: AstVec::new(),
             },
             assignments: vec![Assignment {
                 target: AssignmentTarget::ColumnName(ObjectName::from(vec![Ident::new("name")])),
-                value: Expr::CompoundIdentifier(vec![Ident::new("t2"), Ident::new("name")])
-            }],
-            from: Some(UpdateTableFromKind::AfterSet(vec![TableWithJoins {
-                relation: TableFactor::Derived {
-                    lateral: false,
-                    subquery: Box::new(Query {
-                        with: None,
-                        body: Box::new(SetExpr::Select(Box::new(Select {
-                            select_token: AttachedToken::empty(),
-                            distinct: None,
-                            top: None,
-                            top_before_distinct: false,
-                            projection: vec![
-                                SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("name"))),
-                                SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("id"))),
-                            ],
-                            into: None,
-                            from: vec![TableWithJoins {
-                                relation: table_from_name(ObjectName::from(vec![Ident::new("t1")])),
-                                joins: vec![],
-                            }],
-                            selection: None,
-                            group_by: GroupByExpr::Expressions(
-                                vec![Expr::Identifier(Ident::new("id"))],
-                                vec![]
-                            ),
-                            having: None,
-                            qualify: None,
-                            named_window: vec![],
-                            connect_by: None,
-                            flavor: SelectFlavor::Standard,
-                        }))),
-                        order_by: None,
-                        limit_clause: None,
-                        fetch: None,
-                        locks: vec![],
-                        for_clause: None,
-                    }),
-                    alias: Some(TableAlias {
-                        name: Ident::new("t2"),
-                        columns: vec![],
-                        implicit: false,
-                    })
-                },
-                joins: vec![]
-            }])),
-            selection: Some(
-                Expr::BinaryOp {
-                    left: Box::new(Expr::CompoundIdentifier(vec![
-                        Ident::new("t1"),
-                        Ident::new("id")
-                    ])),
-                    op: BinaryOperator::Eq,
-                    right: Box::new(Expr::CompoundIdentifier(vec![
-                        Ident::new("t2"),
-                        Ident::new("id")
-                    ])),
-                }
+                value: Expr::CompoundIdentifier(vec![Ident::new("t2"), Ident::new("name")].into())
+            }]
+            .into(),
+            from: Some(UpdateTableFromKind::AfterSet(
+                vec![TableWithJoins {
+                    relation: TableFactor::Derived {
+                        lateral: false,
+                        subquery: Box::new(Query {
+                            with: None,
+                            body: Box::new(SetExpr::Select(Box::new(Select {
+                                select_token: AttachedToken::empty(),
+                                distinct: None,
+                                top: None,
+                                top_before_distinct: false,
+                                projection: vec![
+                                    SelectItem::UnnamedExpr {
+                                        expr: Expr::Identifier(Box::new(Ident::new("name"))),
+                                        comments: Comments::default(),
+                                    },
+                                    SelectItem::UnnamedExpr {
+                                        expr: Expr::Identifier(Box::new(Ident::new("id"))),
+                                        comments: Comments::default(),
+                                    },
+                                ]
+                                .into(),
+                                into: None,
+                                from: vec![TableWithJoins {
+                                    relation: table_from_name(ObjectName::from(vec![Ident::new(
+                                        "t1"
+                                    )])),
+                                    joins: AstVec::new(),
+                                }]
+                                .into(),
+                                selection: None,
+                                group_by: GroupByExpr::Expressions(
+                                    vec![Expr::Identifier(Box::new(Ident::new("id")))].into(),
+                                    vec![]
+                                ),
+                                having: None,
+                                qualify: None,
+                                named_window: vec![],
+                                connect_by: None,
+                                flavor: SelectFlavor::Standard,
+                            }))),
+                            order_by: None,
+                            limit_clause: None,
+                            fetch: None,
+                            locks: AstVec::new(),
+                            for_clause: None,
+                            comments: Comments::default(),
+                        }),
+                        alias: Some(TableAlias {
+                            name: Ident::new("t2"),
+                            columns: vec![].into(),
+                            implicit: false,
+                        })
+                    },
+                    joins: AstVec::new()
+                }]
                 .into()
-            ),
+            )),
+            selection: Some(Expr::BinaryOp {
+                left: Box::new(Expr::CompoundIdentifier(
+                    vec![Ident::new("t1"), Ident::new("id")].into()
+                )),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::CompoundIdentifier(
+                    vec![Ident::new("t2"), Ident::new("id")].into()
+                )),
+            }),
             returning: None,
             limit: None,
             for_portion_of: None,
             error_logging: None,
-        })
+            comments: Comments::default(),
+        }))
     );
 
     let sql = "UPDATE T SET a = b FROM U, (SELECT foo FROM V) AS W WHERE 1 = 1";
@@ -490,24 +525,29 @@ fn parse_update_set_from() {
 fn parse_update_with_table_alias() {
     let sql = "UPDATE users AS u SET u.username = 'new_user' WHERE u.username = 'old_user'";
     match verified_stmt(sql) {
-        Statement::Update(Update {
-            table,
-            assignments,
-            from: _from,
-            selection,
-            returning,
-            limit: None,
-            update_token: _,
-            for_portion_of: None,
-            error_logging: _,
-        }) => {
+        Statement::Update(update) => {
+            let Update {
+                table,
+                assignments,
+                from: _from,
+                selection,
+                returning,
+                limit: None,
+                update_token: _,
+                for_portion_of: None,
+                error_logging: _,
+                comments: _,
+            } = Box::into_owned(update)
+            else {
+                unreachable!()
+            };
             assert_eq!(
                 TableWithJoins {
                     relation: TableFactor::Table {
                         name: ObjectName::from(vec![Ident::new("users")]),
                         alias: Some(TableAlias {
                             name: Ident::new("u"),
-                            columns: vec![],
+                            columns: vec![].into(),
                             implicit: false,
                         }),
                         args: None,
@@ -520,7 +560,7 @@ fn parse_update_with_table_alias() {
                         sample: None,
                         index_hints: vec![],
                     },
-                    joins: vec![],
+                    joins: AstVec::new(),
                 },
                 table
             );
@@ -530,22 +570,21 @@ fn parse_update_with_table_alias() {
                         Ident::new("u"),
                         Ident::new("username")
                     ])),
-                    value: Expr::Value(
-                        (Value::SingleQuotedString("new_user".to_string())).with_empty_span()
-                    ),
+                    value: Expr::Value(Box::new(
+                        (Value::SingleQuotedString("new_user".into())).with_empty_span()
+                    )),
                 }],
                 assignments
             );
             assert_eq!(
                 Some(Expr::BinaryOp {
-                    left: Box::new(Expr::CompoundIdentifier(vec![
-                        Ident::new("u"),
-                        Ident::new("username"),
-                    ])),
-                    op: BinaryOperator::Eq,
-                    right: Box::new(Expr::Value(
-                        (Value::SingleQuotedString("old_user".to_string())).with_empty_span()
+                    left: Box::new(Expr::CompoundIdentifier(
+                        vec![Ident::new("u"), Ident::new("username"),].into()
                     )),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(Expr::Value(Box::new(
+                        (Value::SingleQuotedString("old_user".into())).with_empty_span()
+                    ))),
                 }),
                 selection
             );
@@ -570,9 +609,18 @@ fn parse_select_with_table_alias() {
     assert_eq!(
         select.projection,
         vec![
-            SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("a")),),
-            SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("b")),),
-            SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("c")),),
+            SelectItem::UnnamedExpr {
+                expr: Expr::Identifier(Box::new(Ident::new("a"))),
+                comments: Comments::default(),
+            },
+            SelectItem::UnnamedExpr {
+                expr: Expr::Identifier(Box::new(Ident::new("b"))),
+                comments: Comments::default(),
+            },
+            SelectItem::UnnamedExpr {
+                expr: Expr::Identifier(Box::new(Ident::new("c"))),
+                comments: Comments::default(),
+            },
         ]
     );
     assert_eq!(
@@ -586,7 +634,8 @@ fn parse_select_with_table_alias() {
                         TableAliasColumnDef::from_name("A"),
                         TableAliasColumnDef::from_name("B"),
                         TableAliasColumnDef::from_name("C"),
-                    ],
+                    ]
+                    .into(),
                     implicit: false,
                 }),
                 args: None,
@@ -599,7 +648,7 @@ fn parse_select_with_table_alias() {
                 sample: None,
                 index_hints: vec![],
             },
-            joins: vec![],
+            joins: AstVec::new(),
         }]
     );
 }
@@ -632,10 +681,14 @@ fn parse_no_table_name() {
 fn parse_delete_statement() {
     let sql = "DELETE FROM \"table\"";
     match verified_stmt(sql) {
-        Statement::Delete(Delete {
-            from: FromTable::WithFromKeyword(from),
-            ..
-        }) => {
+        Statement::Delete(delete) => {
+            let Delete {
+                from: FromTable::WithFromKeyword(from),
+                ..
+            } = Box::into_owned(delete)
+            else {
+                unreachable!()
+            };
             assert_eq!(
                 table_from_name(ObjectName::from(vec![Ident::with_quote('"', "table")])),
                 from[0].relation
@@ -662,11 +715,15 @@ fn parse_delete_statement_for_multi_tables() {
     let sql = "DELETE schema1.table1, schema2.table2 FROM schema1.table1 JOIN schema2.table2 ON schema2.table2.col1 = schema1.table1.col1 WHERE schema2.table2.col2 = 1";
     let dialects = all_dialects();
     match dialects.verified_stmt(sql) {
-        Statement::Delete(Delete {
-            tables,
-            from: FromTable::WithFromKeyword(from),
-            ..
-        }) => {
+        Statement::Delete(delete) => {
+            let Delete {
+                tables,
+                from: FromTable::WithFromKeyword(from),
+                ..
+            } = Box::into_owned(delete)
+            else {
+                unreachable!()
+            };
             assert_eq!(
                 ObjectName::from(vec![Ident::new("schema1"), Ident::new("table1")]),
                 tables[0]
@@ -698,11 +755,15 @@ fn parse_delete_statement_for_multi_tables() {
 fn parse_delete_statement_for_multi_tables_with_using() {
     let sql = "DELETE FROM schema1.table1, schema2.table2 USING schema1.table1 JOIN schema2.table2 ON schema2.table2.pk = schema1.table1.col1 WHERE schema2.table2.col2 = 1";
     match verified_stmt(sql) {
-        Statement::Delete(Delete {
-            from: FromTable::WithFromKeyword(from),
-            using: Some(using),
-            ..
-        }) => {
+        Statement::Delete(delete) => {
+            let Delete {
+                from: FromTable::WithFromKeyword(from),
+                using: Some(using),
+                ..
+            } = Box::into_owned(delete)
+            else {
+                unreachable!()
+            };
             assert_eq!(
                 table_from_name(ObjectName::from(vec![
                     Ident::new("schema1"),
@@ -742,14 +803,18 @@ fn parse_where_delete_statement() {
 
     let sql = "DELETE FROM foo WHERE name = 5";
     match verified_stmt(sql) {
-        Statement::Delete(Delete {
-            tables: _,
-            from: FromTable::WithFromKeyword(from),
-            using,
-            selection,
-            returning,
-            ..
-        }) => {
+        Statement::Delete(delete) => {
+            let Delete {
+                tables: _,
+                from: FromTable::WithFromKeyword(from),
+                using,
+                selection,
+                returning,
+                ..
+            } = Box::into_owned(delete)
+            else {
+                unreachable!()
+            };
             assert_eq!(
                 table_from_name(ObjectName::from(vec![Ident::new("foo")])),
                 from[0].relation,
@@ -758,7 +823,7 @@ fn parse_where_delete_statement() {
             assert_eq!(None, using);
             assert_eq!(
                 Expr::BinaryOp {
-                    left: Box::new(Expr::Identifier(Ident::new("name"))),
+                    left: Box::new(Expr::Identifier(Box::new(Ident::new("name")))),
                     op: Eq,
                     right: Box::new(Expr::value(number("5"))),
                 },
@@ -776,20 +841,24 @@ fn parse_where_delete_with_alias_statement() {
 
     let sql = "DELETE FROM basket AS a USING basket AS b WHERE a.id < b.id";
     match verified_stmt(sql) {
-        Statement::Delete(Delete {
-            tables: _,
-            from: FromTable::WithFromKeyword(from),
-            using,
-            selection,
-            returning,
-            ..
-        }) => {
+        Statement::Delete(delete) => {
+            let Delete {
+                tables: _,
+                from: FromTable::WithFromKeyword(from),
+                using,
+                selection,
+                returning,
+                ..
+            } = Box::into_owned(delete)
+            else {
+                unreachable!()
+            };
             assert_eq!(
                 TableFactor::Table {
                     name: ObjectName::from(vec![Ident::new("basket")]),
                     alias: Some(TableAlias {
                         name: Ident::new("a"),
-                        columns: vec![],
+                        columns: vec![].into(),
                         implicit: false,
                     }),
                     args: None,
@@ -805,39 +874,40 @@ fn parse_where_delete_with_alias_statement() {
                 from[0].relation,
             );
             assert_eq!(
-                Some(vec![TableWithJoins {
-                    relation: TableFactor::Table {
-                        name: ObjectName::from(vec![Ident::new("basket")]),
-                        alias: Some(TableAlias {
-                            name: Ident::new("b"),
-                            columns: vec![],
-                            implicit: false,
-                        }),
-                        args: None,
-                        with_hints: vec![],
-                        version: None,
-                        partitions: vec![],
-                        with_ordinality: false,
-                        only: false,
-                        json_path: None,
-                        sample: None,
-                        index_hints: vec![],
-                    },
-                    joins: vec![],
-                }]),
+                Some(
+                    vec![TableWithJoins {
+                        relation: TableFactor::Table {
+                            name: ObjectName::from(vec![Ident::new("basket")]),
+                            alias: Some(TableAlias {
+                                name: Ident::new("b"),
+                                columns: vec![].into(),
+                                implicit: false,
+                            }),
+                            args: None,
+                            with_hints: vec![],
+                            version: None,
+                            partitions: vec![],
+                            with_ordinality: false,
+                            only: false,
+                            json_path: None,
+                            sample: None,
+                            index_hints: vec![],
+                        },
+                        joins: AstVec::new(),
+                    }]
+                    .into()
+                ),
                 using
             );
             assert_eq!(
                 Expr::BinaryOp {
-                    left: Box::new(Expr::CompoundIdentifier(vec![
-                        Ident::new("a"),
-                        Ident::new("id"),
-                    ])),
+                    left: Box::new(Expr::CompoundIdentifier(
+                        vec![Ident::new("a"), Ident::new("id"),].into()
+                    )),
                     op: Lt,
-                    right: Box::new(Expr::CompoundIdentifier(vec![
-                        Ident::new("b"),
-                        Ident::new("id"),
-                    ])),
+                    right: Box::new(Expr::CompoundIdentifier(
+                        vec![Ident::new("b"), Ident::new("id"),].into()
+                    )),
                 },
                 selection.unwrap(),
             );
@@ -918,7 +988,10 @@ fn parse_select_distinct() {
     let select = verified_only_select(sql);
     assert!(select.distinct.is_some());
     assert_eq!(
-        &SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("name"))),
+        &SelectItem::UnnamedExpr {
+            expr: Expr::Identifier(Box::new(Ident::new("name"))),
+            comments: Comments::default(),
+        },
         only(&select.projection)
     );
 }
@@ -929,11 +1002,17 @@ fn parse_select_distinct_two_fields() {
     let select = verified_only_select(sql);
     assert!(select.distinct.is_some());
     assert_eq!(
-        &SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("name"))),
+        &SelectItem::UnnamedExpr {
+            expr: Expr::Identifier(Box::new(Ident::new("name"))),
+            comments: Comments::default(),
+        },
         &select.projection[0]
     );
     assert_eq!(
-        &SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("id"))),
+        &SelectItem::UnnamedExpr {
+            expr: Expr::Identifier(Box::new(Ident::new("id"))),
+            comments: Comments::default(),
+        },
         &select.projection[1]
     );
 }
@@ -943,10 +1022,16 @@ fn parse_select_distinct_tuple() {
     let sql = "SELECT DISTINCT (name, id) FROM customer";
     let select = verified_only_select(sql);
     assert_eq!(
-        &vec![SelectItem::UnnamedExpr(Expr::Tuple(vec![
-            Expr::Identifier(Ident::new("name")),
-            Expr::Identifier(Ident::new("id")),
-        ]))],
+        &vec![SelectItem::UnnamedExpr {
+            expr: Expr::Tuple(
+                vec![
+                    Expr::Identifier(Box::new(Ident::new("name"))),
+                    Expr::Identifier(Box::new(Ident::new("id"))),
+                ]
+                .into()
+            ),
+            comments: Comments::default(),
+        }],
         &select.projection
     );
 }
@@ -960,9 +1045,11 @@ fn parse_outer_join_operator() {
         select.selection,
         Some(
             Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
                 op: BinaryOperator::Eq,
-                right: Box::new(Expr::OuterJoin(Box::new(Expr::Identifier(Ident::new("b")))))
+                right: Box::new(Expr::OuterJoin(Box::new(Expr::Identifier(Box::new(
+                    Ident::new("b")
+                )))))
             }
             .into()
         )
@@ -973,16 +1060,13 @@ fn parse_outer_join_operator() {
         select.selection,
         Some(
             Expr::BinaryOp {
-                left: Box::new(Expr::CompoundIdentifier(vec![
-                    Ident::new("t1"),
-                    Ident::new("c1")
-                ])),
+                left: Box::new(Expr::CompoundIdentifier(
+                    vec![Ident::new("t1"), Ident::new("c1")].into()
+                )),
                 op: BinaryOperator::Eq,
-                right: Box::new(Expr::OuterJoin(Box::new(Expr::CompoundIdentifier(vec![
-                    Ident::new("t2"),
-                    Ident::new("c2"),
-                    Ident::new("d3"),
-                ]))))
+                right: Box::new(Expr::OuterJoin(Box::new(Expr::CompoundIdentifier(
+                    vec![Ident::new("t2"), Ident::new("c2"), Ident::new("d3"),].into()
+                ))))
             }
             .into()
         )
@@ -1000,21 +1084,26 @@ fn parse_select_distinct_on() {
     let sql = "SELECT DISTINCT ON (album_id) name FROM track ORDER BY album_id, milliseconds";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Some(Distinct::On(vec![Expr::Identifier(Ident::new("album_id"))])),
+        &Some(Distinct::On(
+            vec![Expr::Identifier(Box::new(Ident::new("album_id")))].into()
+        )),
         &select.distinct
     );
 
     let sql = "SELECT DISTINCT ON () name FROM track ORDER BY milliseconds";
     let select = verified_only_select(sql);
-    assert_eq!(&Some(Distinct::On(vec![])), &select.distinct);
+    assert_eq!(&Some(Distinct::On(vec![].into())), &select.distinct);
 
     let sql = "SELECT DISTINCT ON (album_id, milliseconds) name FROM track";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Some(Distinct::On(vec![
-            Expr::Identifier(Ident::new("album_id")),
-            Expr::Identifier(Ident::new("milliseconds")),
-        ])),
+        &Some(Distinct::On(
+            vec![
+                Expr::Identifier(Box::new(Ident::new("album_id"))),
+                Expr::Identifier(Box::new(Ident::new("milliseconds"))),
+            ]
+            .into()
+        )),
         &select.distinct
     );
 }
@@ -1166,6 +1255,7 @@ fn parse_column_aliases() {
             ref op, ref right, ..
         },
         ref alias,
+        comments: _,
     } = only(&select.projection)
     {
         assert_eq!(&BinaryOperator::Plus, op);
@@ -1199,13 +1289,13 @@ fn parse_select_count_wildcard() {
     let sql = "SELECT COUNT(*) FROM customer";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::Function(Function {
+        &Expr::Function(Box::new(Function {
             name: ObjectName::from(vec![Ident::new("COUNT")]),
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
             args: FunctionArguments::List(FunctionArgumentList {
                 duplicate_treatment: None,
-                args: vec![FunctionArg::Unnamed(FunctionArgExpr::Wildcard)],
+                args: vec![FunctionArg::Unnamed(FunctionArgExpr::Wildcard)].into(),
                 clauses: vec![],
             }),
             null_treatment: None,
@@ -1213,7 +1303,7 @@ fn parse_select_count_wildcard() {
             filter: None,
             over: None,
             within_group: vec![]
-        }),
+        })),
         expr_from_projection(only(&select.projection))
     );
 }
@@ -1223,7 +1313,7 @@ fn parse_select_count_distinct() {
     let sql = "SELECT COUNT(DISTINCT +x) FROM customer";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::Function(Function {
+        &Expr::Function(Box::new(Function {
             name: ObjectName::from(vec![Ident::new("COUNT")]),
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
@@ -1231,8 +1321,9 @@ fn parse_select_count_distinct() {
                 duplicate_treatment: Some(DuplicateTreatment::Distinct),
                 args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::UnaryOp {
                     op: UnaryOperator::Plus,
-                    expr: Box::new(Expr::Identifier(Ident::new("x"))),
-                }))],
+                    expr: Box::new(Expr::Identifier(Box::new(Ident::new("x")))),
+                }))]
+                .into(),
                 clauses: vec![],
             }),
             null_treatment: None,
@@ -1240,7 +1331,7 @@ fn parse_select_count_distinct() {
             within_group: vec![],
             filter: None,
             over: None
-        }),
+        })),
         expr_from_projection(only(&select.projection))
     );
 
@@ -1276,7 +1367,10 @@ fn parse_collate() {
     let sql = "SELECT name COLLATE \"de_DE\" FROM customer";
     assert_matches!(
         only(&all_dialects().verified_only_select(sql).projection),
-        SelectItem::UnnamedExpr(Expr::Collate { .. })
+        SelectItem::UnnamedExpr {
+            expr: Expr::Collate { .. },
+            ..
+        }
     );
 }
 
@@ -1285,7 +1379,10 @@ fn parse_collate_after_parens() {
     let sql = "SELECT (name) COLLATE \"de_DE\" FROM customer";
     assert_matches!(
         only(&all_dialects().verified_only_select(sql).projection),
-        SelectItem::UnnamedExpr(Expr::Collate { .. })
+        SelectItem::UnnamedExpr {
+            expr: Expr::Collate { .. },
+            ..
+        }
     );
 }
 
@@ -1309,7 +1406,7 @@ fn parse_null_in_select() {
     let sql = "SELECT NULL";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::Value((Value::Null).with_empty_span()),
+        &Expr::Value(Box::new((Value::Null).with_empty_span())),
         expr_from_projection(only(&select.projection)),
     );
 }
@@ -1336,18 +1433,32 @@ fn parse_exponent_in_select() -> Result<(), ParserError> {
 
     assert_eq!(
         &vec![
-            SelectItem::UnnamedExpr(Expr::Value((number("10e-20")).with_empty_span())),
-            SelectItem::UnnamedExpr(Expr::value(number("1e3"))),
-            SelectItem::UnnamedExpr(Expr::Value((number("1e+3")).with_empty_span())),
+            SelectItem::UnnamedExpr {
+                expr: Expr::Value(Box::new((number("10e-20")).with_empty_span())),
+                comments: Comments::default(),
+            },
+            SelectItem::UnnamedExpr {
+                expr: Expr::value(number("1e3")),
+                comments: Comments::default(),
+            },
+            SelectItem::UnnamedExpr {
+                expr: Expr::Value(Box::new((number("1e+3")).with_empty_span())),
+                comments: Comments::default(),
+            },
             SelectItem::ExprWithAlias {
                 expr: Expr::value(number("1e3")),
-                alias: Ident::new("a")
+                alias: Ident::new("a"),
+                comments: Comments::default()
             },
             SelectItem::ExprWithAlias {
                 expr: Expr::value(number("1")),
-                alias: Ident::new("e")
+                alias: Ident::new("e"),
+                comments: Comments::default()
+            },
+            SelectItem::UnnamedExpr {
+                expr: Expr::value(number("0.5e2")),
+                comments: Comments::default(),
             },
-            SelectItem::UnnamedExpr(Expr::value(number("0.5e2"))),
         ],
         &select.projection
     );
@@ -1360,11 +1471,11 @@ fn parse_select_with_date_column_name() {
     let sql = "SELECT date";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::Identifier(Ident {
+        &Expr::Identifier(Box::new(Ident {
             value: "date".into(),
             quote_style: None,
             span: Span::empty(),
-        }),
+        })),
         expr_from_projection(only(&select.projection)),
     );
 }
@@ -1379,11 +1490,11 @@ fn parse_escaped_single_quote_string_predicate_with_escape() {
 
     assert_eq!(
         Some(Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::new("salary"))),
+            left: Box::new(Expr::Identifier(Box::new(Ident::new("salary")))),
             op: NotEq,
-            right: Box::new(Expr::Value(
-                (Value::SingleQuotedString("Jim's salary".to_string())).with_empty_span()
-            )),
+            right: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("Jim's salary".into())).with_empty_span()
+            ))),
         }),
         ast.selection.map(Box::into_owned),
     );
@@ -1405,11 +1516,11 @@ fn parse_escaped_single_quote_string_predicate_with_no_escape() {
 
     assert_eq!(
         Some(Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::new("salary"))),
+            left: Box::new(Expr::Identifier(Box::new(Ident::new("salary")))),
             op: NotEq,
-            right: Box::new(Expr::Value(
-                (Value::SingleQuotedString("Jim''s salary".to_string())).with_empty_span()
-            )),
+            right: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("Jim''s salary".into())).with_empty_span()
+            ))),
         }),
         ast.selection.map(Box::into_owned),
     );
@@ -1422,13 +1533,17 @@ fn parse_number() {
     #[cfg(feature = "bigdecimal")]
     assert_eq!(
         expr,
-        Expr::Value((Value::Number(bigdecimal::BigDecimal::from(1), false)).with_empty_span())
+        Expr::Value(Box::new(
+            (Value::Number(Box::new(bigdecimal::BigDecimal::from(1)), false)).with_empty_span()
+        ))
     );
 
     #[cfg(not(feature = "bigdecimal"))]
     assert_eq!(
         expr,
-        Expr::Value((Value::Number("1.0".into(), false)).with_empty_span())
+        Expr::Value(Box::new(
+            (Value::Number("1.0".into(), false)).with_empty_span()
+        ))
     );
 }
 
@@ -1439,12 +1554,12 @@ fn parse_compound_expr_1() {
     let sql = "a + b * c";
     assert_eq!(
         BinaryOp {
-            left: Box::new(Identifier(Ident::new("a"))),
+            left: Box::new(Identifier(Box::new(Ident::new("a")))),
             op: Plus,
             right: Box::new(BinaryOp {
-                left: Box::new(Identifier(Ident::new("b"))),
+                left: Box::new(Identifier(Box::new(Ident::new("b")))),
                 op: Multiply,
-                right: Box::new(Identifier(Ident::new("c"))),
+                right: Box::new(Identifier(Box::new(Ident::new("c")))),
             }),
         },
         verified_expr(sql)
@@ -1459,12 +1574,12 @@ fn parse_compound_expr_2() {
     assert_eq!(
         BinaryOp {
             left: Box::new(BinaryOp {
-                left: Box::new(Identifier(Ident::new("a"))),
+                left: Box::new(Identifier(Box::new(Ident::new("a")))),
                 op: Multiply,
-                right: Box::new(Identifier(Ident::new("b"))),
+                right: Box::new(Identifier(Box::new(Ident::new("b")))),
             }),
             op: Plus,
-            right: Box::new(Identifier(Ident::new("c"))),
+            right: Box::new(Identifier(Box::new(Ident::new("c")))),
         },
         verified_expr(sql)
     );
@@ -1478,12 +1593,12 @@ fn parse_unary_math_with_plus() {
         BinaryOp {
             left: Box::new(UnaryOp {
                 op: UnaryOperator::Minus,
-                expr: Box::new(Identifier(Ident::new("a"))),
+                expr: Box::new(Identifier(Box::new(Ident::new("a")))),
             }),
             op: BinaryOperator::Plus,
             right: Box::new(UnaryOp {
                 op: UnaryOperator::Minus,
-                expr: Box::new(Identifier(Ident::new("b"))),
+                expr: Box::new(Identifier(Box::new(Ident::new("b")))),
             }),
         },
         verified_expr(sql)
@@ -1498,12 +1613,12 @@ fn parse_unary_math_with_multiply() {
         BinaryOp {
             left: Box::new(UnaryOp {
                 op: UnaryOperator::Minus,
-                expr: Box::new(Identifier(Ident::new("a"))),
+                expr: Box::new(Identifier(Box::new(Ident::new("a")))),
             }),
             op: BinaryOperator::Multiply,
             right: Box::new(UnaryOp {
                 op: UnaryOperator::Minus,
-                expr: Box::new(Identifier(Ident::new("b"))),
+                expr: Box::new(Identifier(Box::new(Ident::new("b")))),
             }),
         },
         verified_expr(sql)
@@ -1516,9 +1631,9 @@ fn parse_mod() {
     let sql = "a % b";
     assert_eq!(
         BinaryOp {
-            left: Box::new(Identifier(Ident::new("a"))),
+            left: Box::new(Identifier(Box::new(Ident::new("a")))),
             op: BinaryOperator::Modulo,
-            right: Box::new(Identifier(Ident::new("b"))),
+            right: Box::new(Identifier(Box::new(Ident::new("b")))),
         },
         verified_expr(sql)
     );
@@ -1554,11 +1669,14 @@ fn parse_json_ops_without_colon() {
     for (str_op, op, dialects) in binary_ops {
         let select = dialects.verified_only_select(&format!("SELECT a {} b", &str_op));
         assert_eq!(
-            SelectItem::UnnamedExpr(Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("a"))),
-                op,
-                right: Box::new(Expr::Identifier(Ident::new("b"))),
-            }),
+            SelectItem::UnnamedExpr {
+                expr: Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
+                    op,
+                    right: Box::new(Expr::Identifier(Box::new(Ident::new("b")))),
+                },
+                comments: Comments::default(),
+            },
             select.projection[0]
         );
     }
@@ -1571,21 +1689,28 @@ fn parse_json_object() {
         std::boxed::Box::new(PostgreSqlDialect {}),
     ]);
     let select = dialects.verified_only_select("SELECT JSON_OBJECT('name' : 'value', 'type' : 1)");
-    match expr_from_projection(&select.projection[0]) {
-        Expr::Function(Function {
+    let Expr::Function(function) = expr_from_projection(&select.projection[0]) else {
+        unreachable!()
+    };
+    match &**function {
+        Function {
             args: FunctionArguments::List(FunctionArgumentList { args, .. }),
             ..
-        }) => assert_eq!(
+        } => assert_eq!(
             &[
                 FunctionArg::ExprNamed {
-                    name: Expr::Value((Value::SingleQuotedString("name".into())).with_empty_span()),
-                    arg: FunctionArgExpr::Expr(Expr::Value(
-                        (Value::SingleQuotedString("value".into())).with_empty_span()
+                    name: Expr::Value(Box::new(
+                        (Value::SingleQuotedString("name".into())).with_empty_span()
                     )),
+                    arg: FunctionArgExpr::Expr(Expr::Value(Box::new(
+                        (Value::SingleQuotedString("value".into())).with_empty_span()
+                    ))),
                     operator: FunctionArgOperator::Colon
                 },
                 FunctionArg::ExprNamed {
-                    name: Expr::Value((Value::SingleQuotedString("type".into())).with_empty_span()),
+                    name: Expr::Value(Box::new(
+                        (Value::SingleQuotedString("type".into())).with_empty_span()
+                    )),
                     arg: FunctionArgExpr::Expr(Expr::value(number("1"))),
                     operator: FunctionArgOperator::Colon
                 }
@@ -1596,27 +1721,32 @@ fn parse_json_object() {
     }
     let select = dialects
         .verified_only_select("SELECT JSON_OBJECT('name' : 'value', 'type' : NULL ABSENT ON NULL)");
-    match expr_from_projection(&select.projection[0]) {
-        Expr::Function(Function {
+    let Expr::Function(function) = expr_from_projection(&select.projection[0]) else {
+        unreachable!()
+    };
+    match &**function {
+        Function {
             args: FunctionArguments::List(FunctionArgumentList { args, clauses, .. }),
             ..
-        }) => {
+        } => {
             assert_eq!(
                 &[
                     FunctionArg::ExprNamed {
-                        name: Expr::Value(
+                        name: Expr::Value(Box::new(
                             (Value::SingleQuotedString("name".into())).with_empty_span()
-                        ),
-                        arg: FunctionArgExpr::Expr(Expr::Value(
-                            (Value::SingleQuotedString("value".into())).with_empty_span()
                         )),
+                        arg: FunctionArgExpr::Expr(Expr::Value(Box::new(
+                            (Value::SingleQuotedString("value".into())).with_empty_span()
+                        ))),
                         operator: FunctionArgOperator::Colon
                     },
                     FunctionArg::ExprNamed {
-                        name: Expr::Value(
+                        name: Expr::Value(Box::new(
                             (Value::SingleQuotedString("type".into())).with_empty_span()
-                        ),
-                        arg: FunctionArgExpr::Expr(Expr::Value((Value::Null).with_empty_span())),
+                        )),
+                        arg: FunctionArgExpr::Expr(Expr::Value(Box::new(
+                            (Value::Null).with_empty_span()
+                        ))),
                         operator: FunctionArgOperator::Colon
                     }
                 ],
@@ -1632,11 +1762,14 @@ fn parse_json_object() {
         _ => unreachable!(),
     }
     let select = dialects.verified_only_select("SELECT JSON_OBJECT(NULL ON NULL)");
-    match expr_from_projection(&select.projection[0]) {
-        Expr::Function(Function {
+    let Expr::Function(function) = expr_from_projection(&select.projection[0]) else {
+        unreachable!()
+    };
+    match &**function {
+        Function {
             args: FunctionArguments::List(FunctionArgumentList { args, clauses, .. }),
             ..
-        }) => {
+        } => {
             assert!(args.is_empty());
             assert_eq!(
                 &[FunctionArgumentClause::JsonNullClause(
@@ -1648,11 +1781,14 @@ fn parse_json_object() {
         _ => unreachable!(),
     }
     let select = dialects.verified_only_select("SELECT JSON_OBJECT(ABSENT ON NULL)");
-    match expr_from_projection(&select.projection[0]) {
-        Expr::Function(Function {
+    let Expr::Function(function) = expr_from_projection(&select.projection[0]) else {
+        unreachable!()
+    };
+    match &**function {
+        Function {
             args: FunctionArguments::List(FunctionArgumentList { args, clauses, .. }),
             ..
-        }) => {
+        } => {
             assert!(args.is_empty());
             assert_eq!(
                 &[FunctionArgumentClause::JsonNullClause(
@@ -1666,31 +1802,33 @@ fn parse_json_object() {
     let select = dialects.verified_only_select(
         "SELECT JSON_OBJECT('name' : 'value', 'type' : JSON_ARRAY(1, 2) ABSENT ON NULL)",
     );
-    match expr_from_projection(&select.projection[0]) {
-        Expr::Function(Function {
+    let Expr::Function(function) = expr_from_projection(&select.projection[0]) else {
+        unreachable!()
+    };
+    match &**function {
+        Function {
             args: FunctionArguments::List(FunctionArgumentList { args, clauses, .. }),
             ..
-        }) => {
+        } => {
             assert_eq!(
                 &FunctionArg::ExprNamed {
-                    name: Expr::Value((Value::SingleQuotedString("name".into())).with_empty_span()),
-                    arg: FunctionArgExpr::Expr(Expr::Value(
-                        (Value::SingleQuotedString("value".into())).with_empty_span()
+                    name: Expr::Value(Box::new(
+                        (Value::SingleQuotedString("name".into())).with_empty_span()
                     )),
+                    arg: FunctionArgExpr::Expr(Expr::Value(Box::new(
+                        (Value::SingleQuotedString("value".into())).with_empty_span()
+                    ))),
                     operator: FunctionArgOperator::Colon
                 },
                 &args[0]
             );
             assert!(matches!(
-                args[1],
+                &args[1],
                 FunctionArg::ExprNamed {
-                    name: Expr::Value(ValueWithSpan {
-                        value: Value::SingleQuotedString(_),
-                        span: _
-                    }),
+                    name: Expr::Value(name),
                     arg: FunctionArgExpr::Expr(Expr::Function(_)),
                     operator: FunctionArgOperator::Colon
-                }
+                } if matches!(name.value, Value::SingleQuotedString(_))
             ));
             assert_eq!(
                 &[FunctionArgumentClause::JsonNullClause(
@@ -1704,31 +1842,33 @@ fn parse_json_object() {
     let select = dialects.verified_only_select(
         "SELECT JSON_OBJECT('name' : 'value', 'type' : JSON_OBJECT('type_id' : 1, 'name' : 'a') NULL ON NULL)",
     );
-    match expr_from_projection(&select.projection[0]) {
-        Expr::Function(Function {
+    let Expr::Function(function) = expr_from_projection(&select.projection[0]) else {
+        unreachable!()
+    };
+    match &**function {
+        Function {
             args: FunctionArguments::List(FunctionArgumentList { args, clauses, .. }),
             ..
-        }) => {
+        } => {
             assert_eq!(
                 &FunctionArg::ExprNamed {
-                    name: Expr::Value((Value::SingleQuotedString("name".into())).with_empty_span()),
-                    arg: FunctionArgExpr::Expr(Expr::Value(
-                        (Value::SingleQuotedString("value".into())).with_empty_span()
+                    name: Expr::Value(Box::new(
+                        (Value::SingleQuotedString("name".into())).with_empty_span()
                     )),
+                    arg: FunctionArgExpr::Expr(Expr::Value(Box::new(
+                        (Value::SingleQuotedString("value".into())).with_empty_span()
+                    ))),
                     operator: FunctionArgOperator::Colon
                 },
                 &args[0]
             );
             assert!(matches!(
-                args[1],
+                &args[1],
                 FunctionArg::ExprNamed {
-                    name: Expr::Value(ValueWithSpan {
-                        value: Value::SingleQuotedString(_),
-                        span: _
-                    }),
+                    name: Expr::Value(name),
                     arg: FunctionArgExpr::Expr(Expr::Function(_)),
                     operator: FunctionArgOperator::Colon
-                }
+                } if matches!(name.value, Value::SingleQuotedString(_))
             ));
             assert_eq!(
                 &[FunctionArgumentClause::JsonNullClause(
@@ -1750,9 +1890,9 @@ fn parse_mod_no_spaces() {
         println!("Parsing {sql}");
         assert_eq!(
             BinaryOp {
-                left: Box::new(Identifier(Ident::new("a1"))),
+                left: Box::new(Identifier(Box::new(Ident::new("a1")))),
                 op: BinaryOperator::Modulo,
-                right: Box::new(Identifier(Ident::new("b1"))),
+                right: Box::new(Identifier(Box::new(Ident::new("b1")))),
             },
             pg_and_generic().expr_parses_to(sql, canonical)
         );
@@ -1783,8 +1923,8 @@ fn parse_is_distinct_from() {
     let sql = "a IS DISTINCT FROM b";
     assert_eq!(
         IsDistinctFrom(
-            Box::new(Identifier(Ident::new("a"))),
-            Box::new(Identifier(Ident::new("b"))),
+            Box::new(Identifier(Box::new(Ident::new("a")))),
+            Box::new(Identifier(Box::new(Ident::new("b")))),
         ),
         verified_expr(sql)
     );
@@ -1796,8 +1936,8 @@ fn parse_is_not_distinct_from() {
     let sql = "a IS NOT DISTINCT FROM b";
     assert_eq!(
         IsNotDistinctFrom(
-            Box::new(Identifier(Ident::new("a"))),
-            Box::new(Identifier(Ident::new("b"))),
+            Box::new(Identifier(Box::new(Ident::new("a")))),
+            Box::new(Identifier(Box::new(Ident::new("b")))),
         ),
         verified_expr(sql)
     );
@@ -1809,7 +1949,7 @@ fn parse_is_json() {
     let sql = "a IS JSON";
     assert_eq!(
         Expr::IsJson {
-            expr: Box::new(Expr::Identifier(Ident::new("a"))),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
             negated: false,
             json_predicate_type: None,
             unique_keys: None,
@@ -1821,7 +1961,7 @@ fn parse_is_json() {
     let sql = "a IS NOT JSON";
     assert_eq!(
         Expr::IsJson {
-            expr: Box::new(Expr::Identifier(Ident::new("a"))),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
             negated: true,
             json_predicate_type: None,
             unique_keys: None,
@@ -1833,7 +1973,7 @@ fn parse_is_json() {
     let sql = "a IS JSON VALUE";
     assert_eq!(
         Expr::IsJson {
-            expr: Box::new(Expr::Identifier(Ident::new("a"))),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
             negated: false,
             json_predicate_type: Some(JsonPredicateType::Value),
             unique_keys: None,
@@ -1845,7 +1985,7 @@ fn parse_is_json() {
     let sql = "a IS JSON ARRAY";
     assert_eq!(
         Expr::IsJson {
-            expr: Box::new(Expr::Identifier(Ident::new("a"))),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
             negated: false,
             json_predicate_type: Some(JsonPredicateType::Array),
             unique_keys: None,
@@ -1857,7 +1997,7 @@ fn parse_is_json() {
     let sql = "a IS JSON OBJECT";
     assert_eq!(
         Expr::IsJson {
-            expr: Box::new(Expr::Identifier(Ident::new("a"))),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
             negated: false,
             json_predicate_type: Some(JsonPredicateType::Object),
             unique_keys: None,
@@ -1869,7 +2009,7 @@ fn parse_is_json() {
     let sql = "a IS JSON SCALAR";
     assert_eq!(
         Expr::IsJson {
-            expr: Box::new(Expr::Identifier(Ident::new("a"))),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
             negated: false,
             json_predicate_type: Some(JsonPredicateType::Scalar),
             unique_keys: None,
@@ -1881,7 +2021,7 @@ fn parse_is_json() {
     let sql = "a IS JSON WITH UNIQUE KEYS";
     assert_eq!(
         Expr::IsJson {
-            expr: Box::new(Expr::Identifier(Ident::new("a"))),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
             negated: false,
             json_predicate_type: None,
             unique_keys: Some(JsonPredicateUniqueKeyConstraint::WithUniqueKeys),
@@ -1893,7 +2033,7 @@ fn parse_is_json() {
     let sql = "a IS JSON WITHOUT UNIQUE KEYS";
     assert_eq!(
         Expr::IsJson {
-            expr: Box::new(Expr::Identifier(Ident::new("a"))),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
             negated: false,
             json_predicate_type: None,
             unique_keys: Some(JsonPredicateUniqueKeyConstraint::WithoutUniqueKeys),
@@ -1905,7 +2045,7 @@ fn parse_is_json() {
     let sql = "a IS NOT JSON OBJECT WITH UNIQUE KEYS";
     assert_eq!(
         Expr::IsJson {
-            expr: Box::new(Expr::Identifier(Ident::new("a"))),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
             negated: true,
             json_predicate_type: Some(JsonPredicateType::Object),
             unique_keys: Some(JsonPredicateUniqueKeyConstraint::WithUniqueKeys),
@@ -1963,13 +2103,13 @@ fn parse_not_precedence() {
         Expr::UnaryOp {
             op: UnaryOperator::Not,
             expr: Box::new(Expr::Like {
-                expr: Box::new(Expr::Value(
+                expr: Box::new(Expr::Value(Box::new(
                     (Value::SingleQuotedString("a".into())).with_empty_span()
-                )),
+                ))),
                 negated: true,
-                pattern: Box::new(Expr::Value(
+                pattern: Box::new(Expr::Value(Box::new(
                     (Value::SingleQuotedString("b".into())).with_empty_span()
-                )),
+                ))),
                 escape_char: None,
                 any: false,
             }),
@@ -1983,10 +2123,11 @@ fn parse_not_precedence() {
         Expr::UnaryOp {
             op: UnaryOperator::Not,
             expr: Box::new(Expr::InList {
-                expr: Box::new(Expr::Identifier("a".into())),
-                list: vec![Expr::Value(
+                expr: Box::new(Expr::Identifier(Box::new("a".into()))),
+                list: vec![Expr::Value(Box::new(
                     (Value::SingleQuotedString("a".into())).with_empty_span()
-                )],
+                ))]
+                .into(),
                 negated: true,
             }),
         },
@@ -2003,34 +2144,36 @@ fn parse_null_like() {
     assert_eq!(
         SelectItem::ExprWithAlias {
             expr: Expr::Like {
-                expr: Box::new(Expr::Identifier(Ident::new("column1"))),
+                expr: Box::new(Expr::Identifier(Box::new(Ident::new("column1")))),
                 any: false,
                 negated: false,
-                pattern: Box::new(Expr::Value((Value::Null).with_empty_span())),
+                pattern: Box::new(Expr::Value(Box::new((Value::Null).with_empty_span()))),
                 escape_char: None,
             },
             alias: Ident {
-                value: "col_null".to_owned(),
+                value: "col_null".into(),
                 quote_style: None,
                 span: Span::empty(),
             },
+            comments: Comments::default(),
         },
         select.projection[0]
     );
     assert_eq!(
         SelectItem::ExprWithAlias {
             expr: Expr::Like {
-                expr: Box::new(Expr::Value((Value::Null).with_empty_span())),
+                expr: Box::new(Expr::Value(Box::new((Value::Null).with_empty_span()))),
                 any: false,
                 negated: false,
-                pattern: Box::new(Expr::Identifier(Ident::new("column1"))),
+                pattern: Box::new(Expr::Identifier(Box::new(Ident::new("column1")))),
                 escape_char: None,
             },
             alias: Ident {
-                value: "null_col".to_owned(),
+                value: "null_col".into(),
                 quote_style: None,
                 span: Span::empty(),
             },
+            comments: Comments::default(),
         },
         select.projection[1]
     );
@@ -2046,11 +2189,11 @@ fn parse_ilike() {
         let select = verified_only_select(sql);
         assert_eq!(
             Expr::ILike {
-                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                expr: Box::new(Expr::Identifier(Box::new(Ident::new("name")))),
                 negated,
-                pattern: Box::new(Expr::Value(
-                    (Value::SingleQuotedString("%a".to_string())).with_empty_span()
-                )),
+                pattern: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("%a".into())).with_empty_span()
+                ))),
                 escape_char: None,
                 any: false,
             },
@@ -2065,12 +2208,12 @@ fn parse_ilike() {
         let select = verified_only_select(sql);
         assert_eq!(
             Expr::ILike {
-                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                expr: Box::new(Expr::Identifier(Box::new(Ident::new("name")))),
                 negated,
-                pattern: Box::new(Expr::Value(
-                    (Value::SingleQuotedString("%a".to_string())).with_empty_span()
-                )),
-                escape_char: Some(Value::SingleQuotedString('^'.to_string())),
+                pattern: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("%a".into())).with_empty_span()
+                ))),
+                escape_char: Some(Value::SingleQuotedString("^".into())),
                 any: false,
             },
             Box::into_owned(select.selection.unwrap())
@@ -2102,11 +2245,11 @@ fn parse_like() {
         let select = verified_only_select(sql);
         assert_eq!(
             Expr::Like {
-                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                expr: Box::new(Expr::Identifier(Box::new(Ident::new("name")))),
                 negated,
-                pattern: Box::new(Expr::Value(
-                    (Value::SingleQuotedString("%a".to_string())).with_empty_span()
-                )),
+                pattern: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("%a".into())).with_empty_span()
+                ))),
                 escape_char: None,
                 any: false,
             },
@@ -2121,12 +2264,12 @@ fn parse_like() {
         let select = verified_only_select(sql);
         assert_eq!(
             Expr::Like {
-                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                expr: Box::new(Expr::Identifier(Box::new(Ident::new("name")))),
                 negated,
-                pattern: Box::new(Expr::Value(
-                    (Value::SingleQuotedString("%a".to_string())).with_empty_span()
-                )),
-                escape_char: Some(Value::SingleQuotedString('^'.to_string())),
+                pattern: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("%a".into())).with_empty_span()
+                ))),
+                escape_char: Some(Value::SingleQuotedString("^".into())),
                 any: false,
             },
             Box::into_owned(select.selection.unwrap())
@@ -2158,11 +2301,11 @@ fn parse_similar_to() {
         let select = verified_only_select(sql);
         assert_eq!(
             Expr::SimilarTo {
-                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                expr: Box::new(Expr::Identifier(Box::new(Ident::new("name")))),
                 negated,
-                pattern: Box::new(Expr::Value(
-                    (Value::SingleQuotedString("%a".to_string())).with_empty_span()
-                )),
+                pattern: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("%a".into())).with_empty_span()
+                ))),
                 escape_char: None,
             },
             Box::into_owned(select.selection.unwrap())
@@ -2176,12 +2319,12 @@ fn parse_similar_to() {
         let select = verified_only_select(sql);
         assert_eq!(
             Expr::SimilarTo {
-                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                expr: Box::new(Expr::Identifier(Box::new(Ident::new("name")))),
                 negated,
-                pattern: Box::new(Expr::Value(
-                    (Value::SingleQuotedString("%a".to_string())).with_empty_span()
-                )),
-                escape_char: Some(Value::SingleQuotedString('^'.to_string())),
+                pattern: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("%a".into())).with_empty_span()
+                ))),
+                escape_char: Some(Value::SingleQuotedString("^".into())),
             },
             Box::into_owned(select.selection.unwrap())
         );
@@ -2193,11 +2336,11 @@ fn parse_similar_to() {
         let select = verified_only_select(sql);
         assert_eq!(
             Expr::SimilarTo {
-                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                expr: Box::new(Expr::Identifier(Box::new(Ident::new("name")))),
                 negated,
-                pattern: Box::new(Expr::Value(
-                    (Value::SingleQuotedString("%a".to_string())).with_empty_span()
-                )),
+                pattern: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("%a".into())).with_empty_span()
+                ))),
                 escape_char: Some(Value::Null),
             },
             Box::into_owned(select.selection.unwrap())
@@ -2228,11 +2371,16 @@ fn parse_in_list() {
         let select = verified_only_select(sql);
         assert_eq!(
             Expr::InList {
-                expr: Box::new(Expr::Identifier(Ident::new("segment"))),
+                expr: Box::new(Expr::Identifier(Box::new(Ident::new("segment")))),
                 list: vec![
-                    Expr::Value((Value::SingleQuotedString("HIGH".to_string())).with_empty_span()),
-                    Expr::Value((Value::SingleQuotedString("MED".to_string())).with_empty_span()),
-                ],
+                    Expr::Value(Box::new(
+                        (Value::SingleQuotedString("HIGH".into())).with_empty_span()
+                    )),
+                    Expr::Value(Box::new(
+                        (Value::SingleQuotedString("MED".into())).with_empty_span()
+                    )),
+                ]
+                .into(),
                 negated,
             },
             Box::into_owned(select.selection.unwrap())
@@ -2248,7 +2396,7 @@ fn parse_in_subquery() {
     let select = verified_only_select(sql);
     assert_eq!(
         Expr::InSubquery {
-            expr: Box::new(Expr::Identifier(Ident::new("segment"))),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("segment")))),
             subquery: Box::new(verified_query("SELECT segm FROM bar")),
             negated: false,
         },
@@ -2262,7 +2410,7 @@ fn parse_in_union() {
     let select = verified_only_select(sql);
     assert_eq!(
         Expr::InSubquery {
-            expr: Box::new(Expr::Identifier(Ident::new("segment"))),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("segment")))),
             subquery: Box::new(verified_query(
                 "(SELECT segm FROM bar) UNION (SELECT segm FROM bar2)"
             )),
@@ -2282,7 +2430,7 @@ fn parse_in_unnest() {
         let select = verified_only_select(sql);
         assert_eq!(
             Expr::InUnnest {
-                expr: Box::new(Expr::Identifier(Ident::new("segment"))),
+                expr: Box::new(Expr::Identifier(Box::new(Ident::new("segment")))),
                 array_expr: Box::new(verified_expr("expr")),
                 negated,
             },
@@ -2310,11 +2458,14 @@ fn parse_string_agg() {
 
     let select = verified_only_select(sql);
     assert_eq!(
-        SelectItem::UnnamedExpr(Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::new("a"))),
-            op: BinaryOperator::StringConcat,
-            right: Box::new(Expr::Identifier(Ident::new("b"))),
-        }),
+        SelectItem::UnnamedExpr {
+            expr: Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
+                op: BinaryOperator::StringConcat,
+                right: Box::new(Expr::Identifier(Box::new(Ident::new("b")))),
+            },
+            comments: Comments::default(),
+        },
         select.projection[0]
     );
 }
@@ -2341,11 +2492,14 @@ fn parse_bitwise_ops() {
     for (str_op, op, dialects) in bitwise_ops {
         let select = dialects.verified_only_select(&format!("SELECT a {} b", &str_op));
         assert_eq!(
-            SelectItem::UnnamedExpr(Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("a"))),
-                op: op.clone(),
-                right: Box::new(Expr::Identifier(Ident::new("b"))),
-            }),
+            SelectItem::UnnamedExpr {
+                expr: Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
+                    op: op.clone(),
+                    right: Box::new(Expr::Identifier(Box::new(Ident::new("b")))),
+                },
+                comments: Comments::default(),
+            },
             select.projection[0]
         );
     }
@@ -2355,12 +2509,15 @@ fn parse_bitwise_ops() {
 fn parse_binary_any() {
     let select = verified_only_select("SELECT a = ANY(b)");
     assert_eq!(
-        SelectItem::UnnamedExpr(Expr::AnyOp {
-            left: Box::new(Expr::Identifier(Ident::new("a"))),
-            compare_op: BinaryOperator::Eq,
-            right: Box::new(Expr::Identifier(Ident::new("b"))),
-            is_some: false,
-        }),
+        SelectItem::UnnamedExpr {
+            expr: Expr::AnyOp {
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
+                compare_op: BinaryOperator::Eq,
+                right: Box::new(Expr::Identifier(Box::new(Ident::new("b")))),
+                is_some: false,
+            },
+            comments: Comments::default(),
+        },
         select.projection[0]
     );
 }
@@ -2369,11 +2526,14 @@ fn parse_binary_any() {
 fn parse_binary_all() {
     let select = verified_only_select("SELECT a = ALL(b)");
     assert_eq!(
-        SelectItem::UnnamedExpr(Expr::AllOp {
-            left: Box::new(Expr::Identifier(Ident::new("a"))),
-            compare_op: BinaryOperator::Eq,
-            right: Box::new(Expr::Identifier(Ident::new("b"))),
-        }),
+        SelectItem::UnnamedExpr {
+            expr: Expr::AllOp {
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
+                compare_op: BinaryOperator::Eq,
+                right: Box::new(Expr::Identifier(Box::new(Ident::new("b")))),
+            },
+            comments: Comments::default(),
+        },
         select.projection[0]
     );
 }
@@ -2388,7 +2548,7 @@ fn parse_between() {
         let select = verified_only_select(sql);
         assert_eq!(
             Expr::Between {
-                expr: Box::new(Expr::Identifier(Ident::new("age"))),
+                expr: Box::new(Expr::Identifier(Box::new(Ident::new("age")))),
                 negated,
                 symmetric: BetweenSymmetric::None,
                 low: Box::new(Expr::value(number("25"))),
@@ -2424,7 +2584,7 @@ fn parse_between_with_expr() {
                 expr: Box::new(Expr::BinaryOp {
                     left: Box::new(Expr::value(number("1"))),
                     op: BinaryOperator::Plus,
-                    right: Box::new(Expr::Identifier(Ident::new("x"))),
+                    right: Box::new(Expr::Identifier(Box::new(Ident::new("x")))),
                 }),
                 negated: false,
                 symmetric: BetweenSymmetric::None,
@@ -2442,18 +2602,29 @@ fn parse_tuples() {
     let select = verified_only_select(sql);
     assert_eq!(
         vec![
-            SelectItem::UnnamedExpr(Expr::Tuple(vec![
-                Expr::value(number("1")),
-                Expr::value(number("2")),
-            ])),
-            SelectItem::UnnamedExpr(Expr::Nested(Box::new(Expr::Value(
-                (number("1")).with_empty_span()
-            )))),
-            SelectItem::UnnamedExpr(Expr::Tuple(vec![
-                Expr::Value((Value::SingleQuotedString("foo".into())).with_empty_span()),
-                Expr::value(number("3")),
-                Expr::Identifier(Ident::new("baz")),
-            ])),
+            SelectItem::UnnamedExpr {
+                expr: Expr::Tuple(vec![Expr::value(number("1")), Expr::value(number("2")),].into()),
+                comments: Comments::default(),
+            },
+            SelectItem::UnnamedExpr {
+                expr: Expr::Nested(Box::new(Expr::Value(Box::new(
+                    (number("1")).with_empty_span()
+                )))),
+                comments: Comments::default(),
+            },
+            SelectItem::UnnamedExpr {
+                expr: Expr::Tuple(
+                    vec![
+                        Expr::Value(Box::new(
+                            (Value::SingleQuotedString("foo".into())).with_empty_span()
+                        )),
+                        Expr::value(number("3")),
+                        Expr::Identifier(Box::new(Ident::new("baz"))),
+                    ]
+                    .into()
+                ),
+                comments: Comments::default(),
+            },
         ],
         select.projection
     );
@@ -2481,32 +2652,35 @@ fn parse_select_order_by() {
     fn chk(sql: &str) {
         let select = verified_query(sql);
         assert_eq!(
-            OrderByKind::Expressions(vec![
-                OrderByExpr {
-                    expr: Expr::Identifier(Ident::new("lname")),
-                    options: OrderByOptions {
-                        asc: Some(true),
-                        nulls_first: None,
+            OrderByKind::Expressions(
+                vec![
+                    OrderByExpr {
+                        expr: Expr::Identifier(Box::new(Ident::new("lname"))),
+                        options: OrderByOptions {
+                            asc: Some(true),
+                            nulls_first: None,
+                        },
+                        with_fill: None,
                     },
-                    with_fill: None,
-                },
-                OrderByExpr {
-                    expr: Expr::Identifier(Ident::new("fname")),
-                    options: OrderByOptions {
-                        asc: Some(false),
-                        nulls_first: None,
+                    OrderByExpr {
+                        expr: Expr::Identifier(Box::new(Ident::new("fname"))),
+                        options: OrderByOptions {
+                            asc: Some(false),
+                            nulls_first: None,
+                        },
+                        with_fill: None,
                     },
-                    with_fill: None,
-                },
-                OrderByExpr {
-                    expr: Expr::Identifier(Ident::new("id")),
-                    options: OrderByOptions {
-                        asc: None,
-                        nulls_first: None,
+                    OrderByExpr {
+                        expr: Expr::Identifier(Box::new(Ident::new("id"))),
+                        options: OrderByOptions {
+                            asc: None,
+                            nulls_first: None,
+                        },
+                        with_fill: None,
                     },
-                    with_fill: None,
-                },
-            ]),
+                ]
+                .into()
+            ),
             select.order_by.expect("ORDER BY expected").kind
         );
     }
@@ -2522,24 +2696,27 @@ fn parse_select_order_by_limit() {
                ORDER BY lname ASC, fname DESC LIMIT 2";
     let select = verified_query(sql);
     assert_eq!(
-        OrderByKind::Expressions(vec![
-            OrderByExpr {
-                expr: Expr::Identifier(Ident::new("lname")),
-                options: OrderByOptions {
-                    asc: Some(true),
-                    nulls_first: None,
+        OrderByKind::Expressions(
+            vec![
+                OrderByExpr {
+                    expr: Expr::Identifier(Box::new(Ident::new("lname"))),
+                    options: OrderByOptions {
+                        asc: Some(true),
+                        nulls_first: None,
+                    },
+                    with_fill: None,
                 },
-                with_fill: None,
-            },
-            OrderByExpr {
-                expr: Expr::Identifier(Ident::new("fname")),
-                options: OrderByOptions {
-                    asc: Some(false),
-                    nulls_first: None,
+                OrderByExpr {
+                    expr: Expr::Identifier(Box::new(Ident::new("fname"))),
+                    options: OrderByOptions {
+                        asc: Some(false),
+                        nulls_first: None,
+                    },
+                    with_fill: None,
                 },
-                with_fill: None,
-            },
-        ]),
+            ]
+            .into()
+        ),
         select.order_by.expect("ORDER BY expected").kind
     );
     let expected_limit_clause = LimitClause::LimitOffset {
@@ -2566,36 +2743,45 @@ fn parse_select_order_by_not_support_all() {
     let test_cases = [
         (
             "SELECT id, ALL FROM customer WHERE id < 5 ORDER BY ALL",
-            OrderByKind::Expressions(vec![OrderByExpr {
-                expr: Expr::Identifier(Ident::new("ALL")),
-                options: OrderByOptions {
-                    asc: None,
-                    nulls_first: None,
-                },
-                with_fill: None,
-            }]),
+            OrderByKind::Expressions(
+                vec![OrderByExpr {
+                    expr: Expr::Identifier(Box::new(Ident::new("ALL"))),
+                    options: OrderByOptions {
+                        asc: None,
+                        nulls_first: None,
+                    },
+                    with_fill: None,
+                }]
+                .into(),
+            ),
         ),
         (
             "SELECT id, ALL FROM customer ORDER BY ALL ASC NULLS FIRST",
-            OrderByKind::Expressions(vec![OrderByExpr {
-                expr: Expr::Identifier(Ident::new("ALL")),
-                options: OrderByOptions {
-                    asc: Some(true),
-                    nulls_first: Some(true),
-                },
-                with_fill: None,
-            }]),
+            OrderByKind::Expressions(
+                vec![OrderByExpr {
+                    expr: Expr::Identifier(Box::new(Ident::new("ALL"))),
+                    options: OrderByOptions {
+                        asc: Some(true),
+                        nulls_first: Some(true),
+                    },
+                    with_fill: None,
+                }]
+                .into(),
+            ),
         ),
         (
             "SELECT id, ALL FROM customer ORDER BY ALL DESC NULLS LAST",
-            OrderByKind::Expressions(vec![OrderByExpr {
-                expr: Expr::Identifier(Ident::new("ALL")),
-                options: OrderByOptions {
-                    asc: Some(false),
-                    nulls_first: Some(false),
-                },
-                with_fill: None,
-            }]),
+            OrderByKind::Expressions(
+                vec![OrderByExpr {
+                    expr: Expr::Identifier(Box::new(Ident::new("ALL"))),
+                    options: OrderByOptions {
+                        asc: Some(false),
+                        nulls_first: Some(false),
+                    },
+                    with_fill: None,
+                }]
+                .into(),
+            ),
         ),
     ];
 
@@ -2610,24 +2796,27 @@ fn parse_select_order_by_nulls_order() {
                ORDER BY lname ASC NULLS FIRST, fname DESC NULLS LAST LIMIT 2";
     let select = verified_query(sql);
     assert_eq!(
-        OrderByKind::Expressions(vec![
-            OrderByExpr {
-                expr: Expr::Identifier(Ident::new("lname")),
-                options: OrderByOptions {
-                    asc: Some(true),
-                    nulls_first: Some(true),
+        OrderByKind::Expressions(
+            vec![
+                OrderByExpr {
+                    expr: Expr::Identifier(Box::new(Ident::new("lname"))),
+                    options: OrderByOptions {
+                        asc: Some(true),
+                        nulls_first: Some(true),
+                    },
+                    with_fill: None,
                 },
-                with_fill: None,
-            },
-            OrderByExpr {
-                expr: Expr::Identifier(Ident::new("fname")),
-                options: OrderByOptions {
-                    asc: Some(false),
-                    nulls_first: Some(false),
+                OrderByExpr {
+                    expr: Expr::Identifier(Box::new(Ident::new("fname"))),
+                    options: OrderByOptions {
+                        asc: Some(false),
+                        nulls_first: Some(false),
+                    },
+                    with_fill: None,
                 },
-                with_fill: None,
-            },
-        ]),
+            ]
+            .into()
+        ),
         select.order_by.expect("ORDER BY expeccted").kind
     );
     let expected_limit_clause = LimitClause::LimitOffset {
@@ -2648,9 +2837,10 @@ fn parse_select_group_by() {
     assert_eq!(
         GroupByExpr::Expressions(
             vec![
-                Expr::Identifier(Ident::new("lname")),
-                Expr::Identifier(Ident::new("fname")),
-            ],
+                Expr::Identifier(Box::new(Ident::new("lname"))),
+                Expr::Identifier(Box::new(Ident::new("fname"))),
+            ]
+            .into(),
             vec![]
         ),
         select.group_by
@@ -2687,16 +2877,17 @@ fn parse_group_by_special_grouping_sets() {
                 group_by,
                 &GroupByExpr::Expressions(
                     vec![
-                        Expr::Identifier(Ident::new("a")),
-                        Expr::Identifier(Ident::new("b"))
-                    ],
+                        Expr::Identifier(Box::new(Ident::new("a"))),
+                        Expr::Identifier(Box::new(Ident::new("b")))
+                    ]
+                    .into(),
                     vec![GroupByWithModifier::GroupingSets(Expr::GroupingSets(vec![
                         vec![
-                            Expr::Identifier(Ident::new("a")),
-                            Expr::Identifier(Ident::new("b"))
+                            Expr::Identifier(Box::new(Ident::new("a"))),
+                            Expr::Identifier(Box::new(Ident::new("b")))
                         ],
-                        vec![Expr::Identifier(Ident::new("a")),],
-                        vec![Expr::Identifier(Ident::new("b"))],
+                        vec![Expr::Identifier(Box::new(Ident::new("a"))),],
+                        vec![Expr::Identifier(Box::new(Ident::new("b")))],
                         vec![]
                     ]))]
                 )
@@ -2718,17 +2909,18 @@ fn parse_group_by_grouping_sets_single_values() {
                 group_by,
                 &GroupByExpr::Expressions(
                     vec![
-                        Expr::Identifier(Ident::new("a")),
-                        Expr::Identifier(Ident::new("b"))
-                    ],
+                        Expr::Identifier(Box::new(Ident::new("a"))),
+                        Expr::Identifier(Box::new(Ident::new("b")))
+                    ]
+                    .into(),
                     vec![GroupByWithModifier::GroupingSets(Expr::GroupingSets(vec![
                         vec![
-                            Expr::Identifier(Ident::new("a")),
-                            Expr::Identifier(Ident::new("b"))
+                            Expr::Identifier(Box::new(Ident::new("a"))),
+                            Expr::Identifier(Box::new(Ident::new("b")))
                         ],
-                        vec![Expr::Identifier(Ident::new("a"))],
-                        vec![Expr::Identifier(Ident::new("b"))],
-                        vec![Expr::Identifier(Ident::new("c"))],
+                        vec![Expr::Identifier(Box::new(Ident::new("a")))],
+                        vec![Expr::Identifier(Box::new(Ident::new("b")))],
+                        vec![Expr::Identifier(Box::new(Ident::new("c")))],
                         vec![]
                     ]))]
                 )
@@ -2745,13 +2937,13 @@ fn parse_select_having() {
     assert_eq!(
         Some(
             Expr::BinaryOp {
-                left: Box::new(Expr::Function(Function {
+                left: Box::new(Expr::Function(Box::new(Function {
                     name: ObjectName::from(vec![Ident::new("COUNT")]),
                     uses_odbc_syntax: false,
                     parameters: FunctionArguments::None,
                     args: FunctionArguments::List(FunctionArgumentList {
                         duplicate_treatment: None,
-                        args: vec![FunctionArg::Unnamed(FunctionArgExpr::Wildcard)],
+                        args: vec![FunctionArg::Unnamed(FunctionArgExpr::Wildcard)].into(),
                         clauses: vec![],
                     }),
                     null_treatment: None,
@@ -2759,7 +2951,7 @@ fn parse_select_having() {
                     filter: None,
                     over: None,
                     within_group: vec![]
-                })),
+                }))),
                 op: BinaryOperator::Gt,
                 right: Box::new(Expr::value(number("1"))),
             }
@@ -2796,8 +2988,8 @@ fn parse_cast() {
     assert_eq!(
         &Expr::Cast {
             kind: CastKind::Cast,
-            expr: Box::new(Expr::Identifier(Ident::new("id"))),
-            data_type: DataType::BigInt(None),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("id")))),
+            data_type: Box::new(DataType::BigInt(None)),
             format: None,
         },
         expr_from_projection(only(&select.projection))
@@ -2808,8 +3000,8 @@ fn parse_cast() {
     assert_eq!(
         &Expr::Cast {
             kind: CastKind::Cast,
-            expr: Box::new(Expr::Identifier(Ident::new("id"))),
-            data_type: DataType::TinyInt(None),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("id")))),
+            data_type: Box::new(DataType::TinyInt(None)),
             format: None,
         },
         expr_from_projection(only(&select.projection))
@@ -2836,11 +3028,11 @@ fn parse_cast() {
     assert_eq!(
         &Expr::Cast {
             kind: CastKind::Cast,
-            expr: Box::new(Expr::Identifier(Ident::new("id"))),
-            data_type: DataType::Nvarchar(Some(CharacterLength::IntegerLength {
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("id")))),
+            data_type: Box::new(DataType::Nvarchar(Some(CharacterLength::IntegerLength {
                 length: 50,
                 unit: None,
-            })),
+            }))),
             format: None,
         },
         expr_from_projection(only(&select.projection))
@@ -2851,8 +3043,8 @@ fn parse_cast() {
     assert_eq!(
         &Expr::Cast {
             kind: CastKind::Cast,
-            expr: Box::new(Expr::Identifier(Ident::new("id"))),
-            data_type: DataType::Clob(None),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("id")))),
+            data_type: Box::new(DataType::Clob(None)),
             format: None,
         },
         expr_from_projection(only(&select.projection))
@@ -2863,8 +3055,8 @@ fn parse_cast() {
     assert_eq!(
         &Expr::Cast {
             kind: CastKind::Cast,
-            expr: Box::new(Expr::Identifier(Ident::new("id"))),
-            data_type: DataType::Clob(Some(50)),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("id")))),
+            data_type: Box::new(DataType::Clob(Some(50))),
             format: None,
         },
         expr_from_projection(only(&select.projection))
@@ -2875,8 +3067,8 @@ fn parse_cast() {
     assert_eq!(
         &Expr::Cast {
             kind: CastKind::Cast,
-            expr: Box::new(Expr::Identifier(Ident::new("id"))),
-            data_type: DataType::Binary(Some(50)),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("id")))),
+            data_type: Box::new(DataType::Binary(Some(50))),
             format: None,
         },
         expr_from_projection(only(&select.projection))
@@ -2887,8 +3079,10 @@ fn parse_cast() {
     assert_eq!(
         &Expr::Cast {
             kind: CastKind::Cast,
-            expr: Box::new(Expr::Identifier(Ident::new("id"))),
-            data_type: DataType::Varbinary(Some(BinaryLength::IntegerLength { length: 50 })),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("id")))),
+            data_type: Box::new(DataType::Varbinary(Some(BinaryLength::IntegerLength {
+                length: 50
+            }))),
             format: None,
         },
         expr_from_projection(only(&select.projection))
@@ -2899,8 +3093,8 @@ fn parse_cast() {
     assert_eq!(
         &Expr::Cast {
             kind: CastKind::Cast,
-            expr: Box::new(Expr::Identifier(Ident::new("id"))),
-            data_type: DataType::Blob(None),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("id")))),
+            data_type: Box::new(DataType::Blob(None)),
             format: None,
         },
         expr_from_projection(only(&select.projection))
@@ -2911,8 +3105,8 @@ fn parse_cast() {
     assert_eq!(
         &Expr::Cast {
             kind: CastKind::Cast,
-            expr: Box::new(Expr::Identifier(Ident::new("id"))),
-            data_type: DataType::Blob(Some(50)),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("id")))),
+            data_type: Box::new(DataType::Blob(Some(50))),
             format: None,
         },
         expr_from_projection(only(&select.projection))
@@ -2923,8 +3117,8 @@ fn parse_cast() {
     assert_eq!(
         &Expr::Cast {
             kind: CastKind::Cast,
-            expr: Box::new(Expr::Identifier(Ident::new("details"))),
-            data_type: DataType::JSONB,
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("details")))),
+            data_type: Box::new(DataType::JSONB),
             format: None,
         },
         expr_from_projection(only(&select.projection))
@@ -2938,8 +3132,8 @@ fn parse_try_cast() {
     assert_eq!(
         &Expr::Cast {
             kind: CastKind::TryCast,
-            expr: Box::new(Expr::Identifier(Ident::new("id"))),
-            data_type: DataType::BigInt(None),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("id")))),
+            data_type: Box::new(DataType::BigInt(None)),
             format: None,
         },
         expr_from_projection(only(&select.projection))
@@ -2961,7 +3155,7 @@ fn parse_extract() {
         &Expr::Extract {
             field: DateTimeField::Year,
             syntax: ExtractSyntax::From,
-            expr: Box::new(Expr::Identifier(Ident::new("d"))),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("d")))),
         },
         expr_from_projection(only(&select.projection)),
     );
@@ -3044,8 +3238,11 @@ fn parse_ceil_scale() {
     #[cfg(feature = "bigdecimal")]
     assert_eq!(
         &Expr::Ceil {
-            expr: Box::new(Expr::Identifier(Ident::new("d"))),
-            field: CeilFloorKind::Scale(Value::Number(bigdecimal::BigDecimal::from(2), false)),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("d")))),
+            field: CeilFloorKind::Scale(Value::Number(
+                Box::new(bigdecimal::BigDecimal::from(2)),
+                false
+            )),
         },
         expr_from_projection(only(&select.projection)),
     );
@@ -3053,8 +3250,8 @@ fn parse_ceil_scale() {
     #[cfg(not(feature = "bigdecimal"))]
     assert_eq!(
         &Expr::Ceil {
-            expr: Box::new(Expr::Identifier(Ident::new("d"))),
-            field: CeilFloorKind::Scale(Value::Number(2.to_string(), false)),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("d")))),
+            field: CeilFloorKind::Scale(Value::Number("2".into(), false)),
         },
         expr_from_projection(only(&select.projection)),
     );
@@ -3068,8 +3265,11 @@ fn parse_floor_scale() {
     #[cfg(feature = "bigdecimal")]
     assert_eq!(
         &Expr::Floor {
-            expr: Box::new(Expr::Identifier(Ident::new("d"))),
-            field: CeilFloorKind::Scale(Value::Number(bigdecimal::BigDecimal::from(2), false)),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("d")))),
+            field: CeilFloorKind::Scale(Value::Number(
+                Box::new(bigdecimal::BigDecimal::from(2)),
+                false
+            )),
         },
         expr_from_projection(only(&select.projection)),
     );
@@ -3077,8 +3277,8 @@ fn parse_floor_scale() {
     #[cfg(not(feature = "bigdecimal"))]
     assert_eq!(
         &Expr::Floor {
-            expr: Box::new(Expr::Identifier(Ident::new("d"))),
-            field: CeilFloorKind::Scale(Value::Number(2.to_string(), false)),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("d")))),
+            field: CeilFloorKind::Scale(Value::Number("2".into(), false)),
         },
         expr_from_projection(only(&select.projection)),
     );
@@ -3090,7 +3290,7 @@ fn parse_ceil_datetime() {
     let select = verified_only_select(sql);
     assert_eq!(
         &Expr::Ceil {
-            expr: Box::new(Expr::Identifier(Ident::new("d"))),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("d")))),
             field: CeilFloorKind::DateTimeField(DateTimeField::Day),
         },
         expr_from_projection(only(&select.projection)),
@@ -3117,7 +3317,7 @@ fn parse_floor_datetime() {
     let select = verified_only_select(sql);
     assert_eq!(
         &Expr::Floor {
-            expr: Box::new(Expr::Identifier(Ident::new("d"))),
+            expr: Box::new(Expr::Identifier(Box::new(Ident::new("d")))),
             field: CeilFloorKind::DateTimeField(DateTimeField::Day),
         },
         expr_from_projection(only(&select.projection)),
@@ -3146,25 +3346,26 @@ fn parse_listagg() {
     ));
 
     assert_eq!(
-        &Expr::Function(Function {
+        &Expr::Function(Box::new(Function {
             name: ObjectName::from(vec![Ident::new("LISTAGG")]),
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
             args: FunctionArguments::List(FunctionArgumentList {
                 duplicate_treatment: Some(DuplicateTreatment::Distinct),
                 args: vec![
-                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Identifier(Ident::new(
-                        "dateid"
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Identifier(Box::new(
+                        Ident::new("dateid")
                     )))),
-                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
-                        (Value::SingleQuotedString(", ".to_owned())).with_empty_span()
-                    )))
-                ],
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(Box::new(
+                        (Value::SingleQuotedString(", ".into())).with_empty_span()
+                    ))))
+                ]
+                .into(),
                 clauses: vec![FunctionArgumentClause::OnOverflow(
                     ListAggOnOverflow::Truncate {
-                        filler: Some(Box::new(Expr::Value(
-                            (Value::SingleQuotedString("%".to_string(),)).with_empty_span()
-                        ))),
+                        filler: Some(Box::new(Expr::Value(Box::new(
+                            (Value::SingleQuotedString("%".into(),)).with_empty_span()
+                        )))),
                         with_count: false,
                     }
                 )],
@@ -3175,11 +3376,11 @@ fn parse_listagg() {
             over: None,
             within_group: vec![
                 OrderByExpr {
-                    expr: Expr::Identifier(Ident {
-                        value: "id".to_string(),
+                    expr: Expr::Identifier(Box::new(Ident {
+                        value: "id".into(),
                         quote_style: None,
                         span: Span::empty(),
-                    }),
+                    })),
                     options: OrderByOptions {
                         asc: None,
                         nulls_first: None,
@@ -3187,11 +3388,11 @@ fn parse_listagg() {
                     with_fill: None,
                 },
                 OrderByExpr {
-                    expr: Expr::Identifier(Ident {
-                        value: "username".to_string(),
+                    expr: Expr::Identifier(Box::new(Ident {
+                        value: "username".into(),
                         quote_style: None,
                         span: Span::empty(),
-                    }),
+                    })),
                     options: OrderByOptions {
                         asc: None,
                         nulls_first: None,
@@ -3199,7 +3400,7 @@ fn parse_listagg() {
                     with_fill: None,
                 },
             ]
-        }),
+        })),
         expr_from_projection(only(&select.projection))
     );
 
@@ -3343,14 +3544,19 @@ fn gen_number_case(value: &str) -> (Vec<String>, Vec<SelectItem>) {
         format!("{} AS col_alias", value),
     ];
     let expected = vec![
-        SelectItem::UnnamedExpr(Expr::value(number(value))),
+        SelectItem::UnnamedExpr {
+            expr: Expr::value(number(value)),
+            comments: Comments::default(),
+        },
         SelectItem::ExprWithAlias {
             expr: Expr::value(number(value)),
             alias: Ident::new("col_alias"),
+            comments: Comments::default(),
         },
         SelectItem::ExprWithAlias {
             expr: Expr::value(number(value)),
             alias: Ident::new("col_alias"),
+            comments: Comments::default(),
         },
     ];
     (input, expected)
@@ -3368,16 +3574,20 @@ fn gen_sign_number_case(value: &str, op: UnaryOperator) -> (Vec<String>, Vec<Sel
         format!("{}{} AS col_alias", op, value),
     ];
     let expected = vec![
-        SelectItem::UnnamedExpr(Expr::UnaryOp {
-            op,
-            expr: Box::new(Expr::value(number(value))),
-        }),
+        SelectItem::UnnamedExpr {
+            expr: Expr::UnaryOp {
+                op,
+                expr: Box::new(Expr::value(number(value))),
+            },
+            comments: Comments::default(),
+        },
         SelectItem::ExprWithAlias {
             expr: Expr::UnaryOp {
                 op,
                 expr: Box::new(Expr::value(number(value))),
             },
             alias: Ident::new("col_alias"),
+            comments: Comments::default(),
         },
         SelectItem::ExprWithAlias {
             expr: Expr::UnaryOp {
@@ -3385,6 +3595,7 @@ fn gen_sign_number_case(value: &str, op: UnaryOperator) -> (Vec<String>, Vec<Sel
                 expr: Box::new(Expr::value(number(value))),
             },
             alias: Ident::new("col_alias"),
+            comments: Comments::default(),
         },
     ];
     (input, expected)
@@ -3446,17 +3657,21 @@ fn parse_create_table() {
          FOREIGN KEY (lng) REFERENCES othertable4(longitude) ON UPDATE SET NULL)",
     );
     match ast {
-        Statement::CreateTable(CreateTable {
-            name,
-            columns,
-            constraints,
-            table_options,
-            if_not_exists: false,
-            external: false,
+        Statement::CreateTable(create_table) => {
+            let CreateTable {
+                name,
+                columns,
+                constraints,
+                table_options,
+                if_not_exists: false,
+                external: false,
 
-            location: None,
-            ..
-        }) => {
+                location: None,
+                ..
+            } = Box::into_owned(create_table)
+            else {
+                unreachable!()
+            };
             assert_eq!("uk_cities", name.to_string());
             assert_eq!(
                 columns,
@@ -3471,6 +3686,7 @@ fn parse_create_table() {
                             name: None,
                             option: ColumnOption::NotNull,
                         }],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "lat".into(),
@@ -3479,11 +3695,13 @@ fn parse_create_table() {
                             name: None,
                             option: ColumnOption::Null,
                         }],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "lng".into(),
                         data_type: DataType::Double(ExactNumberInfo::None),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "constrained".into(),
@@ -3531,6 +3749,7 @@ fn parse_create_table() {
                                 }),
                             },
                         ],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "ref".into(),
@@ -3549,6 +3768,7 @@ fn parse_create_table() {
                                 characteristics: None,
                             }),
                         }],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "ref2".into(),
@@ -3567,6 +3787,7 @@ fn parse_create_table() {
                                 characteristics: None,
                             }),
                         },],
+                        comments: Comments::default(),
                     },
                 ]
             );
@@ -3664,17 +3885,21 @@ fn parse_create_table_with_constraint_characteristics() {
          FOREIGN KEY (lng) REFERENCES othertable4(longitude) ON UPDATE SET NULL NOT DEFERRABLE INITIALLY IMMEDIATE ENFORCED)",
     );
     match ast {
-        Statement::CreateTable(CreateTable {
-            name,
-            columns,
-            constraints,
-            table_options,
-            if_not_exists: false,
-            external: false,
+        Statement::CreateTable(create_table) => {
+            let CreateTable {
+                name,
+                columns,
+                constraints,
+                table_options,
+                if_not_exists: false,
+                external: false,
 
-            location: None,
-            ..
-        }) => {
+                location: None,
+                ..
+            } = Box::into_owned(create_table)
+            else {
+                unreachable!()
+            };
             assert_eq!("uk_cities", name.to_string());
             assert_eq!(
                 columns,
@@ -3689,6 +3914,7 @@ fn parse_create_table_with_constraint_characteristics() {
                             name: None,
                             option: ColumnOption::NotNull,
                         }],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "lat".into(),
@@ -3697,11 +3923,13 @@ fn parse_create_table_with_constraint_characteristics() {
                             name: None,
                             option: ColumnOption::Null,
                         }],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "lng".into(),
                         data_type: DataType::Double(ExactNumberInfo::None),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                 ]
             );
@@ -3841,7 +4069,8 @@ fn parse_create_table_column_constraint_characteristics() {
         };
 
         match ast {
-            Statement::CreateTable(CreateTable { columns, .. }) => {
+            Statement::CreateTable(create_table) => {
+                let CreateTable { columns, .. } = Box::into_owned(create_table);
                 assert_eq!(
                     columns,
                     vec![ColumnDef {
@@ -3859,7 +4088,8 @@ fn parse_create_table_column_constraint_characteristics() {
                                 characteristics: expected_value,
                                 nulls_distinct: NullsDistinctOption::None,
                             })
-                        }]
+                        }],
+                        comments: Comments::default(),
                     }],
                     "{message}"
                 )
@@ -3943,12 +4173,13 @@ fn parse_create_table_hive_array() {
     };
 
     match dialects.one_statement_parses_to(sql.as_str(), sql.as_str()) {
-        Statement::CreateTable(CreateTable {
-            if_not_exists,
-            name,
-            columns,
-            ..
-        }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable {
+                if_not_exists,
+                name,
+                columns,
+                ..
+            } = Box::into_owned(create_table);
             assert!(if_not_exists);
             assert_eq!(name, ObjectName::from(vec!["something".into()]));
             assert_eq!(
@@ -3958,11 +4189,13 @@ fn parse_create_table_hive_array() {
                         name: Ident::new("name"),
                         data_type: DataType::Int(None),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("val"),
                         data_type: DataType::Array(expected),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                 ],
             )
@@ -3993,7 +4226,8 @@ fn parse_array_type_postfix_syntax() {
     let sql = "CREATE TABLE t (arr INTEGER ARRAY)";
     let expected = "CREATE TABLE t (arr INTEGER[])";
     match dialects.one_statement_parses_to(sql, expected) {
-        Statement::CreateTable(CreateTable { columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { columns, .. } = Box::into_owned(create_table);
             assert_eq!(columns.len(), 1);
             assert_eq!(
                 columns[0].data_type,
@@ -4010,7 +4244,8 @@ fn parse_array_type_postfix_syntax() {
     let sql = "CREATE TABLE t (arr INTEGER ARRAY[10])";
     let expected = "CREATE TABLE t (arr INTEGER[10])";
     match dialects.one_statement_parses_to(sql, expected) {
-        Statement::CreateTable(CreateTable { columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { columns, .. } = Box::into_owned(create_table);
             assert_eq!(
                 columns[0].data_type,
                 DataType::Array(ArrayElemTypeDef::SquareBracket(
@@ -4026,7 +4261,8 @@ fn parse_array_type_postfix_syntax() {
     let sql = "CREATE TABLE t (arr INTEGER ARRAY ARRAY)";
     let expected = "CREATE TABLE t (arr INTEGER[][])";
     match dialects.one_statement_parses_to(sql, expected) {
-        Statement::CreateTable(CreateTable { columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { columns, .. } = Box::into_owned(create_table);
             assert_eq!(
                 columns[0].data_type,
                 DataType::Array(ArrayElemTypeDef::SquareBracket(
@@ -4045,7 +4281,8 @@ fn parse_array_type_postfix_syntax() {
     let sql = "CREATE TABLE t (arr VARCHAR(100) ARRAY)";
     let expected = "CREATE TABLE t (arr VARCHAR(100)[])";
     match dialects.one_statement_parses_to(sql, expected) {
-        Statement::CreateTable(CreateTable { columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { columns, .. } = Box::into_owned(create_table);
             assert_eq!(
                 columns[0].data_type,
                 DataType::Array(ArrayElemTypeDef::SquareBracket(
@@ -4115,11 +4352,11 @@ fn parse_assert_message() {
             message: Some(message),
             ..
         } => {
-            match message {
-                Expr::Value(ValueWithSpan {
-                    value: Value::SingleQuotedString(s),
-                    span: _,
-                }) => assert_eq!(s, "No rows in my_table"),
+            match Box::into_owned(message) {
+                Expr::Value(value) => match Box::into_owned(value).value {
+                    Value::SingleQuotedString(s) => assert_eq!(s, "No rows in my_table"),
+                    _ => unreachable!(),
+                },
                 _ => unreachable!(),
             };
         }
@@ -4187,7 +4424,8 @@ fn parse_create_table_as() {
     let sql = "CREATE TABLE t AS SELECT * FROM a";
 
     match verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { name, query, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { name, query, .. } = Box::into_owned(create_table);
             assert_eq!(name.to_string(), "t".to_string());
             assert_eq!(query, Some(Box::new(verified_query("SELECT * FROM a"))));
         }
@@ -4212,12 +4450,14 @@ fn parse_create_table_as_table() {
         order_by: None,
         limit_clause: None,
         fetch: None,
-        locks: vec![],
+        locks: AstVec::new(),
         for_clause: None,
+        comments: Comments::default(),
     });
 
     match verified_stmt(sql1) {
-        Statement::CreateTable(CreateTable { query, name, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { query, name, .. } = Box::into_owned(create_table);
             assert_eq!(name, ObjectName::from(vec![Ident::new("new_table")]));
             assert_eq!(query.unwrap(), expected_query1);
         }
@@ -4235,12 +4475,14 @@ fn parse_create_table_as_table() {
         order_by: None,
         limit_clause: None,
         fetch: None,
-        locks: vec![],
+        locks: AstVec::new(),
         for_clause: None,
+        comments: Comments::default(),
     });
 
     match verified_stmt(sql2) {
-        Statement::CreateTable(CreateTable { query, name, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { query, name, .. } = Box::into_owned(create_table);
             assert_eq!(name, ObjectName::from(vec![Ident::new("new_table")]));
             assert_eq!(query.unwrap(), expected_query2);
         }
@@ -4253,9 +4495,10 @@ fn parse_create_or_replace_table() {
     let sql = "CREATE OR REPLACE TABLE t (a INT)";
 
     match verified_stmt(sql) {
-        Statement::CreateTable(CreateTable {
-            name, or_replace, ..
-        }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable {
+                name, or_replace, ..
+            } = Box::into_owned(create_table);
             assert_eq!(name.to_string(), "t".to_string());
             assert!(or_replace);
         }
@@ -4283,7 +4526,8 @@ fn parse_create_table_with_options() {
 
     let sql = "CREATE TABLE t (c INT) WITH (foo = 'bar', a = 123)";
     match generic.verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { table_options, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { table_options, .. } = Box::into_owned(create_table);
             let with_options = match table_options {
                 CreateTableOptions::With(options) => options,
                 _ => unreachable!(),
@@ -4292,9 +4536,9 @@ fn parse_create_table_with_options() {
                 vec![
                     SqlOption::KeyValue {
                         key: "foo".into(),
-                        value: Expr::Value(
+                        value: Expr::Value(Box::new(
                             (Value::SingleQuotedString("bar".into())).with_empty_span()
-                        ),
+                        )),
                     },
                     SqlOption::KeyValue {
                         key: "a".into(),
@@ -4312,7 +4556,8 @@ fn parse_create_table_with_options() {
 fn parse_create_table_clone() {
     let sql = "CREATE OR REPLACE TABLE a CLONE a_tmp";
     match verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { name, clone, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { name, clone, .. } = Box::into_owned(create_table);
             assert_eq!(ObjectName::from(vec![Ident::new("a")]), name);
             assert_eq!(Some(ObjectName::from(vec![(Ident::new("a_tmp"))])), clone)
         }
@@ -4336,16 +4581,17 @@ fn parse_create_external_table() {
          LOCATION '/tmp/example.csv'",
     );
     match ast {
-        Statement::CreateTable(CreateTable {
-            name,
-            columns,
-            constraints,
-            table_options,
-            if_not_exists,
-            external,
-            location,
-            ..
-        }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable {
+                name,
+                columns,
+                constraints,
+                table_options,
+                if_not_exists,
+                external,
+                location,
+                ..
+            } = Box::into_owned(create_table);
             assert_eq!("uk_cities", name.to_string());
             assert_eq!(
                 columns,
@@ -4360,6 +4606,7 @@ fn parse_create_external_table() {
                             name: None,
                             option: ColumnOption::NotNull,
                         }],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "lat".into(),
@@ -4368,11 +4615,13 @@ fn parse_create_external_table() {
                             name: None,
                             option: ColumnOption::Null,
                         }],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "lng".into(),
                         data_type: DataType::Double(ExactNumberInfo::None),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                 ]
             );
@@ -4402,17 +4651,18 @@ fn parse_create_or_replace_external_table() {
          LOCATION '/tmp/example.csv'",
     );
     match ast {
-        Statement::CreateTable(CreateTable {
-            name,
-            columns,
-            constraints,
-            table_options,
-            if_not_exists,
-            external,
-            location,
-            or_replace,
-            ..
-        }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable {
+                name,
+                columns,
+                constraints,
+                table_options,
+                if_not_exists,
+                external,
+                location,
+                or_replace,
+                ..
+            } = Box::into_owned(create_table);
             assert_eq!("uk_cities", name.to_string());
             assert_eq!(
                 columns,
@@ -4426,6 +4676,7 @@ fn parse_create_or_replace_external_table() {
                         name: None,
                         option: ColumnOption::NotNull,
                     }],
+                    comments: Comments::default(),
                 },]
             );
             assert!(constraints.is_empty());
@@ -4456,7 +4707,7 @@ fn parse_create_external_table_lowercase() {
          lng DOUBLE) \
          LOCATION '/tmp/example.csv'",
     );
-    assert_matches!(ast, Statement::CreateTable(CreateTable { .. }));
+    assert_matches!(ast, Statement::CreateTable(ref create_table) if matches!(&**create_table, CreateTable { .. }));
 }
 
 #[test]
@@ -4522,13 +4773,13 @@ fn parse_alter_table() {
                 table_properties,
                 [SqlOption::KeyValue {
                     key: Ident {
-                        value: "classification".to_string(),
+                        value: "classification".into(),
                         quote_style: Some('\''),
                         span: Span::empty(),
                     },
-                    value: Expr::Value(
-                        (Value::SingleQuotedString("parquet".to_string())).with_empty_span()
-                    ),
+                    value: Expr::Value(Box::new(
+                        (Value::SingleQuotedString("parquet".into())).with_empty_span()
+                    )),
                 }],
             );
         }
@@ -4543,19 +4794,19 @@ fn parse_alter_table() {
                 [
                     SqlOption::KeyValue {
                         key: Ident {
-                            value: "autovacuum_vacuum_scale_factor".to_string(),
+                            value: "autovacuum_vacuum_scale_factor".into(),
                             quote_style: None,
                             span: Span::empty(),
                         },
-                        value: Expr::Value(test_utils::number("0.01").with_empty_span()),
+                        value: Expr::Value(Box::new(test_utils::number("0.01").with_empty_span())),
                     },
                     SqlOption::KeyValue {
                         key: Ident {
-                            value: "autovacuum_vacuum_threshold".to_string(),
+                            value: "autovacuum_vacuum_threshold".into(),
                             quote_style: None,
                             span: Span::empty(),
                         },
-                        value: Expr::Value(test_utils::number("500").with_empty_span()),
+                        value: Expr::Value(Box::new(test_utils::number("500").with_empty_span())),
                     }
                 ],
             );
@@ -4676,9 +4927,9 @@ fn parse_alter_view_with_options() {
                 vec![
                     SqlOption::KeyValue {
                         key: "foo".into(),
-                        value: Expr::Value(
+                        value: Expr::Value(Box::new(
                             (Value::SingleQuotedString("bar".into())).with_empty_span()
-                        ),
+                        )),
                     },
                     SqlOption::KeyValue {
                         key: "a".into(),
@@ -4717,7 +4968,7 @@ fn parse_alter_materialized_view_rewrite() {
     match verified_stmt(sql) {
         Statement::AlterMaterializedView { name, operation } => {
             assert_eq!("myschema.mv", name.to_string());
-            assert_eq!(AlterMaterializedViewOperation::EnableRewrite, operation);
+            assert_eq!(AlterMaterializedViewOperation::EnableRewrite, *operation);
         }
         _ => unreachable!(),
     }
@@ -4726,7 +4977,7 @@ fn parse_alter_materialized_view_rewrite() {
     match verified_stmt(sql) {
         Statement::AlterMaterializedView { name, operation } => {
             assert_eq!("mv", name.to_string());
-            assert_eq!(AlterMaterializedViewOperation::DisableRewrite, operation);
+            assert_eq!(AlterMaterializedViewOperation::DisableRewrite, *operation);
         }
         _ => unreachable!(),
     }
@@ -4740,7 +4991,7 @@ fn parse_alter_materialized_view_owner_to() {
             assert_eq!("myschema.mv", name.to_string());
             assert_eq!(
                 AlterMaterializedViewOperation::OwnerTo(Owner::Ident(Ident::new("other_user"))),
-                operation
+                *operation
             );
         }
         _ => unreachable!(),
@@ -4752,7 +5003,7 @@ fn parse_alter_materialized_view_owner_to() {
             assert_eq!("mv", name.to_string());
             assert_eq!(
                 AlterMaterializedViewOperation::OwnerTo(Owner::CurrentUser),
-                operation
+                *operation
             );
         }
         _ => unreachable!(),
@@ -4982,7 +5233,7 @@ fn parse_alter_table_alter_column() {
             assert_eq!(
                 op,
                 AlterColumnOperation::SetDefault {
-                    value: Expr::Value((test_utils::number("0")).with_empty_span())
+                    value: Expr::Value(Box::new((test_utils::number("0")).with_empty_span()))
                 }
             );
         }
@@ -5093,7 +5344,10 @@ fn parse_scalar_function_in_projection() {
         let sql = format!("SELECT {function_name}(id) FROM foo");
         let select = verified_only_select(&sql);
         assert_eq!(
-            &call(function_name, [Expr::Identifier(Ident::new("id"))]),
+            &call(
+                function_name,
+                [Expr::Identifier(Box::new(Ident::new("id")))]
+            ),
             expr_from_projection(only(&select.projection))
         );
     }
@@ -5315,7 +5569,7 @@ fn parse_named_argument_function() {
     let select = dialects.verified_only_select(sql);
 
     assert_eq!(
-        &Expr::Function(Function {
+        &Expr::Function(Box::new(Function {
             name: ObjectName::from(vec![Ident::new("FUN")]),
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
@@ -5324,19 +5578,20 @@ fn parse_named_argument_function() {
                 args: vec![
                     FunctionArg::Named {
                         name: Ident::new("a"),
-                        arg: FunctionArgExpr::Expr(Expr::Value(
-                            (Value::SingleQuotedString("1".to_owned())).with_empty_span()
-                        )),
+                        arg: FunctionArgExpr::Expr(Expr::Value(Box::new(
+                            (Value::SingleQuotedString("1".into())).with_empty_span()
+                        ))),
                         operator: FunctionArgOperator::RightArrow
                     },
                     FunctionArg::Named {
                         name: Ident::new("b"),
-                        arg: FunctionArgExpr::Expr(Expr::Value(
-                            (Value::SingleQuotedString("2".to_owned())).with_empty_span()
-                        )),
+                        arg: FunctionArgExpr::Expr(Expr::Value(Box::new(
+                            (Value::SingleQuotedString("2".into())).with_empty_span()
+                        ))),
                         operator: FunctionArgOperator::RightArrow
                     },
-                ],
+                ]
+                .into(),
                 clauses: vec![],
             }),
             null_treatment: None,
@@ -5344,7 +5599,7 @@ fn parse_named_argument_function() {
             filter: None,
             over: None,
             within_group: vec![]
-        }),
+        })),
         expr_from_projection(only(&select.projection))
     );
 }
@@ -5372,13 +5627,13 @@ fn parse_window_functions() {
     assert_eq!(EXPECTED_PROJ_QTY, select.projection.len());
 
     assert_eq!(
-        &Expr::Function(Function {
+        &Expr::Function(Box::new(Function {
             name: ObjectName::from(vec![Ident::new("row_number")]),
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
             args: FunctionArguments::List(FunctionArgumentList {
                 duplicate_treatment: None,
-                args: vec![],
+                args: AstVec::new(),
                 clauses: vec![],
             }),
             null_treatment: None,
@@ -5386,32 +5641,36 @@ fn parse_window_functions() {
             filter: None,
             over: Some(WindowType::WindowSpec(WindowSpec {
                 window_name: None,
-                partition_by: vec![],
+                partition_by: vec![].into(),
                 order_by: vec![OrderByExpr {
-                    expr: Expr::Identifier(Ident::new("dt")),
+                    expr: Expr::Identifier(Box::new(Ident::new("dt"))),
                     options: OrderByOptions {
                         asc: Some(false),
                         nulls_first: None,
                     },
                     with_fill: None,
-                }],
+                }]
+                .into(),
                 window_frame: None,
             })),
             within_group: vec![],
-        }),
+        })),
         expr_from_projection(&select.projection[0])
     );
 
     for i in 0..EXPECTED_PROJ_QTY {
         assert!(matches!(
             expr_from_projection(&select.projection[i]),
-            Expr::Function(Function {
-                over: Some(WindowType::WindowSpec(WindowSpec {
-                    window_name: None,
+            Expr::Function(function) if matches!(
+                &**function,
+                Function {
+                    over: Some(WindowType::WindowSpec(WindowSpec {
+                        window_name: None,
+                        ..
+                    })),
                     ..
-                })),
-                ..
-            })
+                }
+            )
         ));
     }
 }
@@ -5440,13 +5699,16 @@ fn parse_named_window_functions() {
     for (i, win_name) in EXPECTED_WIN_NAMES.iter().enumerate() {
         assert!(matches!(
             expr_from_projection(&select.projection[i]),
-            Expr::Function(Function {
-                over: Some(WindowType::WindowSpec(WindowSpec {
-                    window_name: Some(Ident { value, .. }),
+            Expr::Function(function) if matches!(
+                &**function,
+                Function {
+                    over: Some(WindowType::WindowSpec(WindowSpec {
+                        window_name: Some(Ident { value, .. }),
+                        ..
+                    })),
                     ..
-                })),
-                ..
-            }) if value == win_name
+                } if value == win_name
+            )
         ));
     }
 
@@ -5538,9 +5800,9 @@ fn test_parse_named_window() {
         top_before_distinct: false,
         projection: vec![
             SelectItem::ExprWithAlias {
-                expr: Expr::Function(Function {
+                expr: Expr::Function(Box::new(Function {
                     name: ObjectName::from(vec![Ident {
-                        value: "MIN".to_string(),
+                        value: "MIN".into(),
                         quote_style: None,
                         span: Span::empty(),
                     }]),
@@ -5549,34 +5811,36 @@ fn test_parse_named_window() {
                     args: FunctionArguments::List(FunctionArgumentList {
                         duplicate_treatment: None,
                         args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
-                            Expr::Identifier(Ident {
-                                value: "c12".to_string(),
+                            Expr::Identifier(Box::new(Ident {
+                                value: "c12".into(),
                                 quote_style: None,
                                 span: Span::empty(),
-                            }),
-                        ))],
+                            })),
+                        ))]
+                        .into(),
                         clauses: vec![],
                     }),
                     null_treatment: None,
                     nth_value_order: None,
                     filter: None,
                     over: Some(WindowType::NamedWindow(Ident {
-                        value: "window1".to_string(),
+                        value: "window1".into(),
                         quote_style: None,
                         span: Span::empty(),
                     })),
                     within_group: vec![],
-                }),
+                })),
                 alias: Ident {
-                    value: "min1".to_string(),
+                    value: "min1".into(),
                     quote_style: None,
                     span: Span::empty(),
                 },
+                comments: Comments::default(),
             },
             SelectItem::ExprWithAlias {
-                expr: Expr::Function(Function {
+                expr: Expr::Function(Box::new(Function {
                     name: ObjectName::from(vec![Ident {
-                        value: "MAX".to_string(),
+                        value: "MAX".into(),
                         quote_style: None,
                         span: Span::empty(),
                     }]),
@@ -5585,83 +5849,89 @@ fn test_parse_named_window() {
                     args: FunctionArguments::List(FunctionArgumentList {
                         duplicate_treatment: None,
                         args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
-                            Expr::Identifier(Ident {
-                                value: "c12".to_string(),
+                            Expr::Identifier(Box::new(Ident {
+                                value: "c12".into(),
                                 quote_style: None,
                                 span: Span::empty(),
-                            }),
-                        ))],
+                            })),
+                        ))]
+                        .into(),
                         clauses: vec![],
                     }),
                     null_treatment: None,
                     nth_value_order: None,
                     filter: None,
                     over: Some(WindowType::NamedWindow(Ident {
-                        value: "window2".to_string(),
+                        value: "window2".into(),
                         quote_style: None,
                         span: Span::empty(),
                     })),
                     within_group: vec![],
-                }),
+                })),
                 alias: Ident {
-                    value: "max1".to_string(),
+                    value: "max1".into(),
                     quote_style: None,
                     span: Span::empty(),
                 },
+                comments: Comments::default(),
             },
-        ],
+        ]
+        .into(),
         into: None,
         from: vec![TableWithJoins {
             relation: table_from_name(ObjectName::from(vec![Ident {
-                value: "aggregate_test_100".to_string(),
+                value: "aggregate_test_100".into(),
                 quote_style: None,
                 span: Span::empty(),
             }])),
-            joins: vec![],
-        }],
+            joins: AstVec::new(),
+        }]
+        .into(),
         selection: None,
-        group_by: GroupByExpr::Expressions(vec![], vec![]),
+        group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
         having: None,
         qualify: None,
         named_window: vec![
             NamedWindowDefinition(
                 Ident {
-                    value: "window1".to_string(),
+                    value: "window1".into(),
                     quote_style: None,
                     span: Span::empty(),
                 },
                 NamedWindowExpr::WindowSpec(WindowSpec {
                     window_name: None,
-                    partition_by: vec![],
+                    partition_by: vec![].into(),
                     order_by: vec![OrderByExpr {
-                        expr: Expr::Identifier(Ident {
-                            value: "C12".to_string(),
+                        expr: Expr::Identifier(Box::new(Ident {
+                            value: "C12".into(),
                             quote_style: None,
                             span: Span::empty(),
-                        }),
+                        })),
                         options: OrderByOptions {
                             asc: None,
                             nulls_first: None,
                         },
                         with_fill: None,
-                    }],
+                    }]
+                    .into(),
                     window_frame: None,
                 }),
             ),
             NamedWindowDefinition(
                 Ident {
-                    value: "window2".to_string(),
+                    value: "window2".into(),
                     quote_style: None,
                     span: Span::empty(),
                 },
                 NamedWindowExpr::WindowSpec(WindowSpec {
                     window_name: None,
-                    partition_by: vec![Expr::Identifier(Ident {
-                        value: "C11".to_string(),
+                    partition_by: vec![Expr::Identifier(Box::new(Ident {
+                        value: "C11".into(),
                         quote_style: None,
                         span: Span::empty(),
-                    })],
-                    order_by: vec![],
+                    }))]
+                    .into(),
+                    order_by: vec![].into(),
                     window_frame: None,
                 }),
             ),
@@ -5733,17 +6003,21 @@ fn parse_literal_string() {
     let select = verified_only_select(sql);
     assert_eq!(3, select.projection.len());
     assert_eq!(
-        &Expr::Value((Value::SingleQuotedString("one".to_string())).with_empty_span()),
+        &Expr::Value(Box::new(
+            (Value::SingleQuotedString("one".into())).with_empty_span()
+        )),
         expr_from_projection(&select.projection[0])
     );
     assert_eq!(
-        &Expr::Value(
+        &Expr::Value(Box::new(
             (Value::NationalStringLiteral("national string".to_string())).with_empty_span()
-        ),
+        )),
         expr_from_projection(&select.projection[1])
     );
     assert_eq!(
-        &Expr::Value((Value::HexStringLiteral("deadBEEF".to_string())).with_empty_span()),
+        &Expr::Value(Box::new(
+            (Value::HexStringLiteral("deadBEEF".to_string())).with_empty_span()
+        )),
         expr_from_projection(&select.projection[2])
     );
 
@@ -5756,14 +6030,14 @@ fn parse_literal_date() {
     let sql = "SELECT DATE '1999-01-01'";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::TypedString(TypedString {
+        &Expr::TypedString(Box::new(TypedString {
             data_type: DataType::Date,
             value: ValueWithSpan {
                 value: Value::SingleQuotedString("1999-01-01".into()),
                 span: Span::empty(),
             },
             uses_odbc_syntax: false
-        }),
+        })),
         expr_from_projection(only(&select.projection)),
     );
 }
@@ -5773,14 +6047,14 @@ fn parse_literal_time() {
     let sql = "SELECT TIME '01:23:34'";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::TypedString(TypedString {
+        &Expr::TypedString(Box::new(TypedString {
             data_type: DataType::Time(None, TimezoneInfo::None),
             value: ValueWithSpan {
                 value: Value::SingleQuotedString("01:23:34".into()),
                 span: Span::empty(),
             },
             uses_odbc_syntax: false
-        }),
+        })),
         expr_from_projection(only(&select.projection)),
     );
 }
@@ -5790,14 +6064,14 @@ fn parse_literal_datetime() {
     let sql = "SELECT DATETIME '1999-01-01 01:23:34.45'";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::TypedString(TypedString {
+        &Expr::TypedString(Box::new(TypedString {
             data_type: DataType::Datetime(None),
             value: ValueWithSpan {
                 value: Value::SingleQuotedString("1999-01-01 01:23:34.45".into()),
                 span: Span::empty(),
             },
             uses_odbc_syntax: false
-        }),
+        })),
         expr_from_projection(only(&select.projection)),
     );
 }
@@ -5807,14 +6081,14 @@ fn parse_literal_timestamp_without_time_zone() {
     let sql = "SELECT TIMESTAMP '1999-01-01 01:23:34'";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::TypedString(TypedString {
+        &Expr::TypedString(Box::new(TypedString {
             data_type: DataType::Timestamp(None, TimezoneInfo::None),
             value: ValueWithSpan {
                 value: Value::SingleQuotedString("1999-01-01 01:23:34".into()),
                 span: Span::empty(),
             },
             uses_odbc_syntax: false
-        }),
+        })),
         expr_from_projection(only(&select.projection)),
     );
 
@@ -5826,14 +6100,14 @@ fn parse_literal_timestamp_with_time_zone() {
     let sql = "SELECT TIMESTAMPTZ '1999-01-01 01:23:34Z'";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::TypedString(TypedString {
+        &Expr::TypedString(Box::new(TypedString {
             data_type: DataType::Timestamp(None, TimezoneInfo::Tz),
             value: ValueWithSpan {
                 value: Value::SingleQuotedString("1999-01-01 01:23:34Z".into()),
                 span: Span::empty(),
             },
             uses_odbc_syntax: false
-        }),
+        })),
         expr_from_projection(only(&select.projection)),
     );
 
@@ -5847,101 +6121,101 @@ fn parse_interval_all() {
     let sql = "SELECT INTERVAL '1-1' YEAR TO MONTH";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::Interval(Interval {
-            value: Box::new(Expr::Value(
-                (Value::SingleQuotedString(String::from("1-1"))).with_empty_span()
-            )),
+        &Expr::Interval(Box::new(Interval {
+            value: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("1-1".into())).with_empty_span()
+            ))),
             leading_field: Some(DateTimeField::Year),
             leading_precision: None,
             last_field: Some(DateTimeField::Month),
             fractional_seconds_precision: None,
-        }),
+        })),
         expr_from_projection(only(&select.projection)),
     );
 
     let sql = "SELECT INTERVAL '01:01.01' MINUTE (5) TO SECOND (5)";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::Interval(Interval {
-            value: Box::new(Expr::Value(
-                (Value::SingleQuotedString(String::from("01:01.01"))).with_empty_span()
-            )),
+        &Expr::Interval(Box::new(Interval {
+            value: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("01:01.01".into())).with_empty_span()
+            ))),
             leading_field: Some(DateTimeField::Minute),
             leading_precision: Some(5),
             last_field: Some(DateTimeField::Second),
             fractional_seconds_precision: Some(5),
-        }),
+        })),
         expr_from_projection(only(&select.projection)),
     );
 
     let sql = "SELECT INTERVAL '1' SECOND (5, 4)";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::Interval(Interval {
-            value: Box::new(Expr::Value(
-                (Value::SingleQuotedString(String::from("1"))).with_empty_span()
-            )),
+        &Expr::Interval(Box::new(Interval {
+            value: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("1".into())).with_empty_span()
+            ))),
             leading_field: Some(DateTimeField::Second),
             leading_precision: Some(5),
             last_field: None,
             fractional_seconds_precision: Some(4),
-        }),
+        })),
         expr_from_projection(only(&select.projection)),
     );
 
     let sql = "SELECT INTERVAL '10' HOUR";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::Interval(Interval {
-            value: Box::new(Expr::Value(
-                (Value::SingleQuotedString(String::from("10"))).with_empty_span()
-            )),
+        &Expr::Interval(Box::new(Interval {
+            value: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("10".into())).with_empty_span()
+            ))),
             leading_field: Some(DateTimeField::Hour),
             leading_precision: None,
             last_field: None,
             fractional_seconds_precision: None,
-        }),
+        })),
         expr_from_projection(only(&select.projection)),
     );
 
     let sql = "SELECT INTERVAL 5 DAY";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::Interval(Interval {
+        &Expr::Interval(Box::new(Interval {
             value: Box::new(Expr::value(number("5"))),
             leading_field: Some(DateTimeField::Day),
             leading_precision: None,
             last_field: None,
             fractional_seconds_precision: None,
-        }),
+        })),
         expr_from_projection(only(&select.projection)),
     );
 
     let sql = "SELECT INTERVAL 5 DAYS";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::Interval(Interval {
+        &Expr::Interval(Box::new(Interval {
             value: Box::new(Expr::value(number("5"))),
             leading_field: Some(DateTimeField::Days),
             leading_precision: None,
             last_field: None,
             fractional_seconds_precision: None,
-        }),
+        })),
         expr_from_projection(only(&select.projection)),
     );
 
     let sql = "SELECT INTERVAL '10' HOUR (1)";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::Interval(Interval {
-            value: Box::new(Expr::Value(
-                (Value::SingleQuotedString(String::from("10"))).with_empty_span()
-            )),
+        &Expr::Interval(Box::new(Interval {
+            value: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("10".into())).with_empty_span()
+            ))),
             leading_field: Some(DateTimeField::Hour),
             leading_precision: Some(1),
             last_field: None,
             fractional_seconds_precision: None,
-        }),
+        })),
         expr_from_projection(only(&select.projection)),
     );
 
@@ -6004,15 +6278,15 @@ fn parse_interval_dont_require_unit() {
     let sql = "SELECT INTERVAL '1 DAY'";
     let select = dialects.verified_only_select(sql);
     assert_eq!(
-        &Expr::Interval(Interval {
-            value: Box::new(Expr::Value(
-                (Value::SingleQuotedString(String::from("1 DAY"))).with_empty_span()
-            )),
+        &Expr::Interval(Box::new(Interval {
+            value: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("1 DAY".into())).with_empty_span()
+            ))),
             leading_field: None,
             leading_precision: None,
             last_field: None,
             fractional_seconds_precision: None,
-        }),
+        })),
         expr_from_projection(only(&select.projection)),
     );
     dialects.verified_only_select("SELECT INTERVAL '1 YEAR'");
@@ -6042,7 +6316,7 @@ fn parse_interval_require_qualifier() {
     let select = dialects.verified_only_select(sql);
     assert_eq!(
         expr_from_projection(only(&select.projection)),
-        &Expr::Interval(Interval {
+        &Expr::Interval(Box::new(Interval {
             value: Box::new(Expr::BinaryOp {
                 left: Box::new(Expr::value(number("1"))),
                 op: BinaryOperator::Plus,
@@ -6052,55 +6326,55 @@ fn parse_interval_require_qualifier() {
             leading_precision: None,
             last_field: None,
             fractional_seconds_precision: None,
-        }),
+        })),
     );
 
     let sql = "SELECT INTERVAL '1' + '1' DAY";
     let select = dialects.verified_only_select(sql);
     assert_eq!(
         expr_from_projection(only(&select.projection)),
-        &Expr::Interval(Interval {
+        &Expr::Interval(Box::new(Interval {
             value: Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Value(
-                    (Value::SingleQuotedString("1".to_string())).with_empty_span()
-                )),
+                left: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("1".into())).with_empty_span()
+                ))),
                 op: BinaryOperator::Plus,
-                right: Box::new(Expr::Value(
-                    (Value::SingleQuotedString("1".to_string())).with_empty_span()
-                )),
+                right: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("1".into())).with_empty_span()
+                ))),
             }),
             leading_field: Some(DateTimeField::Day),
             leading_precision: None,
             last_field: None,
             fractional_seconds_precision: None,
-        }),
+        })),
     );
 
     let sql = "SELECT INTERVAL '1' + '2' - '3' DAY";
     let select = dialects.verified_only_select(sql);
     assert_eq!(
         expr_from_projection(only(&select.projection)),
-        &Expr::Interval(Interval {
+        &Expr::Interval(Box::new(Interval {
             value: Box::new(Expr::BinaryOp {
                 left: Box::new(Expr::BinaryOp {
-                    left: Box::new(Expr::Value(
-                        (Value::SingleQuotedString("1".to_string())).with_empty_span()
-                    )),
+                    left: Box::new(Expr::Value(Box::new(
+                        (Value::SingleQuotedString("1".into())).with_empty_span()
+                    ))),
                     op: BinaryOperator::Plus,
-                    right: Box::new(Expr::Value(
-                        (Value::SingleQuotedString("2".to_string())).with_empty_span()
-                    )),
+                    right: Box::new(Expr::Value(Box::new(
+                        (Value::SingleQuotedString("2".into())).with_empty_span()
+                    ))),
                 }),
                 op: BinaryOperator::Minus,
-                right: Box::new(Expr::Value(
-                    (Value::SingleQuotedString("3".to_string())).with_empty_span()
-                )),
+                right: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("3".into())).with_empty_span()
+                ))),
             }),
             leading_field: Some(DateTimeField::Day),
             leading_precision: None,
             last_field: None,
             fractional_seconds_precision: None,
-        }),
+        })),
     );
 }
 
@@ -6112,15 +6386,15 @@ fn parse_interval_disallow_interval_expr() {
     let select = dialects.verified_only_select(sql);
     assert_eq!(
         expr_from_projection(only(&select.projection)),
-        &Expr::Interval(Interval {
-            value: Box::new(Expr::Value(
-                (Value::SingleQuotedString(String::from("1 DAY"))).with_empty_span()
-            )),
+        &Expr::Interval(Box::new(Interval {
+            value: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("1 DAY".into())).with_empty_span()
+            ))),
             leading_field: None,
             leading_precision: None,
             last_field: None,
             fractional_seconds_precision: None,
-        }),
+        })),
     );
 
     dialects.verified_only_select("SELECT INTERVAL '1 YEAR'");
@@ -6135,25 +6409,25 @@ fn parse_interval_disallow_interval_expr() {
     assert_eq!(
         expr_from_projection(only(&select.projection)),
         &Expr::BinaryOp {
-            left: Box::new(Expr::Interval(Interval {
-                value: Box::new(Expr::Value(
-                    (Value::SingleQuotedString(String::from("1 DAY"))).with_empty_span()
-                )),
+            left: Box::new(Expr::Interval(Box::new(Interval {
+                value: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("1 DAY".into())).with_empty_span()
+                ))),
                 leading_field: None,
                 leading_precision: None,
                 last_field: None,
                 fractional_seconds_precision: None,
-            })),
+            }))),
             op: BinaryOperator::Gt,
-            right: Box::new(Expr::Interval(Interval {
-                value: Box::new(Expr::Value(
-                    (Value::SingleQuotedString(String::from("1 SECOND"))).with_empty_span()
-                )),
+            right: Box::new(Expr::Interval(Box::new(Interval {
+                value: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("1 SECOND".into())).with_empty_span()
+                ))),
                 leading_field: None,
                 leading_precision: None,
                 last_field: None,
                 fractional_seconds_precision: None,
-            }))
+            })))
         }
     );
 }
@@ -6165,21 +6439,21 @@ fn interval_disallow_interval_expr_gt() {
     assert_eq!(
         expr,
         Expr::BinaryOp {
-            left: Box::new(Expr::Interval(Interval {
-                value: Box::new(Expr::Value(
-                    (Value::SingleQuotedString("1 second".to_string())).with_empty_span()
-                )),
+            left: Box::new(Expr::Interval(Box::new(Interval {
+                value: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("1 second".into())).with_empty_span()
+                ))),
                 leading_field: None,
                 leading_precision: None,
                 last_field: None,
                 fractional_seconds_precision: None,
-            },)),
+            },))),
             op: BinaryOperator::Gt,
-            right: Box::new(Expr::Identifier(Ident {
-                value: "x".to_string(),
+            right: Box::new(Expr::Identifier(Box::new(Ident {
+                value: "x".into(),
                 quote_style: None,
                 span: Span::empty(),
-            })),
+            }))),
         }
     )
 }
@@ -6192,16 +6466,16 @@ fn interval_disallow_interval_expr_double_colon() {
         expr,
         Expr::Cast {
             kind: CastKind::DoubleColon,
-            expr: Box::new(Expr::Interval(Interval {
-                value: Box::new(Expr::Value(
-                    (Value::SingleQuotedString("1 second".to_string())).with_empty_span()
-                )),
+            expr: Box::new(Expr::Interval(Box::new(Interval {
+                value: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("1 second".into())).with_empty_span()
+                ))),
                 leading_field: None,
                 leading_precision: None,
                 last_field: None,
                 fractional_seconds_precision: None,
-            })),
-            data_type: DataType::Text,
+            }))),
+            data_type: Box::new(DataType::Text),
             format: None,
         }
     )
@@ -6223,79 +6497,82 @@ fn parse_interval_and_or_xor() {
             distinct: None,
             top: None,
             top_before_distinct: false,
-            projection: vec![UnnamedExpr(Expr::Identifier(Ident {
-                value: "col".to_string(),
-                quote_style: None,
-                span: Span::empty(),
-            }))],
+            projection: vec![UnnamedExpr {
+                expr: Expr::Identifier(Box::new(Ident {
+                    value: "col".into(),
+                    quote_style: None,
+                    span: Span::empty(),
+                })),
+                comments: Comments::default(),
+            }]
+            .into(),
             into: None,
             from: vec![TableWithJoins {
                 relation: table_from_name(ObjectName::from(vec![Ident {
-                    value: "test".to_string(),
+                    value: "test".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }])),
-                joins: vec![],
-            }],
+                joins: AstVec::new(),
+            }]
+            .into(),
             selection: Some(
                 Expr::BinaryOp {
                     left: Box::new(Expr::BinaryOp {
-                        left: Box::new(Expr::Identifier(Ident {
-                            value: "d3_date".to_string(),
+                        left: Box::new(Expr::Identifier(Box::new(Ident {
+                            value: "d3_date".into(),
                             quote_style: None,
                             span: Span::empty(),
-                        })),
+                        }))),
                         op: BinaryOperator::Gt,
                         right: Box::new(Expr::BinaryOp {
-                            left: Box::new(Expr::Identifier(Ident {
-                                value: "d1_date".to_string(),
+                            left: Box::new(Expr::Identifier(Box::new(Ident {
+                                value: "d1_date".into(),
                                 quote_style: None,
                                 span: Span::empty(),
-                            })),
+                            }))),
                             op: BinaryOperator::Plus,
-                            right: Box::new(Expr::Interval(Interval {
-                                value: Box::new(Expr::Value(
-                                    (Value::SingleQuotedString("5 days".to_string()))
-                                        .with_empty_span(),
-                                )),
+                            right: Box::new(Expr::Interval(Box::new(Interval {
+                                value: Box::new(Expr::Value(Box::new(
+                                    (Value::SingleQuotedString("5 days".into())).with_empty_span(),
+                                ))),
                                 leading_field: None,
                                 leading_precision: None,
                                 last_field: None,
                                 fractional_seconds_precision: None,
-                            })),
+                            }))),
                         }),
                     }),
                     op: BinaryOperator::And,
                     right: Box::new(Expr::BinaryOp {
-                        left: Box::new(Expr::Identifier(Ident {
-                            value: "d2_date".to_string(),
+                        left: Box::new(Expr::Identifier(Box::new(Ident {
+                            value: "d2_date".into(),
                             quote_style: None,
                             span: Span::empty(),
-                        })),
+                        }))),
                         op: BinaryOperator::Gt,
                         right: Box::new(Expr::BinaryOp {
-                            left: Box::new(Expr::Identifier(Ident {
-                                value: "d1_date".to_string(),
+                            left: Box::new(Expr::Identifier(Box::new(Ident {
+                                value: "d1_date".into(),
                                 quote_style: None,
                                 span: Span::empty(),
-                            })),
+                            }))),
                             op: BinaryOperator::Plus,
-                            right: Box::new(Expr::Interval(Interval {
-                                value: Box::new(Expr::Value(
-                                    (Value::SingleQuotedString("3 days".to_string()))
-                                        .with_empty_span(),
-                                )),
+                            right: Box::new(Expr::Interval(Box::new(Interval {
+                                value: Box::new(Expr::Value(Box::new(
+                                    (Value::SingleQuotedString("3 days".into())).with_empty_span(),
+                                ))),
                                 leading_field: None,
                                 leading_precision: None,
                                 last_field: None,
                                 fractional_seconds_precision: None,
-                            })),
+                            }))),
                         }),
                     }),
                 }
                 .into(),
             ),
-            group_by: GroupByExpr::Expressions(vec![], vec![]),
+            group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
             having: None,
             qualify: None,
             named_window: vec![],
@@ -6305,8 +6582,9 @@ fn parse_interval_and_or_xor() {
         order_by: None,
         limit_clause: None,
         fetch: None,
-        locks: vec![],
+        locks: AstVec::new(),
         for_clause: None,
+        comments: Comments::default(),
     }))];
 
     assert_eq!(actual_ast, expected_ast);
@@ -6338,9 +6616,9 @@ fn parse_at_timezone() {
     assert_eq!(
         &Expr::AtTimeZone {
             timestamp: Box::new(call("FROM_UNIXTIME", [zero.clone()])),
-            time_zone: Box::new(Expr::Value(
-                (Value::SingleQuotedString("UTC-06:00".to_string())).with_empty_span()
-            )),
+            time_zone: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("UTC-06:00".into())).with_empty_span()
+            ))),
         },
         expr_from_projection(only(&select.projection)),
     );
@@ -6354,20 +6632,21 @@ fn parse_at_timezone() {
                 [
                     Expr::AtTimeZone {
                         timestamp: Box::new(call("FROM_UNIXTIME", [zero])),
-                        time_zone: Box::new(Expr::Value(
-                            (Value::SingleQuotedString("UTC-06:00".to_string())).with_empty_span()
-                        )),
+                        time_zone: Box::new(Expr::Value(Box::new(
+                            (Value::SingleQuotedString("UTC-06:00".into())).with_empty_span()
+                        ))),
                     },
-                    Expr::Value(
-                        (Value::SingleQuotedString("%Y-%m-%dT%H".to_string())).with_empty_span()
-                    )
+                    Expr::Value(Box::new(
+                        (Value::SingleQuotedString("%Y-%m-%dT%H".into())).with_empty_span()
+                    ))
                 ]
             ),
             alias: Ident {
-                value: "hour".to_string(),
+                value: "hour".into(),
                 quote_style: Some('"'),
                 span: Span::empty(),
             },
+            comments: Comments::default(),
         },
         only(&select.projection),
     );
@@ -6397,7 +6676,7 @@ fn parse_json_keyword() {
 }'"#;
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::TypedString(TypedString {
+        &Expr::TypedString(Box::new(TypedString {
             data_type: DataType::JSON,
             value: ValueWithSpan {
                 value: Value::SingleQuotedString(
@@ -6421,12 +6700,12 @@ fn parse_json_keyword() {
       ]
     }
 }"#
-                    .to_string()
+                    .into()
                 ),
                 span: Span::empty(),
             },
             uses_odbc_syntax: false,
-        }),
+        })),
         expr_from_projection(only(&select.projection)),
     );
 }
@@ -6435,23 +6714,21 @@ fn parse_json_keyword() {
 fn parse_typed_strings() {
     let expr = verified_expr(r#"JSON '{"foo":"bar"}'"#);
     assert_eq!(
-        Expr::TypedString(TypedString {
+        Expr::TypedString(Box::new(TypedString {
             data_type: DataType::JSON,
             value: ValueWithSpan {
                 value: Value::SingleQuotedString(r#"{"foo":"bar"}"#.into()),
                 span: Span::empty(),
             },
             uses_odbc_syntax: false
-        }),
+        })),
         expr
     );
 
-    if let Expr::TypedString(TypedString {
-        data_type,
-        value,
-        uses_odbc_syntax: false,
-    }) = expr
-    {
+    if let Expr::TypedString(typed_string) = expr {
+        let TypedString {
+            data_type, value, ..
+        } = Box::into_owned(typed_string);
         assert_eq!(DataType::JSON, data_type);
         assert_eq!(r#"{"foo":"bar"}"#, value.into_string().unwrap());
     }
@@ -6478,9 +6755,9 @@ fn parse_table_function() {
             assert_eq!(
                 call(
                     "FUN",
-                    [Expr::Value(
-                        (Value::SingleQuotedString("1".to_owned())).with_empty_span()
-                    )],
+                    [Expr::Value(Box::new(
+                        (Value::SingleQuotedString("1".into())).with_empty_span()
+                    ))],
                 ),
                 expr
             );
@@ -6580,15 +6857,15 @@ fn parse_unnest_in_from_clause() {
             relation: TableFactor::UNNEST {
                 alias: Some(TableAlias {
                     name: Ident::new("numbers"),
-                    columns: vec![],
+                    columns: vec![].into(),
                     implicit: false,
                 }),
-                array_exprs: vec![Expr::Identifier(Ident::new("expr"))],
+                array_exprs: vec![Expr::Identifier(Box::new(Ident::new("expr")))],
                 with_offset: true,
                 with_offset_alias: None,
                 with_ordinality: false,
             },
-            joins: vec![],
+            joins: AstVec::new(),
         }],
     );
     // 2. neither Alias nor WITH OFFSET clause.
@@ -6601,12 +6878,12 @@ fn parse_unnest_in_from_clause() {
         vec![TableWithJoins {
             relation: TableFactor::UNNEST {
                 alias: None,
-                array_exprs: vec![Expr::Identifier(Ident::new("expr"))],
+                array_exprs: vec![Expr::Identifier(Box::new(Ident::new("expr")))],
                 with_offset: false,
                 with_offset_alias: None,
                 with_ordinality: false,
             },
-            joins: vec![],
+            joins: AstVec::new(),
         }],
     );
     // 3. Alias but no WITH OFFSET clause.
@@ -6619,12 +6896,12 @@ fn parse_unnest_in_from_clause() {
         vec![TableWithJoins {
             relation: TableFactor::UNNEST {
                 alias: None,
-                array_exprs: vec![Expr::Identifier(Ident::new("expr"))],
+                array_exprs: vec![Expr::Identifier(Box::new(Ident::new("expr")))],
                 with_offset: true,
                 with_offset_alias: None,
                 with_ordinality: false,
             },
-            joins: vec![],
+            joins: AstVec::new(),
         }],
     );
     // 4. WITH OFFSET but no Alias.
@@ -6638,15 +6915,15 @@ fn parse_unnest_in_from_clause() {
             relation: TableFactor::UNNEST {
                 alias: Some(TableAlias {
                     name: Ident::new("numbers"),
-                    columns: vec![],
+                    columns: vec![].into(),
                     implicit: false,
                 }),
-                array_exprs: vec![Expr::Identifier(Ident::new("expr"))],
+                array_exprs: vec![Expr::Identifier(Box::new(Ident::new("expr")))],
                 with_offset: false,
                 with_offset_alias: None,
                 with_ordinality: false,
             },
-            joins: vec![],
+            joins: AstVec::new(),
         }],
     );
     // 5. Simple array
@@ -6671,7 +6948,7 @@ fn parse_unnest_in_from_clause() {
                 with_offset_alias: None,
                 with_ordinality: false,
             },
-            joins: vec![],
+            joins: AstVec::new(),
         }],
     );
     // 6. Multiple arrays
@@ -6702,7 +6979,7 @@ fn parse_unnest_in_from_clause() {
                 with_offset_alias: None,
                 with_ordinality: false,
             },
-            joins: vec![],
+            joins: AstVec::new(),
         }],
     )
 }
@@ -6715,15 +6992,15 @@ fn parse_parens() {
     assert_eq!(
         BinaryOp {
             left: Box::new(Nested(Box::new(BinaryOp {
-                left: Box::new(Identifier(Ident::new("a"))),
+                left: Box::new(Identifier(Box::new(Ident::new("a")))),
                 op: Plus,
-                right: Box::new(Identifier(Ident::new("b"))),
+                right: Box::new(Identifier(Box::new(Ident::new("b")))),
             }))),
             op: Minus,
             right: Box::new(Nested(Box::new(BinaryOp {
-                left: Box::new(Identifier(Ident::new("c"))),
+                left: Box::new(Identifier(Box::new(Ident::new("c")))),
                 op: Plus,
-                right: Box::new(Identifier(Ident::new("d"))),
+                right: Box::new(Identifier(Box::new(Ident::new("d")))),
             }))),
         },
         verified_expr(sql)
@@ -6737,39 +7014,40 @@ fn parse_searched_case_expr() {
     use self::Expr::{BinaryOp, Case, Identifier};
     let select = verified_only_select(sql);
     assert_eq!(
-        &Case {
+        &Case(Box::new(CaseExpr {
             case_token: AttachedToken::empty(),
             end_token: AttachedToken::empty(),
             operand: None,
             conditions: vec![
                 CaseWhen {
                     condition: Expr::IsNull {
-                        expr: Box::new(Identifier(Ident::new("bar"))),
-                        suffix_token: AttachedToken::empty(),
+                        expr: Box::new(Identifier(Box::new(Ident::new("bar")))),
+                        suffix_token: Box::new(AttachedToken::empty()),
                     },
-                    result: Expr::value(Value::SingleQuotedString("null".to_string())),
+                    result: Expr::value(Value::SingleQuotedString("null".into())),
                 },
                 CaseWhen {
                     condition: BinaryOp {
-                        left: Box::new(Identifier(Ident::new("bar"))),
+                        left: Box::new(Identifier(Box::new(Ident::new("bar")))),
                         op: Eq,
                         right: Box::new(Expr::value(number("0"))),
                     },
-                    result: Expr::value(Value::SingleQuotedString("=0".to_string())),
+                    result: Expr::value(Value::SingleQuotedString("=0".into())),
                 },
                 CaseWhen {
                     condition: BinaryOp {
-                        left: Box::new(Identifier(Ident::new("bar"))),
+                        left: Box::new(Identifier(Box::new(Ident::new("bar")))),
                         op: GtEq,
                         right: Box::new(Expr::value(number("0"))),
                     },
-                    result: Expr::value(Value::SingleQuotedString(">=0".to_string())),
+                    result: Expr::value(Value::SingleQuotedString(">=0".into())),
                 },
-            ],
+            ]
+            .into(),
             else_result: Some(Box::new(Expr::value(Value::SingleQuotedString(
-                "<0".to_string()
+                "<0".into()
             )))),
-        },
+        })),
         expr_from_projection(only(&select.projection)),
     );
 }
@@ -6781,18 +7059,17 @@ fn parse_simple_case_expr() {
     let select = verified_only_select(sql);
     use self::Expr::{Case, Identifier};
     assert_eq!(
-        &Case {
+        &Case(Box::new(CaseExpr {
             case_token: AttachedToken::empty(),
             end_token: AttachedToken::empty(),
-            operand: Some(Box::new(Identifier(Ident::new("foo")))),
+            operand: Some(Box::new(Identifier(Box::new(Ident::new("foo"))))),
             conditions: vec![CaseWhen {
                 condition: Expr::value(number("1")),
-                result: Expr::value(Value::SingleQuotedString("Y".to_string())),
-            }],
-            else_result: Some(Box::new(Expr::value(Value::SingleQuotedString(
-                "N".to_string()
-            )))),
-        },
+                result: Expr::value(Value::SingleQuotedString("Y".into())),
+            }]
+            .into(),
+            else_result: Some(Box::new(Expr::value(Value::SingleQuotedString("N".into())))),
+        })),
         expr_from_projection(only(&select.projection)),
     );
 }
@@ -6817,11 +7094,11 @@ fn parse_implicit_join() {
         vec![
             TableWithJoins {
                 relation: table_from_name(ObjectName::from(vec!["t1".into()])),
-                joins: vec![],
+                joins: AstVec::new(),
             },
             TableWithJoins {
                 relation: table_from_name(ObjectName::from(vec!["t2".into()])),
-                joins: vec![],
+                joins: AstVec::new(),
             },
         ],
         select.from,
@@ -6837,7 +7114,8 @@ fn parse_implicit_join() {
                     relation: table_from_name(ObjectName::from(vec!["t1b".into()])),
                     global: false,
                     join_operator: JoinOperator::Join(JoinConstraint::Natural),
-                }],
+                }]
+                .into(),
             },
             TableWithJoins {
                 relation: table_from_name(ObjectName::from(vec!["t2a".into()])),
@@ -6845,7 +7123,8 @@ fn parse_implicit_join() {
                     relation: table_from_name(ObjectName::from(vec!["t2b".into()])),
                     global: false,
                     join_operator: JoinOperator::Join(JoinConstraint::Natural),
-                }],
+                }]
+                .into(),
             },
         ],
         select.from,
@@ -6889,16 +7168,16 @@ fn parse_cross_join_constraint() {
         "SELECT * FROM t1 CROSS JOIN t2 ON a = b",
         JoinConstraint::On(
             Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
                 op: BinaryOperator::Eq,
-                right: Box::new(Expr::Identifier(Ident::new("b"))),
+                right: Box::new(Expr::Identifier(Box::new(Ident::new("b")))),
             }
             .into(),
         ),
     );
     test_constraint(
         "SELECT * FROM t1 CROSS JOIN t2 USING(a)",
-        JoinConstraint::Using(vec![ObjectName::from(vec![Ident::new("a")])]),
+        JoinConstraint::Using(vec![ObjectName::from(vec![Ident::new("a")])].into()),
     );
 }
 
@@ -6927,9 +7206,9 @@ fn parse_joins_on() {
             global,
             join_operator: f(JoinConstraint::On(
                 Expr::BinaryOp {
-                    left: Box::new(Expr::Identifier("c1".into())),
+                    left: Box::new(Expr::Identifier(Box::new("c1".into()))),
                     op: BinaryOperator::Eq,
-                    right: Box::new(Expr::Identifier("c2".into())),
+                    right: Box::new(Expr::Identifier(Box::new("c2".into()))),
                 }
                 .into(),
             )),
@@ -7071,9 +7350,9 @@ fn parse_joins_using() {
                 index_hints: vec![],
             },
             global: false,
-            join_operator: f(JoinConstraint::Using(vec![ObjectName::from(vec![
-                "c1".into()
-            ])])),
+            join_operator: f(JoinConstraint::Using(
+                vec![ObjectName::from(vec!["c1".into()])].into(),
+            )),
         }
     }
     // Test parsing of aliases
@@ -7270,7 +7549,7 @@ fn parse_join_nesting() {
         TableFactor::NestedJoin {
             table_with_joins: Box::new(TableWithJoins {
                 relation: table("a"),
-                joins: vec![join(table("b"))],
+                joins: vec![join(table("b"))].into(),
             }),
             alias: table_alias("c"),
         }
@@ -7389,11 +7668,11 @@ fn parse_recursive_cte() {
     let expected = Cte {
         alias: TableAlias {
             name: Ident {
-                value: "nums".to_string(),
+                value: "nums".into(),
                 quote_style: None,
                 span: Span::empty(),
             },
-            columns: vec![TableAliasColumnDef::from_name("val")],
+            columns: vec![TableAliasColumnDef::from_name("val")].into(),
             implicit: false,
         },
         query: Box::new(cte_query),
@@ -7472,7 +7751,7 @@ fn parse_derived_tables() {
                     subquery: Box::new(verified_query("(SELECT 1) UNION (SELECT 2)")),
                     alias: Some(TableAlias {
                         name: "t1".into(),
-                        columns: vec![],
+                        columns: vec![].into(),
                         implicit: false,
                     }),
                 },
@@ -7480,7 +7759,8 @@ fn parse_derived_tables() {
                     relation: table_from_name(ObjectName::from(vec!["t2".into()])),
                     global: false,
                     join_operator: JoinOperator::Join(JoinConstraint::Natural),
-                }],
+                }]
+                .into(),
             }),
             alias: None,
         }
@@ -7625,13 +7905,13 @@ fn parse_overlay() {
     let select = verified_only_select(sql);
     assert_eq!(
         &Expr::Overlay {
-            expr: Box::new(Expr::Value(
-                (Value::SingleQuotedString("abcdef".to_string())).with_empty_span()
-            )),
-            overlay_what: Box::new(Expr::Identifier(Ident::new("name"))),
+            expr: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("abcdef".into())).with_empty_span()
+            ))),
+            overlay_what: Box::new(Expr::Identifier(Box::new(Ident::new("name")))),
             overlay_from: Box::new(Expr::value(number("3"))),
             overlay_for: Some(Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("id"))),
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("id")))),
                 op: BinaryOperator::Plus,
                 right: Box::new(Expr::value(number("1"))),
             })),
@@ -7734,14 +8014,15 @@ fn parse_exists_subquery() {
 fn parse_create_database() {
     let sql = "CREATE DATABASE mydb";
     match verified_stmt(sql) {
-        Statement::CreateDatabase {
-            db_name,
-            if_not_exists,
-            location,
-            managed_location,
-            clone,
-            ..
-        } => {
+        Statement::CreateDatabase(create_database) => {
+            let CreateDatabase {
+                db_name,
+                if_not_exists,
+                location,
+                managed_location,
+                clone,
+                ..
+            } = Box::into_owned(create_database);
             assert_eq!("mydb", db_name.to_string());
             assert!(!if_not_exists);
             assert_eq!(None, location);
@@ -7752,14 +8033,15 @@ fn parse_create_database() {
     }
     let sql = "CREATE DATABASE mydb CLONE otherdb";
     match verified_stmt(sql) {
-        Statement::CreateDatabase {
-            db_name,
-            if_not_exists,
-            location,
-            managed_location,
-            clone,
-            ..
-        } => {
+        Statement::CreateDatabase(create_database) => {
+            let CreateDatabase {
+                db_name,
+                if_not_exists,
+                location,
+                managed_location,
+                clone,
+                ..
+            } = Box::into_owned(create_database);
             assert_eq!("mydb", db_name.to_string());
             assert!(!if_not_exists);
             assert_eq!(None, location);
@@ -7777,14 +8059,15 @@ fn parse_create_database() {
 fn parse_create_database_ine() {
     let sql = "CREATE DATABASE IF NOT EXISTS mydb";
     match verified_stmt(sql) {
-        Statement::CreateDatabase {
-            db_name,
-            if_not_exists,
-            location,
-            managed_location,
-            clone,
-            ..
-        } => {
+        Statement::CreateDatabase(create_database) => {
+            let CreateDatabase {
+                db_name,
+                if_not_exists,
+                location,
+                managed_location,
+                clone,
+                ..
+            } = Box::into_owned(create_database);
             assert_eq!("mydb", db_name.to_string());
             assert!(if_not_exists);
             assert_eq!(None, location);
@@ -7799,15 +8082,16 @@ fn parse_create_database_ine() {
 fn parse_create_database_with_owner() {
     let sql = "CREATE DATABASE tpcc OWNER tpcc";
     match verified_stmt(sql) {
-        Statement::CreateDatabase {
-            db_name,
-            if_not_exists,
-            location,
-            managed_location,
-            owner,
-            clone,
-            ..
-        } => {
+        Statement::CreateDatabase(create_database) => {
+            let CreateDatabase {
+                db_name,
+                if_not_exists,
+                location,
+                managed_location,
+                owner,
+                clone,
+                ..
+            } = Box::into_owned(create_database);
             assert_eq!("tpcc", db_name.to_string());
             assert!(!if_not_exists);
             assert_eq!(None, location);
@@ -7820,7 +8104,8 @@ fn parse_create_database_with_owner() {
 
     let sql = "CREATE DATABASE tpcc WITH OWNER = tpcc";
     match one_statement_parses_to(sql, "CREATE DATABASE tpcc OWNER tpcc") {
-        Statement::CreateDatabase { db_name, owner, .. } => {
+        Statement::CreateDatabase(create_database) => {
+            let CreateDatabase { db_name, owner, .. } = Box::into_owned(create_database);
             assert_eq!("tpcc", db_name.to_string());
             assert_eq!(Some(ObjectName::from(vec![Ident::new("tpcc")])), owner);
         }
@@ -7832,11 +8117,12 @@ fn parse_create_database_with_owner() {
 fn parse_create_database_compatibility() {
     let sql = "CREATE DATABASE app COMPATIBILITY 'oracle'";
     match verified_stmt(sql) {
-        Statement::CreateDatabase {
-            db_name,
-            compatibility,
-            ..
-        } => {
+        Statement::CreateDatabase(create_database) => {
+            let CreateDatabase {
+                db_name,
+                compatibility,
+                ..
+            } = Box::into_owned(create_database);
             assert_eq!("app", db_name.to_string());
             assert_eq!(Some("oracle".to_string()), compatibility);
         }
@@ -7845,18 +8131,20 @@ fn parse_create_database_compatibility() {
 
     let sql = "CREATE DATABASE app WITH COMPATIBILITY = 'postgresql'";
     match one_statement_parses_to(sql, "CREATE DATABASE app COMPATIBILITY 'postgresql'") {
-        Statement::CreateDatabase { compatibility, .. } => {
+        Statement::CreateDatabase(create_database) => {
+            let CreateDatabase { compatibility, .. } = Box::into_owned(create_database);
             assert_eq!(Some("postgresql".to_string()), compatibility);
         }
         _ => unreachable!(),
     }
 
     match verified_stmt("CREATE DATABASE app OWNER tpcc COMPATIBILITY 'oracle'") {
-        Statement::CreateDatabase {
-            owner,
-            compatibility,
-            ..
-        } => {
+        Statement::CreateDatabase(create_database) => {
+            let CreateDatabase {
+                owner,
+                compatibility,
+                ..
+            } = Box::into_owned(create_database);
             assert_eq!(Some(ObjectName::from(vec![Ident::new("tpcc")])), owner);
             assert_eq!(Some("oracle".to_string()), compatibility);
         }
@@ -7864,7 +8152,8 @@ fn parse_create_database_compatibility() {
     }
 
     match verified_stmt("CREATE DATABASE plain") {
-        Statement::CreateDatabase { compatibility, .. } => {
+        Statement::CreateDatabase(create_database) => {
+            let CreateDatabase { compatibility, .. } = Box::into_owned(create_database);
             assert_eq!(None, compatibility);
         }
         _ => unreachable!(),
@@ -7912,17 +8201,18 @@ fn parse_drop_database_if_exists() {
 fn parse_create_view() {
     let sql = "CREATE VIEW myschema.myview AS SELECT foo FROM bar";
     match verified_stmt(sql) {
-        Statement::CreateView(CreateView {
-            or_alter,
-            name,
-            columns,
-            query,
-            or_replace,
-            materialized,
-            options,
-            params,
-            ..
-        }) => {
+        Statement::CreateView(create_view) => {
+            let CreateView {
+                or_alter,
+                name,
+                columns,
+                query,
+                or_replace,
+                materialized,
+                options,
+                params,
+                ..
+            } = Box::into_owned(create_view);
             assert_eq!(or_alter, false);
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<ViewColumnDef>::new(), columns);
@@ -7947,9 +8237,9 @@ fn parse_create_view_with_options() {
                 CreateTableOptions::With(vec![
                     SqlOption::KeyValue {
                         key: "foo".into(),
-                        value: Expr::Value(
+                        value: Expr::Value(Box::new(
                             (Value::SingleQuotedString("bar".into())).with_empty_span()
-                        ),
+                        )),
                     },
                     SqlOption::KeyValue {
                         key: "a".into(),
@@ -7995,17 +8285,18 @@ fn parse_create_view_with_columns() {
 fn parse_create_or_replace_view() {
     let sql = "CREATE OR REPLACE VIEW v AS SELECT 1";
     match verified_stmt(sql) {
-        Statement::CreateView(CreateView {
-            or_alter,
-            name,
-            columns,
-            or_replace,
-            options,
-            query,
-            materialized,
-            params,
-            ..
-        }) => {
+        Statement::CreateView(create_view) => {
+            let CreateView {
+                or_alter,
+                name,
+                columns,
+                or_replace,
+                options,
+                query,
+                materialized,
+                params,
+                ..
+            } = Box::into_owned(create_view);
             assert_eq!(or_alter, false);
             assert_eq!("v", name.to_string());
             assert_eq!(columns, vec![]);
@@ -8027,17 +8318,18 @@ fn parse_create_or_replace_materialized_view() {
     // https://docs.snowflake.com/en/sql-reference/sql/create-materialized-view.html
     let sql = "CREATE OR REPLACE MATERIALIZED VIEW v AS SELECT 1";
     match verified_stmt(sql) {
-        Statement::CreateView(CreateView {
-            or_alter,
-            name,
-            columns,
-            or_replace,
-            options,
-            query,
-            materialized,
-            params,
-            ..
-        }) => {
+        Statement::CreateView(create_view) => {
+            let CreateView {
+                or_alter,
+                name,
+                columns,
+                or_replace,
+                options,
+                query,
+                materialized,
+                params,
+                ..
+            } = Box::into_owned(create_view);
             assert_eq!(or_alter, false);
             assert_eq!("v", name.to_string());
             assert_eq!(columns, vec![]);
@@ -8055,17 +8347,18 @@ fn parse_create_or_replace_materialized_view() {
 fn parse_create_materialized_view() {
     let sql = "CREATE MATERIALIZED VIEW myschema.myview AS SELECT foo FROM bar";
     match verified_stmt(sql) {
-        Statement::CreateView(CreateView {
-            or_alter,
-            name,
-            or_replace,
-            columns,
-            query,
-            materialized,
-            options,
-            params,
-            ..
-        }) => {
+        Statement::CreateView(create_view) => {
+            let CreateView {
+                or_alter,
+                name,
+                or_replace,
+                columns,
+                query,
+                materialized,
+                options,
+                params,
+                ..
+            } = Box::into_owned(create_view);
             assert_eq!(or_alter, false);
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<ViewColumnDef>::new(), columns);
@@ -8284,15 +8577,12 @@ fn parse_fetch() {
     let ast = verified_query("SELECT foo FROM bar FETCH FIRST ROWS ONLY");
     assert_eq!(
         ast.fetch.map(Box::into_owned),
-        Some(
-            Fetch {
-                approximate: false,
-                with_ties: false,
-                percent: false,
-                quantity: None,
-            }
-            .into()
-        )
+        Some(Fetch {
+            approximate: false,
+            with_ties: false,
+            percent: false,
+            quantity: None,
+        })
     );
     let ast = verified_query("SELECT foo FROM bar WHERE foo = 4 FETCH FIRST 2 ROWS ONLY");
     assert_eq!(ast.fetch.as_deref(), fetch_first_two_rows_only.as_ref());
@@ -8417,7 +8707,7 @@ fn lateral_derived() {
         assert_eq!(
             join.join_operator,
             JoinOperator::Left(JoinConstraint::On(
-                Expr::Value((test_utils::number("1")).with_empty_span()).into()
+                Expr::Value(Box::new((test_utils::number("1")).with_empty_span())).into()
             ))
         );
         if let TableFactor::Derived {
@@ -8464,12 +8754,12 @@ fn lateral_function() {
         select_token: AttachedToken::empty(),
         distinct: None,
         top: None,
-        projection: vec![SelectItem::Wildcard(WildcardAdditionalOptions::default())],
+        projection: vec![SelectItem::Wildcard(WildcardAdditionalOptions::default())].into(),
         top_before_distinct: false,
         into: None,
         from: vec![TableWithJoins {
             relation: table_from_name(ObjectName::from(vec![Ident {
-                value: "customer".to_string(),
+                value: "customer".into(),
                 quote_style: None,
                 span: Span::empty(),
             }])),
@@ -8478,21 +8768,23 @@ fn lateral_function() {
                     lateral: true,
                     name: ObjectName::from(vec!["generate_series".into()]),
                     args: vec![
-                        FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
+                        FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(Box::new(
                             (number("1")).with_empty_span(),
-                        ))),
+                        )))),
                         FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::CompoundIdentifier(
-                            vec![Ident::new("customer"), Ident::new("id")],
+                            vec![Ident::new("customer"), Ident::new("id")].into(),
                         ))),
                     ],
                     alias: None,
                 },
                 global: false,
                 join_operator: JoinOperator::Left(JoinConstraint::None),
-            }],
-        }],
+            }]
+            .into(),
+        }]
+        .into(),
         selection: None,
-        group_by: GroupByExpr::Expressions(vec![], vec![]),
+        group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
         having: None,
         qualify: None,
         named_window: vec![],
@@ -8603,15 +8895,19 @@ fn parse_set_transaction() {
     // TRANSACTION, so no need to duplicate the tests here. We just do a quick
     // sanity check.
     match verified_stmt("SET TRANSACTION READ ONLY, READ WRITE, ISOLATION LEVEL SERIALIZABLE") {
-        Statement::Set(SetStatement {
-            inner:
-                Set::SetTransaction {
-                    modes,
-                    session,
-                    snapshot,
-                },
-            ..
-        }) => {
+        Statement::Set(set) => {
+            let SetStatement {
+                inner:
+                    Set::SetTransaction {
+                        modes,
+                        session,
+                        snapshot,
+                    },
+                ..
+            } = Box::into_owned(set)
+            else {
+                unreachable!()
+            };
             assert_eq!(
                 modes,
                 vec![
@@ -8630,44 +8926,52 @@ fn parse_set_transaction() {
 #[test]
 fn parse_set_variable() {
     match verified_stmt("SET SOMETHING = '1'") {
-        Statement::Set(SetStatement {
-            inner:
-                Set::SingleAssignment {
-                    scope,
-                    variable,
-                    values,
-                },
-            ..
-        }) => {
+        Statement::Set(set) => {
+            let SetStatement {
+                inner:
+                    Set::SingleAssignment {
+                        scope,
+                        variable,
+                        values,
+                    },
+                ..
+            } = Box::into_owned(set)
+            else {
+                unreachable!()
+            };
             assert_eq!(scope, None);
             assert_eq!(variable, ObjectName::from(vec!["SOMETHING".into()]));
             assert_eq!(
                 values,
-                vec![Expr::Value(
+                vec![Expr::Value(Box::new(
                     (Value::SingleQuotedString("1".into())).with_empty_span()
-                )]
+                ))]
             );
         }
         _ => unreachable!(),
     }
 
     match verified_stmt("SET GLOBAL VARIABLE = 'Value'") {
-        Statement::Set(SetStatement {
-            inner:
-                Set::SingleAssignment {
-                    scope,
-                    variable,
-                    values,
-                },
-            ..
-        }) => {
+        Statement::Set(set) => {
+            let SetStatement {
+                inner:
+                    Set::SingleAssignment {
+                        scope,
+                        variable,
+                        values,
+                    },
+                ..
+            } = Box::into_owned(set)
+            else {
+                unreachable!()
+            };
             assert_eq!(scope, Some(ContextModifier::Global));
             assert_eq!(variable, ObjectName::from(vec!["VARIABLE".into()]));
             assert_eq!(
                 values,
-                vec![Expr::Value(
+                vec![Expr::Value(Box::new(
                     (Value::SingleQuotedString("Value".into())).with_empty_span()
-                )]
+                ))]
             );
         }
         _ => unreachable!(),
@@ -8681,22 +8985,26 @@ fn parse_set_variable() {
 #[test]
 fn parse_set_role_as_variable() {
     match verified_stmt("SET role = 'foobar'") {
-        Statement::Set(SetStatement {
-            inner:
-                Set::SingleAssignment {
-                    scope,
-                    variable,
-                    values,
-                },
-            ..
-        }) => {
+        Statement::Set(set) => {
+            let SetStatement {
+                inner:
+                    Set::SingleAssignment {
+                        scope,
+                        variable,
+                        values,
+                    },
+                ..
+            } = Box::into_owned(set)
+            else {
+                unreachable!()
+            };
             assert_eq!(scope, None);
             assert_eq!(variable, ObjectName::from(vec!["role".into()]));
             assert_eq!(
                 values,
-                vec![Expr::Value(
+                vec![Expr::Value(Box::new(
                     (Value::SingleQuotedString("foobar".into())).with_empty_span()
-                )]
+                ))]
             );
         }
         _ => unreachable!(),
@@ -8712,16 +9020,16 @@ fn parse_double_colon_cast_at_timezone() {
         &Expr::AtTimeZone {
             timestamp: Box::new(Expr::Cast {
                 kind: CastKind::DoubleColon,
-                expr: Box::new(Expr::Value(
-                    (Value::SingleQuotedString("2001-01-01T00:00:00.000Z".to_string()))
+                expr: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("2001-01-01T00:00:00.000Z".into()))
                         .with_empty_span()
-                )),
-                data_type: DataType::Timestamp(None, TimezoneInfo::None),
+                ))),
+                data_type: Box::new(DataType::Timestamp(None, TimezoneInfo::None)),
                 format: None
             }),
-            time_zone: Box::new(Expr::Value(
-                (Value::SingleQuotedString("Europe/Brussels".to_string())).with_empty_span()
-            )),
+            time_zone: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("Europe/Brussels".into())).with_empty_span()
+            ))),
         },
         expr_from_projection(only(&select.projection)),
     );
@@ -8730,22 +9038,26 @@ fn parse_double_colon_cast_at_timezone() {
 #[test]
 fn parse_set_time_zone() {
     match verified_stmt("SET TIMEZONE = 'UTC'") {
-        Statement::Set(SetStatement {
-            inner:
-                Set::SingleAssignment {
-                    scope,
-                    variable,
-                    values,
-                },
-            ..
-        }) => {
+        Statement::Set(set) => {
+            let SetStatement {
+                inner:
+                    Set::SingleAssignment {
+                        scope,
+                        variable,
+                        values,
+                    },
+                ..
+            } = Box::into_owned(set)
+            else {
+                unreachable!()
+            };
             assert_eq!(scope, None);
             assert_eq!(variable, ObjectName::from(vec!["TIMEZONE".into()]));
             assert_eq!(
                 values,
-                vec![Expr::Value(
+                vec![Expr::Value(Box::new(
                     (Value::SingleQuotedString("UTC".into())).with_empty_span()
-                )]
+                ))]
             );
         }
         _ => unreachable!(),
@@ -8826,7 +9138,7 @@ fn parse_rollback() {
             savepoint,
             ..
         } => {
-            assert_eq!(savepoint, Some(Ident::new("test1")));
+            assert_eq!(savepoint.as_deref(), Some(&Ident::new("test1")));
         }
         _ => unreachable!(),
     }
@@ -8837,7 +9149,7 @@ fn parse_rollback() {
             savepoint,
             ..
         } => {
-            assert_eq!(savepoint, Some(Ident::new("test1")));
+            assert_eq!(savepoint.as_deref(), Some(&Ident::new("test1")));
         }
         _ => unreachable!(),
     }
@@ -8875,7 +9187,7 @@ fn parse_create_index() {
         IndexColumn {
             operator_class: None,
             column: OrderByExpr {
-                expr: Expr::Identifier(Ident::new("name")),
+                expr: Expr::Identifier(Box::new(Ident::new("name"))),
                 with_fill: None,
                 options: OrderByOptions {
                     asc: None,
@@ -8886,7 +9198,7 @@ fn parse_create_index() {
         IndexColumn {
             operator_class: None,
             column: OrderByExpr {
-                expr: Expr::Identifier(Ident::new("age")),
+                expr: Expr::Identifier(Box::new(Ident::new("age"))),
                 with_fill: None,
                 options: OrderByOptions {
                     asc: Some(false),
@@ -8896,14 +9208,18 @@ fn parse_create_index() {
         },
     ];
     match verified_stmt(sql) {
-        Statement::CreateIndex(CreateIndex {
-            name: Some(name),
-            table_name,
-            columns,
-            unique,
-            if_not_exists,
-            ..
-        }) => {
+        Statement::CreateIndex(create_index) => {
+            let CreateIndex {
+                name: Some(name),
+                table_name,
+                columns,
+                unique,
+                if_not_exists,
+                ..
+            } = Box::into_owned(create_index)
+            else {
+                unreachable!()
+            };
             assert_eq!("idx_name", name.to_string());
             assert_eq!("test", table_name.to_string());
             assert_eq!(indexed_columns, columns);
@@ -8921,7 +9237,7 @@ fn test_create_index_with_using_function() {
         IndexColumn {
             operator_class: None,
             column: OrderByExpr {
-                expr: Expr::Identifier(Ident::new("name")),
+                expr: Expr::Identifier(Box::new(Ident::new("name"))),
                 with_fill: None,
                 options: OrderByOptions {
                     asc: None,
@@ -8932,7 +9248,7 @@ fn test_create_index_with_using_function() {
         IndexColumn {
             operator_class: None,
             column: OrderByExpr {
-                expr: Expr::Identifier(Ident::new("age")),
+                expr: Expr::Identifier(Box::new(Ident::new("age"))),
                 with_fill: None,
                 options: OrderByOptions {
                     asc: Some(false),
@@ -8942,21 +9258,25 @@ fn test_create_index_with_using_function() {
         },
     ];
     match verified_stmt(sql) {
-        Statement::CreateIndex(CreateIndex {
-            name: Some(name),
-            table_name,
-            using,
-            columns,
-            unique,
-            concurrently,
-            if_not_exists,
-            include,
-            nulls_distinct: None,
-            with,
-            predicate: None,
-            index_options,
-            alter_options,
-        }) => {
+        Statement::CreateIndex(create_index) => {
+            let CreateIndex {
+                name: Some(name),
+                table_name,
+                using,
+                columns,
+                unique,
+                concurrently,
+                if_not_exists,
+                include,
+                nulls_distinct: None,
+                with,
+                predicate: None,
+                index_options,
+                alter_options,
+            } = Box::into_owned(create_index)
+            else {
+                unreachable!()
+            };
             assert_eq!("idx_name", name.to_string());
             assert_eq!("test", table_name.to_string());
             assert_eq!("BTREE", using.unwrap().to_string());
@@ -8978,7 +9298,7 @@ fn test_create_index_with_with_clause() {
     let sql = "CREATE UNIQUE INDEX title_idx ON films(title) WITH (fillfactor = 70, single_param)";
     let indexed_columns: Vec<IndexColumn> = vec![IndexColumn {
         column: OrderByExpr {
-            expr: Expr::Identifier(Ident::new("title")),
+            expr: Expr::Identifier(Box::new(Ident::new("title"))),
             options: OrderByOptions {
                 asc: None,
                 nulls_first: None,
@@ -8989,29 +9309,33 @@ fn test_create_index_with_with_clause() {
     }];
     let with_parameters = vec![
         Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::new("fillfactor"))),
+            left: Box::new(Expr::Identifier(Box::new(Ident::new("fillfactor")))),
             op: BinaryOperator::Eq,
             right: Box::new(Expr::value(number("70"))),
         },
-        Expr::Identifier(Ident::new("single_param")),
+        Expr::Identifier(Box::new(Ident::new("single_param"))),
     ];
     let dialects = all_dialects_where(|d| d.supports_create_index_with_clause());
     match dialects.verified_stmt(sql) {
-        Statement::CreateIndex(CreateIndex {
-            name: Some(name),
-            table_name,
-            using: None,
-            columns,
-            unique,
-            concurrently,
-            if_not_exists,
-            include,
-            nulls_distinct: None,
-            with,
-            predicate: None,
-            index_options,
-            alter_options,
-        }) => {
+        Statement::CreateIndex(create_index) => {
+            let CreateIndex {
+                name: Some(name),
+                table_name,
+                using: None,
+                columns,
+                unique,
+                concurrently,
+                if_not_exists,
+                include,
+                nulls_distinct: None,
+                with,
+                predicate: None,
+                index_options,
+                alter_options,
+            } = Box::into_owned(create_index)
+            else {
+                unreachable!()
+            };
             pretty_assertions::assert_eq!("title_idx", name.to_string());
             pretty_assertions::assert_eq!("films", table_name.to_string());
             pretty_assertions::assert_eq!(indexed_columns, columns);
@@ -9108,7 +9432,7 @@ fn parse_grant() {
             with_grant_option,
             granted_by,
             ..
-        } => match (privileges, objects) {
+        } => match (privileges, objects.map(Box::into_owned)) {
             (Privileges::Actions(actions), Some(GrantObjects::Tables(objects))) => {
                 assert_eq!(
                     vec![
@@ -9159,7 +9483,7 @@ fn parse_grant() {
             grantees,
             with_grant_option,
             ..
-        } => match (privileges, objects) {
+        } => match (privileges, objects.map(Box::into_owned)) {
             (Privileges::Actions(actions), Some(GrantObjects::AllTablesInSchema { schemas })) => {
                 assert_eq!(vec![Action::Insert { columns: None }], actions);
                 assert_eq_vec(&["public"], &schemas);
@@ -9179,7 +9503,7 @@ fn parse_grant() {
             grantees,
             granted_by,
             ..
-        } => match (privileges, objects, granted_by) {
+        } => match (privileges, objects.map(Box::into_owned), granted_by) {
             (Privileges::Actions(actions), Some(GrantObjects::Sequences(objects)), None) => {
                 assert_eq!(
                     vec![Action::Usage, Action::Select { columns: None }],
@@ -9212,7 +9536,7 @@ fn parse_grant() {
             privileges,
             objects,
             ..
-        } => match (privileges, objects) {
+        } => match (privileges, objects.map(Box::into_owned)) {
             (
                 Privileges::All {
                     with_privileges_keyword,
@@ -9233,7 +9557,7 @@ fn parse_grant() {
             privileges,
             objects,
             ..
-        } => match (privileges, objects) {
+        } => match (privileges, objects.map(Box::into_owned)) {
             (
                 Privileges::Actions(actions),
                 Some(GrantObjects::AllSequencesInSchema { schemas }),
@@ -9313,12 +9637,15 @@ fn test_revoke() {
     match verified_stmt(sql) {
         Statement::Revoke {
             privileges,
-            objects: Some(GrantObjects::Tables(tables)),
+            objects: Some(objects),
             grantees,
             granted_by,
             cascade,
             ..
         } => {
+            let GrantObjects::Tables(tables) = Box::into_owned(objects) else {
+                unreachable!()
+            };
             assert_eq!(
                 Privileges::All {
                     with_privileges_keyword: true
@@ -9340,7 +9667,7 @@ fn test_revoke() {
             objects,
             grantees,
             ..
-        } => match (privileges, objects) {
+        } => match (privileges, objects.map(Box::into_owned)) {
             (
                 Privileges::Actions(actions),
                 Some(GrantObjects::MaterializedViews(materialized_views)),
@@ -9361,7 +9688,7 @@ fn test_revoke() {
             objects,
             grantees,
             ..
-        } => match (privileges, objects) {
+        } => match (privileges, objects.map(Box::into_owned)) {
             (Privileges::Actions(actions), Some(GrantObjects::PropertyGraphs(graphs))) => {
                 assert_eq!(vec![Action::Select { columns: None }], actions);
                 assert_eq_vec(&["social_graph", "org_graph"], &graphs);
@@ -9379,12 +9706,15 @@ fn test_revoke_with_cascade() {
     match all_dialects_except(|d| d.is::<MySqlDialect>()).verified_stmt(sql) {
         Statement::Revoke {
             privileges,
-            objects: Some(GrantObjects::Tables(tables)),
+            objects: Some(objects),
             grantees,
             granted_by,
             cascade,
             ..
         } => {
+            let GrantObjects::Tables(tables) = Box::into_owned(objects) else {
+                unreachable!()
+            };
             assert_eq!(
                 Privileges::All {
                     with_privileges_keyword: true
@@ -9406,10 +9736,14 @@ fn test_revoke_use_for_rewrite_on_materialized_view() {
     match verified_stmt(sql) {
         Statement::Revoke {
             privileges,
-            objects: Some(GrantObjects::MaterializedViews(materialized_views)),
+            objects: Some(objects),
             grantees,
             ..
         } => {
+            let GrantObjects::MaterializedViews(materialized_views) = Box::into_owned(objects)
+            else {
+                unreachable!()
+            };
             assert_eq!(Privileges::Actions(vec![Action::UseForRewrite]), privileges);
             assert_eq_vec(&["mv_sales"], &materialized_views);
             assert_eq_vec(&["analyst"], &grantees);
@@ -9450,7 +9784,7 @@ fn parse_merge() {
                     name: ObjectName::from(vec![Ident::new("s"), Ident::new("bar")]),
                     alias: Some(TableAlias {
                         name: Ident::new("dest"),
-                        columns: vec![],
+                        columns: vec![].into(),
                         implicit: false,
                     }),
                     args: None,
@@ -9479,17 +9813,19 @@ fn parse_merge() {
                             top_before_distinct: false,
                             projection: vec![SelectItem::Wildcard(
                                 WildcardAdditionalOptions::default()
-                            )],
+                            )]
+                            .into(),
                             into: None,
                             from: vec![TableWithJoins {
                                 relation: table_from_name(ObjectName::from(vec![
                                     Ident::new("s"),
                                     Ident::new("foo")
                                 ])),
-                                joins: vec![],
-                            }],
+                                joins: AstVec::new(),
+                            }]
+                            .into(),
                             selection: None,
-                            group_by: GroupByExpr::Expressions(vec![], vec![]),
+                            group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
                             having: None,
                             qualify: None,
                             named_window: vec![],
@@ -9499,16 +9835,17 @@ fn parse_merge() {
                         order_by: None,
                         limit_clause: None,
                         fetch: None,
-                        locks: vec![],
+                        locks: AstVec::new(),
                         for_clause: None,
+                        comments: Comments::default(),
                     }),
                     alias: Some(TableAlias {
                         name: Ident {
-                            value: "stg".to_string(),
+                            value: "stg".into(),
                             quote_style: None,
                             span: Span::empty(),
                         },
-                        columns: vec![],
+                        columns: vec![].into(),
                         implicit: false,
                     }),
                 }
@@ -9519,27 +9856,23 @@ fn parse_merge() {
                 on,
                 Box::new(Expr::BinaryOp {
                     left: Box::new(Expr::BinaryOp {
-                        left: Box::new(Expr::CompoundIdentifier(vec![
-                            Ident::new("dest"),
-                            Ident::new("D"),
-                        ])),
+                        left: Box::new(Expr::CompoundIdentifier(
+                            vec![Ident::new("dest"), Ident::new("D"),].into()
+                        )),
                         op: BinaryOperator::Eq,
-                        right: Box::new(Expr::CompoundIdentifier(vec![
-                            Ident::new("stg"),
-                            Ident::new("D"),
-                        ])),
+                        right: Box::new(Expr::CompoundIdentifier(
+                            vec![Ident::new("stg"), Ident::new("D"),].into()
+                        )),
                     }),
                     op: BinaryOperator::And,
                     right: Box::new(Expr::BinaryOp {
-                        left: Box::new(Expr::CompoundIdentifier(vec![
-                            Ident::new("dest"),
-                            Ident::new("E"),
-                        ])),
+                        left: Box::new(Expr::CompoundIdentifier(
+                            vec![Ident::new("dest"), Ident::new("E"),].into()
+                        )),
                         op: BinaryOperator::Eq,
-                        right: Box::new(Expr::CompoundIdentifier(vec![
-                            Ident::new("stg"),
-                            Ident::new("E"),
-                        ])),
+                        right: Box::new(Expr::CompoundIdentifier(
+                            vec![Ident::new("stg"), Ident::new("E"),].into()
+                        )),
                     }),
                 })
             );
@@ -9557,19 +9890,18 @@ fn parse_merge() {
                                 value_keyword: false,
                                 explicit_row: false,
                                 rows: vec![vec![
-                                    Expr::CompoundIdentifier(vec![
-                                        Ident::new("stg"),
-                                        Ident::new("A")
-                                    ]),
-                                    Expr::CompoundIdentifier(vec![
-                                        Ident::new("stg"),
-                                        Ident::new("B")
-                                    ]),
-                                    Expr::CompoundIdentifier(vec![
-                                        Ident::new("stg"),
-                                        Ident::new("C")
-                                    ]),
-                                ]]
+                                    Expr::CompoundIdentifier(
+                                        vec![Ident::new("stg"), Ident::new("A")].into()
+                                    ),
+                                    Expr::CompoundIdentifier(
+                                        vec![Ident::new("stg"), Ident::new("B")].into()
+                                    ),
+                                    Expr::CompoundIdentifier(
+                                        vec![Ident::new("stg"), Ident::new("C")].into()
+                                    ),
+                                ]
+                                .into()]
+                                .into()
                             }),
                             where_clause: None,
                         }),
@@ -9577,14 +9909,13 @@ fn parse_merge() {
                     MergeClause {
                         clause_kind: MergeClauseKind::Matched,
                         predicate: Some(Expr::BinaryOp {
-                            left: Box::new(Expr::CompoundIdentifier(vec![
-                                Ident::new("dest"),
-                                Ident::new("A"),
-                            ])),
-                            op: BinaryOperator::Eq,
-                            right: Box::new(Expr::Value(
-                                (Value::SingleQuotedString("a".to_string())).with_empty_span()
+                            left: Box::new(Expr::CompoundIdentifier(
+                                vec![Ident::new("dest"), Ident::new("A"),].into()
                             )),
+                            op: BinaryOperator::Eq,
+                            right: Box::new(Expr::Value(Box::new(
+                                (Value::SingleQuotedString("a".into())).with_empty_span()
+                            ))),
                         }),
                         action: MergeAction::Update {
                             assignments: vec![
@@ -9593,20 +9924,18 @@ fn parse_merge() {
                                         Ident::new("dest"),
                                         Ident::new("F")
                                     ])),
-                                    value: Expr::CompoundIdentifier(vec![
-                                        Ident::new("stg"),
-                                        Ident::new("F"),
-                                    ]),
+                                    value: Expr::CompoundIdentifier(
+                                        vec![Ident::new("stg"), Ident::new("F"),].into()
+                                    ),
                                 },
                                 Assignment {
                                     target: AssignmentTarget::ColumnName(ObjectName::from(vec![
                                         Ident::new("dest"),
                                         Ident::new("G")
                                     ])),
-                                    value: Expr::CompoundIdentifier(vec![
-                                        Ident::new("stg"),
-                                        Ident::new("G"),
-                                    ]),
+                                    value: Expr::CompoundIdentifier(
+                                        vec![Ident::new("stg"), Ident::new("G"),].into()
+                                    ),
                                 },
                             ],
                             where_clause: None,
@@ -9754,7 +10083,7 @@ fn test_lock_table() {
     assert_eq!(
         lock.of.unwrap(),
         ObjectName::from(vec![Ident {
-            value: "school".to_string(),
+            value: "school".into(),
             quote_style: None,
             span: Span::empty(),
         }])
@@ -9769,7 +10098,7 @@ fn test_lock_table() {
     assert_eq!(
         lock.of.unwrap(),
         ObjectName::from(vec![Ident {
-            value: "school".to_string(),
+            value: "school".into(),
             quote_style: None,
             span: Span::empty(),
         }])
@@ -9784,7 +10113,7 @@ fn test_lock_table() {
     assert_eq!(
         lock.of.unwrap(),
         ObjectName::from(vec![Ident {
-            value: "school".to_string(),
+            value: "school".into(),
             quote_style: None,
             span: Span::empty(),
         }])
@@ -9795,7 +10124,7 @@ fn test_lock_table() {
     assert_eq!(
         lock.of.unwrap(),
         ObjectName::from(vec![Ident {
-            value: "student".to_string(),
+            value: "student".into(),
             quote_style: None,
             span: Span::empty(),
         }])
@@ -9813,7 +10142,7 @@ fn test_lock_nonblock() {
     assert_eq!(
         lock.of.unwrap(),
         ObjectName::from(vec![Ident {
-            value: "school".to_string(),
+            value: "school".into(),
             quote_style: None,
             span: Span::empty(),
         }])
@@ -9828,7 +10157,7 @@ fn test_lock_nonblock() {
     assert_eq!(
         lock.of.unwrap(),
         ObjectName::from(vec![Ident {
-            value: "school".to_string(),
+            value: "school".into(),
             quote_style: None,
             span: Span::empty(),
         }])
@@ -9848,11 +10177,11 @@ fn test_placeholder() {
         ast.selection,
         Some(
             Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("id"))),
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("id")))),
                 op: BinaryOperator::Eq,
-                right: Box::new(Expr::Value(
+                right: Box::new(Expr::Value(Box::new(
                     (Value::Placeholder("$Id1".into())).with_empty_span()
-                )),
+                ))),
             }
             .into()
         )
@@ -9860,11 +10189,13 @@ fn test_placeholder() {
 
     let ast = dialects.verified_query("SELECT * FROM student LIMIT $1 OFFSET $2");
     let expected_limit_clause = LimitClause::LimitOffset {
-        limit: Some(Expr::Value(
+        limit: Some(Expr::Value(Box::new(
             (Value::Placeholder("$1".into())).with_empty_span(),
-        )),
+        ))),
         offset: Some(Offset {
-            value: Expr::Value((Value::Placeholder("$2".into())).with_empty_span()),
+            value: Expr::Value(Box::new(
+                (Value::Placeholder("$2".into())).with_empty_span(),
+            )),
             rows: OffsetRows::None,
         }),
         limit_by: vec![],
@@ -9885,11 +10216,11 @@ fn test_placeholder() {
         ast.selection,
         Some(
             Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("id"))),
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("id")))),
                 op: BinaryOperator::Eq,
-                right: Box::new(Expr::Value(
+                right: Box::new(Expr::Value(Box::new(
                     (Value::Placeholder("?".into())).with_empty_span()
-                )),
+                ))),
             }
             .into()
         )
@@ -9900,15 +10231,24 @@ fn test_placeholder() {
     assert_eq!(
         ast.projection,
         vec![
-            UnnamedExpr(Expr::Value(
-                (Value::Placeholder("$fromage_français".into())).with_empty_span()
-            )),
-            UnnamedExpr(Expr::Value(
-                (Value::Placeholder(":x".into())).with_empty_span()
-            )),
-            UnnamedExpr(Expr::Value(
-                (Value::Placeholder("?123".into())).with_empty_span()
-            )),
+            UnnamedExpr {
+                expr: Expr::Value(Box::new(
+                    (Value::Placeholder("$fromage_français".into())).with_empty_span()
+                )),
+                comments: Comments::default(),
+            },
+            UnnamedExpr {
+                expr: Expr::Value(Box::new(
+                    (Value::Placeholder(":x".into())).with_empty_span()
+                )),
+                comments: Comments::default(),
+            },
+            UnnamedExpr {
+                expr: Expr::Value(Box::new(
+                    (Value::Placeholder("?123".into())).with_empty_span()
+                )),
+                comments: Comments::default(),
+            },
         ]
     );
 }
@@ -10097,7 +10437,7 @@ fn parse_time_functions() {
             parameters: FunctionArguments::None,
             args: FunctionArguments::List(FunctionArgumentList {
                 duplicate_treatment: None,
-                args: vec![],
+                args: AstVec::new(),
                 clauses: vec![],
             }),
             null_treatment: None,
@@ -10107,7 +10447,7 @@ fn parse_time_functions() {
             within_group: vec![],
         };
         assert_eq!(
-            &Expr::Function(select_localtime_func_call_ast.clone()),
+            &Expr::Function(Box::new(select_localtime_func_call_ast.clone())),
             expr_from_projection(&select.projection[0])
         );
 
@@ -10116,7 +10456,7 @@ fn parse_time_functions() {
         let mut ast_without_parens = select_localtime_func_call_ast;
         ast_without_parens.args = FunctionArguments::None;
         assert_eq!(
-            &Expr::Function(ast_without_parens),
+            &Expr::Function(Box::new(ast_without_parens)),
             expr_from_projection(&verified_only_select(&sql_without_parens).projection[0])
         );
     }
@@ -10132,10 +10472,10 @@ fn parse_time_functions() {
 fn parse_position() {
     assert_eq!(
         Expr::Position {
-            expr: Box::new(Expr::Value(
-                (Value::SingleQuotedString("@".to_string())).with_empty_span()
-            )),
-            r#in: Box::new(Expr::Identifier(Ident::new("field"))),
+            expr: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("@".into())).with_empty_span()
+            ))),
+            r#in: Box::new(Expr::Identifier(Box::new(Ident::new("field")))),
         },
         verified_expr("POSITION('@' IN field)"),
     );
@@ -10145,8 +10485,12 @@ fn parse_position() {
         call(
             "position",
             [
-                Expr::Value((Value::SingleQuotedString("an".to_owned())).with_empty_span()),
-                Expr::Value((Value::SingleQuotedString("banana".to_owned())).with_empty_span()),
+                Expr::Value(Box::new(
+                    (Value::SingleQuotedString("an".into())).with_empty_span()
+                )),
+                Expr::Value(Box::new(
+                    (Value::SingleQuotedString("banana".into())).with_empty_span()
+                )),
                 Expr::value(number("1")),
             ]
         ),
@@ -10195,7 +10539,7 @@ fn parse_is_boolean() {
     let sql = "a IS NORMALIZED";
     assert_eq!(
         IsNormalized {
-            expr: Box::new(Identifier(Ident::new("a"))),
+            expr: Box::new(Identifier(Box::new(Ident::new("a")))),
             form: None,
             negated: false,
         },
@@ -10205,7 +10549,7 @@ fn parse_is_boolean() {
     let sql = "a IS NOT NORMALIZED";
     assert_eq!(
         IsNormalized {
-            expr: Box::new(Identifier(Ident::new("a"))),
+            expr: Box::new(Identifier(Box::new(Ident::new("a")))),
             form: None,
             negated: true,
         },
@@ -10215,7 +10559,7 @@ fn parse_is_boolean() {
     let sql = "a IS NFKC NORMALIZED";
     assert_eq!(
         IsNormalized {
-            expr: Box::new(Identifier(Ident::new("a"))),
+            expr: Box::new(Identifier(Box::new(Ident::new("a")))),
             form: Some(NormalizationForm::NFKC),
             negated: false,
         },
@@ -10225,7 +10569,7 @@ fn parse_is_boolean() {
     let sql = "a IS NOT NFKD NORMALIZED";
     assert_eq!(
         IsNormalized {
-            expr: Box::new(Identifier(Ident::new("a"))),
+            expr: Box::new(Identifier(Box::new(Ident::new("a")))),
             form: Some(NormalizationForm::NFKD),
             negated: true,
         },
@@ -10353,7 +10697,7 @@ fn parse_show_functions() {
         verified_stmt("SHOW FUNCTIONS LIKE 'pattern'"),
         Statement::ShowFunctions {
             show_token: AttachedToken::empty(),
-            filter: Some(ShowStatementFilter::Like("pattern".into())),
+            filter: Some(Box::new(ShowStatementFilter::Like("pattern".into()))),
         }
     );
 }
@@ -10459,7 +10803,7 @@ fn parse_set_backup_audit_retention() {
         verified_stmt("SET BACKUP AUDIT RETENTION TO 90 DAYS"),
         Statement::SetBackupAuditRetention {
             value: BackupAuditRetentionValue::Duration {
-                value: Expr::value(Value::Number("90".parse().unwrap(), false)),
+                value: Expr::value(number("90")),
                 unit: Some(DateTimeField::Days),
             },
         }
@@ -10493,7 +10837,7 @@ fn parse_cache_table() {
     assert_eq!(
         verified_stmt(format!("CACHE TABLE '{cache_table_name}'").as_str()),
         Statement::Cache {
-            cache_token: AttachedToken::empty(),
+            cache_token: Box::new(AttachedToken::empty()),
             table_flag: None,
             table_name: ObjectName::from(vec![Ident::with_quote('\'', cache_table_name)]),
             has_as: false,
@@ -10505,7 +10849,7 @@ fn parse_cache_table() {
     assert_eq!(
         verified_stmt(format!("CACHE {table_flag} TABLE '{cache_table_name}'").as_str()),
         Statement::Cache {
-            cache_token: AttachedToken::empty(),
+            cache_token: Box::new(AttachedToken::empty()),
             table_flag: Some(ObjectName::from(vec![Ident::new(table_flag)])),
             table_name: ObjectName::from(vec![Ident::with_quote('\'', cache_table_name)]),
             has_as: false,
@@ -10522,14 +10866,16 @@ fn parse_cache_table() {
             .as_str()
         ),
         Statement::Cache {
-            cache_token: AttachedToken::empty(),
+            cache_token: Box::new(AttachedToken::empty()),
             table_flag: Some(ObjectName::from(vec![Ident::new(table_flag)])),
             table_name: ObjectName::from(vec![Ident::with_quote('\'', cache_table_name)]),
             has_as: false,
             options: vec![
                 SqlOption::KeyValue {
                     key: Ident::with_quote('\'', "K1"),
-                    value: Expr::Value((Value::SingleQuotedString("V1".into())).with_empty_span()),
+                    value: Expr::Value(Box::new(
+                        (Value::SingleQuotedString("V1".into())).with_empty_span()
+                    )),
                 },
                 SqlOption::KeyValue {
                     key: Ident::with_quote('\'', "K2"),
@@ -10548,14 +10894,14 @@ fn parse_cache_table() {
                 .as_str()
         ),
         Statement::Cache {
-            cache_token: AttachedToken::empty(),
+            cache_token: Box::new(AttachedToken::empty()),
             table_flag: Some(ObjectName::from(vec![Ident::new(table_flag)])),
             table_name: ObjectName::from(vec![Ident::with_quote('\'', cache_table_name)]),
             has_as: false,
             options: vec![
                 SqlOption::KeyValue {
                     key: Ident::with_quote('\'', "K1"),
-                    value: Expr::Value((Value::SingleQuotedString("V1".into())).with_empty_span()),
+                    value: Expr::Value(Box::new((Value::SingleQuotedString("V1".into())).with_empty_span())),
                 },
                 SqlOption::KeyValue {
                     key: Ident::with_quote('\'', "K2"),
@@ -10574,14 +10920,14 @@ fn parse_cache_table() {
                 .as_str()
         ),
         Statement::Cache {
-            cache_token: AttachedToken::empty(),
+            cache_token: Box::new(AttachedToken::empty()),
             table_flag: Some(ObjectName::from(vec![Ident::new(table_flag)])),
             table_name: ObjectName::from(vec![Ident::with_quote('\'', cache_table_name)]),
             has_as: true,
             options: vec![
                 SqlOption::KeyValue {
                     key: Ident::with_quote('\'', "K1"),
-                    value: Expr::Value((Value::SingleQuotedString("V1".into())).with_empty_span()),
+                    value: Expr::Value(Box::new((Value::SingleQuotedString("V1".into())).with_empty_span())),
                 },
                 SqlOption::KeyValue {
                     key: Ident::with_quote('\'', "K2"),
@@ -10595,7 +10941,7 @@ fn parse_cache_table() {
     assert_eq!(
         verified_stmt(format!("CACHE {table_flag} TABLE '{cache_table_name}' {sql}").as_str()),
         Statement::Cache {
-            cache_token: AttachedToken::empty(),
+            cache_token: Box::new(AttachedToken::empty()),
             table_flag: Some(ObjectName::from(vec![Ident::new(table_flag)])),
             table_name: ObjectName::from(vec![Ident::with_quote('\'', cache_table_name)]),
             has_as: false,
@@ -10607,7 +10953,7 @@ fn parse_cache_table() {
     assert_eq!(
         verified_stmt(format!("CACHE {table_flag} TABLE '{cache_table_name}' AS {sql}").as_str()),
         Statement::Cache {
-            cache_token: AttachedToken::empty(),
+            cache_token: Box::new(AttachedToken::empty()),
             table_flag: Some(ObjectName::from(vec![Ident::new(table_flag)])),
             table_name: ObjectName::from(vec![Ident::with_quote('\'', cache_table_name)]),
             has_as: true,
@@ -10784,6 +11130,66 @@ fn parse_with_recursion_limit() {
     assert!(res.is_ok(), "{res:?}");
 }
 
+#[test]
+fn parse_with_size_limits() {
+    use sqlparser::parser::{LimitExceeded, ParserLimit};
+
+    let dialect = PostgreSqlDialect {};
+    let sql = "SELECT a, b, c FROM t -- three columns\nWHERE x IN (1, 2)";
+    let parse = |limits: ParserLimits| {
+        Parser::new(&dialect)
+            .with_options(ParserOptions::new().with_limits(limits))
+            .try_with_sql(sql)
+            .and_then(|parser| parser.parse_statements())
+    };
+    let exceeded = |limit, max| Err(ParserError::LimitExceeded(LimitExceeded { limit, max }));
+
+    // Limits that fit the input exactly are not exceeded. Whitespace and
+    // comments do not count as tokens.
+    let fitting = ParserLimits::new()
+        .with_max_source_bytes(sql.len())
+        .with_max_tokens(16)
+        .with_max_list_length(3);
+    assert!(parse(fitting).is_ok());
+
+    assert_eq!(
+        parse(ParserLimits::new().with_max_source_bytes(sql.len() - 1)),
+        exceeded(ParserLimit::SourceBytes, sql.len() - 1)
+    );
+    assert_eq!(
+        parse(ParserLimits::new().with_max_tokens(15)),
+        exceeded(ParserLimit::Tokens, 15)
+    );
+    assert_eq!(
+        parse(ParserLimits::new().with_max_list_length(2)),
+        exceeded(ParserLimit::ListLength, 2)
+    );
+    assert_eq!(
+        ParserError::LimitExceeded(LimitExceeded {
+            limit: ParserLimit::Tokens,
+            max: 15
+        })
+        .to_string(),
+        "sql parser error: token count limit of 15 exceeded"
+    );
+
+    // A limit stops recovery instead of being skipped like a syntax error.
+    let recovered = Parser::new(&dialect)
+        .with_options(ParserOptions::new().with_limits(ParserLimits::new().with_max_list_length(2)))
+        .try_with_sql("SELECT 1; SELECT a, b, c; SELECT 2")
+        .unwrap()
+        .parse_statements_with_recovery();
+    assert_eq!(recovered.statements.len(), 1);
+    assert_eq!(recovered.errors.len(), 1);
+    assert_eq!(
+        recovered.errors[0].error,
+        ParserError::LimitExceeded(LimitExceeded {
+            limit: ParserLimit::ListLength,
+            max: 2
+        })
+    );
+}
+
 #[test]
 fn parse_escaped_string_with_unescape() {
     fn assert_mysql_query_value(dialects: &TestedDialects, sql: &str, quoted: &str) {
@@ -10797,9 +11203,9 @@ fn parse_escaped_string_with_unescape() {
                     let expr = expr_from_projection(only(&value.projection));
                     assert_eq!(
                         *expr,
-                        Expr::Value(
-                            (Value::SingleQuotedString(quoted.to_string())).with_empty_span()
-                        )
+                        Expr::Value(Box::new(
+                            (Value::SingleQuotedString(quoted.into())).with_empty_span()
+                        ))
                     );
                 }
                 _ => unreachable!(),
@@ -10850,9 +11256,9 @@ fn parse_escaped_string_without_unescape() {
                     let expr = expr_from_projection(only(&value.projection));
                     assert_eq!(
                         *expr,
-                        Expr::Value(
-                            (Value::SingleQuotedString(quoted.to_string())).with_empty_span()
-                        )
+                        Expr::Value(Box::new(
+                            (Value::SingleQuotedString(quoted.into())).with_empty_span()
+                        ))
                     );
                 }
                 _ => unreachable!(),
@@ -10888,10 +11294,9 @@ fn parse_pivot_table() {
         ExprWithAlias {
             expr: call(
                 "SUM",
-                [Expr::CompoundIdentifier(vec![
-                    Ident::new(table),
-                    Ident::new("amount"),
-                ])],
+                [Expr::CompoundIdentifier(
+                    vec![Ident::new(table), Ident::new("amount")].into(),
+                )],
             ),
             alias: alias.map(Ident::new),
         }
@@ -10904,7 +11309,7 @@ fn parse_pivot_table() {
                 name: ObjectName::from(vec![Ident::new("monthly_sales")]),
                 alias: Some(TableAlias {
                     name: Ident::new("a"),
-                    columns: vec![],
+                    columns: vec![].into(),
                     implicit: false,
                 }),
                 args: None,
@@ -10923,37 +11328,37 @@ fn parse_pivot_table() {
                 expected_function("b", Some("t")),
                 expected_function("c", Some("u")),
             ],
-            value_column: vec![Expr::CompoundIdentifier(vec![
-                Ident::new("a"),
-                Ident::new("MONTH")
-            ])],
+            value_column: vec![Expr::CompoundIdentifier(
+                vec![Ident::new("a"), Ident::new("MONTH")].into()
+            )],
             value_source: PivotValueSource::List(vec![
                 ExprWithAlias {
                     expr: Expr::value(number("1")),
                     alias: Some(Ident::new("x"))
                 },
                 ExprWithAlias {
-                    expr: Expr::Value(
-                        (Value::SingleQuotedString("two".to_string())).with_empty_span()
-                    ),
+                    expr: Expr::Value(Box::new(
+                        (Value::SingleQuotedString("two".into())).with_empty_span()
+                    )),
                     alias: None
                 },
                 ExprWithAlias {
-                    expr: Expr::Identifier(Ident::new("three")),
+                    expr: Expr::Identifier(Box::new(Ident::new("three"))),
                     alias: Some(Ident::new("y"))
                 },
             ]),
             default_on_null: None,
             alias: Some(TableAlias {
                 name: Ident {
-                    value: "p".to_string(),
+                    value: "p".into(),
                     quote_style: None,
                     span: Span::empty(),
                 },
                 columns: vec![
                     TableAliasColumnDef::from_name("c"),
                     TableAliasColumnDef::from_name("d"),
-                ],
+                ]
+                .into(),
                 implicit: false,
             }),
         }
@@ -11001,39 +11406,41 @@ fn parse_pivot_table() {
             xml: false,
             aggregate_functions: vec![
                 ExprWithAlias {
-                    expr: call("SUM", [Expr::Identifier(Ident::new("age"))]),
+                    expr: call("SUM", [Expr::Identifier(Box::new(Ident::new("age")))]),
                     alias: Some(Ident::new("a"))
                 },
                 ExprWithAlias {
-                    expr: call("AVG", [Expr::Identifier(Ident::new("class"))]),
+                    expr: call("AVG", [Expr::Identifier(Box::new(Ident::new("class")))]),
                     alias: Some(Ident::new("c"))
                 },
             ],
             value_column: vec![
-                Expr::Identifier(Ident::new("name")),
-                Expr::Identifier(Ident::new("age")),
+                Expr::Identifier(Box::new(Ident::new("name"))),
+                Expr::Identifier(Box::new(Ident::new("age"))),
             ],
             value_source: PivotValueSource::List(vec![
                 ExprWithAlias {
-                    expr: Expr::Tuple(vec![
-                        Expr::Value(
-                            (Value::SingleQuotedString("John".to_string())).with_empty_span()
-                        ),
-                        Expr::Value(
-                            (Value::Number("30".parse().unwrap(), false)).with_empty_span()
-                        ),
-                    ]),
+                    expr: Expr::Tuple(
+                        vec![
+                            Expr::Value(Box::new(
+                                (Value::SingleQuotedString("John".into())).with_empty_span()
+                            )),
+                            Expr::Value(Box::new(number("30").with_empty_span())),
+                        ]
+                        .into()
+                    ),
                     alias: Some(Ident::new("c1"))
                 },
                 ExprWithAlias {
-                    expr: Expr::Tuple(vec![
-                        Expr::Value(
-                            (Value::SingleQuotedString("Mike".to_string())).with_empty_span()
-                        ),
-                        Expr::Value(
-                            (Value::Number("40".parse().unwrap(), false)).with_empty_span()
-                        ),
-                    ]),
+                    expr: Expr::Tuple(
+                        vec![
+                            Expr::Value(Box::new(
+                                (Value::SingleQuotedString("Mike".into())).with_empty_span()
+                            )),
+                            Expr::Value(Box::new(number("40").with_empty_span())),
+                        ]
+                        .into()
+                    ),
                     alias: Some(Ident::new("c2"))
                 },
             ]),
@@ -11058,7 +11465,7 @@ fn parse_unpivot_table() {
             name: ObjectName::from(vec![Ident::new("sales")]),
             alias: Some(TableAlias {
                 name: Ident::new("s"),
-                columns: vec![],
+                columns: vec![].into(),
                 implicit: false,
             }),
             args: None,
@@ -11072,12 +11479,12 @@ fn parse_unpivot_table() {
             index_hints: vec![],
         }),
         null_inclusion: None,
-        value: Expr::Identifier(Ident::new("quantity")),
+        value: Expr::Identifier(Box::new(Ident::new("quantity"))),
         name: Ident::new("quarter"),
         columns: ["Q1", "Q2", "Q3", "Q4"]
             .into_iter()
             .map(|col| ExprWithAlias {
-                expr: Expr::Identifier(Ident::new(col)),
+                expr: Expr::Identifier(Box::new(Ident::new(col))),
                 alias: None,
             })
             .collect(),
@@ -11158,24 +11565,24 @@ fn parse_unpivot_table() {
             *columns,
             vec![
                 ExprWithAlias {
-                    expr: Expr::Identifier(Ident::new("Q1")),
+                    expr: Expr::Identifier(Box::new(Ident::new("Q1"))),
                     alias: Some(Ident::new("Quater1")),
                 },
                 ExprWithAlias {
-                    expr: Expr::Identifier(Ident::new("Q2")),
+                    expr: Expr::Identifier(Box::new(Ident::new("Q2"))),
                     alias: Some(Ident::new("Quater2")),
                 },
                 ExprWithAlias {
-                    expr: Expr::Identifier(Ident::new("Q3")),
+                    expr: Expr::Identifier(Box::new(Ident::new("Q3"))),
                     alias: Some(Ident::new("Quater3")),
                 },
                 ExprWithAlias {
-                    expr: Expr::Identifier(Ident::new("Q4")),
+                    expr: Expr::Identifier(Box::new(Ident::new("Q4"))),
                     alias: Some(Ident::new("Quater4")),
                 },
             ]
         );
-        assert_eq!(*value, Expr::Identifier(Ident::new("quantity")));
+        assert_eq!(*value, Expr::Identifier(Box::new(Ident::new("quantity"))));
     }
 
     assert_eq!(
@@ -11199,27 +11606,36 @@ fn parse_unpivot_table() {
             *columns,
             vec![
                 ExprWithAlias {
-                    expr: Expr::Tuple(vec![
-                        Expr::Identifier(Ident::new("Q1")),
-                        Expr::Identifier(Ident::new("Q2")),
-                    ]),
+                    expr: Expr::Tuple(
+                        vec![
+                            Expr::Identifier(Box::new(Ident::new("Q1"))),
+                            Expr::Identifier(Box::new(Ident::new("Q2"))),
+                        ]
+                        .into()
+                    ),
                     alias: Some(Ident::new("H1")),
                 },
                 ExprWithAlias {
-                    expr: Expr::Tuple(vec![
-                        Expr::Identifier(Ident::new("Q3")),
-                        Expr::Identifier(Ident::new("Q4")),
-                    ]),
+                    expr: Expr::Tuple(
+                        vec![
+                            Expr::Identifier(Box::new(Ident::new("Q3"))),
+                            Expr::Identifier(Box::new(Ident::new("Q4"))),
+                        ]
+                        .into()
+                    ),
                     alias: Some(Ident::new("H2")),
                 },
             ]
         );
         assert_eq!(
             *value,
-            Expr::Tuple(vec![
-                Expr::Identifier(Ident::new("first_quarter")),
-                Expr::Identifier(Ident::new("second_quarter")),
-            ])
+            Expr::Tuple(
+                vec![
+                    Expr::Identifier(Box::new(Ident::new("first_quarter"))),
+                    Expr::Identifier(Box::new(Ident::new("second_quarter"))),
+                ]
+                .into()
+            )
         );
     }
 
@@ -11244,17 +11660,31 @@ fn parse_unpivot_table() {
             *columns,
             vec![
                 ExprWithAlias {
-                    expr: Expr::Tuple(vec![
-                        Expr::CompoundIdentifier(vec![Ident::new("sales"), Ident::new("Q1"),]),
-                        Expr::CompoundIdentifier(vec![Ident::new("sales"), Ident::new("Q2"),]),
-                    ]),
+                    expr: Expr::Tuple(
+                        vec![
+                            Expr::CompoundIdentifier(
+                                vec![Ident::new("sales"), Ident::new("Q1"),].into()
+                            ),
+                            Expr::CompoundIdentifier(
+                                vec![Ident::new("sales"), Ident::new("Q2"),].into()
+                            ),
+                        ]
+                        .into()
+                    ),
                     alias: Some(Ident::new("H1")),
                 },
                 ExprWithAlias {
-                    expr: Expr::Tuple(vec![
-                        Expr::CompoundIdentifier(vec![Ident::new("sales"), Ident::new("Q3"),]),
-                        Expr::CompoundIdentifier(vec![Ident::new("sales"), Ident::new("Q4"),]),
-                    ]),
+                    expr: Expr::Tuple(
+                        vec![
+                            Expr::CompoundIdentifier(
+                                vec![Ident::new("sales"), Ident::new("Q3"),].into()
+                            ),
+                            Expr::CompoundIdentifier(
+                                vec![Ident::new("sales"), Ident::new("Q4"),].into()
+                            ),
+                        ]
+                        .into()
+                    ),
                     alias: Some(Ident::new("H2")),
                 },
             ]
@@ -11314,12 +11744,12 @@ fn parse_select_table_with_index_hints() {
         select.from,
         vec![TableWithJoins {
             relation: TableFactor::Table {
-                name: ObjectName(vec![sqlparser::ast::ObjectNamePart::Identifier(
-                    Ident::new("T")
-                )]),
+                name: ObjectName(
+                    vec![sqlparser::ast::ObjectNamePart::Identifier(Ident::new("T"))].into()
+                ),
                 alias: Some(TableAlias {
                     name: Ident::new("USE"),
-                    columns: vec![],
+                    columns: vec![].into(),
                     implicit: true,
                 }),
                 args: None,
@@ -11332,7 +11762,7 @@ fn parse_select_table_with_index_hints() {
                 sample: None,
                 index_hints: vec![],
             },
-            joins: vec![],
+            joins: AstVec::new(),
         }]
     );
 }
@@ -11353,7 +11783,7 @@ fn parse_pivot_unpivot_table() {
                     name: ObjectName::from(vec![Ident::new("census")]),
                     alias: Some(TableAlias {
                         name: Ident::new("c"),
-                        columns: vec![],
+                        columns: vec![].into(),
                         implicit: false,
                     }),
                     args: None,
@@ -11367,47 +11797,48 @@ fn parse_pivot_unpivot_table() {
                     index_hints: vec![],
                 }),
                 null_inclusion: None,
-                value: Expr::Identifier(Ident::new("population")),
+                value: Expr::Identifier(Box::new(Ident::new("population"))),
                 name: Ident::new("year"),
                 columns: ["population_2000", "population_2010"]
                     .into_iter()
                     .map(|col| ExprWithAlias {
-                        expr: Expr::Identifier(Ident::new(col)),
+                        expr: Expr::Identifier(Box::new(Ident::new(col))),
                         alias: None,
                     })
                     .collect(),
                 alias: Some(TableAlias {
                     name: Ident::new("u"),
-                    columns: vec![],
+                    columns: vec![].into(),
                     implicit: false,
                 }),
             }),
             xml: false,
             aggregate_functions: vec![ExprWithAlias {
-                expr: call("sum", [Expr::Identifier(Ident::new("population"))]),
+                expr: call(
+                    "sum",
+                    [Expr::Identifier(Box::new(Ident::new("population")))]
+                ),
                 alias: None
             }],
-            value_column: vec![Expr::Identifier(Ident::new("year"))],
+            value_column: vec![Expr::Identifier(Box::new(Ident::new("year")))],
             value_source: PivotValueSource::List(vec![
                 ExprWithAlias {
-                    expr: Expr::Value(
-                        (Value::SingleQuotedString("population_2000".to_string()))
-                            .with_empty_span()
-                    ),
+                    expr: Expr::Value(Box::new(
+                        (Value::SingleQuotedString("population_2000".into())).with_empty_span()
+                    )),
                     alias: None
                 },
                 ExprWithAlias {
-                    expr: Expr::Value(
-                        (Value::SingleQuotedString("population_2010".to_string()))
-                            .with_empty_span()
-                    ),
+                    expr: Expr::Value(Box::new(
+                        (Value::SingleQuotedString("population_2010".into())).with_empty_span()
+                    )),
                     alias: None
                 },
             ]),
             default_on_null: None,
             alias: Some(TableAlias {
                 name: Ident::new("p"),
-                columns: vec![],
+                columns: vec![].into(),
                 implicit: false,
             }),
         }
@@ -11766,14 +12197,15 @@ fn parse_call() {
     all_dialects().verified_stmt("CALL my_procedure");
     assert_eq!(
         verified_stmt("CALL my_procedure('a')"),
-        Statement::Call(Function {
+        Statement::Call(Box::new(Function {
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
             args: FunctionArguments::List(FunctionArgumentList {
                 duplicate_treatment: None,
                 args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
-                    (Value::SingleQuotedString("a".to_string())).with_empty_span()
-                )))],
+                    Box::new((Value::SingleQuotedString("a".into())).with_empty_span())
+                )))]
+                .into(),
                 clauses: vec![],
             }),
             name: ObjectName::from(vec![Ident::new("my_procedure")]),
@@ -11782,29 +12214,33 @@ fn parse_call() {
             nth_value_order: None,
             over: None,
             within_group: vec![],
-        })
+        }))
     );
 }
 
 #[test]
 fn parse_execute_stored_procedure() {
     let expected = Statement::Execute {
-        execute_token: AttachedToken::empty(),
-        name: Some(ObjectName::from(vec![
+        execute_token: Box::new(AttachedToken::empty()),
+        name: Some(Box::new(ObjectName::from(vec![
             Ident {
-                value: "my_schema".to_string(),
+                value: "my_schema".into(),
                 quote_style: None,
                 span: Span::empty(),
             },
             Ident {
-                value: "my_stored_procedure".to_string(),
+                value: "my_stored_procedure".into(),
                 quote_style: None,
                 span: Span::empty(),
             },
-        ])),
+        ]))),
         parameters: vec![
-            Expr::Value((Value::NationalStringLiteral("param1".to_string())).with_empty_span()),
-            Expr::Value((Value::NationalStringLiteral("param2".to_string())).with_empty_span()),
+            Expr::Value(Box::new(
+                (Value::NationalStringLiteral("param1".to_string())).with_empty_span(),
+            )),
+            Expr::Value(Box::new(
+                (Value::NationalStringLiteral("param2".to_string())).with_empty_span(),
+            )),
         ],
         has_parentheses: false,
         immediate: false,
@@ -11915,7 +12351,7 @@ fn parse_unload() {
     let unload = verified_stmt("UNLOAD(SELECT cola FROM tab) TO 's3://...' WITH (format = 'AVRO')");
     assert_eq!(
         unload,
-        Statement::Unload {
+        Statement::Unload(Box::new(UnloadStatement {
             unload_token: AttachedToken::empty(),
             query: Some(Box::new(Query {
                 body: Box::new(SetExpr::Select(Box::new(Select {
@@ -11923,14 +12359,19 @@ fn parse_unload() {
                     distinct: None,
                     top: None,
                     top_before_distinct: false,
-                    projection: vec![UnnamedExpr(Expr::Identifier(Ident::new("cola"))),],
+                    projection: vec![UnnamedExpr {
+                        expr: Expr::Identifier(Box::new(Ident::new("cola"))),
+                        comments: Comments::default(),
+                    },]
+                    .into(),
                     into: None,
                     from: vec![TableWithJoins {
                         relation: table_from_name(ObjectName::from(vec![Ident::new("tab")])),
-                        joins: vec![],
-                    }],
+                        joins: AstVec::new(),
+                    }]
+                    .into(),
                     selection: None,
-                    group_by: GroupByExpr::Expressions(vec![], vec![]),
+                    group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
                     having: None,
                     qualify: None,
                     named_window: vec![],
@@ -11940,29 +12381,30 @@ fn parse_unload() {
                 with: None,
                 limit_clause: None,
                 fetch: None,
-                locks: vec![],
+                locks: AstVec::new(),
                 for_clause: None,
                 order_by: None,
+                comments: Comments::default(),
             })),
             to: Ident {
-                value: "s3://...".to_string(),
+                value: "s3://...".into(),
                 quote_style: Some('\''),
                 span: Span::empty(),
             },
             with: vec![SqlOption::KeyValue {
                 key: Ident {
-                    value: "format".to_string(),
+                    value: "format".into(),
                     quote_style: None,
                     span: Span::empty(),
                 },
-                value: Expr::Value(
-                    (Value::SingleQuotedString("AVRO".to_string())).with_empty_span()
-                )
+                value: Expr::Value(Box::new(
+                    (Value::SingleQuotedString("AVRO".into())).with_empty_span()
+                ))
             }],
             query_text: None,
             auth: None,
             options: vec![],
-        }
+        }))
     );
 
     one_statement_parses_to(
@@ -12078,7 +12520,7 @@ fn parse_unload() {
 fn test_savepoint() {
     match verified_stmt("SAVEPOINT test1") {
         Statement::Savepoint { name, .. } => {
-            assert_eq!(Ident::new("test1"), name);
+            assert_eq!(Ident::new("test1"), *name);
         }
         _ => unreachable!(),
     }
@@ -12088,7 +12530,7 @@ fn test_savepoint() {
 fn test_release_savepoint() {
     match verified_stmt("RELEASE SAVEPOINT test1") {
         Statement::ReleaseSavepoint { name, .. } => {
-            assert_eq!(Ident::new("test1"), name);
+            assert_eq!(Ident::new("test1"), *name);
         }
         _ => unreachable!(),
     }
@@ -12116,11 +12558,12 @@ fn test_parse_inline_comment() {
     let sql =
         "CREATE TABLE t0 (id INT COMMENT 'comment without equal') COMMENT = 'comment with equal'";
     match all_dialects().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable {
-            columns,
-            table_options,
-            ..
-        }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable {
+                columns,
+                table_options,
+                ..
+            } = Box::into_owned(create_table);
             assert_eq!(
                 columns,
                 vec![ColumnDef {
@@ -12129,7 +12572,8 @@ fn test_parse_inline_comment() {
                     options: vec![ColumnOptionDef {
                         name: None,
                         option: Comment("comment without equal".to_string()),
-                    }]
+                    }],
+                    comments: Comments::default(),
                 }]
             );
             assert_eq!(
@@ -12164,35 +12608,46 @@ fn parse_connect_by() {
         top: None,
         top_before_distinct: false,
         projection: vec![
-            SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("employee_id"))),
-            SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("manager_id"))),
-            SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("title"))),
-        ],
+            SelectItem::UnnamedExpr {
+                expr: Expr::Identifier(Box::new(Ident::new("employee_id"))),
+                comments: Comments::default(),
+            },
+            SelectItem::UnnamedExpr {
+                expr: Expr::Identifier(Box::new(Ident::new("manager_id"))),
+                comments: Comments::default(),
+            },
+            SelectItem::UnnamedExpr {
+                expr: Expr::Identifier(Box::new(Ident::new("title"))),
+                comments: Comments::default(),
+            },
+        ]
+        .into(),
         from: vec![TableWithJoins {
             relation: table_from_name(ObjectName::from(vec![Ident::new("employees")])),
-            joins: vec![],
-        }],
+            joins: AstVec::new(),
+        }]
+        .into(),
         into: None,
         selection: None,
-        group_by: GroupByExpr::Expressions(vec![], vec![]),
+        group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
         having: None,
         qualify: None,
         named_window: vec![],
         connect_by: Some(
             ConnectBy {
                 condition: Some(Expr::BinaryOp {
-                    left: Box::new(Expr::Identifier(Ident::new("title"))),
+                    left: Box::new(Expr::Identifier(Box::new(Ident::new("title")))),
                     op: BinaryOperator::Eq,
-                    right: Box::new(Expr::Value(
-                        Value::SingleQuotedString("president".to_owned()).with_empty_span(),
-                    )),
+                    right: Box::new(Expr::Value(Box::new(
+                        Value::SingleQuotedString("president".into()).with_empty_span(),
+                    ))),
                 }),
                 nocycle: false,
                 relationships: vec![Expr::BinaryOp {
-                    left: Box::new(Expr::Identifier(Ident::new("manager_id"))),
+                    left: Box::new(Expr::Identifier(Box::new(Ident::new("manager_id")))),
                     op: BinaryOperator::Eq,
-                    right: Box::new(Expr::Prior(Box::new(Expr::Identifier(Ident::new(
-                        "employee_id",
+                    right: Box::new(Expr::Prior(Box::new(Expr::Identifier(Box::new(
+                        Ident::new("employee_id"),
                     ))))),
                 }],
             }
@@ -12242,42 +12697,53 @@ fn parse_connect_by() {
             top: None,
             top_before_distinct: false,
             projection: vec![
-                SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("employee_id"))),
-                SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("manager_id"))),
-                SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("title"))),
-            ],
+                SelectItem::UnnamedExpr {
+                    expr: Expr::Identifier(Box::new(Ident::new("employee_id"))),
+                    comments: Comments::default(),
+                },
+                SelectItem::UnnamedExpr {
+                    expr: Expr::Identifier(Box::new(Ident::new("manager_id"))),
+                    comments: Comments::default(),
+                },
+                SelectItem::UnnamedExpr {
+                    expr: Expr::Identifier(Box::new(Ident::new("title"))),
+                    comments: Comments::default(),
+                },
+            ]
+            .into(),
             from: vec![TableWithJoins {
                 relation: table_from_name(ObjectName::from(vec![Ident::new("employees")])),
-                joins: vec![],
-            }],
+                joins: AstVec::new(),
+            }]
+            .into(),
             into: None,
             selection: Some(
                 Expr::BinaryOp {
-                    left: Box::new(Expr::Identifier(Ident::new("employee_id"))),
+                    left: Box::new(Expr::Identifier(Box::new(Ident::new("employee_id")))),
                     op: BinaryOperator::NotEq,
                     right: Box::new(Expr::value(number("42"))),
                 }
                 .into()
             ),
-            group_by: GroupByExpr::Expressions(vec![], vec![]),
+            group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
             having: None,
             qualify: None,
             named_window: vec![],
             connect_by: Some(
                 ConnectBy {
                     condition: Some(Expr::BinaryOp {
-                        left: Box::new(Expr::Identifier(Ident::new("title"))),
+                        left: Box::new(Expr::Identifier(Box::new(Ident::new("title")))),
                         op: BinaryOperator::Eq,
-                        right: Box::new(Expr::Value(
-                            (Value::SingleQuotedString("president".to_owned(),)).with_empty_span()
-                        )),
+                        right: Box::new(Expr::Value(Box::new(
+                            (Value::SingleQuotedString("president".into(),)).with_empty_span()
+                        ))),
                     }),
                     nocycle: false,
                     relationships: vec![Expr::BinaryOp {
-                        left: Box::new(Expr::Identifier(Ident::new("manager_id"))),
+                        left: Box::new(Expr::Identifier(Box::new(Ident::new("manager_id")))),
                         op: BinaryOperator::Eq,
-                        right: Box::new(Expr::Prior(Box::new(Expr::Identifier(Ident::new(
-                            "employee_id",
+                        right: Box::new(Expr::Prior(Box::new(Expr::Identifier(Box::new(
+                            Ident::new("employee_id",)
                         ))))),
                     }],
                 }
@@ -12304,9 +12770,10 @@ fn parse_connect_by() {
         all_dialects()
             .verified_only_select("SELECT prior FROM some_table")
             .projection,
-        vec![SelectItem::UnnamedExpr(Expr::Identifier(Ident::new(
-            "prior"
-        )))]
+        vec![SelectItem::UnnamedExpr {
+            expr: Expr::Identifier(Box::new(Ident::new("prior"))),
+            comments: Comments::default(),
+        }]
     );
 }
 
@@ -12331,44 +12798,49 @@ fn test_selective_aggregation() {
     assert_eq!(
         testing_dialects.verified_only_select(sql).projection,
         vec![
-            SelectItem::UnnamedExpr(Expr::Function(Function {
-                name: ObjectName::from(vec![Ident::new("array_agg")]),
-                uses_odbc_syntax: false,
-                parameters: FunctionArguments::None,
-                args: FunctionArguments::List(FunctionArgumentList {
-                    duplicate_treatment: None,
-                    args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
-                        Expr::Identifier(Ident::new("name"))
-                    ))],
-                    clauses: vec![],
-                }),
-                filter: Some(Box::new(Expr::IsNotNull {
-                    expr: Box::new(Expr::Identifier(Ident::new("name"))),
-                    suffix_token: AttachedToken::empty(),
+            SelectItem::UnnamedExpr {
+                expr: Expr::Function(Box::new(Function {
+                    name: ObjectName::from(vec![Ident::new("array_agg")]),
+                    uses_odbc_syntax: false,
+                    parameters: FunctionArguments::None,
+                    args: FunctionArguments::List(FunctionArgumentList {
+                        duplicate_treatment: None,
+                        args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
+                            Expr::Identifier(Box::new(Ident::new("name")))
+                        ))]
+                        .into(),
+                        clauses: vec![],
+                    }),
+                    filter: Some(Box::new(Expr::IsNotNull {
+                        expr: Box::new(Expr::Identifier(Box::new(Ident::new("name")))),
+                        suffix_token: Box::new(AttachedToken::empty()),
+                    })),
+                    nth_value_order: None,
+                    null_treatment: None,
+                    over: None,
+                    within_group: vec![],
                 })),
-                nth_value_order: None,
-                null_treatment: None,
-                over: None,
-                within_group: vec![],
-            })),
+                comments: Comments::default(),
+            },
             SelectItem::ExprWithAlias {
-                expr: Expr::Function(Function {
+                expr: Expr::Function(Box::new(Function {
                     name: ObjectName::from(vec![Ident::new("array_agg")]),
                     uses_odbc_syntax: false,
                     parameters: FunctionArguments::None,
                     args: FunctionArguments::List(FunctionArgumentList {
                         duplicate_treatment: None,
                         args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
-                            Expr::Identifier(Ident::new("name"))
-                        ))],
+                            Expr::Identifier(Box::new(Ident::new("name")))
+                        ))]
+                        .into(),
                         clauses: vec![],
                     }),
                     filter: Some(Box::new(Expr::Like {
                         negated: false,
-                        expr: Box::new(Expr::Identifier(Ident::new("name"))),
-                        pattern: Box::new(Expr::Value(
-                            (Value::SingleQuotedString("a%".to_owned())).with_empty_span()
-                        )),
+                        expr: Box::new(Expr::Identifier(Box::new(Ident::new("name")))),
+                        pattern: Box::new(Expr::Value(Box::new(
+                            (Value::SingleQuotedString("a%".into())).with_empty_span()
+                        ))),
                         escape_char: None,
                         any: false,
                     })),
@@ -12376,8 +12848,9 @@ fn test_selective_aggregation() {
                     nth_value_order: None,
                     over: None,
                     within_group: vec![]
-                }),
-                alias: Ident::new("agg2")
+                })),
+                alias: Ident::new("agg2"),
+                comments: Comments::default()
             },
         ]
     )
@@ -12398,13 +12871,14 @@ fn test_group_by_grouping_sets() {
         GroupByExpr::Expressions(
             vec![Expr::GroupingSets(vec![
                 vec![
-                    Expr::Identifier(Ident::new("city")),
-                    Expr::Identifier(Ident::new("car_model"))
+                    Expr::Identifier(Box::new(Ident::new("city"))),
+                    Expr::Identifier(Box::new(Ident::new("car_model")))
                 ],
-                vec![Expr::Identifier(Ident::new("city")),],
-                vec![Expr::Identifier(Ident::new("car_model"))],
+                vec![Expr::Identifier(Box::new(Ident::new("city"))),],
+                vec![Expr::Identifier(Box::new(Ident::new("car_model")))],
                 vec![]
-            ])],
+            ])]
+            .into(),
             vec![]
         )
     );
@@ -12472,15 +12946,15 @@ fn insert_into_with_parentheses() {
 #[test]
 fn parse_odbc_scalar_function() {
     let select = verified_only_select("SELECT {fn my_func(1, 2)}");
-    let Expr::Function(Function {
+    let Expr::Function(function) = expr_from_projection(only(&select.projection)) else {
+        unreachable!("expected function")
+    };
+    let Function {
         name,
         uses_odbc_syntax,
         args,
         ..
-    }) = expr_from_projection(only(&select.projection))
-    else {
-        unreachable!("expected function")
-    };
+    } = &**function;
     assert_eq!(name, &ObjectName::from(vec![Ident::new("my_func")]));
     assert!(uses_odbc_syntax);
     matches!(args, FunctionArguments::List(l) if l.args.len() == 2);
@@ -12518,7 +12992,7 @@ fn test_group_by_nothing() {
         .verified_only_select("SELECT count(1) FROM t GROUP BY ()");
     {
         assert_eq!(
-            GroupByExpr::Expressions(vec![Expr::Tuple(vec![])], vec![]),
+            GroupByExpr::Expressions(vec![Expr::Tuple(AstVec::new())].into(), vec![]),
             group_by
         );
     }
@@ -12529,9 +13003,10 @@ fn test_group_by_nothing() {
         assert_eq!(
             GroupByExpr::Expressions(
                 vec![
-                    Identifier(Ident::new("name".to_string())),
-                    Expr::Tuple(vec![])
-                ],
+                    Identifier(Box::new(Ident::new("name".to_string()))),
+                    Expr::Tuple(AstVec::new())
+                ]
+                .into(),
                 vec![]
             ),
             group_by
@@ -12551,13 +13026,13 @@ fn test_extract_seconds_ok() {
             syntax: ExtractSyntax::From,
             expr: Box::new(Expr::Cast {
                 kind: CastKind::DoubleColon,
-                expr: Box::new(Expr::Value(
-                    (Value::SingleQuotedString("2 seconds".to_string())).with_empty_span()
-                )),
-                data_type: DataType::Interval {
+                expr: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("2 seconds".into())).with_empty_span()
+                ))),
+                data_type: Box::new(DataType::Interval {
                     fields: None,
                     precision: None
-                },
+                }),
                 format: None,
             }),
         }
@@ -12574,25 +13049,29 @@ fn test_extract_seconds_ok() {
             distinct: None,
             top: None,
             top_before_distinct: false,
-            projection: vec![UnnamedExpr(Expr::Extract {
-                field: Seconds,
-                syntax: ExtractSyntax::From,
-                expr: Box::new(Expr::Cast {
-                    kind: CastKind::DoubleColon,
-                    expr: Box::new(Expr::Value(
-                        (Value::SingleQuotedString("2 seconds".to_string())).with_empty_span(),
-                    )),
-                    data_type: DataType::Interval {
-                        fields: None,
-                        precision: None,
-                    },
-                    format: None,
-                }),
-            })],
+            projection: vec![UnnamedExpr {
+                expr: Expr::Extract {
+                    field: Seconds,
+                    syntax: ExtractSyntax::From,
+                    expr: Box::new(Expr::Cast {
+                        kind: CastKind::DoubleColon,
+                        expr: Box::new(Expr::Value(Box::new(
+                            (Value::SingleQuotedString("2 seconds".into())).with_empty_span(),
+                        ))),
+                        data_type: Box::new(DataType::Interval {
+                            fields: None,
+                            precision: None,
+                        }),
+                        format: None,
+                    }),
+                },
+                comments: Comments::default(),
+            }]
+            .into(),
             into: None,
-            from: vec![],
+            from: AstVec::new(),
             selection: None,
-            group_by: GroupByExpr::Expressions(vec![], vec![]),
+            group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
             having: None,
             qualify: None,
             named_window: vec![],
@@ -12602,8 +13081,9 @@ fn test_extract_seconds_ok() {
         order_by: None,
         limit_clause: None,
         fetch: None,
-        locks: vec![],
+        locks: AstVec::new(),
         for_clause: None,
+        comments: Comments::default(),
     }))];
 
     assert_eq!(actual_ast, expected_ast);
@@ -12617,21 +13097,21 @@ fn test_extract_seconds_single_quote_ok() {
     assert_eq!(
         stmt,
         Expr::Extract {
-            field: DateTimeField::Custom(Ident {
-                value: "seconds".to_string(),
+            field: DateTimeField::Custom(Box::new(Ident {
+                value: "seconds".into(),
                 quote_style: Some('\''),
                 span: Span::empty(),
-            }),
+            })),
             syntax: ExtractSyntax::From,
             expr: Box::new(Expr::Cast {
                 kind: CastKind::DoubleColon,
-                expr: Box::new(Expr::Value(
-                    (Value::SingleQuotedString("2 seconds".to_string())).with_empty_span()
-                )),
-                data_type: DataType::Interval {
+                expr: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("2 seconds".into())).with_empty_span()
+                ))),
+                data_type: Box::new(DataType::Interval {
                     fields: None,
                     precision: None
-                },
+                }),
                 format: None,
             }),
         }
@@ -12664,11 +13144,15 @@ fn parse_explain_with_option_list() {
         Some(vec![
             UtilityOption {
                 name: Ident::new("analyze"),
-                arg: Some(Expr::Value((Value::Boolean(false)).with_empty_span())),
+                arg: Some(Expr::Value(Box::new(
+                    (Value::Boolean(false)).with_empty_span(),
+                ))),
             },
             UtilityOption {
                 name: Ident::new("verbose"),
-                arg: Some(Expr::Value((Value::Boolean(true)).with_empty_span())),
+                arg: Some(Expr::Value(Box::new(
+                    (Value::Boolean(true)).with_empty_span(),
+                ))),
             },
         ]),
     );
@@ -12682,11 +13166,11 @@ fn parse_explain_with_option_list() {
         Some(vec![
             UtilityOption {
                 name: Ident::new("analyze"),
-                arg: Some(Expr::Identifier(Ident::new("on"))),
+                arg: Some(Expr::Identifier(Box::new(Ident::new("on")))),
             },
             UtilityOption {
                 name: Ident::new("verbose"),
-                arg: Some(Expr::Identifier(Ident::new("off"))),
+                arg: Some(Expr::Identifier(Box::new(Ident::new("off")))),
             },
         ]),
     );
@@ -12700,21 +13184,21 @@ fn parse_explain_with_option_list() {
         Some(vec![
             UtilityOption {
                 name: Ident::new("format1"),
-                arg: Some(Expr::Identifier(Ident::new("text"))),
+                arg: Some(Expr::Identifier(Box::new(Ident::new("text")))),
             },
             UtilityOption {
                 name: Ident::new("format2"),
-                arg: Some(Expr::Value(
-                    (Value::SingleQuotedString("JSON".to_string())).with_empty_span(),
-                )),
+                arg: Some(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("JSON".into())).with_empty_span(),
+                ))),
             },
             UtilityOption {
                 name: Ident::new("format3"),
-                arg: Some(Expr::Identifier(Ident::with_quote('"', "XML"))),
+                arg: Some(Expr::Identifier(Box::new(Ident::with_quote('"', "XML")))),
             },
             UtilityOption {
                 name: Ident::new("format4"),
-                arg: Some(Expr::Identifier(Ident::new("yaml"))),
+                arg: Some(Expr::Identifier(Box::new(Ident::new("yaml")))),
             },
         ]),
     );
@@ -12728,26 +13212,20 @@ fn parse_explain_with_option_list() {
         Some(vec![
             UtilityOption {
                 name: Ident::new("num1"),
-                arg: Some(Expr::Value(
-                    (Value::Number("10".parse().unwrap(), false)).with_empty_span(),
-                )),
+                arg: Some(Expr::Value(Box::new(number("10").with_empty_span()))),
             },
             UtilityOption {
                 name: Ident::new("num2"),
                 arg: Some(Expr::UnaryOp {
                     op: UnaryOperator::Plus,
-                    expr: Box::new(Expr::Value(
-                        (Value::Number("10.1".parse().unwrap(), false)).with_empty_span(),
-                    )),
+                    expr: Box::new(Expr::Value(Box::new(number("10.1").with_empty_span()))),
                 }),
             },
             UtilityOption {
                 name: Ident::new("num3"),
                 arg: Some(Expr::UnaryOp {
                     op: UnaryOperator::Minus,
-                    expr: Box::new(Expr::Value(
-                        (Value::Number("10.2".parse().unwrap(), false)).with_empty_span(),
-                    )),
+                    expr: Box::new(Expr::Value(Box::new(number("10.2").with_empty_span()))),
                 }),
             },
         ]),
@@ -12760,23 +13238,23 @@ fn parse_explain_with_option_list() {
         },
         UtilityOption {
             name: Ident::new("verbose"),
-            arg: Some(Expr::Value((Value::Boolean(true)).with_empty_span())),
+            arg: Some(Expr::Value(Box::new(
+                (Value::Boolean(true)).with_empty_span(),
+            ))),
         },
         UtilityOption {
             name: Ident::new("wal"),
-            arg: Some(Expr::Identifier(Ident::new("off"))),
+            arg: Some(Expr::Identifier(Box::new(Ident::new("off")))),
         },
         UtilityOption {
             name: Ident::new("format"),
-            arg: Some(Expr::Identifier(Ident::new("yaml"))),
+            arg: Some(Expr::Identifier(Box::new(Ident::new("yaml")))),
         },
         UtilityOption {
             name: Ident::new("user_def_num"),
             arg: Some(Expr::UnaryOp {
                 op: UnaryOperator::Minus,
-                expr: Box::new(Expr::Value(
-                    (Value::Number("100.1".parse().unwrap(), false)).with_empty_span(),
-                )),
+                expr: Box::new(Expr::Value(Box::new(number("100.1").with_empty_span()))),
             }),
         },
     ];
@@ -12799,14 +13277,15 @@ fn test_create_policy() {
                WITH CHECK (1 = 1)";
 
     match all_dialects().verified_stmt(sql) {
-        Statement::CreatePolicy {
-            name,
-            table_name,
-            to,
-            using,
-            with_check,
-            ..
-        } => {
+        Statement::CreatePolicy(create_policy) => {
+            let CreatePolicy {
+                name,
+                table_name,
+                to,
+                using,
+                with_check,
+                ..
+            } = Box::into_owned(create_policy);
             assert_eq!(name.to_string(), "my_policy");
             assert_eq!(table_name.to_string(), "my_table");
             assert_eq!(
@@ -12819,23 +13298,17 @@ fn test_create_policy() {
             assert_eq!(
                 using,
                 Some(Expr::BinaryOp {
-                    left: Box::new(Expr::Identifier(Ident::new("c0"))),
+                    left: Box::new(Expr::Identifier(Box::new(Ident::new("c0")))),
                     op: BinaryOperator::Eq,
-                    right: Box::new(Expr::Value(
-                        (Value::Number("1".parse().unwrap(), false)).with_empty_span()
-                    )),
+                    right: Box::new(Expr::Value(Box::new(number("1").with_empty_span()))),
                 })
             );
             assert_eq!(
                 with_check,
                 Some(Expr::BinaryOp {
-                    left: Box::new(Expr::Value(
-                        (Value::Number("1".parse().unwrap(), false)).with_empty_span()
-                    )),
+                    left: Box::new(Expr::Value(Box::new(number("1").with_empty_span()))),
                     op: BinaryOperator::Eq,
-                    right: Box::new(Expr::Value(
-                        (Value::Number("1".parse().unwrap(), false)).with_empty_span()
-                    )),
+                    right: Box::new(Expr::Value(Box::new(number("1").with_empty_span()))),
                 })
             );
         }
@@ -13154,7 +13627,7 @@ fn parse_listen_channel() {
 
     match dialects.verified_stmt("LISTEN test1") {
         Statement::LISTEN { channel, .. } => {
-            assert_eq!(Ident::new("test1"), channel);
+            assert_eq!(Ident::new("test1"), *channel);
         }
         _ => unreachable!(),
     };
@@ -13178,14 +13651,14 @@ fn parse_unlisten_channel() {
 
     match dialects.verified_stmt("UNLISTEN test1") {
         Statement::UNLISTEN { channel, .. } => {
-            assert_eq!(Ident::new("test1"), channel);
+            assert_eq!(Ident::new("test1"), *channel);
         }
         _ => unreachable!(),
     };
 
     match dialects.verified_stmt("UNLISTEN *") {
         Statement::UNLISTEN { channel, .. } => {
-            assert_eq!(Ident::new("*"), channel);
+            assert_eq!(Ident::new("*"), *channel);
         }
         _ => unreachable!(),
     };
@@ -13211,7 +13684,7 @@ fn parse_notify_channel() {
         Statement::NOTIFY {
             channel, payload, ..
         } => {
-            assert_eq!(Ident::new("test1"), channel);
+            assert_eq!(Ident::new("test1"), *channel);
             assert_eq!(payload, None);
         }
         _ => unreachable!(),
@@ -13223,7 +13696,7 @@ fn parse_notify_channel() {
             payload: Some(payload),
             ..
         } => {
-            assert_eq!(Ident::new("test1"), channel);
+            assert_eq!(Ident::new("test1"), *channel);
             assert_eq!("this is a test notification", payload);
         }
         _ => unreachable!(),
@@ -13261,21 +13734,24 @@ fn parse_factorial_operator() {
     let Select { projection, .. } = dialects.verified_only_select(sql);
 
     for (i, expr) in [
-        Box::new(Expr::Identifier(Ident::new("a"))),
+        Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
         Box::new(Expr::Nested(Box::new(Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::new("b"))),
+            left: Box::new(Expr::Identifier(Box::new(Ident::new("b")))),
             op: BinaryOperator::Plus,
-            right: Box::new(Expr::Identifier(Ident::new("c"))),
+            right: Box::new(Expr::Identifier(Box::new(Ident::new("c")))),
         }))),
     ]
     .into_iter()
     .enumerate()
     {
         assert_eq!(
-            SelectItem::UnnamedExpr(Expr::UnaryOp {
-                op: UnaryOperator::PGPostfixFactorial,
-                expr
-            }),
+            SelectItem::UnnamedExpr {
+                expr: Expr::UnaryOp {
+                    op: UnaryOperator::PGPostfixFactorial,
+                    expr
+                },
+                comments: Comments::default(),
+            },
             projection[i]
         )
     }
@@ -13440,7 +13916,8 @@ fn test_reserved_keywords_for_identifiers() {
 fn parse_create_table_with_bit_types() {
     let sql = "CREATE TABLE t (a BIT, b BIT VARYING, c BIT(42), d BIT VARYING(43))";
     match verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { columns, .. } = Box::into_owned(create_table);
             assert_eq!(columns.len(), 4);
             assert_eq!(columns[0].data_type, DataType::Bit(None));
             assert_eq!(columns[0].to_string(), "a BIT");
@@ -13460,15 +13937,16 @@ fn parse_composite_access_expr() {
     assert_eq!(
         verified_expr("f(a).b"),
         Expr::CompoundFieldAccess {
-            root: Box::new(Expr::Function(Function {
+            root: Box::new(Expr::Function(Box::new(Function {
                 name: ObjectName::from(vec![Ident::new("f")]),
                 uses_odbc_syntax: false,
                 parameters: FunctionArguments::None,
                 args: FunctionArguments::List(FunctionArgumentList {
                     duplicate_treatment: None,
                     args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
-                        Expr::Identifier(Ident::new("a"))
-                    ))],
+                        Expr::Identifier(Box::new(Ident::new("a")))
+                    ))]
+                    .into(),
                     clauses: vec![],
                 }),
                 null_treatment: None,
@@ -13476,8 +13954,8 @@ fn parse_composite_access_expr() {
                 filter: None,
                 over: None,
                 within_group: vec![]
-            })),
-            access_chain: vec![AccessExpr::Dot(Expr::Identifier(Ident::new("b")))]
+            }))),
+            access_chain: vec![AccessExpr::Dot(Expr::Identifier(Box::new(Ident::new("b"))))]
         }
     );
 
@@ -13485,15 +13963,16 @@ fn parse_composite_access_expr() {
     assert_eq!(
         verified_expr("f(a).b.c"),
         Expr::CompoundFieldAccess {
-            root: Box::new(Expr::Function(Function {
+            root: Box::new(Expr::Function(Box::new(Function {
                 name: ObjectName::from(vec![Ident::new("f")]),
                 uses_odbc_syntax: false,
                 parameters: FunctionArguments::None,
                 args: FunctionArguments::List(FunctionArgumentList {
                     duplicate_treatment: None,
                     args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
-                        Expr::Identifier(Ident::new("a"))
-                    ))],
+                        Expr::Identifier(Box::new(Ident::new("a")))
+                    ))]
+                    .into(),
                     clauses: vec![],
                 }),
                 null_treatment: None,
@@ -13501,10 +13980,10 @@ fn parse_composite_access_expr() {
                 filter: None,
                 over: None,
                 within_group: vec![]
-            })),
+            }))),
             access_chain: vec![
-                AccessExpr::Dot(Expr::Identifier(Ident::new("b"))),
-                AccessExpr::Dot(Expr::Identifier(Ident::new("c"))),
+                AccessExpr::Dot(Expr::Identifier(Box::new(Ident::new("b")))),
+                AccessExpr::Dot(Expr::Identifier(Box::new(Ident::new("c")))),
             ]
         }
     );
@@ -13512,15 +13991,16 @@ fn parse_composite_access_expr() {
     // Composite Access in Select and Where Clauses
     let stmt = verified_only_select("SELECT f(a).b FROM t WHERE f(a).b IS NOT NULL");
     let expr = Expr::CompoundFieldAccess {
-        root: Box::new(Expr::Function(Function {
+        root: Box::new(Expr::Function(Box::new(Function {
             name: ObjectName::from(vec![Ident::new("f")]),
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
             args: FunctionArguments::List(FunctionArgumentList {
                 duplicate_treatment: None,
                 args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
-                    Expr::Identifier(Ident::new("a")),
-                ))],
+                    Expr::Identifier(Box::new(Ident::new("a"))),
+                ))]
+                .into(),
                 clauses: vec![],
             }),
             null_treatment: None,
@@ -13528,11 +14008,17 @@ fn parse_composite_access_expr() {
             filter: None,
             over: None,
             within_group: vec![],
-        })),
-        access_chain: vec![AccessExpr::Dot(Expr::Identifier(Ident::new("b")))],
+        }))),
+        access_chain: vec![AccessExpr::Dot(Expr::Identifier(Box::new(Ident::new("b"))))],
     };
 
-    assert_eq!(stmt.projection[0], SelectItem::UnnamedExpr(expr.clone()));
+    assert_eq!(
+        stmt.projection[0],
+        SelectItem::UnnamedExpr {
+            expr: expr.clone(),
+            comments: Comments::default(),
+        }
+    );
     assert!(matches!(
         Box::into_owned(stmt.selection.unwrap()),
         Expr::IsNotNull { expr: inner, .. } if *inner == expr
@@ -13547,7 +14033,8 @@ fn parse_composite_access_expr() {
 fn parse_create_table_with_enum_types() {
     let sql = "CREATE TABLE t0 (baz ENUM('a', 'b'))";
     match all_dialects().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { name, columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { name, columns, .. } = Box::into_owned(create_table);
             assert_eq!(name.to_string(), "t0");
             assert_eq!(
                 vec![ColumnDef {
@@ -13560,6 +14047,7 @@ fn parse_create_table_with_enum_types() {
                         None
                     ),
                     options: vec![],
+                    comments: Comments::default(),
                 }],
                 columns
             );
@@ -13628,7 +14116,7 @@ fn parse_case_statement() {
         stmt.when_blocks[0].condition
     );
     assert_eq!(2, stmt.when_blocks[0].statements().len());
-    assert_eq!(1, stmt.else_block.unwrap().statements().len());
+    assert_eq!(1, stmt.else_block.as_ref().unwrap().statements().len());
 
     verified_stmt(concat!(
         "CASE 1",
@@ -13688,15 +14176,15 @@ fn parse_if_statement() {
     let dialects = all_dialects_except(|d| d.is::<MsSqlDialect>());
 
     let sql = "IF 1 THEN SELECT 1; ELSEIF 2 THEN SELECT 2; ELSE SELECT 3; END IF";
-    let Statement::If(IfStatement {
+    let Statement::If(if_stmt) = dialects.verified_stmt(sql) else {
+        unreachable!()
+    };
+    let IfStatement {
         if_block,
         elseif_blocks,
         else_block,
         ..
-    }) = dialects.verified_stmt(sql)
-    else {
-        unreachable!()
-    };
+    } = Box::into_owned(if_stmt);
     assert_eq!(Some(Expr::value(number("1"))), if_block.condition);
     assert_eq!(Some(Expr::value(number("2"))), elseif_blocks[0].condition);
     assert_eq!(1, else_block.unwrap().statements().len());
@@ -13792,12 +14280,13 @@ fn test_conditional_statement_span() {
         .try_with_sql(sql)
         .unwrap();
     match parser.parse_statement().unwrap() {
-        Statement::If(IfStatement {
-            if_block,
-            elseif_blocks,
-            else_block,
-            ..
-        }) => {
+        Statement::If(if_stmt) => {
+            let IfStatement {
+                if_block,
+                elseif_blocks,
+                else_block,
+                ..
+            } = Box::into_owned(if_stmt);
             assert_eq!(
                 Span::new(Location::new(1, 1), Location::new(1, 21)),
                 if_block.span()
@@ -13999,90 +14488,90 @@ fn test_geometry_type() {
     let sql = "point '1,2'";
     assert_eq!(
         all_dialects_where(|d| d.supports_geometric_types()).verified_expr(sql),
-        Expr::TypedString(TypedString {
+        Expr::TypedString(Box::new(TypedString {
             data_type: DataType::GeometricType(GeometricTypeKind::Point),
             value: ValueWithSpan {
-                value: Value::SingleQuotedString("1,2".to_string()),
+                value: Value::SingleQuotedString("1,2".into()),
                 span: Span::empty(),
             },
             uses_odbc_syntax: false
-        })
+        }))
     );
 
     let sql = "line '1,2,3,4'";
     assert_eq!(
         all_dialects_where(|d| d.supports_geometric_types()).verified_expr(sql),
-        Expr::TypedString(TypedString {
+        Expr::TypedString(Box::new(TypedString {
             data_type: DataType::GeometricType(GeometricTypeKind::Line),
             value: ValueWithSpan {
-                value: Value::SingleQuotedString("1,2,3,4".to_string()),
+                value: Value::SingleQuotedString("1,2,3,4".into()),
                 span: Span::empty(),
             },
             uses_odbc_syntax: false
-        })
+        }))
     );
 
     let sql = "path '1,2,3,4'";
     assert_eq!(
         all_dialects_where(|d| d.supports_geometric_types()).verified_expr(sql),
-        Expr::TypedString(TypedString {
+        Expr::TypedString(Box::new(TypedString {
             data_type: DataType::GeometricType(GeometricTypeKind::GeometricPath),
             value: ValueWithSpan {
-                value: Value::SingleQuotedString("1,2,3,4".to_string()),
+                value: Value::SingleQuotedString("1,2,3,4".into()),
                 span: Span::empty(),
             },
             uses_odbc_syntax: false
-        })
+        }))
     );
     let sql = "box '1,2,3,4'";
     assert_eq!(
         all_dialects_where(|d| d.supports_geometric_types()).verified_expr(sql),
-        Expr::TypedString(TypedString {
+        Expr::TypedString(Box::new(TypedString {
             data_type: DataType::GeometricType(GeometricTypeKind::GeometricBox),
             value: ValueWithSpan {
-                value: Value::SingleQuotedString("1,2,3,4".to_string()),
+                value: Value::SingleQuotedString("1,2,3,4".into()),
                 span: Span::empty(),
             },
             uses_odbc_syntax: false
-        })
+        }))
     );
 
     let sql = "circle '1,2,3'";
     assert_eq!(
         all_dialects_where(|d| d.supports_geometric_types()).verified_expr(sql),
-        Expr::TypedString(TypedString {
+        Expr::TypedString(Box::new(TypedString {
             data_type: DataType::GeometricType(GeometricTypeKind::Circle),
             value: ValueWithSpan {
-                value: Value::SingleQuotedString("1,2,3".to_string()),
+                value: Value::SingleQuotedString("1,2,3".into()),
                 span: Span::empty(),
             },
             uses_odbc_syntax: false
-        })
+        }))
     );
 
     let sql = "polygon '1,2,3,4'";
     assert_eq!(
         all_dialects_where(|d| d.supports_geometric_types()).verified_expr(sql),
-        Expr::TypedString(TypedString {
+        Expr::TypedString(Box::new(TypedString {
             data_type: DataType::GeometricType(GeometricTypeKind::Polygon),
             value: ValueWithSpan {
-                value: Value::SingleQuotedString("1,2,3,4".to_string()),
+                value: Value::SingleQuotedString("1,2,3,4".into()),
                 span: Span::empty(),
             },
             uses_odbc_syntax: false
-        })
+        }))
     );
     let sql = "lseg '1,2,3,4'";
     assert_eq!(
         all_dialects_where(|d| d.supports_geometric_types()).verified_expr(sql),
-        Expr::TypedString(TypedString {
+        Expr::TypedString(Box::new(TypedString {
             data_type: DataType::GeometricType(GeometricTypeKind::LineSegment),
             value: ValueWithSpan {
-                value: Value::SingleQuotedString("1,2,3,4".to_string()),
+                value: Value::SingleQuotedString("1,2,3,4".into()),
                 span: Span::empty(),
             },
             uses_odbc_syntax: false
-        })
+        }))
     );
 }
 #[test]
@@ -14350,10 +14839,14 @@ fn parse_multiple_set_statements() -> Result<(), ParserError> {
     let stmt = dialects.verified_stmt("SET @a = 1, b = 2");
 
     match stmt {
-        Statement::Set(SetStatement {
-            inner: Set::MultipleAssignments { assignments },
-            ..
-        }) => {
+        Statement::Set(set) => {
+            let SetStatement {
+                inner: Set::MultipleAssignments { assignments },
+                ..
+            } = Box::into_owned(set)
+            else {
+                unreachable!()
+            };
             assert_eq!(
                 assignments,
                 vec![
@@ -14376,10 +14869,14 @@ fn parse_multiple_set_statements() -> Result<(), ParserError> {
     let stmt = dialects.verified_stmt("SET GLOBAL @a = 1, SESSION b = 2, LOCAL c = 3, d = 4");
 
     match stmt {
-        Statement::Set(SetStatement {
-            inner: Set::MultipleAssignments { assignments },
-            ..
-        }) => {
+        Statement::Set(set) => {
+            let SetStatement {
+                inner: Set::MultipleAssignments { assignments },
+                ..
+            } = Box::into_owned(set)
+            else {
+                unreachable!()
+            };
             assert_eq!(
                 assignments,
                 vec![
@@ -14415,14 +14912,20 @@ fn parse_multiple_set_statements() -> Result<(), ParserError> {
 #[test]
 fn parse_set_time_zone_alias() {
     match all_dialects().verified_stmt("SET TIME ZONE 'UTC'") {
-        Statement::Set(SetStatement {
-            inner: Set::SetTimeZone { local, value },
-            ..
-        }) => {
+        Statement::Set(set) => {
+            let SetStatement {
+                inner: Set::SetTimeZone { local, value },
+                ..
+            } = Box::into_owned(set)
+            else {
+                unreachable!()
+            };
             assert!(!local);
             assert_eq!(
                 value,
-                Expr::Value((Value::SingleQuotedString("UTC".into())).with_empty_span())
+                Expr::Value(Box::new(
+                    (Value::SingleQuotedString("UTC".into())).with_empty_span()
+                ))
             );
         }
         _ => unreachable!(),
@@ -14434,10 +14937,10 @@ fn parse_return() {
     let stmt = all_dialects().verified_stmt("RETURN");
     assert_eq!(
         stmt,
-        Statement::Return(ReturnStatement {
+        Statement::Return(Box::new(ReturnStatement {
             token: AttachedToken::empty(),
             value: None
-        })
+        }))
     );
 
     let _ = all_dialects().verified_stmt("RETURN 1");
@@ -14454,10 +14957,10 @@ fn test_open() {
     let stmt = all_dialects().verified_stmt(open_cursor);
     assert_eq!(
         stmt,
-        Statement::Open(OpenStatement {
+        Statement::Open(Box::new(OpenStatement {
             cursor_name: Ident::new("Employee_Cursor"),
             open_for: None,
-        })
+        }))
     );
 }
 
@@ -14531,14 +15034,14 @@ fn parse_create_procedure_with_language() {
             assert_eq!(params, None);
             assert_eq!(
                 language,
-                Some(Ident {
+                Some(Box::new(Ident {
                     value: "sql".into(),
                     quote_style: None,
                     span: Span {
                         start: Location::empty(),
                         end: Location::empty()
                     }
-                })
+                }))
             );
         }
         _ => unreachable!(),
@@ -14558,8 +15061,8 @@ fn parse_create_procedure_with_parameter_modes() {
             assert_eq!(or_alter, false);
             assert_eq!(name.to_string(), "test_proc");
             let fake_span = Span {
-                start: Location { line: 0, column: 0 },
-                end: Location { line: 0, column: 0 },
+                start: Location::empty(),
+                end: Location::empty(),
             };
             assert_eq!(
                 params,
@@ -14628,27 +15131,27 @@ fn parse_create_procedure_with_parameter_modes() {
                         name: Ident::new("a"),
                         data_type: DataType::Integer(None),
                         mode: Some(ArgMode::In),
-                        default: Some(Expr::Value((number("1")).with_empty_span())),
+                        default: Some(Expr::Value(Box::new((number("1")).with_empty_span()))),
                     },
                     ProcedureParam {
                         name: Ident::new("b"),
                         data_type: DataType::Text,
                         mode: Some(ArgMode::Out),
-                        default: Some(Expr::Value(
+                        default: Some(Expr::Value(Box::new(
                             Value::SingleQuotedString("2".into()).with_empty_span()
-                        )),
+                        ))),
                     },
                     ProcedureParam {
                         name: Ident::new("c"),
                         data_type: DataType::Timestamp(None, TimezoneInfo::None),
                         mode: Some(ArgMode::InOut),
-                        default: Some(Expr::Value(Value::Null.with_empty_span())),
+                        default: Some(Expr::Value(Box::new(Value::Null.with_empty_span()))),
                     },
                     ProcedureParam {
                         name: Ident::new("d"),
                         data_type: DataType::Bool,
                         mode: None,
-                        default: Some(Expr::Value((number("0")).with_empty_span())),
+                        default: Some(Expr::Value(Box::new((number("0")).with_empty_span()))),
                     }
                 ]),
             );
@@ -14669,15 +15172,15 @@ fn parse_create_procedure_with_parameter_modes() {
                         name: Ident::new("a"),
                         data_type: DataType::Integer(None),
                         mode: Some(ArgMode::In),
-                        default: Some(Expr::Value((number("1")).with_empty_span())),
+                        default: Some(Expr::Value(Box::new((number("1")).with_empty_span()))),
                     },
                     ProcedureParam {
                         name: Ident::new("b"),
                         data_type: DataType::Text,
                         mode: Some(ArgMode::Out),
-                        default: Some(Expr::Value(
+                        default: Some(Expr::Value(Box::new(
                             Value::SingleQuotedString("2".into()).with_empty_span()
-                        )),
+                        ))),
                     }
                 ])
             );
@@ -14719,12 +15222,104 @@ SELECT * FROM tbl2
         bracket_quoted_identifiers: false,
         unescape: true,
         require_semicolon_stmt_delimiter: false,
+        attach_comments: false,
+        chunk_pool: None,
+        limits: ParserLimits::default(),
     });
     let stmts = dialects.parse_sql_statements(sql).unwrap();
     assert_eq!(stmts.len(), 2);
     assert!(stmts.iter().all(|s| matches!(s, Statement::Query { .. })));
 }
 
+#[test]
+fn parse_attached_comments() {
+    let dialects = all_dialects_with_options(ParserOptions::new().with_attach_comments(true));
+
+    let sql = "-- header\nSELECT a, -- first\n/* second */ b FROM t -- done";
+    let canonical = "-- header\nSELECT a -- first\n, /* second */ b FROM t -- done\n";
+    let statement = dialects.one_statement_parses_to(sql, canonical);
+    let comments = statement.comments().unwrap();
+    assert_eq!(
+        comments.leading,
+        vec![SourceComment::SingleLine {
+            prefix: "--".to_string(),
+            text: " header".to_string(),
+        }]
+    );
+    assert_eq!(
+        comments.trailing,
+        vec![SourceComment::SingleLine {
+            prefix: "--".to_string(),
+            text: " done".to_string(),
+        }]
+    );
+    let Statement::Query(query) = &statement else {
+        panic!("Expected Query");
+    };
+    let select = query.body.as_select().unwrap();
+    assert!(matches!(
+        &select.projection[0],
+        SelectItem::UnnamedExpr { comments, .. }
+            if comments.leading.is_empty() && comments.trailing.len() == 1
+    ));
+    assert!(matches!(
+        &select.projection[1],
+        SelectItem::UnnamedExpr { comments, .. }
+            if comments.leading == vec![SourceComment::MultiLine(" second ".to_string())]
+    ));
+
+    let sql = "CREATE TABLE t (\n  -- key\n  id INT, -- pk\n  name TEXT /* label */\n)";
+    let Statement::CreateTable(create) = dialects.one_statement_parses_to(
+        sql,
+        "CREATE TABLE t (-- key\nid INT -- pk\n, name TEXT /* label */)",
+    ) else {
+        panic!("Expected CreateTable");
+    };
+    assert_eq!(
+        create.columns[0].comments.leading,
+        vec![SourceComment::SingleLine {
+            prefix: "--".to_string(),
+            text: " key".to_string(),
+        }]
+    );
+    assert_eq!(create.columns[0].comments.trailing.len(), 1);
+    assert_eq!(
+        create.columns[1].comments.trailing,
+        vec![SourceComment::MultiLine(" label ".to_string())]
+    );
+    assert_eq!(
+        format!("{create:#}"),
+        "CREATE TABLE t (\n  -- key\n  id INT -- pk\n  ,\n  name TEXT /* label */  \n)"
+    );
+
+    // Comments inside an expression have no node to attach to.
+    let parser = Parser::new(&PostgreSqlDialect {})
+        .with_options(ParserOptions::new().with_attach_comments(true))
+        .try_with_sql("-- sum\nSELECT 1 + /* inner */ 2")
+        .unwrap();
+    let statements = parser.parse_statements().unwrap();
+    assert_eq!(statements[0].to_string(), "-- sum\nSELECT 1 + 2");
+    assert_eq!(parser.unattached_comment_count(), 1);
+
+    // Statements without a `comments` field leave their comments unattached.
+    let parser = Parser::new(&PostgreSqlDialect {})
+        .with_options(ParserOptions::new().with_attach_comments(true))
+        .try_with_sql("-- tidy up\nDROP TABLE t")
+        .unwrap();
+    let statements = parser.parse_statements().unwrap();
+    assert_eq!(statements[0].comments(), None);
+    assert_eq!(statements[0].to_string(), "DROP TABLE t");
+    assert_eq!(parser.unattached_comment_count(), 1);
+
+    // Without the option comments are dropped.
+    let select = verified_only_select("SELECT a FROM t");
+    assert!(select.projection[0].comments().is_empty());
+    assert_eq!(
+        one_statement_parses_to("SELECT a /* gone */ FROM t -- gone", ""),
+        verified_stmt("SELECT a FROM t")
+    );
+}
+
 #[test]
 fn test_identifier_unicode_support() {
     let sql = r#"SELECT phoneǤЖשचᎯ⻩☯♜🦄⚛🀄ᚠ⌛🌀 AS tbl FROM customers"#;
@@ -14823,7 +15418,7 @@ fn parse_create_user() {
                         KeyValueOption {
                             option_name: "PASSWORD".to_string(),
                             option_value: KeyValueOptionKind::Single(Value::SingleQuotedString(
-                                "secret".to_string()
+                                "secret".into()
                             )),
                         },
                         KeyValueOption {
@@ -14833,7 +15428,7 @@ fn parse_create_user() {
                         KeyValueOption {
                             option_name: "TYPE".to_string(),
                             option_value: KeyValueOptionKind::Single(Value::Placeholder(
-                                "SERVICE".to_string()
+                                "SERVICE".into()
                             )),
                         },
                     ],
@@ -14848,13 +15443,13 @@ fn parse_create_user() {
                         KeyValueOption {
                             option_name: "t1".to_string(),
                             option_value: KeyValueOptionKind::Single(Value::SingleQuotedString(
-                                "v1".to_string()
+                                "v1".into()
                             )),
                         },
                         KeyValueOption {
                             option_name: "t2".to_string(),
                             option_value: KeyValueOptionKind::Single(Value::SingleQuotedString(
-                                "v2".to_string()
+                                "v2".into()
                             )),
                         },
                     ]
@@ -14911,7 +15506,8 @@ fn test_parse_not_null_in_column_options() {
 fn test_parse_default_with_collate_column_option() {
     let sql = "CREATE TABLE foo (abc TEXT DEFAULT 'foo' COLLATE 'en_US')";
     let stmt = all_dialects().verified_stmt(sql);
-    if let Statement::CreateTable(CreateTable { mut columns, .. }) = stmt {
+    if let Statement::CreateTable(create_table) = stmt {
+        let CreateTable { mut columns, .. } = Box::into_owned(create_table);
         let mut column = columns.pop().unwrap();
         assert_eq!(&column.name.value, "abc");
         assert_eq!(column.data_type, DataType::Text);
@@ -15081,14 +15677,16 @@ fn parse_invisible_column() {
     let sql = r#"CREATE TABLE t (foo INT, bar INT INVISIBLE)"#;
     let stmt = verified_stmt(sql);
     match stmt {
-        Statement::CreateTable(CreateTable { columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { columns, .. } = Box::into_owned(create_table);
             assert_eq!(
                 columns,
                 vec![
                     ColumnDef {
                         name: "foo".into(),
                         data_type: DataType::Int(None),
-                        options: vec![]
+                        options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "bar".into(),
@@ -15096,7 +15694,8 @@ fn parse_invisible_column() {
                         options: vec![ColumnOptionDef {
                             name: None,
                             option: ColumnOption::Invisible
-                        }]
+                        }],
+                        comments: Comments::default(),
                     }
                 ]
             );
@@ -15119,7 +15718,8 @@ fn parse_invisible_column() {
                         options: vec![ColumnOptionDef {
                             name: None,
                             option: ColumnOption::Invisible
-                        }]
+                        }],
+                        comments: Comments::default(),
                     },
                     column_position: None
                 }]
@@ -15134,14 +15734,15 @@ fn parse_create_index_different_using_positions() {
     let sql = "CREATE INDEX idx_name USING BTREE ON table_name (col1)";
     let expected = "CREATE INDEX idx_name ON table_name USING BTREE (col1)";
     match all_dialects().one_statement_parses_to(sql, expected) {
-        Statement::CreateIndex(CreateIndex {
-            name,
-            table_name,
-            using,
-            columns,
-            unique,
-            ..
-        }) => {
+        Statement::CreateIndex(create_index) => {
+            let CreateIndex {
+                name,
+                table_name,
+                using,
+                columns,
+                unique,
+                ..
+            } = Box::into_owned(create_index);
             assert_eq!(name.unwrap().to_string(), "idx_name");
             assert_eq!(table_name.to_string(), "table_name");
             assert_eq!(using, Some(IndexType::BTree));
@@ -15154,13 +15755,14 @@ fn parse_create_index_different_using_positions() {
     let sql = "CREATE INDEX idx_name USING BTREE ON table_name (col1) USING HASH";
     let expected = "CREATE INDEX idx_name ON table_name USING BTREE (col1) USING HASH";
     match all_dialects().one_statement_parses_to(sql, expected) {
-        Statement::CreateIndex(CreateIndex {
-            name,
-            table_name,
-            columns,
-            index_options,
-            ..
-        }) => {
+        Statement::CreateIndex(create_index) => {
+            let CreateIndex {
+                name,
+                table_name,
+                columns,
+                index_options,
+                ..
+            } = Box::into_owned(create_index);
             assert_eq!(name.unwrap().to_string(), "idx_name");
             assert_eq!(table_name.to_string(), "table_name");
             assert_eq!(columns.len(), 1);
@@ -15208,7 +15810,7 @@ fn test_parse_alter_user() {
                         KeyValueOption {
                             option_name: "PASSWORD".to_string(),
                             option_value: KeyValueOptionKind::Single(Value::SingleQuotedString(
-                                "secret".to_string()
+                                "secret".into()
                             )),
                         },
                         KeyValueOption {
@@ -15247,10 +15849,13 @@ fn parse_generic_unary_ops() {
     for (str_op, op) in unary_ops {
         let select = verified_only_select(&format!("SELECT {}expr", &str_op));
         assert_eq!(
-            UnnamedExpr(UnaryOp {
-                op: *op,
-                expr: Box::new(Identifier(Ident::new("expr"))),
-            }),
+            UnnamedExpr {
+                expr: UnaryOp {
+                    op: *op,
+                    expr: Box::new(Identifier(Box::new(Ident::new("expr")))),
+                },
+                comments: Comments::default(),
+            },
             select.projection[0]
         );
     }
@@ -15328,29 +15933,29 @@ fn test_parse_set_session_authorization() {
     let stmt = verified_stmt("SET SESSION AUTHORIZATION DEFAULT");
     assert_eq!(
         stmt,
-        Statement::Set(SetStatement {
+        Statement::Set(Box::new(SetStatement {
             token: AttachedToken::empty(),
             inner: Set::SetSessionAuthorization(SetSessionAuthorizationParam {
                 scope: ContextModifier::Session,
                 kind: SetSessionAuthorizationParamKind::Default,
             })
-        })
+        }))
     );
 
     let stmt = verified_stmt("SET SESSION AUTHORIZATION 'username'");
     assert_eq!(
         stmt,
-        Statement::Set(SetStatement {
+        Statement::Set(Box::new(SetStatement {
             token: AttachedToken::empty(),
             inner: Set::SetSessionAuthorization(SetSessionAuthorizationParam {
                 scope: ContextModifier::Session,
                 kind: SetSessionAuthorizationParamKind::User(Ident {
-                    value: "username".to_string(),
+                    value: "username".into(),
                     quote_style: Some('\''),
                     span: Span::empty(),
                 }),
             })
-        })
+        }))
     );
 }
 
@@ -15367,11 +15972,12 @@ fn parse_create_function_psm_begin_end() {
         "CREATE FUNCTION increment(i INTEGER) RETURNS INTEGER AS BEGIN RETURN i + 1; END",
     );
     match stmt {
-        Statement::CreateFunction(CreateFunction {
-            name,
-            function_body,
-            ..
-        }) => {
+        Statement::CreateFunction(create_function) => {
+            let CreateFunction {
+                name,
+                function_body,
+                ..
+            } = Box::into_owned(create_function);
             assert_eq!(name.to_string(), "increment");
             assert!(matches!(
                 function_body,
@@ -15388,11 +15994,12 @@ fn parse_create_function_psm_begin_end() {
         "CREATE FUNCTION calc(x INTEGER, y INTEGER) RETURNS INTEGER AS BEGIN SELECT x + y; RETURN x * y; END",
     );
     match stmt {
-        Statement::CreateFunction(CreateFunction {
-            name,
-            function_body,
-            ..
-        }) => {
+        Statement::CreateFunction(create_function) => {
+            let CreateFunction {
+                name,
+                function_body,
+                ..
+            } = Box::into_owned(create_function);
             assert_eq!(name.to_string(), "calc");
             match function_body {
                 Some(CreateFunctionBody::AsBeginEnd(bes)) => {
@@ -15414,9 +16021,12 @@ fn parse_grant_usage_on_type_domain_collation() {
     let stmt = verified_stmt(sql);
     match stmt {
         Statement::Grant {
-            objects: Some(GrantObjects::Types(types)),
+            objects: Some(objects),
             ..
         } => {
+            let GrantObjects::Types(types) = Box::into_owned(objects) else {
+                unreachable!()
+            };
             assert_eq!(types.len(), 1);
             assert_eq!(types[0].to_string(), "address_type");
         }
@@ -15428,9 +16038,12 @@ fn parse_grant_usage_on_type_domain_collation() {
     let stmt = verified_stmt(sql);
     match stmt {
         Statement::Grant {
-            objects: Some(GrantObjects::Domains(domains)),
+            objects: Some(objects),
             ..
         } => {
+            let GrantObjects::Domains(domains) = Box::into_owned(objects) else {
+                unreachable!()
+            };
             assert_eq!(domains.len(), 1);
             assert_eq!(domains[0].to_string(), "email_address");
         }
@@ -15442,9 +16055,12 @@ fn parse_grant_usage_on_type_domain_collation() {
     let stmt = verified_stmt(sql);
     match stmt {
         Statement::Grant {
-            objects: Some(GrantObjects::Collations(collations)),
+            objects: Some(objects),
             ..
         } => {
+            let GrantObjects::Collations(collations) = Box::into_owned(objects) else {
+                unreachable!()
+            };
             assert_eq!(collations.len(), 1);
             assert_eq!(collations[0].to_string(), "utf8_general_ci");
         }
@@ -15456,9 +16072,12 @@ fn parse_grant_usage_on_type_domain_collation() {
     let stmt = verified_stmt(sql);
     match stmt {
         Statement::Grant {
-            objects: Some(GrantObjects::Types(types)),
+            objects: Some(objects),
             ..
         } => {
+            let GrantObjects::Types(types) = Box::into_owned(objects) else {
+                unreachable!()
+            };
             assert_eq!(types.len(), 3);
         }
         _ => unreachable!(),
@@ -15653,11 +16272,12 @@ fn parse_loop_statement() {
     let sql = "LOOP SELECT 1; END LOOP";
     let stmt = verified_stmt(sql);
     match stmt {
-        Statement::Loop(LoopStatement {
-            label,
-            body: _,
-            end_label,
-        }) => {
+        Statement::Loop(loop_stmt) => {
+            let LoopStatement {
+                label,
+                body: _,
+                end_label,
+            } = Box::into_owned(loop_stmt);
             assert!(label.is_none());
             assert!(end_label.is_none());
         }
@@ -15668,7 +16288,8 @@ fn parse_loop_statement() {
     let sql = "LOOP SELECT 1; SELECT 2; END LOOP";
     let stmt = verified_stmt(sql);
     match stmt {
-        Statement::Loop(LoopStatement { body, .. }) => {
+        Statement::Loop(loop_stmt) => {
+            let LoopStatement { body, .. } = Box::into_owned(loop_stmt);
             assert_eq!(body.statements().len(), 2);
         }
         _ => unreachable!(),
@@ -15678,11 +16299,12 @@ fn parse_loop_statement() {
     let sql = "my_loop: LOOP SELECT 1; END LOOP my_loop";
     let stmt = verified_stmt(sql);
     match stmt {
-        Statement::Loop(LoopStatement {
-            label,
-            body: _,
-            end_label,
-        }) => {
+        Statement::Loop(loop_stmt) => {
+            let LoopStatement {
+                label,
+                body: _,
+                end_label,
+            } = Box::into_owned(loop_stmt);
             assert_eq!(label.unwrap().to_string(), "my_loop");
             assert_eq!(end_label.unwrap().to_string(), "my_loop");
         }
@@ -15693,9 +16315,10 @@ fn parse_loop_statement() {
     let sql = "my_loop: LOOP SELECT 1; END LOOP";
     let stmt = verified_stmt(sql);
     match stmt {
-        Statement::Loop(LoopStatement {
-            label, end_label, ..
-        }) => {
+        Statement::Loop(loop_stmt) => {
+            let LoopStatement {
+                label, end_label, ..
+            } = Box::into_owned(loop_stmt);
             assert_eq!(label.unwrap().to_string(), "my_loop");
             assert!(end_label.is_none());
         }
@@ -15709,12 +16332,13 @@ fn parse_repeat_statement() {
     let sql = "REPEAT SELECT 1; UNTIL x > 10 END REPEAT";
     let stmt = verified_stmt(sql);
     match stmt {
-        Statement::Repeat(RepeatStatement {
-            label,
-            body: _,
-            until,
-            end_label,
-        }) => {
+        Statement::Repeat(repeat) => {
+            let RepeatStatement {
+                label,
+                body: _,
+                until,
+                end_label,
+            } = Box::into_owned(repeat);
             assert!(label.is_none());
             assert!(end_label.is_none());
             // Check that until condition was parsed
@@ -15727,12 +16351,13 @@ fn parse_repeat_statement() {
     let sql = "my_repeat: REPEAT SELECT 1; UNTIL done END REPEAT my_repeat";
     let stmt = verified_stmt(sql);
     match stmt {
-        Statement::Repeat(RepeatStatement {
-            label,
-            end_label,
-            until,
-            ..
-        }) => {
+        Statement::Repeat(repeat) => {
+            let RepeatStatement {
+                label,
+                end_label,
+                until,
+                ..
+            } = Box::into_owned(repeat);
             assert_eq!(label.unwrap().to_string(), "my_repeat");
             assert_eq!(end_label.unwrap().to_string(), "my_repeat");
             // The until condition is just an identifier
@@ -15745,7 +16370,8 @@ fn parse_repeat_statement() {
     let sql = "REPEAT SELECT 1; SELECT 2; UNTIL x = 0 END REPEAT";
     let stmt = verified_stmt(sql);
     match stmt {
-        Statement::Repeat(RepeatStatement { body, .. }) => {
+        Statement::Repeat(repeat) => {
+            let RepeatStatement { body, .. } = Box::into_owned(repeat);
             assert_eq!(body.statements().len(), 2);
         }
         _ => unreachable!(),
@@ -15783,11 +16409,12 @@ fn parse_nested_loop_statements() {
 
     let stmt = verified_stmt(sql);
     match stmt {
-        Statement::Loop(LoopStatement {
-            label,
-            body,
-            end_label,
-        }) => {
+        Statement::Loop(loop_stmt) => {
+            let LoopStatement {
+                label,
+                body,
+                end_label,
+            } = Box::into_owned(loop_stmt);
             assert_eq!(label.unwrap().to_string(), "outer_loop");
             assert_eq!(end_label.unwrap().to_string(), "outer_loop");
             // The outer loop body should have 3 statements: SELECT, inner LOOP, ITERATE
@@ -15803,10 +16430,13 @@ fn parse_loop_with_begin_end() {
     let sql = "LOOP BEGIN SELECT 1; SELECT 2; END END LOOP";
     let stmt = verified_stmt(sql);
     match stmt {
-        Statement::Loop(LoopStatement { body, .. }) => match body {
-            ConditionalStatements::BeginEnd(_) => {}
-            _ => panic!("Expected BeginEnd block"),
-        },
+        Statement::Loop(loop_stmt) => {
+            let LoopStatement { body, .. } = Box::into_owned(loop_stmt);
+            match body {
+                ConditionalStatements::BeginEnd(_) => {}
+                _ => panic!("Expected BeginEnd block"),
+            }
+        }
         _ => unreachable!(),
     }
 }
@@ -15955,7 +16585,11 @@ fn parse_tablespace_maintenance() {
     let dialects = all_dialects_where(|dialect| dialect.supports_tablespace_commands());
 
     for (sql, expected_action, expected_name) in [
-        ("QUIESCE TABLESPACE fast", TableMaintenanceAction::Quiesce, "fast"),
+        (
+            "QUIESCE TABLESPACE fast",
+            TableMaintenanceAction::Quiesce,
+            "fast",
+        ),
         (
             "UNQUIESCE TABLESPACE \"Cold Storage\"",
             TableMaintenanceAction::Unquiesce,
@@ -16042,7 +16676,7 @@ fn parse_alter_tablespace() {
             name, operation, ..
         } => {
             assert_eq!("fast", name.to_string());
-            match operation {
+            match Box::into_owned(operation) {
                 AlterTablespaceOperation::AddDatafile {
                     path,
                     size,
@@ -16062,13 +16696,12 @@ fn parse_alter_tablespace() {
 
     // A bare byte count carries no unit.
     match dialects.verified_stmt("ALTER TABLESPACE fast ADD DATAFILE '/d.dat' SIZE 67108864") {
-        Statement::AlterTablespace {
-            operation:
-                AlterTablespaceOperation::AddDatafile {
-                    size, max_size, ..
-                },
-            ..
-        } => {
+        Statement::AlterTablespace { operation, .. } => {
+            let AlterTablespaceOperation::AddDatafile { size, max_size, .. } =
+                Box::into_owned(operation)
+            else {
+                unreachable!()
+            };
             assert_eq!("67108864", size.to_string());
             assert!(max_size.is_none());
         }
@@ -16180,12 +16813,13 @@ fn parse_restore_tenant() {
         ),
     ] {
         match verified_stmt(sql) {
-            Statement::Restore {
-                object_type,
-                tenant,
-                as_new_tenant,
-                ..
-            } => {
+            Statement::Restore(restore) => {
+                let RestoreStatement {
+                    object_type,
+                    tenant,
+                    as_new_tenant,
+                    ..
+                } = Box::into_owned(restore);
                 assert!(object_type.is_none());
                 assert_eq!(
                     Some(expected_tenant.to_string()),
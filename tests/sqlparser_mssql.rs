@@ -33,18 +33,18 @@ use sqlparser::ast::DeclareAssignment::MsSqlAssignment;
 use sqlparser::ast::Value::SingleQuotedString;
 use sqlparser::ast::*;
 use sqlparser::dialect::MsSqlDialect;
-use sqlparser::parser::{Parser, ParserError, ParserOptions};
+use sqlparser::parser::{Parser, ParserError, ParserLimits, ParserOptions};
 
 #[test]
 fn parse_mssql_identifiers() {
     let sql = "SELECT @@version, _foo$123 FROM ##temp";
     let select = ms_and_generic().verified_only_select(sql);
     assert_eq!(
-        &Expr::Identifier(Ident::new("@@version")),
+        &Expr::Identifier(Box::new(Ident::new("@@version"))),
         expr_from_projection(&select.projection[0]),
     );
     assert_eq!(
-        &Expr::Identifier(Ident::new("_foo$123")),
+        &Expr::Identifier(Box::new(Ident::new("_foo$123"))),
         expr_from_projection(&select.projection[1]),
     );
     assert_eq!(2, select.projection.len());
@@ -70,9 +70,9 @@ fn parse_table_time_travel() {
                 args: None,
                 with_hints: vec![],
                 version: Some(
-                    TableVersion::ForSystemTimeAsOf(Expr::Value(
-                        (Value::SingleQuotedString(version)).with_empty_span(),
-                    ))
+                    TableVersion::ForSystemTimeAsOf(Expr::Value(Box::new(
+                        (Value::SingleQuotedString(version.into())).with_empty_span()
+                    ),))
                     .into(),
                 ),
                 partitions: vec![],
@@ -82,7 +82,7 @@ fn parse_table_time_travel() {
                 sample: None,
                 index_hints: vec![]
             },
-            joins: vec![]
+            joins: AstVec::new()
         },]
     );
 
@@ -111,9 +111,9 @@ fn parse_create_procedure() {
     assert_eq!(
         ms().verified_stmt(sql),
         Statement::CreateProcedure {
-            create_token: AttachedToken::empty(),
+            create_token: Box::new(AttachedToken::empty()),
             or_alter: true,
-            body: ConditionalStatements::BeginEnd(BeginEndStatements {
+            body: Box::new(ConditionalStatements::BeginEnd(BeginEndStatements {
                 begin_token: AttachedToken::empty(),
                 label: None,
                 declarations: vec![],
@@ -121,7 +121,7 @@ fn parse_create_procedure() {
                     with: None,
                     limit_clause: None,
                     fetch: None,
-                    locks: vec![],
+                    locks: AstVec::new(),
                     for_clause: None,
                     order_by: None,
                     body: Box::new(SetExpr::Select(Box::new(Select {
@@ -129,24 +129,27 @@ fn parse_create_procedure() {
                         distinct: None,
                         top: None,
                         top_before_distinct: false,
-                        projection: vec![SelectItem::UnnamedExpr(Expr::Value(
-                            (number("1")).with_empty_span()
-                        ))],
+                        projection: vec![SelectItem::UnnamedExpr {
+                            expr: Expr::Value(Box::new((number("1")).with_empty_span())),
+                            comments: Comments::default(),
+                        }]
+                        .into(),
                         into: None,
-                        from: vec![],
+                        from: AstVec::new(),
                         selection: None,
-                        group_by: GroupByExpr::Expressions(vec![], vec![]),
+                        group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
                         having: None,
                         qualify: None,
                         named_window: vec![],
                         connect_by: None,
                         flavor: SelectFlavor::Standard,
-                    })))
+                    }))),
+                    comments: Comments::default()
                 }))],
                 exception_handlers: None,
                 end_token: AttachedToken::empty(),
                 end_label: None,
-            }),
+            })),
             params: Some(vec![
                 ProcedureParam {
                     name: Ident {
@@ -212,7 +215,7 @@ fn parse_create_function() {
     let return_expression_function = "CREATE FUNCTION some_scalar_udf(@foo INT, @bar VARCHAR(256)) RETURNS INT AS BEGIN RETURN 1; END";
     assert_eq!(
         ms().verified_stmt(return_expression_function),
-        sqlparser::ast::Statement::CreateFunction(CreateFunction {
+        sqlparser::ast::Statement::CreateFunction(Box::new(CreateFunction {
             token: AttachedToken::empty(),
             or_alter: false,
             or_replace: false,
@@ -243,12 +246,12 @@ fn parse_create_function() {
                 begin_token: AttachedToken::empty(),
                 label: None,
                 declarations: vec![],
-                statements: vec![Statement::Return(ReturnStatement {
+                statements: vec![Statement::Return(Box::new(ReturnStatement {
                     token: AttachedToken::empty(),
-                    value: Some(ReturnStatementValue::Expr(Expr::Value(
+                    value: Some(ReturnStatementValue::Expr(Expr::Value(Box::new(
                         (number("1")).with_empty_span()
-                    ))),
-                })],
+                    )))),
+                }))],
                 exception_handlers: None,
                 end_token: AttachedToken::empty(),
                 end_label: None,
@@ -270,7 +273,8 @@ fn parse_create_function() {
             polymorphic: false,
             sql_data_access: None,
             behavior: None,
-        }),
+            comments: Comments::default(),
+        })),
     );
 
     let multi_statement_function = "\
@@ -413,7 +417,7 @@ fn parse_create_function_parameter_default_values() {
         "CREATE FUNCTION test_func(@param1 INT = 42) RETURNS INT AS BEGIN RETURN @param1; END";
     assert_eq!(
         ms().verified_stmt(single_default_sql),
-        Statement::CreateFunction(CreateFunction {
+        Statement::CreateFunction(Box::new(CreateFunction {
             token: AttachedToken::empty(),
             or_alter: false,
             or_replace: false,
@@ -425,19 +429,19 @@ fn parse_create_function_parameter_default_values() {
                 mode: None,
                 name: Some(Ident::new("@param1")),
                 data_type: DataType::Int(None),
-                default_expr: Some(Expr::Value((number("42")).with_empty_span())),
+                default_expr: Some(Expr::Value(Box::new((number("42")).with_empty_span()))),
             },]),
             return_type: Some(DataType::Int(None)),
             function_body: Some(CreateFunctionBody::AsBeginEnd(BeginEndStatements {
                 begin_token: AttachedToken::empty(),
                 label: None,
                 declarations: vec![],
-                statements: vec![Statement::Return(ReturnStatement {
+                statements: vec![Statement::Return(Box::new(ReturnStatement {
                     token: AttachedToken::empty(),
-                    value: Some(ReturnStatementValue::Expr(Expr::Identifier(Ident::new(
-                        "@param1"
+                    value: Some(ReturnStatementValue::Expr(Expr::Identifier(Box::new(
+                        Ident::new("@param1")
                     )))),
-                })],
+                }))],
                 exception_handlers: None,
                 end_token: AttachedToken::empty(),
                 end_label: None,
@@ -459,7 +463,8 @@ fn parse_create_function_parameter_default_values() {
             polymorphic: false,
             sql_data_access: None,
             behavior: None,
-        }),
+            comments: Comments::default(),
+        })),
     );
 }
 
@@ -492,7 +497,7 @@ fn parse_mssql_openjson() {
                 name: ObjectName::from(vec![Ident::new("t_test_table")]),
                 alias: Some(TableAlias {
                     name: Ident::new("A"),
-                    columns: vec![],
+                    columns: vec![].into(),
                     implicit: false,
                 }),
                 args: None,
@@ -508,7 +513,7 @@ fn parse_mssql_openjson() {
             joins: vec![Join {
                 relation: TableFactor::OpenJsonTable {
                     json_expr: Expr::CompoundIdentifier(
-                        vec![Ident::new("A"), Ident::new("param"),]
+                        vec![Ident::new("A"), Ident::new("param"),].into()
                     ),
                     json_path: Some(Value::SingleQuotedString("$.config".into())),
                     columns: vec![
@@ -534,13 +539,14 @@ fn parse_mssql_openjson() {
                     ],
                     alias: Some(TableAlias {
                         name: Ident::new("B"),
-                        columns: vec![],
+                        columns: vec![].into(),
                         implicit: false,
                     })
                 },
                 global: false,
                 join_operator: JoinOperator::CrossApply
             }]
+            .into()
         }],
         select.from
     );
@@ -555,7 +561,7 @@ fn parse_mssql_openjson() {
                 name: ObjectName::from(vec![Ident::new("t_test_table"),]),
                 alias: Some(TableAlias {
                     name: Ident::new("A"),
-                    columns: vec![],
+                    columns: vec![].into(),
                     implicit: false,
                 }),
                 args: None,
@@ -571,7 +577,7 @@ fn parse_mssql_openjson() {
             joins: vec![Join {
                 relation: TableFactor::OpenJsonTable {
                     json_expr: Expr::CompoundIdentifier(
-                        vec![Ident::new("A"), Ident::new("param"),]
+                        vec![Ident::new("A"), Ident::new("param"),].into()
                     ),
                     json_path: None,
                     columns: vec![
@@ -597,13 +603,14 @@ fn parse_mssql_openjson() {
                     ],
                     alias: Some(TableAlias {
                         name: Ident::new("B"),
-                        columns: vec![],
+                        columns: vec![].into(),
                         implicit: false,
                     })
                 },
                 global: false,
                 join_operator: JoinOperator::CrossApply
             }]
+            .into()
         }],
         select.from
     );
@@ -618,7 +625,7 @@ fn parse_mssql_openjson() {
                 name: ObjectName::from(vec![Ident::new("t_test_table")]),
                 alias: Some(TableAlias {
                     name: Ident::new("A"),
-                    columns: vec![],
+                    columns: vec![].into(),
                     implicit: false,
                 }),
                 args: None,
@@ -634,7 +641,7 @@ fn parse_mssql_openjson() {
             joins: vec![Join {
                 relation: TableFactor::OpenJsonTable {
                     json_expr: Expr::CompoundIdentifier(
-                        vec![Ident::new("A"), Ident::new("param"),]
+                        vec![Ident::new("A"), Ident::new("param"),].into()
                     ),
                     json_path: None,
                     columns: vec![
@@ -660,13 +667,14 @@ fn parse_mssql_openjson() {
                     ],
                     alias: Some(TableAlias {
                         name: Ident::new("B"),
-                        columns: vec![],
+                        columns: vec![].into(),
                         implicit: false,
                     })
                 },
                 global: false,
                 join_operator: JoinOperator::CrossApply
             }]
+            .into()
         }],
         select.from
     );
@@ -681,7 +689,7 @@ fn parse_mssql_openjson() {
                 name: ObjectName::from(vec![Ident::new("t_test_table")]),
                 alias: Some(TableAlias {
                     name: Ident::new("A"),
-                    columns: vec![],
+                    columns: vec![].into(),
                     implicit: false,
                 }),
                 args: None,
@@ -697,19 +705,20 @@ fn parse_mssql_openjson() {
             joins: vec![Join {
                 relation: TableFactor::OpenJsonTable {
                     json_expr: Expr::CompoundIdentifier(
-                        vec![Ident::new("A"), Ident::new("param"),]
+                        vec![Ident::new("A"), Ident::new("param"),].into()
                     ),
                     json_path: Some(Value::SingleQuotedString("$.config".into())),
                     columns: vec![],
                     alias: Some(TableAlias {
                         name: Ident::new("B"),
-                        columns: vec![],
+                        columns: vec![].into(),
                         implicit: false,
                     })
                 },
                 global: false,
                 join_operator: JoinOperator::CrossApply
             }]
+            .into()
         }],
         select.from
     );
@@ -724,7 +733,7 @@ fn parse_mssql_openjson() {
                 name: ObjectName::from(vec![Ident::new("t_test_table")]),
                 alias: Some(TableAlias {
                     name: Ident::new("A"),
-                    columns: vec![],
+                    columns: vec![].into(),
                     implicit: false,
                 }),
                 args: None,
@@ -740,19 +749,20 @@ fn parse_mssql_openjson() {
             joins: vec![Join {
                 relation: TableFactor::OpenJsonTable {
                     json_expr: Expr::CompoundIdentifier(
-                        vec![Ident::new("A"), Ident::new("param"),]
+                        vec![Ident::new("A"), Ident::new("param"),].into()
                     ),
                     json_path: None,
                     columns: vec![],
                     alias: Some(TableAlias {
                         name: Ident::new("B"),
-                        columns: vec![],
+                        columns: vec![].into(),
                         implicit: false,
                     })
                 },
                 global: false,
                 join_operator: JoinOperator::CrossApply
             }]
+            .into()
         }],
         select.from
     );
@@ -764,9 +774,9 @@ fn parse_mssql_top_paren() {
     let select = ms_and_generic().verified_only_select(sql);
     let top = select.top.unwrap();
     assert_eq!(
-        Some(TopQuantity::Expr(Expr::Value(
+        Some(TopQuantity::Expr(Expr::Value(Box::new(
             (number("5")).with_empty_span()
-        ))),
+        )))),
         top.quantity
     );
     assert!(!top.percent);
@@ -778,9 +788,9 @@ fn parse_mssql_top_percent() {
     let select = ms_and_generic().verified_only_select(sql);
     let top = select.top.unwrap();
     assert_eq!(
-        Some(TopQuantity::Expr(Expr::Value(
+        Some(TopQuantity::Expr(Expr::Value(Box::new(
             (number("5")).with_empty_span()
-        ))),
+        )))),
         top.quantity
     );
     assert!(top.percent);
@@ -792,9 +802,9 @@ fn parse_mssql_top_with_ties() {
     let select = ms_and_generic().verified_only_select(sql);
     let top = select.top.unwrap();
     assert_eq!(
-        Some(TopQuantity::Expr(Expr::Value(
+        Some(TopQuantity::Expr(Expr::Value(Box::new(
             (number("5")).with_empty_span()
-        ))),
+        )))),
         top.quantity
     );
     assert!(top.with_ties);
@@ -806,9 +816,9 @@ fn parse_mssql_top_percent_with_ties() {
     let select = ms_and_generic().verified_only_select(sql);
     let top = select.top.unwrap();
     assert_eq!(
-        Some(TopQuantity::Expr(Expr::Value(
+        Some(TopQuantity::Expr(Expr::Value(Box::new(
             (number("10")).with_empty_span()
-        ))),
+        )))),
         top.quantity
     );
     assert!(top.percent);
@@ -851,18 +861,18 @@ fn parse_alter_role() {
         ms().parse_sql_statements(sql).unwrap(),
         [Statement::AlterRole {
             token: AttachedToken::empty(),
-            name: Ident {
+            name: Box::new(Ident {
                 value: "old_name".into(),
                 quote_style: None,
                 span: Span::empty(),
-            },
-            operation: AlterRoleOperation::RenameRole {
+            }),
+            operation: Box::new(AlterRoleOperation::RenameRole {
                 role_name: Ident {
                     value: "new_name".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }
-            },
+            }),
         }]
     );
 
@@ -871,18 +881,18 @@ fn parse_alter_role() {
         ms().verified_stmt(sql),
         Statement::AlterRole {
             token: AttachedToken::empty(),
-            name: Ident {
+            name: Box::new(Ident {
                 value: "role_name".into(),
                 quote_style: None,
                 span: Span::empty(),
-            },
-            operation: AlterRoleOperation::AddMember {
+            }),
+            operation: Box::new(AlterRoleOperation::AddMember {
                 member_name: Ident {
                     value: "new_member".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }
-            },
+            }),
         }
     );
 
@@ -891,18 +901,18 @@ fn parse_alter_role() {
         ms().verified_stmt(sql),
         Statement::AlterRole {
             token: AttachedToken::empty(),
-            name: Ident {
+            name: Box::new(Ident {
                 value: "role_name".into(),
                 quote_style: None,
                 span: Span::empty(),
-            },
-            operation: AlterRoleOperation::DropMember {
+            }),
+            operation: Box::new(AlterRoleOperation::DropMember {
                 member_name: Ident {
                     value: "old_member".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }
-            },
+            }),
         }
     );
 }
@@ -937,20 +947,23 @@ fn parse_delimited_identifiers() {
     // check SELECT
     assert_eq!(3, select.projection.len());
     assert_eq!(
-        &Expr::CompoundIdentifier(vec![
-            Ident::with_quote('"', "alias"),
-            Ident::with_quote('"', "bar baz"),
-        ]),
+        &Expr::CompoundIdentifier(
+            vec![
+                Ident::with_quote('"', "alias"),
+                Ident::with_quote('"', "bar baz"),
+            ]
+            .into()
+        ),
         expr_from_projection(&select.projection[0]),
     );
     assert_eq!(
-        &Expr::Function(Function {
+        &Expr::Function(Box::new(Function {
             name: ObjectName::from(vec![Ident::with_quote('"', "myfun")]),
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
             args: FunctionArguments::List(FunctionArgumentList {
                 duplicate_treatment: None,
-                args: vec![],
+                args: AstVec::new(),
                 clauses: vec![],
             }),
             null_treatment: None,
@@ -958,12 +971,19 @@ fn parse_delimited_identifiers() {
             filter: None,
             over: None,
             within_group: vec![],
-        }),
+        })),
         expr_from_projection(&select.projection[1]),
     );
     match &select.projection[2] {
-        SelectItem::ExprWithAlias { expr, alias } => {
-            assert_eq!(&Expr::Identifier(Ident::with_quote('"', "simple id")), expr);
+        SelectItem::ExprWithAlias {
+            expr,
+            alias,
+            comments: _,
+        } => {
+            assert_eq!(
+                &Expr::Identifier(Box::new(Ident::with_quote('"', "simple id"))),
+                expr
+            );
             assert_eq!(&Ident::with_quote('"', "column alias"), alias);
         }
         _ => panic!("Expected ExprWithAlias"),
@@ -989,7 +1009,7 @@ fn parse_table_name_in_square_brackets() {
         panic!("Expecting TableFactor::Table");
     }
     assert_eq!(
-        &Expr::Identifier(Ident::with_quote('[', "a column")),
+        &Expr::Identifier(Box::new(Ident::with_quote('[', "a column"))),
         expr_from_projection(&select.projection[0]),
     );
 }
@@ -1037,21 +1057,21 @@ fn parse_mssql_json_object() {
     let select = ms().verified_only_select(
         "SELECT JSON_OBJECT('user_name' : USER_NAME(), LOWER(@id_key) : @id_value, 'sid' : (SELECT @@SPID) ABSENT ON NULL)",
     );
-    match expr_from_projection(&select.projection[0]) {
-        Expr::Function(Function {
+    let Expr::Function(function) = expr_from_projection(&select.projection[0]) else {
+        unreachable!()
+    };
+    match &**function {
+        Function {
             args: FunctionArguments::List(FunctionArgumentList { args, clauses, .. }),
             ..
-        }) => {
+        } => {
             assert!(matches!(
-                args[0],
+                &args[0],
                 FunctionArg::ExprNamed {
-                    name: Expr::Value(ValueWithSpan {
-                        value: Value::SingleQuotedString(_),
-                        span: _
-                    }),
+                    name: Expr::Value(name),
                     arg: FunctionArgExpr::Expr(Expr::Function(_)),
                     operator: FunctionArgOperator::Colon
-                }
+                } if matches!(name.value, Value::SingleQuotedString(_))
             ));
             assert!(matches!(
                 args[1],
@@ -1062,15 +1082,12 @@ fn parse_mssql_json_object() {
                 }
             ));
             assert!(matches!(
-                args[2],
+                &args[2],
                 FunctionArg::ExprNamed {
-                    name: Expr::Value(ValueWithSpan {
-                        value: Value::SingleQuotedString(_),
-                        span: _
-                    }),
+                    name: Expr::Value(name),
                     arg: FunctionArgExpr::Expr(Expr::Subquery(_)),
                     operator: FunctionArgOperator::Colon
-                }
+                } if matches!(name.value, Value::SingleQuotedString(_))
             ));
             assert_eq!(
                 &[FunctionArgumentClause::JsonNullClause(
@@ -1088,45 +1105,39 @@ fn parse_mssql_json_object() {
     );
     match &select.projection[1] {
         SelectItem::ExprWithAlias {
-            expr:
-                Expr::Function(Function {
-                    args: FunctionArguments::List(FunctionArgumentList { args, .. }),
-                    ..
-                }),
+            expr: Expr::Function(function),
             ..
         } => {
+            let Function {
+                args: FunctionArguments::List(FunctionArgumentList { args, .. }),
+                ..
+            } = &**function
+            else {
+                unreachable!()
+            };
             assert!(matches!(
-                args[0],
+                &args[0],
                 FunctionArg::ExprNamed {
-                    name: Expr::Value(ValueWithSpan {
-                        value: Value::SingleQuotedString(_),
-                        span: _
-                    }),
+                    name: Expr::Value(name),
                     arg: FunctionArgExpr::Expr(Expr::CompoundIdentifier(_)),
                     operator: FunctionArgOperator::Colon
-                }
+                } if matches!(name.value, Value::SingleQuotedString(_))
             ));
             assert!(matches!(
-                args[1],
+                &args[1],
                 FunctionArg::ExprNamed {
-                    name: Expr::Value(ValueWithSpan {
-                        value: Value::SingleQuotedString(_),
-                        span: _
-                    }),
+                    name: Expr::Value(name),
                     arg: FunctionArgExpr::Expr(Expr::CompoundIdentifier(_)),
                     operator: FunctionArgOperator::Colon
-                }
+                } if matches!(name.value, Value::SingleQuotedString(_))
             ));
             assert!(matches!(
-                args[2],
+                &args[2],
                 FunctionArg::ExprNamed {
-                    name: Expr::Value(ValueWithSpan {
-                        value: Value::SingleQuotedString(_),
-                        span: _
-                    }),
+                    name: Expr::Value(name),
                     arg: FunctionArgExpr::Expr(Expr::CompoundIdentifier(_)),
                     operator: FunctionArgOperator::Colon
-                }
+                } if matches!(name.value, Value::SingleQuotedString(_))
             ));
         }
         _ => unreachable!(),
@@ -1136,25 +1147,28 @@ fn parse_mssql_json_object() {
 #[test]
 fn parse_mssql_json_array() {
     let select = ms().verified_only_select("SELECT JSON_ARRAY('a', 1, NULL, 2 NULL ON NULL)");
-    match expr_from_projection(&select.projection[0]) {
-        Expr::Function(Function {
+    let Expr::Function(function) = expr_from_projection(&select.projection[0]) else {
+        unreachable!()
+    };
+    match &**function {
+        Function {
             args: FunctionArguments::List(FunctionArgumentList { args, clauses, .. }),
             ..
-        }) => {
+        } => {
             assert_eq!(
                 &[
-                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(Box::new(
                         (Value::SingleQuotedString("a".into())).with_empty_span()
-                    ))),
-                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
+                    )))),
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(Box::new(
                         (number("1")).with_empty_span()
-                    ))),
-                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
+                    )))),
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(Box::new(
                         (Value::Null).with_empty_span()
-                    ))),
-                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
+                    )))),
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(Box::new(
                         (number("2")).with_empty_span()
-                    ))),
+                    )))),
                 ],
                 &args[..]
             );
@@ -1168,25 +1182,28 @@ fn parse_mssql_json_array() {
         _ => unreachable!(),
     }
     let select = ms().verified_only_select("SELECT JSON_ARRAY('a', 1, NULL, 2 ABSENT ON NULL)");
-    match expr_from_projection(&select.projection[0]) {
-        Expr::Function(Function {
+    let Expr::Function(function) = expr_from_projection(&select.projection[0]) else {
+        unreachable!()
+    };
+    match &**function {
+        Function {
             args: FunctionArguments::List(FunctionArgumentList { args, clauses, .. }),
             ..
-        }) => {
+        } => {
             assert_eq!(
                 &[
-                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(Box::new(
                         (Value::SingleQuotedString("a".into())).with_empty_span()
-                    ))),
-                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
+                    )))),
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(Box::new(
                         (number("1")).with_empty_span()
-                    ))),
-                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
+                    )))),
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(Box::new(
                         (Value::Null).with_empty_span()
-                    ))),
-                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
+                    )))),
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(Box::new(
                         (number("2")).with_empty_span()
-                    ))),
+                    )))),
                 ],
                 &args[..]
             );
@@ -1200,11 +1217,14 @@ fn parse_mssql_json_array() {
         _ => unreachable!(),
     }
     let select = ms().verified_only_select("SELECT JSON_ARRAY(NULL ON NULL)");
-    match expr_from_projection(&select.projection[0]) {
-        Expr::Function(Function {
+    let Expr::Function(function) = expr_from_projection(&select.projection[0]) else {
+        unreachable!()
+    };
+    match &**function {
+        Function {
             args: FunctionArguments::List(FunctionArgumentList { args, clauses, .. }),
             ..
-        }) => {
+        } => {
             assert!(args.is_empty());
             assert_eq!(
                 &[FunctionArgumentClause::JsonNullClause(
@@ -1216,11 +1236,14 @@ fn parse_mssql_json_array() {
         _ => unreachable!(),
     }
     let select = ms().verified_only_select("SELECT JSON_ARRAY(ABSENT ON NULL)");
-    match expr_from_projection(&select.projection[0]) {
-        Expr::Function(Function {
+    let Expr::Function(function) = expr_from_projection(&select.projection[0]) else {
+        unreachable!()
+    };
+    match &**function {
+        Function {
             args: FunctionArguments::List(FunctionArgumentList { args, clauses, .. }),
             ..
-        }) => {
+        } => {
             assert!(args.is_empty());
             assert_eq!(
                 &[FunctionArgumentClause::JsonNullClause(
@@ -1234,15 +1257,18 @@ fn parse_mssql_json_array() {
     let select = ms().verified_only_select(
         "SELECT JSON_ARRAY('a', JSON_OBJECT('name' : 'value', 'type' : 1) NULL ON NULL)",
     );
-    match expr_from_projection(&select.projection[0]) {
-        Expr::Function(Function {
+    let Expr::Function(function) = expr_from_projection(&select.projection[0]) else {
+        unreachable!()
+    };
+    match &**function {
+        Function {
             args: FunctionArguments::List(FunctionArgumentList { args, clauses, .. }),
             ..
-        }) => {
+        } => {
             assert_eq!(
-                &FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
+                &FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(Box::new(
                     (Value::SingleQuotedString("a".into())).with_empty_span()
-                ))),
+                )))),
                 &args[0]
             );
             assert!(matches!(
@@ -1261,15 +1287,18 @@ fn parse_mssql_json_array() {
     let select = ms().verified_only_select(
         "SELECT JSON_ARRAY('a', JSON_OBJECT('name' : 'value', 'type' : 1), JSON_ARRAY(1, NULL, 2 NULL ON NULL))",
     );
-    match expr_from_projection(&select.projection[0]) {
-        Expr::Function(Function {
+    let Expr::Function(function) = expr_from_projection(&select.projection[0]) else {
+        unreachable!()
+    };
+    match &**function {
+        Function {
             args: FunctionArguments::List(FunctionArgumentList { args, .. }),
             ..
-        }) => {
+        } => {
             assert_eq!(
-                &FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
+                &FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(Box::new(
                     (Value::SingleQuotedString("a".into())).with_empty_span()
-                ))),
+                )))),
                 &args[0]
             );
             assert!(matches!(
@@ -1284,15 +1313,18 @@ fn parse_mssql_json_array() {
         _ => unreachable!(),
     }
     let select = ms().verified_only_select("SELECT JSON_ARRAY(1, @id_value, (SELECT @@SPID))");
-    match expr_from_projection(&select.projection[0]) {
-        Expr::Function(Function {
+    let Expr::Function(function) = expr_from_projection(&select.projection[0]) else {
+        unreachable!()
+    };
+    match &**function {
+        Function {
             args: FunctionArguments::List(FunctionArgumentList { args, .. }),
             ..
-        }) => {
+        } => {
             assert_eq!(
-                &FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
+                &FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(Box::new(
                     (number("1")).with_empty_span()
-                ))),
+                )))),
                 &args[0]
             );
             assert!(matches!(
@@ -1313,13 +1345,16 @@ fn parse_mssql_json_array() {
     );
     match &select.projection[1] {
         SelectItem::ExprWithAlias {
-            expr:
-                Expr::Function(Function {
-                    args: FunctionArguments::List(FunctionArgumentList { args, clauses, .. }),
-                    ..
-                }),
+            expr: Expr::Function(function),
             ..
         } => {
+            let Function {
+                args: FunctionArguments::List(FunctionArgumentList { args, clauses, .. }),
+                ..
+            } = &**function
+            else {
+                unreachable!()
+            };
             assert!(matches!(
                 args[0],
                 FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::CompoundIdentifier(_)))
@@ -1371,14 +1406,14 @@ fn parse_convert() {
     };
     assert!(!is_try);
     assert_eq!(Expr::value(number("1")), *expr);
-    assert_eq!(Some(DataType::Int(None)), data_type);
+    assert_eq!(Some(DataType::Int(None)), data_type.map(Box::into_owned));
     assert!(charset.is_none());
     assert!(target_before_value);
     assert_eq!(
         vec![
             Expr::value(number("2")),
             Expr::value(number("3")),
-            Expr::Value((Value::Null).with_empty_span()),
+            Expr::Value(Box::new((Value::Null).with_empty_span())),
         ],
         styles
     );
@@ -1411,32 +1446,37 @@ fn parse_substring_in_select() {
                         distinct: Some(Distinct::Distinct),
                         top: None,
                         top_before_distinct: false,
-                        projection: vec![SelectItem::UnnamedExpr(Expr::Substring {
-                            expr: Box::new(Expr::Identifier(Ident {
-                                value: "description".to_string(),
-                                quote_style: None,
-                                span: Span::empty(),
-                            })),
-                            substring_from: Some(Box::new(Expr::Value(
-                                (number("0")).with_empty_span()
-                            ))),
-                            substring_for: Some(Box::new(Expr::Value(
-                                (number("1")).with_empty_span()
-                            ))),
-                            special: true,
-                            shorthand: false,
-                        })],
+                        projection: vec![SelectItem::UnnamedExpr {
+                            expr: Expr::Substring {
+                                expr: Box::new(Expr::Identifier(Box::new(Ident {
+                                    value: "description".into(),
+                                    quote_style: None,
+                                    span: Span::empty(),
+                                }))),
+                                substring_from: Some(Box::new(Expr::Value(Box::new(
+                                    (number("0")).with_empty_span()
+                                )))),
+                                substring_for: Some(Box::new(Expr::Value(Box::new(
+                                    (number("1")).with_empty_span()
+                                )))),
+                                special: true,
+                                shorthand: false,
+                            },
+                            comments: Comments::default(),
+                        }]
+                        .into(),
                         into: None,
                         from: vec![TableWithJoins {
                             relation: table_from_name(ObjectName::from(vec![Ident {
-                                value: "test".to_string(),
+                                value: "test".into(),
                                 quote_style: None,
                                 span: Span::empty(),
                             }])),
-                            joins: vec![]
-                        }],
+                            joins: AstVec::new()
+                        }]
+                        .into(),
                         selection: None,
-                        group_by: GroupByExpr::Expressions(vec![], vec![]),
+                        group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
                         having: None,
                         qualify: None,
                         named_window: vec![],
@@ -1446,8 +1486,9 @@ fn parse_substring_in_select() {
                     order_by: None,
                     limit_clause: None,
                     fetch: None,
-                    locks: vec![],
+                    locks: AstVec::new(),
                     for_clause: None,
+                    comments: Comments::default(),
                 }),
                 query
             );
@@ -1467,7 +1508,7 @@ fn parse_mssql_declare() {
             stmts: vec![
                 Declare {
                     names: vec![Ident {
-                        value: "@foo".to_string(),
+                        value: "@foo".into(),
                         quote_style: None,
                         span: Span::empty(),
                     }],
@@ -1483,7 +1524,7 @@ fn parse_mssql_declare() {
                 },
                 Declare {
                     names: vec![Ident {
-                        value: "@bar".to_string(),
+                        value: "@bar".into(),
                         quote_style: None,
                         span: Span::empty(),
                     }],
@@ -1499,14 +1540,14 @@ fn parse_mssql_declare() {
                 },
                 Declare {
                     names: vec![Ident {
-                        value: "@baz".to_string(),
+                        value: "@baz".into(),
                         quote_style: None,
                         span: Span::empty(),
                     }],
                     data_type: Some(Text),
-                    assignment: Some(MsSqlAssignment(Box::new(Expr::Value(
-                        (SingleQuotedString("foobar".to_string())).with_empty_span()
-                    )))),
+                    assignment: Some(MsSqlAssignment(Box::new(Expr::Value(Box::new(
+                        (SingleQuotedString("foobar".into())).with_empty_span()
+                    ))))),
                     declare_type: None,
                     binary: None,
                     sensitive: None,
@@ -1539,21 +1580,19 @@ fn parse_mssql_declare() {
                     handler_body: None
                 }]
             },
-            Statement::Set(SetStatement {
+            Statement::Set(Box::new(SetStatement {
                 token: AttachedToken::empty(),
                 inner: Set::SingleAssignment {
                     scope: None,
                     variable: ObjectName::from(vec![Ident::new("@bar")]),
-                    values: vec![Expr::Value(
-                        (Value::Number("2".parse().unwrap(), false)).with_empty_span()
-                    )],
+                    values: vec![Expr::Value(Box::new(number("2").with_empty_span()))],
                 }
-            }),
+            })),
             Statement::Query(Box::new(Query {
                 with: None,
                 limit_clause: None,
                 fetch: None,
-                locks: vec![],
+                locks: AstVec::new(),
                 for_clause: None,
                 order_by: None,
 
@@ -1562,23 +1601,26 @@ fn parse_mssql_declare() {
                     distinct: None,
                     top: None,
                     top_before_distinct: false,
-                    projection: vec![SelectItem::UnnamedExpr(Expr::BinaryOp {
-                        left: Box::new(Expr::Identifier(Ident::new("@bar"))),
-                        op: BinaryOperator::Multiply,
-                        right: Box::new(Expr::Value(
-                            (Value::Number("4".parse().unwrap(), false)).with_empty_span()
-                        )),
-                    })],
+                    projection: vec![SelectItem::UnnamedExpr {
+                        expr: Expr::BinaryOp {
+                            left: Box::new(Expr::Identifier(Box::new(Ident::new("@bar")))),
+                            op: BinaryOperator::Multiply,
+                            right: Box::new(Expr::Value(Box::new(number("4").with_empty_span()))),
+                        },
+                        comments: Comments::default(),
+                    }]
+                    .into(),
                     into: None,
-                    from: vec![],
+                    from: AstVec::new(),
                     selection: None,
-                    group_by: GroupByExpr::Expressions(vec![], vec![]),
+                    group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
                     having: None,
                     qualify: None,
                     named_window: vec![],
                     connect_by: None,
                     flavor: SelectFlavor::Standard,
-                })))
+                }))),
+                comments: Comments::default()
             }))
         ],
         ast
@@ -1618,7 +1660,7 @@ fn test_mssql_while_statement() {
     let stmt = ms().verified_stmt(while_single_statement);
     assert_eq!(
         stmt,
-        Statement::While(sqlparser::ast::WhileStatement {
+        Statement::While(Box::new(sqlparser::ast::WhileStatement {
             label: None,
             condition: None,
             body: ConditionalStatements::Sequence { statements: vec![] },
@@ -1628,33 +1670,28 @@ fn test_mssql_while_statement() {
             while_block: Some(ConditionalStatementBlock {
                 start_token: AttachedToken::from(TokenWithSpan {
                     token: Token::Word(Word {
-                        value: "WHILE".to_string().into(),
+                        value: "WHILE".into(),
                         quote_style: None,
                         keyword: Keyword::WHILE
                     }),
                     span: Span::empty()
                 }),
                 condition: Some(Expr::BinaryOp {
-                    left: Box::new(Expr::Value(
-                        (Value::Number("1".parse().unwrap(), false)).with_empty_span()
-                    )),
+                    left: Box::new(Expr::Value(Box::new(number("1").with_empty_span()))),
                     op: BinaryOperator::Eq,
-                    right: Box::new(Expr::Value(
-                        (Value::Number("0".parse().unwrap(), false)).with_empty_span()
-                    )),
+                    right: Box::new(Expr::Value(Box::new(number("0").with_empty_span()))),
                 }),
                 then_token: None,
                 conditional_statements: ConditionalStatements::Sequence {
                     statements: vec![Statement::Print(PrintStatement {
                         token: AttachedToken::empty(),
-                        message: Box::new(Expr::Value(
-                            (Value::SingleQuotedString("Hello World".to_string()))
-                                .with_empty_span()
-                        )),
+                        message: Box::new(Expr::Value(Box::new(
+                            (Value::SingleQuotedString("Hello World".into())).with_empty_span()
+                        ))),
                     })],
                 }
             })
-        })
+        }))
     );
 
     let while_begin_end = "\
@@ -1682,16 +1719,12 @@ fn test_parse_raiserror() {
     assert_eq!(
         s,
         Statement::RaisError {
-            token: AttachedToken::empty(),
-            message: Box::new(Expr::Value(
-                (Value::SingleQuotedString("This is a test".to_string())).with_empty_span()
-            )),
-            severity: Box::new(Expr::Value(
-                (Value::Number("16".parse().unwrap(), false)).with_empty_span()
-            )),
-            state: Box::new(Expr::Value(
-                (Value::Number("1".parse().unwrap(), false)).with_empty_span()
-            )),
+            token: Box::new(AttachedToken::empty()),
+            message: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("This is a test".into())).with_empty_span()
+            ))),
+            severity: Box::new(Expr::Value(Box::new(number("16").with_empty_span()))),
+            state: Box::new(Expr::Value(Box::new(number("1").with_empty_span()))),
             arguments: vec![],
             options: vec![],
         }
@@ -1753,20 +1786,20 @@ fn parse_create_table_with_valid_options() {
             vec![
                 SqlOption::KeyValue {
                     key: Ident {
-                        value: "DISTRIBUTION".to_string(),
+                        value: "DISTRIBUTION".into(),
                         quote_style: None,
                         span: Span::empty(),
                     },
-                    value: Expr::Identifier(Ident {
-                        value: "ROUND_ROBIN".to_string(),
+                    value: Expr::Identifier(Box::new(Ident {
+                        value: "ROUND_ROBIN".into(),
                         quote_style: None,
                         span: Span::empty(),
-                    })
+                    }))
                 },
                 SqlOption::Partition {
                     column_name: "column_a".into(),
                     range_direction: None,
-                    for_values: vec![Expr::Value((test_utils::number("10")).with_empty_span()), Expr::Value((test_utils::number("11")).with_empty_span())] ,
+                    for_values: vec![Expr::Value(Box::new((test_utils::number("10")).with_empty_span())), Expr::Value(Box::new((test_utils::number("11")).with_empty_span()))] ,
                 },
             ],
         ),
@@ -1777,8 +1810,8 @@ fn parse_create_table_with_valid_options() {
                         column_name: "column_a".into(),
                         range_direction: Some(PartitionRangeDirection::Left),
                         for_values: vec![
-                            Expr::Value((test_utils::number("10")).with_empty_span()),
-                            Expr::Value((test_utils::number("11")).with_empty_span()),
+                            Expr::Value(Box::new((test_utils::number("10")).with_empty_span())),
+                            Expr::Value(Box::new((test_utils::number("11")).with_empty_span())),
                         ],
                     }
             ],
@@ -1802,7 +1835,7 @@ fn parse_create_table_with_valid_options() {
                 SqlOption::Clustered(TableOptionsClustered::Index(vec![
                         ClusteredIndex {
                             name: Ident {
-                                value: "column_a".to_string(),
+                                value: "column_a".into(),
                                 quote_style: None,
                                 span: Span::empty(),
                             },
@@ -1810,7 +1843,7 @@ fn parse_create_table_with_valid_options() {
                         },
                         ClusteredIndex {
                             name: Ident {
-                                value: "column_b".to_string(),
+                                value: "column_b".into(),
                                 quote_style: None,
                                 span: Span::empty(),
                             },
@@ -1818,7 +1851,7 @@ fn parse_create_table_with_valid_options() {
                         },
                         ClusteredIndex {
                             name: Ident {
-                                value: "column_c".to_string(),
+                                value: "column_c".into(),
                                 quote_style: None,
                                 span: Span::empty(),
                             },
@@ -1832,16 +1865,16 @@ fn parse_create_table_with_valid_options() {
             vec![
                 SqlOption::KeyValue {
                     key: Ident {
-                        value: "DISTRIBUTION".to_string(),
+                        value: "DISTRIBUTION".into(),
                         quote_style: None,
                         span: Span::empty(),
                     },
-                    value: Expr::Function(
+                    value: Expr::Function(Box::new(
                         Function {
                             name: ObjectName::from(
                                 vec![
                                     Ident {
-                                        value: "HASH".to_string(),
+                                        value: "HASH".into(),
                                         quote_style: None,
                                         span: Span::empty(),
                                     },
@@ -1856,26 +1889,26 @@ fn parse_create_table_with_valid_options() {
                                         FunctionArg::Unnamed(
                                             FunctionArgExpr::Expr(
                                                 Expr::Identifier(
-                                                    Ident {
-                                                        value: "column_a".to_string(),
+                                                    Box::new(Ident {
+                                                        value: "column_a".into(),
                                                         quote_style: None,
                                                         span: Span::empty(),
-                                                    },
+                                                    }),
                                                 ),
                                             ),
                                         ),
                                         FunctionArg::Unnamed(
                                             FunctionArgExpr::Expr(
                                                 Expr::Identifier(
-                                                    Ident {
-                                                        value: "column_b".to_string(),
+                                                    Box::new(Ident {
+                                                        value: "column_b".into(),
                                                         quote_style: None,
                                                         span: Span::empty(),
-                                                    },
+                                                    }),
                                                 ),
                                             ),
                                         ),
-                                    ],
+                                    ].into(),
                                     clauses: vec![],
                                 },
                             ),
@@ -1885,7 +1918,7 @@ fn parse_create_table_with_valid_options() {
                             over: None,
                             within_group: vec![],
                         },
-                    ),
+                    )),
                 },
                 SqlOption::Ident("HEAP".into()),
             ],
@@ -1895,7 +1928,7 @@ fn parse_create_table_with_valid_options() {
     for (sql, with_options) in options {
         assert_eq!(
             ms_and_generic().verified_stmt(sql),
-            Statement::CreateTable(CreateTable {
+            Statement::CreateTable(Box::new(CreateTable {
                 or_replace: false,
                 temporary: false,
                 external: false,
@@ -1904,37 +1937,40 @@ fn parse_create_table_with_valid_options() {
                 if_not_exists: false,
                 volatile: false,
                 name: ObjectName::from(vec![Ident {
-                    value: "mytable".to_string(),
+                    value: "mytable".into(),
                     quote_style: None,
                     span: Span::empty(),
                 },],),
                 columns: vec![
                     ColumnDef {
                         name: Ident {
-                            value: "column_a".to_string(),
+                            value: "column_a".into(),
                             quote_style: None,
                             span: Span::empty(),
                         },
                         data_type: Int(None,),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident {
-                            value: "column_b".to_string(),
+                            value: "column_b".into(),
                             quote_style: None,
                             span: Span::empty(),
                         },
                         data_type: Int(None,),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident {
-                            value: "column_c".to_string(),
+                            value: "column_c".into(),
                             quote_style: None,
                             span: Span::empty(),
                         },
                         data_type: Int(None,),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                 ],
                 constraints: vec![],
@@ -1954,7 +1990,8 @@ fn parse_create_table_with_valid_options() {
                 partition_bound: None,
                 clustering_by: None,
                 distribution: None,
-            })
+                comments: Comments::default(),
+            }))
         );
     }
 }
@@ -2055,7 +2092,7 @@ fn parse_create_table_with_identity_column() {
     for (sql, column_options) in with_column_options {
         assert_eq!(
             ms_and_generic().verified_stmt(sql),
-            Statement::CreateTable(CreateTable {
+            Statement::CreateTable(Box::new(CreateTable {
                 or_replace: false,
                 temporary: false,
                 external: false,
@@ -2064,19 +2101,20 @@ fn parse_create_table_with_identity_column() {
                 if_not_exists: false,
                 volatile: false,
                 name: ObjectName::from(vec![Ident {
-                    value: "mytable".to_string(),
+                    value: "mytable".into(),
                     quote_style: None,
                     span: Span::empty(),
                 },],),
                 columns: vec![ColumnDef {
                     name: Ident {
-                        value: "columnA".to_string(),
+                        value: "columnA".into(),
                         quote_style: None,
                         span: Span::empty(),
                     },
                     data_type: Int(None,),
 
                     options: column_options,
+                    comments: Comments::default(),
                 },],
                 constraints: vec![],
                 location: None,
@@ -2095,7 +2133,8 @@ fn parse_create_table_with_identity_column() {
                 partition_bound: None,
                 clustering_by: None,
                 distribution: None,
-            }),
+                comments: Comments::default(),
+            })),
         );
     }
 }
@@ -2104,11 +2143,11 @@ fn parse_create_table_with_identity_column() {
 fn parse_true_false_as_identifiers() {
     assert_eq!(
         ms().verified_expr("true"),
-        Expr::Identifier(Ident::new("true"))
+        Expr::Identifier(Box::new(Ident::new("true")))
     );
     assert_eq!(
         ms().verified_expr("false"),
-        Expr::Identifier(Ident::new("false"))
+        Expr::Identifier(Box::new(Ident::new("false")))
     );
 }
 
@@ -2232,11 +2271,15 @@ fn test_mssql_if_statements_span() {
     let mut sql = "IF 1 = 1 SELECT '1' ELSE SELECT '2'";
     let mut parser = Parser::new(&MsSqlDialect {}).try_with_sql(sql).unwrap();
     match parser.parse_statement().unwrap() {
-        Statement::If(IfStatement {
-            if_block,
-            else_block: Some(else_block),
-            ..
-        }) => {
+        Statement::If(if_stmt) => {
+            let IfStatement {
+                if_block,
+                else_block: Some(else_block),
+                ..
+            } = Box::into_owned(if_stmt)
+            else {
+                unreachable!()
+            };
             assert_eq!(
                 if_block.span(),
                 Span::new(Location::new(1, 1), Location::new(1, 20))
@@ -2253,11 +2296,15 @@ fn test_mssql_if_statements_span() {
     sql = "IF 1 = 1 BEGIN SET @A = 1; END ELSE BEGIN SET @A = 2 END";
     parser = Parser::new(&MsSqlDialect {}).try_with_sql(sql).unwrap();
     match parser.parse_statement().unwrap() {
-        Statement::If(IfStatement {
-            if_block,
-            else_block: Some(else_block),
-            ..
-        }) => {
+        Statement::If(if_stmt) => {
+            let IfStatement {
+                if_block,
+                else_block: Some(else_block),
+                ..
+            } = Box::into_owned(if_stmt)
+            else {
+                unreachable!()
+            };
             assert_eq!(
                 if_block.span(),
                 Span::new(Location::new(1, 1), Location::new(1, 31))
@@ -2276,11 +2323,12 @@ fn parse_mssql_varbinary_max_length() {
     let sql = "CREATE TABLE example (var_binary_col VARBINARY(MAX))";
 
     match ms_and_generic().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { name, columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { name, columns, .. } = Box::into_owned(create_table);
             assert_eq!(
                 name,
                 ObjectName::from(vec![Ident {
-                    value: "example".to_string(),
+                    value: "example".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }])
@@ -2291,7 +2339,8 @@ fn parse_mssql_varbinary_max_length() {
                     name: Ident::new("var_binary_col"),
                     data_type: Varbinary(Some(BinaryLength::Max)),
 
-                    options: vec![]
+                    options: vec![],
+                    comments: Comments::default(),
                 },],
             );
         }
@@ -2301,11 +2350,12 @@ fn parse_mssql_varbinary_max_length() {
     let sql = "CREATE TABLE example (var_binary_col VARBINARY(50))";
 
     match ms_and_generic().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { name, columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { name, columns, .. } = Box::into_owned(create_table);
             assert_eq!(
                 name,
                 ObjectName::from(vec![Ident {
-                    value: "example".to_string(),
+                    value: "example".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }])
@@ -2316,7 +2366,8 @@ fn parse_mssql_varbinary_max_length() {
                     name: Ident::new("var_binary_col"),
                     data_type: Varbinary(Some(BinaryLength::IntegerLength { length: 50 })),
 
-                    options: vec![]
+                    options: vec![],
+                    comments: Comments::default(),
                 },],
             );
         }
@@ -2355,6 +2406,9 @@ fn tsql() -> TestedDialects {
             bracket_quoted_identifiers: false,
             unescape: true,
             require_semicolon_stmt_delimiter: false,
+            attach_comments: false,
+            chunk_pool: None,
+            limits: ParserLimits::default(),
         },
     )
 }
@@ -2390,7 +2444,7 @@ fn parse_create_trigger() {
     let create_stmt = ms().verified_stmt(create_trigger);
     assert_eq!(
         create_stmt,
-        Statement::CreateTrigger(CreateTrigger {
+        Statement::CreateTrigger(Box::new(CreateTrigger {
             token: AttachedToken::empty(),
             or_alter: true,
             temporary: false,
@@ -2409,23 +2463,19 @@ fn parse_create_trigger() {
             statements_as: true,
             statements: Some(ConditionalStatements::Sequence {
                 statements: vec![Statement::RaisError {
-                    token: AttachedToken::empty(),
-                    message: Box::new(Expr::Value(
-                        (Value::SingleQuotedString("Notify Customer Relations".to_string()))
+                    token: Box::new(AttachedToken::empty()),
+                    message: Box::new(Expr::Value(Box::new(
+                        (Value::SingleQuotedString("Notify Customer Relations".into()))
                             .with_empty_span()
-                    )),
-                    severity: Box::new(Expr::Value(
-                        (Value::Number("16".parse().unwrap(), false)).with_empty_span()
-                    )),
-                    state: Box::new(Expr::Value(
-                        (Value::Number("10".parse().unwrap(), false)).with_empty_span()
-                    )),
+                    ))),
+                    severity: Box::new(Expr::Value(Box::new(number("16").with_empty_span()))),
+                    state: Box::new(Expr::Value(Box::new(number("10").with_empty_span()))),
                     arguments: vec![],
                     options: vec![],
                 }],
             }),
             characteristics: None,
-        })
+        }))
     );
 
     let multi_statement_as_trigger = "\
@@ -2502,9 +2552,9 @@ fn parse_print() {
         print_stmt,
         Statement::Print(PrintStatement {
             token: AttachedToken::empty(),
-            message: Box::new(Expr::Value(
-                (Value::SingleQuotedString("Hello, world!".to_string())).with_empty_span()
-            )),
+            message: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("Hello, world!".into())).with_empty_span()
+            ))),
         })
     );
 
@@ -2533,3 +2583,20 @@ DECLARE @Y AS NVARCHAR(MAX)='y'
     assert_eq!(stmts.len(), 2);
     assert!(stmts.iter().all(|s| matches!(s, Statement::Declare { .. })));
 }
+
+#[test]
+fn mssql_recovery_resumes_after_a_failed_begin_try() {
+    // `BEGIN TRY` starts a transaction statement, not a block, so recovery
+    // resumes at the next semicolon instead of skipping to `END TRY`.
+    let sql = "BEGIN TRY BAD; SELECT 2; END TRY; SELECT 3";
+    let recovered =
+        Parser::parse_sql_with_recovery(&MsSqlDialect {}, sql, ParserOptions::new()).unwrap();
+
+    let statements: Vec<_> = recovered.statements.iter().map(|s| s.to_string()).collect();
+    assert_eq!(statements, vec!["SELECT 2", "END TRY", "SELECT 3"]);
+    let error_spans: Vec<_> = recovered.errors.iter().map(|e| e.span).collect();
+    assert_eq!(
+        error_spans,
+        vec![Span::new(Location::new(1, 1), Location::new(1, 14))]
+    );
+}
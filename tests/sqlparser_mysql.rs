@@ -26,7 +26,7 @@ use sqlparser::ast::AstBox as Box;
 use sqlparser::ast::MysqlInsertPriority::{Delayed, HighPriority, LowPriority};
 use sqlparser::ast::*;
 use sqlparser::dialect::MySqlDialect;
-use sqlparser::parser::{ParserError, ParserOptions};
+use sqlparser::parser::{ParserError, ParserLimits, ParserOptions};
 use sqlparser::tokenizer::Span;
 use sqlparser::tokenizer::Token;
 use test_utils::*;
@@ -53,11 +53,15 @@ fn parse_literal_string() {
     let select = mysql().verified_only_select(sql);
     assert_eq!(2, select.projection.len());
     assert_eq!(
-        &Expr::Value((Value::SingleQuotedString("single".to_string())).with_empty_span()),
+        &Expr::Value(Box::new(
+            (Value::SingleQuotedString("single".into())).with_empty_span()
+        )),
         expr_from_projection(&select.projection[0])
     );
     assert_eq!(
-        &Expr::Value((Value::DoubleQuotedString("double".to_string())).with_empty_span()),
+        &Expr::Value(Box::new(
+            (Value::DoubleQuotedString("double".to_string())).with_empty_span()
+        )),
         expr_from_projection(&select.projection[1])
     );
 }
@@ -160,18 +164,18 @@ fn parse_flush() {
             tables: vec![
                 ObjectName::from(vec![
                     Ident {
-                        value: "mek".to_string(),
+                        value: "mek".into(),
                         quote_style: Some('`'),
                         span: Span::empty(),
                     },
                     Ident {
-                        value: "table1".to_string(),
+                        value: "table1".into(),
                         quote_style: Some('`'),
                         span: Span::empty(),
                     }
                 ]),
                 ObjectName::from(vec![Ident {
-                    value: "table2".to_string(),
+                    value: "table2".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }])
@@ -202,18 +206,18 @@ fn parse_flush() {
             tables: vec![
                 ObjectName::from(vec![
                     Ident {
-                        value: "mek".to_string(),
+                        value: "mek".into(),
                         quote_style: Some('`'),
                         span: Span::empty(),
                     },
                     Ident {
-                        value: "table1".to_string(),
+                        value: "table1".into(),
                         quote_style: Some('`'),
                         span: Span::empty(),
                     }
                 ]),
                 ObjectName::from(vec![Ident {
-                    value: "table2".to_string(),
+                    value: "table2".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }])
@@ -232,18 +236,18 @@ fn parse_flush() {
             tables: vec![
                 ObjectName::from(vec![
                     Ident {
-                        value: "mek".to_string(),
+                        value: "mek".into(),
                         quote_style: Some('`'),
                         span: Span::empty(),
                     },
                     Ident {
-                        value: "table1".to_string(),
+                        value: "table1".into(),
                         quote_style: Some('`'),
                         span: Span::empty(),
                     }
                 ]),
                 ObjectName::from(vec![Ident {
-                    value: "table2".to_string(),
+                    value: "table2".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }])
@@ -260,7 +264,7 @@ fn parse_show_columns() {
             show_token: AttachedToken::empty(),
             extended: false,
             full: false,
-            show_options: ShowStatementOptions {
+            show_options: Box::new(ShowStatementOptions {
                 show_in: Some(ShowStatementIn {
                     clause: ShowStatementInClause::FROM,
                     parent_type: None,
@@ -270,7 +274,7 @@ fn parse_show_columns() {
                 limit_from: None,
                 limit: None,
                 starts_with: None,
-            }
+            })
         }
     );
     assert_eq!(
@@ -279,7 +283,7 @@ fn parse_show_columns() {
             show_token: AttachedToken::empty(),
             extended: false,
             full: false,
-            show_options: ShowStatementOptions {
+            show_options: Box::new(ShowStatementOptions {
                 show_in: Some(ShowStatementIn {
                     clause: ShowStatementInClause::FROM,
                     parent_type: None,
@@ -292,7 +296,7 @@ fn parse_show_columns() {
                 limit_from: None,
                 limit: None,
                 starts_with: None,
-            }
+            })
         }
     );
     assert_eq!(
@@ -301,7 +305,7 @@ fn parse_show_columns() {
             show_token: AttachedToken::empty(),
             extended: true,
             full: false,
-            show_options: ShowStatementOptions {
+            show_options: Box::new(ShowStatementOptions {
                 show_in: Some(ShowStatementIn {
                     clause: ShowStatementInClause::FROM,
                     parent_type: None,
@@ -311,7 +315,7 @@ fn parse_show_columns() {
                 limit_from: None,
                 limit: None,
                 starts_with: None,
-            }
+            })
         }
     );
     assert_eq!(
@@ -320,7 +324,7 @@ fn parse_show_columns() {
             show_token: AttachedToken::empty(),
             extended: false,
             full: true,
-            show_options: ShowStatementOptions {
+            show_options: Box::new(ShowStatementOptions {
                 show_in: Some(ShowStatementIn {
                     clause: ShowStatementInClause::FROM,
                     parent_type: None,
@@ -330,7 +334,7 @@ fn parse_show_columns() {
                 limit_from: None,
                 limit: None,
                 starts_with: None,
-            }
+            })
         }
     );
     assert_eq!(
@@ -339,7 +343,7 @@ fn parse_show_columns() {
             show_token: AttachedToken::empty(),
             extended: false,
             full: false,
-            show_options: ShowStatementOptions {
+            show_options: Box::new(ShowStatementOptions {
                 show_in: Some(ShowStatementIn {
                     clause: ShowStatementInClause::FROM,
                     parent_type: None,
@@ -351,7 +355,7 @@ fn parse_show_columns() {
                 limit_from: None,
                 limit: None,
                 starts_with: None,
-            }
+            })
         }
     );
     assert_eq!(
@@ -360,7 +364,7 @@ fn parse_show_columns() {
             show_token: AttachedToken::empty(),
             extended: false,
             full: false,
-            show_options: ShowStatementOptions {
+            show_options: Box::new(ShowStatementOptions {
                 show_in: Some(ShowStatementIn {
                     clause: ShowStatementInClause::FROM,
                     parent_type: None,
@@ -372,7 +376,7 @@ fn parse_show_columns() {
                 limit_from: None,
                 limit: None,
                 starts_with: None,
-            }
+            })
         }
     );
     mysql_and_generic()
@@ -393,7 +397,7 @@ fn parse_show_status() {
         mysql_and_generic().verified_stmt("SHOW SESSION STATUS LIKE 'ssl_cipher'"),
         Statement::ShowStatus {
             token: AttachedToken::empty(),
-            filter: Some(ShowStatementFilter::Like("ssl_cipher".into())),
+            filter: Some(Box::new(ShowStatementFilter::Like("ssl_cipher".into()))),
             session: true,
             global: false
         }
@@ -402,7 +406,7 @@ fn parse_show_status() {
         mysql_and_generic().verified_stmt("SHOW GLOBAL STATUS LIKE 'ssl_cipher'"),
         Statement::ShowStatus {
             token: AttachedToken::empty(),
-            filter: Some(ShowStatementFilter::Like("ssl_cipher".into())),
+            filter: Some(Box::new(ShowStatementFilter::Like("ssl_cipher".into()))),
             session: false,
             global: true
         }
@@ -411,9 +415,9 @@ fn parse_show_status() {
         mysql_and_generic().verified_stmt("SHOW STATUS WHERE value = 2"),
         Statement::ShowStatus {
             token: AttachedToken::empty(),
-            filter: Some(ShowStatementFilter::Where(
+            filter: Some(Box::new(ShowStatementFilter::Where(
                 mysql_and_generic().verified_expr("value = 2")
-            )),
+            ))),
             session: false,
             global: false
         }
@@ -431,13 +435,13 @@ fn parse_show_tables() {
             extended: false,
             full: false,
             external: false,
-            show_options: ShowStatementOptions {
+            show_options: Box::new(ShowStatementOptions {
                 starts_with: None,
                 limit: None,
                 limit_from: None,
                 show_in: None,
                 filter_position: None
-            }
+            })
         }
     );
     assert_eq!(
@@ -449,7 +453,7 @@ fn parse_show_tables() {
             extended: false,
             full: false,
             external: false,
-            show_options: ShowStatementOptions {
+            show_options: Box::new(ShowStatementOptions {
                 starts_with: None,
                 limit: None,
                 limit_from: None,
@@ -459,7 +463,7 @@ fn parse_show_tables() {
                     parent_name: Some(ObjectName::from(vec![Ident::new("mydb")])),
                 }),
                 filter_position: None
-            }
+            })
         }
     );
     assert_eq!(
@@ -471,13 +475,13 @@ fn parse_show_tables() {
             extended: true,
             full: false,
             external: false,
-            show_options: ShowStatementOptions {
+            show_options: Box::new(ShowStatementOptions {
                 starts_with: None,
                 limit: None,
                 limit_from: None,
                 show_in: None,
                 filter_position: None
-            }
+            })
         }
     );
     assert_eq!(
@@ -489,13 +493,13 @@ fn parse_show_tables() {
             extended: false,
             full: true,
             external: false,
-            show_options: ShowStatementOptions {
+            show_options: Box::new(ShowStatementOptions {
                 starts_with: None,
                 limit: None,
                 limit_from: None,
                 show_in: None,
                 filter_position: None
-            }
+            })
         }
     );
     assert_eq!(
@@ -507,7 +511,7 @@ fn parse_show_tables() {
             extended: false,
             full: false,
             external: false,
-            show_options: ShowStatementOptions {
+            show_options: Box::new(ShowStatementOptions {
                 starts_with: None,
                 limit: None,
                 limit_from: None,
@@ -515,7 +519,7 @@ fn parse_show_tables() {
                 filter_position: Some(ShowStatementFilterPosition::Suffix(
                     ShowStatementFilter::Like("pattern".into())
                 ))
-            }
+            })
         }
     );
     assert_eq!(
@@ -527,7 +531,7 @@ fn parse_show_tables() {
             extended: false,
             full: false,
             external: false,
-            show_options: ShowStatementOptions {
+            show_options: Box::new(ShowStatementOptions {
                 starts_with: None,
                 limit: None,
                 limit_from: None,
@@ -535,7 +539,7 @@ fn parse_show_tables() {
                 filter_position: Some(ShowStatementFilterPosition::Suffix(
                     ShowStatementFilter::Where(mysql_and_generic().verified_expr("1 = 2"))
                 ))
-            }
+            })
         }
     );
     mysql_and_generic().verified_stmt("SHOW TABLES IN mydb");
@@ -598,16 +602,16 @@ fn parse_show_collation() {
         mysql_and_generic().verified_stmt("SHOW COLLATION LIKE 'pattern'"),
         Statement::ShowCollation {
             show_token: AttachedToken::empty(),
-            filter: Some(ShowStatementFilter::Like("pattern".into())),
+            filter: Some(Box::new(ShowStatementFilter::Like("pattern".into()))),
         }
     );
     assert_eq!(
         mysql_and_generic().verified_stmt("SHOW COLLATION WHERE 1 = 2"),
         Statement::ShowCollation {
             show_token: AttachedToken::empty(),
-            filter: Some(ShowStatementFilter::Where(
+            filter: Some(Box::new(ShowStatementFilter::Where(
                 mysql_and_generic().verified_expr("1 = 2")
-            )),
+            ))),
         }
     );
 }
@@ -649,14 +653,14 @@ fn parse_set_variables() {
     mysql_and_generic().verified_stmt("SET sql_mode = CONCAT(@@sql_mode, ',STRICT_TRANS_TABLES')");
     assert_eq!(
         mysql_and_generic().verified_stmt("SET LOCAL autocommit = 1"),
-        Statement::Set(SetStatement {
+        Statement::Set(Box::new(SetStatement {
             token: AttachedToken::empty(),
             inner: Set::SingleAssignment {
                 scope: Some(ContextModifier::Local),
                 variable: ObjectName::from(vec!["autocommit".into()]),
                 values: vec![Expr::value(number("1"))],
             }
-        })
+        }))
     );
 }
 
@@ -664,7 +668,8 @@ fn parse_set_variables() {
 fn parse_create_table_auto_increment() {
     let sql = "CREATE TABLE foo (bar INT PRIMARY KEY AUTO_INCREMENT)";
     match mysql().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { name, columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { name, columns, .. } = Box::into_owned(create_table);
             assert_eq!(name.to_string(), "foo");
             assert_eq!(
                 vec![ColumnDef {
@@ -690,6 +695,7 @@ fn parse_create_table_auto_increment() {
                             )]),
                         },
                     ],
+                    comments: Comments::default(),
                 }],
                 columns
             );
@@ -713,7 +719,7 @@ fn table_constraint_unique_primary_ctor(
         .into_iter()
         .map(|ident| IndexColumn {
             column: OrderByExpr {
-                expr: Expr::Identifier(ident),
+                expr: Expr::Identifier(Box::new(ident)),
                 options: OrderByOptions {
                     asc: None,
                     nulls_first: None,
@@ -757,12 +763,13 @@ fn parse_create_table_primary_and_unique_key() {
 
     for (sql, index_type_display) in sqls.iter().zip(index_type_display) {
         match mysql().one_statement_parses_to(sql, "") {
-            Statement::CreateTable(CreateTable {
-                name,
-                columns,
-                constraints,
-                ..
-            }) => {
+            Statement::CreateTable(create_table) => {
+                let CreateTable {
+                    name,
+                    columns,
+                    constraints,
+                    ..
+                } = Box::into_owned(create_table);
                 assert_eq!(name.to_string(), "foo");
 
                 let expected_constraint = table_constraint_unique_primary_ctor(
@@ -801,6 +808,7 @@ fn parse_create_table_primary_and_unique_key() {
                                     ]),
                                 },
                             ],
+                            comments: Comments::default(),
                         },
                         ColumnDef {
                             name: Ident::new("bar"),
@@ -809,6 +817,7 @@ fn parse_create_table_primary_and_unique_key() {
                                 name: None,
                                 option: ColumnOption::NotNull,
                             },],
+                            comments: Comments::default(),
                         },
                     ],
                     columns
@@ -828,9 +837,10 @@ fn parse_create_table_primary_and_unique_key_with_index_options() {
 
     for (sql, index_type_display) in sqls.iter().zip(index_type_display) {
         match mysql_and_generic().one_statement_parses_to(sql, "") {
-            Statement::CreateTable(CreateTable {
-                name, constraints, ..
-            }) => {
+            Statement::CreateTable(create_table) => {
+                let CreateTable {
+                    name, constraints, ..
+                } = Box::into_owned(create_table);
                 assert_eq!(name.to_string(), "foo");
 
                 let expected_constraint = table_constraint_unique_primary_ctor(
@@ -870,14 +880,17 @@ fn parse_prefix_key_part() {
         "ALTER TABLE tab ADD FULLTEXT INDEX (textcol(10))",
         "CREATE TABLE t (textcol TEXT, INDEX idx_index (textcol(10)))",
     ] {
-        match index_column(mysql_and_generic().verified_stmt(sql)) {
-            Expr::Function(Function {
+        let Expr::Function(function) = index_column(mysql_and_generic().verified_stmt(sql)) else {
+            unreachable!()
+        };
+        match &*function {
+            Function {
                 name,
                 args: FunctionArguments::List(FunctionArgumentList { args, .. }),
                 ..
-            }) => {
+            } => {
                 assert_eq!(name.to_string(), "textcol");
-                assert_eq!(args, expected);
+                assert_eq!(*args, expected);
             }
             expr => panic!("unexpected expression {expr} for {sql}"),
         }
@@ -892,10 +905,13 @@ fn test_functional_key_part() {
                 .verified_stmt("CREATE INDEX idx_index ON t((col COLLATE utf8mb4_bin) DESC)")
         ),
         Expr::Nested(Box::new(Expr::Collate {
-            expr: Box::new(Expr::Identifier("col".into())),
-            collation: ObjectName(vec![sqlparser::ast::ObjectNamePart::Identifier(
-                Ident::new("utf8mb4_bin")
-            )]),
+            expr: Box::new(Expr::Identifier(Box::new("col".into()))),
+            collation: ObjectName(
+                vec![sqlparser::ast::ObjectNamePart::Identifier(Ident::new(
+                    "utf8mb4_bin"
+                ))]
+                .into()
+            ),
         }))
     );
     assert_eq!(
@@ -905,13 +921,13 @@ fn test_functional_key_part() {
         Expr::Nested(Box::new(Expr::Cast {
             kind: CastKind::Cast,
             expr: Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("col"))),
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("col")))),
                 op: BinaryOperator::LongArrow,
-                right: Box::new(Expr::Value(
-                    Value::SingleQuotedString("$.id".to_string()).with_empty_span()
-                )),
+                right: Box::new(Expr::Value(Box::new(
+                    Value::SingleQuotedString("$.id".into()).with_empty_span()
+                ))),
             }),
-            data_type: DataType::Unsigned,
+            data_type: Box::new(DataType::Unsigned),
             format: None,
         })),
     );
@@ -927,9 +943,10 @@ fn parse_create_table_primary_and_unique_key_with_index_type() {
 
     for (sql, index_type_display) in sqls.iter().zip(index_type_display) {
         match mysql_and_generic().one_statement_parses_to(sql, "") {
-            Statement::CreateTable(CreateTable {
-                name, constraints, ..
-            }) => {
+            Statement::CreateTable(create_table) => {
+                let CreateTable {
+                    name, constraints, ..
+                } = Box::into_owned(create_table);
                 assert_eq!(name.to_string(), "foo");
 
                 let expected_constraint = table_constraint_unique_primary_ctor(
@@ -970,11 +987,12 @@ fn parse_create_table_comment() {
 
     for sql in [without_equal, with_equal] {
         match mysql().verified_stmt(sql) {
-            Statement::CreateTable(CreateTable {
-                name,
-                table_options,
-                ..
-            }) => {
+            Statement::CreateTable(create_table) => {
+                let CreateTable {
+                    name,
+                    table_options,
+                    ..
+                } = Box::into_owned(create_table);
                 assert_eq!(name.to_string(), "foo");
 
                 let plain_options = match table_options {
@@ -999,11 +1017,12 @@ fn parse_create_table_auto_increment_offset() {
         "CREATE TABLE foo (bar INT NOT NULL AUTO_INCREMENT) ENGINE = InnoDB AUTO_INCREMENT = 123";
 
     match mysql().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable {
-            name,
-            table_options,
-            ..
-        }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable {
+                name,
+                table_options,
+                ..
+            } = Box::into_owned(create_table);
             assert_eq!(name.to_string(), "foo");
 
             let plain_options = match table_options {
@@ -1013,7 +1032,7 @@ fn parse_create_table_auto_increment_offset() {
 
             assert!(plain_options.contains(&SqlOption::KeyValue {
                 key: Ident::new("AUTO_INCREMENT"),
-                value: Expr::Value(test_utils::number("123").with_empty_span())
+                value: Expr::Value(Box::new(test_utils::number("123").with_empty_span()))
             }));
         }
         _ => unreachable!(),
@@ -1028,11 +1047,12 @@ fn parse_create_table_multiple_options_order_independent() {
 
     for sql in [sql1, sql2, sql3] {
         match mysql().parse_sql_statements(sql).unwrap().pop().unwrap() {
-            Statement::CreateTable(CreateTable {
-                name,
-                table_options,
-                ..
-            }) => {
+            Statement::CreateTable(create_table) => {
+                let CreateTable {
+                    name,
+                    table_options,
+                    ..
+                } = Box::into_owned(create_table);
                 assert_eq!(name.to_string(), "mytable");
 
                 let plain_options = match table_options {
@@ -1050,7 +1070,7 @@ fn parse_create_table_multiple_options_order_independent() {
 
                 assert!(plain_options.contains(&SqlOption::KeyValue {
                     key: Ident::new("KEY_BLOCK_SIZE"),
-                    value: Expr::Value(test_utils::number("8").with_empty_span())
+                    value: Expr::Value(Box::new(test_utils::number("8").with_empty_span()))
                 }));
 
                 assert!(plain_options
@@ -1058,7 +1078,7 @@ fn parse_create_table_multiple_options_order_independent() {
 
                 assert!(plain_options.contains(&SqlOption::KeyValue {
                     key: Ident::new("ROW_FORMAT"),
-                    value: Expr::Identifier(Ident::new("DYNAMIC".to_owned()))
+                    value: Expr::Identifier(Box::new(Ident::new("DYNAMIC".to_owned())))
                 }));
             }
             _ => unreachable!(),
@@ -1072,11 +1092,12 @@ fn parse_create_table_with_all_table_options() {
         "CREATE TABLE foo (bar INT NOT NULL AUTO_INCREMENT) ENGINE = InnoDB AUTO_INCREMENT = 123 DEFAULT CHARSET = utf8mb4 COLLATE = utf8mb4_0900_ai_ci INSERT_METHOD = FIRST KEY_BLOCK_SIZE = 8 ROW_FORMAT = DYNAMIC DATA DIRECTORY = '/var/lib/mysql/data' INDEX DIRECTORY = '/var/lib/mysql/index' PACK_KEYS = 1 STATS_AUTO_RECALC = 1 STATS_PERSISTENT = 0 STATS_SAMPLE_PAGES = 128 DELAY_KEY_WRITE = 1 COMPRESSION = 'ZLIB' ENCRYPTION = 'Y' MAX_ROWS = 10000 MIN_ROWS = 10 AUTOEXTEND_SIZE = 64 AVG_ROW_LENGTH = 128 CHECKSUM = 1 CONNECTION = 'mysql://localhost' ENGINE_ATTRIBUTE = 'primary' PASSWORD = 'secure_password' SECONDARY_ENGINE_ATTRIBUTE = 'secondary_attr' START TRANSACTION TABLESPACE my_tablespace STORAGE DISK UNION = (table1, table2, table3)";
 
     match mysql().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable {
-            name,
-            table_options,
-            ..
-        }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable {
+                name,
+                table_options,
+                ..
+            } = Box::into_owned(create_table);
             assert_eq!(name, vec![Ident::new("foo".to_owned())].into());
 
             let plain_options = match table_options {
@@ -1094,11 +1115,11 @@ fn parse_create_table_with_all_table_options() {
 
             assert!(plain_options.contains(&SqlOption::KeyValue {
                 key: Ident::new("COLLATE"),
-                value: Expr::Identifier(Ident::new("utf8mb4_0900_ai_ci".to_owned()))
+                value: Expr::Identifier(Box::new(Ident::new("utf8mb4_0900_ai_ci".to_owned())))
             }));
             assert!(plain_options.contains(&SqlOption::KeyValue {
                 key: Ident::new("DEFAULT CHARSET"),
-                value: Expr::Identifier(Ident::new("utf8mb4".to_owned()))
+                value: Expr::Identifier(Box::new(Ident::new("utf8mb4".to_owned())))
             }));
             assert!(plain_options.contains(&SqlOption::KeyValue {
                 key: Ident::new("AUTO_INCREMENT"),
@@ -1110,7 +1131,7 @@ fn parse_create_table_with_all_table_options() {
             }));
             assert!(plain_options.contains(&SqlOption::KeyValue {
                 key: Ident::new("ROW_FORMAT"),
-                value: Expr::Identifier(Ident::new("DYNAMIC".to_owned()))
+                value: Expr::Identifier(Box::new(Ident::new("DYNAMIC".to_owned())))
             }));
             assert!(plain_options.contains(&SqlOption::KeyValue {
                 key: Ident::new("PACK_KEYS"),
@@ -1134,15 +1155,15 @@ fn parse_create_table_with_all_table_options() {
             }));
             assert!(plain_options.contains(&SqlOption::KeyValue {
                 key: Ident::new("INSERT_METHOD"),
-                value: Expr::Identifier(Ident::new("FIRST".to_owned()))
+                value: Expr::Identifier(Box::new(Ident::new("FIRST".to_owned())))
             }));
             assert!(plain_options.contains(&SqlOption::KeyValue {
                 key: Ident::new("COMPRESSION"),
-                value: Expr::value(Value::SingleQuotedString("ZLIB".to_owned()))
+                value: Expr::value(Value::SingleQuotedString("ZLIB".into()))
             }));
             assert!(plain_options.contains(&SqlOption::KeyValue {
                 key: Ident::new("ENCRYPTION"),
-                value: Expr::value(Value::SingleQuotedString("Y".to_owned()))
+                value: Expr::value(Value::SingleQuotedString("Y".into()))
             }));
             assert!(plain_options.contains(&SqlOption::KeyValue {
                 key: Ident::new("MAX_ROWS"),
@@ -1166,19 +1187,19 @@ fn parse_create_table_with_all_table_options() {
             }));
             assert!(plain_options.contains(&SqlOption::KeyValue {
                 key: Ident::new("CONNECTION"),
-                value: Expr::value(Value::SingleQuotedString("mysql://localhost".to_owned()))
+                value: Expr::value(Value::SingleQuotedString("mysql://localhost".into()))
             }));
             assert!(plain_options.contains(&SqlOption::KeyValue {
                 key: Ident::new("ENGINE_ATTRIBUTE"),
-                value: Expr::value(Value::SingleQuotedString("primary".to_owned()))
+                value: Expr::value(Value::SingleQuotedString("primary".into()))
             }));
             assert!(plain_options.contains(&SqlOption::KeyValue {
                 key: Ident::new("PASSWORD"),
-                value: Expr::value(Value::SingleQuotedString("secure_password".to_owned()))
+                value: Expr::value(Value::SingleQuotedString("secure_password".into()))
             }));
             assert!(plain_options.contains(&SqlOption::KeyValue {
                 key: Ident::new("SECONDARY_ENGINE_ATTRIBUTE"),
-                value: Expr::value(Value::SingleQuotedString("secondary_attr".to_owned()))
+                value: Expr::value(Value::SingleQuotedString("secondary_attr".into()))
             }));
             assert!(plain_options.contains(&SqlOption::Ident(Ident::new(
                 "START TRANSACTION".to_owned()
@@ -1204,11 +1225,11 @@ fn parse_create_table_with_all_table_options() {
 
             assert!(plain_options.contains(&SqlOption::KeyValue {
                 key: Ident::new("DATA DIRECTORY"),
-                value: Expr::value(Value::SingleQuotedString("/var/lib/mysql/data".to_owned()))
+                value: Expr::value(Value::SingleQuotedString("/var/lib/mysql/data".into()))
             }));
             assert!(plain_options.contains(&SqlOption::KeyValue {
                 key: Ident::new("INDEX DIRECTORY"),
-                value: Expr::value(Value::SingleQuotedString("/var/lib/mysql/index".to_owned()))
+                value: Expr::value(Value::SingleQuotedString("/var/lib/mysql/index".into()))
             }));
         }
         _ => unreachable!(),
@@ -1219,7 +1240,8 @@ fn parse_create_table_with_all_table_options() {
 fn parse_create_table_set_enum() {
     let sql = "CREATE TABLE foo (bar SET('a', 'b'), baz ENUM('a', 'b'))";
     match mysql().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { name, columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { name, columns, .. } = Box::into_owned(create_table);
             assert_eq!(name.to_string(), "foo");
             assert_eq!(
                 vec![
@@ -1227,6 +1249,7 @@ fn parse_create_table_set_enum() {
                         name: Ident::new("bar"),
                         data_type: DataType::Set(vec!["a".to_string(), "b".to_string()]),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("baz"),
@@ -1238,6 +1261,7 @@ fn parse_create_table_set_enum() {
                             None
                         ),
                         options: vec![],
+                        comments: Comments::default(),
                     }
                 ],
                 columns
@@ -1251,18 +1275,20 @@ fn parse_create_table_set_enum() {
 fn parse_create_table_engine_default_charset() {
     let sql = "CREATE TABLE foo (id INT(11)) ENGINE = InnoDB DEFAULT CHARSET = utf8mb3";
     match mysql().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable {
-            name,
-            columns,
-            table_options,
-            ..
-        }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable {
+                name,
+                columns,
+                table_options,
+                ..
+            } = Box::into_owned(create_table);
             assert_eq!(name.to_string(), "foo");
             assert_eq!(
                 vec![ColumnDef {
                     name: Ident::new("id"),
                     data_type: DataType::Int(Some(11)),
                     options: vec![],
+                    comments: Comments::default(),
                 },],
                 columns
             );
@@ -1274,7 +1300,7 @@ fn parse_create_table_engine_default_charset() {
 
             assert!(plain_options.contains(&SqlOption::KeyValue {
                 key: Ident::new("DEFAULT CHARSET"),
-                value: Expr::Identifier(Ident::new("utf8mb3".to_owned()))
+                value: Expr::Identifier(Box::new(Ident::new("utf8mb3".to_owned())))
             }));
 
             assert!(plain_options.contains(&SqlOption::NamedParenthesizedList(
@@ -1293,18 +1319,20 @@ fn parse_create_table_engine_default_charset() {
 fn parse_create_table_collate() {
     let sql = "CREATE TABLE foo (id INT(11)) COLLATE = utf8mb4_0900_ai_ci";
     match mysql().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable {
-            name,
-            columns,
-            table_options,
-            ..
-        }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable {
+                name,
+                columns,
+                table_options,
+                ..
+            } = Box::into_owned(create_table);
             assert_eq!(name.to_string(), "foo");
             assert_eq!(
                 vec![ColumnDef {
                     name: Ident::new("id"),
                     data_type: DataType::Int(Some(11)),
                     options: vec![],
+                    comments: Comments::default(),
                 },],
                 columns
             );
@@ -1316,7 +1344,7 @@ fn parse_create_table_collate() {
 
             assert!(plain_options.contains(&SqlOption::KeyValue {
                 key: Ident::new("COLLATE"),
-                value: Expr::Identifier(Ident::new("utf8mb4_0900_ai_ci".to_owned()))
+                value: Expr::Identifier(Box::new(Ident::new("utf8mb4_0900_ai_ci".to_owned())))
             }));
         }
         _ => unreachable!(),
@@ -1327,12 +1355,13 @@ fn parse_create_table_collate() {
 fn parse_create_table_both_options_and_as_query() {
     let sql = "CREATE TABLE foo (id INT(11)) ENGINE = InnoDB DEFAULT CHARSET = utf8mb3 COLLATE = utf8mb4_0900_ai_ci AS SELECT 1";
     match mysql_and_generic().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable {
-            name,
-            query,
-            table_options,
-            ..
-        }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable {
+                name,
+                query,
+                table_options,
+                ..
+            } = Box::into_owned(create_table);
             assert_eq!(name.to_string(), "foo");
 
             let plain_options = match table_options {
@@ -1342,14 +1371,15 @@ fn parse_create_table_both_options_and_as_query() {
 
             assert!(plain_options.contains(&SqlOption::KeyValue {
                 key: Ident::new("COLLATE"),
-                value: Expr::Identifier(Ident::new("utf8mb4_0900_ai_ci".to_owned()))
+                value: Expr::Identifier(Box::new(Ident::new("utf8mb4_0900_ai_ci".to_owned())))
             }));
 
             assert_eq!(
                 query.unwrap().body.as_select().unwrap().projection,
-                vec![SelectItem::UnnamedExpr(Expr::Value(
-                    (number("1")).with_empty_span()
-                ))]
+                vec![SelectItem::UnnamedExpr {
+                    expr: Expr::Value(Box::new((number("1")).with_empty_span())),
+                    comments: Comments::default(),
+                }]
             );
         }
         _ => unreachable!(),
@@ -1367,7 +1397,8 @@ fn parse_create_table_both_options_and_as_query() {
 fn parse_create_table_comment_character_set() {
     let sql = "CREATE TABLE foo (s TEXT CHARACTER SET utf8mb4 COMMENT 'comment')";
     match mysql().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { name, columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { name, columns, .. } = Box::into_owned(create_table);
             assert_eq!(name.to_string(), "foo");
             assert_eq!(
                 vec![ColumnDef {
@@ -1385,6 +1416,7 @@ fn parse_create_table_comment_character_set() {
                             option: ColumnOption::Comment("comment".to_string())
                         }
                     ],
+                    comments: Comments::default(),
                 },],
                 columns
             );
@@ -1420,7 +1452,8 @@ fn parse_create_table_options_comma_separated() {
 fn parse_quote_identifiers() {
     let sql = "CREATE TABLE `PRIMARY` (`BEGIN` INT PRIMARY KEY)";
     match mysql().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { name, columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { name, columns, .. } = Box::into_owned(create_table);
             assert_eq!(name.to_string(), "`PRIMARY`");
             assert_eq!(
                 vec![ColumnDef {
@@ -1438,6 +1471,7 @@ fn parse_quote_identifiers() {
                             period_without_overlaps: None,
                         }),
                     }],
+                    comments: Comments::default(),
                 }],
                 columns
             );
@@ -1458,15 +1492,19 @@ fn parse_escaped_quote_identifiers_with_escape() {
                 distinct: None,
                 top: None,
                 top_before_distinct: false,
-                projection: vec![SelectItem::UnnamedExpr(Expr::Identifier(Ident {
-                    value: "quoted ` identifier".into(),
-                    quote_style: Some('`'),
-                    span: Span::empty(),
-                }))],
+                projection: vec![SelectItem::UnnamedExpr {
+                    expr: Expr::Identifier(Box::new(Ident {
+                        value: "quoted ` identifier".into(),
+                        quote_style: Some('`'),
+                        span: Span::empty(),
+                    })),
+                    comments: Comments::default(),
+                }]
+                .into(),
                 into: None,
-                from: vec![],
+                from: AstVec::new(),
                 selection: None,
-                group_by: GroupByExpr::Expressions(vec![], vec![]),
+                group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
                 having: None,
                 qualify: None,
                 named_window: vec![],
@@ -1476,8 +1514,9 @@ fn parse_escaped_quote_identifiers_with_escape() {
             order_by: None,
             limit_clause: None,
             fetch: None,
-            locks: vec![],
+            locks: AstVec::new(),
             for_clause: None,
+            comments: Comments::default(),
         }))
     );
 }
@@ -1493,6 +1532,9 @@ fn parse_escaped_quote_identifiers_with_no_escape() {
                 bracket_quoted_identifiers: false,
                 unescape: false,
                 require_semicolon_stmt_delimiter: true,
+                attach_comments: false,
+                chunk_pool: None,
+                limits: ParserLimits::default(),
             }
         )
         .verified_stmt(sql),
@@ -1503,15 +1545,19 @@ fn parse_escaped_quote_identifiers_with_no_escape() {
                 distinct: None,
                 top: None,
                 top_before_distinct: false,
-                projection: vec![SelectItem::UnnamedExpr(Expr::Identifier(Ident {
-                    value: "quoted `` identifier".into(),
-                    quote_style: Some('`'),
-                    span: Span::empty(),
-                }))],
+                projection: vec![SelectItem::UnnamedExpr {
+                    expr: Expr::Identifier(Box::new(Ident {
+                        value: "quoted `` identifier".into(),
+                        quote_style: Some('`'),
+                        span: Span::empty(),
+                    })),
+                    comments: Comments::default(),
+                }]
+                .into(),
                 into: None,
-                from: vec![],
+                from: AstVec::new(),
                 selection: None,
-                group_by: GroupByExpr::Expressions(vec![], vec![]),
+                group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
                 having: None,
                 qualify: None,
                 named_window: vec![],
@@ -1521,8 +1567,9 @@ fn parse_escaped_quote_identifiers_with_no_escape() {
             order_by: None,
             limit_clause: None,
             fetch: None,
-            locks: vec![],
+            locks: AstVec::new(),
             for_clause: None,
+            comments: Comments::default(),
         }))
     );
 }
@@ -1540,15 +1587,19 @@ fn parse_escaped_backticks_with_escape() {
                 distinct: None,
                 top: None,
                 top_before_distinct: false,
-                projection: vec![SelectItem::UnnamedExpr(Expr::Identifier(Ident {
-                    value: "`quoted identifier`".into(),
-                    quote_style: Some('`'),
-                    span: Span::empty(),
-                }))],
+                projection: vec![SelectItem::UnnamedExpr {
+                    expr: Expr::Identifier(Box::new(Ident {
+                        value: "`quoted identifier`".into(),
+                        quote_style: Some('`'),
+                        span: Span::empty(),
+                    })),
+                    comments: Comments::default(),
+                }]
+                .into(),
                 into: None,
-                from: vec![],
+                from: AstVec::new(),
                 selection: None,
-                group_by: GroupByExpr::Expressions(vec![], vec![]),
+                group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
                 having: None,
                 qualify: None,
                 named_window: vec![],
@@ -1558,8 +1609,9 @@ fn parse_escaped_backticks_with_escape() {
             order_by: None,
             limit_clause: None,
             fetch: None,
-            locks: vec![],
+            locks: AstVec::new(),
             for_clause: None,
+            comments: Comments::default(),
         }))
     );
 }
@@ -1581,15 +1633,19 @@ fn parse_escaped_backticks_with_no_escape() {
                 distinct: None,
                 top: None,
                 top_before_distinct: false,
-                projection: vec![SelectItem::UnnamedExpr(Expr::Identifier(Ident {
-                    value: "``quoted identifier``".into(),
-                    quote_style: Some('`'),
-                    span: Span::empty(),
-                }))],
+                projection: vec![SelectItem::UnnamedExpr {
+                    expr: Expr::Identifier(Box::new(Ident {
+                        value: "``quoted identifier``".into(),
+                        quote_style: Some('`'),
+                        span: Span::empty(),
+                    })),
+                    comments: Comments::default(),
+                }]
+                .into(),
                 into: None,
-                from: vec![],
+                from: AstVec::new(),
                 selection: None,
-                group_by: GroupByExpr::Expressions(vec![], vec![]),
+                group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
                 having: None,
                 qualify: None,
                 named_window: vec![],
@@ -1599,8 +1655,9 @@ fn parse_escaped_backticks_with_no_escape() {
             order_by: None,
             limit_clause: None,
             fetch: None,
-            locks: vec![],
+            locks: AstVec::new(),
             for_clause: None,
+            comments: Comments::default(),
         }))
     );
 }
@@ -1644,7 +1701,8 @@ fn check_roundtrip_of_escaped_string() {
 fn parse_create_table_with_minimum_display_width() {
     let sql = "CREATE TABLE foo (bar_tinyint TINYINT(3), bar_smallint SMALLINT(5), bar_mediumint MEDIUMINT(6), bar_int INT(11), bar_bigint BIGINT(20))";
     match mysql().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { name, columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { name, columns, .. } = Box::into_owned(create_table);
             assert_eq!(name.to_string(), "foo");
             assert_eq!(
                 vec![
@@ -1652,26 +1710,31 @@ fn parse_create_table_with_minimum_display_width() {
                         name: Ident::new("bar_tinyint"),
                         data_type: DataType::TinyInt(Some(3)),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_smallint"),
                         data_type: DataType::SmallInt(Some(5)),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_mediumint"),
                         data_type: DataType::MediumInt(Some(6)),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_int"),
                         data_type: DataType::Int(Some(11)),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_bigint"),
                         data_type: DataType::BigInt(Some(20)),
                         options: vec![],
+                        comments: Comments::default(),
                     }
                 ],
                 columns
@@ -1685,7 +1748,8 @@ fn parse_create_table_with_minimum_display_width() {
 fn parse_create_table_unsigned() {
     let sql = "CREATE TABLE foo (bar_tinyint TINYINT(3) UNSIGNED, bar_smallint SMALLINT(5) UNSIGNED, bar_mediumint MEDIUMINT(13) UNSIGNED, bar_int INT(11) UNSIGNED, bar_bigint BIGINT(20) UNSIGNED)";
     match mysql().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { name, columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { name, columns, .. } = Box::into_owned(create_table);
             assert_eq!(name.to_string(), "foo");
             assert_eq!(
                 vec![
@@ -1693,26 +1757,31 @@ fn parse_create_table_unsigned() {
                         name: Ident::new("bar_tinyint"),
                         data_type: DataType::TinyIntUnsigned(Some(3)),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_smallint"),
                         data_type: DataType::SmallIntUnsigned(Some(5)),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_mediumint"),
                         data_type: DataType::MediumIntUnsigned(Some(13)),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_int"),
                         data_type: DataType::IntUnsigned(Some(11)),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_bigint"),
                         data_type: DataType::BigIntUnsigned(Some(20)),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                 ],
                 columns
@@ -1727,7 +1796,8 @@ fn parse_signed_data_types() {
     let sql = "CREATE TABLE foo (bar_tinyint TINYINT(3) SIGNED, bar_smallint SMALLINT(5) SIGNED, bar_mediumint MEDIUMINT(13) SIGNED, bar_int INT(11) SIGNED, bar_bigint BIGINT(20) SIGNED)";
     let canonical = "CREATE TABLE foo (bar_tinyint TINYINT(3), bar_smallint SMALLINT(5), bar_mediumint MEDIUMINT(13), bar_int INT(11), bar_bigint BIGINT(20))";
     match mysql().one_statement_parses_to(sql, canonical) {
-        Statement::CreateTable(CreateTable { name, columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { name, columns, .. } = Box::into_owned(create_table);
             assert_eq!(name.to_string(), "foo");
             assert_eq!(
                 vec![
@@ -1735,26 +1805,31 @@ fn parse_signed_data_types() {
                         name: Ident::new("bar_tinyint"),
                         data_type: DataType::TinyInt(Some(3)),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_smallint"),
                         data_type: DataType::SmallInt(Some(5)),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_mediumint"),
                         data_type: DataType::MediumInt(Some(13)),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_int"),
                         data_type: DataType::Int(Some(11)),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_bigint"),
                         data_type: DataType::BigInt(Some(20)),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                 ],
                 columns
@@ -1771,7 +1846,8 @@ fn parse_signed_data_types() {
 fn parse_deprecated_mysql_unsigned_data_types() {
     let sql = "CREATE TABLE foo (bar_decimal DECIMAL UNSIGNED, bar_decimal_prec DECIMAL(10) UNSIGNED, bar_decimal_scale DECIMAL(10,2) UNSIGNED, bar_dec DEC UNSIGNED, bar_dec_prec DEC(10) UNSIGNED, bar_dec_scale DEC(10,2) UNSIGNED, bar_float FLOAT UNSIGNED, bar_float_prec FLOAT(10) UNSIGNED, bar_float_scale FLOAT(10,2) UNSIGNED, bar_double DOUBLE UNSIGNED, bar_double_prec DOUBLE(10) UNSIGNED, bar_double_scale DOUBLE(10,2) UNSIGNED, bar_real REAL UNSIGNED, bar_double_precision DOUBLE PRECISION UNSIGNED)";
     match mysql().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { name, columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { name, columns, .. } = Box::into_owned(create_table);
             assert_eq!(name.to_string(), "foo");
             assert_eq!(
                 vec![
@@ -1779,11 +1855,13 @@ fn parse_deprecated_mysql_unsigned_data_types() {
                         name: Ident::new("bar_decimal"),
                         data_type: DataType::DecimalUnsigned(ExactNumberInfo::None),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_decimal_prec"),
                         data_type: DataType::DecimalUnsigned(ExactNumberInfo::Precision(10)),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_decimal_scale"),
@@ -1791,31 +1869,37 @@ fn parse_deprecated_mysql_unsigned_data_types() {
                             10, 2
                         )),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_dec"),
                         data_type: DataType::DecUnsigned(ExactNumberInfo::None),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_dec_prec"),
                         data_type: DataType::DecUnsigned(ExactNumberInfo::Precision(10)),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_dec_scale"),
                         data_type: DataType::DecUnsigned(ExactNumberInfo::PrecisionAndScale(10, 2)),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_float"),
                         data_type: DataType::FloatUnsigned(ExactNumberInfo::None),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_float_prec"),
                         data_type: DataType::FloatUnsigned(ExactNumberInfo::Precision(10)),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_float_scale"),
@@ -1823,16 +1907,19 @@ fn parse_deprecated_mysql_unsigned_data_types() {
                             10, 2
                         )),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_double"),
                         data_type: DataType::DoubleUnsigned(ExactNumberInfo::None),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_double_prec"),
                         data_type: DataType::DoubleUnsigned(ExactNumberInfo::Precision(10)),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_double_scale"),
@@ -1840,16 +1927,19 @@ fn parse_deprecated_mysql_unsigned_data_types() {
                             10, 2
                         )),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_real"),
                         data_type: DataType::RealUnsigned,
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: Ident::new("bar_double_precision"),
                         data_type: DataType::DoublePrecisionUnsigned,
                         options: vec![],
+                        comments: Comments::default(),
                     },
                 ],
                 columns
@@ -1864,13 +1954,14 @@ fn parse_simple_insert() {
     let sql = r"INSERT INTO tasks (title, priority) VALUES ('Test Some Inserts', 1), ('Test Entry 2', 2), ('Test Entry 3', 3)";
 
     match mysql().verified_stmt(sql) {
-        Statement::Insert(Insert {
-            table: table_name,
-            columns,
-            source,
-            on,
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                table: table_name,
+                columns,
+                source,
+                on,
+                ..
+            } = Box::into_owned(insert);
             assert_eq!(
                 TableObject::TableName(ObjectName::from(vec![Ident::new("tasks")])),
                 table_name
@@ -1885,33 +1976,38 @@ fn parse_simple_insert() {
                         explicit_row: false,
                         rows: vec![
                             vec![
-                                Expr::Value(
-                                    (Value::SingleQuotedString("Test Some Inserts".to_string()))
+                                Expr::Value(Box::new(
+                                    (Value::SingleQuotedString("Test Some Inserts".into()))
                                         .with_empty_span()
-                                ),
+                                )),
                                 Expr::value(number("1"))
-                            ],
+                            ]
+                            .into(),
                             vec![
-                                Expr::Value(
-                                    (Value::SingleQuotedString("Test Entry 2".to_string()))
+                                Expr::Value(Box::new(
+                                    (Value::SingleQuotedString("Test Entry 2".into()))
                                         .with_empty_span()
-                                ),
+                                )),
                                 Expr::value(number("2"))
-                            ],
+                            ]
+                            .into(),
                             vec![
-                                Expr::Value(
-                                    (Value::SingleQuotedString("Test Entry 3".to_string()))
+                                Expr::Value(Box::new(
+                                    (Value::SingleQuotedString("Test Entry 3".into()))
                                         .with_empty_span()
-                                ),
+                                )),
                                 Expr::value(number("3"))
                             ]
+                            .into()
                         ]
+                        .into()
                     })),
                     order_by: None,
                     limit_clause: None,
                     fetch: None,
-                    locks: vec![],
+                    locks: AstVec::new(),
                     for_clause: None,
+                    comments: Comments::default(),
                 })),
                 source
             );
@@ -1925,14 +2021,15 @@ fn parse_ignore_insert() {
     let sql = r"INSERT IGNORE INTO tasks (title, priority) VALUES ('Test Some Inserts', 1)";
 
     match mysql_and_generic().verified_stmt(sql) {
-        Statement::Insert(Insert {
-            table: table_name,
-            columns,
-            source,
-            on,
-            ignore,
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                table: table_name,
+                columns,
+                source,
+                on,
+                ignore,
+                ..
+            } = Box::into_owned(insert);
             assert_eq!(
                 TableObject::TableName(ObjectName::from(vec![Ident::new("tasks")])),
                 table_name
@@ -1947,18 +2044,21 @@ fn parse_ignore_insert() {
                         value_keyword: false,
                         explicit_row: false,
                         rows: vec![vec![
-                            Expr::Value(
-                                (Value::SingleQuotedString("Test Some Inserts".to_string()))
+                            Expr::Value(Box::new(
+                                (Value::SingleQuotedString("Test Some Inserts".into()))
                                     .with_empty_span()
-                            ),
+                            )),
                             Expr::value(number("1"))
-                        ]]
+                        ]
+                        .into()]
+                        .into()
                     })),
                     order_by: None,
                     limit_clause: None,
                     fetch: None,
-                    locks: vec![],
+                    locks: AstVec::new(),
                     for_clause: None,
+                    comments: Comments::default(),
                 })),
                 source
             );
@@ -1972,14 +2072,15 @@ fn parse_priority_insert() {
     let sql = r"INSERT HIGH_PRIORITY INTO tasks (title, priority) VALUES ('Test Some Inserts', 1)";
 
     match mysql_and_generic().verified_stmt(sql) {
-        Statement::Insert(Insert {
-            table: table_name,
-            columns,
-            source,
-            on,
-            priority,
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                table: table_name,
+                columns,
+                source,
+                on,
+                priority,
+                ..
+            } = Box::into_owned(insert);
             assert_eq!(
                 TableObject::TableName(ObjectName::from(vec![Ident::new("tasks")])),
                 table_name
@@ -1994,18 +2095,21 @@ fn parse_priority_insert() {
                         value_keyword: false,
                         explicit_row: false,
                         rows: vec![vec![
-                            Expr::Value(
-                                (Value::SingleQuotedString("Test Some Inserts".to_string()))
+                            Expr::Value(Box::new(
+                                (Value::SingleQuotedString("Test Some Inserts".into()))
                                     .with_empty_span()
-                            ),
+                            )),
                             Expr::value(number("1"))
-                        ]]
+                        ]
+                        .into()]
+                        .into()
                     })),
                     order_by: None,
                     limit_clause: None,
                     fetch: None,
-                    locks: vec![],
+                    locks: AstVec::new(),
                     for_clause: None,
+                    comments: Comments::default(),
                 })),
                 source
             );
@@ -2016,14 +2120,15 @@ fn parse_priority_insert() {
     let sql2 = r"INSERT LOW_PRIORITY INTO tasks (title, priority) VALUES ('Test Some Inserts', 1)";
 
     match mysql().verified_stmt(sql2) {
-        Statement::Insert(Insert {
-            table: table_name,
-            columns,
-            source,
-            on,
-            priority,
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                table: table_name,
+                columns,
+                source,
+                on,
+                priority,
+                ..
+            } = Box::into_owned(insert);
             assert_eq!(
                 TableObject::TableName(ObjectName::from(vec![Ident::new("tasks")])),
                 table_name
@@ -2038,18 +2143,21 @@ fn parse_priority_insert() {
                         value_keyword: false,
                         explicit_row: false,
                         rows: vec![vec![
-                            Expr::Value(
-                                (Value::SingleQuotedString("Test Some Inserts".to_string()))
+                            Expr::Value(Box::new(
+                                (Value::SingleQuotedString("Test Some Inserts".into()))
                                     .with_empty_span()
-                            ),
+                            )),
                             Expr::value(number("1"))
-                        ]]
+                        ]
+                        .into()]
+                        .into()
                     })),
                     order_by: None,
                     limit_clause: None,
                     fetch: None,
-                    locks: vec![],
+                    locks: AstVec::new(),
                     for_clause: None,
+                    comments: Comments::default(),
                 })),
                 source
             );
@@ -2062,13 +2170,14 @@ fn parse_priority_insert() {
 fn parse_insert_as() {
     let sql = r"INSERT INTO `table` (`date`) VALUES ('2024-01-01') AS `alias`";
     match mysql_and_generic().verified_stmt(sql) {
-        Statement::Insert(Insert {
-            table: table_name,
-            columns,
-            source,
-            insert_alias,
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                table: table_name,
+                columns,
+                source,
+                insert_alias,
+                ..
+            } = Box::into_owned(insert);
             assert_eq!(
                 TableObject::TableName(ObjectName::from(vec![Ident::with_quote('`', "table")])),
                 table_name
@@ -2087,15 +2196,18 @@ fn parse_insert_as() {
                     body: Box::new(SetExpr::Values(Values {
                         value_keyword: false,
                         explicit_row: false,
-                        rows: vec![vec![Expr::Value(
-                            (Value::SingleQuotedString("2024-01-01".to_string())).with_empty_span()
-                        )]]
+                        rows: vec![vec![Expr::Value(Box::new(
+                            (Value::SingleQuotedString("2024-01-01".into())).with_empty_span()
+                        ))]
+                        .into()]
+                        .into()
                     })),
                     order_by: None,
                     limit_clause: None,
                     fetch: None,
-                    locks: vec![],
+                    locks: AstVec::new(),
                     for_clause: None,
+                    comments: Comments::default(),
                 })),
                 source
             );
@@ -2111,13 +2223,14 @@ fn parse_insert_as() {
 
     let sql = r"INSERT INTO `table` (`id`, `date`) VALUES (1, '2024-01-01') AS `alias` (`mek_id`, `mek_date`)";
     match mysql_and_generic().verified_stmt(sql) {
-        Statement::Insert(Insert {
-            table: table_name,
-            columns,
-            source,
-            insert_alias,
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                table: table_name,
+                columns,
+                source,
+                insert_alias,
+                ..
+            } = Box::into_owned(insert);
             assert_eq!(
                 TableObject::TableName(ObjectName::from(vec![Ident::with_quote('`', "table")])),
                 table_name
@@ -2146,17 +2259,19 @@ fn parse_insert_as() {
                         explicit_row: false,
                         rows: vec![vec![
                             Expr::value(number("1")),
-                            Expr::Value(
-                                (Value::SingleQuotedString("2024-01-01".to_string()))
-                                    .with_empty_span()
-                            )
-                        ]]
+                            Expr::Value(Box::new(
+                                (Value::SingleQuotedString("2024-01-01".into())).with_empty_span()
+                            ))
+                        ]
+                        .into()]
+                        .into()
                     })),
                     order_by: None,
                     limit_clause: None,
                     fetch: None,
-                    locks: vec![],
+                    locks: AstVec::new(),
                     for_clause: None,
+                    comments: Comments::default(),
                 })),
                 source
             );
@@ -2169,15 +2284,16 @@ fn parse_insert_as() {
 fn parse_replace_insert() {
     let sql = r"REPLACE DELAYED INTO tasks (title, priority) VALUES ('Test Some Inserts', 1)";
     match mysql().verified_stmt(sql) {
-        Statement::Insert(Insert {
-            table: table_name,
-            columns,
-            source,
-            on,
-            replace_into,
-            priority,
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                table: table_name,
+                columns,
+                source,
+                on,
+                replace_into,
+                priority,
+                ..
+            } = Box::into_owned(insert);
             assert_eq!(
                 TableObject::TableName(ObjectName::from(vec![Ident::new("tasks")])),
                 table_name
@@ -2193,18 +2309,21 @@ fn parse_replace_insert() {
                         value_keyword: false,
                         explicit_row: false,
                         rows: vec![vec![
-                            Expr::Value(
-                                (Value::SingleQuotedString("Test Some Inserts".to_string()))
+                            Expr::Value(Box::new(
+                                (Value::SingleQuotedString("Test Some Inserts".into()))
                                     .with_empty_span()
-                            ),
+                            )),
                             Expr::value(number("1"))
-                        ]]
+                        ]
+                        .into()]
+                        .into()
                     })),
                     order_by: None,
                     limit_clause: None,
                     fetch: None,
-                    locks: vec![],
+                    locks: AstVec::new(),
                     for_clause: None,
+                    comments: Comments::default(),
                 })),
                 source
             );
@@ -2218,13 +2337,14 @@ fn parse_empty_row_insert() {
     let sql = "INSERT INTO tb () VALUES (), ()";
 
     match mysql().one_statement_parses_to(sql, "INSERT INTO tb VALUES (), ()") {
-        Statement::Insert(Insert {
-            table: table_name,
-            columns,
-            source,
-            on,
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                table: table_name,
+                columns,
+                source,
+                on,
+                ..
+            } = Box::into_owned(insert);
             assert_eq!(
                 TableObject::TableName(ObjectName::from(vec![Ident::new("tb")])),
                 table_name
@@ -2237,13 +2357,14 @@ fn parse_empty_row_insert() {
                     body: Box::new(SetExpr::Values(Values {
                         value_keyword: false,
                         explicit_row: false,
-                        rows: vec![vec![], vec![]]
+                        rows: vec![vec![].into(), vec![].into()].into()
                     })),
                     order_by: None,
                     limit_clause: None,
                     fetch: None,
-                    locks: vec![],
+                    locks: AstVec::new(),
                     for_clause: None,
+                    comments: Comments::default(),
                 })),
                 source
             );
@@ -2257,13 +2378,14 @@ fn parse_insert_with_on_duplicate_update() {
     let sql = "INSERT INTO permission_groups (name, description, perm_create, perm_read, perm_update, perm_delete) VALUES ('accounting_manager', 'Some description about the group', true, true, true, true) ON DUPLICATE KEY UPDATE description = VALUES(description), perm_create = VALUES(perm_create), perm_read = VALUES(perm_read), perm_update = VALUES(perm_update), perm_delete = VALUES(perm_delete)";
 
     match mysql().verified_stmt(sql) {
-        Statement::Insert(Insert {
-            table: table_name,
-            columns,
-            source,
-            on,
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                table: table_name,
+                columns,
+                source,
+                on,
+                ..
+            } = Box::into_owned(insert);
             assert_eq!(
                 TableObject::TableName(ObjectName::from(vec![Ident::new("permission_groups")])),
                 table_name
@@ -2286,27 +2408,30 @@ fn parse_insert_with_on_duplicate_update() {
                         value_keyword: false,
                         explicit_row: false,
                         rows: vec![vec![
-                            Expr::Value(
-                                (Value::SingleQuotedString("accounting_manager".to_string()))
+                            Expr::Value(Box::new(
+                                (Value::SingleQuotedString("accounting_manager".into()))
                                     .with_empty_span()
-                            ),
-                            Expr::Value(
+                            )),
+                            Expr::Value(Box::new(
                                 (Value::SingleQuotedString(
-                                    "Some description about the group".to_string()
+                                    "Some description about the group".into()
                                 ))
                                 .with_empty_span()
-                            ),
-                            Expr::Value((Value::Boolean(true)).with_empty_span()),
-                            Expr::Value((Value::Boolean(true)).with_empty_span()),
-                            Expr::Value((Value::Boolean(true)).with_empty_span()),
-                            Expr::Value((Value::Boolean(true)).with_empty_span()),
-                        ]]
+                            )),
+                            Expr::Value(Box::new((Value::Boolean(true)).with_empty_span())),
+                            Expr::Value(Box::new((Value::Boolean(true)).with_empty_span())),
+                            Expr::Value(Box::new((Value::Boolean(true)).with_empty_span())),
+                            Expr::Value(Box::new((Value::Boolean(true)).with_empty_span())),
+                        ]
+                        .into()]
+                        .into()
                     })),
                     order_by: None,
                     limit_clause: None,
                     fetch: None,
-                    locks: vec![],
+                    locks: AstVec::new(),
                     for_clause: None,
+                    comments: Comments::default(),
                 })),
                 source
             );
@@ -2316,31 +2441,46 @@ fn parse_insert_with_on_duplicate_update() {
                         target: AssignmentTarget::ColumnName(ObjectName::from(vec![Ident::new(
                             "description".to_string()
                         )])),
-                        value: call("VALUES", [Expr::Identifier(Ident::new("description"))]),
+                        value: call(
+                            "VALUES",
+                            [Expr::Identifier(Box::new(Ident::new("description")))]
+                        ),
                     },
                     Assignment {
                         target: AssignmentTarget::ColumnName(ObjectName::from(vec![Ident::new(
                             "perm_create".to_string()
                         )])),
-                        value: call("VALUES", [Expr::Identifier(Ident::new("perm_create"))]),
+                        value: call(
+                            "VALUES",
+                            [Expr::Identifier(Box::new(Ident::new("perm_create")))]
+                        ),
                     },
                     Assignment {
                         target: AssignmentTarget::ColumnName(ObjectName::from(vec![Ident::new(
                             "perm_read".to_string()
                         )])),
-                        value: call("VALUES", [Expr::Identifier(Ident::new("perm_read"))]),
+                        value: call(
+                            "VALUES",
+                            [Expr::Identifier(Box::new(Ident::new("perm_read")))]
+                        ),
                     },
                     Assignment {
                         target: AssignmentTarget::ColumnName(ObjectName::from(vec![Ident::new(
                             "perm_update".to_string()
                         )])),
-                        value: call("VALUES", [Expr::Identifier(Ident::new("perm_update"))]),
+                        value: call(
+                            "VALUES",
+                            [Expr::Identifier(Box::new(Ident::new("perm_update")))]
+                        ),
                     },
                     Assignment {
                         target: AssignmentTarget::ColumnName(ObjectName::from(vec![Ident::new(
                             "perm_delete".to_string()
                         )])),
-                        value: call("VALUES", [Expr::Identifier(Ident::new("perm_delete"))]),
+                        value: call(
+                            "VALUES",
+                            [Expr::Identifier(Box::new(Ident::new("perm_delete")))]
+                        ),
                     },
                 ])),
                 on
@@ -2363,18 +2503,21 @@ fn parse_select_with_numeric_prefix_column_name() {
                     distinct: None,
                     top: None,
                     top_before_distinct: false,
-                    projection: vec![SelectItem::UnnamedExpr(Expr::Identifier(Ident::new(
-                        "123col_$@123abc"
-                    )))],
+                    projection: vec![SelectItem::UnnamedExpr {
+                        expr: Expr::Identifier(Box::new(Ident::new("123col_$@123abc"))),
+                        comments: Comments::default(),
+                    }]
+                    .into(),
                     into: None,
                     from: vec![TableWithJoins {
                         relation: table_from_name(ObjectName::from(vec![Ident::with_quote(
                             '"', "table"
                         )])),
-                        joins: vec![]
-                    }],
+                        joins: AstVec::new()
+                    }]
+                    .into(),
                     selection: None,
-                    group_by: GroupByExpr::Expressions(vec![], vec![]),
+                    group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
                     having: None,
                     qualify: None,
                     named_window: vec![],
@@ -2393,7 +2536,10 @@ fn parse_qualified_identifiers_with_numeric_prefix() {
     match mysql().verified_stmt("SELECT t.15to29 FROM my_table AS t") {
         Statement::Query(q) => match q.body.as_ref() {
             SetExpr::Select(s) => match s.projection.last() {
-                Some(SelectItem::UnnamedExpr(Expr::CompoundIdentifier(parts))) => {
+                Some(SelectItem::UnnamedExpr {
+                    expr: Expr::CompoundIdentifier(parts),
+                    ..
+                }) => {
                     assert_eq!(&[Ident::new("t"), Ident::new("15to29")], &parts[..]);
                 }
                 proj => panic!("Unexpected projection: {proj:?}"),
@@ -2407,7 +2553,10 @@ fn parse_qualified_identifiers_with_numeric_prefix() {
     match mysql().verified_stmt("SELECT t.15e29 FROM my_table AS t") {
         Statement::Query(q) => match q.body.as_ref() {
             SetExpr::Select(s) => match s.projection.last() {
-                Some(SelectItem::UnnamedExpr(Expr::CompoundIdentifier(parts))) => {
+                Some(SelectItem::UnnamedExpr {
+                    expr: Expr::CompoundIdentifier(parts),
+                    ..
+                }) => {
                     assert_eq!(&[Ident::new("t"), Ident::new("15e29")], &parts[..]);
                 }
                 proj => panic!("Unexpected projection: {proj:?}"),
@@ -2425,8 +2574,11 @@ fn parse_qualified_identifiers_with_numeric_prefix() {
     {
         Some(Statement::Query(q)) => match q.body.as_ref() {
             SetExpr::Select(s) => match s.projection.last() {
-                Some(SelectItem::UnnamedExpr(Expr::Value(ValueWithSpan { value, .. }))) => {
-                    assert_eq!(&number("15e29"), value);
+                Some(SelectItem::UnnamedExpr {
+                    expr: Expr::Value(value),
+                    ..
+                }) => {
+                    assert_eq!(number("15e29"), value.value);
                 }
                 proj => panic!("Unexpected projection: {proj:?}"),
             },
@@ -2439,8 +2591,11 @@ fn parse_qualified_identifiers_with_numeric_prefix() {
     match mysql().verified_stmt("SELECT `15e29` FROM my_table") {
         Statement::Query(q) => match q.body.as_ref() {
             SetExpr::Select(s) => match s.projection.last() {
-                Some(SelectItem::UnnamedExpr(Expr::Identifier(name))) => {
-                    assert_eq!(&Ident::with_quote('`', "15e29"), name);
+                Some(SelectItem::UnnamedExpr {
+                    expr: Expr::Identifier(name),
+                    ..
+                }) => {
+                    assert_eq!(&Ident::with_quote('`', "15e29"), &**name);
                 }
                 proj => panic!("Unexpected projection: {proj:?}"),
             },
@@ -2453,7 +2608,10 @@ fn parse_qualified_identifiers_with_numeric_prefix() {
     match mysql().verified_stmt("SELECT t.`15e29` FROM my_table AS t") {
         Statement::Query(q) => match q.body.as_ref() {
             SetExpr::Select(s) => match s.projection.last() {
-                Some(SelectItem::UnnamedExpr(Expr::CompoundIdentifier(parts))) => {
+                Some(SelectItem::UnnamedExpr {
+                    expr: Expr::CompoundIdentifier(parts),
+                    ..
+                }) => {
                     assert_eq!(
                         &[Ident::new("t"), Ident::with_quote('`', "15e29")],
                         &parts[..]
@@ -2470,7 +2628,10 @@ fn parse_qualified_identifiers_with_numeric_prefix() {
     match mysql().verified_stmt("SELECT 1db.1table.1column") {
         Statement::Query(q) => match q.body.as_ref() {
             SetExpr::Select(s) => match s.projection.last() {
-                Some(SelectItem::UnnamedExpr(Expr::CompoundIdentifier(parts))) => {
+                Some(SelectItem::UnnamedExpr {
+                    expr: Expr::CompoundIdentifier(parts),
+                    ..
+                }) => {
                     assert_eq!(
                         &[
                             Ident::new("1db"),
@@ -2491,7 +2652,10 @@ fn parse_qualified_identifiers_with_numeric_prefix() {
     match mysql().verified_stmt("SELECT `1`.`2`.`3`") {
         Statement::Query(q) => match q.body.as_ref() {
             SetExpr::Select(s) => match s.projection.last() {
-                Some(SelectItem::UnnamedExpr(Expr::CompoundIdentifier(parts))) => {
+                Some(SelectItem::UnnamedExpr {
+                    expr: Expr::CompoundIdentifier(parts),
+                    ..
+                }) => {
                     assert_eq!(
                         &[
                             Ident::with_quote('`', "1"),
@@ -2530,18 +2694,26 @@ fn parse_select_with_concatenation_of_exp_number_and_numeric_prefix_column() {
                     top: None,
                     top_before_distinct: false,
                     projection: vec![
-                        SelectItem::UnnamedExpr(Expr::value(number("123e4"))),
-                        SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("123col_$@123abc")))
-                    ],
+                        SelectItem::UnnamedExpr {
+                            expr: Expr::value(number("123e4")),
+                            comments: Comments::default(),
+                        },
+                        SelectItem::UnnamedExpr {
+                            expr: Expr::Identifier(Box::new(Ident::new("123col_$@123abc"))),
+                            comments: Comments::default(),
+                        }
+                    ]
+                    .into(),
                     into: None,
                     from: vec![TableWithJoins {
                         relation: table_from_name(ObjectName::from(vec![Ident::with_quote(
                             '"', "table"
                         )])),
-                        joins: vec![]
-                    }],
+                        joins: AstVec::new()
+                    }]
+                    .into(),
                     selection: None,
-                    group_by: GroupByExpr::Expressions(vec![], vec![]),
+                    group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
                     having: None,
                     qualify: None,
                     named_window: vec![],
@@ -2558,11 +2730,12 @@ fn parse_select_with_concatenation_of_exp_number_and_numeric_prefix_column() {
 fn parse_insert_with_numeric_prefix_column_name() {
     let sql = "INSERT INTO s1.t1 (123col_$@length123) VALUES (67.654)";
     match mysql().verified_stmt(sql) {
-        Statement::Insert(Insert {
-            table: table_name,
-            columns,
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                table: table_name,
+                columns,
+                ..
+            } = Box::into_owned(insert);
             assert_eq!(
                 TableObject::TableName(ObjectName::from(vec![Ident::new("s1"), Ident::new("t1")])),
                 table_name
@@ -2577,24 +2750,29 @@ fn parse_insert_with_numeric_prefix_column_name() {
 fn parse_update_with_joins() {
     let sql = "UPDATE orders AS o JOIN customers AS c ON o.customer_id = c.id SET o.completed = true WHERE c.firstname = 'Peter'";
     match mysql().verified_stmt(sql) {
-        Statement::Update(Update {
-            table,
-            assignments,
-            from: _from,
-            selection,
-            returning,
-            limit: None,
-            update_token: _,
-            for_portion_of: None,
-            error_logging: _,
-        }) => {
+        Statement::Update(update) => {
+            let Update {
+                table,
+                assignments,
+                from: _from,
+                selection,
+                returning,
+                limit: None,
+                update_token: _,
+                for_portion_of: None,
+                error_logging: _,
+                comments: _,
+            } = Box::into_owned(update)
+            else {
+                unreachable!()
+            };
             assert_eq!(
                 TableWithJoins {
                     relation: TableFactor::Table {
                         name: ObjectName::from(vec![Ident::new("orders")]),
                         alias: Some(TableAlias {
                             name: Ident::new("o"),
-                            columns: vec![],
+                            columns: vec![].into(),
                             implicit: false,
                         }),
                         args: None,
@@ -2612,7 +2790,7 @@ fn parse_update_with_joins() {
                             name: ObjectName::from(vec![Ident::new("customers")]),
                             alias: Some(TableAlias {
                                 name: Ident::new("c"),
-                                columns: vec![],
+                                columns: vec![].into(),
                                 implicit: false,
                             }),
                             args: None,
@@ -2628,19 +2806,18 @@ fn parse_update_with_joins() {
                         global: false,
                         join_operator: JoinOperator::Join(JoinConstraint::On(
                             Expr::BinaryOp {
-                                left: Box::new(Expr::CompoundIdentifier(vec![
-                                    Ident::new("o"),
-                                    Ident::new("customer_id")
-                                ])),
+                                left: Box::new(Expr::CompoundIdentifier(
+                                    vec![Ident::new("o"), Ident::new("customer_id")].into()
+                                )),
                                 op: BinaryOperator::Eq,
-                                right: Box::new(Expr::CompoundIdentifier(vec![
-                                    Ident::new("c"),
-                                    Ident::new("id")
-                                ]))
+                                right: Box::new(Expr::CompoundIdentifier(
+                                    vec![Ident::new("c"), Ident::new("id")].into()
+                                ))
                             }
                             .into()
                         )),
                     }]
+                    .into()
                 },
                 table
             );
@@ -2650,20 +2827,19 @@ fn parse_update_with_joins() {
                         Ident::new("o"),
                         Ident::new("completed")
                     ])),
-                    value: Expr::Value((Value::Boolean(true)).with_empty_span())
+                    value: Expr::Value(Box::new((Value::Boolean(true)).with_empty_span()))
                 }],
                 assignments
             );
             assert_eq!(
                 Some(Expr::BinaryOp {
-                    left: Box::new(Expr::CompoundIdentifier(vec![
-                        Ident::new("c"),
-                        Ident::new("firstname")
-                    ])),
+                    left: Box::new(Expr::CompoundIdentifier(
+                        vec![Ident::new("c"), Ident::new("firstname")].into()
+                    )),
                     op: BinaryOperator::Eq,
-                    right: Box::new(Expr::Value(
-                        (Value::SingleQuotedString("Peter".to_string())).with_empty_span()
-                    ))
+                    right: Box::new(Expr::Value(Box::new(
+                        (Value::SingleQuotedString("Peter".into())).with_empty_span()
+                    )))
                 }),
                 selection
             );
@@ -2677,14 +2853,15 @@ fn parse_update_with_joins() {
 fn parse_delete_with_order_by() {
     let sql = "DELETE FROM customers ORDER BY id DESC";
     match mysql().verified_stmt(sql) {
-        Statement::Delete(Delete { order_by, .. }) => {
+        Statement::Delete(delete) => {
+            let Delete { order_by, .. } = Box::into_owned(delete);
             assert_eq!(
                 vec![OrderByExpr {
-                    expr: Expr::Identifier(Ident {
-                        value: "id".to_owned(),
+                    expr: Expr::Identifier(Box::new(Ident {
+                        value: "id".into(),
                         quote_style: None,
                         span: Span::empty(),
-                    }),
+                    })),
                     options: OrderByOptions {
                         asc: Some(false),
                         nulls_first: None,
@@ -2702,7 +2879,8 @@ fn parse_delete_with_order_by() {
 fn parse_delete_with_limit() {
     let sql = "DELETE FROM customers LIMIT 100";
     match mysql().verified_stmt(sql) {
-        Statement::Delete(Delete { limit, .. }) => {
+        Statement::Delete(delete) => {
+            let Delete { limit, .. } = Box::into_owned(delete);
             assert_eq!(Some(Expr::value(number("100"))), limit);
         }
         _ => unreachable!(),
@@ -2731,6 +2909,7 @@ fn parse_alter_table_add_column() {
                         name: "b".into(),
                         data_type: DataType::Int(None),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     column_position: Some(MySQLColumnPosition::First),
                 },]
@@ -2759,9 +2938,10 @@ fn parse_alter_table_add_column() {
                         name: "b".into(),
                         data_type: DataType::Int(None),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     column_position: Some(MySQLColumnPosition::After(Ident {
-                        value: String::from("foo"),
+                        value: "foo".into(),
                         quote_style: None,
                         span: Span::empty(),
                     })),
@@ -2797,6 +2977,7 @@ fn parse_alter_table_add_columns() {
                             name: "a".into(),
                             data_type: DataType::Text,
                             options: vec![],
+                            comments: Comments::default(),
                         },
                         column_position: Some(MySQLColumnPosition::First),
                     },
@@ -2807,9 +2988,10 @@ fn parse_alter_table_add_columns() {
                             name: "b".into(),
                             data_type: DataType::Int(None),
                             options: vec![],
+                            comments: Comments::default(),
                         },
                         column_position: Some(MySQLColumnPosition::After(Ident {
-                            value: String::from("foo"),
+                            value: "foo".into(),
                             quote_style: None,
                             span: Span::empty(),
                         })),
@@ -2882,7 +3064,7 @@ fn parse_alter_table_change_column() {
         data_type: DataType::Text,
         options: vec![ColumnOption::NotNull],
         column_position: Some(MySQLColumnPosition::After(Ident {
-            value: String::from("foo"),
+            value: "foo".into(),
             quote_style: None,
             span: Span::empty(),
         })),
@@ -2922,7 +3104,7 @@ fn parse_alter_table_change_column_with_column_position() {
         data_type: DataType::Text,
         options: vec![ColumnOption::NotNull],
         column_position: Some(MySQLColumnPosition::After(Ident {
-            value: String::from("total_count"),
+            value: "total_count".into(),
             quote_style: None,
             span: Span::empty(),
         })),
@@ -2979,7 +3161,7 @@ fn parse_alter_table_modify_column() {
         data_type: DataType::Text,
         options: vec![ColumnOption::NotNull],
         column_position: Some(MySQLColumnPosition::After(Ident {
-            value: String::from("foo"),
+            value: "foo".into(),
             quote_style: None,
             span: Span::empty(),
         })),
@@ -3126,7 +3308,7 @@ fn parse_alter_table_modify_column_with_column_position() {
         data_type: DataType::Text,
         options: vec![ColumnOption::NotNull],
         column_position: Some(MySQLColumnPosition::After(Ident {
-            value: String::from("total_count"),
+            value: "total_count".into(),
             quote_style: None,
             span: Span::empty(),
         })),
@@ -3163,32 +3345,37 @@ fn parse_substring_in_select() {
                         distinct: Some(Distinct::Distinct),
                         top: None,
                         top_before_distinct: false,
-                        projection: vec![SelectItem::UnnamedExpr(Expr::Substring {
-                            expr: Box::new(Expr::Identifier(Ident {
-                                value: "description".to_string(),
-                                quote_style: None,
-                                span: Span::empty(),
-                            })),
-                            substring_from: Some(Box::new(Expr::Value(
-                                (number("0")).with_empty_span()
-                            ))),
-                            substring_for: Some(Box::new(Expr::Value(
-                                (number("1")).with_empty_span()
-                            ))),
-                            special: true,
-                            shorthand: false,
-                        })],
+                        projection: vec![SelectItem::UnnamedExpr {
+                            expr: Expr::Substring {
+                                expr: Box::new(Expr::Identifier(Box::new(Ident {
+                                    value: "description".into(),
+                                    quote_style: None,
+                                    span: Span::empty(),
+                                }))),
+                                substring_from: Some(Box::new(Expr::Value(Box::new(
+                                    (number("0")).with_empty_span()
+                                )))),
+                                substring_for: Some(Box::new(Expr::Value(Box::new(
+                                    (number("1")).with_empty_span()
+                                )))),
+                                special: true,
+                                shorthand: false,
+                            },
+                            comments: Comments::default(),
+                        }]
+                        .into(),
                         into: None,
                         from: vec![TableWithJoins {
                             relation: table_from_name(ObjectName::from(vec![Ident {
-                                value: "test".to_string(),
+                                value: "test".into(),
                                 quote_style: None,
                                 span: Span::empty(),
                             }])),
-                            joins: vec![]
-                        }],
+                            joins: AstVec::new()
+                        }]
+                        .into(),
                         selection: None,
-                        group_by: GroupByExpr::Expressions(vec![], vec![]),
+                        group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
                         having: None,
                         qualify: None,
                         named_window: vec![],
@@ -3198,8 +3385,9 @@ fn parse_substring_in_select() {
                     order_by: None,
                     limit_clause: None,
                     fetch: None,
-                    locks: vec![],
+                    locks: AstVec::new(),
                     for_clause: None,
+                    comments: Comments::default(),
                 }),
                 query
             );
@@ -3281,7 +3469,8 @@ fn parse_kill() {
 fn parse_table_column_option_on_update() {
     let sql1 = "CREATE TABLE foo (`modification_time` DATETIME ON UPDATE CURRENT_TIMESTAMP())";
     match mysql().verified_stmt(sql1) {
-        Statement::CreateTable(CreateTable { name, columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { name, columns, .. } = Box::into_owned(create_table);
             assert_eq!(name.to_string(), "foo");
             assert_eq!(
                 vec![ColumnDef {
@@ -3291,6 +3480,7 @@ fn parse_table_column_option_on_update() {
                         name: None,
                         option: ColumnOption::OnUpdate(call("CURRENT_TIMESTAMP", [])),
                     },],
+                    comments: Comments::default(),
                 }],
                 columns
             );
@@ -3304,25 +3494,25 @@ fn parse_set_names() {
     let stmt = mysql_and_generic().verified_stmt("SET NAMES utf8mb4");
     assert_eq!(
         stmt,
-        Statement::Set(SetStatement {
+        Statement::Set(Box::new(SetStatement {
             token: AttachedToken::empty(),
             inner: Set::SetNames {
                 charset_name: "utf8mb4".into(),
                 collation_name: None,
             }
-        })
+        }))
     );
 
     let stmt = mysql_and_generic().verified_stmt("SET NAMES utf8mb4 COLLATE bogus");
     assert_eq!(
         stmt,
-        Statement::Set(SetStatement {
+        Statement::Set(Box::new(SetStatement {
             token: AttachedToken::empty(),
             inner: Set::SetNames {
                 charset_name: "utf8mb4".into(),
                 collation_name: Some("bogus".to_string()),
             }
-        })
+        }))
     );
 
     let stmt = mysql_and_generic()
@@ -3330,22 +3520,22 @@ fn parse_set_names() {
         .unwrap();
     assert_eq!(
         stmt,
-        vec![Statement::Set(SetStatement {
+        vec![Statement::Set(Box::new(SetStatement {
             token: AttachedToken::empty(),
             inner: Set::SetNames {
                 charset_name: "utf8mb4".into(),
                 collation_name: Some("bogus".to_string()),
             }
-        })]
+        }))]
     );
 
     let stmt = mysql_and_generic().verified_stmt("SET NAMES DEFAULT");
     assert_eq!(
         stmt,
-        Statement::Set(SetStatement {
+        Statement::Set(Box::new(SetStatement {
             token: AttachedToken::empty(),
             inner: Set::SetNamesDefault {}
-        })
+        }))
     );
 }
 
@@ -3498,16 +3688,20 @@ fn parse_hex_string_introducer() {
                 distinct: None,
                 top: None,
                 top_before_distinct: false,
-                projection: vec![SelectItem::UnnamedExpr(Expr::Prefixed {
-                    prefix: Ident::from("_latin1"),
-                    value: Expr::Value(
-                        Value::HexStringLiteral("4D7953514C".to_string()).with_empty_span()
-                    )
-                    .into(),
-                })],
-                from: vec![],
+                projection: vec![SelectItem::UnnamedExpr {
+                    expr: Expr::Prefixed {
+                        prefix: Box::new(Ident::from("_latin1")),
+                        value: Expr::Value(Box::new(
+                            Value::HexStringLiteral("4D7953514C".to_string()).with_empty_span()
+                        ))
+                        .into(),
+                    },
+                    comments: Comments::default(),
+                }]
+                .into(),
+                from: AstVec::new(),
                 selection: None,
-                group_by: GroupByExpr::Expressions(vec![], vec![]),
+                group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
                 having: None,
                 qualify: None,
                 named_window: vec![],
@@ -3518,8 +3712,9 @@ fn parse_hex_string_introducer() {
             order_by: None,
             limit_clause: None,
             fetch: None,
-            locks: vec![],
+            locks: AstVec::new(),
             for_clause: None,
+            comments: Comments::default(),
         }))
     )
 }
@@ -3582,7 +3777,8 @@ fn parse_convert_using() {
 fn parse_create_table_with_column_collate() {
     let sql = "CREATE TABLE tb (id TEXT CHARACTER SET utf8mb4 COLLATE utf8mb4_0900_ai_ci)";
     match mysql().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { name, columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { name, columns, .. } = Box::into_owned(create_table);
             assert_eq!(name.to_string(), "tb");
             assert_eq!(
                 vec![ColumnDef {
@@ -3602,6 +3798,7 @@ fn parse_create_table_with_column_collate() {
                             )]))
                         }
                     ],
+                    comments: Comments::default(),
                 },],
                 columns
             );
@@ -3662,21 +3859,21 @@ fn parse_json_table() {
             .from[0]
             .relation,
         TableFactor::JsonTable {
-            json_expr: Expr::Value((Value::SingleQuotedString("[1,2]".to_string())).with_empty_span()),
-            json_path: Value::SingleQuotedString("$[*]".to_string()),
+            json_expr: Expr::Value(Box::new((Value::SingleQuotedString("[1,2]".into())).with_empty_span())),
+            json_path: Value::SingleQuotedString("$[*]".into()),
             columns: vec![
                 JsonTableColumn::Named(JsonTableNamedColumn {
                     name: Ident::new("x"),
                     r#type: DataType::Int(None),
-                    path: Value::SingleQuotedString("$".to_string()),
+                    path: Value::SingleQuotedString("$".into()),
                     exists: false,
-                    on_empty: Some(JsonTableColumnErrorHandling::Default(Value::SingleQuotedString("0".to_string()))),
+                    on_empty: Some(JsonTableColumnErrorHandling::Default(Value::SingleQuotedString("0".into()))),
                     on_error: Some(JsonTableColumnErrorHandling::Null),
                 }),
             ],
             alias: Some(TableAlias {
                 name: Ident::new("t"),
-                columns: vec![],
+                columns: vec![].into(),
                                     implicit: false,
             }),
         }
@@ -3700,35 +3897,63 @@ fn parse_logical_xor() {
     let sql = "SELECT true XOR true, false XOR false, true XOR false, false XOR true";
     let select = mysql_and_generic().verified_only_select(sql);
     assert_eq!(
-        SelectItem::UnnamedExpr(Expr::BinaryOp {
-            left: Box::new(Expr::Value((Value::Boolean(true)).with_empty_span())),
-            op: BinaryOperator::Xor,
-            right: Box::new(Expr::Value((Value::Boolean(true)).with_empty_span())),
-        }),
+        SelectItem::UnnamedExpr {
+            expr: Expr::BinaryOp {
+                left: Box::new(Expr::Value(Box::new(
+                    (Value::Boolean(true)).with_empty_span()
+                ))),
+                op: BinaryOperator::Xor,
+                right: Box::new(Expr::Value(Box::new(
+                    (Value::Boolean(true)).with_empty_span()
+                ))),
+            },
+            comments: Comments::default(),
+        },
         select.projection[0]
     );
     assert_eq!(
-        SelectItem::UnnamedExpr(Expr::BinaryOp {
-            left: Box::new(Expr::Value((Value::Boolean(false)).with_empty_span())),
-            op: BinaryOperator::Xor,
-            right: Box::new(Expr::Value((Value::Boolean(false)).with_empty_span())),
-        }),
+        SelectItem::UnnamedExpr {
+            expr: Expr::BinaryOp {
+                left: Box::new(Expr::Value(Box::new(
+                    (Value::Boolean(false)).with_empty_span()
+                ))),
+                op: BinaryOperator::Xor,
+                right: Box::new(Expr::Value(Box::new(
+                    (Value::Boolean(false)).with_empty_span()
+                ))),
+            },
+            comments: Comments::default(),
+        },
         select.projection[1]
     );
     assert_eq!(
-        SelectItem::UnnamedExpr(Expr::BinaryOp {
-            left: Box::new(Expr::Value((Value::Boolean(true)).with_empty_span())),
-            op: BinaryOperator::Xor,
-            right: Box::new(Expr::Value((Value::Boolean(false)).with_empty_span())),
-        }),
+        SelectItem::UnnamedExpr {
+            expr: Expr::BinaryOp {
+                left: Box::new(Expr::Value(Box::new(
+                    (Value::Boolean(true)).with_empty_span()
+                ))),
+                op: BinaryOperator::Xor,
+                right: Box::new(Expr::Value(Box::new(
+                    (Value::Boolean(false)).with_empty_span()
+                ))),
+            },
+            comments: Comments::default(),
+        },
         select.projection[2]
     );
     assert_eq!(
-        SelectItem::UnnamedExpr(Expr::BinaryOp {
-            left: Box::new(Expr::Value((Value::Boolean(false)).with_empty_span())),
-            op: BinaryOperator::Xor,
-            right: Box::new(Expr::Value((Value::Boolean(true)).with_empty_span())),
-        }),
+        SelectItem::UnnamedExpr {
+            expr: Expr::BinaryOp {
+                left: Box::new(Expr::Value(Box::new(
+                    (Value::Boolean(false)).with_empty_span()
+                ))),
+                op: BinaryOperator::Xor,
+                right: Box::new(Expr::Value(Box::new(
+                    (Value::Boolean(true)).with_empty_span()
+                ))),
+            },
+            comments: Comments::default(),
+        },
         select.projection[3]
     );
 }
@@ -3738,9 +3963,12 @@ fn parse_bitstring_literal() {
     let select = mysql_and_generic().verified_only_select("SELECT B'111'");
     assert_eq!(
         select.projection,
-        vec![SelectItem::UnnamedExpr(Expr::Value(
-            (Value::SingleQuotedByteStringLiteral("111".to_string())).with_empty_span()
-        ))]
+        vec![SelectItem::UnnamedExpr {
+            expr: Expr::Value(Box::new(
+                (Value::SingleQuotedByteStringLiteral("111".to_string())).with_empty_span()
+            )),
+            comments: Comments::default(),
+        }]
     );
 }
 
@@ -3766,10 +3994,9 @@ fn parse_grant() {
         );
         assert_eq!(
             objects,
-            Some(GrantObjects::Tables(vec![ObjectName::from(vec![
-                "*".into(),
-                "*".into()
-            ])]))
+            Some(Box::new(GrantObjects::Tables(vec![ObjectName::from(
+                vec!["*".into(), "*".into()]
+            )])))
         );
         assert!(!with_grant_option);
         assert!(granted_by.is_none());
@@ -3811,10 +4038,9 @@ fn parse_revoke() {
         );
         assert_eq!(
             objects,
-            Some(GrantObjects::Tables(vec![ObjectName::from(vec![
-                "db1".into(),
-                "*".into()
-            ])]))
+            Some(Box::new(GrantObjects::Tables(vec![ObjectName::from(
+                vec!["db1".into(), "*".into()]
+            )])))
         );
         if let [Grantee {
             grantee_type: GranteesType::None,
@@ -3839,16 +4065,19 @@ fn parse_revoke() {
 fn parse_create_view_algorithm_param() {
     let sql = "CREATE ALGORITHM = MERGE VIEW foo AS SELECT 1";
     let stmt = mysql().verified_stmt(sql);
-    if let Statement::CreateView(CreateView {
-        params:
-            Some(CreateViewParams {
-                algorithm,
-                definer,
-                security,
-            }),
-        ..
-    }) = stmt
-    {
+    if let Statement::CreateView(create_view) = stmt {
+        let CreateView {
+            params:
+                Some(CreateViewParams {
+                    algorithm,
+                    definer,
+                    security,
+                }),
+            ..
+        } = Box::into_owned(create_view)
+        else {
+            unreachable!()
+        };
         assert_eq!(algorithm, Some(CreateViewAlgorithm::Merge));
         assert!(definer.is_none());
         assert!(security.is_none());
@@ -3863,16 +4092,19 @@ fn parse_create_view_algorithm_param() {
 fn parse_create_view_definer_param() {
     let sql = "CREATE DEFINER = 'jeffrey'@'localhost' VIEW foo AS SELECT 1";
     let stmt = mysql().verified_stmt(sql);
-    if let Statement::CreateView(CreateView {
-        params:
-            Some(CreateViewParams {
-                algorithm,
-                definer,
-                security,
-            }),
-        ..
-    }) = stmt
-    {
+    if let Statement::CreateView(create_view) = stmt {
+        let CreateView {
+            params:
+                Some(CreateViewParams {
+                    algorithm,
+                    definer,
+                    security,
+                }),
+            ..
+        } = Box::into_owned(create_view)
+        else {
+            unreachable!()
+        };
         assert!(algorithm.is_none());
         if let Some(GranteeName::UserHost { user, host }) = definer {
             assert_eq!(user.value, "jeffrey");
@@ -3892,16 +4124,19 @@ fn parse_create_view_definer_param() {
 fn parse_create_view_security_param() {
     let sql = "CREATE SQL SECURITY DEFINER VIEW foo AS SELECT 1";
     let stmt = mysql().verified_stmt(sql);
-    if let Statement::CreateView(CreateView {
-        params:
-            Some(CreateViewParams {
-                algorithm,
-                definer,
-                security,
-            }),
-        ..
-    }) = stmt
-    {
+    if let Statement::CreateView(create_view) = stmt {
+        let CreateView {
+            params:
+                Some(CreateViewParams {
+                    algorithm,
+                    definer,
+                    security,
+                }),
+            ..
+        } = Box::into_owned(create_view)
+        else {
+            unreachable!()
+        };
         assert!(algorithm.is_none());
         assert!(definer.is_none());
         assert_eq!(security, Some(CreateViewSecurity::Definer));
@@ -3915,16 +4150,19 @@ fn parse_create_view_security_param() {
 fn parse_create_view_multiple_params() {
     let sql = "CREATE ALGORITHM = UNDEFINED DEFINER = `root`@`%` SQL SECURITY INVOKER VIEW foo AS SELECT 1";
     let stmt = mysql().verified_stmt(sql);
-    if let Statement::CreateView(CreateView {
-        params:
-            Some(CreateViewParams {
-                algorithm,
-                definer,
-                security,
-            }),
-        ..
-    }) = stmt
-    {
+    if let Statement::CreateView(create_view) = stmt {
+        let CreateView {
+            params:
+                Some(CreateViewParams {
+                    algorithm,
+                    definer,
+                    security,
+                }),
+            ..
+        } = Box::into_owned(create_view)
+        else {
+            unreachable!()
+        };
         assert_eq!(algorithm, Some(CreateViewAlgorithm::Undefined));
         if let Some(GranteeName::UserHost { user, host }) = definer {
             assert_eq!(user.value, "root");
@@ -3944,7 +4182,8 @@ fn parse_create_view_multiple_params() {
 fn parse_longblob_type() {
     let sql = "CREATE TABLE foo (bar LONGBLOB)";
     let stmt = mysql_and_generic().verified_stmt(sql);
-    if let Statement::CreateTable(CreateTable { columns, .. }) = stmt {
+    if let Statement::CreateTable(create_table) = stmt {
+        let CreateTable { columns, .. } = Box::into_owned(create_table);
         assert_eq!(columns.len(), 1);
         assert_eq!(columns[0].data_type, DataType::LongBlob);
     } else {
@@ -3998,7 +4237,7 @@ fn parse_create_trigger() {
     let create_stmt = mysql().verified_stmt(sql_create_trigger);
     assert_eq!(
         create_stmt,
-        Statement::CreateTrigger(CreateTrigger {
+        Statement::CreateTrigger(Box::new(CreateTrigger {
             token: AttachedToken::empty(),
             or_alter: false,
             temporary: false,
@@ -4023,7 +4262,7 @@ fn parse_create_trigger() {
             statements_as: false,
             statements: None,
             characteristics: None,
-        })
+        }))
     );
 }
 
@@ -4085,7 +4324,7 @@ fn parse_match_against_with_alias() {
                             Ident::new("ReferenceID")
                         ])]
                     );
-                    assert_eq!(match_value, &Value::SingleQuotedString("AAA".to_owned()));
+                    assert_eq!(match_value, &Value::SingleQuotedString("AAA".into()));
                     assert_eq!(opt_search_modifier, &Some(SearchModifier::InBooleanMode));
                 }
                 _ => unreachable!(),
@@ -4107,38 +4346,44 @@ fn test_variable_assignment_using_colon_equal() {
             assert_eq!(
                 select.projection,
                 vec![
-                    SelectItem::UnnamedExpr(Expr::BinaryOp {
-                        left: Box::new(Expr::Identifier(Ident {
-                            value: "@price".to_string(),
-                            quote_style: None,
-                            span: Span::empty(),
-                        })),
-                        op: BinaryOperator::Assignment,
-                        right: Box::new(Expr::Identifier(Ident {
-                            value: "price".to_string(),
-                            quote_style: None,
-                            span: Span::empty(),
-                        })),
-                    }),
-                    SelectItem::UnnamedExpr(Expr::BinaryOp {
-                        left: Box::new(Expr::Identifier(Ident {
-                            value: "@tax".to_string(),
-                            quote_style: None,
-                            span: Span::empty(),
-                        })),
-                        op: BinaryOperator::Assignment,
-                        right: Box::new(Expr::BinaryOp {
-                            left: Box::new(Expr::Identifier(Ident {
-                                value: "price".to_string(),
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::BinaryOp {
+                            left: Box::new(Expr::Identifier(Box::new(Ident {
+                                value: "@price".into(),
                                 quote_style: None,
                                 span: Span::empty(),
-                            })),
-                            op: BinaryOperator::Multiply,
-                            right: Box::new(Expr::Value(
-                                (test_utils::number("0.1")).with_empty_span()
-                            )),
-                        }),
-                    }),
+                            }))),
+                            op: BinaryOperator::Assignment,
+                            right: Box::new(Expr::Identifier(Box::new(Ident {
+                                value: "price".into(),
+                                quote_style: None,
+                                span: Span::empty(),
+                            }))),
+                        },
+                        comments: Comments::default(),
+                    },
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::BinaryOp {
+                            left: Box::new(Expr::Identifier(Box::new(Ident {
+                                value: "@tax".into(),
+                                quote_style: None,
+                                span: Span::empty(),
+                            }))),
+                            op: BinaryOperator::Assignment,
+                            right: Box::new(Expr::BinaryOp {
+                                left: Box::new(Expr::Identifier(Box::new(Ident {
+                                    value: "price".into(),
+                                    quote_style: None,
+                                    span: Span::empty(),
+                                }))),
+                                op: BinaryOperator::Multiply,
+                                right: Box::new(Expr::Value(Box::new(
+                                    (test_utils::number("0.1")).with_empty_span()
+                                ))),
+                            }),
+                        },
+                        comments: Comments::default(),
+                    },
                 ]
             );
 
@@ -4146,13 +4391,15 @@ fn test_variable_assignment_using_colon_equal() {
                 select.selection,
                 Some(
                     Expr::BinaryOp {
-                        left: Box::new(Expr::Identifier(Ident {
-                            value: "id".to_string(),
+                        left: Box::new(Expr::Identifier(Box::new(Ident {
+                            value: "id".into(),
                             quote_style: None,
                             span: Span::empty(),
-                        })),
+                        }))),
                         op: BinaryOperator::Eq,
-                        right: Box::new(Expr::Value((test_utils::number("1")).with_empty_span())),
+                        right: Box::new(Expr::Value(Box::new(
+                            (test_utils::number("1")).with_empty_span()
+                        ))),
                     }
                     .into()
                 )
@@ -4166,34 +4413,36 @@ fn test_variable_assignment_using_colon_equal() {
     let stmt = mysql().verified_stmt(sql_update);
 
     match stmt {
-        Statement::Update(Update { assignments, .. }) => {
+        Statement::Update(update) => {
+            let Update { assignments, .. } = Box::into_owned(update);
             assert_eq!(
                 assignments,
                 vec![Assignment {
-                    target: AssignmentTarget::ColumnName(ObjectName(vec![
-                        ObjectNamePart::Identifier(Ident {
-                            value: "price".to_string(),
+                    target: AssignmentTarget::ColumnName(ObjectName(
+                        vec![ObjectNamePart::Identifier(Ident {
+                            value: "price".into(),
                             quote_style: None,
                             span: Span::empty(),
-                        })
-                    ])),
+                        })]
+                        .into()
+                    )),
                     value: Expr::BinaryOp {
-                        left: Box::new(Expr::Identifier(Ident {
-                            value: "@new_price".to_string(),
+                        left: Box::new(Expr::Identifier(Box::new(Ident {
+                            value: "@new_price".into(),
                             quote_style: None,
                             span: Span::empty(),
-                        })),
+                        }))),
                         op: BinaryOperator::Assignment,
                         right: Box::new(Expr::BinaryOp {
-                            left: Box::new(Expr::Identifier(Ident {
-                                value: "price".to_string(),
+                            left: Box::new(Expr::Identifier(Box::new(Ident {
+                                value: "price".into(),
                                 quote_style: None,
                                 span: Span::empty(),
-                            })),
+                            }))),
                             op: BinaryOperator::Multiply,
-                            right: Box::new(Expr::Value(
+                            right: Box::new(Expr::Value(Box::new(
                                 (test_utils::number("1.1")).with_empty_span()
-                            )),
+                            ))),
                         }),
                     },
                 }]
@@ -4272,15 +4521,17 @@ fn parse_json_member_of() {
             let select = query.body.as_select().unwrap();
             assert_eq!(
                 select.projection,
-                vec![SelectItem::UnnamedExpr(Expr::MemberOf(MemberOf {
-                    value: Box::new(Expr::Value(
-                        Value::SingleQuotedString("ab".to_string()).into()
-                    )),
-                    array: Box::new(Expr::Value(
-                        Value::SingleQuotedString(r#"[23, "abc", 17, "ab", 10]"#.to_string())
-                            .into()
-                    )),
-                }))]
+                vec![SelectItem::UnnamedExpr {
+                    expr: Expr::MemberOf(MemberOf {
+                        value: Box::new(Expr::Value(Box::new(
+                            Value::SingleQuotedString("ab".into()).into()
+                        ))),
+                        array: Box::new(Expr::Value(Box::new(
+                            Value::SingleQuotedString(r#"[23, "abc", 17, "ab", 10]"#.into()).into()
+                        ))),
+                    }),
+                    comments: Comments::default(),
+                }]
             );
         }
         _ => panic!("Unexpected statement {stmt}"),
@@ -4292,11 +4543,11 @@ fn parse_show_charset() {
     let res = mysql().verified_stmt("SHOW CHARACTER SET");
     assert_eq!(
         res,
-        Statement::ShowCharset(ShowCharset {
+        Statement::ShowCharset(Box::new(ShowCharset {
             token: AttachedToken::empty(),
             is_shorthand: false,
             filter: None
-        })
+        }))
     );
     mysql().verified_stmt("SHOW CHARACTER SET LIKE 'utf8mb4%'");
     mysql().verified_stmt("SHOW CHARSET WHERE charset = 'utf8mb4%'");
@@ -15,11 +15,12 @@
 // limitations under the License.
 
 use sqlparser::ast::{
-    AlternativeQuotedString, DataType, Expr, GraphPropertiesClause, Ident, SelectItem, SetExpr,
-    Statement, Value,
+    AlternativeQuotedString, AstBox, DataType, Expr, GraphPropertiesClause, Ident, SelectItem,
+    SetExpr, Statement, Value,
 };
 use sqlparser::dialect::{OracleDialect, PostgreSqlDialect};
-use sqlparser::parser::Parser;
+use sqlparser::parser::{Parser, ParserOptions};
+use sqlparser::tokenizer::{Location, Span};
 
 fn parse_one(sql: &str) -> Statement {
     let mut statements = Parser::parse_sql(&OracleDialect {}, sql).expect(sql);
@@ -37,7 +38,11 @@ fn oracle_decode_exposes_a_typed_argument_shape() {
     let SetExpr::Select(select) = query.body.as_ref() else {
         panic!("expected SELECT");
     };
-    let SelectItem::UnnamedExpr(Expr::Function(function)) = &select.projection[0] else {
+    let SelectItem::UnnamedExpr {
+        expr: Expr::Function(function),
+        ..
+    } = &select.projection[0]
+    else {
         panic!("expected DECODE function AST");
     };
     let arguments = function
@@ -67,7 +72,11 @@ fn postgres_decode_does_not_acquire_oracle_argument_semantics() {
     let SetExpr::Select(select) = query.body.as_ref() else {
         panic!("expected SELECT");
     };
-    let SelectItem::UnnamedExpr(Expr::Function(function)) = &select.projection[0] else {
+    let SelectItem::UnnamedExpr {
+        expr: Expr::Function(function),
+        ..
+    } = &select.projection[0]
+    else {
         panic!("expected function AST");
     };
     assert!(function
@@ -156,12 +165,16 @@ fn oracle_alternative_quoted_strings_preserve_content_and_delimiter() {
         },
     ];
     for (item, expected) in select.projection.iter().zip(expected) {
-        let SelectItem::UnnamedExpr(Expr::Value(value)) = item else {
+        let SelectItem::UnnamedExpr {
+            expr: Expr::Value(value),
+            ..
+        } = item
+        else {
             panic!("expected an alternative quoted value, got {item:?}");
         };
         assert_eq!(
             value.value,
-            Value::AlternativeQuotedString(expected.clone())
+            Value::AlternativeQuotedString(AstBox::new(expected.clone()))
         );
         assert_eq!(value.to_string(), expected.to_string());
     }
@@ -184,17 +197,17 @@ fn oracle_binary_float_suffixes_belong_to_the_literal_not_an_alias() {
 
     assert!(matches!(
         &select.projection[0],
-        SelectItem::UnnamedExpr(Expr::Value(value))
+        SelectItem::UnnamedExpr { expr: Expr::Value(value), .. }
             if value.value == Value::OracleBinaryFloat("1.25".to_string())
     ));
     assert!(matches!(
         &select.projection[1],
-        SelectItem::UnnamedExpr(Expr::Value(value))
+        SelectItem::UnnamedExpr { expr: Expr::Value(value), .. }
             if value.value == Value::OracleBinaryDouble("6.022".to_string())
     ));
     assert!(matches!(
         &select.projection[2],
-        SelectItem::UnnamedExpr(Expr::UnaryOp { expr, .. })
+        SelectItem::UnnamedExpr { expr: Expr::UnaryOp { expr, .. }, .. }
             if matches!(
                 expr.as_ref(),
                 Expr::Value(value)
@@ -258,7 +271,10 @@ fn oracle_bind_variables_are_typed_placeholders() {
         .projection
         .iter()
         .map(|item| match item {
-            SelectItem::UnnamedExpr(Expr::Value(value)) => &value.value,
+            SelectItem::UnnamedExpr {
+                expr: Expr::Value(value),
+                ..
+            } => &value.value,
             _ => panic!("expected bind value, got {item:?}"),
         })
         .collect::<Vec<_>>();
@@ -296,21 +312,22 @@ fn oracle_subquery_and_local_time_zone_expressions_are_typed() {
 
     assert!(matches!(
         &select.projection[0],
-        SelectItem::UnnamedExpr(Expr::Cursor(query))
+        SelectItem::UnnamedExpr { expr: Expr::Cursor(query), .. }
             if matches!(query.body.as_ref(), SetExpr::Select(_))
     ));
     assert!(matches!(
         &select.projection[1],
-        SelectItem::UnnamedExpr(Expr::Cast {
+        SelectItem::UnnamedExpr { expr: Expr::Cast {
             expr,
-            data_type: sqlparser::ast::DataType::Custom(_, _),
+            data_type,
             ..
-        }) if matches!(expr.as_ref(), Expr::Multiset(query)
+        }, .. } if matches!(data_type.as_ref(), sqlparser::ast::DataType::Custom(_, _))
+            && matches!(expr.as_ref(), Expr::Multiset(query)
             if matches!(query.body.as_ref(), SetExpr::Select(_)))
     ));
     assert!(matches!(
         &select.projection[2],
-        SelectItem::UnnamedExpr(Expr::AtLocal { timestamp })
+        SelectItem::UnnamedExpr { expr: Expr::AtLocal { timestamp }, .. }
             if matches!(timestamp.as_ref(), Expr::Identifier(identifier)
                 if identifier.value == "ORDER_TS")
     ));
@@ -448,10 +465,11 @@ fn oracle_partition_extensions_are_preserved() {
 #[test]
 fn oracle_plsql_procedure_invocations_are_typed() {
     let statement = parse_one("BEGIN DBMS_OUTPUT.PUT_LINE('hello'); risky_operation; END;");
-    let Statement::PlSqlBlock(sqlparser::ast::BeginEndStatements { statements, .. }) = statement
-    else {
+    let Statement::PlSqlBlock(begin_end_statements) = statement else {
         panic!("expected PL/SQL block");
     };
+    let sqlparser::ast::BeginEndStatements { statements, .. } =
+        AstBox::into_owned(begin_end_statements);
 
     assert_eq!(statements.len(), 2);
     assert!(matches!(
@@ -484,6 +502,47 @@ fn oracle_bare_begin_is_a_transaction_but_begin_end_is_plsql() {
     ));
 }
 
+#[test]
+fn oracle_recovery_does_not_split_plsql_bodies() {
+    let sql = "CREATE OR REPLACE PACKAGE BODY p AS\n\
+               PROCEDURE a IS\n\
+               x NUMBER;\n\
+               BEGIN\n\
+               IF x > 1 THEN\n\
+               x := CASE WHEN x = 1 THEN 2 END;\n\
+               END IF;\n\
+               SELEC 1;\n\
+               END a;\n\
+               END p;\n\
+               /\n\
+               SELECT 1 FROM dual\n\
+               /\n\
+               DECLARE\n\
+               y NUMBER;\n\
+               BEGIN\n\
+               y := 1 +;\n\
+               END;\n\
+               /\n\
+               SELECT 6\n\
+               / 3 FROM dual;";
+    let recovered =
+        Parser::parse_sql_with_recovery(&OracleDialect {}, sql, ParserOptions::new()).unwrap();
+
+    let statements: Vec<_> = recovered.statements.iter().map(|s| s.to_string()).collect();
+    assert_eq!(
+        statements,
+        vec!["SELECT 1 FROM DUAL", "SELECT 6 / 3 FROM DUAL"]
+    );
+    let error_spans: Vec<_> = recovered.errors.iter().map(|e| e.span).collect();
+    assert_eq!(
+        error_spans,
+        vec![
+            Span::new(Location::new(1, 1), Location::new(10, 6)),
+            Span::new(Location::new(14, 1), Location::new(18, 4)),
+        ]
+    );
+}
+
 #[test]
 fn oracle_plsql_block_declarations_are_typed() {
     let statement = parse_one(
@@ -528,6 +587,61 @@ fn oracle_plsql_block_declarations_are_typed() {
     assert_eq!(parse_one(&statement.to_string()), statement);
 }
 
+#[test]
+fn oracle_plsql_declarations_keep_attached_comments() {
+    let sql = "DECLARE\n\
+               -- running total\n\
+               amount NUMBER := 0; -- reset nightly\n\
+               /* unused */ label VARCHAR2(10);\n\
+               BEGIN NULL; END;";
+    let options = ParserOptions::new().with_attach_comments(true);
+    let statements = Parser::new(&OracleDialect {})
+        .with_options(options.clone())
+        .try_with_sql(sql)
+        .and_then(|parser| parser.parse_statements())
+        .unwrap();
+    let Statement::PlSqlBlock(block) = &statements[0] else {
+        panic!("expected PL/SQL block");
+    };
+
+    use sqlparser::ast::{PlSqlDeclaration, SourceComment};
+    let comments = block.declarations[0].comments();
+    assert_eq!(
+        comments.leading,
+        vec![SourceComment::SingleLine {
+            prefix: "--".to_string(),
+            text: " running total".to_string(),
+        }]
+    );
+    assert_eq!(
+        comments.trailing,
+        vec![SourceComment::SingleLine {
+            prefix: "--".to_string(),
+            text: " reset nightly".to_string(),
+        }]
+    );
+    assert!(matches!(
+        &block.declarations[1],
+        PlSqlDeclaration::Variable(declaration)
+            if declaration.comments.leading
+                == vec![SourceComment::MultiLine(" unused ".to_string())]
+    ));
+
+    let rendered = statements[0].to_string();
+    assert_eq!(
+        rendered,
+        "DECLARE -- running total\n\
+         AMOUNT NUMBER := 0 -- reset nightly\n\
+         ; /* unused */ LABEL VARCHAR2(10); BEGIN NULL; END"
+    );
+    let reparsed = Parser::new(&OracleDialect {})
+        .with_options(options)
+        .try_with_sql(&rendered)
+        .and_then(|parser| parser.parse_statements())
+        .unwrap();
+    assert_eq!(reparsed, statements);
+}
+
 #[test]
 fn oracle_plsql_type_and_cursor_declarations_are_typed() {
     let statement = parse_one(
@@ -676,15 +790,15 @@ fn oracle_commit_options_and_rename_are_typed() {
     assert!(matches!(
         &statement,
         Statement::Commit {
-            oracle: Some(sqlparser::ast::OracleCommitOptions {
-                write: Some(sqlparser::ast::OracleCommitWrite {
-                    wait: Some(false),
-                    immediate: Some(true),
-                }),
-                ..
+            oracle: Some(options),
+            ..
+        } if matches!(&**options, sqlparser::ast::OracleCommitOptions {
+            write: Some(sqlparser::ast::OracleCommitWrite {
+                wait: Some(false),
+                immediate: Some(true),
             }),
             ..
-        }
+        })
     ));
     assert_eq!(parse_one(&statement.to_string()), statement);
 
@@ -692,12 +806,12 @@ fn oracle_commit_options_and_rename_are_typed() {
     assert!(matches!(
         &statement,
         Statement::Commit {
-            oracle: Some(sqlparser::ast::OracleCommitOptions {
-                comment: Some(comment),
-                ..
-            }),
+            oracle: Some(options),
+            ..
+        } if matches!(&**options, sqlparser::ast::OracleCommitOptions {
+            comment: Some(comment),
             ..
-        } if comment == "batch-42"
+        } if comment == "batch-42")
     ));
 
     let statement = parse_one("RENAME employees_stage TO employees_ready");
@@ -776,7 +890,7 @@ fn oracle_bulk_collect_clauses_are_typed() {
     let Statement::Update(update) = statement else {
         panic!("expected update");
     };
-    let returning = update.returning.expect("RETURNING clause");
+    let returning = update.returning.as_ref().expect("RETURNING clause");
     assert!(returning.bulk_collect);
     assert_eq!(returning.into.as_ref().map(Vec::len), Some(1));
 }
@@ -1108,11 +1222,8 @@ fn oracle_extended_controls_and_directives_are_typed() {
             if compilation.branches.len() == 1
                 && compilation.else_statements.len() == 1
                 && matches!(
-                    compilation.branches[0].condition,
-                    Expr::Value(sqlparser::ast::ValueWithSpan {
-                        value: Value::PlSqlInquiryDirective(_),
-                        ..
-                    })
+                    &compilation.branches[0].condition,
+                    Expr::Value(value) if matches!(value.value, Value::PlSqlInquiryDirective(_))
                 )
     ));
     assert_eq!(parse_one(&statement.to_string()), statement);
@@ -1159,12 +1270,11 @@ fn oracle_administrative_statements_have_structured_ast() {
     let statement =
         parse_one("ASSOCIATE STATISTICS WITH COLUMNS employees.salary USING salary_stats_type");
     assert!(matches!(
-        statement,
-        Statement::OracleCommand(sqlparser::ast::OracleCommandStatement {
-            command: sqlparser::ast::OracleCommand::AssociateColumnStatistics { .. },
-            ..
-        })
-    ));
+    statement,
+    Statement::OracleCommand(ref oracle_command) if matches!(&**oracle_command, sqlparser::ast::OracleCommandStatement {
+        command: sqlparser::ast::OracleCommand::AssociateColumnStatistics { .. },
+        ..
+    })));
 
     assert_eq!(parse_one(&statement.to_string()), statement);
 }
@@ -1303,23 +1413,22 @@ fn oracle_transaction_privilege_and_explain_commands_are_typed() {
     let transaction = parse_one("SET TRANSACTION READ ONLY NAME 'reporting'");
     assert!(matches!(
         &transaction,
-        Statement::OracleCommand(sqlparser::ast::OracleCommandStatement {
+        Statement::OracleCommand(oracle_command) if matches!(&**oracle_command, sqlparser::ast::OracleCommandStatement {
             command: sqlparser::ast::OracleCommand::SetTransaction { modes, name },
             ..
-        }) if modes == &[sqlparser::ast::TransactionMode::AccessMode(
+        } if modes == &[sqlparser::ast::TransactionMode::AccessMode(
             sqlparser::ast::TransactionAccessMode::ReadOnly
-        )] && name.is_some()
-    ));
+        )] && name.is_some())));
 
     let unnamed_transaction = parse_one("SET TRANSACTION READ ONLY");
     assert!(matches!(
         &unnamed_transaction,
-        Statement::Set(sqlparser::ast::SetStatement {
+        Statement::Set(set) if matches!(&**set, sqlparser::ast::SetStatement {
             inner: sqlparser::ast::Set::SetTransaction { modes, snapshot: None, session: false },
             ..
-        }) if modes == &[sqlparser::ast::TransactionMode::AccessMode(
+        } if modes == &[sqlparser::ast::TransactionMode::AccessMode(
             sqlparser::ast::TransactionAccessMode::ReadOnly
-        )]
+        )])
     ));
 
     let wait = parse_one("SELECT * FROM jobs FOR UPDATE WAIT 7");
@@ -1337,27 +1446,25 @@ fn oracle_transaction_privilege_and_explain_commands_are_typed() {
     let role = parse_one("SET ROLE reporting_role IDENTIFIED BY secret");
     assert!(matches!(
         &role,
-        Statement::OracleCommand(sqlparser::ast::OracleCommandStatement {
+        Statement::OracleCommand(oracle_command) if matches!(&**oracle_command, sqlparser::ast::OracleCommandStatement {
             command: sqlparser::ast::OracleCommand::SetRole { role, password },
             ..
-        }) if role.to_string() == "REPORTING_ROLE" && password.value == "SECRET"
-    ));
+        } if role.to_string() == "REPORTING_ROLE" && password.value == "SECRET")));
 
     let grant = parse_one("GRANT CREATE SESSION, CREATE TABLE TO app_user WITH ADMIN OPTION");
     assert!(matches!(
         &grant,
-        Statement::OracleCommand(sqlparser::ast::OracleCommandStatement {
+        Statement::OracleCommand(oracle_command) if matches!(&**oracle_command, sqlparser::ast::OracleCommandStatement {
             command: sqlparser::ast::OracleCommand::GrantSystemPrivileges {
                 privileges,
                 grantees,
                 admin_option: true,
             },
             ..
-        }) if privileges == &[
+        } if privileges == &[
             sqlparser::ast::OracleSystemPrivilege::CreateSession,
             sqlparser::ast::OracleSystemPrivilege::CreateTable,
-        ] && grantees.len() == 1
-    ));
+        ] && grantees.len() == 1)));
 
     let explain = parse_one(
         "EXPLAIN PLAN SET STATEMENT_ID = 'q1' INTO plan_table \
@@ -1365,16 +1472,15 @@ fn oracle_transaction_privilege_and_explain_commands_are_typed() {
     );
     assert!(matches!(
         &explain,
-        Statement::OracleCommand(sqlparser::ast::OracleCommandStatement {
+        Statement::OracleCommand(oracle_command) if matches!(&**oracle_command, sqlparser::ast::OracleCommandStatement {
             command: sqlparser::ast::OracleCommand::ExplainPlan {
                 statement_id: Some(_),
                 into,
                 statement,
             },
             ..
-        }) if into.to_string() == "PLAN_TABLE"
-            && matches!(statement.as_ref(), Statement::Query(_))
-    ));
+        } if into.to_string() == "PLAN_TABLE"
+            && matches!(statement.as_ref(), Statement::Query(_)))));
 
     for statement in [transaction, role, grant, explain] {
         assert_eq!(parse_one(&statement.to_string()), statement);
@@ -1386,10 +1492,11 @@ fn oracle_mode_accepts_gantry_named_schema_extension() {
     let statement = parse_one("CREATE SCHEMA graph_a");
     assert!(matches!(
         statement,
-        Statement::CreateSchema {
-            schema_name: sqlparser::ast::SchemaName::Simple(ref name),
-            ..
-        } if name.to_string() == "GRAPH_A"
+        Statement::CreateSchema { ref schema_name, .. }
+            if matches!(
+                &**schema_name,
+                sqlparser::ast::SchemaName::Simple(name) if name.to_string() == "GRAPH_A"
+            )
     ));
 }
 
@@ -1438,7 +1545,7 @@ fn oracle_synonym_directory_link_and_sequence_lifecycle_is_typed() {
     let synonym = parse_one("CREATE OR REPLACE PUBLIC SYNONYM emp FOR hr.employees");
     assert!(matches!(
         &synonym,
-        Statement::OracleCreate(sqlparser::ast::OracleCreateStatement {
+        Statement::OracleCreate(oracle_create) if matches!(&**oracle_create, sqlparser::ast::OracleCreateStatement {
             or_replace: true,
             definition: sqlparser::ast::OracleCreateDefinition::Synonym {
                 public: true,
@@ -1446,20 +1553,18 @@ fn oracle_synonym_directory_link_and_sequence_lifecycle_is_typed() {
                 target,
             },
             ..
-        }) if name.to_string() == "EMP" && target.to_string() == "HR.EMPLOYEES"
-    ));
+        } if name.to_string() == "EMP" && target.to_string() == "HR.EMPLOYEES")));
 
     let directory = parse_one("CREATE OR REPLACE DIRECTORY data_dir AS '/srv/data' SHARING = NONE");
     assert!(matches!(
         &directory,
-        Statement::OracleCreate(sqlparser::ast::OracleCreateStatement {
+        Statement::OracleCreate(oracle_create) if matches!(&**oracle_create, sqlparser::ast::OracleCreateStatement {
             definition: sqlparser::ast::OracleCreateDefinition::Directory {
                 sharing: Some(sharing),
                 ..
             },
             ..
-        }) if sharing.value == "NONE"
-    ));
+        } if sharing.value == "NONE")));
 
     let database_link = parse_one(
         "CREATE PUBLIC DATABASE LINK reporting CONNECT TO report_user \
@@ -1467,15 +1572,14 @@ fn oracle_synonym_directory_link_and_sequence_lifecycle_is_typed() {
     );
     assert!(matches!(
         &database_link,
-        Statement::OracleCreate(sqlparser::ast::OracleCreateStatement {
+        Statement::OracleCreate(oracle_create) if matches!(&**oracle_create, sqlparser::ast::OracleCreateStatement {
             definition: sqlparser::ast::OracleCreateDefinition::DatabaseLink {
                 public: true,
                 user,
                 ..
             },
             ..
-        }) if user.to_string() == "REPORT_USER"
-    ));
+        } if user.to_string() == "REPORT_USER")));
 
     let altered = parse_one(
         "ALTER PUBLIC DATABASE LINK reporting \
@@ -1483,12 +1587,11 @@ fn oracle_synonym_directory_link_and_sequence_lifecycle_is_typed() {
     );
     assert!(matches!(
         &altered,
-        Statement::OracleAlter(sqlparser::ast::OracleAlterStatement {
+        Statement::OracleAlter(oracle_alter) if matches!(&**oracle_alter, sqlparser::ast::OracleAlterStatement {
             object_type: sqlparser::ast::OracleAlterObjectType::PublicDatabaseLink,
             operation: sqlparser::ast::OracleAlterOperation::ConnectTo { user, .. },
             ..
-        }) if user.to_string() == "REPORT_USER"
-    ));
+        } if user.to_string() == "REPORT_USER")));
 
     let sequence =
         parse_one("CREATE SEQUENCE audit_seq NOMINVALUE MAXVALUE 999 NOCACHE ORDER CYCLE");
@@ -1533,10 +1636,10 @@ fn oracle_pattern_floating_object_and_translation_expressions_are_typed() {
     };
     assert!(matches!(
         &select.projection[0],
-        SelectItem::UnnamedExpr(Expr::OracleTranslateUsing {
+        SelectItem::UnnamedExpr { expr: Expr::OracleTranslateUsing {
             character_set,
             ..
-        }) if character_set.to_string() == "NCHAR_CS"
+        }, .. } if character_set.to_string() == "NCHAR_CS"
     ));
     assert!(format!("{:?}", select.projection[1]).contains("OracleTreat"));
 
@@ -1618,12 +1721,12 @@ fn oracle_drop_and_truncate_modifiers_are_typed() {
     let drop_user = parse_one("DROP USER IF EXISTS app_user CASCADE");
     assert!(matches!(
         &drop_user,
-        Statement::OracleDrop(sqlparser::ast::OracleDropStatement {
+        Statement::OracleDrop(drop) if matches!(&**drop, sqlparser::ast::OracleDropStatement {
             object_type: sqlparser::ast::OracleDropObjectType::User,
             if_exists: true,
             options,
             ..
-        }) if options == &[sqlparser::ast::OracleDropOption::Cascade]
+        } if options == &[sqlparser::ast::OracleDropOption::Cascade])
     ));
 
     let drop_table = parse_one("DROP TABLE employees_stage CASCADE CONSTRAINTS PURGE");
@@ -1715,7 +1818,7 @@ fn oracle_index_families_are_typed() {
     );
     assert!(matches!(
         &standard,
-        Statement::OracleCreate(sqlparser::ast::OracleCreateStatement {
+        Statement::OracleCreate(oracle_create) if matches!(&**oracle_create, sqlparser::ast::OracleCreateStatement {
             definition: sqlparser::ast::OracleCreateDefinition::Index {
                 kind: sqlparser::ast::OracleIndexKind::Standard,
                 unique: true,
@@ -1727,8 +1830,7 @@ fn oracle_index_families_are_typed() {
                 ..
             },
             ..
-        }) if columns.len() == 2
-    ));
+        } if columns.len() == 2)));
 
     let domain = parse_one(
         "CREATE INDEX documents_text_ix ON documents (body) \
@@ -1736,7 +1838,7 @@ fn oracle_index_families_are_typed() {
     );
     assert!(matches!(
         &domain,
-        Statement::OracleCreate(sqlparser::ast::OracleCreateStatement {
+        Statement::OracleCreate(oracle_create) if matches!(&**oracle_create, sqlparser::ast::OracleCreateStatement {
             definition: sqlparser::ast::OracleCreateDefinition::Index {
                 options: sqlparser::ast::OracleIndexOptions {
                     indextype: Some(indextype),
@@ -1746,8 +1848,7 @@ fn oracle_index_families_are_typed() {
                 ..
             },
             ..
-        }) if indextype.to_string() == "CTXSYS.CONTEXT"
-    ));
+        } if indextype.to_string() == "CTXSYS.CONTEXT")));
 
     let vector = parse_one(
         "CREATE VECTOR INDEX items_embedding_hnsw ON items (embedding) \
@@ -1756,7 +1857,7 @@ fn oracle_index_families_are_typed() {
     );
     assert!(matches!(
         &vector,
-        Statement::OracleCreate(sqlparser::ast::OracleCreateStatement {
+        Statement::OracleCreate(oracle_create) if matches!(&**oracle_create, sqlparser::ast::OracleCreateStatement {
             definition: sqlparser::ast::OracleCreateDefinition::Index {
                 kind: sqlparser::ast::OracleIndexKind::Vector,
                 options: sqlparser::ast::OracleIndexOptions {
@@ -1767,18 +1868,16 @@ fn oracle_index_families_are_typed() {
                 ..
             },
             ..
-        }) if distance.value == "COSINE" && vector_parameters.len() == 2
-    ));
+        } if distance.value == "COSINE" && vector_parameters.len() == 2)));
 
     let altered = parse_one("ALTER INDEX employees_name_ix REBUILD ONLINE");
     assert!(matches!(
-        &altered,
-        Statement::OracleAlter(sqlparser::ast::OracleAlterStatement {
-            object_type: sqlparser::ast::OracleAlterObjectType::Index,
-            operation: sqlparser::ast::OracleAlterOperation::RebuildIndex { online: true },
-            ..
-        })
-    ));
+    &altered,
+    Statement::OracleAlter(oracle_alter) if matches!(&**oracle_alter, sqlparser::ast::OracleAlterStatement {
+        object_type: sqlparser::ast::OracleAlterObjectType::Index,
+        operation: sqlparser::ast::OracleAlterOperation::RebuildIndex { online: true },
+        ..
+    })));
 
     for statement in [standard, domain, vector, altered] {
         assert_eq!(parse_one(&statement.to_string()), statement);
@@ -1930,11 +2029,11 @@ fn oracle_keep_dense_rank_is_typed() {
     };
     assert!(matches!(
         &select.projection[1],
-        SelectItem::UnnamedExpr(Expr::OracleKeep {
+        SelectItem::UnnamedExpr { expr: Expr::OracleKeep {
             aggregate,
             rank: sqlparser::ast::OracleKeepRank::Last,
             order_by,
-        }) if matches!(aggregate.as_ref(), Expr::Function(_)) && order_by.len() == 2
+        }, .. } if matches!(aggregate.as_ref(), Expr::Function(_)) && order_by.len() == 2
     ));
     assert_eq!(parse_one(&statement.to_string()), statement);
 }
@@ -2023,12 +2122,12 @@ fn oracle_json_exists_clauses_are_typed() {
     };
     assert!(matches!(
         select.selection.as_deref(),
-        Some(Expr::Function(sqlparser::ast::Function {
+        Some(Expr::Function(function)) if matches!(&**function, sqlparser::ast::Function {
             args: sqlparser::ast::FunctionArguments::List(
                 sqlparser::ast::FunctionArgumentList { args, clauses, .. }
             ),
             ..
-        })) if args.len() == 2
+        } if args.len() == 2
             && matches!(
                 clauses.as_slice(),
                 [
@@ -2040,7 +2139,7 @@ fn oracle_json_exists_clauses_are_typed() {
                     && bindings[0].alias.as_ref().is_some_and(|alias| {
                         alias.value == "min" && alias.quote_style == Some('"')
                     })
-            )
+            ))
     ));
 
     for behavior in ["TRUE", "FALSE", "UNKNOWN"] {
@@ -2078,13 +2177,12 @@ fn oracle_alter_view_compile_is_typed() {
     let statement = parse_one("ALTER VIEW active_employees COMPILE");
     assert!(matches!(
         &statement,
-        Statement::OracleAlter(sqlparser::ast::OracleAlterStatement {
+        Statement::OracleAlter(oracle_alter) if matches!(&**oracle_alter, sqlparser::ast::OracleAlterStatement {
             object_type: sqlparser::ast::OracleAlterObjectType::View,
             target: sqlparser::ast::OracleAlterTarget::Name(name),
             operation: sqlparser::ast::OracleAlterOperation::Compile,
             ..
-        }) if name.to_string() == "ACTIVE_EMPLOYEES"
-    ));
+        } if name.to_string() == "ACTIVE_EMPLOYEES")));
     assert_eq!(parse_one(&statement.to_string()), statement);
 }
 
@@ -2093,13 +2191,13 @@ fn oracle_alter_materialized_view_refresh_is_typed() {
     let statement = parse_one("ALTER MATERIALIZED VIEW department_totals REFRESH FAST ON DEMAND");
     assert!(matches!(
         &statement,
-        Statement::AlterMaterializedView {
-            operation: sqlparser::ast::AlterMaterializedViewOperation::OracleRefresh {
+        Statement::AlterMaterializedView { operation, .. } if matches!(
+            &**operation,
+            sqlparser::ast::AlterMaterializedViewOperation::OracleRefresh {
                 method: sqlparser::ast::OracleMaterializedViewRefreshMethod::Fast,
                 mode: sqlparser::ast::OracleMaterializedViewRefreshMode::Demand,
-            },
-            ..
-        }
+            }
+        )
     ));
     for sql in [
         "ALTER MATERIALIZED VIEW department_totals REFRESH COMPLETE ON COMMIT",
@@ -2121,7 +2219,7 @@ fn oracle_relational_and_object_views_are_typed() {
     );
     assert!(matches!(
         &relational,
-        Statement::CreateView(sqlparser::ast::CreateView {
+        Statement::CreateView(create_view) if matches!(&**create_view, sqlparser::ast::CreateView {
             oracle: Some(sqlparser::ast::OracleCreateViewOptions {
                 force: Some(true),
                 editioning: Some(true),
@@ -2130,8 +2228,7 @@ fn oracle_relational_and_object_views_are_typed() {
             }),
             columns,
             ..
-        }) if columns.len() == 2
-    ));
+        } if columns.len() == 2)));
 
     let object = parse_one(
         "CREATE VIEW employee_objects OF employee_t \
@@ -2140,7 +2237,7 @@ fn oracle_relational_and_object_views_are_typed() {
     );
     assert!(matches!(
         &object,
-        Statement::CreateView(sqlparser::ast::CreateView {
+        Statement::CreateView(create_view) if matches!(&**create_view, sqlparser::ast::CreateView {
             oracle: Some(sqlparser::ast::OracleCreateViewOptions {
                 object: Some(sqlparser::ast::OracleObjectView {
                     data_type,
@@ -2149,8 +2246,7 @@ fn oracle_relational_and_object_views_are_typed() {
                 ..
             }),
             ..
-        }) if data_type.to_string() == "EMPLOYEE_T" && object_identifier.len() == 1
-    ));
+        } if data_type.to_string() == "EMPLOYEE_T" && object_identifier.len() == 1)));
 
     for statement in [relational, object] {
         assert_eq!(parse_one(&statement.to_string()), statement);
@@ -2166,8 +2262,7 @@ fn common_view_shapes_do_not_mint_empty_oracle_options() {
         let statement = parse_one(sql);
         assert!(matches!(
             &statement,
-            Statement::CreateView(sqlparser::ast::CreateView { oracle: None, .. })
-        ));
+            Statement::CreateView(create_view) if matches!(&**create_view, sqlparser::ast::CreateView { oracle: None, .. })));
         assert_eq!(parse_one(&statement.to_string()), statement);
     }
 }
@@ -2181,21 +2276,20 @@ fn oracle_create_materialized_view_options_are_typed() {
          GROUP BY department_id",
     );
     assert!(matches!(
-        &statement,
-        Statement::CreateView(sqlparser::ast::CreateView {
-            materialized: true,
-            oracle: Some(sqlparser::ast::OracleCreateViewOptions {
-                materialized: Some(sqlparser::ast::OracleCreateMaterializedViewOptions {
-                    build: Some(sqlparser::ast::OracleMaterializedViewBuild::Immediate),
-                    refresh_method: Some(sqlparser::ast::OracleMaterializedViewRefreshMethod::Fast),
-                    refresh_mode: Some(sqlparser::ast::OracleMaterializedViewRefreshMode::Commit),
-                    query_rewrite: Some(true),
-                }),
-                ..
+    &statement,
+    Statement::CreateView(create_view) if matches!(&**create_view, sqlparser::ast::CreateView {
+        materialized: true,
+        oracle: Some(sqlparser::ast::OracleCreateViewOptions {
+            materialized: Some(sqlparser::ast::OracleCreateMaterializedViewOptions {
+                build: Some(sqlparser::ast::OracleMaterializedViewBuild::Immediate),
+                refresh_method: Some(sqlparser::ast::OracleMaterializedViewRefreshMethod::Fast),
+                refresh_mode: Some(sqlparser::ast::OracleMaterializedViewRefreshMode::Commit),
+                query_rewrite: Some(true),
             }),
             ..
-        })
-    ));
+        }),
+        ..
+    })));
     for sql in [
         "CREATE MATERIALIZED VIEW mv BUILD DEFERRED REFRESH COMPLETE ON DEMAND DISABLE QUERY REWRITE AS SELECT 1 FROM dual",
         "CREATE MATERIALIZED VIEW mv REFRESH FORCE ON DEMAND AS SELECT 1 FROM dual",
@@ -2235,7 +2329,7 @@ fn oracle_dml_error_logging_is_typed() {
     );
     assert!(matches!(
         &insert,
-        Statement::Insert(sqlparser::ast::Insert {
+        Statement::Insert(insert) if matches!(&**insert, sqlparser::ast::Insert {
             error_logging:
                 Some(sqlparser::ast::OracleErrorLoggingClause {
                     table: Some(table),
@@ -2243,23 +2337,21 @@ fn oracle_dml_error_logging_is_typed() {
                     reject_limit: Some(sqlparser::ast::OracleRejectLimit::Unlimited),
                 }),
             ..
-        }) if table.to_string() == "ERR$_TARGET_TABLE"
-    ));
+        } if table.to_string() == "ERR$_TARGET_TABLE")));
 
     let update = parse_one(
         "UPDATE employees SET salary = salary * 2 \
          LOG ERRORS INTO err$_employees ('raise') REJECT LIMIT 10",
     );
     assert!(matches!(
-        &update,
-        Statement::Update(sqlparser::ast::Update {
-            error_logging: Some(sqlparser::ast::OracleErrorLoggingClause {
-                reject_limit: Some(sqlparser::ast::OracleRejectLimit::Value(_)),
-                ..
-            }),
+    &update,
+    Statement::Update(update) if matches!(&**update, sqlparser::ast::Update {
+        error_logging: Some(sqlparser::ast::OracleErrorLoggingClause {
+            reject_limit: Some(sqlparser::ast::OracleRejectLimit::Value(_)),
             ..
-        })
-    ));
+        }),
+        ..
+    })));
 
     let merge = parse_one(
         "MERGE INTO target d USING source s ON (d.id = s.id) \
@@ -2269,7 +2361,7 @@ fn oracle_dml_error_logging_is_typed() {
     assert!(matches!(
         &merge,
         Statement::Merge {
-            error_logging: Some(sqlparser::ast::OracleErrorLoggingClause { .. }),
+            error_logging: Some(_),
             ..
         }
     ));
@@ -2446,7 +2538,7 @@ fn oracle_interval_and_reference_partitioning_are_typed() {
     );
     assert!(matches!(
         &interval,
-        Statement::CreateTable(sqlparser::ast::CreateTable {
+        Statement::CreateTable(create_table) if matches!(&**create_table, sqlparser::ast::CreateTable {
             partition_by:
                 Some(sqlparser::ast::PartitionByClause {
                     strategy: sqlparser::ast::PartitionStrategy::Range,
@@ -2455,8 +2547,7 @@ fn oracle_interval_and_reference_partitioning_are_typed() {
                     ..
                 }),
             ..
-        }) if partitions.len() == 1 && partitions[0].values_less_than.len() == 1
-    ));
+        } if partitions.len() == 1 && partitions[0].values_less_than.len() == 1)));
 
     let reference = parse_one(
         "CREATE TABLE order_items (\
@@ -2466,7 +2557,7 @@ fn oracle_interval_and_reference_partitioning_are_typed() {
     );
     assert!(matches!(
         &reference,
-        Statement::CreateTable(sqlparser::ast::CreateTable {
+        Statement::CreateTable(create_table) if matches!(&**create_table, sqlparser::ast::CreateTable {
             partition_by:
                 Some(sqlparser::ast::PartitionByClause {
                     strategy: sqlparser::ast::PartitionStrategy::Reference,
@@ -2475,8 +2566,7 @@ fn oracle_interval_and_reference_partitioning_are_typed() {
                     ..
                 }),
             ..
-        }) if columns.len() == 1
-    ));
+        } if columns.len() == 1)));
 
     for statement in [interval, reference] {
         assert_eq!(parse_one(&statement.to_string()), statement);
@@ -2490,15 +2580,14 @@ fn oracle_special_table_kinds_are_typed() {
          ON COMMIT DROP DEFINITION",
     );
     assert!(matches!(
-        &private,
-        Statement::OracleCreateTable(sqlparser::ast::OracleCreateTable {
-            kind: sqlparser::ast::OracleCreateTableKind::PrivateTemporary,
-            options: sqlparser::ast::OracleCreateTableOptions::PrivateTemporary {
-                drop_definition: true,
-            },
-            ..
-        })
-    ));
+    &private,
+    Statement::OracleCreateTable(oracle_create_table) if matches!(&**oracle_create_table, sqlparser::ast::OracleCreateTable {
+        kind: sqlparser::ast::OracleCreateTableKind::PrivateTemporary,
+        options: sqlparser::ast::OracleCreateTableOptions::PrivateTemporary {
+            drop_definition: true,
+        },
+        ..
+    })));
 
     let blockchain = parse_one(
         "CREATE BLOCKCHAIN TABLE ledger (id NUMBER, payload JSON) \
@@ -2507,35 +2596,33 @@ fn oracle_special_table_kinds_are_typed() {
          HASHING USING SHA2_512 VERSION v1",
     );
     assert!(matches!(
-        &blockchain,
-        Statement::OracleCreateTable(sqlparser::ast::OracleCreateTable {
-            kind: sqlparser::ast::OracleCreateTableKind::Blockchain,
-            options: sqlparser::ast::OracleCreateTableOptions::Retention {
-                no_delete_until: Some(_),
-                no_delete_after_insert: true,
-                hashing: Some(_),
-                ..
-            },
+    &blockchain,
+    Statement::OracleCreateTable(oracle_create_table) if matches!(&**oracle_create_table, sqlparser::ast::OracleCreateTable {
+        kind: sqlparser::ast::OracleCreateTableKind::Blockchain,
+        options: sqlparser::ast::OracleCreateTableOptions::Retention {
+            no_delete_until: Some(_),
+            no_delete_after_insert: true,
+            hashing: Some(_),
             ..
-        })
-    ));
+        },
+        ..
+    })));
 
     let immutable = parse_one(
         "CREATE IMMUTABLE TABLE audit_events (event_id NUMBER, payload JSON) \
          NO DROP UNTIL 30 DAYS IDLE NO DELETE",
     );
     assert!(matches!(
-        &immutable,
-        Statement::OracleCreateTable(sqlparser::ast::OracleCreateTable {
-            kind: sqlparser::ast::OracleCreateTableKind::Immutable,
-            options: sqlparser::ast::OracleCreateTableOptions::Retention {
-                no_delete_until: None,
-                hashing: None,
-                ..
-            },
+    &immutable,
+    Statement::OracleCreateTable(oracle_create_table) if matches!(&**oracle_create_table, sqlparser::ast::OracleCreateTable {
+        kind: sqlparser::ast::OracleCreateTableKind::Immutable,
+        options: sqlparser::ast::OracleCreateTableOptions::Retention {
+            no_delete_until: None,
+            hashing: None,
             ..
-        })
-    ));
+        },
+        ..
+    })));
 
     for statement in [private, blockchain, immutable] {
         assert_eq!(parse_one(&statement.to_string()), statement);
@@ -2553,21 +2640,20 @@ fn oracle_external_tables_are_typed() {
     );
     assert!(matches!(
         &create,
-        Statement::OracleCreateExternalTable {
-            definition:
-                sqlparser::ast::OracleExternalTableDefinition {
-                    columns,
-                    access_driver,
-                    access_parameters,
-                    locations,
-                    reject_limit: Some(sqlparser::ast::OracleRejectLimit::Unlimited),
-                    ..
-                },
-            ..
-        } if columns.len() == 2
-            && access_driver.value == "ORACLE_LOADER"
-            && access_parameters.len() == 1
-            && locations.len() == 1
+        Statement::OracleCreateExternalTable { definition, .. } if matches!(
+            &**definition,
+            sqlparser::ast::OracleExternalTableDefinition {
+                columns,
+                access_driver,
+                access_parameters,
+                locations,
+                reject_limit: Some(sqlparser::ast::OracleRejectLimit::Unlimited),
+                ..
+            } if columns.len() == 2
+                && access_driver.value == "ORACLE_LOADER"
+                && access_parameters.len() == 1
+                && locations.len() == 1
+        )
     ));
 
     let inline = parse_one(
@@ -2806,17 +2892,17 @@ fn oracle_dynamic_open_collection_calls_and_trigger_aliases_are_typed() {
     };
     assert!(matches!(
         &block.statements[0],
-        Statement::Open(sqlparser::ast::OpenStatement {
+        Statement::Open(open) if matches!(&**open, sqlparser::ast::OpenStatement {
             open_for:
                 Some(sqlparser::ast::OpenFor::OracleDynamic {
                     query_expr,
                     using,
                 }),
             ..
-        }) if matches!(query_expr.as_ref(), Expr::Identifier(_))
+        } if matches!(query_expr.as_ref(), Expr::Identifier(_))
             && using.len() == 2
             && using[0].mode == sqlparser::ast::PlSqlParameterMode::In
-            && using[1].mode == sqlparser::ast::PlSqlParameterMode::Out
+            && using[1].mode == sqlparser::ast::PlSqlParameterMode::Out)
     ));
 
     let static_open = parse_one(
@@ -2828,10 +2914,10 @@ fn oracle_dynamic_open_collection_calls_and_trigger_aliases_are_typed() {
     };
     assert!(matches!(
         &block.statements[0],
-        Statement::Open(sqlparser::ast::OpenStatement {
+        Statement::Open(open) if matches!(&**open, sqlparser::ast::OpenStatement {
             open_for: Some(sqlparser::ast::OpenFor::OracleQuery { using, .. }),
             ..
-        }) if using.len() == 1
+        } if using.len() == 1)
     ));
 
     let bound_open = parse_one(
@@ -2844,11 +2930,11 @@ fn oracle_dynamic_open_collection_calls_and_trigger_aliases_are_typed() {
     };
     assert!(matches!(
         &block.statements[0],
-        Statement::Open(sqlparser::ast::OpenStatement {
+        Statement::Open(open) if matches!(&**open, sqlparser::ast::OpenStatement {
             open_for: Some(sqlparser::ast::OpenFor::BoundCursorArgs(arguments)),
             ..
-        }) if arguments.len() == 2
-            && arguments.iter().all(|argument| argument.name.is_some())
+        } if arguments.len() == 2
+            && arguments.iter().all(|argument| argument.name.is_some()))
     ));
 
     let collection_call = parse_one(
@@ -2871,26 +2957,25 @@ fn oracle_dynamic_open_collection_calls_and_trigger_aliases_are_typed() {
          FOR EACH ROW BEGIN NULL; END;",
     );
     assert!(matches!(
-        &trigger,
-        Statement::OracleCreateTrigger(sqlparser::ast::OracleCreateTrigger {
-            referencing,
-            ..
-        }) if matches!(
-            referencing.as_slice(),
-            [
-                sqlparser::ast::OracleTriggerReferencing {
-                    kind: sqlparser::ast::OracleTriggerReferencingKind::Old,
-                    is_as: true,
-                    ..
-                },
-                sqlparser::ast::OracleTriggerReferencing {
-                    kind: sqlparser::ast::OracleTriggerReferencingKind::New,
-                    is_as: false,
-                    ..
-                },
-            ]
-        )
-    ));
+    &trigger,
+    Statement::OracleCreateTrigger(oracle_create_trigger) if matches!(&**oracle_create_trigger, sqlparser::ast::OracleCreateTrigger {
+        referencing,
+        ..
+    } if matches!(
+        referencing.as_slice(),
+        [
+            sqlparser::ast::OracleTriggerReferencing {
+                kind: sqlparser::ast::OracleTriggerReferencingKind::Old,
+                is_as: true,
+                ..
+            },
+            sqlparser::ast::OracleTriggerReferencing {
+                kind: sqlparser::ast::OracleTriggerReferencingKind::New,
+                is_as: false,
+                ..
+            },
+        ]
+    ))));
 
     let ordered_trigger = parse_one(
         "CREATE TRIGGER IF NOT EXISTS employees_forward BEFORE UPDATE ON employees \
@@ -2899,7 +2984,7 @@ fn oracle_dynamic_open_collection_calls_and_trigger_aliases_are_typed() {
     );
     assert!(matches!(
         &ordered_trigger,
-        Statement::OracleCreateTrigger(sqlparser::ast::OracleCreateTrigger {
+        Statement::OracleCreateTrigger(oracle_create_trigger) if matches!(&**oracle_create_trigger, sqlparser::ast::OracleCreateTrigger {
             if_not_exists: true,
             crossedition: Some(sqlparser::ast::OracleTriggerCrossedition::Forward),
             ordering: Some(sqlparser::ast::OracleTriggerOrdering {
@@ -2908,20 +2993,18 @@ fn oracle_dynamic_open_collection_calls_and_trigger_aliases_are_typed() {
             }),
             enabled: Some(false),
             ..
-        }) if triggers.len() == 2
-    ));
+        } if triggers.len() == 2)));
 
     let call_trigger = parse_one(
         "CREATE TRIGGER employees_call AFTER INSERT ON employees \
          FOR EACH ROW CALL audit_employee_change(NEW.employee_id)",
     );
     assert!(matches!(
-        &call_trigger,
-        Statement::OracleCreateTrigger(sqlparser::ast::OracleCreateTrigger {
-            body: sqlparser::ast::OracleTriggerBody::Call(Expr::Function(_)),
-            ..
-        })
-    ));
+    &call_trigger,
+    Statement::OracleCreateTrigger(oracle_create_trigger) if matches!(&**oracle_create_trigger, sqlparser::ast::OracleCreateTrigger {
+        body: sqlparser::ast::OracleTriggerBody::Call(Expr::Function(_)),
+        ..
+    })));
 
     for statement in [
         dynamic_open,
@@ -2943,52 +3026,49 @@ fn oracle_trigger_event_families_and_targets_are_typed() {
          OR DISASSOCIATE STATISTICS OR DDL ON SCHEMA BEGIN NULL; END;",
     );
     assert!(matches!(
-        &ddl,
-        Statement::OracleCreateTrigger(sqlparser::ast::OracleCreateTrigger {
-            events,
-            target: sqlparser::ast::OracleTriggerTarget::Schema,
-            ..
-        }) if matches!(
-            events.as_slice(),
-            [
-                sqlparser::ast::OracleTriggerEvent::Analyze,
-                sqlparser::ast::OracleTriggerEvent::AssociateStatistics,
-                sqlparser::ast::OracleTriggerEvent::DisassociateStatistics,
-                sqlparser::ast::OracleTriggerEvent::Ddl,
-            ]
-        )
-    ));
+    &ddl,
+    Statement::OracleCreateTrigger(oracle_create_trigger) if matches!(&**oracle_create_trigger, sqlparser::ast::OracleCreateTrigger {
+        events,
+        target: sqlparser::ast::OracleTriggerTarget::Schema,
+        ..
+    } if matches!(
+        events.as_slice(),
+        [
+            sqlparser::ast::OracleTriggerEvent::Analyze,
+            sqlparser::ast::OracleTriggerEvent::AssociateStatistics,
+            sqlparser::ast::OracleTriggerEvent::DisassociateStatistics,
+            sqlparser::ast::OracleTriggerEvent::Ddl,
+        ]
+    ))));
 
     let database = parse_one(
         "CREATE TRIGGER pdb_events AFTER STARTUP OR DB_ROLE_CHANGE OR SERVERERROR \
          OR SET CONTAINER ON PLUGGABLE DATABASE BEGIN NULL; END;",
     );
     assert!(matches!(
-        &database,
-        Statement::OracleCreateTrigger(sqlparser::ast::OracleCreateTrigger {
-            events,
-            target: sqlparser::ast::OracleTriggerTarget::PluggableDatabase,
-            ..
-        }) if matches!(
-            events.as_slice(),
-            [
-                sqlparser::ast::OracleTriggerEvent::Startup,
-                sqlparser::ast::OracleTriggerEvent::DbRoleChange,
-                sqlparser::ast::OracleTriggerEvent::ServerError,
-                sqlparser::ast::OracleTriggerEvent::SetContainer,
-            ]
-        )
-    ));
+    &database,
+    Statement::OracleCreateTrigger(oracle_create_trigger) if matches!(&**oracle_create_trigger, sqlparser::ast::OracleCreateTrigger {
+        events,
+        target: sqlparser::ast::OracleTriggerTarget::PluggableDatabase,
+        ..
+    } if matches!(
+        events.as_slice(),
+        [
+            sqlparser::ast::OracleTriggerEvent::Startup,
+            sqlparser::ast::OracleTriggerEvent::DbRoleChange,
+            sqlparser::ast::OracleTriggerEvent::ServerError,
+            sqlparser::ast::OracleTriggerEvent::SetContainer,
+        ]
+    ))));
 
     let named_schema =
         parse_one("CREATE TRIGGER schema_logon AFTER LOGON ON hr.SCHEMA BEGIN NULL; END;");
     assert!(matches!(
         &named_schema,
-        Statement::OracleCreateTrigger(sqlparser::ast::OracleCreateTrigger {
+        Statement::OracleCreateTrigger(oracle_create_trigger) if matches!(&**oracle_create_trigger, sqlparser::ast::OracleCreateTrigger {
             target: sqlparser::ast::OracleTriggerTarget::NamedSchema(schema),
             ..
-        }) if schema.value == "HR"
-    ));
+        } if schema.value == "HR")));
 
     for statement in [ddl, database, named_schema] {
         assert_eq!(parse_one(&statement.to_string()), statement);
@@ -159,7 +159,7 @@ fn returning_into_is_scoped_to_procedural_bodies() {
     let Statement::CreateFunction(function) = pg().verified_stmt(sql) else {
         panic!("expected CREATE FUNCTION");
     };
-    let Some(CreateFunctionBody::AsBeginEnd(body)) = function.function_body else {
+    let Some(CreateFunctionBody::AsBeginEnd(body)) = &function.function_body else {
         panic!("expected structured procedural body");
     };
     let Some(Statement::Update(update)) = body.statements.first() else {
@@ -532,7 +532,7 @@ fn parse_begin_isolation_level_as_transaction() {
     assert_eq!(
         stmt,
         Statement::StartTransaction {
-            start_token: AttachedToken::empty(),
+            start_token: Box::new(AttachedToken::empty()),
             modes: vec![TransactionMode::IsolationLevel(
                 TransactionIsolationLevel::RepeatableRead,
             )],
@@ -616,16 +616,20 @@ fn parse_create_table_with_defaults() {
             active int NOT NULL
     ) WITH (fillfactor = 20, user_catalog_table = true, autovacuum_vacuum_threshold = 100)";
     match pg_and_generic().one_statement_parses_to(sql, "") {
-        Statement::CreateTable(CreateTable {
-            name,
-            columns,
-            constraints,
-            table_options,
-            if_not_exists: false,
-            external: false,
-            location: None,
-            ..
-        }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable {
+                name,
+                columns,
+                constraints,
+                table_options,
+                if_not_exists: false,
+                external: false,
+                location: None,
+                ..
+            } = Box::into_owned(create_table)
+            else {
+                unreachable!()
+            };
             use pretty_assertions::assert_eq;
             assert_eq!("public.customer", name.to_string());
             assert_eq!(
@@ -640,6 +644,7 @@ fn parse_create_table_with_defaults() {
                                 pg().verified_expr("nextval(public.customer_customer_id_seq)")
                             )
                         }],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "store_id".into(),
@@ -648,6 +653,7 @@ fn parse_create_table_with_defaults() {
                             name: None,
                             option: ColumnOption::NotNull,
                         }],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "first_name".into(),
@@ -661,6 +667,7 @@ fn parse_create_table_with_defaults() {
                             name: None,
                             option: ColumnOption::NotNull,
                         }],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "last_name".into(),
@@ -682,6 +689,7 @@ fn parse_create_table_with_defaults() {
                                 option: ColumnOption::NotNull,
                             }
                         ],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "email".into(),
@@ -692,6 +700,7 @@ fn parse_create_table_with_defaults() {
                             }
                         )),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "address_id".into(),
@@ -700,6 +709,7 @@ fn parse_create_table_with_defaults() {
                             name: None,
                             option: ColumnOption::NotNull
                         }],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "activebool".into(),
@@ -707,15 +717,16 @@ fn parse_create_table_with_defaults() {
                         options: vec![
                             ColumnOptionDef {
                                 name: None,
-                                option: ColumnOption::Default(Expr::Value(
+                                option: ColumnOption::Default(Expr::Value(Box::new(
                                     (Value::Boolean(true)).with_empty_span()
-                                )),
+                                ))),
                             },
                             ColumnOptionDef {
                                 name: None,
                                 option: ColumnOption::NotNull,
                             }
                         ],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "create_date".into(),
@@ -730,6 +741,7 @@ fn parse_create_table_with_defaults() {
                                 option: ColumnOption::NotNull,
                             }
                         ],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "last_update".into(),
@@ -744,6 +756,7 @@ fn parse_create_table_with_defaults() {
                                 option: ColumnOption::NotNull,
                             }
                         ],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "active".into(),
@@ -752,6 +765,7 @@ fn parse_create_table_with_defaults() {
                             name: None,
                             option: ColumnOption::NotNull
                         }],
+                        comments: Comments::default(),
                     },
                 ]
             );
@@ -770,7 +784,7 @@ fn parse_create_table_with_defaults() {
                     },
                     SqlOption::KeyValue {
                         key: "user_catalog_table".into(),
-                        value: Expr::Value((Value::Boolean(true)).with_empty_span())
+                        value: Expr::Value(Box::new((Value::Boolean(true)).with_empty_span()))
                     },
                     SqlOption::KeyValue {
                         key: "autovacuum_vacuum_threshold".into(),
@@ -840,12 +854,13 @@ fn parse_create_table_constraints_only() {
     let sql = "CREATE TABLE t (CONSTRAINT positive CHECK (2 > 1))";
     let ast = pg_and_generic().verified_stmt(sql);
     match ast {
-        Statement::CreateTable(CreateTable {
-            name,
-            columns,
-            constraints,
-            ..
-        }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable {
+                name,
+                columns,
+                constraints,
+                ..
+            } = Box::into_owned(create_table);
             assert_eq!("t", name.to_string());
             assert!(columns.is_empty());
             assert_eq!(
@@ -1070,8 +1085,9 @@ fn parse_alter_table_alter_column() {
     ) {
         AlterTableOperation::AlterColumn { column_name, op } => {
             assert_eq!("is_active", column_name.to_string());
-            let using_expr =
-                Expr::Value(Value::SingleQuotedString("text".to_string()).with_empty_span());
+            let using_expr = Expr::Value(Box::new(
+                Value::SingleQuotedString("text".into()).with_empty_span(),
+            ));
             assert_eq!(
                 op,
                 AlterColumnOperation::SetDataType {
@@ -1144,6 +1160,7 @@ fn parse_alter_table_add_columns() {
                             name: "a".into(),
                             data_type: DataType::Text,
                             options: vec![],
+                            comments: Comments::default(),
                         },
                         column_position: None,
                     },
@@ -1154,6 +1171,7 @@ fn parse_alter_table_add_columns() {
                             name: "b".into(),
                             data_type: DataType::Int(None),
                             options: vec![],
+                            comments: Comments::default(),
                         },
                         column_position: None,
                     },
@@ -1249,11 +1267,15 @@ fn parse_create_table_if_not_exists() {
     let sql = "CREATE TABLE IF NOT EXISTS uk_cities ()";
     let ast = pg_and_generic().verified_stmt(sql);
     match ast {
-        Statement::CreateTable(CreateTable {
-            name,
-            if_not_exists: true,
-            ..
-        }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable {
+                name,
+                if_not_exists: true,
+                ..
+            } = Box::into_owned(create_table)
+            else {
+                unreachable!()
+            };
             assert_eq!("uk_cities", name.to_string());
         }
         _ => unreachable!(),
@@ -1365,10 +1387,10 @@ fn test_copy_from() {
     assert_eq!(
         stmt,
         Statement::Copy {
-            source: CopySource::Table {
+            source: Box::new(CopySource::Table {
                 table_name: ObjectName::from(vec!["users".into()]),
                 columns: vec![],
-            },
+            }),
             to: false,
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
@@ -1383,10 +1405,10 @@ fn test_copy_from() {
     assert_eq!(
         stmt,
         Statement::Copy {
-            source: CopySource::Table {
+            source: Box::new(CopySource::Table {
                 table_name: ObjectName::from(vec!["users".into()]),
                 columns: vec![],
-            },
+            }),
             to: false,
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
@@ -1401,10 +1423,10 @@ fn test_copy_from() {
     assert_eq!(
         stmt,
         Statement::Copy {
-            source: CopySource::Table {
+            source: Box::new(CopySource::Table {
                 table_name: ObjectName::from(vec!["users".into()]),
                 columns: vec![],
-            },
+            }),
             to: false,
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
@@ -1425,10 +1447,10 @@ fn test_copy_to() {
     assert_eq!(
         stmt,
         Statement::Copy {
-            source: CopySource::Table {
+            source: Box::new(CopySource::Table {
                 table_name: ObjectName::from(vec!["users".into()]),
                 columns: vec![],
-            },
+            }),
             to: true,
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
@@ -1443,10 +1465,10 @@ fn test_copy_to() {
     assert_eq!(
         stmt,
         Statement::Copy {
-            source: CopySource::Table {
+            source: Box::new(CopySource::Table {
                 table_name: ObjectName::from(vec!["users".into()]),
                 columns: vec![],
-            },
+            }),
             to: true,
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
@@ -1461,10 +1483,10 @@ fn test_copy_to() {
     assert_eq!(
         stmt,
         Statement::Copy {
-            source: CopySource::Table {
+            source: Box::new(CopySource::Table {
                 table_name: ObjectName::from(vec!["users".into()]),
                 columns: vec![],
-            },
+            }),
             to: true,
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
@@ -1510,10 +1532,10 @@ fn parse_copy_from() {
     assert_eq!(
         pg_and_generic().one_statement_parses_to(sql, ""),
         Statement::Copy {
-            source: CopySource::Table {
+            source: Box::new(CopySource::Table {
                 table_name: ObjectName::from(vec!["table".into()]),
                 columns: vec!["a".into(), "b".into()],
-            },
+            }),
             to: false,
             target: CopyTarget::File {
                 filename: "file.csv".into()
@@ -1564,10 +1586,10 @@ fn parse_copy_to() {
     assert_eq!(
         stmt,
         Statement::Copy {
-            source: CopySource::Table {
+            source: Box::new(CopySource::Table {
                 table_name: ObjectName::from(vec!["users".into()]),
                 columns: vec![],
-            },
+            }),
             to: true,
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
@@ -1582,10 +1604,10 @@ fn parse_copy_to() {
     assert_eq!(
         stmt,
         Statement::Copy {
-            source: CopySource::Table {
+            source: Box::new(CopySource::Table {
                 table_name: ObjectName::from(vec!["country".into()]),
                 columns: vec![],
-            },
+            }),
             to: true,
             target: CopyTarget::Stdout,
             options: vec![CopyOption::Delimiter('|')],
@@ -1599,10 +1621,10 @@ fn parse_copy_to() {
     assert_eq!(
         stmt,
         Statement::Copy {
-            source: CopySource::Table {
+            source: Box::new(CopySource::Table {
                 table_name: ObjectName::from(vec!["country".into()]),
                 columns: vec![],
-            },
+            }),
             to: true,
             target: CopyTarget::Program {
                 command: "gzip > /usr1/proj/bray/sql/country_data.gz".into(),
@@ -1617,7 +1639,7 @@ fn parse_copy_to() {
     assert_eq!(
         stmt,
         Statement::Copy {
-            source: CopySource::Query(Box::new(Query {
+            source: Box::new(CopySource::Query(Box::new(Query {
                 with: None,
                 body: Box::new(SetExpr::Select(Box::new(Select {
                     select_token: AttachedToken::empty(),
@@ -1632,22 +1654,25 @@ fn parse_copy_to() {
                                 quote_style: None,
                                 span: Span::empty(),
                             },
+                            comments: Comments::default(),
                         },
                         SelectItem::ExprWithAlias {
-                            expr: Expr::Value(
+                            expr: Expr::Value(Box::new(
                                 (Value::SingleQuotedString("hello".into())).with_empty_span()
-                            ),
+                            )),
                             alias: Ident {
                                 value: "b".into(),
                                 quote_style: None,
                                 span: Span::empty(),
                             },
+                            comments: Comments::default(),
                         }
-                    ],
+                    ]
+                    .into(),
                     into: None,
-                    from: vec![],
+                    from: AstVec::new(),
                     selection: None,
-                    group_by: GroupByExpr::Expressions(vec![], vec![]),
+                    group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
                     having: None,
                     qualify: None,
                     named_window: vec![],
@@ -1657,9 +1682,10 @@ fn parse_copy_to() {
                 order_by: None,
                 limit_clause: None,
                 fetch: None,
-                locks: vec![],
+                locks: AstVec::new(),
                 for_clause: None,
-            })),
+                comments: Comments::default(),
+            }))),
             to: true,
             target: CopyTarget::File {
                 filename: "query.csv".into(),
@@ -1677,10 +1703,10 @@ fn parse_copy_from_before_v9_0() {
     assert_eq!(
         stmt,
         Statement::Copy {
-            source: CopySource::Table {
+            source: Box::new(CopySource::Table {
                 table_name: ObjectName::from(vec!["users".into()]),
                 columns: vec![],
-            },
+            }),
             to: false,
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
@@ -1706,10 +1732,10 @@ fn parse_copy_from_before_v9_0() {
     assert_eq!(
         pg_and_generic().one_statement_parses_to(sql, ""),
         Statement::Copy {
-            source: CopySource::Table {
+            source: Box::new(CopySource::Table {
                 table_name: ObjectName::from(vec!["users".into()]),
                 columns: vec![],
-            },
+            }),
             to: false,
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
@@ -1734,10 +1760,10 @@ fn parse_copy_to_before_v9_0() {
     assert_eq!(
         stmt,
         Statement::Copy {
-            source: CopySource::Table {
+            source: Box::new(CopySource::Table {
                 table_name: ObjectName::from(vec!["users".into()]),
                 columns: vec![],
-            },
+            }),
             to: true,
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
@@ -1764,89 +1790,89 @@ fn parse_set() {
     let stmt = pg_and_generic().verified_stmt("SET a = b");
     assert_eq!(
         stmt,
-        Statement::Set(SetStatement {
+        Statement::Set(Box::new(SetStatement {
             token: AttachedToken::empty(),
             inner: Set::SingleAssignment {
                 scope: None,
                 variable: ObjectName::from(vec![Ident::new("a")]),
-                values: vec![Expr::Identifier(Ident {
+                values: vec![Expr::Identifier(Box::new(Ident {
                     value: "b".into(),
                     quote_style: None,
                     span: Span::empty(),
-                })],
+                }))],
             }
-        })
+        }))
     );
 
     let stmt = pg_and_generic().verified_stmt("SET a = 'b'");
     assert_eq!(
         stmt,
-        Statement::Set(SetStatement {
+        Statement::Set(Box::new(SetStatement {
             token: AttachedToken::empty(),
             inner: Set::SingleAssignment {
                 scope: None,
                 variable: ObjectName::from(vec![Ident::new("a")]),
-                values: vec![Expr::Value(
+                values: vec![Expr::Value(Box::new(
                     (Value::SingleQuotedString("b".into())).with_empty_span()
-                )],
+                ))],
             }
-        })
+        }))
     );
 
     let stmt = pg_and_generic().verified_stmt("SET a = 0");
     assert_eq!(
         stmt,
-        Statement::Set(SetStatement {
+        Statement::Set(Box::new(SetStatement {
             token: AttachedToken::empty(),
             inner: Set::SingleAssignment {
                 scope: None,
                 variable: ObjectName::from(vec![Ident::new("a")]),
                 values: vec![Expr::value(number("0"))],
             }
-        })
+        }))
     );
 
     let stmt = pg_and_generic().one_statement_parses_to("SET a = DEFAULT", "SET a = default");
     assert_eq!(
         stmt,
-        Statement::Set(SetStatement {
+        Statement::Set(Box::new(SetStatement {
             token: AttachedToken::empty(),
             inner: Set::SingleAssignment {
                 scope: None,
                 variable: ObjectName::from(vec![Ident::new("a")]),
-                values: vec![Expr::Identifier(Ident::new("default"))],
+                values: vec![Expr::Identifier(Box::new(Ident::new("default")))],
             }
-        })
+        }))
     );
 
     let stmt = pg_and_generic().verified_stmt("SET LOCAL a = b");
     assert_eq!(
         stmt,
-        Statement::Set(SetStatement {
+        Statement::Set(Box::new(SetStatement {
             token: AttachedToken::empty(),
             inner: Set::SingleAssignment {
                 scope: Some(ContextModifier::Local),
                 variable: ObjectName::from(vec![Ident::new("a")]),
-                values: vec![Expr::Identifier("b".into())],
+                values: vec![Expr::Identifier(Box::new("b".into()))],
             }
-        })
+        }))
     );
 
     let stmt = pg_and_generic().verified_stmt("SET a.b.c = b");
     assert_eq!(
         stmt,
-        Statement::Set(SetStatement {
+        Statement::Set(Box::new(SetStatement {
             token: AttachedToken::empty(),
             inner: Set::SingleAssignment {
                 scope: None,
                 variable: ObjectName::from(vec![Ident::new("a"), Ident::new("b"), Ident::new("c")]),
-                values: vec![Expr::Identifier(Ident {
+                values: vec![Expr::Identifier(Box::new(Ident {
                     value: "b".into(),
                     quote_style: None,
                     span: Span::empty(),
-                })],
+                }))],
             }
-        })
+        }))
     );
 
     let stmt = pg_and_generic().one_statement_parses_to(
@@ -1855,7 +1881,7 @@ fn parse_set() {
     );
     assert_eq!(
         stmt,
-        Statement::Set(SetStatement {
+        Statement::Set(Box::new(SetStatement {
             token: AttachedToken::empty(),
             inner: Set::SingleAssignment {
                 scope: None,
@@ -1866,9 +1892,11 @@ fn parse_set() {
                     Ident::new("reducer"),
                     Ident::new("parallelism")
                 ]),
-                values: vec![Expr::Value((Value::Boolean(false)).with_empty_span())],
+                values: vec![Expr::Value(Box::new(
+                    (Value::Boolean(false)).with_empty_span()
+                ))],
             }
-        })
+        }))
     );
 
     pg_and_generic().one_statement_parses_to("SET a TO b", "SET a = b");
@@ -1901,13 +1929,13 @@ fn parse_set_role() {
     let stmt = pg_and_generic().verified_stmt(query);
     assert_eq!(
         stmt,
-        Statement::Set(SetStatement {
+        Statement::Set(Box::new(SetStatement {
             token: AttachedToken::empty(),
             inner: Set::SetRole {
                 context_modifier: Some(ContextModifier::Session),
                 role_name: None,
             }
-        })
+        }))
     );
     assert_eq!(query, stmt.to_string());
 
@@ -1915,17 +1943,17 @@ fn parse_set_role() {
     let stmt = pg_and_generic().verified_stmt(query);
     assert_eq!(
         stmt,
-        Statement::Set(SetStatement {
+        Statement::Set(Box::new(SetStatement {
             token: AttachedToken::empty(),
             inner: Set::SetRole {
                 context_modifier: Some(ContextModifier::Local),
                 role_name: Some(Ident {
-                    value: "rolename".to_string(),
+                    value: "rolename".into(),
                     quote_style: Some('\"'),
                     span: Span::empty(),
                 }),
             }
-        })
+        }))
     );
     assert_eq!(query, stmt.to_string());
 
@@ -1933,17 +1961,17 @@ fn parse_set_role() {
     let stmt = pg_and_generic().verified_stmt(query);
     assert_eq!(
         stmt,
-        Statement::Set(SetStatement {
+        Statement::Set(Box::new(SetStatement {
             token: AttachedToken::empty(),
             inner: Set::SetRole {
                 context_modifier: None,
                 role_name: Some(Ident {
-                    value: "rolename".to_string(),
+                    value: "rolename".into(),
                     quote_style: Some('\''),
                     span: Span::empty(),
                 }),
             }
-        })
+        }))
     );
     assert_eq!(query, stmt.to_string());
 }
@@ -1976,7 +2004,7 @@ fn parse_deallocate() {
         stmt,
         Statement::Deallocate {
             deallocate_token: AttachedToken::empty(),
-            name: "a".into(),
+            name: Box::new("a".into()),
             prepare: false,
         }
     );
@@ -1986,7 +2014,7 @@ fn parse_deallocate() {
         stmt,
         Statement::Deallocate {
             deallocate_token: AttachedToken::empty(),
-            name: "all".into(),
+            name: Box::new("all".into()),
             prepare: false,
         }
     );
@@ -1996,7 +2024,7 @@ fn parse_deallocate() {
         stmt,
         Statement::Deallocate {
             deallocate_token: AttachedToken::empty(),
-            name: "a".into(),
+            name: Box::new("a".into()),
             prepare: true,
         }
     );
@@ -2007,7 +2035,7 @@ fn parse_deallocate() {
         stmt,
         Statement::Deallocate {
             deallocate_token: AttachedToken::empty(),
-            name: "all".into(),
+            name: Box::new("all".into()),
             prepare: true,
         }
     );
@@ -2019,8 +2047,8 @@ fn parse_execute() {
     assert_eq!(
         stmt,
         Statement::Execute {
-            execute_token: AttachedToken::empty(),
-            name: Some(ObjectName::from(vec!["a".into()])),
+            execute_token: Box::new(AttachedToken::empty()),
+            name: Some(Box::new(ObjectName::from(vec!["a".into()]))),
             parameters: vec![],
             has_parentheses: false,
             using: vec![],
@@ -2035,11 +2063,13 @@ fn parse_execute() {
     assert_eq!(
         stmt,
         Statement::Execute {
-            execute_token: AttachedToken::empty(),
-            name: Some(ObjectName::from(vec!["a".into()])),
+            execute_token: Box::new(AttachedToken::empty()),
+            name: Some(Box::new(ObjectName::from(vec!["a".into()]))),
             parameters: vec![
                 Expr::value(number("1")),
-                Expr::Value((Value::SingleQuotedString("t".to_string())).with_empty_span())
+                Expr::Value(Box::new(
+                    (Value::SingleQuotedString("t".into())).with_empty_span()
+                ))
             ],
             has_parentheses: true,
             using: vec![],
@@ -2055,18 +2085,16 @@ fn parse_execute() {
     assert_eq!(
         stmt,
         Statement::Execute {
-            execute_token: AttachedToken::empty(),
-            name: Some(ObjectName::from(vec!["a".into()])),
+            execute_token: Box::new(AttachedToken::empty()),
+            name: Some(Box::new(ObjectName::from(vec!["a".into()]))),
             parameters: vec![],
             has_parentheses: false,
             using: vec![
                 ExprWithAlias {
                     expr: Expr::Cast {
                         kind: CastKind::Cast,
-                        expr: Box::new(Expr::Value(
-                            (Value::Number("1337".parse().unwrap(), false)).with_empty_span()
-                        )),
-                        data_type: DataType::SmallInt(None),
+                        expr: Box::new(Expr::Value(Box::new(number("1337").with_empty_span()))),
+                        data_type: Box::new(DataType::SmallInt(None)),
                         format: None
                     },
                     alias: None
@@ -2074,10 +2102,8 @@ fn parse_execute() {
                 ExprWithAlias {
                     expr: Expr::Cast {
                         kind: CastKind::Cast,
-                        expr: Box::new(Expr::Value(
-                            (Value::Number("7331".parse().unwrap(), false)).with_empty_span()
-                        )),
-                        data_type: DataType::SmallInt(None),
+                        expr: Box::new(Expr::Value(Box::new(number("7331").with_empty_span()))),
+                        data_type: Box::new(DataType::SmallInt(None)),
                         format: None
                     },
                     alias: None
@@ -2102,7 +2128,7 @@ fn parse_prepare() {
             statement,
             ..
         } => {
-            assert_eq!(name, "a".into());
+            assert_eq!(*name, "a".into());
             assert!(data_types.is_empty());
 
             statement
@@ -2110,19 +2136,23 @@ fn parse_prepare() {
         _ => unreachable!(),
     };
     match sub_stmt.as_ref() {
-        Statement::Insert(Insert {
-            table: table_name,
-            columns,
-            source: Some(source),
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                table: table_name,
+                columns,
+                source: Some(source),
+                ..
+            } = &**insert
+            else {
+                unreachable!()
+            };
             assert_eq!(table_name.to_string(), "customers");
             assert!(columns.is_empty());
 
             let expected_values = [vec![
-                Expr::Identifier("a1".into()),
-                Expr::Identifier("a2".into()),
-                Expr::Identifier("a3".into()),
+                Expr::Identifier(Box::new("a1".into())),
+                Expr::Identifier(Box::new("a2".into())),
+                Expr::Identifier(Box::new("a3".into())),
             ]];
             match source.body.as_ref() {
                 SetExpr::Values(Values { rows, .. }) => {
@@ -2143,7 +2173,7 @@ fn parse_prepare() {
             statement,
             ..
         } => {
-            assert_eq!(name, "a".into());
+            assert_eq!(*name, "a".into());
             assert_eq!(data_types, vec![DataType::Int(None), DataType::Text]);
 
             statement
@@ -2171,14 +2201,18 @@ fn parse_pg_on_conflict() {
         DO UPDATE SET dname = excluded.dname",
     );
     match stmt {
-        Statement::Insert(Insert {
-            on:
-                Some(OnInsert::OnConflict(OnConflict {
-                    conflict_target: Some(ConflictTarget::Columns(cols)),
-                    action,
-                })),
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                on:
+                    Some(OnInsert::OnConflict(OnConflict {
+                        conflict_target: Some(ConflictTarget::Columns(cols)),
+                        action,
+                    })),
+                ..
+            } = Box::into_owned(insert)
+            else {
+                unreachable!()
+            };
             assert_eq!(vec![Ident::from("did")], cols);
             assert_eq!(
                 OnConflictAction::DoUpdate(DoUpdate {
@@ -2186,8 +2220,11 @@ fn parse_pg_on_conflict() {
                         target: AssignmentTarget::ColumnName(ObjectName::from(
                             vec!["dname".into()]
                         )),
-                        value: Expr::CompoundIdentifier(vec!["excluded".into(), "dname".into()])
-                    },],
+                        value: Expr::CompoundIdentifier(
+                            vec!["excluded".into(), "dname".into()].into()
+                        )
+                    },]
+                    .into(),
                     selection: None
                 }),
                 action
@@ -2207,14 +2244,18 @@ fn parse_pg_on_conflict() {
         DO UPDATE SET dname = excluded.dname, area = excluded.area",
     );
     match stmt {
-        Statement::Insert(Insert {
-            on:
-                Some(OnInsert::OnConflict(OnConflict {
-                    conflict_target: Some(ConflictTarget::Columns(cols)),
-                    action,
-                })),
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                on:
+                    Some(OnInsert::OnConflict(OnConflict {
+                        conflict_target: Some(ConflictTarget::Columns(cols)),
+                        action,
+                    })),
+                ..
+            } = Box::into_owned(insert)
+            else {
+                unreachable!()
+            };
             assert_eq!(vec![Ident::from("did"), Ident::from("area"),], cols);
             assert_eq!(
                 OnConflictAction::DoUpdate(DoUpdate {
@@ -2223,18 +2264,20 @@ fn parse_pg_on_conflict() {
                             target: AssignmentTarget::ColumnName(ObjectName::from(vec![
                                 "dname".into()
                             ])),
-                            value: Expr::CompoundIdentifier(vec![
-                                "excluded".into(),
-                                "dname".into()
-                            ])
+                            value: Expr::CompoundIdentifier(
+                                vec!["excluded".into(), "dname".into()].into()
+                            )
                         },
                         Assignment {
                             target: AssignmentTarget::ColumnName(ObjectName::from(vec![
                                 "area".into()
                             ])),
-                            value: Expr::CompoundIdentifier(vec!["excluded".into(), "area".into()])
+                            value: Expr::CompoundIdentifier(
+                                vec!["excluded".into(), "area".into()].into()
+                            )
                         },
-                    ],
+                    ]
+                    .into(),
                     selection: None
                 }),
                 action
@@ -2249,14 +2292,18 @@ fn parse_pg_on_conflict() {
     ON CONFLICT DO NOTHING",
     );
     match stmt {
-        Statement::Insert(Insert {
-            on:
-                Some(OnInsert::OnConflict(OnConflict {
-                    conflict_target: None,
-                    action,
-                })),
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                on:
+                    Some(OnInsert::OnConflict(OnConflict {
+                        conflict_target: None,
+                        action,
+                    })),
+                ..
+            } = Box::into_owned(insert)
+            else {
+                unreachable!()
+            };
             assert_eq!(OnConflictAction::DoNothing, action);
         }
         _ => unreachable!(),
@@ -2269,14 +2316,18 @@ fn parse_pg_on_conflict() {
         DO UPDATE SET dname = $1 WHERE dsize > $2",
     );
     match stmt {
-        Statement::Insert(Insert {
-            on:
-                Some(OnInsert::OnConflict(OnConflict {
-                    conflict_target: Some(ConflictTarget::Columns(cols)),
-                    action,
-                })),
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                on:
+                    Some(OnInsert::OnConflict(OnConflict {
+                        conflict_target: Some(ConflictTarget::Columns(cols)),
+                        action,
+                    })),
+                ..
+            } = Box::into_owned(insert)
+            else {
+                unreachable!()
+            };
             assert_eq!(vec![Ident::from("did")], cols);
             assert_eq!(
                 OnConflictAction::DoUpdate(DoUpdate {
@@ -2284,20 +2335,21 @@ fn parse_pg_on_conflict() {
                         target: AssignmentTarget::ColumnName(ObjectName::from(
                             vec!["dname".into()]
                         )),
-                        value: Expr::Value(
-                            (Value::Placeholder("$1".to_string())).with_empty_span()
-                        )
-                    },],
+                        value: Expr::Value(Box::new(
+                            (Value::Placeholder("$1".into())).with_empty_span()
+                        ))
+                    },]
+                    .into(),
                     selection: Some(Expr::BinaryOp {
-                        left: Box::new(Expr::Identifier(Ident {
-                            value: "dsize".to_string(),
+                        left: Box::new(Expr::Identifier(Box::new(Ident {
+                            value: "dsize".into(),
                             quote_style: None,
                             span: Span::empty(),
-                        })),
+                        }))),
                         op: BinaryOperator::Gt,
-                        right: Box::new(Expr::Value(
-                            (Value::Placeholder("$2".to_string())).with_empty_span()
-                        ))
+                        right: Box::new(Expr::Value(Box::new(
+                            (Value::Placeholder("$2".into())).with_empty_span()
+                        )))
                     })
                 }),
                 action
@@ -2313,14 +2365,18 @@ fn parse_pg_on_conflict() {
         DO UPDATE SET dname = $1 WHERE dsize > $2",
     );
     match stmt {
-        Statement::Insert(Insert {
-            on:
-                Some(OnInsert::OnConflict(OnConflict {
-                    conflict_target: Some(ConflictTarget::OnConstraint(cname)),
-                    action,
-                })),
-            ..
-        }) => {
+        Statement::Insert(insert) => {
+            let Insert {
+                on:
+                    Some(OnInsert::OnConflict(OnConflict {
+                        conflict_target: Some(ConflictTarget::OnConstraint(cname)),
+                        action,
+                    })),
+                ..
+            } = Box::into_owned(insert)
+            else {
+                unreachable!()
+            };
             assert_eq!(
                 ObjectName::from(vec![Ident::from("distributors_did_pkey")]),
                 cname
@@ -2331,20 +2387,21 @@ fn parse_pg_on_conflict() {
                         target: AssignmentTarget::ColumnName(ObjectName::from(
                             vec!["dname".into()]
                         )),
-                        value: Expr::Value(
-                            (Value::Placeholder("$1".to_string())).with_empty_span()
-                        )
-                    },],
+                        value: Expr::Value(Box::new(
+                            (Value::Placeholder("$1".into())).with_empty_span()
+                        ))
+                    },]
+                    .into(),
                     selection: Some(Expr::BinaryOp {
-                        left: Box::new(Expr::Identifier(Ident {
-                            value: "dsize".to_string(),
+                        left: Box::new(Expr::Identifier(Box::new(Ident {
+                            value: "dsize".into(),
                             quote_style: None,
                             span: Span::empty(),
-                        })),
+                        }))),
                         op: BinaryOperator::Gt,
-                        right: Box::new(Expr::Value(
-                            (Value::Placeholder("$2".to_string())).with_empty_span()
-                        ))
+                        right: Box::new(Expr::Value(Box::new(
+                            (Value::Placeholder("$2".into())).with_empty_span()
+                        )))
                     })
                 }),
                 action
@@ -2361,9 +2418,13 @@ fn parse_pg_returning() {
         "INSERT INTO distributors (did, dname) VALUES (default, 'XYZ Widgets') RETURNING did",
     );
     match stmt {
-        Statement::Insert(Insert { returning, .. }) => {
+        Statement::Insert(insert) => {
+            let Insert { returning, .. } = Box::into_owned(insert);
             assert_eq!(
-                vec![SelectItem::UnnamedExpr(Expr::Identifier("did".into()))],
+                vec![SelectItem::UnnamedExpr {
+                    expr: Expr::Identifier(Box::new("did".into())),
+                    comments: Comments::default(),
+                }],
                 returning.expect("RETURNING clause").expressions
             );
         }
@@ -2379,18 +2440,24 @@ fn parse_pg_returning() {
              RETURNING temp_lo AS lo, temp_hi AS hi, prcp",
     );
     match stmt {
-        Statement::Update(Update { returning, .. }) => {
+        Statement::Update(update) => {
+            let Update { returning, .. } = Box::into_owned(update);
             assert_eq!(
                 vec![
                     SelectItem::ExprWithAlias {
-                        expr: Expr::Identifier("temp_lo".into()),
-                        alias: "lo".into()
+                        expr: Expr::Identifier(Box::new("temp_lo".into())),
+                        alias: "lo".into(),
+                        comments: Comments::default()
                     },
                     SelectItem::ExprWithAlias {
-                        expr: Expr::Identifier("temp_hi".into()),
-                        alias: "hi".into()
+                        expr: Expr::Identifier(Box::new("temp_hi".into())),
+                        alias: "hi".into(),
+                        comments: Comments::default()
+                    },
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::Identifier(Box::new("prcp".into())),
+                        comments: Comments::default(),
                     },
-                    SelectItem::UnnamedExpr(Expr::Identifier("prcp".into())),
                 ],
                 returning.expect("RETURNING clause").expressions
             );
@@ -2400,7 +2467,8 @@ fn parse_pg_returning() {
     let stmt =
         pg_and_generic().verified_stmt("DELETE FROM tasks WHERE status = 'DONE' RETURNING *");
     match stmt {
-        Statement::Delete(Delete { returning, .. }) => {
+        Statement::Delete(delete) => {
+            let Delete { returning, .. } = Box::into_owned(delete);
             assert_eq!(
                 vec![SelectItem::Wildcard(WildcardAdditionalOptions::default())],
                 returning.expect("RETURNING clause").expressions
@@ -2450,7 +2518,7 @@ fn parse_pg_for_no_key_update_with_of_and_nowait() {
     assert_eq!(
         lock.of.unwrap(),
         ObjectName::from(vec![Ident {
-            value: "school".to_string(),
+            value: "school".into(),
             quote_style: None,
             span: Span::empty(),
         }])
@@ -2468,7 +2536,7 @@ fn parse_pg_for_key_share_with_of_and_skip_locked() {
     assert_eq!(
         lock.of.unwrap(),
         ObjectName::from(vec![Ident {
-            value: "school".to_string(),
+            value: "school".into(),
             quote_style: None,
             span: Span::empty(),
         }])
@@ -2486,7 +2554,7 @@ fn parse_pg_mixed_lock_clauses() {
     assert_eq!(
         lock.of.unwrap(),
         ObjectName::from(vec![Ident {
-            value: "school".to_string(),
+            value: "school".into(),
             quote_style: None,
             span: Span::empty(),
         }])
@@ -2496,7 +2564,7 @@ fn parse_pg_mixed_lock_clauses() {
     assert_eq!(
         lock.of.unwrap(),
         ObjectName::from(vec![Ident {
-            value: "student".to_string(),
+            value: "student".into(),
             quote_style: None,
             span: Span::empty(),
         }])
@@ -2506,7 +2574,7 @@ fn parse_pg_mixed_lock_clauses() {
     assert_eq!(
         lock.of.unwrap(),
         ObjectName::from(vec![Ident {
-            value: "grades".to_string(),
+            value: "grades".into(),
             quote_style: None,
             span: Span::empty(),
         }])
@@ -2524,9 +2592,9 @@ fn test_operator(operator: &str, dialect: &TestedDialects, expected: BinaryOpera
         "binary op should be 3 tokens, not {operator_tokens:?}"
     );
     let expected_expr = Expr::BinaryOp {
-        left: Box::new(Expr::Identifier(Ident::new("a"))),
+        left: Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
         op: expected,
-        right: Box::new(Expr::Identifier(Ident::new("b"))),
+        right: Box::new(Expr::Identifier(Box::new(Ident::new("b")))),
     };
     let str_expr_canonical = format!("a {operator} b");
     assert_eq!(expected_expr, dialect.verified_expr(&str_expr_canonical));
@@ -2569,15 +2637,15 @@ fn parse_pg_search_binary_ops() {
             select.selection,
             Some(
                 Expr::BinaryOp {
-                    left: Box::new(Expr::Identifier(Ident {
+                    left: Box::new(Expr::Identifier(Box::new(Ident {
                         value: "body".into(),
                         quote_style: None,
                         span: Span::empty(),
-                    })),
+                    }))),
                     op: expected_operator,
-                    right: Box::new(Expr::Value(
+                    right: Box::new(Expr::Value(Box::new(
                         (Value::SingleQuotedString("alpha beta".into())).with_empty_span()
-                    ))
+                    )))
                 }
                 .into()
             )
@@ -2657,10 +2725,13 @@ fn parse_pg_unary_ops() {
     for (str_op, op) in pg_unary_ops {
         let select = pg().verified_only_select(&format!("SELECT {}a", &str_op));
         assert_eq!(
-            SelectItem::UnnamedExpr(Expr::UnaryOp {
-                op: *op,
-                expr: Box::new(Expr::Identifier(Ident::new("a"))),
-            }),
+            SelectItem::UnnamedExpr {
+                expr: Expr::UnaryOp {
+                    op: *op,
+                    expr: Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
+                },
+                comments: Comments::default(),
+            },
             select.projection[0]
         );
     }
@@ -2673,10 +2744,13 @@ fn parse_pg_postfix_factorial() {
     for (str_op, op) in postfix_factorial {
         let select = pg().verified_only_select(&format!("SELECT a{}", &str_op));
         assert_eq!(
-            SelectItem::UnnamedExpr(Expr::UnaryOp {
-                op: *op,
-                expr: Box::new(Expr::Identifier(Ident::new("a"))),
-            }),
+            SelectItem::UnnamedExpr {
+                expr: Expr::UnaryOp {
+                    op: *op,
+                    expr: Box::new(Expr::Identifier(Box::new(Ident::new("a")))),
+                },
+                comments: Comments::default(),
+            },
             select.projection[0]
         );
     }
@@ -2695,11 +2769,18 @@ fn parse_pg_regex_match_ops() {
     for (str_op, op) in pg_regex_match_ops {
         let select = pg().verified_only_select(&format!("SELECT 'abc' {str_op} '^a'"));
         assert_eq!(
-            SelectItem::UnnamedExpr(Expr::BinaryOp {
-                left: Box::new(Expr::Value(single_quoted_string("abc").with_empty_span(),)),
-                op: op.clone(),
-                right: Box::new(Expr::Value(single_quoted_string("^a").with_empty_span(),)),
-            }),
+            SelectItem::UnnamedExpr {
+                expr: Expr::BinaryOp {
+                    left: Box::new(Expr::Value(Box::new(
+                        single_quoted_string("abc").with_empty_span()
+                    ),)),
+                    op: op.clone(),
+                    right: Box::new(Expr::Value(Box::new(
+                        single_quoted_string("^a").with_empty_span()
+                    ),)),
+                },
+                comments: Comments::default(),
+            },
             select.projection[0]
         );
     }
@@ -2709,18 +2790,23 @@ fn parse_pg_regex_match_ops() {
         let select =
             pg().verified_only_select(&format!("SELECT 'abc' {str_op} ANY(ARRAY['^a', 'x'])"));
         assert_eq!(
-            SelectItem::UnnamedExpr(Expr::AnyOp {
-                left: Box::new(Expr::Value(single_quoted_string("abc").with_empty_span(),)),
-                compare_op: op.clone(),
-                right: Box::new(Expr::Array(Array {
-                    elem: vec![
-                        Expr::Value(single_quoted_string("^a").with_empty_span()),
-                        Expr::Value(single_quoted_string("x").with_empty_span()),
-                    ],
-                    named: true,
-                })),
-                is_some: false,
-            }),
+            SelectItem::UnnamedExpr {
+                expr: Expr::AnyOp {
+                    left: Box::new(Expr::Value(Box::new(
+                        single_quoted_string("abc").with_empty_span()
+                    ),)),
+                    compare_op: op.clone(),
+                    right: Box::new(Expr::Array(Array {
+                        elem: vec![
+                            Expr::Value(Box::new(single_quoted_string("^a").with_empty_span())),
+                            Expr::Value(Box::new(single_quoted_string("x").with_empty_span())),
+                        ],
+                        named: true,
+                    })),
+                    is_some: false,
+                },
+                comments: Comments::default(),
+            },
             select.projection[0]
         )
     }
@@ -2739,11 +2825,18 @@ fn parse_pg_like_match_ops() {
     for (str_op, op) in pg_like_match_ops {
         let select = pg().verified_only_select(&format!("SELECT 'abc' {str_op} 'a_c%'"));
         assert_eq!(
-            SelectItem::UnnamedExpr(Expr::BinaryOp {
-                left: Box::new(Expr::Value(single_quoted_string("abc").with_empty_span(),)),
-                op: op.clone(),
-                right: Box::new(Expr::Value(single_quoted_string("a_c%").with_empty_span(),)),
-            }),
+            SelectItem::UnnamedExpr {
+                expr: Expr::BinaryOp {
+                    left: Box::new(Expr::Value(Box::new(
+                        single_quoted_string("abc").with_empty_span()
+                    ),)),
+                    op: op.clone(),
+                    right: Box::new(Expr::Value(Box::new(
+                        single_quoted_string("a_c%").with_empty_span()
+                    ),)),
+                },
+                comments: Comments::default(),
+            },
             select.projection[0]
         );
     }
@@ -2753,14 +2846,21 @@ fn parse_pg_like_match_ops() {
         let select =
             pg().verified_only_select(&format!("SELECT 'abc' {str_op} ALL(ARRAY['a_c%'])"));
         assert_eq!(
-            SelectItem::UnnamedExpr(Expr::AllOp {
-                left: Box::new(Expr::Value(single_quoted_string("abc").with_empty_span(),)),
-                compare_op: op.clone(),
-                right: Box::new(Expr::Array(Array {
-                    elem: vec![Expr::Value(single_quoted_string("a_c%").with_empty_span())],
-                    named: true,
-                })),
-            }),
+            SelectItem::UnnamedExpr {
+                expr: Expr::AllOp {
+                    left: Box::new(Expr::Value(Box::new(
+                        single_quoted_string("abc").with_empty_span()
+                    ),)),
+                    compare_op: op.clone(),
+                    right: Box::new(Expr::Array(Array {
+                        elem: vec![Expr::Value(Box::new(
+                            single_quoted_string("a_c%").with_empty_span()
+                        ))],
+                        named: true,
+                    })),
+                },
+                comments: Comments::default(),
+            },
             select.projection[0]
         )
     }
@@ -2769,14 +2869,14 @@ fn parse_pg_like_match_ops() {
 #[test]
 fn parse_array_index_expr() {
     let num: Vec<Expr> = (0..=10)
-        .map(|s| Expr::Value(number(&s.to_string()).with_empty_span()))
+        .map(|s| Expr::Value(Box::new(number(&s.to_string()).with_empty_span())))
         .collect();
 
     let sql = "SELECT foo[0] FROM foos";
     let select = pg_and_generic().verified_only_select(sql);
     assert_eq!(
         &Expr::CompoundFieldAccess {
-            root: Box::new(Expr::Identifier(Ident::new("foo"))),
+            root: Box::new(Expr::Identifier(Box::new(Ident::new("foo")))),
             access_chain: vec![AccessExpr::Subscript(Subscript::Index {
                 index: num[0].clone()
             })],
@@ -2788,7 +2888,7 @@ fn parse_array_index_expr() {
     let select = pg_and_generic().verified_only_select(sql);
     assert_eq!(
         &Expr::CompoundFieldAccess {
-            root: Box::new(Expr::Identifier(Ident::new("foo"))),
+            root: Box::new(Expr::Identifier(Box::new(Ident::new("foo")))),
             access_chain: vec![
                 AccessExpr::Subscript(Subscript::Index {
                     index: num[0].clone()
@@ -2805,24 +2905,24 @@ fn parse_array_index_expr() {
     let select = pg_and_generic().verified_only_select(sql);
     assert_eq!(
         &Expr::CompoundFieldAccess {
-            root: Box::new(Expr::Identifier(Ident::new("bar"))),
+            root: Box::new(Expr::Identifier(Box::new(Ident::new("bar")))),
             access_chain: vec![
                 AccessExpr::Subscript(Subscript::Index {
                     index: num[0].clone()
                 }),
                 AccessExpr::Subscript(Subscript::Index {
-                    index: Expr::Identifier(Ident {
-                        value: "baz".to_string(),
+                    index: Expr::Identifier(Box::new(Ident {
+                        value: "baz".into(),
                         quote_style: Some('"'),
                         span: Span::empty(),
-                    })
+                    }))
                 }),
                 AccessExpr::Subscript(Subscript::Index {
-                    index: Expr::Identifier(Ident {
-                        value: "fooz".to_string(),
+                    index: Expr::Identifier(Box::new(Ident {
+                        value: "fooz".into(),
                         quote_style: Some('"'),
                         span: Span::empty(),
-                    })
+                    }))
                 }),
             ],
         },
@@ -2842,13 +2942,13 @@ fn parse_array_index_expr() {
                     })],
                     named: true,
                 })),
-                data_type: DataType::Array(ArrayElemTypeDef::SquareBracket(
+                data_type: Box::new(DataType::Array(ArrayElemTypeDef::SquareBracket(
                     Box::new(DataType::Array(ArrayElemTypeDef::SquareBracket(
                         Box::new(DataType::Int(None)),
                         None
                     ))),
                     None
-                )),
+                ))),
                 format: None,
             }))),
             access_chain: vec![
@@ -2886,7 +2986,7 @@ fn parse_array_subscript() {
         (
             "(ARRAY[1, 2, 3, 4, 5, 6])[foo]",
             Subscript::Index {
-                index: Expr::Identifier(Ident::new("foo")),
+                index: Expr::Identifier(Box::new(Ident::new("foo"))),
             },
         ),
         (
@@ -2909,12 +3009,18 @@ fn parse_array_subscript() {
             "arr[array_length(arr) - 3:array_length(arr) - 1]",
             Subscript::Slice {
                 lower_bound: Some(Expr::BinaryOp {
-                    left: Box::new(call("array_length", [Expr::Identifier(Ident::new("arr"))])),
+                    left: Box::new(call(
+                        "array_length",
+                        [Expr::Identifier(Box::new(Ident::new("arr")))],
+                    )),
                     op: BinaryOperator::Minus,
                     right: Box::new(Expr::value(number("3"))),
                 }),
                 upper_bound: Some(Expr::BinaryOp {
-                    left: Box::new(call("array_length", [Expr::Identifier(Ident::new("arr"))])),
+                    left: Box::new(call(
+                        "array_length",
+                        [Expr::Identifier(Box::new(Ident::new("arr")))],
+                    )),
                     op: BinaryOperator::Minus,
                     right: Box::new(Expr::value(number("1"))),
                 }),
@@ -3023,21 +3129,25 @@ fn parse_update_subscript_assignment_rejects_non_index_targets() {
 fn parse_create_index() {
     let sql = "CREATE INDEX IF NOT EXISTS my_index ON my_table(col1, col2)";
     match pg().verified_stmt(sql) {
-        Statement::CreateIndex(CreateIndex {
-            name: Some(ObjectName(name)),
-            table_name: ObjectName(table_name),
-            using,
-            columns,
-            unique,
-            concurrently,
-            if_not_exists,
-            nulls_distinct: None,
-            include,
-            with,
-            predicate: None,
-            index_options,
-            alter_options,
-        }) => {
+        Statement::CreateIndex(create_index) => {
+            let CreateIndex {
+                name: Some(ObjectName(name)),
+                table_name: ObjectName(table_name),
+                using,
+                columns,
+                unique,
+                concurrently,
+                if_not_exists,
+                nulls_distinct: None,
+                include,
+                with,
+                predicate: None,
+                index_options,
+                alter_options,
+            } = Box::into_owned(create_index)
+            else {
+                unreachable!()
+            };
             assert_eq_vec(&["my_index"], &name);
             assert_eq_vec(&["my_table"], &table_name);
             assert_eq!(None, using);
@@ -3058,21 +3168,25 @@ fn parse_create_index() {
 fn parse_create_anonymous_index() {
     let sql = "CREATE INDEX ON my_table(col1, col2)";
     match pg().verified_stmt(sql) {
-        Statement::CreateIndex(CreateIndex {
-            name,
-            table_name: ObjectName(table_name),
-            using,
-            columns,
-            unique,
-            concurrently,
-            if_not_exists,
-            include,
-            nulls_distinct: None,
-            with,
-            predicate: None,
-            index_options,
-            alter_options,
-        }) => {
+        Statement::CreateIndex(create_index) => {
+            let CreateIndex {
+                name,
+                table_name: ObjectName(table_name),
+                using,
+                columns,
+                unique,
+                concurrently,
+                if_not_exists,
+                include,
+                nulls_distinct: None,
+                with,
+                predicate: None,
+                index_options,
+                alter_options,
+            } = Box::into_owned(create_index)
+            else {
+                unreachable!()
+            };
             assert_eq!(None, name);
             assert_eq_vec(&["my_table"], &table_name);
             assert_eq!(None, using);
@@ -3131,32 +3245,36 @@ fn parse_create_indices_with_operator_classes() {
 
             let expected_function_column = IndexColumn {
                 column: OrderByExpr {
-                    expr: Expr::Function(Function {
-                        name: ObjectName(vec![ObjectNamePart::Identifier(Ident {
-                            value: "concat_users_name".to_owned(),
-                            quote_style: None,
-                            span: Span::empty(),
-                        })]),
+                    expr: Expr::Function(Box::new(Function {
+                        name: ObjectName(
+                            vec![ObjectNamePart::Identifier(Ident {
+                                value: "concat_users_name".into(),
+                                quote_style: None,
+                                span: Span::empty(),
+                            })]
+                            .into(),
+                        ),
                         uses_odbc_syntax: false,
                         parameters: FunctionArguments::None,
                         args: FunctionArguments::List(FunctionArgumentList {
                             duplicate_treatment: None,
                             args: vec![
                                 FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Identifier(
-                                    Ident {
-                                        value: "first_name".to_owned(),
+                                    Box::new(Ident {
+                                        value: "first_name".into(),
                                         quote_style: None,
                                         span: Span::empty(),
-                                    },
+                                    }),
                                 ))),
                                 FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Identifier(
-                                    Ident {
-                                        value: "last_name".to_owned(),
+                                    Box::new(Ident {
+                                        value: "last_name".into(),
                                         quote_style: None,
                                         span: Span::empty(),
-                                    },
+                                    }),
                                 ))),
-                            ],
+                            ]
+                            .into(),
                             clauses: vec![],
                         }),
                         filter: None,
@@ -3164,7 +3282,7 @@ fn parse_create_indices_with_operator_classes() {
                         nth_value_order: None,
                         over: None,
                         within_group: vec![],
-                    }),
+                    })),
                     options: OrderByOptions {
                         asc: None,
                         nulls_first: None,
@@ -3175,21 +3293,25 @@ fn parse_create_indices_with_operator_classes() {
             };
 
             match pg().verified_stmt(&single_column_sql_statement) {
-                Statement::CreateIndex(CreateIndex {
-                    name: Some(ObjectName(name)),
-                    table_name: ObjectName(table_name),
-                    using: Some(using),
-                    columns,
-                    unique: false,
-                    concurrently: false,
-                    if_not_exists: false,
-                    include,
-                    nulls_distinct: None,
-                    with,
-                    predicate: None,
-                    index_options,
-                    alter_options,
-                }) => {
+                Statement::CreateIndex(create_index) => {
+                    let CreateIndex {
+                        name: Some(ObjectName(name)),
+                        table_name: ObjectName(table_name),
+                        using: Some(using),
+                        columns,
+                        unique: false,
+                        concurrently: false,
+                        if_not_exists: false,
+                        include,
+                        nulls_distinct: None,
+                        with,
+                        predicate: None,
+                        index_options,
+                        alter_options,
+                    } = Box::into_owned(create_index)
+                    else {
+                        unreachable!()
+                    };
                     assert_eq_vec(&["the_index_name"], &name);
                     assert_eq_vec(&["users"], &table_name);
                     assert_eq!(expected_index_type, using);
@@ -3203,32 +3325,36 @@ fn parse_create_indices_with_operator_classes() {
             }
 
             match pg().verified_stmt(&multi_column_sql_statement) {
-                Statement::CreateIndex(CreateIndex {
-                    name: Some(ObjectName(name)),
-                    table_name: ObjectName(table_name),
-                    using: Some(using),
-                    columns,
-                    unique: false,
-                    concurrently: false,
-                    if_not_exists: false,
-                    include,
-                    nulls_distinct: None,
-                    with,
-                    predicate: None,
-                    index_options,
-                    alter_options,
-                }) => {
+                Statement::CreateIndex(create_index) => {
+                    let CreateIndex {
+                        name: Some(ObjectName(name)),
+                        table_name: ObjectName(table_name),
+                        using: Some(using),
+                        columns,
+                        unique: false,
+                        concurrently: false,
+                        if_not_exists: false,
+                        include,
+                        nulls_distinct: None,
+                        with,
+                        predicate: None,
+                        index_options,
+                        alter_options,
+                    } = Box::into_owned(create_index)
+                    else {
+                        unreachable!()
+                    };
                     assert_eq_vec(&["the_index_name"], &name);
                     assert_eq_vec(&["users"], &table_name);
                     assert_eq!(expected_index_type, using);
                     assert_eq!(
                         IndexColumn {
                             column: OrderByExpr {
-                                expr: Expr::Identifier(Ident {
-                                    value: "column_name".to_owned(),
+                                expr: Expr::Identifier(Box::new(Ident {
+                                    value: "column_name".into(),
                                     quote_style: None,
                                     span: Span::empty()
-                                }),
+                                })),
                                 options: OrderByOptions {
                                     asc: None,
                                     nulls_first: None,
@@ -3255,21 +3381,25 @@ fn parse_create_indices_with_operator_classes() {
 fn parse_create_bloom() {
     let sql = "CREATE INDEX bloomidx ON tbloom USING BLOOM (i1, i2, i3) WITH (length = 80, col1 = 2, col2 = 2, col3 = 4)";
     match pg().verified_stmt(sql) {
-        Statement::CreateIndex(CreateIndex {
-            name: Some(ObjectName(name)),
-            table_name: ObjectName(table_name),
-            using: Some(using),
-            columns,
-            unique: false,
-            concurrently: false,
-            if_not_exists: false,
-            include,
-            nulls_distinct: None,
-            with,
-            predicate: None,
-            index_options,
-            alter_options,
-        }) => {
+        Statement::CreateIndex(create_index) => {
+            let CreateIndex {
+                name: Some(ObjectName(name)),
+                table_name: ObjectName(table_name),
+                using: Some(using),
+                columns,
+                unique: false,
+                concurrently: false,
+                if_not_exists: false,
+                include,
+                nulls_distinct: None,
+                with,
+                predicate: None,
+                index_options,
+                alter_options,
+            } = Box::into_owned(create_index)
+            else {
+                unreachable!()
+            };
             assert_eq_vec(&["bloomidx"], &name);
             assert_eq_vec(&["tbloom"], &table_name);
             assert_eq!(IndexType::Bloom, using);
@@ -3278,24 +3408,24 @@ fn parse_create_bloom() {
             assert_eq!(
                 vec![
                     Expr::BinaryOp {
-                        left: Box::new(Expr::Identifier(Ident::new("length"))),
+                        left: Box::new(Expr::Identifier(Box::new(Ident::new("length")))),
                         op: BinaryOperator::Eq,
-                        right: Box::new(Expr::Value(number("80").into())),
+                        right: Box::new(Expr::Value(Box::new(number("80").into()))),
                     },
                     Expr::BinaryOp {
-                        left: Box::new(Expr::Identifier(Ident::new("col1"))),
+                        left: Box::new(Expr::Identifier(Box::new(Ident::new("col1")))),
                         op: BinaryOperator::Eq,
-                        right: Box::new(Expr::Value(number("2").into())),
+                        right: Box::new(Expr::Value(Box::new(number("2").into()))),
                     },
                     Expr::BinaryOp {
-                        left: Box::new(Expr::Identifier(Ident::new("col2"))),
+                        left: Box::new(Expr::Identifier(Box::new(Ident::new("col2")))),
                         op: BinaryOperator::Eq,
-                        right: Box::new(Expr::Value(number("2").into())),
+                        right: Box::new(Expr::Value(Box::new(number("2").into()))),
                     },
                     Expr::BinaryOp {
-                        left: Box::new(Expr::Identifier(Ident::new("col3"))),
+                        left: Box::new(Expr::Identifier(Box::new(Ident::new("col3")))),
                         op: BinaryOperator::Eq,
-                        right: Box::new(Expr::Value(number("4").into())),
+                        right: Box::new(Expr::Value(Box::new(number("4").into()))),
                     },
                 ],
                 with
@@ -3311,21 +3441,25 @@ fn parse_create_bloom() {
 fn parse_create_brin() {
     let sql = "CREATE INDEX brin_sensor_data_recorded_at ON sensor_data USING BRIN (recorded_at)";
     match pg().verified_stmt(sql) {
-        Statement::CreateIndex(CreateIndex {
-            name: Some(ObjectName(name)),
-            table_name: ObjectName(table_name),
-            using: Some(using),
-            columns,
-            unique: false,
-            concurrently: false,
-            if_not_exists: false,
-            include,
-            nulls_distinct: None,
-            with,
-            predicate: None,
-            index_options,
-            alter_options,
-        }) => {
+        Statement::CreateIndex(create_index) => {
+            let CreateIndex {
+                name: Some(ObjectName(name)),
+                table_name: ObjectName(table_name),
+                using: Some(using),
+                columns,
+                unique: false,
+                concurrently: false,
+                if_not_exists: false,
+                include,
+                nulls_distinct: None,
+                with,
+                predicate: None,
+                index_options,
+                alter_options,
+            } = Box::into_owned(create_index)
+            else {
+                unreachable!()
+            };
             assert_eq_vec(&["brin_sensor_data_recorded_at"], &name);
             assert_eq_vec(&["sensor_data"], &table_name);
             assert_eq!(IndexType::BRIN, using);
@@ -3344,10 +3478,14 @@ fn parse_create_table_with_inherits() {
     let single_inheritance_sql =
         "CREATE TABLE child_table (child_column INT) INHERITS (public.parent_table)";
     match pg().verified_stmt(single_inheritance_sql) {
-        Statement::CreateTable(CreateTable {
-            inherits: Some(inherits),
-            ..
-        }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable {
+                inherits: Some(inherits),
+                ..
+            } = Box::into_owned(create_table)
+            else {
+                unreachable!()
+            };
             assert_eq_vec(&["public", "parent_table"], &inherits[0].0);
         }
         _ => unreachable!(),
@@ -3355,10 +3493,14 @@ fn parse_create_table_with_inherits() {
 
     let double_inheritance_sql = "CREATE TABLE child_table (child_column INT) INHERITS (public.parent_table, pg_catalog.pg_settings)";
     match pg().verified_stmt(double_inheritance_sql) {
-        Statement::CreateTable(CreateTable {
-            inherits: Some(inherits),
-            ..
-        }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable {
+                inherits: Some(inherits),
+                ..
+            } = Box::into_owned(create_table)
+            else {
+                unreachable!()
+            };
             assert_eq_vec(&["public", "parent_table"], &inherits[0].0);
             assert_eq_vec(&["pg_catalog", "pg_settings"], &inherits[1].0);
         }
@@ -3378,21 +3520,25 @@ fn parse_create_table_with_empty_inherits_fails() {
 fn parse_create_index_concurrently() {
     let sql = "CREATE INDEX CONCURRENTLY IF NOT EXISTS my_index ON my_table(col1, col2)";
     match pg().verified_stmt(sql) {
-        Statement::CreateIndex(CreateIndex {
-            name: Some(ObjectName(name)),
-            table_name: ObjectName(table_name),
-            using,
-            columns,
-            unique,
-            concurrently,
-            if_not_exists,
-            include,
-            nulls_distinct: None,
-            with,
-            predicate: None,
-            index_options,
-            alter_options,
-        }) => {
+        Statement::CreateIndex(create_index) => {
+            let CreateIndex {
+                name: Some(ObjectName(name)),
+                table_name: ObjectName(table_name),
+                using,
+                columns,
+                unique,
+                concurrently,
+                if_not_exists,
+                include,
+                nulls_distinct: None,
+                with,
+                predicate: None,
+                index_options,
+                alter_options,
+            } = Box::into_owned(create_index)
+            else {
+                unreachable!()
+            };
             assert_eq_vec(&["my_index"], &name);
             assert_eq_vec(&["my_table"], &table_name);
             assert_eq!(None, using);
@@ -3413,21 +3559,25 @@ fn parse_create_index_concurrently() {
 fn parse_create_index_with_predicate() {
     let sql = "CREATE INDEX IF NOT EXISTS my_index ON my_table(col1, col2) WHERE col3 IS NULL";
     match pg().verified_stmt(sql) {
-        Statement::CreateIndex(CreateIndex {
-            name: Some(ObjectName(name)),
-            table_name: ObjectName(table_name),
-            using,
-            columns,
-            unique,
-            concurrently,
-            if_not_exists,
-            include,
-            nulls_distinct: None,
-            with,
-            predicate: Some(_),
-            index_options,
-            alter_options,
-        }) => {
+        Statement::CreateIndex(create_index) => {
+            let CreateIndex {
+                name: Some(ObjectName(name)),
+                table_name: ObjectName(table_name),
+                using,
+                columns,
+                unique,
+                concurrently,
+                if_not_exists,
+                include,
+                nulls_distinct: None,
+                with,
+                predicate: Some(_),
+                index_options,
+                alter_options,
+            } = Box::into_owned(create_index)
+            else {
+                unreachable!()
+            };
             assert_eq_vec(&["my_index"], &name);
             assert_eq_vec(&["my_table"], &table_name);
             assert_eq!(None, using);
@@ -3448,21 +3598,25 @@ fn parse_create_index_with_predicate() {
 fn parse_create_index_with_include() {
     let sql = "CREATE INDEX IF NOT EXISTS my_index ON my_table(col1, col2) INCLUDE (col3, col4)";
     match pg().verified_stmt(sql) {
-        Statement::CreateIndex(CreateIndex {
-            name: Some(ObjectName(name)),
-            table_name: ObjectName(table_name),
-            using,
-            columns,
-            unique,
-            concurrently,
-            if_not_exists,
-            include,
-            nulls_distinct: None,
-            with,
-            predicate: None,
-            index_options,
-            alter_options,
-        }) => {
+        Statement::CreateIndex(create_index) => {
+            let CreateIndex {
+                name: Some(ObjectName(name)),
+                table_name: ObjectName(table_name),
+                using,
+                columns,
+                unique,
+                concurrently,
+                if_not_exists,
+                include,
+                nulls_distinct: None,
+                with,
+                predicate: None,
+                index_options,
+                alter_options,
+            } = Box::into_owned(create_index)
+            else {
+                unreachable!()
+            };
             assert_eq_vec(&["my_index"], &name);
             assert_eq_vec(&["my_table"], &table_name);
             assert_eq!(None, using);
@@ -3483,21 +3637,25 @@ fn parse_create_index_with_include() {
 fn parse_create_index_with_nulls_distinct() {
     let sql = "CREATE INDEX IF NOT EXISTS my_index ON my_table(col1, col2) NULLS NOT DISTINCT";
     match pg().verified_stmt(sql) {
-        Statement::CreateIndex(CreateIndex {
-            name: Some(ObjectName(name)),
-            table_name: ObjectName(table_name),
-            using,
-            columns,
-            unique,
-            concurrently,
-            if_not_exists,
-            include,
-            nulls_distinct: Some(nulls_distinct),
-            with,
-            predicate: None,
-            index_options,
-            alter_options,
-        }) => {
+        Statement::CreateIndex(create_index) => {
+            let CreateIndex {
+                name: Some(ObjectName(name)),
+                table_name: ObjectName(table_name),
+                using,
+                columns,
+                unique,
+                concurrently,
+                if_not_exists,
+                include,
+                nulls_distinct: Some(nulls_distinct),
+                with,
+                predicate: None,
+                index_options,
+                alter_options,
+            } = Box::into_owned(create_index)
+            else {
+                unreachable!()
+            };
             assert_eq_vec(&["my_index"], &name);
             assert_eq_vec(&["my_table"], &table_name);
             assert_eq!(None, using);
@@ -3516,21 +3674,25 @@ fn parse_create_index_with_nulls_distinct() {
 
     let sql = "CREATE INDEX IF NOT EXISTS my_index ON my_table(col1, col2) NULLS DISTINCT";
     match pg().verified_stmt(sql) {
-        Statement::CreateIndex(CreateIndex {
-            name: Some(ObjectName(name)),
-            table_name: ObjectName(table_name),
-            using,
-            columns,
-            unique,
-            concurrently,
-            if_not_exists,
-            include,
-            nulls_distinct: Some(nulls_distinct),
-            with,
-            predicate: None,
-            index_options,
-            alter_options,
-        }) => {
+        Statement::CreateIndex(create_index) => {
+            let CreateIndex {
+                name: Some(ObjectName(name)),
+                table_name: ObjectName(table_name),
+                using,
+                columns,
+                unique,
+                concurrently,
+                if_not_exists,
+                include,
+                nulls_distinct: Some(nulls_distinct),
+                with,
+                predicate: None,
+                index_options,
+                alter_options,
+            } = Box::into_owned(create_index)
+            else {
+                unreachable!()
+            };
             assert_eq_vec(&["my_index"], &name);
             assert_eq_vec(&["my_table"], &table_name);
             assert_eq!(None, using);
@@ -3554,7 +3716,7 @@ fn parse_array_subquery_expr() {
     let select =
         pg().verified_only_select_with_canonical(sql, "SELECT array(SELECT 1 UNION SELECT 2)");
     assert_eq!(
-        &Expr::Function(Function {
+        &Expr::Function(Box::new(Function {
             name: ObjectName::from(vec![Ident::new("array")]),
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
@@ -3568,13 +3730,15 @@ fn parse_array_subquery_expr() {
                         distinct: None,
                         top: None,
                         top_before_distinct: false,
-                        projection: vec![SelectItem::UnnamedExpr(Expr::Value(
-                            (number("1")).with_empty_span()
-                        ))],
+                        projection: vec![SelectItem::UnnamedExpr {
+                            expr: Expr::Value(Box::new((number("1")).with_empty_span())),
+                            comments: Comments::default(),
+                        }]
+                        .into(),
                         into: None,
-                        from: vec![],
+                        from: AstVec::new(),
                         selection: None,
-                        group_by: GroupByExpr::Expressions(vec![], vec![]),
+                        group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
                         having: None,
                         qualify: None,
                         named_window: vec![],
@@ -3586,13 +3750,15 @@ fn parse_array_subquery_expr() {
                         distinct: None,
                         top: None,
                         top_before_distinct: false,
-                        projection: vec![SelectItem::UnnamedExpr(Expr::Value(
-                            (number("2")).with_empty_span()
-                        ))],
+                        projection: vec![SelectItem::UnnamedExpr {
+                            expr: Expr::Value(Box::new((number("2")).with_empty_span())),
+                            comments: Comments::default(),
+                        }]
+                        .into(),
                         into: None,
-                        from: vec![],
+                        from: AstVec::new(),
                         selection: None,
-                        group_by: GroupByExpr::Expressions(vec![], vec![]),
+                        group_by: GroupByExpr::Expressions(AstVec::new(), vec![]),
                         having: None,
                         qualify: None,
                         named_window: vec![],
@@ -3603,15 +3769,16 @@ fn parse_array_subquery_expr() {
                 order_by: None,
                 limit_clause: None,
                 fetch: None,
-                locks: vec![],
+                locks: AstVec::new(),
                 for_clause: None,
+                comments: Comments::default(),
             })),
             filter: None,
             null_treatment: None,
             nth_value_order: None,
             over: None,
             within_group: vec![]
-        }),
+        })),
         expr_from_projection(only(&select.projection)),
     );
 }
@@ -3621,19 +3788,19 @@ fn test_transaction_statement() {
     let statement = pg().verified_stmt("SET TRANSACTION SNAPSHOT '000003A1-1'");
     assert_eq!(
         statement,
-        Statement::Set(SetStatement {
+        Statement::Set(Box::new(SetStatement {
             token: AttachedToken::empty(),
             inner: Set::SetTransaction {
                 modes: vec![],
-                snapshot: Some(Value::SingleQuotedString(String::from("000003A1-1"))),
+                snapshot: Some(Value::SingleQuotedString("000003A1-1".into())),
                 session: false
             }
-        })
+        }))
     );
     let statement = pg().verified_stmt("SET SESSION CHARACTERISTICS AS TRANSACTION READ ONLY, READ WRITE, ISOLATION LEVEL SERIALIZABLE");
     assert_eq!(
         statement,
-        Statement::Set(SetStatement {
+        Statement::Set(Box::new(SetStatement {
             token: AttachedToken::empty(),
             inner: Set::SetTransaction {
                 modes: vec![
@@ -3644,7 +3811,7 @@ fn test_transaction_statement() {
                 snapshot: None,
                 session: true
             }
-        })
+        }))
     );
 }
 
@@ -3653,45 +3820,54 @@ fn test_json() {
     let sql = "SELECT params ->> 'name' FROM events";
     let select = pg().verified_only_select(sql);
     assert_eq!(
-        SelectItem::UnnamedExpr(Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::new("params"))),
-            op: BinaryOperator::LongArrow,
-            right: Box::new(Expr::Value(
-                (Value::SingleQuotedString("name".to_string())).with_empty_span()
-            )),
-        }),
+        SelectItem::UnnamedExpr {
+            expr: Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("params")))),
+                op: BinaryOperator::LongArrow,
+                right: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("name".into())).with_empty_span()
+                ))),
+            },
+            comments: Comments::default(),
+        },
         select.projection[0]
     );
 
     let sql = "SELECT params -> 'name' FROM events";
     let select = pg().verified_only_select(sql);
     assert_eq!(
-        SelectItem::UnnamedExpr(Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::new("params"))),
-            op: BinaryOperator::Arrow,
-            right: Box::new(Expr::Value(
-                (Value::SingleQuotedString("name".to_string())).with_empty_span()
-            )),
-        }),
+        SelectItem::UnnamedExpr {
+            expr: Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("params")))),
+                op: BinaryOperator::Arrow,
+                right: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("name".into())).with_empty_span()
+                ))),
+            },
+            comments: Comments::default(),
+        },
         select.projection[0]
     );
 
     let sql = "SELECT info -> 'items' ->> 'product' FROM orders";
     let select = pg().verified_only_select(sql);
     assert_eq!(
-        SelectItem::UnnamedExpr(Expr::BinaryOp {
-            left: Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("info"))),
-                op: BinaryOperator::Arrow,
-                right: Box::new(Expr::Value(
-                    (Value::SingleQuotedString("items".to_string())).with_empty_span()
-                ))
-            }),
-            op: BinaryOperator::LongArrow,
-            right: Box::new(Expr::Value(
-                (Value::SingleQuotedString("product".to_string())).with_empty_span()
-            )),
-        }),
+        SelectItem::UnnamedExpr {
+            expr: Expr::BinaryOp {
+                left: Box::new(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Box::new(Ident::new("info")))),
+                    op: BinaryOperator::Arrow,
+                    right: Box::new(Expr::Value(Box::new(
+                        (Value::SingleQuotedString("items".into())).with_empty_span()
+                    )))
+                }),
+                op: BinaryOperator::LongArrow,
+                right: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("product".into())).with_empty_span()
+                ))),
+            },
+            comments: Comments::default(),
+        },
         select.projection[0]
     );
 
@@ -3699,11 +3875,14 @@ fn test_json() {
     let sql = "SELECT obj -> 42";
     let select = pg().verified_only_select(sql);
     assert_eq!(
-        SelectItem::UnnamedExpr(Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::new("obj"))),
-            op: BinaryOperator::Arrow,
-            right: Box::new(Expr::value(number("42"))),
-        }),
+        SelectItem::UnnamedExpr {
+            expr: Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("obj")))),
+                op: BinaryOperator::Arrow,
+                right: Box::new(Expr::value(number("42"))),
+            },
+            comments: Comments::default(),
+        },
         select.projection[0]
     );
 
@@ -3711,11 +3890,14 @@ fn test_json() {
     let sql = "SELECT obj -> key";
     let select = pg().verified_only_select(sql);
     assert_eq!(
-        SelectItem::UnnamedExpr(Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::new("obj"))),
-            op: BinaryOperator::Arrow,
-            right: Box::new(Expr::Identifier(Ident::new("key"))),
-        }),
+        SelectItem::UnnamedExpr {
+            expr: Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("obj")))),
+                op: BinaryOperator::Arrow,
+                right: Box::new(Expr::Identifier(Box::new(Ident::new("key")))),
+            },
+            comments: Comments::default(),
+        },
         select.projection[0]
     );
 
@@ -3723,41 +3905,50 @@ fn test_json() {
     let sql = "SELECT obj -> 3 * 2";
     let select = pg().verified_only_select(sql);
     assert_eq!(
-        SelectItem::UnnamedExpr(Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::new("obj"))),
-            op: BinaryOperator::Arrow,
-            right: Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::value(number("3"))),
-                op: BinaryOperator::Multiply,
-                right: Box::new(Expr::value(number("2"))),
-            }),
-        }),
+        SelectItem::UnnamedExpr {
+            expr: Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("obj")))),
+                op: BinaryOperator::Arrow,
+                right: Box::new(Expr::BinaryOp {
+                    left: Box::new(Expr::value(number("3"))),
+                    op: BinaryOperator::Multiply,
+                    right: Box::new(Expr::value(number("2"))),
+                }),
+            },
+            comments: Comments::default(),
+        },
         select.projection[0]
     );
 
     let sql = "SELECT info #> '{a,b,c}' FROM orders";
     let select = pg().verified_only_select(sql);
     assert_eq!(
-        SelectItem::UnnamedExpr(Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::new("info"))),
-            op: BinaryOperator::HashArrow,
-            right: Box::new(Expr::Value(
-                (Value::SingleQuotedString("{a,b,c}".to_string())).with_empty_span()
-            )),
-        }),
+        SelectItem::UnnamedExpr {
+            expr: Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("info")))),
+                op: BinaryOperator::HashArrow,
+                right: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("{a,b,c}".into())).with_empty_span()
+                ))),
+            },
+            comments: Comments::default(),
+        },
         select.projection[0]
     );
 
     let sql = "SELECT info #>> '{a,b,c}' FROM orders";
     let select = pg().verified_only_select(sql);
-    assert_eq!(
-        SelectItem::UnnamedExpr(Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::new("info"))),
-            op: BinaryOperator::HashLongArrow,
-            right: Box::new(Expr::Value(
-                (Value::SingleQuotedString("{a,b,c}".to_string())).with_empty_span()
-            )),
-        }),
+    assert_eq!(
+        SelectItem::UnnamedExpr {
+            expr: Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("info")))),
+                op: BinaryOperator::HashLongArrow,
+                right: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("{a,b,c}".into())).with_empty_span()
+                ))),
+            },
+            comments: Comments::default(),
+        },
         select.projection[0]
     );
 
@@ -3765,11 +3956,11 @@ fn test_json() {
     let select = pg().verified_only_select(sql);
     assert_eq!(
         Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::new("info"))),
+            left: Box::new(Expr::Identifier(Box::new(Ident::new("info")))),
             op: BinaryOperator::AtArrow,
-            right: Box::new(Expr::Value(
-                (Value::SingleQuotedString("{\"a\": 1}".to_string())).with_empty_span()
-            )),
+            right: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("{\"a\": 1}".into())).with_empty_span()
+            ))),
         },
         Box::into_owned(select.selection.unwrap()),
     );
@@ -3778,11 +3969,11 @@ fn test_json() {
     let select = pg().verified_only_select(sql);
     assert_eq!(
         Expr::BinaryOp {
-            left: Box::new(Expr::Value(
-                (Value::SingleQuotedString("{\"a\": 1}".to_string())).with_empty_span()
-            )),
+            left: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("{\"a\": 1}".into())).with_empty_span()
+            ))),
             op: BinaryOperator::ArrowAt,
-            right: Box::new(Expr::Identifier(Ident::new("info"))),
+            right: Box::new(Expr::Identifier(Box::new(Ident::new("info")))),
         },
         Box::into_owned(select.selection.unwrap()),
     );
@@ -3790,17 +3981,24 @@ fn test_json() {
     let sql = "SELECT info #- ARRAY['a', 'b'] FROM orders";
     let select = pg().verified_only_select(sql);
     assert_eq!(
-        SelectItem::UnnamedExpr(Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::from("info"))),
-            op: BinaryOperator::HashMinus,
-            right: Box::new(Expr::Array(Array {
-                elem: vec![
-                    Expr::Value((Value::SingleQuotedString("a".to_string())).with_empty_span()),
-                    Expr::Value((Value::SingleQuotedString("b".to_string())).with_empty_span()),
-                ],
-                named: true,
-            })),
-        }),
+        SelectItem::UnnamedExpr {
+            expr: Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Box::new(Ident::from("info")))),
+                op: BinaryOperator::HashMinus,
+                right: Box::new(Expr::Array(Array {
+                    elem: vec![
+                        Expr::Value(Box::new(
+                            (Value::SingleQuotedString("a".into())).with_empty_span()
+                        )),
+                        Expr::Value(Box::new(
+                            (Value::SingleQuotedString("b".into())).with_empty_span()
+                        )),
+                    ],
+                    named: true,
+                })),
+            },
+            comments: Comments::default(),
+        },
         select.projection[0],
     );
 
@@ -3808,11 +4006,11 @@ fn test_json() {
     let select = pg().verified_only_select(sql);
     assert_eq!(
         Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::from("info"))),
+            left: Box::new(Expr::Identifier(Box::new(Ident::from("info")))),
             op: BinaryOperator::AtQuestion,
-            right: Box::new(Expr::Value(
-                (Value::SingleQuotedString("$.a".to_string())).with_empty_span()
-            ),),
+            right: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("$.a".into())).with_empty_span()
+            )),),
         },
         Box::into_owned(select.selection.unwrap()),
     );
@@ -3821,11 +4019,11 @@ fn test_json() {
     let select = pg().verified_only_select(sql);
     assert_eq!(
         Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::from("info"))),
+            left: Box::new(Expr::Identifier(Box::new(Ident::from("info")))),
             op: BinaryOperator::AtAt,
-            right: Box::new(Expr::Value(
-                (Value::SingleQuotedString("$.a".to_string())).with_empty_span()
-            ),),
+            right: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("$.a".into())).with_empty_span()
+            )),),
         },
         Box::into_owned(select.selection.unwrap()),
     );
@@ -3834,11 +4032,11 @@ fn test_json() {
     let select = pg().verified_only_select(sql);
     assert_eq!(
         Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::new("info"))),
+            left: Box::new(Expr::Identifier(Box::new(Ident::new("info")))),
             op: BinaryOperator::Question,
-            right: Box::new(Expr::Value(
-                (Value::SingleQuotedString("b".to_string())).with_empty_span()
-            )),
+            right: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("b".into())).with_empty_span()
+            ))),
         },
         Box::into_owned(select.selection.unwrap()),
     );
@@ -3847,12 +4045,16 @@ fn test_json() {
     let select = pg().verified_only_select(sql);
     assert_eq!(
         Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::new("info"))),
+            left: Box::new(Expr::Identifier(Box::new(Ident::new("info")))),
             op: BinaryOperator::QuestionAnd,
             right: Box::new(Expr::Array(Array {
                 elem: vec![
-                    Expr::Value((Value::SingleQuotedString("b".to_string())).with_empty_span()),
-                    Expr::Value((Value::SingleQuotedString("c".to_string())).with_empty_span())
+                    Expr::Value(Box::new(
+                        (Value::SingleQuotedString("b".into())).with_empty_span()
+                    )),
+                    Expr::Value(Box::new(
+                        (Value::SingleQuotedString("c".into())).with_empty_span()
+                    ))
                 ],
                 named: true
             }))
@@ -3864,12 +4066,16 @@ fn test_json() {
     let select = pg().verified_only_select(sql);
     assert_eq!(
         Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::new("info"))),
+            left: Box::new(Expr::Identifier(Box::new(Ident::new("info")))),
             op: BinaryOperator::QuestionPipe,
             right: Box::new(Expr::Array(Array {
                 elem: vec![
-                    Expr::Value((Value::SingleQuotedString("b".to_string())).with_empty_span()),
-                    Expr::Value((Value::SingleQuotedString("c".to_string())).with_empty_span())
+                    Expr::Value(Box::new(
+                        (Value::SingleQuotedString("b".into())).with_empty_span()
+                    )),
+                    Expr::Value(Box::new(
+                        (Value::SingleQuotedString("c".into())).with_empty_span()
+                    ))
                 ],
                 named: true
             }))
@@ -3880,14 +4086,17 @@ fn test_json() {
 
 #[test]
 fn json_object_colon_syntax() {
-    match pg().expr_parses_to(
+    let Expr::Function(function) = pg().expr_parses_to(
         "JSON_OBJECT('name' : 'value')",
         "json_object('name' : 'value')",
-    ) {
-        Expr::Function(Function {
+    ) else {
+        unreachable!()
+    };
+    match &*function {
+        Function {
             args: FunctionArguments::List(FunctionArgumentList { args, .. }),
             ..
-        }) => {
+        } => {
             assert!(
                 matches!(
                     &args[..],
@@ -3907,11 +4116,17 @@ fn json_object_colon_syntax() {
 
 #[test]
 fn json_object_value_syntax() {
-    match pg().expr_parses_to("JSON_OBJECT('name' VALUE 'value')", "json_object('name' VALUE 'value')") {
-        Expr::Function(Function {
+    let Expr::Function(function) = pg().expr_parses_to(
+        "JSON_OBJECT('name' VALUE 'value')",
+        "json_object('name' VALUE 'value')",
+    ) else {
+        unreachable!()
+    };
+    match &*function {
+        Function {
             args: FunctionArguments::List(FunctionArgumentList { args, .. }),
             ..
-        }) => {
+        } => {
             assert!(
                 matches!(
                     &args[..],
@@ -3936,16 +4151,16 @@ fn parse_json_object() {
     assert!(
         matches!(
             expr.clone(),
-            Expr::Function(Function {
+            Expr::Function(function) if matches!(Function::clone(&function), Function {
                 name: ObjectName(parts),
                 args: FunctionArguments::List(FunctionArgumentList { args, clauses, .. }),
                 ..
-            }) if parts == vec![ObjectNamePart::Identifier(Ident::new("json_object"))]
+            } if parts == vec![ObjectNamePart::Identifier(Ident::new("json_object"))]
                 && matches!(
                     &args[..],
                     &[FunctionArg::ExprNamed { operator: FunctionArgOperator::Value, .. }]
                 )
-                && clauses == vec![FunctionArgumentClause::JsonNullClause(JsonNullClause::NullOnNull)]
+                && clauses == vec![FunctionArgumentClause::JsonNullClause(JsonNullClause::NullOnNull)])
         ),
         "Failed to parse JSON_OBJECT with expected structure, got: {expr:?}"
     );
@@ -3955,16 +4170,16 @@ fn parse_json_object() {
     assert!(
         matches!(
             expr.clone(),
-            Expr::Function(Function {
+            Expr::Function(function) if matches!(Function::clone(&function), Function {
                 name: ObjectName(parts),
                 args: FunctionArguments::List(FunctionArgumentList { args, clauses, .. }),
                 ..
-            }) if parts == vec![ObjectNamePart::Identifier(Ident::new("json_object"))]
+            } if parts == vec![ObjectNamePart::Identifier(Ident::new("json_object"))]
                 && matches!(
                     &args[..],
                     &[FunctionArg::ExprNamed { operator: FunctionArgOperator::Value, .. }]
                 )
-                && clauses == vec![FunctionArgumentClause::JsonReturningClause(JsonReturningClause { data_type: DataType::JSONB })]
+                && clauses == vec![FunctionArgumentClause::JsonReturningClause(JsonReturningClause { data_type: DataType::JSONB })])
         ),
         "Failed to parse JSON_OBJECT with expected structure, got: {expr:?}"
     );
@@ -3974,13 +4189,13 @@ fn parse_json_object() {
     assert!(
         matches!(
             expr.clone(),
-            Expr::Function(Function {
+            Expr::Function(function) if matches!(Function::clone(&function), Function {
                 name: ObjectName(parts),
                 args: FunctionArguments::List(FunctionArgumentList { args, clauses, .. }),
                 ..
-            }) if parts == vec![ObjectNamePart::Identifier(Ident::new("json_object"))]
+            } if parts == vec![ObjectNamePart::Identifier(Ident::new("json_object"))]
                 && args.is_empty()
-                && clauses == vec![FunctionArgumentClause::JsonReturningClause(JsonReturningClause { data_type: DataType::JSONB })]
+                && clauses == vec![FunctionArgumentClause::JsonReturningClause(JsonReturningClause { data_type: DataType::JSONB })])
         ),
         "Failed to parse JSON_OBJECT with expected structure, got: {expr:?}"
     );
@@ -4019,26 +4234,28 @@ fn test_composite_value() {
     };
     assert_eq!(
         root.as_ref(),
-        &Expr::Nested(Box::new(Expr::CompoundIdentifier(vec![
-            Ident::new("on_hand"),
-            Ident::new("item")
-        ])))
+        &Expr::Nested(Box::new(Expr::CompoundIdentifier(
+            vec![Ident::new("on_hand"), Ident::new("item")].into()
+        )))
     );
     assert_eq!(
         access_chain.as_slice(),
-        &[AccessExpr::Dot(Expr::Identifier(Ident::new("name")))]
+        &[AccessExpr::Dot(Expr::Identifier(Box::new(Ident::new(
+            "name"
+        ))))]
     );
 
     assert_eq!(
         select.selection.as_ref().unwrap(),
         &Expr::BinaryOp {
             left: Box::new(Expr::CompoundFieldAccess {
-                root: Expr::Nested(Box::new(Expr::CompoundIdentifier(vec![
-                    Ident::new("on_hand"),
-                    Ident::new("item")
-                ])))
+                root: Expr::Nested(Box::new(Expr::CompoundIdentifier(
+                    vec![Ident::new("on_hand"), Ident::new("item")].into()
+                )))
                 .into(),
-                access_chain: vec![AccessExpr::Dot(Expr::Identifier(Ident::new("price")))]
+                access_chain: vec![AccessExpr::Dot(Expr::Identifier(Box::new(Ident::new(
+                    "price"
+                ))))]
             }),
             op: BinaryOperator::Gt,
             right: Box::new(Expr::value(number("9")))
@@ -4049,7 +4266,7 @@ fn test_composite_value() {
     let select = pg().verified_only_select(sql);
     assert_eq!(
         &Expr::CompoundFieldAccess {
-            root: Box::new(Expr::Nested(Box::new(Expr::Function(Function {
+            root: Box::new(Expr::Nested(Box::new(Expr::Function(Box::new(Function {
                 name: ObjectName::from(vec![
                     Ident::new("information_schema"),
                     Ident::new("_pg_expandarray")
@@ -4061,16 +4278,17 @@ fn test_composite_value() {
                     args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Array(
                         Array {
                             elem: vec![
-                                Expr::Value(
-                                    (Value::SingleQuotedString("i".to_string())).with_empty_span()
-                                ),
-                                Expr::Value(
-                                    (Value::SingleQuotedString("i".to_string())).with_empty_span()
-                                ),
+                                Expr::Value(Box::new(
+                                    (Value::SingleQuotedString("i".into())).with_empty_span()
+                                )),
+                                Expr::Value(Box::new(
+                                    (Value::SingleQuotedString("i".into())).with_empty_span()
+                                )),
                             ],
                             named: true
                         }
-                    )))],
+                    )))]
+                    .into(),
                     clauses: vec![],
                 }),
                 null_treatment: None,
@@ -4078,8 +4296,8 @@ fn test_composite_value() {
                 filter: None,
                 over: None,
                 within_group: vec![],
-            })))),
-            access_chain: vec![AccessExpr::Dot(Expr::Identifier(Ident::new("n")))],
+            }))))),
+            access_chain: vec![AccessExpr::Dot(Expr::Identifier(Box::new(Ident::new("n"))))],
         },
         expr_from_projection(&select.projection[0])
     );
@@ -4135,27 +4353,39 @@ fn parse_escaped_literal_string() {
     let select = pg_and_generic().verified_only_select(sql);
     assert_eq!(6, select.projection.len());
     assert_eq!(
-        &Expr::Value((Value::EscapedStringLiteral("s1 \n s1".to_string())).with_empty_span()),
+        &Expr::Value(Box::new(
+            (Value::EscapedStringLiteral("s1 \n s1".to_string())).with_empty_span()
+        )),
         expr_from_projection(&select.projection[0])
     );
     assert_eq!(
-        &Expr::Value((Value::EscapedStringLiteral("s2 \\n s2".to_string())).with_empty_span()),
+        &Expr::Value(Box::new(
+            (Value::EscapedStringLiteral("s2 \\n s2".to_string())).with_empty_span()
+        )),
         expr_from_projection(&select.projection[1])
     );
     assert_eq!(
-        &Expr::Value((Value::EscapedStringLiteral("s3 \\\n s3".to_string())).with_empty_span()),
+        &Expr::Value(Box::new(
+            (Value::EscapedStringLiteral("s3 \\\n s3".to_string())).with_empty_span()
+        )),
         expr_from_projection(&select.projection[2])
     );
     assert_eq!(
-        &Expr::Value((Value::EscapedStringLiteral("s4 \\\\n s4".to_string())).with_empty_span()),
+        &Expr::Value(Box::new(
+            (Value::EscapedStringLiteral("s4 \\\\n s4".to_string())).with_empty_span()
+        )),
         expr_from_projection(&select.projection[3])
     );
     assert_eq!(
-        &Expr::Value((Value::EscapedStringLiteral("'".to_string())).with_empty_span()),
+        &Expr::Value(Box::new(
+            (Value::EscapedStringLiteral("'".to_string())).with_empty_span()
+        )),
         expr_from_projection(&select.projection[4])
     );
     assert_eq!(
-        &Expr::Value((Value::EscapedStringLiteral("foo \\".to_string())).with_empty_span()),
+        &Expr::Value(Box::new(
+            (Value::EscapedStringLiteral("foo \\".to_string())).with_empty_span()
+        )),
         expr_from_projection(&select.projection[5])
     );
 
@@ -4173,31 +4403,45 @@ fn parse_escaped_literal_string() {
     let select = pg_and_generic().verified_only_select_with_canonical(sql, canonical);
     assert_eq!(7, select.projection.len());
     assert_eq!(
-        &Expr::Value((Value::EscapedStringLiteral("\u{0001}".to_string())).with_empty_span()),
+        &Expr::Value(Box::new(
+            (Value::EscapedStringLiteral("\u{0001}".to_string())).with_empty_span()
+        )),
         expr_from_projection(&select.projection[0])
     );
     assert_eq!(
-        &Expr::Value((Value::EscapedStringLiteral("\u{10ffff}".to_string())).with_empty_span()),
+        &Expr::Value(Box::new(
+            (Value::EscapedStringLiteral("\u{10ffff}".to_string())).with_empty_span()
+        )),
         expr_from_projection(&select.projection[1])
     );
     assert_eq!(
-        &Expr::Value((Value::EscapedStringLiteral("\u{000c}".to_string())).with_empty_span()),
+        &Expr::Value(Box::new(
+            (Value::EscapedStringLiteral("\u{000c}".to_string())).with_empty_span()
+        )),
         expr_from_projection(&select.projection[2])
     );
     assert_eq!(
-        &Expr::Value((Value::EscapedStringLiteral("%".to_string())).with_empty_span()),
+        &Expr::Value(Box::new(
+            (Value::EscapedStringLiteral("%".to_string())).with_empty_span()
+        )),
         expr_from_projection(&select.projection[3])
     );
     assert_eq!(
-        &Expr::Value((Value::EscapedStringLiteral("\u{0002}".to_string())).with_empty_span()),
+        &Expr::Value(Box::new(
+            (Value::EscapedStringLiteral("\u{0002}".to_string())).with_empty_span()
+        )),
         expr_from_projection(&select.projection[4])
     );
     assert_eq!(
-        &Expr::Value((Value::EscapedStringLiteral("%".to_string())).with_empty_span()),
+        &Expr::Value(Box::new(
+            (Value::EscapedStringLiteral("%".to_string())).with_empty_span()
+        )),
         expr_from_projection(&select.projection[5])
     );
     assert_eq!(
-        &Expr::Value((Value::EscapedStringLiteral("%".to_string())).with_empty_span()),
+        &Expr::Value(Box::new(
+            (Value::EscapedStringLiteral("%".to_string())).with_empty_span()
+        )),
         expr_from_projection(&select.projection[6])
     );
 
@@ -4255,7 +4499,7 @@ fn parse_current_functions() {
     let canonical = "SELECT current_catalog, current_user, session_user, user";
     let select = pg_and_generic().verified_only_select_with_canonical(sql, canonical);
     assert_eq!(
-        &Expr::Function(Function {
+        &Expr::Function(Box::new(Function {
             name: ObjectName::from(vec![Ident::new("current_catalog")]),
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
@@ -4265,11 +4509,11 @@ fn parse_current_functions() {
             filter: None,
             over: None,
             within_group: vec![],
-        }),
+        })),
         expr_from_projection(&select.projection[0])
     );
     assert_eq!(
-        &Expr::Function(Function {
+        &Expr::Function(Box::new(Function {
             name: ObjectName::from(vec![Ident::new("current_user")]),
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
@@ -4279,11 +4523,11 @@ fn parse_current_functions() {
             filter: None,
             over: None,
             within_group: vec![],
-        }),
+        })),
         expr_from_projection(&select.projection[1])
     );
     assert_eq!(
-        &Expr::Function(Function {
+        &Expr::Function(Box::new(Function {
             name: ObjectName::from(vec![Ident::new("session_user")]),
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
@@ -4293,11 +4537,11 @@ fn parse_current_functions() {
             filter: None,
             over: None,
             within_group: vec![],
-        }),
+        })),
         expr_from_projection(&select.projection[2])
     );
     assert_eq!(
-        &Expr::Function(Function {
+        &Expr::Function(Box::new(Function {
             name: ObjectName::from(vec![Ident::new("user")]),
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
@@ -4307,7 +4551,7 @@ fn parse_current_functions() {
             filter: None,
             over: None,
             within_group: vec![],
-        }),
+        })),
         expr_from_projection(&select.projection[3])
     );
 }
@@ -4319,7 +4563,7 @@ fn parse_qualified_current_schema_function() {
         "SELECT pg_catalog.current_schema",
     );
     assert_eq!(
-        &Expr::Function(Function {
+        &Expr::Function(Box::new(Function {
             name: ObjectName::from(vec![Ident::new("pg_catalog"), Ident::new("current_schema")]),
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
@@ -4329,7 +4573,7 @@ fn parse_qualified_current_schema_function() {
             filter: None,
             over: None,
             within_group: vec![],
-        }),
+        })),
         expr_from_projection(&select.projection[0])
     );
 }
@@ -4364,13 +4608,9 @@ fn parse_custom_operator() {
     assert_eq!(
         expr_from_projection(&select.projection[0]),
         &Expr::BinaryOp {
-            left: Box::new(Expr::Value(
-                Value::Number("2".into(), false).with_empty_span()
-            )),
+            left: Box::new(Expr::Value(Box::new(number("2").with_empty_span()))),
             op: BinaryOperator::Custom("===".into()),
-            right: Box::new(Expr::Value(
-                Value::Number("2".into(), false).with_empty_span()
-            )),
+            right: Box::new(Expr::Value(Box::new(number("2").with_empty_span()))),
         }
     );
 
@@ -4381,19 +4621,19 @@ fn parse_custom_operator() {
         select.selection,
         Some(
             Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident {
+                left: Box::new(Expr::Identifier(Box::new(Ident {
                     value: "relname".into(),
                     quote_style: None,
                     span: Span::empty(),
-                })),
+                }))),
                 op: BinaryOperator::PGCustomBinaryOperator(vec![
                     "database".into(),
                     "pg_catalog".into(),
                     "~".into()
                 ]),
-                right: Box::new(Expr::Value(
+                right: Box::new(Expr::Value(Box::new(
                     (Value::SingleQuotedString("^(table)$".into())).with_empty_span()
-                ))
+                )))
             }
             .into()
         )
@@ -4406,15 +4646,15 @@ fn parse_custom_operator() {
         select.selection,
         Some(
             Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident {
+                left: Box::new(Expr::Identifier(Box::new(Ident {
                     value: "relname".into(),
                     quote_style: None,
                     span: Span::empty(),
-                })),
+                }))),
                 op: BinaryOperator::PGCustomBinaryOperator(vec!["pg_catalog".into(), "~".into()]),
-                right: Box::new(Expr::Value(
+                right: Box::new(Expr::Value(Box::new(
                     (Value::SingleQuotedString("^(table)$".into())).with_empty_span()
-                ))
+                )))
             }
             .into()
         )
@@ -4427,15 +4667,15 @@ fn parse_custom_operator() {
         select.selection,
         Some(
             Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident {
+                left: Box::new(Expr::Identifier(Box::new(Ident {
                     value: "relname".into(),
                     quote_style: None,
                     span: Span::empty(),
-                })),
+                }))),
                 op: BinaryOperator::PGCustomBinaryOperator(vec!["~".into()]),
-                right: Box::new(Expr::Value(
+                right: Box::new(Expr::Value(Box::new(
                     (Value::SingleQuotedString("^(table)$".into())).with_empty_span()
-                ))
+                )))
             }
             .into()
         )
@@ -4484,9 +4724,9 @@ fn parse_create_role() {
             assert_eq!(create_role.bypassrls, Some(true));
             assert_eq!(
                 create_role.password,
-                Some(Password::Password(Expr::Value(
+                Some(Password::Password(Expr::Value(Box::new(
                     (Value::SingleQuotedString("abcdef".into())).with_empty_span()
-                )))
+                ))))
             );
             assert_eq!(create_role.superuser, Some(true));
             assert_eq!(create_role.create_db, Some(false));
@@ -4495,9 +4735,9 @@ fn parse_create_role() {
             assert_eq!(create_role.connection_limit, None);
             assert_eq!(
                 create_role.valid_until,
-                Some(Expr::Value(
+                Some(Expr::Value(Box::new(
                     (Value::SingleQuotedString("2025-01-01".into())).with_empty_span()
-                ))
+                )))
             );
             assert_eq_vec(&["role1", "role2"], &create_role.in_role);
             assert!(create_role.in_group.is_empty());
@@ -4545,18 +4785,18 @@ fn parse_alter_role() {
         pg().verified_stmt(sql),
         Statement::AlterRole {
             token: AttachedToken::empty(),
-            name: Ident {
+            name: Box::new(Ident {
                 value: "old_name".into(),
                 quote_style: None,
                 span: Span::empty(),
-            },
-            operation: AlterRoleOperation::RenameRole {
+            }),
+            operation: Box::new(AlterRoleOperation::RenameRole {
                 role_name: Ident {
                     value: "new_name".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }
-            },
+            }),
         }
     );
 
@@ -4565,12 +4805,12 @@ fn parse_alter_role() {
         pg().verified_stmt(sql),
         Statement::AlterRole {
             token: AttachedToken::empty(),
-            name: Ident {
+            name: Box::new(Ident {
                 value: "role_name".into(),
                 quote_style: None,
                 span: Span::empty(),
-            },
-            operation: AlterRoleOperation::WithOptions {
+            }),
+            operation: Box::new(AlterRoleOperation::WithOptions {
                 options: vec![
                     RoleOption::SuperUser(true),
                     RoleOption::CreateDB(true),
@@ -4581,15 +4821,15 @@ fn parse_alter_role() {
                     RoleOption::BypassRLS(true),
                     RoleOption::ConnectionLimit(Expr::value(number("100"))),
                     RoleOption::Password({
-                        Password::Password(Expr::Value(
+                        Password::Password(Expr::Value(Box::new(
                             (Value::SingleQuotedString("abcdef".into())).with_empty_span(),
-                        ))
+                        )))
                     }),
-                    RoleOption::ValidUntil(Expr::Value(
+                    RoleOption::ValidUntil(Expr::Value(Box::new(
                         (Value::SingleQuotedString("2025-01-01".into(),)).with_empty_span()
-                    ))
+                    )))
                 ]
-            },
+            }),
         }
     );
 
@@ -4598,12 +4838,12 @@ fn parse_alter_role() {
         pg().verified_stmt(sql),
         Statement::AlterRole {
             token: AttachedToken::empty(),
-            name: Ident {
+            name: Box::new(Ident {
                 value: "role_name".into(),
                 quote_style: None,
                 span: Span::empty(),
-            },
-            operation: AlterRoleOperation::WithOptions {
+            }),
+            operation: Box::new(AlterRoleOperation::WithOptions {
                 options: vec![
                     RoleOption::SuperUser(false),
                     RoleOption::CreateDB(false),
@@ -4614,7 +4854,7 @@ fn parse_alter_role() {
                     RoleOption::BypassRLS(false),
                     RoleOption::Password(Password::NullPassword),
                 ]
-            },
+            }),
         }
     );
 
@@ -4623,12 +4863,12 @@ fn parse_alter_role() {
         pg().verified_stmt(sql),
         Statement::AlterRole {
             token: AttachedToken::empty(),
-            name: Ident {
+            name: Box::new(Ident {
                 value: "role_name".into(),
                 quote_style: None,
                 span: Span::empty(),
-            },
-            operation: AlterRoleOperation::Set {
+            }),
+            operation: Box::new(AlterRoleOperation::Set {
                 config_name: ObjectName::from(vec![Ident {
                     value: "maintenance_work_mem".into(),
                     quote_style: None,
@@ -4636,7 +4876,7 @@ fn parse_alter_role() {
                 }]),
                 config_value: SetConfigValue::FromCurrent,
                 in_database: None
-            },
+            }),
         }
     );
 
@@ -4645,26 +4885,26 @@ fn parse_alter_role() {
         pg().parse_sql_statements(sql).unwrap(),
         [Statement::AlterRole {
             token: AttachedToken::empty(),
-            name: Ident {
+            name: Box::new(Ident {
                 value: "role_name".into(),
                 quote_style: None,
                 span: Span::empty(),
-            },
-            operation: AlterRoleOperation::Set {
+            }),
+            operation: Box::new(AlterRoleOperation::Set {
                 config_name: ObjectName::from(vec![Ident {
                     value: "maintenance_work_mem".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }]),
-                config_value: SetConfigValue::Value(Expr::Value(
+                config_value: SetConfigValue::Value(Expr::Value(Box::new(
                     (number("100000")).with_empty_span()
-                )),
+                ))),
                 in_database: Some(ObjectName::from(vec![Ident {
                     value: "database_name".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }]))
-            },
+            }),
         }]
     );
 
@@ -4673,26 +4913,26 @@ fn parse_alter_role() {
         pg().verified_stmt(sql),
         Statement::AlterRole {
             token: AttachedToken::empty(),
-            name: Ident {
+            name: Box::new(Ident {
                 value: "role_name".into(),
                 quote_style: None,
                 span: Span::empty(),
-            },
-            operation: AlterRoleOperation::Set {
+            }),
+            operation: Box::new(AlterRoleOperation::Set {
                 config_name: ObjectName::from(vec![Ident {
                     value: "maintenance_work_mem".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }]),
-                config_value: SetConfigValue::Value(Expr::Value(
+                config_value: SetConfigValue::Value(Expr::Value(Box::new(
                     (number("100000")).with_empty_span()
-                )),
+                ))),
                 in_database: Some(ObjectName::from(vec![Ident {
                     value: "database_name".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }]))
-            },
+            }),
         }
     );
 
@@ -4701,12 +4941,12 @@ fn parse_alter_role() {
         pg().verified_stmt(sql),
         Statement::AlterRole {
             token: AttachedToken::empty(),
-            name: Ident {
+            name: Box::new(Ident {
                 value: "role_name".into(),
                 quote_style: None,
                 span: Span::empty(),
-            },
-            operation: AlterRoleOperation::Set {
+            }),
+            operation: Box::new(AlterRoleOperation::Set {
                 config_name: ObjectName::from(vec![Ident {
                     value: "maintenance_work_mem".into(),
                     quote_style: None,
@@ -4718,7 +4958,7 @@ fn parse_alter_role() {
                     quote_style: None,
                     span: Span::empty(),
                 }]))
-            },
+            }),
         }
     );
 
@@ -4727,15 +4967,15 @@ fn parse_alter_role() {
         pg().verified_stmt(sql),
         Statement::AlterRole {
             token: AttachedToken::empty(),
-            name: Ident {
+            name: Box::new(Ident {
                 value: "role_name".into(),
                 quote_style: None,
                 span: Span::empty(),
-            },
-            operation: AlterRoleOperation::Reset {
+            }),
+            operation: Box::new(AlterRoleOperation::Reset {
                 config_name: ResetConfig::ALL,
                 in_database: None
-            },
+            }),
         }
     );
 
@@ -4744,12 +4984,12 @@ fn parse_alter_role() {
         pg().verified_stmt(sql),
         Statement::AlterRole {
             token: AttachedToken::empty(),
-            name: Ident {
+            name: Box::new(Ident {
                 value: "role_name".into(),
                 quote_style: None,
                 span: Span::empty(),
-            },
-            operation: AlterRoleOperation::Reset {
+            }),
+            operation: Box::new(AlterRoleOperation::Reset {
                 config_name: ResetConfig::ConfigName(ObjectName::from(vec![Ident {
                     value: "maintenance_work_mem".into(),
                     quote_style: None,
@@ -4760,7 +5000,7 @@ fn parse_alter_role() {
                     quote_style: None,
                     span: Span::empty(),
                 }]))
-            },
+            }),
         }
     );
 }
@@ -4772,16 +5012,16 @@ fn parse_alter_system_and_database() {
         pg().verified_stmt(sql),
         Statement::AlterSystem {
             token: AttachedToken::empty(),
-            operation: AlterConfigurationOperation::Set {
+            operation: Box::new(AlterConfigurationOperation::Set {
                 config_name: ObjectName::from(vec![Ident {
                     value: "maintenance_work_mem".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }]),
-                config_value: SetConfigValue::Value(Expr::Value(
+                config_value: SetConfigValue::Value(Expr::Value(Box::new(
                     (number("100000")).with_empty_span()
-                )),
-            },
+                ))),
+            }),
         }
     );
 
@@ -4790,9 +5030,9 @@ fn parse_alter_system_and_database() {
         pg().verified_stmt(sql),
         Statement::AlterSystem {
             token: AttachedToken::empty(),
-            operation: AlterConfigurationOperation::Reset {
+            operation: Box::new(AlterConfigurationOperation::Reset {
                 config_name: ResetConfig::ALL,
-            },
+            }),
         }
     );
 
@@ -4806,16 +5046,16 @@ fn parse_alter_system_and_database() {
                 quote_style: None,
                 span: Span::empty(),
             }]),
-            operation: AlterConfigurationOperation::Set {
+            operation: Box::new(AlterConfigurationOperation::Set {
                 config_name: ObjectName::from(vec![Ident {
                     value: "maintenance_work_mem".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }]),
-                config_value: SetConfigValue::Value(Expr::Value(
+                config_value: SetConfigValue::Value(Expr::Value(Box::new(
                     (number("100000")).with_empty_span()
-                )),
-            },
+                ))),
+            }),
         }
     );
 
@@ -4829,13 +5069,13 @@ fn parse_alter_system_and_database() {
                 quote_style: None,
                 span: Span::empty(),
             }]),
-            operation: AlterConfigurationOperation::Reset {
+            operation: Box::new(AlterConfigurationOperation::Reset {
                 config_name: ResetConfig::ConfigName(ObjectName::from(vec![Ident {
                     value: "maintenance_work_mem".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }])),
-            },
+            }),
         }
     );
 }
@@ -4870,20 +5110,23 @@ fn parse_delimited_identifiers() {
     // check SELECT
     assert_eq!(3, select.projection.len());
     assert_eq!(
-        &Expr::CompoundIdentifier(vec![
-            Ident::with_quote('"', "alias"),
-            Ident::with_quote('"', "bar baz"),
-        ]),
+        &Expr::CompoundIdentifier(
+            vec![
+                Ident::with_quote('"', "alias"),
+                Ident::with_quote('"', "bar baz"),
+            ]
+            .into()
+        ),
         expr_from_projection(&select.projection[0]),
     );
     assert_eq!(
-        &Expr::Function(Function {
+        &Expr::Function(Box::new(Function {
             name: ObjectName::from(vec![Ident::with_quote('"', "myfun")]),
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
             args: FunctionArguments::List(FunctionArgumentList {
                 duplicate_treatment: None,
-                args: vec![],
+                args: AstVec::new(),
                 clauses: vec![],
             }),
             null_treatment: None,
@@ -4891,12 +5134,19 @@ fn parse_delimited_identifiers() {
             filter: None,
             over: None,
             within_group: vec![],
-        }),
+        })),
         expr_from_projection(&select.projection[1]),
     );
     match &select.projection[2] {
-        SelectItem::ExprWithAlias { expr, alias } => {
-            assert_eq!(&Expr::Identifier(Ident::with_quote('"', "simple id")), expr);
+        SelectItem::ExprWithAlias {
+            expr,
+            alias,
+            comments: _,
+        } => {
+            assert_eq!(
+                &Expr::Identifier(Box::new(Ident::with_quote('"', "simple id"))),
+                expr
+            );
             assert_eq!(&Ident::with_quote('"', "column alias"), alias);
         }
         _ => panic!("Expected: ExprWithAlias"),
@@ -4933,7 +5183,7 @@ fn parse_create_function() {
     let canonical = "CREATE FUNCTION add(INTEGER, INTEGER) RETURNS INTEGER LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE AS 'select $1 + $2;'";
     assert_eq!(
         pg_and_generic().one_statement_parses_to(sql, canonical),
-        Statement::CreateFunction(CreateFunction {
+        Statement::CreateFunction(Box::new(CreateFunction {
             token: AttachedToken::empty(),
             or_alter: false,
             or_replace: false,
@@ -4955,9 +5205,9 @@ fn parse_create_function() {
             window: false,
             support: None,
             set_options: vec![],
-            function_body: Some(CreateFunctionBody::AsBeforeOptions(Expr::Value(
+            function_body: Some(CreateFunctionBody::AsBeforeOptions(Expr::Value(Box::new(
                 (Value::SingleQuotedString("select $1 + $2;".into())).with_empty_span()
-            ))),
+            )))),
             if_not_exists: false,
             using: None,
             determinism_specifier: None,
@@ -4965,7 +5215,8 @@ fn parse_create_function() {
             remote_connection: None,
             polymorphic: false,
             sql_data_access: None,
-        })
+            comments: Comments::default(),
+        }))
     );
 }
 
@@ -4984,7 +5235,7 @@ fn parse_drop_function() {
             if_exists: true,
             func_desc: vec![FunctionDesc {
                 name: ObjectName::from(vec![Ident {
-                    value: "test_func".to_string(),
+                    value: "test_func".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }]),
@@ -5001,7 +5252,7 @@ fn parse_drop_function() {
             if_exists: true,
             func_desc: vec![FunctionDesc {
                 name: ObjectName::from(vec![Ident {
-                    value: "test_func".to_string(),
+                    value: "test_func".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }]),
@@ -5012,9 +5263,7 @@ fn parse_drop_function() {
                         mode: Some(ArgMode::In),
                         name: Some("b".into()),
                         data_type: DataType::Integer(None),
-                        default_expr: Some(Expr::Value(
-                            (Value::Number("1".parse().unwrap(), false)).with_empty_span()
-                        )),
+                        default_expr: Some(Expr::Value(Box::new(number("1").with_empty_span()))),
                     }
                 ]),
             }],
@@ -5030,7 +5279,7 @@ fn parse_drop_function() {
             func_desc: vec![
                 FunctionDesc {
                     name: ObjectName::from(vec![Ident {
-                        value: "test_func1".to_string(),
+                        value: "test_func1".into(),
                         quote_style: None,
                         span: Span::empty(),
                     }]),
@@ -5041,15 +5290,15 @@ fn parse_drop_function() {
                             mode: Some(ArgMode::In),
                             name: Some("b".into()),
                             data_type: DataType::Integer(None),
-                            default_expr: Some(Expr::Value(
-                                (Value::Number("1".parse().unwrap(), false)).with_empty_span()
-                            )),
+                            default_expr: Some(Expr::Value(Box::new(
+                                number("1").with_empty_span()
+                            ))),
                         }
                     ]),
                 },
                 FunctionDesc {
                     name: ObjectName::from(vec![Ident {
-                        value: "test_func2".to_string(),
+                        value: "test_func2".into(),
                         quote_style: None,
                         span: Span::empty(),
                     }]),
@@ -5060,9 +5309,9 @@ fn parse_drop_function() {
                             mode: Some(ArgMode::In),
                             name: Some("b".into()),
                             data_type: DataType::Integer(None),
-                            default_expr: Some(Expr::Value(
-                                (Value::Number("1".parse().unwrap(), false)).with_empty_span()
-                            )),
+                            default_expr: Some(Expr::Value(Box::new(
+                                number("1").with_empty_span()
+                            ))),
                         }
                     ]),
                 }
@@ -5081,7 +5330,7 @@ fn parse_drop_domain() {
             token: AttachedToken::empty(),
             if_exists: true,
             name: ObjectName::from(vec![Ident {
-                value: "jpeg_domain".to_string(),
+                value: "jpeg_domain".into(),
                 quote_style: None,
                 span: Span::empty(),
             }]),
@@ -5096,7 +5345,7 @@ fn parse_drop_domain() {
             token: AttachedToken::empty(),
             if_exists: false,
             name: ObjectName::from(vec![Ident {
-                value: "jpeg_domain".to_string(),
+                value: "jpeg_domain".into(),
                 quote_style: None,
                 span: Span::empty(),
             }]),
@@ -5111,7 +5360,7 @@ fn parse_drop_domain() {
             token: AttachedToken::empty(),
             if_exists: true,
             name: ObjectName::from(vec![Ident {
-                value: "jpeg_domain".to_string(),
+                value: "jpeg_domain".into(),
                 quote_style: None,
                 span: Span::empty(),
             }]),
@@ -5127,7 +5376,7 @@ fn parse_drop_domain() {
             token: AttachedToken::empty(),
             if_exists: true,
             name: ObjectName::from(vec![Ident {
-                value: "jpeg_domain".to_string(),
+                value: "jpeg_domain".into(),
                 quote_style: None,
                 span: Span::empty(),
             }]),
@@ -5146,7 +5395,7 @@ fn parse_drop_procedure() {
             if_exists: true,
             proc_desc: vec![FunctionDesc {
                 name: ObjectName::from(vec![Ident {
-                    value: "test_proc".to_string(),
+                    value: "test_proc".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }]),
@@ -5164,7 +5413,7 @@ fn parse_drop_procedure() {
             if_exists: true,
             proc_desc: vec![FunctionDesc {
                 name: ObjectName::from(vec![Ident {
-                    value: "test_proc".to_string(),
+                    value: "test_proc".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }]),
@@ -5175,9 +5424,7 @@ fn parse_drop_procedure() {
                         mode: Some(ArgMode::In),
                         name: Some("b".into()),
                         data_type: DataType::Integer(None),
-                        default_expr: Some(Expr::Value(
-                            (Value::Number("1".parse().unwrap(), false)).with_empty_span()
-                        )),
+                        default_expr: Some(Expr::Value(Box::new(number("1").with_empty_span()))),
                     }
                 ]),
             }],
@@ -5194,7 +5441,7 @@ fn parse_drop_procedure() {
             proc_desc: vec![
                 FunctionDesc {
                     name: ObjectName::from(vec![Ident {
-                        value: "test_proc1".to_string(),
+                        value: "test_proc1".into(),
                         quote_style: None,
                         span: Span::empty(),
                     }]),
@@ -5205,15 +5452,15 @@ fn parse_drop_procedure() {
                             mode: Some(ArgMode::In),
                             name: Some("b".into()),
                             data_type: DataType::Integer(None),
-                            default_expr: Some(Expr::Value(
-                                (Value::Number("1".parse().unwrap(), false)).with_empty_span()
-                            )),
+                            default_expr: Some(Expr::Value(Box::new(
+                                number("1").with_empty_span()
+                            ))),
                         }
                     ]),
                 },
                 FunctionDesc {
                     name: ObjectName::from(vec![Ident {
-                        value: "test_proc2".to_string(),
+                        value: "test_proc2".into(),
                         quote_style: None,
                         span: Span::empty(),
                     }]),
@@ -5224,9 +5471,9 @@ fn parse_drop_procedure() {
                             mode: Some(ArgMode::In),
                             name: Some("b".into()),
                             data_type: DataType::Integer(None),
-                            default_expr: Some(Expr::Value(
-                                (Value::Number("1".parse().unwrap(), false)).with_empty_span()
-                            )),
+                            default_expr: Some(Expr::Value(Box::new(
+                                number("1").with_empty_span()
+                            ))),
                         }
                     ]),
                 }
@@ -5263,76 +5510,77 @@ fn parse_dollar_quoted_string() {
     };
 
     assert_eq!(
-        &Expr::Value(
-            (Value::DollarQuotedString(DollarQuotedString {
+        &Expr::Value(Box::new(
+            (Value::DollarQuotedString(Box::new(DollarQuotedString {
                 tag: None,
                 value: "hello".into()
-            }))
+            })))
             .with_empty_span()
-        ),
+        )),
         expr_from_projection(&projection[0])
     );
 
     assert_eq!(
-        &Expr::Value(
-            (Value::DollarQuotedString(DollarQuotedString {
+        &Expr::Value(Box::new(
+            (Value::DollarQuotedString(Box::new(DollarQuotedString {
                 tag: Some("tag_name".into()),
                 value: "world".into()
-            }))
+            })))
             .with_empty_span()
-        ),
+        )),
         expr_from_projection(&projection[1])
     );
 
     assert_eq!(
-        &Expr::Value(
-            (Value::DollarQuotedString(DollarQuotedString {
+        &Expr::Value(Box::new(
+            (Value::DollarQuotedString(Box::new(DollarQuotedString {
                 tag: None,
                 value: "Foo$Bar".into()
-            }))
+            })))
             .with_empty_span()
-        ),
+        )),
         expr_from_projection(&projection[2])
     );
 
     assert_eq!(
         projection[3],
         SelectItem::ExprWithAlias {
-            expr: Expr::Value(
-                (Value::DollarQuotedString(DollarQuotedString {
+            expr: Expr::Value(Box::new(
+                (Value::DollarQuotedString(Box::new(DollarQuotedString {
                     tag: None,
                     value: "Foo$Bar".into(),
-                }))
+                })))
                 .with_empty_span()
-            ),
+            )),
             alias: Ident {
                 value: "col_name".into(),
                 quote_style: None,
                 span: Span::empty(),
             },
+            comments: Comments::default(),
         },
     );
 
     assert_eq!(
         expr_from_projection(&projection[4]),
-        &Expr::Value(
-            (Value::DollarQuotedString(DollarQuotedString {
+        &Expr::Value(Box::new(
+            (Value::DollarQuotedString(Box::new(DollarQuotedString {
                 tag: None,
                 value: "".into()
-            }))
+            })))
             .with_empty_span()
-        ),
+        )),
     );
 
     assert_eq!(
         expr_from_projection(&projection[5]),
-        &Expr::Value(
-            (Value::DollarQuotedString(DollarQuotedString {
+        &Expr::Value(Box::new(
+            (Value::DollarQuotedString(Box::new(DollarQuotedString {
                 tag: Some("tag_name".into()),
                 value: "".into()
-            }))
+            })))
             .with_empty_span()
-        ),
+        )),
     );
 }
 
@@ -5356,13 +5604,14 @@ fn parse_select_group_by_grouping_sets() {
     assert_eq!(
         GroupByExpr::Expressions(
             vec![
-                Expr::Identifier(Ident::new("size")),
+                Expr::Identifier(Box::new(Ident::new("size"))),
                 Expr::GroupingSets(vec![
-                    vec![Expr::Identifier(Ident::new("brand"))],
-                    vec![Expr::Identifier(Ident::new("size"))],
+                    vec![Expr::Identifier(Box::new(Ident::new("brand")))],
+                    vec![Expr::Identifier(Box::new(Ident::new("size")))],
                     vec![],
                 ]),
-            ],
+            ]
+            .into(),
             vec![]
         ),
         select.group_by
@@ -5377,12 +5626,13 @@ fn parse_select_group_by_rollup() {
     assert_eq!(
         GroupByExpr::Expressions(
             vec![
-                Expr::Identifier(Ident::new("size")),
+                Expr::Identifier(Box::new(Ident::new("size"))),
                 Expr::Rollup(vec![
-                    vec![Expr::Identifier(Ident::new("brand"))],
-                    vec![Expr::Identifier(Ident::new("size"))],
+                    vec![Expr::Identifier(Box::new(Ident::new("brand")))],
+                    vec![Expr::Identifier(Box::new(Ident::new("size")))],
                 ]),
-            ],
+            ]
+            .into(),
             vec![]
         ),
         select.group_by
@@ -5397,12 +5647,13 @@ fn parse_select_group_by_cube() {
     assert_eq!(
         GroupByExpr::Expressions(
             vec![
-                Expr::Identifier(Ident::new("size")),
+                Expr::Identifier(Box::new(Ident::new("size"))),
                 Expr::Cube(vec![
-                    vec![Expr::Identifier(Ident::new("brand"))],
-                    vec![Expr::Identifier(Ident::new("size"))],
+                    vec![Expr::Identifier(Box::new(Ident::new("brand")))],
+                    vec![Expr::Identifier(Box::new(Ident::new("size")))],
                 ]),
-            ],
+            ]
+            .into(),
             vec![]
         ),
         select.group_by
@@ -5507,15 +5758,19 @@ fn parse_create_table_with_alias() {
       bool_col BOOL
     );";
     match pg_and_generic().one_statement_parses_to(sql, "") {
-        Statement::CreateTable(CreateTable {
-            name,
-            columns,
-            constraints,
-            if_not_exists: false,
-            external: false,
-            location: None,
-            ..
-        }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable {
+                name,
+                columns,
+                constraints,
+                if_not_exists: false,
+                external: false,
+                location: None,
+                ..
+            } = Box::into_owned(create_table)
+            else {
+                unreachable!()
+            };
             assert_eq!("public.datatype_aliases", name.to_string());
             assert_eq!(
                 columns,
@@ -5523,32 +5778,38 @@ fn parse_create_table_with_alias() {
                     ColumnDef {
                         name: "int8_col".into(),
                         data_type: DataType::Int8(None),
-                        options: vec![]
+                        options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "int4_col".into(),
                         data_type: DataType::Int4(None),
-                        options: vec![]
+                        options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "int2_col".into(),
                         data_type: DataType::Int2(None),
-                        options: vec![]
+                        options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "float8_col".into(),
                         data_type: DataType::Float8,
-                        options: vec![]
+                        options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "float4_col".into(),
                         data_type: DataType::Float4,
-                        options: vec![]
+                        options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "bool_col".into(),
                         data_type: DataType::Bool,
-                        options: vec![]
+                        options: vec![],
+                        comments: Comments::default(),
                     },
                 ]
             );
@@ -5571,10 +5832,9 @@ fn parse_join_constraint_unnest_alias() {
         vec![Join {
             relation: TableFactor::UNNEST {
                 alias: table_alias("f"),
-                array_exprs: vec![Expr::CompoundIdentifier(vec![
-                    Ident::new("t1"),
-                    Ident::new("a")
-                ])],
+                array_exprs: vec![Expr::CompoundIdentifier(
+                    vec![Ident::new("t1"), Ident::new("a")].into()
+                )],
                 with_offset: false,
                 with_offset_alias: None,
                 with_ordinality: false,
@@ -5582,9 +5842,9 @@ fn parse_join_constraint_unnest_alias() {
             global: false,
             join_operator: JoinOperator::Join(JoinConstraint::On(
                 Expr::BinaryOp {
-                    left: Box::new(Expr::Identifier("c1".into())),
+                    left: Box::new(Expr::Identifier(Box::new("c1".into()))),
                     op: BinaryOperator::Eq,
-                    right: Box::new(Expr::Identifier("c2".into())),
+                    right: Box::new(Expr::Identifier(Box::new("c2".into()))),
                 }
                 .into()
             )),
@@ -5610,32 +5870,33 @@ fn test_simple_postgres_insert_with_alias() {
 
     assert_eq!(
         statement,
-        Statement::Insert(Insert {
+        Statement::Insert(Box::new(Insert {
             insert_token: AttachedToken::empty(),
             ignore: false,
             into: true,
             table: TableObject::TableName(ObjectName::from(vec![Ident {
-                value: "test_tables".to_string(),
+                value: "test_tables".into(),
                 quote_style: None,
                 span: Span::empty(),
             }])),
             table_alias: Some(Ident {
-                value: "test_table".to_string(),
+                value: "test_table".into(),
                 quote_style: None,
                 span: Span::empty(),
             }),
             columns: vec![
                 Ident {
-                    value: "id".to_string(),
+                    value: "id".into(),
                     quote_style: None,
                     span: Span::empty(),
                 },
                 Ident {
-                    value: "a".to_string(),
+                    value: "a".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }
-            ],
+            ]
+            .into(),
             overriding: None,
             overwrite: false,
             source: Some(Box::new(Query {
@@ -5644,15 +5905,20 @@ fn test_simple_postgres_insert_with_alias() {
                     value_keyword: false,
                     explicit_row: false,
                     rows: vec![vec![
-                        Expr::Identifier(Ident::new("default")),
-                        Expr::Value((Value::Number("123".to_string(), false)).with_empty_span())
-                    ]]
+                        Expr::Identifier(Box::new(Ident::new("default"))),
+                        Expr::Value(Box::new(
+                            (Value::Number("123".into(), false)).with_empty_span()
+                        ))
+                    ]
+                    .into()]
+                    .into()
                 })),
                 order_by: None,
                 limit_clause: None,
                 fetch: None,
-                locks: vec![],
+                locks: AstVec::new(),
                 for_clause: None,
+                comments: Comments::default(),
             })),
             assignments: vec![],
             partitioned: None,
@@ -5664,7 +5930,8 @@ fn test_simple_postgres_insert_with_alias() {
             priority: None,
             insert_alias: None,
             error_logging: None,
-        })
+            comments: Comments::default(),
+        }))
     )
 }
 
@@ -5678,32 +5945,33 @@ fn test_simple_postgres_insert_with_alias() {
 
     assert_eq!(
         statement,
-        Statement::Insert(Insert {
+        Statement::Insert(Box::new(Insert {
             insert_token: AttachedToken::empty(),
             ignore: false,
             into: true,
             table: TableObject::TableName(ObjectName::from(vec![Ident {
-                value: "test_tables".to_string(),
+                value: "test_tables".into(),
                 quote_style: None,
                 span: Span::empty(),
             }])),
             table_alias: Some(Ident {
-                value: "test_table".to_string(),
+                value: "test_table".into(),
                 quote_style: None,
                 span: Span::empty(),
             }),
             columns: vec![
                 Ident {
-                    value: "id".to_string(),
+                    value: "id".into(),
                     quote_style: None,
                     span: Span::empty(),
                 },
                 Ident {
-                    value: "a".to_string(),
+                    value: "a".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }
-            ],
+            ]
+            .into(),
             overriding: None,
             overwrite: false,
             source: Some(Box::new(Query {
@@ -5712,18 +5980,24 @@ fn test_simple_postgres_insert_with_alias() {
                     value_keyword: false,
                     explicit_row: false,
                     rows: vec![vec![
-                        Expr::Identifier(Ident::new("default")),
-                        Expr::Value(
-                            (Value::Number(bigdecimal::BigDecimal::new(123.into(), 0), false))
-                                .with_empty_span()
-                        )
-                    ]]
+                        Expr::Identifier(Box::new(Ident::new("default"))),
+                        Expr::Value(Box::new(
+                            (Value::Number(
+                                Box::new(bigdecimal::BigDecimal::new(123.into(), 0)),
+                                false
+                            ))
+                            .with_empty_span()
+                        ))
+                    ]
+                    .into()]
+                    .into()
                 })),
                 order_by: None,
                 limit_clause: None,
                 fetch: None,
-                locks: vec![],
+                locks: AstVec::new(),
                 for_clause: None,
+                comments: Comments::default(),
             })),
             assignments: vec![],
             partitioned: None,
@@ -5735,7 +6009,8 @@ fn test_simple_postgres_insert_with_alias() {
             priority: None,
             insert_alias: None,
             error_logging: None,
-        })
+            comments: Comments::default(),
+        }))
     )
 }
 
@@ -5748,32 +6023,33 @@ fn test_simple_insert_with_quoted_alias() {
 
     assert_eq!(
         statement,
-        Statement::Insert(Insert {
+        Statement::Insert(Box::new(Insert {
             insert_token: AttachedToken::empty(),
             ignore: false,
             into: true,
             table: TableObject::TableName(ObjectName::from(vec![Ident {
-                value: "test_tables".to_string(),
+                value: "test_tables".into(),
                 quote_style: None,
                 span: Span::empty(),
             }])),
             table_alias: Some(Ident {
-                value: "Test_Table".to_string(),
+                value: "Test_Table".into(),
                 quote_style: Some('"'),
                 span: Span::empty(),
             }),
             columns: vec![
                 Ident {
-                    value: "id".to_string(),
+                    value: "id".into(),
                     quote_style: None,
                     span: Span::empty(),
                 },
                 Ident {
-                    value: "a".to_string(),
+                    value: "a".into(),
                     quote_style: None,
                     span: Span::empty(),
                 }
-            ],
+            ]
+            .into(),
             overriding: None,
             overwrite: false,
             source: Some(Box::new(Query {
@@ -5782,17 +6058,20 @@ fn test_simple_insert_with_quoted_alias() {
                     value_keyword: false,
                     explicit_row: false,
                     rows: vec![vec![
-                        Expr::Identifier(Ident::new("default")),
-                        Expr::Value(
-                            (Value::SingleQuotedString("0123".to_string())).with_empty_span()
-                        )
-                    ]]
+                        Expr::Identifier(Box::new(Ident::new("default"))),
+                        Expr::Value(Box::new(
+                            (Value::SingleQuotedString("0123".into())).with_empty_span()
+                        ))
+                    ]
+                    .into()]
+                    .into()
                 })),
                 order_by: None,
                 limit_clause: None,
                 fetch: None,
-                locks: vec![],
+                locks: AstVec::new(),
                 for_clause: None,
+                comments: Comments::default(),
             })),
             assignments: vec![],
             partitioned: None,
@@ -5804,7 +6083,8 @@ fn test_simple_insert_with_quoted_alias() {
             priority: None,
             insert_alias: None,
             error_logging: None,
-        })
+            comments: Comments::default(),
+        }))
     )
 }
 
@@ -5858,33 +6138,33 @@ fn parse_at_time_zone() {
     // check precedence
     let expr = Expr::BinaryOp {
         left: Box::new(Expr::AtTimeZone {
-            timestamp: Box::new(Expr::TypedString(TypedString {
+            timestamp: Box::new(Expr::TypedString(Box::new(TypedString {
                 data_type: DataType::Timestamp(None, TimezoneInfo::None),
                 value: ValueWithSpan {
-                    value: Value::SingleQuotedString("2001-09-28 01:00".to_string()),
+                    value: Value::SingleQuotedString("2001-09-28 01:00".into()),
                     span: Span::empty(),
                 },
                 uses_odbc_syntax: false,
-            })),
+            }))),
             time_zone: Box::new(Expr::Cast {
                 kind: CastKind::DoubleColon,
-                expr: Box::new(Expr::Value(
-                    Value::SingleQuotedString("America/Los_Angeles".to_owned()).with_empty_span(),
-                )),
-                data_type: DataType::Text,
+                expr: Box::new(Expr::Value(Box::new(
+                    Value::SingleQuotedString("America/Los_Angeles".into()).with_empty_span(),
+                ))),
+                data_type: Box::new(DataType::Text),
                 format: None,
             }),
         }),
         op: BinaryOperator::Plus,
-        right: Box::new(Expr::Interval(Interval {
-            value: Box::new(Expr::Value(
-                Value::SingleQuotedString("23 hours".to_owned()).with_empty_span(),
-            )),
+        right: Box::new(Expr::Interval(Box::new(Interval {
+            value: Box::new(Expr::Value(Box::new(
+                Value::SingleQuotedString("23 hours".into()).with_empty_span(),
+            ))),
             leading_field: None,
             leading_precision: None,
             last_field: None,
             fractional_seconds_precision: None,
-        })),
+        }))),
     };
     pretty_assertions::assert_eq!(
         pg_and_generic().verified_expr(
@@ -5936,7 +6216,8 @@ fn parse_interval_data_type() {
 fn parse_create_table_with_options() {
     let sql = "CREATE TABLE t (c INT) WITH (foo = 'bar', a = 123)";
     match pg().verified_stmt(sql) {
-        Statement::CreateTable(CreateTable { table_options, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { table_options, .. } = Box::into_owned(create_table);
             let with_options = match table_options {
                 CreateTableOptions::With(options) => options,
                 _ => unreachable!(),
@@ -5945,9 +6226,9 @@ fn parse_create_table_with_options() {
                 vec![
                     SqlOption::KeyValue {
                         key: "foo".into(),
-                        value: Expr::Value(
+                        value: Expr::Value(Box::new(
                             (Value::SingleQuotedString("bar".into())).with_empty_span()
-                        ),
+                        )),
                     },
                     SqlOption::KeyValue {
                         key: "a".into(),
@@ -6019,10 +6300,10 @@ fn test_table_unnest_with_ordinality() {
 #[test]
 fn test_escaped_string_literal() {
     match pg().verified_expr(r#"E'\n'"#) {
-        Expr::Value(ValueWithSpan {
-            value: Value::EscapedStringLiteral(s),
-            span: _,
-        }) => {
+        Expr::Value(value) => {
+            let Value::EscapedStringLiteral(s) = Box::into_owned(value).value else {
+                unreachable!()
+            };
             assert_eq!("\n", s);
         }
         _ => unreachable!(),
@@ -6033,7 +6314,7 @@ fn test_escaped_string_literal() {
 fn parse_create_domain() {
     let sql1 = "CREATE DOMAIN my_domain AS INTEGER CHECK (VALUE > 0)";
     let canonical1 = "CREATE DOMAIN my_domain AS INTEGER CHECK (value > 0)";
-    let expected = Statement::CreateDomain(CreateDomain {
+    let expected = Statement::CreateDomain(Box::new(CreateDomain {
         token: AttachedToken::empty(),
         not_null: false,
         name: ObjectName::from(vec![Ident::new("my_domain")]),
@@ -6043,20 +6324,20 @@ fn parse_create_domain() {
         constraints: vec![CheckConstraint {
             name: None,
             expr: Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("value"))),
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("value")))),
                 op: BinaryOperator::Gt,
-                right: Box::new(Expr::Value(test_utils::number("0").into())),
+                right: Box::new(Expr::Value(Box::new(test_utils::number("0").into()))),
             }),
             enforced: None,
         }
         .into()],
-    });
+    }));
 
     assert_eq!(pg().one_statement_parses_to(sql1, canonical1), expected);
 
     let sql2 = "CREATE DOMAIN my_domain AS INTEGER COLLATE \"en_US\" CHECK (VALUE > 0)";
     let canonical2 = "CREATE DOMAIN my_domain AS INTEGER COLLATE \"en_US\" CHECK (value > 0)";
-    let expected = Statement::CreateDomain(CreateDomain {
+    let expected = Statement::CreateDomain(Box::new(CreateDomain {
         token: AttachedToken::empty(),
         not_null: false,
         name: ObjectName::from(vec![Ident::new("my_domain")]),
@@ -6066,68 +6347,68 @@ fn parse_create_domain() {
         constraints: vec![CheckConstraint {
             name: None,
             expr: Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("value"))),
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("value")))),
                 op: BinaryOperator::Gt,
-                right: Box::new(Expr::Value(test_utils::number("0").into())),
+                right: Box::new(Expr::Value(Box::new(test_utils::number("0").into()))),
             }),
             enforced: None,
         }
         .into()],
-    });
+    }));
 
     assert_eq!(pg().one_statement_parses_to(sql2, canonical2), expected);
 
     let sql3 = "CREATE DOMAIN my_domain AS INTEGER DEFAULT 1 CHECK (VALUE > 0)";
     let canonical3 = "CREATE DOMAIN my_domain AS INTEGER DEFAULT 1 CHECK (value > 0)";
-    let expected = Statement::CreateDomain(CreateDomain {
+    let expected = Statement::CreateDomain(Box::new(CreateDomain {
         token: AttachedToken::empty(),
         not_null: false,
         name: ObjectName::from(vec![Ident::new("my_domain")]),
         data_type: DataType::Integer(None),
         collation: None,
-        default: Some(Expr::Value(test_utils::number("1").into())),
+        default: Some(Expr::Value(Box::new(test_utils::number("1").into()))),
         constraints: vec![CheckConstraint {
             name: None,
             expr: Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("value"))),
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("value")))),
                 op: BinaryOperator::Gt,
-                right: Box::new(Expr::Value(test_utils::number("0").into())),
+                right: Box::new(Expr::Value(Box::new(test_utils::number("0").into()))),
             }),
             enforced: None,
         }
         .into()],
-    });
+    }));
 
     assert_eq!(pg().one_statement_parses_to(sql3, canonical3), expected);
 
     let sql4 = "CREATE DOMAIN my_domain AS INTEGER COLLATE \"en_US\" DEFAULT 1 CHECK (VALUE > 0)";
     let canonical4 =
         "CREATE DOMAIN my_domain AS INTEGER COLLATE \"en_US\" DEFAULT 1 CHECK (value > 0)";
-    let expected = Statement::CreateDomain(CreateDomain {
+    let expected = Statement::CreateDomain(Box::new(CreateDomain {
         token: AttachedToken::empty(),
         not_null: false,
         name: ObjectName::from(vec![Ident::new("my_domain")]),
         data_type: DataType::Integer(None),
         collation: Some(Ident::with_quote('"', "en_US")),
-        default: Some(Expr::Value(test_utils::number("1").into())),
+        default: Some(Expr::Value(Box::new(test_utils::number("1").into()))),
         constraints: vec![CheckConstraint {
             name: None,
             expr: Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("value"))),
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("value")))),
                 op: BinaryOperator::Gt,
-                right: Box::new(Expr::Value(test_utils::number("0").into())),
+                right: Box::new(Expr::Value(Box::new(test_utils::number("0").into()))),
             }),
             enforced: None,
         }
         .into()],
-    });
+    }));
 
     assert_eq!(pg().one_statement_parses_to(sql4, canonical4), expected);
 
     let sql5 = "CREATE DOMAIN my_domain AS INTEGER CONSTRAINT my_constraint CHECK (VALUE > 0)";
     let canonical5 =
         "CREATE DOMAIN my_domain AS INTEGER CONSTRAINT my_constraint CHECK (value > 0)";
-    let expected = Statement::CreateDomain(CreateDomain {
+    let expected = Statement::CreateDomain(Box::new(CreateDomain {
         token: AttachedToken::empty(),
         not_null: false,
         name: ObjectName::from(vec![Ident::new("my_domain")]),
@@ -6137,14 +6418,14 @@ fn parse_create_domain() {
         constraints: vec![CheckConstraint {
             name: Some(Ident::new("my_constraint")),
             expr: Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("value"))),
+                left: Box::new(Expr::Identifier(Box::new(Ident::new("value")))),
                 op: BinaryOperator::Gt,
-                right: Box::new(Expr::Value(test_utils::number("0").into())),
+                right: Box::new(Expr::Value(Box::new(test_utils::number("0").into()))),
             }),
             enforced: None,
         }
         .into()],
-    });
+    }));
 
     assert_eq!(pg().one_statement_parses_to(sql5, canonical5), expected);
 }
@@ -6152,7 +6433,7 @@ fn parse_create_domain() {
 #[test]
 fn parse_create_simple_before_insert_trigger() {
     let sql = "CREATE TRIGGER check_insert BEFORE INSERT ON accounts FOR EACH ROW EXECUTE FUNCTION check_account_insert";
-    let expected = Statement::CreateTrigger(CreateTrigger {
+    let expected = Statement::CreateTrigger(Box::new(CreateTrigger {
         token: AttachedToken::empty(),
         or_alter: false,
         temporary: false,
@@ -6177,7 +6458,7 @@ fn parse_create_simple_before_insert_trigger() {
         statements_as: false,
         statements: None,
         characteristics: None,
-    });
+    }));
 
     assert_eq!(pg().verified_stmt(sql), expected);
 }
@@ -6186,7 +6467,7 @@ fn parse_create_simple_before_insert_trigger() {
 fn parse_create_after_update_trigger_with_condition() {
     let sql = "CREATE TRIGGER check_update AFTER UPDATE ON accounts FOR EACH ROW WHEN (NEW.balance > 10000) EXECUTE FUNCTION check_account_update";
     let canonical = "CREATE TRIGGER check_update AFTER UPDATE ON accounts FOR EACH ROW WHEN (new.balance > 10000) EXECUTE FUNCTION check_account_update";
-    let expected = Statement::CreateTrigger(CreateTrigger {
+    let expected = Statement::CreateTrigger(Box::new(CreateTrigger {
         token: AttachedToken::empty(),
         or_alter: false,
         temporary: false,
@@ -6201,10 +6482,9 @@ fn parse_create_after_update_trigger_with_condition() {
         referencing: vec![],
         trigger_object: Some(TriggerObjectKind::ForEach(TriggerObject::Row)),
         condition: Some(Expr::Nested(Box::new(Expr::BinaryOp {
-            left: Box::new(Expr::CompoundIdentifier(vec![
-                Ident::new("new"),
-                Ident::new("balance"),
-            ])),
+            left: Box::new(Expr::CompoundIdentifier(
+                vec![Ident::new("new"), Ident::new("balance")].into(),
+            )),
             op: BinaryOperator::Gt,
             right: Box::new(Expr::value(number("10000"))),
         }))),
@@ -6218,7 +6498,7 @@ fn parse_create_after_update_trigger_with_condition() {
         statements_as: false,
         statements: None,
         characteristics: None,
-    });
+    }));
 
     assert_eq!(pg().one_statement_parses_to(sql, canonical), expected);
 }
@@ -6226,7 +6506,7 @@ fn parse_create_after_update_trigger_with_condition() {
 #[test]
 fn parse_create_instead_of_delete_trigger() {
     let sql = "CREATE TRIGGER check_delete INSTEAD OF DELETE ON accounts FOR EACH ROW EXECUTE FUNCTION check_account_deletes";
-    let expected = Statement::CreateTrigger(CreateTrigger {
+    let expected = Statement::CreateTrigger(Box::new(CreateTrigger {
         token: AttachedToken::empty(),
         or_alter: false,
         temporary: false,
@@ -6251,7 +6531,7 @@ fn parse_create_instead_of_delete_trigger() {
         statements_as: false,
         statements: None,
         characteristics: None,
-    });
+    }));
 
     assert_eq!(pg().verified_stmt(sql), expected);
 }
@@ -6259,7 +6539,7 @@ fn parse_create_instead_of_delete_trigger() {
 #[test]
 fn parse_create_trigger_with_multiple_events_and_deferrable() {
     let sql = "CREATE CONSTRAINT TRIGGER check_multiple_events BEFORE INSERT OR UPDATE OR DELETE ON accounts DEFERRABLE INITIALLY DEFERRED FOR EACH ROW EXECUTE FUNCTION check_account_changes";
-    let expected = Statement::CreateTrigger(CreateTrigger {
+    let expected = Statement::CreateTrigger(Box::new(CreateTrigger {
         token: AttachedToken::empty(),
         or_alter: false,
         temporary: false,
@@ -6292,7 +6572,7 @@ fn parse_create_trigger_with_multiple_events_and_deferrable() {
             initially: Some(DeferrableInitial::Deferred),
             enforced: None,
         }),
-    });
+    }));
 
     assert_eq!(pg().verified_stmt(sql), expected);
 }
@@ -6300,7 +6580,7 @@ fn parse_create_trigger_with_multiple_events_and_deferrable() {
 #[test]
 fn parse_create_trigger_with_referencing() {
     let sql = "CREATE TRIGGER check_referencing BEFORE INSERT ON accounts REFERENCING NEW TABLE AS new_accounts OLD TABLE AS old_accounts FOR EACH ROW EXECUTE FUNCTION check_account_referencing";
-    let expected = Statement::CreateTrigger(CreateTrigger {
+    let expected = Statement::CreateTrigger(Box::new(CreateTrigger {
         token: AttachedToken::empty(),
         or_alter: false,
         temporary: false,
@@ -6336,7 +6616,7 @@ fn parse_create_trigger_with_referencing() {
         statements_as: false,
         statements: None,
         characteristics: None,
-    });
+    }));
 
     assert_eq!(pg().verified_stmt(sql), expected);
 }
@@ -6441,10 +6721,10 @@ fn test_unicode_string_literal() {
     ];
     for (input, expected) in pairs {
         match pg_and_generic().verified_expr(input) {
-            Expr::Value(ValueWithSpan {
-                value: Value::UnicodeStringLiteral(s),
-                span: _,
-            }) => {
+            Expr::Value(value) => {
+                let Value::UnicodeStringLiteral(s) = Box::into_owned(value).value else {
+                    unreachable!()
+                };
                 assert_eq!(expected, s);
             }
             _ => unreachable!(),
@@ -6457,20 +6737,20 @@ fn check_arrow_precedence(sql: &str, arrow_operator: BinaryOperator) {
         pg().verified_expr(sql),
         Expr::BinaryOp {
             left: Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident {
-                    value: "foo".to_string(),
+                left: Box::new(Expr::Identifier(Box::new(Ident {
+                    value: "foo".into(),
                     quote_style: None,
                     span: Span::empty(),
-                })),
+                }))),
                 op: arrow_operator,
-                right: Box::new(Expr::Value(
-                    (Value::SingleQuotedString("bar".to_string())).with_empty_span()
-                )),
+                right: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("bar".into())).with_empty_span()
+                ))),
             }),
             op: BinaryOperator::Eq,
-            right: Box::new(Expr::Value(
-                (Value::SingleQuotedString("spam".to_string())).with_empty_span()
-            )),
+            right: Box::new(Expr::Value(Box::new(
+                (Value::SingleQuotedString("spam".into())).with_empty_span()
+            ))),
         }
     )
 }
@@ -6492,18 +6772,18 @@ fn arrow_cast_precedence() {
     assert_eq!(
         stmt,
         Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident {
-                value: "foo".to_string(),
+            left: Box::new(Expr::Identifier(Box::new(Ident {
+                value: "foo".into(),
                 quote_style: None,
                 span: Span::empty(),
-            })),
+            }))),
             op: BinaryOperator::Arrow,
             right: Box::new(Expr::Cast {
                 kind: CastKind::DoubleColon,
-                expr: Box::new(Expr::Value(
-                    (Value::SingleQuotedString("bar".to_string())).with_empty_span()
-                )),
-                data_type: DataType::Text,
+                expr: Box::new(Expr::Value(Box::new(
+                    (Value::SingleQuotedString("bar".into())).with_empty_span()
+                ))),
+                data_type: Box::new(DataType::Text),
                 format: None,
             }),
         }
@@ -6615,10 +6895,10 @@ fn parse_alter_type() {
     .enumerate()
     .for_each(|(index, tc)| {
         let statement = pg_and_generic().verified_stmt(tc.sql);
-        if let Statement::AlterType(AlterType {
-            name, operation, ..
-        }) = statement
-        {
+        if let Statement::AlterType(alter_type) = statement {
+            let AlterType {
+                name, operation, ..
+            } = Box::into_owned(alter_type);
             assert_eq!(tc.name, name.to_string(), "TestCase[{index}].name");
             assert_eq!(tc.operation, operation, "TestCase[{index}].operation");
         } else {
@@ -6632,16 +6912,20 @@ fn parse_bitstring_literal() {
     let select = pg_and_generic().verified_only_select("SELECT B'111'");
     assert_eq!(
         select.projection,
-        vec![SelectItem::UnnamedExpr(Expr::Value(
-            (Value::SingleQuotedByteStringLiteral("111".to_string())).with_empty_span()
-        ))]
+        vec![SelectItem::UnnamedExpr {
+            expr: Expr::Value(Box::new(
+                (Value::SingleQuotedByteStringLiteral("111".to_string())).with_empty_span()
+            )),
+            comments: Comments::default(),
+        }]
     );
 }
 
 #[test]
 fn parse_varbit_datatype() {
     match pg_and_generic().verified_stmt("CREATE TABLE foo (x VARBIT, y VARBIT(42))") {
-        Statement::CreateTable(CreateTable { columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { columns, .. } = Box::into_owned(create_table);
             assert_eq!(
                 columns,
                 vec![
@@ -6649,11 +6933,13 @@ fn parse_varbit_datatype() {
                         name: "x".into(),
                         data_type: DataType::VarBit(None),
                         options: vec![],
+                        comments: Comments::default(),
                     },
                     ColumnDef {
                         name: "y".into(),
                         data_type: DataType::VarBit(Some(42)),
                         options: vec![],
+                        comments: Comments::default(),
                     }
                 ]
             );
@@ -6684,7 +6970,10 @@ fn parse_variadic_function_args() {
     match stmt {
         Statement::Query(query) => match query.body.as_ref() {
             SetExpr::Select(select) => match &select.projection[0] {
-                SelectItem::UnnamedExpr(Expr::Function(func)) => {
+                SelectItem::UnnamedExpr {
+                    expr: Expr::Function(func),
+                    ..
+                } => {
                     let FunctionArguments::List(list) = &func.args else {
                         panic!("expected arg list, got {:?}", func.args);
                     };
@@ -6803,13 +7092,15 @@ fn parse_alter_table_replica_identity() {
 #[test]
 fn parse_ts_datatypes() {
     match pg_and_generic().verified_stmt("CREATE TABLE foo (x TSVECTOR)") {
-        Statement::CreateTable(CreateTable { columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { columns, .. } = Box::into_owned(create_table);
             assert_eq!(
                 columns,
                 vec![ColumnDef {
                     name: "x".into(),
                     data_type: DataType::TsVector,
                     options: vec![],
+                    comments: Comments::default(),
                 }]
             );
         }
@@ -6817,13 +7108,15 @@ fn parse_ts_datatypes() {
     }
 
     match pg_and_generic().verified_stmt("CREATE TABLE foo (x TSQUERY)") {
-        Statement::CreateTable(CreateTable { columns, .. }) => {
+        Statement::CreateTable(create_table) => {
+            let CreateTable { columns, .. } = Box::into_owned(create_table);
             assert_eq!(
                 columns,
                 vec![ColumnDef {
                     name: "x".into(),
                     data_type: DataType::TsQuery,
                     options: vec![],
+                    comments: Comments::default(),
                 }]
             );
         }
@@ -6895,12 +7188,12 @@ fn parse_create_server() {
                 name: ObjectName::from(vec!["myserver".into()]),
                 if_not_exists: true,
                 server_type: Some(Ident {
-                    value: "server_type".to_string(),
+                    value: "server_type".into(),
                     quote_style: Some('\''),
                     span: Span::empty(),
                 }),
                 version: Some(Ident {
-                    value: "server_version".to_string(),
+                    value: "server_version".into(),
                     quote_style: Some('\''),
                     span: Span::empty(),
                 }),
@@ -6921,7 +7214,7 @@ fn parse_create_server() {
                     CreateServerOption {
                         key: "host".into(),
                         value: Ident {
-                            value: "foo".to_string(),
+                            value: "foo".into(),
                             quote_style: Some('\''),
                             span: Span::empty(),
                         },
@@ -6929,7 +7222,7 @@ fn parse_create_server() {
                     CreateServerOption {
                         key: "dbname".into(),
                         value: Ident {
-                            value: "foodb".to_string(),
+                            value: "foodb".into(),
                             quote_style: Some('\''),
                             span: Span::empty(),
                         },
@@ -6937,7 +7230,7 @@ fn parse_create_server() {
                     CreateServerOption {
                         key: "port".into(),
                         value: Ident {
-                            value: "5432".to_string(),
+                            value: "5432".into(),
                             quote_style: Some('\''),
                             span: Span::empty(),
                         },
@@ -7007,7 +7300,8 @@ fn parse_foreign_key_match() {
         let sql = format!("CREATE TABLE t (id INT REFERENCES other_table(id) {match_clause})");
         let statement = pg_and_generic().verified_stmt(&sql);
         match statement {
-            Statement::CreateTable(CreateTable { columns, .. }) => {
+            Statement::CreateTable(create_table) => {
+                let CreateTable { columns, .. } = Box::into_owned(create_table);
                 match &columns[0].options[0].option {
                     ColumnOption::ForeignKey(constraint) => {
                         assert_eq!(constraint.match_kind, Some(expected_kind));
@@ -7024,12 +7318,15 @@ fn parse_foreign_key_match() {
         );
         let statement = pg_and_generic().verified_stmt(&sql);
         match statement {
-            Statement::CreateTable(CreateTable { constraints, .. }) => match &constraints[0] {
-                TableConstraint::ForeignKey(constraint) => {
-                    assert_eq!(constraint.match_kind, Some(expected_kind));
+            Statement::CreateTable(create_table) => {
+                let CreateTable { constraints, .. } = Box::into_owned(create_table);
+                match &constraints[0] {
+                    TableConstraint::ForeignKey(constraint) => {
+                        assert_eq!(constraint.match_kind, Some(expected_kind));
+                    }
+                    _ => panic!("Expected TableConstraint::ForeignKey"),
                 }
-                _ => panic!("Expected TableConstraint::ForeignKey"),
-            },
+            }
             _ => unreachable!("{:?} should parse to Statement::CreateTable", sql),
         }
     }
@@ -7047,7 +7344,7 @@ fn parse_create_operator() {
     let sql = "CREATE OPERATOR myschema.@@ (PROCEDURE = myschema.my_proc, LEFTARG = TIMESTAMP WITH TIME ZONE, RIGHTARG = VARCHAR(255), COMMUTATOR = schema.>, NEGATOR = schema.<=, RESTRICT = myschema.sel_func, JOIN = myschema.join_func, HASHES, MERGES)";
     assert_eq!(
         pg().verified_stmt(sql),
-        Statement::CreateOperator(CreateOperator {
+        Statement::CreateOperator(Box::new(CreateOperator {
             name: ObjectName::from(vec![Ident::new("myschema"), Ident::new("@@")]),
             function: ObjectName::from(vec![Ident::new("myschema"), Ident::new("my_proc")]),
             is_procedure: true,
@@ -7074,7 +7371,7 @@ fn parse_create_operator() {
             ])),
             hashes: true,
             merges: true,
-        })
+        }))
     );
 
     for op_symbol in &[
@@ -7083,7 +7380,7 @@ fn parse_create_operator() {
     ] {
         assert_eq!(
             pg().verified_stmt(&format!("CREATE OPERATOR {op_symbol} (FUNCTION = f)")),
-            Statement::CreateOperator(CreateOperator {
+            Statement::CreateOperator(Box::new(CreateOperator {
                 name: ObjectName::from(vec![Ident::new(*op_symbol)]),
                 function: ObjectName::from(vec![Ident::new("f")]),
                 is_procedure: false,
@@ -7095,7 +7392,7 @@ fn parse_create_operator() {
                 join: None,
                 hashes: false,
                 merges: false,
-            })
+            }))
         );
     }
 
@@ -7119,12 +7416,16 @@ fn parse_create_operator() {
         ),
     ] {
         match pg().verified_stmt(&format!("CREATE OPERATOR {name} (FUNCTION = f)")) {
-            Statement::CreateOperator(CreateOperator {
-                name,
-                hashes: false,
-                merges: false,
-                ..
-            }) => {
+            Statement::CreateOperator(create_operator) => {
+                let CreateOperator {
+                    name,
+                    hashes: false,
+                    merges: false,
+                    ..
+                } = Box::into_owned(create_operator)
+                else {
+                    unreachable!()
+                };
                 assert_eq!(name, expected_name);
             }
             _ => unreachable!(),
@@ -7207,14 +7508,15 @@ fn parse_create_operator_class() {
                     "CREATE OPERATOR CLASS {class_name} {default_clause}FOR TYPE INT4 USING btree{family_clause} AS OPERATOR 1 <"
                 );
                 match pg().verified_stmt(&sql) {
-                    Statement::CreateOperatorClass(CreateOperatorClass {
-                        name,
-                        default,
-                        ref for_type,
-                        ref using,
-                        ref family,
-                        ref items,
-                    }) => {
+                    Statement::CreateOperatorClass(create_operator_class) => {
+                        let CreateOperatorClass {
+                            name,
+                            default,
+                            ref for_type,
+                            ref using,
+                            ref family,
+                            ref items,
+                        } = Box::into_owned(create_operator_class);
                         assert_eq!(name, expected_name);
                         assert_eq!(default, is_default);
                         assert_eq!(for_type, &DataType::Int4(None));
@@ -7240,14 +7542,17 @@ fn parse_create_operator_class() {
         "CREATE OPERATOR CLASS CAS_btree_ops DEFAULT FOR TYPE CAS USING btree FAMILY CAS_btree_ops AS OPERATOR 1 <, OPERATOR 2 <=, OPERATOR 3 =, OPERATOR 4 >=, OPERATOR 5 >, FUNCTION 1 cas_cmp(CAS, CAS)",
         "CREATE OPERATOR CLASS cas_btree_ops DEFAULT FOR TYPE cas USING btree FAMILY cas_btree_ops AS OPERATOR 1 <, OPERATOR 2 <=, OPERATOR 3 =, OPERATOR 4 >=, OPERATOR 5 >, FUNCTION 1 cas_cmp(cas, cas)",
     ) {
-        Statement::CreateOperatorClass(CreateOperatorClass {
+        Statement::CreateOperatorClass(create_operator_class) => {
+            let CreateOperatorClass {
             name,
             default: true,
             ref for_type,
             ref using,
             ref family,
             ref items,
-        }) => {
+        } = Box::into_owned(create_operator_class) else {
+ unreachable!()
+ };
             assert_eq!(name, ObjectName::from(vec![Ident::new("cas_btree_ops")]));
             assert_eq!(for_type, &DataType::Custom(ObjectName::from(vec![Ident::new("cas")]), vec![]));
             assert_eq!(using, &Ident::new("btree"));
@@ -7261,7 +7566,8 @@ fn parse_create_operator_class() {
     match pg().verified_stmt(
         "CREATE OPERATOR CLASS test_ops FOR TYPE INT4 USING gist AS OPERATOR 1 < (INT4, INT4)",
     ) {
-        Statement::CreateOperatorClass(CreateOperatorClass { ref items, .. }) => {
+        Statement::CreateOperatorClass(create_operator_class) => {
+            let CreateOperatorClass { ref items, .. } = Box::into_owned(create_operator_class);
             assert_eq!(items.len(), 1);
             match &items[0] {
                 OperatorClassItem::Operator {
@@ -7286,7 +7592,8 @@ fn parse_create_operator_class() {
     match pg().verified_stmt(
         "CREATE OPERATOR CLASS test_ops FOR TYPE INT4 USING gist AS OPERATOR 1 < FOR SEARCH",
     ) {
-        Statement::CreateOperatorClass(CreateOperatorClass { ref items, .. }) => {
+        Statement::CreateOperatorClass(create_operator_class) => {
+            let CreateOperatorClass { ref items, .. } = Box::into_owned(create_operator_class);
             assert_eq!(items.len(), 1);
             match &items[0] {
                 OperatorClassItem::Operator {
@@ -7305,10 +7612,11 @@ fn parse_create_operator_class() {
 
     // Test operator FOR ORDER BY
     match pg().verified_stmt("CREATE OPERATOR CLASS test_ops FOR TYPE INT4 USING gist AS OPERATOR 2 <<-> FOR ORDER BY float_ops") {
-        Statement::CreateOperatorClass(CreateOperatorClass {
+        Statement::CreateOperatorClass(create_operator_class) => {
+            let CreateOperatorClass {
             ref items,
             ..
-        }) => {
+        } = Box::into_owned(create_operator_class);
             assert_eq!(items.len(), 1);
             match &items[0] {
                 OperatorClassItem::Operator {
@@ -7328,10 +7636,11 @@ fn parse_create_operator_class() {
 
     // Test function with operator class arg types
     match pg().verified_stmt("CREATE OPERATOR CLASS test_ops FOR TYPE INT4 USING btree AS FUNCTION 1 (INT4, INT4) btcmp(INT4, INT4)") {
-        Statement::CreateOperatorClass(CreateOperatorClass {
+        Statement::CreateOperatorClass(create_operator_class) => {
+            let CreateOperatorClass {
             ref items,
             ..
-        }) => {
+        } = Box::into_owned(create_operator_class);
             assert_eq!(items.len(), 1);
             match &items[0] {
                 OperatorClassItem::Function {
@@ -7357,7 +7666,8 @@ fn parse_create_operator_class() {
     match pg().verified_stmt(
         "CREATE OPERATOR CLASS test_ops FOR TYPE INT4 USING btree AS FUNCTION 1 my_func",
     ) {
-        Statement::CreateOperatorClass(CreateOperatorClass { ref items, .. }) => {
+        Statement::CreateOperatorClass(create_operator_class) => {
+            let CreateOperatorClass { ref items, .. } = Box::into_owned(create_operator_class);
             assert_eq!(items.len(), 1);
             match &items[0] {
                 OperatorClassItem::Function {
@@ -7380,10 +7690,11 @@ fn parse_create_operator_class() {
 
     // Test multiple items including STORAGE
     match pg().verified_stmt("CREATE OPERATOR CLASS gist_ops FOR TYPE geometry USING gist AS OPERATOR 1 <<, FUNCTION 1 gist_consistent(internal, geometry, INT4), STORAGE box") {
-        Statement::CreateOperatorClass(CreateOperatorClass {
+        Statement::CreateOperatorClass(create_operator_class) => {
+            let CreateOperatorClass {
             ref items,
             ..
-        }) => {
+        } = Box::into_owned(create_operator_class);
             assert_eq!(items.len(), 3);
             // Check operator item
             match &items[0] {
@@ -7438,7 +7749,7 @@ fn parse_where_current_of() {
             assert_eq!(
                 update.selection,
                 Some(Expr::CurrentOf {
-                    cursor_name: Ident::new("my_cursor"),
+                    cursor_name: Box::new(Ident::new("my_cursor")),
                 })
             );
         }
@@ -7453,7 +7764,7 @@ fn parse_where_current_of() {
             assert_eq!(
                 delete.selection,
                 Some(Expr::CurrentOf {
-                    cursor_name: Ident::new("my_cursor"),
+                    cursor_name: Box::new(Ident::new("my_cursor")),
                 })
             );
         }
@@ -7708,12 +8019,13 @@ fn parse_create_subscription() {
         "CREATE SUBSCRIPTION my_sub CONNECTION 'host=localhost dbname=mydb' PUBLICATION pub1, pub2";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateSubscription {
-            name,
-            connection_string,
-            publications,
-            with_options,
-        } => {
+        Statement::CreateSubscription(create_subscription) => {
+            let CreateSubscription {
+                name,
+                connection_string,
+                publications,
+                with_options,
+            } = Box::into_owned(create_subscription);
             assert_eq!(name, Ident::new("my_sub"));
             assert_eq!(connection_string, "host=localhost dbname=mydb");
             assert_eq!(publications.len(), 2);
@@ -7814,7 +8126,7 @@ fn parse_create_rule() {
             assert!(matches!(event, RuleEvent::Insert));
             assert_eq!(table_name.to_string(), "mytable");
             assert!(condition.is_none());
-            assert!(matches!(action, RuleAction::Do(RuleCommands::Nothing)));
+            assert!(matches!(*action, RuleAction::Do(RuleCommands::Nothing)));
         }
         _ => panic!("Expected CreateRule statement"),
     }
@@ -7831,7 +8143,10 @@ fn parse_create_rule() {
         } => {
             assert!(or_replace);
             assert!(matches!(event, RuleEvent::Update));
-            assert!(matches!(action, RuleAction::Instead(RuleCommands::Nothing)));
+            assert!(matches!(
+                *action,
+                RuleAction::Instead(RuleCommands::Nothing)
+            ));
         }
         _ => panic!("Expected CreateRule statement"),
     }
@@ -7853,10 +8168,10 @@ fn parse_create_rule() {
     let sql = "CREATE RULE multi AS ON INSERT TO mytable DO ALSO (INSERT INTO log1 VALUES (1); INSERT INTO log2 VALUES (2))";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateRule {
-            action: RuleAction::Also(RuleCommands::Commands(cmds)),
-            ..
-        } => {
+        Statement::CreateRule { action, .. } => {
+            let RuleAction::Also(RuleCommands::Commands(cmds)) = Box::into_owned(action) else {
+                panic!("Expected CreateRule with multiple commands");
+            };
             assert_eq!(cmds.len(), 2);
         }
         _ => panic!("Expected CreateRule with multiple commands"),
@@ -8141,7 +8456,10 @@ fn parse_bare_interval_identifier_as_column_reference() {
     let select = pg().verified_only_select("SELECT interval FROM temp_table");
     assert_eq!(
         select.projection[0],
-        SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("interval")))
+        SelectItem::UnnamedExpr {
+            expr: Expr::Identifier(Box::new(Ident::new("interval"))),
+            comments: Comments::default(),
+        }
     );
 }
 
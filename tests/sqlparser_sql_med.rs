@@ -24,6 +24,7 @@
 //! - USER MAPPING (CREATE, ALTER, DROP)
 //! - IMPORT FOREIGN SCHEMA
 
+use sqlparser::ast::AstBox as Box;
 use sqlparser::ast::*;
 use sqlparser::dialect::PostgreSqlDialect;
 use sqlparser::parser::Parser;
@@ -45,14 +46,15 @@ fn parse_create_foreign_data_wrapper_basic() {
     let sql = "CREATE FOREIGN DATA WRAPPER postgres_fdw";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateForeignDataWrapper(CreateForeignDataWrapperStatement {
-            name,
-            if_not_exists,
-            handler,
-            validator,
-            options,
-            ..
-        }) => {
+        Statement::CreateForeignDataWrapper(create_foreign_data_wrapper) => {
+            let CreateForeignDataWrapperStatement {
+                name,
+                if_not_exists,
+                handler,
+                validator,
+                options,
+                ..
+            } = Box::into_owned(create_foreign_data_wrapper);
             assert_eq!(name.to_string(), "postgres_fdw");
             assert!(!if_not_exists);
             assert!(handler.is_none());
@@ -68,11 +70,12 @@ fn parse_create_foreign_data_wrapper_if_not_exists() {
     let sql = "CREATE FOREIGN DATA WRAPPER IF NOT EXISTS my_fdw";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateForeignDataWrapper(CreateForeignDataWrapperStatement {
-            name,
-            if_not_exists,
-            ..
-        }) => {
+        Statement::CreateForeignDataWrapper(create_foreign_data_wrapper) => {
+            let CreateForeignDataWrapperStatement {
+                name,
+                if_not_exists,
+                ..
+            } = Box::into_owned(create_foreign_data_wrapper);
             assert_eq!(name.to_string(), "my_fdw");
             assert!(if_not_exists);
         }
@@ -85,11 +88,9 @@ fn parse_create_foreign_data_wrapper_with_handler() {
     let sql = "CREATE FOREIGN DATA WRAPPER postgres_fdw HANDLER postgres_fdw_handler";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateForeignDataWrapper(CreateForeignDataWrapperStatement {
-            name,
-            handler,
-            ..
-        }) => {
+        Statement::CreateForeignDataWrapper(create_foreign_data_wrapper) => {
+            let CreateForeignDataWrapperStatement { name, handler, .. } =
+                Box::into_owned(create_foreign_data_wrapper);
             assert_eq!(name.to_string(), "postgres_fdw");
             assert_eq!(handler.unwrap().to_string(), "postgres_fdw_handler");
         }
@@ -102,11 +103,9 @@ fn parse_create_foreign_data_wrapper_no_handler() {
     let sql = "CREATE FOREIGN DATA WRAPPER file_fdw NO HANDLER";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateForeignDataWrapper(CreateForeignDataWrapperStatement {
-            name,
-            handler,
-            ..
-        }) => {
+        Statement::CreateForeignDataWrapper(create_foreign_data_wrapper) => {
+            let CreateForeignDataWrapperStatement { name, handler, .. } =
+                Box::into_owned(create_foreign_data_wrapper);
             assert_eq!(name.to_string(), "file_fdw");
             // NO HANDLER is represented as None with a special flag
             assert!(handler.is_none());
@@ -120,10 +119,9 @@ fn parse_create_foreign_data_wrapper_with_validator() {
     let sql = "CREATE FOREIGN DATA WRAPPER postgres_fdw VALIDATOR postgres_fdw_validator";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateForeignDataWrapper(CreateForeignDataWrapperStatement {
-            validator,
-            ..
-        }) => {
+        Statement::CreateForeignDataWrapper(create_foreign_data_wrapper) => {
+            let CreateForeignDataWrapperStatement { validator, .. } =
+                Box::into_owned(create_foreign_data_wrapper);
             assert_eq!(validator.unwrap().to_string(), "postgres_fdw_validator");
         }
         _ => unreachable!(),
@@ -135,11 +133,10 @@ fn parse_create_foreign_data_wrapper_no_validator() {
     let sql = "CREATE FOREIGN DATA WRAPPER file_fdw NO VALIDATOR";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateForeignDataWrapper(CreateForeignDataWrapperStatement {
-            name,
-            validator,
-            ..
-        }) => {
+        Statement::CreateForeignDataWrapper(create_foreign_data_wrapper) => {
+            let CreateForeignDataWrapperStatement {
+                name, validator, ..
+            } = Box::into_owned(create_foreign_data_wrapper);
             assert_eq!(name.to_string(), "file_fdw");
             assert!(validator.is_none());
         }
@@ -152,13 +149,14 @@ fn parse_create_foreign_data_wrapper_full() {
     let sql = "CREATE FOREIGN DATA WRAPPER postgres_fdw HANDLER postgres_fdw_handler VALIDATOR postgres_fdw_validator OPTIONS (debug 'true', timeout '30')";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateForeignDataWrapper(CreateForeignDataWrapperStatement {
-            name,
-            handler,
-            validator,
-            options,
-            ..
-        }) => {
+        Statement::CreateForeignDataWrapper(create_foreign_data_wrapper) => {
+            let CreateForeignDataWrapperStatement {
+                name,
+                handler,
+                validator,
+                options,
+                ..
+            } = Box::into_owned(create_foreign_data_wrapper);
             assert_eq!(name.to_string(), "postgres_fdw");
             assert_eq!(handler.unwrap().to_string(), "postgres_fdw_handler");
             assert_eq!(validator.unwrap().to_string(), "postgres_fdw_validator");
@@ -176,9 +174,9 @@ fn parse_create_foreign_data_wrapper_schema_qualified_handler() {
     let sql = "CREATE FOREIGN DATA WRAPPER my_fdw HANDLER public.my_handler";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateForeignDataWrapper(CreateForeignDataWrapperStatement {
-            handler, ..
-        }) => {
+        Statement::CreateForeignDataWrapper(create_foreign_data_wrapper) => {
+            let CreateForeignDataWrapperStatement { handler, .. } =
+                Box::into_owned(create_foreign_data_wrapper);
             assert_eq!(handler.unwrap().to_string(), "public.my_handler");
         }
         _ => unreachable!(),
@@ -433,7 +431,8 @@ fn parse_create_server_basic() {
     let sql = "CREATE SERVER myserver FOREIGN DATA WRAPPER postgres_fdw";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateServer(CreateServerStatement { name, .. }) => {
+        Statement::CreateServer(create_server) => {
+            let CreateServerStatement { name, .. } = AstBox::into_owned(create_server);
             assert_eq!(name.to_string(), "myserver");
         }
         _ => unreachable!(),
@@ -637,14 +636,15 @@ fn parse_create_foreign_table_basic() {
     let sql = "CREATE FOREIGN TABLE remote_users (id INT, name VARCHAR(100)) SERVER myserver";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateForeignTable(CreateForeignTableStatement {
-            name,
-            columns,
-            server,
-            if_not_exists,
-            options,
-            ..
-        }) => {
+        Statement::CreateForeignTable(create_foreign_table) => {
+            let CreateForeignTableStatement {
+                name,
+                columns,
+                server,
+                if_not_exists,
+                options,
+                ..
+            } = AstBox::into_owned(create_foreign_table);
             assert_eq!(name.to_string(), "remote_users");
             assert_eq!(columns.len(), 2);
             assert_eq!(server.to_string(), "myserver");
@@ -660,7 +660,9 @@ fn parse_create_foreign_table_if_not_exists() {
     let sql = "CREATE FOREIGN TABLE IF NOT EXISTS remote_users (id INT) SERVER myserver";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateForeignTable(CreateForeignTableStatement { if_not_exists, .. }) => {
+        Statement::CreateForeignTable(create_foreign_table) => {
+            let CreateForeignTableStatement { if_not_exists, .. } =
+                AstBox::into_owned(create_foreign_table);
             assert!(if_not_exists);
         }
         _ => unreachable!(),
@@ -672,7 +674,9 @@ fn parse_create_foreign_table_with_options() {
     let sql = "CREATE FOREIGN TABLE remote_users (id INT) SERVER myserver OPTIONS (schema_name 'public', table_name 'users')";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateForeignTable(CreateForeignTableStatement { options, .. }) => {
+        Statement::CreateForeignTable(create_foreign_table) => {
+            let CreateForeignTableStatement { options, .. } =
+                AstBox::into_owned(create_foreign_table);
             let opts = options.unwrap();
             assert_eq!(opts.len(), 2);
         }
@@ -687,7 +691,9 @@ fn parse_create_foreign_table_with_column_options() {
     let sql = "CREATE FOREIGN TABLE remote_users (id INT, name VARCHAR(100)) SERVER myserver";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateForeignTable(CreateForeignTableStatement { columns, .. }) => {
+        Statement::CreateForeignTable(create_foreign_table) => {
+            let CreateForeignTableStatement { columns, .. } =
+                AstBox::into_owned(create_foreign_table);
             assert_eq!(columns.len(), 2);
         }
         _ => unreachable!(),
@@ -700,7 +706,9 @@ fn parse_create_foreign_table_with_constraints() {
         "CREATE FOREIGN TABLE remote_users (id INT NOT NULL, name VARCHAR(100)) SERVER myserver";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateForeignTable(CreateForeignTableStatement { columns, .. }) => {
+        Statement::CreateForeignTable(create_foreign_table) => {
+            let CreateForeignTableStatement { columns, .. } =
+                AstBox::into_owned(create_foreign_table);
             assert_eq!(columns.len(), 2);
         }
         _ => unreachable!(),
@@ -712,11 +720,12 @@ fn parse_create_foreign_table_partitioned() {
     let sql = "CREATE FOREIGN TABLE remote_logs PARTITION OF parent_logs FOR VALUES FROM ('2020-01-01') TO ('2021-01-01') SERVER myserver";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateForeignTable(CreateForeignTableStatement {
-            partition_of,
-            partition_bound,
-            ..
-        }) => {
+        Statement::CreateForeignTable(create_foreign_table) => {
+            let CreateForeignTableStatement {
+                partition_of,
+                partition_bound,
+                ..
+            } = AstBox::into_owned(create_foreign_table);
             assert!(partition_of.is_some());
             assert!(partition_bound.is_some());
         }
@@ -894,13 +903,14 @@ fn parse_create_user_mapping_basic() {
     let sql = "CREATE USER MAPPING FOR bob SERVER myserver";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateUserMapping(CreateUserMappingStatement {
-            user,
-            server,
-            if_not_exists,
-            options,
-            ..
-        }) => {
+        Statement::CreateUserMapping(create_user_mapping) => {
+            let CreateUserMappingStatement {
+                user,
+                server,
+                if_not_exists,
+                options,
+                ..
+            } = AstBox::into_owned(create_user_mapping);
             match user {
                 UserMappingUser::User(u) => assert_eq!(u.to_string(), "bob"),
                 _ => unreachable!(),
@@ -918,7 +928,9 @@ fn parse_create_user_mapping_if_not_exists() {
     let sql = "CREATE USER MAPPING IF NOT EXISTS FOR bob SERVER myserver";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateUserMapping(CreateUserMappingStatement { if_not_exists, .. }) => {
+        Statement::CreateUserMapping(create_user_mapping) => {
+            let CreateUserMappingStatement { if_not_exists, .. } =
+                AstBox::into_owned(create_user_mapping);
             assert!(if_not_exists);
         }
         _ => unreachable!(),
@@ -930,10 +942,13 @@ fn parse_create_user_mapping_current_user() {
     let sql = "CREATE USER MAPPING FOR CURRENT_USER SERVER myserver";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateUserMapping(CreateUserMappingStatement { user, .. }) => match user {
-            UserMappingUser::CurrentUser => {}
-            _ => unreachable!(),
-        },
+        Statement::CreateUserMapping(create_user_mapping) => {
+            let CreateUserMappingStatement { user, .. } = AstBox::into_owned(create_user_mapping);
+            match user {
+                UserMappingUser::CurrentUser => {}
+                _ => unreachable!(),
+            }
+        }
         _ => unreachable!(),
     }
 }
@@ -943,10 +958,13 @@ fn parse_create_user_mapping_current_role() {
     let sql = "CREATE USER MAPPING FOR CURRENT_ROLE SERVER myserver";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateUserMapping(CreateUserMappingStatement { user, .. }) => match user {
-            UserMappingUser::CurrentRole => {}
-            _ => unreachable!(),
-        },
+        Statement::CreateUserMapping(create_user_mapping) => {
+            let CreateUserMappingStatement { user, .. } = AstBox::into_owned(create_user_mapping);
+            match user {
+                UserMappingUser::CurrentRole => {}
+                _ => unreachable!(),
+            }
+        }
         _ => unreachable!(),
     }
 }
@@ -956,10 +974,13 @@ fn parse_create_user_mapping_user_keyword() {
     let sql = "CREATE USER MAPPING FOR USER SERVER myserver";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateUserMapping(CreateUserMappingStatement { user, .. }) => match user {
-            UserMappingUser::UserKeyword => {}
-            _ => unreachable!(),
-        },
+        Statement::CreateUserMapping(create_user_mapping) => {
+            let CreateUserMappingStatement { user, .. } = AstBox::into_owned(create_user_mapping);
+            match user {
+                UserMappingUser::UserKeyword => {}
+                _ => unreachable!(),
+            }
+        }
         _ => unreachable!(),
     }
 }
@@ -969,10 +990,13 @@ fn parse_create_user_mapping_public() {
     let sql = "CREATE USER MAPPING FOR PUBLIC SERVER myserver";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateUserMapping(CreateUserMappingStatement { user, .. }) => match user {
-            UserMappingUser::Public => {}
-            _ => unreachable!(),
-        },
+        Statement::CreateUserMapping(create_user_mapping) => {
+            let CreateUserMappingStatement { user, .. } = AstBox::into_owned(create_user_mapping);
+            match user {
+                UserMappingUser::Public => {}
+                _ => unreachable!(),
+            }
+        }
         _ => unreachable!(),
     }
 }
@@ -983,7 +1007,9 @@ fn parse_create_user_mapping_with_options() {
         "CREATE USER MAPPING FOR bob SERVER myserver OPTIONS (user 'remote_bob', password 'secret')";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateUserMapping(CreateUserMappingStatement { options, .. }) => {
+        Statement::CreateUserMapping(create_user_mapping) => {
+            let CreateUserMappingStatement { options, .. } =
+                AstBox::into_owned(create_user_mapping);
             let opts = options.unwrap();
             assert_eq!(opts.len(), 2);
         }
@@ -996,12 +1022,13 @@ fn parse_alter_user_mapping_options() {
     let sql = "ALTER USER MAPPING FOR bob SERVER myserver OPTIONS (SET password 'newsecret')";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::AlterUserMapping(AlterUserMappingStatement {
-            user,
-            server,
-            options,
-            ..
-        }) => {
+        Statement::AlterUserMapping(alter_user_mapping) => {
+            let AlterUserMappingStatement {
+                user,
+                server,
+                options,
+                ..
+            } = AstBox::into_owned(alter_user_mapping);
             match user {
                 UserMappingUser::User(u) => assert_eq!(u.to_string(), "bob"),
                 _ => unreachable!(),
@@ -1018,7 +1045,8 @@ fn parse_alter_user_mapping_add_option() {
     let sql = "ALTER USER MAPPING FOR bob SERVER myserver OPTIONS (ADD sslmode 'require')";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::AlterUserMapping(AlterUserMappingStatement { options, .. }) => {
+        Statement::AlterUserMapping(alter_user_mapping) => {
+            let AlterUserMappingStatement { options, .. } = AstBox::into_owned(alter_user_mapping);
             assert_eq!(options.len(), 1);
         }
         _ => unreachable!(),
@@ -1030,7 +1058,8 @@ fn parse_alter_user_mapping_drop_option() {
     let sql = "ALTER USER MAPPING FOR bob SERVER myserver OPTIONS (DROP password)";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::AlterUserMapping(AlterUserMappingStatement { options, .. }) => {
+        Statement::AlterUserMapping(alter_user_mapping) => {
+            let AlterUserMappingStatement { options, .. } = AstBox::into_owned(alter_user_mapping);
             assert_eq!(options.len(), 1);
         }
         _ => unreachable!(),
@@ -1042,10 +1071,13 @@ fn parse_alter_user_mapping_current_user() {
     let sql = "ALTER USER MAPPING FOR CURRENT_USER SERVER myserver OPTIONS (SET password 'new')";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::AlterUserMapping(AlterUserMappingStatement { user, .. }) => match user {
-            UserMappingUser::CurrentUser => {}
-            _ => unreachable!(),
-        },
+        Statement::AlterUserMapping(alter_user_mapping) => {
+            let AlterUserMappingStatement { user, .. } = AstBox::into_owned(alter_user_mapping);
+            match user {
+                UserMappingUser::CurrentUser => {}
+                _ => unreachable!(),
+            }
+        }
         _ => unreachable!(),
     }
 }
@@ -1055,12 +1087,13 @@ fn parse_drop_user_mapping() {
     let sql = "DROP USER MAPPING FOR bob SERVER myserver";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::DropUserMapping(DropUserMappingStatement {
-            user,
-            server,
-            if_exists,
-            ..
-        }) => {
+        Statement::DropUserMapping(drop_user_mapping) => {
+            let DropUserMappingStatement {
+                user,
+                server,
+                if_exists,
+                ..
+            } = Box::into_owned(drop_user_mapping);
             match user {
                 UserMappingUser::User(u) => assert_eq!(u.to_string(), "bob"),
                 _ => unreachable!(),
@@ -1077,7 +1110,8 @@ fn parse_drop_user_mapping_if_exists() {
     let sql = "DROP USER MAPPING IF EXISTS FOR bob SERVER myserver";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::DropUserMapping(DropUserMappingStatement { if_exists, .. }) => {
+        Statement::DropUserMapping(drop_user_mapping) => {
+            let DropUserMappingStatement { if_exists, .. } = Box::into_owned(drop_user_mapping);
             assert!(if_exists);
         }
         _ => unreachable!(),
@@ -1089,10 +1123,13 @@ fn parse_drop_user_mapping_public() {
     let sql = "DROP USER MAPPING FOR PUBLIC SERVER myserver";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::DropUserMapping(DropUserMappingStatement { user, .. }) => match user {
-            UserMappingUser::Public => {}
-            _ => unreachable!(),
-        },
+        Statement::DropUserMapping(drop_user_mapping) => {
+            let DropUserMappingStatement { user, .. } = Box::into_owned(drop_user_mapping);
+            match user {
+                UserMappingUser::Public => {}
+                _ => unreachable!(),
+            }
+        }
         _ => unreachable!(),
     }
 }
@@ -1106,15 +1143,16 @@ fn parse_import_foreign_schema_basic() {
     let sql = "IMPORT FOREIGN SCHEMA remote_schema FROM SERVER myserver INTO local_schema";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::ImportForeignSchema(ImportForeignSchemaStatement {
-            remote_schema,
-            server,
-            local_schema,
-            limit_type,
-            tables,
-            options,
-            ..
-        }) => {
+        Statement::ImportForeignSchema(import_foreign_schema) => {
+            let ImportForeignSchemaStatement {
+                remote_schema,
+                server,
+                local_schema,
+                limit_type,
+                tables,
+                options,
+                ..
+            } = AstBox::into_owned(import_foreign_schema);
             assert_eq!(remote_schema.to_string(), "remote_schema");
             assert_eq!(server.to_string(), "myserver");
             assert_eq!(local_schema.to_string(), "local_schema");
@@ -1131,9 +1169,10 @@ fn parse_import_foreign_schema_limit_to() {
     let sql = "IMPORT FOREIGN SCHEMA remote_schema LIMIT TO (users, orders) FROM SERVER myserver INTO local_schema";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::ImportForeignSchema(ImportForeignSchemaStatement {
-            limit_type, tables, ..
-        }) => {
+        Statement::ImportForeignSchema(import_foreign_schema) => {
+            let ImportForeignSchemaStatement {
+                limit_type, tables, ..
+            } = AstBox::into_owned(import_foreign_schema);
             assert_eq!(limit_type, Some(ImportForeignSchemaLimitType::LimitTo));
             assert_eq!(tables.len(), 2);
         }
@@ -1146,9 +1185,10 @@ fn parse_import_foreign_schema_except() {
     let sql = "IMPORT FOREIGN SCHEMA remote_schema EXCEPT (internal_table) FROM SERVER myserver INTO local_schema";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::ImportForeignSchema(ImportForeignSchemaStatement {
-            limit_type, tables, ..
-        }) => {
+        Statement::ImportForeignSchema(import_foreign_schema) => {
+            let ImportForeignSchemaStatement {
+                limit_type, tables, ..
+            } = AstBox::into_owned(import_foreign_schema);
             assert_eq!(limit_type, Some(ImportForeignSchemaLimitType::Except));
             assert_eq!(tables.len(), 1);
         }
@@ -1161,7 +1201,9 @@ fn parse_import_foreign_schema_with_options() {
     let sql = "IMPORT FOREIGN SCHEMA remote_schema FROM SERVER myserver INTO local_schema OPTIONS (import_collate 'false', import_default 'true')";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::ImportForeignSchema(ImportForeignSchemaStatement { options, .. }) => {
+        Statement::ImportForeignSchema(import_foreign_schema) => {
+            let ImportForeignSchemaStatement { options, .. } =
+                AstBox::into_owned(import_foreign_schema);
             let opts = options.unwrap();
             assert_eq!(opts.len(), 2);
         }
@@ -1174,15 +1216,16 @@ fn parse_import_foreign_schema_full() {
     let sql = "IMPORT FOREIGN SCHEMA remote_schema LIMIT TO (users, orders, products) FROM SERVER myserver INTO public OPTIONS (import_collate 'false')";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::ImportForeignSchema(ImportForeignSchemaStatement {
-            remote_schema,
-            limit_type,
-            tables,
-            server,
-            local_schema,
-            options,
-            ..
-        }) => {
+        Statement::ImportForeignSchema(import_foreign_schema) => {
+            let ImportForeignSchemaStatement {
+                remote_schema,
+                limit_type,
+                tables,
+                server,
+                local_schema,
+                options,
+                ..
+            } = AstBox::into_owned(import_foreign_schema);
             assert_eq!(remote_schema.to_string(), "remote_schema");
             assert_eq!(limit_type, Some(ImportForeignSchemaLimitType::LimitTo));
             assert_eq!(tables.len(), 3);
@@ -1203,7 +1246,9 @@ fn parse_foreign_data_wrapper_quoted_name() {
     let sql = r#"CREATE FOREIGN DATA WRAPPER "my-fdw""#;
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateForeignDataWrapper(CreateForeignDataWrapperStatement { name, .. }) => {
+        Statement::CreateForeignDataWrapper(create_foreign_data_wrapper) => {
+            let CreateForeignDataWrapperStatement { name, .. } =
+                Box::into_owned(create_foreign_data_wrapper);
             assert_eq!(name.to_string(), "\"my-fdw\"");
         }
         _ => unreachable!(),
@@ -1215,10 +1260,11 @@ fn parse_server_with_schema_qualified_fdw() {
     let sql = "CREATE SERVER myserver FOREIGN DATA WRAPPER public.postgres_fdw";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateServer(CreateServerStatement {
-            foreign_data_wrapper,
-            ..
-        }) => {
+        Statement::CreateServer(create_server) => {
+            let CreateServerStatement {
+                foreign_data_wrapper,
+                ..
+            } = AstBox::into_owned(create_server);
             assert_eq!(foreign_data_wrapper.to_string(), "public.postgres_fdw");
         }
         _ => unreachable!(),
@@ -1230,7 +1276,9 @@ fn parse_foreign_table_with_all_pg_types() {
     let sql = "CREATE FOREIGN TABLE test_types (col_int INT, col_bigint BIGINT, col_text TEXT, col_varchar VARCHAR(255), col_bool BOOLEAN, col_date DATE, col_timestamp TIMESTAMP, col_numeric NUMERIC(10,2), col_json JSON, col_jsonb JSONB, col_uuid UUID, col_array INT[]) SERVER myserver";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateForeignTable(CreateForeignTableStatement { columns, .. }) => {
+        Statement::CreateForeignTable(create_foreign_table) => {
+            let CreateForeignTableStatement { columns, .. } =
+                AstBox::into_owned(create_foreign_table);
             assert_eq!(columns.len(), 12);
         }
         _ => unreachable!(),
@@ -1242,10 +1290,13 @@ fn parse_user_mapping_quoted_username() {
     let sql = r#"CREATE USER MAPPING FOR "special-user" SERVER myserver"#;
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateUserMapping(CreateUserMappingStatement { user, .. }) => match user {
-            UserMappingUser::User(u) => assert_eq!(u.to_string(), "\"special-user\""),
-            _ => unreachable!(),
-        },
+        Statement::CreateUserMapping(create_user_mapping) => {
+            let CreateUserMappingStatement { user, .. } = AstBox::into_owned(create_user_mapping);
+            match user {
+                UserMappingUser::User(u) => assert_eq!(u.to_string(), "\"special-user\""),
+                _ => unreachable!(),
+            }
+        }
         _ => unreachable!(),
     }
 }
@@ -1255,7 +1306,8 @@ fn parse_options_with_empty_string() {
     let sql = "CREATE SERVER myserver FOREIGN DATA WRAPPER postgres_fdw OPTIONS (password '')";
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateServer(CreateServerStatement { options, .. }) => {
+        Statement::CreateServer(create_server) => {
+            let CreateServerStatement { options, .. } = AstBox::into_owned(create_server);
             let opts = options.unwrap();
             assert_eq!(opts.len(), 1);
             assert_eq!(opts[0].value.value, "");
@@ -1269,7 +1321,8 @@ fn parse_options_with_special_characters() {
     let sql = r#"CREATE SERVER myserver FOREIGN DATA WRAPPER postgres_fdw OPTIONS (connstr 'host=db.example.com port=5432 dbname=mydb')"#;
     let stmt = pg_and_generic().verified_stmt(sql);
     match stmt {
-        Statement::CreateServer(CreateServerStatement { options, .. }) => {
+        Statement::CreateServer(create_server) => {
+            let CreateServerStatement { options, .. } = AstBox::into_owned(create_server);
             let opts = options.unwrap();
             assert_eq!(opts.len(), 1);
             assert!(opts[0].value.value.contains("host=db.example.com"));
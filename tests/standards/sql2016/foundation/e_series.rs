@@ -43,10 +43,10 @@
 
 use crate::standards::common::{one_statement_parses_to_std, verified_standard_stmt};
 use sqlparser::ast::{
-    Action, Assignment, BinaryOperator, CascadeOption, CharacterLength, ColumnOption, CreateTable,
-    DataType, Delete, ExactNumberInfo, Expr, GrantObjects, GroupByExpr, Insert, Privileges,
-    SelectItem, SetExpr, SetOperator, Statement, TableConstraint, TableFactor, TableObject, Update,
-    Value, ValueWithSpan, Values,
+    Action, Assignment, AstBox, BinaryOperator, CascadeOption, CharacterLength, ColumnOption,
+    CreateTable, DataType, Delete, ExactNumberInfo, Expr, GrantObjects, GroupByExpr, Insert,
+    Privileges, SelectItem, SetExpr, SetOperator, Statement, TableConstraint, TableFactor,
+    TableObject, Update, Value, Values,
 };
 
 // =============================================================================
@@ -62,7 +62,8 @@ mod e011_numeric_data_types {
         verified_with_ast!(
             "CREATE TABLE t (a INTEGER, b SMALLINT)",
             |stmt: Statement| {
-                if let Statement::CreateTable(CreateTable { columns, .. }) = stmt {
+                if let Statement::CreateTable(create_table) = stmt {
+                    let CreateTable { columns, .. } = AstBox::into_owned(create_table);
                     assert_eq!(columns.len(), 2);
                     assert_eq!(columns[0].name.to_string(), "a");
                     assert!(matches!(columns[0].data_type, DataType::Integer(_)));
@@ -75,7 +76,8 @@ mod e011_numeric_data_types {
         );
 
         verified_with_ast!("CREATE TABLE t (id INT PRIMARY KEY)", |stmt: Statement| {
-            if let Statement::CreateTable(CreateTable { columns, .. }) = stmt {
+            if let Statement::CreateTable(create_table) = stmt {
+                let CreateTable { columns, .. } = AstBox::into_owned(create_table);
                 assert_eq!(columns.len(), 1);
                 assert_eq!(columns[0].name.to_string(), "id");
                 assert!(matches!(columns[0].data_type, DataType::Int(_)));
@@ -116,7 +118,8 @@ mod e011_numeric_data_types {
         verified_with_ast!(
             "CREATE TABLE t (a DECIMAL, b NUMERIC)",
             |stmt: Statement| {
-                if let Statement::CreateTable(CreateTable { columns, .. }) = stmt {
+                if let Statement::CreateTable(create_table) = stmt {
+                    let CreateTable { columns, .. } = AstBox::into_owned(create_table);
                     assert_eq!(columns.len(), 2);
                     assert!(matches!(columns[0].data_type, DataType::Decimal(_)));
                     assert!(matches!(columns[1].data_type, DataType::Numeric(_)));
@@ -127,7 +130,8 @@ mod e011_numeric_data_types {
         );
 
         verified_with_ast!("CREATE TABLE t (price DECIMAL(10,2))", |stmt: Statement| {
-            if let Statement::CreateTable(CreateTable { columns, .. }) = stmt {
+            if let Statement::CreateTable(create_table) = stmt {
+                let CreateTable { columns, .. } = AstBox::into_owned(create_table);
                 assert_eq!(columns.len(), 1);
                 if let DataType::Decimal(ExactNumberInfo::PrecisionAndScale(p, s)) =
                     &columns[0].data_type
@@ -145,7 +149,8 @@ mod e011_numeric_data_types {
         verified_with_ast!(
             "CREATE TABLE t (amount NUMERIC(15,4))",
             |stmt: Statement| {
-                if let Statement::CreateTable(CreateTable { columns, .. }) = stmt {
+                if let Statement::CreateTable(create_table) = stmt {
+                    let CreateTable { columns, .. } = AstBox::into_owned(create_table);
                     assert_eq!(columns.len(), 1);
                     if let DataType::Numeric(ExactNumberInfo::PrecisionAndScale(p, s)) =
                         &columns[0].data_type
@@ -179,8 +184,10 @@ mod e011_numeric_data_types {
         verified_with_ast!("SELECT 1 + 2", |stmt: Statement| {
             if let Statement::Query(query) = stmt {
                 if let SetExpr::Select(select) = query.body.as_ref() {
-                    if let SelectItem::UnnamedExpr(Expr::BinaryOp { op, .. }) =
-                        &select.projection[0]
+                    if let SelectItem::UnnamedExpr {
+                        expr: Expr::BinaryOp { op, .. },
+                        ..
+                    } = &select.projection[0]
                     {
                         assert!(matches!(op, BinaryOperator::Plus));
                     } else {
@@ -195,8 +202,10 @@ mod e011_numeric_data_types {
         verified_with_ast!("SELECT 10 - 3", |stmt: Statement| {
             if let Statement::Query(query) = stmt {
                 if let SetExpr::Select(select) = query.body.as_ref() {
-                    if let SelectItem::UnnamedExpr(Expr::BinaryOp { op, .. }) =
-                        &select.projection[0]
+                    if let SelectItem::UnnamedExpr {
+                        expr: Expr::BinaryOp { op, .. },
+                        ..
+                    } = &select.projection[0]
                     {
                         assert!(matches!(op, BinaryOperator::Minus));
                     } else {
@@ -209,8 +218,10 @@ mod e011_numeric_data_types {
         verified_with_ast!("SELECT 4 * 5", |stmt: Statement| {
             if let Statement::Query(query) = stmt {
                 if let SetExpr::Select(select) = query.body.as_ref() {
-                    if let SelectItem::UnnamedExpr(Expr::BinaryOp { op, .. }) =
-                        &select.projection[0]
+                    if let SelectItem::UnnamedExpr {
+                        expr: Expr::BinaryOp { op, .. },
+                        ..
+                    } = &select.projection[0]
                     {
                         assert!(matches!(op, BinaryOperator::Multiply));
                     }
@@ -221,8 +232,10 @@ mod e011_numeric_data_types {
         verified_with_ast!("SELECT 20 / 4", |stmt: Statement| {
             if let Statement::Query(query) = stmt {
                 if let SetExpr::Select(select) = query.body.as_ref() {
-                    if let SelectItem::UnnamedExpr(Expr::BinaryOp { op, .. }) =
-                        &select.projection[0]
+                    if let SelectItem::UnnamedExpr {
+                        expr: Expr::BinaryOp { op, .. },
+                        ..
+                    } = &select.projection[0]
                     {
                         assert!(matches!(op, BinaryOperator::Divide));
                     }
@@ -233,8 +246,10 @@ mod e011_numeric_data_types {
         verified_with_ast!("SELECT 17 % 5", |stmt: Statement| {
             if let Statement::Query(query) = stmt {
                 if let SetExpr::Select(select) = query.body.as_ref() {
-                    if let SelectItem::UnnamedExpr(Expr::BinaryOp { op, .. }) =
-                        &select.projection[0]
+                    if let SelectItem::UnnamedExpr {
+                        expr: Expr::BinaryOp { op, .. },
+                        ..
+                    } = &select.projection[0]
                     {
                         assert!(matches!(op, BinaryOperator::Modulo));
                     }
@@ -297,7 +312,8 @@ mod e021_character_string_types {
     fn e021_01_character_and_char() {
         // SQL:2016 E021-01: CHARACTER(n) and CHAR(n)
         verified_with_ast!("CREATE TABLE t (a CHARACTER(10))", |stmt: Statement| {
-            if let Statement::CreateTable(CreateTable { columns, .. }) = stmt {
+            if let Statement::CreateTable(create_table) = stmt {
+                let CreateTable { columns, .. } = AstBox::into_owned(create_table);
                 assert_eq!(columns.len(), 1);
                 if let DataType::Character(Some(CharacterLength::IntegerLength {
                     length, ..
@@ -313,7 +329,8 @@ mod e021_character_string_types {
         });
 
         verified_with_ast!("CREATE TABLE t (b CHAR(20))", |stmt: Statement| {
-            if let Statement::CreateTable(CreateTable { columns, .. }) = stmt {
+            if let Statement::CreateTable(create_table) = stmt {
+                let CreateTable { columns, .. } = AstBox::into_owned(create_table);
                 assert_eq!(columns.len(), 1);
                 if let DataType::Char(Some(CharacterLength::IntegerLength { length, .. })) =
                     &columns[0].data_type
@@ -922,10 +939,11 @@ mod e051_basic_query_specification {
                 if let Statement::Query(query) = stmt {
                     if let SetExpr::Select(select) = query.body.as_ref() {
                         assert_eq!(select.from.len(), 1);
-                        if let TableFactor::Table { alias, .. } = &select.from[0].relation {
-                            if let Some(ref alias) = alias {
-                                assert_eq!(alias.name.value, "u");
-                            }
+                        if let TableFactor::Table {
+                            alias: Some(alias), ..
+                        } = &select.from[0].relation
+                        {
+                            assert_eq!(alias.name.value, "u");
                         }
                     }
                 }
@@ -938,15 +956,17 @@ mod e051_basic_query_specification {
                 if let Statement::Query(query) = stmt {
                     if let SetExpr::Select(select) = query.body.as_ref() {
                         assert_eq!(select.from.len(), 2);
-                        if let TableFactor::Table { alias, .. } = &select.from[0].relation {
-                            if let Some(ref alias) = alias {
-                                assert_eq!(alias.name.value, "a");
-                            }
+                        if let TableFactor::Table {
+                            alias: Some(alias), ..
+                        } = &select.from[0].relation
+                        {
+                            assert_eq!(alias.name.value, "a");
                         }
-                        if let TableFactor::Table { alias, .. } = &select.from[1].relation {
-                            if let Some(ref alias) = alias {
-                                assert_eq!(alias.name.value, "b");
-                            }
+                        if let TableFactor::Table {
+                            alias: Some(alias), ..
+                        } = &select.from[1].relation
+                        {
+                            assert_eq!(alias.name.value, "b");
                         }
                     }
                 }
@@ -957,10 +977,11 @@ mod e051_basic_query_specification {
         verified_with_ast!("SELECT e.name FROM employees AS e", |stmt: Statement| {
             if let Statement::Query(query) = stmt {
                 if let SetExpr::Select(select) = query.body.as_ref() {
-                    if let TableFactor::Table { alias, .. } = &select.from[0].relation {
-                        if let Some(ref alias) = alias {
-                            assert_eq!(alias.name.value, "e");
-                        }
+                    if let TableFactor::Table {
+                        alias: Some(alias), ..
+                    } = &select.from[0].relation
+                    {
+                        assert_eq!(alias.name.value, "e");
                     }
                 }
             }
@@ -972,10 +993,11 @@ mod e051_basic_query_specification {
                 if let Statement::Query(query) = stmt {
                     if let SetExpr::Select(select) = query.body.as_ref() {
                         // Verify both table aliases exist
-                        if let TableFactor::Table { alias, .. } = &select.from[0].relation {
-                            if let Some(ref alias) = alias {
-                                assert_eq!(alias.name.value, "t1");
-                            }
+                        if let TableFactor::Table {
+                            alias: Some(alias), ..
+                        } = &select.from[0].relation
+                        {
+                            assert_eq!(alias.name.value, "t1");
                         }
                         assert_eq!(select.from[0].joins.len(), 1);
                     }
@@ -1009,13 +1031,14 @@ mod e051_basic_query_specification {
             |stmt: Statement| {
                 if let Statement::Query(query) = stmt {
                     if let SetExpr::Select(select) = query.body.as_ref() {
-                        if let TableFactor::Derived { alias, .. } = &select.from[0].relation {
-                            if let Some(ref alias) = alias {
-                                assert_eq!(alias.name.value, "derived");
-                                assert_eq!(alias.columns.len(), 2);
-                                assert_eq!(alias.columns[0].name.value, "col1");
-                                assert_eq!(alias.columns[1].name.value, "col2");
-                            }
+                        if let TableFactor::Derived {
+                            alias: Some(alias), ..
+                        } = &select.from[0].relation
+                        {
+                            assert_eq!(alias.name.value, "derived");
+                            assert_eq!(alias.columns.len(), 2);
+                            assert_eq!(alias.columns[0].name.value, "col1");
+                            assert_eq!(alias.columns[1].name.value, "col2");
                         }
                     }
                 }
@@ -1027,13 +1050,14 @@ mod e051_basic_query_specification {
             |stmt: Statement| {
                 if let Statement::Query(query) = stmt {
                     if let SetExpr::Select(select) = query.body.as_ref() {
-                        if let TableFactor::Table { alias, .. } = &select.from[0].relation {
-                            if let Some(ref alias) = alias {
-                                assert_eq!(alias.name.value, "u");
-                                assert_eq!(alias.columns.len(), 2);
-                                assert_eq!(alias.columns[0].name.value, "first");
-                                assert_eq!(alias.columns[1].name.value, "last");
-                            }
+                        if let TableFactor::Table {
+                            alias: Some(alias), ..
+                        } = &select.from[0].relation
+                        {
+                            assert_eq!(alias.name.value, "u");
+                            assert_eq!(alias.columns.len(), 2);
+                            assert_eq!(alias.columns[0].name.value, "first");
+                            assert_eq!(alias.columns[1].name.value, "last");
                         }
                     }
                 }
@@ -1794,7 +1818,7 @@ mod e081_basic_privileges {
                     panic!("Expected Actions privileges");
                 }
                 // Verify table object
-                if let Some(GrantObjects::Tables(tables)) = objects {
+                if let Some(GrantObjects::Tables(tables)) = objects.as_deref() {
                     assert_eq!(tables.len(), 1);
                     assert_eq!(tables[0].to_string(), "t");
                 } else {
@@ -1891,7 +1915,7 @@ mod e081_basic_privileges {
                     } else {
                         panic!("Expected Actions privileges");
                     }
-                    if let Some(GrantObjects::Tables(tables)) = objects {
+                    if let Some(GrantObjects::Tables(tables)) = objects.as_deref() {
                         assert_eq!(tables[0].to_string(), "employees");
                     }
                 } else {
@@ -1903,18 +1927,20 @@ mod e081_basic_privileges {
         verified_with_ast!(
             "GRANT UPDATE (status, updated_at) ON orders TO system_user",
             |stmt: Statement| {
-                if let Statement::Grant { privileges, .. } = stmt {
-                    if let Privileges::Actions(actions) = privileges {
-                        if let Action::Update {
-                            columns: Some(cols),
-                        } = &actions[0]
-                        {
-                            assert_eq!(cols.len(), 2);
-                            assert_eq!(cols[0].to_string(), "status");
-                            assert_eq!(cols[1].to_string(), "updated_at");
-                        } else {
-                            panic!("Expected Update action with columns");
-                        }
+                if let Statement::Grant {
+                    privileges: Privileges::Actions(actions),
+                    ..
+                } = stmt
+                {
+                    if let Action::Update {
+                        columns: Some(cols),
+                    } = &actions[0]
+                    {
+                        assert_eq!(cols.len(), 2);
+                        assert_eq!(cols[0].to_string(), "status");
+                        assert_eq!(cols[1].to_string(), "updated_at");
+                    } else {
+                        panic!("Expected Update action with columns");
                     }
                 }
             }
@@ -1970,18 +1996,20 @@ mod e081_basic_privileges {
         verified_with_ast!(
             "GRANT REFERENCES (dept_id, manager_id) ON departments TO user1",
             |stmt: Statement| {
-                if let Statement::Grant { privileges, .. } = stmt {
-                    if let Privileges::Actions(actions) = privileges {
-                        if let Action::References {
-                            columns: Some(cols),
-                        } = &actions[0]
-                        {
-                            assert_eq!(cols.len(), 2);
-                            assert_eq!(cols[0].to_string(), "dept_id");
-                            assert_eq!(cols[1].to_string(), "manager_id");
-                        } else {
-                            panic!("Expected References action with columns");
-                        }
+                if let Statement::Grant {
+                    privileges: Privileges::Actions(actions),
+                    ..
+                } = stmt
+                {
+                    if let Action::References {
+                        columns: Some(cols),
+                    } = &actions[0]
+                    {
+                        assert_eq!(cols.len(), 2);
+                        assert_eq!(cols[0].to_string(), "dept_id");
+                        assert_eq!(cols[1].to_string(), "manager_id");
+                    } else {
+                        panic!("Expected References action with columns");
                     }
                 }
             }
@@ -2065,7 +2093,7 @@ mod e081_basic_privileges {
                     } else {
                         panic!("Expected Actions privileges");
                     }
-                    if let Some(GrantObjects::Schemas(schemas)) = objects {
+                    if let Some(GrantObjects::Schemas(schemas)) = objects.as_deref() {
                         assert_eq!(schemas.len(), 1);
                         assert_eq!(schemas[0].to_string(), "public");
                     } else {
@@ -2089,7 +2117,7 @@ mod e081_basic_privileges {
                     if let Privileges::Actions(actions) = privileges {
                         assert!(matches!(actions[0], Action::Usage));
                     }
-                    if let Some(GrantObjects::Domains(domains)) = objects {
+                    if let Some(GrantObjects::Domains(domains)) = objects.as_deref() {
                         assert_eq!(domains.len(), 1);
                         assert_eq!(domains[0].to_string(), "email_domain");
                     } else {
@@ -2114,11 +2142,11 @@ mod e081_basic_privileges {
                 {
                     if let Privileges::Actions(actions) = privileges {
                         assert_eq!(actions.len(), 1);
-                        assert!(matches!(actions[0], Action::Execute { .. }));
+                        assert!(matches!(actions[0], Action::Execute));
                     } else {
                         panic!("Expected Actions privileges");
                     }
-                    if let Some(GrantObjects::Function { name, .. }) = objects {
+                    if let Some(GrantObjects::Function { name, .. }) = objects.as_deref() {
                         assert_eq!(name.to_string(), "calculate_bonus");
                     } else {
                         panic!("Expected Function grant object");
@@ -2139,9 +2167,9 @@ mod e081_basic_privileges {
                 } = stmt
                 {
                     if let Privileges::Actions(actions) = privileges {
-                        assert!(matches!(actions[0], Action::Execute { .. }));
+                        assert!(matches!(actions[0], Action::Execute));
                     }
-                    if let Some(GrantObjects::Procedure { name, .. }) = objects {
+                    if let Some(GrantObjects::Procedure { name, .. }) = objects.as_deref() {
                         assert_eq!(name.to_string(), "process_order");
                     } else {
                         panic!("Expected Procedure grant object");
@@ -2172,7 +2200,7 @@ mod e081_basic_privileges {
                     panic!("Expected Actions privileges");
                 }
                 // Verify table object
-                if let Some(GrantObjects::Tables(tables)) = objects {
+                if let Some(GrantObjects::Tables(tables)) = objects.as_deref() {
                     assert_eq!(tables.len(), 1);
                     assert_eq!(tables[0].to_string(), "t");
                 } else {
@@ -2190,12 +2218,14 @@ mod e081_basic_privileges {
         verified_with_ast!(
             "REVOKE INSERT, UPDATE ON customers FROM temp_user",
             |stmt: Statement| {
-                if let Statement::Revoke { privileges, .. } = stmt {
-                    if let Privileges::Actions(actions) = privileges {
-                        assert_eq!(actions.len(), 2);
-                        assert!(matches!(actions[0], Action::Insert { .. }));
-                        assert!(matches!(actions[1], Action::Update { .. }));
-                    }
+                if let Statement::Revoke {
+                    privileges: Privileges::Actions(actions),
+                    ..
+                } = stmt
+                {
+                    assert_eq!(actions.len(), 2);
+                    assert!(matches!(actions[0], Action::Insert { .. }));
+                    assert!(matches!(actions[1], Action::Update { .. }));
                 }
             }
         );
@@ -2302,7 +2332,11 @@ mod e091_set_functions {
         verified_with_ast!("SELECT AVG(salary) FROM employees", |stmt: Statement| {
             if let Statement::Query(query) = stmt {
                 if let SetExpr::Select(select) = query.body.as_ref() {
-                    if let SelectItem::UnnamedExpr(Expr::Function(func)) = &select.projection[0] {
+                    if let SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
+                    {
                         assert_eq!(func.name.to_string().to_uppercase(), "AVG");
                     } else {
                         panic!("Expected AVG function");
@@ -2316,7 +2350,10 @@ mod e091_set_functions {
             |stmt: Statement| {
                 if let Statement::Query(query) = stmt {
                     if let SetExpr::Select(select) = query.body.as_ref() {
-                        if let SelectItem::UnnamedExpr(Expr::Function(func)) = &select.projection[0]
+                        if let SelectItem::UnnamedExpr {
+                            expr: Expr::Function(func),
+                            ..
+                        } = &select.projection[0]
                         {
                             assert_eq!(func.name.to_string().to_uppercase(), "AVG");
                             if let sqlparser::ast::FunctionArguments::List(arg_list) = &func.args {
@@ -2346,7 +2383,11 @@ mod e091_set_functions {
         verified_with_ast!("SELECT COUNT(*) FROM employees", |stmt: Statement| {
             if let Statement::Query(query) = stmt {
                 if let SetExpr::Select(select) = query.body.as_ref() {
-                    if let SelectItem::UnnamedExpr(Expr::Function(func)) = &select.projection[0] {
+                    if let SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
+                    {
                         assert_eq!(func.name.to_string().to_uppercase(), "COUNT");
                         if let sqlparser::ast::FunctionArguments::List(arg_list) = &func.args {
                             assert_eq!(arg_list.args.len(), 1);
@@ -2369,7 +2410,11 @@ mod e091_set_functions {
         verified_with_ast!("SELECT COUNT(id) FROM users", |stmt: Statement| {
             if let Statement::Query(query) = stmt {
                 if let SetExpr::Select(select) = query.body.as_ref() {
-                    if let SelectItem::UnnamedExpr(Expr::Function(func)) = &select.projection[0] {
+                    if let SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
+                    {
                         assert_eq!(func.name.to_string().to_uppercase(), "COUNT");
                     } else {
                         panic!("Expected COUNT function");
@@ -2383,7 +2428,10 @@ mod e091_set_functions {
             |stmt: Statement| {
                 if let Statement::Query(query) = stmt {
                     if let SetExpr::Select(select) = query.body.as_ref() {
-                        if let SelectItem::UnnamedExpr(Expr::Function(func)) = &select.projection[0]
+                        if let SelectItem::UnnamedExpr {
+                            expr: Expr::Function(func),
+                            ..
+                        } = &select.projection[0]
                         {
                             assert_eq!(func.name.to_string().to_uppercase(), "COUNT");
                             if let sqlparser::ast::FunctionArguments::List(arg_list) = &func.args {
@@ -2413,7 +2461,11 @@ mod e091_set_functions {
         verified_with_ast!("SELECT MAX(salary) FROM employees", |stmt: Statement| {
             if let Statement::Query(query) = stmt {
                 if let SetExpr::Select(select) = query.body.as_ref() {
-                    if let SelectItem::UnnamedExpr(Expr::Function(func)) = &select.projection[0] {
+                    if let SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
+                    {
                         assert_eq!(func.name.to_string().to_uppercase(), "MAX");
                     } else {
                         panic!("Expected MAX function");
@@ -2425,7 +2477,11 @@ mod e091_set_functions {
         verified_with_ast!("SELECT MAX(price) FROM products", |stmt: Statement| {
             if let Statement::Query(query) = stmt {
                 if let SetExpr::Select(select) = query.body.as_ref() {
-                    if let SelectItem::UnnamedExpr(Expr::Function(func)) = &select.projection[0] {
+                    if let SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
+                    {
                         assert_eq!(func.name.to_string().to_uppercase(), "MAX");
                     } else {
                         panic!("Expected MAX function");
@@ -2444,7 +2500,11 @@ mod e091_set_functions {
         verified_with_ast!("SELECT MIN(salary) FROM employees", |stmt: Statement| {
             if let Statement::Query(query) = stmt {
                 if let SetExpr::Select(select) = query.body.as_ref() {
-                    if let SelectItem::UnnamedExpr(Expr::Function(func)) = &select.projection[0] {
+                    if let SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
+                    {
                         assert_eq!(func.name.to_string().to_uppercase(), "MIN");
                     } else {
                         panic!("Expected MIN function");
@@ -2456,7 +2516,11 @@ mod e091_set_functions {
         verified_with_ast!("SELECT MIN(price) FROM products", |stmt: Statement| {
             if let Statement::Query(query) = stmt {
                 if let SetExpr::Select(select) = query.body.as_ref() {
-                    if let SelectItem::UnnamedExpr(Expr::Function(func)) = &select.projection[0] {
+                    if let SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
+                    {
                         assert_eq!(func.name.to_string().to_uppercase(), "MIN");
                     } else {
                         panic!("Expected MIN function");
@@ -2475,7 +2539,11 @@ mod e091_set_functions {
         verified_with_ast!("SELECT SUM(amount) FROM transactions", |stmt: Statement| {
             if let Statement::Query(query) = stmt {
                 if let SetExpr::Select(select) = query.body.as_ref() {
-                    if let SelectItem::UnnamedExpr(Expr::Function(func)) = &select.projection[0] {
+                    if let SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
+                    {
                         assert_eq!(func.name.to_string().to_uppercase(), "SUM");
                     } else {
                         panic!("Expected SUM function");
@@ -2491,7 +2559,10 @@ mod e091_set_functions {
             |stmt: Statement| {
                 if let Statement::Query(query) = stmt {
                     if let SetExpr::Select(select) = query.body.as_ref() {
-                        if let SelectItem::UnnamedExpr(Expr::Function(func)) = &select.projection[0]
+                        if let SelectItem::UnnamedExpr {
+                            expr: Expr::Function(func),
+                            ..
+                        } = &select.projection[0]
                         {
                             assert_eq!(func.name.to_string().to_uppercase(), "SUM");
                             if let sqlparser::ast::FunctionArguments::List(arg_list) = &func.args {
@@ -2519,7 +2590,10 @@ mod e091_set_functions {
             |stmt: Statement| {
                 if let Statement::Query(query) = stmt {
                     if let SetExpr::Select(select) = query.body.as_ref() {
-                        if let SelectItem::UnnamedExpr(Expr::Function(func)) = &select.projection[0]
+                        if let SelectItem::UnnamedExpr {
+                            expr: Expr::Function(func),
+                            ..
+                        } = &select.projection[0]
                         {
                             assert_eq!(func.name.to_string().to_uppercase(), "SUM");
                             if let sqlparser::ast::FunctionArguments::List(arg_list) = &func.args {
@@ -2541,7 +2615,11 @@ mod e091_set_functions {
         verified_with_ast!("SELECT AVG(ALL price) FROM products", |stmt: Statement| {
             if let Statement::Query(query) = stmt {
                 if let SetExpr::Select(select) = query.body.as_ref() {
-                    if let SelectItem::UnnamedExpr(Expr::Function(func)) = &select.projection[0] {
+                    if let SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
+                    {
                         assert_eq!(func.name.to_string().to_uppercase(), "AVG");
                         if let sqlparser::ast::FunctionArguments::List(arg_list) = &func.args {
                             assert!(matches!(
@@ -2571,7 +2649,10 @@ mod e091_set_functions {
             |stmt: Statement| {
                 if let Statement::Query(query) = stmt {
                     if let SetExpr::Select(select) = query.body.as_ref() {
-                        if let SelectItem::UnnamedExpr(Expr::Function(func)) = &select.projection[0]
+                        if let SelectItem::UnnamedExpr {
+                            expr: Expr::Function(func),
+                            ..
+                        } = &select.projection[0]
                         {
                             assert_eq!(func.name.to_string().to_uppercase(), "COUNT");
                             if let sqlparser::ast::FunctionArguments::List(arg_list) = &func.args {
@@ -2593,7 +2674,10 @@ mod e091_set_functions {
             |stmt: Statement| {
                 if let Statement::Query(query) = stmt {
                     if let SetExpr::Select(select) = query.body.as_ref() {
-                        if let SelectItem::UnnamedExpr(Expr::Function(func)) = &select.projection[0]
+                        if let SelectItem::UnnamedExpr {
+                            expr: Expr::Function(func),
+                            ..
+                        } = &select.projection[0]
                         {
                             assert_eq!(func.name.to_string().to_uppercase(), "SUM");
                             if let sqlparser::ast::FunctionArguments::List(arg_list) = &func.args {
@@ -2615,7 +2699,10 @@ mod e091_set_functions {
             |stmt: Statement| {
                 if let Statement::Query(query) = stmt {
                     if let SetExpr::Select(select) = query.body.as_ref() {
-                        if let SelectItem::UnnamedExpr(Expr::Function(func)) = &select.projection[0]
+                        if let SelectItem::UnnamedExpr {
+                            expr: Expr::Function(func),
+                            ..
+                        } = &select.projection[0]
                         {
                             assert_eq!(func.name.to_string().to_uppercase(), "AVG");
                             if let sqlparser::ast::FunctionArguments::List(arg_list) = &func.args {
@@ -2657,13 +2744,13 @@ mod e101_basic_data_manipulation {
     fn e101_01_insert_values() {
         // SQL:2016 E101-01: INSERT statement with VALUES
         verified_with_ast!("INSERT INTO t (a, b) VALUES (1, 2)", |stmt: Statement| {
-            if let Statement::Insert(Insert {
-                table,
-                columns,
-                source,
-                ..
-            }) = stmt
-            {
+            if let Statement::Insert(insert) = stmt {
+                let Insert {
+                    table,
+                    columns,
+                    source,
+                    ..
+                } = AstBox::into_owned(insert);
                 if let TableObject::TableName(name) = table {
                     assert_eq!(name.to_string(), "t");
                 } else {
@@ -2688,13 +2775,13 @@ mod e101_basic_data_manipulation {
         });
 
         verified_with_ast!("INSERT INTO t VALUES (1, 2, 3)", |stmt: Statement| {
-            if let Statement::Insert(Insert {
-                table,
-                columns,
-                source,
-                ..
-            }) = stmt
-            {
+            if let Statement::Insert(insert) = stmt {
+                let Insert {
+                    table,
+                    columns,
+                    source,
+                    ..
+                } = AstBox::into_owned(insert);
                 if let TableObject::TableName(name) = table {
                     assert_eq!(name.to_string(), "t");
                 } else {
@@ -2724,13 +2811,13 @@ mod e101_basic_data_manipulation {
         verified_with_ast!(
             "INSERT INTO t (a, b) VALUES (1, 2), (3, 4), (5, 6)",
             |stmt: Statement| {
-                if let Statement::Insert(Insert {
-                    table,
-                    columns,
-                    source,
-                    ..
-                }) = stmt
-                {
+                if let Statement::Insert(insert) = stmt {
+                    let Insert {
+                        table,
+                        columns,
+                        source,
+                        ..
+                    } = AstBox::into_owned(insert);
                     if let TableObject::TableName(name) = table {
                         assert_eq!(name.to_string(), "t");
                     } else {
@@ -2764,13 +2851,13 @@ mod e101_basic_data_manipulation {
     fn e101_01_insert_select() {
         // SQL:2016 E101-01: INSERT with SELECT
         verified_with_ast!("INSERT INTO t SELECT * FROM s", |stmt: Statement| {
-            if let Statement::Insert(Insert {
-                table,
-                columns,
-                source,
-                ..
-            }) = stmt
-            {
+            if let Statement::Insert(insert) = stmt {
+                let Insert {
+                    table,
+                    columns,
+                    source,
+                    ..
+                } = AstBox::into_owned(insert);
                 if let TableObject::TableName(name) = table {
                     assert_eq!(name.to_string(), "t");
                 } else {
@@ -2796,7 +2883,8 @@ mod e101_basic_data_manipulation {
         verified_with_ast!(
             "INSERT INTO summary (category, total) SELECT category, SUM(amount) FROM transactions GROUP BY category",
             |stmt: Statement| {
-                if let Statement::Insert(Insert { table, columns, source, .. }) = stmt {
+                if let Statement::Insert(insert) = stmt {
+                    let Insert { table, columns, source, .. } = AstBox::into_owned(insert);
                     if let TableObject::TableName(name) = table {
                         assert_eq!(name.to_string(), "summary");
                     } else {
@@ -2831,13 +2919,13 @@ mod e101_basic_data_manipulation {
     fn e101_01_insert_default_values() {
         // SQL:2016 E101-01: INSERT with DEFAULT VALUES
         verified_with_ast!("INSERT INTO t DEFAULT VALUES", |stmt: Statement| {
-            if let Statement::Insert(Insert {
-                table,
-                columns,
-                source,
-                ..
-            }) = stmt
-            {
+            if let Statement::Insert(insert) = stmt {
+                let Insert {
+                    table,
+                    columns,
+                    source,
+                    ..
+                } = AstBox::into_owned(insert);
                 if let TableObject::TableName(name) = table {
                     assert_eq!(name.to_string(), "t");
                 } else {
@@ -2860,13 +2948,13 @@ mod e101_basic_data_manipulation {
     fn e101_03_searched_update() {
         // SQL:2016 E101-03: Searched UPDATE statement
         verified_with_ast!("UPDATE t SET a = 1", |stmt: Statement| {
-            if let Statement::Update(Update {
-                table,
-                assignments,
-                selection,
-                ..
-            }) = stmt
-            {
+            if let Statement::Update(update) = stmt {
+                let Update {
+                    table,
+                    assignments,
+                    selection,
+                    ..
+                } = AstBox::into_owned(update);
                 assert_eq!(table.relation.to_string(), "t");
                 assert_eq!(assignments.len(), 1);
                 assert!(selection.is_none());
@@ -2876,13 +2964,13 @@ mod e101_basic_data_manipulation {
         });
 
         verified_with_ast!("UPDATE t SET a = 1, b = 2", |stmt: Statement| {
-            if let Statement::Update(Update {
-                table,
-                assignments,
-                selection,
-                ..
-            }) = stmt
-            {
+            if let Statement::Update(update) = stmt {
+                let Update {
+                    table,
+                    assignments,
+                    selection,
+                    ..
+                } = AstBox::into_owned(update);
                 assert_eq!(table.relation.to_string(), "t");
                 assert_eq!(assignments.len(), 2);
                 assert!(selection.is_none());
@@ -2894,13 +2982,13 @@ mod e101_basic_data_manipulation {
         verified_with_ast!(
             "UPDATE employees SET salary = salary * 1.1 WHERE department = 'Sales'",
             |stmt: Statement| {
-                if let Statement::Update(Update {
-                    table,
-                    assignments,
-                    selection,
-                    ..
-                }) = stmt
-                {
+                if let Statement::Update(update) = stmt {
+                    let Update {
+                        table,
+                        assignments,
+                        selection,
+                        ..
+                    } = AstBox::into_owned(update);
                     assert_eq!(table.relation.to_string(), "employees");
                     assert_eq!(assignments.len(), 1);
                     assert!(selection.is_some(), "Expected WHERE clause");
@@ -2918,13 +3006,13 @@ mod e101_basic_data_manipulation {
         verified_with_ast!(
             "UPDATE t SET a = (SELECT MAX(b) FROM s)",
             |stmt: Statement| {
-                if let Statement::Update(Update {
-                    table,
-                    assignments,
-                    selection,
-                    ..
-                }) = stmt
-                {
+                if let Statement::Update(update) = stmt {
+                    let Update {
+                        table,
+                        assignments,
+                        selection,
+                        ..
+                    } = AstBox::into_owned(update);
                     assert_eq!(table.relation.to_string(), "t");
                     assert_eq!(assignments.len(), 1);
                     // Verify assignment contains subquery
@@ -2954,10 +3042,10 @@ mod e101_basic_data_manipulation {
     fn e101_04_searched_delete() {
         // SQL:2016 E101-04: Searched DELETE statement
         verified_with_ast!("DELETE FROM t", |stmt: Statement| {
-            if let Statement::Delete(Delete {
-                from, selection, ..
-            }) = stmt
-            {
+            if let Statement::Delete(delete) = stmt {
+                let Delete {
+                    from, selection, ..
+                } = AstBox::into_owned(delete);
                 // FromTable can be WithFromKeyword or WithoutKeyword
                 match from {
                     sqlparser::ast::FromTable::WithFromKeyword(tables) => {
@@ -2976,10 +3064,10 @@ mod e101_basic_data_manipulation {
         });
 
         verified_with_ast!("DELETE FROM t WHERE a = 1", |stmt: Statement| {
-            if let Statement::Delete(Delete {
-                from, selection, ..
-            }) = stmt
-            {
+            if let Statement::Delete(delete) = stmt {
+                let Delete {
+                    from, selection, ..
+                } = AstBox::into_owned(delete);
                 match from {
                     sqlparser::ast::FromTable::WithFromKeyword(tables) => {
                         assert_eq!(tables.len(), 1);
@@ -3003,10 +3091,10 @@ mod e101_basic_data_manipulation {
         verified_with_ast!(
             "DELETE FROM t WHERE id IN (SELECT ref_id FROM s)",
             |stmt: Statement| {
-                if let Statement::Delete(Delete {
-                    from, selection, ..
-                }) = stmt
-                {
+                if let Statement::Delete(delete) = stmt {
+                    let Delete {
+                        from, selection, ..
+                    } = AstBox::into_owned(delete);
                     match from {
                         sqlparser::ast::FromTable::WithFromKeyword(tables) => {
                             assert_eq!(tables.len(), 1);
@@ -3168,7 +3256,8 @@ mod e141_basic_integrity_constraints {
     fn e141_01_not_null() {
         // SQL:2016 E141-01: NOT NULL constraints
         verified_with_ast!("CREATE TABLE t (a INTEGER NOT NULL)", |stmt: Statement| {
-            if let Statement::CreateTable(CreateTable { columns, .. }) = stmt {
+            if let Statement::CreateTable(create_table) = stmt {
+                let CreateTable { columns, .. } = AstBox::into_owned(create_table);
                 assert_eq!(columns.len(), 1);
                 assert!(columns[0]
                     .options
@@ -3187,7 +3276,8 @@ mod e141_basic_integrity_constraints {
     fn e141_02_unique() {
         // SQL:2016 E141-02: UNIQUE constraints
         verified_with_ast!("CREATE TABLE t (a INTEGER UNIQUE)", |stmt: Statement| {
-            if let Statement::CreateTable(CreateTable { columns, .. }) = stmt {
+            if let Statement::CreateTable(create_table) = stmt {
+                let CreateTable { columns, .. } = AstBox::into_owned(create_table);
                 assert_eq!(columns.len(), 1);
                 assert!(columns[0]
                     .options
@@ -3201,7 +3291,8 @@ mod e141_basic_integrity_constraints {
         verified_with_ast!(
             "CREATE TABLE t (a INTEGER, UNIQUE (a))",
             |stmt: Statement| {
-                if let Statement::CreateTable(CreateTable { constraints, .. }) = stmt {
+                if let Statement::CreateTable(create_table) = stmt {
+                    let CreateTable { constraints, .. } = AstBox::into_owned(create_table);
                     assert!(constraints
                         .iter()
                         .any(|c| matches!(c, TableConstraint::Unique { .. })));
@@ -3252,7 +3343,8 @@ mod e141_basic_integrity_constraints {
         verified_with_ast!(
             "CREATE TABLE t (a INTEGER CHECK (a > 0))",
             |stmt: Statement| {
-                if let Statement::CreateTable(CreateTable { columns, .. }) = stmt {
+                if let Statement::CreateTable(create_table) = stmt {
+                    let CreateTable { columns, .. } = AstBox::into_owned(create_table);
                     assert_eq!(columns.len(), 1);
                     assert!(columns[0]
                         .options
@@ -3267,7 +3359,8 @@ mod e141_basic_integrity_constraints {
         verified_with_ast!(
             "CREATE TABLE t (a INTEGER, b INTEGER, CHECK (a < b))",
             |stmt: Statement| {
-                if let Statement::CreateTable(CreateTable { constraints, .. }) = stmt {
+                if let Statement::CreateTable(create_table) = stmt {
+                    let CreateTable { constraints, .. } = AstBox::into_owned(create_table);
                     assert!(constraints
                         .iter()
                         .any(|c| matches!(c, TableConstraint::Check { .. })));
@@ -3291,7 +3384,8 @@ mod e141_basic_integrity_constraints {
     fn e141_07_column_defaults() {
         // SQL:2016 E141-07: Column defaults
         verified_with_ast!("CREATE TABLE t (a INTEGER DEFAULT 0)", |stmt: Statement| {
-            if let Statement::CreateTable(CreateTable { columns, .. }) = stmt {
+            if let Statement::CreateTable(create_table) = stmt {
+                let CreateTable { columns, .. } = AstBox::into_owned(create_table);
                 assert_eq!(columns.len(), 1);
                 assert!(columns[0]
                     .options
@@ -3305,16 +3399,15 @@ mod e141_basic_integrity_constraints {
         verified_with_ast!(
             "CREATE TABLE t (status VARCHAR(20) DEFAULT 'active')",
             |stmt: Statement| {
-                if let Statement::CreateTable(CreateTable { columns, .. }) = stmt {
+                if let Statement::CreateTable(create_table) = stmt {
+                    let CreateTable { columns, .. } = AstBox::into_owned(create_table);
                     if let Some(default_opt) = columns[0]
                         .options
                         .iter()
                         .find(|opt| matches!(opt.option, ColumnOption::Default(_)))
                     {
-                        if let ColumnOption::Default(Expr::Value(ValueWithSpan { value, .. })) =
-                            &default_opt.option
-                        {
-                            if let Value::SingleQuotedString(s) = value {
+                        if let ColumnOption::Default(Expr::Value(value)) = &default_opt.option {
+                            if let Value::SingleQuotedString(s) = &value.value {
                                 assert_eq!(s, "active");
                             } else {
                                 panic!("Expected SingleQuotedString");
@@ -3608,7 +3701,8 @@ fn e_series_comprehensive_ddl() {
     verified_with_ast!(
         "CREATE TABLE employees (employee_id INTEGER PRIMARY KEY, department_id INTEGER NOT NULL REFERENCES departments(id) ON DELETE RESTRICT, manager_id INTEGER REFERENCES employees(employee_id) ON DELETE SET NULL, first_name VARCHAR(50) NOT NULL, last_name VARCHAR(50) NOT NULL, email VARCHAR(255) UNIQUE NOT NULL, salary DECIMAL(10,2) CHECK (salary >= 0), hire_date DATE NOT NULL DEFAULT CURRENT_DATE, status VARCHAR(20) DEFAULT 'active' CHECK (status IN ('active', 'inactive', 'on_leave')), created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP, CONSTRAINT check_name_length CHECK (CHARACTER_LENGTH(first_name) > 0 AND CHARACTER_LENGTH(last_name) > 0), CONSTRAINT unique_email UNIQUE (email))",
         |stmt: Statement| {
-            if let Statement::CreateTable(CreateTable { columns, constraints, .. }) = stmt {
+            if let Statement::CreateTable(create_table) = stmt {
+                let CreateTable { columns, constraints, .. } = AstBox::into_owned(create_table);
                 // Verify we have all expected columns
                 assert_eq!(columns.len(), 10);
 
@@ -172,7 +172,9 @@ mod f031_basic_schema_manipulation {
     fn f031_02_create_view() {
         // F031-02: CREATE VIEW statement
         verified_with_ast!("CREATE VIEW v AS SELECT a, b FROM t", |stmt: Statement| {
-            if let Statement::CreateView(CreateView { name, query, .. }) = stmt {
+            if let Statement::CreateView(create_view) = stmt {
+                let CreateView { name, query, .. } =
+                    sqlparser::ast::AstBox::into_owned(create_view);
                 assert_eq!(name.to_string(), "v");
                 if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                     assert_eq!(select.projection.len(), 2);
@@ -184,7 +186,9 @@ mod f031_basic_schema_manipulation {
         verified_with_ast!(
             "CREATE VIEW employee_view AS SELECT id, name FROM employees WHERE salary > 50000",
             |stmt: Statement| {
-                if let Statement::CreateView(CreateView { name, query, .. }) = stmt {
+                if let Statement::CreateView(create_view) = stmt {
+                    let CreateView { name, query, .. } =
+                        sqlparser::ast::AstBox::into_owned(create_view);
                     assert_eq!(name.to_string(), "employee_view");
                     if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                         assert_eq!(select.projection.len(), 2);
@@ -212,7 +216,7 @@ mod f031_basic_schema_manipulation {
                     assert_eq!(actions.len(), 1);
                     assert_eq!(actions[0].to_string().to_uppercase(), "SELECT");
                 }
-                if let Some(GrantObjects::Tables(tables)) = objects {
+                if let Some(GrantObjects::Tables(tables)) = objects.as_deref() {
                     assert_eq!(tables.len(), 1);
                     assert_eq!(tables[0].to_string(), "t");
                 } else {
@@ -237,7 +241,7 @@ mod f031_basic_schema_manipulation {
                     if let Privileges::Actions(actions) = privileges {
                         assert_eq!(actions.len(), 3);
                     }
-                    if let Some(GrantObjects::Tables(tables)) = objects {
+                    if let Some(GrantObjects::Tables(tables)) = objects.as_deref() {
                         assert_eq!(tables.len(), 1);
                         assert_eq!(tables[0].to_string(), "employees");
                     }
@@ -258,7 +262,7 @@ mod f031_basic_schema_manipulation {
             } = stmt
             {
                 assert!(matches!(privileges, Privileges::All { .. }));
-                if let Some(GrantObjects::Tables(tables)) = objects {
+                if let Some(GrantObjects::Tables(tables)) = objects.as_deref() {
                     assert_eq!(tables.len(), 1);
                 }
                 assert_eq!(grantees.len(), 1);
@@ -440,7 +444,7 @@ mod f031_basic_schema_manipulation {
                     assert_eq!(actions.len(), 1);
                     assert_eq!(actions[0].to_string().to_uppercase(), "SELECT");
                 }
-                if let Some(GrantObjects::Tables(tables)) = objects {
+                if let Some(GrantObjects::Tables(tables)) = objects.as_deref() {
                     assert_eq!(tables.len(), 1);
                     assert_eq!(tables[0].to_string(), "t");
                 } else {
@@ -465,7 +469,7 @@ mod f031_basic_schema_manipulation {
                     if let Privileges::Actions(actions) = privileges {
                         assert_eq!(actions.len(), 2);
                     }
-                    if let Some(GrantObjects::Tables(tables)) = objects {
+                    if let Some(GrantObjects::Tables(tables)) = objects.as_deref() {
                         assert_eq!(tables.len(), 1);
                         assert_eq!(tables[0].to_string(), "employees");
                     }
@@ -488,7 +492,7 @@ mod f031_basic_schema_manipulation {
                 } = stmt
                 {
                     assert!(matches!(privileges, Privileges::All { .. }));
-                    if let Some(GrantObjects::Tables(tables)) = objects {
+                    if let Some(GrantObjects::Tables(tables)) = objects.as_deref() {
                         assert_eq!(tables.len(), 1);
                     }
                     assert_eq!(grantees.len(), 1);
@@ -742,8 +746,10 @@ mod f051_basic_date_and_time {
         verified_with_ast!("SELECT DATE '2024-01-15'", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                    if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::TypedString(ts)) =
-                        &select.projection[0]
+                    if let sqlparser::ast::SelectItem::UnnamedExpr {
+                        expr: Expr::TypedString(ts),
+                        ..
+                    } = &select.projection[0]
                     {
                         assert!(
                             matches!(ts.data_type, DataType::Date),
@@ -787,8 +793,10 @@ mod f051_basic_date_and_time {
         verified_with_ast!("SELECT TIME '12:34:56.789'", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                    if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::TypedString(ts)) =
-                        &select.projection[0]
+                    if let sqlparser::ast::SelectItem::UnnamedExpr {
+                        expr: Expr::TypedString(ts),
+                        ..
+                    } = &select.projection[0]
                     {
                         assert!(
                             matches!(ts.data_type, DataType::Time(_, _)),
@@ -834,8 +842,10 @@ mod f051_basic_date_and_time {
             |stmt: Statement| {
                 if let Statement::Query(q) = stmt {
                     if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                        if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::TypedString(ts)) =
-                            &select.projection[0]
+                        if let sqlparser::ast::SelectItem::UnnamedExpr {
+                            expr: Expr::TypedString(ts),
+                            ..
+                        } = &select.projection[0]
                         {
                             assert!(
                                 matches!(ts.data_type, DataType::Timestamp(_, _)),
@@ -912,11 +922,15 @@ mod f051_basic_date_and_time {
         verified_with_ast!("SELECT CAST('2024-01-15' AS DATE)", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                    if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Cast {
-                        data_type, ..
-                    }) = &select.projection[0]
+                    if let sqlparser::ast::SelectItem::UnnamedExpr {
+                        expr: Expr::Cast { data_type, .. },
+                        ..
+                    } = &select.projection[0]
                     {
-                        assert!(matches!(data_type, DataType::Date), "Expected CAST to DATE");
+                        assert!(
+                            matches!(data_type.as_ref(), DataType::Date),
+                            "Expected CAST to DATE"
+                        );
                     } else {
                         panic!("Expected CAST expression");
                     }
@@ -927,12 +941,13 @@ mod f051_basic_date_and_time {
         verified_with_ast!("SELECT CAST('12:34:56' AS TIME)", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                    if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Cast {
-                        data_type, ..
-                    }) = &select.projection[0]
+                    if let sqlparser::ast::SelectItem::UnnamedExpr {
+                        expr: Expr::Cast { data_type, .. },
+                        ..
+                    } = &select.projection[0]
                     {
                         assert!(
-                            matches!(data_type, DataType::Time(_, _)),
+                            matches!(data_type.as_ref(), DataType::Time(_, _)),
                             "Expected CAST to TIME"
                         );
                     } else {
@@ -946,13 +961,13 @@ mod f051_basic_date_and_time {
             |stmt: Statement| {
                 if let Statement::Query(q) = stmt {
                     if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                        if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Cast {
-                            data_type,
+                        if let sqlparser::ast::SelectItem::UnnamedExpr {
+                            expr: Expr::Cast { data_type, .. },
                             ..
-                        }) = &select.projection[0]
+                        } = &select.projection[0]
                         {
                             assert!(
-                                matches!(data_type, DataType::Timestamp(_, _)),
+                                matches!(data_type.as_ref(), DataType::Timestamp(_, _)),
                                 "Expected CAST to TIMESTAMP"
                             );
                         } else {
@@ -970,8 +985,10 @@ mod f051_basic_date_and_time {
         verified_with_ast!("SELECT CURRENT_DATE", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                    if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Function(func)) =
-                        &select.projection[0]
+                    if let sqlparser::ast::SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
                     {
                         assert_eq!(
                             func.name.to_string().to_uppercase(),
@@ -1010,8 +1027,10 @@ mod f051_basic_date_and_time {
         verified_with_ast!("SELECT LOCALTIME", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                    if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Function(func)) =
-                        &select.projection[0]
+                    if let sqlparser::ast::SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
                     {
                         assert_eq!(
                             func.name.to_string().to_uppercase(),
@@ -1027,8 +1046,10 @@ mod f051_basic_date_and_time {
         verified_with_ast!("SELECT LOCALTIME(3)", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                    if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Function(func)) =
-                        &select.projection[0]
+                    if let sqlparser::ast::SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
                     {
                         assert_eq!(
                             func.name.to_string().to_uppercase(),
@@ -1049,8 +1070,10 @@ mod f051_basic_date_and_time {
         verified_with_ast!("SELECT LOCALTIMESTAMP", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                    if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Function(func)) =
-                        &select.projection[0]
+                    if let sqlparser::ast::SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
                     {
                         assert_eq!(
                             func.name.to_string().to_uppercase(),
@@ -1066,8 +1089,10 @@ mod f051_basic_date_and_time {
         verified_with_ast!("SELECT LOCALTIMESTAMP(6)", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                    if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Function(func)) =
-                        &select.projection[0]
+                    if let sqlparser::ast::SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
                     {
                         assert_eq!(
                             func.name.to_string().to_uppercase(),
@@ -1108,8 +1133,10 @@ mod f051_basic_date_and_time {
             |stmt: Statement| {
                 if let Statement::Query(q) = stmt {
                     if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                        if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::TypedString(ts)) =
-                            &select.projection[0]
+                        if let sqlparser::ast::SelectItem::UnnamedExpr {
+                            expr: Expr::TypedString(ts),
+                            ..
+                        } = &select.projection[0]
                         {
                             assert!(
                                 matches!(
@@ -1153,8 +1180,10 @@ mod f051_basic_date_and_time {
             |stmt: Statement| {
                 if let Statement::Query(q) = stmt {
                     if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                        if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::TypedString(ts)) =
-                            &select.projection[0]
+                        if let sqlparser::ast::SelectItem::UnnamedExpr {
+                            expr: Expr::TypedString(ts),
+                            ..
+                        } = &select.projection[0]
                         {
                             assert!(
                                 matches!(
@@ -1181,8 +1210,10 @@ mod f051_basic_date_and_time {
         verified_with_ast!("SELECT CURRENT_TIMESTAMP", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                    if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Function(func)) =
-                        &select.projection[0]
+                    if let sqlparser::ast::SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
                     {
                         assert_eq!(
                             func.name.to_string().to_uppercase(),
@@ -1198,8 +1229,10 @@ mod f051_basic_date_and_time {
         verified_with_ast!("SELECT CURRENT_TIMESTAMP(6)", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                    if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Function(func)) =
-                        &select.projection[0]
+                    if let sqlparser::ast::SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
                     {
                         assert_eq!(
                             func.name.to_string().to_uppercase(),
@@ -1220,8 +1253,10 @@ mod f051_basic_date_and_time {
         verified_with_ast!("SELECT CURRENT_TIME", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                    if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Function(func)) =
-                        &select.projection[0]
+                    if let sqlparser::ast::SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
                     {
                         assert_eq!(
                             func.name.to_string().to_uppercase(),
@@ -1237,8 +1272,10 @@ mod f051_basic_date_and_time {
         verified_with_ast!("SELECT CURRENT_TIME(3)", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                    if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Function(func)) =
-                        &select.projection[0]
+                    if let sqlparser::ast::SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
                     {
                         assert_eq!(
                             func.name.to_string().to_uppercase(),
@@ -1285,7 +1322,8 @@ mod f131_grouped_operations {
         verified_with_ast!(
             "CREATE VIEW v AS SELECT a, COUNT(*) FROM t WHERE b > 0 GROUP BY a HAVING COUNT(*) > 1",
             |stmt: Statement| {
-                if let Statement::CreateView(CreateView { query, .. }) = stmt {
+                if let Statement::CreateView(create_view) = stmt {
+                    let CreateView { query, .. } = sqlparser::ast::AstBox::into_owned(create_view);
                     if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                         // Verify WHERE clause exists
                         assert!(
@@ -1308,7 +1346,10 @@ mod f131_grouped_operations {
 
                         // Verify COUNT(*) in projection
                         assert_eq!(select.projection.len(), 2, "Expected 2 projection items");
-                        if let SelectItem::UnnamedExpr(Expr::Function(func)) = &select.projection[1]
+                        if let SelectItem::UnnamedExpr {
+                            expr: Expr::Function(func),
+                            ..
+                        } = &select.projection[1]
                         {
                             assert_eq!(
                                 func.name.to_string(),
@@ -1334,7 +1375,8 @@ mod f131_grouped_operations {
         verified_with_ast!(
             "CREATE VIEW v AS SELECT t1.a, COUNT(*) FROM t1, t2 WHERE t1.id = t2.id GROUP BY t1.a",
             |stmt: Statement| {
-                if let Statement::CreateView(CreateView { query, .. }) = stmt {
+                if let Statement::CreateView(create_view) = stmt {
+                    let CreateView { query, .. } = sqlparser::ast::AstBox::into_owned(create_view);
                     if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                         // Verify multiple tables in FROM clause
                         assert_eq!(
@@ -1351,7 +1393,10 @@ mod f131_grouped_operations {
                         }
 
                         // Verify COUNT(*) in projection
-                        if let SelectItem::UnnamedExpr(Expr::Function(func)) = &select.projection[1]
+                        if let SelectItem::UnnamedExpr {
+                            expr: Expr::Function(func),
+                            ..
+                        } = &select.projection[1]
                         {
                             assert_eq!(
                                 func.name.to_string(),
@@ -1377,15 +1422,18 @@ mod f131_grouped_operations {
         verified_with_ast!(
             "CREATE VIEW v AS SELECT SUM(a), AVG(b), MAX(c), MIN(d), COUNT(*) FROM t",
             |stmt: Statement| {
-                if let Statement::CreateView(CreateView { query, .. }) = stmt {
+                if let Statement::CreateView(create_view) = stmt {
+                    let CreateView { query, .. } = sqlparser::ast::AstBox::into_owned(create_view);
                     if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                         // Verify all 5 aggregate functions in projection
                         assert_eq!(select.projection.len(), 5, "Expected 5 projection items");
 
-                        let expected_funcs = vec!["sum", "avg", "max", "min", "count"];
+                        let expected_funcs = ["sum", "avg", "max", "min", "count"];
                         for (i, expected_name) in expected_funcs.iter().enumerate() {
-                            if let SelectItem::UnnamedExpr(Expr::Function(func)) =
-                                &select.projection[i]
+                            if let SelectItem::UnnamedExpr {
+                                expr: Expr::Function(func),
+                                ..
+                            } = &select.projection[i]
                             {
                                 assert_eq!(
                                     func.name.to_string(),
@@ -1417,7 +1465,8 @@ mod f131_grouped_operations {
         verified_with_ast!(
             "CREATE VIEW v AS SELECT * FROM (SELECT a, COUNT(*) AS cnt FROM t GROUP BY a) AS sub WHERE cnt > 5",
             |stmt: Statement| {
-                if let Statement::CreateView(CreateView { query, .. }) = stmt {
+                if let Statement::CreateView(create_view) = stmt {
+                    let CreateView { query, .. } = sqlparser::ast::AstBox::into_owned(create_view);
                     if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                         // Verify outer WHERE clause exists
                         assert!(
@@ -1446,7 +1495,7 @@ mod f131_grouped_operations {
                                 }
 
                                 // Verify COUNT(*) in subquery projection
-                                if let SelectItem::ExprWithAlias { expr, alias } =
+                                if let SelectItem::ExprWithAlias { expr, alias, comments: _ } =
                                     &inner_select.projection[1]
                                 {
                                     if let Expr::Function(func) = expr {
@@ -1487,7 +1536,8 @@ mod f131_grouped_operations {
         verified_with_ast!(
             "CREATE VIEW v AS SELECT t1.dept, COUNT(*) FROM t1 JOIN t2 ON t1.id = t2.id GROUP BY t1.dept",
             |stmt: Statement| {
-                if let Statement::CreateView(CreateView { query, .. }) = stmt {
+                if let Statement::CreateView(create_view) = stmt {
+                    let CreateView { query, .. } = sqlparser::ast::AstBox::into_owned(create_view);
                     if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                         // Verify JOIN exists
                         assert_eq!(select.from.len(), 1, "Expected one table with joins");
@@ -1514,7 +1564,7 @@ mod f131_grouped_operations {
 
                         // Verify COUNT(*) in projection
                         assert_eq!(select.projection.len(), 2, "Expected 2 projection items");
-                        if let SelectItem::UnnamedExpr(Expr::Function(func)) =
+                        if let SelectItem::UnnamedExpr { expr: Expr::Function(func), .. } =
                             &select.projection[1]
                         {
                             assert_eq!(
@@ -1547,12 +1597,13 @@ mod f201_cast_function {
         verified_with_ast!("SELECT CAST('123' AS INTEGER)", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                    if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Cast {
-                        data_type, ..
-                    }) = &select.projection[0]
+                    if let sqlparser::ast::SelectItem::UnnamedExpr {
+                        expr: Expr::Cast { data_type, .. },
+                        ..
+                    } = &select.projection[0]
                     {
                         assert!(
-                            matches!(data_type, DataType::Integer(_) | DataType::Int(_)),
+                            matches!(data_type.as_ref(), DataType::Integer(_) | DataType::Int(_)),
                             "Expected INTEGER data type"
                         );
                     } else {
@@ -1566,14 +1617,14 @@ mod f201_cast_function {
             |stmt: Statement| {
                 if let Statement::Query(q) = stmt {
                     if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                        if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Cast {
-                            data_type,
+                        if let sqlparser::ast::SelectItem::UnnamedExpr {
+                            expr: Expr::Cast { data_type, .. },
                             ..
-                        }) = &select.projection[0]
+                        } = &select.projection[0]
                         {
                             if let DataType::Decimal(
                                 sqlparser::ast::ExactNumberInfo::PrecisionAndScale(p, s),
-                            ) = data_type
+                            ) = data_type.as_ref()
                             {
                                 assert_eq!(*p, 10);
                                 assert_eq!(*s, 2);
@@ -1595,13 +1646,14 @@ mod f201_cast_function {
         verified_with_ast!("SELECT CAST(123 AS VARCHAR(10))", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                    if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Cast {
-                        data_type, ..
-                    }) = &select.projection[0]
+                    if let sqlparser::ast::SelectItem::UnnamedExpr {
+                        expr: Expr::Cast { data_type, .. },
+                        ..
+                    } = &select.projection[0]
                     {
                         if let DataType::Varchar(Some(
                             sqlparser::ast::CharacterLength::IntegerLength { length, .. },
-                        )) = data_type
+                        )) = data_type.as_ref()
                         {
                             assert_eq!(*length, 10);
                         } else {
@@ -1621,11 +1673,12 @@ mod f201_cast_function {
         verified_with_ast!("SELECT CAST('2024-01-15' AS DATE)", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                    if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Cast {
-                        data_type, ..
-                    }) = &select.projection[0]
+                    if let sqlparser::ast::SelectItem::UnnamedExpr {
+                        expr: Expr::Cast { data_type, .. },
+                        ..
+                    } = &select.projection[0]
                     {
-                        assert!(matches!(data_type, DataType::Date));
+                        assert!(matches!(data_type.as_ref(), DataType::Date));
                     }
                 }
             }
@@ -1633,11 +1686,12 @@ mod f201_cast_function {
         verified_with_ast!("SELECT CAST('12:34:56' AS TIME)", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                    if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Cast {
-                        data_type, ..
-                    }) = &select.projection[0]
+                    if let sqlparser::ast::SelectItem::UnnamedExpr {
+                        expr: Expr::Cast { data_type, .. },
+                        ..
+                    } = &select.projection[0]
                     {
-                        assert!(matches!(data_type, DataType::Time(_, _)));
+                        assert!(matches!(data_type.as_ref(), DataType::Time(_, _)));
                     }
                 }
             }
@@ -1653,14 +1707,16 @@ mod f201_cast_function {
             |stmt: Statement| {
                 if let Statement::Query(q) = stmt {
                     if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                        if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Cast {
-                            expr,
-                            data_type,
+                        if let sqlparser::ast::SelectItem::UnnamedExpr {
+                            expr:
+                                Expr::Cast {
+                                    expr, data_type, ..
+                                },
                             ..
-                        }) = &select.projection[0]
+                        } = &select.projection[0]
                         {
                             // Outer CAST should be to VARCHAR
-                            assert!(matches!(data_type, DataType::Varchar(_)));
+                            assert!(matches!(data_type.as_ref(), DataType::Varchar(_)));
                             // Inner expression should also be a CAST
                             assert!(matches!(expr.as_ref(), Expr::Cast { .. }));
                         }
@@ -1717,13 +1773,15 @@ mod f261_case_expression {
             |stmt: Statement| {
                 if let Statement::Query(q) = stmt {
                     if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                        if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Case {
-                            operand,
-                            conditions,
-                            else_result,
-                            ..
-                        }) = &select.projection[0]
+                        if let sqlparser::ast::SelectItem::UnnamedExpr { expr: Expr::Case(case), .. } =
+                            &select.projection[0]
                         {
+                            let sqlparser::ast::CaseExpr {
+                                operand,
+                                conditions,
+                                else_result,
+                                ..
+                            } = &**case;
                             assert!(operand.is_some(), "Simple CASE should have operand");
                             assert_eq!(conditions.len(), 2, "Expected 2 WHEN clauses");
                             assert!(else_result.is_some(), "Expected ELSE clause");
@@ -1739,13 +1797,17 @@ mod f261_case_expression {
             |stmt: Statement| {
                 if let Statement::Query(q) = stmt {
                     if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                        if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Case {
-                            operand,
-                            conditions,
-                            else_result,
+                        if let sqlparser::ast::SelectItem::UnnamedExpr {
+                            expr: Expr::Case(case),
                             ..
-                        }) = &select.projection[0]
+                        } = &select.projection[0]
                         {
+                            let sqlparser::ast::CaseExpr {
+                                operand,
+                                conditions,
+                                else_result,
+                                ..
+                            } = &**case;
                             assert!(operand.is_some(), "Simple CASE should have operand");
                             assert_eq!(conditions.len(), 2);
                             assert!(else_result.is_none(), "No ELSE clause expected");
@@ -1766,13 +1828,17 @@ mod f261_case_expression {
             |stmt: Statement| {
                 if let Statement::Query(q) = stmt {
                     if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                        if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Case {
-                            operand,
-                            conditions,
-                            else_result,
+                        if let sqlparser::ast::SelectItem::UnnamedExpr {
+                            expr: Expr::Case(case),
                             ..
-                        }) = &select.projection[0]
+                        } = &select.projection[0]
                         {
+                            let sqlparser::ast::CaseExpr {
+                                operand,
+                                conditions,
+                                else_result,
+                                ..
+                            } = &**case;
                             assert!(operand.is_none(), "Searched CASE should not have operand");
                             assert_eq!(conditions.len(), 2, "Expected 2 WHEN clauses");
                             assert!(else_result.is_some(), "Expected ELSE clause");
@@ -1788,12 +1854,16 @@ mod f261_case_expression {
             |stmt: Statement| {
                 if let Statement::Query(q) = stmt {
                     if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                        if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Case {
-                            operand,
-                            conditions,
+                        if let sqlparser::ast::SelectItem::UnnamedExpr {
+                            expr: Expr::Case(case),
                             ..
-                        }) = &select.projection[0]
+                        } = &select.projection[0]
                         {
+                            let sqlparser::ast::CaseExpr {
+                                operand,
+                                conditions,
+                                ..
+                            } = &**case;
                             assert!(operand.is_none());
                             assert_eq!(conditions.len(), 1);
                         }
@@ -1809,8 +1879,10 @@ mod f261_case_expression {
         verified_with_ast!("SELECT NULLIF(a, b) FROM t", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                    if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Function(func)) =
-                        &select.projection[0]
+                    if let sqlparser::ast::SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
                     {
                         assert_eq!(func.name.to_string(), "nullif");
                         if let sqlparser::ast::FunctionArguments::List(arg_list) = &func.args {
@@ -1835,8 +1907,10 @@ mod f261_case_expression {
         verified_with_ast!("SELECT COALESCE(a, b, c, 0) FROM t", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                    if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Function(func)) =
-                        &select.projection[0]
+                    if let sqlparser::ast::SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &select.projection[0]
                     {
                         assert_eq!(func.name.to_string(), "coalesce");
                         if let sqlparser::ast::FunctionArguments::List(arg_list) = &func.args {
@@ -1863,13 +1937,15 @@ mod f261_case_expression {
             |stmt: Statement| {
                 if let Statement::Query(q) = stmt {
                     if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
-                        if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Case {
-                            conditions,
-                            ..
-                        }) = &select.projection[0]
+                        if let sqlparser::ast::SelectItem::UnnamedExpr { expr: Expr::Case(case), .. } =
+                            &select.projection[0]
                         {
+                            let sqlparser::ast::CaseExpr {
+                                conditions,
+                                ..
+                            } = &**case;
                             // Verify outer CASE has nested CASE in THEN clause
-                            if let Expr::Case { .. } = &conditions[0].result {
+                            if let Expr::Case(_) = &conditions[0].result {
                                 // Nested CASE confirmed
                             } else {
                                 panic!("Expected nested CASE in THEN clause");
@@ -2262,7 +2338,7 @@ mod f401_extended_joined_table {
                         // Verify three joins for four-way join
                         assert_eq!(select.from[0].joins.len(), 3);
                         // Verify each joined table
-                        let expected_tables = vec!["t2", "t3", "t4"];
+                        let expected_tables = ["t2", "t3", "t4"];
                         for (i, expected_name) in expected_tables.iter().enumerate() {
                             if let TableFactor::Table { name, .. } =
                                 &select.from[0].joins[i].relation
@@ -2298,8 +2374,10 @@ mod f471_scalar_subquery_values {
                     if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
                         assert_eq!(select.projection.len(), 2);
                         // Second projection item should be a subquery
-                        if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Subquery(_)) =
-                            &select.projection[1]
+                        if let sqlparser::ast::SelectItem::UnnamedExpr {
+                            expr: Expr::Subquery(_),
+                            ..
+                        } = &select.projection[1]
                         {
                             // Scalar subquery confirmed in SELECT list
                         } else {
@@ -2316,7 +2394,7 @@ mod f471_scalar_subquery_values {
                     if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
                         assert_eq!(select.projection.len(), 3);
                         // Third projection item should be a correlated subquery
-                        if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Subquery(subquery)) =
+                        if let sqlparser::ast::SelectItem::UnnamedExpr { expr: Expr::Subquery(subquery), .. } =
                             &select.projection[2]
                         {
                             // Verify it's a SELECT query
@@ -2396,8 +2474,10 @@ mod f471_scalar_subquery_values {
                 if let Statement::Query(q) = stmt {
                     if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
                         // Second projection should be a correlated subquery
-                        if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Subquery(subquery)) =
-                            &select.projection[1]
+                        if let sqlparser::ast::SelectItem::UnnamedExpr {
+                            expr: Expr::Subquery(subquery),
+                            ..
+                        } = &select.projection[1]
                         {
                             // Verify subquery has WHERE clause (correlation)
                             if let sqlparser::ast::SetExpr::Select(sub_select) =
@@ -2421,7 +2501,7 @@ mod f471_scalar_subquery_values {
                 if let Statement::Query(q) = stmt {
                     if let sqlparser::ast::SetExpr::Select(select) = q.body.as_ref() {
                         // Second projection should be a correlated subquery
-                        if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Subquery(subquery)) =
+                        if let sqlparser::ast::SelectItem::UnnamedExpr { expr: Expr::Subquery(subquery), .. } =
                             &select.projection[1]
                         {
                             // Verify it's a SELECT with WHERE clause
@@ -2823,10 +2903,10 @@ mod f850_f869_order_fetch_offset {
         // F861: OFFSET n ROWS
         verified_with_ast!("SELECT * FROM t OFFSET 10 ROWS", |stmt: Statement| {
             if let Statement::Query(q) = stmt {
-                if let Some(limit_clause) = q.limit_clause.as_deref() {
-                    if let sqlparser::ast::LimitClause::LimitOffset { offset, .. } = limit_clause {
-                        assert!(offset.is_some(), "Expected OFFSET clause");
-                    }
+                if let Some(sqlparser::ast::LimitClause::LimitOffset { offset, .. }) =
+                    q.limit_clause.as_deref()
+                {
+                    assert!(offset.is_some(), "Expected OFFSET clause");
                 }
             }
         });
@@ -2841,12 +2921,10 @@ mod f850_f869_order_fetch_offset {
             |stmt: Statement| {
                 if let Statement::Query(q) = stmt {
                     // Check OFFSET
-                    if let Some(limit_clause) = q.limit_clause.as_deref() {
-                        if let sqlparser::ast::LimitClause::LimitOffset { offset, .. } =
-                            limit_clause
-                        {
-                            assert!(offset.is_some());
-                        }
+                    if let Some(sqlparser::ast::LimitClause::LimitOffset { offset, .. }) =
+                        q.limit_clause.as_deref()
+                    {
+                        assert!(offset.is_some());
                     }
                     // Check FETCH
                     assert!(q.fetch.is_some(), "Expected FETCH clause");
@@ -2907,7 +2985,7 @@ mod f_series_integration_tests {
     use sqlparser::ast::{
         BinaryOperator, CaseWhen, CreateTable, Expr, Fetch, GrantObjects, GroupByExpr,
         JoinConstraint, JoinOperator, Offset, OffsetRows, Privileges, SelectItem, SetExpr,
-        SetOperator, Statement, TableFactor, Value, ValueWithSpan,
+        SetOperator, Statement, TableFactor, Value,
     };
 
     #[test]
@@ -2942,8 +3020,8 @@ mod f_series_integration_tests {
 
                         // Verify FETCH FIRST clause
                         assert!(q.fetch.is_some());
-                        if let Some(Fetch { quantity: Some(quantity), .. }) = q.fetch.as_deref() {
-                            if let Expr::Value(ValueWithSpan { value: Value::Number(n, _), .. }) = quantity {
+                        if let Some(Fetch { quantity: Some(Expr::Value(value)), .. }) = q.fetch.as_deref() {
+                            if let Value::Number(n, _) = &value.value {
                                 assert_eq!(n.to_string(), "10");
                             }
                         }
@@ -3012,18 +3090,20 @@ mod f_series_integration_tests {
                     assert!(q.order_by.is_some());
 
                     // Verify OFFSET in limit_clause
-                    if let Some(sqlparser::ast::LimitClause::LimitOffset { offset: Some(offset), .. }) =
+                    if let Some(sqlparser::ast::LimitClause::LimitOffset { offset: Some(Offset { value: Expr::Value(value), rows: OffsetRows::Rows }), .. }) =
                         q.limit_clause.as_deref()
                     {
-                        if let Offset { value: Expr::Value(ValueWithSpan { value: Value::Number(n, _), .. }), rows: OffsetRows::Rows } = offset {
+                        if let Value::Number(n, _) = &value.value {
                             assert_eq!(n.to_string(), "10");
                         }
                     }
 
                     // Verify FETCH
                     assert!(q.fetch.is_some());
-                    if let Some(Fetch { quantity: Some(Expr::Value(ValueWithSpan { value: Value::Number(n, _), .. })), .. }) = q.fetch.as_deref() {
-                        assert_eq!(n.to_string(), "20");
+                    if let Some(Fetch { quantity: Some(Expr::Value(value)), .. }) = q.fetch.as_deref() {
+                        if let Value::Number(n, _) = &value.value {
+                            assert_eq!(n.to_string(), "20");
+                        }
                     }
                 }
             }
@@ -3040,7 +3120,8 @@ mod f_series_integration_tests {
                     if let SetExpr::Select(select) = q.body.as_ref() {
                         // Verify CASE expression in projection
                         assert_eq!(select.projection.len(), 2);
-                        if let SelectItem::ExprWithAlias { expr: Expr::Case { conditions, .. }, .. } = &select.projection[1] {
+                        if let SelectItem::ExprWithAlias { expr: Expr::Case(case), .. } = &select.projection[1] {
+                            let conditions = &case.conditions;
                             // Verify multiple WHEN clauses (at least 2)
                             assert!(conditions.len() >= 2);
 
@@ -3134,7 +3215,9 @@ mod f_series_integration_tests {
         verified_with_ast!(
             "CREATE TABLE production.products (id INTEGER, name VARCHAR(100))",
             |stmt: Statement| {
-                if let Statement::CreateTable(CreateTable { name, columns, .. }) = stmt {
+                if let Statement::CreateTable(create_table) = stmt {
+                    let CreateTable { name, columns, .. } =
+                        sqlparser::ast::AstBox::into_owned(create_table);
                     assert_eq!(name.to_string(), "production.products");
                     assert_eq!(columns.len(), 2);
                 }
@@ -3157,7 +3240,7 @@ mod f_series_integration_tests {
                     }
 
                     // Verify object
-                    if let Some(GrantObjects::Tables(tables)) = objects {
+                    if let Some(GrantObjects::Tables(tables)) = objects.as_deref() {
                         assert_eq!(tables.len(), 1);
                         assert_eq!(tables[0].to_string(), "production.products");
                     }
@@ -3184,7 +3267,7 @@ mod f_series_integration_tests {
                     }
 
                     // Verify object
-                    if let Some(GrantObjects::Tables(tables)) = objects {
+                    if let Some(GrantObjects::Tables(tables)) = objects.as_deref() {
                         assert_eq!(tables.len(), 1);
                         assert_eq!(tables[0].to_string(), "production.products");
                     }
@@ -3259,8 +3342,10 @@ mod f_series_integration_tests {
 
                         // Verify FETCH
                         assert!(q.fetch.is_some());
-                        if let Some(Fetch { quantity: Some(Expr::Value(ValueWithSpan { value: Value::Number(n, _), .. })), .. }) = q.fetch.as_deref() {
-                            assert_eq!(n.to_string(), "50");
+                        if let Some(Fetch { quantity: Some(Expr::Value(value)), .. }) = q.fetch.as_deref() {
+                            if let Value::Number(n, _) = &value.value {
+                                assert_eq!(n.to_string(), "50");
+                            }
                         }
                     }
                 }
@@ -39,8 +39,7 @@
 
 use crate::standards::common::verified_standard_stmt;
 use sqlparser::ast::{
-    Expr, Statement, TableSampleKind, TableSampleMethod, Value, ValueWithSpan, WindowFrameBound,
-    WindowFrameUnits,
+    Expr, Statement, TableSampleKind, TableSampleMethod, Value, WindowFrameBound, WindowFrameUnits,
 };
 
 // =============================================================================
@@ -161,8 +160,10 @@ mod t612_advanced_olap {
                     Statement::Query(query) => {
                         if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                             let projection = &select.projection[0];
-                            if let sqlparser::ast::SelectItem::UnnamedExpr(Expr::Function(func)) =
-                                projection
+                            if let sqlparser::ast::SelectItem::UnnamedExpr {
+                                expr: Expr::Function(func),
+                                ..
+                            } = projection
                             {
                                 if let Some(sqlparser::ast::WindowType::WindowSpec(spec)) =
                                     &func.over
@@ -176,34 +177,28 @@ mod t612_advanced_olap {
 
                                     // Verify start bound: 10 PRECEDING
                                     match &frame.start_bound {
-                                        WindowFrameBound::Preceding(Some(expr)) => {
-                                            if let Expr::Value(ValueWithSpan {
-                                                value: Value::Number(n, _),
-                                                ..
-                                            }) = &**expr
+                                        WindowFrameBound::Preceding(Some(expr)) => match &**expr {
+                                            Expr::Value(value)
+                                                if matches!(value.value, Value::Number(..)) =>
                                             {
-                                                assert_eq!(n.to_string(), "10");
-                                            } else {
-                                                panic!("Expected numeric value for PRECEDING");
+                                                assert_eq!(value.to_string(), "10");
                                             }
-                                        }
+                                            _ => panic!("Expected numeric value for PRECEDING"),
+                                        },
                                         _ => panic!("Expected PRECEDING bound with value"),
                                     }
 
                                     // Verify end bound: 10 FOLLOWING
                                     assert!(frame.end_bound.is_some());
                                     match frame.end_bound.as_ref().unwrap() {
-                                        WindowFrameBound::Following(Some(expr)) => {
-                                            if let Expr::Value(ValueWithSpan {
-                                                value: Value::Number(n, _),
-                                                ..
-                                            }) = &**expr
+                                        WindowFrameBound::Following(Some(expr)) => match &**expr {
+                                            Expr::Value(value)
+                                                if matches!(value.value, Value::Number(..)) =>
                                             {
-                                                assert_eq!(n.to_string(), "10");
-                                            } else {
-                                                panic!("Expected numeric value for FOLLOWING");
+                                                assert_eq!(value.to_string(), "10");
                                             }
-                                        }
+                                            _ => panic!("Expected numeric value for FOLLOWING"),
+                                        },
                                         _ => panic!("Expected FOLLOWING bound with value"),
                                     }
                                 } else {
@@ -324,14 +319,13 @@ mod t613_sampling {
                                     // Verify the quantity is 10 and parenthesized
                                     let quantity = ts.quantity.as_ref().unwrap();
                                     assert!(quantity.parenthesized);
-                                    if let Expr::Value(ValueWithSpan {
-                                        value: Value::Number(n, _),
-                                        ..
-                                    }) = &quantity.value
-                                    {
-                                        assert_eq!(n.to_string(), "10");
-                                    } else {
-                                        panic!("Expected numeric value for sample quantity");
+                                    match &quantity.value {
+                                        Expr::Value(value)
+                                            if matches!(value.value, Value::Number(..)) =>
+                                        {
+                                            assert_eq!(value.to_string(), "10");
+                                        }
+                                        _ => panic!("Expected numeric value for sample quantity"),
                                     }
                                 } else {
                                     panic!("Expected AfterTableAlias position");
@@ -376,14 +370,13 @@ mod t613_sampling {
                                     // Verify the quantity is 5 and parenthesized
                                     let quantity = ts.quantity.as_ref().unwrap();
                                     assert!(quantity.parenthesized);
-                                    if let Expr::Value(ValueWithSpan {
-                                        value: Value::Number(n, _),
-                                        ..
-                                    }) = &quantity.value
-                                    {
-                                        assert_eq!(n.to_string(), "5");
-                                    } else {
-                                        panic!("Expected numeric value for sample quantity");
+                                    match &quantity.value {
+                                        Expr::Value(value)
+                                            if matches!(value.value, Value::Number(..)) =>
+                                        {
+                                            assert_eq!(value.to_string(), "5");
+                                        }
+                                        _ => panic!("Expected numeric value for sample quantity"),
                                     }
                                 } else {
                                     panic!("Expected AfterTableAlias position");
@@ -67,7 +67,10 @@ fn t860_01_json_dot_notation_simple() {
             if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                 assert_eq!(select.projection.len(), 1);
                 match &select.projection[0] {
-                    SelectItem::UnnamedExpr(Expr::CompoundIdentifier(idents)) => {
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::CompoundIdentifier(idents),
+                        ..
+                    } => {
                         assert_eq!(idents.len(), 3);
                         assert_eq!(idents[0].value, "t");
                         assert_eq!(idents[1].value, "data");
@@ -94,7 +97,10 @@ fn t860_02_json_dot_notation_nested() {
         Statement::Query(query) => {
             if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                 match &select.projection[0] {
-                    SelectItem::UnnamedExpr(Expr::CompoundIdentifier(idents)) => {
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::CompoundIdentifier(idents),
+                        ..
+                    } => {
                         assert_eq!(idents.len(), 4);
                         assert_eq!(idents[0].value, "t");
                         assert_eq!(idents[1].value, "profile");
@@ -120,7 +126,10 @@ fn t860_03_json_dot_notation_deep_nesting() {
         Statement::Query(query) => {
             if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                 match &select.projection[0] {
-                    SelectItem::UnnamedExpr(Expr::CompoundIdentifier(idents)) => {
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::CompoundIdentifier(idents),
+                        ..
+                    } => {
                         assert_eq!(idents.len(), 6);
                         assert_eq!(idents[0].value, "doc");
                         assert_eq!(idents[5].value, "value");
@@ -144,7 +153,10 @@ fn t861_01_json_array_subscript_basic() {
         Statement::Query(query) => {
             if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                 match &select.projection[0] {
-                    SelectItem::UnnamedExpr(Expr::CompoundFieldAccess { root, access_chain }) => {
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::CompoundFieldAccess { root, access_chain },
+                        ..
+                    } => {
                         // Root should be identifier "t"
                         assert!(matches!(**root, Expr::Identifier(_)));
                         // Should have 3 access elements: .data, .items, [0]
@@ -177,7 +189,10 @@ fn t861_02_json_array_subscript_nested() {
         Statement::Query(query) => {
             if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                 match &select.projection[0] {
-                    SelectItem::UnnamedExpr(Expr::CompoundFieldAccess { access_chain, .. }) => {
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::CompoundFieldAccess { access_chain, .. },
+                        ..
+                    } => {
                         // Should have 4 access elements: .data, .items, [0], .name
                         assert_eq!(access_chain.len(), 4);
                         // Third should be subscript, fourth should be dot
@@ -206,7 +221,10 @@ fn t861_03_json_array_multiple_subscripts() {
         Statement::Query(query) => {
             if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                 match &select.projection[0] {
-                    SelectItem::UnnamedExpr(Expr::CompoundFieldAccess { access_chain, .. }) => {
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::CompoundFieldAccess { access_chain, .. },
+                        ..
+                    } => {
                         // Should have 3 access elements: .matrix, [0], [1]
                         assert_eq!(access_chain.len(), 3);
                         // Last two should be subscripts
@@ -256,7 +274,10 @@ fn t863_01_json_combined_access() {
         Statement::Query(query) => {
             if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                 match &select.projection[0] {
-                    SelectItem::UnnamedExpr(Expr::CompoundFieldAccess { access_chain, .. }) => {
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::CompoundFieldAccess { access_chain, .. },
+                        ..
+                    } => {
                         // Should have complex chain: .data, .list, [0], .field, .nested, [1]
                         assert_eq!(access_chain.len(), 6);
                         // Check pattern: dot, dot, subscript, dot, dot, subscript
@@ -306,7 +327,10 @@ fn t865_01_json_bigint_method() {
         Statement::Query(query) => {
             if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                 match &select.projection[0] {
-                    SelectItem::UnnamedExpr(Expr::Function(func)) => {
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } => {
                         // Function name should be compound: t.data.id.bigint
                         assert_eq!(func.name.0.len(), 4);
                         if let sqlparser::ast::ObjectNamePart::Identifier(ident) = &func.name.0[3] {
@@ -171,7 +171,10 @@ fn mda_e01_simple_1d_constructor() {
         Statement::Query(query) => {
             if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                 match &select.projection[0] {
-                    SelectItem::UnnamedExpr(Expr::MdArray(MdArray { dimensions, values })) => {
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::MdArray(MdArray { dimensions, values }),
+                        ..
+                    } => {
                         // Check dimension
                         assert_eq!(dimensions.len(), 1);
                         assert_eq!(dimensions[0].name.value, "x");
@@ -197,7 +200,10 @@ fn mda_e02_2d_constructor() {
         Statement::Query(query) => {
             if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                 match &select.projection[0] {
-                    SelectItem::UnnamedExpr(Expr::MdArray(MdArray { dimensions, values })) => {
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::MdArray(MdArray { dimensions, values }),
+                        ..
+                    } => {
                         assert_eq!(dimensions.len(), 2);
                         assert_eq!(dimensions[0].name.value, "x");
                         assert_eq!(dimensions[1].name.value, "y");
@@ -219,7 +225,10 @@ fn mda_e03_open_upper_bound() {
         Statement::Query(query) => {
             if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                 match &select.projection[0] {
-                    SelectItem::UnnamedExpr(Expr::MdArray(MdArray { dimensions, .. })) => {
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::MdArray(MdArray { dimensions, .. }),
+                        ..
+                    } => {
                         assert_eq!(dimensions.len(), 1);
                         assert!(dimensions[0].lower_bound.is_some());
                         assert!(dimensions[0].upper_bound.is_none()); // Open upper bound
@@ -240,7 +249,10 @@ fn mda_e04_open_lower_bound() {
         Statement::Query(query) => {
             if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                 match &select.projection[0] {
-                    SelectItem::UnnamedExpr(Expr::MdArray(MdArray { dimensions, .. })) => {
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::MdArray(MdArray { dimensions, .. }),
+                        ..
+                    } => {
                         assert_eq!(dimensions.len(), 1);
                         assert!(dimensions[0].lower_bound.is_none()); // Open lower bound
                         assert!(dimensions[0].upper_bound.is_some());
@@ -261,7 +273,10 @@ fn mda_e05_dimensions_without_bounds() {
         Statement::Query(query) => {
             if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                 match &select.projection[0] {
-                    SelectItem::UnnamedExpr(Expr::MdArray(MdArray { dimensions, values })) => {
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::MdArray(MdArray { dimensions, values }),
+                        ..
+                    } => {
                         assert_eq!(dimensions.len(), 2);
                         assert_eq!(dimensions[0].name.value, "x");
                         assert_eq!(dimensions[1].name.value, "y");
@@ -288,7 +303,10 @@ fn mda_e06_empty_constructor() {
         Statement::Query(query) => {
             if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                 match &select.projection[0] {
-                    SelectItem::UnnamedExpr(Expr::MdArray(MdArray { dimensions, values })) => {
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::MdArray(MdArray { dimensions, values }),
+                        ..
+                    } => {
                         assert_eq!(dimensions.len(), 1);
                         assert_eq!(values.len(), 0);
                     }
@@ -309,7 +327,10 @@ fn mda_e07_3d_constructor() {
         Statement::Query(query) => {
             if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                 match &select.projection[0] {
-                    SelectItem::UnnamedExpr(Expr::MdArray(MdArray { dimensions, values })) => {
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::MdArray(MdArray { dimensions, values }),
+                        ..
+                    } => {
                         assert_eq!(dimensions.len(), 3);
                         assert_eq!(values.len(), 8);
                     }
@@ -329,7 +350,10 @@ fn mda_e08_expression_bounds() {
         Statement::Query(query) => {
             if let sqlparser::ast::SetExpr::Select(select) = query.body.as_ref() {
                 match &select.projection[0] {
-                    SelectItem::UnnamedExpr(Expr::MdArray(MdArray { dimensions, .. })) => {
+                    SelectItem::UnnamedExpr {
+                        expr: Expr::MdArray(MdArray { dimensions, .. }),
+                        ..
+                    } => {
                         assert_eq!(dimensions.len(), 1);
                         // Verify bounds are numeric literals
                         match &dimensions[0].lower_bound {
@@ -49,16 +49,20 @@
 
 use crate::standards::common::{one_statement_parses_to_std, verified_standard_stmt};
 use sqlparser::ast::{
-    CreateTable, DataType, Expr, Function, FunctionArg, FunctionArgExpr, FunctionArgumentList,
-    FunctionArguments, SelectItem, SetExpr, Statement,
+    AstBox, CreateTable, DataType, Expr, Function, FunctionArg, FunctionArgExpr,
+    FunctionArgumentList, FunctionArguments, SelectItem, SetExpr, Statement,
 };
 
 /// Helper function to extract a function call from a SELECT statement
 fn extract_function_from_select(stmt: Statement) -> Function {
     if let Statement::Query(q) = stmt {
         if let SetExpr::Select(sel) = q.body.as_ref() {
-            if let Some(SelectItem::UnnamedExpr(Expr::Function(func))) = sel.projection.first() {
-                return func.clone();
+            if let Some(SelectItem::UnnamedExpr {
+                expr: Expr::Function(func),
+                ..
+            }) = sel.projection.first()
+            {
+                return (**func).clone();
             }
         }
     }
@@ -215,7 +219,7 @@ fn t054_05_greatest_least_in_where() {
                     if let Some(Expr::BinaryOp { left, .. }) = sel.selection.as_deref() {
                         if let Expr::Function(func) = left.as_ref() {
                             assert_eq!(func.name.to_string(), "greatest");
-                            let args = get_function_args(&func);
+                            let args = get_function_args(func);
                             assert_eq!(args.len(), 3);
                         } else {
                             panic!("Expected GREATEST function in WHERE");
@@ -234,7 +238,7 @@ fn t054_05_greatest_least_in_where() {
                     if let Some(Expr::BinaryOp { left, .. }) = sel.selection.as_deref() {
                         if let Expr::Function(func) = left.as_ref() {
                             assert_eq!(func.name.to_string(), "least");
-                            let args = get_function_args(&func);
+                            let args = get_function_args(func);
                             assert_eq!(args.len(), 2);
                         }
                     }
@@ -420,11 +424,15 @@ fn t056_01_trim_multi_char_leading() {
     verified_with_ast!("SELECT TRIM(LEADING 'abc' FROM name)", |stmt: Statement| {
         if let Statement::Query(q) = stmt {
             if let SetExpr::Select(sel) = q.body.as_ref() {
-                if let Some(SelectItem::UnnamedExpr(Expr::Trim {
-                    trim_where,
-                    trim_what,
+                if let Some(SelectItem::UnnamedExpr {
+                    expr:
+                        Expr::Trim {
+                            trim_where,
+                            trim_what,
+                            ..
+                        },
                     ..
-                })) = sel.projection.first()
+                }) = sel.projection.first()
                 {
                     assert!(matches!(
                         trim_where,
@@ -443,11 +451,15 @@ fn t056_01_trim_multi_char_leading() {
         |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let SetExpr::Select(sel) = q.body.as_ref() {
-                    if let Some(SelectItem::UnnamedExpr(Expr::Trim {
-                        trim_where,
-                        trim_what,
+                    if let Some(SelectItem::UnnamedExpr {
+                        expr:
+                            Expr::Trim {
+                                trim_where,
+                                trim_what,
+                                ..
+                            },
                         ..
-                    })) = sel.projection.first()
+                    }) = sel.projection.first()
                     {
                         assert!(matches!(
                             trim_where,
@@ -471,11 +483,15 @@ fn t056_02_trim_multi_char_trailing() {
         |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let SetExpr::Select(sel) = q.body.as_ref() {
-                    if let Some(SelectItem::UnnamedExpr(Expr::Trim {
-                        trim_where,
-                        trim_what,
+                    if let Some(SelectItem::UnnamedExpr {
+                        expr:
+                            Expr::Trim {
+                                trim_where,
+                                trim_what,
+                                ..
+                            },
                         ..
-                    })) = sel.projection.first()
+                    }) = sel.projection.first()
                     {
                         assert!(matches!(
                             trim_where,
@@ -499,11 +515,15 @@ fn t056_03_trim_multi_char_both() {
         |stmt: Statement| {
             if let Statement::Query(q) = stmt {
                 if let SetExpr::Select(sel) = q.body.as_ref() {
-                    if let Some(SelectItem::UnnamedExpr(Expr::Trim {
-                        trim_where,
-                        trim_what,
+                    if let Some(SelectItem::UnnamedExpr {
+                        expr:
+                            Expr::Trim {
+                                trim_where,
+                                trim_what,
+                                ..
+                            },
                         ..
-                    })) = sel.projection.first()
+                    }) = sel.projection.first()
                     {
                         assert!(matches!(
                             trim_where,
@@ -686,7 +706,8 @@ fn t056_09_btrim_two_args() {
 fn t081_01_varchar_no_length() {
     // SQL:2023 T081: VARCHAR without length specification
     verified_with_ast!("CREATE TABLE t (a VARCHAR)", |stmt: Statement| {
-        if let Statement::CreateTable(CreateTable { columns, .. }) = stmt {
+        if let Statement::CreateTable(create_table) = stmt {
+            let CreateTable { columns, .. } = AstBox::into_owned(create_table);
             assert_eq!(columns.len(), 1);
             assert_eq!(columns[0].name.to_string(), "a");
             // VARCHAR without length should be represented as Varchar(None) or similar
@@ -702,7 +723,8 @@ fn t081_01_varchar_no_length() {
     verified_with_ast!(
         "CREATE TABLE users (name VARCHAR, email VARCHAR)",
         |stmt: Statement| {
-            if let Statement::CreateTable(CreateTable { columns, .. }) = stmt {
+            if let Statement::CreateTable(create_table) = stmt {
+                let CreateTable { columns, .. } = AstBox::into_owned(create_table);
                 assert_eq!(columns.len(), 2);
                 assert_eq!(columns[0].name.to_string(), "name");
                 assert_eq!(columns[1].name.to_string(), "email");
@@ -727,7 +749,8 @@ fn t081_02_varchar_no_length_multiple_columns() {
     verified_with_ast!(
         "CREATE TABLE products (name VARCHAR, description VARCHAR, category VARCHAR)",
         |stmt: Statement| {
-            if let Statement::CreateTable(CreateTable { columns, .. }) = stmt {
+            if let Statement::CreateTable(create_table) = stmt {
+                let CreateTable { columns, .. } = AstBox::into_owned(create_table);
                 assert_eq!(columns.len(), 3);
                 for col in &columns {
                     assert!(matches!(
@@ -748,7 +771,8 @@ fn t081_03_varchar_mixed_with_length() {
     verified_with_ast!(
         "CREATE TABLE t (short_name VARCHAR(50), long_description VARCHAR)",
         |stmt: Statement| {
-            if let Statement::CreateTable(CreateTable { columns, .. }) = stmt {
+            if let Statement::CreateTable(create_table) = stmt {
+                let CreateTable { columns, .. } = AstBox::into_owned(create_table);
                 assert_eq!(columns.len(), 2);
                 assert_eq!(columns[0].name.to_string(), "short_name");
                 assert_eq!(columns[1].name.to_string(), "long_description");
@@ -770,7 +794,8 @@ fn t081_03_varchar_mixed_with_length() {
     verified_with_ast!(
         "CREATE TABLE users (username VARCHAR(32), bio VARCHAR, email VARCHAR(255))",
         |stmt: Statement| {
-            if let Statement::CreateTable(CreateTable { columns, .. }) = stmt {
+            if let Statement::CreateTable(create_table) = stmt {
+                let CreateTable { columns, .. } = AstBox::into_owned(create_table);
                 assert_eq!(columns.len(), 3);
                 for col in &columns {
                     assert!(matches!(
@@ -845,11 +870,19 @@ fn t626_02_any_value_group_by() {
                 if let SetExpr::Select(sel) = q.body.as_ref() {
                     assert_eq!(sel.projection.len(), 3);
                     // Second item should be ANY_VALUE
-                    if let SelectItem::UnnamedExpr(Expr::Function(func)) = &sel.projection[1] {
+                    if let SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &sel.projection[1]
+                    {
                         assert_eq!(func.name.to_string(), "any_value");
                     }
                     // Third item should be COUNT
-                    if let SelectItem::UnnamedExpr(Expr::Function(func)) = &sel.projection[2] {
+                    if let SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &sel.projection[2]
+                    {
                         assert_eq!(func.name.to_string(), "count");
                     }
                 }
@@ -864,7 +897,11 @@ fn t626_02_any_value_group_by() {
                 if let SetExpr::Select(sel) = q.body.as_ref() {
                     assert_eq!(sel.projection.len(), 2);
                     // Second item should be ANY_VALUE
-                    if let SelectItem::UnnamedExpr(Expr::Function(func)) = &sel.projection[1] {
+                    if let SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &sel.projection[1]
+                    {
                         assert_eq!(func.name.to_string(), "any_value");
                     }
                 }
@@ -883,10 +920,10 @@ fn t626_03_any_value_complex() {
                 if let SetExpr::Select(sel) = q.body.as_ref() {
                     assert_eq!(sel.projection.len(), 4);
                     // Second and third items should be ANY_VALUE
-                    if let SelectItem::UnnamedExpr(Expr::Function(func)) = &sel.projection[1] {
+                    if let SelectItem::UnnamedExpr { expr: Expr::Function(func), .. } = &sel.projection[1] {
                         assert_eq!(func.name.to_string(), "any_value");
                     }
-                    if let SelectItem::UnnamedExpr(Expr::Function(func)) = &sel.projection[2] {
+                    if let SelectItem::UnnamedExpr { expr: Expr::Function(func), .. } = &sel.projection[2] {
                         assert_eq!(func.name.to_string(), "any_value");
                     }
                 }
@@ -917,7 +954,11 @@ fn t626_04_any_value_with_having() {
                 if let SetExpr::Select(sel) = q.body.as_ref() {
                     assert_eq!(sel.projection.len(), 2);
                     // Second item should be ANY_VALUE
-                    if let SelectItem::UnnamedExpr(Expr::Function(func)) = &sel.projection[1] {
+                    if let SelectItem::UnnamedExpr {
+                        expr: Expr::Function(func),
+                        ..
+                    } = &sel.projection[1]
+                    {
                         assert_eq!(func.name.to_string(), "any_value");
                     }
                     // Verify HAVING clause exists
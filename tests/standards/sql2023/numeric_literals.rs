@@ -60,7 +60,7 @@ fn get_first_projection(stmt: &Statement) -> Option<&SelectItem> {
 /// Helper to extract the first value from a SELECT statement
 fn get_first_value(stmt: &Statement) -> Option<&Value> {
     match get_first_projection(stmt)? {
-        SelectItem::UnnamedExpr(Expr::Value(val)) => Some(&val.value),
+        SelectItem::UnnamedExpr { expr: Expr::Value(val), .. } => Some(&val.value),
         _ => None,
     }
 }
@@ -33,7 +33,7 @@ macro_rules! nest {
     ($base:expr $(, $join:expr)*) => {
         TableFactor::NestedJoin { table_with_joins: Box::new(TableWithJoins {
             relation: $base,
-            joins: vec![$(join($join)),*]
+            joins: vec![$(join($join)),*].into()
         }), alias: None}
     };
 }